@@ -0,0 +1,327 @@
+// Block audit - Re-execute stored blocks and report where they diverge
+// Principle: Auditable means every stored block can be re-derived from genesis
+//
+// `audit --from N --to M` replays the stored chain into an in-memory node
+// built from the same genesis, through the regular import path. Blocks before
+// N only rebuild the parent state; each block of [N, M] is then checked:
+// state root, receipts root and fee accounting (fees of the stored receipts,
+// governed fee split, author and treasury credits). A block that does not
+// re-execute to its header ends the audit: later blocks have no known parent
+// state. The report is JSON, for scripts and CI.
+
+use crate::cli::export::parse_account;
+use crate::cli::AuditCmd;
+use crate::consensus::economics::FeeDistribution;
+use crate::genesis::{ChainConfig, GenesisSpec};
+use crate::node::service::{BlockExecution, KratOsNode, NodeError};
+use crate::storage::{open_existing, DatabaseBackend, StateBackend};
+use crate::types::{Balance, BlockNumber, Hash};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tracing::{info, warn};
+
+/// Progress log interval in blocks
+const PROGRESS_INTERVAL: u64 = 1000;
+
+/// Outcome of an audit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditReport {
+    pub genesis_hash: Hash,
+    pub from: BlockNumber,
+    pub to: BlockNumber,
+    /// Blocks of the range that re-executed without divergence
+    pub blocks_verified: u64,
+    /// Fees of the verified blocks
+    pub total_fees: Balance,
+    pub divergences: Vec<Divergence>,
+}
+
+impl AuditReport {
+    pub fn is_clean(&self) -> bool {
+        self.divergences.is_empty()
+    }
+}
+
+/// A block that does not re-execute to what is stored
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Divergence {
+    pub block: BlockNumber,
+    pub kind: DivergenceKind,
+    /// Stored value (header, receipts, governed split)
+    pub expected: String,
+    /// Value re-execution gave
+    pub computed: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DivergenceKind {
+    StateRoot,
+    ReceiptsRoot,
+    /// Fees of the stored receipts
+    Fees,
+    /// Fee shares invalid, or a split losing fees
+    FeeSplit,
+    /// Author credited other than its block reward and fee share
+    ProducerCredit,
+    /// Treasury credited other than its fee share (and the finality voters',
+    /// which imported blocks have none of)
+    TreasuryCredit,
+    /// Block refused by the import path (signature, failed transaction...)
+    Rejected,
+}
+
+/// Re-execute blocks [from, to] of the node database
+pub async fn audit_chain(cmd: &AuditCmd) -> Result<AuditReport, AuditError> {
+    let base_path = cmd.get_base_path();
+    if !base_path.exists() {
+        return Err(AuditError::NotFound(base_path.display().to_string()));
+    }
+    let db = open_existing(&base_path).map_err(|e| AuditError::Storage(e.to_string()))?;
+    let stored = StateBackend::new(db);
+
+    let genesis_hash = stored
+        .get_genesis_hash()
+        .map_err(|e| AuditError::Storage(format!("{:?}", e)))?
+        .ok_or_else(|| AuditError::NotFound(base_path.display().to_string()))?;
+    let best = stored
+        .get_best_block()
+        .map_err(|e| AuditError::Storage(format!("{:?}", e)))?
+        .unwrap_or(0);
+    let to = cmd.to.unwrap_or(best).min(best);
+    if cmd.from == 0 || cmd.from > to {
+        return Err(AuditError::InvalidRange { from: cmd.from, to });
+    }
+
+    let genesis = match cmd.genesis_validator {
+        Some(ref account) => {
+            GenesisSpec::with_validator(parse_account(account).map_err(|e| AuditError::InvalidAccount(e.to_string()))?)
+        }
+        None => GenesisSpec::default(),
+    };
+
+    // Offline node, state in memory: ephemeral P2P port, no bootnodes
+    let scratch = std::env::temp_dir().join(format!("kratos-audit-{}", std::process::id()));
+    std::fs::create_dir_all(&scratch)?;
+    let report = replay(&stored, &scratch, genesis, genesis_hash, cmd.from, to).await;
+    if let Err(e) = std::fs::remove_dir_all(&scratch) {
+        warn!("Failed to remove {}: {}", scratch.display(), e);
+    }
+    report
+}
+
+async fn replay(
+    stored: &StateBackend,
+    scratch: &Path,
+    genesis: GenesisSpec,
+    genesis_hash: Hash,
+    from: BlockNumber,
+    to: BlockNumber,
+) -> Result<AuditReport, AuditError> {
+    let mut chain = ChainConfig::mainnet();
+    chain.network.listen_port = 0;
+    chain.network.bootnodes.clear();
+    let node = KratOsNode::with_database(chain, scratch, genesis, true, DatabaseBackend::Memory)
+        .await
+        .map_err(|e| AuditError::Node(e.to_string()))?;
+    if node.genesis_hash() != genesis_hash {
+        return Err(AuditError::GenesisMismatch { expected: genesis_hash, actual: node.genesis_hash() });
+    }
+
+    let mut report = AuditReport {
+        genesis_hash,
+        from,
+        to,
+        blocks_verified: 0,
+        total_fees: 0,
+        divergences: Vec::new(),
+    };
+    for number in 1..=to {
+        let block = stored
+            .get_block_by_number(number)
+            .map_err(|e| AuditError::Storage(format!("{:?}", e)))?
+            .ok_or(AuditError::MissingBlock(number))?;
+
+        // Fees of the stored receipts (None once receipts were pruned)
+        let mut stored_fees = Some::<Balance>(0);
+        for tx in &block.body.transactions {
+            let receipt = stored.get_receipt(&tx.hash()).map_err(|e| AuditError::Storage(format!("{:?}", e)))?;
+            stored_fees = stored_fees.zip(receipt).map(|(fees, r)| fees.saturating_add(r.receipt.fee_paid));
+        }
+
+        match node.import_block_execution(block).await {
+            Ok(Some(execution)) => {
+                if number >= from {
+                    let divergences = check_fees(&execution, stored_fees);
+                    if divergences.is_empty() {
+                        report.blocks_verified += 1;
+                        report.total_fees = report.total_fees.saturating_add(execution.total_fees);
+                    }
+                    report.divergences.extend(divergences);
+                }
+            }
+            Ok(None) => {}
+            Err(NodeError::RootMismatch { root, expected, computed }) => {
+                let kind = if root == "State" { DivergenceKind::StateRoot } else { DivergenceKind::ReceiptsRoot };
+                report.divergences.push(Divergence {
+                    block: number,
+                    kind,
+                    expected: expected.to_string(),
+                    computed: computed.to_string(),
+                });
+                break;
+            }
+            Err(e) => {
+                report.divergences.push(Divergence {
+                    block: number,
+                    kind: DivergenceKind::Rejected,
+                    expected: "valid block".to_string(),
+                    computed: e.to_string(),
+                });
+                break;
+            }
+        }
+
+        if number % PROGRESS_INTERVAL == 0 {
+            info!("🔎 Re-executed block #{}/{}", number, to);
+        }
+    }
+
+    Ok(report)
+}
+
+/// Fee accounting divergences of a re-executed block
+///
+/// `stored_fees` sums the fees of the block's stored receipts. Without
+/// finality voters at import, their share goes to the treasury.
+pub fn check_fees(execution: &BlockExecution, stored_fees: Option<Balance>) -> Vec<Divergence> {
+    let divergence = |kind, expected: String, computed: String| Divergence {
+        block: execution.number,
+        kind,
+        expected,
+        computed,
+    };
+    let mut divergences = Vec::new();
+
+    if let Some(stored_fees) = stored_fees {
+        if stored_fees != execution.total_fees {
+            divergences.push(divergence(
+                DivergenceKind::Fees,
+                stored_fees.to_string(),
+                execution.total_fees.to_string(),
+            ));
+        }
+    }
+
+    let shares = &execution.fee_shares;
+    if !shares.is_valid() {
+        divergences.push(divergence(
+            DivergenceKind::FeeSplit,
+            "valid fee shares".to_string(),
+            format!("{}/{}/{}/{}", shares.producer, shares.finality_voters, shares.burn, shares.treasury),
+        ));
+        return divergences;
+    }
+    let split = FeeDistribution::from_shares(shares).distribute(execution.total_fees);
+    let distributed = split
+        .producer
+        .saturating_add(split.finality_voters)
+        .saturating_add(split.burn)
+        .saturating_add(split.treasury);
+    if distributed != execution.total_fees {
+        divergences.push(divergence(
+            DivergenceKind::FeeSplit,
+            execution.total_fees.to_string(),
+            distributed.to_string(),
+        ));
+    }
+
+    if execution.producer_credit != execution.producer_reward || execution.producer_reward < split.producer {
+        divergences.push(divergence(
+            DivergenceKind::ProducerCredit,
+            execution.producer_reward.to_string(),
+            execution.producer_credit.to_string(),
+        ));
+    }
+    let treasury_share = split.treasury.saturating_add(split.finality_voters);
+    if execution.treasury_credit != treasury_share {
+        divergences.push(divergence(
+            DivergenceKind::TreasuryCredit,
+            treasury_share.to_string(),
+            execution.treasury_credit.to_string(),
+        ));
+    }
+    divergences
+}
+
+/// Audit errors (a divergence is not one: it is in the report)
+#[derive(Debug, thiserror::Error)]
+pub enum AuditError {
+    #[error("No chain data at {0}")]
+    NotFound(String),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Storage error: {0} (is the node running?)")]
+    Storage(String),
+
+    #[error("Invalid block range: {from}..{to} (block #0 is the genesis, audits start at #1)")]
+    InvalidRange { from: BlockNumber, to: BlockNumber },
+
+    #[error("Block #{0} missing from database (pruned?)")]
+    MissingBlock(BlockNumber),
+
+    #[error("Invalid genesis validator: {0}")]
+    InvalidAccount(String),
+
+    #[error("Genesis mismatch: database has {expected}, local spec builds {actual} (check --genesis-validator)")]
+    GenesisMismatch { expected: Hash, actual: Hash },
+
+    #[error("Replay node failed: {0}")]
+    Node(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::FeeShares;
+
+    fn execution(total_fees: Balance, producer_credit: Balance, treasury_credit: Balance) -> BlockExecution {
+        BlockExecution {
+            number: 7,
+            state_root: Hash::ZERO,
+            receipts_root: Hash::ZERO,
+            total_fees,
+            fee_shares: FeeShares::default(),
+            producer_reward: 1_000 + total_fees / 2,
+            producer_credit,
+            treasury_credit,
+        }
+    }
+
+    #[test]
+    fn test_check_fees() {
+        // 50/10/30/10: the treasury gets its share and the voters'
+        assert!(check_fees(&execution(100, 1_050, 20), Some(100)).is_empty());
+        assert!(check_fees(&execution(100, 1_050, 20), None).is_empty());
+
+        let kinds = |divergences: Vec<Divergence>| divergences.iter().map(|d| d.kind).collect::<Vec<_>>();
+        assert_eq!(kinds(check_fees(&execution(100, 1_050, 20), Some(90))), vec![DivergenceKind::Fees]);
+        assert_eq!(
+            kinds(check_fees(&execution(100, 1_060, 10), Some(100))),
+            vec![DivergenceKind::ProducerCredit, DivergenceKind::TreasuryCredit]
+        );
+
+        let mut invalid = execution(100, 1_050, 20);
+        invalid.fee_shares = FeeShares { burn: 0, ..FeeShares::default() };
+        assert_eq!(kinds(check_fees(&invalid, Some(100))), vec![DivergenceKind::FeeSplit]);
+
+        let divergence = &check_fees(&execution(100, 1_050, 20), Some(90))[0];
+        let json = serde_json::to_value(divergence).unwrap();
+        assert_eq!(json["kind"], "fees");
+        assert_eq!((json["expected"].as_str(), json["computed"].as_str()), (Some("90"), Some("100")));
+    }
+}
@@ -0,0 +1,132 @@
+// Build spec - Write the chain spec of a custom network
+// Principle: Testnets are reproducible, every node starts from the same file
+//
+// `build-spec` starts from the mainnet spec (or a spec file given with
+// --chain), applies the flags and checks the result as `run --chain` will,
+// so a written spec always loads. Constitutional parameters keep their
+// genesis values: edit them in the JSON, within their bounds.
+
+use crate::cli::export::parse_account;
+use crate::cli::BuildSpecCmd;
+use crate::genesis::{ChainSpec, ChainSpecError};
+use crate::genesis::chain_spec::{ChainSpecBalance, ChainSpecValidator};
+use crate::types::Balance;
+
+/// Chain spec from the base spec and the command's flags
+pub fn build_spec(cmd: &BuildSpecCmd) -> Result<ChainSpec, BuildSpecError> {
+    let mut spec = match cmd.chain.as_str() {
+        "kratos" => ChainSpec::mainnet(),
+        path => ChainSpec::from_file(path)?,
+    };
+
+    if let Some(ref name) = cmd.name {
+        spec.name = name.clone();
+    }
+    if let Some(chain_id) = cmd.chain_id {
+        spec.chain_id = chain_id;
+    }
+    if let Some(timestamp) = cmd.timestamp {
+        spec.genesis.timestamp = timestamp;
+    }
+    for bootnode in &cmd.bootnodes {
+        if !spec.bootnodes.contains(bootnode) {
+            spec.bootnodes.push(bootnode.clone());
+        }
+    }
+
+    for entry in &cmd.balances {
+        let (account, balance) = parse_allocation(entry)?;
+        spec.genesis.balances.retain(|b| b.account != account);
+        spec.genesis.balances.push(ChainSpecBalance { account, balance });
+    }
+    spec.genesis.balances.sort_by(|a, b| a.account.cmp(&b.account));
+
+    if !cmd.genesis_validator.is_empty() || !cmd.validators.is_empty() {
+        spec.genesis.validators.clear();
+        for account in &cmd.genesis_validator {
+            let account = account_hex(account)?;
+            spec.genesis.validators.push(ChainSpecValidator { account, stake: 0, bootstrap: true });
+        }
+        for entry in &cmd.validators {
+            let (account, stake) = parse_allocation(entry)?;
+            spec.genesis.validators.push(ChainSpecValidator { account, stake, bootstrap: false });
+        }
+    }
+
+    // Refuse what `run --chain` would
+    spec.clone().into_genesis()?;
+    Ok(spec)
+}
+
+/// Parse an <account:amount> flag, the account normalized to 0x-prefixed hex
+fn parse_allocation(entry: &str) -> Result<(String, Balance), BuildSpecError> {
+    let invalid = || BuildSpecError::InvalidAllocation(entry.to_string());
+    let (account, amount) = entry.split_once(':').ok_or_else(invalid)?;
+    let amount = amount.parse().map_err(|_| invalid())?;
+    Ok((account_hex(account)?, amount))
+}
+
+fn account_hex(account: &str) -> Result<String, BuildSpecError> {
+    let account = parse_account(account).map_err(|e| BuildSpecError::InvalidAccount(e.to_string()))?;
+    Ok(format!("0x{}", hex::encode(account.as_bytes())))
+}
+
+/// Build spec errors
+#[derive(Debug, thiserror::Error)]
+pub enum BuildSpecError {
+    #[error("Invalid allocation (expected <account:amount>): {0}")]
+    InvalidAllocation(String),
+
+    #[error("Invalid account: {0}")]
+    InvalidAccount(String),
+
+    #[error("{0}")]
+    Spec(#[from] ChainSpecError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Cli, Commands};
+    use clap::Parser;
+
+    fn build(args: &[&str]) -> Result<ChainSpec, BuildSpecError> {
+        let cli = Cli::try_parse_from([&["kratos-node", "build-spec"][..], args].concat()).unwrap();
+        match cli.command {
+            Commands::BuildSpec(cmd) => build_spec(&cmd),
+            _ => panic!("Expected BuildSpec command"),
+        }
+    }
+
+    #[test]
+    fn test_build_spec() {
+        let alice = "01".repeat(32);
+        let bob = format!("0x{}", "02".repeat(32));
+        let (validator, balance) = (format!("{}:20000", bob), format!("{}:50000", bob));
+        let args: [&str; 10] = [
+            "--name", "Testnet",
+            "--genesis-validator", &alice,
+            "--validator", &validator,
+            "--balance", &balance,
+            "--bootnode", "/ip4/10.0.0.1/tcp/30333",
+        ];
+        let spec = build(&args).unwrap();
+
+        assert_eq!(spec.name, "Testnet");
+        assert_eq!(spec.bootnodes.len(), 1);
+        assert_eq!(spec.genesis.validators.len(), 2);
+        assert_eq!(spec.genesis.validators[0].account, format!("0x{}", alice));
+        assert_eq!(spec.genesis.balances, vec![ChainSpecBalance { account: bob.clone(), balance: 50_000 }]);
+
+        // Reproducible: the same flags give the same file
+        let again = build(&args).unwrap();
+        assert_eq!(spec.to_json().unwrap(), again.to_json().unwrap());
+
+        // The stake must be covered by a genesis balance
+        assert!(matches!(
+            build(&["--validator", &validator]),
+            Err(BuildSpecError::Spec(ChainSpecError::InvalidValidator(_)))
+        ));
+        assert!(matches!(build(&["--balance", &bob]), Err(BuildSpecError::InvalidAllocation(_))));
+    }
+}
@@ -1,10 +1,22 @@
 // CLI Configuration - Convert CLI args to node config
 // Principle: Clear mapping between user input and internal configuration
 
-use crate::genesis::{ChainConfig, GenesisSpec};
+use crate::genesis::{ChainConfig, ChainSpec, GenesisSpec};
+use crate::cli::config_file::ConfigFile;
+use crate::network::parse_bootnode;
+use crate::network::peer::PeerScoring;
+use crate::network::warp_sync::WarpCheckpoint;
+use crate::node::alerts::SecurityAlerts;
+use crate::node::mempool::MempoolConfig;
+use crate::node::producer::ProducerConfig;
+use crate::node::keystore::Keystore;
 use crate::cli::RunCmd;
-use crate::rpc::RpcConfig;
-use crate::types::AccountId;
+use crate::rpc::cors::ANY_ORIGIN;
+use crate::rpc::hosts::ANY_HOST;
+use crate::rpc::{RateLimitConfig, RpcConfig, RpcProfile, RpcTls};
+use crate::storage::DatabaseBackend;
+use crate::types::{AccountId, EpochNumber, Hash};
+use std::net::IpAddr;
 use std::path::PathBuf;
 use tracing::info;
 
@@ -28,14 +40,40 @@ pub struct NodeConfig {
     pub validator: bool,
     /// Validator key path
     pub validator_key: Option<PathBuf>,
+    /// Keystore password (used when no key path is given)
+    pub keystore_password: Option<String>,
+    /// Epochs between a key rotation and the archiving of the old key
+    pub keystore_retire_after: EpochNumber,
     /// Sync mode
     pub sync_mode: SyncMode,
+    /// Trusted finalized block corroborating warp sync snapshots
+    pub warp_checkpoint: Option<WarpCheckpoint>,
+    /// Finalized block to sync from (implies warp sync)
+    pub sync_checkpoint: Option<WarpCheckpoint>,
     /// Pruning mode
     pub pruning: PruningMode,
+    /// Secondary indexes (transactions by account, events, authorship)
+    pub index: bool,
+    /// Database backend
+    pub database: DatabaseBackend,
     /// Database cache size in MB
     pub db_cache_mb: u32,
+    /// Number of accounts kept in the state cache
+    pub state_cache_accounts: usize,
+    /// Prometheus exporter port (None = disabled)
+    pub prometheus_port: Option<u16>,
     /// Enable GRANDPA finality debug traces
     pub debug_grandpa: bool,
+    /// Mempool limits ([mempool] of the config file)
+    pub mempool: MempoolConfig,
+    /// Block production limits ([producer] of the config file)
+    pub producer: ProducerConfig,
+    /// Peer scoring weights ([peer-scoring] of the config file)
+    pub peer_scoring: PeerScoring,
+    /// Maximum connected peers (bootstrap peers excepted)
+    pub max_peers: usize,
+    /// Hooks fired on security state alerts ([security-alerts] of the config file)
+    pub security_alerts: SecurityAlerts,
 }
 
 /// Sync modes
@@ -61,12 +99,22 @@ pub enum PruningMode {
 impl NodeConfig {
     /// Create configuration from CLI run command
     pub fn from_run_cmd(cmd: &RunCmd) -> Result<Self, ConfigError> {
+        // Tunables without a flag come from the config file only
+        let file = match &cmd.config {
+            Some(path) => ConfigFile::load(path).map_err(|e| ConfigError::ConfigFileError(e.to_string()))?,
+            None => ConfigFile::default(),
+        };
+
         // Try to load validator account from key file if specified
         let validator_account = if cmd.validator {
             if let Some(ref key_path) = cmd.validator_key {
                 Self::load_validator_account_from_key(key_path)?
             } else {
-                None
+                // Fall back to the keystore (public key only, no password needed)
+                Keystore::open(&cmd.get_base_path())
+                    .validator_entry()
+                    .map_err(|e| ConfigError::KeyLoadError(e.to_string()))?
+                    .map(|entry| AccountId::from_bytes(entry.public_key))
             }
         } else {
             None
@@ -93,7 +141,28 @@ impl NodeConfig {
         // Override network config with CLI args
         let mut chain = chain;
         chain.network.listen_port = cmd.port;
-        chain.network.bootnodes = cmd.bootnodes.clone();
+        chain.network.relay = cmd.relay;
+        chain.network.public_addr = cmd.public_addr.clone();
+        chain.network.quic_port = cmd.listen_quic;
+        // LAN devnets find each other without bootnodes; the main network
+        // doesn't announce its nodes on local networks, nor does a node
+        // hidden behind its reserved peers
+        chain.network.mdns = cmd.chain != "kratos" && !cmd.no_mdns && !cmd.reserved_only;
+        for peer in &cmd.reserved_peers {
+            parse_bootnode(peer).map_err(|e| ConfigError::InvalidReservedPeer(format!("{}: {}", peer, e)))?;
+        }
+        for validator in &cmd.sentry_for {
+            parse_bootnode(validator).map_err(|e| ConfigError::InvalidSentryValidator(format!("{}: {}", validator, e)))?;
+        }
+        chain.network.reserved_peers = cmd.reserved_peers.clone();
+        chain.network.reserved_only = cmd.reserved_only;
+        chain.network.sentry_for = cmd.sentry_for.clone();
+        // CLI bootnodes come in addition to the chain spec's
+        for bootnode in &cmd.bootnodes {
+            if !chain.network.bootnodes.contains(bootnode) {
+                chain.network.bootnodes.push(bootnode.clone());
+            }
+        }
 
         // Parse sync mode
         let sync_mode = match cmd.sync.as_str() {
@@ -105,16 +174,12 @@ impl NodeConfig {
             }
         };
 
+        let warp_checkpoint = cmd.warp_checkpoint.as_deref().map(Self::parse_checkpoint).transpose()?;
+        let sync_checkpoint = cmd.sync_from_checkpoint.as_deref().map(Self::parse_checkpoint).transpose()?;
+        let sync_mode = if sync_checkpoint.is_some() { SyncMode::Warp } else { sync_mode };
+
         // Parse pruning mode
-        let pruning = match cmd.pruning.as_str() {
-            "archive" => PruningMode::Archive,
-            n => {
-                let blocks = n
-                    .parse::<u32>()
-                    .map_err(|_| ConfigError::InvalidPruningMode(cmd.pruning.clone()))?;
-                PruningMode::Blocks(blocks)
-            }
-        };
+        let pruning = Self::parse_pruning(&cmd.pruning)?;
 
         // RPC configuration
         let rpc_addr: [u8; 4] = match cmd.rpc_addr.as_str() {
@@ -123,14 +188,35 @@ impl NodeConfig {
             addr => Self::parse_ip_addr(addr)?,
         };
 
+        // Public endpoints never expose the unsafe methods; the archive
+        // profile serves historical state only an archive node keeps
+        let rpc_tokens = file.rpc_tokens().map_err(|e| ConfigError::ConfigFileError(e.to_string()))?;
+        if cmd.rpc_profile == RpcProfile::Public && (cmd.rpc_methods_unsafe || !rpc_tokens.is_empty()) {
+            return Err(ConfigError::InvalidRpcProfile(
+                "the public profile cannot serve unsafe methods".to_string(),
+            ));
+        }
+        if cmd.rpc_profile == RpcProfile::Archive && pruning != PruningMode::Archive {
+            return Err(ConfigError::InvalidRpcProfile(
+                "the archive profile requires --pruning archive".to_string(),
+            ));
+        }
+
         let rpc = RpcConfig {
             enabled: cmd.rpc,
             port: cmd.rpc_port,
             address: rpc_addr,
-            cors: cmd.rpc_cors_all,
-            cors_origins: vec![], // SECURITY FIX #3: Empty = localhost only
+            cors_origins: Self::rpc_cors_origins(cmd),
+            allowed_hosts: Self::rpc_allowed_hosts(cmd),
             max_request_size: 10 * 1024 * 1024, // 10 MB
-            rate_limit: Some(100),
+            rate_limit: Self::rpc_rate_limit(cmd, &file),
+            unsafe_methods: cmd.rpc_methods_unsafe,
+            profile: cmd.rpc_profile,
+            tokens: rpc_tokens,
+            tls: cmd.rpc_tls_cert.clone().zip(cmd.rpc_tls_key.clone()).map(|(cert_path, key_path)| RpcTls {
+                cert_path,
+                key_path,
+            }),
         };
 
         // Generate node name
@@ -157,13 +243,51 @@ impl NodeConfig {
             name,
             validator: cmd.validator,
             validator_key: cmd.validator_key.clone(),
+            keystore_password: cmd.keystore_password.clone(),
+            keystore_retire_after: cmd.keystore_retire_after,
             sync_mode,
+            warp_checkpoint,
+            sync_checkpoint,
             pruning,
+            index: cmd.index,
+            database: cmd.database,
             db_cache_mb: cmd.db_cache,
+            state_cache_accounts: cmd.state_cache,
+            prometheus_port: (cmd.prometheus_port != 0).then_some(cmd.prometheus_port),
             debug_grandpa: cmd.debug_grandpa,
+            mempool: file.mempool_config(),
+            producer: file.producer_config(),
+            peer_scoring: file.peer_scoring(),
+            max_peers: cmd.max_peers as usize,
+            security_alerts: file.security_alerts(),
         })
     }
 
+    /// SECURITY FIX #3: Allowed CORS origins (empty = localhost only)
+    pub fn rpc_cors_origins(cmd: &RunCmd) -> Vec<String> {
+        if cmd.rpc_cors_all {
+            vec![ANY_ORIGIN.to_string()]
+        } else {
+            cmd.rpc_cors.clone()
+        }
+    }
+
+    /// Allowed Host headers: localhost by default, but any host when listening
+    /// on another address (the node is then reached under names it can't guess)
+    pub fn rpc_allowed_hosts(cmd: &RunCmd) -> Vec<String> {
+        let loopback = cmd.rpc_addr == "localhost" || cmd.rpc_addr.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback());
+        if cmd.rpc_allowed_hosts.is_empty() && !loopback {
+            vec![ANY_HOST.to_string()]
+        } else {
+            cmd.rpc_allowed_hosts.clone()
+        }
+    }
+
+    /// RPC rate limit: [rpc-rate-limit] of the config file, else the profile's
+    pub fn rpc_rate_limit(cmd: &RunCmd, file: &ConfigFile) -> Option<RateLimitConfig> {
+        file.rpc_rate_limit().or_else(|| cmd.rpc_profile.rate_limit())
+    }
+
     /// Load validator account ID from key file
     fn load_validator_account_from_key(key_path: &PathBuf) -> Result<Option<AccountId>, ConfigError> {
         use ed25519_dalek::SigningKey;
@@ -252,15 +376,36 @@ impl NodeConfig {
         let content = fs::read_to_string(spec_path)
             .map_err(|e| ConfigError::ChainSpecReadError(e.to_string()))?;
 
-        // Parse JSON chain spec
-        let _spec: ChainSpecJson = serde_json::from_str(&content)
+        // Parse JSON chain spec (see `build-spec`)
+        let spec = ChainSpec::from_json(&content)
             .map_err(|e| ConfigError::ChainSpecParseError(e.to_string()))?;
+        info!("Using chain spec {}: {}", path, spec.name);
+
+        spec.into_genesis()
+            .map_err(|e| ConfigError::InvalidChainSpec(e.to_string()))
+    }
+
+    /// Parse a pruning mode (`archive` or a number of blocks)
+    pub fn parse_pruning(pruning: &str) -> Result<PruningMode, ConfigError> {
+        match pruning {
+            "archive" => Ok(PruningMode::Archive),
+            n => n
+                .parse::<u32>()
+                .map(PruningMode::Blocks)
+                .map_err(|_| ConfigError::InvalidPruningMode(pruning.to_string())),
+        }
+    }
+
+    /// Parse a checkpoint given as <hash:number>
+    fn parse_checkpoint(checkpoint: &str) -> Result<WarpCheckpoint, ConfigError> {
+        let invalid = || ConfigError::InvalidCheckpoint(checkpoint.to_string());
+        let (hash, number) = checkpoint.split_once(':').ok_or_else(invalid)?;
 
-        // Use mainnet config as base
-        let chain = ChainConfig::mainnet();
-        let genesis = GenesisSpec::default();
+        let bytes = hex::decode(hash.trim_start_matches("0x")).map_err(|_| invalid())?;
+        let hash: [u8; 32] = bytes.try_into().map_err(|_| invalid())?;
+        let number = number.parse().map_err(|_| invalid())?;
 
-        Ok((chain, genesis))
+        Ok(WarpCheckpoint { number, hash: Hash::from_bytes(hash) })
     }
 
     /// Parse IP address string to bytes
@@ -281,25 +426,6 @@ impl NodeConfig {
     }
 }
 
-/// Chain specification JSON format
-#[derive(Debug, serde::Deserialize)]
-#[allow(dead_code)]
-struct ChainSpecJson {
-    name: String,
-    id: String,
-    #[serde(default)]
-    network: ChainSpecNetwork,
-}
-
-#[derive(Debug, Default, serde::Deserialize)]
-#[allow(dead_code)]
-struct ChainSpecNetwork {
-    listen_port: Option<u16>,
-    #[serde(default)]
-    bootnodes: Vec<String>,
-    max_peers: Option<u32>,
-}
-
 /// Configuration errors
 #[derive(Debug, thiserror::Error)]
 pub enum ConfigError {
@@ -312,17 +438,35 @@ pub enum ConfigError {
     #[error("Failed to parse chain spec: {0}")]
     ChainSpecParseError(String),
 
+    #[error("Invalid chain spec: {0}")]
+    InvalidChainSpec(String),
+
     #[error("Invalid sync mode: {0}")]
     InvalidSyncMode(String),
 
     #[error("Invalid pruning mode: {0}")]
     InvalidPruningMode(String),
 
+    #[error("Invalid checkpoint (expected <hash:number>): {0}")]
+    InvalidCheckpoint(String),
+
     #[error("Invalid IP address: {0}")]
     InvalidIpAddress(String),
 
+    #[error("Invalid reserved peer: {0}")]
+    InvalidReservedPeer(String),
+
+    #[error("Invalid --sentry-for validator: {0}")]
+    InvalidSentryValidator(String),
+
     #[error("Key load error: {0}")]
     KeyLoadError(String),
+
+    #[error("{0}")]
+    ConfigFileError(String),
+
+    #[error("Invalid RPC profile: {0}")]
+    InvalidRpcProfile(String),
 }
 
 #[cfg(test)]
@@ -332,6 +476,7 @@ mod tests {
     #[test]
     fn test_node_config_from_kratos() {
         let cmd = RunCmd {
+            config: None,
             genesis: false,
             base_path: None,
             chain: "kratos".to_string(),
@@ -340,17 +485,36 @@ mod tests {
             rpc_port: 9933,
             rpc: true,
             rpc_addr: "127.0.0.1".to_string(),
+            rpc_profile: RpcProfile::Private,
             bootnodes: vec![],
             max_peers: 50,
             validator: false,
             validator_key: None,
+            keystore_password: None,
+            keystore_retire_after: 2,
             sync: "full".to_string(),
+            warp_checkpoint: None,
+            sync_from_checkpoint: None,
             pruning: "256".to_string(),
+            index: false,
+            database: DatabaseBackend::RocksDb,
             db_cache: 128,
+            state_cache: 16384,
             prometheus_port: 0,
+            tracing_endpoint: None,
             public_addr: None,
+            relay: false,
+            listen_quic: None,
+            no_mdns: false,
+            reserved_peers: vec![],
+            reserved_only: false,
+            sentry_for: vec![],
             rpc_methods_unsafe: false,
             rpc_cors_all: false,
+            rpc_cors: vec![],
+            rpc_allowed_hosts: vec![],
+            rpc_tls_cert: None,
+            rpc_tls_key: None,
             debug_grandpa: false,
         };
 
@@ -360,6 +524,92 @@ mod tests {
         assert_eq!(config.sync_mode, SyncMode::Full);
         assert_eq!(config.pruning, PruningMode::Blocks(256));
         assert!(!config.genesis_mode);
+        assert!(!config.chain.network.mdns);
+    }
+
+    #[test]
+    fn test_node_config_from_chain_spec() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("testnet.json");
+        let mut spec = crate::genesis::ChainSpec::mainnet();
+        spec.name = "Testnet".to_string();
+        spec.bootnodes = vec!["/ip4/10.0.0.1/tcp/30333".to_string()];
+        spec.to_file(path.to_str().unwrap()).unwrap();
+
+        let mut cmd = RunCmd {
+            config: None,
+            genesis: false,
+            base_path: None,
+            chain: path.to_str().unwrap().to_string(),
+            name: None,
+            port: 30333,
+            rpc_port: 9933,
+            rpc: true,
+            rpc_addr: "127.0.0.1".to_string(),
+            rpc_profile: RpcProfile::Private,
+            bootnodes: vec!["/ip4/10.0.0.2/tcp/30333".to_string()],
+            max_peers: 50,
+            validator: false,
+            validator_key: None,
+            keystore_password: None,
+            keystore_retire_after: 2,
+            sync: "full".to_string(),
+            warp_checkpoint: None,
+            sync_from_checkpoint: None,
+            pruning: "256".to_string(),
+            index: false,
+            database: DatabaseBackend::RocksDb,
+            db_cache: 128,
+            state_cache: 16384,
+            prometheus_port: 0,
+            tracing_endpoint: None,
+            public_addr: None,
+            relay: false,
+            listen_quic: None,
+            no_mdns: false,
+            reserved_peers: vec![],
+            reserved_only: false,
+            sentry_for: vec![],
+            rpc_methods_unsafe: false,
+            rpc_cors_all: false,
+            rpc_cors: vec![],
+            rpc_allowed_hosts: vec![],
+            rpc_tls_cert: None,
+            rpc_tls_key: None,
+            debug_grandpa: false,
+        };
+
+        // Spec bootnodes, then the CLI's
+        let config = NodeConfig::from_run_cmd(&cmd).unwrap();
+        assert_eq!(config.chain.chain_name, "Testnet");
+        assert_eq!(config.chain.network.bootnodes.len(), 2);
+        assert_eq!(config.genesis.validators.len(), 1);
+        // mDNS is on for custom chains unless disabled
+        assert!(config.chain.network.mdns);
+        cmd.no_mdns = true;
+        assert!(!NodeConfig::from_run_cmd(&cmd).unwrap().chain.network.mdns);
+        cmd.no_mdns = false;
+
+        // Reserved peers need a peer ID
+        cmd.reserved_peers = vec!["/ip4/10.0.0.2/tcp/30333".to_string()];
+        assert!(matches!(NodeConfig::from_run_cmd(&cmd), Err(ConfigError::InvalidReservedPeer(_))));
+        cmd.reserved_peers = vec![format!("/ip4/10.0.0.2/tcp/30333/p2p/{}", libp2p::PeerId::random())];
+        cmd.reserved_only = true;
+        let config = NodeConfig::from_run_cmd(&cmd).unwrap();
+        assert_eq!(config.chain.network.reserved_peers, cmd.reserved_peers);
+        assert!(config.chain.network.reserved_only && !config.chain.network.mdns);
+        cmd.reserved_peers = vec![];
+        cmd.reserved_only = false;
+        cmd.sentry_for = vec!["/ip4/10.0.0.3/tcp/30333".to_string()];
+        assert!(matches!(NodeConfig::from_run_cmd(&cmd), Err(ConfigError::InvalidSentryValidator(_))));
+        cmd.sentry_for = vec![];
+
+        spec.bootstrap.end_epoch = 0;
+        spec.to_file(path.to_str().unwrap()).unwrap();
+        assert!(matches!(NodeConfig::from_run_cmd(&cmd), Err(ConfigError::InvalidChainSpec(_))));
+
+        cmd.chain = dir.path().join("missing.json").to_str().unwrap().to_string();
+        assert!(matches!(NodeConfig::from_run_cmd(&cmd), Err(ConfigError::ChainSpecNotFound(_))));
     }
 
     #[test]
@@ -374,6 +624,7 @@ mod tests {
     #[test]
     fn test_sync_mode_parsing() {
         let cmd = RunCmd {
+            config: None,
             genesis: false,
             base_path: None,
             chain: "kratos".to_string(),
@@ -382,28 +633,65 @@ mod tests {
             rpc_port: 9933,
             rpc: true,
             rpc_addr: "127.0.0.1".to_string(),
+            rpc_profile: RpcProfile::Private,
             bootnodes: vec![],
             max_peers: 50,
             validator: false,
             validator_key: None,
+            keystore_password: None,
+            keystore_retire_after: 2,
             sync: "warp".to_string(),
+            warp_checkpoint: Some(format!("0x{}:1001", "ab".repeat(32))),
+            sync_from_checkpoint: None,
             pruning: "archive".to_string(),
+            index: false,
+            database: DatabaseBackend::ParityDb,
             db_cache: 128,
+            state_cache: 16384,
             prometheus_port: 0,
+            tracing_endpoint: None,
             public_addr: None,
+            relay: false,
+            listen_quic: None,
+            no_mdns: false,
+            reserved_peers: vec![],
+            reserved_only: false,
+            sentry_for: vec![],
             rpc_methods_unsafe: false,
             rpc_cors_all: false,
+            rpc_cors: vec![],
+            rpc_allowed_hosts: vec![],
+            rpc_tls_cert: None,
+            rpc_tls_key: None,
             debug_grandpa: false,
         };
 
         let config = NodeConfig::from_run_cmd(&cmd).unwrap();
         assert_eq!(config.sync_mode, SyncMode::Warp);
         assert_eq!(config.pruning, PruningMode::Archive);
+        assert_eq!(config.database, DatabaseBackend::ParityDb);
+        assert_eq!(
+            config.warp_checkpoint,
+            Some(WarpCheckpoint { number: 1001, hash: Hash::from_bytes([0xab; 32]) })
+        );
+    }
+
+    #[test]
+    fn test_parse_checkpoint() {
+        let hash = "cd".repeat(32);
+        let checkpoint = NodeConfig::parse_checkpoint(&format!("{}:42", hash)).unwrap();
+        assert_eq!(checkpoint.number, 42);
+        assert_eq!(checkpoint.hash, Hash::from_bytes([0xcd; 32]));
+
+        assert!(NodeConfig::parse_checkpoint(&hash).is_err());
+        assert!(NodeConfig::parse_checkpoint(&format!("{}:x", hash)).is_err());
+        assert!(NodeConfig::parse_checkpoint("abcd:42").is_err());
     }
 
     #[test]
     fn test_invalid_sync_mode() {
         let cmd = RunCmd {
+            config: None,
             genesis: false,
             base_path: None,
             chain: "kratos".to_string(),
@@ -412,17 +700,36 @@ mod tests {
             rpc_port: 9933,
             rpc: true,
             rpc_addr: "127.0.0.1".to_string(),
+            rpc_profile: RpcProfile::Private,
             bootnodes: vec![],
             max_peers: 50,
             validator: false,
             validator_key: None,
+            keystore_password: None,
+            keystore_retire_after: 2,
             sync: "invalid".to_string(),
+            warp_checkpoint: None,
+            sync_from_checkpoint: None,
             pruning: "256".to_string(),
+            index: false,
+            database: DatabaseBackend::RocksDb,
             db_cache: 128,
+            state_cache: 16384,
             prometheus_port: 0,
+            tracing_endpoint: None,
             public_addr: None,
+            relay: false,
+            listen_quic: None,
+            no_mdns: false,
+            reserved_peers: vec![],
+            reserved_only: false,
+            sentry_for: vec![],
             rpc_methods_unsafe: false,
             rpc_cors_all: false,
+            rpc_cors: vec![],
+            rpc_allowed_hosts: vec![],
+            rpc_tls_cert: None,
+            rpc_tls_key: None,
             debug_grandpa: false,
         };
 
@@ -433,6 +740,7 @@ mod tests {
     #[test]
     fn test_genesis_mode() {
         let cmd = RunCmd {
+            config: None,
             genesis: true,
             base_path: None,
             chain: "kratos".to_string(),
@@ -441,17 +749,36 @@ mod tests {
             rpc_port: 9933,
             rpc: true,
             rpc_addr: "127.0.0.1".to_string(),
+            rpc_profile: RpcProfile::Private,
             bootnodes: vec![],
             max_peers: 50,
             validator: true,
             validator_key: None,
+            keystore_password: None,
+            keystore_retire_after: 2,
             sync: "full".to_string(),
+            warp_checkpoint: None,
+            sync_from_checkpoint: None,
             pruning: "256".to_string(),
+            index: false,
+            database: DatabaseBackend::RocksDb,
             db_cache: 128,
+            state_cache: 16384,
             prometheus_port: 0,
+            tracing_endpoint: None,
             public_addr: None,
+            relay: false,
+            listen_quic: None,
+            no_mdns: false,
+            reserved_peers: vec![],
+            reserved_only: false,
+            sentry_for: vec![],
             rpc_methods_unsafe: false,
             rpc_cors_all: false,
+            rpc_cors: vec![],
+            rpc_allowed_hosts: vec![],
+            rpc_tls_cert: None,
+            rpc_tls_key: None,
             debug_grandpa: false,
         };
 
@@ -459,4 +786,23 @@ mod tests {
         assert!(config.genesis_mode);
         assert!(config.validator);
     }
+
+    #[test]
+    fn test_rpc_profile_config() {
+        use clap::Parser;
+        let parse = |args: &[&str]| match crate::cli::Cli::try_parse_from(args).unwrap().command {
+            crate::cli::Commands::Run(cmd) => NodeConfig::from_run_cmd(&cmd),
+            _ => panic!("Expected Run command"),
+        };
+
+        let config = parse(&["kratos-node", "run", "--rpc-profile", "public"]).unwrap();
+        assert_eq!(config.rpc.profile, RpcProfile::Public);
+        assert_eq!(config.rpc.rate_limit.map(|l| l.max_requests), Some(50));
+        assert!(parse(&["kratos-node", "run", "--rpc-profile", "public", "--rpc-methods-unsafe"]).is_err());
+
+        // Historical queries need the historical state
+        assert!(parse(&["kratos-node", "run", "--rpc-profile", "archive"]).is_err());
+        assert!(parse(&["kratos-node", "run", "--rpc-profile", "archive", "--pruning", "archive"]).is_ok());
+        assert!(parse(&["kratos-node", "run"]).unwrap().rpc.rate_limit.is_none());
+    }
 }
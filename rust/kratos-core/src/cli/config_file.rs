@@ -0,0 +1,585 @@
+// Config file - node.toml layered under the command line
+// - `run --config node.toml`: top-level keys are the `run` flags and the log
+//   options without the leading dashes (rpc-port = 9944, log-level = "debug",
+//   bootnode = ["/ip4/..."]); a key only
+//   applies when its flag is neither given nor set through its environment
+//   variable. Precedence: command line > environment > file > defaults
+// - Sections hold the tunables without a flag: [mempool], [producer],
+//   [rpc-rate-limit], [peer-scoring], [security-alerts], and the [[rpc-token]] entries
+// - `config generate` prints a template documenting every key and its default
+
+use crate::cli::{Cli, Commands, RunCmd};
+use crate::network::peer::PeerScoring;
+use crate::node::alerts::SecurityAlerts;
+use crate::node::mempool::MempoolConfig;
+use crate::node::producer::ProducerConfig;
+use crate::rpc::{RateLimitConfig, RpcToken};
+use crate::types::Balance;
+use clap::parser::ValueSource;
+use clap::{Arg, ArgAction, ArgMatches, CommandFactory, FromArgMatches, Parser};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fmt::Write as _;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+/// First line of the tunables part of the template
+const TUNABLES_HEADER: &str = "# Advanced tunables (no command line flag)";
+
+#[derive(Debug, Error)]
+pub enum ConfigFileError {
+    #[error("Cannot read config file {0}: {1}")]
+    Read(String, std::io::Error),
+
+    #[error("Invalid config file {0}: {1}")]
+    Parse(String, toml::de::Error),
+
+    #[error("Unknown option '{0}' in config file (keys are the run flags without the leading dashes)")]
+    UnknownOption(String),
+
+    #[error("Invalid value for '{0}' in config file: {1}")]
+    InvalidValue(String, String),
+
+    #[error("Config file {0} rejected: {1}")]
+    Rejected(String, String),
+}
+
+/// Contents of node.toml
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct ConfigFile {
+    #[serde(default)]
+    pub mempool: MempoolSection,
+
+    #[serde(default)]
+    pub producer: ProducerSection,
+
+    /// RPC requests are not rate limited without this section
+    pub rpc_rate_limit: Option<RateLimitSection>,
+
+    /// [[rpc-token]] - tokens for the unsafe RPC methods
+    #[serde(default)]
+    pub rpc_token: Vec<RpcTokenSection>,
+
+    #[serde(default)]
+    pub peer_scoring: PeerScoringSection,
+
+    #[serde(default)]
+    pub security_alerts: SecurityAlertsSection,
+
+    /// `run` options, by flag name
+    #[serde(flatten)]
+    pub options: toml::Table,
+}
+
+/// [mempool] - overrides of MempoolConfig
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct MempoolSection {
+    pub max_size: Option<usize>,
+    pub max_per_account: Option<usize>,
+    /// TOML integers are 64-bit
+    pub min_fee: Option<u64>,
+    pub rbf_min_increase_pct: Option<u8>,
+    pub tx_expiration_secs: Option<u64>,
+    pub tx_expiration_blocks: Option<u64>,
+    pub fee_escalation_free_slots: Option<usize>,
+    pub fee_escalation_pct: Option<u8>,
+    pub rate_limit_per_account: Option<usize>,
+    pub rate_limit_window_secs: Option<u64>,
+    pub max_nonce_gap: Option<u64>,
+    pub max_total_pending: Option<usize>,
+}
+
+/// [producer] - overrides of ProducerConfig
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ProducerSection {
+    pub max_transactions: Option<usize>,
+    pub production_budget_ms: Option<u64>,
+    pub seal_reserve_ms: Option<u64>,
+}
+
+/// [rpc-rate-limit] - overrides of RateLimitConfig
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RateLimitSection {
+    pub max_requests: Option<u32>,
+    pub window_secs: Option<u64>,
+    pub ban_secs: Option<u64>,
+    pub max_violations: Option<u32>,
+    /// [rpc-rate-limit.costs] - cost per method, added to the built-in ones
+    pub costs: HashMap<String, u32>,
+    /// [rpc-rate-limit.ip-limits] - budget per IP instead of max-requests
+    pub ip_limits: HashMap<IpAddr, u32>,
+}
+
+/// [[rpc-token]] - an RpcToken
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RpcTokenSection {
+    pub name: String,
+    /// Hex BLAKE3 hash of the token (`config token` prints both)
+    pub hash: String,
+    pub methods: Vec<String>,
+}
+
+/// [peer-scoring] - overrides of PeerScoring
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct PeerScoringSection {
+    pub initial: Option<i32>,
+    pub min: Option<i32>,
+    pub good_block: Option<i32>,
+    pub bad_block: Option<i32>,
+    pub good_transaction: Option<i32>,
+    pub bad_transaction: Option<i32>,
+    pub timeout: Option<i32>,
+}
+
+/// [security-alerts] - hooks fired when the security state degrades
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct SecurityAlertsSection {
+    pub webhook: Option<String>,
+    pub command: Option<PathBuf>,
+    pub timeout_secs: Option<u64>,
+}
+
+impl ConfigFile {
+    /// Read and parse a config file
+    pub fn load(path: &Path) -> Result<Self, ConfigFileError> {
+        let name = path.display().to_string();
+        let content = std::fs::read_to_string(path).map_err(|e| ConfigFileError::Read(name.clone(), e))?;
+        toml::from_str(&content).map_err(|e| ConfigFileError::Parse(name, e))
+    }
+
+    /// Mempool limits, defaults overridden by [mempool]
+    pub fn mempool_config(&self) -> MempoolConfig {
+        let section = &self.mempool;
+        let default = MempoolConfig::default();
+        MempoolConfig {
+            max_size: section.max_size.unwrap_or(default.max_size),
+            max_per_account: section.max_per_account.unwrap_or(default.max_per_account),
+            min_fee: section.min_fee.map_or(default.min_fee, Balance::from),
+            rbf_min_increase_pct: section.rbf_min_increase_pct.unwrap_or(default.rbf_min_increase_pct),
+            tx_expiration: section.tx_expiration_secs.map_or(default.tx_expiration, Duration::from_secs),
+            tx_expiration_blocks: section.tx_expiration_blocks.unwrap_or(default.tx_expiration_blocks),
+            fee_escalation_free_slots: section.fee_escalation_free_slots.unwrap_or(default.fee_escalation_free_slots),
+            fee_escalation_pct: section.fee_escalation_pct.unwrap_or(default.fee_escalation_pct),
+            rate_limit_per_account: section.rate_limit_per_account.unwrap_or(default.rate_limit_per_account),
+            rate_limit_window: section.rate_limit_window_secs.map_or(default.rate_limit_window, Duration::from_secs),
+            max_nonce_gap: section.max_nonce_gap.unwrap_or(default.max_nonce_gap),
+            max_total_pending: section.max_total_pending.unwrap_or(default.max_total_pending),
+            ..default
+        }
+    }
+
+    /// Block production limits, defaults overridden by [producer]
+    pub fn producer_config(&self) -> ProducerConfig {
+        let section = &self.producer;
+        let default = ProducerConfig::default();
+        ProducerConfig {
+            max_transactions_per_block: section.max_transactions.unwrap_or(default.max_transactions_per_block),
+            production_budget: section.production_budget_ms.map_or(default.production_budget, Duration::from_millis),
+            seal_reserve: section.seal_reserve_ms.map_or(default.seal_reserve, Duration::from_millis),
+            ..default
+        }
+    }
+
+    /// RPC rate limiting, None without a [rpc-rate-limit] section
+    pub fn rpc_rate_limit(&self) -> Option<RateLimitConfig> {
+        let section = self.rpc_rate_limit.as_ref()?;
+        let default = RateLimitConfig::default();
+        Some(RateLimitConfig {
+            max_requests: section.max_requests.unwrap_or(default.max_requests),
+            window_duration: section.window_secs.map_or(default.window_duration, Duration::from_secs),
+            ban_duration: section.ban_secs.map_or(default.ban_duration, Duration::from_secs),
+            max_violations: section.max_violations.unwrap_or(default.max_violations),
+            method_costs: default.method_costs.into_iter().chain(section.costs.clone()).collect(),
+            ip_limits: section.ip_limits.clone(),
+        })
+    }
+
+    /// Tokens of the unsafe RPC methods ([[rpc-token]] sections)
+    pub fn rpc_tokens(&self) -> Result<Vec<RpcToken>, ConfigFileError> {
+        self.rpc_token
+            .iter()
+            .map(|section| {
+                let hash = blake3::Hash::from_hex(&section.hash).map_err(|e| {
+                    ConfigFileError::InvalidValue(format!("rpc-token.{}.hash", section.name), e.to_string())
+                })?;
+                Ok(RpcToken {
+                    name: section.name.clone(),
+                    hash,
+                    methods: section.methods.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Peer scoring weights, defaults overridden by [peer-scoring]
+    pub fn peer_scoring(&self) -> PeerScoring {
+        let section = &self.peer_scoring;
+        let default = PeerScoring::default();
+        PeerScoring {
+            initial: section.initial.unwrap_or(default.initial),
+            min: section.min.unwrap_or(default.min),
+            good_block: section.good_block.unwrap_or(default.good_block),
+            bad_block: section.bad_block.unwrap_or(default.bad_block),
+            good_transaction: section.good_transaction.unwrap_or(default.good_transaction),
+            bad_transaction: section.bad_transaction.unwrap_or(default.bad_transaction),
+            timeout: section.timeout.unwrap_or(default.timeout),
+        }
+    }
+
+    /// Security alert hooks, none without [security-alerts]
+    pub fn security_alerts(&self) -> SecurityAlerts {
+        let section = &self.security_alerts;
+        let default = SecurityAlerts::default();
+        SecurityAlerts {
+            webhook: section.webhook.clone(),
+            command: section.command.clone(),
+            timeout: section.timeout_secs.map_or(default.timeout, Duration::from_secs),
+        }
+    }
+
+    /// `run` arguments for the file options the command line leaves unset
+    ///
+    /// `matches` are those of the `run` subcommand: a flag given on the command
+    /// line or through its environment variable keeps its value.
+    fn run_args(&self, matches: &ArgMatches) -> Result<Vec<OsString>, ConfigFileError> {
+        let arguments = file_arguments();
+        let mut args = Vec::new();
+
+        for (key, value) in &self.options {
+            let arg = arguments
+                .iter()
+                .find(|arg| arg.get_long() == Some(key.as_str()))
+                .ok_or_else(|| ConfigFileError::UnknownOption(key.clone()))?;
+
+            let source = matches.value_source(arg.get_id().as_str());
+            if matches!(source, Some(ValueSource::CommandLine | ValueSource::EnvVariable)) {
+                continue;
+            }
+
+            let flag = format!("--{}", key);
+            match (arg.get_action(), value) {
+                (ArgAction::SetTrue, toml::Value::Boolean(enabled)) => {
+                    if *enabled {
+                        args.push(flag.into());
+                    }
+                }
+                (ArgAction::SetTrue, _) => {
+                    return Err(ConfigFileError::InvalidValue(key.clone(), "expected true or false".to_string()));
+                }
+                (ArgAction::Append, toml::Value::Array(values)) => {
+                    for value in values {
+                        args.push(format!("{}={}", flag, scalar(key, value)?).into());
+                    }
+                }
+                (_, value) => args.push(format!("{}={}", flag, scalar(key, value)?).into()),
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+/// Options a config file can set: the `run` flags and the global log options
+fn file_arguments() -> Vec<Arg> {
+    let globals = Cli::command().get_arguments().filter(|arg| arg.is_global_set()).cloned().collect::<Vec<_>>();
+    RunCmd::command()
+        .get_arguments()
+        .filter(|arg| arg.get_id() != "config")
+        .cloned()
+        .chain(globals)
+        .collect()
+}
+
+/// Command line form of a file value
+fn scalar(key: &str, value: &toml::Value) -> Result<String, ConfigFileError> {
+    match value {
+        toml::Value::String(s) => Ok(s.clone()),
+        toml::Value::Integer(i) => Ok(i.to_string()),
+        toml::Value::Float(f) => Ok(f.to_string()),
+        toml::Value::Boolean(b) => Ok(b.to_string()),
+        _ => Err(ConfigFileError::InvalidValue(
+            key.to_string(),
+            "expected a string, a number or a boolean".to_string(),
+        )),
+    }
+}
+
+/// Parse the process arguments, layering the `run --config` file under them
+pub fn parse_cli() -> Result<Cli, ConfigFileError> {
+    parse_cli_from(std::env::args_os().collect())
+}
+
+/// Parse `args`, layering the `run --config` file under them
+///
+/// The file options become extra arguments, so clap validates them like the
+/// command line (types, conflicts, requirements).
+pub fn parse_cli_from(args: Vec<OsString>) -> Result<Cli, ConfigFileError> {
+    let matches = Cli::command().get_matches_from(args.clone());
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    layer_file(cli, &matches, args)
+}
+
+/// Parse `args` again while running: errors are returned instead of exiting
+pub fn reparse_cli_from(args: Vec<OsString>) -> Result<Cli, ConfigFileError> {
+    let rejected = |e: clap::Error| ConfigFileError::Rejected("command line".to_string(), e.to_string());
+    let matches = Cli::command().try_get_matches_from(args.clone()).map_err(rejected)?;
+    let cli = Cli::from_arg_matches(&matches).map_err(rejected)?;
+    layer_file(cli, &matches, args)
+}
+
+/// Reparse `args` with the file options of a `run --config` command
+fn layer_file(cli: Cli, matches: &ArgMatches, args: Vec<OsString>) -> Result<Cli, ConfigFileError> {
+
+    let path = match &cli.command {
+        Commands::Run(cmd) => match &cmd.config {
+            Some(path) => path.clone(),
+            None => return Ok(cli),
+        },
+        _ => return Ok(cli),
+    };
+    let run_matches = matches.subcommand_matches("run").expect("run command parsed");
+
+    let file = ConfigFile::load(&path)?;
+    let mut layered = args;
+    layered.extend(file.run_args(run_matches)?);
+
+    Cli::try_parse_from(layered).map_err(|e| ConfigFileError::Rejected(path.display().to_string(), e.to_string()))
+}
+
+/// Documented node.toml: every `run` option and tunable, commented out, with its default
+pub fn template() -> String {
+    let mut out = String::new();
+    out.push_str("# KratOs node configuration\n");
+    out.push_str("# Usage: kratos-node run --config node.toml\n");
+    out.push_str("#\n");
+    out.push_str("# Precedence: command line > environment variables > this file > defaults.\n");
+    out.push_str("# Keys are the `run` flags without the leading dashes; uncomment to set.\n\n");
+
+    // Deprecated options are still read, not advertised
+    for arg in file_arguments().iter().filter(|arg| !arg.is_hide_set()) {
+        let Some(long) = arg.get_long() else { continue };
+        let help = arg.get_long_help().or(arg.get_help()).map(|h| h.to_string()).unwrap_or_default();
+        for line in help.lines() {
+            let _ = writeln!(out, "#{}{}", if line.is_empty() { "" } else { " " }, line);
+        }
+        if let Some(env) = arg.get_env() {
+            let _ = writeln!(out, "# Environment: {}", env.to_string_lossy());
+        }
+        let _ = writeln!(out, "# {} = {}\n", long, default_value(arg));
+    }
+
+    let _ = writeln!(out, "# {}", "-".repeat(77));
+    let _ = writeln!(out, "{}", TUNABLES_HEADER);
+    let _ = writeln!(out, "# {}\n", "-".repeat(77));
+
+    let mempool = MempoolConfig::default();
+    out.push_str("# Transaction pool limits\n[mempool]\n");
+    entry(&mut out, "Maximum number of transactions in the pool", "max-size", mempool.max_size);
+    entry(&mut out, "Maximum pending transactions per account", "max-per-account", mempool.max_per_account);
+    entry(&mut out, "Minimum fee accepted", "min-fee", mempool.min_fee);
+    entry(&mut out, "Fee increase (%) to replace a pending transaction", "rbf-min-increase-pct", mempool.rbf_min_increase_pct);
+    entry(&mut out, "Seconds before a pending transaction expires", "tx-expiration-secs", mempool.tx_expiration.as_secs());
+    entry(&mut out, "Blocks before a pending transaction expires, 0 for no limit", "tx-expiration-blocks", mempool.tx_expiration_blocks);
+    entry(&mut out, "Pending transactions per account at the minimum fee", "fee-escalation-free-slots", mempool.fee_escalation_free_slots);
+    entry(&mut out, "Fee increase (%) per pending transaction over the free slots", "fee-escalation-pct", mempool.fee_escalation_pct);
+    entry(&mut out, "Submissions per account per rate limit window", "rate-limit-per-account", mempool.rate_limit_per_account);
+    entry(&mut out, "Rate limit window in seconds", "rate-limit-window-secs", mempool.rate_limit_window.as_secs());
+    entry(&mut out, "Maximum gap between an account nonce and a pending one", "max-nonce-gap", mempool.max_nonce_gap);
+    entry(&mut out, "Maximum pending transactions, all accounts", "max-total-pending", mempool.max_total_pending);
+
+    let producer = ProducerConfig::default();
+    out.push_str("# Block production: transactions left when the budget runs out wait for the next block\n[producer]\n");
+    entry(&mut out, "Maximum transactions per block", "max-transactions", producer.max_transactions_per_block);
+    entry(&mut out, "Milliseconds a block may take to produce (slots are 6s)", "production-budget-ms", producer.production_budget.as_millis());
+    entry(&mut out, "Milliseconds of the budget kept to seal the block", "seal-reserve-ms", producer.seal_reserve.as_millis());
+
+    let rate_limit = RateLimitConfig::default();
+    out.push_str("# Per-IP rate limiting of the RPC server (off unless the section is present)\n# [rpc-rate-limit]\n");
+    entry(&mut out, "Requests per window", "max-requests", rate_limit.max_requests);
+    entry(&mut out, "Window in seconds", "window-secs", rate_limit.window_duration.as_secs());
+    entry(&mut out, "Ban duration in seconds", "ban-secs", rate_limit.ban_duration.as_secs());
+    entry(&mut out, "Windows over the limit before a ban", "max-violations", rate_limit.max_violations);
+    out.push_str("# Budget used by a call per method (names or `prefix_*` patterns), others cost 1\n# [rpc-rate-limit.costs]\n");
+    let mut costs: Vec<_> = rate_limit.method_costs.iter().collect();
+    costs.sort();
+    for (method, cost) in costs {
+        let _ = writeln!(out, "# \"{}\" = {}", method, cost);
+    }
+    out.push('\n');
+    out.push_str("# Budget per window of given IPs, instead of max-requests\n# [rpc-rate-limit.ip-limits]\n");
+    entry(&mut out, "Trusted indexer", "\"10.0.0.5\"", 1000);
+
+    out.push_str("# Token for the unsafe RPC methods (`kratos-node config token` generates one);\n");
+    out.push_str("# once a token exists, unsafe methods require `Authorization: Bearer <token>`\n# [[rpc-token]]\n");
+    entry(&mut out, "Name, for the logs", "name", "\"ops\"");
+    entry(&mut out, "BLAKE3 hash of the token (hex)", "hash", "\"<HASH>\"");
+    entry(&mut out, "Methods allowed: names, `prefix_*` patterns or `*`", "methods", "[\"admin_reloadConfig\"]");
+
+    let scoring = PeerScoring::default();
+    out.push_str("# Peer reputation: scores decay toward `initial`, peers below `min` are disconnected\n[peer-scoring]\n");
+    entry(&mut out, "Score of a new peer", "initial", scoring.initial);
+    entry(&mut out, "Disconnection threshold", "min", scoring.min);
+    entry(&mut out, "Valid block received", "good-block", scoring.good_block);
+    entry(&mut out, "Invalid block received", "bad-block", scoring.bad_block);
+    entry(&mut out, "Valid transaction received", "good-transaction", scoring.good_transaction);
+    entry(&mut out, "Invalid transaction received", "bad-transaction", scoring.bad_transaction);
+    entry(&mut out, "Request timed out", "timeout", scoring.timeout);
+
+    let alerts = SecurityAlerts::default();
+    out.push_str("# Hooks fired when the network security state enters Degraded, Critical or Terminal
+[security-alerts]
+");
+    entry(&mut out, "URL the transition is POSTed to as JSON", "webhook", "\"https://alerts.example.com/kratos\"");
+    entry(&mut out, "Program run with KRATOS_SECURITY_FROM/TO/EPOCH/VALIDATORS/REASON set", "command", "\"/usr/local/bin/kratos-alert\"");
+    entry(&mut out, "Seconds a hook may take", "timeout-secs", alerts.timeout.as_secs());
+
+    out
+}
+
+/// One commented template entry
+fn entry(out: &mut String, doc: &str, key: &str, value: impl std::fmt::Display) {
+    let _ = writeln!(out, "# {}\n# {} = {}\n", doc, key, value);
+}
+
+/// Template value of a `run` option: its default, or a placeholder
+fn default_value(arg: &Arg) -> String {
+    let default = arg.get_default_values().first().map(|v| v.to_string_lossy().into_owned());
+    match (arg.get_action(), default) {
+        (ArgAction::SetTrue, default) => default.unwrap_or_else(|| "false".to_string()),
+        (ArgAction::Append, _) => "[]".to_string(),
+        (_, Some(value)) if value.parse::<i64>().is_ok() => value,
+        (_, Some(value)) => format!("\"{}\"", value),
+        (_, None) => {
+            let name = arg
+                .get_value_names()
+                .and_then(|names| names.first())
+                .map(|name| name.to_string())
+                .unwrap_or_else(|| arg.get_id().to_string().to_uppercase());
+            format!("\"<{}>\"", name)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_with_file(file: &str, args: &[&str]) -> Result<RunCmd, ConfigFileError> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("node.toml");
+        std::fs::write(&path, file).unwrap();
+
+        let mut argv: Vec<OsString> = ["kratos-node", "run", "--config"].iter().map(OsString::from).collect();
+        argv.push(path.into_os_string());
+        argv.extend(args.iter().map(OsString::from));
+        match parse_cli_from(argv)?.command {
+            Commands::Run(cmd) => Ok(cmd),
+            _ => panic!("Expected Run command"),
+        }
+    }
+
+    #[test]
+    fn test_file_layered_under_command_line() {
+        let file = r#"
+            port = 40000
+            rpc-port = 9944
+            validator = true
+            pruning = "archive"
+            bootnode = ["/ip4/1.2.3.4/tcp/30333/p2p/12D3KooWPeer1", "/ip4/5.6.7.8/tcp/30333/p2p/12D3KooWPeer2"]
+
+            [mempool]
+            max-size = 500
+        "#;
+        let cmd = parse_with_file(file, &["--port", "30334"]).unwrap();
+
+        // Command line wins, the file fills the rest, defaults stay otherwise
+        assert_eq!(cmd.port, 30334);
+        assert_eq!(cmd.rpc_port, 9944);
+        assert!(cmd.validator);
+        assert_eq!(cmd.pruning, "archive");
+        assert_eq!(cmd.bootnodes.len(), 2);
+        assert_eq!(cmd.sync, "full");
+    }
+
+    #[test]
+    fn test_log_options_in_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("node.toml");
+        std::fs::write(&path, "log-level = \"debug\"\nlog-format = \"json\"\n").unwrap();
+        let config = path.to_str().unwrap();
+
+        let cli = parse_cli_from(["kratos-node", "run", "--config", config].iter().map(OsString::from).collect()).unwrap();
+        assert_eq!(cli.log_level, "debug");
+        assert_eq!(cli.log_format, "json");
+
+        // Before or after the subcommand, the command line wins
+        let cli = reparse_cli_from(
+            ["kratos-node", "--log-level", "warn", "run", "--config", config].iter().map(OsString::from).collect(),
+        )
+        .unwrap();
+        assert_eq!(cli.log_level, "warn");
+        assert!(reparse_cli_from(vec!["kratos-node".into(), "run".into(), "--rpc-port".into()]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_file_options() {
+        assert!(matches!(
+            parse_with_file("rpc-prot = 1", &[]),
+            Err(ConfigFileError::UnknownOption(key)) if key == "rpc-prot"
+        ));
+        assert!(matches!(parse_with_file("validator = \"yes\"", &[]), Err(ConfigFileError::InvalidValue(..))));
+        assert!(matches!(parse_with_file("rpc-port = \"high\"", &[]), Err(ConfigFileError::Rejected(..))));
+        assert!(matches!(parse_with_file("[mempool]\nmax-sise = 1", &[]), Err(ConfigFileError::Parse(..))));
+    }
+
+    #[test]
+    fn test_template() {
+        let template = template();
+        let config: ConfigFile = toml::from_str(&template).unwrap();
+        assert!(config.options.is_empty());
+        assert!(config.rpc_rate_limit().is_none());
+
+        for arg in file_arguments().iter().filter(|arg| !arg.is_hide_set()) {
+            if let Some(long) = arg.get_long() {
+                assert!(template.contains(&format!("\n# {} = ", long)), "{} missing", long);
+            }
+        }
+
+        // Uncommented, the tunables are the defaults
+        let (_, tunables) = template.split_once(TUNABLES_HEADER).unwrap();
+        let uncommented: String = tunables
+            .lines()
+            .map(|line| match line.strip_prefix("# ") {
+                Some(rest) if rest.contains(" = ") || rest.starts_with('[') => rest,
+                _ => line,
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        let config: ConfigFile = toml::from_str(&uncommented).unwrap();
+        assert_eq!(config.mempool_config().max_size, MempoolConfig::default().max_size);
+        assert_eq!(config.mempool_config().tx_expiration, MempoolConfig::default().tx_expiration);
+        assert_eq!(config.producer_config().production_budget, ProducerConfig::default().production_budget);
+        assert_eq!(config.producer_config().seal_reserve, ProducerConfig::default().seal_reserve);
+        let rate_limit = config.rpc_rate_limit().unwrap();
+        assert_eq!(rate_limit.max_requests, RateLimitConfig::default().max_requests);
+        assert_eq!(rate_limit.method_costs, RateLimitConfig::default_method_costs());
+        assert_eq!(rate_limit.limit(&"10.0.0.5".parse().unwrap()), 1000);
+        assert_eq!(config.peer_scoring(), PeerScoring::default());
+        assert_eq!(config.security_alerts().timeout, SecurityAlerts::default().timeout);
+        assert!(config.security_alerts().is_enabled());
+        assert!(!ConfigFile::default().security_alerts().is_enabled());
+        // The hash is a placeholder
+        assert_eq!(config.rpc_token.len(), 1);
+        assert!(matches!(config.rpc_tokens(), Err(ConfigFileError::InvalidValue(..))));
+    }
+}
@@ -0,0 +1,108 @@
+// Database maintenance - Offline compaction and integrity check
+// Principle: Report corruption with a way out instead of failing at startup
+//
+// `db compact` rewrites the database files (space freed by pruning is only
+// reclaimed by compaction); `db check` walks the stored chain and tells which
+// data is damaged and how to recover. Both need the node to be stopped.
+
+use crate::storage::{open_existing, Database, DatabaseBackend, DatabaseError, IntegrityReport, RocksDatabase, StateBackend};
+use std::path::Path;
+use tracing::{info, warn};
+
+/// Size before and after a compaction, in bytes (when RocksDB reports it)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompactReport {
+    pub size_before: Option<u64>,
+    pub size_after: Option<u64>,
+}
+
+/// Compact the database at `base_path`
+pub fn compact(base_path: &Path) -> Result<CompactReport, DbError> {
+    let db = open(base_path)?;
+    let size_before = db.live_size();
+    info!("🗜️ Compacting database at {}", base_path.display());
+    db.compact();
+    Ok(CompactReport { size_before, size_after: db.live_size() })
+}
+
+/// Check the database at `base_path`, repairing what can be re-derived
+///
+/// With `repair`, a database that does not open is first passed through
+/// RocksDB repair (RocksDB databases only).
+pub fn check(base_path: &Path, repair: bool) -> Result<IntegrityReport, DbError> {
+    let db = match open(base_path) {
+        Ok(db) => db,
+        Err(DbError::Open { .. }) if repair && DatabaseBackend::detect(base_path) != Some(DatabaseBackend::ParityDb) => {
+            warn!("Database does not open, running RocksDB repair");
+            RocksDatabase::repair(base_path).map_err(DbError::Storage)?;
+            open(base_path)?
+        }
+        Err(e) => return Err(e),
+    };
+
+    StateBackend::new(db)
+        .check_integrity(repair)
+        .map_err(|e| DbError::Check(e.to_string()))
+}
+
+fn open(base_path: &Path) -> Result<Box<dyn Database>, DbError> {
+    if !base_path.exists() {
+        return Err(DbError::NotFound(base_path.display().to_string()));
+    }
+    open_existing(base_path).map_err(|e| DbError::Open {
+        path: base_path.display().to_string(),
+        error: e.to_string(),
+    })
+}
+
+/// Database maintenance errors
+#[derive(Debug, thiserror::Error)]
+pub enum DbError {
+    #[error("No chain data at {0}")]
+    NotFound(String),
+
+    #[error("Cannot open database at {path}: {error} (is the node running? otherwise try `kratos-node db check --repair`)")]
+    Open { path: String, error: String },
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] DatabaseError),
+
+    #[error("Integrity check failed: {0}")]
+    Check(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::IntegrityIssue;
+    use crate::types::{AccountId, Block, Hash};
+
+    #[test]
+    fn test_check_repairs_missing_index() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let state = StateBackend::new(RocksDatabase::open(dir.path()).unwrap());
+            let genesis = Block::genesis(Hash::ZERO, vec![AccountId::from_bytes([1; 32])]);
+            state.store_block(&genesis).unwrap();
+            state.set_best_block(0).unwrap();
+        }
+        assert!(check(dir.path(), false).unwrap().is_healthy());
+
+        // Lose the number -> hash index
+        {
+            let db = RocksDatabase::open(dir.path()).unwrap();
+            let mut key = b"block_hash:".to_vec();
+            key.extend_from_slice(&0u64.to_le_bytes());
+            db.delete(&key).unwrap();
+        }
+        let report = check(dir.path(), false).unwrap();
+        assert_eq!(report.issues, vec![IntegrityIssue::MissingIndex(0)]);
+        assert!(!report.is_healthy());
+
+        let report = check(dir.path(), true).unwrap();
+        assert_eq!(report.repaired, 1);
+        assert!(check(dir.path(), false).unwrap().issues.is_empty());
+
+        assert!(matches!(check(&dir.path().join("missing"), false), Err(DbError::NotFound(_))));
+    }
+}
@@ -0,0 +1,426 @@
+// Chain export/import - Stream blocks to and from a portable file
+// Principle: Replaying an export must re-verify every block, never trust the file
+//
+// File formats:
+// - binary: "KRATOSEX" magic, then frames of [u32 LE length][bincode payload]
+// - json:   one JSON document per line (JSON Lines)
+// In both formats the first record is the ExportHeader, followed by blocks in order.
+
+use crate::cli::{ExportCmd, ImportCmd};
+use crate::genesis::{ChainConfig, GenesisSpec};
+use crate::node::service::KratOsNode;
+use crate::storage::{open_existing, StateBackend};
+use crate::types::{AccountId, Block, BlockNumber, Hash};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::str::FromStr;
+use tracing::info;
+
+/// Magic bytes at the start of a binary export
+const BINARY_MAGIC: &[u8; 8] = b"KRATOSEX";
+
+/// Current export file version
+const EXPORT_VERSION: u32 = 1;
+
+/// Upper bound on a single binary frame (guards against corrupt length prefixes)
+const MAX_FRAME_SIZE: usize = 64 * 1024 * 1024;
+
+/// Progress log interval in blocks
+const PROGRESS_INTERVAL: u64 = 1000;
+
+/// Export file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Binary,
+    Json,
+}
+
+impl FromStr for ExportFormat {
+    type Err = ExportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "binary" => Ok(ExportFormat::Binary),
+            "json" => Ok(ExportFormat::Json),
+            other => Err(ExportError::UnknownFormat(other.to_string())),
+        }
+    }
+}
+
+/// First record of every export file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportHeader {
+    pub version: u32,
+    pub genesis_hash: Hash,
+    pub from: BlockNumber,
+    pub to: BlockNumber,
+}
+
+// =============================================================================
+// WRITER / READER
+// =============================================================================
+
+/// Streaming export writer
+pub struct ExportWriter<W: Write> {
+    inner: W,
+    format: ExportFormat,
+}
+
+impl<W: Write> ExportWriter<W> {
+    /// Create a writer and emit the header
+    pub fn new(mut inner: W, format: ExportFormat, header: &ExportHeader) -> Result<Self, ExportError> {
+        if format == ExportFormat::Binary {
+            inner.write_all(BINARY_MAGIC)?;
+        }
+        let mut writer = Self { inner, format };
+        writer.write_record(header)?;
+        Ok(writer)
+    }
+
+    /// Append one block
+    pub fn write_block(&mut self, block: &Block) -> Result<(), ExportError> {
+        self.write_record(block)
+    }
+
+    /// Flush buffered output
+    pub fn finish(mut self) -> Result<(), ExportError> {
+        self.inner.flush()?;
+        Ok(())
+    }
+
+    fn write_record<T: Serialize>(&mut self, record: &T) -> Result<(), ExportError> {
+        match self.format {
+            ExportFormat::Binary => {
+                let payload = bincode::serialize(record)
+                    .map_err(|e| ExportError::Encoding(e.to_string()))?;
+                self.inner.write_all(&(payload.len() as u32).to_le_bytes())?;
+                self.inner.write_all(&payload)?;
+            }
+            ExportFormat::Json => {
+                serde_json::to_writer(&mut self.inner, record)
+                    .map_err(|e| ExportError::Encoding(e.to_string()))?;
+                self.inner.write_all(b"\n")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Streaming export reader
+pub struct ExportReader<R: BufRead> {
+    inner: R,
+    format: ExportFormat,
+    header: ExportHeader,
+}
+
+impl<R: BufRead> ExportReader<R> {
+    /// Open a reader and parse the header
+    pub fn new(mut inner: R, format: ExportFormat) -> Result<Self, ExportError> {
+        if format == ExportFormat::Binary {
+            let mut magic = [0u8; 8];
+            inner.read_exact(&mut magic)?;
+            if &magic != BINARY_MAGIC {
+                return Err(ExportError::Corrupted("not a KratOs binary export".to_string()));
+            }
+        }
+
+        let header: ExportHeader = Self::read_record(&mut inner, format)?
+            .ok_or_else(|| ExportError::Corrupted("missing header".to_string()))?;
+        if header.version != EXPORT_VERSION {
+            return Err(ExportError::Corrupted(format!("unsupported version {}", header.version)));
+        }
+
+        Ok(Self { inner, format, header })
+    }
+
+    /// Header of the export
+    pub fn header(&self) -> &ExportHeader {
+        &self.header
+    }
+
+    /// Next block, or None at end of file
+    pub fn next_block(&mut self) -> Result<Option<Block>, ExportError> {
+        Self::read_record(&mut self.inner, self.format)
+    }
+
+    fn read_record<T: for<'de> Deserialize<'de>>(
+        inner: &mut R,
+        format: ExportFormat,
+    ) -> Result<Option<T>, ExportError> {
+        match format {
+            ExportFormat::Binary => {
+                let mut len_bytes = [0u8; 4];
+                match inner.read_exact(&mut len_bytes) {
+                    Ok(()) => {}
+                    Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+                    Err(e) => return Err(e.into()),
+                }
+                let len = u32::from_le_bytes(len_bytes) as usize;
+                if len > MAX_FRAME_SIZE {
+                    return Err(ExportError::Corrupted(format!("frame of {} bytes exceeds limit", len)));
+                }
+                let mut payload = vec![0u8; len];
+                inner.read_exact(&mut payload)?;
+                bincode::deserialize(&payload)
+                    .map(Some)
+                    .map_err(|e| ExportError::Corrupted(e.to_string()))
+            }
+            ExportFormat::Json => {
+                let mut line = String::new();
+                loop {
+                    line.clear();
+                    if inner.read_line(&mut line)? == 0 {
+                        return Ok(None);
+                    }
+                    if !line.trim().is_empty() {
+                        break;
+                    }
+                }
+                serde_json::from_str(&line)
+                    .map(Some)
+                    .map_err(|e| ExportError::Corrupted(e.to_string()))
+            }
+        }
+    }
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Export blocks [from, to] from the node database, returning the number written
+pub fn export_chain(cmd: &ExportCmd) -> Result<u64, ExportError> {
+    let format: ExportFormat = cmd.format.parse()?;
+    let base_path = cmd.get_base_path();
+
+    let db = open_existing(&base_path).map_err(|e| ExportError::Storage(format!("{:?}", e)))?;
+    let state = StateBackend::new(db);
+
+    let genesis_hash = state
+        .get_genesis_hash()
+        .map_err(|e| ExportError::Storage(format!("{:?}", e)))?
+        .ok_or_else(|| ExportError::Storage(format!("no chain found at {}", base_path.display())))?;
+    let best = state
+        .get_best_block()
+        .map_err(|e| ExportError::Storage(format!("{:?}", e)))?
+        .unwrap_or(0);
+
+    let to = cmd.to.unwrap_or(best).min(best);
+    if cmd.from > to {
+        return Err(ExportError::InvalidRange { from: cmd.from, to });
+    }
+
+    let header = ExportHeader {
+        version: EXPORT_VERSION,
+        genesis_hash,
+        from: cmd.from,
+        to,
+    };
+    let file = std::fs::File::create(&cmd.output)?;
+    let mut writer = ExportWriter::new(BufWriter::new(file), format, &header)?;
+
+    for number in cmd.from..=to {
+        let block = state
+            .get_block_by_number(number)
+            .map_err(|e| ExportError::Storage(format!("{:?}", e)))?
+            .ok_or(ExportError::MissingBlock(number))?;
+        writer.write_block(&block)?;
+
+        if number > cmd.from && number % PROGRESS_INTERVAL == 0 {
+            info!("📤 Exported block #{}/{}", number, to);
+        }
+    }
+    writer.finish()?;
+
+    Ok(to - cmd.from + 1)
+}
+
+/// Replay an export into a fresh database, returning the resulting chain height
+///
+/// The genesis block is rebuilt from the spec and must match the export;
+/// every later block goes through the regular import path (signature,
+/// parent hash, drift, transaction execution and state root checks).
+pub async fn import_chain(cmd: &ImportCmd) -> Result<BlockNumber, ExportError> {
+    let format: ExportFormat = cmd.format.parse()?;
+    let base_path = cmd.get_base_path();
+
+    let not_empty = std::fs::read_dir(&base_path)
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false);
+    if not_empty {
+        return Err(ExportError::NotEmpty(base_path.display().to_string()));
+    }
+
+    let file = std::fs::File::open(&cmd.input)?;
+    let mut reader = ExportReader::new(BufReader::new(file), format)?;
+    let header = reader.header().clone();
+    if header.from != 0 {
+        return Err(ExportError::Corrupted(format!(
+            "export starts at block #{}, a fresh database needs block #0",
+            header.from
+        )));
+    }
+
+    let genesis = match cmd.genesis_validator {
+        Some(ref account) => GenesisSpec::with_validator(parse_account(account)?),
+        None => GenesisSpec::default(),
+    };
+
+    // Offline node: ephemeral P2P port, no bootnodes
+    let mut chain = ChainConfig::mainnet();
+    chain.network.listen_port = 0;
+    chain.network.bootnodes.clear();
+
+    std::fs::create_dir_all(&base_path)?;
+    let node = KratOsNode::new(chain, &base_path, genesis, true)
+        .await
+        .map_err(|e| ExportError::Import(e.to_string()))?;
+
+    if node.genesis_hash() != header.genesis_hash {
+        return Err(ExportError::GenesisMismatch {
+            expected: header.genesis_hash,
+            actual: node.genesis_hash(),
+        });
+    }
+
+    let mut expected: BlockNumber = 0;
+    while let Some(block) = reader.next_block()? {
+        let number = block.header.number;
+        if number != expected {
+            return Err(ExportError::Corrupted(format!(
+                "expected block #{}, found #{}",
+                expected, number
+            )));
+        }
+
+        if number == 0 {
+            if block.hash() != header.genesis_hash {
+                return Err(ExportError::GenesisMismatch {
+                    expected: header.genesis_hash,
+                    actual: block.hash(),
+                });
+            }
+        } else {
+            node.import_block(block)
+                .await
+                .map_err(|e| ExportError::Import(format!("block #{}: {}", number, e)))?;
+        }
+
+        if number > 0 && number % PROGRESS_INTERVAL == 0 {
+            info!("📥 Imported block #{}/{}", number, header.to);
+        }
+        expected += 1;
+    }
+
+    let height = node.chain_height().await;
+    if height != header.to {
+        return Err(ExportError::Corrupted(format!(
+            "export truncated: header announces #{}, got #{}",
+            header.to, height
+        )));
+    }
+
+    Ok(height)
+}
+
+pub(crate) fn parse_account(hex_str: &str) -> Result<AccountId, ExportError> {
+    let bytes = hex::decode(hex_str.strip_prefix("0x").unwrap_or(hex_str))
+        .map_err(|e| ExportError::InvalidAccount(e.to_string()))?;
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| ExportError::InvalidAccount("expected 32 bytes".to_string()))?;
+    Ok(AccountId::from_bytes(bytes))
+}
+
+/// Export/import errors
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Unknown export format: {0} (expected binary or json)")]
+    UnknownFormat(String),
+
+    #[error("Storage error: {0}")]
+    Storage(String),
+
+    #[error("Invalid block range: {from}..{to}")]
+    InvalidRange { from: BlockNumber, to: BlockNumber },
+
+    #[error("Block #{0} missing from database")]
+    MissingBlock(BlockNumber),
+
+    #[error("Encoding error: {0}")]
+    Encoding(String),
+
+    #[error("Corrupted export: {0}")]
+    Corrupted(String),
+
+    #[error("Target database is not empty: {0} (purge it first)")]
+    NotEmpty(String),
+
+    #[error("Invalid genesis validator: {0}")]
+    InvalidAccount(String),
+
+    #[error("Genesis mismatch: export has {expected}, local spec builds {actual} (check --genesis-validator)")]
+    GenesisMismatch { expected: Hash, actual: Hash },
+
+    #[error("Import failed: {0}")]
+    Import(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genesis::GenesisBuilder;
+    use crate::storage::RocksDatabase;
+
+    fn genesis_block() -> Block {
+        let dir = tempfile::tempdir().unwrap();
+        let mut state = StateBackend::new(RocksDatabase::open(dir.path()).unwrap());
+        let spec = GenesisSpec::with_validator(AccountId::from_bytes([1u8; 32]));
+        GenesisBuilder::new(spec).build(&mut state).unwrap().0
+    }
+
+    #[test]
+    fn test_export_roundtrip_both_formats() {
+        let block = genesis_block();
+        let header = ExportHeader {
+            version: EXPORT_VERSION,
+            genesis_hash: block.hash(),
+            from: 0,
+            to: 0,
+        };
+
+        for format in [ExportFormat::Binary, ExportFormat::Json] {
+            let mut buf = Vec::new();
+            let mut writer = ExportWriter::new(&mut buf, format, &header).unwrap();
+            writer.write_block(&block).unwrap();
+            writer.finish().unwrap();
+
+            let mut reader = ExportReader::new(buf.as_slice(), format).unwrap();
+            assert_eq!(reader.header(), &header);
+            let read = reader.next_block().unwrap().unwrap();
+            assert_eq!(read.hash(), block.hash());
+            assert!(reader.next_block().unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_export_reader_rejects_bad_input() {
+        assert!(matches!(
+            ExportReader::new(&b"NOTKRATOS"[..], ExportFormat::Binary),
+            Err(ExportError::Corrupted(_))
+        ));
+
+        let mut oversized = BINARY_MAGIC.to_vec();
+        oversized.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            ExportReader::new(oversized.as_slice(), ExportFormat::Binary),
+            Err(ExportError::Corrupted(_))
+        ));
+
+        assert!("csv".parse::<ExportFormat>().is_err());
+    }
+}
@@ -0,0 +1,295 @@
+// Node info - Query a running node over JSON-RPC
+// Principle: One command answers "is this node healthy?" for humans and monitoring scripts
+//
+// Queried methods:
+// - system_info:        name, version, chain head, peers
+// - system_syncState:   sync progress
+// - system_health:      overall health flag
+// - finality_getStatus: last finalized block (optional, older nodes may not expose it)
+// - validator_getInfo:  validator status (only with --validator)
+
+use crate::cli::InfoCmd;
+use crate::rpc::{HealthStatus, JsonRpcError, SyncStatus, SystemInfo, ValidatorInfoRpc};
+use crate::types::BlockNumber;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Timeout for a single RPC call
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Output format of the info command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InfoFormat {
+    Text,
+    Json,
+}
+
+impl std::str::FromStr for InfoFormat {
+    type Err = InfoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(InfoFormat::Text),
+            "json" => Ok(InfoFormat::Json),
+            other => Err(InfoError::InvalidFormat(other.to_string())),
+        }
+    }
+}
+
+/// Finality status as returned by finality_getStatus
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinalityStatus {
+    pub last_finalized_block: BlockNumber,
+    pub last_finalized_hash: String,
+    pub current_round: u64,
+    pub finality_lag: u64,
+    pub total_validators: usize,
+}
+
+/// Aggregated node report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InfoReport {
+    pub endpoint: String,
+    pub system: SystemInfo,
+    pub sync: SyncStatus,
+    pub health: HealthStatus,
+    pub best_height: BlockNumber,
+    pub finalized_height: Option<BlockNumber>,
+    pub finality: Option<FinalityStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validator: Option<ValidatorStatus>,
+}
+
+/// Validator status of the address passed with --validator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorStatus {
+    pub address: String,
+    /// None when the address is not a registered validator
+    pub info: Option<ValidatorInfoRpc>,
+}
+
+/// Minimal JSON-RPC 2.0 client over HTTP
+pub struct RpcClient {
+    endpoint: String,
+    http: reqwest::Client,
+    next_id: std::sync::atomic::AtomicI64,
+}
+
+impl RpcClient {
+    pub fn new(endpoint: &str) -> Result<Self, InfoError> {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| InfoError::Transport(e.to_string()))?;
+        Ok(Self {
+            endpoint: endpoint.to_string(),
+            http,
+            next_id: std::sync::atomic::AtomicI64::new(1),
+        })
+    }
+
+    /// Call a method and decode its result
+    pub async fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<T, InfoError> {
+        let id = self.next_id.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let body = request_body(method, params, id);
+
+        let response = self
+            .http
+            .post(&self.endpoint)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| InfoError::Transport(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(InfoError::Transport(format!("HTTP {}", response.status())));
+        }
+
+        let value: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| InfoError::InvalidResponse(e.to_string()))?;
+        parse_response(method, value)
+    }
+}
+
+fn request_body(method: &str, params: serde_json::Value, id: i64) -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": method,
+        "params": params,
+        "id": id,
+    })
+}
+
+fn parse_response<T: DeserializeOwned>(method: &str, value: serde_json::Value) -> Result<T, InfoError> {
+    if let Some(error) = value.get("error").filter(|e| !e.is_null()) {
+        let error: JsonRpcError = serde_json::from_value(error.clone())
+            .map_err(|e| InfoError::InvalidResponse(e.to_string()))?;
+        return Err(InfoError::Rpc {
+            method: method.to_string(),
+            code: error.code,
+            message: error.message,
+        });
+    }
+
+    let result = value
+        .get("result")
+        .cloned()
+        .ok_or_else(|| InfoError::InvalidResponse(format!("{}: missing result", method)))?;
+    serde_json::from_value(result).map_err(|e| InfoError::InvalidResponse(format!("{}: {}", method, e)))
+}
+
+/// Query the node and build a report
+pub async fn query_info(cmd: &InfoCmd) -> Result<InfoReport, InfoError> {
+    let client = RpcClient::new(&cmd.rpc)?;
+    let no_params = serde_json::json!([]);
+
+    let system: SystemInfo = client.call("system_info", no_params.clone()).await?;
+    let sync: SyncStatus = client.call("system_syncState", no_params.clone()).await?;
+    let health: HealthStatus = client.call("system_health", no_params.clone()).await?;
+
+    // Finality is optional: a node without the method still gets a report
+    let finality: Option<FinalityStatus> = match client.call("finality_getStatus", no_params).await {
+        Ok(status) => Some(status),
+        Err(InfoError::Rpc { .. }) => None,
+        Err(e) => return Err(e),
+    };
+
+    let validator = match &cmd.validator {
+        Some(address) => {
+            let info = match client
+                .call::<ValidatorInfoRpc>("validator_getInfo", serde_json::json!([address]))
+                .await
+            {
+                Ok(info) => Some(info),
+                Err(InfoError::Rpc { code, .. }) if code == JsonRpcError::validator_not_found().code => None,
+                Err(e) => return Err(e),
+            };
+            Some(ValidatorStatus { address: address.clone(), info })
+        }
+        None => None,
+    };
+
+    Ok(InfoReport {
+        endpoint: cmd.rpc.clone(),
+        best_height: system.chain.height,
+        finalized_height: finality.as_ref().map(|f| f.last_finalized_block),
+        system,
+        sync,
+        health,
+        finality,
+        validator,
+    })
+}
+
+/// Print a report in the requested format
+pub fn print_report(report: &InfoReport, format: InfoFormat) -> Result<(), InfoError> {
+    match format {
+        InfoFormat::Json => {
+            let json = serde_json::to_string_pretty(report)
+                .map_err(|e| InfoError::InvalidResponse(e.to_string()))?;
+            println!("{}", json);
+        }
+        InfoFormat::Text => print_text(report),
+    }
+    Ok(())
+}
+
+fn print_text(report: &InfoReport) {
+    let system = &report.system;
+    println!("Node:            {} v{}", system.name, system.version);
+    println!("Endpoint:        {}", report.endpoint);
+    println!("Chain:           {}", system.chain.chain_name);
+    println!("Genesis:         {}", system.chain.genesis_hash);
+    println!("Health:          {}", if report.health.healthy { "healthy" } else { "unhealthy" });
+    println!();
+    println!("Best block:      #{} ({})", report.best_height, system.chain.best_hash);
+    match &report.finality {
+        Some(f) => println!("Finalized block: #{} (lag {})", f.last_finalized_block, f.finality_lag),
+        None => println!("Finalized block: unavailable"),
+    }
+    println!("Epoch / slot:    {} / {}", system.chain.current_epoch, system.chain.current_slot);
+    println!(
+        "Sync:            {} ({} / {}, {} behind)",
+        report.sync.state, report.sync.current_block, report.sync.highest_block, report.sync.blocks_behind
+    );
+    println!();
+    println!("Peer ID:         {}", system.network.local_peer_id);
+    println!("Peers:           {}", system.network.peer_count);
+    println!("Network height:  #{}", system.network.network_best_height);
+    println!("Pending txs:     {}", system.pending_txs);
+
+    if let Some(validator) = &report.validator {
+        println!();
+        match &validator.info {
+            Some(info) => {
+                println!("Validator:       {}", info.address);
+                println!("  Status:        {}{}", info.status, if info.is_active { " (active)" } else { "" });
+                println!("  Stake:         {}", info.stake);
+                println!("  Credits:       {}", info.validator_credits);
+                println!(
+                    "  Blocks:        {} produced, {} missed ({:.1}%)",
+                    info.blocks_produced, info.blocks_missed, info.participation_percent
+                );
+                println!("  Can produce:   {}", if info.can_participate { "yes" } else { "no" });
+            }
+            None => println!("Validator:       {} is not a registered validator", validator.address),
+        }
+    }
+}
+
+/// Info command errors
+#[derive(Debug, thiserror::Error)]
+pub enum InfoError {
+    #[error("Invalid output format: {0} (expected text or json)")]
+    InvalidFormat(String),
+
+    #[error("Could not reach node: {0}")]
+    Transport(String),
+
+    #[error("RPC error from {method} ({code}): {message}")]
+    Rpc { method: String, code: i32, message: String },
+
+    #[error("Invalid RPC response: {0}")]
+    InvalidResponse(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_body() {
+        let body = request_body("system_info", serde_json::json!([]), 3);
+        assert_eq!(body["jsonrpc"], "2.0");
+        assert_eq!(body["method"], "system_info");
+        assert_eq!(body["id"], 3);
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let ok = serde_json::json!({
+            "jsonrpc": "2.0",
+            "result": { "syncing": false, "currentBlock": 10, "highestBlock": 10, "blocksBehind": 0, "state": "Synced" },
+            "id": 1
+        });
+        let sync: SyncStatus = parse_response("system_syncState", ok).unwrap();
+        assert_eq!(sync.current_block, 10);
+
+        let err = serde_json::json!({
+            "jsonrpc": "2.0",
+            "error": { "code": -32601, "message": "Method not found" },
+            "id": 2
+        });
+        let result: Result<SyncStatus, _> = parse_response("finality_getStatus", err);
+        assert!(matches!(result, Err(InfoError::Rpc { code: -32601, .. })));
+    }
+}
@@ -0,0 +1,329 @@
+// Logging - Console and file output of the node logs
+// - --log-format text|json: JSON is one object per line (timestamp, level,
+//   target, fields, spans), ready for ELK/Loki shippers
+// - --log-dir: logs are also written to <dir>/kratos-node.log, rotated
+//   hourly/daily (--log-rotation) or when it exceeds --log-max-size MB;
+//   --log-max-files rotated files are kept, oldest removed first
+// - The filter of every layer can be replaced while running (config reload)
+
+use chrono::{DateTime, Utc};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use thiserror::Error;
+use tracing_subscriber::{reload, EnvFilter, Layer, Registry};
+
+/// Name of the active log file (rotated files: kratos-node.<time>.log)
+const FILE_STEM: &str = "kratos-node";
+const FILE_EXTENSION: &str = "log";
+
+#[derive(Debug, Error)]
+pub enum LogError {
+    #[error("Invalid log format: {0} (expected text or json)")]
+    InvalidFormat(String),
+
+    #[error("Invalid log rotation: {0} (expected hourly, daily or never)")]
+    InvalidRotation(String),
+
+    #[error("Cannot open log file in {0}: {1}")]
+    Io(String, io::Error),
+
+    #[error("Cannot replace the log filter: {0}")]
+    Reload(String),
+}
+
+/// Format of the log lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = LogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            other => Err(LogError::InvalidFormat(other.to_string())),
+        }
+    }
+}
+
+/// Time-based rotation of the log file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Hourly,
+    Daily,
+    Never,
+}
+
+impl std::str::FromStr for Rotation {
+    type Err = LogError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hourly" => Ok(Rotation::Hourly),
+            "daily" => Ok(Rotation::Daily),
+            "never" => Ok(Rotation::Never),
+            other => Err(LogError::InvalidRotation(other.to_string())),
+        }
+    }
+}
+
+impl Rotation {
+    /// Period containing `time` (the file rotates when it changes)
+    fn period(&self, time: DateTime<Utc>) -> i64 {
+        match self {
+            Rotation::Hourly => time.timestamp().div_euclid(3600),
+            Rotation::Daily => time.timestamp().div_euclid(86400),
+            Rotation::Never => 0,
+        }
+    }
+}
+
+/// Log file settings
+#[derive(Debug, Clone)]
+pub struct FileOptions {
+    pub dir: PathBuf,
+    pub rotation: Rotation,
+    /// Rotate past this size in bytes (0: no size limit)
+    pub max_size: u64,
+    /// Rotated files kept next to the active one
+    pub max_files: usize,
+}
+
+/// Log file rotated on size and time
+pub struct RotatingFile {
+    options: FileOptions,
+    file: File,
+    size: u64,
+    period: i64,
+}
+
+impl RotatingFile {
+    /// Open (append to) the active file of `options.dir`
+    pub fn open(options: FileOptions) -> io::Result<Self> {
+        std::fs::create_dir_all(&options.dir)?;
+        let path = active_path(&options.dir);
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let metadata = file.metadata()?;
+        // A file left by a previous run belongs to the period it was last written in
+        let modified = metadata.modified().map(DateTime::<Utc>::from).unwrap_or_else(|_| Utc::now());
+
+        Ok(Self {
+            size: metadata.len(),
+            period: options.rotation.period(modified),
+            options,
+            file,
+        })
+    }
+
+    fn should_rotate(&self, incoming: usize, now: DateTime<Utc>) -> bool {
+        if self.size == 0 {
+            return false;
+        }
+        let too_large = self.options.max_size > 0 && self.size + incoming as u64 > self.options.max_size;
+        too_large || self.options.rotation.period(now) != self.period
+    }
+
+    /// Move the active file aside, start a new one and prune old files
+    fn rotate(&mut self, now: DateTime<Utc>) -> io::Result<()> {
+        self.file.flush()?;
+        let dir = &self.options.dir;
+        // Fixed width with microseconds: names sort in rotation order, even for
+        // several size rotations within a second
+        let stamp = now.format("%Y%m%dT%H%M%S%.6f").to_string();
+        let target = dir.join(format!("{}.{}.{}", FILE_STEM, stamp, FILE_EXTENSION));
+        std::fs::rename(active_path(dir), &target)?;
+
+        self.file = OpenOptions::new().create(true).append(true).open(active_path(dir))?;
+        self.size = 0;
+        self.period = self.options.rotation.period(now);
+
+        let rotated = rotated_files(dir)?;
+        for old in rotated.iter().take(rotated.len().saturating_sub(self.options.max_files)) {
+            let _ = std::fs::remove_file(old);
+        }
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let now = Utc::now();
+        if self.should_rotate(buf.len(), now) {
+            self.rotate(now)?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn active_path(dir: &Path) -> PathBuf {
+    dir.join(format!("{}.{}", FILE_STEM, FILE_EXTENSION))
+}
+
+/// Rotated files, oldest first (names sort by rotation time)
+fn rotated_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let prefix = format!("{}.", FILE_STEM);
+    let suffix = format!(".{}", FILE_EXTENSION);
+    let active = format!("{}.{}", FILE_STEM, FILE_EXTENSION);
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name != active && name.starts_with(&prefix) && name.ends_with(&suffix))
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync>;
+type FilterHandle = reload::Handle<EnvFilter, Registry>;
+
+/// Filter directives of the log options
+pub fn directives(verbose: bool, log_level: &str, debug_grandpa: bool) -> String {
+    if debug_grandpa {
+        // Debug GRANDPA: enable trace logs for finality modules
+        "info,kratos_core::consensus::finality=trace,kratos_core::node::finality_integration=trace".to_string()
+    } else if verbose {
+        "debug".to_string()
+    } else {
+        log_level.to_string()
+    }
+}
+
+/// Filter of `directives`, unless RUST_LOG is set
+pub fn env_filter(directives: &str) -> EnvFilter {
+    EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(directives))
+}
+
+/// Replaces the filter of the log layers
+pub struct LogReload {
+    handles: Vec<FilterHandle>,
+    directives: String,
+}
+
+impl LogReload {
+    /// Current filter directives
+    pub fn directives(&self) -> &str {
+        &self.directives
+    }
+
+    /// Filter every layer (console and file) with `directives` (RUST_LOG still wins)
+    pub fn set_directives(&mut self, directives: &str) -> Result<(), LogError> {
+        for handle in &self.handles {
+            handle.reload(env_filter(directives)).map_err(|e| LogError::Reload(e.to_string()))?;
+        }
+        self.directives = directives.to_string();
+        Ok(())
+    }
+}
+
+/// One formatting layer, text or JSON
+fn fmt_layer<W>(format: LogFormat, writer: W, ansi: bool, filter: EnvFilter) -> (BoxedLayer, FilterHandle)
+where
+    W: for<'writer> tracing_subscriber::fmt::MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let (filter, handle) = reload::Layer::new(filter);
+    let layer = tracing_subscriber::fmt::layer().with_writer(writer).with_ansi(ansi);
+    let layer = match format {
+        LogFormat::Text => layer.with_filter(filter).boxed(),
+        LogFormat::Json => layer.json().flatten_event(true).with_filter(filter).boxed(),
+    };
+    (layer, handle)
+}
+
+/// Console layer, plus the file layer with `file` (same format and filter)
+pub fn layers(
+    format: LogFormat,
+    file: Option<FileOptions>,
+    directives: &str,
+) -> Result<(Vec<BoxedLayer>, LogReload), LogError> {
+    let (console, handle) = fmt_layer(format, io::stdout, format == LogFormat::Text, env_filter(directives));
+    let mut layers = vec![console];
+    let mut handles = vec![handle];
+    if let Some(options) = file {
+        let dir = options.dir.display().to_string();
+        let writer = RotatingFile::open(options).map_err(|e| LogError::Io(dir, e))?;
+        let (layer, handle) = fmt_layer(format, Mutex::new(writer), false, env_filter(directives));
+        layers.push(layer);
+        handles.push(handle);
+    }
+    Ok((layers, LogReload { handles, directives: directives.to_string() }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(dir: &Path, max_size: u64, max_files: usize) -> FileOptions {
+        FileOptions {
+            dir: dir.to_path_buf(),
+            rotation: Rotation::Never,
+            max_size,
+            max_files,
+        }
+    }
+
+    #[test]
+    fn test_size_rotation_and_pruning() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut file = RotatingFile::open(options(dir.path(), 64, 2)).unwrap();
+
+        // The fmt layer writes each formatted line at once
+        for i in 0..10 {
+            file.write_all(format!("line {:02} ..............................\n", i).as_bytes()).unwrap();
+        }
+        file.flush().unwrap();
+
+        // One line per file past 64 bytes, two rotated files kept
+        let rotated = rotated_files(dir.path()).unwrap();
+        assert_eq!(rotated.len(), 2);
+        let active = std::fs::read_to_string(active_path(dir.path())).unwrap();
+        assert!(active.starts_with("line 09"));
+        assert!(std::fs::read_to_string(&rotated[1]).unwrap().starts_with("line 08"));
+
+        // Reopening appends to the active file
+        drop(file);
+        let mut file = RotatingFile::open(options(dir.path(), 0, 2)).unwrap();
+        file.write_all(b"after restart\n").unwrap();
+        let active = std::fs::read_to_string(active_path(dir.path())).unwrap();
+        assert!(active.starts_with("line 09") && active.ends_with("after restart\n"));
+    }
+
+    #[test]
+    fn test_time_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut opts = options(dir.path(), 0, 5);
+        opts.rotation = Rotation::Daily;
+        let mut file = RotatingFile::open(opts).unwrap();
+        file.write_all(b"yesterday\n").unwrap();
+
+        let now = Utc::now();
+        assert!(!file.should_rotate(1, now));
+        assert!(file.should_rotate(1, now + chrono::Duration::days(1)));
+
+        file.rotate(now + chrono::Duration::days(1)).unwrap();
+        assert_eq!(rotated_files(dir.path()).unwrap().len(), 1);
+        assert_eq!(std::fs::read_to_string(active_path(dir.path())).unwrap(), "");
+    }
+
+    #[test]
+    fn test_parse_options() {
+        assert_eq!("JSON".parse::<LogFormat>().unwrap(), LogFormat::Json);
+        assert!("xml".parse::<LogFormat>().is_err());
+        assert_eq!("hourly".parse::<Rotation>().unwrap(), Rotation::Hourly);
+        assert!("weekly".parse::<Rotation>().is_err());
+    }
+}
@@ -1,9 +1,23 @@
 // CLI - Command Line Interface for KratOs Node
 // Principle: Simple, clear, composable commands
 
+pub mod audit;
+pub mod build_spec;
 pub mod config;
+pub mod config_file;
+pub mod db;
+pub mod export;
+pub mod info;
+pub mod logging;
+pub mod otlp;
+pub mod peers;
+pub mod prune;
+pub mod reload;
 pub mod runner;
+pub mod snapshot;
 
+use crate::rpc::RpcProfile;
+use crate::storage::DatabaseBackend;
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
@@ -42,10 +56,31 @@ pub struct Cli {
     /// Log level (error, warn, info, debug, trace)
     #[arg(long, global = true, default_value = "info", env = "KRATOS_LOG")]
     pub log_level: String,
+
+    /// Log format (text, json)
+    #[arg(long, global = true, default_value = "text", env = "KRATOS_LOG_FORMAT")]
+    pub log_format: String,
+
+    /// Also write logs to files in this directory
+    #[arg(long, global = true)]
+    pub log_dir: Option<PathBuf>,
+
+    /// Time-based rotation of the log file (hourly, daily, never)
+    #[arg(long, global = true, default_value = "daily")]
+    pub log_rotation: String,
+
+    /// Rotate the log file past this size in MB (0 for no limit)
+    #[arg(long, global = true, default_value = "100")]
+    pub log_max_size: u64,
+
+    /// Rotated log files kept
+    #[arg(long, global = true, default_value = "7")]
+    pub log_max_files: usize,
 }
 
 /// Available commands
 #[derive(Subcommand, Debug)]
+#[allow(clippy::large_enum_variant)] // Parsed once at startup
 pub enum Commands {
     /// Run the node
     Run(RunCmd),
@@ -56,16 +91,45 @@ pub enum Commands {
     /// Key management
     Key(KeyCmd),
 
-    /// Export/import chain data
+    /// Export chain data
     Export(ExportCmd),
 
+    /// Import chain data from an export file
+    Import(ImportCmd),
+
+    /// State snapshots (export the state at a block, bootstrap a node from it)
+    Snapshot(SnapshotCmd),
+
+    /// Known peers (export them from a node, seed a new node with them; node must be stopped)
+    Peers(PeersCmd),
+
     /// Purge chain data
     Purge(PurgeCmd),
+
+    /// Prune finalized block data now (node must be stopped)
+    PruneNow(PruneNowCmd),
+
+    /// Database maintenance (node must be stopped)
+    Db(DbCmd),
+
+    /// Re-execute stored blocks and report divergences (node must be stopped)
+    Audit(AuditCmd),
+
+    /// Node configuration file
+    Config(ConfigCmd),
+
+    /// Write a chain spec (JSON) to start a custom network with `run --chain <file>`
+    BuildSpec(BuildSpecCmd),
 }
 
 /// Run the node
 #[derive(Parser, Debug)]
 pub struct RunCmd {
+    /// Node configuration file (TOML, see `config generate`)
+    /// Command line flags and environment variables take precedence over it
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
     /// Genesis mode - create a new network (generates genesis block)
     /// Without this flag, the node joins an existing network via DNS Seeds
     #[arg(long)]
@@ -99,6 +163,11 @@ pub struct RunCmd {
     #[arg(long, default_value = "127.0.0.1")]
     pub rpc_addr: String,
 
+    /// RPC exposure profile: private (every method), public (curated methods,
+    /// rate limited, capped responses), archive (public plus historical queries)
+    #[arg(long, default_value = "private")]
+    pub rpc_profile: RpcProfile,
+
     /// Bootstrap nodes (can be specified multiple times)
     #[arg(long = "bootnode", value_name = "MULTIADDR")]
     pub bootnodes: Vec<String>,
@@ -111,43 +180,153 @@ pub struct RunCmd {
     #[arg(long)]
     pub validator: bool,
 
-    /// Validator key file (required for --validator)
+    /// Validator key file (defaults to the aura key in the keystore)
     #[arg(long, requires = "validator")]
     pub validator_key: Option<PathBuf>,
 
+    /// Password for the keystore under the base path
+    #[arg(long, env = "KRATOS_KEYSTORE_PASSWORD", hide_env_values = true)]
+    pub keystore_password: Option<String>,
+
+    /// Epochs after a key rotation before the old key is moved to the keystore archive
+    #[arg(long, default_value = "2")]
+    pub keystore_retire_after: u64,
+
     /// Sync mode (full, light, warp)
     #[arg(long, default_value = "full")]
     pub sync: String,
 
+    /// Trusted finalized block for warp sync (<hash:number>)
+    #[arg(long)]
+    pub warp_checkpoint: Option<String>,
+
+    /// Sync from a trusted finalized block, skipping earlier blocks (<hash:number>)
+    #[arg(long, conflicts_with = "warp_checkpoint")]
+    pub sync_from_checkpoint: Option<String>,
+
     /// Pruning mode (archive, 256, 1000, etc.)
     #[arg(long, default_value = "256")]
     pub pruning: String,
 
+    /// Index transactions by account, events by type and blocks by author
+    /// (account_getTransactions, chain_getEvents, validator_getAuthoredBlocks)
+    #[arg(long)]
+    pub index: bool,
+
+    /// Database backend (rocksdb, paritydb, memory)
+    #[arg(long, default_value = "rocksdb")]
+    pub database: DatabaseBackend,
+
     /// Database cache size in MB
     #[arg(long, default_value = "128")]
     pub db_cache: u32,
 
+    /// Number of accounts kept in the state cache
+    #[arg(long, default_value = "16384")]
+    pub state_cache: usize,
+
     /// Prometheus metrics port (0 to disable)
     #[arg(long, default_value = "0")]
     pub prometheus_port: u16,
 
+    /// OTLP collector receiving tracing spans (gRPC, e.g. http://localhost:4317)
+    #[arg(long)]
+    pub tracing_endpoint: Option<String>,
+
     /// External address for P2P (useful behind NAT)
     #[arg(long)]
     pub public_addr: Option<String>,
 
-    /// Force unsafe RPC methods
+    /// Relay connections for nodes that can't be dialed (circuit relay v2,
+    /// advertised to the DNS Seeds; needs a publicly reachable P2P port)
     #[arg(long)]
-    pub rpc_methods_unsafe: bool,
+    pub relay: bool,
+
+    /// Also accept QUIC connections on this UDP port (e.g. 30333), next to TCP
+    #[arg(long, value_name = "UDP_PORT")]
+    pub listen_quic: Option<u16>,
+
+    /// Don't discover peers on the local network (mDNS is on for custom
+    /// chains, never on the main network)
+    #[arg(long)]
+    pub no_mdns: bool,
+
+    /// Peer kept connected at all times, never evicted (can be specified
+    /// multiple times; multiaddr with /p2p/<peer id>)
+    #[arg(long = "reserved-peer", value_name = "MULTIADDR")]
+    pub reserved_peers: Vec<String>,
+
+    /// Only connect to reserved peers: no discovery, no DNS Seed heartbeats
+    /// (validators behind sentry nodes)
+    #[arg(long, requires = "reserved_peers")]
+    pub reserved_only: bool,
 
-    /// Allow connections from any origin (CORS)
+    /// Shield this validator as its sentry node (can be specified multiple
+    /// times): kept connected like a reserved peer, its address never shared
+    #[arg(long = "sentry-for", value_name = "MULTIADDR", conflicts_with = "reserved_only")]
+    pub sentry_for: Vec<String>,
+
+    /// Serve unsafe RPC methods (debug_*)
     #[arg(long)]
+    pub rpc_methods_unsafe: bool,
+
+    /// Deprecated: use --rpc-cors "*"
+    #[arg(long, hide = true)]
     pub rpc_cors_all: bool,
 
+    /// Browser origin allowed to call the RPC server (can be specified
+    /// multiple times, "*" for any; default: localhost only)
+    #[arg(long, value_name = "ORIGIN")]
+    pub rpc_cors: Vec<String>,
+
+    /// Host header accepted by the RPC server, against DNS rebinding (can be
+    /// specified multiple times: a name or IP for any port, HOST:PORT, "*" for
+    /// any; default: localhost, or any host when listening on another address)
+    #[arg(long, value_name = "HOST")]
+    pub rpc_allowed_hosts: Vec<String>,
+
+    /// Serve the RPC over HTTPS with this certificate chain (PEM, leaf first;
+    /// requires --rpc-tls-key)
+    #[arg(long, value_name = "FILE", requires = "rpc_tls_key")]
+    pub rpc_tls_cert: Option<PathBuf>,
+
+    /// Private key of --rpc-tls-cert (PEM)
+    #[arg(long, value_name = "FILE", requires = "rpc_tls_cert")]
+    pub rpc_tls_key: Option<PathBuf>,
+
     /// Enable detailed trace logs for GRANDPA finality debugging
     #[arg(long)]
     pub debug_grandpa: bool,
 }
 
+/// Node configuration file commands
+#[derive(Parser, Debug)]
+pub struct ConfigCmd {
+    #[command(subcommand)]
+    pub subcommand: ConfigSubcommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigSubcommand {
+    /// Print a documented configuration template (every option, with its default)
+    Generate {
+        /// Output file (stdout if not specified)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Generate a token for the unsafe RPC methods and its [[rpc-token]] section
+    Token {
+        /// Token name, for the logs
+        #[arg(long)]
+        name: String,
+
+        /// Method allowed (repeatable; `prefix_*` patterns, `*` for every unsafe method)
+        #[arg(long = "method", value_name = "METHOD", required = true)]
+        methods: Vec<String>,
+    },
+}
+
 /// Show node info
 #[derive(Parser, Debug)]
 pub struct InfoCmd {
@@ -158,6 +337,10 @@ pub struct InfoCmd {
     /// Output format (text, json)
     #[arg(long, default_value = "text")]
     pub format: String,
+
+    /// Also report the status of this validator account
+    #[arg(long)]
+    pub validator: Option<String>,
 }
 
 /// Key management commands
@@ -200,7 +383,7 @@ pub enum KeySubcommand {
         #[arg(short = 'd', long)]
         base_path: Option<PathBuf>,
 
-        /// Key type (aura, babe, grandpa)
+        /// Key type (aura, babe, grandpa, session)
         #[arg(long)]
         key_type: String,
 
@@ -208,9 +391,13 @@ pub enum KeySubcommand {
         #[arg(long, default_value = "ed25519")]
         scheme: String,
 
-        /// Seed or key file
+        /// Hex secret (0x...) or key file from `key generate`
         #[arg(long)]
         suri: String,
+
+        /// Keystore password (prompted if not specified)
+        #[arg(long, env = "KRATOS_KEYSTORE_PASSWORD", hide_env_values = true)]
+        password: Option<String>,
     },
 
     /// List keys in keystore
@@ -243,6 +430,142 @@ pub struct ExportCmd {
     /// Export format (binary, json)
     #[arg(long, default_value = "binary")]
     pub format: String,
+
+    /// Chain to export (chain name)
+    #[arg(long, default_value = "kratos")]
+    pub chain: String,
+}
+
+/// Import chain data into a fresh database
+#[derive(Parser, Debug)]
+pub struct ImportCmd {
+    /// Base path for chain data (must be empty)
+    #[arg(short = 'd', long)]
+    pub base_path: Option<PathBuf>,
+
+    /// Input file
+    #[arg(short, long)]
+    pub input: PathBuf,
+
+    /// Import format (binary, json)
+    #[arg(long, default_value = "binary")]
+    pub format: String,
+
+    /// Chain to import into (chain name)
+    #[arg(long, default_value = "kratos")]
+    pub chain: String,
+
+    /// Genesis validator account (hex) the chain was started with (default: mainnet)
+    #[arg(long)]
+    pub genesis_validator: Option<String>,
+}
+
+/// State snapshots
+#[derive(Parser, Debug)]
+pub struct SnapshotCmd {
+    #[command(subcommand)]
+    pub subcommand: SnapshotSubcommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SnapshotSubcommand {
+    /// Write a compressed, content-addressed snapshot of the state at a block
+    Export(SnapshotExportCmd),
+
+    /// Bootstrap a fresh database from a snapshot
+    Import(SnapshotImportCmd),
+}
+
+/// Export a state snapshot
+#[derive(Parser, Debug)]
+pub struct SnapshotExportCmd {
+    /// Base path for chain data
+    #[arg(short = 'd', long)]
+    pub base_path: Option<PathBuf>,
+
+    /// Output directory (the file is named after its content hash)
+    #[arg(short, long, default_value = ".")]
+    pub output: PathBuf,
+
+    /// Block to snapshot (best block if not specified; older blocks need archive mode)
+    #[arg(long)]
+    pub at: Option<u64>,
+
+    /// Chain to snapshot (chain name)
+    #[arg(long, default_value = "kratos")]
+    pub chain: String,
+}
+
+/// Import a state snapshot into a fresh database
+#[derive(Parser, Debug)]
+pub struct SnapshotImportCmd {
+    /// Base path for chain data (must be empty)
+    #[arg(short = 'd', long)]
+    pub base_path: Option<PathBuf>,
+
+    /// Snapshot file
+    #[arg(short, long)]
+    pub input: PathBuf,
+
+    /// Chain to import into (chain name)
+    #[arg(long, default_value = "kratos")]
+    pub chain: String,
+
+    /// Storage backend of the new database (rocksdb, paritydb)
+    #[arg(long, default_value = "rocksdb")]
+    pub database: DatabaseBackend,
+}
+
+/// Known-peers set
+#[derive(Parser, Debug)]
+pub struct PeersCmd {
+    #[command(subcommand)]
+    pub subcommand: PeersSubcommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PeersSubcommand {
+    /// Write the known-peers set (JSON)
+    Export(PeersExportCmd),
+
+    /// Merge an exported set into the known peers (dialed on the next start)
+    Import(PeersImportCmd),
+}
+
+/// Export the known-peers set
+#[derive(Parser, Debug)]
+pub struct PeersExportCmd {
+    /// Base path for chain data
+    #[arg(short = 'd', long)]
+    pub base_path: Option<PathBuf>,
+
+    /// Chain to export from (chain name)
+    #[arg(long, default_value = "kratos")]
+    pub chain: String,
+
+    /// Output file (stdout if not specified)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Import a known-peers set
+#[derive(Parser, Debug)]
+pub struct PeersImportCmd {
+    /// Base path for chain data (created if missing)
+    #[arg(short = 'd', long)]
+    pub base_path: Option<PathBuf>,
+
+    /// Chain to import into (chain name)
+    #[arg(long, default_value = "kratos")]
+    pub chain: String,
+
+    /// File written by `peers export`
+    #[arg(short, long)]
+    pub input: PathBuf,
+
+    /// Storage backend of a new database (rocksdb, paritydb)
+    #[arg(long, default_value = "rocksdb")]
+    pub database: DatabaseBackend,
 }
 
 /// Purge chain data
@@ -261,8 +584,273 @@ pub struct PurgeCmd {
     pub yes: bool,
 }
 
+/// Prune finalized block data outside the pruning window
+#[derive(Parser, Debug)]
+pub struct PruneNowCmd {
+    /// Base path for chain data
+    #[arg(short = 'd', long)]
+    pub base_path: Option<PathBuf>,
+
+    /// Chain to prune (chain name)
+    #[arg(long, default_value = "kratos")]
+    pub chain: String,
+
+    /// Blocks to keep behind finality
+    #[arg(long, default_value = "256")]
+    pub pruning: String,
+}
+
+/// Re-execute stored blocks against their parent state
+#[derive(Parser, Debug)]
+pub struct AuditCmd {
+    /// Base path for chain data
+    #[arg(short = 'd', long)]
+    pub base_path: Option<PathBuf>,
+
+    /// Chain to audit (chain name)
+    #[arg(long, default_value = "kratos")]
+    pub chain: String,
+
+    /// First block to audit
+    #[arg(long, default_value = "1")]
+    pub from: u64,
+
+    /// Last block to audit (latest if not specified)
+    #[arg(long)]
+    pub to: Option<u64>,
+
+    /// Genesis validator account (hex) the chain was started with (default: mainnet)
+    #[arg(long)]
+    pub genesis_validator: Option<String>,
+
+    /// Write the JSON report to this file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Write a chain spec
+#[derive(Parser, Debug)]
+pub struct BuildSpecCmd {
+    /// Spec to start from (kratos or path to spec file)
+    #[arg(long, default_value = "kratos")]
+    pub chain: String,
+
+    /// Chain name
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Chain ID
+    #[arg(long)]
+    pub chain_id: Option<u32>,
+
+    /// Genesis timestamp (unix seconds)
+    #[arg(long)]
+    pub timestamp: Option<u64>,
+
+    /// Bootstrap validator account (hex), can be repeated
+    /// (validator flags replace the validators of the base spec)
+    #[arg(long)]
+    pub genesis_validator: Vec<String>,
+
+    /// Staking validator as <account:stake>, can be repeated (stake is
+    /// reserved from its genesis balance)
+    #[arg(long = "validator")]
+    pub validators: Vec<String>,
+
+    /// Genesis balance as <account:amount>, can be repeated
+    #[arg(long = "balance")]
+    pub balances: Vec<String>,
+
+    /// Bootnode multiaddr, can be repeated
+    #[arg(long = "bootnode")]
+    pub bootnodes: Vec<String>,
+
+    /// Write the spec to this file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Database maintenance
+#[derive(Parser, Debug)]
+pub struct DbCmd {
+    #[command(subcommand)]
+    pub subcommand: DbSubcommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DbSubcommand {
+    /// Compact the database (reclaims space left by pruning and purges)
+    Compact {
+        /// Base path for chain data
+        #[arg(short = 'd', long)]
+        base_path: Option<PathBuf>,
+
+        /// Chain to compact (chain name)
+        #[arg(long, default_value = "kratos")]
+        chain: String,
+    },
+
+    /// Check the stored chain and report corruption
+    Check {
+        /// Base path for chain data
+        #[arg(short = 'd', long)]
+        base_path: Option<PathBuf>,
+
+        /// Chain to check (chain name)
+        #[arg(long, default_value = "kratos")]
+        chain: String,
+
+        /// Re-derive missing indexes and run RocksDB repair if the database does not open
+        #[arg(long)]
+        repair: bool,
+    },
+}
+
 impl RunCmd {
     /// Get the base path, defaulting to platform-specific data directory
+    /// (`chains/<name>`, named after the file for a chain spec file)
+    pub fn get_base_path(&self) -> PathBuf {
+        if let Some(ref path) = self.base_path {
+            path.clone()
+        } else {
+            let data_dir = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("kratos");
+
+            let chain = std::path::Path::new(&self.chain)
+                .file_stem()
+                .map_or_else(|| self.chain.clone(), |stem| stem.to_string_lossy().into_owned());
+            data_dir.join("chains").join(chain)
+        }
+    }
+}
+
+impl ExportCmd {
+    /// Get the base path for the chain to export
+    pub fn get_base_path(&self) -> PathBuf {
+        if let Some(ref path) = self.base_path {
+            path.clone()
+        } else {
+            let data_dir = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("kratos");
+
+            data_dir.join("chains").join(&self.chain)
+        }
+    }
+}
+
+impl ImportCmd {
+    /// Get the base path for the chain to import into
+    pub fn get_base_path(&self) -> PathBuf {
+        if let Some(ref path) = self.base_path {
+            path.clone()
+        } else {
+            let data_dir = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("kratos");
+
+            data_dir.join("chains").join(&self.chain)
+        }
+    }
+}
+
+impl AuditCmd {
+    /// Get the base path for the chain to audit
+    pub fn get_base_path(&self) -> PathBuf {
+        if let Some(ref path) = self.base_path {
+            path.clone()
+        } else {
+            let data_dir = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("kratos");
+
+            data_dir.join("chains").join(&self.chain)
+        }
+    }
+}
+
+impl SnapshotExportCmd {
+    /// Get the base path for the chain to snapshot
+    pub fn get_base_path(&self) -> PathBuf {
+        if let Some(ref path) = self.base_path {
+            path.clone()
+        } else {
+            let data_dir = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("kratos");
+
+            data_dir.join("chains").join(&self.chain)
+        }
+    }
+}
+
+impl SnapshotImportCmd {
+    /// Get the base path for the chain to bootstrap
+    pub fn get_base_path(&self) -> PathBuf {
+        if let Some(ref path) = self.base_path {
+            path.clone()
+        } else {
+            let data_dir = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("kratos");
+
+            data_dir.join("chains").join(&self.chain)
+        }
+    }
+}
+
+impl PeersExportCmd {
+    /// Get the base path for the chain to export from
+    pub fn get_base_path(&self) -> PathBuf {
+        if let Some(ref path) = self.base_path {
+            path.clone()
+        } else {
+            let data_dir = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("kratos");
+
+            data_dir.join("chains").join(&self.chain)
+        }
+    }
+}
+
+impl PeersImportCmd {
+    /// Get the base path for the chain to import into
+    pub fn get_base_path(&self) -> PathBuf {
+        if let Some(ref path) = self.base_path {
+            path.clone()
+        } else {
+            let data_dir = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("kratos");
+
+            data_dir.join("chains").join(&self.chain)
+        }
+    }
+}
+
+impl DbSubcommand {
+    /// Get the base path of the database to maintain
+    pub fn get_base_path(&self) -> PathBuf {
+        let (base_path, chain) = match self {
+            DbSubcommand::Compact { base_path, chain } => (base_path, chain),
+            DbSubcommand::Check { base_path, chain, .. } => (base_path, chain),
+        };
+        if let Some(ref path) = base_path {
+            path.clone()
+        } else {
+            let data_dir = dirs::data_dir()
+                .unwrap_or_else(|| PathBuf::from("."))
+                .join("kratos");
+
+            data_dir.join("chains").join(chain)
+        }
+    }
+}
+
+impl PruneNowCmd {
+    /// Get the base path for the chain to prune
     pub fn get_base_path(&self) -> PathBuf {
         if let Some(ref path) = self.base_path {
             path.clone()
@@ -334,6 +922,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parse_reserved_only_needs_reserved_peers() {
+        assert!(Cli::try_parse_from(["kratos-node", "run", "--reserved-only"]).is_err());
+        let cli = Cli::try_parse_from([
+            "kratos-node",
+            "run",
+            "--reserved-peer", "/ip4/10.0.0.2/tcp/30333/p2p/12D3KooWPeer1",
+            "--reserved-only",
+        ]).unwrap();
+        match cli.command {
+            Commands::Run(cmd) => {
+                assert_eq!(cmd.reserved_peers.len(), 1);
+                assert!(cmd.reserved_only);
+            }
+            _ => panic!("Expected Run command"),
+        }
+
+        // A sentry is a public node: it can't be reserved-only
+        assert!(Cli::try_parse_from([
+            "kratos-node",
+            "run",
+            "--reserved-peer", "/ip4/10.0.0.2/tcp/30333/p2p/12D3KooWPeer1",
+            "--reserved-only",
+            "--sentry-for", "/ip4/10.0.0.3/tcp/30333/p2p/12D3KooWPeer2",
+        ]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parse_sync_from_checkpoint() {
+        let checkpoint = format!("{}:1000", "ab".repeat(32));
+        let cli = Cli::try_parse_from(["kratos-node", "run", "--sync-from-checkpoint", &checkpoint]).unwrap();
+        match cli.command {
+            Commands::Run(cmd) => {
+                let config = config::NodeConfig::from_run_cmd(&cmd).unwrap();
+                assert_eq!(config.sync_mode, config::SyncMode::Warp);
+                assert_eq!(config.sync_checkpoint.map(|c| c.number), Some(1000));
+            }
+            _ => panic!("Expected Run command"),
+        }
+
+        // A sync checkpoint is already trusted on its own
+        let result = Cli::try_parse_from([
+            "kratos-node", "run", "--sync-from-checkpoint", &checkpoint, "--warp-checkpoint", &checkpoint,
+        ]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_cli_parse_key_generate() {
         let cli = Cli::try_parse_from(["kratos-node", "key", "generate"]).unwrap();
@@ -362,9 +997,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parse_db_check() {
+        let cli = Cli::try_parse_from(["kratos-node", "db", "check", "--repair"]).unwrap();
+        match cli.command {
+            Commands::Db(cmd) => match cmd.subcommand {
+                DbSubcommand::Check { chain, repair, .. } => {
+                    assert_eq!(chain, "kratos");
+                    assert!(repair);
+                }
+                _ => panic!("Expected Check subcommand"),
+            },
+            _ => panic!("Expected Db command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_peers_import() {
+        let cli = Cli::try_parse_from(["kratos-node", "peers", "import", "--input", "peers.json", "--chain", "devnet"]).unwrap();
+        match cli.command {
+            Commands::Peers(cmd) => match cmd.subcommand {
+                PeersSubcommand::Import(cmd) => {
+                    assert_eq!(cmd.input, PathBuf::from("peers.json"));
+                    assert_eq!(cmd.chain, "devnet");
+                    assert_eq!(cmd.database, DatabaseBackend::RocksDb);
+                }
+                _ => panic!("Expected Import subcommand"),
+            },
+            _ => panic!("Expected Peers command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parse_prune_now() {
+        let cli = Cli::try_parse_from(["kratos-node", "prune-now", "--pruning", "1000"]).unwrap();
+        match cli.command {
+            Commands::PruneNow(cmd) => {
+                assert_eq!(cmd.pruning, "1000");
+                assert_eq!(cmd.chain, "kratos");
+            }
+            _ => panic!("Expected PruneNow command"),
+        }
+    }
+
     #[test]
     fn test_run_cmd_base_path() {
         let cmd = RunCmd {
+            config: None,
             genesis: false,
             base_path: None,
             chain: "kratos".to_string(),
@@ -373,17 +1052,36 @@ mod tests {
             rpc_port: 9933,
             rpc: true,
             rpc_addr: "127.0.0.1".to_string(),
+            rpc_profile: RpcProfile::Private,
             bootnodes: vec![],
             max_peers: 50,
             validator: false,
             validator_key: None,
+            keystore_password: None,
+            keystore_retire_after: 2,
             sync: "full".to_string(),
+            warp_checkpoint: None,
+            sync_from_checkpoint: None,
             pruning: "256".to_string(),
+            index: false,
+            database: DatabaseBackend::RocksDb,
             db_cache: 128,
+            state_cache: 16384,
             prometheus_port: 0,
+            tracing_endpoint: None,
             public_addr: None,
+            relay: false,
+            listen_quic: None,
+            no_mdns: false,
+            reserved_peers: vec![],
+            reserved_only: false,
+            sentry_for: vec![],
             rpc_methods_unsafe: false,
             rpc_cors_all: false,
+            rpc_cors: vec![],
+            rpc_allowed_hosts: vec![],
+            rpc_tls_cert: None,
+            rpc_tls_key: None,
             debug_grandpa: false,
         };
 
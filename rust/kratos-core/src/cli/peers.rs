@@ -0,0 +1,117 @@
+// Known peers - Export and import the known-peers set of a node
+// Principle: A private network is seeded from the nodes it already has
+//
+// Private deployments have no DNS Seed: `export` writes the peers a running
+// network's node knows, `import` hands them to a new node before its first
+// start, which dials them right away.
+
+use crate::cli::{PeersExportCmd, PeersImportCmd};
+use crate::node::persistence::{merge_known_peers, SavedPeer};
+use crate::storage::{migrate, open_database, open_existing, DatabaseBackend, StateBackend};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Exported known-peers set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownPeersFile {
+    pub peers: Vec<SavedPeer>,
+}
+
+/// Known-peers set of the database at the command's base path
+///
+/// The node must be stopped (the database is opened exclusively).
+pub fn export_peers(cmd: &PeersExportCmd) -> Result<KnownPeersFile, PeersError> {
+    let base_path = cmd.get_base_path();
+    if DatabaseBackend::detect(&base_path).is_none() {
+        return Err(PeersError::NoDatabase(base_path));
+    }
+    let db = open_existing(&base_path).map_err(|e| PeersError::Storage(format!("{:?}", e)))?;
+    let peers = StateBackend::new(db).get_known_peers().map_err(|e| PeersError::Storage(e.to_string()))?;
+    Ok(KnownPeersFile { peers })
+}
+
+/// Merge an exported set into the known peers of the database at the
+/// command's base path (created if missing), returning how many peers the
+/// file held
+///
+/// The node must be stopped (the database is opened exclusively).
+pub fn import_peers(cmd: &PeersImportCmd) -> Result<usize, PeersError> {
+    let content = std::fs::read(&cmd.input)?;
+    let file: KnownPeersFile =
+        serde_json::from_slice(&content).map_err(|e| PeersError::InvalidFile(e.to_string()))?;
+    if let Some(peer) = file.peers.iter().find(|p| p.parse().is_none()) {
+        return Err(PeersError::InvalidPeer(peer.peer_id.clone()));
+    }
+
+    let base_path = cmd.get_base_path();
+    std::fs::create_dir_all(&base_path)?;
+    let db = match DatabaseBackend::detect(&base_path) {
+        Some(_) => open_existing(&base_path).map_err(|e| PeersError::Storage(format!("{:?}", e)))?,
+        None => {
+            let db = open_database(&base_path, cmd.database).map_err(|e| PeersError::Storage(format!("{:?}", e)))?;
+            migrate(db.as_ref()).map_err(|e| PeersError::Storage(e.to_string()))?;
+            db
+        }
+    };
+    let state = StateBackend::new(db);
+
+    let count = file.peers.len();
+    let stored = state.get_known_peers().map_err(|e| PeersError::Storage(e.to_string()))?;
+    state
+        .put_known_peers(&merge_known_peers(stored, file.peers))
+        .map_err(|e| PeersError::Storage(e.to_string()))?;
+    Ok(count)
+}
+
+/// Known-peers export/import errors
+#[derive(Debug, thiserror::Error)]
+pub enum PeersError {
+    #[error("No database at {0}")]
+    NoDatabase(PathBuf),
+
+    #[error("Invalid peers file: {0}")]
+    InvalidFile(String),
+
+    #[error("Invalid peer {0} (needs a peer ID and a multiaddr)")]
+    InvalidPeer(String),
+
+    #[error("Storage error: {0}")]
+    Storage(String),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libp2p::{Multiaddr, PeerId};
+
+    #[test]
+    fn test_export_import_peers() {
+        let dir = tempfile::tempdir().unwrap();
+        let base_path = dir.path().join("node");
+        let export_cmd = PeersExportCmd { base_path: Some(base_path.clone()), chain: "kratos".to_string(), output: None };
+        assert!(matches!(export_peers(&export_cmd), Err(PeersError::NoDatabase(_))));
+
+        let addr: Multiaddr = "/ip4/10.0.0.1/tcp/30333".parse().unwrap();
+        let file = KnownPeersFile { peers: vec![SavedPeer::new(&PeerId::random(), &[addr])] };
+        let input = dir.path().join("peers.json");
+        std::fs::write(&input, serde_json::to_vec(&file).unwrap()).unwrap();
+        let import_cmd = PeersImportCmd {
+            base_path: Some(base_path),
+            chain: "kratos".to_string(),
+            input: input.clone(),
+            database: DatabaseBackend::RocksDb,
+        };
+
+        // A new node gets its database, then the set is merged (no duplicates)
+        assert_eq!(import_peers(&import_cmd).unwrap(), 1);
+        assert_eq!(import_peers(&import_cmd).unwrap(), 1);
+        assert_eq!(export_peers(&export_cmd).unwrap(), file);
+
+        let invalid = KnownPeersFile { peers: vec![SavedPeer { peer_id: "nope".to_string(), addresses: vec![] }] };
+        std::fs::write(&input, serde_json::to_vec(&invalid).unwrap()).unwrap();
+        assert!(matches!(import_peers(&import_cmd), Err(PeersError::InvalidPeer(_))));
+    }
+}
@@ -0,0 +1,78 @@
+// Pruning maintenance - Prune an offline database down to the pruning window
+// Principle: Only finalized data is ever deleted
+//
+// The node prunes continuously in the background; `prune-now` catches up in
+// one go, e.g. after switching a node from a larger window.
+
+use crate::cli::config::{ConfigError, NodeConfig, PruningMode};
+use crate::cli::PruneNowCmd;
+use crate::storage::{open_existing, PruneReport, StateBackend};
+use std::collections::BTreeSet;
+use tracing::info;
+
+/// Blocks pruned per batch
+const PRUNE_BATCH_BLOCKS: u64 = 10_000;
+
+/// Prune the database at the command's base path
+///
+/// The node must be stopped (the database is opened exclusively).
+pub fn prune_now(cmd: &PruneNowCmd) -> Result<PruneReport, PruneError> {
+    let keep_blocks = match NodeConfig::parse_pruning(&cmd.pruning)? {
+        PruningMode::Archive => return Err(PruneError::Archive),
+        PruningMode::Blocks(keep_blocks) => keep_blocks,
+    };
+
+    let base_path = cmd.get_base_path();
+    let db = open_existing(&base_path).map_err(|e| PruneError::Storage(format!("{:?}", e)))?;
+    let mut state = StateBackend::new(db);
+
+    let mut total = PruneReport::default();
+    loop {
+        let report = state
+            .prune(keep_blocks, &BTreeSet::new(), PRUNE_BATCH_BLOCKS)
+            .map_err(|e| PruneError::Storage(e.to_string()))?;
+        total.pruned_blocks += report.pruned_blocks;
+        total.pruned_up_to = report.pruned_up_to;
+        total.prunable_up_to = report.prunable_up_to;
+
+        if report.pruned_up_to >= report.prunable_up_to {
+            break;
+        }
+        info!("🧹 Pruned up to block #{}", report.pruned_up_to.unwrap_or(0));
+    }
+
+    Ok(total)
+}
+
+/// Pruning errors
+#[derive(Debug, thiserror::Error)]
+pub enum PruneError {
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+
+    #[error("Archive mode keeps all blocks: nothing to prune")]
+    Archive,
+
+    #[error("Storage error: {0}")]
+    Storage(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_now_rejects_archive() {
+        let dir = tempfile::tempdir().unwrap();
+        let cmd = PruneNowCmd {
+            base_path: Some(dir.path().to_path_buf()),
+            chain: "kratos".to_string(),
+            pruning: "archive".to_string(),
+        };
+        assert!(matches!(prune_now(&cmd), Err(PruneError::Archive)));
+
+        // Nothing justified yet: nothing pruned
+        let cmd = PruneNowCmd { pruning: "256".to_string(), ..cmd };
+        assert_eq!(prune_now(&cmd).unwrap().pruned_blocks, 0);
+    }
+}
@@ -0,0 +1,226 @@
+// Reload - Apply configuration changes to a running node (SIGHUP, admin_reloadConfig)
+// The command line is parsed again with its --config file. Log level, RPC rate
+// limit, max peers, CORS origins, allowed hosts and RPC tokens take their new values; block production,
+// connected peers and the RPC listener keep running. Other options need a restart.
+
+use crate::cli::config::NodeConfig;
+use crate::cli::config_file::{reparse_cli_from, ConfigFile};
+use crate::cli::logging::{self, LogReload};
+use crate::cli::{Cli, Commands};
+use crate::node::service::KratOsNode;
+use crate::rpc::auth::{RpcAuth, RpcToken};
+use crate::rpc::cors::CorsOrigins;
+use crate::rpc::hosts::AllowedHosts;
+use crate::rpc::rate_limit::{RateLimitConfig, RpcRateLimiter};
+use std::ffi::OsString;
+use tracing::{info, warn};
+
+/// Settings a running node can change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReloadableSettings {
+    /// Log filter directives
+    pub log_filter: String,
+    /// Per-IP RPC rate limit (None = unlimited)
+    pub rpc_rate_limit: Option<RateLimitConfig>,
+    /// Maximum connected peers
+    pub max_peers: usize,
+    /// Allowed CORS origins (empty = localhost only)
+    pub cors_origins: Vec<String>,
+    /// Allowed Host headers of the RPC server (empty = localhost only)
+    pub allowed_hosts: Vec<String>,
+    /// Tokens of the unsafe RPC methods
+    pub rpc_tokens: Vec<RpcToken>,
+}
+
+impl ReloadableSettings {
+    /// Settings of a `run` command line
+    pub fn from_cli(cli: &Cli) -> Result<Self, String> {
+        let Commands::Run(cmd) = &cli.command else {
+            return Err("not a run command".to_string());
+        };
+        let file = match &cmd.config {
+            Some(path) => ConfigFile::load(path).map_err(|e| e.to_string())?,
+            None => ConfigFile::default(),
+        };
+
+        Ok(Self {
+            log_filter: logging::directives(cli.verbose, &cli.log_level, cmd.debug_grandpa),
+            rpc_rate_limit: NodeConfig::rpc_rate_limit(cmd, &file),
+            max_peers: cmd.max_peers as usize,
+            cors_origins: NodeConfig::rpc_cors_origins(cmd),
+            allowed_hosts: NodeConfig::rpc_allowed_hosts(cmd),
+            rpc_tokens: file.rpc_tokens().map_err(|e| e.to_string())?,
+        })
+    }
+
+    /// Description of each setting that differs in `new`
+    pub fn changes(&self, new: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+        if self.log_filter != new.log_filter {
+            changes.push(format!("log filter: {}", new.log_filter));
+        }
+        if self.rpc_rate_limit != new.rpc_rate_limit {
+            changes.push(match &new.rpc_rate_limit {
+                Some(limit) => format!(
+                    "RPC rate limit: {} req/{}s",
+                    limit.max_requests,
+                    limit.window_duration.as_secs()
+                ),
+                None => "RPC rate limit: off".to_string(),
+            });
+        }
+        if self.max_peers != new.max_peers {
+            changes.push(format!("max peers: {}", new.max_peers));
+        }
+        if self.cors_origins != new.cors_origins {
+            changes.push(format!("CORS origins: {}", CorsOrigins::new(new.cors_origins.clone()).describe()));
+        }
+        if self.allowed_hosts != new.allowed_hosts {
+            changes.push(format!("RPC allowed hosts: {}", AllowedHosts::new(new.allowed_hosts.clone()).describe()));
+        }
+        if self.rpc_tokens != new.rpc_tokens {
+            let names: Vec<_> = new.rpc_tokens.iter().map(|token| token.name.as_str()).collect();
+            changes.push(format!("RPC tokens: {}", if names.is_empty() { "none".to_string() } else { names.join(", ") }));
+        }
+        changes
+    }
+}
+
+/// Applies the reloadable settings of the process arguments
+pub struct Reloader {
+    args: Vec<OsString>,
+    current: ReloadableSettings,
+    log: LogReload,
+    rpc_limiter: RpcRateLimiter,
+    cors: CorsOrigins,
+    allowed_hosts: AllowedHosts,
+    auth: RpcAuth,
+}
+
+impl Reloader {
+    /// Reloader of the settings `config` was started with
+    pub fn new(
+        config: &NodeConfig,
+        log: LogReload,
+        rpc_limiter: RpcRateLimiter,
+        cors: CorsOrigins,
+        allowed_hosts: AllowedHosts,
+        auth: RpcAuth,
+    ) -> Self {
+        let current = ReloadableSettings {
+            log_filter: log.directives().to_string(),
+            rpc_rate_limit: config.rpc.rate_limit.clone(),
+            max_peers: config.max_peers,
+            cors_origins: config.rpc.cors_origins.clone(),
+            allowed_hosts: config.rpc.allowed_hosts.clone(),
+            rpc_tokens: config.rpc.tokens.clone(),
+        };
+        Self {
+            args: std::env::args_os().collect(),
+            current,
+            log,
+            rpc_limiter,
+            cors,
+            allowed_hosts,
+            auth,
+        }
+    }
+
+    /// Re-read the configuration and apply what changed
+    ///
+    /// Nothing is applied when the configuration is invalid.
+    pub async fn reload(&mut self, node: &KratOsNode) -> Result<Vec<String>, String> {
+        let new = reparse_cli_from(self.args.clone())
+            .map_err(|e| e.to_string())
+            .and_then(|cli| ReloadableSettings::from_cli(&cli))
+            .inspect_err(|e| warn!("⚠️  Configuration reload failed: {}", e))?;
+
+        let changes = self.current.changes(&new);
+        if new.log_filter != self.current.log_filter {
+            self.log.set_directives(&new.log_filter).map_err(|e| e.to_string())?;
+        }
+        self.rpc_limiter.set_config(new.rpc_rate_limit.clone());
+        node.set_max_peers(new.max_peers).await;
+        self.cors.set(new.cors_origins.clone());
+        self.allowed_hosts.set(new.allowed_hosts.clone());
+        self.auth.set(new.rpc_tokens.clone());
+        self.current = new;
+
+        if changes.is_empty() {
+            info!("🔄 Configuration reloaded: no change");
+        } else {
+            info!("🔄 Configuration reloaded: {}", changes.join(", "));
+        }
+        Ok(changes)
+    }
+}
+
+/// SIGHUP notifications (never fire off Unix)
+pub struct Hangup {
+    #[cfg(unix)]
+    signal: Option<tokio::signal::unix::Signal>,
+}
+
+impl Hangup {
+    pub fn new() -> Self {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{signal, SignalKind};
+            let signal = signal(SignalKind::hangup())
+                .inspect_err(|e| warn!("⚠️  SIGHUP unavailable, reload with admin_reloadConfig: {}", e))
+                .ok();
+            Self { signal }
+        }
+        #[cfg(not(unix))]
+        Self {}
+    }
+
+    /// Wait for the next SIGHUP
+    pub async fn recv(&mut self) {
+        #[cfg(unix)]
+        if let Some(signal) = self.signal.as_mut() {
+            if signal.recv().await.is_some() {
+                return;
+            }
+        }
+        std::future::pending::<()>().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("node.toml");
+        let config = path.to_str().unwrap().to_string();
+        let settings = |file: &str| {
+            std::fs::write(&path, file).unwrap();
+            let args = ["kratos-node", "run", "--config", &config].iter().map(OsString::from).collect();
+            ReloadableSettings::from_cli(&reparse_cli_from(args).unwrap()).unwrap()
+        };
+
+        let before = settings("");
+        assert_eq!(before.max_peers, 50);
+        assert!(before.rpc_rate_limit.is_none());
+        assert!(before.changes(&before).is_empty());
+
+        let (_, hash) = RpcToken::generate();
+        let after = settings(&format!(
+            "log-level = \"debug\"\nmax-peers = 10\nrpc-cors = [\"https://wallet.example\"]\n\
+             rpc-allowed-hosts = [\"rpc.example\"]\n\n[rpc-rate-limit]\nmax-requests = 20\n\n\
+             [[rpc-token]]\nname = \"ops\"\nhash = \"{}\"\nmethods = [\"admin_*\"]\n",
+            hash.to_hex()
+        ));
+        let changes = before.changes(&after);
+        assert_eq!(changes.len(), 6);
+        assert!(changes.contains(&"RPC tokens: ops".to_string()));
+        assert!(changes.contains(&"RPC allowed hosts: rpc.example".to_string()));
+        assert!(changes.contains(&"log filter: debug".to_string()));
+        assert!(changes.contains(&"max peers: 10".to_string()));
+        assert_eq!(after.rpc_rate_limit.unwrap().max_requests, 20);
+        assert_eq!(after.cors_origins, vec!["https://wallet.example".to_string()]);
+    }
+}
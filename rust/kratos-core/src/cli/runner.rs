@@ -1,14 +1,19 @@
 // Runner - Main node execution logic
 // Principle: Orchestrate node startup, RPC server, and graceful shutdown
 
-use crate::cli::config::NodeConfig;
+use crate::cli::config::{NodeConfig, PruningMode, SyncMode};
+use crate::cli::logging::LogReload;
+use crate::cli::reload::{Hangup, Reloader};
 use crate::consensus::epoch::{EPOCH_DURATION_BLOCKS, SLOT_DURATION_SECS};
+use crate::network::Capabilities;
+use crate::node::keystore::{KeyScheme, KeyType, Keystore};
 use crate::node::producer::BlockProducer;
 use crate::node::service::{KratOsNode, NodeError};
-use crate::rpc::{RpcCall, RpcServer};
+use crate::rpc::{AllowedHosts, CorsOrigins, RpcAuth, RpcCall, RpcProfile, RpcRateLimiter, RpcServer};
+use crate::rpc::prometheus::PrometheusServer;
 use crate::rpc::types::{
-    AccountInfoRpc, BlockWithTransactions, ChainInfo, HealthStatus, MempoolStats, MempoolStatus,
-    NetworkStatus, SyncStatus, SystemInfo,
+    AccountInfoRpc, AccountMessageRpc, AccountTransactionsRpc, AuthoredBlocksRpc, BlockWithTransactions, ChainInfo, FeeHistoryRpc, HealthStatus, MempoolContent, MempoolStats, MempoolStatus, MortalityRpc, ForksRpc, ReorgEventsRpc,
+    FinalityProofRpc, NetworkStatus, PendingEvidenceRpc, DelegationsRpc, DelegationPoolRpc, UnbondingRpc, PendingRewardsRpc, EmergencyStatusRpc, IdentityRpc, ParameterChangeRecordRpc, PersonhoodStatusRpc, ProposalRpc, ProtocolParametersRpc, ReputationRpc, RotateKeysRpc, EventsRpc, StateProofRpc, SyncStatus, SystemChainRpc, SystemInfo, SystemPeersRpc, TransactionReceipt, TreasuryRpc, UpgradeStatusRpc, ValidatorCreditsRpc, ValidatorInfoRpc, VcHistoryRpc, EpochInfoRpc,
 };
 use crate::types::*;
 use ed25519_dalek::SigningKey;
@@ -18,7 +23,7 @@ use tokio::sync::{mpsc, RwLock};
 use tracing::{info, warn, debug, error, trace};
 
 /// Run the node with the given configuration
-pub async fn run_node(config: NodeConfig) -> Result<(), RunnerError> {
+pub async fn run_node(config: NodeConfig, log: LogReload) -> Result<(), RunnerError> {
     info!("🚀 Starting KratOs node: {}", config.name);
     info!("📁 Data path: {}", config.base_path.display());
     info!("⛓️  Chain: {}", config.chain.chain_name);
@@ -38,27 +43,95 @@ pub async fn run_node(config: NodeConfig) -> Result<(), RunnerError> {
     // genesis_mode = true  -> creates new network (genesis node)
     // genesis_mode = false -> joins existing network via DNS Seeds / bootnodes
     let node = Arc::new(
-        KratOsNode::new(
+        KratOsNode::with_database(
             config.chain.clone(),
             &config.base_path,
             config.genesis.clone(),
             config.genesis_mode,
+            config.database,
         )
         .await
         .map_err(RunnerError::Node)?,
     );
 
     info!("🔗 Genesis: {}", node.genesis_hash());
+    node.set_state_cache_capacity(config.state_cache_accounts).await;
+    node.set_mempool_config(config.mempool.clone()).await;
+    node.set_producer_config(config.producer.clone()).await;
+    node.set_peer_scoring(config.peer_scoring).await;
+    node.set_max_peers(config.max_peers).await;
+    if validator_key.is_some() {
+        load_session_keys(&node, &config).await;
+    }
+
+    // Archive mode keeps every block's state for historical RPC queries;
+    // otherwise block data older than the window behind finality is pruned
+    match config.pruning {
+        PruningMode::Archive => {
+            let from = node.enable_archive().await.map_err(RunnerError::Node)?;
+            info!("🗄️  Archive mode: historical state available from block #{}", from);
+        }
+        PruningMode::Blocks(keep_blocks) => {
+            node.enable_pruning(keep_blocks).await;
+            info!("🧹 Pruning: keeping {} blocks behind finality", keep_blocks);
+        }
+    }
+    if config.index {
+        let indexed = node.enable_index().await.map_err(RunnerError::Node)?;
+        info!("📇 Indexing: {} stored blocks indexed", indexed);
+    }
     info!("🆔 Peer ID: {}", node.local_peer_id().await);
 
+    // Mempool and peer bans saved before the restart, peers saved by the
+    // last clean shutdown, then the known-peers set
+    if let Err(e) = node.restore_mempool().await {
+        warn!("⚠️  {}", e);
+    }
+    if let Err(e) = node.restore_peer_reputations().await {
+        warn!("⚠️  {}", e);
+    }
+    node.restore_shutdown_state().await;
+    if let Err(e) = node.restore_known_peers().await {
+        warn!("⚠️  {}", e);
+    }
+
     // Create RPC channel
     let (rpc_tx, rpc_rx) = mpsc::unbounded_channel::<RpcCall>();
 
+    // Rate limits, CORS origins, allowed hosts and tokens are shared with the reloader
+    let rpc_limiter = RpcRateLimiter::with_config(config.rpc.rate_limit.clone());
+    let cors = CorsOrigins::new(config.rpc.cors_origins.clone());
+    let allowed_hosts = AllowedHosts::new(config.rpc.allowed_hosts.clone());
+    let rpc_auth = RpcAuth::new(config.rpc.tokens.clone());
+
     // Start RPC server if enabled
     let rpc_handle = if config.rpc.enabled {
-        let rpc_server = RpcServer::with_address(config.rpc.port, config.rpc.address);
+        let rpc_server = RpcServer::with_address(config.rpc.port, config.rpc.address)
+            .with_unsafe_methods(config.rpc.unsafe_methods)
+            .with_metrics(node.metrics())
+            .with_profile(config.rpc.profile)
+            .with_rate_limiter(rpc_limiter.clone())
+            .with_cors(cors.clone())
+            .with_allowed_hosts(allowed_hosts.clone())
+            .with_auth(rpc_auth.clone())
+            .with_tls(config.rpc.tls.clone())
+            .with_drop_notifier(node.mempool().read().await.drop_notifier())
+            .with_transition_notifier(node.security_transition_notifier().await);
+        if let Some(limit) = &config.rpc.rate_limit {
+            info!("Rate limiting enabled: {} req/{}s", limit.max_requests, limit.window_duration.as_secs());
+        }
+        if !config.rpc.tokens.is_empty() {
+            let names: Vec<_> = config.rpc.tokens.iter().map(|t| t.name.as_str()).collect();
+            info!("🔐 Unsafe RPC methods require a token: {}", names.join(", "));
+        } else if config.rpc.unsafe_methods {
+            warn!("⚠️  Unsafe RPC methods enabled: keep the RPC port private");
+        }
+        if config.rpc.profile != RpcProfile::Private {
+            info!("🛡️  RPC profile: {}", config.rpc.profile);
+        }
         info!(
-            "🌐 RPC server: http://{}:{}",
+            "🌐 RPC server: {}://{}:{}",
+            if config.rpc.tls.is_some() { "https" } else { "http" },
             format_ip(config.rpc.address),
             config.rpc.port
         );
@@ -73,6 +146,36 @@ pub async fn run_node(config: NodeConfig) -> Result<(), RunnerError> {
         None
     };
 
+    // Prometheus exporter, on the RPC listen address
+    let prometheus_handle = match config.prometheus_port {
+        Some(port) => {
+            let handle = PrometheusServer::new(port, config.rpc.address)
+                .start_background(rpc_tx.clone())
+                .await
+                .map_err(|e| RunnerError::Rpc(format!("Prometheus exporter error: {:?}", e)))?;
+            info!("📈 Prometheus metrics: http://{}/metrics", handle.address());
+            Some(handle)
+        }
+        None => None,
+    };
+
+    // Operator hooks on security state alerts
+    let alerts_handle = if config.security_alerts.is_enabled() {
+        info!("🚨 Security alert hooks enabled");
+        Some(config.security_alerts.clone().spawn(&node.security_transition_notifier().await))
+    } else {
+        None
+    };
+
+    // Warp sync must be set up before the network starts block sync
+    if let Some(checkpoint) = config.sync_checkpoint {
+        info!("📦 Sync mode: from checkpoint #{} ({})", checkpoint.number, checkpoint.hash);
+        node.enable_checkpoint_sync(checkpoint).await;
+    } else if config.sync_mode == SyncMode::Warp {
+        info!("📦 Sync mode: warp");
+        node.enable_warp_sync(config.warp_checkpoint).await;
+    }
+
     // Start the node
     node.start().await.map_err(RunnerError::Node)?;
 
@@ -80,6 +183,9 @@ pub async fn run_node(config: NodeConfig) -> Result<(), RunnerError> {
     info!("📡 P2P port: {}", config.chain.network.listen_port);
 
     if config.validator {
+        // Validators serve state snapshots to warp syncing nodes
+        node.enable_snapshots().await;
+
         if let Some(ref key) = validator_key {
             info!("⚡ Validator mode: ACTIVE");
 
@@ -97,9 +203,11 @@ pub async fn run_node(config: NodeConfig) -> Result<(), RunnerError> {
         let network_key = load_or_generate_network_key(&config.base_path);
         node.initialize_dns_client(network_key).await;
     }
+    node.set_dns_capabilities(advertised_capabilities(&config)).await;
 
     // Run the main event loop
-    let result = run_event_loop(node.clone(), rpc_rx, &config, validator_key).await;
+    let mut reloader = Reloader::new(&config, log, rpc_limiter, cors, allowed_hosts, rpc_auth);
+    let result = run_event_loop(node.clone(), rpc_rx, &config, validator_key, &mut reloader).await;
 
     // Cleanup
     info!("🛑 Shutting down...");
@@ -108,6 +216,38 @@ pub async fn run_node(config: NodeConfig) -> Result<(), RunnerError> {
         handle.shutdown();
         info!("   RPC server stopped");
     }
+    if let Some(handle) = prometheus_handle {
+        handle.shutdown();
+    }
+    if let Some(handle) = alerts_handle {
+        handle.abort();
+    }
+
+    // Finish importing the blocks already received, then save what the next
+    // start restores
+    let processed = node.finish_imports().await;
+    if processed > 0 {
+        info!("   Processed {} pending network events", processed);
+    }
+    match node.save_mempool().await {
+        Ok(Some(count)) => info!("💾 Saved {} pending transactions", count),
+        Ok(None) => {}
+        Err(e) => warn!("⚠️  {}", e),
+    }
+    if let Err(e) = node.save_peer_reputations().await {
+        warn!("⚠️  {}", e);
+    }
+    if let Err(e) = node.save_known_peers().await {
+        warn!("⚠️  {}", e);
+    }
+    match node.save_shutdown_state().await {
+        Ok(state) => info!(
+            "💾 Saved {} peers and sync cursor #{}",
+            state.peers.len(),
+            state.sync.best_block
+        ),
+        Err(e) => warn!("⚠️  Failed to save shutdown state: {}", e),
+    }
 
     node.stop().await.map_err(RunnerError::Node)?;
     info!("👋 Node stopped cleanly");
@@ -121,6 +261,7 @@ async fn run_event_loop(
     mut rpc_rx: mpsc::UnboundedReceiver<RpcCall>,
     config: &NodeConfig,
     validator_key: Option<SigningKey>,
+    reloader: &mut Reloader,
 ) -> Result<(), RunnerError> {
     let mut maintenance_interval = tokio::time::interval(std::time::Duration::from_secs(30));
     let mut stats_interval = tokio::time::interval(std::time::Duration::from_secs(60));
@@ -145,6 +286,9 @@ async fn run_event_loop(
         info!("⏱️  Block production: every {}s", SLOT_DURATION_SECS);
     }
 
+    // Configuration reload (log level, RPC limits, max peers, CORS, hosts, tokens)
+    let mut hangup = Hangup::new();
+
     loop {
         tokio::select! {
             // Handle shutdown signals
@@ -153,9 +297,15 @@ async fn run_event_loop(
                 break;
             }
 
+            // Reload the configuration
+            _ = hangup.recv() => {
+                info!("🔄 SIGHUP received, reloading configuration");
+                let _ = reloader.reload(&node).await;
+            }
+
             // Handle RPC calls
             Some(call) = rpc_rx.recv() => {
-                handle_rpc_call(&node, call, config).await;
+                handle_rpc_call(&node, call, config, validator_key.as_ref(), reloader).await;
             }
 
             // CRITICAL: Network polling - processes connections, genesis requests, sync
@@ -186,6 +336,7 @@ async fn run_event_loop(
             // Periodic maintenance
             _ = maintenance_interval.tick() => {
                 perform_maintenance(&node).await;
+                retire_rotated_keys(&node, config, validator_key.as_ref()).await;
             }
 
             // Periodic stats logging
@@ -265,13 +416,41 @@ async fn try_produce_block(node: &Arc<KratOsNode>, validator_key: &SigningKey, g
 }
 
 /// Handle an RPC call by routing it to the appropriate node method
-async fn handle_rpc_call(node: &Arc<KratOsNode>, call: RpcCall, config: &NodeConfig) {
+async fn handle_rpc_call(
+    node: &Arc<KratOsNode>,
+    call: RpcCall,
+    config: &NodeConfig,
+    validator_key: Option<&SigningKey>,
+    reloader: &mut Reloader,
+) {
     match call {
         RpcCall::ChainGetInfo(resp) => {
             let info = build_chain_info(node, config).await;
             let _ = resp.send(Ok(info));
         }
 
+        RpcCall::ChainGetMortality(resp) => {
+            let _ = resp.send(MortalityRpc::new(node.chain_height().await));
+        }
+
+        RpcCall::ChainGetForks(resp) => {
+            let result = node.forks_report().await.map(|report| ForksRpc::from(&report)).map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::ChainGetReorgEvents(after_id, resp) => {
+            let result = node
+                .reorg_events(after_id)
+                .await
+                .map(|events| ReorgEventsRpc::from(events.as_slice()))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::ChainGetEpochInfo(resp) => {
+            let _ = resp.send(EpochInfoRpc::from(&node.epoch_report().await));
+        }
+
         RpcCall::ChainGetBlock(number, resp) => {
             match node.get_block_by_number(number).await {
                 Ok(Some(block)) => {
@@ -296,6 +475,15 @@ async fn handle_rpc_call(node: &Arc<KratOsNode>, call: RpcCall, config: &NodeCon
             }
         }
 
+        RpcCall::ChainGetTransactionReceipt(tx_hash, resp) => {
+            let result = node
+                .get_receipt(&tx_hash)
+                .await
+                .map(|stored| stored.as_ref().map(TransactionReceipt::from))
+                .map_err(|e| format!("Failed to get receipt: {:?}", e));
+            let _ = resp.send(result);
+        }
+
         RpcCall::StateGetBalance(account, resp) => {
             match node.get_balance(&account).await {
                 Ok(balance) => {
@@ -324,6 +512,76 @@ async fn handle_rpc_call(node: &Arc<KratOsNode>, call: RpcCall, config: &NodeCon
             }
         }
 
+        RpcCall::StateGetAccountAt(account, block_hash, resp) => {
+            let result = node
+                .get_account_info_at(&account, &block_hash)
+                .await
+                .map(|at_block| {
+                    at_block.map(|info| match info {
+                        Some(info) => AccountInfoRpc::from_info(&account, &info),
+                        None => AccountInfoRpc::empty(&account),
+                    })
+                })
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::StateGetStorageAt(key, block_hash, resp) => {
+            let result = node
+                .get_storage_at(&key, &block_hash)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::StateGetProof(account, block_hash, resp) => {
+            let result = match node.get_account_proof(&account, &block_hash).await {
+                Ok(Some(Some(proof))) => StateProofRpc::from_proof(&account, &block_hash, &proof).map(|p| Some(Some(p))),
+                Ok(Some(None)) => Ok(Some(None)),
+                Ok(None) => Ok(None),
+                Err(e) => Err(e.to_string()),
+            };
+            let _ = resp.send(result);
+        }
+
+        RpcCall::AccountNextNonce(account_id, resp) => {
+            let result = node
+                .next_nonce(&account_id)
+                .await
+                .map_err(|e| format!("Failed to get nonce: {:?}", e));
+            let _ = resp.send(result);
+        }
+
+        RpcCall::AccountGetTransactions(account, query, resp) => {
+            let result = match node.indexed_transactions(&account, &query.filter, query.cursor, query.limit).await {
+                Ok(page) if query.wants_pending() => {
+                    let pending = node.pending_transactions_of(&account).await;
+                    Ok(AccountTransactionsRpc::new(&account, &page).with_pending(&account, &query, &pending))
+                }
+                Ok(page) => Ok(AccountTransactionsRpc::new(&account, &page)),
+                Err(e) => Err(e.to_string()),
+            };
+            let _ = resp.send(result);
+        }
+
+        RpcCall::ChainGetEvents(event_type, limit, offset, resp) => {
+            let result = node
+                .indexed_events(&event_type, offset, limit)
+                .await
+                .map(|page| EventsRpc::new(&event_type, offset, &page))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::ValidatorGetAuthoredBlocks(validator, limit, offset, resp) => {
+            let result = node
+                .indexed_blocks_by_author(&validator, offset, limit)
+                .await
+                .map(|page| AuthoredBlocksRpc::new(&validator, offset, &page))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
         RpcCall::SystemHealth(resp) => {
             let health = HealthStatus {
                 healthy: true,
@@ -341,9 +599,7 @@ async fn handle_rpc_call(node: &Arc<KratOsNode>, call: RpcCall, config: &NodeCon
         }
 
         RpcCall::SystemPeers(resp) => {
-            let peers = node.connected_peers().await;
-            let peer_ids: Vec<String> = peers.iter().map(|p| p.to_string()).collect();
-            let _ = resp.send((peers.len(), peer_ids));
+            let _ = resp.send(SystemPeersRpc::new(&node.peers().await));
         }
 
         RpcCall::SyncState(resp) => {
@@ -382,21 +638,56 @@ async fn handle_rpc_call(node: &Arc<KratOsNode>, call: RpcCall, config: &NodeCon
             let _ = resp.send(status);
         }
 
+        RpcCall::MempoolContent(resp) => {
+            let content = node.mempool_content().await;
+            let _ = resp.send(MempoolContent::from(&content));
+        }
+
+        RpcCall::FeeHistory(block_count, resp) => {
+            let result = node
+                .fee_history(block_count)
+                .await
+                .map(|history| FeeHistoryRpc::from(&history))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
         RpcCall::SubmitTransaction(tx, resp) => {
-            match node.submit_transaction(tx).await {
-                Ok(hash) => {
-                    let _ = resp.send(Ok(hash));
-                }
-                Err(e) => {
-                    let _ = resp.send(Err(format!("Failed to submit: {:?}", e)));
-                }
-            }
+            let _ = resp.send(node.submit_transaction(*tx).await);
+        }
+
+        RpcCall::SubmitTransactionIdempotent(tx, key, resp) => {
+            let result = node
+                .submit_transaction_idempotent(*tx, &key)
+                .await
+                .map(|submission| (submission.hash, submission.duplicate));
+            let _ = resp.send(result);
+        }
+
+        RpcCall::SystemChain(resp) => {
+            let _ = resp.send(SystemChainRpc::new(&config.chain, node.genesis_hash()));
         }
 
         RpcCall::GetVersion(resp) => {
             let _ = resp.send(env!("CARGO_PKG_VERSION").to_string());
         }
 
+        RpcCall::SystemInvariants(resp) => {
+            let _ = resp.send(node.invariant_report().await);
+        }
+
+        RpcCall::SystemSecurityStateHistory(resp) => {
+            let _ = resp.send(node.security_history().await);
+        }
+
+        RpcCall::SystemStateCache(resp) => {
+            let _ = resp.send(node.state_cache_stats().await);
+        }
+
+        RpcCall::SystemMetrics(resp) => {
+            let _ = resp.send(node.prometheus_metrics().await);
+        }
+
         RpcCall::StateGetNonce(account_id, resp) => {
             match node.get_nonce(&account_id).await {
                 Ok(nonce) => {
@@ -435,14 +726,14 @@ async fn handle_rpc_call(node: &Arc<KratOsNode>, call: RpcCall, config: &NodeCon
                         // Check if this transaction involves the account
                         let is_sender = tx.transaction.sender == account_id;
                         let is_recipient = match &tx.transaction.call {
-                            TransactionCall::Transfer { to, .. } => *to == account_id,
+                            TransactionCall::Transfer { to, .. } | TransactionCall::TransferWithMemo { to, .. } => *to == account_id,
                             _ => false,
                         };
 
                         if is_sender || is_recipient {
                             // Build transaction record
                             let (tx_type, counterparty, amount) = match &tx.transaction.call {
-                                TransactionCall::Transfer { to, amount } => {
+                                TransactionCall::Transfer { to, amount } | TransactionCall::TransferWithMemo { to, amount, .. } => {
                                     let cp = if is_sender {
                                         format!("0x{}", hex::encode(to.as_bytes()))
                                     } else {
@@ -453,7 +744,7 @@ async fn handle_rpc_call(node: &Arc<KratOsNode>, call: RpcCall, config: &NodeCon
                                 TransactionCall::Stake { amount } => ("Stake", format!("0x{}", hex::encode(account_id.as_bytes())), *amount),
                                 TransactionCall::Unstake { amount } => ("Unstake", format!("0x{}", hex::encode(account_id.as_bytes())), *amount),
                                 TransactionCall::RegisterValidator { stake } => ("RegisterValidator", format!("0x{}", hex::encode(account_id.as_bytes())), *stake),
-                                TransactionCall::ProposeEarlyValidator { candidate } => {
+                                TransactionCall::ProposeEarlyValidator { candidate, .. } => {
                                     ("ProposeEarlyValidator", format!("0x{}", hex::encode(candidate.as_bytes())), 0)
                                 }
                                 TransactionCall::VoteEarlyValidator { candidate } => {
@@ -476,6 +767,7 @@ async fn handle_rpc_call(node: &Arc<KratOsNode>, call: RpcCall, config: &NodeCon
                                 "timestamp": tx.transaction.timestamp,
                                 "blockNumber": block_num,
                                 "blockHash": format!("0x{}", hex::encode(block.hash().as_bytes())),
+                                "memo": tx.transaction.call.memo().map(|memo| format!("0x{}", hex::encode(memo))),
                             }));
 
                             if transactions.len() >= limit {
@@ -536,6 +828,8 @@ async fn handle_rpc_call(node: &Arc<KratOsNode>, call: RpcCall, config: &NodeCon
                         "votes_required": c.votes_required,
                         "has_quorum": c.has_quorum(),
                         "created_at": c.created_at,
+                        "display_name": c.metadata.display_name,
+                        "url": c.metadata.url,
                         "voters": c.voters.iter()
                             .map(|v| format!("0x{}", hex::encode(v.as_bytes())))
                             .collect::<Vec<_>>()
@@ -608,6 +902,227 @@ async fn handle_rpc_call(node: &Arc<KratOsNode>, call: RpcCall, config: &NodeCon
             });
             let _ = resp.send(Ok(result));
         }
+
+        RpcCall::ValidatorGetInfo(account_id, resp) => {
+            let report = node.validator_report(&account_id).await;
+            let _ = resp.send(report.as_ref().map(ValidatorInfoRpc::from));
+        }
+
+        RpcCall::ValidatorGetCredits(account_id, resp) => {
+            let report = node.validator_report(&account_id).await;
+            let _ = resp.send(report.as_ref().map(ValidatorCreditsRpc::from));
+        }
+
+        RpcCall::ValidatorCreditsHistory(account_id, epochs, resp) => {
+            let report = node.validator_report(&account_id).await;
+            let _ = resp.send(report.as_ref().map(|report| VcHistoryRpc::new(report, epochs)));
+        }
+
+        RpcCall::FinalityGetStatus(resp) => {
+            let height = node.chain_height().await;
+            let finality_info = node.finality_info().await;
+            let _ = resp.send(serde_json::json!({
+                "enabled": true,
+                "last_finalized_block": finality_info.last_finalized_block,
+                "last_finalized_hash": format!("0x{}", hex::encode(finality_info.last_finalized_hash.as_bytes())),
+                "current_round": finality_info.current_round,
+                "current_epoch": finality_info.current_epoch,
+                "chain_height": height,
+                "finality_lag": height.saturating_sub(finality_info.last_finalized_block),
+                "total_validators": finality_info.total_validators,
+                "supermajority_threshold": "66%"
+            }));
+        }
+
+        RpcCall::FinalityGetStallStatus(resp) => {
+            let _ = resp.send(node.finality_stall().await);
+        }
+
+        RpcCall::GrandpaProveFinality(block_number, resp) => {
+            let result = node
+                .prove_finality(block_number)
+                .await
+                .map(|j| j.map(|j| FinalityProofRpc::from_justification(block_number, &j)))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::StakingGetDelegations(account_id, resp) => {
+            let result = node
+                .delegations(&account_id)
+                .await
+                .map(|delegations| DelegationsRpc::new(&account_id, &delegations))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::StakingGetDelegators(validator, resp) => {
+            let result = node
+                .delegation_pool(&validator)
+                .await
+                .map(|(pool, commission)| DelegationPoolRpc::new(&validator, &pool, commission))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::StakingGetUnbonding(account_id, resp) => {
+            let next_block = node.chain_height().await + 1;
+            let result = node
+                .unbonding_queue(&account_id)
+                .await
+                .map(|queue| UnbondingRpc::new(&account_id, &queue, next_block))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::StakingPendingRewards(account_id, resp) => {
+            let next_block = node.chain_height().await + 1;
+            let result = node
+                .reward_ledger(&account_id)
+                .await
+                .map(|ledger| PendingRewardsRpc::new(&account_id, &ledger, next_block))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::GovernanceProposals(proposal_id, resp) => {
+            let result = node
+                .governance_report(proposal_id)
+                .await
+                .map(|report| ProposalRpc::from_report(&report))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::TreasuryBalance(resp) => {
+            let result = node
+                .treasury_report()
+                .await
+                .map(|report| TreasuryRpc::from(&report))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::TreasuryProposals(resp) => {
+            let result = node
+                .treasury_report()
+                .await
+                .map(|report| ProposalRpc::from_report(&report.governance))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::GovernanceParameters(resp) => {
+            let result = node
+                .parameters_report()
+                .await
+                .map(|report| ProtocolParametersRpc::from(&report))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::GovernanceParameterLog(resp) => {
+            let result = node
+                .parameters_report()
+                .await
+                .map(|report| ParameterChangeRecordRpc::from_report(&report))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::UpgradeGetStatus(resp) => {
+            let result = node
+                .parameters_report()
+                .await
+                .map(|report| UpgradeStatusRpc::from(&report))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::IdentityGet(account_id, resp) => {
+            let next_block = node.chain_height().await + 1;
+            let result = node
+                .identity(&account_id)
+                .await
+                .map(|identity| identity.map(|identity| IdentityRpc::new(&identity, next_block)))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::PersonhoodGetStatus(account_id, resp) => {
+            let result = node
+                .personhood_report(&account_id)
+                .await
+                .map(|report| PersonhoodStatusRpc::from(&report))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::MessagingGetInbox(account_id, limit, resp) => {
+            let result = node
+                .inbox(&account_id, limit)
+                .await
+                .map(|messages| messages.iter().map(AccountMessageRpc::from).collect())
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::ReputationGet(account_id, resp) => {
+            let result = node
+                .reputation(&account_id)
+                .await
+                .map(|reputation| reputation.as_ref().map(ReputationRpc::from))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::ReputationTop(limit, domain, resp) => {
+            let result = node
+                .reputations()
+                .await
+                .map(|reputations| ReputationRpc::top(&reputations, limit, domain))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::EmergencyGetStatus(resp) => {
+            let result = node
+                .emergency_report()
+                .await
+                .map(|report| EmergencyStatusRpc::from(&report))
+                .map_err(|e| e.to_string());
+            let _ = resp.send(result);
+        }
+
+        RpcCall::SlashingPendingEvidence(resp) => {
+            let pending = node
+                .pending_evidence()
+                .await
+                .iter()
+                .map(|(offence, pending)| PendingEvidenceRpc::new(offence, pending))
+                .collect();
+            let _ = resp.send(pending);
+        }
+
+        RpcCall::DebugGetStateRootForensics(block_number, resp) => {
+            let _ = resp.send(node.state_root_forensics(block_number));
+        }
+
+        RpcCall::AdminReloadConfig(resp) => {
+            let _ = resp.send(reloader.reload(node).await);
+        }
+
+        RpcCall::AuthorRotateKeys(resp) => {
+            let _ = resp.send(rotate_session_keys(node, config, validator_key).await);
+        }
+
+        RpcCall::SystemBanPeer(peer_id, reason, secs, resp) => {
+            let _ = resp.send(node.ban_peer(peer_id, &reason, secs).await);
+        }
+
+        RpcCall::SystemUnbanPeer(peer_id, resp) => {
+            let _ = resp.send(node.unban_peer(&peer_id).await);
+        }
     }
 }
 
@@ -637,9 +1152,14 @@ async fn build_system_info(node: &Arc<KratOsNode>, config: &NodeConfig) -> Syste
     let stats = node.network_stats().await;
     let chain_info = build_chain_info(node, config).await;
 
+    let mut listening_addresses = vec![format!("/ip4/0.0.0.0/tcp/{}", config.chain.network.listen_port)];
+    if let Some(port) = config.chain.network.quic_port {
+        listening_addresses.push(crate::network::behaviour::quic_listen_address(port).to_string());
+    }
+
     let network = NetworkStatus {
         local_peer_id: node.local_peer_id().await.to_string(),
-        listening_addresses: vec![format!("/ip4/0.0.0.0/tcp/{}", config.chain.network.listen_port)],
+        listening_addresses,
         peer_count: stats.connected,
         network_best_height: stats.best_height,
         average_peer_score: stats.average_score,
@@ -663,6 +1183,34 @@ async fn perform_maintenance(node: &Arc<KratOsNode>) {
 
     // Send heartbeats to DNS Seeds (every 4 cycles = 120 seconds)
     node.send_dns_heartbeats().await;
+
+    // Retry or give up stalled warp sync downloads
+    node.tick_warp_sync().await;
+
+    // Continuous verification of SPEC invariants
+    node.check_invariants().await;
+
+    // Prune block data that left the window behind finality
+    if let Err(e) = node.prune().await {
+        warn!("{}", e);
+    }
+
+    // Forget expired idempotency keys
+    if let Err(e) = node.expire_idempotency_keys().await {
+        warn!("{}", e);
+    }
+
+    // Save the mempool (if it changed), the peer bans and the known peers,
+    // a crash loses at most one interval
+    if let Err(e) = node.save_mempool().await {
+        warn!("{}", e);
+    }
+    if let Err(e) = node.save_peer_reputations().await {
+        warn!("{}", e);
+    }
+    if let Err(e) = node.save_known_peers().await {
+        warn!("{}", e);
+    }
 }
 
 /// Log node statistics
@@ -684,6 +1232,28 @@ async fn log_stats(node: &Arc<KratOsNode>) {
     );
 }
 
+/// Services advertised to DNS Seeds
+///
+/// Light-client serving is not implemented yet, so only archive state,
+/// warp-sync snapshots (taken by validators), a publicly bound RPC server
+/// and circuit relaying (--relay) are announced.
+fn advertised_capabilities(config: &NodeConfig) -> Capabilities {
+    let mut caps = Capabilities::NONE;
+    if config.validator {
+        caps = caps | Capabilities::WARP_SYNC;
+    }
+    if config.pruning == PruningMode::Archive {
+        caps = caps | Capabilities::ARCHIVE;
+    }
+    if config.rpc.enabled && config.rpc.address[0] != 127 {
+        caps = caps | Capabilities::RPC;
+    }
+    if config.chain.network.relay {
+        caps = caps | Capabilities::RELAY;
+    }
+    caps
+}
+
 /// Format IP address bytes to string
 fn format_ip(addr: [u8; 4]) -> String {
     format!("{}.{}.{}.{}", addr[0], addr[1], addr[2], addr[3])
@@ -769,13 +1339,120 @@ fn load_validator_key(config: &NodeConfig) -> Result<Option<SigningKey>, RunnerE
         return Ok(Some(signing_key));
     }
 
+    // Otherwise use the aura key from the keystore
+    let keystore = Keystore::open(&config.base_path);
+    let entry = keystore
+        .validator_entry()
+        .map_err(|e| RunnerError::Key(e.to_string()))?;
+
+    if let Some(entry) = entry {
+        info!("🔑 Loading key from keystore: {}", entry.path.display());
+
+        let password = config.keystore_password.as_deref().ok_or_else(|| {
+            RunnerError::Key(
+                "Keystore is locked: set --keystore-password or KRATOS_KEYSTORE_PASSWORD".to_string(),
+            )
+        })?;
+        let signing_key = keystore
+            .load_ed25519(&entry, password)
+            .map_err(|e| RunnerError::Key(e.to_string()))?;
+        let account_hex = hex::encode(signing_key.verifying_key().to_bytes());
+
+        info!("🏦 Account: 0x{}...{}", &account_hex[..8], &account_hex[56..]);
+
+        return Ok(Some(signing_key));
+    }
+
     // No key available
-    warn!("⚠️  No validator key! Use --validator-key <path>");
+    warn!("⚠️  No validator key! Use --validator-key <path> or insert one into the keystore");
     warn!("   Generate with: kratos-node key generate -o validator.json");
+    warn!("   Insert with:   kratos-node key insert --key-type aura --suri validator.json");
 
     Ok(None)
 }
 
+/// Make the keystore's session keys available for block signing
+///
+/// Superseded keys are loaded too: a key stays bound on chain until the
+/// epoch its replacement takes effect.
+async fn load_session_keys(node: &Arc<KratOsNode>, config: &NodeConfig) {
+    let Some(password) = config.keystore_password.as_deref() else {
+        return;
+    };
+
+    let keystore = Keystore::open(&config.base_path);
+    let entries = match keystore.list() {
+        Ok(entries) => entries,
+        Err(e) => {
+            warn!("Failed to list session keys: {}", e);
+            return;
+        }
+    };
+    for entry in entries.iter().filter(|e| e.key_type == KeyType::Session && e.scheme == KeyScheme::Ed25519) {
+        match keystore.load_ed25519(entry, password) {
+            Ok(key) => node.add_session_key(key).await,
+            Err(e) => warn!("Failed to load session key {}: {}", entry.path.display(), e),
+        }
+    }
+}
+
+/// Generate a session key and bind it to the validator account (author_rotateKeys)
+async fn rotate_session_keys(
+    node: &Arc<KratOsNode>,
+    config: &NodeConfig,
+    validator_key: Option<&SigningKey>,
+) -> Result<RotateKeysRpc, String> {
+    let account_key = validator_key.ok_or("Not a validator: no account key to sign the binding")?;
+    let password = config
+        .keystore_password
+        .as_deref()
+        .ok_or("Keystore is locked: set --keystore-password or KRATOS_KEYSTORE_PASSWORD")?;
+
+    let keystore = Keystore::open(&config.base_path);
+    let entry = keystore
+        .generate(KeyType::Session, KeyScheme::Ed25519, password)
+        .map_err(|e| e.to_string())?;
+    let session_key = keystore.load_ed25519(&entry, password).map_err(|e| e.to_string())?;
+    info!("🔑 Generated session key: {}", entry.path.display());
+
+    let (keys, hash) = node
+        .rotate_session_keys(session_key, account_key)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(RotateKeysRpc {
+        session_keys: format!("0x{}", hex::encode(keys.to_bytes())),
+        transaction_hash: format!("0x{}", hex::encode(hash.as_bytes())),
+        effective_epoch: node.chain_height().await / EPOCH_DURATION_BLOCKS + 1,
+    })
+}
+
+/// Archive keystore keys rotated `keystore_retire_after` epochs ago
+async fn retire_rotated_keys(node: &Arc<KratOsNode>, config: &NodeConfig, validator_key: Option<&SigningKey>) {
+    // Archiving re-encrypts the old secrets: only with an unlocked keystore
+    let Some(password) = config.keystore_password.as_deref() else {
+        return;
+    };
+
+    let keystore = Keystore::open(&config.base_path);
+    let epoch = node.chain_height().await / EPOCH_DURATION_BLOCKS;
+    match keystore.retire_rotated(epoch, config.keystore_retire_after, password) {
+        Ok(retired) => {
+            for entry in retired {
+                info!(
+                    "🗄️  Retired {} key 0x{} into the keystore archive (rotated at epoch {})",
+                    entry.key_type,
+                    hex::encode(entry.public_key),
+                    entry.rotated_at.unwrap_or(epoch)
+                );
+                if validator_key.is_some_and(|key| key.verifying_key().to_bytes() == entry.public_key) {
+                    warn!("⚠️  The validator key in use was retired: restart the node to sign with the new key");
+                }
+            }
+        }
+        Err(e) => warn!("Keystore key retirement failed: {}", e),
+    }
+}
+
 /// Load or generate a network identity key for DNS Seed heartbeats
 ///
 /// Non-validator nodes use this key to sign heartbeats.
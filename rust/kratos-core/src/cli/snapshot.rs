@@ -0,0 +1,362 @@
+// State snapshots - Bootstrap a node from a file instead of replaying the chain
+// Principle: The file is only trusted once its accounts rebuild the block's state root
+//
+// File format: "KRATOSSN" magic, one [u32 LE length][bincode SnapshotHeader]
+// frame, then the Snappy-framed bincode SnapshotBody (genesis block, snapshot
+// block, accounts). The header carries the Blake3 hash of the uncompressed
+// body, which is also the file name (`<hash>.ksnap`): a snapshot is addressed
+// by its content and any change to it is detected before it is imported.
+
+use crate::cli::{SnapshotExportCmd, SnapshotImportCmd};
+use crate::storage::{migrate, open_database, open_existing, StateBackend};
+use crate::types::{AccountId, AccountInfo, Block, BlockNumber, ChainId, Hash};
+use serde::{Deserialize, Serialize};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Magic bytes at the start of a snapshot file
+const SNAPSHOT_MAGIC: &[u8; 8] = b"KRATOSSN";
+
+/// Current snapshot file version
+const SNAPSHOT_VERSION: u32 = 1;
+
+/// Snapshot file extension
+pub const SNAPSHOT_EXTENSION: &str = "ksnap";
+
+/// Upper bound on the header frame (guards against corrupt length prefixes)
+const MAX_HEADER_SIZE: usize = 64 * 1024;
+
+/// Upper bound on the uncompressed body
+const MAX_BODY_SIZE: u64 = 4 * 1024 * 1024 * 1024;
+
+/// Uncompressed description of a snapshot
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotHeader {
+    pub version: u32,
+    pub genesis_hash: Hash,
+    pub block_number: BlockNumber,
+    pub block_hash: Hash,
+    pub state_root: Hash,
+    pub account_count: u64,
+    /// Blake3 hash of the uncompressed body
+    pub content_hash: Hash,
+}
+
+/// Snapshot payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotBody {
+    /// Block #0 (a node needs it to recognise its chain at startup)
+    pub genesis: Block,
+    /// Block the accounts were taken at
+    pub block: Block,
+    /// Accounts in ID order
+    pub accounts: Vec<(AccountId, AccountInfo)>,
+}
+
+/// Encode a snapshot, returning its header
+pub fn write_snapshot<W: Write>(mut out: W, body: &SnapshotBody) -> Result<SnapshotHeader, SnapshotError> {
+    let payload = bincode::serialize(body).map_err(|e| SnapshotError::Encoding(e.to_string()))?;
+    let header = SnapshotHeader {
+        version: SNAPSHOT_VERSION,
+        genesis_hash: body.genesis.hash(),
+        block_number: body.block.header.number,
+        block_hash: body.block.hash(),
+        state_root: body.block.header.state_root,
+        account_count: body.accounts.len() as u64,
+        content_hash: Hash::hash(&payload),
+    };
+    let header_bytes = bincode::serialize(&header).map_err(|e| SnapshotError::Encoding(e.to_string()))?;
+
+    out.write_all(SNAPSHOT_MAGIC)?;
+    out.write_all(&(header_bytes.len() as u32).to_le_bytes())?;
+    out.write_all(&header_bytes)?;
+    let mut encoder = snap::write::FrameEncoder::new(out);
+    encoder.write_all(&payload)?;
+    encoder
+        .into_inner()
+        .map_err(|e| SnapshotError::Io(e.into_error()))?
+        .flush()?;
+    Ok(header)
+}
+
+/// Decode the header of a snapshot
+pub fn read_header<R: Read>(input: &mut R) -> Result<SnapshotHeader, SnapshotError> {
+    let mut magic = [0u8; 8];
+    input.read_exact(&mut magic)?;
+    if &magic != SNAPSHOT_MAGIC {
+        return Err(SnapshotError::Corrupted("not a KratOs state snapshot".to_string()));
+    }
+
+    let mut len_bytes = [0u8; 4];
+    input.read_exact(&mut len_bytes)?;
+    let len = u32::from_le_bytes(len_bytes) as usize;
+    if len > MAX_HEADER_SIZE {
+        return Err(SnapshotError::Corrupted(format!("header of {} bytes exceeds limit", len)));
+    }
+    let mut header_bytes = vec![0u8; len];
+    input.read_exact(&mut header_bytes)?;
+    let header: SnapshotHeader =
+        bincode::deserialize(&header_bytes).map_err(|e| SnapshotError::Corrupted(e.to_string()))?;
+    if header.version != SNAPSHOT_VERSION {
+        return Err(SnapshotError::Corrupted(format!("unsupported version {}", header.version)));
+    }
+    Ok(header)
+}
+
+/// Decode a whole snapshot, checking the body against the header
+pub fn read_snapshot<R: Read>(mut input: R) -> Result<(SnapshotHeader, SnapshotBody), SnapshotError> {
+    let header = read_header(&mut input)?;
+
+    let mut payload = Vec::new();
+    snap::read::FrameDecoder::new(input)
+        .take(MAX_BODY_SIZE)
+        .read_to_end(&mut payload)
+        .map_err(|e| SnapshotError::Corrupted(format!("decompression failed: {}", e)))?;
+    let actual = Hash::hash(&payload);
+    if actual != header.content_hash {
+        return Err(SnapshotError::ContentHashMismatch { expected: header.content_hash, actual });
+    }
+
+    let body: SnapshotBody = bincode::deserialize(&payload).map_err(|e| SnapshotError::Corrupted(e.to_string()))?;
+    if body.genesis.header.number != 0
+        || body.genesis.hash() != header.genesis_hash
+        || body.block.hash() != header.block_hash
+        || body.block.header.number != header.block_number
+        || body.accounts.len() as u64 != header.account_count
+    {
+        return Err(SnapshotError::Corrupted("body does not match header".to_string()));
+    }
+    Ok((header, body))
+}
+
+/// Path of a snapshot inside `dir`, named after its content hash
+pub fn snapshot_path(dir: &Path, content_hash: &Hash) -> PathBuf {
+    dir.join(format!("{}.{}", hex::encode(content_hash.as_bytes()), SNAPSHOT_EXTENSION))
+}
+
+// =============================================================================
+// COMMANDS
+// =============================================================================
+
+/// Write the state at `--at` (best block by default) to the output directory
+pub fn export_snapshot(cmd: &SnapshotExportCmd) -> Result<(PathBuf, SnapshotHeader), SnapshotError> {
+    let base_path = cmd.get_base_path();
+    let db = open_existing(&base_path).map_err(|e| SnapshotError::Storage(format!("{:?}", e)))?;
+    let state = StateBackend::new(db);
+
+    let best = state
+        .get_best_block()
+        .map_err(|e| SnapshotError::Storage(e.to_string()))?
+        .ok_or_else(|| SnapshotError::Storage(format!("no chain found at {}", base_path.display())))?;
+    let at = cmd.at.unwrap_or(best);
+    if at > best {
+        return Err(SnapshotError::Storage(format!("block #{} is above the best block #{}", at, best)));
+    }
+
+    let block_at = |number| {
+        state
+            .get_block_by_number(number)
+            .map_err(|e| SnapshotError::Storage(e.to_string()))?
+            .ok_or(SnapshotError::MissingBlock(number))
+    };
+    let genesis = block_at(0)?;
+    let block = block_at(at)?;
+    let accounts = state
+        .accounts_at(at)
+        .map_err(|e| SnapshotError::Storage(format!("state of block #{}: {}", at, e)))?;
+    let body = SnapshotBody { genesis, block, accounts };
+
+    // Written under a temporary name until the content hash is known
+    std::fs::create_dir_all(&cmd.output)?;
+    let partial = cmd.output.join(format!(".snapshot-{}.partial", at));
+    let header = write_snapshot(BufWriter::new(std::fs::File::create(&partial)?), &body)?;
+    let path = snapshot_path(&cmd.output, &header.content_hash);
+    std::fs::rename(&partial, &path)?;
+
+    Ok((path, header))
+}
+
+/// Bootstrap a fresh database from a snapshot file
+///
+/// The accounts must rebuild the state root of the snapshot block's header,
+/// otherwise nothing is activated.
+pub fn import_snapshot(cmd: &SnapshotImportCmd) -> Result<SnapshotHeader, SnapshotError> {
+    let base_path = cmd.get_base_path();
+    let not_empty = std::fs::read_dir(&base_path)
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false);
+    if not_empty {
+        return Err(SnapshotError::NotEmpty(base_path.display().to_string()));
+    }
+
+    let (header, body) = read_snapshot(BufReader::new(std::fs::File::open(&cmd.input)?))?;
+
+    // Content address: a file renamed to another hash is refused
+    let stem = cmd.input.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    if stem.len() == 64 && stem.chars().all(|c| c.is_ascii_hexdigit())
+        && !stem.eq_ignore_ascii_case(&hex::encode(header.content_hash.as_bytes()))
+    {
+        return Err(SnapshotError::Corrupted(format!("file name {} does not match content hash", stem)));
+    }
+
+    std::fs::create_dir_all(&base_path)?;
+    let result = activate(&base_path, cmd, body);
+    if result.is_err() {
+        // Leave no half-initialised database behind
+        let _ = std::fs::remove_dir_all(&base_path);
+    }
+    result.map(|_| header)
+}
+
+fn activate(base_path: &Path, cmd: &SnapshotImportCmd, body: SnapshotBody) -> Result<(), SnapshotError> {
+    let db = open_database(base_path, cmd.database).map_err(|e| SnapshotError::Storage(format!("{:?}", e)))?;
+    migrate(db.as_ref()).map_err(|e| SnapshotError::Storage(e.to_string()))?;
+    let mut state = StateBackend::new(db);
+    state
+        .init_drift_tracker(body.genesis.header.timestamp)
+        .map_err(|e| SnapshotError::Storage(e.to_string()))?;
+
+    state
+        .import_snapshot(&body.block, body.accounts, ChainId(0))
+        .map_err(|e| SnapshotError::Import(e.to_string()))?;
+    state
+        .store_block(&body.genesis)
+        .and_then(|_| state.set_genesis_hash(body.genesis.hash()))
+        .map_err(|e| SnapshotError::Storage(e.to_string()))
+}
+
+/// Snapshot export/import errors
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Storage error: {0}")]
+    Storage(String),
+
+    #[error("Block #{0} missing from database")]
+    MissingBlock(BlockNumber),
+
+    #[error("Encoding error: {0}")]
+    Encoding(String),
+
+    #[error("Corrupted snapshot: {0}")]
+    Corrupted(String),
+
+    #[error("Snapshot content hash mismatch: header has {expected}, content hashes to {actual}")]
+    ContentHashMismatch { expected: Hash, actual: Hash },
+
+    #[error("Target database is not empty: {0} (purge it first)")]
+    NotEmpty(String),
+
+    #[error("Import failed: {0}")]
+    Import(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::genesis::{GenesisBuilder, GenesisSpec};
+    use crate::storage::{DatabaseBackend, RocksDatabase};
+
+    fn chain(path: &Path) -> Block {
+        let mut state = StateBackend::new(RocksDatabase::open(path).unwrap());
+        let spec = GenesisSpec::with_validator(AccountId::from_bytes([1u8; 32]));
+        let block = GenesisBuilder::new(spec).build(&mut state).unwrap().0;
+        state.store_block(&block).unwrap();
+        state.set_best_block(0).unwrap();
+        state.set_genesis_hash(block.hash()).unwrap();
+        block
+    }
+
+    fn import_cmd(input: PathBuf, base_path: PathBuf) -> SnapshotImportCmd {
+        SnapshotImportCmd {
+            base_path: Some(base_path),
+            input,
+            chain: "kratos".to_string(),
+            database: DatabaseBackend::RocksDb,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_export_import_roundtrip() {
+        let source = tempfile::tempdir().unwrap();
+        let genesis = chain(source.path());
+        let out = tempfile::tempdir().unwrap();
+
+        let (path, header) = export_snapshot(&SnapshotExportCmd {
+            base_path: Some(source.path().to_path_buf()),
+            output: out.path().to_path_buf(),
+            at: Some(0),
+            chain: "kratos".to_string(),
+        })
+        .unwrap();
+        assert_eq!(path, snapshot_path(out.path(), &header.content_hash));
+        assert_eq!(header.genesis_hash, genesis.hash());
+        assert!(header.account_count > 0);
+
+        let target = tempfile::tempdir().unwrap();
+        let imported = import_snapshot(&import_cmd(path.clone(), target.path().join("db"))).unwrap();
+        assert_eq!(imported, header);
+
+        let state = StateBackend::new(RocksDatabase::open(target.path().join("db")).unwrap());
+        assert_eq!(state.get_genesis_hash().unwrap(), Some(genesis.hash()));
+        assert_eq!(state.get_best_block().unwrap(), Some(0));
+        let balances = |accounts: Vec<(AccountId, AccountInfo)>| {
+            accounts.into_iter().map(|(id, info)| (id, info.free)).collect::<Vec<_>>()
+        };
+        let source_state = StateBackend::new(RocksDatabase::open(source.path()).unwrap());
+        assert_eq!(balances(state.accounts().unwrap()), balances(source_state.accounts().unwrap()));
+
+        // A non-empty directory is never overwritten
+        std::fs::write(target.path().join("LOCK"), b"").unwrap();
+        assert!(matches!(
+            import_snapshot(&import_cmd(path, target.path().to_path_buf())),
+            Err(SnapshotError::NotEmpty(_))
+        ));
+    }
+
+    #[test]
+    fn test_snapshot_rejects_tampering() {
+        let dir = tempfile::tempdir().unwrap();
+        let genesis = chain(dir.path());
+        let accounts = StateBackend::new(RocksDatabase::open(dir.path()).unwrap()).accounts().unwrap();
+        let body = SnapshotBody { genesis: genesis.clone(), block: genesis, accounts };
+
+        let mut file = Vec::new();
+        let header = write_snapshot(&mut file, &body).unwrap();
+        assert!(read_snapshot(file.as_slice()).is_ok());
+
+        // Flipped byte in the compressed body
+        let mut corrupted = file.clone();
+        let middle = file.len() - (file.len() - 64) / 2;
+        corrupted[middle] ^= 0xff;
+        assert!(read_snapshot(corrupted.as_slice()).is_err());
+
+        // Accounts that do not rebuild the header's state root
+        let mut forged = body.clone();
+        forged.accounts[0].1.free += 1;
+        let mut file = Vec::new();
+        write_snapshot(&mut file, &forged).unwrap();
+        let input = dir.path().join("forged.ksnap");
+        std::fs::write(&input, &file).unwrap();
+        assert!(matches!(
+            import_snapshot(&import_cmd(input, dir.path().join("target"))),
+            Err(SnapshotError::Import(_))
+        ));
+        assert!(!dir.path().join("target").exists());
+
+        // Renamed to another content hash
+        let input = snapshot_path(dir.path(), &Hash::hash(b"other"));
+        let mut file = Vec::new();
+        write_snapshot(&mut file, &body).unwrap();
+        std::fs::write(&input, &file).unwrap();
+        assert!(matches!(
+            import_snapshot(&import_cmd(input, dir.path().join("renamed"))),
+            Err(SnapshotError::Corrupted(_))
+        ));
+
+        assert!(matches!(read_header(&mut &b"NOTKRATOS"[..]), Err(SnapshotError::Corrupted(_))));
+        assert_eq!(header.block_number, 0);
+    }
+}
@@ -1,7 +1,7 @@
 // Economics - Bootstrap era and adaptive inflation
 // Principle: Early flexibility, long-term sobriety, recoverable failure
 
-use crate::types::{Balance, EpochNumber, KRAT};
+use crate::types::{Balance, EconomicsParameters, EpochNumber, FeeShares, KRAT};
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -1526,6 +1526,12 @@ impl SecurityStateTracker {
         self.is_terminal() && self.config.terminal_read_only
     }
 
+    /// Check if the emergency circuit breakers are armed
+    /// SPEC v7.1 §5.1: Armed in Restricted state, emergency in Terminal state
+    pub fn is_emergency_armed(&self) -> bool {
+        (self.is_critical() && self.config.restricted_emergency_armed) || self.is_terminal()
+    }
+
     // Backward compatibility aliases
     pub fn is_validator_degraded(&self) -> bool { self.is_dsm() }
     pub fn is_survival_mode(&self) -> bool { self.is_shm() }
@@ -2231,6 +2237,17 @@ impl Default for InflationConfig {
     }
 }
 
+impl InflationConfig {
+    /// Default config within the governed inflation bounds (see ParameterChange::InflationBounds)
+    pub fn governed(economics: &EconomicsParameters) -> Self {
+        Self {
+            min_inflation: economics.inflation_floor_bps.value() as f64 / 10_000.0,
+            max_inflation: economics.inflation_cap_bps.value() as f64 / 10_000.0,
+            ..Self::default()
+        }
+    }
+}
+
 /// Network metrics for adaptive inflation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkMetrics {
@@ -2386,6 +2403,16 @@ impl FeeDistribution {
         }
     }
 
+    /// Distribution of governed fee shares (percent, see ParameterChange::FeeShares)
+    pub fn from_shares(shares: &FeeShares) -> Self {
+        Self {
+            producer_share: shares.producer as f64 / 100.0,
+            finality_voters_share: shares.finality_voters as f64 / 100.0,
+            burn_share: shares.burn as f64 / 100.0,
+            treasury_share: shares.treasury as f64 / 100.0,
+        }
+    }
+
     /// SECURITY FIX #27: Create with validation
     /// Returns error if shares don't sum to 1.0 or are negative
     pub fn new(
@@ -2898,6 +2925,20 @@ mod tests {
         assert_eq!(result.voter_remainder(3), 1);      // 100k % 3 = 1
     }
 
+    #[test]
+    fn test_governed_economics() {
+        // Genesis parameters reproduce the built-in split and inflation bounds
+        let economics = EconomicsParameters::default();
+        let governed = FeeDistribution::from_shares(&economics.fee_shares);
+        assert!(governed.validate());
+        assert_eq!(governed.distribute(1_000_003), FeeDistribution::default_distribution().distribute(1_000_003));
+        let inflation = InflationConfig::governed(&economics);
+        assert_eq!((inflation.min_inflation, inflation.max_inflation), (0.005, 0.10));
+
+        let shares = FeeShares { producer: 40, finality_voters: 10, burn: 40, treasury: 10 };
+        assert_eq!(FeeDistribution::from_shares(&shares).distribute(1_000).burn, 400);
+    }
+
     #[test]
     fn test_economics_manager() {
         let manager = EconomicsManager::new();
@@ -0,0 +1,428 @@
+// Evidence - Provable equivocations, from detection to the slashing transaction
+// Principle: A validator is only slashed on messages it signed itself
+//
+// Two offences can be proven by anyone holding the signed messages:
+// - Double proposal: two different headers by the same author for the same
+//   epoch and slot, both signed with its block signer at that epoch
+// - Double vote: two finality votes of the same type, round and epoch for
+//   different targets (EquivocationProof, signed with the account key)
+//
+// Nodes detect them on block gossip, block import and finality votes, keep
+// them in the EvidencePool and gossip them (GossipTopic::Consensus). The next
+// time a validator node produces a block, it reports pending evidence with a
+// ReportEquivocation transaction; execution verifies it again and applies the
+// Critical penalty (slashing::offence_penalty) once per offence.
+//
+// Evidence is accepted up to the epoch after the offence: session keys are
+// only known that far back (SessionKeyRecord::previous).
+
+use crate::consensus::finality::EquivocationProof;
+use crate::consensus::finality::VoteType;
+use crate::consensus::slashing::SlashableEvent;
+use crate::types::{domain_separate, AccountId, BlockHeader, EpochNumber, Hash, SlotNumber, DOMAIN_BLOCK_HEADER};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Maximum encoded size of evidence in a ReportEquivocation transaction
+pub const MAX_EVIDENCE_SIZE: usize = 4096;
+
+/// Epochs after the offence during which evidence is accepted
+pub const EVIDENCE_MAX_AGE_EPOCHS: EpochNumber = 1;
+
+/// Maximum offences awaiting a report
+pub const MAX_PENDING_EVIDENCE: usize = 256;
+
+/// Headers remembered per (epoch, slot) for detection
+const MAX_HEADERS_PER_SLOT: usize = 4;
+
+/// Signed proof that a validator equivocated
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EquivocationEvidence {
+    /// Two headers for the same slot
+    DoubleProposal {
+        header_1: BlockHeader,
+        header_2: BlockHeader,
+    },
+
+    /// Two conflicting finality votes in the same round
+    DoubleVote(EquivocationProof),
+}
+
+/// What was done wrong, independently of which messages prove it
+///
+/// Two pairs of conflicting headers for one slot are one offence, and so is
+/// an invalid block for it (consensus::fraud): it is punished once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Offence {
+    /// Misbehaving as leader of a slot: two headers or an invalid block
+    Proposal {
+        offender: AccountId,
+        epoch: EpochNumber,
+        slot: SlotNumber,
+    },
+    DoubleVote {
+        offender: AccountId,
+        epoch: EpochNumber,
+        round: u32,
+        vote_type: VoteType,
+    },
+}
+
+impl Offence {
+    /// Identifier of the offence (evidence pool and state key)
+    pub fn id(&self) -> Hash {
+        Hash::hash(&bincode::serialize(self).expect("Offence serialization should not fail"))
+    }
+}
+
+impl EquivocationEvidence {
+    /// Validator the evidence accuses
+    pub fn offender(&self) -> AccountId {
+        match self {
+            EquivocationEvidence::DoubleProposal { header_1, .. } => header_1.author,
+            EquivocationEvidence::DoubleVote(proof) => proof.vote1.voter,
+        }
+    }
+
+    /// Epoch of the offence
+    pub fn epoch(&self) -> EpochNumber {
+        match self {
+            EquivocationEvidence::DoubleProposal { header_1, .. } => header_1.epoch,
+            EquivocationEvidence::DoubleVote(proof) => proof.vote1.epoch,
+        }
+    }
+
+    /// Offence the evidence proves
+    pub fn offence(&self) -> Offence {
+        match self {
+            EquivocationEvidence::DoubleProposal { header_1, .. } => Offence::Proposal {
+                offender: header_1.author,
+                epoch: header_1.epoch,
+                slot: header_1.slot,
+            },
+            EquivocationEvidence::DoubleVote(proof) => Offence::DoubleVote {
+                offender: proof.vote1.voter,
+                epoch: proof.vote1.epoch,
+                round: proof.vote1.round,
+                vote_type: proof.vote1.vote_type,
+            },
+        }
+    }
+
+    /// Short name for logs and RPC
+    pub fn kind(&self) -> &'static str {
+        match self {
+            EquivocationEvidence::DoubleProposal { .. } => "doubleProposal",
+            EquivocationEvidence::DoubleVote(_) => "doubleVote",
+        }
+    }
+
+    /// Check the evidence at `current_epoch`
+    ///
+    /// `block_signer` is the offender's block signer at the offence epoch
+    /// (StateBackend::block_signer); votes are checked against the voter.
+    pub fn verify(&self, block_signer: &AccountId, current_epoch: EpochNumber) -> Result<(), EvidenceError> {
+        let epoch = self.epoch();
+        if epoch > current_epoch {
+            return Err(EvidenceError::FutureEpoch(epoch));
+        }
+        if epoch.saturating_add(EVIDENCE_MAX_AGE_EPOCHS) < current_epoch {
+            return Err(EvidenceError::Expired(epoch));
+        }
+
+        match self {
+            EquivocationEvidence::DoubleProposal { header_1, header_2 } => {
+                if header_1.author != header_2.author {
+                    return Err(EvidenceError::DifferentOffenders);
+                }
+                if header_1.epoch != header_2.epoch || header_1.slot != header_2.slot {
+                    return Err(EvidenceError::NotSameSlot);
+                }
+                if header_1.hash() == header_2.hash() {
+                    return Err(EvidenceError::NoConflict);
+                }
+                if !header_signed_by(header_1, block_signer) || !header_signed_by(header_2, block_signer) {
+                    return Err(EvidenceError::InvalidSignature);
+                }
+            }
+            EquivocationEvidence::DoubleVote(proof) => {
+                if proof.validator != proof.vote1.voter || proof.vote1.voter != proof.vote2.voter {
+                    return Err(EvidenceError::DifferentOffenders);
+                }
+                if proof.vote1.round != proof.vote2.round
+                    || proof.vote1.epoch != proof.vote2.epoch
+                    || proof.vote1.vote_type != proof.vote2.vote_type
+                {
+                    return Err(EvidenceError::NotSameSlot);
+                }
+                if proof.vote1.target_hash == proof.vote2.target_hash {
+                    return Err(EvidenceError::NoConflict);
+                }
+                if !proof.is_valid() {
+                    return Err(EvidenceError::InvalidSignature);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Slashable event recorded for the offence
+    pub fn slashable_event(&self) -> SlashableEvent {
+        match self {
+            EquivocationEvidence::DoubleProposal { header_1, header_2 } => SlashableEvent::DoubleSigning {
+                slot: header_1.slot,
+                epoch: header_1.epoch,
+                block_hash_1: *header_1.hash().as_bytes(),
+                block_hash_2: *header_2.hash().as_bytes(),
+            },
+            EquivocationEvidence::DoubleVote(proof) => SlashableEvent::Equivocation {
+                epoch: proof.vote1.epoch,
+                evidence: self.to_bytes(),
+            },
+        }
+    }
+
+    /// Encoding carried by ReportEquivocation and gossip
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("EquivocationEvidence serialization should not fail")
+    }
+
+    /// Decode evidence, refusing oversized input before deserializing
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EvidenceError> {
+        if bytes.len() > MAX_EVIDENCE_SIZE {
+            return Err(EvidenceError::TooLarge(bytes.len()));
+        }
+        bincode::deserialize(bytes).map_err(|e| EvidenceError::Malformed(e.to_string()))
+    }
+}
+
+/// Header signature check (same message as BlockValidator)
+pub fn header_signed_by(header: &BlockHeader, signer: &AccountId) -> bool {
+    let message = domain_separate(DOMAIN_BLOCK_HEADER, header.hash().as_bytes());
+    signer.verify(&message, &header.signature.0)
+}
+
+/// Rejected evidence
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EvidenceError {
+    #[error("Evidence too large: {0} bytes (max {MAX_EVIDENCE_SIZE})")]
+    TooLarge(usize),
+
+    #[error("Malformed evidence: {0}")]
+    Malformed(String),
+
+    #[error("Evidence for future epoch {0}")]
+    FutureEpoch(EpochNumber),
+
+    #[error("Evidence for epoch {0} has expired")]
+    Expired(EpochNumber),
+
+    #[error("Messages come from different validators")]
+    DifferentOffenders,
+
+    #[error("Messages are for different slots or rounds")]
+    NotSameSlot,
+
+    #[error("Messages do not conflict")]
+    NoConflict,
+
+    #[error("Invalid signature")]
+    InvalidSignature,
+}
+
+/// Evidence awaiting its report on chain
+#[derive(Debug, Clone)]
+pub struct PendingEvidence {
+    pub evidence: EquivocationEvidence,
+
+    /// A ReportEquivocation for it was submitted by this node
+    pub reported: bool,
+}
+
+/// Equivocation detection and evidence awaiting a report
+#[derive(Debug, Default)]
+pub struct EvidencePool {
+    /// Signed headers seen recently, by (epoch, slot)
+    headers: BTreeMap<(EpochNumber, SlotNumber), Vec<BlockHeader>>,
+
+    /// Pending evidence by offence id
+    pending: BTreeMap<Hash, PendingEvidence>,
+}
+
+impl EvidencePool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember a header whose signature was checked, returning evidence if
+    /// its author already signed another header for the same slot
+    pub fn observe_header(&mut self, header: &BlockHeader) -> Option<EquivocationEvidence> {
+        let hash = header.hash();
+        let seen = self.headers.entry((header.epoch, header.slot)).or_default();
+        if let Some(existing) = seen.iter().find(|h| h.author == header.author && h.hash() != hash) {
+            return Some(EquivocationEvidence::DoubleProposal {
+                header_1: existing.clone(),
+                header_2: header.clone(),
+            });
+        }
+        if seen.len() < MAX_HEADERS_PER_SLOT && !seen.iter().any(|h| h.hash() == hash) {
+            seen.push(header.clone());
+        }
+        None
+    }
+
+    /// Add verified evidence, returning true if its offence is new
+    pub fn insert(&mut self, evidence: EquivocationEvidence) -> bool {
+        let id = evidence.offence().id();
+        if self.pending.contains_key(&id) || self.pending.len() >= MAX_PENDING_EVIDENCE {
+            return false;
+        }
+        self.pending.insert(id, PendingEvidence { evidence, reported: false });
+        true
+    }
+
+    /// Whether an offence is awaiting a report
+    pub fn contains(&self, id: &Hash) -> bool {
+        self.pending.contains_key(id)
+    }
+
+    /// Pending evidence by offence id
+    pub fn pending(&self) -> impl Iterator<Item = (&Hash, &PendingEvidence)> {
+        self.pending.iter()
+    }
+
+    /// Evidence this node has not reported yet, marked as reported
+    pub fn take_unreported(&mut self) -> Vec<EquivocationEvidence> {
+        self.pending
+            .values_mut()
+            .filter(|pending| !pending.reported)
+            .map(|pending| {
+                pending.reported = true;
+                pending.evidence.clone()
+            })
+            .collect()
+    }
+
+    /// Report again (the transaction was lost or failed)
+    pub fn unmark_reported(&mut self, id: &Hash) {
+        if let Some(pending) = self.pending.get_mut(id) {
+            pending.reported = false;
+        }
+    }
+
+    /// Drop an offence punished on chain
+    pub fn remove(&mut self, id: &Hash) -> Option<EquivocationEvidence> {
+        self.pending.remove(id).map(|pending| pending.evidence)
+    }
+
+    /// Drop headers and evidence that can no longer be reported
+    pub fn prune(&mut self, current_epoch: EpochNumber) {
+        let min_epoch = current_epoch.saturating_sub(EVIDENCE_MAX_AGE_EPOCHS);
+        self.headers.retain(|(epoch, _), _| *epoch >= min_epoch);
+        self.pending.retain(|_, pending| pending.evidence.epoch() >= min_epoch);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::finality::FinalityVote;
+    use crate::types::{ProtocolVersion, Signature64};
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn signed_header(key: &SigningKey, slot: SlotNumber, state_root: u8) -> BlockHeader {
+        let mut header = BlockHeader {
+            number: 10,
+            parent_hash: Hash::ZERO,
+            transactions_root: Hash::ZERO,
+            state_root: Hash::hash(&[state_root]),
+            receipts_root: Hash::ZERO,
+            timestamp: 1_000,
+            epoch: 3,
+            slot,
+            protocol_version: ProtocolVersion::default(),
+            author: AccountId::from_public_key(&key.verifying_key()),
+            signature: Signature64::zero(),
+        };
+        let message = domain_separate(DOMAIN_BLOCK_HEADER, header.hash().as_bytes());
+        header.signature = Signature64(key.sign(&message).to_bytes());
+        header
+    }
+
+    fn signed_vote(key: &SigningKey, target: u8) -> FinalityVote {
+        let voter = AccountId::from_public_key(&key.verifying_key());
+        let mut vote = FinalityVote::new(VoteType::Precommit, 10, Hash::hash(&[target]), 2, 3, voter);
+        vote.signature = Signature64(key.sign(&vote.signing_message()).to_bytes());
+        vote
+    }
+
+    #[test]
+    fn test_double_proposal_detected_and_verified() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let author = AccountId::from_public_key(&key.verifying_key());
+        let mut pool = EvidencePool::new();
+
+        assert!(pool.observe_header(&signed_header(&key, 5, 1)).is_none());
+        assert!(pool.observe_header(&signed_header(&key, 5, 1)).is_none());
+        assert!(pool.observe_header(&signed_header(&key, 6, 2)).is_none());
+        let evidence = pool.observe_header(&signed_header(&key, 5, 2)).expect("double proposal");
+
+        assert_eq!(evidence.offender(), author);
+        assert_eq!(evidence.verify(&author, 3), Ok(()));
+        assert_eq!(evidence.verify(&author, 4), Ok(()));
+        assert_eq!(evidence.verify(&author, 5), Err(EvidenceError::Expired(3)));
+        assert_eq!(evidence.verify(&author, 2), Err(EvidenceError::FutureEpoch(3)));
+
+        // Signed by another key than the author's block signer
+        let other = AccountId::from_public_key(&SigningKey::from_bytes(&[8; 32]).verifying_key());
+        assert_eq!(evidence.verify(&other, 3), Err(EvidenceError::InvalidSignature));
+
+        let decoded = EquivocationEvidence::from_bytes(&evidence.to_bytes()).unwrap();
+        assert_eq!(decoded.offence(), evidence.offence());
+        assert!(matches!(decoded.slashable_event(), SlashableEvent::DoubleSigning { slot: 5, epoch: 3, .. }));
+    }
+
+    #[test]
+    fn test_double_vote_verified() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let voter = AccountId::from_public_key(&key.verifying_key());
+        let proof = |vote2: FinalityVote| {
+            EquivocationEvidence::DoubleVote(EquivocationProof {
+                validator: voter,
+                vote1: signed_vote(&key, 1),
+                vote2,
+                round: 2,
+                epoch: 3,
+            })
+        };
+
+        assert_eq!(proof(signed_vote(&key, 2)).verify(&voter, 3), Ok(()));
+        assert_eq!(proof(signed_vote(&key, 1)).verify(&voter, 3), Err(EvidenceError::NoConflict));
+
+        let mut forged = signed_vote(&key, 2);
+        forged.target_number = 11;
+        assert_eq!(proof(forged).verify(&voter, 3), Err(EvidenceError::InvalidSignature));
+    }
+
+    #[test]
+    fn test_pool_one_entry_per_offence() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let evidence = |root| EquivocationEvidence::DoubleProposal {
+            header_1: signed_header(&key, 5, 1),
+            header_2: signed_header(&key, 5, root),
+        };
+        let mut pool = EvidencePool::new();
+
+        assert!(pool.insert(evidence(2)));
+        assert!(!pool.insert(evidence(3)));
+        assert_eq!(pool.take_unreported().len(), 1);
+        assert!(pool.take_unreported().is_empty());
+
+        pool.prune(4);
+        assert_eq!(pool.pending().count(), 1);
+        pool.prune(5);
+        assert_eq!(pool.pending().count(), 0);
+
+        assert!(EquivocationEvidence::from_bytes(&vec![0; MAX_EVIDENCE_SIZE + 1]).is_err());
+    }
+}
@@ -6,17 +6,27 @@
 // 3. Generating and broadcasting our votes
 // 4. Creating finality justifications
 // 5. Detecting and reporting equivocations
+// 6. Recovering from stalls (timeout backoff, vote re-broadcast, round skipping)
+//
+// Stall recovery: every consecutive timeout doubles the next round's timeout
+// (up to MAX_ROUND_TIMEOUT_MS), and our votes are re-broadcast along with a
+// vote request each base timeout within a round. Rounds advance
+// deterministically: to round + 1 on timeout, or straight to the highest round
+// in which more than 1/3 of the validators already voted (at least one honest
+// validator is there), so validators whose round counters drifted apart
+// converge again without operator intervention.
 
 use super::rounds::{FinalityRound, RoundManager};
-use super::types::{EquivocationProof, FinalityMessage, FinalityVote, RoundState, VoteType};
+use super::types::{EquivocationProof, FinalityMessage, FinalityVote, RoundState, StallStatus, VoteType};
 use super::votes::VoteError;
 use super::config;
 use crate::types::account::AccountId;
 use crate::types::block::FinalityJustification;
 use crate::types::primitives::{BlockNumber, EpochNumber, Hash};
 use crate::types::signature::Signature64;
-use std::collections::{HashSet, VecDeque};
+use std::collections::{BTreeMap, HashSet, VecDeque};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn, trace};
 
@@ -76,6 +86,24 @@ pub struct FinalityGadget<S: FinalitySigner, B: FinalityBroadcaster> {
 
     /// Blocks pending finality (not yet targeted by a round)
     pending_blocks: Vec<(BlockNumber, Hash)>,
+
+    /// Rounds timed out since the last finalization
+    consecutive_timeouts: u32,
+
+    /// Rounds skipped to catch up with other validators
+    rounds_skipped: u64,
+
+    /// Times our votes were re-broadcast
+    rebroadcasts: u64,
+
+    /// Re-broadcasts done in the active round
+    round_rebroadcasts: u32,
+
+    /// Last finalization seen
+    last_finality_at: Instant,
+
+    /// Verified votes for rounds ahead of ours (current epoch), by round
+    future_votes: BTreeMap<u32, Vec<FinalityVote>>,
 }
 
 impl<S: FinalitySigner, B: FinalityBroadcaster> FinalityGadget<S, B> {
@@ -116,6 +144,12 @@ impl<S: FinalitySigner, B: FinalityBroadcaster> FinalityGadget<S, B> {
             is_validator,
             last_block: (0, genesis_hash),
             pending_blocks: Vec::new(),
+            consecutive_timeouts: 0,
+            rounds_skipped: 0,
+            rebroadcasts: 0,
+            round_rebroadcasts: 0,
+            last_finality_at: Instant::now(),
+            future_votes: BTreeMap::new(),
         }
     }
 
@@ -168,7 +202,9 @@ impl<S: FinalitySigner, B: FinalityBroadcaster> FinalityGadget<S, B> {
             round, target_number
         );
 
-        self.rounds.start_round(epoch, round);
+        let timeout = self.round_timeout();
+        self.rounds.start_round(epoch, round).set_timeout(timeout);
+        self.round_rebroadcasts = 0;
 
         // If we're a validator, create and broadcast our prevote
         if self.is_validator {
@@ -297,14 +333,21 @@ impl<S: FinalitySigner, B: FinalityBroadcaster> FinalityGadget<S, B> {
             vote.round
         );
 
-        let round = match self.rounds.active_round_mut() {
-            Some(r) => r,
+        let (epoch, current_round) = match self.rounds.active_round() {
+            Some(r) => (r.epoch(), r.round()),
             None => {
                 trace!("[GRANDPA] on_vote: no active round, ignoring vote");
                 return None;
             }
         };
 
+        // Other validators are ahead of us: keep the vote, maybe skip ahead
+        if vote.epoch == epoch && vote.round > current_round {
+            return self.on_future_vote(vote, current_round);
+        }
+
+        let round = self.rounds.active_round_mut()?;
+
         // Check if vote is for current round
         if vote.epoch != round.epoch() || vote.round != round.round() {
             trace!(
@@ -395,6 +438,12 @@ impl<S: FinalitySigner, B: FinalityBroadcaster> FinalityGadget<S, B> {
             Err(e) => {
                 trace!("[GRANDPA] on_vote: vote rejected with error: {:?}", e);
                 warn!("Vote rejected: {}", e);
+                // Keep the proof for slashing (consensus::evidence)
+                if let VoteError::Equivocation(_) = e {
+                    if let Some(proof) = round.collector().equivocations().last() {
+                        self.equivocations.push(proof.clone());
+                    }
+                }
                 Some(VoteResult::Rejected(e))
             }
         }
@@ -478,6 +527,8 @@ impl<S: FinalitySigner, B: FinalityBroadcaster> FinalityGadget<S, B> {
         // Remove finalized blocks from pending
         if let Some((block_num, _)) = finalized {
             self.pending_blocks.retain(|(n, _)| *n > block_num);
+            self.consecutive_timeouts = 0;
+            self.last_finality_at = Instant::now();
         }
 
         // Start next round if there are pending blocks
@@ -508,19 +559,28 @@ impl<S: FinalitySigner, B: FinalityBroadcaster> FinalityGadget<S, B> {
                     round.round()
                 );
                 round.mark_failed();
+                self.consecutive_timeouts = self.consecutive_timeouts.saturating_add(1);
                 self.complete_current_round(None);
 
-                // Check for stuck finality
-                if self.rounds.current_round() > config::MAX_ROUNDS_BEFORE_FORCE {
+                if self.consecutive_timeouts == config::STALL_ROUNDS {
                     warn!(
-                        "🚨 Finality stuck for {} rounds!",
-                        self.rounds.current_round()
+                        "🚨 Finality stalled: {} rounds timed out in a row (next timeout {:?})",
+                        self.consecutive_timeouts,
+                        self.round_timeout()
                     );
                 }
 
+                // Votes already received for the new round
+                self.replay_future_votes();
                 return true;
             }
 
+            // Quorum not reached after a base timeout: re-send our votes
+            let base = Duration::from_millis(config::ROUND_TIMEOUT_MS);
+            if round.elapsed() >= base * (self.round_rebroadcasts + 1) {
+                self.rebroadcast_votes();
+            }
+
             // Check if we should precommit
             if should_precommit && self.is_validator {
                 trace!("[GRANDPA] tick: should_precommit=true, creating precommit");
@@ -533,6 +593,134 @@ impl<S: FinalitySigner, B: FinalityBroadcaster> FinalityGadget<S, B> {
         false
     }
 
+    /// Timeout of the next round: doubled by each consecutive timeout
+    fn round_timeout(&self) -> Duration {
+        let backoff = config::ROUND_TIMEOUT_MS << self.consecutive_timeouts.min(16);
+        Duration::from_millis(backoff.min(config::MAX_ROUND_TIMEOUT_MS))
+    }
+
+    /// Re-broadcast our votes of the active round and ask peers for theirs
+    fn rebroadcast_votes(&mut self) {
+        let Some(round) = self.rounds.active_round() else {
+            return;
+        };
+        let (epoch, round_number) = (round.epoch(), round.round());
+        let votes = round.our_votes();
+        self.round_rebroadcasts += 1;
+
+        debug!(
+            "📣 Round {} without quorum after {:?}, re-broadcasting {} votes",
+            round_number,
+            round.elapsed(),
+            votes.len()
+        );
+        for vote in votes {
+            let message = FinalityMessage::Vote(vote);
+            self.broadcaster.broadcast(message.clone());
+            self.outbound_queue.push_back(message);
+        }
+        self.broadcaster.broadcast(FinalityMessage::RequestVotes { epoch, round: round_number });
+        self.rebroadcasts += 1;
+    }
+
+    /// Buffer a verified vote for a later round, skipping ahead once more
+    /// than 1/3 of the validators are there
+    fn on_future_vote(&mut self, vote: FinalityVote, current_round: u32) -> Option<VoteResult> {
+        if vote.round > current_round.saturating_add(config::MAX_FUTURE_ROUNDS) {
+            debug!("Vote for round {} too far ahead of round {}", vote.round, current_round);
+            return None;
+        }
+        if !self.rounds.validators().contains(&vote.voter) {
+            return Some(VoteResult::Rejected(VoteError::NotValidator(vote.voter)));
+        }
+        if !vote.verify() {
+            return Some(VoteResult::Rejected(VoteError::InvalidSignature(vote.voter)));
+        }
+
+        let buffered: usize = self.future_votes.values().map(Vec::len).sum();
+        let votes = self.future_votes.entry(vote.round).or_default();
+        if votes.iter().any(|v| v.id() == vote.id()) {
+            return Some(VoteResult::Accepted);
+        }
+        if buffered >= config::MAX_PENDING_VOTES {
+            debug!("Future vote buffer full, dropping vote for round {}", vote.round);
+            return None;
+        }
+        votes.push(vote);
+
+        // Highest round with more than 1/3 of the validators
+        let total = self.rounds.validators().len();
+        let target = self.future_votes.iter().rev().find_map(|(round, votes)| {
+            let voters: HashSet<AccountId> = votes.iter().map(|v| v.voter).collect();
+            (voters.len() * 3 > total).then_some(*round)
+        });
+
+        match target {
+            Some(target) => self.skip_to_round(target),
+            None => Some(VoteResult::Accepted),
+        }
+    }
+
+    /// Abandon the active round and start `target` with the votes buffered for it
+    fn skip_to_round(&mut self, target: u32) -> Option<VoteResult> {
+        let current = self.rounds.current_round();
+        warn!(
+            "⏭️ Skipping finality round {} -> {}: other validators are ahead",
+            current, target
+        );
+
+        if let Some(round) = self.rounds.active_round_mut() {
+            round.mark_failed();
+        }
+        self.rounds.complete_round(None);
+        self.rounds.skip_to(target);
+        self.rounds_skipped += u64::from(target - current);
+        self.start_finality_round();
+
+        self.replay_future_votes()
+            .or(Some(VoteResult::StateChanged(RoundState::Prevoting)))
+    }
+
+    /// Feed the votes buffered for the current round, dropping older rounds
+    fn replay_future_votes(&mut self) -> Option<VoteResult> {
+        let current = self.rounds.current_round();
+        self.future_votes = self.future_votes.split_off(&current);
+        let votes = self.future_votes.remove(&current)?;
+
+        let mut outcome = None;
+        for vote in votes {
+            match self.on_vote(vote) {
+                Some(VoteResult::Finalized(block, hash)) => {
+                    outcome = Some(VoteResult::Finalized(block, hash));
+                }
+                Some(VoteResult::StateChanged(state)) if outcome.is_none() => {
+                    outcome = Some(VoteResult::StateChanged(state));
+                }
+                _ => {}
+            }
+        }
+        outcome
+    }
+
+    /// Liveness of finality for RPC
+    pub fn stall_status(&self) -> StallStatus {
+        let round_timeout = self
+            .rounds
+            .active_round()
+            .map_or_else(|| self.round_timeout(), |round| round.timeout());
+
+        StallStatus {
+            stalled: self.consecutive_timeouts >= config::STALL_ROUNDS,
+            consecutive_timeouts: self.consecutive_timeouts,
+            current_round: self.rounds.current_round(),
+            round_timeout_ms: round_timeout.as_millis() as u64,
+            rounds_skipped: self.rounds_skipped,
+            rebroadcasts: self.rebroadcasts,
+            last_finalized_block: self.rounds.last_finalized().0,
+            secs_since_finality: self.last_finality_at.elapsed().as_secs(),
+        }
+    }
+
     /// Get last finalized block
     pub fn last_finalized(&self) -> (BlockNumber, Hash) {
         self.rounds.last_finalized()
@@ -587,6 +775,7 @@ impl<S: FinalitySigner, B: FinalityBroadcaster> FinalityGadget<S, B> {
     pub fn new_epoch(&mut self, epoch: EpochNumber) {
         info!("📅 Starting new finality epoch {}", epoch);
         self.rounds.new_epoch(epoch);
+        self.future_votes.clear();
     }
 
     /// Get pending outbound messages
@@ -594,6 +783,16 @@ impl<S: FinalitySigner, B: FinalityBroadcaster> FinalityGadget<S, B> {
         self.outbound_queue.drain(..).collect()
     }
 
+    /// Equivocations detected since the last call
+    pub fn drain_equivocations(&mut self) -> Vec<EquivocationProof> {
+        std::mem::take(&mut self.equivocations)
+    }
+
+    /// Rounds completed since the last call, with whether they finalized a block
+    pub fn drain_completed_rounds(&mut self) -> Vec<(super::types::RoundSummary, bool)> {
+        self.rounds.drain_completed()
+    }
+
     /// Check if gadget is actively participating
     pub fn is_active(&self) -> bool {
         self.is_validator && self.rounds.has_active_round()
@@ -708,4 +907,119 @@ mod tests {
         // Non-validator should not broadcast votes
         assert_eq!(broadcaster.message_count(), 0);
     }
+
+    fn signed_prevote(key: &ed25519_dalek::SigningKey, round: u32, target: (BlockNumber, Hash)) -> FinalityVote {
+        use ed25519_dalek::Signer;
+
+        let voter = AccountId::from_public_key(&key.verifying_key());
+        let mut vote = FinalityVote::new(VoteType::Prevote, target.0, target.1, round, 0, voter);
+        vote.signature = Signature64(key.sign(&vote.signing_message()).to_bytes());
+        vote
+    }
+
+    #[test]
+    fn test_skip_to_round_with_third_of_validators() {
+        let keys: Vec<_> = (1..=4u8)
+            .map(|i| ed25519_dalek::SigningKey::from_bytes(&[i; 32]))
+            .collect();
+        let validators: HashSet<AccountId> = keys
+            .iter()
+            .map(|k| AccountId::from_public_key(&k.verifying_key()))
+            .collect();
+        let our_id = AccountId::from_public_key(&keys[0].verifying_key());
+
+        let signer = Arc::new(MockSigner { id: our_id });
+        let broadcaster = Arc::new(MockBroadcaster::new());
+        let mut gadget = FinalityGadget::new(signer, broadcaster, validators, Hash::ZERO);
+
+        let target = (1, Hash::hash(b"block1"));
+        gadget.on_block_imported(target.0, target.1);
+        assert_eq!(gadget.current_round(), 0);
+
+        // One validator in round 5 is not enough (1 * 3 <= 4)
+        gadget.on_vote(signed_prevote(&keys[1], 5, target));
+        assert_eq!(gadget.current_round(), 0);
+
+        // Unsigned votes for future rounds are rejected
+        let mut forged = signed_prevote(&keys[3], 5, target);
+        forged.signature = Signature64::zero();
+        assert!(matches!(
+            gadget.on_vote(forged),
+            Some(VoteResult::Rejected(VoteError::InvalidSignature(_)))
+        ));
+
+        // Second validator: more than 1/3 are in round 5
+        gadget.on_vote(signed_prevote(&keys[2], 5, target));
+        assert_eq!(gadget.current_round(), 5);
+
+        let status = gadget.stall_status();
+        assert_eq!(status.rounds_skipped, 5);
+        assert_eq!(status.current_round, 5);
+        assert!(!status.stalled);
+
+        // The buffered votes were replayed into the new round
+        let round = gadget.rounds.active_round().unwrap();
+        assert_eq!(round.collector().prevote_count(&target), 2);
+    }
+
+    #[test]
+    fn test_equivocation_kept_for_slashing() {
+        let keys: Vec<_> = (1..=4u8)
+            .map(|i| ed25519_dalek::SigningKey::from_bytes(&[i; 32]))
+            .collect();
+        let validators: HashSet<AccountId> = keys
+            .iter()
+            .map(|k| AccountId::from_public_key(&k.verifying_key()))
+            .collect();
+        let our_id = AccountId::from_public_key(&keys[0].verifying_key());
+
+        let signer = Arc::new(MockSigner { id: our_id });
+        let broadcaster = Arc::new(MockBroadcaster::new());
+        let mut gadget = FinalityGadget::new(signer, broadcaster, validators, Hash::ZERO);
+        gadget.on_block_imported(1, Hash::hash(b"block1"));
+
+        gadget.on_vote(signed_prevote(&keys[1], 0, (1, Hash::hash(b"block1"))));
+        assert!(matches!(
+            gadget.on_vote(signed_prevote(&keys[1], 0, (1, Hash::hash(b"fork1")))),
+            Some(VoteResult::Rejected(VoteError::Equivocation(_)))
+        ));
+
+        let proofs = gadget.drain_equivocations();
+        assert_eq!(proofs.len(), 1);
+        assert!(proofs[0].is_valid());
+        assert!(gadget.drain_equivocations().is_empty());
+    }
+
+    #[test]
+    fn test_round_timeout_backoff() {
+        let validators = make_validators(3);
+        let validator_id = *validators.iter().next().unwrap();
+
+        let signer = Arc::new(MockSigner { id: validator_id });
+        let broadcaster = Arc::new(MockBroadcaster::new());
+        let mut gadget = FinalityGadget::new(signer, broadcaster, validators, Hash::ZERO);
+
+        let base = Duration::from_millis(config::ROUND_TIMEOUT_MS);
+        assert_eq!(gadget.round_timeout(), base);
+
+        gadget.consecutive_timeouts = 2;
+        assert_eq!(gadget.round_timeout(), base * 4);
+
+        gadget.consecutive_timeouts = config::STALL_ROUNDS;
+        assert!(gadget.stall_status().stalled);
+
+        gadget.consecutive_timeouts = 40;
+        assert_eq!(
+            gadget.round_timeout(),
+            Duration::from_millis(config::MAX_ROUND_TIMEOUT_MS)
+        );
+
+        // The backed-off timeout applies to the next round, finality resets it
+        gadget.on_block_imported(1, Hash::hash(b"block1"));
+        let round = gadget.rounds.active_round().unwrap();
+        assert_eq!(round.timeout(), Duration::from_millis(config::MAX_ROUND_TIMEOUT_MS));
+
+        gadget.complete_current_round(Some((1, Hash::hash(b"block1"))));
+        assert_eq!(gadget.round_timeout(), base);
+    }
 }
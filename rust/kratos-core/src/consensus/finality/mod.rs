@@ -39,6 +39,15 @@ pub mod config {
     /// Timeout for a single round (in milliseconds)
     pub const ROUND_TIMEOUT_MS: u64 = 6000; // 6 seconds (1 block time)
 
+    /// Upper bound of the round timeout after exponential backoff (in milliseconds)
+    pub const MAX_ROUND_TIMEOUT_MS: u64 = 96_000; // 16 x ROUND_TIMEOUT_MS
+
+    /// Consecutive round timeouts before finality is reported as stalled
+    pub const STALL_ROUNDS: u32 = 3;
+
+    /// How far ahead of the current round votes are buffered (for round skipping)
+    pub const MAX_FUTURE_ROUNDS: u32 = 16;
+
     /// Maximum pending votes to keep in memory
     pub const MAX_PENDING_VOTES: usize = 1000;
 }
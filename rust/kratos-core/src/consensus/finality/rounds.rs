@@ -38,6 +38,9 @@ pub struct FinalityRound {
 
     /// Have we precommitted this round?
     have_precommitted: bool,
+
+    /// Tracing span covering the round, closed when the round is dropped
+    span: tracing::Span,
 }
 
 impl FinalityRound {
@@ -55,6 +58,8 @@ impl FinalityRound {
             our_validator,
             have_prevoted: false,
             have_precommitted: false,
+            // Not nested in whatever span started the round (a block import)
+            span: tracing::info_span!(parent: None, "finality_round", epoch, round, outcome = tracing::field::Empty),
         }
     }
 
@@ -88,6 +93,33 @@ impl FinalityRound {
         self.timeout.saturating_sub(self.started_at.elapsed())
     }
 
+    /// Time since the round started
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Round timeout duration
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Change the round timeout (backoff after repeated timeouts)
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Votes we cast in this round (for re-broadcast)
+    pub fn our_votes(&self) -> Vec<FinalityVote> {
+        let Some(our_validator) = self.our_validator else {
+            return Vec::new();
+        };
+        self.collector
+            .all_votes()
+            .into_iter()
+            .filter(|vote| vote.voter == our_validator)
+            .collect()
+    }
+
     /// Add a vote to this round
     pub fn add_vote(&mut self, vote: FinalityVote) -> Result<bool, VoteError> {
         self.collector.add_vote(vote)
@@ -214,6 +246,7 @@ impl FinalityRound {
             block_hash,
             signatures,
             epoch: self.epoch(),
+            round: self.round(),
         })
     }
 
@@ -227,6 +260,7 @@ impl FinalityRound {
             precommit_count: self.collector.total_precommits(),
             target_block: self.collector.best_prevote(),
             total_validators: self.collector.validator_count(),
+            duration_ms: self.elapsed().as_millis() as u64,
         }
     }
 
@@ -273,6 +307,9 @@ pub struct RoundManager {
     /// Historical rounds (for catch-up)
     completed_rounds: Vec<RoundSummary>,
 
+    /// Rounds completed since the last drain, with whether they finalized a block
+    undrained_rounds: Vec<(RoundSummary, bool)>,
+
     /// Last finalized block
     last_finalized: (BlockNumber, Hash),
 
@@ -295,6 +332,7 @@ impl RoundManager {
             current_round: 0,
             active_round: None,
             completed_rounds: Vec::new(),
+            undrained_rounds: Vec::new(),
             last_finalized,
             our_validator,
             validators,
@@ -332,12 +370,18 @@ impl RoundManager {
     pub fn complete_round(&mut self, finalized: Option<(BlockNumber, Hash)>) {
         if let Some(round) = self.active_round.take() {
             // Store summary
-            self.completed_rounds.push(round.summary());
+            let summary = round.summary();
+            round.span.record("outcome", if finalized.is_some() { "finalized" } else { "failed" });
+            self.completed_rounds.push(summary.clone());
+            self.undrained_rounds.push((summary, finalized.is_some()));
 
             // Keep only recent rounds
             if self.completed_rounds.len() > 100 {
                 self.completed_rounds.remove(0);
             }
+            if self.undrained_rounds.len() > 100 {
+                self.undrained_rounds.remove(0);
+            }
 
             // Update finalized if we got a result
             if let Some((block, hash)) = finalized {
@@ -347,12 +391,22 @@ impl RoundManager {
         }
     }
 
+    /// Rounds completed since the last call, with whether they finalized a block
+    pub fn drain_completed(&mut self) -> Vec<(RoundSummary, bool)> {
+        std::mem::take(&mut self.undrained_rounds)
+    }
+
     /// Advance to next round (after timeout or completion)
     pub fn next_round(&mut self) -> &mut FinalityRound {
         self.current_round += 1;
         self.start_round(self.current_epoch, self.current_round)
     }
 
+    /// Jump ahead: the next started round is `round`
+    pub fn skip_to(&mut self, round: u32) {
+        self.current_round = round;
+    }
+
     /// Set new epoch (resets round to 0)
     pub fn new_epoch(&mut self, epoch: EpochNumber) {
         self.current_epoch = epoch;
@@ -389,6 +443,11 @@ impl RoundManager {
     pub fn has_active_round(&self) -> bool {
         self.active_round.is_some()
     }
+
+    /// Current validator set
+    pub fn validators(&self) -> &HashSet<AccountId> {
+        &self.validators
+    }
 }
 
 #[cfg(test)]
@@ -467,4 +526,43 @@ mod tests {
         assert_eq!(summary.state, RoundState::Prevoting);
         assert_eq!(summary.total_validators, 3);
     }
+
+    #[test]
+    fn test_justification_verifies_from_precommits() {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let keys: Vec<SigningKey> = (1..=3u8).map(|i| SigningKey::from_bytes(&[i; 32])).collect();
+        let validators: HashSet<AccountId> = keys
+            .iter()
+            .map(|k| AccountId::from_bytes(k.verifying_key().to_bytes()))
+            .collect();
+        let target = Hash::hash(b"block 10");
+
+        let signed = |key: &SigningKey, vote_type| {
+            let voter = AccountId::from_bytes(key.verifying_key().to_bytes());
+            let mut vote = FinalityVote::new(vote_type, 10, target, 1, 0, voter);
+            vote.signature = Signature64(key.sign(&vote.signing_message()).to_bytes());
+            vote
+        };
+
+        let mut round = FinalityRound::new(0, 1, validators.clone(), None);
+        for key in &keys {
+            round.add_vote(signed(key, VoteType::Prevote)).unwrap();
+        }
+        for key in &keys {
+            let _ = round.add_vote(signed(key, VoteType::Precommit));
+        }
+        assert_eq!(round.state(), RoundState::Completed);
+
+        let justification = round.create_justification().unwrap();
+        assert_eq!(justification.round, 1);
+        assert!(justification.is_valid(validators.len()));
+        assert!(justification.verify_with_validators(&validators));
+
+        // Signatures are bound to the round and to the validator set
+        let mut forged = justification.clone();
+        forged.round = 2;
+        assert!(!forged.verify_with_validators(&validators));
+        assert!(!justification.verify_with_validators(&make_validators(3)));
+    }
 }
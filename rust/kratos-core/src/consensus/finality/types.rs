@@ -181,6 +181,28 @@ pub struct RoundSummary {
     pub precommit_count: usize,
     pub target_block: Option<(BlockNumber, Hash)>,
     pub total_validators: usize,
+    /// Time since the round started (its duration once completed)
+    pub duration_ms: u64,
+}
+
+/// Finality liveness, as seen by the local gadget (finality_getStallStatus)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StallStatus {
+    /// At least STALL_ROUNDS consecutive rounds timed out
+    pub stalled: bool,
+    /// Rounds timed out since the last finalization
+    pub consecutive_timeouts: u32,
+    pub current_round: u32,
+    /// Timeout of the current round after backoff
+    pub round_timeout_ms: u64,
+    /// Rounds skipped to catch up with the other validators
+    pub rounds_skipped: u64,
+    /// Times our votes were re-broadcast
+    pub rebroadcasts: u64,
+    pub last_finalized_block: BlockNumber,
+    /// Seconds since the last finalization seen by the gadget
+    pub secs_since_finality: u64,
 }
 
 /// Network message for finality gossip
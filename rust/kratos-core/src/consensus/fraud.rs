@@ -0,0 +1,262 @@
+// Fraud Proofs - Invalid blocks, from detection to the slashing transaction
+// Principle: Execution is deterministic, so any full node can re-execute a
+// block on its parent's state and see whether it gives the block's roots
+//
+// A validator that signs a block whose transactions fail, or whose state or
+// receipts root is not the result of executing it, claims a state transition
+// that execution does not give (FraudProof::InvalidStateTransition, carrying
+// the block). Nodes detect it when the block fails to import, keep the proof
+// in the FraudPool and, the next time they produce a block, submit it with a
+// SubmitFraudProof transaction. Execution checks the block extends the chain
+// and is signed by its author, re-executes it on the state after its parent
+// (producer::check_fraud_proof), applies the Critical penalty
+// (slashing::offence_penalty) and pays the reporter
+// FRAUD_REPORT_REWARD_PERCENT of the slashed stake; the rest is burned. The
+// offence is the one of a double proposal for the block's slot
+// (evidence::Offence::Proposal), so a slot leader is punished once for it,
+// whichever way it is proven.
+//
+// The parent's state is rebuilt from undo records (StateBackend::state_at),
+// so proofs are accepted for FRAUD_PROOF_WINDOW blocks: half the reorg
+// window, leaving room for a proof inside the replayed block to be replayed
+// too. A node imported by warp sync has no undo records for the blocks
+// before its snapshot, and fails proofs of those blocks.
+//
+// Only invalid state transitions are provable on chain. Sidechain state
+// transitions are not executed on the main chain, so a checkpoint cannot be
+// proven invalid by re-execution; only main chain blocks are.
+
+use crate::consensus::evidence::{header_signed_by, Offence};
+use crate::types::{AccountId, Balance, Block, BlockHeader, BlockNumber, FraudProof, FraudProofError, Hash};
+use std::collections::BTreeMap;
+
+/// Share of the slashed stake paid to the reporter (percent)
+pub const FRAUD_REPORT_REWARD_PERCENT: Balance = 10;
+
+/// Maximum fraud proofs awaiting a report
+pub const MAX_PENDING_FRAUD_PROOFS: usize = 64;
+
+/// Blocks after the invalid block during which its fraud proof is accepted
+/// (half of storage::state::MAX_REORG_DEPTH)
+pub const FRAUD_PROOF_WINDOW: BlockNumber = 512;
+
+/// Reporter's share of a slashed stake
+pub fn reporter_reward(slashed: Balance) -> Balance {
+    slashed.saturating_mul(FRAUD_REPORT_REWARD_PERCENT) / 100
+}
+
+/// Block a fraud proof disputes, if it is provable on chain
+pub fn disputed_block(proof: &FraudProof) -> Result<&Block, FraudProofError> {
+    match proof {
+        FraudProof::InvalidStateTransition { block } => Ok(block),
+        _ => Err(FraudProofError::InvalidProofStructure(
+            "Only invalid state transitions are provable on chain".to_string(),
+        )),
+    }
+}
+
+/// Offence a fraud proof proves: misbehaving as leader of the block's slot
+pub fn fraud_offence(proof: &FraudProof) -> Result<Offence, FraudProofError> {
+    let header = &disputed_block(proof)?.header;
+    Ok(Offence::Proposal { offender: header.author, epoch: header.epoch, slot: header.slot })
+}
+
+/// Check a fraud proof against the chain at `current_block`, short of
+/// re-executing the block
+///
+/// `parent` is the chain's header below the disputed block, `block_signer`
+/// the accused's block signer at the disputed epoch
+/// (StateBackend::block_signer).
+pub fn verify_fraud_proof(
+    proof: &FraudProof,
+    parent: &BlockHeader,
+    block_signer: &AccountId,
+    current_block: BlockNumber,
+) -> Result<(), FraudProofError> {
+    let header = &disputed_block(proof)?.header;
+    if header.number.saturating_add(FRAUD_PROOF_WINDOW) < current_block {
+        return Err(FraudProofError::ProofExpired);
+    }
+    proof.verify()?;
+    if header.parent_hash != parent.hash() || header.number != parent.number + 1 {
+        return Err(FraudProofError::InvalidProofStructure(
+            "Block does not extend the chain".to_string(),
+        ));
+    }
+    if !header_signed_by(header, block_signer) {
+        return Err(FraudProofError::InvalidSignature);
+    }
+    Ok(())
+}
+
+/// Fraud proof awaiting its report on chain
+#[derive(Debug, Clone)]
+pub struct PendingFraudProof {
+    pub proof: FraudProof,
+
+    /// A SubmitFraudProof for it was submitted by this node
+    pub reported: bool,
+}
+
+/// Verified fraud proofs awaiting a report, by offence id
+#[derive(Debug, Default)]
+pub struct FraudPool {
+    pending: BTreeMap<Hash, PendingFraudProof>,
+}
+
+impl FraudPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a verified proof, returning true if its offence is new
+    pub fn insert(&mut self, proof: FraudProof) -> bool {
+        let Ok(id) = fraud_offence(&proof).map(|offence| offence.id()) else {
+            return false;
+        };
+        if self.pending.contains_key(&id) || self.pending.len() >= MAX_PENDING_FRAUD_PROOFS {
+            return false;
+        }
+        self.pending.insert(id, PendingFraudProof { proof, reported: false });
+        true
+    }
+
+    /// Pending proofs by offence
+    pub fn pending(&self) -> impl Iterator<Item = (&Hash, &PendingFraudProof)> {
+        self.pending.iter()
+    }
+
+    /// Proofs this node has not reported yet, marked as reported
+    pub fn take_unreported(&mut self) -> Vec<FraudProof> {
+        self.pending
+            .values_mut()
+            .filter(|pending| !pending.reported)
+            .map(|pending| {
+                pending.reported = true;
+                pending.proof.clone()
+            })
+            .collect()
+    }
+
+    /// Report again (the transaction was lost or failed)
+    pub fn unmark_reported(&mut self, id: &Hash) {
+        if let Some(pending) = self.pending.get_mut(id) {
+            pending.reported = false;
+        }
+    }
+
+    /// Drop an offence punished on chain
+    pub fn remove(&mut self, id: &Hash) -> Option<FraudProof> {
+        self.pending.remove(id).map(|pending| pending.proof)
+    }
+
+    /// Drop proofs that can no longer be reported
+    pub fn prune(&mut self, current_block: BlockNumber) {
+        let min_block = current_block.saturating_sub(FRAUD_PROOF_WINDOW);
+        self.pending
+            .retain(|_, pending| disputed_block(&pending.proof).is_ok_and(|block| block.header.number >= min_block));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::evidence::EquivocationEvidence;
+    use crate::types::{domain_separate, BlockBody, ProtocolVersion, Signature64, DOMAIN_BLOCK_HEADER};
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn signed_block(key: &SigningKey, parent: &BlockHeader, state_root: Hash) -> Block {
+        let mut header = BlockHeader {
+            number: parent.number + 1,
+            parent_hash: parent.hash(),
+            transactions_root: Hash::ZERO,
+            state_root,
+            receipts_root: Hash::ZERO,
+            timestamp: 60,
+            epoch: 0,
+            slot: parent.slot + 1,
+            protocol_version: ProtocolVersion::default(),
+            author: AccountId::from_public_key(&key.verifying_key()),
+            signature: Signature64::zero(),
+        };
+        let message = domain_separate(DOMAIN_BLOCK_HEADER, header.hash().as_bytes());
+        header.signature = Signature64(key.sign(&message).to_bytes());
+        Block::new(header, BlockBody::new(Vec::new()))
+    }
+
+    fn parent_at(number: BlockNumber) -> BlockHeader {
+        BlockHeader {
+            number,
+            parent_hash: Hash::hash(b"parent"),
+            transactions_root: Hash::ZERO,
+            state_root: Hash::hash(b"state"),
+            receipts_root: Hash::ZERO,
+            timestamp: 54,
+            epoch: 0,
+            slot: number,
+            protocol_version: ProtocolVersion::default(),
+            author: AccountId::from_bytes([1; 32]),
+            signature: Signature64::zero(),
+        }
+    }
+
+    #[test]
+    fn test_verify_fraud_proof() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let signer = AccountId::from_public_key(&key.verifying_key());
+        let parent = parent_at(9);
+        let proof = FraudProof::InvalidStateTransition { block: signed_block(&key, &parent, Hash::hash(b"inflated")) };
+
+        assert!(verify_fraud_proof(&proof, &parent, &signer, 10 + FRAUD_PROOF_WINDOW).is_ok());
+        assert!(matches!(
+            verify_fraud_proof(&proof, &parent, &signer, 11 + FRAUD_PROOF_WINDOW),
+            Err(FraudProofError::ProofExpired)
+        ));
+        assert!(matches!(
+            verify_fraud_proof(&proof, &parent, &AccountId::from_bytes([1; 32]), 10),
+            Err(FraudProofError::InvalidSignature)
+        ));
+
+        // The parent is the chain's, not the reporter's
+        assert!(matches!(
+            verify_fraud_proof(&proof, &parent_at(8), &signer, 10),
+            Err(FraudProofError::InvalidProofStructure(_))
+        ));
+
+        assert_eq!(reporter_reward(1_000), 100);
+    }
+
+    #[test]
+    fn test_fraud_offence_is_the_slot() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let invalid = signed_block(&key, &parent_at(9), Hash::hash(b"inflated"));
+        let other = signed_block(&key, &parent_at(9), Hash::hash(b"other"));
+        let proof = FraudProof::InvalidStateTransition { block: invalid.clone() };
+
+        // Same offence as the double proposal of the slot
+        let evidence = EquivocationEvidence::DoubleProposal { header_1: invalid.header, header_2: other.header };
+        assert_eq!(fraud_offence(&proof).unwrap(), evidence.offence());
+    }
+
+    #[test]
+    fn test_fraud_pool() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let proof = |state_root: &[u8], number| FraudProof::InvalidStateTransition {
+            block: signed_block(&key, &parent_at(number - 1), Hash::hash(state_root)),
+        };
+        let mut pool = FraudPool::new();
+
+        assert!(pool.insert(proof(b"a", 10)));
+        assert!(!pool.insert(proof(b"a", 10)));
+        assert!(pool.insert(proof(b"b", 600)));
+
+        assert_eq!(pool.take_unreported().len(), 2);
+        assert!(pool.take_unreported().is_empty());
+        pool.unmark_reported(&fraud_offence(&proof(b"a", 10)).unwrap().id());
+        assert_eq!(pool.take_unreported().len(), 1);
+
+        pool.prune(600);
+        assert_eq!(pool.pending().count(), 1);
+        assert!(pool.remove(&fraud_offence(&proof(b"b", 600)).unwrap().id()).is_some());
+    }
+}
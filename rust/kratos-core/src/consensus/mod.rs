@@ -12,5 +12,9 @@ pub mod slashing;
 pub mod vc_decay;
 pub mod economics;
 pub mod clock_health;
+pub mod seed_attestation;
+pub mod session_keys;
+pub mod evidence;
+pub mod fraud;
 pub mod finality;
 
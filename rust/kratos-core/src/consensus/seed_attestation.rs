@@ -0,0 +1,285 @@
+// Seed Attestation - DNS seed heartbeat receipts as an uptime input
+// Principle: A third party saw the validator online, the chain saw it produce
+//
+// Validators send a signed heartbeat to the DNS seeds every 2 minutes and the
+// seeds answer with a signed receipt. Once an epoch is over, a validator may
+// submit its receipts for that epoch (SubmitSeedAttestation). They count only
+// if the validator also authored a block in that epoch (UptimeRecord, updated
+// on every block), so heartbeating without validating earns nothing. Coverage
+// is the share of the epoch's 2-minute heartbeat slots attested by at least
+// one trusted seed; it feeds the usual ≥95% uptime credit rule.
+
+use crate::consensus::epoch::{EPOCH_DURATION_BLOCKS, SLOT_DURATION_SECS};
+use crate::types::{invalid_signatures, AccountId, EpochNumber, Hash, SeedReceipt, SignatureItem};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Interval between heartbeats (network::dns_seed_client::HEARTBEAT_INTERVAL_SECS)
+pub const HEARTBEAT_INTERVAL_SECS: u64 = 120;
+
+/// Wall-clock duration of an epoch
+pub const EPOCH_DURATION_SECS: u64 = EPOCH_DURATION_BLOCKS * SLOT_DURATION_SECS;
+
+/// Heartbeat slots in an epoch (full coverage)
+pub const HEARTBEATS_PER_EPOCH: u64 = EPOCH_DURATION_SECS / HEARTBEAT_INTERVAL_SECS;
+
+/// Maximum receipts in one attestation (a few seeds × one epoch)
+pub const MAX_SEED_RECEIPTS: usize = 128;
+
+/// Seed IDs (hex Ed25519 public keys) whose receipts are accepted
+///
+/// Consensus parameter, like DNS_SEED_HEARTBEAT_IPS: every node must use the
+/// same list. Receipts from other seeds are ignored.
+pub const TRUSTED_SEED_IDS: &[&str] = &[];
+
+/// Trusted seeds, parsed
+pub fn trusted_seeds() -> Vec<AccountId> {
+    TRUSTED_SEED_IDS
+        .iter()
+        .filter_map(|id| {
+            let bytes: [u8; 32] = hex::decode(id).ok()?.try_into().ok()?;
+            Some(AccountId::from_bytes(bytes))
+        })
+        .collect()
+}
+
+/// Per-validator uptime state (blocks authored, last attested epoch)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UptimeRecord {
+    /// Epoch counted by `blocks`
+    pub epoch: EpochNumber,
+
+    /// Blocks authored in `epoch`
+    pub blocks: u32,
+
+    /// Blocks authored in `epoch - 1`
+    pub previous_blocks: u32,
+
+    /// Last epoch an attestation was accepted for
+    pub attested_epoch: Option<EpochNumber>,
+}
+
+impl UptimeRecord {
+    /// Count a block authored in `epoch`
+    pub fn record_block(&mut self, epoch: EpochNumber) {
+        if epoch > self.epoch {
+            self.previous_blocks = if epoch == self.epoch + 1 { self.blocks } else { 0 };
+            self.blocks = 0;
+            self.epoch = epoch;
+        }
+        if epoch == self.epoch {
+            self.blocks = self.blocks.saturating_add(1);
+        }
+    }
+
+    /// Blocks authored in `epoch` (0 if no longer tracked)
+    pub fn blocks_in(&self, epoch: EpochNumber) -> u32 {
+        if epoch == self.epoch {
+            self.blocks
+        } else if epoch + 1 == self.epoch {
+            self.previous_blocks
+        } else {
+            0
+        }
+    }
+}
+
+/// Accepted attestation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttestedUptime {
+    /// Distinct heartbeat slots attested by trusted seeds
+    pub heartbeats: u32,
+
+    /// Share of the epoch's heartbeat slots (0.0 - 1.0)
+    pub coverage: f64,
+}
+
+/// Rejected attestation
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AttestationError {
+    #[error("Epoch {epoch} is not the last completed epoch (current {current})")]
+    WrongEpoch { epoch: EpochNumber, current: EpochNumber },
+
+    #[error("Epoch {0} already attested")]
+    AlreadyAttested(EpochNumber),
+
+    #[error("Too many receipts: {0} (max {MAX_SEED_RECEIPTS})")]
+    TooManyReceipts(usize),
+
+    #[error("No block authored in epoch {0}")]
+    NoBlocksAuthored(EpochNumber),
+
+    #[error("Receipt {index}: {reason}")]
+    InvalidReceipt { index: usize, reason: &'static str },
+
+    #[error("Receipts span {0}s, more than one epoch")]
+    SpanTooLong(u64),
+}
+
+/// Check an attestation for `epoch` submitted by `validator`
+///
+/// `current` is the epoch of the block including it; only `current - 1` can
+/// be attested, once.
+pub fn verify_attestation(
+    validator: &AccountId,
+    epoch: EpochNumber,
+    current: EpochNumber,
+    receipts: &[SeedReceipt],
+    genesis_hash: &Hash,
+    trusted: &[AccountId],
+    record: &UptimeRecord,
+) -> Result<AttestedUptime, AttestationError> {
+    if epoch + 1 != current {
+        return Err(AttestationError::WrongEpoch { epoch, current });
+    }
+    if record.attested_epoch.is_some_and(|attested| attested >= epoch) {
+        return Err(AttestationError::AlreadyAttested(epoch));
+    }
+    if receipts.len() > MAX_SEED_RECEIPTS {
+        return Err(AttestationError::TooManyReceipts(receipts.len()));
+    }
+    if record.blocks_in(epoch) == 0 {
+        return Err(AttestationError::NoBlocksAuthored(epoch));
+    }
+
+    let heights = epoch * EPOCH_DURATION_BLOCKS..(epoch + 1) * EPOCH_DURATION_BLOCKS;
+    let mut counted = Vec::new();
+    for (index, receipt) in receipts.iter().enumerate() {
+        let invalid = |reason| AttestationError::InvalidReceipt { index, reason };
+        if receipt.validator != *validator {
+            return Err(invalid("issued for another validator"));
+        }
+        if receipt.genesis_hash != *genesis_hash {
+            return Err(invalid("issued for another chain"));
+        }
+        if !heights.contains(&receipt.height) {
+            return Err(invalid("height outside the epoch"));
+        }
+        if trusted.contains(&receipt.seed) {
+            counted.push(receipt);
+        }
+    }
+
+    let messages: Vec<Vec<u8>> = counted.iter().map(|r| r.signing_message()).collect();
+    let items: Vec<SignatureItem<'_>> = counted
+        .iter()
+        .zip(&messages)
+        .map(|(receipt, message)| SignatureItem::new(receipt.seed, message, &receipt.signature))
+        .collect();
+    if let Some(&offender) = invalid_signatures(&items).first() {
+        let index = receipts.iter().position(|r| r == counted[offender]).unwrap_or(offender);
+        return Err(AttestationError::InvalidReceipt { index, reason: "invalid seed signature" });
+    }
+
+    let first = counted.iter().map(|r| r.timestamp).min().unwrap_or(0);
+    let last = counted.iter().map(|r| r.timestamp).max().unwrap_or(0);
+    if last - first > EPOCH_DURATION_SECS + HEARTBEAT_INTERVAL_SECS {
+        return Err(AttestationError::SpanTooLong(last - first));
+    }
+
+    let slots: BTreeSet<u64> = counted
+        .iter()
+        .map(|r| r.timestamp / HEARTBEAT_INTERVAL_SECS)
+        .collect();
+    let heartbeats = (slots.len() as u64).min(HEARTBEATS_PER_EPOCH);
+
+    Ok(AttestedUptime {
+        heartbeats: heartbeats as u32,
+        coverage: heartbeats as f64 / HEARTBEATS_PER_EPOCH as f64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Signature64;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn receipt(seed: &SigningKey, validator: AccountId, height: u64, timestamp: u64) -> SeedReceipt {
+        let mut receipt = SeedReceipt {
+            seed: AccountId::from_public_key(&seed.verifying_key()),
+            validator,
+            genesis_hash: Hash::ZERO,
+            height,
+            timestamp,
+            signature: Signature64::zero(),
+        };
+        receipt.signature = Signature64(seed.sign(&receipt.signing_message()).to_bytes());
+        receipt
+    }
+
+    fn authored(epoch: EpochNumber) -> UptimeRecord {
+        let mut record = UptimeRecord::default();
+        record.record_block(epoch);
+        record
+    }
+
+    #[test]
+    fn test_full_epoch_coverage() {
+        let seed = SigningKey::from_bytes(&[7; 32]);
+        let trusted = [AccountId::from_public_key(&seed.verifying_key())];
+        let validator = AccountId::from_bytes([1; 32]);
+
+        // One heartbeat per slot of epoch 3, received by the same seed
+        let receipts: Vec<_> = (0..HEARTBEATS_PER_EPOCH)
+            .map(|i| receipt(&seed, validator, 1800 + i * 20, 1_000_000 + i * HEARTBEAT_INTERVAL_SECS))
+            .collect();
+
+        let mut record = authored(3);
+        record.record_block(4);
+        assert_eq!(record.blocks_in(3), 1);
+
+        let uptime = verify_attestation(&validator, 3, 4, &receipts, &Hash::ZERO, &trusted, &record).unwrap();
+        assert_eq!(uptime.heartbeats as u64, HEARTBEATS_PER_EPOCH);
+        assert!((uptime.coverage - 1.0).abs() < f64::EPSILON);
+
+        // Receipts from untrusted seeds are ignored
+        let uptime = verify_attestation(&validator, 3, 4, &receipts, &Hash::ZERO, &[], &record).unwrap();
+        assert_eq!(uptime.heartbeats, 0);
+    }
+
+    #[test]
+    fn test_attestation_rejections() {
+        let seed = SigningKey::from_bytes(&[7; 32]);
+        let trusted = [AccountId::from_public_key(&seed.verifying_key())];
+        let validator = AccountId::from_bytes([1; 32]);
+        let receipts = vec![receipt(&seed, validator, 1800, 1_000_000)];
+        let record = authored(3);
+
+        let check = |receipts: &[SeedReceipt], epoch, record: &UptimeRecord| {
+            verify_attestation(&validator, epoch, 4, receipts, &Hash::ZERO, &trusted, record)
+        };
+
+        assert!(check(&receipts, 3, &record).is_ok());
+        assert!(matches!(check(&receipts, 2, &record), Err(AttestationError::WrongEpoch { .. })));
+
+        // No block authored in the epoch: heartbeats alone earn nothing
+        assert_eq!(
+            check(&receipts, 3, &authored(1)),
+            Err(AttestationError::NoBlocksAuthored(3))
+        );
+
+        let attested = UptimeRecord { attested_epoch: Some(3), ..record.clone() };
+        assert_eq!(check(&receipts, 3, &attested), Err(AttestationError::AlreadyAttested(3)));
+
+        let mut forged = receipts.clone();
+        forged[0].timestamp += 1;
+        assert!(matches!(
+            check(&forged, 3, &record),
+            Err(AttestationError::InvalidReceipt { index: 0, reason: "invalid seed signature" })
+        ));
+
+        let other = vec![receipt(&seed, AccountId::from_bytes([2; 32]), 1800, 1_000_000)];
+        assert!(matches!(check(&other, 3, &record), Err(AttestationError::InvalidReceipt { .. })));
+
+        let outside = vec![receipt(&seed, validator, 2400, 1_000_000)];
+        assert!(matches!(check(&outside, 3, &record), Err(AttestationError::InvalidReceipt { .. })));
+
+        // Receipts gathered over more than an epoch
+        let spread = vec![
+            receipt(&seed, validator, 1800, 1_000_000),
+            receipt(&seed, validator, 1900, 1_000_000 + 2 * EPOCH_DURATION_SECS),
+        ];
+        assert!(matches!(check(&spread, 3, &record), Err(AttestationError::SpanTooLong(_))));
+    }
+}
@@ -0,0 +1,147 @@
+// Session Keys - Replaceable block signing keys of a validator
+// Principle: The account key holds the stake, a disposable key does the daily signing
+//
+// A validator generates session keys on its node (author_rotateKeys, stored in
+// the keystore as `sess` keys) and binds them to its account with a
+// SetSessionKeys transaction signed by the account key. The binding takes
+// effect at the next epoch: blocks of the current epoch still verify against
+// the key every node expects, so rotating causes no missed slot. A validator
+// that never bound keys signs its blocks with the account key.
+//
+// Finality votes are still signed by the account key.
+
+use crate::types::{AccountId, EpochNumber, SessionKeys};
+use ed25519_dalek::VerifyingKey;
+use serde::{Deserialize, Serialize};
+
+/// Session keys of a validator: in use and queued
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionKeyRecord {
+    /// Keys in use (None: the account key signs)
+    pub current: Option<SessionKeys>,
+
+    /// Keys bound by the last SetSessionKeys, with the epoch they take effect
+    pub queued: Option<(SessionKeys, EpochNumber)>,
+
+    /// Keys in force before `current`, with the epoch `current` took effect
+    /// (equivocation evidence from the previous epoch is checked against them)
+    pub previous: Option<(Option<SessionKeys>, EpochNumber)>,
+}
+
+impl SessionKeyRecord {
+    /// Keys in force at `epoch` (None: the account key)
+    ///
+    /// Exact back to the epoch before the last change took effect.
+    pub fn keys_at(&self, epoch: EpochNumber) -> Option<SessionKeys> {
+        match (self.queued, self.previous) {
+            (Some((keys, effective)), _) if epoch >= effective => Some(keys),
+            (_, Some((keys, since))) if epoch < since => keys,
+            _ => self.current,
+        }
+    }
+
+    /// Queue `keys` for the epoch after `current_epoch`, returning that epoch
+    ///
+    /// Queued keys already in effect become current; queued keys not yet in
+    /// effect are replaced.
+    pub fn queue(&mut self, keys: SessionKeys, current_epoch: EpochNumber) -> EpochNumber {
+        if let Some((queued, effective)) = self.queued {
+            if current_epoch >= effective {
+                self.previous = Some((self.current, effective));
+                self.current = Some(queued);
+            }
+        }
+        let effective = current_epoch.saturating_add(1);
+        self.queued = Some((keys, effective));
+        effective
+    }
+}
+
+/// Rejected SetSessionKeys transaction
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SessionKeysError {
+    #[error("Sender is not a validator")]
+    NotValidator,
+
+    #[error("Block key is not a valid Ed25519 public key")]
+    InvalidBlockKey,
+
+    #[error("Session keys already bound")]
+    Unchanged,
+}
+
+/// Check keys a validator binds at `current_epoch`
+pub fn check_session_keys(
+    keys: &SessionKeys,
+    record: &SessionKeyRecord,
+    current_epoch: EpochNumber,
+) -> Result<(), SessionKeysError> {
+    VerifyingKey::from_bytes(keys.block.as_bytes()).map_err(|_| SessionKeysError::InvalidBlockKey)?;
+    // Re-binding the keys in force (or already queued) would only burn the fee
+    let latest = record.queued.map(|(keys, _)| keys).or(record.keys_at(current_epoch));
+    if latest == Some(*keys) {
+        return Err(SessionKeysError::Unchanged);
+    }
+    Ok(())
+}
+
+/// Key the block headers of `author` at `epoch` are signed with
+pub fn block_signer(author: &AccountId, record: Option<&SessionKeyRecord>, epoch: EpochNumber) -> AccountId {
+    record
+        .and_then(|record| record.keys_at(epoch))
+        .map_or(*author, |keys| keys.block)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::SigningKey;
+
+    fn keys(seed: u8) -> SessionKeys {
+        let key = SigningKey::from_bytes(&[seed; 32]);
+        SessionKeys { block: AccountId::from_public_key(&key.verifying_key()) }
+    }
+
+    #[test]
+    fn test_rotation_takes_effect_next_epoch() {
+        let author = AccountId::from_bytes([9; 32]);
+        let mut record = SessionKeyRecord::default();
+        assert_eq!(block_signer(&author, None, 5), author);
+
+        assert_eq!(record.queue(keys(1), 5), 6);
+        assert_eq!(block_signer(&author, Some(&record), 5), author);
+        assert_eq!(block_signer(&author, Some(&record), 6), keys(1).block);
+
+        // A second rotation in the same epoch replaces the queued keys
+        record.queue(keys(2), 5);
+        assert_eq!(record.keys_at(6), Some(keys(2)));
+        assert_eq!(record.current, None);
+
+        // Later, the keys in force become current until the next ones apply
+        record.queue(keys(3), 8);
+        assert_eq!(record.current, Some(keys(2)));
+        assert_eq!(record.keys_at(8), Some(keys(2)));
+        assert_eq!(record.keys_at(9), Some(keys(3)));
+
+        // The epochs before keys(2) still resolve to the account key
+        assert_eq!(record.previous, Some((None, 6)));
+        assert_eq!(block_signer(&author, Some(&record), 5), author);
+    }
+
+    #[test]
+    fn test_check_session_keys() {
+        let mut record = SessionKeyRecord::default();
+        assert_eq!(check_session_keys(&keys(1), &record, 0), Ok(()));
+
+        record.queue(keys(1), 0);
+        assert_eq!(check_session_keys(&keys(1), &record, 0), Err(SessionKeysError::Unchanged));
+        assert_eq!(check_session_keys(&keys(1), &record, 3), Err(SessionKeysError::Unchanged));
+        assert_eq!(check_session_keys(&keys(2), &record, 0), Ok(()));
+
+        // y = 2 is not on the curve
+        let mut bytes = [0; 32];
+        bytes[0] = 2;
+        let invalid = SessionKeys { block: AccountId::from_bytes(bytes) };
+        assert_eq!(check_session_keys(&invalid, &record, 0), Err(SessionKeysError::InvalidBlockKey));
+    }
+}
@@ -9,7 +9,7 @@ use std::collections::HashMap;
 /// Slashing severity levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SlashingSeverity {
-    /// Critical: Double signing, proven equivocation
+    /// Critical: Double signing, proven equivocation, proven invalid block
     Critical,
     /// High: Arbitration misconduct, invalid governance execution
     High,
@@ -70,6 +70,11 @@ pub enum SlashableEvent {
         epoch: EpochNumber,
         evidence: Vec<u8>,
     },
+    /// Block whose state transition a fraud proof showed to be invalid
+    InvalidStateTransition {
+        block_number: BlockNumber,
+        block_hash: [u8; 32],
+    },
     /// Arbitration misconduct
     ArbitrationMisconduct {
         arbitration_id: u64,
@@ -97,6 +102,7 @@ impl SlashableEvent {
         match self {
             SlashableEvent::DoubleSigning { .. } => SlashingSeverity::Critical,
             SlashableEvent::Equivocation { .. } => SlashingSeverity::Critical,
+            SlashableEvent::InvalidStateTransition { .. } => SlashingSeverity::Critical,
             SlashableEvent::ArbitrationMisconduct { .. } => SlashingSeverity::High,
             SlashableEvent::InvalidGovernanceExecution { .. } => SlashingSeverity::High,
             SlashableEvent::ExtendedDowntime { epochs_offline } => {
@@ -216,19 +222,8 @@ impl SlashingManager {
     ) -> Result<SlashingRecord, SlashingError> {
         let severity = event.severity();
 
-        // STEP 1: Slash VC (MANDATORY FIRST)
-        let vc_before = vc_record.total_vc();
-        let vc_slash_amount = self.calculate_vc_slash(vc_record, severity);
-        self.apply_vc_slash(vc_record, vc_slash_amount, current_block);
-        let vc_after = vc_record.total_vc();
-
-        // STEP 2: Slash stake (only if VC exhausted or critical)
-        let stake_slash_amount = if vc_after == 0 || matches!(severity, SlashingSeverity::Critical)
-        {
-            self.calculate_stake_slash(current_stake, severity)
-        } else {
-            0
-        };
+        // STEPS 1-2: Slash VC, then stake
+        let (vc_slashed, stake_slash_amount) = offence_penalty(&event, vc_record, current_stake, current_block);
 
         // STEP 3: Determine cooldown/ejection
         let (cooldown_until, ejected) = self.determine_cooldown_ejection(
@@ -243,7 +238,7 @@ impl SlashingManager {
             event,
             slash_epoch: current_epoch,
             slash_block: current_block,
-            vc_slashed: vc_before - vc_after,
+            vc_slashed,
             stake_slashed: stake_slash_amount,
             cooldown_until_epoch: cooldown_until,
             ejected,
@@ -258,7 +253,6 @@ impl SlashingManager {
     /// SECURITY FIX #8: Use checked arithmetic to prevent overflow
     /// SECURITY FIX #25: Safe f64→u64 conversion with bounds checking
     fn calculate_vc_slash(
-        &self,
         vc_record: &ValidatorCreditsRecord,
         severity: SlashingSeverity,
     ) -> u64 {
@@ -288,7 +282,6 @@ impl SlashingManager {
     /// Apply VC slash proportionally across categories
     /// SPEC v2: Preserves category separation with proper rounding
     fn apply_vc_slash(
-        &self,
         vc_record: &mut ValidatorCreditsRecord,
         slash_amount: u64,
         current_block: BlockNumber,
@@ -356,7 +349,7 @@ impl SlashingManager {
     /// Calculate stake slash amount
     /// SECURITY FIX #8: Use checked arithmetic to prevent overflow
     /// SECURITY FIX #25: Safe f64→Balance conversion with bounds checking
-    fn calculate_stake_slash(&self, current_stake: Balance, severity: SlashingSeverity) -> Balance {
+    fn calculate_stake_slash(current_stake: Balance, severity: SlashingSeverity) -> Balance {
         let slash_percent = severity.stake_slash_percent();
 
         // SECURITY FIX #25: Safe floating point conversion
@@ -543,6 +536,36 @@ impl SlashingManager {
     }
 }
 
+/// VC-then-stake penalty of an event: (VC slashed, stake slashed)
+///
+/// VC is slashed first; stake only once VC is exhausted or for a critical
+/// event. Used as is for offences proven on chain (ReportEquivocation,
+/// SubmitFraudProof), where the manager's in-memory cooldown and ejection
+/// tracking does not apply.
+pub fn offence_penalty(
+    event: &SlashableEvent,
+    vc_record: &mut ValidatorCreditsRecord,
+    current_stake: Balance,
+    current_block: BlockNumber,
+) -> (u64, Balance) {
+    let severity = event.severity();
+
+    // STEP 1: Slash VC (MANDATORY FIRST)
+    let vc_before = vc_record.total_vc();
+    let vc_slash_amount = SlashingManager::calculate_vc_slash(vc_record, severity);
+    SlashingManager::apply_vc_slash(vc_record, vc_slash_amount, current_block);
+    let vc_after = vc_record.total_vc();
+
+    // STEP 2: Slash stake (only if VC exhausted or critical)
+    let stake_slash_amount = if vc_after == 0 || matches!(severity, SlashingSeverity::Critical) {
+        SlashingManager::calculate_stake_slash(current_stake, severity)
+    } else {
+        0
+    };
+
+    (vc_before - vc_after, stake_slash_amount)
+}
+
 impl Default for SlashingManager {
     fn default() -> Self {
         Self::new()
@@ -791,6 +814,23 @@ mod tests {
         assert!(record4.ejected);
     }
 
+    #[test]
+    fn test_offence_penalty() {
+        let mut vc_record = ValidatorCreditsRecord::new(0, 0);
+        vc_record.vote_credits = 100;
+        let event = SlashableEvent::DoubleSigning {
+            slot: 1,
+            epoch: 0,
+            block_hash_1: [1; 32],
+            block_hash_2: [2; 32],
+        };
+
+        // Critical: half the VC and 20% of the stake
+        assert_eq!(offence_penalty(&event, &mut vc_record, 1_000_000, 10), (50, 200_000));
+        assert_eq!(vc_record.total_vc(), 50);
+        assert_eq!(vc_record.last_update, 10);
+    }
+
     #[test]
     fn test_epoch_boundary_cleanup() {
         let mut manager = SlashingManager::new();
@@ -278,15 +278,15 @@ impl BlockValidator {
 
     /// Valide toutes les transactions du bloc
     fn validate_transactions(&self, block: &Block) -> Result<(), ValidationError> {
-        for (index, tx) in block.body.transactions.iter().enumerate() {
-            // Vérifier la signature de la transaction
-            if !tx.verify() {
-                return Err(ValidationError::InvalidTransactionSignature {
-                    block_number: block.header.number,
-                    tx_index: index,
-                });
+        // Vérifier les signatures des transactions (par lots, multi-thread)
+        SignedTransaction::verify_batch(&block.body.transactions).map_err(|index| {
+            ValidationError::InvalidTransactionSignature {
+                block_number: block.header.number,
+                tx_index: index,
             }
+        })?;
 
+        for (index, tx) in block.body.transactions.iter().enumerate() {
             // Vérifier que le nonce n'est pas nul (sauf pour les transactions système)
             // TODO: Vérifier le nonce par rapport à l'état
 
@@ -408,9 +408,11 @@ mod tests {
                 parent_hash: Hash::ZERO,
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 1000,
                 epoch: 0,
                 slot: 0,
+                protocol_version: ProtocolVersion::default(),
                 author: AccountId::from_bytes([1; 32]),
                 signature: Signature64([0; 64]),
             },
@@ -425,9 +427,11 @@ mod tests {
                 parent_hash: parent.hash(),
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 1100,
                 epoch: 0,
                 slot: 1,
+                protocol_version: ProtocolVersion::default(),
                 author: AccountId::from_bytes([1; 32]),
                 signature: Signature64([0; 64]),
             },
@@ -450,9 +454,11 @@ mod tests {
                 parent_hash: Hash::ZERO,
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 1000,
                 epoch: 0,
                 slot: 0,
+                protocol_version: ProtocolVersion::default(),
                 author: AccountId::from_bytes([1; 32]),
                 signature: Signature64([0; 64]),
             },
@@ -467,9 +473,11 @@ mod tests {
                 parent_hash: parent.hash(),
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 1100,
                 epoch: 0,
                 slot: 1,
+                protocol_version: ProtocolVersion::default(),
                 author: AccountId::from_bytes([1; 32]),
                 signature: Signature64([0; 64]),
             },
@@ -495,9 +503,11 @@ mod tests {
                 parent_hash: Hash::ZERO,
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 1000,
                 epoch: 0,
                 slot: 0,
+                protocol_version: ProtocolVersion::default(),
                 author: AccountId::from_bytes([1; 32]),
                 signature: Signature64([0; 64]),
             },
@@ -513,9 +523,11 @@ mod tests {
                 parent_hash: parent.hash(),
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 999, // Plus ancien que le parent
                 epoch: 0,
                 slot: 1,
+                protocol_version: ProtocolVersion::default(),
                 author: AccountId::from_bytes([1; 32]),
                 signature: Signature64([0; 64]),
             },
@@ -540,9 +552,11 @@ mod tests {
                 parent_hash: Hash::ZERO,
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 0,
                 epoch: 0,
                 slot: 0,
+                protocol_version: ProtocolVersion::default(),
                 author: AccountId::from_bytes([0; 32]),
                 signature: Signature64([0; 64]),
             },
@@ -607,9 +621,11 @@ mod tests {
                 parent_hash: Hash::ZERO,
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 1000,
                 epoch: 0,
                 slot: 1,
+                protocol_version: ProtocolVersion::default(),
                 author: selected, // Correct author
                 signature: Signature64([0; 64]),
             },
@@ -659,9 +675,11 @@ mod tests {
                 parent_hash: Hash::ZERO,
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 1000,
                 epoch: 0,
                 slot: 1,
+                protocol_version: ProtocolVersion::default(),
                 author: wrong_author, // Wrong author!
                 signature: Signature64([0; 64]),
             },
@@ -722,9 +740,11 @@ mod tests {
                 parent_hash: Hash::ZERO,
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 1000,
                 epoch: 0,
                 slot: 1,
+                protocol_version: ProtocolVersion::default(),
                 author: wrong_author,
                 signature: Signature64([0; 64]),
             },
@@ -1,5 +1,5 @@
 // Validator - Gestion des validateurs pour le consensus
-use crate::types::{AccountId, Balance, BlockNumber};
+use crate::types::{AccountId, Balance, BlockNumber, CandidateMetadata};
 use crate::types::contributor::{NetworkRoleRegistry, RoleRegistryError};
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
@@ -77,6 +77,10 @@ pub struct EarlyValidatorCandidate {
 
     /// Block number when approved (if approved)
     pub approved_at: Option<BlockNumber>,
+
+    /// Display name and contact URL supplied by the proposer
+    #[serde(default)]
+    pub metadata: CandidateMetadata,
 }
 
 impl EarlyValidatorCandidate {
@@ -99,9 +103,16 @@ impl EarlyValidatorCandidate {
             created_at,
             status: CandidacyStatus::Pending,
             approved_at: None,
+            metadata: CandidateMetadata::default(),
         }
     }
 
+    /// Attach proposer-supplied metadata
+    pub fn with_metadata(mut self, metadata: CandidateMetadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
     /// Add a vote from a validator
     pub fn add_vote(&mut self, voter: AccountId) -> bool {
         self.voters.insert(voter)
@@ -625,6 +636,22 @@ impl ValidatorSet {
         candidate: AccountId,
         proposer: AccountId,
         current_block: BlockNumber,
+    ) -> Result<(), ValidatorError> {
+        self.propose_early_validator_with_metadata(
+            candidate,
+            proposer,
+            CandidateMetadata::default(),
+            current_block,
+        )
+    }
+
+    /// Propose a new early validator candidate with display name / contact URL
+    pub fn propose_early_validator_with_metadata(
+        &mut self,
+        candidate: AccountId,
+        proposer: AccountId,
+        metadata: CandidateMetadata,
+        current_block: BlockNumber,
     ) -> Result<(), ValidatorError> {
         // Security: Only during bootstrap era
         if !Self::is_bootstrap_era(current_block) {
@@ -651,6 +678,11 @@ impl ValidatorSet {
             return Err(ValidatorError::CandidacyExists);
         }
 
+        // Security: Bounded, printable metadata only
+        metadata
+            .validate()
+            .map_err(|reason| ValidatorError::InvalidCandidateMetadata(reason.to_string()))?;
+
         // Create candidacy with calculated vote threshold
         let votes_required = self.votes_required_for_new_validator();
         let candidacy = EarlyValidatorCandidate::new(
@@ -658,7 +690,8 @@ impl ValidatorSet {
             proposer,
             votes_required,
             current_block,
-        );
+        )
+        .with_metadata(metadata);
 
         self.early_candidates.insert(candidate, candidacy);
 
@@ -862,6 +895,9 @@ pub enum ValidatorError {
     #[error("Voter has already voted for this candidate")]
     AlreadyVoted,
 
+    #[error("Invalid candidate metadata: {0}")]
+    InvalidCandidateMetadata(String),
+
     #[error("Insufficient votes for approval")]
     InsufficientVotes,
 }
@@ -1350,6 +1386,32 @@ mod tests {
         assert_eq!(pending[0].candidate, c2);
     }
 
+    #[test]
+    fn test_early_validator_candidate_metadata() {
+        let mut set = ValidatorSet::new();
+
+        let bootstrap = AccountId::from_bytes([1; 32]);
+        set.add_validator(ValidatorInfo::new_bootstrap(bootstrap, 0)).unwrap();
+
+        let c1 = AccountId::from_bytes([10; 32]);
+        let metadata = CandidateMetadata {
+            display_name: Some("Alice Node".to_string()),
+            url: Some("https://alice.example".to_string()),
+        };
+        set.propose_early_validator_with_metadata(c1, bootstrap, metadata.clone(), 100).unwrap();
+        assert_eq!(set.get_candidate(&c1).unwrap().metadata, metadata);
+
+        // Invalid metadata is rejected and no candidacy is created
+        let c2 = AccountId::from_bytes([11; 32]);
+        let bad = CandidateMetadata {
+            display_name: None,
+            url: Some("ftp://alice.example".to_string()),
+        };
+        let result = set.propose_early_validator_with_metadata(c2, bootstrap, bad, 101);
+        assert!(matches!(result.unwrap_err(), ValidatorError::InvalidCandidateMetadata(_)));
+        assert!(set.get_candidate(&c2).is_none());
+    }
+
     #[test]
     fn test_cleanup_expired_candidacies() {
         let mut set = ValidatorSet::new();
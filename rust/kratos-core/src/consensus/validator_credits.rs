@@ -3,7 +3,7 @@
 
 use crate::types::{AccountId, BlockNumber, EpochNumber};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Time windows for anti-spam (in epochs)
 /// With 1 epoch = 1 hour (600 blocks at 6s/slot):
@@ -11,6 +11,9 @@ const EPOCHS_PER_DAY: u64 = 24;    // 24 hours = 24 epochs
 const EPOCHS_PER_MONTH: u64 = 720; // 30 days × 24 hours = 720 epochs
 const EPOCHS_PER_YEAR: u64 = 8_760; // 365 days × 24 hours = 8,760 epochs
 
+/// Minimum participation rate for an uptime credit
+pub const MIN_UPTIME_PARTICIPATION: f64 = 0.95;
+
 /// Validator Credits Record
 /// Non-transferable credits earned through participation
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -174,7 +177,7 @@ impl ValidatorCreditsRecord {
         participation_rate: f64,
         multiplier: u32,
     ) -> Result<bool, VCError> {
-        if participation_rate < 0.95 {
+        if participation_rate < MIN_UPTIME_PARTICIPATION {
             return Ok(false);
         }
 
@@ -233,6 +236,84 @@ impl ValidatorCreditsRecord {
     }
 }
 
+/// Epochs of VC history kept per validator
+pub const VC_HISTORY_EPOCHS: usize = 64;
+
+/// VC movements of a validator during one epoch
+///
+/// VC does not decay with time: the only deductions are slashes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VcEpochChange {
+    /// Credits earned from governance votes
+    pub vote: u32,
+    /// Credits earned from uptime
+    pub uptime: u32,
+    /// Credits earned from accepted arbitrations
+    pub arbitration: u32,
+    /// Credits earned from seniority
+    pub seniority: u32,
+    /// Credits removed (slashing)
+    pub deducted: u64,
+    /// Total VC after the last change of the epoch
+    pub total: u64,
+}
+
+/// Per-epoch VC history of a validator (last VC_HISTORY_EPOCHS epochs with a change)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VcHistory {
+    pub epochs: BTreeMap<EpochNumber, VcEpochChange>,
+}
+
+impl VcHistory {
+    /// Record the change from `before` to `after` in `epoch`
+    ///
+    /// Returns false if no credit moved.
+    pub fn record(
+        &mut self,
+        epoch: EpochNumber,
+        before: Option<&ValidatorCreditsRecord>,
+        after: &ValidatorCreditsRecord,
+    ) -> bool {
+        let before = before.map_or([0; 4], |r| {
+            [r.vote_credits, r.uptime_credits, r.arbitration_credits, r.seniority_credits]
+        });
+        let after_sources = [
+            after.vote_credits,
+            after.uptime_credits,
+            after.arbitration_credits,
+            after.seniority_credits,
+        ];
+        if before == after_sources {
+            return false;
+        }
+
+        let entry = self.epochs.entry(epoch).or_default();
+        let earned = |i: usize| after_sources[i].saturating_sub(before[i]);
+        entry.vote = entry.vote.saturating_add(earned(0));
+        entry.uptime = entry.uptime.saturating_add(earned(1));
+        entry.arbitration = entry.arbitration.saturating_add(earned(2));
+        entry.seniority = entry.seniority.saturating_add(earned(3));
+        let deducted: u64 = (0..4).map(|i| before[i].saturating_sub(after_sources[i]) as u64).sum();
+        entry.deducted = entry.deducted.saturating_add(deducted);
+        entry.total = after.total_vc();
+
+        while self.epochs.len() > VC_HISTORY_EPOCHS {
+            self.epochs.pop_first();
+        }
+        true
+    }
+
+    /// Latest `count` epochs with a change, newest first
+    pub fn latest(&self, count: usize) -> impl Iterator<Item = (&EpochNumber, &VcEpochChange)> {
+        self.epochs.iter().rev().take(count)
+    }
+
+    /// Credits removed over the recorded epochs
+    pub fn deducted(&self) -> u64 {
+        self.epochs.values().map(|e| e.deducted).sum()
+    }
+}
+
 /// Validator Credits Manager
 /// Manages VC records for all validators
 pub struct ValidatorCreditsManager {
@@ -532,6 +613,40 @@ mod tests {
         assert_eq!(manager.get_total_vc(&validator), 7);
     }
 
+    #[test]
+    fn test_vc_history() {
+        let mut history = VcHistory::default();
+        let mut record = ValidatorCreditsRecord::new(0, 0);
+        record.uptime_credits = 100;
+        assert!(history.record(0, None, &record));
+
+        let before = record.clone();
+        record.add_uptime_credit(700, 1.0).unwrap();
+        record.add_arbitration_credit(700, 1).unwrap();
+        assert!(history.record(1, Some(&before), &record));
+        assert!(!history.record(1, Some(&record), &record));
+
+        // A slash shows as a deduction, not as negative accrual
+        let before = record.clone();
+        record.uptime_credits -= 30;
+        assert!(history.record(1, Some(&before), &record));
+
+        let epoch = history.epochs[&1];
+        assert_eq!((epoch.uptime, epoch.arbitration, epoch.deducted), (1, 5, 30));
+        assert_eq!(epoch.total, 76);
+        assert_eq!(history.deducted(), 30);
+        assert_eq!(history.latest(1).map(|(e, _)| *e).collect::<Vec<_>>(), vec![1]);
+
+        // Only the latest epochs are kept
+        for epoch in 2..(VC_HISTORY_EPOCHS as u64 + 10) {
+            let before = record.clone();
+            record.vote_credits += 1;
+            history.record(epoch, Some(&before), &record);
+        }
+        assert_eq!(history.epochs.len(), VC_HISTORY_EPOCHS);
+        assert_eq!(history.deducted(), 0);
+    }
+
     // ========================================================================
     // SECURITY INVARIANT TESTS
     // ========================================================================
@@ -24,7 +24,7 @@ pub struct ValidatorInfo {
 /// Arbitration contract for cross-chain dispute resolution
 pub struct ArbitrationContract {
     /// Persistent storage
-    db: Database,
+    db: Box<dyn Database>,
 
     /// In-memory dispute cache
     disputes: HashMap<DisputeId, Dispute>,
@@ -44,9 +44,9 @@ pub struct ArbitrationContract {
 
 impl ArbitrationContract {
     /// Create a new arbitration contract
-    pub fn new(db: Database, validator_credits: ValidatorCreditsManager) -> Self {
+    pub fn new<D: Database + 'static>(db: D, validator_credits: ValidatorCreditsManager) -> Self {
         Self {
-            db,
+            db: Box::new(db),
             disputes: HashMap::new(),
             validators: HashMap::new(),
             validator_credits,
@@ -711,12 +711,13 @@ impl ArbitrationContract {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{DisputeType, FraudProof, Hash};
+    use crate::storage::RocksDatabase;
+    use crate::types::{DisputeType, FraudProof, Hash, ProtocolVersion};
     use tempfile::TempDir;
 
     fn setup_arbitration() -> (ArbitrationContract, TempDir) {
         let temp_dir = TempDir::new().unwrap();
-        let db = Database::open(temp_dir.path()).unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
         let validator_credits = ValidatorCreditsManager::new();
 
         let contract = ArbitrationContract::new(db, validator_credits);
@@ -730,10 +731,12 @@ mod tests {
             number: 500,
             parent_hash: Hash::from_bytes([0; 32]),
             state_root: Hash::from_bytes([1; 32]),
+            receipts_root: Hash::ZERO,
             transactions_root: Hash::from_bytes([2; 32]),
             timestamp: 1000,
             epoch: 0,
             slot: 0,
+            protocol_version: ProtocolVersion::default(),
             author: AccountId::from_bytes([0; 32]),
             signature: Signature64::zero(),
         };
@@ -742,10 +745,12 @@ mod tests {
             number: 500, // Same height
             parent_hash: Hash::from_bytes([0; 32]),
             state_root: Hash::from_bytes([3; 32]), // Different state
+            receipts_root: Hash::ZERO,
             transactions_root: Hash::from_bytes([4; 32]),
             timestamp: 1000,
             epoch: 0,
             slot: 0,
+            protocol_version: ProtocolVersion::default(),
             author: AccountId::from_bytes([0; 32]),
             signature: Signature64::zero(),
         };
@@ -1057,7 +1062,7 @@ mod tests {
         let temp_dir = TempDir::new().unwrap();
 
         {
-            let db = Database::open(temp_dir.path()).unwrap();
+            let db = RocksDatabase::open(temp_dir.path()).unwrap();
             let validator_credits = ValidatorCreditsManager::new();
             let mut contract = ArbitrationContract::new(db, validator_credits);
 
@@ -1074,7 +1079,7 @@ mod tests {
 
         // Reload from disk
         {
-            let db = Database::open(temp_dir.path()).unwrap();
+            let db = RocksDatabase::open(temp_dir.path()).unwrap();
             let validator_credits = ValidatorCreditsManager::new();
             let mut contract = ArbitrationContract::new(db, validator_credits);
 
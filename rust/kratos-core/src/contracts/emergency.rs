@@ -6,16 +6,21 @@
 // - Circuit breaker activation and reset
 // - Failure signal detection and aggregation
 // - Recovery coordination
+// - The on-chain declaration: validators co-sign an emergency while its
+//   circuit breakers are armed (SignEmergencyDeclaration) and co-sign its
+//   recovery (SignEmergencyRecovery); see EmergencyDeclaration
 
+use crate::types::protocol::MIN_SUPERMAJORITY;
 use crate::types::{
-    AccountId, Balance, BlockNumber, ChainId, Hash,
+    AccountId, Balance, BlockNumber, ChainId, Hash, TransactionCall,
     EmergencyState, EmergencyTrigger, EmergencyAction, ConsensusFailureType,
     FailureSignal, CircuitBreaker, BreakerCondition, BreakerAction,
     RecoveryProcess, RecoveryStep, Checkpoint, ParameterTightening,
     EMERGENCY_APPROVAL_THRESHOLD, EMERGENCY_MAX_DURATION, MIN_SIGNALS_FOR_EMERGENCY,
     MIN_SEVERITY_FOR_EMERGENCY, DEFAULT_BREAKER_DURATION,
 };
-use std::collections::{HashMap, HashSet};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 // =============================================================================
 // CONSTANTS
@@ -39,6 +44,10 @@ pub const CHECKPOINT_INTERVAL: BlockNumber = 1_000;
 /// Maximum checkpoints to keep
 pub const MAX_CHECKPOINTS: usize = 100;
 
+/// Validators co-signing the recovery of a declared emergency (percent)
+/// 2/3 supermajority per Constitution Article III
+pub const EMERGENCY_RECOVERY_THRESHOLD: u8 = MIN_SUPERMAJORITY;
+
 // =============================================================================
 // EMERGENCY CONTRACT
 // =============================================================================
@@ -167,54 +176,70 @@ pub enum EmergencyEvent {
 // ERRORS
 // =============================================================================
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum EmergencyError {
     /// Emergency already active
+    #[error("Emergency already active")]
     AlreadyActive,
 
     /// No emergency active
+    #[error("No emergency active")]
     NotActive,
 
     /// Insufficient approval
+    #[error("Insufficient approval: {have}% of {need}%")]
     InsufficientApproval { have: u8, need: u8 },
 
     /// In cooldown period
+    #[error("Emergency cooldown until block {ends_at}")]
     InCooldown { ends_at: BlockNumber },
 
     /// Already voted
+    #[error("Already signed")]
     AlreadyVoted,
 
     /// No voting power
+    #[error("No voting power")]
     NoVotingPower,
 
     /// Invalid action
+    #[error("Invalid action: {0}")]
     InvalidAction(String),
 
     /// Action forbidden during emergency
+    #[error("Forbidden during an emergency: {0}")]
     ForbiddenAction(String),
 
     /// Breaker not found
+    #[error("Circuit breaker not found: {0}")]
     BreakerNotFound(String),
 
     /// Too many breakers
+    #[error("Too many circuit breakers")]
     TooManyBreakers,
 
     /// Breaker already active
+    #[error("Circuit breaker already active: {0}")]
     BreakerAlreadyActive(String),
 
     /// Recovery already in progress
+    #[error("Recovery already in progress")]
     RecoveryInProgress,
 
     /// No recovery in progress
+    #[error("No recovery in progress")]
     NoRecoveryInProgress,
 
     /// Invalid recovery step
+    #[error("Invalid recovery step")]
     InvalidRecoveryStep,
 
     /// Checkpoint not found
+    #[error("Checkpoint not found")]
     CheckpointNotFound,
 
     /// Insufficient confirmations
+    #[error("Insufficient confirmations: {have} of {need}")]
     InsufficientConfirmations { have: u32, need: u32 },
 }
 
@@ -959,6 +984,144 @@ impl Default for EmergencyContract {
     }
 }
 
+// =============================================================================
+// ON-CHAIN DECLARATION
+// =============================================================================
+
+/// Emergency declared on chain by validator co-signatures
+///
+/// A declaration takes EMERGENCY_APPROVAL_THRESHOLD percent of the validators
+/// within EMERGENCY_VOTING_PERIOD of its first signature, and lasts
+/// EMERGENCY_MAX_DURATION unless EMERGENCY_RECOVERY_THRESHOLD percent co-sign
+/// its recovery first. A new one waits EMERGENCY_COOLDOWN after the last.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmergencyDeclaration {
+    /// Validators who co-signed the pending or declared emergency
+    pub declaring: BTreeSet<AccountId>,
+
+    /// Block of the first signature of the pending declaration
+    pub opened_at: Option<BlockNumber>,
+
+    /// Block the declaration reached its threshold
+    pub declared_at: Option<BlockNumber>,
+
+    /// Validators who co-signed the recovery of the declared emergency
+    pub recovering: BTreeSet<AccountId>,
+
+    /// Block the last emergency was recovered
+    pub recovered_at: Option<BlockNumber>,
+}
+
+impl EmergencyDeclaration {
+    /// Block the declared emergency expires
+    pub fn expires_at(&self) -> Option<BlockNumber> {
+        self.declared_at.map(|declared| declared.saturating_add(EMERGENCY_MAX_DURATION))
+    }
+
+    /// Declared, and neither recovered nor expired
+    pub fn is_active(&self, current_block: BlockNumber) -> bool {
+        self.expires_at().is_some_and(|expires| current_block < expires)
+    }
+
+    /// Block the last emergency ended (recovered or expired)
+    pub fn ended_at(&self, current_block: BlockNumber) -> Option<BlockNumber> {
+        match self.expires_at() {
+            Some(expires) if current_block >= expires => Some(expires),
+            _ => self.recovered_at,
+        }
+    }
+
+    /// Signatures of the pending declaration, none once its voting period is over
+    pub fn pending_signatures(&self, current_block: BlockNumber) -> usize {
+        match self.opened_at {
+            Some(opened) if self.declared_at.is_none() && current_block < opened + EMERGENCY_VOTING_PERIOD => {
+                self.declaring.len()
+            }
+            _ => 0,
+        }
+    }
+
+    /// Co-signatures reaching `threshold` percent of `validators`
+    pub fn required_signatures(validators: u32, threshold: u8) -> u32 {
+        (validators.saturating_mul(threshold as u32).div_ceil(100)).max(1)
+    }
+
+    /// Co-sign the declaration, returning true if it declared the emergency
+    pub fn sign_declaration(
+        &mut self,
+        validator: AccountId,
+        validators: u32,
+        current_block: BlockNumber,
+    ) -> Result<bool, EmergencyError> {
+        if self.is_active(current_block) {
+            return Err(EmergencyError::AlreadyActive);
+        }
+        let last_ended = self.ended_at(current_block);
+        if let Some(ended) = last_ended {
+            if current_block < ended + EMERGENCY_COOLDOWN {
+                return Err(EmergencyError::InCooldown { ends_at: ended + EMERGENCY_COOLDOWN });
+            }
+        }
+        // A new declaration: the expired one and stale signatures are dropped
+        if self.pending_signatures(current_block) == 0 {
+            *self = Self { recovered_at: last_ended, ..Self::default() };
+        }
+
+        if !self.declaring.insert(validator) {
+            return Err(EmergencyError::AlreadyVoted);
+        }
+        self.opened_at.get_or_insert(current_block);
+        if self.declaring.len() as u32 >= Self::required_signatures(validators, EMERGENCY_APPROVAL_THRESHOLD) {
+            self.declared_at = Some(current_block);
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Co-sign the recovery of the declared emergency, returning true if it
+    /// ended the emergency
+    pub fn sign_recovery(
+        &mut self,
+        validator: AccountId,
+        validators: u32,
+        current_block: BlockNumber,
+    ) -> Result<bool, EmergencyError> {
+        if !self.is_active(current_block) {
+            return Err(EmergencyError::NotActive);
+        }
+        if !self.recovering.insert(validator) {
+            return Err(EmergencyError::AlreadyVoted);
+        }
+        if self.recovering.len() as u32 >= Self::required_signatures(validators, EMERGENCY_RECOVERY_THRESHOLD) {
+            *self = Self { recovered_at: Some(current_block), ..Self::default() };
+            return Ok(true);
+        }
+        Ok(false)
+    }
+}
+
+/// Whether a transaction may enter the mempool during an emergency
+///
+/// SPEC v7: exits, transfers, staking (validators are what ends an
+/// emergency), forks and identities stay open. Governance is frozen, new
+/// sidechains are halted and slashing does not escalate.
+pub fn allowed_during_emergency(call: &TransactionCall) -> Result<(), EmergencyError> {
+    let forbidden = match call {
+        TransactionCall::ProposeEarlyValidator { .. }
+        | TransactionCall::VoteEarlyValidator { .. }
+        | TransactionCall::SubmitProposal { .. }
+        | TransactionCall::SecondProposal { .. }
+        | TransactionCall::VoteProposal { .. }
+        | TransactionCall::ExecuteProposal { .. } => "governance is frozen",
+        TransactionCall::CreateSidechain { .. } => "sidechain creation is halted",
+        TransactionCall::ReportEquivocation { .. } | TransactionCall::SubmitFraudProof { .. } => {
+            "slashing is halted"
+        }
+        _ => return Ok(()),
+    };
+    Err(EmergencyError::ForbiddenAction(forbidden.to_string()))
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -1367,4 +1530,63 @@ mod tests {
         let active = contract.active_breakers();
         assert_eq!(active.len(), 2);
     }
+
+    #[test]
+    fn test_emergency_declaration() {
+        let mut declaration = EmergencyDeclaration::default();
+
+        // 3 of 4 validators (75%)
+        assert_eq!(declaration.sign_declaration(create_account(1), 4, 100), Ok(false));
+        assert_eq!(declaration.sign_declaration(create_account(1), 4, 101), Err(EmergencyError::AlreadyVoted));
+        assert_eq!(declaration.sign_declaration(create_account(2), 4, 102), Ok(false));
+
+        // Signatures older than the voting period are dropped
+        let late = 100 + EMERGENCY_VOTING_PERIOD;
+        assert_eq!(declaration.sign_declaration(create_account(3), 4, late), Ok(false));
+        assert_eq!(declaration.pending_signatures(late), 1);
+        assert_eq!(declaration.sign_declaration(create_account(1), 4, late + 1), Ok(false));
+        assert_eq!(declaration.sign_declaration(create_account(2), 4, late + 2), Ok(true));
+        assert!(declaration.is_active(late + 2));
+        assert_eq!(declaration.expires_at(), Some(late + 2 + EMERGENCY_MAX_DURATION));
+        assert_eq!(declaration.sign_declaration(create_account(4), 4, late + 3), Err(EmergencyError::AlreadyActive));
+    }
+
+    #[test]
+    fn test_emergency_recovery() {
+        let mut declaration = EmergencyDeclaration::default();
+        assert_eq!(declaration.sign_recovery(create_account(1), 3, 100), Err(EmergencyError::NotActive));
+        for i in 1..=3 {
+            declaration.sign_declaration(create_account(i), 3, 100).unwrap();
+        }
+
+        // 2 of 3 validators (66%)
+        assert_eq!(declaration.sign_recovery(create_account(1), 3, 200), Ok(false));
+        assert_eq!(declaration.sign_recovery(create_account(2), 3, 201), Ok(true));
+        assert!(!declaration.is_active(201));
+        assert_eq!(
+            declaration.sign_declaration(create_account(1), 3, 300),
+            Err(EmergencyError::InCooldown { ends_at: 201 + EMERGENCY_COOLDOWN })
+        );
+
+        // An expired emergency starts its cooldown at expiry
+        for i in 1..=3 {
+            declaration.sign_declaration(create_account(i), 3, 201 + EMERGENCY_COOLDOWN).unwrap();
+        }
+        let expires = declaration.expires_at().unwrap();
+        assert!(!declaration.is_active(expires));
+        assert_eq!(declaration.ended_at(expires), Some(expires));
+        assert!(declaration.sign_declaration(create_account(1), 3, expires + EMERGENCY_COOLDOWN).is_ok());
+        assert_eq!(declaration.pending_signatures(expires + EMERGENCY_COOLDOWN), 1);
+    }
+
+    #[test]
+    fn test_allowed_during_emergency() {
+        assert!(allowed_during_emergency(&TransactionCall::Unstake { amount: 1 }).is_ok());
+        assert!(allowed_during_emergency(&TransactionCall::ExitSidechain { chain_id: ChainId(1) }).is_ok());
+        assert!(allowed_during_emergency(&TransactionCall::SignEmergencyRecovery).is_ok());
+        assert!(matches!(
+            allowed_during_emergency(&TransactionCall::ExecuteProposal { proposal_id: 1 }),
+            Err(EmergencyError::ForbiddenAction(_))
+        ));
+    }
 }
@@ -1,7 +1,8 @@
 // Governance - SPEC v3.1 Phase 6: Voluntary Exit System
 // On-chain voting for sidechain governance decisions
 
-use crate::types::{AccountId, Balance, BlockNumber, ChainId, Hash};
+use crate::contracts::meta_governance::{MAX_BATCH_SIZE, PROTOCOL_UPGRADE_THRESHOLD, PROTOCOL_UPGRADE_TIMELOCK};
+use crate::types::{AccountId, Balance, BlockNumber, ChainId, Hash, ParameterChange, ProtocolParameters};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
@@ -25,6 +26,14 @@ pub const GRACE_PERIOD: BlockNumber = 28_800;          // 2 days grace period
 /// Proposal deposit to prevent spam
 pub const PROPOSAL_DEPOSIT: Balance = 100;
 
+/// Endorsements (besides the proposer) a proposal needs to pass
+pub const MIN_SECONDS: usize = 2;
+
+/// Size bounds of proposals submitted on-chain (see validate_proposal)
+pub const MAX_DESCRIPTION_LEN: usize = 1024;
+pub const MAX_PROPOSAL_TEXT_LEN: usize = 256;
+pub const MAX_PROPOSAL_DATA_LEN: usize = 4096;
+
 /// Type of governance proposal
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ProposalType {
@@ -76,6 +85,13 @@ pub enum ProposalType {
         reason: String,
     },
 
+    /// Change of governed protocol parameters, enacted at the first epoch
+    /// boundary after the timelock (see meta_governance::enact_changes)
+    ProtocolChange {
+        changes: Vec<ParameterChange>,
+        rationale: String,
+    },
+
     /// Custom proposal with arbitrary data
     Custom {
         title: String,
@@ -98,7 +114,10 @@ impl ProposalType {
 
     /// Get the required approval threshold for this proposal type
     pub fn required_threshold(&self) -> u8 {
-        if self.is_exit_proposal() {
+        if matches!(self, ProposalType::ProtocolChange { .. }) {
+            // Power is slow: protocol changes need the meta-governance supermajority
+            PROTOCOL_UPGRADE_THRESHOLD
+        } else if self.is_exit_proposal() {
             SUPERMAJORITY_THRESHOLD
         } else {
             STANDARD_THRESHOLD
@@ -107,7 +126,9 @@ impl ProposalType {
 
     /// Get the timelock period for this proposal type
     pub fn timelock_period(&self) -> BlockNumber {
-        if self.is_exit_proposal() {
+        if matches!(self, ProposalType::ProtocolChange { .. }) {
+            PROTOCOL_UPGRADE_TIMELOCK
+        } else if self.is_exit_proposal() {
             EXIT_TIMELOCK
         } else {
             STANDARD_TIMELOCK
@@ -207,6 +228,12 @@ pub struct Proposal {
 
     /// Block when proposal was executed (if executed)
     pub executed_at: Option<BlockNumber>,
+
+    /// Accounts that endorsed the proposal
+    pub seconds: Vec<AccountId>,
+
+    /// Total voting power when the proposal was submitted (quorum base)
+    pub eligible_votes: Balance,
 }
 
 impl Proposal {
@@ -237,6 +264,8 @@ impl Proposal {
             deposit,
             deposit_returned: false,
             executed_at: None,
+            seconds: Vec::new(),
+            eligible_votes: 0,
         }
     }
 
@@ -280,6 +309,148 @@ impl Proposal {
         self.quorum_reached(total_eligible_votes)
             && self.approval_percentage() >= self.proposal_type.required_threshold()
     }
+
+    /// Endorse the proposal, once per account other than the proposer
+    pub fn second(&mut self, who: AccountId, current_block: BlockNumber) -> Result<(), GovernanceError> {
+        if !self.is_voting_open(current_block) {
+            return Err(GovernanceError::VotingClosed);
+        }
+        if who == self.proposer {
+            return Err(GovernanceError::CannotSecondOwnProposal);
+        }
+        if self.seconds.contains(&who) {
+            return Err(GovernanceError::AlreadySeconded);
+        }
+        self.seconds.push(who);
+        Ok(())
+    }
+
+    /// Record a vote carrying `weight` voting power
+    pub fn cast(
+        &mut self,
+        voter: AccountId,
+        vote: Vote,
+        weight: Balance,
+        current_block: BlockNumber,
+    ) -> Result<(), GovernanceError> {
+        if !self.is_voting_open(current_block) {
+            return Err(GovernanceError::VotingClosed);
+        }
+        if self.has_voted(&voter) {
+            return Err(GovernanceError::AlreadyVoted);
+        }
+        if weight == 0 {
+            return Err(GovernanceError::NoVotingPower);
+        }
+
+        match vote {
+            Vote::Yes => self.yes_votes = self.yes_votes.saturating_add(weight),
+            Vote::No => self.no_votes = self.no_votes.saturating_add(weight),
+            Vote::Abstain => self.abstain_votes = self.abstain_votes.saturating_add(weight),
+        }
+        self.votes.push(VoteRecord {
+            voter,
+            vote,
+            weight,
+            timestamp: current_block,
+        });
+        Ok(())
+    }
+
+    /// Endorsed, quorum of `eligible_votes` and threshold reached
+    pub fn carried(&self) -> bool {
+        self.seconds.len() >= MIN_SECONDS && self.passed(self.eligible_votes)
+    }
+
+    /// End of the timelock once voting closed
+    ///
+    /// The base period of the proposal type times `timelock_multiplier`
+    /// (DegradedSecurityConfig: ×2 in Degraded). None while governance is
+    /// frozen (multiplier 0): the timelock does not run.
+    pub fn timelock_end(&self, timelock_multiplier: u32) -> Option<BlockNumber> {
+        if timelock_multiplier == 0 {
+            return None;
+        }
+        let timelock = self.proposal_type.timelock_period().saturating_mul(timelock_multiplier as BlockNumber);
+        Some(self.voting_ends_at.saturating_add(timelock))
+    }
+
+    /// Status at `current_block` of a proposal stored on-chain
+    ///
+    /// Stored proposals stay Active: the outcome follows from the votes once
+    /// voting closes, and the timelock from the current security state.
+    pub fn status_at(&self, current_block: BlockNumber, timelock_multiplier: u32) -> ProposalStatus {
+        if self.status != ProposalStatus::Active {
+            return self.status;
+        }
+        if current_block <= self.voting_ends_at {
+            return ProposalStatus::Active;
+        }
+        if !self.carried() {
+            return ProposalStatus::Rejected;
+        }
+        match self.timelock_end(timelock_multiplier) {
+            Some(end) if current_block >= end.saturating_add(GRACE_PERIOD) => ProposalStatus::Expired,
+            Some(end) if current_block >= end => ProposalStatus::ReadyToExecute,
+            _ => ProposalStatus::Passed,
+        }
+    }
+
+    /// Enact a stored proposal once its timelock has run out
+    ///
+    /// The chain applies the base timelock: the security state is node-local,
+    /// it only holds back the submission of the execution.
+    pub fn enact(&mut self, current_block: BlockNumber) -> Result<(), GovernanceError> {
+        match self.status_at(current_block, 1) {
+            ProposalStatus::ReadyToExecute => {}
+            ProposalStatus::Executed => return Err(GovernanceError::AlreadyFinalized),
+            _ => return Err(GovernanceError::NotReadyToExecute),
+        }
+        self.status = ProposalStatus::Executed;
+        self.executed_at = Some(current_block);
+        Ok(())
+    }
+}
+
+/// Check a proposal submitted on-chain (pool admission and execution)
+///
+/// Exit and affiliation proposals concern sidechains, not the root chain.
+pub fn validate_proposal(proposal_type: &ProposalType, description: Option<&str>) -> Result<(), GovernanceError> {
+    if proposal_type.is_exit_proposal()
+        || matches!(proposal_type, ProposalType::LeaveHost | ProposalType::RequestAffiliation { .. })
+    {
+        return Err(GovernanceError::UnsupportedProposal);
+    }
+    let too_long = |text: &str| text.len() > MAX_PROPOSAL_TEXT_LEN;
+    let oversized = description.is_some_and(|d| d.len() > MAX_DESCRIPTION_LEN)
+        || match proposal_type {
+            ProposalType::ParameterChange { parameter, old_value, new_value } => {
+                too_long(parameter) || too_long(old_value) || too_long(new_value)
+            }
+            ProposalType::TreasurySpend { reason, .. } => too_long(reason),
+            ProposalType::ProtocolChange { changes, rationale } => {
+                changes.is_empty() || changes.len() > MAX_BATCH_SIZE || rationale.len() > MAX_DESCRIPTION_LEN
+            }
+            ProposalType::Custom { title, description, data } => {
+                too_long(title) || description.len() > MAX_DESCRIPTION_LEN || data.len() > MAX_PROPOSAL_DATA_LEN
+            }
+            _ => false,
+        };
+    if oversized {
+        return Err(GovernanceError::ProposalTooLarge);
+    }
+    if let ProposalType::ProtocolChange { changes, .. } = proposal_type {
+        // Only parameters block production reads can be governed
+        if !changes.iter().all(ParameterChange::is_enforced) {
+            return Err(GovernanceError::UnsupportedProposal);
+        }
+        // Checked against genesis here (upgrades: against the version in force at enactment)
+        let genesis = ProtocolParameters::genesis();
+        if !changes.iter().all(|change| change.is_constitutional(&genesis)) {
+            return Err(GovernanceError::ConstitutionalViolation);
+        }
+    }
+    Ok(())
 }
 
 /// Governance contract for a chain
@@ -461,23 +632,7 @@ impl GovernanceContract {
             .get_mut(&proposal_id)
             .ok_or(GovernanceError::ProposalNotFound)?;
 
-        // Record vote
-        let vote_record = VoteRecord {
-            voter,
-            vote,
-            weight,
-            timestamp: current_block,
-        };
-
-        match vote {
-            Vote::Yes => proposal.yes_votes += weight,
-            Vote::No => proposal.no_votes += weight,
-            Vote::Abstain => proposal.abstain_votes += weight,
-        }
-
-        proposal.votes.push(vote_record);
-
-        Ok(())
+        proposal.cast(voter, vote, weight, current_block)
     }
 
     /// Finalize voting and determine outcome
@@ -778,6 +933,27 @@ pub enum GovernanceError {
 
     #[error("Insufficient deposit")]
     InsufficientDeposit,
+
+    #[error("Already seconded this proposal")]
+    AlreadySeconded,
+
+    #[error("Cannot second own proposal")]
+    CannotSecondOwnProposal,
+
+    #[error("Proposal exceeds the size limits")]
+    ProposalTooLarge,
+
+    #[error("Proposal type not supported on the root chain")]
+    UnsupportedProposal,
+
+    #[error("Governance is frozen in the current security state")]
+    GovernanceFrozen,
+
+    #[error("Proposal has no on-chain effect to execute")]
+    NotExecutable,
+
+    #[error("Parameter change outside the constitutional bounds")]
+    ConstitutionalViolation,
 }
 
 #[cfg(test)]
@@ -1139,4 +1315,88 @@ mod tests {
         assert_eq!(ProposalType::ExitReattachRoot.required_threshold(), SUPERMAJORITY_THRESHOLD);
         assert_eq!(ProposalType::ExitJoinHost { host_chain: ChainId(1) }.required_threshold(), SUPERMAJORITY_THRESHOLD);
     }
+
+    #[test]
+    fn test_stored_proposal_status() {
+        let (alice, bob, charlie) = (AccountId::from_bytes([1; 32]), AccountId::from_bytes([2; 32]), AccountId::from_bytes([3; 32]));
+        let mut proposal = Proposal::new(
+            1,
+            ChainId::ROOT,
+            alice,
+            ProposalType::AddValidator { validator: AccountId::from_bytes([10; 32]) },
+            None,
+            PROPOSAL_DEPOSIT,
+            1000,
+        );
+        proposal.eligible_votes = 3000;
+
+        assert!(matches!(proposal.second(alice, 1001), Err(GovernanceError::CannotSecondOwnProposal)));
+        proposal.second(bob, 1001).unwrap();
+        assert!(matches!(proposal.second(bob, 1002), Err(GovernanceError::AlreadySeconded)));
+        proposal.cast(alice, Vote::Yes, 1000, 1002).unwrap();
+        proposal.cast(bob, Vote::No, 500, 1002).unwrap();
+        assert!(matches!(proposal.cast(charlie, Vote::Yes, 0, 1003), Err(GovernanceError::NoVotingPower)));
+
+        // One endorsement short
+        let closed = proposal.voting_ends_at + 1;
+        assert_eq!(proposal.status_at(1003, 1), ProposalStatus::Active);
+        assert_eq!(proposal.status_at(closed, 1), ProposalStatus::Rejected);
+        proposal.second(charlie, 1003).unwrap();
+        assert_eq!(proposal.status_at(closed, 1), ProposalStatus::Passed);
+
+        // Degraded doubles the timelock, frozen governance stops it
+        let end = proposal.voting_ends_at + STANDARD_TIMELOCK;
+        assert_eq!(proposal.timelock_end(2), Some(proposal.voting_ends_at + 2 * STANDARD_TIMELOCK));
+        assert_eq!(proposal.status_at(end, 1), ProposalStatus::ReadyToExecute);
+        assert_eq!(proposal.status_at(end, 2), ProposalStatus::Passed);
+        assert_eq!(proposal.status_at(end + GRACE_PERIOD, 1), ProposalStatus::Expired);
+        assert_eq!(proposal.timelock_end(0), None);
+        assert_eq!(proposal.status_at(end + GRACE_PERIOD, 0), ProposalStatus::Passed);
+
+        // Enacted once, within the grace period of the base timelock
+        assert!(matches!(proposal.clone().enact(end - 1), Err(GovernanceError::NotReadyToExecute)));
+        assert!(matches!(proposal.clone().enact(end + GRACE_PERIOD), Err(GovernanceError::NotReadyToExecute)));
+        proposal.enact(end).unwrap();
+        assert_eq!(proposal.executed_at, Some(end));
+        assert_eq!(proposal.status_at(end + 1, 2), ProposalStatus::Executed);
+        assert!(matches!(proposal.enact(end + 1), Err(GovernanceError::AlreadyFinalized)));
+    }
+
+    #[test]
+    fn test_validate_proposal() {
+        let spend = ProposalType::TreasurySpend {
+            recipient: AccountId::from_bytes([4; 32]),
+            amount: 10,
+            reason: "audit".to_string(),
+        };
+        assert!(validate_proposal(&spend, Some("Fund the audit")).is_ok());
+        assert!(matches!(
+            validate_proposal(&spend, Some(&"x".repeat(MAX_DESCRIPTION_LEN + 1))),
+            Err(GovernanceError::ProposalTooLarge)
+        ));
+        let custom = ProposalType::Custom {
+            title: "t".to_string(),
+            description: String::new(),
+            data: vec![0; MAX_PROPOSAL_DATA_LEN + 1],
+        };
+        assert!(matches!(validate_proposal(&custom, None), Err(GovernanceError::ProposalTooLarge)));
+        assert!(matches!(
+            validate_proposal(&ProposalType::ExitDissolve, None),
+            Err(GovernanceError::UnsupportedProposal)
+        ));
+
+        let protocol = |changes| ProposalType::ProtocolChange { changes, rationale: "fees".to_string() };
+        let bounds = ParameterChange::InflationBounds { floor_bps: 100, cap_bps: 800 };
+        assert!(validate_proposal(&protocol(vec![bounds.clone()]), None).is_ok());
+        assert_eq!(protocol(vec![bounds]).required_threshold(), PROTOCOL_UPGRADE_THRESHOLD);
+        assert!(matches!(
+            validate_proposal(&protocol(vec![ParameterChange::InflationRate(3)]), None),
+            Err(GovernanceError::UnsupportedProposal)
+        ));
+        assert!(matches!(
+            validate_proposal(&protocol(vec![ParameterChange::InflationBounds { floor_bps: 0, cap_bps: 5_000 }]), None),
+            Err(GovernanceError::ConstitutionalViolation)
+        ));
+        assert!(matches!(validate_proposal(&protocol(vec![]), None), Err(GovernanceError::ProposalTooLarge)));
+    }
 }
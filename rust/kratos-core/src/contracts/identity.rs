@@ -10,7 +10,7 @@
 use crate::types::identity::{
     AntiSybilConfig, IdentityAttestation, IdentityCommitment, IdentityEvent, IdentityId,
     IdentityStatus, ReputationScore, RevocationReason,
-    calculate_attestation_weight, MIN_ATTESTATIONS_FOR_ACTIVE, MAX_ATTESTATIONS,
+    calculate_attestation_weight, ATTESTATION_EXPIRY, MIN_ATTESTATIONS_FOR_ACTIVE, MAX_ATTESTATIONS,
 };
 use crate::types::{AccountId, Balance, BlockNumber, ChainId, Hash};
 use serde::{Deserialize, Serialize};
@@ -741,8 +741,143 @@ impl Default for IdentityRegistry {
     }
 }
 
+// =============================================================================
+// ON-CHAIN IDENTITIES
+// =============================================================================
+//
+// Root chain identities are registered, attested and revoked by transactions
+// and stored per owner. Like proposals, only revocation is written: the
+// status follows from the expiry and the attestations still valid.
+
+/// Maximum display name length (bytes)
+pub const MAX_DISPLAY_NAME_LEN: usize = 64;
+
+/// Maximum contact hashes per identity
+pub const MAX_CONTACT_HASHES: usize = 8;
+
+/// Identity registered on chain (RegisterIdentity)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegisteredIdentity {
+    /// Commitment to the contact hashes; its status is only set on revocation
+    pub identity: IdentityCommitment,
+
+    /// Hashes of the owner's contact details (the details stay off-chain)
+    pub contact_hashes: Vec<Hash>,
+
+    /// Deposit held while registered, refunded on revocation
+    pub deposit: Balance,
+
+    /// Attestations received, at most one per attester
+    pub attestations: Vec<IdentityAttestation>,
+}
+
+impl RegisteredIdentity {
+    /// Declare the identity of `owner` on the root chain
+    pub fn new(
+        owner: AccountId,
+        display_name: Option<String>,
+        contact_hashes: Vec<Hash>,
+        current_block: BlockNumber,
+    ) -> Self {
+        let commitment_data: Vec<u8> = contact_hashes.iter().flat_map(|h| h.as_bytes().to_vec()).collect();
+        Self {
+            identity: IdentityCommitment::new(owner, ChainId::ROOT, &commitment_data, display_name, current_block),
+            contact_hashes,
+            deposit: IDENTITY_DEPOSIT,
+            attestations: Vec::new(),
+        }
+    }
+
+    /// Owner account
+    pub fn owner(&self) -> AccountId {
+        self.identity.owner
+    }
+
+    /// Attestations still valid at `current_block`
+    pub fn valid_attestations(&self, current_block: BlockNumber) -> usize {
+        self.attestations.iter().filter(|a| a.is_active(current_block)).count()
+    }
+
+    /// Status at `current_block`
+    pub fn status_at(&self, current_block: BlockNumber) -> IdentityStatus {
+        if self.identity.status == IdentityStatus::Revoked {
+            return IdentityStatus::Revoked;
+        }
+        if self.identity.is_expired(current_block) {
+            return IdentityStatus::Expired;
+        }
+        match self.valid_attestations(current_block) {
+            0 => IdentityStatus::Declared,
+            n if n < MIN_ATTESTATIONS_FOR_ACTIVE => IdentityStatus::Attested,
+            _ => IdentityStatus::Active,
+        }
+    }
+
+    /// Record the attestation of `attester`, weighted by its reputation
+    ///
+    /// Lapsed attestations are dropped first, so an attester may renew its own.
+    pub fn attest(
+        &mut self,
+        attester: AccountId,
+        attester_identity: Option<&RegisteredIdentity>,
+        claim_hash: Hash,
+        current_block: BlockNumber,
+    ) -> Result<u8, IdentityError> {
+        if attester == self.owner() {
+            return Err(IdentityError::CannotSelfAttest);
+        }
+        match self.status_at(current_block) {
+            IdentityStatus::Revoked => return Err(IdentityError::IdentityRevoked),
+            status if !status.can_receive_attestations() => return Err(IdentityError::CannotReceiveAttestations),
+            _ => {}
+        }
+        self.attestations.retain(|a| a.is_active(current_block));
+        if self.attestations.iter().any(|a| a.attester == attester) {
+            return Err(IdentityError::AlreadyAttested);
+        }
+        if self.attestations.len() >= MAX_ATTESTATIONS {
+            return Err(IdentityError::MaxAttestationsReached);
+        }
+
+        let reputation = attester_identity.map_or(0, |identity| identity.identity.reputation.score);
+        let weight = calculate_attestation_weight(reputation);
+        self.attestations.push(IdentityAttestation {
+            attester,
+            // Stakers without an identity attest too (they bootstrap the web of trust)
+            attester_identity: attester_identity.map_or(Hash::ZERO, |identity| identity.identity.identity_id),
+            target_identity: self.identity.identity_id,
+            claim_hash,
+            weight,
+            attested_at: current_block,
+            expires_at: current_block + ATTESTATION_EXPIRY,
+            is_valid: true,
+        });
+        Ok(weight)
+    }
+
+    /// Revoke the identity, returning the deposit to refund
+    pub fn revoke(&mut self) -> Result<Balance, IdentityError> {
+        if self.identity.status == IdentityStatus::Revoked {
+            return Err(IdentityError::AlreadyRevoked);
+        }
+        self.identity.status = IdentityStatus::Revoked;
+        Ok(std::mem::take(&mut self.deposit))
+    }
+}
+
+/// Check a registration (pool admission and execution)
+pub fn validate_registration(display_name: Option<&str>, contact_hashes: &[Hash]) -> Result<(), IdentityError> {
+    if display_name.is_some_and(|name| name.is_empty() || name.len() > MAX_DISPLAY_NAME_LEN) {
+        return Err(IdentityError::InvalidDisplayName);
+    }
+    if contact_hashes.len() > MAX_CONTACT_HASHES {
+        return Err(IdentityError::TooManyContacts);
+    }
+    Ok(())
+}
+
 /// Errors that can occur in identity operations
-#[derive(Debug, Clone, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum IdentityError {
     #[error("Identity already exists for this owner")]
     IdentityAlreadyExists,
@@ -773,6 +908,15 @@ pub enum IdentityError {
 
     #[error("Identity already revoked")]
     AlreadyRevoked,
+
+    #[error("Display name must be 1 to 64 bytes")]
+    InvalidDisplayName,
+
+    #[error("At most 8 contact hashes")]
+    TooManyContacts,
+
+    #[error("Attesting requires stake or an active identity")]
+    NotAttester,
 }
 
 // =============================================================================
@@ -1139,4 +1283,35 @@ mod tests {
         registry.clear_events();
         assert!(registry.events().is_empty());
     }
+
+    #[test]
+    fn test_registered_identity_lifecycle() {
+        use crate::types::identity::DEFAULT_IDENTITY_EXPIRY;
+
+        let owner = create_account(1);
+        let mut identity = RegisteredIdentity::new(owner, Some("alice".to_string()), vec![Hash::hash(b"mail")], 100);
+        assert_eq!(identity.status_at(100), IdentityStatus::Declared);
+        assert_eq!(identity.attest(owner, None, Hash::ZERO, 101), Err(IdentityError::CannotSelfAttest));
+
+        for seed in 2..=4 {
+            assert_eq!(identity.attest(create_account(seed), None, Hash::ZERO, 100 + seed as BlockNumber), Ok(1));
+            let expected = if seed < 4 { IdentityStatus::Attested } else { IdentityStatus::Active };
+            assert_eq!(identity.status_at(110), expected);
+        }
+        assert_eq!(identity.attest(create_account(2), None, Hash::ZERO, 110), Err(IdentityError::AlreadyAttested));
+
+        // Attestations lapse, then the identity itself
+        assert_eq!(identity.status_at(103 + ATTESTATION_EXPIRY), IdentityStatus::Attested);
+        assert_eq!(identity.attest(create_account(2), None, Hash::ZERO, 103 + ATTESTATION_EXPIRY), Ok(1));
+        assert_eq!(identity.status_at(100 + DEFAULT_IDENTITY_EXPIRY + 1), IdentityStatus::Expired);
+
+        assert_eq!(identity.revoke(), Ok(IDENTITY_DEPOSIT));
+        assert_eq!(identity.revoke(), Err(IdentityError::AlreadyRevoked));
+        assert_eq!(identity.status_at(200), IdentityStatus::Revoked);
+        assert_eq!(identity.attest(create_account(5), None, Hash::ZERO, 200), Err(IdentityError::IdentityRevoked));
+
+        assert!(validate_registration(Some("alice"), &[Hash::ZERO; MAX_CONTACT_HASHES]).is_ok());
+        assert!(validate_registration(Some(""), &[]).is_err());
+        assert!(validate_registration(None, &[Hash::ZERO; MAX_CONTACT_HASHES + 1]).is_err());
+    }
 }
@@ -1,7 +1,7 @@
 // KRAT Token - System contract pour le token natif
 // Principe: Supply adaptative, emission décroissante, burn automatique
 
-use crate::types::{Balance, BlockNumber, KRAT, MILLIKRAT};
+use crate::types::{AccountId, Balance, BlockNumber, KRAT, MILLIKRAT};
 use serde::{Deserialize, Serialize};
 
 /// Supply initiale: 1 milliard de KRAT
@@ -180,6 +180,50 @@ pub struct EmissionDistribution {
 /// Dépôt existentiel minimum (anti-spam)
 pub const EXISTENTIAL_DEPOSIT: Balance = 1 * MILLIKRAT;
 
+/// Compte du trésor: reçoit 10% des frais (et la part de finalité sans votants)
+/// Aucune clé ne le contrôle: il ne se dépense que par une proposition
+/// TreasurySpend adoptée (voir `treasury_payout`)
+pub const TREASURY_ACCOUNT: [u8; 32] = [
+    0xFE, 0xED, 0xC0, 0xDE, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x54, 0x52, 0x45, 0x41, // "TREA"
+];
+
+/// Compte du trésor
+pub fn treasury_account() -> AccountId {
+    AccountId::from_bytes(TREASURY_ACCOUNT)
+}
+
+/// Erreurs de paiement du trésor
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TreasuryError {
+    #[error("Treasury balance insufficient: need {needed}, have {available}")]
+    InsufficientBalance { needed: Balance, available: Balance },
+
+    #[error("Treasury payout to the treasury itself")]
+    SelfPayout,
+}
+
+/// Paiement d'une dépense approuvée: débite `amount` de `treasury_free`, à
+/// créditer au bénéficiaire
+/// Le trésor ne passe jamais en négatif; un paiement refusé peut être
+/// réessayé tant que la proposition reste exécutable
+pub fn treasury_payout(
+    treasury_free: &mut Balance,
+    recipient: &AccountId,
+    amount: Balance,
+) -> Result<(), TreasuryError> {
+    if *recipient == treasury_account() {
+        return Err(TreasuryError::SelfPayout);
+    }
+    if *treasury_free < amount {
+        return Err(TreasuryError::InsufficientBalance { needed: amount, available: *treasury_free });
+    }
+    *treasury_free -= amount;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -271,4 +315,20 @@ mod tests {
         assert!(state.should_emit(EMISSION_PERIOD_BLOCKS));
         assert!(state.should_emit(EMISSION_PERIOD_BLOCKS + 1000));
     }
+
+    #[test]
+    fn test_treasury_payout() {
+        let recipient = AccountId::from_bytes([7; 32]);
+        let mut treasury = 100 * KRAT;
+
+        assert_eq!(
+            treasury_payout(&mut treasury, &recipient, 101 * KRAT),
+            Err(TreasuryError::InsufficientBalance { needed: 101 * KRAT, available: 100 * KRAT })
+        );
+        assert_eq!(treasury_payout(&mut treasury, &treasury_account(), KRAT), Err(TreasuryError::SelfPayout));
+        assert_eq!(treasury, 100 * KRAT, "Refused payouts leave the treasury untouched");
+
+        treasury_payout(&mut treasury, &recipient, 40 * KRAT).unwrap();
+        assert_eq!(treasury, 60 * KRAT);
+    }
 }
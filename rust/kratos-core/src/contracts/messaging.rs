@@ -5,6 +5,7 @@ use crate::types::{AccountId, Balance, BlockNumber, ChainId, Hash};
 use crate::types::merkle::MerkleProof;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
+use std::ops::Range;
 
 /// Unique identifier for a cross-chain message
 pub type MessageId = u64;
@@ -522,6 +523,79 @@ pub struct MessageStats {
     pub expired: u64,
 }
 
+// =============================================================================
+// ACCOUNT MESSAGES
+// =============================================================================
+//
+// Short messages between accounts of the root chain, paid per byte. The chain
+// never reads the body: `encrypted` only tells the recipient's wallet that it
+// holds ciphertext rather than UTF-8 text. An inbox keeps the latest
+// MAX_INBOX_MESSAGES messages; older ones are pruned as new ones arrive.
+
+/// Maximum size of a message body (bytes)
+pub const MAX_MESSAGE_LEN: usize = 1_024;
+
+/// Fee per body byte, on top of the base message fee
+pub const MESSAGE_BYTE_FEE: Balance = 100;
+
+/// Messages kept per inbox
+pub const MAX_INBOX_MESSAGES: u64 = 256;
+
+/// Message delivered to an account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountMessage {
+    /// Position in the recipient's inbox (from 0)
+    pub id: MessageId,
+    pub sender: AccountId,
+    pub recipient: AccountId,
+    pub body: Vec<u8>,
+    pub encrypted: bool,
+    pub sent_at: BlockNumber,
+}
+
+/// Message ids stored in an account's inbox
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Inbox {
+    /// Id of the next message delivered
+    pub next_id: MessageId,
+    /// Oldest message still stored
+    pub first_id: MessageId,
+}
+
+impl Inbox {
+    /// Reserve the id of a new message
+    ///
+    /// Returns it with the ids of the messages to prune.
+    pub fn push(&mut self) -> (MessageId, Range<MessageId>) {
+        let id = self.next_id;
+        self.next_id += 1;
+        let first_id = self.next_id.saturating_sub(MAX_INBOX_MESSAGES).max(self.first_id);
+        let pruned = self.first_id..first_id;
+        self.first_id = first_id;
+        (id, pruned)
+    }
+
+    /// Messages stored
+    pub fn len(&self) -> u64 {
+        self.next_id - self.first_id
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Check the body of an account message
+pub fn validate_account_message(body: &[u8]) -> Result<(), MessagingError> {
+    if body.is_empty() {
+        return Err(MessagingError::EmptyMessage);
+    }
+    if body.len() > MAX_MESSAGE_LEN {
+        return Err(MessagingError::MessageTooLong);
+    }
+    Ok(())
+}
+
 /// Errors that can occur during messaging
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum MessagingError {
@@ -554,6 +628,12 @@ pub enum MessagingError {
 
     #[error("Message verification failed: {0}")]
     VerificationFailed(String),
+
+    #[error("Message is empty")]
+    EmptyMessage,
+
+    #[error("Message too long (max {MAX_MESSAGE_LEN} bytes)")]
+    MessageTooLong,
 }
 
 #[cfg(test)]
@@ -990,4 +1070,24 @@ mod tests {
         let stats2 = messaging.get_stats(chain2);
         assert_eq!(stats2.received, 5);
     }
+
+    #[test]
+    fn test_account_inbox() {
+        assert!(matches!(validate_account_message(&[]), Err(MessagingError::EmptyMessage)));
+        assert!(matches!(validate_account_message(&[0; MAX_MESSAGE_LEN + 1]), Err(MessagingError::MessageTooLong)));
+        assert!(validate_account_message(&[0; MAX_MESSAGE_LEN]).is_ok());
+
+        let mut inbox = Inbox::default();
+        for expected in 0..MAX_INBOX_MESSAGES {
+            let (id, pruned) = inbox.push();
+            assert_eq!(id, expected);
+            assert!(pruned.is_empty());
+        }
+        assert_eq!(inbox.len(), MAX_INBOX_MESSAGES);
+
+        // Full: each new message prunes the oldest
+        assert_eq!(inbox.push(), (MAX_INBOX_MESSAGES, 0..1));
+        assert_eq!(inbox.push(), (MAX_INBOX_MESSAGES + 1, 1..2));
+        assert_eq!((inbox.first_id, inbox.len()), (2, MAX_INBOX_MESSAGES));
+    }
 }
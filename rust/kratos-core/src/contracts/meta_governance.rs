@@ -3,8 +3,10 @@
 //
 // Principle: The protocol can change, but its guarantees cannot.
 
+use crate::consensus::epoch::EPOCH_DURATION_BLOCKS;
+use crate::contracts::governance::ProposalId;
 use crate::types::{
-    AccountId, Balance, BlockNumber, ChainId, Hash,
+    AccountId, Balance, BlockNumber, ChainId, EpochNumber, Hash,
     ProtocolParameters, ProtocolVersion, ParameterChange, ParameterError,
     ConstitutionalAxiom, ConstitutionalProhibition,
 };
@@ -777,6 +779,56 @@ impl Default for MetaGovernanceContract {
     }
 }
 
+// =============================================================================
+// ON-CHAIN ENACTMENT
+// =============================================================================
+//
+// Parameter changes reach the chain as `ProposalType::ProtocolChange` root
+// proposals. Power is slow: they need PROTOCOL_UPGRADE_THRESHOLD of the votes,
+// wait out PROTOCOL_UPGRADE_TIMELOCK, and only take effect at the first epoch
+// boundary after it. Every enactment attempt is kept in the audit log.
+
+/// Audit log entry of an enacted (or failed) parameter change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterChangeRecord {
+    /// Position in the log (0-based)
+    pub sequence: u64,
+    /// Governance proposal carrying the changes
+    pub proposal_id: ProposalId,
+    /// Changes, in proposal order
+    pub changes: Vec<ParameterChange>,
+    /// Block at which they took effect
+    pub block: BlockNumber,
+    /// Epoch starting at that block
+    pub epoch: EpochNumber,
+    /// Why the changes were not applied, if they failed
+    pub error: Option<String>,
+}
+
+/// Apply `changes` to `parameters` as of `block`: all of them or none
+pub fn enact_changes(
+    parameters: &mut ProtocolParameters,
+    changes: &[ParameterChange],
+    block: BlockNumber,
+) -> Result<(), MetaGovernanceError> {
+    let mut next = parameters.clone();
+    for change in changes {
+        // Checked against the parameters in force, not those at submission
+        if !change.is_constitutional(&next) {
+            return Err(MetaGovernanceError::ConstitutionalViolation);
+        }
+        change.apply(&mut next)?;
+    }
+    next.active_since = block;
+    *parameters = next;
+    Ok(())
+}
+
+/// First epoch boundary at or after `timelock_end`
+pub fn enactment_block(timelock_end: BlockNumber) -> BlockNumber {
+    timelock_end.div_ceil(EPOCH_DURATION_BLOCKS) * EPOCH_DURATION_BLOCKS
+}
+
 // =============================================================================
 // ERRORS
 // =============================================================================
@@ -1182,4 +1234,30 @@ mod tests {
         assert_eq!(history.len(), 1);
         assert_eq!(history[0].1.economics.inflation_rate.value(), initial);
     }
+
+    #[test]
+    fn test_enact_changes() {
+        let mut params = ProtocolParameters::genesis();
+        let shares = crate::types::FeeShares { producer: 40, finality_voters: 10, burn: 30, treasury: 20 };
+        enact_changes(&mut params, &[ParameterChange::FeeShares(shares)], 864_600).unwrap();
+        assert_eq!(params.economics.fee_shares, shares);
+        assert_eq!(params.active_since, 864_600);
+
+        // A failing change leaves the whole batch unapplied
+        let batch = [
+            ParameterChange::InflationBounds { floor_bps: 100, cap_bps: 800 },
+            ParameterChange::InflationBounds { floor_bps: 100, cap_bps: 2_000 },
+        ];
+        assert_eq!(
+            enact_changes(&mut params, &batch, 865_200),
+            Err(MetaGovernanceError::ConstitutionalViolation)
+        );
+        assert_eq!(params.economics.inflation_floor_bps.value(), 50);
+        assert_eq!(params.economics.inflation_cap_bps.value(), 1_000);
+        assert_eq!(params.active_since, 864_600);
+
+        assert_eq!(enactment_block(864_000), 864_000);
+        assert_eq!(enactment_block(864_001), 864_600);
+        assert_eq!(enactment_block(0), 0);
+    }
 }
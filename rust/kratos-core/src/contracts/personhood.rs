@@ -15,10 +15,11 @@ use crate::types::personhood::{
     CHALLENGE_PERIOD, MAX_CHALLENGES_PER_IDENTITY, MIN_CHALLENGE_INTERVAL,
     MIN_CONNECTIVITY_SCORE, UNIQUENESS_MIN_ATTESTERS,
 };
-use crate::types::{AccountId, Balance, BlockNumber, ChainId, Hash};
+use crate::consensus::epoch::EPOCH_DURATION_BLOCKS;
+use crate::types::{AccountId, Balance, BlockNumber, ChainId, EpochNumber, Hash};
 use crate::contracts::identity::{IdentityRegistry, IdentityError};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 // =============================================================================
 // PERSONHOOD REGISTRY
@@ -679,8 +680,143 @@ impl PersonhoodRegistry {
     }
 }
 
+// =============================================================================
+// VERIFICATION CEREMONIES
+// =============================================================================
+//
+// Every epoch holds a verification round. During the commit phase, holders of
+// an active identity commit to a secret received at the ceremony; during the
+// reveal phase they reveal it. A matching reveal makes the account a verified
+// person until PERSONHOOD_VALIDITY blocks later.
+
+/// Blocks at the start of each epoch open to commitments; the rest is the reveal phase
+pub const CEREMONY_COMMIT_BLOCKS: BlockNumber = 400;
+
+/// Verified-person status lasts 30 days (~432,000 blocks) after the reveal
+pub const PERSONHOOD_VALIDITY: BlockNumber = 432_000;
+
+/// Phase of a verification round
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CeremonyPhase {
+    Commit,
+    Reveal,
+}
+
+/// Round and phase a block belongs to
+pub fn ceremony_phase(block: BlockNumber) -> (EpochNumber, CeremonyPhase) {
+    let phase = if block % EPOCH_DURATION_BLOCKS < CEREMONY_COMMIT_BLOCKS {
+        CeremonyPhase::Commit
+    } else {
+        CeremonyPhase::Reveal
+    };
+    (block / EPOCH_DURATION_BLOCKS, phase)
+}
+
+/// Commitment of `account` to `secret`; binding the account keeps a commitment from being replayed
+pub fn ceremony_commitment(account: &AccountId, secret: &Hash) -> Hash {
+    let mut data = account.as_bytes().to_vec();
+    data.extend_from_slice(secret.as_bytes());
+    Hash::hash(&data)
+}
+
+/// Verification round of one epoch
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CeremonyRound {
+    pub epoch: EpochNumber,
+
+    /// Commitment of each participant
+    pub commitments: BTreeMap<AccountId, Hash>,
+
+    /// Participants whose reveal matched their commitment
+    pub revealed: BTreeSet<AccountId>,
+}
+
+impl CeremonyRound {
+    pub fn new(epoch: EpochNumber) -> Self {
+        Self { epoch, ..Self::default() }
+    }
+
+    /// Last block of the commit phase
+    pub fn commit_ends_at(&self) -> BlockNumber {
+        self.epoch * EPOCH_DURATION_BLOCKS + CEREMONY_COMMIT_BLOCKS - 1
+    }
+
+    /// Last block of the reveal phase
+    pub fn reveal_ends_at(&self) -> BlockNumber {
+        (self.epoch + 1) * EPOCH_DURATION_BLOCKS - 1
+    }
+
+    /// Record the commitment of `account` (once per round)
+    pub fn commit(&mut self, account: AccountId, commitment: Hash) -> Result<(), PersonhoodError> {
+        if self.commitments.contains_key(&account) {
+            return Err(PersonhoodError::AlreadyCommitted);
+        }
+        self.commitments.insert(account, commitment);
+        Ok(())
+    }
+
+    /// Check the secret of `account` against its commitment
+    pub fn reveal(&mut self, account: AccountId, secret: &Hash) -> Result<(), PersonhoodError> {
+        let commitment = self.commitments.get(&account).ok_or(PersonhoodError::NotCommitted)?;
+        if self.revealed.contains(&account) {
+            return Err(PersonhoodError::AlreadyRevealed);
+        }
+        if ceremony_commitment(&account, secret) != *commitment {
+            return Err(PersonhoodError::RevealMismatch);
+        }
+        self.revealed.insert(account);
+        Ok(())
+    }
+}
+
+/// Verified-person status of an account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonhoodRecord {
+    pub account: AccountId,
+
+    /// Identity the account held when last verified
+    pub identity_id: IdentityId,
+
+    /// Round of the last verification
+    pub verified_round: EpochNumber,
+
+    /// Block of the last reveal
+    pub verified_at: BlockNumber,
+
+    /// Status lapses after this block unless renewed in a later round
+    pub expires_at: BlockNumber,
+
+    /// Rounds the account was verified in
+    pub rounds_attended: u32,
+}
+
+impl PersonhoodRecord {
+    /// Record a successful reveal, creating or renewing the status
+    pub fn verify(
+        previous: Option<PersonhoodRecord>,
+        account: AccountId,
+        identity_id: IdentityId,
+        round: EpochNumber,
+        current_block: BlockNumber,
+    ) -> Self {
+        Self {
+            account,
+            identity_id,
+            verified_round: round,
+            verified_at: current_block,
+            expires_at: current_block + PERSONHOOD_VALIDITY,
+            rounds_attended: previous.map_or(0, |record| record.rounds_attended) + 1,
+        }
+    }
+
+    /// Counts as one person at `current_block`
+    pub fn is_verified(&self, current_block: BlockNumber) -> bool {
+        current_block <= self.expires_at
+    }
+}
+
 /// Personhood errors
-#[derive(Debug, Clone, thiserror::Error)]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum PersonhoodError {
     #[error("Challenges are disabled")]
     ChallengesDisabled,
@@ -732,6 +868,27 @@ pub enum PersonhoodError {
 
     #[error("No uniqueness commitment found")]
     NoCommitment,
+
+    #[error("Participating requires an active identity")]
+    IdentityNotActive,
+
+    #[error("Commitments are only accepted in the commit phase of an epoch")]
+    NotCommitPhase,
+
+    #[error("Reveals are only accepted in the reveal phase of an epoch")]
+    NotRevealPhase,
+
+    #[error("Already committed to this round")]
+    AlreadyCommitted,
+
+    #[error("No commitment in this round")]
+    NotCommitted,
+
+    #[error("Already revealed in this round")]
+    AlreadyRevealed,
+
+    #[error("Secret does not match the commitment")]
+    RevealMismatch,
 }
 
 // =============================================================================
@@ -1048,4 +1205,32 @@ mod tests {
         let cluster = &personhood.sybil_clusters[0];
         assert_eq!(cluster.members.len(), 4);
     }
+
+    #[test]
+    fn test_ceremony_round() {
+        assert_eq!(ceremony_phase(1_200), (2, CeremonyPhase::Commit));
+        assert_eq!(ceremony_phase(1_200 + CEREMONY_COMMIT_BLOCKS), (2, CeremonyPhase::Reveal));
+
+        let mut round = CeremonyRound::new(2);
+        assert_eq!((round.commit_ends_at(), round.reveal_ends_at()), (1_599, 1_799));
+
+        let alice = create_account(1);
+        let secret = create_hash(7);
+        round.commit(alice, ceremony_commitment(&alice, &secret)).unwrap();
+        assert_eq!(round.commit(alice, Hash::ZERO), Err(PersonhoodError::AlreadyCommitted));
+        // A copied commitment does not verify another account
+        let bob = create_account(2);
+        round.commit(bob, ceremony_commitment(&alice, &secret)).unwrap();
+        assert_eq!(round.reveal(bob, &secret), Err(PersonhoodError::RevealMismatch));
+        assert_eq!(round.reveal(create_account(3), &secret), Err(PersonhoodError::NotCommitted));
+
+        round.reveal(alice, &secret).unwrap();
+        assert_eq!(round.reveal(alice, &secret), Err(PersonhoodError::AlreadyRevealed));
+
+        let record = PersonhoodRecord::verify(None, alice, create_hash(1), 2, 1_650);
+        let renewed = PersonhoodRecord::verify(Some(record.clone()), alice, create_hash(1), 3, 2_250);
+        assert!(record.is_verified(1_650 + PERSONHOOD_VALIDITY));
+        assert!(!record.is_verified(1_651 + PERSONHOOD_VALIDITY));
+        assert_eq!((renewed.rounds_attended, renewed.expires_at), (2, 2_250 + PERSONHOOD_VALIDITY));
+    }
 }
@@ -6,7 +6,7 @@
 // - Cross-chain reputation portability
 // - Endorsement system
 
-use crate::types::identity::IdentityId;
+use crate::types::identity::{IdentityId, REPUTATION_DECAY_INTERVAL};
 use crate::types::reputation::{
     CrossChainReputation, DomainReputation, Endorsement, MultiDimensionalReputation,
     ReputationDomain, ReputationEvent, ReputationStake,
@@ -16,7 +16,7 @@ use crate::types::reputation::{
 use crate::types::{AccountId, Balance, BlockNumber, ChainId, Hash};
 use crate::contracts::identity::IdentityRegistry;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 // =============================================================================
 // REPUTATION REGISTRY
@@ -651,6 +651,94 @@ impl ReputationRegistry {
     }
 }
 
+// =============================================================================
+// ON-CHAIN REPUTATION
+// =============================================================================
+//
+// Accounts accrue reputation from governance activity (votes, proposals) as
+// it executes, and from the uptime and arbitration credits of each closed
+// epoch. The epoch job then decays domains left inactive for a whole
+// REPUTATION_DECAY_INTERVAL, at most once per interval.
+
+/// Points per governance vote cast
+pub const GOVERNANCE_VOTE_REPUTATION: u32 = 5;
+
+/// Points per proposal submitted
+pub const PROPOSAL_REPUTATION: u32 = 20;
+
+/// Points per epoch with uptime credits
+pub const UPTIME_REPUTATION: u32 = 10;
+
+/// Points per accepted arbitration (5 arbitration credits)
+pub const ARBITRATION_REPUTATION: u32 = 50;
+
+/// Reputation of an account, per domain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountReputation {
+    pub account: AccountId,
+
+    /// Domains with activity (ordered, so every node encodes the same bytes)
+    pub domains: BTreeMap<ReputationDomain, DomainReputation>,
+
+    /// Weighted average over all domains
+    pub overall_score: u32,
+
+    /// Last accrual or decay
+    pub updated_at: BlockNumber,
+
+    /// Last decay pass that changed or checked the scores
+    pub last_decay: BlockNumber,
+}
+
+impl AccountReputation {
+    pub fn new(account: AccountId, current_block: BlockNumber) -> Self {
+        Self {
+            account,
+            domains: BTreeMap::new(),
+            overall_score: 0,
+            updated_at: current_block,
+            last_decay: current_block,
+        }
+    }
+
+    /// Score in one domain
+    pub fn score(&self, domain: ReputationDomain) -> u32 {
+        self.domains.get(&domain).map_or(0, |rep| rep.score)
+    }
+
+    /// Add `points` in `domain`
+    pub fn accrue(&mut self, domain: ReputationDomain, points: u32, current_block: BlockNumber) {
+        self.domains.entry(domain).or_default().add(points, current_block);
+        self.updated_at = current_block;
+        self.recalculate_overall();
+    }
+
+    /// Decay the domains inactive for a whole interval, once per interval
+    ///
+    /// Returns false if no interval has passed since the last decay.
+    pub fn decay(&mut self, current_block: BlockNumber) -> bool {
+        if current_block < self.last_decay + REPUTATION_DECAY_INTERVAL {
+            return false;
+        }
+        for (domain, rep) in self.domains.iter_mut() {
+            if current_block.saturating_sub(rep.last_activity) >= REPUTATION_DECAY_INTERVAL {
+                let decay = (rep.score as u64 * domain.decay_rate() as u64 / 100) as u32;
+                rep.score = rep.score.saturating_sub(decay.max(1));
+            }
+        }
+        self.last_decay = current_block;
+        self.updated_at = current_block;
+        self.recalculate_overall();
+        true
+    }
+
+    fn recalculate_overall(&mut self) {
+        let total_weight: u64 = ReputationDomain::all().iter().map(|d| d.weight() as u64).sum();
+        let weighted: u64 = self.domains.iter().map(|(d, rep)| rep.score as u64 * d.weight() as u64).sum();
+        self.overall_score = (weighted / total_weight) as u32;
+    }
+}
+
 /// Reputation errors
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum ReputationError {
@@ -1043,4 +1131,23 @@ mod tests {
 
         assert!(score_with_import > score_without_import);
     }
+
+    #[test]
+    fn test_account_reputation_decay() {
+        let mut reputation = AccountReputation::new(create_account(1), 0);
+        reputation.accrue(ReputationDomain::Governance, 1_000, 100);
+        reputation.accrue(ReputationDomain::Validation, 1_000, 100);
+        // (1000 × 25 + 1000 × 10) / 100
+        assert_eq!(reputation.overall_score, 350);
+
+        // At most once per interval
+        assert!(!reputation.decay(REPUTATION_DECAY_INTERVAL - 1));
+        reputation.accrue(ReputationDomain::Validation, 0, REPUTATION_DECAY_INTERVAL);
+        assert!(reputation.decay(REPUTATION_DECAY_INTERVAL + 100));
+        // Governance was idle the whole interval, Validation was not
+        assert_eq!(reputation.score(ReputationDomain::Governance), 980);
+        assert_eq!(reputation.score(ReputationDomain::Validation), 1_000);
+        assert!(!reputation.decay(REPUTATION_DECAY_INTERVAL + 200));
+        assert_eq!(reputation.last_decay, REPUTATION_DECAY_INTERVAL + 100);
+    }
 }
@@ -1,7 +1,7 @@
 // Sidechains - System contract pour la gestion des sidechains et hostchains
 use crate::types::{
-    AccountId, Balance, BlockNumber, ChainId, ChainStatus, HostChainInfo, SecurityMode,
-    SidechainInfo,
+    AccountId, Balance, BlockNumber, ChainId, ChainStatus, Hash, HostChainInfo, MerkleProof,
+    SecurityMode, SidechainInfo,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -13,6 +13,80 @@ pub const INACTIVITY_THRESHOLD: BlockNumber = 1_296_000;
 /// Intervalle de vérification pour purge auto
 pub const PURGE_CHECK_INTERVAL: BlockNumber = 3_600; // 6 heures
 
+/// Domain of the exit leaves committed by sidechain checkpoints
+const EXIT_LEAF_DOMAIN: &[u8] = b"KRATOS_SIDECHAIN_EXIT";
+
+/// Leaf committing the `balance` of `account` in a sidechain checkpoint
+///
+/// Binds the chain and the account, which the raw account leaves of a state
+/// root do not: a proof of someone else's leaf cannot be replayed.
+pub fn exit_leaf(chain_id: ChainId, account: &AccountId, balance: Balance) -> Vec<u8> {
+    let mut leaf = EXIT_LEAF_DOMAIN.to_vec();
+    leaf.extend_from_slice(&chain_id.0.to_le_bytes());
+    leaf.extend_from_slice(account.as_bytes());
+    leaf.extend_from_slice(&balance.to_le_bytes());
+    leaf
+}
+
+/// Record a checkpoint of `sidechain` submitted by `submitter`
+///
+/// Only its owner and validators checkpoint it, and only while it runs: from
+/// the freeze on, the last checkpoint is the one exits are proven against.
+pub fn record_checkpoint(
+    sidechain: &mut SidechainInfo,
+    submitter: &AccountId,
+    state_root: Hash,
+    block: BlockNumber,
+) -> Result<(), ChainError> {
+    if *submitter != sidechain.owner && !sidechain.validators.contains(submitter) {
+        return Err(ChainError::Unauthorized {
+            action: "checkpoint".to_string(),
+            required: "owner or validator of the sidechain".to_string(),
+        });
+    }
+    if !matches!(sidechain.status, ChainStatus::Active | ChainStatus::Inactive) {
+        return Err(ChainError::InvalidState);
+    }
+    sidechain.last_verified_state_root = Some(state_root);
+    sidechain.last_activity = block;
+    Ok(())
+}
+
+/// Record the exit of `account` with `balance` from `sidechain`
+///
+/// Constitution Article I §6: "Exit is a fundamental right". Allowed in any
+/// chain status, so a halted sidechain cannot hold funds back. The proof's
+/// leaf must be `exit_leaf(chain_id, account, balance)` and its root the
+/// latest verified checkpoint: an older one may commit balances spent since.
+/// Exits never release more than the chain's bonded deposit in total.
+pub fn record_checkpoint_exit(
+    sidechain: &mut SidechainInfo,
+    account: AccountId,
+    balance: Balance,
+    merkle_proof: &MerkleProof,
+) -> Result<(), ChainError> {
+    if sidechain.withdrawn_accounts.contains(&account) {
+        return Err(ChainError::AlreadyWithdrawn);
+    }
+
+    let checkpoint = sidechain.last_verified_state_root.ok_or(ChainError::NoCheckpoint)?;
+    if merkle_proof.chain_id != sidechain.id
+        || merkle_proof.leaf != exit_leaf(sidechain.id, &account, balance)
+        || !merkle_proof.verify()
+        || merkle_proof.root != checkpoint
+    {
+        return Err(ChainError::InvalidMerkleProof);
+    }
+
+    let exited = sidechain.exited.saturating_add(balance);
+    if exited > sidechain.deposit {
+        return Err(ChainError::ExitExceedsBond);
+    }
+    sidechain.exited = exited;
+    sidechain.withdrawn_accounts.insert(account);
+    Ok(())
+}
+
 /// Registre des sidechains et hostchains
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChainRegistry {
@@ -909,7 +983,8 @@ impl ChainRegistry {
         // For chain owner: return full deposit
         // For other users: return proportional share based on snapshot state
         let withdrawal_amount = if owner == sidechain.owner {
-            sidechain.deposit
+            // Less what checkpoint exits already released
+            sidechain.deposit.saturating_sub(sidechain.exited)
         } else {
             // For non-owners, withdrawal requires Merkle proof of balance at snapshot
             // This is handled by withdraw_with_proof()
@@ -997,13 +1072,13 @@ impl ChainRegistry {
             return Err(ChainError::AlreadyWithdrawn);
         }
 
-        // For owner, return deposit directly
+        // For owner, return deposit directly (less checkpoint exits)
         if account == sidechain.owner {
             sidechain.withdrawn_accounts.insert(account);
             return Ok(WithdrawalResult {
                 chain_id,
                 recipient: account,
-                amount: sidechain.deposit,
+                amount: sidechain.deposit.saturating_sub(sidechain.exited),
                 withdrawal_type: WithdrawalType::EmergencyOwner,
             });
         }
@@ -1037,6 +1112,29 @@ impl ChainRegistry {
             withdrawal_type: WithdrawalType::Emergency,
         })
     }
+
+    /// Exit a sidechain with a Merkle proof against its latest checkpoint
+    /// (see `record_checkpoint_exit`)
+    pub fn exit_with_proof(
+        &mut self,
+        chain_id: ChainId,
+        account: AccountId,
+        balance: Balance,
+        merkle_proof: &MerkleProof,
+    ) -> Result<WithdrawalResult, ChainError> {
+        let sidechain = self
+            .sidechains
+            .get_mut(&chain_id)
+            .ok_or(ChainError::ChainNotFound)?;
+        record_checkpoint_exit(sidechain, account, balance, merkle_proof)?;
+
+        Ok(WithdrawalResult {
+            chain_id,
+            recipient: account,
+            amount: balance,
+            withdrawal_type: WithdrawalType::CheckpointExit,
+        })
+    }
 }
 
 impl Default for ChainRegistry {
@@ -1069,6 +1167,8 @@ pub enum WithdrawalType {
     EmergencyOwner,
     /// Emergency withdrawal (may have slippage)
     Emergency,
+    /// User balance proven against a checkpoint
+    CheckpointExit,
 }
 
 /// Erreurs de gestion de chaînes
@@ -1127,6 +1227,12 @@ pub enum ChainError {
     #[error("Preuve Merkle invalide")]
     InvalidMerkleProof,
 
+    #[error("Aucun checkpoint pour cette chaîne")]
+    NoCheckpoint,
+
+    #[error("Les sorties dépassent le dépôt de la chaîne")]
+    ExitExceedsBond,
+
     #[error("Erreur de chaîne: {0}")]
     ChainTypeError(#[from] crate::types::ChainError),
 }
@@ -2503,4 +2609,64 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), ChainError::InvalidState));
     }
+
+    #[test]
+    fn test_exit_with_proof() {
+        use crate::types::merkle::StateMerkleTree;
+
+        let mut registry = ChainRegistry::new();
+        let owner = AccountId::from_bytes([1; 32]);
+        let alice = AccountId::from_bytes([2; 32]);
+        let bob = AccountId::from_bytes([3; 32]);
+        let chain_id = registry
+            .create_sidechain(owner, None, None, None, SecurityMode::Sovereign, None, crate::types::SOVEREIGN_DEPOSIT, 0)
+            .unwrap();
+
+        // Checkpoint committing 6,000 to alice and 5,000 to bob
+        let tree = StateMerkleTree::new(vec![exit_leaf(chain_id, &alice, 6_000), exit_leaf(chain_id, &bob, 5_000)]);
+        let alice_proof = tree.generate_proof(0, 100, chain_id).unwrap();
+        let bob_proof = tree.generate_proof(1, 100, chain_id).unwrap();
+        assert!(matches!(
+            registry.exit_with_proof(chain_id, alice, 6_000, &alice_proof),
+            Err(ChainError::NoCheckpoint)
+        ));
+        registry.update_verified_state_root(chain_id, tree.root()).unwrap();
+
+        // The chain halts: exit stays possible
+        registry.trigger_purge(chain_id, crate::types::PurgeTrigger::Inactivity, 200).unwrap();
+
+        // The leaf binds the account and the balance
+        assert!(matches!(
+            registry.exit_with_proof(chain_id, alice, 7_000, &alice_proof),
+            Err(ChainError::InvalidMerkleProof)
+        ));
+        assert!(matches!(
+            registry.exit_with_proof(chain_id, owner, 6_000, &alice_proof),
+            Err(ChainError::InvalidMerkleProof)
+        ));
+
+        let exit = registry.exit_with_proof(chain_id, alice, 6_000, &alice_proof).unwrap();
+        assert_eq!((exit.recipient, exit.amount, exit.withdrawal_type), (alice, 6_000, WithdrawalType::CheckpointExit));
+        assert!(matches!(
+            registry.exit_with_proof(chain_id, alice, 6_000, &alice_proof),
+            Err(ChainError::AlreadyWithdrawn)
+        ));
+
+        // Only the latest checkpoint proves a balance
+        let tree = StateMerkleTree::new(vec![exit_leaf(chain_id, &bob, 5_000), exit_leaf(chain_id, &owner, 1)]);
+        registry.update_verified_state_root(chain_id, tree.root()).unwrap();
+        assert!(matches!(
+            registry.exit_with_proof(chain_id, bob, 5_000, &bob_proof),
+            Err(ChainError::InvalidMerkleProof)
+        ));
+
+        // Exits are capped by the bonded deposit, which the owner gets the rest of
+        let bob_proof = tree.generate_proof(0, 300, chain_id).unwrap();
+        assert!(matches!(
+            registry.exit_with_proof(chain_id, bob, 5_000, &bob_proof),
+            Err(ChainError::ExitExceedsBond)
+        ));
+        let rest = registry.emergency_exit(chain_id, owner, 0, None).unwrap();
+        assert_eq!(rest.amount, crate::types::SOVEREIGN_DEPOSIT - 6_000);
+    }
 }
@@ -1,8 +1,9 @@
 // Staking - System contract pour la gestion du staking
+use crate::consensus::epoch::EPOCH_DURATION_BLOCKS;
 use crate::consensus::validator::{ValidatorInfo, ValidatorSet};
-use crate::types::{AccountId, Balance, BlockNumber};
+use crate::types::{AccountId, Balance, BlockNumber, EpochNumber, KRAT};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 // SECURITY FIX #31: Consolidate constants - use single source of truth from validator module
 // Re-export to maintain backward compatibility but prevent divergence
@@ -262,6 +263,384 @@ pub enum StakingError {
     ValidatorSetError(#[from] crate::consensus::validator::ValidatorError),
 }
 
+// =============================================================================
+// DELEGATION
+// =============================================================================
+//
+// An account that does not validate can delegate stake to a validator: the
+// amount moves from its free to its reserved balance and joins the
+// validator's DelegationPool. The block reward of the validator is split in
+// proportion to its own stake and the delegated stake; the delegated part,
+// minus the validator's commission, is owed to the delegators pro rata and
+// claimed with ClaimRewards (see DELEGATOR REWARDS).
+// Undelegated stake goes through the standard unbonding period.
+
+/// Minimum delegation, and minimum left after a partial undelegation
+pub const MIN_DELEGATION: Balance = 100 * KRAT;
+
+/// Delegators per validator (bounds the payouts of a block)
+pub const MAX_DELEGATORS_PER_VALIDATOR: usize = 64;
+
+/// Validators an account delegates to at once
+pub const MAX_DELEGATIONS_PER_ACCOUNT: usize = 16;
+
+/// Commission of a validator that never set one, in percent
+pub const DEFAULT_COMMISSION_PERCENT: u8 = 10;
+
+/// Stake delegated to one validator
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DelegationPool {
+    /// Amount delegated by each delegator
+    pub delegators: BTreeMap<AccountId, Balance>,
+}
+
+/// Split of a block reward between a validator and its delegators
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RewardShares {
+    /// Own share, commission and rounding remainder
+    pub validator: Balance,
+
+    /// Payout of each delegator
+    pub delegators: Vec<(AccountId, Balance)>,
+}
+
+impl DelegationPool {
+    /// Total delegated stake
+    pub fn total(&self) -> Balance {
+        self.delegators.values().fold(0, |total, amount| total.saturating_add(*amount))
+    }
+
+    /// Amount delegated by `who`
+    pub fn delegated_by(&self, who: &AccountId) -> Balance {
+        self.delegators.get(who).copied().unwrap_or(0)
+    }
+
+    /// Add `amount` to the delegation of `who`
+    pub fn delegate(&mut self, who: AccountId, amount: Balance) -> Result<(), DelegationError> {
+        let current = self.delegated_by(&who);
+        if current == 0 && self.delegators.len() >= MAX_DELEGATORS_PER_VALIDATOR {
+            return Err(DelegationError::TooManyDelegators);
+        }
+        let delegated = current.saturating_add(amount);
+        if delegated < MIN_DELEGATION {
+            return Err(DelegationError::BelowMinimum);
+        }
+        self.delegators.insert(who, delegated);
+        Ok(())
+    }
+
+    /// Remove `amount` from the delegation of `who`
+    ///
+    /// What remains must be nothing or at least MIN_DELEGATION.
+    pub fn undelegate(&mut self, who: &AccountId, amount: Balance) -> Result<(), DelegationError> {
+        let current = self.delegated_by(who);
+        if current == 0 {
+            return Err(DelegationError::NotDelegated);
+        }
+        if amount == 0 || amount > current {
+            return Err(DelegationError::InsufficientDelegation { delegated: current });
+        }
+        match current - amount {
+            0 => {
+                self.delegators.remove(who);
+            }
+            left if left < MIN_DELEGATION => return Err(DelegationError::BelowMinimum),
+            left => {
+                self.delegators.insert(*who, left);
+            }
+        }
+        Ok(())
+    }
+
+    /// Split `reward` between the validator, staking `own_stake` and keeping
+    /// `commission` percent of the delegators' part, and the delegators
+    pub fn reward_shares(&self, reward: Balance, own_stake: Balance, commission: u8) -> RewardShares {
+        let delegated = self.total();
+        if delegated == 0 {
+            return RewardShares { validator: reward, delegators: Vec::new() };
+        }
+
+        let pool_reward = mul_div(reward, delegated, own_stake.saturating_add(delegated));
+        let commission = mul_div(pool_reward, commission.min(MAX_COMMISSION_PERCENT) as Balance, 100);
+        let payable = pool_reward - commission;
+
+        let delegators: Vec<(AccountId, Balance)> = self
+            .delegators
+            .iter()
+            .map(|(who, amount)| (*who, mul_div(payable, *amount, delegated)))
+            .filter(|(_, payout)| *payout > 0)
+            .collect();
+        let paid = delegators.iter().map(|(_, payout)| *payout).sum::<Balance>();
+        RewardShares { validator: reward - paid, delegators }
+    }
+}
+
+/// `value * numerator / denominator` for `numerator <= denominator`, without
+/// overflowing on large balances
+fn mul_div(value: Balance, numerator: Balance, denominator: Balance) -> Balance {
+    if denominator == 0 {
+        return 0;
+    }
+    match value.checked_mul(numerator) {
+        Some(product) => product / denominator,
+        None => value / denominator * numerator + (value % denominator).saturating_mul(numerator) / denominator,
+    }
+}
+
+/// Rejected Delegate or Undelegate transaction
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum DelegationError {
+    #[error("Target is not a validator")]
+    NotValidator,
+
+    #[error("A validator cannot delegate to itself")]
+    SelfDelegation,
+
+    #[error("Delegation below the minimum of {} KRAT", MIN_DELEGATION / KRAT)]
+    BelowMinimum,
+
+    #[error("Validator has the maximum number of delegators")]
+    TooManyDelegators,
+
+    #[error("Account delegates to the maximum number of validators")]
+    TooManyDelegations,
+
+    #[error("No delegation to this validator")]
+    NotDelegated,
+
+    #[error("Invalid amount: {delegated} delegated")]
+    InsufficientDelegation { delegated: Balance },
+}
+
+// =============================================================================
+// UNBONDING QUEUE
+// =============================================================================
+//
+// Unstaked and undelegated stake leaves the reserved balance for the
+// account's unbonding queue, indexed by the epoch it unlocks at: the first
+// epoch starting UNBONDING_PERIOD blocks or more after the unbond. Unbonds
+// unlocking in the same epoch merge into one chunk, so partial unbonds keep
+// the queue small. WithdrawUnbonded moves every unlocked chunk to the free
+// balance.
+
+/// Pending unlock epochs per account (further unbonds must wait for one)
+pub const MAX_UNBONDING_CHUNKS: usize = 32;
+
+/// Stake waiting for its unlock epoch
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnbondingQueue {
+    /// Amount unlocking at each epoch
+    pub chunks: BTreeMap<EpochNumber, Balance>,
+}
+
+impl UnbondingQueue {
+    /// Epoch at which stake unbonded at `current_block` unlocks
+    pub fn unlock_epoch(current_block: BlockNumber) -> EpochNumber {
+        current_block
+            .saturating_add(UNBONDING_PERIOD)
+            .div_ceil(EPOCH_DURATION_BLOCKS)
+    }
+
+    /// First block of `epoch`, from which its chunk can be withdrawn
+    pub fn unlock_block(epoch: EpochNumber) -> BlockNumber {
+        epoch.saturating_mul(EPOCH_DURATION_BLOCKS)
+    }
+
+    /// Queue `amount` unbonded at `current_block`, returning its unlock epoch
+    pub fn schedule(&mut self, amount: Balance, current_block: BlockNumber) -> Result<EpochNumber, UnbondingError> {
+        if amount == 0 {
+            return Err(UnbondingError::ZeroAmount);
+        }
+        let epoch = Self::unlock_epoch(current_block);
+        if !self.chunks.contains_key(&epoch) && self.chunks.len() >= MAX_UNBONDING_CHUNKS {
+            return Err(UnbondingError::TooManyChunks);
+        }
+        let chunk = self.chunks.entry(epoch).or_insert(0);
+        *chunk = chunk.saturating_add(amount);
+        Ok(epoch)
+    }
+
+    /// Total amount unbonding, unlocked or not
+    pub fn total(&self) -> Balance {
+        self.chunks.values().fold(0, |total, amount| total.saturating_add(*amount))
+    }
+
+    /// Amount unlocked at `current_block`
+    pub fn withdrawable(&self, current_block: BlockNumber) -> Balance {
+        self.chunks
+            .range(..=current_block / EPOCH_DURATION_BLOCKS)
+            .fold(0, |total, (_, amount)| total.saturating_add(*amount))
+    }
+
+    /// Remove the chunks unlocked at `current_block`, returning their total
+    pub fn withdraw(&mut self, current_block: BlockNumber) -> Balance {
+        let withdrawable = self.withdrawable(current_block);
+        self.chunks = self.chunks.split_off(&(current_block / EPOCH_DURATION_BLOCKS + 1));
+        withdrawable
+    }
+}
+
+/// Rejected Unstake or Undelegate transaction
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum UnbondingError {
+    #[error("Cannot unbond a zero amount")]
+    ZeroAmount,
+
+    #[error("Too many pending unbonding chunks (max {MAX_UNBONDING_CHUNKS}): withdraw or wait for an unlock")]
+    TooManyChunks,
+}
+
+// =============================================================================
+// DELEGATOR REWARDS
+// =============================================================================
+//
+// The delegators' share of a block reward is not credited right away: it is
+// recorded in each delegator's reward ledger under the epoch it was earned
+// in. Once that epoch is over, a ClaimRewards transaction moves everything
+// owed to the free balance. The ledger keeps the last REWARD_HISTORY_EPOCHS
+// epochs apart and folds older ones into a carried amount, so the record of
+// an account that never claims stays bounded.
+
+/// Epochs a reward ledger keeps apart
+pub const REWARD_HISTORY_EPOCHS: usize = 32;
+
+/// Rewards owed to a delegator
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RewardLedger {
+    /// Rewards of epochs older than those in `epochs`
+    pub carried: Balance,
+
+    /// Rewards earned in each epoch
+    pub epochs: BTreeMap<EpochNumber, Balance>,
+}
+
+impl RewardLedger {
+    /// Record `amount` earned during `epoch`
+    pub fn credit(&mut self, epoch: EpochNumber, amount: Balance) {
+        let earned = self.epochs.entry(epoch).or_insert(0);
+        *earned = earned.saturating_add(amount);
+        while self.epochs.len() > REWARD_HISTORY_EPOCHS {
+            if let Some((_, oldest)) = self.epochs.pop_first() {
+                self.carried = self.carried.saturating_add(oldest);
+            }
+        }
+    }
+
+    /// Total owed, claimable or not
+    pub fn total(&self) -> Balance {
+        self.epochs.values().fold(self.carried, |total, amount| total.saturating_add(*amount))
+    }
+
+    /// Amount earned in the epochs before `current_epoch`
+    pub fn claimable(&self, current_epoch: EpochNumber) -> Balance {
+        self.epochs
+            .range(..current_epoch)
+            .fold(self.carried, |total, (_, amount)| total.saturating_add(*amount))
+    }
+
+    /// Remove the rewards of the epochs before `current_epoch`, returning their total
+    pub fn claim(&mut self, current_epoch: EpochNumber) -> Balance {
+        let claimable = self.claimable(current_epoch);
+        self.epochs = self.epochs.split_off(&current_epoch);
+        self.carried = 0;
+        claimable
+    }
+
+    /// Nothing owed
+    pub fn is_empty(&self) -> bool {
+        self.carried == 0 && self.epochs.is_empty()
+    }
+}
+
+// =============================================================================
+// VALIDATOR COMMISSION
+// =============================================================================
+//
+// A validator sets its commission with SetCommission. The new rate is
+// announced for the next epoch, so delegators see it coming, and may exceed
+// the rate in force by at most MAX_COMMISSION_INCREASE_PER_EPOCH points:
+// raising the commission takes as many epochs as points. Lowering it is not
+// bounded.
+
+/// Highest commission, in percent
+pub const MAX_COMMISSION_PERCENT: u8 = 100;
+
+/// Largest commission increase per epoch, in percentage points
+pub const MAX_COMMISSION_INCREASE_PER_EPOCH: u8 = 1;
+
+/// Commission of a validator: in force and announced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommissionRecord {
+    /// Commission in force until the announced one takes effect, in percent
+    pub current: u8,
+
+    /// Commission set by the last SetCommission, with the epoch it takes effect
+    pub announced: Option<(u8, EpochNumber)>,
+}
+
+impl Default for CommissionRecord {
+    fn default() -> Self {
+        Self { current: DEFAULT_COMMISSION_PERCENT, announced: None }
+    }
+}
+
+impl CommissionRecord {
+    /// Commission in force at `epoch`
+    pub fn at(&self, epoch: EpochNumber) -> u8 {
+        match self.announced {
+            Some((commission, effective)) if epoch >= effective => commission,
+            _ => self.current,
+        }
+    }
+
+    /// Announcement not yet in force at `epoch`
+    pub fn pending(&self, epoch: EpochNumber) -> Option<(u8, EpochNumber)> {
+        self.announced.filter(|(_, effective)| *effective > epoch)
+    }
+
+    /// Announce `commission` for the epoch after `current_epoch`, returning that epoch
+    ///
+    /// An announcement already in effect becomes current; one not yet in
+    /// effect is replaced.
+    pub fn announce(&mut self, commission: u8, current_epoch: EpochNumber) -> Result<EpochNumber, CommissionError> {
+        if commission > MAX_COMMISSION_PERCENT {
+            return Err(CommissionError::AboveMaximum);
+        }
+        if let Some((announced, effective)) = self.announced {
+            if current_epoch >= effective {
+                self.current = announced;
+                self.announced = None;
+            }
+        }
+        if commission == self.announced.map_or(self.current, |(announced, _)| announced) {
+            return Err(CommissionError::Unchanged);
+        }
+        let max = self.current.saturating_add(MAX_COMMISSION_INCREASE_PER_EPOCH).min(MAX_COMMISSION_PERCENT);
+        if commission > max {
+            return Err(CommissionError::IncreaseTooLarge { max });
+        }
+        let effective = current_epoch.saturating_add(1);
+        self.announced = Some((commission, effective));
+        Ok(effective)
+    }
+}
+
+/// Rejected SetCommission transaction
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum CommissionError {
+    #[error("Sender is not a validator")]
+    NotValidator,
+
+    #[error("Commission above {MAX_COMMISSION_PERCENT}%")]
+    AboveMaximum,
+
+    #[error("Commission can rise to {max}% at most next epoch")]
+    IncreaseTooLarge { max: u8 },
+
+    #[error("Commission already set")]
+    Unchanged,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -333,4 +712,125 @@ mod tests {
         let validator = registry.get_validator(&validator_id).unwrap();
         assert_eq!(validator.stake, MIN_VALIDATOR_STAKE - slash_amount);
     }
+
+    #[test]
+    fn test_delegation_pool() {
+        let mut pool = DelegationPool::default();
+        let alice = AccountId::from_bytes([2; 32]);
+
+        assert_eq!(pool.delegate(alice, MIN_DELEGATION - 1), Err(DelegationError::BelowMinimum));
+        pool.delegate(alice, MIN_DELEGATION).unwrap();
+        pool.delegate(alice, 50 * KRAT).unwrap();
+        assert_eq!(pool.total(), 150 * KRAT);
+
+        // Partial undelegation must leave at least the minimum
+        assert_eq!(pool.undelegate(&alice, 100 * KRAT), Err(DelegationError::BelowMinimum));
+        pool.undelegate(&alice, 50 * KRAT).unwrap();
+        pool.undelegate(&alice, MIN_DELEGATION).unwrap();
+        assert!(pool.delegators.is_empty());
+        assert_eq!(pool.undelegate(&alice, KRAT), Err(DelegationError::NotDelegated));
+    }
+
+    #[test]
+    fn test_reward_shares() {
+        let mut pool = DelegationPool::default();
+        let alice = AccountId::from_bytes([2; 32]);
+        let bob = AccountId::from_bytes([3; 32]);
+        pool.delegate(alice, 300 * KRAT).unwrap();
+        pool.delegate(bob, 100 * KRAT).unwrap();
+
+        // Validator 600, delegators 400: 400 of 1000 to the pool, 10% commission
+        let shares = pool.reward_shares(1000 * KRAT, 600 * KRAT, DEFAULT_COMMISSION_PERCENT);
+        assert_eq!(shares.delegators, vec![(alice, 270 * KRAT), (bob, 90 * KRAT)]);
+        assert_eq!(shares.validator, 640 * KRAT);
+
+        // All of it with a full commission
+        let kept = pool.reward_shares(1000 * KRAT, 600 * KRAT, MAX_COMMISSION_PERCENT);
+        assert_eq!(kept.validator, 1000 * KRAT);
+        assert!(kept.delegators.is_empty());
+
+        let none = DelegationPool::default().reward_shares(1000 * KRAT, 600 * KRAT, DEFAULT_COMMISSION_PERCENT);
+        assert_eq!(none.validator, 1000 * KRAT);
+        assert!(none.delegators.is_empty());
+    }
+
+    #[test]
+    fn test_unbonding_queue() {
+        let mut queue = UnbondingQueue::default();
+        let epoch = UnbondingQueue::unlock_epoch(10);
+        assert!(UnbondingQueue::unlock_block(epoch) >= 10 + UNBONDING_PERIOD);
+        assert_eq!(queue.schedule(0, 10), Err(UnbondingError::ZeroAmount));
+
+        // Partial unbonds of the same epoch share a chunk
+        assert_eq!(queue.schedule(100 * KRAT, 10), Ok(epoch));
+        assert_eq!(queue.schedule(50 * KRAT, 20), Ok(epoch));
+        let later = queue.schedule(30 * KRAT, 10 + EPOCH_DURATION_BLOCKS).unwrap();
+        assert_eq!(later, epoch + 1);
+        assert_eq!(queue.chunks.len(), 2);
+        assert_eq!(queue.total(), 180 * KRAT);
+
+        // Nothing before the unlock epoch starts
+        let unlock = UnbondingQueue::unlock_block(epoch);
+        assert_eq!(queue.withdraw(unlock - 1), 0);
+        assert_eq!(queue.withdrawable(unlock), 150 * KRAT);
+        assert_eq!(queue.withdraw(unlock), 150 * KRAT);
+        assert_eq!(queue.chunks, BTreeMap::from([(later, 30 * KRAT)]));
+
+        // Bounded number of pending epochs
+        let mut full = UnbondingQueue::default();
+        for i in 0..MAX_UNBONDING_CHUNKS as BlockNumber {
+            full.schedule(KRAT, i * EPOCH_DURATION_BLOCKS).unwrap();
+        }
+        let last = (MAX_UNBONDING_CHUNKS as BlockNumber - 1) * EPOCH_DURATION_BLOCKS;
+        assert!(full.schedule(KRAT, last).is_ok());
+        assert_eq!(full.schedule(KRAT, last + EPOCH_DURATION_BLOCKS), Err(UnbondingError::TooManyChunks));
+    }
+
+    #[test]
+    fn test_reward_ledger() {
+        let mut ledger = RewardLedger::default();
+        ledger.credit(3, 10 * KRAT);
+        ledger.credit(3, 5 * KRAT);
+        ledger.credit(4, 7 * KRAT);
+        assert_eq!(ledger.total(), 22 * KRAT);
+
+        // The running epoch is not claimable yet
+        assert_eq!(ledger.claimable(4), 15 * KRAT);
+        assert_eq!(ledger.claim(4), 15 * KRAT);
+        assert_eq!(ledger.epochs, BTreeMap::from([(4, 7 * KRAT)]));
+        assert_eq!(ledger.claim(5), 7 * KRAT);
+        assert!(ledger.is_empty());
+
+        // Old epochs are folded into the carried amount
+        for epoch in 0..REWARD_HISTORY_EPOCHS as EpochNumber + 2 {
+            ledger.credit(epoch, KRAT);
+        }
+        assert_eq!(ledger.epochs.len(), REWARD_HISTORY_EPOCHS);
+        assert_eq!(ledger.carried, 2 * KRAT);
+        assert_eq!(ledger.claimable(2), 2 * KRAT);
+    }
+
+    #[test]
+    fn test_commission_change_rate() {
+        let mut record = CommissionRecord::default();
+        assert_eq!(record.at(0), DEFAULT_COMMISSION_PERCENT);
+
+        // Raised by one point per epoch at most, from the next epoch
+        assert_eq!(record.announce(15, 4), Err(CommissionError::IncreaseTooLarge { max: 11 }));
+        assert_eq!(record.announce(11, 4), Ok(5));
+        assert_eq!((record.at(4), record.at(5)), (10, 11));
+        assert_eq!(record.pending(4), Some((11, 5)));
+        assert_eq!(record.announce(11, 4), Err(CommissionError::Unchanged));
+        assert_eq!(record.announce(12, 4), Err(CommissionError::IncreaseTooLarge { max: 11 }));
+
+        // Once in force, the next point can be announced
+        assert_eq!(record.announce(12, 5), Ok(6));
+        assert_eq!(record.current, 11);
+        assert_eq!(record.pending(6), None);
+
+        // Lowering is not bounded
+        assert_eq!(record.announce(0, 5), Ok(6));
+        assert_eq!(record.at(6), 0);
+        assert_eq!(record.announce(101, 7), Err(CommissionError::AboveMaximum));
+    }
 }
@@ -1,5 +1,6 @@
 // Gas - Système de gas metering pour éviter les DoS
 use crate::types::Balance;
+use serde::{Deserialize, Serialize};
 
 /// Unité de gas (1 KRAT = 1_000_000_000_000 units)
 pub const GAS_UNIT: u64 = 1_000_000;
@@ -208,6 +209,84 @@ impl BlockGasMeter {
     }
 }
 
+/// Adaptive base fee (EIP-1559 style)
+///
+/// The base fee scales the static fee of every call (TransactionCall::base_fee):
+/// a base fee of BASE_FEE_PRECISION charges exactly the static fee. It moves
+/// with the fullness of the parent block: up when the parent held more than
+/// half of BLOCK_TRANSACTION_CAPACITY, down when it held less, by at most
+/// 1/BASE_FEE_MAX_CHANGE_DENOMINATOR per block. Every node derives it from the
+/// chain, so it needs no header field.
+pub const BASE_FEE_PRECISION: u64 = 1_000_000;
+
+/// Base fee of the first block, and floor: the static fees stay the minimum
+pub const MIN_BASE_FEE: u64 = BASE_FEE_PRECISION;
+
+/// Ceiling: 1000x the static fees
+pub const MAX_BASE_FEE: u64 = 1_000 * BASE_FEE_PRECISION;
+
+/// Transactions in a full block (default ProducerConfig::max_transactions_per_block)
+pub const BLOCK_TRANSACTION_CAPACITY: u64 = 1_000;
+
+/// Largest change between two blocks: 1/8 (12.5%) of the parent base fee
+pub const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+
+/// Base fee of a block, from the base fee and transaction count of its parent
+pub fn next_base_fee(parent_base_fee: u64, parent_transactions: u64) -> u64 {
+    let target = (BLOCK_TRANSACTION_CAPACITY / 2) as u128;
+    let used = parent_transactions.min(BLOCK_TRANSACTION_CAPACITY) as u128;
+    let parent = parent_base_fee as u128;
+    let denominator = BASE_FEE_MAX_CHANGE_DENOMINATOR as u128;
+
+    let next = if used > target {
+        // Always move up, even when the step rounds to zero
+        parent + (parent * (used - target) / target / denominator).max(1)
+    } else {
+        parent - parent * (target - used) / target / denominator
+    };
+    (next.min(MAX_BASE_FEE as u128) as u64).max(MIN_BASE_FEE)
+}
+
+/// Fee charged for a call of static fee `static_fee` at `base_fee`
+pub fn scale_fee(static_fee: Balance, base_fee: u64) -> Balance {
+    static_fee.saturating_mul(base_fee as Balance) / BASE_FEE_PRECISION as Balance
+}
+
+/// Fee market record of an executed block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockFee {
+    /// Base fee the block's transactions paid
+    pub base_fee: u64,
+
+    /// Transactions included in the block
+    pub transactions: u32,
+}
+
+impl BlockFee {
+    /// Share of BLOCK_TRANSACTION_CAPACITY used (0.0 - 1.0)
+    pub fn fullness(&self) -> f64 {
+        (self.transactions as f64 / BLOCK_TRANSACTION_CAPACITY as f64).min(1.0)
+    }
+
+    /// Base fee of the next block
+    pub fn next_base_fee(&self) -> u64 {
+        next_base_fee(self.base_fee, self.transactions as u64)
+    }
+}
+
+/// Fee records of a range of recent blocks (fee_history RPC)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeHistory {
+    /// First block of `blocks`
+    pub oldest_block: u64,
+
+    /// One record per block, oldest first
+    pub blocks: Vec<BlockFee>,
+
+    /// Base fee of the block after the newest one
+    pub next_base_fee: u64,
+}
+
 /// Erreurs de gas
 #[derive(Debug, thiserror::Error, Clone)]
 pub enum GasError {
@@ -300,6 +379,27 @@ mod tests {
         assert_eq!(block_meter.used(), 8000);
     }
 
+    #[test]
+    fn test_base_fee_follows_fullness() {
+        let half = BLOCK_TRANSACTION_CAPACITY / 2;
+
+        // Full block: +12.5%, capped at MAX_BASE_FEE
+        assert_eq!(next_base_fee(8 * MIN_BASE_FEE, BLOCK_TRANSACTION_CAPACITY), 9 * MIN_BASE_FEE);
+        assert_eq!(next_base_fee(MAX_BASE_FEE, BLOCK_TRANSACTION_CAPACITY), MAX_BASE_FEE);
+
+        // Half full: unchanged; just over half: rises
+        assert_eq!(next_base_fee(2 * MIN_BASE_FEE, half), 2 * MIN_BASE_FEE);
+        assert!(next_base_fee(2 * MIN_BASE_FEE, half + 1) > 2 * MIN_BASE_FEE);
+
+        // Empty block: -12.5%, never below MIN_BASE_FEE
+        assert_eq!(next_base_fee(8 * MIN_BASE_FEE, 0), 7 * MIN_BASE_FEE);
+        assert_eq!(next_base_fee(MIN_BASE_FEE, 0), MIN_BASE_FEE);
+
+        // The static fee is charged at MIN_BASE_FEE
+        assert_eq!(scale_fee(1_000, MIN_BASE_FEE), 1_000);
+        assert_eq!(scale_fee(1_000, next_base_fee(MIN_BASE_FEE, BLOCK_TRANSACTION_CAPACITY)), 1_125);
+    }
+
     #[test]
     fn test_gas_costs_reasonable() {
         // Vérifier que les coûts sont cohérents
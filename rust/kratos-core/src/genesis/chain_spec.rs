@@ -0,0 +1,293 @@
+// Chain spec - Portable JSON description of a chain and its genesis
+// Principle: A testnet is reproducible from one file
+//
+// `build-spec` writes a ChainSpec and `run --chain <file.json>` starts from
+// it: chain identity and bootnodes, genesis balances and validators, the
+// bootstrap era configuration and the constitutional parameters. Accounts are
+// hex strings. The bootstrap configuration is protocol-wide: a spec carries it
+// for review and is refused if it differs from the node's. Parameter values
+// may move within their constitutional bounds, the bounds may not.
+//
+// The genesis state root only commits to accounts: every node of a chain,
+// joining ones included, must load the same spec.
+
+use crate::consensus::economics::{get_bootstrap_config, BootstrapConfig};
+use crate::contracts::krat::TokenomicsState;
+use crate::genesis::config::{ChainConfig, ConsensusConfig, NetworkConfig, TokenomicsConfig};
+use crate::genesis::spec::{GenesisSpec, GenesisValidator};
+use crate::types::{AccountId, Balance, ParameterError, ProtocolParameters, Timestamp};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Default P2P port of a spec (`run --port` overrides it)
+const DEFAULT_LISTEN_PORT: u16 = 30333;
+
+/// Chain spec file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainSpec {
+    pub name: String,
+    pub chain_id: u32,
+    pub protocol_name: String,
+    pub protocol_version: u32,
+    /// Multiaddrs of the chain's bootnodes
+    #[serde(default)]
+    pub bootnodes: Vec<String>,
+    pub consensus: ConsensusConfig,
+    pub tokenomics: TokenomicsConfig,
+    pub genesis: ChainSpecGenesis,
+    /// Bootstrap era configuration (protocol-wide, checked on load)
+    pub bootstrap: BootstrapConfig,
+    /// Constitutional parameters in force at genesis
+    pub parameters: ProtocolParameters,
+}
+
+/// Genesis state of a chain spec
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainSpecGenesis {
+    pub timestamp: Timestamp,
+    /// Initial free balances, sorted by account
+    #[serde(default)]
+    pub balances: Vec<ChainSpecBalance>,
+    pub validators: Vec<ChainSpecValidator>,
+    pub tokenomics: TokenomicsState,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainSpecBalance {
+    /// Hex account (0x...)
+    pub account: String,
+    pub balance: Balance,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainSpecValidator {
+    /// Hex account (0x...)
+    pub account: String,
+    /// Reserved from the account's genesis balance (0 for a bootstrap validator)
+    pub stake: Balance,
+    /// Bootstrap validator: no stake, produces blocks during the bootstrap era
+    #[serde(default)]
+    pub bootstrap: bool,
+}
+
+impl ChainSpec {
+    /// Spec of a chain config and genesis
+    pub fn new(chain: &ChainConfig, genesis: &GenesisSpec) -> Self {
+        let mut balances: Vec<_> = genesis
+            .balances
+            .iter()
+            .map(|(account, balance)| ChainSpecBalance { account: account_hex(account), balance: *balance })
+            .collect();
+        balances.sort_by(|a, b| a.account.cmp(&b.account));
+
+        Self {
+            name: chain.chain_name.clone(),
+            chain_id: chain.chain_id,
+            protocol_name: chain.network.protocol_name.clone(),
+            protocol_version: chain.network.protocol_version,
+            bootnodes: chain.network.bootnodes.clone(),
+            consensus: chain.consensus.clone(),
+            tokenomics: chain.tokenomics.clone(),
+            genesis: ChainSpecGenesis {
+                timestamp: genesis.timestamp,
+                balances,
+                validators: genesis
+                    .validators
+                    .iter()
+                    .map(|v| ChainSpecValidator {
+                        account: account_hex(&v.account),
+                        stake: v.stake,
+                        bootstrap: v.is_bootstrap_validator,
+                    })
+                    .collect(),
+                tokenomics: genesis.tokenomics.clone(),
+            },
+            bootstrap: get_bootstrap_config(),
+            parameters: genesis.parameters.clone(),
+        }
+    }
+
+    /// Unified KratOs chain
+    pub fn mainnet() -> Self {
+        Self::new(&ChainConfig::mainnet(), &GenesisSpec::mainnet())
+    }
+
+    /// Check the spec and convert it to the node's chain config and genesis
+    pub fn into_genesis(self) -> Result<(ChainConfig, GenesisSpec), ChainSpecError> {
+        let expected = serde_json::to_string(&get_bootstrap_config())?;
+        if serde_json::to_string(&self.bootstrap)? != expected {
+            return Err(ChainSpecError::BootstrapMismatch);
+        }
+        self.parameters.validate_genesis().map_err(ChainSpecError::InvalidParameters)?;
+
+        let mut balances = HashMap::new();
+        for entry in &self.genesis.balances {
+            let account = parse_account(&entry.account)?;
+            if balances.insert(account, entry.balance).is_some() {
+                return Err(ChainSpecError::DuplicateAccount(entry.account.clone()));
+            }
+        }
+
+        if self.genesis.validators.is_empty() {
+            return Err(ChainSpecError::NoValidators);
+        }
+        let mut validators: Vec<GenesisValidator> = Vec::with_capacity(self.genesis.validators.len());
+        for entry in &self.genesis.validators {
+            let account = parse_account(&entry.account)?;
+            if validators.iter().any(|v| v.account == account) {
+                return Err(ChainSpecError::DuplicateAccount(entry.account.clone()));
+            }
+            if entry.bootstrap && entry.stake != 0 {
+                return Err(ChainSpecError::InvalidValidator(format!(
+                    "{}: bootstrap validators have no stake",
+                    entry.account
+                )));
+            }
+            if !entry.bootstrap && (entry.stake == 0 || balances.get(&account).copied().unwrap_or(0) < entry.stake) {
+                return Err(ChainSpecError::InvalidValidator(format!(
+                    "{}: stake must be non-zero and covered by its genesis balance",
+                    entry.account
+                )));
+            }
+            validators.push(GenesisValidator {
+                account,
+                stake: entry.stake,
+                is_bootstrap_validator: entry.bootstrap,
+            });
+        }
+
+        let chain = ChainConfig {
+            chain_name: self.name,
+            chain_id: self.chain_id,
+            consensus: self.consensus,
+            network: NetworkConfig {
+                listen_port: DEFAULT_LISTEN_PORT,
+                bootnodes: self.bootnodes,
+                protocol_name: self.protocol_name,
+                protocol_version: self.protocol_version,
+                relay: false,
+                public_addr: None,
+                quic_port: None,
+                mdns: false,
+                reserved_peers: vec![],
+                reserved_only: false,
+                sentry_for: vec![],
+            },
+            tokenomics: self.tokenomics,
+        };
+        let genesis = GenesisSpec {
+            timestamp: self.genesis.timestamp,
+            balances,
+            validators,
+            tokenomics: self.genesis.tokenomics,
+            parameters: self.parameters,
+        };
+        Ok((chain, genesis))
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ChainSpecError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, ChainSpecError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, ChainSpecError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn to_file(&self, path: &str) -> Result<(), ChainSpecError> {
+        Ok(std::fs::write(path, self.to_json()? + "\n")?)
+    }
+}
+
+fn account_hex(account: &AccountId) -> String {
+    format!("0x{}", hex::encode(account.as_bytes()))
+}
+
+fn parse_account(hex_str: &str) -> Result<AccountId, ChainSpecError> {
+    let invalid = || ChainSpecError::InvalidAccount(hex_str.to_string());
+    let bytes = hex::decode(hex_str.strip_prefix("0x").unwrap_or(hex_str)).map_err(|_| invalid())?;
+    let bytes: [u8; 32] = bytes.try_into().map_err(|_| invalid())?;
+    Ok(AccountId::from_bytes(bytes))
+}
+
+/// Chain spec errors
+#[derive(Debug, thiserror::Error)]
+pub enum ChainSpecError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Invalid account (expected 32 hex bytes): {0}")]
+    InvalidAccount(String),
+
+    #[error("Account listed twice: {0}")]
+    DuplicateAccount(String),
+
+    #[error("No genesis validator")]
+    NoValidators,
+
+    #[error("Invalid genesis validator {0}")]
+    InvalidValidator(String),
+
+    #[error("Bootstrap configuration differs from the protocol's (it is not configurable per chain)")]
+    BootstrapMismatch,
+
+    #[error("Invalid constitutional parameters: {0:?}")]
+    InvalidParameters(ParameterError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_spec_roundtrip() {
+        let alice = AccountId::from_bytes([1; 32]);
+        let bob = AccountId::from_bytes([2; 32]);
+        let mut genesis = GenesisSpec::with_validator(alice);
+        genesis.balances.insert(bob, 50_000);
+        genesis.validators.push(GenesisValidator { account: bob, stake: 20_000, is_bootstrap_validator: false });
+        assert!(genesis.parameters.governance.voting_period.try_set(20_000));
+
+        let json = ChainSpec::new(&ChainConfig::mainnet(), &genesis).to_json().unwrap();
+        assert!(json.contains("\"0x0101010101010101010101010101010101010101010101010101010101010101\""));
+
+        let (chain, loaded) = ChainSpec::from_json(&json).unwrap().into_genesis().unwrap();
+        assert_eq!(chain.chain_name, "KratOs");
+        assert_eq!(loaded.balances, genesis.balances);
+        assert_eq!(loaded.validators.len(), 2);
+        assert!(loaded.validators[0].is_bootstrap_validator);
+        assert_eq!((loaded.validators[1].account, loaded.validators[1].stake), (bob, 20_000));
+        assert_eq!(loaded.parameters.governance.voting_period.value(), 20_000);
+    }
+
+    #[test]
+    fn test_chain_spec_rejected() {
+        let reject = |edit: fn(&mut ChainSpec)| {
+            let mut spec = ChainSpec::mainnet();
+            edit(&mut spec);
+            spec.into_genesis().unwrap_err()
+        };
+
+        assert!(matches!(reject(|s| s.bootstrap.end_epoch += 1), ChainSpecError::BootstrapMismatch));
+        assert!(matches!(
+            reject(|s| s.parameters.economics.fee_shares.burn = 0),
+            ChainSpecError::InvalidParameters(ParameterError::InvalidFeeShares)
+        ));
+        assert!(matches!(reject(|s| s.genesis.validators.clear()), ChainSpecError::NoValidators));
+        assert!(matches!(
+            reject(|s| s.genesis.validators[0].account = "0x1234".to_string()),
+            ChainSpecError::InvalidAccount(_)
+        ));
+        // A regular validator's stake comes out of its genesis balance
+        assert!(matches!(
+            reject(|s| s.genesis.validators[0].bootstrap = false),
+            ChainSpecError::InvalidValidator(_)
+        ));
+    }
+}
@@ -54,6 +54,34 @@ pub struct NetworkConfig {
 
     /// Version du protocole
     pub protocol_version: u32,
+
+    /// Relaie les connexions des nœuds injoignables (--relay)
+    #[serde(default)]
+    pub relay: bool,
+
+    /// Adresse publique annoncée (--public-addr)
+    #[serde(default)]
+    pub public_addr: Option<String>,
+
+    /// Port UDP d'écoute QUIC, en plus de TCP (--listen-quic)
+    #[serde(default)]
+    pub quic_port: Option<u16>,
+
+    /// Découverte des nœuds du réseau local par mDNS (--no-mdns pour désactiver)
+    #[serde(default)]
+    pub mdns: bool,
+
+    /// Peers réservés, toujours connectés et jamais évincés (--reserved-peer)
+    #[serde(default)]
+    pub reserved_peers: Vec<String>,
+
+    /// N'accepte que les peers réservés (--reserved-only, architecture sentry)
+    #[serde(default)]
+    pub reserved_only: bool,
+
+    /// Validateurs protégés par ce nœud sentry, adresses jamais partagées (--sentry-for)
+    #[serde(default)]
+    pub sentry_for: Vec<String>,
 }
 
 /// Configuration de la tokenomics
@@ -87,6 +115,13 @@ impl ChainConfig {
                 bootnodes: vec![],
                 protocol_name: "/kratos/1.0.0".to_string(),
                 protocol_version: 1,
+                relay: false,
+                public_addr: None,
+                quic_port: None,
+                mdns: false,
+                reserved_peers: vec![],
+                reserved_only: false,
+                sentry_for: vec![],
             },
             tokenomics: TokenomicsConfig {
                 initial_supply: INITIAL_SUPPLY,
@@ -1,6 +1,8 @@
 // Genesis - Configuration et bloc genesis
+pub mod chain_spec;
 pub mod config;
 pub mod spec;
 
+pub use chain_spec::{ChainSpec, ChainSpecError};
 pub use config::ChainConfig;
 pub use spec::{GenesisSpec, GenesisBuilder};
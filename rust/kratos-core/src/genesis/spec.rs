@@ -20,6 +20,10 @@ pub struct GenesisSpec {
 
     /// État tokenomics initial
     pub tokenomics: TokenomicsState,
+
+    /// Constitutional parameters in force at genesis
+    #[serde(default = "ProtocolParameters::genesis")]
+    pub parameters: ProtocolParameters,
 }
 
 /// Validateur dans le genesis
@@ -67,6 +71,7 @@ impl GenesisSpec {
                 is_bootstrap_validator: true, // Constitutional exception for block production
             }],
             tokenomics: TokenomicsState::genesis(),
+            parameters: ProtocolParameters::genesis(),
         }
     }
 
@@ -86,6 +91,7 @@ impl GenesisSpec {
                 is_bootstrap_validator: true, // Constitutional exception for block production
             }],
             tokenomics: TokenomicsState::genesis(),
+            parameters: ProtocolParameters::genesis(),
         }
     }
 
@@ -177,6 +183,9 @@ impl GenesisSpec {
             }
         }
 
+        state.set_protocol_parameters(&self.parameters)
+            .map_err(|e| format!("Error setting protocol parameters: {:?}", e))?;
+
         Ok(validator_set)
     }
 }
@@ -254,6 +263,10 @@ impl GenesisBuilder {
             }
         }
 
+        // Paramètres constitutionnels (hors state root, qui ne couvre que les comptes)
+        state.set_protocol_parameters(&self.spec.parameters)
+            .map_err(|e| format!("Erreur set_protocol_parameters: {:?}", e))?;
+
         // Crée le bloc genesis
         // TODO: ChainId should be configured, not hardcoded
         let chain_id = ChainId(0);
@@ -273,9 +286,11 @@ impl GenesisBuilder {
             parent_hash: Hash::ZERO,
             transactions_root: Hash::ZERO,
             state_root: state_root_computed.root,
+            receipts_root: Hash::ZERO,
             timestamp: self.spec.timestamp,
             epoch: 0,
             slot: 0,
+            protocol_version: self.spec.parameters.version,
             author: AccountId::from_bytes([0; 32]), // Pas d'auteur pour genesis
             signature: Signature64([0; 64]),        // Pas de signature pour genesis
         };
@@ -294,7 +309,7 @@ impl GenesisBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::storage::db::Database;
+    use crate::storage::db::{Database, RocksDatabase};
     use tempfile::tempdir;
 
     #[test]
@@ -325,7 +340,7 @@ mod tests {
     #[test]
     fn test_genesis_builder_with_custom_validator() {
         let dir = tempdir().unwrap();
-        let db = Database::open(dir.path().to_str().unwrap()).unwrap();
+        let db = RocksDatabase::open(dir.path().to_str().unwrap()).unwrap();
         let mut state = StateBackend::new(db);
 
         let alice = AccountId::from_bytes([1u8; 32]);
@@ -354,7 +369,7 @@ mod tests {
     #[test]
     fn test_genesis_validators_bootstrap() {
         let dir = tempdir().unwrap();
-        let db = Database::open(dir.path().to_str().unwrap()).unwrap();
+        let db = RocksDatabase::open(dir.path().to_str().unwrap()).unwrap();
         let mut state = StateBackend::new(db);
 
         let alice = AccountId::from_bytes([1u8; 32]);
@@ -378,7 +393,7 @@ mod tests {
     fn test_bootstrap_validator_genesis() {
         // Bootstrap validator has 0 stake and produces blocks
         let dir = tempdir().unwrap();
-        let db = Database::open(dir.path().to_str().unwrap()).unwrap();
+        let db = RocksDatabase::open(dir.path().to_str().unwrap()).unwrap();
         let mut state = StateBackend::new(db);
 
         let spec = GenesisSpec::mainnet();
@@ -20,39 +20,66 @@ mod types;
 mod tests;
 
 use clap::Parser;
-use cli::{Cli, Commands, KeySubcommand};
+use cli::{Cli, Commands, ConfigSubcommand, DbSubcommand, KeySubcommand, PeersSubcommand, SnapshotSubcommand};
 use cli::config::NodeConfig;
+use cli::info::{print_report, query_info, InfoFormat};
 use cli::runner::run_node;
+use rpc::RpcToken;
 use tracing::{error, info, warn};
-use tracing_subscriber::EnvFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use cli::logging::{FileOptions, LogFormat};
+use tracing_subscriber::Layer;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    // Parse CLI arguments
-    let cli = Cli::parse();
+    // Parse CLI arguments (over the run --config file)
+    let cli = cli::config_file::parse_cli()?;
 
     // Check if --debug-grandpa is passed (need to peek into Run command)
     let debug_grandpa = matches!(&cli.command, Commands::Run(cmd) if cmd.debug_grandpa);
 
     // Initialize logging based on verbosity and debug-grandpa mode
-    let log_filter = if debug_grandpa {
-        // Debug GRANDPA: enable trace logs for finality modules
-        "info,kratos_core::consensus::finality=trace,kratos_core::node::finality_integration=trace"
-    } else if cli.verbose {
-        "debug"
-    } else {
-        &cli.log_level
+    let log_filter = cli::logging::directives(cli.verbose, &cli.log_level, debug_grandpa);
+    let log_format: LogFormat = cli.log_format.parse()?;
+    let log_file = match &cli.log_dir {
+        Some(dir) => Some(FileOptions {
+            dir: dir.clone(),
+            rotation: cli.log_rotation.parse()?,
+            max_size: cli.log_max_size * 1024 * 1024,
+            max_files: cli.log_max_files,
+        }),
+        None => None,
+    };
+    let (mut log_layers, log_reload) = cli::logging::layers(log_format, log_file, &log_filter)?;
+
+    // Optional OTLP export of the node's spans (kept alive to flush on exit)
+    let _otlp_guard = match &cli.command {
+        Commands::Run(cmd) => match &cmd.tracing_endpoint {
+            Some(endpoint) => {
+                let (layer, guard) = cli::otlp::layer(endpoint, cmd.name.as_deref())
+                    .map_err(|e| anyhow::anyhow!("Invalid tracing endpoint {}: {}", endpoint, e))?;
+                log_layers.push(layer.with_filter(cli::otlp::span_filter()).boxed());
+                Some(guard)
+            }
+            None => None,
+        },
+        _ => None,
     };
 
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| EnvFilter::new(log_filter)),
-        )
-        .init();
+    tracing_subscriber::registry().with(log_layers).init();
 
-    // Print banner
-    print_banner();
+    // Print banner (stdout stays parseable line by line in JSON, and a
+    // generated config template, audit report, chain spec or peers export
+    // stays valid)
+    if log_format == LogFormat::Text
+        && !matches!(
+            cli.command,
+            Commands::Config(_) | Commands::Audit(_) | Commands::BuildSpec(_) | Commands::Peers(_)
+        )
+    {
+        print_banner();
+    }
 
     // Execute command
     match cli.command {
@@ -60,6 +87,13 @@ async fn main() -> anyhow::Result<()> {
             if cmd.debug_grandpa {
                 info!("🔧 GRANDPA debug enabled - finality traces at TRACE level");
             }
+            if let Some(endpoint) = &cmd.tracing_endpoint {
+                info!("📡 Exporting tracing spans to {}", endpoint);
+            }
+
+            if cmd.rpc_cors_all {
+                warn!("⚠️  --rpc-cors-all is deprecated, use --rpc-cors \"*\"");
+            }
 
             // Build node configuration from CLI args
             let config = NodeConfig::from_run_cmd(&cmd).map_err(|e| {
@@ -68,17 +102,16 @@ async fn main() -> anyhow::Result<()> {
             })?;
 
             // Run the node
-            if let Err(e) = run_node(config).await {
+            if let Err(e) = run_node(config, log_reload).await {
                 error!("Node error: {}", e);
                 return Err(anyhow::anyhow!("Node error: {}", e));
             }
         }
 
         Commands::Info(cmd) => {
-            info!("Querying node at {}", cmd.rpc);
-            // TODO: Implement RPC client to query node info
-            println!("Node info query not yet implemented");
-            println!("Try: curl -X POST {} -H 'Content-Type: application/json' -d '{{\"jsonrpc\":\"2.0\",\"method\":\"system_info\",\"params\":[],\"id\":1}}'", cmd.rpc);
+            let format: InfoFormat = cmd.format.parse()?;
+            let report = query_info(&cmd).await?;
+            print_report(&report, format)?;
         }
 
         Commands::Key(cmd) => {
@@ -89,25 +122,167 @@ async fn main() -> anyhow::Result<()> {
                 KeySubcommand::Inspect { key, scheme } => {
                     inspect_key(&key, &scheme)?;
                 }
-                KeySubcommand::Insert { base_path, key_type, scheme, suri } => {
+                KeySubcommand::Insert { base_path, key_type, scheme, suri, password } => {
                     info!("Inserting key of type {} into keystore", key_type);
-                    // TODO: Implement keystore
-                    warn!("Keystore not yet implemented");
+                    insert_key(base_path, &key_type, &scheme, &suri, password)?;
                 }
                 KeySubcommand::List { base_path } => {
                     info!("Listing keys in keystore");
-                    // TODO: Implement keystore listing
-                    warn!("Keystore not yet implemented");
+                    list_keys(base_path)?;
                 }
             }
         }
 
         Commands::Export(cmd) => {
             info!("Exporting chain data to {}", cmd.output.display());
-            // TODO: Implement chain export
-            warn!("Chain export not yet implemented");
+            let count = cli::export::export_chain(&cmd)?;
+            info!("Exported {} blocks to {}", count, cmd.output.display());
+        }
+
+        Commands::Import(cmd) => {
+            info!("Importing chain data from {}", cmd.input.display());
+            let height = cli::export::import_chain(&cmd).await?;
+            info!("Imported and verified chain up to block #{}", height);
+        }
+
+        Commands::Snapshot(cmd) => match cmd.subcommand {
+            SnapshotSubcommand::Export(cmd) => {
+                let (path, header) = cli::snapshot::export_snapshot(&cmd)?;
+                info!(
+                    "📸 Snapshot of block #{} ({} accounts) written to {}",
+                    header.block_number, header.account_count, path.display()
+                );
+            }
+            SnapshotSubcommand::Import(cmd) => {
+                info!("Importing state snapshot from {}", cmd.input.display());
+                let header = cli::snapshot::import_snapshot(&cmd)?;
+                info!(
+                    "✅ State root verified, node bootstrapped at block #{} ({}), {} accounts",
+                    header.block_number, header.block_hash, header.account_count
+                );
+            }
+        },
+
+        Commands::Peers(cmd) => match cmd.subcommand {
+            PeersSubcommand::Export(cmd) => {
+                let file = cli::peers::export_peers(&cmd)?;
+                let json = serde_json::to_string_pretty(&file)?;
+                match &cmd.output {
+                    Some(path) => {
+                        std::fs::write(path, json + "\n")?;
+                        info!("📒 Exported {} known peers to {}", file.peers.len(), path.display());
+                    }
+                    None => println!("{}", json),
+                }
+            }
+            PeersSubcommand::Import(cmd) => {
+                let count = cli::peers::import_peers(&cmd)?;
+                info!("📒 Imported {} known peers, dialed on the next start", count);
+            }
+        },
+
+        Commands::PruneNow(cmd) => {
+            let report = cli::prune::prune_now(&cmd)?;
+            match report.pruned_up_to {
+                Some(to) => info!("🧹 Pruned {} blocks, data kept from block #{}", report.pruned_blocks, to + 1),
+                None => info!("Nothing to prune (no finalized block outside the window)"),
+            }
+        }
+
+        Commands::Db(cmd) => {
+            let path = cmd.subcommand.get_base_path();
+            match cmd.subcommand {
+                DbSubcommand::Compact { .. } => {
+                    let report = cli::db::compact(&path)?;
+                    match (report.size_before, report.size_after) {
+                        (Some(before), Some(after)) => info!("Compacted: {} -> {} bytes", before, after),
+                        _ => info!("Compaction complete"),
+                    }
+                }
+                DbSubcommand::Check { repair, .. } => {
+                    let report = cli::db::check(&path, repair)?;
+                    info!(
+                        "Checked {} blocks (best #{}), {} accounts",
+                        report.blocks_checked,
+                        report.best_block.map_or("none".to_string(), |n| n.to_string()),
+                        report.accounts_checked
+                    );
+                    for issue in &report.issues {
+                        warn!("{} - {}", issue, issue.suggestion());
+                    }
+                    if repair && report.repaired > 0 {
+                        info!("Repaired {} of {} issues", report.repaired, report.issues.len());
+                    }
+                    if report.is_healthy() {
+                        info!("✅ Database is consistent");
+                    } else {
+                        let fixable = report.issues.iter().filter(|i| i.repairable()).count();
+                        if !repair && fixable > 0 {
+                            warn!("{} issues can be fixed with `kratos-node db check --repair`", fixable);
+                        }
+                        anyhow::bail!("{} database issues found", report.issues.len() - report.repaired);
+                    }
+                }
+            }
+        }
+
+        Commands::Audit(cmd) => {
+            let report = cli::audit::audit_chain(&cmd).await?;
+            let json = serde_json::to_string_pretty(&report)?;
+            match &cmd.output {
+                Some(path) => std::fs::write(path, json + "\n")?,
+                None => println!("{}", json),
+            }
+            if !report.is_clean() {
+                anyhow::bail!("{} divergences found", report.divergences.len());
+            }
+            info!(
+                "✅ Blocks #{}-#{} re-executed to their state roots, receipts roots and fee accounting",
+                report.from, report.to
+            );
         }
 
+        Commands::BuildSpec(cmd) => {
+            let spec = cli::build_spec::build_spec(&cmd)?;
+            match cmd.output {
+                Some(path) => {
+                    spec.to_file(&path.to_string_lossy())?;
+                    eprintln!("Chain spec written to {} (start with `run --chain {}`)", path.display(), path.display());
+                }
+                None => println!("{}", spec.to_json()?),
+            }
+            // Nothing else on stdout
+            return Ok(());
+        }
+
+        Commands::Config(cmd) => match cmd.subcommand {
+            ConfigSubcommand::Generate { output } => {
+                let template = cli::config_file::template();
+                match output {
+                    Some(path) => {
+                        std::fs::write(&path, template)?;
+                        eprintln!("Configuration template written to {}", path.display());
+                    }
+                    None => print!("{}", template),
+                }
+                // Nothing else on stdout
+                return Ok(());
+            }
+            ConfigSubcommand::Token { name, methods } => {
+                let (secret, hash) = RpcToken::generate();
+                let methods: Vec<_> = methods.iter().map(|m| format!("\"{}\"", m)).collect();
+                println!("[[rpc-token]]");
+                println!("name = \"{}\"", name);
+                println!("hash = \"{}\"", hash.to_hex());
+                println!("methods = [{}]", methods.join(", "));
+                eprintln!();
+                eprintln!("Add the section above to the node config file, then restart or reload the node.");
+                eprintln!("Token (shown once, only its hash is stored): {}", secret);
+                eprintln!("Send it as: Authorization: Bearer <token>");
+                return Ok(());
+            }
+        },
+
         Commands::Purge(cmd) => {
             let path = cmd.get_base_path();
 
@@ -326,3 +501,84 @@ fn inspect_key(key: &str, scheme: &str) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Resolve the keystore base path, defaulting to the kratos chain data directory
+fn keystore_base_path(base_path: Option<std::path::PathBuf>) -> std::path::PathBuf {
+    base_path.unwrap_or_else(|| {
+        dirs::data_dir()
+            .unwrap_or_else(|| std::path::PathBuf::from("."))
+            .join("kratos")
+            .join("chains")
+            .join("kratos")
+    })
+}
+
+/// Insert a key into the node keystore
+fn insert_key(
+    base_path: Option<std::path::PathBuf>,
+    key_type: &str,
+    scheme: &str,
+    suri: &str,
+    password: Option<String>,
+) -> anyhow::Result<()> {
+    use node::keystore::{parse_suri, KeyScheme, KeyType, Keystore};
+
+    let key_type: KeyType = key_type.parse()?;
+    let scheme: KeyScheme = scheme.parse()?;
+    let secret = parse_suri(suri, scheme)?;
+
+    let password = match password {
+        Some(password) => password,
+        None => {
+            println!("Keystore password:");
+            let mut input = String::new();
+            std::io::stdin().read_line(&mut input)?;
+            input.trim_end_matches(['\r', '\n']).to_string()
+        }
+    };
+
+    let keystore = Keystore::open(&keystore_base_path(base_path));
+    let entry = keystore.insert(key_type, scheme, &secret, &password)?;
+
+    println!("Key Type: {}", entry.key_type);
+    println!("Scheme: {}", entry.scheme);
+    println!("Public Key: 0x{}", hex::encode(entry.public_key));
+    info!("Key saved to: {}", entry.path.display());
+
+    if key_type == KeyType::Aura && scheme == KeyScheme::Sr25519 {
+        warn!("Block production signs with ed25519; this sr25519 aura key will not be used by the validator");
+    }
+
+    Ok(())
+}
+
+/// List keys in the node keystore
+fn list_keys(base_path: Option<std::path::PathBuf>) -> anyhow::Result<()> {
+    use node::keystore::Keystore;
+
+    let keystore = Keystore::open(&keystore_base_path(base_path));
+    let entries = keystore.list()?;
+
+    if entries.is_empty() {
+        println!("No keys in keystore at: {}", keystore.path().display());
+        return Ok(());
+    }
+
+    let superseded = keystore.superseded()?;
+    println!("Keystore: {}", keystore.path().display());
+    for entry in entries {
+        let status = if !superseded.contains(&entry) {
+            String::new()
+        } else if let Some(epoch) = entry.rotated_at {
+            format!("  (rotated at epoch {}, not used for signing)", epoch)
+        } else {
+            "  (rotated, not used for signing)".to_string()
+        };
+        println!("  {}  {}  0x{}{}", entry.key_type, entry.scheme, hex::encode(entry.public_key), status);
+    }
+    if keystore.archive_path().exists() {
+        println!("Retired keys (encrypted): {}", keystore.archive_path().display());
+    }
+
+    Ok(())
+}
@@ -3,18 +3,43 @@
 
 use libp2p::{
     gossipsub::{self, IdentTopic, MessageAuthenticity, ValidationMode},
+    identify,
+    identity::PublicKey,
     kad::{self, store::MemoryStore},
+    mdns,
+    relay,
     request_response::{self, ProtocolSupport},
-    swarm::NetworkBehaviour,
-    PeerId, StreamProtocol,
+    multiaddr::Protocol,
+    swarm::{behaviour::toggle::Toggle, NetworkBehaviour},
+    Multiaddr, PeerId, StreamProtocol,
 };
 
 use super::protocol::GossipTopic;
 use super::request::{KratosCodec, KratosRequest, KratosResponse};
+use crate::types::Hash;
 
 /// Request-response protocol name
 pub const KRATOS_PROTOCOL: &str = "/kratos/req/1.0.0";
 
+/// Identify protocol name (peers report the address they see us at)
+pub const IDENTIFY_PROTOCOL: &str = "/kratos/id/1.0.0";
+
+/// Interval between Kademlia random walks (lookups of a random peer ID)
+pub const KAD_WALK_INTERVAL_SECS: u64 = 60;
+
+/// Peers dialed at most per random walk
+pub const KAD_MAX_DIALS_PER_WALK: usize = 8;
+
+/// Kademlia protocol name of a chain
+///
+/// The genesis hash in the name keeps the DHTs of different chains apart:
+/// a node never adds a peer of another chain to its routing table, nor
+/// learns about one through a lookup.
+pub fn kad_protocol(genesis_hash: &Hash) -> StreamProtocol {
+    StreamProtocol::try_from_owned(format!("/kratos/{}/kad/1.0.0", hex::encode(genesis_hash.as_bytes())))
+        .expect("protocol name starts with /")
+}
+
 // =============================================================================
 // SECURITY FIX #15-16: Network Security Constants
 // =============================================================================
@@ -31,6 +56,18 @@ pub const MAX_MESSAGES_PER_RPC: usize = 100;
 /// Prevents duplicate message processing
 pub const MESSAGE_CACHE_LENGTH: usize = 5;
 
+/// Gossip message ID: hash of the topic and content
+///
+/// The default ID (publisher and sequence number) lets the same block or
+/// transaction published by several nodes travel the mesh once per
+/// publisher; content-addressed IDs make gossipsub drop the copies.
+pub fn gossip_message_id(message: &gossipsub::Message) -> gossipsub::MessageId {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(message.topic.as_str().as_bytes());
+    hasher.update(&message.data);
+    gossipsub::MessageId::from(hasher.finalize().as_bytes().to_vec())
+}
+
 /// SECURITY FIX #16: Maximum connections per IP address
 /// Prevents Sybil attacks from single hosts
 pub const MAX_CONNECTIONS_PER_IP: u32 = 5;
@@ -38,6 +75,22 @@ pub const MAX_CONNECTIONS_PER_IP: u32 = 5;
 /// SECURITY FIX #16: Maximum total peer connections
 pub const MAX_PEER_CONNECTIONS: u32 = 100;
 
+/// QUIC listen address on a UDP port (--listen-quic)
+///
+/// Every node dials QUIC addresses; only nodes started with --listen-quic
+/// accept QUIC connections, next to their TCP listener.
+pub fn quic_listen_address(port: u16) -> Multiaddr {
+    Multiaddr::empty()
+        .with(Protocol::Ip4(std::net::Ipv4Addr::UNSPECIFIED))
+        .with(Protocol::Udp(port))
+        .with(Protocol::QuicV1)
+}
+
+/// Whether an address uses the QUIC transport
+pub fn is_quic(addr: &Multiaddr) -> bool {
+    addr.iter().any(|p| matches!(p, Protocol::QuicV1))
+}
+
 /// Network behaviour for KratOs
 #[derive(NetworkBehaviour)]
 pub struct KratOsBehaviour {
@@ -49,10 +102,30 @@ pub struct KratOsBehaviour {
 
     /// Kademlia for global peer discovery
     pub kad: kad::Behaviour<MemoryStore>,
+
+    /// Observed addresses (a relay learns its external address from them)
+    pub identify: identify::Behaviour,
+
+    /// Circuit relay client: dial and listen through relays
+    pub relay_client: relay::client::Behaviour,
+
+    /// Circuit relay server (--relay only)
+    pub relay: Toggle<relay::Behaviour>,
+
+    /// Local network discovery (custom chains, unless --no-mdns)
+    pub mdns: Toggle<mdns::tokio::Behaviour>,
 }
 
 impl KratOsBehaviour {
-    pub fn new(local_peer_id: PeerId) -> Result<Self, Box<dyn std::error::Error>> {
+    pub fn new(
+        public_key: PublicKey,
+        relay_client: relay::client::Behaviour,
+        serve_relay: bool,
+        genesis_hash: Hash,
+        enable_mdns: bool,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let local_peer_id = public_key.to_peer_id();
+
         // SECURITY FIX #15: Configure Gossipsub with anti-flood measures
         let gossipsub_config = gossipsub::ConfigBuilder::default()
             .heartbeat_interval(std::time::Duration::from_secs(1))
@@ -64,6 +137,7 @@ impl KratOsBehaviour {
             // SECURITY FIX #15: Message deduplication cache
             .history_length(MESSAGE_CACHE_LENGTH)
             .history_gossip(3) // Number of heartbeats to gossip about
+            .message_id_fn(gossip_message_id)
             // SECURITY FIX #15: Mesh parameters for DoS resistance
             .mesh_n(8)           // Target number of peers in mesh
             .mesh_n_low(6)       // Minimum peers before trying to add more
@@ -105,18 +179,33 @@ impl KratOsBehaviour {
                 .with_max_concurrent_streams(128), // Increased from default 10
         );
 
-        // Configure Kademlia
-        let mut kad = kad::Behaviour::new(
+        // Configure Kademlia (namespaced by genesis hash)
+        let mut kad_config = kad::Config::default();
+        kad_config.set_protocol_names(vec![kad_protocol(&genesis_hash)]);
+        let mut kad = kad::Behaviour::with_config(
             local_peer_id,
             MemoryStore::new(local_peer_id),
+            kad_config,
         );
         // Set Kademlia to server mode for better discovery
         kad.set_mode(Some(kad::Mode::Server));
 
+        let identify = identify::Behaviour::new(identify::Config::new(IDENTIFY_PROTOCOL.to_string(), public_key));
+        let relay = serve_relay.then(|| relay::Behaviour::new(local_peer_id, super::relay::server_config()));
+        let mdns = if enable_mdns {
+            Some(mdns::tokio::Behaviour::new(mdns::Config::default(), local_peer_id)?)
+        } else {
+            None
+        };
+
         Ok(Self {
             gossipsub,
             request_response,
             kad,
+            identify,
+            relay_client,
+            relay: Toggle::from(relay),
+            mdns: Toggle::from(mdns),
         })
     }
 
@@ -153,4 +242,47 @@ impl KratOsBehaviour {
     pub fn bootstrap_kad(&mut self) -> Result<kad::QueryId, kad::NoKnownPeers> {
         self.kad.bootstrap()
     }
+
+    /// Look up the peers closest to a random ID: refreshes the routing
+    /// table and finds peers to dial
+    pub fn kad_random_walk(&mut self) -> kad::QueryId {
+        self.kad.get_closest_peers(PeerId::random())
+    }
+
+    /// Drop a peer from the Kademlia routing table
+    pub fn remove_kad_peer(&mut self, peer_id: &PeerId) {
+        self.kad.remove_peer(peer_id);
+    }
+
+    /// Query Kademlia without serving it: peers don't add the node to their
+    /// routing tables (a validator behind sentries)
+    pub fn kad_client_mode(&mut self) {
+        self.kad.set_mode(Some(kad::Mode::Client));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kad_protocol_per_chain() {
+        let a = kad_protocol(&Hash::hash(b"chain a"));
+        assert!(a.as_ref().starts_with("/kratos/") && a.as_ref().ends_with("/kad/1.0.0"));
+        assert_eq!(a, kad_protocol(&Hash::hash(b"chain a")));
+        assert_ne!(a, kad_protocol(&Hash::hash(b"chain b")));
+    }
+
+    #[test]
+    fn test_gossip_message_id_by_content() {
+        let message = |source: Option<PeerId>, seqno: u64, data: &[u8]| gossipsub::Message {
+            source,
+            data: data.to_vec(),
+            sequence_number: Some(seqno),
+            topic: gossipsub::TopicHash::from_raw("kratos/transactions/1"),
+        };
+        let id = gossip_message_id(&message(Some(PeerId::random()), 1, b"tx"));
+        assert_eq!(id, gossip_message_id(&message(Some(PeerId::random()), 7, b"tx")));
+        assert_ne!(id, gossip_message_id(&message(None, 1, b"other tx")));
+    }
 }
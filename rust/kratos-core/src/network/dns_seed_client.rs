@@ -11,7 +11,8 @@
 //! 1. Node connects to DNS Seed
 //! 2. Node sends HeartbeatMessage (bincode serialized, length-prefixed)
 //! 3. DNS Seed verifies signature and responds with HeartbeatResponse
-//! 4. Response includes current network state
+//! 4. Response includes current network state (and, for validators, a
+//!    signed receipt later submitted on-chain as uptime proof)
 //!
 //! IDpeers.json (HTTP):
 //! 1. Node fetches /idpeers.json from DNS Seed
@@ -30,6 +31,9 @@ use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
 
 use super::dns_seeds::DEFAULT_P2P_PORT;
+use crate::consensus::epoch::EPOCH_DURATION_BLOCKS;
+use crate::consensus::seed_attestation::MAX_SEED_RECEIPTS;
+use crate::types::{AccountId, EpochNumber, Hash, SeedReceipt, Signature64};
 
 // =============================================================================
 // SERDE HELPERS FOR BYTE ARRAYS
@@ -97,6 +101,9 @@ pub const HEARTBEAT_PORT: u16 = 30334;
 /// Heartbeat interval (2 minutes as specified)
 pub const HEARTBEAT_INTERVAL_SECS: u64 = 120;
 
+/// Receipts kept until they are submitted (about two epochs from three seeds)
+pub const MAX_HELD_RECEIPTS: usize = 2 * MAX_SEED_RECEIPTS;
+
 /// Connection timeout for heartbeat
 pub const CONNECTION_TIMEOUT_SECS: u64 = 30;
 
@@ -117,6 +124,57 @@ pub const DNS_SEED_HEARTBEAT_IPS: [&str; 3] = [
 // TYPES (Compatible with kratos-dns-seed)
 // =============================================================================
 
+/// Heartbeat protocol version (v2 adds capabilities)
+pub const HEARTBEAT_VERSION: u32 = 2;
+
+/// Services a node offers on top of block relay (bit flags)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Capabilities(pub u32);
+
+impl Capabilities {
+    pub const NONE: Self = Self(0);
+    /// Keeps historical state for every block
+    pub const ARCHIVE: Self = Self(1 << 0);
+    /// JSON-RPC reachable from other hosts
+    pub const RPC: Self = Self(1 << 1);
+    /// Serves state snapshots for warp sync
+    pub const WARP_SYNC: Self = Self(1 << 2);
+    /// Serves proofs to light clients
+    pub const LIGHT_CLIENT: Self = Self(1 << 3);
+    /// Relays connections for nodes that can't be dialed (--relay)
+    pub const RELAY: Self = Self(1 << 4);
+
+    /// Whether all flags of `other` are set
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Value of the DNS Seed `capability` query parameter
+    pub fn query_names(self) -> String {
+        [
+            (Self::ARCHIVE, "archive"),
+            (Self::RPC, "rpc"),
+            (Self::WARP_SYNC, "warp-sync"),
+            (Self::LIGHT_CLIENT, "light-client"),
+            (Self::RELAY, "relay"),
+        ]
+        .iter()
+        .filter(|(flag, _)| self.contains(*flag))
+        .map(|(_, name)| *name)
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+}
+
+impl std::ops::BitOr for Capabilities {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
 /// Heartbeat message sent to DNS Seeds
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HeartbeatMessage {
@@ -139,6 +197,9 @@ pub struct HeartbeatMessage {
     pub timestamp: u64,
     #[serde(with = "sig_serde")]
     pub signature: [u8; 64],
+    /// Services offered (v2, after the signature so v1 stays a prefix)
+    #[serde(default)]
+    pub capabilities: Capabilities,
 }
 
 impl HeartbeatMessage {
@@ -163,6 +224,9 @@ impl HeartbeatMessage {
         }
         data.extend_from_slice(&self.protocol_version.to_le_bytes());
         data.extend_from_slice(&self.timestamp.to_le_bytes());
+        if self.version >= 2 {
+            data.extend_from_slice(&self.capabilities.0.to_le_bytes());
+        }
         data
     }
 
@@ -185,6 +249,49 @@ pub struct HeartbeatResponse {
     pub error: Option<String>,
     pub network_state: Option<NetworkStateInfo>,
     pub timestamp: u64,
+    #[serde(default)]
+    pub receipt: Option<HeartbeatReceipt>,
+}
+
+impl HeartbeatResponse {
+    /// Decode a bincode response, accepting responses without a receipt
+    pub fn decode(bytes: &[u8]) -> bincode::Result<Self> {
+        bincode::deserialize(bytes).or_else(|err| {
+            // Older seeds end at the timestamp: pad a `None` receipt
+            let mut padded = bytes.to_vec();
+            padded.push(0);
+            bincode::deserialize::<Self>(&padded).map_err(|_| err)
+        })
+    }
+}
+
+/// Receipt signed by a DNS Seed for a validator heartbeat
+/// (same format as kratos-dns-seed)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeartbeatReceipt {
+    #[serde(with = "hash_serde")]
+    pub seed_id: [u8; 32],
+    #[serde(with = "hash_serde")]
+    pub peer_id: [u8; 32],
+    #[serde(with = "hash_serde")]
+    pub genesis_hash: [u8; 32],
+    pub height: u64,
+    pub timestamp: u64,
+    #[serde(with = "sig_serde")]
+    pub signature: [u8; 64],
+}
+
+impl From<HeartbeatReceipt> for SeedReceipt {
+    fn from(receipt: HeartbeatReceipt) -> Self {
+        SeedReceipt {
+            seed: AccountId::from_bytes(receipt.seed_id),
+            validator: AccountId::from_bytes(receipt.peer_id),
+            genesis_hash: Hash::from_bytes(receipt.genesis_hash),
+            height: receipt.height,
+            timestamp: receipt.timestamp,
+            signature: Signature64(receipt.signature),
+        }
+    }
 }
 
 /// Network state information from DNS Seed
@@ -231,10 +338,15 @@ pub struct IdPeersFile {
 pub struct PeerInfoCompact {
     #[serde(with = "hash_serde")]
     pub peer_id: [u8; 32],
+    /// libp2p PeerId (base58 encoded)
+    #[serde(default)]
+    pub libp2p_peer_id: String,
     pub addresses: Vec<String>,
     pub height: u64,
     pub is_validator: bool,
     pub score: i32,
+    #[serde(default)]
+    pub capabilities: Capabilities,
 }
 
 // =============================================================================
@@ -260,6 +372,12 @@ pub struct DnsSeedClient {
 
     /// Last heartbeat timestamp per seed
     last_heartbeat: std::collections::HashMap<String, u64>,
+
+    /// Services advertised in heartbeats
+    capabilities: Capabilities,
+
+    /// Seed receipts not yet submitted on-chain (oldest first)
+    receipts: Vec<SeedReceipt>,
 }
 
 impl DnsSeedClient {
@@ -285,9 +403,21 @@ impl DnsSeedClient {
             seed_ips,
             last_network_state: None,
             last_heartbeat: std::collections::HashMap::new(),
+            capabilities: Capabilities::NONE,
+            receipts: Vec::new(),
         }
     }
 
+    /// Set the services advertised in heartbeats
+    pub fn set_capabilities(&mut self, capabilities: Capabilities) {
+        self.capabilities = capabilities;
+    }
+
+    /// Services advertised in heartbeats
+    pub fn capabilities(&self) -> Capabilities {
+        self.capabilities
+    }
+
     /// Send heartbeat to all DNS Seeds
     pub async fn send_heartbeats(
         &mut self,
@@ -320,6 +450,9 @@ impl DnsSeedClient {
                     self.last_network_state = Some(state.clone());
                 }
             }
+            if let Some(receipt) = &result.receipt {
+                self.hold_receipt(receipt.clone());
+            }
 
             results.push(result);
         }
@@ -327,6 +460,37 @@ impl DnsSeedClient {
         results
     }
 
+    /// Keep a receipt for our own heartbeat, dropping the oldest beyond the limit
+    fn hold_receipt(&mut self, receipt: SeedReceipt) {
+        if receipt.validator.as_bytes() != &self.peer_id || !receipt.verify() {
+            debug!("Ignoring invalid heartbeat receipt from {}", receipt.seed);
+            return;
+        }
+        self.receipts.push(receipt);
+        if self.receipts.len() > MAX_HELD_RECEIPTS {
+            let excess = self.receipts.len() - MAX_HELD_RECEIPTS;
+            self.receipts.drain(..excess);
+        }
+    }
+
+    /// Take the receipts for `epoch`, dropping older ones
+    pub fn take_receipts(&mut self, epoch: EpochNumber) -> Vec<SeedReceipt> {
+        let start = epoch * EPOCH_DURATION_BLOCKS;
+        let end = start + EPOCH_DURATION_BLOCKS;
+        let (done, pending) = self.receipts.drain(..).partition(|r| r.height < end);
+        self.receipts = pending;
+
+        let mut receipts: Vec<SeedReceipt> = done;
+        receipts.retain(|r| r.height >= start);
+        receipts.truncate(MAX_SEED_RECEIPTS);
+        receipts
+    }
+
+    /// Sign with the node key (the validator key when validating)
+    pub fn sign(&self, message: &[u8]) -> Signature64 {
+        Signature64(self.keypair.sign(message).to_bytes())
+    }
+
     /// Send heartbeat to a single DNS Seed
     async fn send_heartbeat_to_seed(
         &self,
@@ -343,7 +507,7 @@ impl DnsSeedClient {
 
         // Build message
         let mut message = HeartbeatMessage {
-            version: 1,
+            version: HEARTBEAT_VERSION,
             peer_id: self.peer_id,
             libp2p_peer_id: self.libp2p_peer_id.clone(),
             addresses,
@@ -356,6 +520,7 @@ impl DnsSeedClient {
             protocol_version: 1,
             timestamp: current_timestamp(),
             signature: [0u8; 64],
+            capabilities: self.capabilities,
         };
 
         // Sign the message
@@ -377,6 +542,7 @@ impl DnsSeedClient {
                     success: false,
                     error: Some(format!("Connection failed: {}", e)),
                     network_state: None,
+                    receipt: None,
                 };
             }
             Err(_) => {
@@ -385,6 +551,7 @@ impl DnsSeedClient {
                     success: false,
                     error: Some("Connection timeout".to_string()),
                     network_state: None,
+                    receipt: None,
                 };
             }
         };
@@ -398,6 +565,7 @@ impl DnsSeedClient {
                     success: false,
                     error: Some(format!("Serialization failed: {}", e)),
                     network_state: None,
+                    receipt: None,
                 };
             }
         };
@@ -411,6 +579,7 @@ impl DnsSeedClient {
                 success: false,
                 error: Some(format!("Write failed: {}", e)),
                 network_state: None,
+                receipt: None,
             };
         }
 
@@ -420,6 +589,7 @@ impl DnsSeedClient {
                 success: false,
                 error: Some(format!("Write failed: {}", e)),
                 network_state: None,
+                receipt: None,
             };
         }
 
@@ -429,6 +599,7 @@ impl DnsSeedClient {
                 success: false,
                 error: Some(format!("Flush failed: {}", e)),
                 network_state: None,
+                receipt: None,
             };
         }
 
@@ -447,6 +618,7 @@ impl DnsSeedClient {
                     success: false,
                     error: Some(format!("Read failed: {}", e)),
                     network_state: None,
+                    receipt: None,
                 };
             }
             Err(_) => {
@@ -455,6 +627,7 @@ impl DnsSeedClient {
                     success: false,
                     error: Some("Read timeout".to_string()),
                     network_state: None,
+                    receipt: None,
                 };
             }
         }
@@ -466,6 +639,7 @@ impl DnsSeedClient {
                 success: false,
                 error: Some("Response too large".to_string()),
                 network_state: None,
+                receipt: None,
             };
         }
 
@@ -483,6 +657,7 @@ impl DnsSeedClient {
                     success: false,
                     error: Some(format!("Read failed: {}", e)),
                     network_state: None,
+                    receipt: None,
                 };
             }
             Err(_) => {
@@ -491,12 +666,13 @@ impl DnsSeedClient {
                     success: false,
                     error: Some("Read timeout".to_string()),
                     network_state: None,
+                    receipt: None,
                 };
             }
         }
 
         // Parse response
-        let response: HeartbeatResponse = match bincode::deserialize(&resp_buf) {
+        let response = match HeartbeatResponse::decode(&resp_buf) {
             Ok(r) => r,
             Err(e) => {
                 return HeartbeatResult {
@@ -504,6 +680,7 @@ impl DnsSeedClient {
                     success: false,
                     error: Some(format!("Deserialization failed: {}", e)),
                     network_state: None,
+                    receipt: None,
                 };
             }
         };
@@ -513,12 +690,26 @@ impl DnsSeedClient {
             success: response.accepted,
             error: response.error,
             network_state: response.network_state,
+            receipt: response.receipt.map(SeedReceipt::from),
         }
     }
 
     /// Fetch IDpeers.json from a DNS Seed
     pub async fn fetch_idpeers(&self, seed_ip: &str) -> Result<IdPeersFile, String> {
-        let url = format!("http://{}:8080/idpeers.json", seed_ip);
+        self.fetch_idpeers_with(seed_ip, Capabilities::NONE).await
+    }
+
+    /// Fetch IDpeers.json listing only peers that offer all `required` capabilities
+    pub async fn fetch_idpeers_with(
+        &self,
+        seed_ip: &str,
+        required: Capabilities,
+    ) -> Result<IdPeersFile, String> {
+        let url = if required == Capabilities::NONE {
+            format!("http://{}:8080/idpeers.json", seed_ip)
+        } else {
+            format!("http://{}:8080/idpeers.json?capability={}", seed_ip, required.query_names())
+        };
 
         let client = reqwest::Client::builder()
             .timeout(Duration::from_secs(30))
@@ -592,6 +783,47 @@ impl DnsSeedClient {
         peers
     }
 
+    /// Find peers offering `required` capabilities (e.g. a warp-sync provider)
+    ///
+    /// Unlike `get_peers_from_dns_seeds`, fallback bootnodes are not added since
+    /// nothing is known about their services.
+    pub async fn get_peers_with_capabilities(&self, required: Capabilities) -> Vec<(PeerId, Multiaddr)> {
+        for seed_ip in &self.seed_ips {
+            match self.fetch_idpeers_with(seed_ip, required).await {
+                Ok(file) => {
+                    let peers: Vec<_> = file
+                        .peers
+                        .iter()
+                        // The seed filters already; don't trust it blindly
+                        .filter(|p| p.capabilities.contains(required))
+                        .filter_map(|p| PeerId::from_str(&p.libp2p_peer_id).ok().map(|id| (id, p)))
+                        .flat_map(|(id, p)| {
+                            p.addresses
+                                .iter()
+                                .filter_map(move |a| Multiaddr::from_str(a).ok().map(|addr| (id, addr)))
+                        })
+                        .collect();
+
+                    info!(
+                        "📋 Found {} peers with capabilities {} via DNS Seed {}",
+                        peers.len(),
+                        required.query_names(),
+                        seed_ip
+                    );
+
+                    if !peers.is_empty() {
+                        return peers;
+                    }
+                }
+                Err(e) => {
+                    warn!("Failed to fetch IDpeers.json from {}: {}", seed_ip, e);
+                }
+            }
+        }
+
+        Vec::new()
+    }
+
     /// Get last known network state
     pub fn network_state(&self) -> Option<&NetworkStateInfo> {
         self.last_network_state.as_ref()
@@ -610,6 +842,7 @@ pub struct HeartbeatResult {
     pub success: bool,
     pub error: Option<String>,
     pub network_state: Option<NetworkStateInfo>,
+    pub receipt: Option<SeedReceipt>,
 }
 
 // =============================================================================
@@ -746,6 +979,7 @@ mod tests {
             protocol_version: 1,
             timestamp: current_timestamp(),
             signature: [0u8; 64],
+            capabilities: Capabilities::ARCHIVE,
         };
 
         message.sign(&keypair);
@@ -754,6 +988,30 @@ mod tests {
         assert_ne!(message.signature, [0u8; 64]);
     }
 
+    #[test]
+    fn test_capabilities_in_signing_data() {
+        let mut message = HeartbeatMessage {
+            version: HEARTBEAT_VERSION,
+            peer_id: [0u8; 32],
+            libp2p_peer_id: "12D3KooWTestPeerId".to_string(),
+            addresses: vec![],
+            current_height: 1,
+            best_hash: [0u8; 32],
+            genesis_hash: [0u8; 32],
+            is_validator: false,
+            validator_count: None,
+            total_stake: None,
+            protocol_version: 1,
+            timestamp: 0,
+            signature: [0u8; 64],
+            capabilities: Capabilities::WARP_SYNC,
+        };
+        let warp = message.signing_data();
+        message.capabilities = Capabilities::ARCHIVE | Capabilities::RPC;
+        assert_ne!(warp, message.signing_data());
+        assert_eq!(message.capabilities.query_names(), "archive,rpc");
+    }
+
     #[test]
     fn test_client_creation() {
         let keypair = SigningKey::generate(&mut OsRng);
@@ -763,4 +1021,42 @@ mod tests {
         assert!(client.last_network_state.is_none());
         assert_eq!(client.libp2p_peer_id, "12D3KooWTestPeerId");
     }
+
+    #[test]
+    fn test_receipts_taken_per_epoch() {
+        let seed = SigningKey::generate(&mut OsRng);
+        let mut client = DnsSeedClient::new(SigningKey::generate(&mut OsRng), "12D3KooWTestPeerId".to_string());
+
+        let receipt = |peer_id: [u8; 32], height: u64| {
+            let mut receipt = SeedReceipt {
+                seed: AccountId::from_public_key(&seed.verifying_key()),
+                validator: AccountId::from_bytes(peer_id),
+                genesis_hash: Hash::ZERO,
+                height,
+                timestamp: 1_000_000 + height,
+                signature: Signature64::zero(),
+            };
+            receipt.signature = Signature64(seed.sign(&receipt.signing_message()).to_bytes());
+            receipt
+        };
+
+        let own = *client.peer_id();
+        client.hold_receipt(receipt(own, 10));
+        client.hold_receipt(receipt(own, EPOCH_DURATION_BLOCKS + 10));
+        client.hold_receipt(receipt(own, 2 * EPOCH_DURATION_BLOCKS + 10));
+
+        // Receipts for another node or with a bad signature are dropped
+        client.hold_receipt(receipt([9u8; 32], EPOCH_DURATION_BLOCKS + 20));
+        let mut forged = receipt(own, EPOCH_DURATION_BLOCKS + 30);
+        forged.height += 1;
+        client.hold_receipt(forged);
+
+        let taken = client.take_receipts(1);
+        assert_eq!(taken.len(), 1);
+        assert_eq!(taken[0].height, EPOCH_DURATION_BLOCKS + 10);
+
+        // Older receipts went with them, newer ones are kept
+        assert_eq!(client.receipts.len(), 1);
+        assert!(client.take_receipts(1).is_empty());
+    }
 }
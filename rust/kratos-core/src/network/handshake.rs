@@ -0,0 +1,285 @@
+// Handshake - Protocol version negotiation on connection
+// Principle: upgrade the fleet peer by peer, never all at once
+// - Each node advertises the version range it speaks per sub-protocol (sync,
+//   gossip, finality, warp); both sides use the highest common version
+// - A version below `deprecated_below` (ours or the peer's) still works but
+//   logs a warning, so operators upgrade before support is dropped
+// - Peers released before the handshake do not answer it: they speak v1
+// - The block protocol version (chain rules, upgraded by governance) is
+//   advertised, not negotiated: a peer ahead of us means an upgrade is coming
+// Sub-protocols are named on the wire: a node ignores those it doesn't know.
+
+use crate::types::{ProtocolVersion as BlockProtocolVersion, SUPPORTED_PROTOCOL_VERSION};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Wire name of the block protocol entry, versions packed as `major << 16 | minor`
+const BLOCK_PROTOCOL: &str = "block";
+
+/// Versioned sub-protocols
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SubProtocol {
+    /// Block sync requests
+    Sync,
+    /// Block and transaction gossip
+    Gossip,
+    /// GRANDPA votes and justifications
+    Finality,
+    /// Warp sync snapshots
+    Warp,
+}
+
+impl SubProtocol {
+    pub const ALL: [SubProtocol; 4] = [SubProtocol::Sync, SubProtocol::Gossip, SubProtocol::Finality, SubProtocol::Warp];
+
+    /// Wire name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SubProtocol::Sync => "sync",
+            SubProtocol::Gossip => "gossip",
+            SubProtocol::Finality => "finality",
+            SubProtocol::Warp => "warp",
+        }
+    }
+}
+
+impl fmt::Display for SubProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Versions of a sub-protocol a node speaks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtocolVersion {
+    /// Sub-protocol wire name
+    pub name: String,
+    /// Oldest version spoken
+    pub min: u32,
+    /// Newest version spoken
+    pub max: u32,
+    /// Versions below are deprecated (kept for the peers not yet upgraded)
+    pub deprecated_below: u32,
+}
+
+impl ProtocolVersion {
+    fn new(protocol: SubProtocol, min: u32, max: u32, deprecated_below: u32) -> Self {
+        Self {
+            name: protocol.name().to_string(),
+            min,
+            max,
+            deprecated_below,
+        }
+    }
+}
+
+/// Handshake message, sent by both sides
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Handshake {
+    pub versions: Vec<ProtocolVersion>,
+}
+
+impl Handshake {
+    /// Versions spoken by this node
+    pub fn local() -> Self {
+        Self {
+            versions: vec![
+                ProtocolVersion::new(SubProtocol::Sync, 1, 1, 1),
+                // v2: transactions announced by hash, bodies pulled
+                // v3: blocks announced by header, bodies fetched once it checks out
+                ProtocolVersion::new(SubProtocol::Gossip, 1, 3, 1),
+                ProtocolVersion::new(SubProtocol::Finality, 1, 1, 1),
+                ProtocolVersion::new(SubProtocol::Warp, 1, 1, 1),
+                ProtocolVersion {
+                    name: BLOCK_PROTOCOL.to_string(),
+                    min: pack(BlockProtocolVersion::default()),
+                    max: pack(SUPPORTED_PROTOCOL_VERSION),
+                    deprecated_below: pack(BlockProtocolVersion::default()),
+                },
+            ],
+        }
+    }
+
+    /// Handshake of a peer released before versions were negotiated
+    pub fn legacy() -> Self {
+        Self {
+            versions: SubProtocol::ALL.iter().map(|p| ProtocolVersion::new(*p, 1, 1, 1)).collect(),
+        }
+    }
+
+    /// Versions of `protocol` (missing: v1 only, it predates the versioning)
+    fn get(&self, protocol: SubProtocol) -> ProtocolVersion {
+        self.versions
+            .iter()
+            .find(|v| v.name == protocol.name())
+            .cloned()
+            .unwrap_or_else(|| ProtocolVersion::new(protocol, 1, 1, 1))
+    }
+
+    /// Newest block protocol version the node validates (None: not advertised)
+    pub fn block_protocol(&self) -> Option<BlockProtocolVersion> {
+        self.versions.iter().find(|v| v.name == BLOCK_PROTOCOL).map(|v| unpack(v.max))
+    }
+
+    /// Highest common version of every sub-protocol with `remote`
+    pub fn negotiate(&self, remote: &Handshake) -> Result<NegotiatedVersions, HandshakeError> {
+        let mut negotiated = NegotiatedVersions {
+            block_protocol: remote.block_protocol(),
+            ..Default::default()
+        };
+        for protocol in SubProtocol::ALL {
+            let (ours, theirs) = (self.get(protocol), remote.get(protocol));
+            let version = ours.max.min(theirs.max);
+            if version < ours.min.max(theirs.min) {
+                return Err(HandshakeError::NoCommonVersion { protocol, ours, theirs });
+            }
+            negotiated.set(protocol, version);
+            if version < ours.deprecated_below.max(theirs.deprecated_below) {
+                negotiated.deprecated.push(protocol);
+            }
+        }
+        Ok(negotiated)
+    }
+}
+
+/// Versions used with a peer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegotiatedVersions {
+    pub sync: u32,
+    pub gossip: u32,
+    pub finality: u32,
+    pub warp: u32,
+    /// Sub-protocols spoken in a deprecated version
+    pub deprecated: Vec<SubProtocol>,
+    /// Newest block protocol version the peer validates, if it advertises one
+    pub block_protocol: Option<BlockProtocolVersion>,
+}
+
+impl Default for NegotiatedVersions {
+    fn default() -> Self {
+        Self {
+            sync: 1,
+            gossip: 1,
+            finality: 1,
+            warp: 1,
+            deprecated: Vec::new(),
+            block_protocol: None,
+        }
+    }
+}
+
+impl NegotiatedVersions {
+    /// Version used for `protocol`
+    pub fn get(&self, protocol: SubProtocol) -> u32 {
+        match protocol {
+            SubProtocol::Sync => self.sync,
+            SubProtocol::Gossip => self.gossip,
+            SubProtocol::Finality => self.finality,
+            SubProtocol::Warp => self.warp,
+        }
+    }
+
+    /// Block protocol version the peer validates and this node does not
+    pub fn peer_ahead(&self) -> Option<BlockProtocolVersion> {
+        self.block_protocol.filter(|version| version.is_newer(&SUPPORTED_PROTOCOL_VERSION))
+    }
+
+    fn set(&mut self, protocol: SubProtocol, version: u32) {
+        match protocol {
+            SubProtocol::Sync => self.sync = version,
+            SubProtocol::Gossip => self.gossip = version,
+            SubProtocol::Finality => self.finality = version,
+            SubProtocol::Warp => self.warp = version,
+        }
+    }
+}
+
+impl fmt::Display for NegotiatedVersions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sync v{}, gossip v{}, finality v{}, warp v{}", self.sync, self.gossip, self.finality, self.warp)
+    }
+}
+
+/// Block protocol version on the wire (patch releases keep the block rules)
+fn pack(version: BlockProtocolVersion) -> u32 {
+    (u32::from(version.major) << 16) | u32::from(version.minor)
+}
+
+fn unpack(packed: u32) -> BlockProtocolVersion {
+    BlockProtocolVersion::new((packed >> 16) as u16, packed as u16, 0)
+}
+
+/// Error type for version negotiation
+#[derive(Debug, thiserror::Error)]
+pub enum HandshakeError {
+    #[error("No common {protocol} version (ours: v{}-v{}, theirs: v{}-v{})", ours.min, ours.max, theirs.min, theirs.max)]
+    NoCommonVersion {
+        protocol: SubProtocol,
+        ours: ProtocolVersion,
+        theirs: ProtocolVersion,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handshake(sync: (u32, u32, u32)) -> Handshake {
+        let mut handshake = Handshake::local();
+        handshake.versions[0] = ProtocolVersion::new(SubProtocol::Sync, sync.0, sync.1, sync.2);
+        handshake
+    }
+
+    #[test]
+    fn test_negotiate_highest_common_version() {
+        let negotiated = Handshake::local().negotiate(&Handshake::local()).unwrap();
+        assert_eq!(
+            negotiated,
+            NegotiatedVersions { gossip: 3, block_protocol: Some(SUPPORTED_PROTOCOL_VERSION), ..Default::default() }
+        );
+
+        // Upgraded node (v1-v3) with a v1-v2 peer: v2
+        let negotiated = handshake((1, 3, 1)).negotiate(&handshake((1, 2, 1))).unwrap();
+        assert_eq!(negotiated.get(SubProtocol::Sync), 2);
+        assert!(negotiated.deprecated.is_empty());
+
+        // Legacy peer: v1, deprecated by the upgraded side
+        let negotiated = handshake((1, 3, 2)).negotiate(&Handshake::legacy()).unwrap();
+        assert_eq!(negotiated.sync, 1);
+        assert_eq!(negotiated.deprecated, vec![SubProtocol::Sync]);
+
+        // Old version dropped: no common version
+        let err = handshake((2, 3, 2)).negotiate(&Handshake::legacy()).unwrap_err();
+        assert!(err.to_string().contains("No common sync version"));
+    }
+
+    #[test]
+    fn test_unknown_and_missing_protocols() {
+        // Sub-protocols added later are ignored, missing ones are v1
+        let mut remote = Handshake::local();
+        remote.versions.retain(|v| v.name != "warp");
+        remote.versions.push(ProtocolVersion {
+            name: "light-client".to_string(),
+            min: 1,
+            max: 4,
+            deprecated_below: 1,
+        });
+        let negotiated = Handshake::local().negotiate(&remote).unwrap();
+        assert_eq!(negotiated.warp, 1);
+    }
+
+    #[test]
+    fn test_block_protocol_advertised() {
+        let negotiated = Handshake::local().negotiate(&Handshake::local()).unwrap();
+        assert_eq!(negotiated.peer_ahead(), None);
+        assert_eq!(Handshake::legacy().block_protocol(), None);
+
+        // Upgraded peer: advertised, not negotiated
+        let mut remote = Handshake::local();
+        let block = remote.versions.iter_mut().find(|v| v.name == BLOCK_PROTOCOL).unwrap();
+        block.max = pack(BlockProtocolVersion::new(1, 1, 0));
+        let negotiated = Handshake::local().negotiate(&remote).unwrap();
+        assert_eq!(negotiated.peer_ahead(), Some(BlockProtocolVersion::new(1, 1, 0)));
+        assert_eq!(negotiated.sync, 1);
+    }
+}
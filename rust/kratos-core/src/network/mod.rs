@@ -4,10 +4,13 @@
 pub mod behaviour;
 pub mod dns_seeds;
 pub mod dns_seed_client;
+pub mod handshake;
 pub mod peer;
 pub mod protocol;
 pub mod rate_limit;
+pub mod relay;
 pub mod request;
+pub mod sentry;
 pub mod service;
 pub mod sync;
 pub mod warp_sync;
@@ -15,13 +18,14 @@ pub mod warp_sync;
 pub use dns_seeds::{DnsSeedResolver, DnsSeedRegistry, DnsSeedInfo, parse_bootnode};
 pub use dns_seed_client::{
     DnsSeedClient, HeartbeatService, HeartbeatMessage, HeartbeatResponse,
-    NetworkStateInfo, SecurityState, IdPeersFile, NodeInfo,
+    NetworkStateInfo, SecurityState, IdPeersFile, NodeInfo, Capabilities,
     HEARTBEAT_PORT, HEARTBEAT_INTERVAL_SECS,
 };
 pub use peer::{PeerManager, PeerInfo, PeerState, PeerStats};
 pub use request::{
     BlockRequest, BlockResponse, SyncRequest, SyncResponse,
     StatusRequest, StatusResponse, KratosRequest, KratosResponse,
+    JustificationRequest, JustificationResponse,
 };
 pub use service::{NetworkService, NetworkEvent};
 pub use sync::{SyncManager, SyncState};
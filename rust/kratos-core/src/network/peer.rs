@@ -1,9 +1,11 @@
 // Peer Management - Peer tracking, scoring, and connection management
 // Principle: Track peer behavior, prioritize good actors, manage connections
 
+use super::handshake::{NegotiatedVersions, SubProtocol};
 use libp2p::PeerId;
-use std::collections::HashMap;
-use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tracing::{debug, info, warn};
 
 // =============================================================================
@@ -43,10 +45,68 @@ pub const BAD_TX_SCORE: i32 = -10;
 /// Score decrease for timeout
 pub const TIMEOUT_SCORE: i32 = -20;
 
+/// Peer scoring weights (defaults: the constants above)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeerScoring {
+    /// Score of a new peer, and baseline scores decay toward
+    pub initial: i32,
+    /// Minimum score before disconnection
+    pub min: i32,
+    pub good_block: i32,
+    pub bad_block: i32,
+    pub good_transaction: i32,
+    pub bad_transaction: i32,
+    pub timeout: i32,
+}
+
+impl Default for PeerScoring {
+    fn default() -> Self {
+        Self {
+            initial: INITIAL_SCORE,
+            min: MIN_SCORE,
+            good_block: GOOD_BLOCK_SCORE,
+            bad_block: BAD_BLOCK_SCORE,
+            good_transaction: GOOD_TX_SCORE,
+            bad_transaction: BAD_TX_SCORE,
+            timeout: TIMEOUT_SCORE,
+        }
+    }
+}
+
 // =============================================================================
 // PEER INFO
 // =============================================================================
 
+/// How long a ban lasts unless the operator sets it (system_banPeer)
+pub const DEFAULT_BAN_SECS: u64 = 24 * 3600;
+
+/// Why and until when a peer is banned
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeerBan {
+    pub reason: String,
+    /// Unix time the ban ends at
+    pub until: u64,
+}
+
+/// Reputation of a peer kept across restarts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeerReputation {
+    /// PeerId bytes
+    pub peer_id: Vec<u8>,
+    pub score: i32,
+    pub ban: Option<PeerBan>,
+}
+
+impl PeerReputation {
+    pub fn peer_id(&self) -> Option<PeerId> {
+        PeerId::from_bytes(&self.peer_id).ok()
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
 /// Peer connection state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PeerState {
@@ -101,16 +161,30 @@ pub struct PeerInfo {
 
     /// Latency estimate (ms)
     pub latency_ms: Option<u32>,
+
+    /// Scoring weights
+    pub scoring: PeerScoring,
+
+    /// Protocol versions negotiated on connection (None = handshake pending)
+    pub protocols: Option<NegotiatedVersions>,
+
+    /// Current ban (state Banned)
+    pub ban: Option<PeerBan>,
 }
 
 impl PeerInfo {
     /// Create a new peer info
     pub fn new(id: PeerId) -> Self {
+        Self::with_scoring(id, PeerScoring::default())
+    }
+
+    /// Create a new peer info with custom scoring weights
+    pub fn with_scoring(id: PeerId, scoring: PeerScoring) -> Self {
         let now = Instant::now();
         Self {
             id,
             state: PeerState::Connecting,
-            score: INITIAL_SCORE,
+            score: scoring.initial,
             best_height: 0,
             first_seen: now,
             last_seen: now,
@@ -121,12 +195,15 @@ impl PeerInfo {
             is_bootstrap: false,
             addresses: Vec::new(),
             latency_ms: None,
+            scoring,
+            protocols: None,
+            ban: None,
         }
     }
 
     /// Create a bootstrap peer
-    pub fn bootstrap(id: PeerId, addr: libp2p::Multiaddr) -> Self {
-        let mut info = Self::new(id);
+    pub fn bootstrap(id: PeerId, addr: libp2p::Multiaddr, scoring: PeerScoring) -> Self {
+        let mut info = Self::with_scoring(id, scoring);
         info.is_bootstrap = true;
         info.addresses.push(addr);
         info
@@ -144,7 +221,7 @@ impl PeerInfo {
 
     /// Check if peer should be disconnected
     pub fn should_disconnect(&self) -> bool {
-        self.score < MIN_SCORE || matches!(self.state, PeerState::Banned)
+        self.score < self.scoring.min || matches!(self.state, PeerState::Banned)
     }
 
     /// Check if peer is stale (no activity for timeout)
@@ -154,35 +231,35 @@ impl PeerInfo {
 
     /// Record a good block
     pub fn good_block(&mut self) {
-        self.score = self.score.saturating_add(GOOD_BLOCK_SCORE);
+        self.score = self.score.saturating_add(self.scoring.good_block);
         self.blocks_received += 1;
         self.touch();
     }
 
     /// Record a bad block
     pub fn bad_block(&mut self) {
-        self.score = self.score.saturating_sub(BAD_BLOCK_SCORE.abs());
+        self.score = self.score.saturating_sub(self.scoring.bad_block.abs());
         self.invalid_messages += 1;
         self.touch();
     }
 
     /// Record a good transaction
     pub fn good_transaction(&mut self) {
-        self.score = self.score.saturating_add(GOOD_TX_SCORE);
+        self.score = self.score.saturating_add(self.scoring.good_transaction);
         self.transactions_received += 1;
         self.touch();
     }
 
     /// Record a bad transaction
     pub fn bad_transaction(&mut self) {
-        self.score = self.score.saturating_sub(BAD_TX_SCORE.abs());
+        self.score = self.score.saturating_sub(self.scoring.bad_transaction.abs());
         self.invalid_messages += 1;
         self.touch();
     }
 
     /// Record a timeout
     pub fn timeout(&mut self) {
-        self.score = self.score.saturating_sub(TIMEOUT_SCORE.abs());
+        self.score = self.score.saturating_sub(self.scoring.timeout.abs());
         self.failed_requests += 1;
     }
 
@@ -207,9 +284,21 @@ pub struct PeerManager {
     /// Bootstrap nodes
     bootstrap_nodes: Vec<(PeerId, libp2p::Multiaddr)>,
 
+    /// Reserved peers: always accepted, never evicted
+    reserved: HashSet<PeerId>,
+
+    /// Only reserved peers may connect (--reserved-only)
+    reserved_only: bool,
+
+    /// Peers whose addresses stay private (validators behind this sentry)
+    private: HashSet<PeerId>,
+
     /// Maximum peers
     max_peers: usize,
 
+    /// Scoring weights of the peers
+    scoring: PeerScoring,
+
     /// Last score decay time
     last_decay: Instant,
 }
@@ -220,16 +309,91 @@ impl PeerManager {
         Self {
             peers: HashMap::new(),
             bootstrap_nodes: Vec::new(),
+            reserved: HashSet::new(),
+            reserved_only: false,
+            private: HashSet::new(),
             max_peers: MAX_PEERS,
+            scoring: PeerScoring::default(),
             last_decay: Instant::now(),
         }
     }
 
+    /// Use custom scoring weights (applies to known peers too)
+    pub fn set_scoring(&mut self, scoring: PeerScoring) {
+        self.scoring = scoring;
+        for info in self.peers.values_mut() {
+            info.scoring = scoring;
+        }
+    }
+
+    /// Change the peer limit (connected peers above it are kept)
+    pub fn set_max_peers(&mut self, max_peers: usize) {
+        self.max_peers = max_peers;
+    }
+
+    /// Set the reserved peers, and whether only they may connect
+    pub fn set_reserved(&mut self, peers: impl IntoIterator<Item = PeerId>, only: bool) {
+        self.reserved = peers.into_iter().collect();
+        self.reserved_only = only;
+    }
+
+    /// Whether `peer_id` is a reserved peer
+    pub fn is_reserved(&self, peer_id: &PeerId) -> bool {
+        self.reserved.contains(peer_id)
+    }
+
+    /// Whether only reserved peers may connect
+    pub fn reserved_only(&self) -> bool {
+        self.reserved_only
+    }
+
+    /// Keep the addresses of these peers private (--sentry-for)
+    pub fn set_private(&mut self, peers: impl IntoIterator<Item = PeerId>) {
+        self.private = peers.into_iter().collect();
+    }
+
+    /// Whether the addresses of `peer_id` stay private
+    pub fn is_private(&self, peer_id: &PeerId) -> bool {
+        self.private.contains(peer_id)
+    }
+
+    /// Whether some peers stay private (the node is a sentry)
+    pub fn has_private_peers(&self) -> bool {
+        !self.private.is_empty()
+    }
+
+    /// Reserved peers that are not connected
+    pub fn disconnected_reserved(&self) -> Vec<PeerId> {
+        self.reserved.iter()
+            .filter(|id| !self.is_connected(id) && !self.is_banned(id))
+            .copied()
+            .collect()
+    }
+
+    /// Whether a new connection from `peer_id` fits the peer limit
+    /// (reserved peers, bootstrap nodes and already connected peers always
+    /// do; in reserved-only mode, no other peer does)
+    pub fn accepts_connection(&self, peer_id: &PeerId) -> bool {
+        if self.is_banned(peer_id) {
+            return false;
+        }
+        if self.is_reserved(peer_id) {
+            return true;
+        }
+        if self.reserved_only {
+            return false;
+        }
+        match self.peers.get(peer_id) {
+            Some(info) if info.is_bootstrap || info.is_active() => true,
+            _ => self.can_accept_peer(),
+        }
+    }
+
     /// Add bootstrap nodes
     pub fn add_bootstrap_nodes(&mut self, nodes: Vec<(PeerId, libp2p::Multiaddr)>) {
         for (peer_id, addr) in nodes {
             if !self.peers.contains_key(&peer_id) {
-                let info = PeerInfo::bootstrap(peer_id, addr.clone());
+                let info = PeerInfo::bootstrap(peer_id, addr.clone(), self.scoring);
                 self.peers.insert(peer_id, info);
             }
             self.bootstrap_nodes.push((peer_id, addr));
@@ -244,7 +408,11 @@ impl PeerManager {
 
     /// Register a new peer connection
     pub fn peer_connected(&mut self, peer_id: PeerId) {
-        let info = self.peers.entry(peer_id).or_insert_with(|| PeerInfo::new(peer_id));
+        let info = self.peers.entry(peer_id).or_insert_with(|| PeerInfo::with_scoring(peer_id, self.scoring));
+        if !info.is_active() {
+            // The peer may have upgraded since the last connection
+            info.protocols = None;
+        }
         info.state = PeerState::Connected;
         info.touch();
         debug!("Peer connected: {}", peer_id);
@@ -260,7 +428,7 @@ impl PeerManager {
 
     /// Add address for a peer
     pub fn add_peer_address(&mut self, peer_id: PeerId, addr: libp2p::Multiaddr) {
-        let info = self.peers.entry(peer_id).or_insert_with(|| PeerInfo::new(peer_id));
+        let info = self.peers.entry(peer_id).or_insert_with(|| PeerInfo::with_scoring(peer_id, self.scoring));
         if !info.addresses.contains(&addr) {
             info.addresses.push(addr);
         }
@@ -303,6 +471,11 @@ impl PeerManager {
         self.connected_count() < self.max_peers
     }
 
+    /// Peers that can still connect before the limit
+    pub fn free_slots(&self) -> usize {
+        self.max_peers.saturating_sub(self.connected_count())
+    }
+
     /// Get best peer for sync (highest score among those with blocks)
     pub fn best_sync_peer(&self) -> Option<&PeerInfo> {
         self.peers.values()
@@ -310,23 +483,141 @@ impl PeerManager {
             .max_by_key(|p| (p.best_height, p.score))
     }
 
-    /// Get peers to disconnect (low score or stale)
+    /// Active peers with blocks, and their best height (parallel sync candidates)
+    pub fn sync_peers(&self) -> Vec<(PeerId, u64)> {
+        self.peers.values()
+            .filter(|p| p.is_active() && p.best_height > 0)
+            .map(|p| (p.id, p.best_height))
+            .collect()
+    }
+
+    /// Peers worth dialing first after a restart: connected or in good
+    /// standing, with a dialable address (best first, at most the peer limit;
+    /// private peers never, as the set is saved and exported)
+    pub fn known_good_peers(&self) -> Vec<(PeerId, Vec<libp2p::Multiaddr>)> {
+        let mut peers: Vec<_> = self.peers.values()
+            .filter(|p| !p.is_bootstrap && !p.addresses.is_empty() && !self.private.contains(&p.id))
+            .filter(|p| p.is_active() || (p.state == PeerState::Disconnected && p.score >= self.scoring.initial))
+            .collect();
+        peers.sort_by_key(|p| (std::cmp::Reverse(p.is_active()), std::cmp::Reverse(p.score)));
+        peers.into_iter()
+            .take(self.max_peers)
+            .map(|p| (p.id, p.addresses.clone()))
+            .collect()
+    }
+
+    /// Get peers to disconnect (low score or stale, reserved peers excepted)
     pub fn peers_to_disconnect(&self) -> Vec<PeerId> {
         self.peers.values()
+            .filter(|p| !self.reserved.contains(&p.id))
             .filter(|p| p.should_disconnect() || (p.is_active() && p.is_stale()))
             .map(|p| p.id)
             .collect()
     }
 
-    /// Ban a peer
+    /// Ban a peer for DEFAULT_BAN_SECS
     pub fn ban_peer(&mut self, peer_id: &PeerId, reason: &str) {
+        self.ban_peer_for(peer_id, reason, DEFAULT_BAN_SECS);
+    }
+
+    /// Ban a peer for `secs` seconds (known or not: the ban applies when it
+    /// connects)
+    pub fn ban_peer_for(&mut self, peer_id: &PeerId, reason: &str, secs: u64) -> PeerBan {
+        let ban = PeerBan { reason: reason.to_string(), until: unix_now().saturating_add(secs) };
+        let info = self.peers.entry(*peer_id).or_insert_with(|| PeerInfo::with_scoring(*peer_id, self.scoring));
+        info.state = PeerState::Banned;
+        info.score = self.scoring.min - 1;
+        info.ban = Some(ban.clone());
+        warn!("Peer {} banned: {}", peer_id, reason);
+        ban
+    }
+
+    /// Lift a ban, the peer starts over at the initial score (false: not banned)
+    pub fn unban_peer(&mut self, peer_id: &PeerId) -> bool {
+        match self.peers.get_mut(peer_id) {
+            Some(info) if info.ban.is_some() => {
+                info.ban = None;
+                info.state = PeerState::Disconnected;
+                info.score = self.scoring.initial;
+                info.touch();
+                info!("Peer {} unbanned", peer_id);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn is_banned(&self, peer_id: &PeerId) -> bool {
+        self.peers.get(peer_id).is_some_and(|info| info.ban.is_some())
+    }
+
+    /// Known peers, connected or not
+    pub fn peers(&self) -> impl Iterator<Item = &PeerInfo> {
+        self.peers.values()
+    }
+
+    /// Reputations worth keeping across a restart: bans, and scores away
+    /// from the initial one
+    pub fn reputations(&self) -> Vec<PeerReputation> {
+        self.peers
+            .values()
+            .filter(|p| p.ban.is_some() || p.score != self.scoring.initial)
+            .map(|p| PeerReputation { peer_id: p.id.to_bytes(), score: p.score, ban: p.ban.clone() })
+            .collect()
+    }
+
+    /// Restore saved reputations (expired bans are dropped), returning how
+    /// many bans were restored
+    pub fn restore_reputations(&mut self, reputations: Vec<PeerReputation>) -> usize {
+        let now = unix_now();
+        let mut bans = 0;
+        for reputation in reputations {
+            let Some(peer_id) = reputation.peer_id() else { continue };
+            let ban = reputation.ban.filter(|ban| ban.until > now);
+            let info = self.peers.entry(peer_id).or_insert_with(|| PeerInfo::with_scoring(peer_id, self.scoring));
+            if ban.is_some() {
+                info.state = PeerState::Banned;
+                info.score = reputation.score.min(self.scoring.min - 1);
+                bans += 1;
+            } else {
+                if info.state == PeerState::Connecting {
+                    info.state = PeerState::Disconnected;
+                }
+                info.score = reputation.score.max(self.scoring.min);
+            }
+            info.ban = ban;
+        }
+        bans
+    }
+
+    /// Lift the bans that ended
+    fn expire_bans(&mut self, now: u64) {
+        for info in self.peers.values_mut() {
+            if info.ban.as_ref().is_some_and(|ban| ban.until <= now) {
+                info.ban = None;
+                info.state = PeerState::Disconnected;
+                info.score = self.scoring.initial;
+                info.touch();
+                debug!("Ban of {} expired", info.id);
+            }
+        }
+    }
+
+    /// Record the protocol versions negotiated with a peer
+    pub fn set_protocols(&mut self, peer_id: &PeerId, versions: NegotiatedVersions) {
         if let Some(info) = self.peers.get_mut(peer_id) {
-            info.state = PeerState::Banned;
-            info.score = MIN_SCORE - 1;
-            warn!("Peer {} banned: {}", peer_id, reason);
+            info.protocols = Some(versions);
         }
     }
 
+    /// Version of `protocol` to speak with a peer (v1 until negotiated)
+    pub fn protocol_version(&self, peer_id: &PeerId, protocol: SubProtocol) -> u32 {
+        self.peers
+            .get(peer_id)
+            .and_then(|info| info.protocols.as_ref())
+            .map_or(1, |versions| versions.get(protocol))
+    }
+
     /// Update peer's best height
     pub fn update_peer_height(&mut self, peer_id: &PeerId, height: u64) {
         if let Some(info) = self.peers.get_mut(peer_id) {
@@ -370,27 +661,30 @@ impl PeerManager {
             self.last_decay = Instant::now();
         }
 
+        self.expire_bans(unix_now());
+
         // Clean up very old disconnected peers
         self.cleanup_stale_peers();
     }
 
     /// Decay all peer scores toward baseline
     fn decay_scores(&mut self) {
-        for info in self.peers.values_mut() {
-            if info.score > INITIAL_SCORE {
+        for info in self.peers.values_mut().filter(|p| p.ban.is_none()) {
+            if info.score > info.scoring.initial {
                 info.score -= 1;
-            } else if info.score < INITIAL_SCORE {
+            } else if info.score < info.scoring.initial {
                 info.score += 1;
             }
         }
     }
 
-    /// Remove very old disconnected peers (keep bootstrap)
+    /// Remove very old disconnected peers (keep bootstrap and reserved)
     fn cleanup_stale_peers(&mut self) {
         let stale_threshold = Duration::from_secs(3600); // 1 hour
         let to_remove: Vec<PeerId> = self.peers.iter()
-            .filter(|(_, info)| {
+            .filter(|(id, info)| {
                 !info.is_bootstrap
+                    && !self.reserved.contains(id)
                     && matches!(info.state, PeerState::Disconnected)
                     && info.last_seen.elapsed() > stale_threshold
             })
@@ -453,7 +747,7 @@ pub struct PeerStats {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use libp2p::PeerId;
+use libp2p::PeerId;
 
     fn create_peer_id(seed: u8) -> PeerId {
         let keypair = libp2p::identity::Keypair::generate_ed25519();
@@ -502,6 +796,93 @@ mod tests {
         assert!(manager.can_accept_peer());
     }
 
+    #[test]
+    fn test_peer_limit() {
+        let mut manager = PeerManager::new();
+        let (peer1, peer2, peer3) = (create_peer_id(1), create_peer_id(2), create_peer_id(3));
+        manager.peer_connected(peer1);
+        manager.peer_connected(peer2);
+
+        // Lowering the limit keeps the connected peers
+        manager.set_max_peers(1);
+        assert_eq!(manager.connected_count(), 2);
+        assert!(manager.accepts_connection(&peer1));
+        assert!(!manager.accepts_connection(&peer3));
+
+        let bootnode = create_peer_id(4);
+        manager.add_bootstrap_nodes(vec![(bootnode, "/ip4/127.0.0.1/tcp/30333".parse().unwrap())]);
+        assert!(manager.accepts_connection(&bootnode));
+
+        manager.set_max_peers(3);
+        assert!(manager.accepts_connection(&peer3));
+    }
+
+    #[test]
+    fn test_reserved_peers() {
+        let mut manager = PeerManager::new();
+        let (reserved, other, connected) = (create_peer_id(1), create_peer_id(2), create_peer_id(3));
+        manager.peer_connected(connected);
+        manager.set_max_peers(1);
+        manager.set_reserved([reserved], false);
+
+        // Reserved peers bypass the limit and are never evicted
+        assert!(manager.accepts_connection(&reserved));
+        assert!(!manager.accepts_connection(&other));
+        assert_eq!(manager.disconnected_reserved(), vec![reserved]);
+        manager.peer_connected(reserved);
+        manager.get_peer_mut(&reserved).unwrap().score = MIN_SCORE;
+        assert!(manager.peers_to_disconnect().is_empty());
+        assert!(manager.disconnected_reserved().is_empty());
+
+        // Reserved-only: nobody else, even below the limit
+        manager.set_max_peers(10);
+        manager.set_reserved([reserved], true);
+        assert!(manager.reserved_only());
+        assert!(manager.accepts_connection(&reserved));
+        assert!(!manager.accepts_connection(&other));
+        assert!(!manager.accepts_connection(&connected));
+    }
+
+    #[test]
+    fn test_private_peers() {
+        let mut manager = PeerManager::new();
+        let addr: libp2p::Multiaddr = "/ip4/10.8.0.2/tcp/30333".parse().unwrap();
+        let (validator, public) = (create_peer_id(1), create_peer_id(2));
+        for peer in [validator, public] {
+            manager.add_peer_address(peer, addr.clone());
+            manager.peer_connected(peer);
+        }
+        manager.set_private([validator]);
+
+        // A sentry's validator never reaches the saved or exported peers
+        assert!(manager.has_private_peers());
+        assert!(manager.is_private(&validator) && !manager.is_private(&public));
+        assert_eq!(manager.known_good_peers(), vec![(public, vec![addr])]);
+    }
+
+    #[test]
+    fn test_known_good_peers() {
+        let mut manager = PeerManager::new();
+        let addr: libp2p::Multiaddr = "/ip4/10.0.0.1/tcp/30333".parse().unwrap();
+        let (connected, left, bad, unknown) = (create_peer_id(1), create_peer_id(2), create_peer_id(3), create_peer_id(4));
+        for peer in [connected, left, bad] {
+            manager.add_peer_address(peer, addr.clone());
+            manager.peer_connected(peer);
+        }
+        manager.peer_connected(unknown);
+        manager.record_good_block(&left);
+        manager.peer_disconnected(&left);
+        manager.record_bad_block(&bad);
+        manager.peer_disconnected(&bad);
+
+        // No address (inbound only) or below the initial score: skipped
+        let peers = manager.known_good_peers();
+        assert_eq!(peers, vec![(connected, vec![addr.clone()]), (left, vec![addr])]);
+
+        manager.set_max_peers(1);
+        assert_eq!(manager.known_good_peers().len(), 1);
+    }
+
     #[test]
     fn test_peer_ban() {
         let mut manager = PeerManager::new();
@@ -513,6 +894,47 @@ mod tests {
         let info = manager.get_peer(&peer_id).unwrap();
         assert_eq!(info.state, PeerState::Banned);
         assert!(info.should_disconnect());
+        assert_eq!(info.ban.as_ref().unwrap().reason, "test ban");
+        assert!(!manager.accepts_connection(&peer_id));
+
+        assert!(manager.unban_peer(&peer_id));
+        assert!(!manager.unban_peer(&peer_id));
+        assert!(manager.accepts_connection(&peer_id));
+        assert_eq!(manager.get_peer(&peer_id).unwrap().score, INITIAL_SCORE);
+
+        // Bans end on their own
+        manager.ban_peer_for(&peer_id, "short", 10);
+        manager.expire_bans(unix_now() + 5);
+        assert!(manager.is_banned(&peer_id));
+        manager.expire_bans(unix_now() + 10);
+        assert!(!manager.is_banned(&peer_id));
+    }
+
+    #[test]
+    fn test_reputation_restore() {
+        let mut manager = PeerManager::new();
+        let (banned, bad, neutral, expired) = (create_peer_id(1), create_peer_id(2), create_peer_id(3), create_peer_id(4));
+        for peer in [banned, bad, neutral] {
+            manager.peer_connected(peer);
+        }
+        manager.ban_peer(&banned, "invalid block");
+        manager.record_bad_block(&bad);
+        let mut saved = manager.reputations();
+        assert_eq!(saved.len(), 2);
+        saved.push(PeerReputation {
+            peer_id: expired.to_bytes(),
+            score: MIN_SCORE - 1,
+            ban: Some(PeerBan { reason: "old".to_string(), until: 1 }),
+        });
+
+        let mut restarted = PeerManager::new();
+        assert_eq!(restarted.restore_reputations(saved), 1);
+        assert!(restarted.is_banned(&banned) && !restarted.accepts_connection(&banned));
+        assert_eq!(restarted.get_peer(&banned).unwrap().ban.as_ref().unwrap().reason, "invalid block");
+        assert_eq!(restarted.get_peer(&bad).unwrap().score, INITIAL_SCORE - BAD_BLOCK_SCORE.abs());
+        assert!(restarted.get_peer(&neutral).is_none());
+        assert!(!restarted.is_banned(&expired));
+        assert_eq!(restarted.connected_count(), 0);
     }
 
     #[test]
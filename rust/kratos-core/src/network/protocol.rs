@@ -1,7 +1,10 @@
 // Protocol - Messages et topics pour le réseau KratOs
-use crate::types::{Block, SignedTransaction, Hash};
+use crate::types::{Block, BlockNumber, SignedTransaction, Hash};
+use libp2p::PeerId;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 use std::hash::Hash as StdHash;
+use std::time::{Duration, Instant};
 
 // =============================================================================
 // SECURITY FIX #17: Maximum message size for deserialization
@@ -126,6 +129,16 @@ pub enum NetworkMessage {
         votes: Vec<Vec<u8>>,
         epoch: u64,
     },
+
+    // =========================================================================
+    // SLASHING EVIDENCE
+    // =========================================================================
+
+    /// Proof that a validator equivocated
+    Evidence {
+        /// Encoded EquivocationEvidence
+        evidence_data: Vec<u8>,
+    },
 }
 
 impl NetworkMessage {
@@ -175,11 +188,455 @@ impl NetworkMessage {
             NetworkMessage::FinalityJustification { .. } => GossipTopic::Finality,
             NetworkMessage::FinalityVotesRequest { .. } => GossipTopic::Finality,
             NetworkMessage::FinalityVotesResponse { .. } => GossipTopic::Finality,
+            NetworkMessage::Evidence { .. } => GossipTopic::Consensus,
             _ => GossipTopic::Blocks, // Par défaut
         }
     }
 }
 
+// =============================================================================
+// TRANSACTION RELAY (gossip v2)
+// Transactions are announced by hash to the peers that don't know them yet;
+// a peer pulls the bodies it lacks. Peers still on gossip v1 get the full
+// transaction over gossipsub, as before.
+// =============================================================================
+
+/// Gossip version from which peers announce transactions instead of pushing them
+pub const TX_ANNOUNCE_VERSION: u32 = 2;
+
+/// Hashes per announcement or pull at most
+pub const MAX_TX_ANNOUNCE: usize = 256;
+
+/// Transactions kept to serve pulls (oldest evicted first)
+pub const TX_RELAY_CACHE: usize = 8192;
+
+/// A pull not answered by then may go to another announcer
+pub const TX_PULL_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Transactions per filter generation (~0.25% false positives when full)
+pub const KNOWN_TX_FILTER_CAPACITY: usize = 4096;
+
+/// Bits per filter generation (8 KiB)
+const KNOWN_TX_FILTER_BITS: usize = 1 << 16;
+
+/// Bits set per transaction
+const KNOWN_TX_FILTER_HASHES: usize = 4;
+
+/// Size of an announced hash on the wire
+const HASH_BYTES: u64 = 32;
+
+/// Transactions a peer knows (it sent them to us, or we announced them)
+///
+/// Bloom filter in two generations: when the current one is full it becomes
+/// the previous one, so memory stays bounded and old transactions fade out.
+/// A false positive only means the peer hears of the transaction from
+/// another peer.
+#[derive(Debug, Clone)]
+pub struct KnownTxFilter {
+    current: Vec<u64>,
+    previous: Vec<u64>,
+    inserted: usize,
+}
+
+impl KnownTxFilter {
+    pub fn new() -> Self {
+        Self {
+            current: vec![0; KNOWN_TX_FILTER_BITS / 64],
+            previous: vec![0; KNOWN_TX_FILTER_BITS / 64],
+            inserted: 0,
+        }
+    }
+
+    /// Bit positions of a transaction (hashes are uniform: their bytes are
+    /// used directly)
+    fn positions(hash: &Hash) -> impl Iterator<Item = usize> + '_ {
+        hash.as_bytes()
+            .chunks_exact(4)
+            .take(KNOWN_TX_FILTER_HASHES)
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]) as usize % KNOWN_TX_FILTER_BITS)
+    }
+
+    fn test(bits: &[u64], position: usize) -> bool {
+        bits[position / 64] & (1 << (position % 64)) != 0
+    }
+
+    /// Whether the peer (probably) knows the transaction
+    pub fn contains(&self, hash: &Hash) -> bool {
+        Self::positions(hash).all(|p| Self::test(&self.current, p))
+            || Self::positions(hash).all(|p| Self::test(&self.previous, p))
+    }
+
+    /// Record a transaction, returning false if it was (probably) known
+    pub fn insert(&mut self, hash: &Hash) -> bool {
+        if self.contains(hash) {
+            return false;
+        }
+        if self.inserted >= KNOWN_TX_FILTER_CAPACITY {
+            self.previous = std::mem::replace(&mut self.current, vec![0; KNOWN_TX_FILTER_BITS / 64]);
+            self.inserted = 0;
+        }
+        for position in Self::positions(hash) {
+            self.current[position / 64] |= 1 << (position % 64);
+        }
+        self.inserted += 1;
+        true
+    }
+}
+
+impl Default for KnownTxFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Transaction relay counters (since startup)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TxRelayStats {
+    /// Hashes announced to peers
+    pub announced: u64,
+    /// Announced hashes we already had (no pull)
+    pub duplicates: u64,
+    /// Bodies pulled from peers
+    pub pulled: u64,
+    /// Bodies served to peers' pulls
+    pub served: u64,
+    /// Body bytes a push to every peer would have sent
+    pub push_bytes: u64,
+    /// Bytes actually sent: announcements and served bodies
+    pub sent_bytes: u64,
+}
+
+impl TxRelayStats {
+    /// Bandwidth saved by announcing instead of pushing
+    pub fn bytes_saved(&self) -> u64 {
+        self.push_bytes.saturating_sub(self.sent_bytes)
+    }
+}
+
+/// A relayed transaction kept to serve pulls
+#[derive(Debug, Clone)]
+struct CachedTx {
+    tx: SignedTransaction,
+    /// Arrived over gossipsub: gossip v1 peers already got it from there
+    gossiped: bool,
+}
+
+/// Announcement-based transaction relay: per-peer knowledge, announcements
+/// waiting to go out, bodies served to pulls
+#[derive(Debug, Default)]
+pub struct TxRelay {
+    /// Transactions each gossip v2 peer knows
+    known: HashMap<PeerId, KnownTxFilter>,
+    /// Hashes waiting to be announced, per peer
+    pending: HashMap<PeerId, Vec<Hash>>,
+    /// Recent transactions, served to pulls
+    cache: HashMap<Hash, CachedTx>,
+    cache_order: VecDeque<Hash>,
+    /// Hashes being pulled, since when
+    pulling: HashMap<Hash, Instant>,
+    stats: TxRelayStats,
+}
+
+impl TxRelay {
+    /// Announce transactions to `peer` from now on (it speaks gossip v2)
+    pub fn add_peer(&mut self, peer: PeerId) {
+        self.known.entry(peer).or_default();
+    }
+
+    pub fn remove_peer(&mut self, peer: &PeerId) {
+        self.known.remove(peer);
+        self.pending.remove(peer);
+    }
+
+    /// Whether `peer` gets announcements
+    pub fn announces_to(&self, peer: &PeerId) -> bool {
+        self.known.contains_key(peer)
+    }
+
+    /// Record that `peer` knows a transaction
+    pub fn mark_known(&mut self, peer: &PeerId, hash: &Hash) {
+        if let Some(filter) = self.known.get_mut(peer) {
+            filter.insert(hash);
+        }
+    }
+
+    /// Keep a transaction to serve pulls
+    pub fn cache(&mut self, hash: Hash, tx: SignedTransaction, gossiped: bool) {
+        if let Some(cached) = self.cache.get_mut(&hash) {
+            cached.gossiped |= gossiped;
+            return;
+        }
+        if self.cache_order.len() >= TX_RELAY_CACHE {
+            if let Some(oldest) = self.cache_order.pop_front() {
+                self.cache.remove(&oldest);
+            }
+        }
+        self.cache.insert(hash, CachedTx { tx, gossiped });
+        self.cache_order.push_back(hash);
+    }
+
+    /// Whether a transaction arrived over gossipsub
+    pub fn gossiped(&self, hash: &Hash) -> bool {
+        self.cache.get(hash).is_some_and(|cached| cached.gossiped)
+    }
+
+    /// Queue the announcement of a transaction of `size` bytes to the peers
+    /// that don't know it (`from` sent it to us)
+    pub fn announce(&mut self, hash: Hash, size: u64, from: Option<PeerId>) {
+        for (peer, filter) in self.known.iter_mut() {
+            if Some(*peer) == from {
+                filter.insert(&hash);
+                continue;
+            }
+            // A push would have sent the body to this peer
+            self.stats.push_bytes += size;
+            if filter.insert(&hash) {
+                self.pending.entry(*peer).or_default().push(hash);
+                self.stats.announced += 1;
+                self.stats.sent_bytes += HASH_BYTES;
+            }
+        }
+    }
+
+    /// Announcements to send now, at most MAX_TX_ANNOUNCE hashes each
+    pub fn take_announcements(&mut self) -> Vec<(PeerId, Vec<Hash>)> {
+        let mut announcements = Vec::new();
+        for (peer, hashes) in self.pending.drain() {
+            for chunk in hashes.chunks(MAX_TX_ANNOUNCE) {
+                announcements.push((peer, chunk.to_vec()));
+            }
+        }
+        announcements
+    }
+
+    /// Hashes announced by `peer` to pull: neither known nor being pulled
+    pub fn to_pull(&mut self, peer: &PeerId, hashes: &[Hash], now: Instant) -> Vec<Hash> {
+        self.pulling.retain(|_, since| now.duration_since(*since) < TX_PULL_TIMEOUT);
+        let mut wanted = Vec::new();
+        for hash in hashes.iter().take(MAX_TX_ANNOUNCE) {
+            self.mark_known(peer, hash);
+            if self.cache.contains_key(hash) || self.pulling.contains_key(hash) {
+                self.stats.duplicates += 1;
+            } else {
+                self.pulling.insert(*hash, now);
+                wanted.push(*hash);
+            }
+        }
+        wanted
+    }
+
+    /// Bodies for a pull by `peer`
+    pub fn serve(&mut self, peer: &PeerId, hashes: &[Hash]) -> Vec<SignedTransaction> {
+        let mut bodies = Vec::new();
+        for hash in hashes.iter().take(MAX_TX_ANNOUNCE) {
+            if let Some(cached) = self.cache.get(hash) {
+                self.stats.served += 1;
+                self.stats.sent_bytes += bincode::serialized_size(&cached.tx).unwrap_or(0);
+                bodies.push(cached.tx.clone());
+            }
+        }
+        for hash in hashes {
+            self.mark_known(peer, hash);
+        }
+        bodies
+    }
+
+    /// A pulled body arrived (false: nobody asked for it)
+    pub fn pulled(&mut self, hash: &Hash) -> bool {
+        let requested = self.pulling.remove(hash).is_some();
+        if requested {
+            self.stats.pulled += 1;
+        }
+        requested
+    }
+
+    pub fn stats(&self) -> TxRelayStats {
+        self.stats
+    }
+}
+
+// =============================================================================
+// BLOCK ANNOUNCEMENTS (gossip v3)
+// Blocks are announced by header to the peers that don't know them yet; a
+// peer checks the header, then fetches the body from the best announcer.
+// Peers still on an older gossip version get the full block over gossipsub.
+// =============================================================================
+
+/// Gossip version from which peers announce blocks instead of pushing them
+pub const BLOCK_ANNOUNCE_VERSION: u32 = 3;
+
+/// Recent blocks kept to serve fetches, and remembered per peer
+pub const RECENT_BLOCKS: usize = 64;
+
+/// Announced blocks awaiting a check or a fetch at most (later announcements
+/// are ignored until some are imported)
+pub const MAX_PENDING_ANNOUNCEMENTS: usize = 64;
+
+/// A recent block
+#[derive(Debug, Clone)]
+struct CachedBlock {
+    block: Block,
+    /// Arrived over gossipsub: older peers already got it from there
+    gossiped: bool,
+    /// Announced (or published) by this node already
+    relayed: bool,
+}
+
+/// An announced block not imported yet
+#[derive(Debug, Clone)]
+struct PendingBlock {
+    number: BlockNumber,
+    /// Peers that announced it, in order (the rejected ones removed)
+    announcers: Vec<PeerId>,
+    /// Peer the body is being fetched from
+    fetching: Option<PeerId>,
+}
+
+/// What to do with a block announcement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnouncementOutcome {
+    /// First announcement of the block: check its header
+    Check,
+    /// Already announced, known or beyond what we track: nothing to do
+    Ignore,
+}
+
+/// Header-first block propagation: per-peer knowledge, recent blocks served
+/// to fetches, announced blocks being checked or fetched
+#[derive(Debug, Default)]
+pub struct BlockRelay {
+    /// Recent blocks each gossip v3 peer knows
+    known: HashMap<PeerId, VecDeque<Hash>>,
+    blocks: HashMap<Hash, CachedBlock>,
+    order: VecDeque<Hash>,
+    pending: HashMap<Hash, PendingBlock>,
+}
+
+impl BlockRelay {
+    /// Announce blocks to `peer` from now on (it speaks gossip v3)
+    pub fn add_peer(&mut self, peer: PeerId) {
+        self.known.entry(peer).or_default();
+    }
+
+    pub fn remove_peer(&mut self, peer: &PeerId) {
+        self.known.remove(peer);
+        for pending in self.pending.values_mut() {
+            pending.announcers.retain(|p| p != peer);
+        }
+    }
+
+    /// Whether `peer` gets announcements
+    pub fn announces_to(&self, peer: &PeerId) -> bool {
+        self.known.contains_key(peer)
+    }
+
+    /// Record that `peer` knows a block, returning false if already recorded
+    pub fn mark_known(&mut self, peer: &PeerId, hash: &Hash) -> bool {
+        let Some(known) = self.known.get_mut(peer) else {
+            return false;
+        };
+        if known.contains(hash) {
+            return false;
+        }
+        if known.len() >= RECENT_BLOCKS {
+            known.pop_front();
+        }
+        known.push_back(*hash);
+        true
+    }
+
+    /// Keep a block to serve fetches
+    pub fn cache(&mut self, block: Block, gossiped: bool) {
+        let hash = block.hash();
+        if let Some(cached) = self.blocks.get_mut(&hash) {
+            cached.gossiped |= gossiped;
+            return;
+        }
+        if self.order.len() >= RECENT_BLOCKS {
+            if let Some(oldest) = self.order.pop_front() {
+                self.blocks.remove(&oldest);
+            }
+        }
+        self.blocks.insert(hash, CachedBlock { block, gossiped, relayed: false });
+        self.order.push_back(hash);
+    }
+
+    /// A recent block
+    pub fn block(&self, hash: &Hash) -> Option<&Block> {
+        self.blocks.get(hash).map(|cached| &cached.block)
+    }
+
+    /// Whether a block arrived over gossipsub
+    pub fn gossiped(&self, hash: &Hash) -> bool {
+        self.blocks.get(hash).is_some_and(|cached| cached.gossiped)
+    }
+
+    /// Peers to announce a cached block to (`from` sent it to us), or None
+    /// if it was relayed already
+    pub fn announce(&mut self, hash: &Hash, from: Option<PeerId>) -> Option<Vec<PeerId>> {
+        let cached = self.blocks.get_mut(hash)?;
+        if cached.relayed {
+            return None;
+        }
+        cached.relayed = true;
+        if let Some(from) = from {
+            self.mark_known(&from, hash);
+        }
+        let peers: Vec<PeerId> = self.known.keys().copied().collect();
+        Some(peers.into_iter().filter(|peer| self.mark_known(peer, hash)).collect())
+    }
+
+    /// Record an announcement of block `number` by `peer`
+    pub fn announced(&mut self, peer: &PeerId, hash: Hash, number: BlockNumber) -> AnnouncementOutcome {
+        self.mark_known(peer, &hash);
+        if self.blocks.contains_key(&hash) {
+            return AnnouncementOutcome::Ignore;
+        }
+        if let Some(pending) = self.pending.get_mut(&hash) {
+            if !pending.announcers.contains(peer) {
+                pending.announcers.push(*peer);
+            }
+            return AnnouncementOutcome::Ignore;
+        }
+        if self.pending.len() >= MAX_PENDING_ANNOUNCEMENTS {
+            return AnnouncementOutcome::Ignore;
+        }
+        self.pending.insert(hash, PendingBlock { number, announcers: vec![*peer], fetching: None });
+        AnnouncementOutcome::Check
+    }
+
+    /// Header checked: the announcer to fetch the body from, best `rank` first
+    pub fn fetch_from(&mut self, hash: &Hash, rank: impl Fn(&PeerId) -> i32) -> Option<PeerId> {
+        let pending = self.pending.get_mut(hash)?;
+        let peer = pending.announcers.iter().max_by_key(|p| rank(p)).copied()?;
+        pending.fetching = Some(peer);
+        Some(peer)
+    }
+
+    /// The fetch from `peer` failed: it is no longer asked for this block
+    pub fn fetch_failed(&mut self, hash: &Hash, peer: &PeerId) {
+        if let Some(pending) = self.pending.get_mut(hash) {
+            pending.announcers.retain(|p| p != peer);
+        }
+    }
+
+    /// Whether a fetched body was asked of `peer`
+    pub fn is_fetching(&self, hash: &Hash, peer: &PeerId) -> bool {
+        self.pending.get(hash).is_some_and(|pending| pending.fetching == Some(*peer))
+    }
+
+    /// Stop tracking an announced block (fetched, invalid or not needed),
+    /// returning its announcers
+    pub fn forget(&mut self, hash: &Hash) -> Vec<PeerId> {
+        self.pending.remove(hash).map(|pending| pending.announcers).unwrap_or_default()
+    }
+
+    /// Stop tracking the announced blocks at or below `height`
+    pub fn prune(&mut self, height: BlockNumber) {
+        self.pending.retain(|_, pending| pending.number > height);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,6 +653,10 @@ mod tests {
                     amount: 1000,
                 },
                 timestamp: 0,
+                genesis_hash: Hash::ZERO,
+                valid_until: None,
+                max_fee: None,
+                tip: 0,
             },
             signature: Signature64([0; 64]),
             hash: Some(Hash::from_bytes([0; 32])),
@@ -220,4 +681,99 @@ mod tests {
         assert_eq!(GossipTopic::Transactions.as_str(), "/kratos/transactions/1.0.0");
         assert_eq!(GossipTopic::Consensus.as_str(), "/kratos/consensus/1.0.0");
     }
+
+    #[test]
+    fn test_known_tx_filter() {
+        let mut filter = KnownTxFilter::new();
+        let hashes: Vec<Hash> = (0..KNOWN_TX_FILTER_CAPACITY as u32).map(|i| Hash::hash(&i.to_le_bytes())).collect();
+        for hash in &hashes {
+            filter.insert(hash);
+        }
+        assert!(hashes.iter().all(|h| filter.contains(h)));
+        assert!(!filter.insert(&hashes[0]));
+
+        // False positives stay rare when full
+        let false_positives = (0..10_000u32)
+            .filter(|i| filter.contains(&Hash::hash(&(i + 1_000_000).to_le_bytes())))
+            .count();
+        assert!(false_positives < 100, "{} false positives", false_positives);
+
+        // The previous generation is kept, the one before is forgotten
+        let next: Vec<Hash> = (0..2 * KNOWN_TX_FILTER_CAPACITY as u32).map(|i| Hash::hash(&(i + 2_000_000).to_le_bytes())).collect();
+        for hash in &next[..KNOWN_TX_FILTER_CAPACITY] {
+            filter.insert(hash);
+        }
+        assert!(filter.contains(&hashes[0]));
+        for hash in &next[KNOWN_TX_FILTER_CAPACITY..] {
+            filter.insert(hash);
+        }
+        assert!(!filter.contains(&hashes[0]));
+    }
+
+    #[test]
+    fn test_tx_relay_announce_and_pull() {
+        let (source, a, b, legacy) = (PeerId::random(), PeerId::random(), PeerId::random(), PeerId::random());
+        let hash = Hash::hash(b"tx");
+        let mut relay = TxRelay::default();
+        for peer in [source, a, b] {
+            relay.add_peer(peer);
+        }
+        assert!(!relay.announces_to(&legacy));
+
+        // Announced to the peers but the source and those that know it
+        relay.mark_known(&b, &hash);
+        relay.announce(hash, 200, Some(source));
+        let announcements = relay.take_announcements();
+        assert_eq!(announcements, vec![(a, vec![hash])]);
+        let stats = relay.stats();
+        assert_eq!(stats.announced, 1);
+        assert_eq!(stats.bytes_saved(), 2 * 200 - 32);
+
+        // The receiving side pulls what it lacks, once
+        let mut receiver = TxRelay::default();
+        receiver.add_peer(source);
+        let other = Hash::hash(b"other");
+        let now = Instant::now();
+        assert_eq!(receiver.to_pull(&source, &[hash, other], now), vec![hash, other]);
+        assert!(receiver.to_pull(&a, &[hash], now).is_empty());
+        assert_eq!(receiver.stats().duplicates, 1);
+        assert!(receiver.pulled(&hash) && !receiver.pulled(&hash));
+        assert_eq!(receiver.to_pull(&a, &[other], now + TX_PULL_TIMEOUT), vec![other]);
+    }
+
+    #[test]
+    fn test_block_relay_announce_and_fetch() {
+        let (producer, good, bad, legacy) = (PeerId::random(), PeerId::random(), PeerId::random(), PeerId::random());
+        let block = Block::genesis(Hash::hash(b"state"), vec![]);
+        let hash = block.hash();
+
+        // Relayed once, to the v3 peers but the one it came from
+        let mut relay = BlockRelay::default();
+        for peer in [producer, good] {
+            relay.add_peer(peer);
+        }
+        relay.cache(block.clone(), false);
+        assert_eq!(relay.announce(&hash, Some(producer)), Some(vec![good]));
+        assert_eq!(relay.announce(&hash, None), None);
+        assert!(!relay.announces_to(&legacy));
+
+        // Receiving side: one check per block, the body fetched from the best announcer
+        let mut receiver = BlockRelay::default();
+        assert_eq!(receiver.announced(&bad, hash, 1), AnnouncementOutcome::Check);
+        assert_eq!(receiver.announced(&good, hash, 1), AnnouncementOutcome::Ignore);
+        let rank = |peer: &PeerId| if *peer == bad { 50 } else { 100 };
+        assert_eq!(receiver.fetch_from(&hash, rank), Some(good));
+        assert!(receiver.is_fetching(&hash, &good));
+        receiver.fetch_failed(&hash, &good);
+        assert_eq!(receiver.fetch_from(&hash, rank), Some(bad));
+        receiver.fetch_failed(&hash, &bad);
+        assert_eq!(receiver.fetch_from(&hash, rank), None);
+
+        // Imported meanwhile: no longer tracked
+        receiver.prune(1);
+        assert!(receiver.forget(&hash).is_empty());
+        receiver.cache(block, true);
+        assert_eq!(receiver.announced(&good, hash, 1), AnnouncementOutcome::Ignore);
+        assert!(receiver.gossiped(&hash));
+    }
 }
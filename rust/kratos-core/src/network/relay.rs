@@ -0,0 +1,200 @@
+// Relay - Circuit relay v2 for nodes that can't be dialed
+// Principle: A node behind NAT still takes part, through a public node
+//
+// - Every node carries the relay client transport, so it can dial circuit
+//   addresses (/ip4/R/tcp/P/p2p/<relay>/p2p-circuit/p2p/<target>)
+// - A node started with --relay serves reservations and circuits, and
+//   advertises the relay capability to the DNS Seeds
+// - A node no peer has dialed after RELAY_GRACE_SECS reserves a slot on a
+//   few relays listed by the DNS Seeds; its circuit addresses then go out
+//   with its heartbeats, so other nodes reach it through the relays
+
+use libp2p::{core::transport::ListenerId, multiaddr::Protocol, relay, Multiaddr, PeerId};
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::time::Duration;
+
+/// Relays a node listens through at most
+pub const MAX_RELAYS: usize = 2;
+
+/// Time without inbound connection before a node turns to relays
+pub const RELAY_GRACE_SECS: u64 = 300;
+
+/// Reservations a relay holds at most
+pub const RELAY_MAX_RESERVATIONS: usize = 128;
+
+/// Circuits a relay carries at most (each one is a full peer connection)
+pub const RELAY_MAX_CIRCUITS: usize = 64;
+
+/// Circuits a relay carries at most per relayed node
+pub const RELAY_MAX_CIRCUITS_PER_PEER: usize = 8;
+
+/// Lifetime of a circuit: peers redial through the relay when it expires
+pub const RELAY_CIRCUIT_DURATION_SECS: u64 = 30 * 60;
+
+/// Bytes a circuit carries at most, each way (blocks and gossip, not a
+/// full sync: a relayed node syncs from the peers it dials itself)
+pub const RELAY_CIRCUIT_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Relay server configuration (--relay)
+///
+/// The libp2p defaults (2 minutes, 128 KiB per circuit) suit hole punching,
+/// not a node that stays behind the relay.
+pub fn server_config() -> relay::Config {
+    relay::Config {
+        max_reservations: RELAY_MAX_RESERVATIONS,
+        max_circuits: RELAY_MAX_CIRCUITS,
+        max_circuits_per_peer: RELAY_MAX_CIRCUITS_PER_PEER,
+        max_circuit_duration: Duration::from_secs(RELAY_CIRCUIT_DURATION_SECS),
+        max_circuit_bytes: RELAY_CIRCUIT_BYTES,
+        ..Default::default()
+    }
+}
+
+/// Address to listen on through a relay
+pub fn circuit_address(relay: PeerId, relay_addr: &Multiaddr) -> Multiaddr {
+    let mut addr: Multiaddr = relay_addr
+        .iter()
+        .filter(|p| !matches!(p, Protocol::P2p(_) | Protocol::P2pCircuit))
+        .collect();
+    addr.push(Protocol::P2p(relay));
+    addr.push(Protocol::P2pCircuit);
+    addr
+}
+
+/// Whether an address goes through a relay
+pub fn is_relayed(addr: &Multiaddr) -> bool {
+    addr.iter().any(|p| matches!(p, Protocol::P2pCircuit))
+}
+
+/// External address of a relay, from the address a peer sees it at
+///
+/// The observed port is the one of an outbound connection, so the listen
+/// port replaces it. Addresses peers can't reach from outside are skipped.
+pub fn external_candidate(observed: &Multiaddr, listen_port: u16) -> Option<Multiaddr> {
+    let ip = observed.iter().find_map(|p| match p {
+        Protocol::Ip4(ip) => Some(IpAddr::V4(ip)),
+        Protocol::Ip6(ip) => Some(IpAddr::V6(ip)),
+        _ => None,
+    })?;
+    let routable = match ip {
+        IpAddr::V4(ip) => !(ip.is_private() || ip.is_loopback() || ip.is_link_local() || ip.is_unspecified()),
+        IpAddr::V6(ip) => !(ip.is_loopback() || ip.is_unspecified()),
+    };
+    routable.then(|| Multiaddr::empty().with(ip.into()).with(Protocol::Tcp(listen_port)))
+}
+
+/// Relays a node listens through
+#[derive(Debug, Default)]
+pub struct RelayListeners {
+    /// Relay of each circuit listener
+    listeners: HashMap<ListenerId, PeerId>,
+    /// Relays that accepted the reservation
+    reserved: HashSet<PeerId>,
+}
+
+impl RelayListeners {
+    /// Number of relays listened through (reservation accepted or pending)
+    pub fn len(&self) -> usize {
+        self.listeners.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.listeners.is_empty()
+    }
+
+    /// Whether a listener goes through `relay`
+    pub fn uses(&self, relay: &PeerId) -> bool {
+        self.listeners.values().any(|r| r == relay)
+    }
+
+    /// Relays among `candidates` to listen through, one address each
+    pub fn pick(&self, local: PeerId, candidates: Vec<(PeerId, Multiaddr)>) -> Vec<(PeerId, Multiaddr)> {
+        let mut picked: Vec<(PeerId, Multiaddr)> = Vec::new();
+        for (relay, addr) in candidates {
+            if picked.len() + self.len() >= MAX_RELAYS {
+                break;
+            }
+            if relay == local || is_relayed(&addr) || self.uses(&relay) || picked.iter().any(|(r, _)| *r == relay) {
+                continue;
+            }
+            picked.push((relay, addr));
+        }
+        picked
+    }
+
+    pub fn listening(&mut self, listener: ListenerId, relay: PeerId) {
+        self.listeners.insert(listener, relay);
+    }
+
+    pub fn reserved(&mut self, relay: PeerId) {
+        self.reserved.insert(relay);
+    }
+
+    /// Forget a closed listener (reservation refused, relay gone), returning
+    /// its relay
+    pub fn closed(&mut self, listener: ListenerId) -> Option<PeerId> {
+        let relay = self.listeners.remove(&listener)?;
+        if !self.uses(&relay) {
+            self.reserved.remove(&relay);
+        }
+        Some(relay)
+    }
+
+    /// Relays holding a reservation for the node
+    pub fn reserved_relays(&self) -> impl Iterator<Item = &PeerId> {
+        self.reserved.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relay_addresses() {
+        let relay = PeerId::random();
+        let addr: Multiaddr = format!("/ip4/203.0.113.7/tcp/30333/p2p/{}", relay).parse().unwrap();
+        let circuit = circuit_address(relay, &addr);
+        assert_eq!(circuit.to_string(), format!("/ip4/203.0.113.7/tcp/30333/p2p/{}/p2p-circuit", relay));
+        assert!(is_relayed(&circuit) && !is_relayed(&addr));
+
+        let observed: Multiaddr = "/ip4/203.0.113.7/tcp/51234".parse().unwrap();
+        assert_eq!(
+            external_candidate(&observed, 30333),
+            Some("/ip4/203.0.113.7/tcp/30333".parse().unwrap())
+        );
+        assert_eq!(external_candidate(&"/ip4/192.168.1.5/tcp/51234".parse().unwrap(), 30333), None);
+        assert_eq!(external_candidate(&"/ip4/127.0.0.1/tcp/51234".parse().unwrap(), 30333), None);
+    }
+
+    #[test]
+    fn test_pick_relays() {
+        let local = PeerId::random();
+        let (a, b, c) = (PeerId::random(), PeerId::random(), PeerId::random());
+        let addr: Multiaddr = "/ip4/203.0.113.7/tcp/30333".parse().unwrap();
+        let circuit = circuit_address(c, &addr);
+        let candidates = vec![
+            (local, addr.clone()),
+            (a, addr.clone()),
+            (a, addr.clone()),
+            (b, circuit),
+            (c, addr.clone()),
+            (b, addr.clone()),
+        ];
+
+        let mut listeners = RelayListeners::default();
+        let picked: Vec<_> = listeners.pick(local, candidates.clone()).into_iter().map(|(r, _)| r).collect();
+        assert_eq!(picked, vec![a, c]);
+
+        // A relay already listened through is skipped, and the total stays bounded
+        let listener = ListenerId::next();
+        listeners.listening(listener, a);
+        listeners.reserved(a);
+        let picked: Vec<_> = listeners.pick(local, candidates).into_iter().map(|(r, _)| r).collect();
+        assert_eq!(picked, vec![c]);
+
+        assert_eq!(listeners.closed(listener), Some(a));
+        assert!(listeners.is_empty() && listeners.reserved_relays().next().is_none());
+    }
+}
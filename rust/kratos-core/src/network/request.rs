@@ -1,7 +1,9 @@
 // Request-Response Protocol - Direct peer-to-peer message exchange
 // Principle: Request specific data from specific peers with timeout handling
 
-use crate::types::{AccountId, Balance, Block, BlockNumber, Hash};
+use super::handshake::Handshake;
+use super::warp_sync::{SnapshotManifest, StateChunk};
+use crate::types::{AccountId, Balance, Block, BlockHeader, BlockNumber, FinalityJustification, Hash, SignedTransaction};
 use futures::prelude::*;
 use libp2p::request_response::{self, Codec, ProtocolSupport};
 use libp2p::StreamProtocol;
@@ -25,6 +27,12 @@ pub const STATUS_PROTOCOL: &str = "/kratos/status/1.0.0";
 /// Protocol name for genesis requests (used by joining nodes)
 pub const GENESIS_PROTOCOL: &str = "/kratos/genesis/1.0.0";
 
+/// Protocol name for finality justification requests
+pub const JUSTIFICATION_PROTOCOL: &str = "/kratos/justification/1.0.0";
+
+/// Protocol name for warp sync snapshot requests
+pub const WARP_SYNC_PROTOCOL: &str = "/kratos/warp-sync/1.0.0";
+
 /// Request timeout
 pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
 
@@ -184,6 +192,119 @@ pub struct GenesisValidatorInfo {
     pub is_bootstrap_validator: bool,
 }
 
+// =============================================================================
+// JUSTIFICATION REQUEST/RESPONSE
+// Lets syncing nodes check finality without replaying the votes
+// =============================================================================
+
+/// Request for a finality justification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum JustificationRequest {
+    /// Justification proving the finality of a block
+    /// (its own, or that of the first justified block above it)
+    Covering(BlockNumber),
+
+    /// Justification of the highest finalized block
+    Latest,
+}
+
+/// Response for justification requests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum JustificationResponse {
+    /// Justification found
+    Justification(FinalityJustification),
+
+    /// No justification for the requested block
+    NotFound,
+}
+
+// =============================================================================
+// WARP SYNC REQUEST/RESPONSE
+// Lets new nodes download a finalized state snapshot instead of every block
+// =============================================================================
+
+/// Request for warp sync snapshot data
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum WarpSyncRequest {
+    /// Manifest of the latest finalized snapshot
+    Manifest,
+
+    /// One chunk of the snapshot taken at a block
+    Chunk { block_number: BlockNumber, index: u32 },
+}
+
+/// Response for warp sync requests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum WarpSyncResponse {
+    /// Snapshot manifest (boxed: it carries a full block)
+    Manifest(Box<SnapshotManifest>),
+
+    /// Snapshot chunk
+    Chunk { block_number: BlockNumber, chunk: StateChunk },
+
+    /// This peer does not serve the requested snapshot
+    NotAvailable,
+}
+
+// =============================================================================
+// TRANSACTION RELAY REQUEST/RESPONSE
+// Gossip v2: transactions are announced by hash, peers pull the bodies they lack
+// =============================================================================
+
+/// Transaction relay request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TransactionRequest {
+    /// Hashes of transactions the sender has (MAX_TX_ANNOUNCE at most)
+    Announce(Vec<Hash>),
+
+    /// Transactions the sender wants, among those announced to it
+    Pull(Vec<Hash>),
+}
+
+/// Response for transaction relay requests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TransactionResponse {
+    /// Announcement received
+    Ack,
+
+    /// Pulled transactions (those no longer held are left out)
+    Transactions(Vec<SignedTransaction>),
+}
+
+// =============================================================================
+// BLOCK ANNOUNCEMENT REQUEST/RESPONSE
+// Gossip v3: blocks are announced by header, peers fetch the body
+// (BlockRequest::ByHash) once the header checks out
+// =============================================================================
+
+/// Announcement of a new block
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockAnnouncement {
+    /// Block hash (the hash of the header)
+    pub hash: Hash,
+
+    /// Block header, checked before the body is fetched
+    pub header: BlockHeader,
+}
+
+impl BlockAnnouncement {
+    pub fn new(header: BlockHeader) -> Self {
+        Self { hash: header.hash(), header }
+    }
+
+    /// Whether the announced hash is the header's
+    pub fn is_consistent(&self) -> bool {
+        self.header.hash() == self.hash
+    }
+}
+
+/// Response for block announcements
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BlockAnnounceResponse {
+    /// Announcement received
+    Ack,
+}
+
 // =============================================================================
 // UNIFIED REQUEST/RESPONSE
 // =============================================================================
@@ -196,6 +317,33 @@ pub enum KratosRequest {
     Status(StatusRequest),
     /// Genesis request - used by joining nodes to get genesis info
     Genesis(GenesisRequest),
+    /// Finality justification request
+    Justification(JustificationRequest),
+    /// Warp sync snapshot request
+    WarpSync(WarpSyncRequest),
+    /// Protocol versions, sent on connection (last: older peers fail to decode it)
+    Handshake(Handshake),
+    /// Transaction announcement or pull (gossip v2 peers only)
+    Transactions(TransactionRequest),
+    /// New block announcement (gossip v3 peers only)
+    BlockAnnounce(BlockAnnouncement),
+}
+
+impl KratosRequest {
+    /// Short name, for logs and traces
+    pub fn kind(&self) -> &'static str {
+        match self {
+            KratosRequest::Block(_) => "block",
+            KratosRequest::Sync(_) => "sync",
+            KratosRequest::Status(_) => "status",
+            KratosRequest::Genesis(_) => "genesis",
+            KratosRequest::Justification(_) => "justification",
+            KratosRequest::WarpSync(_) => "warp_sync",
+            KratosRequest::Handshake(_) => "handshake",
+            KratosRequest::Transactions(_) => "transactions",
+            KratosRequest::BlockAnnounce(_) => "block_announce",
+        }
+    }
 }
 
 /// All response types
@@ -206,6 +354,16 @@ pub enum KratosResponse {
     Status(StatusResponse),
     /// Genesis response - sent to joining nodes
     Genesis(GenesisResponse),
+    /// Finality justification response
+    Justification(JustificationResponse),
+    /// Warp sync snapshot response
+    WarpSync(WarpSyncResponse),
+    /// Protocol versions of the responder
+    Handshake(Handshake),
+    /// Transaction relay response
+    Transactions(TransactionResponse),
+    /// Block announcement response
+    BlockAnnounce(BlockAnnounceResponse),
 }
 
 // =============================================================================
@@ -365,6 +523,8 @@ pub fn get_protocols() -> Vec<(StreamProtocol, ProtocolSupport)> {
         (StreamProtocol::new(SYNC_PROTOCOL), ProtocolSupport::Full),
         (StreamProtocol::new(STATUS_PROTOCOL), ProtocolSupport::Full),
         (StreamProtocol::new(GENESIS_PROTOCOL), ProtocolSupport::Full),
+        (StreamProtocol::new(JUSTIFICATION_PROTOCOL), ProtocolSupport::Full),
+        (StreamProtocol::new(WARP_SYNC_PROTOCOL), ProtocolSupport::Full),
     ]
 }
 
@@ -504,6 +664,70 @@ impl GenesisResponse {
     }
 }
 
+impl JustificationRequest {
+    /// Create a request for the justification covering a block
+    pub fn covering(block_number: BlockNumber) -> KratosRequest {
+        KratosRequest::Justification(JustificationRequest::Covering(block_number))
+    }
+
+    /// Create a request for the latest justification
+    pub fn latest() -> KratosRequest {
+        KratosRequest::Justification(JustificationRequest::Latest)
+    }
+}
+
+impl JustificationResponse {
+    /// Create a response from an optional justification
+    pub fn from_option(justification: Option<FinalityJustification>) -> KratosResponse {
+        KratosResponse::Justification(match justification {
+            Some(j) => JustificationResponse::Justification(j),
+            None => JustificationResponse::NotFound,
+        })
+    }
+}
+
+impl WarpSyncRequest {
+    /// Create a request for the latest snapshot manifest
+    pub fn manifest() -> KratosRequest {
+        KratosRequest::WarpSync(WarpSyncRequest::Manifest)
+    }
+
+    /// Create a request for a snapshot chunk
+    pub fn chunk(block_number: BlockNumber, index: u32) -> KratosRequest {
+        KratosRequest::WarpSync(WarpSyncRequest::Chunk { block_number, index })
+    }
+}
+
+impl WarpSyncResponse {
+    /// Create a response from an optional manifest
+    pub fn manifest(manifest: Option<SnapshotManifest>) -> KratosResponse {
+        KratosResponse::WarpSync(match manifest {
+            Some(m) => WarpSyncResponse::Manifest(Box::new(m)),
+            None => WarpSyncResponse::NotAvailable,
+        })
+    }
+
+    /// Create a response from an optional chunk
+    pub fn chunk(block_number: BlockNumber, chunk: Option<StateChunk>) -> KratosResponse {
+        KratosResponse::WarpSync(match chunk {
+            Some(chunk) => WarpSyncResponse::Chunk { block_number, chunk },
+            None => WarpSyncResponse::NotAvailable,
+        })
+    }
+}
+
+impl TransactionRequest {
+    /// Create an announcement of transaction hashes
+    pub fn announce(hashes: Vec<Hash>) -> KratosRequest {
+        KratosRequest::Transactions(TransactionRequest::Announce(hashes))
+    }
+
+    /// Create a pull of announced transactions
+    pub fn pull(hashes: Vec<Hash>) -> KratosRequest {
+        KratosRequest::Transactions(TransactionRequest::Pull(hashes))
+    }
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -589,4 +813,103 @@ mod tests {
             _ => panic!("Wrong response type"),
         }
     }
+
+    #[test]
+    fn test_justification_serialization() {
+        let request = JustificationRequest::covering(42);
+        let serialized = bincode::serialize(&request).unwrap();
+        match bincode::deserialize::<KratosRequest>(&serialized).unwrap() {
+            KratosRequest::Justification(JustificationRequest::Covering(n)) => assert_eq!(n, 42),
+            _ => panic!("Wrong request type"),
+        }
+
+        let response = JustificationResponse::from_option(Some(FinalityJustification {
+            block_number: 42,
+            block_hash: Hash::hash(b"block"),
+            signatures: vec![],
+            epoch: 1,
+            round: 3,
+        }));
+        let serialized = bincode::serialize(&response).unwrap();
+        match bincode::deserialize::<KratosResponse>(&serialized).unwrap() {
+            KratosResponse::Justification(JustificationResponse::Justification(j)) => {
+                assert_eq!(j.block_number, 42);
+                assert_eq!(j.round, 3);
+            }
+            _ => panic!("Wrong response type"),
+        }
+    }
+
+    #[test]
+    fn test_warp_sync_serialization() {
+        use crate::network::warp_sync::StateSnapshot;
+        use crate::types::{AccountInfo, ChainId, StateRoot};
+
+        let request = WarpSyncRequest::chunk(1000, 3);
+        let serialized = bincode::serialize(&request).unwrap();
+        match bincode::deserialize::<KratosRequest>(&serialized).unwrap() {
+            KratosRequest::WarpSync(WarpSyncRequest::Chunk { block_number, index }) => {
+                assert_eq!(block_number, 1000);
+                assert_eq!(index, 3);
+            }
+            _ => panic!("Wrong request type"),
+        }
+
+        let accounts = vec![(AccountId::from_bytes([1; 32]), AccountInfo::new())];
+        let snapshot = StateSnapshot::new(1000, Hash::hash(b"block"), StateRoot::zero(ChainId(0)), accounts, 256);
+        let response = WarpSyncResponse::chunk(1000, snapshot.chunks.first().cloned());
+        let serialized = bincode::serialize(&response).unwrap();
+        match bincode::deserialize::<KratosResponse>(&serialized).unwrap() {
+            KratosResponse::WarpSync(WarpSyncResponse::Chunk { block_number, chunk }) => {
+                assert_eq!(block_number, 1000);
+                assert!(chunk.verify_merkle_proof(&snapshot.header.state_root.root, 1));
+            }
+            _ => panic!("Wrong response type"),
+        }
+
+        assert!(matches!(
+            WarpSyncResponse::manifest(None),
+            KratosResponse::WarpSync(WarpSyncResponse::NotAvailable)
+        ));
+    }
+
+    #[test]
+    fn test_transaction_relay_serialization() {
+        let hashes = vec![Hash::hash(b"tx1"), Hash::hash(b"tx2")];
+        let request = TransactionRequest::pull(hashes.clone());
+        assert_eq!(request.kind(), "transactions");
+        let serialized = bincode::serialize(&request).unwrap();
+        match bincode::deserialize::<KratosRequest>(&serialized).unwrap() {
+            KratosRequest::Transactions(TransactionRequest::Pull(h)) => assert_eq!(h, hashes),
+            _ => panic!("Wrong request type"),
+        }
+
+        let serialized = bincode::serialize(&KratosResponse::Transactions(TransactionResponse::Ack)).unwrap();
+        assert!(matches!(
+            bincode::deserialize::<KratosResponse>(&serialized).unwrap(),
+            KratosResponse::Transactions(TransactionResponse::Ack)
+        ));
+    }
+
+    #[test]
+    fn test_block_announcement_serialization() {
+        let mut header = Block::genesis(Hash::hash(b"state"), vec![]).header;
+        header.number = 7;
+        let announcement = BlockAnnouncement::new(header);
+        assert!(announcement.is_consistent());
+
+        let serialized = bincode::serialize(&KratosRequest::BlockAnnounce(announcement.clone())).unwrap();
+        match bincode::deserialize::<KratosRequest>(&serialized).unwrap() {
+            KratosRequest::BlockAnnounce(a) => {
+                assert_eq!(a.hash, announcement.hash);
+                assert_eq!(a.header.number, 7);
+            }
+            _ => panic!("Wrong request type"),
+        }
+
+        // A hash that isn't the header's is caught before anything is fetched
+        let mut forged = announcement;
+        forged.header.number = 8;
+        assert!(!forged.is_consistent());
+    }
 }
@@ -0,0 +1,76 @@
+// Sentry - Validators hidden behind sentry nodes
+// Principle: Nobody can flood a validator whose address nobody knows
+//
+// - The validator runs with --reserved-only and its sentries as reserved
+//   peers: it dials nobody else, accepts nobody else, sends no heartbeat and
+//   keeps Kademlia in client mode, so no routing table ever holds it
+// - Each sentry runs with --sentry-for <validator>: the validator is a
+//   reserved peer, and a private one. Its addresses never enter Kademlia nor
+//   the known-peers set, so no lookup, export or restart reveals them
+// - Sentries are ordinary public nodes: they carry the validator's gossip
+//   and advertise themselves in heartbeats, private interface addresses
+//   (the links to the validator) left out
+
+use libp2p::{multiaddr::Protocol, Multiaddr};
+use std::net::IpAddr;
+
+/// Whether other nodes could reach `addr` from the Internet (relayed and
+/// DNS addresses are taken as public)
+pub fn is_public_address(addr: &Multiaddr) -> bool {
+    let ip = addr.iter().find_map(|p| match p {
+        Protocol::Ip4(ip) => Some(IpAddr::V4(ip)),
+        Protocol::Ip6(ip) => Some(IpAddr::V6(ip)),
+        _ => None,
+    });
+    match ip {
+        Some(IpAddr::V4(ip)) => {
+            // Carrier-grade NAT (100.64.0.0/10) is shared, not public
+            let [a, b, ..] = ip.octets();
+            let shared = a == 100 && b & 0xc0 == 64;
+            !(ip.is_private() || ip.is_loopback() || ip.is_link_local() || ip.is_unspecified() || shared)
+        }
+        // Unique local (fc00::/7) and link-local (fe80::/10) addresses
+        Some(IpAddr::V6(ip)) => {
+            let segment = ip.segments()[0];
+            !(ip.is_loopback() || ip.is_unspecified() || segment & 0xfe00 == 0xfc00 || segment & 0xffc0 == 0xfe80)
+        }
+        None => true,
+    }
+}
+
+/// Addresses a node advertises in its heartbeats: a sentry keeps its
+/// private interfaces to itself
+pub fn advertised_addresses(addresses: Vec<Multiaddr>, sentry: bool) -> Vec<Multiaddr> {
+    if !sentry {
+        return addresses;
+    }
+    addresses.into_iter().filter(is_public_address).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sentry_advertised_addresses() {
+        let addresses: Vec<Multiaddr> = [
+            "/ip4/203.0.113.7/tcp/30333",
+            "/ip4/10.8.0.1/tcp/30333",
+            "/ip4/127.0.0.1/tcp/30333",
+            "/ip4/100.64.0.3/tcp/30333",
+            "/ip6/fd00::1/tcp/30333",
+            "/ip6/2001:db8::1/tcp/30333",
+            "/dns4/sentry.example.org/tcp/30333",
+        ]
+        .iter()
+        .map(|a| a.parse().unwrap())
+        .collect();
+
+        assert_eq!(advertised_addresses(addresses.clone(), false), addresses);
+        let public: Vec<String> = advertised_addresses(addresses, true).iter().map(|a| a.to_string()).collect();
+        assert_eq!(
+            public,
+            vec!["/ip4/203.0.113.7/tcp/30333", "/ip6/2001:db8::1/tcp/30333", "/dns4/sentry.example.org/tcp/30333"]
+        );
+    }
+}
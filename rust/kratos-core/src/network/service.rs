@@ -2,27 +2,39 @@
 // Principle: Orchestrate all network protocols, emit events for application
 
 use super::{
-    behaviour::KratOsBehaviour,
-    peer::PeerManager,
-    protocol::{GossipTopic, NetworkMessage},
+    behaviour::{self, KratOsBehaviour, KAD_MAX_DIALS_PER_WALK, KAD_WALK_INTERVAL_SECS},
+    handshake::{Handshake, NegotiatedVersions},
+    peer::{PeerBan, PeerInfo, PeerManager, PeerReputation, PeerScoring, DEFAULT_BAN_SECS},
+    protocol::{
+        AnnouncementOutcome, BlockRelay, GossipTopic, NetworkMessage, TxRelay, TxRelayStats, BLOCK_ANNOUNCE_VERSION,
+        TX_ANNOUNCE_VERSION,
+    },
     rate_limit::{NetworkRateLimiter, RateLimitConfig},
+    relay::{self as circuit, RelayListeners, MAX_RELAYS, RELAY_GRACE_SECS},
     request::{
         BlockRequest, BlockResponse, KratosRequest, KratosResponse,
         StatusRequest, StatusResponse, SyncRequest, SyncResponse,
-        GenesisRequest, GenesisResponse,
+        GenesisRequest, GenesisResponse, JustificationRequest, JustificationResponse,
+        WarpSyncRequest, WarpSyncResponse, TransactionRequest, TransactionResponse,
+        BlockAnnouncement, BlockAnnounceResponse,
     },
-    sync::SyncManager,
+    sync::{AncestorProbe, ForkDownloaded, ForkHead, SyncManager},
+    warp_sync::{SnapshotManifest, StateChunk},
 };
-use crate::types::{Block, BlockNumber, Hash, SignedTransaction};
+use crate::types::{AccountId, Block, BlockHeader, BlockNumber, EpochNumber, FinalityJustification, Hash, SignedTransaction, SUPPORTED_PROTOCOL_VERSION};
 use futures::StreamExt;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use libp2p::{
     gossipsub::Event as GossipsubEvent,
+    identify::Event as IdentifyEvent,
     identity::Keypair,
-    kad::Event as KadEvent,
+    kad::{self, Event as KadEvent},
+    mdns::Event as MdnsEvent,
+    multiaddr::Protocol,
+    relay::{client::Event as RelayClientEvent, Event as RelayEvent},
     request_response::{self, Event as ReqResEvent, Message as ReqResMessage},
-    swarm::SwarmEvent,
+    swarm::{dial_opts::DialOpts, SwarmEvent},
     Multiaddr, PeerId, Swarm, SwarmBuilder,
 };
 use std::collections::HashMap;
@@ -45,6 +57,19 @@ pub trait BlockProvider: Send + Sync {
 
     /// Get a specific block by number
     fn get_block_by_number(&self, number: BlockNumber) -> Option<Block>;
+
+    /// Get a stored finality justification
+    fn get_justification(&self, request: &JustificationRequest) -> Option<FinalityJustification>;
+
+    /// Get the manifest of the latest finalized state snapshot
+    fn get_warp_manifest(&self) -> Option<SnapshotManifest>;
+
+    /// Get a chunk of a stored state snapshot
+    fn get_warp_chunk(&self, block_number: BlockNumber, index: u32) -> Option<StateChunk>;
+
+    /// Key the headers of `author` at `epoch` are signed with, None when the
+    /// local state can't tell yet (a session key rotation not imported may apply)
+    fn block_signer(&self, author: &AccountId, epoch: EpochNumber) -> Option<AccountId>;
 }
 
 /// Type alias for the block provider
@@ -63,6 +88,13 @@ pub enum NetworkEvent {
         from: PeerId,
     },
 
+    /// New block announced by a peer (gossip v3): its header is to be
+    /// checked before the body is fetched (fetch_announced_block)
+    BlockAnnounced {
+        header: BlockHeader,
+        from: PeerId,
+    },
+
     /// New transaction received via gossip
     TransactionReceived {
         transaction: SignedTransaction,
@@ -118,6 +150,43 @@ pub enum NetworkEvent {
         justification_data: Vec<u8>,
         from: PeerId,
     },
+
+    /// Finality justification received via request (not verified yet)
+    JustificationReceived {
+        justification: FinalityJustification,
+        from: PeerId,
+    },
+
+    /// Warp sync snapshot manifest received (not verified yet)
+    WarpManifestReceived {
+        manifest: Box<SnapshotManifest>,
+        from: PeerId,
+    },
+
+    /// Warp sync snapshot chunk received (not verified yet)
+    WarpChunkReceived {
+        block_number: BlockNumber,
+        chunk: StateChunk,
+        from: PeerId,
+    },
+
+    /// Warp sync request refused or failed (`chunk` is None for manifests)
+    WarpSyncUnavailable {
+        chunk: Option<u32>,
+        from: PeerId,
+    },
+
+    /// Equivocation evidence received via gossip (not verified yet)
+    EvidenceReceived {
+        evidence_data: Vec<u8>,
+        from: PeerId,
+    },
+
+    /// Competing chain downloaded up to a justified block past the common
+    /// ancestor (justification not verified yet): the node decides on a reorg
+    ForkReceived {
+        fork: ForkDownloaded,
+    },
 }
 
 // =============================================================================
@@ -130,6 +199,26 @@ struct PendingRequest {
     request_type: RequestType,
     #[allow(dead_code)]
     sent_at: std::time::Instant,
+    /// Tracing span from send to response or failure (closed on drop)
+    span: tracing::Span,
+}
+
+impl PendingRequest {
+    fn new(peer: PeerId, request_type: RequestType) -> Self {
+        let span = tracing::info_span!(
+            parent: None,
+            "network_request",
+            peer = %peer,
+            request = ?request_type,
+            outcome = tracing::field::Empty
+        );
+        Self {
+            peer,
+            request_type,
+            sent_at: std::time::Instant::now(),
+            span,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -137,7 +226,18 @@ enum RequestType {
     Block(Hash),
     Sync { from: BlockNumber, max: u32 },
     Status,
+    Handshake,
     Genesis,
+    Justification,
+    WarpManifest,
+    WarpChunk { index: u32 },
+    TxAnnounce,
+    TxPull,
+    BlockAnnounce,
+    AnnouncedBlock(Hash),
+    ForkProbe(BlockNumber),
+    ForkJustification(Hash),
+    ForkSync { from: BlockNumber },
 }
 
 // =============================================================================
@@ -191,11 +291,31 @@ pub struct NetworkService {
     /// Block provider for serving sync requests (optional)
     block_provider: Option<SharedBlockProvider>,
 
-    /// Last sync request time (to prevent request storms)
-    last_sync_request: std::time::Instant,
+    /// Block sync suspended (while warp sync downloads a snapshot)
+    sync_paused: bool,
+
+    /// Serving as a circuit relay (--relay)
+    serve_relay: bool,
+
+    /// Relays we listen through
+    relays: RelayListeners,
+
+    /// A peer dialed us since startup (we are reachable)
+    inbound_seen: bool,
+
+    started_at: std::time::Instant,
+
+    /// Kademlia protocol of the chain (peers advertising it join the DHT)
+    kad_protocol: libp2p::StreamProtocol,
+
+    /// Last Kademlia random walk
+    last_kad_walk: std::time::Instant,
 
-    /// Number of pending sync requests (to limit concurrent requests)
-    pending_sync_requests: u32,
+    /// Transaction announcements and pulls (gossip v2 peers)
+    tx_relay: TxRelay,
+
+    /// Block announcements and body fetches (gossip v3 peers)
+    block_relay: BlockRelay,
 }
 
 // =============================================================================
@@ -254,7 +374,7 @@ impl NetworkService {
     pub async fn new(
         listen_addr: &str,
     ) -> Result<(Self, mpsc::UnboundedReceiver<NetworkEvent>), Box<dyn Error>> {
-        Self::with_genesis_and_datadir(listen_addr, Hash::ZERO, None).await
+        Self::with_genesis_and_datadir(listen_addr, Hash::ZERO, None, false, false).await
     }
 
     /// Create a new network service with genesis hash
@@ -262,14 +382,18 @@ impl NetworkService {
         listen_addr: &str,
         genesis_hash: Hash,
     ) -> Result<(Self, mpsc::UnboundedReceiver<NetworkEvent>), Box<dyn Error>> {
-        Self::with_genesis_and_datadir(listen_addr, genesis_hash, None).await
+        Self::with_genesis_and_datadir(listen_addr, genesis_hash, None, false, false).await
     }
 
     /// Create a new network service with genesis hash and data directory for persistent identity
+    /// (`serve_relay`: relay circuits for unreachable nodes, `mdns`: discover
+    /// peers on the local network)
     pub async fn with_genesis_and_datadir(
         listen_addr: &str,
         genesis_hash: Hash,
         data_dir: Option<PathBuf>,
+        serve_relay: bool,
+        mdns: bool,
     ) -> Result<(Self, mpsc::UnboundedReceiver<NetworkEvent>), Box<dyn Error>> {
         // Load or generate keypair for node identity (persistent if data_dir is provided)
         let local_key = load_or_generate_keypair(data_dir.as_ref())?;
@@ -277,10 +401,9 @@ impl NetworkService {
 
         info!("Local peer id: {}", local_peer_id);
 
-        // Create behaviour
-        let behaviour = KratOsBehaviour::new(local_peer_id)?;
-
-        // Create swarm
+        // Create swarm: TCP and QUIC transports (QUIC brings its own TLS and
+        // stream multiplexing), the relay client transport dials and listens
+        // through relays
         let swarm = SwarmBuilder::with_existing_identity(local_key)
             .with_tokio()
             .with_tcp(
@@ -288,7 +411,12 @@ impl NetworkService {
                 libp2p::noise::Config::new,
                 libp2p::yamux::Config::default,
             )?
-            .with_behaviour(|_| behaviour)?
+            .with_quic()
+            .with_relay_client(libp2p::noise::Config::new, libp2p::yamux::Config::default)?
+            .with_behaviour(|key, relay_client| {
+                KratOsBehaviour::new(key.public(), relay_client, serve_relay, genesis_hash, mdns)
+                    .map_err(|e| Box::<dyn Error + Send + Sync>::from(e.to_string()))
+            })?
             .with_swarm_config(|c| c.with_idle_connection_timeout(std::time::Duration::from_secs(60)))
             .build();
 
@@ -316,8 +444,15 @@ impl NetworkService {
             local_height: 0,
             local_hash: Hash::ZERO,
             block_provider: None,
-            last_sync_request: std::time::Instant::now(),
-            pending_sync_requests: 0,
+            sync_paused: false,
+            serve_relay,
+            relays: RelayListeners::default(),
+            inbound_seen: false,
+            started_at: std::time::Instant::now(),
+            kad_protocol: behaviour::kad_protocol(&genesis_hash),
+            last_kad_walk: std::time::Instant::now(),
+            tx_relay: TxRelay::default(),
+            block_relay: BlockRelay::default(),
         };
 
         // Start listening
@@ -334,6 +469,66 @@ impl NetworkService {
         self.peer_manager.add_bootstrap_nodes(nodes);
     }
 
+    /// Set the reserved peers (--reserved-peer) and dial them: they bypass
+    /// the peer limit, are never evicted and are redialed when they drop.
+    /// With `only`, no other peer connects nor gets dialed (sentry setups)
+    pub fn set_reserved_peers(&mut self, peers: Vec<(PeerId, Multiaddr)>, only: bool) {
+        self.peer_manager.set_reserved(peers.iter().map(|(peer_id, _)| *peer_id), only);
+        if only {
+            // Out of every routing table: only the reserved peers know the node
+            self.swarm.behaviour_mut().kad_client_mode();
+        }
+        for (peer_id, addr) in peers {
+            self.swarm.behaviour_mut().add_address(peer_id, addr.clone());
+            self.peer_manager.add_peer_address(peer_id, addr.clone());
+            if let Err(e) = self.swarm.dial(DialOpts::peer_id(peer_id).addresses(vec![addr]).build()) {
+                warn!("Failed to dial reserved peer {}: {}", peer_id, e);
+            }
+        }
+    }
+
+    /// Keep the addresses of validators shielded by this sentry private
+    /// (--sentry-for): out of Kademlia and the known-peers set
+    pub fn set_private_peers(&mut self, peers: Vec<PeerId>) {
+        for peer_id in &peers {
+            self.swarm.behaviour_mut().remove_kad_peer(peer_id);
+        }
+        self.peer_manager.set_private(peers);
+    }
+
+    /// Whether this node shields validators (--sentry-for)
+    pub fn is_sentry(&self) -> bool {
+        self.peer_manager.has_private_peers()
+    }
+
+    /// Dial peers known from a previous run (not bootstrap nodes: no
+    /// reconnection nor peer limit exemption; none in reserved-only mode)
+    pub fn add_known_peers(&mut self, peers: Vec<(PeerId, Vec<Multiaddr>)>) {
+        if self.peer_manager.reserved_only() {
+            return;
+        }
+        for (peer_id, addresses) in peers {
+            for addr in &addresses {
+                self.swarm.behaviour_mut().add_address(peer_id, addr.clone());
+                self.peer_manager.add_peer_address(peer_id, addr.clone());
+            }
+            let opts = DialOpts::peer_id(peer_id).addresses(addresses).build();
+            if let Err(e) = self.swarm.dial(opts) {
+                debug!("Failed to dial known peer {}: {}", peer_id, e);
+            }
+        }
+    }
+
+    /// Peers that can still connect before the limit
+    pub fn free_peer_slots(&self) -> usize {
+        self.peer_manager.free_slots()
+    }
+
+    /// Connected or well scored peers, with their addresses
+    pub fn known_good_peers(&self) -> Vec<(PeerId, Vec<Multiaddr>)> {
+        self.peer_manager.known_good_peers()
+    }
+
     /// Connect to a specific address
     pub fn dial(&mut self, addr: Multiaddr) -> Result<(), Box<dyn Error>> {
         self.swarm.dial(addr)?;
@@ -345,13 +540,33 @@ impl NetworkService {
         self.local_height = height;
         self.local_hash = hash;
         self.sync_manager.update_local_height(height);
+        self.block_relay.prune(height);
+    }
+
+    /// Move the local chain back to `height` after a reorg: what sync
+    /// buffered or requested for the previous chain is dropped
+    pub fn rewind_local_state(&mut self, height: BlockNumber, hash: Hash) {
+        self.local_height = height;
+        self.local_hash = hash;
+        self.sync_manager.rewind(height);
     }
 
     /// Set block provider for serving sync requests
     pub fn set_block_provider(&mut self, provider: SharedBlockProvider) {
+        self.sync_manager.set_block_provider(provider.clone());
         self.block_provider = Some(provider);
     }
 
+    /// Set the peer scoring weights
+    pub fn set_peer_scoring(&mut self, scoring: PeerScoring) {
+        self.peer_manager.set_scoring(scoring);
+    }
+
+    /// Limit the connected peers (existing connections are kept)
+    pub fn set_max_peers(&mut self, max_peers: usize) {
+        self.peer_manager.set_max_peers(max_peers);
+    }
+
     /// Set genesis info for serving to joining nodes
     pub fn set_genesis_info(&mut self, genesis_block: Block, chain_name: String) {
         self.genesis_hash = genesis_block.hash();
@@ -379,31 +594,117 @@ impl NetworkService {
         let request = GenesisRequest::new();
         let request_id = self.swarm.behaviour_mut().send_request(peer_id, request);
 
-        self.pending_requests.insert(request_id, PendingRequest {
-            peer: *peer_id,
-            request_type: RequestType::Genesis,
-            sent_at: std::time::Instant::now(),
-        });
+        self.pending_requests.insert(request_id, PendingRequest::new(*peer_id, RequestType::Genesis));
 
         info!("📥 Requesting genesis info from peer {}", peer_id);
     }
 
-    /// Broadcast a block via gossip
+    /// Broadcast a block of ours
     pub fn broadcast_block(&mut self, block: Block) -> Result<(), Box<dyn Error>> {
-        let msg = NetworkMessage::NewBlock(block);
-        let data = msg.encode()?;
-        self.swarm.behaviour_mut().publish(GossipTopic::Blocks, data)?;
+        self.relay_block(block, None)
+    }
+
+    /// Relay an imported block (`from`: the peer it came from), once
+    ///
+    /// Gossip v3 peers get its header and fetch the body once it checks out;
+    /// it goes over gossipsub only for older peers, and not again if it came
+    /// that way (gossipsub forwards it).
+    pub fn relay_block(&mut self, block: Block, from: Option<PeerId>) -> Result<(), Box<dyn Error>> {
+        let hash = block.hash();
+        self.block_relay.cache(block.clone(), false);
+        let Some(peers) = self.block_relay.announce(&hash, from) else {
+            return Ok(());
+        };
+        for peer in peers {
+            let announcement = KratosRequest::BlockAnnounce(BlockAnnouncement::new(block.header.clone()));
+            let request_id = self.swarm.behaviour_mut().send_request(&peer, announcement);
+            self.pending_requests.insert(request_id, PendingRequest::new(peer, RequestType::BlockAnnounce));
+        }
+
+        let legacy_peers = self
+            .peer_manager
+            .connected_peers()
+            .iter()
+            .any(|info| !self.block_relay.announces_to(&info.id));
+        if legacy_peers && !self.block_relay.gossiped(&hash) {
+            let data = NetworkMessage::NewBlock(block).encode()?;
+            self.swarm.behaviour_mut().publish(GossipTopic::Blocks, data)?;
+        }
         Ok(())
     }
 
-    /// Broadcast a transaction via gossip
+    /// Fetch the body of an announced block whose header checked out, from
+    /// the announcer in the best standing
+    pub fn fetch_announced_block(&mut self, hash: &Hash) {
+        let peer_manager = &self.peer_manager;
+        let rank = |peer: &PeerId| peer_manager.get_peer(peer).map_or(i32::MIN, |info| info.score);
+        match self.block_relay.fetch_from(hash, rank) {
+            Some(peer) => {
+                let request_id = self.swarm.behaviour_mut().send_request(&peer, BlockRequest::by_hash(*hash));
+                self.pending_requests.insert(request_id, PendingRequest::new(peer, RequestType::AnnouncedBlock(*hash)));
+                debug!("Fetching announced block {} from {}", hash, peer);
+            }
+            None => {
+                debug!("No announcer left to fetch block {} from", hash);
+                self.block_relay.forget(hash);
+            }
+        }
+    }
+
+    /// Drop an announced block whose header failed the checks: its
+    /// announcers lose standing, so they are asked last from now on
+    pub fn reject_announced_block(&mut self, hash: &Hash, reason: &str) {
+        for peer in self.block_relay.forget(hash) {
+            warn!("Peer {} announced invalid block {}: {}", peer, hash, reason);
+            self.peer_manager.record_bad_block(&peer);
+        }
+    }
+
+    /// Drop an announced block without blame (not needed, or not checkable yet)
+    pub fn discard_announced_block(&mut self, hash: &Hash) {
+        self.block_relay.forget(hash);
+    }
+
+    /// Broadcast a transaction of ours
     pub fn broadcast_transaction(&mut self, tx: SignedTransaction) -> Result<(), Box<dyn Error>> {
-        let msg = NetworkMessage::NewTransaction(tx);
-        let data = msg.encode()?;
-        self.swarm.behaviour_mut().publish(GossipTopic::Transactions, data)?;
+        self.relay_transaction(tx, None)
+    }
+
+    /// Relay a transaction accepted into the mempool (`from`: the peer it
+    /// came from)
+    ///
+    /// Gossip v2 peers get its hash and pull the body if they lack it; it
+    /// goes over gossipsub only for gossip v1 peers, and not again if it
+    /// came that way (gossipsub forwards it).
+    pub fn relay_transaction(&mut self, tx: SignedTransaction, from: Option<PeerId>) -> Result<(), Box<dyn Error>> {
+        let hash = tx.hash();
+        let data = NetworkMessage::NewTransaction(tx.clone()).encode()?;
+        let legacy_peers = self
+            .peer_manager
+            .connected_peers()
+            .iter()
+            .any(|info| !self.tx_relay.announces_to(&info.id));
+        if legacy_peers && !self.tx_relay.gossiped(&hash) {
+            self.swarm.behaviour_mut().publish(GossipTopic::Transactions, data.clone())?;
+        }
+        self.tx_relay.cache(hash, tx, false);
+        self.tx_relay.announce(hash, data.len() as u64, from);
         Ok(())
     }
 
+    /// Send the queued transaction announcements
+    fn flush_tx_announcements(&mut self) {
+        for (peer, hashes) in self.tx_relay.take_announcements() {
+            let request_id = self.swarm.behaviour_mut().send_request(&peer, TransactionRequest::announce(hashes));
+            self.pending_requests.insert(request_id, PendingRequest::new(peer, RequestType::TxAnnounce));
+        }
+    }
+
+    /// Transaction relay counters
+    pub fn tx_relay_stats(&self) -> TxRelayStats {
+        self.tx_relay.stats()
+    }
+
     /// Broadcast a finality message via gossip
     ///
     /// Used for GRANDPA finality votes and justifications
@@ -413,16 +714,20 @@ impl NetworkService {
         Ok(())
     }
 
+    /// Broadcast equivocation evidence via gossip
+    pub fn broadcast_evidence(&mut self, evidence_data: Vec<u8>) -> Result<(), Box<dyn Error>> {
+        let msg = NetworkMessage::Evidence { evidence_data };
+        let data = msg.encode()?;
+        self.swarm.behaviour_mut().publish(GossipTopic::Consensus, data)?;
+        Ok(())
+    }
+
     /// Request a specific block from a peer
     pub fn request_block(&mut self, peer_id: &PeerId, hash: Hash) {
         let request = KratosRequest::Block(BlockRequest::ByHash(hash));
         let request_id = self.swarm.behaviour_mut().send_request(peer_id, request);
 
-        self.pending_requests.insert(request_id, PendingRequest {
-            peer: *peer_id,
-            request_type: RequestType::Block(hash),
-            sent_at: std::time::Instant::now(),
-        });
+        self.pending_requests.insert(request_id, PendingRequest::new(*peer_id, RequestType::Block(hash)));
 
         debug!("Requested block {:?} from {}", hash, peer_id);
     }
@@ -436,15 +741,60 @@ impl NetworkService {
         });
         let request_id = self.swarm.behaviour_mut().send_request(peer_id, request);
 
-        self.pending_requests.insert(request_id, PendingRequest {
-            peer: *peer_id,
-            request_type: RequestType::Sync { from: from_block, max: max_blocks },
-            sent_at: std::time::Instant::now(),
-        });
+        self.pending_requests.insert(request_id, PendingRequest::new(*peer_id, RequestType::Sync { from: from_block, max: max_blocks }));
 
         debug!("Requested sync from {} starting at block {}", peer_id, from_block);
     }
 
+    /// Send our protocol versions to a peer
+    pub fn request_handshake(&mut self, peer_id: &PeerId) {
+        let request_id = self.swarm.behaviour_mut().send_request(peer_id, KratosRequest::Handshake(Handshake::local()));
+
+        self.pending_requests.insert(request_id, PendingRequest::new(*peer_id, RequestType::Handshake));
+
+        debug!("Sent protocol handshake to {}", peer_id);
+    }
+
+    /// Negotiate protocol versions with a peer (false: no common version, disconnected)
+    fn negotiate_protocols(&mut self, peer: &PeerId, remote: &Handshake) -> bool {
+        match Handshake::local().negotiate(remote) {
+            Ok(versions) => {
+                self.record_protocols(peer, versions);
+                true
+            }
+            Err(e) => {
+                warn!("⚠️  Incompatible peer {}: {}", peer, e);
+                let _ = self.swarm.disconnect_peer_id(*peer);
+                false
+            }
+        }
+    }
+
+    fn record_protocols(&mut self, peer: &PeerId, versions: NegotiatedVersions) {
+        for protocol in &versions.deprecated {
+            warn!(
+                "⚠️  Peer {} speaks deprecated {} protocol v{}: upgrade before support is dropped",
+                peer,
+                protocol,
+                versions.get(*protocol)
+            );
+        }
+        if let Some(version) = versions.peer_ahead() {
+            warn!(
+                "⚠️  Peer {} validates protocol version {}, this node up to {}: upgrade before governance activates it",
+                peer, version, SUPPORTED_PROTOCOL_VERSION
+            );
+        }
+        debug!("Protocols with {}: {}", peer, versions);
+        if versions.gossip >= TX_ANNOUNCE_VERSION {
+            self.tx_relay.add_peer(*peer);
+        }
+        if versions.gossip >= BLOCK_ANNOUNCE_VERSION {
+            self.block_relay.add_peer(*peer);
+        }
+        self.peer_manager.set_protocols(peer, versions);
+    }
+
     /// Request status from a peer
     pub fn request_status(&mut self, peer_id: &PeerId) {
         let request = KratosRequest::Status(StatusRequest {
@@ -455,50 +805,66 @@ impl NetworkService {
         });
         let request_id = self.swarm.behaviour_mut().send_request(peer_id, request);
 
-        self.pending_requests.insert(request_id, PendingRequest {
-            peer: *peer_id,
-            request_type: RequestType::Status,
-            sent_at: std::time::Instant::now(),
-        });
+        self.pending_requests.insert(request_id, PendingRequest::new(*peer_id, RequestType::Status));
 
         debug!("Requested status from {}", peer_id);
     }
 
-    /// Start sync if needed
-    /// Rate-limited to prevent request storms during high gossip activity
-    pub fn maybe_start_sync(&mut self) {
-        if !self.sync_manager.should_sync() {
-            return;
-        }
+    /// Request a finality justification from a peer
+    pub fn request_justification(&mut self, peer_id: &PeerId, request: JustificationRequest) {
+        debug!("Requested justification {:?} from {}", request, peer_id);
+        let request_id = self.swarm.behaviour_mut().send_request(peer_id, KratosRequest::Justification(request));
 
-        // Rate limit: max 1 sync request per 500ms, max 3 pending requests
-        const MIN_SYNC_INTERVAL_MS: u64 = 500;
-        const MAX_PENDING_SYNC_REQUESTS: u32 = 3;
+        self.pending_requests.insert(request_id, PendingRequest::new(*peer_id, RequestType::Justification));
+    }
 
-        let elapsed = self.last_sync_request.elapsed();
-        if elapsed.as_millis() < MIN_SYNC_INTERVAL_MS as u128 {
-            debug!("Sync request rate-limited ({}ms since last)", elapsed.as_millis());
-            return;
-        }
+    /// Request the latest snapshot manifest from a peer
+    pub fn request_warp_manifest(&mut self, peer_id: &PeerId) {
+        let request_id = self.swarm.behaviour_mut().send_request(peer_id, WarpSyncRequest::manifest());
 
-        if self.pending_sync_requests >= MAX_PENDING_SYNC_REQUESTS {
-            debug!("Sync request limited: {} pending requests", self.pending_sync_requests);
+        self.pending_requests.insert(request_id, PendingRequest::new(*peer_id, RequestType::WarpManifest));
+
+        debug!("Requested warp sync manifest from {}", peer_id);
+    }
+
+    /// Request a snapshot chunk from a peer
+    pub fn request_warp_chunk(&mut self, peer_id: &PeerId, block_number: BlockNumber, index: u32) {
+        let request_id = self.swarm.behaviour_mut().send_request(peer_id, WarpSyncRequest::chunk(block_number, index));
+
+        self.pending_requests.insert(request_id, PendingRequest::new(*peer_id, RequestType::WarpChunk { index }));
+
+        trace!("Requested warp sync chunk {} of #{} from {}", index, block_number, peer_id);
+    }
+
+    /// Suspend or resume block sync
+    ///
+    /// Warp sync pauses block sync so blocks aren't replayed from genesis
+    /// while the snapshot downloads.
+    pub fn set_sync_paused(&mut self, paused: bool) {
+        self.sync_paused = paused;
+    }
+
+    /// Start sync if needed
+    ///
+    /// Requests the missing range in chunks from several peers at once. The
+    /// sync manager bounds the chunks in flight, so calling this on every
+    /// gossip block or response does not cause request storms.
+    pub fn maybe_start_sync(&mut self) {
+        if self.sync_paused || !self.sync_manager.should_sync() {
             return;
         }
 
-        // Find best peer for sync
-        if let Some(peer) = self.peer_manager.best_sync_peer() {
-            let peer_id = peer.id;
-            let from_block = self.local_height + 1;
-            self.request_sync(&peer_id, from_block, 50);
-            self.last_sync_request = std::time::Instant::now();
-            self.pending_sync_requests += 1;
+        let peers = self.peer_manager.sync_peers();
+        let requests = self.sync_manager.schedule_chunks(&peers, std::time::Instant::now());
+        for request in requests {
+            self.request_sync(&request.peer, request.from_block, request.max_blocks);
         }
     }
 
     /// Run the network event loop
     pub async fn run(&mut self) -> Result<(), Box<dyn Error>> {
         let mut maintenance_interval = tokio::time::interval(std::time::Duration::from_secs(30));
+        let mut announce_interval = tokio::time::interval(std::time::Duration::from_millis(100));
 
         loop {
             tokio::select! {
@@ -512,6 +878,9 @@ impl NetworkService {
                 _ = maintenance_interval.tick() => {
                     self.perform_maintenance();
                 }
+                _ = announce_interval.tick() => {
+                    self.flush_tx_announcements();
+                }
             }
         }
 
@@ -543,7 +912,10 @@ impl NetworkService {
                     }
                 }
             }
-        }).await
+        }).await;
+
+        // Transactions relayed since the last poll are announced together
+        self.flush_tx_announcements();
     }
 
     /// Handle swarm events
@@ -553,19 +925,48 @@ impl NetworkService {
                 self.handle_behaviour_event(behaviour_event);
             }
             SwarmEvent::NewListenAddr { address, .. } => {
-                info!("Listening on {}", address);
+                if circuit::is_relayed(&address) {
+                    info!("📡 Reachable through relay at {}", address);
+                } else if behaviour::is_quic(&address) {
+                    info!("Listening on {} (QUIC)", address);
+                } else {
+                    info!("Listening on {}", address);
+                }
             }
-            SwarmEvent::ConnectionEstablished { peer_id, .. } => {
+            SwarmEvent::ListenerClosed { listener_id, reason, .. } => {
+                if let Some(relay) = self.relays.closed(listener_id) {
+                    warn!("Stopped listening through relay {}: {:?}", relay, reason);
+                }
+            }
+            SwarmEvent::ConnectionEstablished { peer_id, endpoint, .. } => {
+                if !self.peer_manager.accepts_connection(&peer_id) {
+                    debug!("Peer limit reached, refusing {}", peer_id);
+                    let _ = self.swarm.disconnect_peer_id(peer_id);
+                    return;
+                }
                 debug!("Connection established with peer: {}", peer_id);
+                if !endpoint.is_dialer() {
+                    self.inbound_seen = true;
+                }
+                // Only dialed addresses are dialable again (inbound ones are ephemeral ports)
+                if endpoint.is_dialer() {
+                    self.peer_manager.add_peer_address(peer_id, endpoint.get_remote_address().clone());
+                }
                 self.peer_manager.peer_connected(peer_id);
                 let _ = self.event_tx.send(NetworkEvent::PeerConnected(peer_id));
 
-                // Request status from new peer
+                // Negotiate protocol versions, then request status from new peer
+                if self.peer_manager.get_peer(&peer_id).is_some_and(|info| info.protocols.is_none()) {
+                    self.request_handshake(&peer_id);
+                }
                 self.request_status(&peer_id);
             }
             SwarmEvent::ConnectionClosed { peer_id, .. } => {
                 debug!("Connection closed with peer: {}", peer_id);
                 self.peer_manager.peer_disconnected(&peer_id);
+                self.sync_manager.peer_disconnected(&peer_id);
+                self.tx_relay.remove_peer(&peer_id);
+                self.block_relay.remove_peer(&peer_id);
                 let _ = self.event_tx.send(NetworkEvent::PeerDisconnected(peer_id));
             }
             SwarmEvent::OutgoingConnectionError { peer_id, error, .. } => {
@@ -607,6 +1008,65 @@ impl NetworkService {
             super::behaviour::KratOsBehaviourEvent::Kad(KadEvent::RoutingUpdated { peer, .. }) => {
                 debug!("Kademlia routing updated for {}", peer);
             }
+            super::behaviour::KratOsBehaviourEvent::Kad(KadEvent::OutboundQueryProgressed {
+                result: kad::QueryResult::GetClosestPeers(Ok(closest)),
+                ..
+            }) => {
+                self.dial_discovered(closest.peers);
+            }
+
+            // Local network discovery
+            super::behaviour::KratOsBehaviourEvent::Mdns(MdnsEvent::Discovered(peers)) => {
+                self.dial_local(peers);
+            }
+            super::behaviour::KratOsBehaviourEvent::Mdns(MdnsEvent::Expired(peers)) => {
+                for (peer_id, addr) in peers {
+                    debug!("mDNS record of {} at {} expired", peer_id, addr);
+                }
+            }
+
+            // Peers speaking our Kademlia protocol join the routing table
+            // (not the validators behind this sentry); a relay learns its
+            // external address from what peers see
+            super::behaviour::KratOsBehaviourEvent::Identify(IdentifyEvent::Received { peer_id, info, .. }) => {
+                if self.peer_manager.is_private(&peer_id) {
+                    self.swarm.behaviour_mut().remove_kad_peer(&peer_id);
+                } else if info.protocols.contains(&self.kad_protocol) {
+                    for addr in info.listen_addrs.iter().filter(|a| !circuit::is_relayed(a)) {
+                        self.swarm.behaviour_mut().add_address(peer_id, addr.clone());
+                    }
+                }
+                if self.serve_relay {
+                    let candidate = self
+                        .listen_port()
+                        .and_then(|port| circuit::external_candidate(&info.observed_addr, port));
+                    if let Some(addr) = candidate.filter(|a| !self.swarm.external_addresses().any(|e| e == a)) {
+                        info!("🔁 Relay external address: {}", addr);
+                        self.swarm.add_external_address(addr);
+                    }
+                }
+            }
+
+            // Circuit relay
+            super::behaviour::KratOsBehaviourEvent::RelayClient(RelayClientEvent::ReservationReqAccepted {
+                relay_peer_id,
+                renewal,
+                ..
+            }) => {
+                if !renewal {
+                    info!("🔁 Relay {} accepted our reservation", relay_peer_id);
+                }
+                self.relays.reserved(relay_peer_id);
+            }
+            super::behaviour::KratOsBehaviourEvent::Relay(RelayEvent::ReservationReqAccepted {
+                src_peer_id,
+                renewed: false,
+            }) => {
+                debug!("Relaying for {}", src_peer_id);
+            }
+            super::behaviour::KratOsBehaviourEvent::Relay(RelayEvent::CircuitReqAccepted { src_peer_id, dst_peer_id }) => {
+                debug!("Relay circuit {} -> {}", src_peer_id, dst_peer_id);
+            }
 
             _ => {}
         }
@@ -622,6 +1082,13 @@ impl NetworkService {
                 self.peer_manager.update_peer_height(from, block.header.number);
                 self.sync_manager.peer_height_update(block.header.number);
 
+                // Kept (when the body matches the header) so it is neither
+                // fetched again on announcement nor published again
+                self.block_relay.mark_known(from, &block.hash());
+                if block.verify_body_root() {
+                    self.block_relay.cache(block.clone(), true);
+                }
+
                 // During initial sync, defer gossip blocks if they're too far ahead
                 // This prevents "block number mismatch" errors when we receive
                 // new blocks before the sync protocol delivers historical blocks
@@ -645,6 +1112,9 @@ impl NetworkService {
             }
             Ok(NetworkMessage::NewTransaction(tx)) => {
                 debug!("Received new transaction from {}", from);
+                let hash = tx.hash();
+                self.tx_relay.mark_known(from, &hash);
+                self.tx_relay.cache(hash, tx.clone(), true);
                 let _ = self.event_tx.send(NetworkEvent::TransactionReceived {
                     transaction: tx,
                     from: *from,
@@ -674,6 +1144,13 @@ impl NetworkService {
                 });
                 trace!("[GRANDPA] network: FinalityJustificationReceived event sent to node");
             }
+            Ok(NetworkMessage::Evidence { evidence_data }) => {
+                debug!("Received equivocation evidence from {}", from);
+                let _ = self.event_tx.send(NetworkEvent::EvidenceReceived {
+                    evidence_data,
+                    from: *from,
+                });
+            }
             Ok(msg) => {
                 debug!("Received other gossip message: {:?}", msg);
             }
@@ -690,6 +1167,13 @@ impl NetworkService {
             ReqResEvent::Message { peer, message } => {
                 match message {
                     ReqResMessage::Request { request, channel, .. } => {
+                        let _span = tracing::info_span!(
+                            parent: None,
+                            "network_serve",
+                            peer = %peer,
+                            request = request.kind()
+                        )
+                        .entered();
                         self.handle_incoming_request(peer, request, channel);
                     }
                     ReqResMessage::Response { request_id, response } => {
@@ -700,10 +1184,37 @@ impl NetworkService {
             ReqResEvent::OutboundFailure { peer, request_id, error, .. } => {
                 warn!("Request to {} failed: {:?}", peer, error);
 
-                // Check if this was a sync request and decrement counter
+                // A failed sync chunk is requested again from another peer
                 if let Some(pending) = self.pending_requests.get(&request_id) {
-                    if matches!(pending.request_type, RequestType::Sync { .. }) {
-                        self.pending_sync_requests = self.pending_sync_requests.saturating_sub(1);
+                    pending.span.record("outcome", "failed");
+                    match pending.request_type {
+                        RequestType::Sync { from, .. } => {
+                            self.sync_manager.on_chunk_failed(from);
+                        }
+                        RequestType::ForkSync { from } => {
+                            self.sync_manager.on_fork_chunk_failed(from);
+                            self.request_fork_chunks();
+                        }
+                        RequestType::ForkProbe(number) => {
+                            self.sync_manager.on_ancestor_probe(&peer, number, None, None);
+                        }
+                        RequestType::WarpChunk { index } => {
+                            let _ = self.event_tx.send(NetworkEvent::WarpSyncUnavailable { chunk: Some(index), from: peer });
+                        }
+                        // Another announcer is asked for the body
+                        RequestType::AnnouncedBlock(hash) => {
+                            self.block_relay.fetch_failed(&hash, &peer);
+                            self.fetch_announced_block(&hash);
+                        }
+                        // Released before the handshake: v1 everywhere
+                        RequestType::Handshake => {
+                            if let Ok(versions) = Handshake::local().negotiate(&Handshake::legacy()) {
+                                self.record_protocols(&peer, versions);
+                            }
+                            self.pending_requests.remove(&request_id);
+                            return;
+                        }
+                        _ => {}
                     }
                 }
 
@@ -728,6 +1239,12 @@ impl NetworkService {
         channel: request_response::ResponseChannel<KratosResponse>,
     ) {
         match request {
+            // Announced blocks are fetched right after the announcement
+            KratosRequest::Block(BlockRequest::ByHash(hash)) if self.block_relay.block(&hash).is_some() => {
+                let block = self.block_relay.block(&hash).cloned().map(BlockResponse::Block);
+                let response = KratosResponse::Block(block.unwrap_or(BlockResponse::NotFound));
+                let _ = self.swarm.behaviour_mut().send_response(channel, response);
+            }
             KratosRequest::Block(block_req) => {
                 let response = if let Some(ref provider) = self.block_provider {
                     // Try to acquire lock without blocking
@@ -803,6 +1320,7 @@ impl NetworkService {
                     warn!("Peer {} has different genesis hash (theirs: {}, ours: {})!",
                           peer, status_req.genesis_hash, self.genesis_hash);
                     self.peer_manager.ban_peer(&peer, "Different genesis");
+                    self.swarm.behaviour_mut().remove_kad_peer(&peer);
                     return;
                 }
 
@@ -842,9 +1360,242 @@ impl NetworkService {
                     // Don't respond - let the request timeout so the peer tries another node
                 }
             }
+            KratosRequest::Justification(justification_req) => {
+                let justification = self.block_provider.as_ref().and_then(|provider| {
+                    // Try to acquire lock without blocking
+                    provider.try_read().ok()?.get_justification(&justification_req)
+                });
+                let _ = self.swarm.behaviour_mut().send_response(channel, JustificationResponse::from_option(justification));
+            }
+            KratosRequest::Handshake(remote) => {
+                // Answered even without a common version: the peer logs why and disconnects
+                let _ = self.swarm.behaviour_mut().send_response(channel, KratosResponse::Handshake(Handshake::local()));
+                if let Ok(versions) = Handshake::local().negotiate(&remote) {
+                    self.record_protocols(&peer, versions);
+                }
+            }
+            KratosRequest::WarpSync(warp_req) => {
+                let response = match warp_req {
+                    WarpSyncRequest::Manifest => {
+                        let manifest = self.block_provider.as_ref().and_then(|provider| {
+                            provider.try_read().ok()?.get_warp_manifest()
+                        });
+                        if let Some(ref manifest) = manifest {
+                            info!("📤 Serving warp sync manifest for block #{} to {}", manifest.header.block_number, peer);
+                        }
+                        WarpSyncResponse::manifest(manifest)
+                    }
+                    WarpSyncRequest::Chunk { block_number, index } => {
+                        let chunk = self.block_provider.as_ref().and_then(|provider| {
+                            provider.try_read().ok()?.get_warp_chunk(block_number, index)
+                        });
+                        WarpSyncResponse::chunk(block_number, chunk)
+                    }
+                };
+                let _ = self.swarm.behaviour_mut().send_response(channel, response);
+            }
+            KratosRequest::Transactions(TransactionRequest::Announce(hashes)) => {
+                let _ = self.swarm.behaviour_mut().send_response(channel, KratosResponse::Transactions(TransactionResponse::Ack));
+                let wanted = self.tx_relay.to_pull(&peer, &hashes, std::time::Instant::now());
+                if !wanted.is_empty() {
+                    debug!("Pulling {} of {} transactions announced by {}", wanted.len(), hashes.len(), peer);
+                    let request_id = self.swarm.behaviour_mut().send_request(&peer, TransactionRequest::pull(wanted));
+                    self.pending_requests.insert(request_id, PendingRequest::new(peer, RequestType::TxPull));
+                }
+            }
+            KratosRequest::Transactions(TransactionRequest::Pull(hashes)) => {
+                let transactions = self.tx_relay.serve(&peer, &hashes);
+                let _ = self
+                    .swarm
+                    .behaviour_mut()
+                    .send_response(channel, KratosResponse::Transactions(TransactionResponse::Transactions(transactions)));
+            }
+            KratosRequest::BlockAnnounce(announcement) => {
+                let _ = self.swarm.behaviour_mut().send_response(channel, KratosResponse::BlockAnnounce(BlockAnnounceResponse::Ack));
+                self.handle_block_announcement(peer, announcement);
+            }
+        }
+    }
+
+    /// Handle a block announcement: anything that doesn't hold together is
+    /// dropped here, the header itself is checked by the node
+    fn handle_block_announcement(&mut self, peer: PeerId, announcement: BlockAnnouncement) {
+        if !announcement.is_consistent() {
+            warn!("Peer {} announced block {} with another header", peer, announcement.hash);
+            self.peer_manager.record_bad_block(&peer);
+            return;
+        }
+        let number = announcement.header.number;
+        self.peer_manager.update_peer_height(&peer, number);
+        self.sync_manager.peer_height_update(number);
+
+        if number <= self.local_height {
+            self.block_relay.mark_known(&peer, &announcement.hash);
+            return;
+        }
+        if self.block_relay.announced(&peer, announcement.hash, number) == AnnouncementOutcome::Ignore {
+            return;
+        }
+        if self.is_competing(&announcement.header) {
+            self.on_competing_block(peer, &announcement.header);
+        } else if number == self.local_height + 1 {
+            self.sync_manager.leave_forks(&peer);
+        }
+        // Without its parent the header can't be checked: sync catches up
+        if number > self.local_height + 1 {
+            debug!("Block #{} announced by {} is ahead of #{}, syncing", number, peer, self.local_height);
+            self.block_relay.forget(&announcement.hash);
+            self.maybe_start_sync();
+            return;
+        }
+        let _ = self.event_tx.send(NetworkEvent::BlockAnnounced { header: announcement.header, from: peer });
+    }
+
+    /// Handle the body of an announced block
+    fn handle_announced_block(&mut self, hash: Hash, peer: PeerId, response: KratosResponse) {
+        match response {
+            KratosResponse::Block(BlockResponse::Block(block)) => {
+                // The header was checked: the body must be the one it commits to
+                if block.hash() != hash || !block.verify_body_root() {
+                    warn!("Peer {} sent another block for announced block {}", peer, hash);
+                    self.peer_manager.record_bad_block(&peer);
+                    self.block_relay.fetch_failed(&hash, &peer);
+                    self.fetch_announced_block(&hash);
+                    return;
+                }
+                debug!("Fetched announced block #{} from {}", block.header.number, peer);
+                let _ = self.event_tx.send(NetworkEvent::BlockReceived { block, from: peer });
+            }
+            _ => {
+                debug!("Peer {} doesn't serve announced block {}", peer, hash);
+                self.block_relay.fetch_failed(&hash, &peer);
+                self.fetch_announced_block(&hash);
+            }
+        }
+    }
+
+    // =========================================================================
+    // FORKS (see sync.rs)
+    // =========================================================================
+
+    /// Whether a block is the child of another block than the local one at
+    /// its parent's height
+    fn is_competing(&self, header: &BlockHeader) -> bool {
+        header.number == self.local_height + 1 && header.parent_hash != self.local_hash && self.local_hash != Hash::ZERO
+    }
+
+    /// A peer served a block of a competing chain: search the common
+    /// ancestor, or check the known fork's justification again
+    fn on_competing_block(&mut self, peer: PeerId, header: &BlockHeader) {
+        match self.sync_manager.on_competing_block(peer, header) {
+            Some(probe) => self.on_ancestor_step(peer, probe),
+            None => self.check_fork(&peer),
+        }
+    }
+
+    fn on_ancestor_step(&mut self, peer: PeerId, probe: AncestorProbe) {
+        match probe {
+            AncestorProbe::Request(number) => {
+                let request = KratosRequest::Block(BlockRequest::HeaderByNumber(number));
+                let request_id = self.swarm.behaviour_mut().send_request(&peer, request);
+                self.pending_requests.insert(request_id, PendingRequest::new(peer, RequestType::ForkProbe(number)));
+            }
+            AncestorProbe::Found { .. } => self.check_fork(&peer),
+            AncestorProbe::Abandoned => debug!("Common ancestor search with {} given up", peer),
+        }
+    }
+
+    /// Request the latest justification of a fork peer
+    fn check_fork(&mut self, peer: &PeerId) {
+        if let Some(branch) = self.sync_manager.fork_to_check(peer, std::time::Instant::now()) {
+            let request = KratosRequest::Justification(JustificationRequest::Latest);
+            let request_id = self.swarm.behaviour_mut().send_request(peer, request);
+            self.pending_requests.insert(request_id, PendingRequest::new(*peer, RequestType::ForkJustification(branch)));
+        }
+    }
+
+    /// Handle the peer's block at a height probed by an ancestor search
+    fn handle_fork_probe(&mut self, number: BlockNumber, peer: PeerId, response: KratosResponse) {
+        let remote = match response {
+            KratosResponse::Block(BlockResponse::Block(block)) if block.header.number == number => Some(block.hash()),
+            _ => None,
+        };
+        let local = if number > self.local_height {
+            None
+        } else {
+            let block = self
+                .block_provider
+                .as_ref()
+                .and_then(|provider| provider.try_read().ok().and_then(|guard| guard.get_block_by_number(number)));
+            match block {
+                Some(block) => Some(block.hash()),
+                // Nothing to compare with: given up, the next competing
+                // block starts it over
+                None => {
+                    self.sync_manager.on_ancestor_probe(&peer, number, None, None);
+                    return;
+                }
+            }
+        };
+        let probe = self.sync_manager.on_ancestor_probe(&peer, number, remote, local);
+        self.on_ancestor_step(peer, probe);
+    }
+
+    /// Handle the latest justification of a fork peer
+    fn handle_fork_justification(&mut self, branch: Hash, peer: PeerId, response: KratosResponse) {
+        let KratosResponse::Justification(JustificationResponse::Justification(justification)) = response else {
+            debug!("Fork peer {} has no justification", peer);
+            return;
+        };
+        if self.sync_manager.on_fork_justification(&branch, justification) {
+            self.request_fork_chunks();
+        }
+    }
+
+    /// Request the missing chunks of the fork being downloaded
+    fn request_fork_chunks(&mut self) {
+        for request in self.sync_manager.schedule_fork_chunks(std::time::Instant::now()) {
+            let sync_request = KratosRequest::Sync(SyncRequest {
+                from_block: request.from_block,
+                max_blocks: request.max_blocks,
+                include_bodies: true,
+            });
+            let request_id = self.swarm.behaviour_mut().send_request(&request.peer, sync_request);
+            self.pending_requests
+                .insert(request_id, PendingRequest::new(request.peer, RequestType::ForkSync { from: request.from_block }));
         }
     }
 
+    /// Handle a chunk of the fork being downloaded
+    fn handle_fork_chunk(&mut self, from: BlockNumber, peer: PeerId, response: KratosResponse) {
+        let blocks = match response {
+            KratosResponse::Sync(sync_res) => sync_res.blocks,
+            _ => Vec::new(),
+        };
+        match self.sync_manager.on_fork_chunk(&peer, from, blocks) {
+            Some(fork) => {
+                info!("🍴 Fork {} downloaded up to its justified block #{}", fork.branch, fork.justification.block_number);
+                let _ = self.event_tx.send(NetworkEvent::ForkReceived { fork });
+            }
+            None => self.request_fork_chunks(),
+        }
+    }
+
+    /// Known competing chains
+    pub fn forks(&self) -> Vec<ForkHead> {
+        self.sync_manager.forks().to_vec()
+    }
+
+    /// Branch of the fork being downloaded
+    pub fn downloading_fork(&self) -> Option<Hash> {
+        self.sync_manager.downloading_fork()
+    }
+
+    /// Drop a fork the node adopted or rejected
+    pub fn forget_fork(&mut self, branch: &Hash) {
+        self.sync_manager.forget_fork(branch);
+    }
+
     /// Handle responses to our requests
     fn handle_response(
         &mut self,
@@ -852,13 +1603,34 @@ impl NetworkService {
         peer: PeerId,
         response: KratosResponse,
     ) {
-        let _pending = match self.pending_requests.remove(&request_id) {
+        let pending = match self.pending_requests.remove(&request_id) {
             Some(p) => p,
             None => {
                 warn!("Received response for unknown request");
                 return;
             }
         };
+        pending.span.record("outcome", "response");
+
+        match pending.request_type {
+            RequestType::AnnouncedBlock(hash) => {
+                self.handle_announced_block(hash, peer, response);
+                return;
+            }
+            RequestType::ForkProbe(number) => {
+                self.handle_fork_probe(number, peer, response);
+                return;
+            }
+            RequestType::ForkJustification(branch) => {
+                self.handle_fork_justification(branch, peer, response);
+                return;
+            }
+            RequestType::ForkSync { from } => {
+                self.handle_fork_chunk(from, peer, response);
+                return;
+            }
+            _ => {}
+        }
 
         match response {
             KratosResponse::Block(block_res) => {
@@ -886,12 +1658,31 @@ impl NetworkService {
                 info!("Received {} blocks from {} (has_more: {})",
                     sync_res.blocks.len(), peer, sync_res.has_more);
 
-                // Decrement pending sync counter
-                self.pending_sync_requests = self.pending_sync_requests.saturating_sub(1);
-
                 self.peer_manager.update_peer_height(&peer, sync_res.best_height);
                 self.sync_manager.peer_height_update(sync_res.best_height);
-                self.sync_manager.handle_sync_response(sync_res.blocks.clone(), sync_res.has_more);
+
+                // The chain of the peer does not go through the local block
+                if let Some(first) = sync_res.blocks.first().filter(|block| self.is_competing(&block.header)) {
+                    let header = first.header.clone();
+                    if let RequestType::Sync { from, .. } = pending.request_type {
+                        self.sync_manager.on_chunk_failed(from);
+                    }
+                    self.on_competing_block(peer, &header);
+                    self.maybe_start_sync();
+                    return;
+                }
+
+                if let RequestType::Sync { from, .. } = pending.request_type {
+                    let accepted = self.sync_manager.on_chunk_response(
+                        &peer,
+                        from,
+                        sync_res.blocks.clone(),
+                        std::time::Instant::now(),
+                    );
+                    if accepted < sync_res.blocks.len() {
+                        warn!("⚠️ Rejected {} blocks from {} during sync", sync_res.blocks.len() - accepted, peer);
+                    }
+                }
 
                 let _ = self.event_tx.send(NetworkEvent::SyncBlocksReceived {
                     blocks: sync_res.blocks,
@@ -899,16 +1690,15 @@ impl NetworkService {
                     has_more: sync_res.has_more,
                 });
 
-                // Continue sync if needed
-                if sync_res.has_more {
-                    self.maybe_start_sync();
-                }
+                // Refill the download pipeline
+                self.maybe_start_sync();
             }
             KratosResponse::Status(status_res) => {
                 // Validate genesis
                 if status_res.genesis_hash != self.genesis_hash && self.genesis_hash != Hash::ZERO {
                     warn!("Peer {} has different genesis hash!", peer);
                     self.peer_manager.ban_peer(&peer, "Different genesis");
+                    self.swarm.behaviour_mut().remove_kad_peer(&peer);
                     return;
                 }
 
@@ -940,6 +1730,11 @@ impl NetworkService {
                     self.maybe_start_sync();
                 }
             }
+            KratosResponse::Handshake(remote) => {
+                if self.negotiate_protocols(&peer, &remote) {
+                    debug!("Protocol handshake with {} complete", peer);
+                }
+            }
             KratosResponse::Genesis(genesis_res) => {
                 // We received genesis info from a peer - this is for joining nodes
                 info!("📥 Received genesis from peer {}: hash={}, chain={}",
@@ -964,6 +1759,48 @@ impl NetworkService {
                     genesis_balances: genesis_res.genesis_balances,
                 });
             }
+            KratosResponse::Justification(justification_res) => match justification_res {
+                JustificationResponse::Justification(justification) => {
+                    debug!("Received justification for block #{} from {}", justification.block_number, peer);
+                    let _ = self.event_tx.send(NetworkEvent::JustificationReceived {
+                        justification,
+                        from: peer,
+                    });
+                }
+                JustificationResponse::NotFound => {
+                    debug!("Justification not found at peer {}", peer);
+                }
+            },
+            KratosResponse::WarpSync(warp_res) => match warp_res {
+                WarpSyncResponse::Manifest(manifest) => {
+                    debug!("Received warp sync manifest for block #{} from {}", manifest.header.block_number, peer);
+                    let _ = self.event_tx.send(NetworkEvent::WarpManifestReceived { manifest, from: peer });
+                }
+                WarpSyncResponse::Chunk { block_number, chunk } => {
+                    let _ = self.event_tx.send(NetworkEvent::WarpChunkReceived { block_number, chunk, from: peer });
+                }
+                WarpSyncResponse::NotAvailable => {
+                    debug!("Warp sync data not available at peer {}", peer);
+                    let chunk = match pending.request_type {
+                        RequestType::WarpChunk { index } => Some(index),
+                        _ => None,
+                    };
+                    let _ = self.event_tx.send(NetworkEvent::WarpSyncUnavailable { chunk, from: peer });
+                }
+            },
+            KratosResponse::Transactions(TransactionResponse::Ack) => {}
+            KratosResponse::BlockAnnounce(BlockAnnounceResponse::Ack) => {}
+            KratosResponse::Transactions(TransactionResponse::Transactions(transactions)) => {
+                for transaction in transactions {
+                    // Only what we pulled: anything else is pushed spam
+                    if !self.tx_relay.pulled(&transaction.hash()) {
+                        debug!("Unrequested transaction from {}", peer);
+                        self.peer_manager.record_bad_transaction(&peer);
+                        continue;
+                    }
+                    let _ = self.event_tx.send(NetworkEvent::TransactionReceived { transaction, from: peer });
+                }
+            }
         }
     }
 
@@ -982,8 +1819,17 @@ impl NetworkService {
             let _ = self.swarm.disconnect_peer_id(peer_id);
         }
 
-        // Try to connect to more peers if needed
-        if self.peer_manager.needs_more_peers() {
+        // Redial reserved peers that dropped
+        for peer_id in self.peer_manager.disconnected_reserved() {
+            debug!("Redialing reserved peer {}", peer_id);
+            if let Err(e) = self.swarm.dial(DialOpts::peer_id(peer_id).build()) {
+                debug!("Failed to dial reserved peer {}: {}", peer_id, e);
+            }
+        }
+
+        // Try to connect to more peers if needed (never beyond the reserved
+        // peers in reserved-only mode)
+        if self.peer_manager.needs_more_peers() && !self.peer_manager.reserved_only() {
             // Try bootstrap nodes
             for (peer_id, addr) in self.peer_manager.get_bootstrap_nodes().to_vec() {
                 if self.peer_manager.get_peer(&peer_id).map(|p| !p.is_active()).unwrap_or(true) {
@@ -996,8 +1842,26 @@ impl NetworkService {
             let _ = self.swarm.behaviour_mut().bootstrap_kad();
         }
 
+        // Kademlia random walk: keeps the routing table fresh and finds
+        // peers beyond the DNS Seeds and bootnodes
+        if !self.peer_manager.reserved_only()
+            && self.last_kad_walk.elapsed() >= std::time::Duration::from_secs(KAD_WALK_INTERVAL_SECS)
+        {
+            self.last_kad_walk = std::time::Instant::now();
+            self.swarm.behaviour_mut().kad_random_walk();
+        }
+
+        // Requeue sync chunks that were not answered
+        for peer in self.sync_manager.expire_chunks(std::time::Instant::now()) {
+            debug!("Sync chunk request to {} timed out", peer);
+            if let Some(info) = self.peer_manager.get_peer_mut(&peer) {
+                info.timeout();
+            }
+        }
+
         // Maybe start sync
         self.maybe_start_sync();
+        self.request_fork_chunks();
 
         // Log stats
         let stats = self.peer_manager.stats();
@@ -1040,10 +1904,93 @@ impl NetworkService {
 
     /// Ban a peer manually
     pub fn ban_peer(&mut self, peer_id: PeerId, reason: &str) {
-        self.peer_manager.ban_peer(&peer_id, reason);
+        self.ban_peer_for(peer_id, reason, DEFAULT_BAN_SECS);
+    }
+
+    /// Ban a peer for `secs` seconds
+    pub fn ban_peer_for(&mut self, peer_id: PeerId, reason: &str, secs: u64) -> PeerBan {
+        let ban = self.peer_manager.ban_peer_for(&peer_id, reason, secs);
         self.rate_limiter.ban_peer(peer_id);
+        self.swarm.behaviour_mut().remove_kad_peer(&peer_id);
         let _ = self.swarm.disconnect_peer_id(peer_id);
         info!("Peer {} banned: {}", peer_id, reason);
+        ban
+    }
+
+    /// Lift a ban (false: the peer wasn't banned)
+    pub fn unban_peer(&mut self, peer_id: &PeerId) -> bool {
+        self.rate_limiter.unban_peer(peer_id);
+        self.peer_manager.unban_peer(peer_id)
+    }
+
+    /// Whether only reserved peers may connect (--reserved-only)
+    pub fn reserved_only(&self) -> bool {
+        self.peer_manager.reserved_only()
+    }
+
+    /// Known peers, connected or banned
+    pub fn peers(&self) -> Vec<PeerInfo> {
+        self.peer_manager.peers().filter(|p| p.is_active() || p.ban.is_some()).cloned().collect()
+    }
+
+    /// Peer scores and bans to keep across a restart
+    pub fn peer_reputations(&self) -> Vec<PeerReputation> {
+        self.peer_manager.reputations()
+    }
+
+    /// Restore saved peer scores and bans, returning how many bans apply
+    pub fn restore_peer_reputations(&mut self, reputations: Vec<PeerReputation>) -> usize {
+        self.peer_manager.restore_reputations(reputations)
+    }
+
+    /// Dial peers found on the local network by mDNS, while below the peer
+    /// limit (a peer of another chain is banned by the status exchange)
+    fn dial_local(&mut self, peers: Vec<(PeerId, Multiaddr)>) {
+        if self.peer_manager.reserved_only() {
+            return;
+        }
+        let mut addresses: HashMap<PeerId, Vec<Multiaddr>> = HashMap::new();
+        for (peer_id, addr) in peers {
+            if peer_id != self.local_peer_id {
+                addresses.entry(peer_id).or_default().push(addr);
+            }
+        }
+        let mut room = self.peer_manager.free_slots();
+        for (peer_id, addrs) in addresses {
+            for addr in &addrs {
+                self.swarm.behaviour_mut().add_address(peer_id, addr.clone());
+            }
+            let dialable = !self.swarm.is_connected(&peer_id)
+                && self.peer_manager.get_peer(&peer_id).is_none_or(|info| !info.should_disconnect());
+            if !dialable || room == 0 {
+                continue;
+            }
+            info!("🏠 Found peer {} on the local network", peer_id);
+            match self.swarm.dial(DialOpts::peer_id(peer_id).addresses(addrs).build()) {
+                Ok(()) => room -= 1,
+                Err(e) => debug!("Failed to dial local peer {}: {}", peer_id, e),
+            }
+        }
+    }
+
+    /// Dial peers found by a Kademlia lookup, while below the peer limit
+    fn dial_discovered(&mut self, peers: Vec<PeerId>) {
+        if self.peer_manager.reserved_only() {
+            return;
+        }
+        let room = self.peer_manager.free_slots();
+        let candidates = peers
+            .into_iter()
+            .filter(|p| *p != self.local_peer_id && !self.swarm.is_connected(p))
+            .filter(|p| self.peer_manager.get_peer(p).is_none_or(|info| !info.should_disconnect()))
+            .take(room.min(KAD_MAX_DIALS_PER_WALK));
+        for peer_id in candidates.collect::<Vec<_>>() {
+            debug!("Dialing peer {} found through Kademlia", peer_id);
+            // Kademlia provides the addresses
+            if let Err(e) = self.swarm.dial(DialOpts::peer_id(peer_id).build()) {
+                debug!("Failed to dial discovered peer {}: {}", peer_id, e);
+            }
+        }
     }
 
     /// Get local peer ID
@@ -1051,9 +1998,69 @@ impl NetworkService {
         self.local_peer_id
     }
 
-    /// Get local listening addresses (for DNS Seed heartbeats)
+    /// Get local listening addresses (for DNS Seed heartbeats), external
+    /// and circuit addresses included
     pub fn local_listen_addresses(&self) -> Vec<Multiaddr> {
-        self.swarm.listeners().cloned().collect()
+        self.swarm.listeners().chain(self.swarm.external_addresses()).cloned().collect()
+    }
+
+    /// Also accept QUIC connections on a UDP port (--listen-quic)
+    pub fn listen_quic(&mut self, port: u16) -> Result<(), Box<dyn Error>> {
+        self.swarm.listen_on(behaviour::quic_listen_address(port))?;
+        Ok(())
+    }
+
+    /// Address the node is reachable at from outside (--public-addr)
+    pub fn add_external_address(&mut self, addr: Multiaddr) {
+        self.swarm.add_external_address(addr);
+    }
+
+    /// Whether the node relays circuits (--relay)
+    pub fn serves_relay(&self) -> bool {
+        self.serve_relay
+    }
+
+    /// Whether the node should listen through relays: no peer dialed it
+    /// since startup, and it has fewer than MAX_RELAYS
+    pub fn needs_relay(&self) -> bool {
+        !self.serve_relay
+            && !self.inbound_seen
+            && self.relays.len() < MAX_RELAYS
+            && self.started_at.elapsed() >= std::time::Duration::from_secs(RELAY_GRACE_SECS)
+    }
+
+    /// Listen through relays among `candidates` (relay nodes from the DNS
+    /// Seeds), returning how many were added
+    pub fn listen_via_relays(&mut self, candidates: Vec<(PeerId, Multiaddr)>) -> usize {
+        let mut added = 0;
+        for (relay, addr) in self.relays.pick(self.local_peer_id, candidates) {
+            self.swarm.behaviour_mut().add_address(relay, addr.clone());
+            match self.swarm.listen_on(circuit::circuit_address(relay, &addr)) {
+                Ok(listener) => {
+                    self.relays.listening(listener, relay);
+                    added += 1;
+                }
+                Err(e) => debug!("Failed to listen through relay {}: {}", relay, e),
+            }
+        }
+        added
+    }
+
+    /// Relays holding a reservation for the node
+    pub fn relay_count(&self) -> usize {
+        self.relays.reserved_relays().count()
+    }
+
+    /// TCP port of the first direct listener
+    fn listen_port(&self) -> Option<u16> {
+        self.swarm
+            .listeners()
+            .filter(|a| !circuit::is_relayed(a))
+            .flat_map(|a| a.iter())
+            .find_map(|p| match p {
+                Protocol::Tcp(port) => Some(port),
+                _ => None,
+            })
     }
 
     /// Cleanup rate limiter
@@ -1079,8 +2086,84 @@ impl NetworkService {
         self.sync_manager.next_block_to_import()
     }
 
+    /// Best network height seen by the sync manager
+    pub fn best_known_height(&self) -> BlockNumber {
+        self.sync_manager.best_known_height()
+    }
+
     /// Update local height in sync manager
     pub fn update_sync_local_height(&mut self, height: BlockNumber) {
         self.sync_manager.update_local_height(height);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_protocol_handshake_on_connection() {
+        let (mut a, _a_events) = NetworkService::new("/ip4/127.0.0.1/tcp/0").await.unwrap();
+        let (mut b, _b_events) = NetworkService::new("/ip4/127.0.0.1/tcp/0").await.unwrap();
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        let addr = loop {
+            a.poll_once().await;
+            if let Some(addr) = a.swarm.listeners().next() {
+                break addr.clone();
+            }
+            assert!(std::time::Instant::now() < deadline, "no listen address");
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        };
+        b.dial(addr).unwrap();
+
+        // Both sides record the versions: the dialer from the response, the listener from the request
+        let (a_id, b_id) = (a.local_peer_id(), b.local_peer_id());
+        loop {
+            a.poll_once().await;
+            b.poll_once().await;
+            let a_done = a.peer_manager.get_peer(&b_id).is_some_and(|p| p.protocols.is_some());
+            let b_done = b.peer_manager.get_peer(&a_id).is_some_and(|p| p.protocols.is_some());
+            if a_done && b_done {
+                break;
+            }
+            assert!(std::time::Instant::now() < deadline, "handshake not completed");
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(b.peer_manager.get_peer(&a_id).unwrap().protocols, Some(NegotiatedVersions::default()));
+        assert_eq!(b.peer_manager.protocol_version(&a_id, crate::network::handshake::SubProtocol::Warp), 1);
+    }
+
+    #[tokio::test]
+    async fn test_quic_connection() {
+        let (mut a, _a_events) = NetworkService::new("/ip4/127.0.0.1/tcp/0").await.unwrap();
+        let (mut b, _b_events) = NetworkService::new("/ip4/127.0.0.1/tcp/0").await.unwrap();
+        a.listen_quic(0).unwrap();
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        let addr = loop {
+            a.poll_once().await;
+            let quic = a
+                .swarm
+                .listeners()
+                .find(|addr| behaviour::is_quic(addr) && addr.iter().any(|p| p == Protocol::Ip4(std::net::Ipv4Addr::LOCALHOST)));
+            if let Some(addr) = quic {
+                break addr.clone();
+            }
+            assert!(std::time::Instant::now() < deadline, "no QUIC listen address");
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        };
+        b.dial(addr).unwrap();
+
+        let (a_id, b_id) = (a.local_peer_id(), b.local_peer_id());
+        loop {
+            a.poll_once().await;
+            b.poll_once().await;
+            if a.swarm.is_connected(&b_id) && b.swarm.is_connected(&a_id) {
+                break;
+            }
+            assert!(std::time::Instant::now() < deadline, "QUIC connection not established");
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+    }
+}
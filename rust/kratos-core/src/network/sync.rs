@@ -1,10 +1,52 @@
 // Sync - Protocole de synchronisation de la chaîne
+//
+// The range between the local height and the best known height is split into
+// chunks requested concurrently from several peers. Peers are ranked by their
+// measured throughput; chunks that fail or time out are requested again from
+// another peer. Blocks arriving out of order wait in `pending_blocks` until
+// they can be imported, and nothing is requested beyond MAX_BUFFERED_BLOCKS
+// past the local height, so the verification pipeline stays bounded.
 use crate::network::protocol::NetworkMessage;
+use crate::network::service::SharedBlockProvider;
 use crate::node::producer::BlockValidator;
+use crate::storage::MAX_REORG_DEPTH;
 use crate::types::*;
-use std::collections::{HashMap, VecDeque};
+use libp2p::PeerId;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
+/// Blocks per chunk request
+pub const SYNC_CHUNK_SIZE: u32 = 50;
+
+/// Chunk requests in flight across all peers
+pub const MAX_CHUNKS_IN_FLIGHT: usize = 8;
+
+/// Chunk requests in flight to one peer
+pub const MAX_CHUNKS_PER_PEER: usize = 2;
+
+/// Download window ahead of the local height (blocks)
+pub const MAX_BUFFERED_BLOCKS: u64 = 512;
+
+/// A chunk not answered within this delay is requested from another peer
+pub const CHUNK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Peers that failed this many chunks in a row are not asked again
+const MAX_CONSECUTIVE_FAILURES: u32 = 3;
+
+/// Weight of the latest sample in the throughput average
+const THROUGHPUT_SMOOTHING: f64 = 0.3;
+
+/// Ancestor search steps before the search is given up (the search window is
+/// MAX_REORG_DEPTH blocks deep)
+const MAX_ANCESTOR_PROBES: u32 = 16;
+
+/// Competing chains kept (chain_getForks)
+pub const MAX_KNOWN_FORKS: usize = 16;
+
+/// The latest justification of a fork is requested at most this often
+pub const FORK_RECHECK_INTERVAL: Duration = Duration::from_secs(30);
+
 /// État de synchronisation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SyncState {
@@ -43,6 +85,159 @@ pub struct SyncManager {
 
     /// Seuil pour déclencher le sync
     sync_threshold: u64,
+
+    /// Chunk requests in flight, by first block
+    in_flight: BTreeMap<BlockNumber, InFlightChunk>,
+
+    /// Chunks to request again (first block -> block count)
+    retry_chunks: BTreeMap<BlockNumber, u32>,
+
+    /// First block not requested yet
+    next_chunk_start: BlockNumber,
+
+    /// Download throughput per peer
+    peer_throughput: HashMap<PeerId, PeerThroughput>,
+
+    /// Competing chains whose common ancestor is known
+    forks: Vec<ForkHead>,
+
+    /// Common ancestor searches in progress, by peer
+    ancestor_searches: HashMap<PeerId, AncestorSearch>,
+
+    /// Fork being downloaded (one at a time)
+    fork_download: Option<ForkDownload>,
+
+    /// Local chain, tells the key a downloaded block must be signed with
+    block_provider: Option<SharedBlockProvider>,
+}
+
+/// A block range to request from a peer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkRequest {
+    pub peer: PeerId,
+    pub from_block: BlockNumber,
+    pub max_blocks: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct InFlightChunk {
+    peer: PeerId,
+    count: u32,
+    sent_at: Instant,
+}
+
+/// Measured download performance of a peer
+#[derive(Debug, Clone, Default)]
+pub struct PeerThroughput {
+    /// Smoothed blocks per second (None until a chunk completed)
+    pub blocks_per_sec: Option<f64>,
+
+    /// Chunks delivered
+    pub chunks_completed: u64,
+
+    /// Chunks failed or timed out since the last delivered one
+    pub consecutive_failures: u32,
+}
+
+impl PeerThroughput {
+    /// Ranking score; unmeasured peers come first so they get measured
+    pub fn score(&self) -> f64 {
+        match self.blocks_per_sec {
+            Some(rate) => rate / (1 + self.consecutive_failures) as f64,
+            None => f64::INFINITY,
+        }
+    }
+
+    fn record_delivery(&mut self, blocks: usize, elapsed: Duration) {
+        let sample = blocks as f64 / elapsed.as_secs_f64().max(0.001);
+        self.blocks_per_sec = Some(match self.blocks_per_sec {
+            Some(rate) => rate * (1.0 - THROUGHPUT_SMOOTHING) + sample * THROUGHPUT_SMOOTHING,
+            None => sample,
+        });
+        self.chunks_completed += 1;
+        self.consecutive_failures = 0;
+    }
+}
+
+/// A chain competing with the local one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForkHead {
+    /// First block of the fork (its parent is the common ancestor)
+    pub branch: Hash,
+
+    /// Last block shared with the local chain
+    pub common_ancestor: BlockNumber,
+
+    /// Best block seen on the fork
+    pub head: Hash,
+    pub number: BlockNumber,
+
+    /// Highest block the fork's peers served a justification for (verified
+    /// by the node before any reorg)
+    pub justified: Option<BlockNumber>,
+
+    /// Peers serving the fork
+    pub peers: Vec<PeerId>,
+
+    /// Last time the fork's justification was requested
+    checked_at: Option<Instant>,
+}
+
+impl ForkHead {
+    pub fn new(branch: Hash, common_ancestor: BlockNumber, head: Hash, number: BlockNumber, peer: PeerId) -> Self {
+        Self { branch, common_ancestor, head, number, justified: None, peers: vec![peer], checked_at: None }
+    }
+}
+
+/// Next step of a common ancestor search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AncestorProbe {
+    /// Request the peer's block at this height
+    Request(BlockNumber),
+
+    /// Common ancestor found; `branch` is the first block of the fork
+    Found { ancestor: BlockNumber, branch: Hash },
+
+    /// Search given up (block not served, fork deeper than a reorg can go)
+    Abandoned,
+}
+
+/// A fork downloaded up to its justified block, to be checked by the node
+#[derive(Debug, Clone)]
+pub struct ForkDownloaded {
+    pub branch: Hash,
+    pub ancestor: BlockNumber,
+    /// Blocks from the ancestor's child to the justified block
+    pub blocks: Vec<Block>,
+    pub justification: FinalityJustification,
+}
+
+/// Binary search of the last block a peer's chain shares with the local one
+#[derive(Debug, Clone)]
+struct AncestorSearch {
+    /// Highest block known to be shared (unless `floor_checked` is false:
+    /// the bottom of the search window, probed first)
+    low: BlockNumber,
+    floor_checked: bool,
+    /// Lowest block known to differ, and the peer's hash of it
+    high: BlockNumber,
+    high_hash: Hash,
+    /// Best block the peer served
+    head: Hash,
+    head_number: BlockNumber,
+    probes: u32,
+}
+
+/// Chunks of a fork requested from its peers
+#[derive(Debug, Clone)]
+struct ForkDownload {
+    branch: Hash,
+    ancestor: BlockNumber,
+    justification: FinalityJustification,
+    blocks: BTreeMap<BlockNumber, Block>,
+    in_flight: BTreeMap<BlockNumber, InFlightChunk>,
+    retry: BTreeMap<BlockNumber, u32>,
+    next: BlockNumber,
 }
 
 impl SyncManager {
@@ -53,14 +248,35 @@ impl SyncManager {
             best_known_height: local_height,
             pending_blocks: HashMap::new(),
             download_queue: VecDeque::new(),
-            batch_size: 50,
+            batch_size: SYNC_CHUNK_SIZE,
             sync_threshold: 10,
+            in_flight: BTreeMap::new(),
+            retry_chunks: BTreeMap::new(),
+            next_chunk_start: local_height + 1,
+            peer_throughput: HashMap::new(),
+            forks: Vec::new(),
+            ancestor_searches: HashMap::new(),
+            fork_download: None,
+            block_provider: None,
         }
     }
 
+    /// Check downloaded blocks against the signers the local chain knows
+    pub fn set_block_provider(&mut self, provider: SharedBlockProvider) {
+        self.block_provider = Some(provider);
+    }
+
+    /// Key `header` must be signed with, if the local chain can tell
+    fn known_signer(&self, header: &BlockHeader) -> Option<AccountId> {
+        let provider = self.block_provider.as_ref()?.try_read().ok()?;
+        provider.block_signer(&header.author, header.epoch)
+    }
+
     /// Met à jour la hauteur locale
     pub fn update_local_height(&mut self, height: BlockNumber) {
         self.local_height = height;
+        // Blocks at or below the local height were imported (or superseded)
+        self.pending_blocks.retain(|number, _| *number > height);
         self.update_state();
     }
 
@@ -144,7 +360,7 @@ impl SyncManager {
     pub fn add_downloaded_block(&mut self, block: Block) -> bool {
         let block_num = block.header.number;
 
-        // Validate block signature and transactions root
+        // Validate transactions root
         // Full validation with parent will happen during import
         if let Err(e) = BlockValidator::validate_standalone(&block) {
             warn!(
@@ -154,6 +370,15 @@ impl SyncManager {
             return false;
         }
 
+        // Validate the signature when the signer is known (the author or its
+        // session key); one a rotation not imported yet may apply waits for the import
+        if let Some(signer) = self.known_signer(&block.header) {
+            if let Err(e) = BlockValidator::verify_signature(&block.header, &signer) {
+                warn!("❌ Block #{} has a bad signature: {}", block_num, e);
+                return false;
+            }
+        }
+
         // Basic sanity checks
         if block_num <= self.local_height {
             warn!(
@@ -189,6 +414,11 @@ impl SyncManager {
         self.pending_blocks.len()
     }
 
+    /// Meilleure hauteur connue du réseau
+    pub fn best_known_height(&self) -> BlockNumber {
+        self.best_known_height
+    }
+
     /// Gap avec le réseau
     pub fn sync_gap(&self) -> u64 {
         self.best_known_height.saturating_sub(self.local_height)
@@ -210,6 +440,585 @@ impl SyncManager {
         })
     }
 
+    // =========================================================================
+    // PARALLEL CHUNK DOWNLOAD
+    // =========================================================================
+
+    /// Assign chunks of the missing range to peers
+    ///
+    /// `peers` are the candidate peers with their best height. Each chunk goes
+    /// to the eligible peer with the fewest chunks in flight, the fastest one
+    /// first. Returns the requests to send.
+    pub fn schedule_chunks(&mut self, peers: &[(PeerId, BlockNumber)], now: Instant) -> Vec<ChunkRequest> {
+        if !self.should_sync() {
+            return Vec::new();
+        }
+
+        let first_missing = self.local_height + 1;
+        self.next_chunk_start = self.next_chunk_start.max(first_missing);
+        self.trim_retry_chunks();
+
+        // The next block to import was downloaded but dropped (failed import):
+        // request it again
+        if self.next_chunk_start > first_missing && !self.is_covered(first_missing) {
+            let count = (self.next_chunk_start - first_missing).min(self.batch_size as u64) as u32;
+            self.retry_chunks.insert(first_missing, count);
+        }
+
+        let window_end = self.best_known_height.min(self.local_height + MAX_BUFFERED_BLOCKS);
+
+        let mut candidates: Vec<(PeerId, BlockNumber)> = peers
+            .iter()
+            .filter(|(peer, height)| {
+                *height >= first_missing
+                    && !self.is_fork_peer(peer)
+                    && self.peer_throughput.get(peer).map_or(0, |t| t.consecutive_failures) < MAX_CONSECUTIVE_FAILURES
+            })
+            .copied()
+            .collect();
+
+        if candidates.is_empty() && !peers.is_empty() {
+            // Every synced peer failed repeatedly: give them another chance
+            // rather than stalling
+            for (peer, _) in peers {
+                if let Some(throughput) = self.peer_throughput.get_mut(peer) {
+                    throughput.consecutive_failures = 0;
+                }
+            }
+            candidates = peers
+                .iter()
+                .filter(|(peer, height)| *height >= first_missing && !self.is_fork_peer(peer))
+                .copied()
+                .collect();
+        }
+
+        candidates.sort_by(|a, b| self.peer_score(&b.0).total_cmp(&self.peer_score(&a.0)));
+
+        let mut requests = Vec::new();
+        while self.in_flight.len() < MAX_CHUNKS_IN_FLIGHT {
+            let Some((from_block, count)) = self.next_chunk(window_end) else { break };
+
+            let peer = candidates
+                .iter()
+                .filter(|(peer, height)| *height >= from_block && self.chunks_in_flight(peer) < MAX_CHUNKS_PER_PEER)
+                .min_by_key(|(peer, _)| self.chunks_in_flight(peer))
+                .copied();
+
+            let Some((peer, peer_height)) = peer else {
+                // No peer can serve it now; keep it for the next round
+                self.retry_chunks.insert(from_block, count);
+                break;
+            };
+
+            // A peer behind the chunk end gets the part it has
+            let served = count.min((peer_height - from_block + 1) as u32);
+            if served < count {
+                self.retry_chunks.insert(from_block + served as u64, count - served);
+            }
+
+            self.in_flight.insert(from_block, InFlightChunk { peer, count: served, sent_at: now });
+            requests.push(ChunkRequest { peer, from_block, max_blocks: served });
+        }
+
+        if !requests.is_empty() {
+            debug!(
+                "Sync: {} chunks requested, {} in flight from {} peers",
+                requests.len(),
+                self.in_flight.len(),
+                candidates.len()
+            );
+        }
+
+        requests
+    }
+
+    /// Handle the blocks answering a chunk request
+    ///
+    /// Blocks are validated and buffered until they can be imported; the part
+    /// of the chunk the peer did not deliver is requested again. Returns the
+    /// number of blocks accepted.
+    pub fn on_chunk_response(&mut self, peer: &PeerId, from_block: BlockNumber, blocks: Vec<Block>, now: Instant) -> usize {
+        let chunk = self.in_flight.remove(&from_block).filter(|chunk| chunk.peer == *peer);
+
+        let mut accepted = 0;
+        let mut delivered_up_to = None;
+        for block in blocks {
+            let number = block.header.number;
+            if self.add_downloaded_block(block) {
+                accepted += 1;
+                delivered_up_to = delivered_up_to.max(Some(number));
+            }
+        }
+
+        let Some(chunk) = chunk else {
+            return accepted;
+        };
+
+        let chunk_end = from_block + chunk.count as u64 - 1;
+        match delivered_up_to {
+            Some(last) if accepted > 0 => {
+                self.peer_throughput
+                    .entry(*peer)
+                    .or_default()
+                    .record_delivery(accepted, now.saturating_duration_since(chunk.sent_at));
+                if last < chunk_end {
+                    self.requeue(last + 1, (chunk_end - last) as u32);
+                }
+            }
+            _ => {
+                debug!("Peer {} delivered no usable block for chunk #{}", peer, from_block);
+                self.record_failure(peer);
+                self.requeue(from_block, chunk.count);
+            }
+        }
+
+        accepted
+    }
+
+    /// A chunk request failed (transport error, peer gone)
+    pub fn on_chunk_failed(&mut self, from_block: BlockNumber) {
+        if let Some(chunk) = self.in_flight.remove(&from_block) {
+            self.record_failure(&chunk.peer);
+            self.requeue(from_block, chunk.count);
+        }
+    }
+
+    /// Requeue chunks not answered within CHUNK_TIMEOUT
+    ///
+    /// Returns the peers that timed out.
+    pub fn expire_chunks(&mut self, now: Instant) -> Vec<PeerId> {
+        let expired: Vec<BlockNumber> = self
+            .in_flight
+            .iter()
+            .filter(|(_, chunk)| now.saturating_duration_since(chunk.sent_at) >= CHUNK_TIMEOUT)
+            .map(|(from_block, _)| *from_block)
+            .collect();
+
+        let mut peers = Vec::new();
+        for from_block in expired {
+            if let Some(chunk) = self.in_flight.get(&from_block) {
+                peers.push(chunk.peer);
+            }
+            self.on_chunk_failed(from_block);
+        }
+
+        if let Some(download) = self.fork_download.as_mut() {
+            let expired: Vec<BlockNumber> = download
+                .in_flight
+                .iter()
+                .filter(|(_, chunk)| now.saturating_duration_since(chunk.sent_at) >= CHUNK_TIMEOUT)
+                .map(|(from_block, _)| *from_block)
+                .collect();
+            for from_block in expired {
+                if let Some(chunk) = download.in_flight.remove(&from_block) {
+                    peers.push(chunk.peer);
+                    download.retry.insert(from_block, chunk.count);
+                }
+            }
+        }
+        peers
+    }
+
+    /// Requeue the chunks of a disconnected peer
+    pub fn peer_disconnected(&mut self, peer: &PeerId) {
+        let chunks: Vec<(BlockNumber, u32)> = self
+            .in_flight
+            .iter()
+            .filter(|(_, chunk)| chunk.peer == *peer)
+            .map(|(from_block, chunk)| (*from_block, chunk.count))
+            .collect();
+        for (from_block, count) in chunks {
+            self.in_flight.remove(&from_block);
+            self.requeue(from_block, count);
+        }
+        self.peer_throughput.remove(peer);
+
+        self.leave_forks(peer);
+        if let Some(download) = self.fork_download.as_mut() {
+            let chunks: Vec<BlockNumber> =
+                download.in_flight.iter().filter(|(_, chunk)| chunk.peer == *peer).map(|(from_block, _)| *from_block).collect();
+            for from_block in chunks {
+                if let Some(chunk) = download.in_flight.remove(&from_block) {
+                    download.retry.insert(from_block, chunk.count);
+                }
+            }
+        }
+    }
+
+    /// Chunk requests in flight
+    pub fn in_flight_count(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    /// Measured throughput of a peer
+    pub fn peer_throughput(&self, peer: &PeerId) -> Option<&PeerThroughput> {
+        self.peer_throughput.get(peer)
+    }
+
+    /// Whether a block is buffered, in flight or queued for retry
+    fn is_covered(&self, number: BlockNumber) -> bool {
+        let in_range = |(from_block, count): (&BlockNumber, u32)| {
+            *from_block <= number && number < *from_block + count as u64
+        };
+        self.pending_blocks.contains_key(&number)
+            || self.in_flight.iter().any(|(from_block, chunk)| in_range((from_block, chunk.count)))
+            || self.retry_chunks.iter().any(|(from_block, count)| in_range((from_block, *count)))
+    }
+
+    fn chunks_in_flight(&self, peer: &PeerId) -> usize {
+        self.in_flight.values().filter(|chunk| chunk.peer == *peer).count()
+    }
+
+    fn peer_score(&self, peer: &PeerId) -> f64 {
+        self.peer_throughput.get(peer).map_or(f64::INFINITY, PeerThroughput::score)
+    }
+
+    fn record_failure(&mut self, peer: &PeerId) {
+        self.peer_throughput.entry(*peer).or_default().consecutive_failures += 1;
+    }
+
+    /// Next chunk to request: failed chunks first, then new ones up to `window_end`
+    fn next_chunk(&mut self, window_end: BlockNumber) -> Option<(BlockNumber, u32)> {
+        if let Some((from_block, count)) = self.retry_chunks.pop_first() {
+            return Some((from_block, count));
+        }
+
+        let from_block = self.next_chunk_start;
+        if from_block > window_end {
+            return None;
+        }
+        let count = (window_end - from_block + 1).min(self.batch_size as u64) as u32;
+        self.next_chunk_start = from_block + count as u64;
+        Some((from_block, count))
+    }
+
+    fn requeue(&mut self, from_block: BlockNumber, count: u32) {
+        let end = from_block + count as u64 - 1;
+        let start = from_block.max(self.local_height + 1);
+        if count > 0 && end >= start {
+            self.retry_chunks.insert(start, (end - start + 1) as u32);
+        }
+    }
+
+    /// Drop the parts of retry chunks already imported
+    fn trim_retry_chunks(&mut self) {
+        let chunks = std::mem::take(&mut self.retry_chunks);
+        for (from_block, count) in chunks {
+            self.requeue(from_block, count);
+        }
+    }
+
+    // =========================================================================
+    // FORKS
+    // =========================================================================
+    //
+    // A peer serving a block whose parent is not the local block at that
+    // height serves a competing chain. The last block both chains share is
+    // found by binary search, one block request per step, within
+    // MAX_REORG_DEPTH of the divergence. The peer's latest justification
+    // then tells whether the fork finalized anything past that ancestor: if
+    // so, the fork is downloaded up to the justified block, in chunks spread
+    // over the peers serving it, and handed to the node, which reorgs only if
+    // that finalizes more than the local chain. Fork peers are left out of
+    // the regular sync.
+
+    /// A peer served `header`, whose parent is not the local block at that
+    /// height: returns the first step of the ancestor search (None if the
+    /// peer's fork is known or being searched)
+    pub fn on_competing_block(&mut self, peer: PeerId, header: &BlockHeader) -> Option<AncestorProbe> {
+        let (hash, number) = (header.hash(), header.number);
+        if let Some(fork) = self.forks.iter_mut().find(|fork| fork.peers.contains(&peer)) {
+            if number > fork.number {
+                fork.head = hash;
+                fork.number = number;
+            }
+            return None;
+        }
+        if let Some(search) = self.ancestor_searches.get_mut(&peer) {
+            if number > search.head_number {
+                search.head = hash;
+                search.head_number = number;
+            }
+            return None;
+        }
+
+        // A different genesis is another network, not a fork
+        let diverged = number.checked_sub(1).filter(|n| *n > 0)?;
+        let floor = diverged.saturating_sub(MAX_REORG_DEPTH);
+        debug!("Peer {} serves a competing block #{} ({}), searching the common ancestor", peer, number, hash);
+        self.ancestor_searches.insert(
+            peer,
+            AncestorSearch {
+                low: floor,
+                floor_checked: floor == 0,
+                high: diverged,
+                high_hash: header.parent_hash,
+                head: hash,
+                head_number: number,
+                probes: 0,
+            },
+        );
+        Some(self.next_probe(&peer))
+    }
+
+    /// The peer's block at `number` (None: not served) against the local one
+    /// (None: the local chain does not reach it): returns the next step of
+    /// the search
+    pub fn on_ancestor_probe(
+        &mut self,
+        peer: &PeerId,
+        number: BlockNumber,
+        remote: Option<Hash>,
+        local: Option<Hash>,
+    ) -> AncestorProbe {
+        let Some(search) = self.ancestor_searches.get_mut(peer) else {
+            return AncestorProbe::Abandoned;
+        };
+        search.probes += 1;
+
+        let Some(remote) = remote else {
+            self.ancestor_searches.remove(peer);
+            return AncestorProbe::Abandoned;
+        };
+        if local == Some(remote) {
+            search.low = number;
+            search.floor_checked = true;
+        } else if !search.floor_checked {
+            warn!("Fork of peer {} goes deeper than {} blocks, ignored", peer, MAX_REORG_DEPTH);
+            self.ancestor_searches.remove(peer);
+            return AncestorProbe::Abandoned;
+        } else {
+            search.high = number;
+            search.high_hash = remote;
+        }
+
+        if search.probes > MAX_ANCESTOR_PROBES {
+            self.ancestor_searches.remove(peer);
+            return AncestorProbe::Abandoned;
+        }
+        self.next_probe(peer)
+    }
+
+    fn next_probe(&mut self, peer: &PeerId) -> AncestorProbe {
+        let Some(search) = self.ancestor_searches.get(peer) else {
+            return AncestorProbe::Abandoned;
+        };
+        if !search.floor_checked {
+            return AncestorProbe::Request(search.low);
+        }
+        if search.high - search.low > 1 {
+            return AncestorProbe::Request(search.low + (search.high - search.low) / 2);
+        }
+
+        let Some(search) = self.ancestor_searches.remove(peer) else {
+            return AncestorProbe::Abandoned;
+        };
+        let (ancestor, branch) = (search.low, search.high_hash);
+        info!("🍴 Peer {} is on a fork of block #{} (head #{} {})", peer, ancestor, search.head_number, search.head);
+        self.add_fork(*peer, search);
+        AncestorProbe::Found { ancestor, branch }
+    }
+
+    fn add_fork(&mut self, peer: PeerId, search: AncestorSearch) {
+        if let Some(fork) = self.forks.iter_mut().find(|fork| fork.branch == search.high_hash) {
+            fork.peers.push(peer);
+            if search.head_number > fork.number {
+                fork.head = search.head;
+                fork.number = search.head_number;
+            }
+            return;
+        }
+
+        if self.forks.len() >= MAX_KNOWN_FORKS {
+            let downloading = self.fork_download.as_ref().map(|download| download.branch);
+            if let Some(oldest) = self.forks.iter().position(|fork| Some(fork.branch) != downloading) {
+                self.forks.remove(oldest);
+            }
+        }
+        self.forks.push(ForkHead::new(search.high_hash, search.low, search.head, search.head_number, peer));
+    }
+
+    /// Branch of the fork whose latest justification to request from
+    /// `peer`, at most once per FORK_RECHECK_INTERVAL and not while the fork
+    /// downloads
+    pub fn fork_to_check(&mut self, peer: &PeerId, now: Instant) -> Option<Hash> {
+        let downloading = self.fork_download.as_ref().map(|download| download.branch);
+        let fork = self.forks.iter_mut().find(|fork| fork.peers.contains(peer))?;
+        let recent = fork.checked_at.is_some_and(|at| now.saturating_duration_since(at) < FORK_RECHECK_INTERVAL);
+        if recent || downloading == Some(fork.branch) {
+            return None;
+        }
+        fork.checked_at = Some(now);
+        Some(fork.branch)
+    }
+
+    /// Latest justification served by a fork peer: the fork is downloaded up
+    /// to the justified block if that is past the common ancestor (returns
+    /// whether the download started)
+    pub fn on_fork_justification(&mut self, branch: &Hash, justification: FinalityJustification) -> bool {
+        let Some(fork) = self.forks.iter_mut().find(|fork| fork.branch == *branch) else {
+            return false;
+        };
+        let (ancestor, target) = (fork.common_ancestor, justification.block_number);
+        fork.justified = fork.justified.max(Some(target));
+        if target <= ancestor || target - ancestor > MAX_REORG_DEPTH || self.fork_download.is_some() {
+            return false;
+        }
+
+        info!("🍴 Fork {} finalized block #{} past the common ancestor #{}, downloading it", branch, target, ancestor);
+        self.fork_download = Some(ForkDownload {
+            branch: *branch,
+            ancestor,
+            justification,
+            blocks: BTreeMap::new(),
+            in_flight: BTreeMap::new(),
+            retry: BTreeMap::new(),
+            next: ancestor + 1,
+        });
+        true
+    }
+
+    /// Assign the missing chunks of the fork being downloaded to its peers
+    pub fn schedule_fork_chunks(&mut self, now: Instant) -> Vec<ChunkRequest> {
+        let Some(download) = self.fork_download.as_mut() else {
+            return Vec::new();
+        };
+        let Some(fork) = self.forks.iter().find(|fork| fork.branch == download.branch) else {
+            return Vec::new();
+        };
+        let target = download.justification.block_number;
+
+        let mut requests = Vec::new();
+        while download.in_flight.len() < MAX_CHUNKS_IN_FLIGHT {
+            let (from_block, count) = match download.retry.pop_first() {
+                Some(chunk) => chunk,
+                None if download.next <= target => {
+                    let count = (target - download.next + 1).min(self.batch_size as u64) as u32;
+                    download.next += count as u64;
+                    (download.next - count as u64, count)
+                }
+                None => break,
+            };
+
+            let busy = |peer: &PeerId| download.in_flight.values().filter(|chunk| chunk.peer == *peer).count();
+            let peer = fork.peers.iter().filter(|peer| busy(peer) < MAX_CHUNKS_PER_PEER).min_by_key(|peer| busy(peer)).copied();
+            let Some(peer) = peer else {
+                download.retry.insert(from_block, count);
+                break;
+            };
+
+            download.in_flight.insert(from_block, InFlightChunk { peer, count, sent_at: now });
+            requests.push(ChunkRequest { peer, from_block, max_blocks: count });
+        }
+        requests
+    }
+
+    /// Blocks answering a fork chunk request: returns the fork once it is
+    /// downloaded whole and leads to its justified block
+    pub fn on_fork_chunk(&mut self, peer: &PeerId, from_block: BlockNumber, blocks: Vec<Block>) -> Option<ForkDownloaded> {
+        let download = self.fork_download.as_mut()?;
+        let chunk = download.in_flight.remove(&from_block).filter(|chunk| chunk.peer == *peer)?;
+
+        // Consecutive blocks from the start of the chunk; the rest is requested again
+        let chunk_end = from_block + chunk.count as u64 - 1;
+        let mut next = from_block;
+        for block in blocks {
+            if block.header.number != next || next > chunk_end || BlockValidator::validate_standalone(&block).is_err() {
+                break;
+            }
+            download.blocks.insert(next, block);
+            next += 1;
+        }
+        if next <= chunk_end {
+            download.retry.insert(next, (chunk_end - next + 1) as u32);
+        }
+
+        if download.blocks.len() as u64 != download.justification.block_number - download.ancestor {
+            return None;
+        }
+        let download = self.fork_download.take()?;
+        let blocks: Vec<Block> = download.blocks.into_values().collect();
+        let linked = blocks.windows(2).all(|pair| pair[1].header.parent_hash == pair[0].hash())
+            && blocks.first().is_some_and(|first| first.hash() == download.branch)
+            && blocks.last().is_some_and(|last| last.hash() == download.justification.block_hash);
+        if !linked {
+            warn!(
+                "Fork {} does not lead to its justified block #{}, dropped",
+                download.branch, download.justification.block_number
+            );
+            self.forks.retain(|fork| fork.branch != download.branch);
+            return None;
+        }
+
+        Some(ForkDownloaded {
+            branch: download.branch,
+            ancestor: download.ancestor,
+            blocks,
+            justification: download.justification,
+        })
+    }
+
+    /// A fork chunk request failed: the chunk goes to another fork peer
+    pub fn on_fork_chunk_failed(&mut self, from_block: BlockNumber) {
+        if let Some(download) = self.fork_download.as_mut() {
+            if let Some(chunk) = download.in_flight.remove(&from_block) {
+                download.retry.insert(from_block, chunk.count);
+            }
+        }
+    }
+
+    /// Forget a fork (adopted, or rejected by the node)
+    pub fn forget_fork(&mut self, branch: &Hash) {
+        self.forks.retain(|fork| fork.branch != *branch);
+        if self.fork_download.as_ref().is_some_and(|download| download.branch == *branch) {
+            self.fork_download = None;
+        }
+    }
+
+    /// Known competing chains
+    pub fn forks(&self) -> &[ForkHead] {
+        &self.forks
+    }
+
+    /// Branch of the fork being downloaded
+    pub fn downloading_fork(&self) -> Option<Hash> {
+        self.fork_download.as_ref().map(|download| download.branch)
+    }
+
+    /// A peer extends the local chain again, or is gone: it leaves its
+    /// fork (dropped once no peer serves it)
+    pub fn leave_forks(&mut self, peer: &PeerId) {
+        self.ancestor_searches.remove(peer);
+        for fork in &mut self.forks {
+            fork.peers.retain(|p| p != peer);
+        }
+        self.forks.retain(|fork| !fork.peers.is_empty());
+        let fork_gone = self
+            .fork_download
+            .as_ref()
+            .is_some_and(|download| !self.forks.iter().any(|fork| fork.branch == download.branch));
+        if fork_gone {
+            self.fork_download = None;
+        }
+    }
+
+    /// Whether a peer serves a fork (or is being searched)
+    pub fn is_fork_peer(&self, peer: &PeerId) -> bool {
+        self.ancestor_searches.contains_key(peer) || self.forks.iter().any(|fork| fork.peers.contains(peer))
+    }
+
+    /// Start over from `height` after a reorg: blocks buffered or requested
+    /// for the previous chain are dropped
+    pub fn rewind(&mut self, height: BlockNumber) {
+        self.local_height = height;
+        self.pending_blocks.clear();
+        self.download_queue.clear();
+        self.in_flight.clear();
+        self.retry_chunks.clear();
+        self.next_chunk_start = height + 1;
+        self.update_state();
+    }
+
     /// Traite une réponse de sync
     /// Returns the number of blocks that were accepted
     pub fn handle_sync_response(&mut self, blocks: Vec<Block>, has_more: bool) -> usize {
@@ -247,6 +1056,11 @@ impl Default for SyncManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::network::request::JustificationRequest;
+    use crate::network::service::BlockProvider;
+    use crate::network::warp_sync::{SnapshotManifest, StateChunk};
+    use std::sync::Arc;
+    use tokio::sync::RwLock;
 
     #[test]
     fn test_sync_state_synced() {
@@ -307,9 +1121,11 @@ mod tests {
             parent_hash: Hash::ZERO,
             transactions_root: Hash::ZERO, // Empty transactions -> ZERO root
             state_root: Hash::ZERO,
+            receipts_root: Hash::ZERO,
             timestamp: 0,
             epoch: 0,
             slot: 0,
+            protocol_version: ProtocolVersion::default(),
             author,
             signature: Signature64([0; 64]),
         };
@@ -337,12 +1153,35 @@ mod tests {
         assert_eq!(sync.pending_count(), 0);
     }
 
-    #[test]
-    fn test_reject_invalid_signature_block() {
-        use ed25519_dalek::{SigningKey, Signer};
+    /// Local chain of authors without session keys
+    struct AuthorSigners;
 
-        let mut sync = SyncManager::new(100);
-        sync.peer_height_update(200);
+    impl BlockProvider for AuthorSigners {
+        fn get_blocks_range(&self, _from: BlockNumber, _max_count: u32) -> Vec<Block> {
+            Vec::new()
+        }
+        fn get_block_by_hash(&self, _hash: &Hash) -> Option<Block> {
+            None
+        }
+        fn get_block_by_number(&self, _number: BlockNumber) -> Option<Block> {
+            None
+        }
+        fn get_justification(&self, _request: &JustificationRequest) -> Option<FinalityJustification> {
+            None
+        }
+        fn get_warp_manifest(&self) -> Option<SnapshotManifest> {
+            None
+        }
+        fn get_warp_chunk(&self, _block_number: BlockNumber, _index: u32) -> Option<StateChunk> {
+            None
+        }
+        fn block_signer(&self, author: &AccountId, _epoch: EpochNumber) -> Option<AccountId> {
+            Some(*author)
+        }
+    }
+
+    fn badly_signed_block(number: BlockNumber) -> Block {
+        use ed25519_dalek::{SigningKey, Signer};
 
         // Create a valid keypair but sign with wrong data
         let signing_key = SigningKey::from_bytes(&[1u8; 32]);
@@ -351,29 +1190,48 @@ mod tests {
         // Sign with wrong message (not the header hash)
         let wrong_signature = signing_key.sign(b"wrong message");
 
-        // Block with mismatched signature should be rejected
-        let block = Block {
+        Block {
             header: BlockHeader {
-                number: 101,
+                number,
                 parent_hash: Hash::ZERO,
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 0,
                 epoch: 0,
                 slot: 0,
+                protocol_version: ProtocolVersion::default(),
                 author,
                 signature: Signature64(wrong_signature.to_bytes()), // Wrong signature
             },
             body: BlockBody {
                 transactions: vec![],
             },
-        };
+        }
+    }
 
-        // Block should be rejected due to invalid signature
-        assert!(!sync.add_downloaded_block(block));
+    #[test]
+    fn test_reject_invalid_signature_block() {
+        let mut sync = SyncManager::new(100);
+        sync.peer_height_update(200);
+        sync.set_block_provider(Arc::new(RwLock::new(AuthorSigners)));
+
+        // Block with mismatched signature should be rejected
+        assert!(!sync.add_downloaded_block(badly_signed_block(101)));
         assert_eq!(sync.pending_count(), 0);
     }
 
+    #[test]
+    fn test_signature_checked_on_import_when_signer_unknown() {
+        let mut sync = SyncManager::new(100);
+        sync.peer_height_update(200);
+
+        // A session key rotated in a block not imported yet may have signed
+        // it: queued, BlockValidator::validate checks it against the signer
+        assert!(sync.add_downloaded_block(badly_signed_block(101)));
+        assert_eq!(sync.pending_count(), 1);
+    }
+
     #[test]
     fn test_reject_block_behind_local_height() {
         use ed25519_dalek::{SigningKey, Signer};
@@ -391,9 +1249,11 @@ mod tests {
             parent_hash: Hash::ZERO,
             transactions_root: Hash::ZERO,
             state_root: Hash::ZERO,
+            receipts_root: Hash::ZERO,
             timestamp: 0,
             epoch: 0,
             slot: 0,
+            protocol_version: ProtocolVersion::default(),
             author,
             signature: Signature64([0; 64]),
         };
@@ -416,6 +1276,216 @@ mod tests {
         assert_eq!(sync.pending_count(), 0);
     }
 
+    fn signed_block(number: BlockNumber) -> Block {
+        use crate::types::signature::{domain_separate, DOMAIN_BLOCK_HEADER};
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let signing_key = SigningKey::from_bytes(&[1u8; 32]);
+        let mut header = BlockHeader {
+            number,
+            parent_hash: Hash::ZERO,
+            transactions_root: Hash::ZERO,
+            state_root: Hash::ZERO,
+            receipts_root: Hash::ZERO,
+            timestamp: 0,
+            epoch: 0,
+            slot: 0,
+            protocol_version: ProtocolVersion::default(),
+            author: AccountId::from_bytes(signing_key.verifying_key().to_bytes()),
+            signature: Signature64([0; 64]),
+        };
+        let message = domain_separate(DOMAIN_BLOCK_HEADER, header.hash().as_bytes());
+        header.signature = Signature64(signing_key.sign(&message).to_bytes());
+
+        Block { header, body: BlockBody { transactions: vec![] } }
+    }
+
+    fn blocks(range: std::ops::RangeInclusive<BlockNumber>) -> Vec<Block> {
+        range.map(signed_block).collect()
+    }
+
+    #[test]
+    fn test_parallel_chunk_scheduling() {
+        let mut sync = SyncManager::new(100);
+        sync.peer_height_update(2000);
+
+        let (a, b, c) = (PeerId::random(), PeerId::random(), PeerId::random());
+        let now = Instant::now();
+        let peers = [(c, 130), (a, 2000), (b, 2000)];
+        let requests = sync.schedule_chunks(&peers, now);
+
+        // Peer `c` only gets the part of the first chunk it has; the rest of
+        // the chunk and the next ones are spread over `a` and `b`
+        assert_eq!((requests[0].peer, requests[0].from_block, requests[0].max_blocks), (c, 101, 30));
+        assert_eq!(requests[1].from_block, 131);
+        assert_eq!(requests.len(), 1 + 2 * MAX_CHUNKS_PER_PEER);
+        assert_eq!(requests.iter().filter(|r| r.peer == a).count(), MAX_CHUNKS_PER_PEER);
+        assert_eq!(requests.iter().filter(|r| r.peer == b).count(), MAX_CHUNKS_PER_PEER);
+
+        // Everything in flight: nothing more to send
+        assert!(sync.schedule_chunks(&peers, now).is_empty());
+    }
+
+    #[test]
+    fn test_chunk_response_out_of_order() {
+        let mut sync = SyncManager::new(0);
+        sync.peer_height_update(100);
+
+        let (a, b) = (PeerId::random(), PeerId::random());
+        let now = Instant::now();
+        let requests = sync.schedule_chunks(&[(a, 100), (b, 100)], now);
+        assert_eq!(requests.len(), 2);
+
+        // Second chunk arrives first and waits in the buffer
+        let second = requests.iter().find(|r| r.from_block == 51).unwrap();
+        assert_eq!(sync.on_chunk_response(&second.peer, 51, blocks(51..=100), now), 50);
+        assert!(sync.next_block_to_import().is_none());
+
+        // First chunk delivered partially: the rest is requested again
+        let first = requests.iter().find(|r| r.from_block == 1).unwrap();
+        assert_eq!(sync.on_chunk_response(&first.peer, 1, blocks(1..=30), now + Duration::from_secs(1)), 30);
+        assert_eq!(sync.next_block_to_import().unwrap().header.number, 1);
+        sync.update_local_height(1);
+
+        let retry = sync.schedule_chunks(&[(a, 100), (b, 100)], now);
+        assert_eq!(retry.len(), 1);
+        assert_eq!((retry[0].from_block, retry[0].max_blocks), (31, 20));
+        assert!(sync.peer_throughput(&first.peer).unwrap().blocks_per_sec.is_some());
+    }
+
+    #[test]
+    fn test_failed_chunks_move_to_other_peers() {
+        let mut sync = SyncManager::new(0);
+        sync.peer_height_update(50);
+
+        let (slow, fast) = (PeerId::random(), PeerId::random());
+        let now = Instant::now();
+
+        // `fast` delivers quickly, `slow` keeps timing out
+        sync.peer_throughput.entry(fast).or_default().record_delivery(50, Duration::from_millis(100));
+        for _ in 0..MAX_CONSECUTIVE_FAILURES {
+            let requests = sync.schedule_chunks(&[(slow, 50)], now);
+            assert_eq!(requests[0].peer, slow);
+            assert_eq!(sync.expire_chunks(now + CHUNK_TIMEOUT), vec![slow]);
+        }
+
+        // The chunk goes to the remaining peer
+        let requests = sync.schedule_chunks(&[(slow, 50), (fast, 50)], now);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].peer, fast);
+        assert_eq!(requests[0].from_block, 1);
+
+        // A disconnected peer's chunks are requested again
+        sync.peer_disconnected(&fast);
+        assert_eq!(sync.in_flight_count(), 0);
+        assert_eq!(sync.schedule_chunks(&[(slow, 50)], now).len(), 1);
+    }
+
+    /// Chain of blocks from `parent`; the timestamp tells chains apart
+    /// (signatures are checked on import, not here)
+    fn linked_blocks(range: std::ops::RangeInclusive<BlockNumber>, parent: Hash, timestamp: u64) -> Vec<Block> {
+        let mut parent = parent;
+        range
+            .map(|number| {
+                let mut block = signed_block(number);
+                block.header.parent_hash = parent;
+                block.header.timestamp = timestamp;
+                parent = block.hash();
+                block
+            })
+            .collect()
+    }
+
+    /// Local chain up to #300, and a fork of #200 up to #320
+    fn competing_chains() -> (Vec<Block>, Vec<Block>) {
+        let local = linked_blocks(1..=300, Hash::ZERO, 0);
+        let mut fork = local[..200].to_vec();
+        fork.extend(linked_blocks(201..=320, local[199].hash(), 1));
+        (local, fork)
+    }
+
+    fn search_ancestor(sync: &mut SyncManager, peer: PeerId, local: &[Block], fork: &[Block], head: BlockNumber) -> AncestorProbe {
+        let hash_at = |chain: &[Block], number: BlockNumber| chain.get(number as usize - 1).map(|block| block.hash());
+        let mut probe = sync.on_competing_block(peer, &fork[head as usize - 1].header).unwrap();
+        while let AncestorProbe::Request(number) = probe {
+            probe = sync.on_ancestor_probe(&peer, number, hash_at(fork, number), hash_at(local, number));
+        }
+        probe
+    }
+
+    #[test]
+    fn test_common_ancestor_search() {
+        let (local, fork) = competing_chains();
+        let mut sync = SyncManager::new(300);
+        sync.peer_height_update(320);
+        let peer = PeerId::random();
+
+        let found = search_ancestor(&mut sync, peer, &local, &fork, 301);
+        assert_eq!(found, AncestorProbe::Found { ancestor: 200, branch: fork[200].hash() });
+        assert_eq!(sync.forks().len(), 1);
+        assert_eq!(sync.forks()[0].common_ancestor, 200);
+
+        // Known fork: its head moves, no new search, and the peer is left out
+        // of the regular sync
+        assert!(sync.on_competing_block(peer, &fork[319].header).is_none());
+        assert_eq!(sync.forks()[0].number, 320);
+        assert!(sync.schedule_chunks(&[(peer, 320)], Instant::now()).is_empty());
+
+        // A block the peer does not serve ends the search
+        let other = PeerId::random();
+        let probe = sync.on_competing_block(other, &fork[300].header).unwrap();
+        let AncestorProbe::Request(number) = probe else { panic!("expected a probe") };
+        assert_eq!(sync.on_ancestor_probe(&other, number, None, Some(local[0].hash())), AncestorProbe::Abandoned);
+        assert!(!sync.is_fork_peer(&other));
+    }
+
+    #[test]
+    fn test_fork_download() {
+        let (local, fork) = competing_chains();
+        let mut sync = SyncManager::new(300);
+        let (a, b) = (PeerId::random(), PeerId::random());
+        let now = Instant::now();
+        search_ancestor(&mut sync, a, &local, &fork, 301);
+        search_ancestor(&mut sync, b, &local, &fork, 320);
+        assert_eq!(sync.forks().len(), 1);
+        assert_eq!(sync.forks()[0].peers, vec![a, b]);
+
+        let branch = fork[200].hash();
+        assert_eq!(sync.fork_to_check(&a, now), Some(branch));
+        assert_eq!(sync.fork_to_check(&b, now), None);
+        assert_eq!(sync.fork_to_check(&b, now + FORK_RECHECK_INTERVAL), Some(branch));
+
+        let justification = |number: BlockNumber| FinalityJustification {
+            block_number: number,
+            block_hash: fork[number as usize - 1].hash(),
+            signatures: vec![],
+            epoch: 0,
+            round: 0,
+        };
+        // Nothing finalized past the common ancestor: no download
+        assert!(!sync.on_fork_justification(&branch, justification(150)));
+        assert!(sync.on_fork_justification(&branch, justification(260)));
+        assert_eq!(sync.downloading_fork(), Some(branch));
+
+        // Spread over both fork peers
+        let requests = sync.schedule_fork_chunks(now);
+        assert_eq!(requests.len(), 2);
+        assert_eq!((requests[0].from_block, requests[0].max_blocks), (201, 50));
+        assert_eq!((requests[1].from_block, requests[1].max_blocks), (251, 10));
+        assert_ne!(requests[0].peer, requests[1].peer);
+
+        assert!(sync.on_fork_chunk(&requests[1].peer, 251, fork[250..260].to_vec()).is_none());
+        assert!(sync.on_fork_chunk(&requests[0].peer, 201, fork[200..230].to_vec()).is_none());
+        let retry = sync.schedule_fork_chunks(now);
+        assert_eq!((retry[0].from_block, retry[0].max_blocks), (231, 20));
+
+        let downloaded = sync.on_fork_chunk(&retry[0].peer, 231, fork[230..250].to_vec()).unwrap();
+        assert_eq!(downloaded.ancestor, 200);
+        assert_eq!(downloaded.blocks.len(), 60);
+        assert_eq!(downloaded.blocks.last().unwrap().hash(), downloaded.justification.block_hash);
+        assert_eq!(sync.downloading_fork(), None);
+    }
+
     #[test]
     fn test_sync_gap() {
         let mut sync = SyncManager::new(100);
@@ -8,13 +8,56 @@
 
 // SECURITY FIX #37: Merkle proof verification for warp sync chunks
 // Prevents malicious peers from injecting fake state data during warp sync
-
-use crate::types::{AccountId, AccountInfo, BlockNumber, Hash, StateRoot, Blake3Hasher};
+//
+// Snapshots are taken by validators every SNAPSHOT_INTERVAL blocks and served
+// in chunks. The snapshot block is trusted only through a finality
+// justification, and the downloaded accounts must rebuild that block's
+// state root before they are imported.
+//
+// A valid justification is not enough on its own: the finalized head it
+// proves must also be corroborated by MIN_TRUST_ANCHORS of the DNS seeds'
+// network state, the configured checkpoint and a majority of connected peers.
+
+use crate::types::{
+    AccountId, AccountInfo, Block, BlockHeader, BlockNumber, FinalityJustification, Hash, StateMerkleTree,
+    StateRoot, Blake3Hasher,
+};
+use libp2p::PeerId;
 use rs_merkle::Hasher;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
+/// Snapshots are taken at blocks that are a multiple of this
+pub const SNAPSHOT_INTERVAL: BlockNumber = 1000;
+
+/// Accounts per snapshot chunk
+pub const SNAPSHOT_CHUNK_SIZE: usize = 256;
+
+/// Snapshots kept in memory by a serving node
+pub const MAX_STORED_SNAPSHOTS: usize = 2;
+
+/// Most headers between the snapshot block and its justification
+pub const MAX_MANIFEST_HEADERS: usize = 1000;
+
+/// Chunk requests outstanding at once
+pub const MAX_CHUNKS_IN_FLIGHT: usize = 8;
+
+/// Time after which an unanswered chunk request is sent again
+pub const CHUNK_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Manifest file in the progress directory
+const MANIFEST_FILE: &str = "manifest.bin";
+
+/// Independent sources that must agree on a snapshot's finalized head
+pub const MIN_TRUST_ANCHORS: usize = 2;
+
+/// Largest distance between the finalized head of a snapshot and the best
+/// height reported by the DNS seeds (heartbeats lag behind the chain)
+pub const SEED_HEIGHT_TOLERANCE: BlockNumber = 600;
+
 /// Warp sync state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WarpSyncState {
@@ -65,6 +108,10 @@ pub enum WarpSyncError {
     InvalidChunk,
     /// Merkle proof verification failed (SECURITY FIX #37)
     InvalidMerkleProof,
+    /// Manifest blocks, headers and justification do not link up
+    InvalidManifest,
+    /// Justification lacks a supermajority of the known validators
+    InvalidJustification,
 }
 
 /// State snapshot header (metadata)
@@ -398,6 +445,179 @@ impl StateSnapshot {
     pub fn account_count(&self) -> usize {
         self.chunks.iter().map(|c| c.accounts.len()).sum()
     }
+
+    /// Account state root of the snapshot, as computed by the state backend
+    ///
+    /// Leaves are the encoded accounts in account ID order, so this equals the
+    /// `state_root` of the snapshot block's header for a genuine snapshot.
+    pub fn account_state_root(&self) -> Hash {
+        let mut accounts: Vec<_> = self.accounts().collect();
+        accounts.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+        accounts.dedup_by(|a, b| a.0 == b.0);
+
+        let leaves = accounts
+            .into_iter()
+            .map(|(_, info)| bincode::serialize(info).expect("AccountInfo serialization cannot fail"))
+            .collect();
+        StateMerkleTree::new(leaves).root()
+    }
+}
+
+/// Everything a warp syncing node needs before downloading chunks
+///
+/// The snapshot block is linked by its descendants' headers to a finalized block,
+/// whose justification is the only thing the downloading node has to trust.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    /// Snapshot metadata (chunk count and chunks root)
+    pub header: StateSnapshotHeader,
+
+    /// Block the snapshot was taken at
+    pub block: Block,
+
+    /// Headers from the block after the snapshot up to the justified block
+    pub headers: Vec<BlockHeader>,
+
+    /// Justification of the last of `headers` (or of `block` if there are none)
+    pub justification: FinalityJustification,
+}
+
+impl SnapshotManifest {
+    /// Check that the snapshot block is finalized by `validators`
+    pub fn verify(&self, validators: &HashSet<AccountId>) -> Result<(), WarpSyncError> {
+        if self.block.header.number != self.header.block_number || self.block.hash() != self.header.block_hash {
+            return Err(WarpSyncError::InvalidManifest);
+        }
+        if self.headers.len() > MAX_MANIFEST_HEADERS {
+            return Err(WarpSyncError::InvalidManifest);
+        }
+
+        let mut justified = &self.block.header;
+        for header in &self.headers {
+            if header.number != justified.number + 1 || header.parent_hash != justified.hash() {
+                return Err(WarpSyncError::InvalidManifest);
+            }
+            justified = header;
+        }
+
+        if self.justification.block_number != justified.number || self.justification.block_hash != justified.hash() {
+            return Err(WarpSyncError::InvalidManifest);
+        }
+        if !self.justification.verify_with_validators(validators) {
+            return Err(WarpSyncError::InvalidJustification);
+        }
+        Ok(())
+    }
+
+    /// Whether two manifests describe the same snapshot
+    pub fn same_snapshot(&self, other: &SnapshotManifest) -> bool {
+        self.header.block_hash == other.header.block_hash
+            && self.header.state_root.root == other.header.state_root.root
+            && self.header.total_chunks == other.header.total_chunks
+    }
+
+    /// Finalized head proven by the justification
+    pub fn finalized_head(&self) -> (BlockNumber, Hash) {
+        (self.justification.block_number, self.justification.block_hash)
+    }
+
+    /// Hash of the block at `number` on the chain carried by the manifest
+    pub fn chain_hash(&self, number: BlockNumber) -> Option<Hash> {
+        if number == self.block.header.number {
+            return Some(self.block.hash());
+        }
+        self.headers.iter().find(|h| h.number == number).map(|h| h.hash())
+    }
+
+    /// Whether the finalized head of one manifest is on the other's chain
+    pub fn agrees_with(&self, other: &SnapshotManifest) -> bool {
+        let (number, hash) = other.finalized_head();
+        let (own_number, own_hash) = self.finalized_head();
+        self.chain_hash(number) == Some(hash) || other.chain_hash(own_number) == Some(own_hash)
+    }
+}
+
+/// Finalized block trusted by the operator (`--warp-checkpoint <hash:number>`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WarpCheckpoint {
+    pub number: BlockNumber,
+    pub hash: Hash,
+}
+
+/// Source corroborating the finalized head of a snapshot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustAnchor {
+    /// Best height reported by the DNS seeds is close to the finalized head
+    DnsSeed,
+
+    /// The configured checkpoint is on the manifest's chain
+    Checkpoint,
+
+    /// Most connected peers offer a manifest agreeing with it
+    PeerMajority,
+}
+
+/// Views of the network a snapshot is checked against
+#[derive(Debug, Clone, Default)]
+pub struct TrustContext {
+    /// Best height reported by the DNS seeds for our genesis
+    pub seed_best_height: Option<BlockNumber>,
+
+    /// Currently connected peers
+    pub connected_peers: HashSet<PeerId>,
+}
+
+/// Recent snapshots kept by a serving node
+#[derive(Debug, Default)]
+pub struct SnapshotStore {
+    /// Whether this node takes snapshots
+    enabled: bool,
+
+    /// Snapshots, oldest first
+    snapshots: VecDeque<StateSnapshot>,
+}
+
+impl SnapshotStore {
+    /// Start taking snapshots
+    pub fn enable(&mut self) {
+        self.enabled = true;
+    }
+
+    /// Whether this node takes snapshots
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Whether a snapshot should be taken after `block_number`
+    pub fn is_due(&self, block_number: BlockNumber) -> bool {
+        self.enabled
+            && block_number > 0
+            && block_number.is_multiple_of(SNAPSHOT_INTERVAL)
+            && self.get(block_number).is_none()
+    }
+
+    /// Keep a snapshot, dropping the oldest beyond MAX_STORED_SNAPSHOTS
+    pub fn insert(&mut self, snapshot: StateSnapshot) {
+        self.snapshots.push_back(snapshot);
+        while self.snapshots.len() > MAX_STORED_SNAPSHOTS {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Snapshot taken at a block
+    pub fn get(&self, block_number: BlockNumber) -> Option<&StateSnapshot> {
+        self.snapshots.iter().find(|s| s.header.block_number == block_number)
+    }
+
+    /// Snapshots, newest first
+    pub fn newest_first(&self) -> impl Iterator<Item = &StateSnapshot> {
+        self.snapshots.iter().rev()
+    }
+
+    /// Chunk of the snapshot taken at a block
+    pub fn chunk(&self, block_number: BlockNumber, index: u32) -> Option<&StateChunk> {
+        self.get(block_number)?.chunks.get(index as usize)
+    }
 }
 
 /// Warp sync manager
@@ -422,6 +642,30 @@ pub struct WarpSyncManager {
 
     /// Maximum snapshot age in blocks
     max_snapshot_age: u64,
+
+    /// Verified manifest of the snapshot being downloaded
+    manifest: Option<SnapshotManifest>,
+
+    /// Chunk requests not answered yet, with the time they were sent
+    in_flight: HashMap<u32, Instant>,
+
+    /// Last time the download moved forward
+    last_progress: Instant,
+
+    /// Peers known to serve the snapshot being downloaded
+    sources: Vec<PeerId>,
+
+    /// Directory where the download is persisted (None = memory only)
+    data_dir: Option<PathBuf>,
+
+    /// Operator-supplied finalized block used as a trust anchor
+    checkpoint: Option<WarpCheckpoint>,
+
+    /// Whether the checkpoint alone is trusted (`--sync-from-checkpoint`)
+    checkpoint_required: bool,
+
+    /// Verified manifests offered by each peer, awaiting corroboration
+    offers: HashMap<PeerId, SnapshotManifest>,
 }
 
 impl WarpSyncManager {
@@ -435,9 +679,107 @@ impl WarpSyncManager {
             snapshot_header: None,
             received_chunks: HashMap::new(),
             max_snapshot_age: 10000, // Max 10k blocks old
+            manifest: None,
+            in_flight: HashMap::new(),
+            last_progress: Instant::now(),
+            sources: Vec::new(),
+            data_dir: None,
+            checkpoint: None,
+            checkpoint_required: false,
+            offers: HashMap::new(),
         }
     }
 
+    /// Create a manager that persists its download in `data_dir`
+    ///
+    /// A download interrupted by a restart is resumed: the manifest and the
+    /// chunks already received are reloaded and only the missing chunks are
+    /// requested again.
+    pub fn with_data_dir(local_height: BlockNumber, data_dir: PathBuf) -> Self {
+        let mut manager = Self::new(local_height);
+        manager.data_dir = Some(data_dir);
+        manager.resume();
+        manager
+    }
+
+    /// Reload a persisted download
+    fn resume(&mut self) {
+        let Some(dir) = self.data_dir.clone() else {
+            return;
+        };
+        let Ok(data) = std::fs::read(dir.join(MANIFEST_FILE)) else {
+            return;
+        };
+        let manifest: SnapshotManifest = match bincode::deserialize(&data) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                warn!("Discarding unreadable warp sync progress: {}", e);
+                self.clear_progress();
+                return;
+            }
+        };
+
+        self.snapshot_header = Some(manifest.header.clone());
+        self.network_height = self.network_height.max(manifest.header.block_number);
+        for index in 0..manifest.header.total_chunks {
+            let Ok(data) = std::fs::read(dir.join(Self::chunk_file(index))) else {
+                continue;
+            };
+            match bincode::deserialize::<StateChunk>(&data) {
+                Ok(chunk) if chunk.index == index
+                    && chunk.verify_merkle_proof(&manifest.header.state_root.root, manifest.header.total_chunks) =>
+                {
+                    self.received_chunks.insert(index, chunk);
+                }
+                _ => warn!("Discarding invalid persisted warp sync chunk {}", index),
+            }
+        }
+
+        let received = self.received_chunks.len() as u32;
+        self.state = if received == manifest.header.total_chunks {
+            WarpSyncState::VerifyingState
+        } else {
+            WarpSyncState::DownloadingState {
+                total_chunks: manifest.header.total_chunks,
+                received,
+            }
+        };
+        info!(
+            "📦 Resuming warp sync to block #{}: {}/{} chunks already downloaded",
+            manifest.header.block_number, received, manifest.header.total_chunks
+        );
+        self.manifest = Some(manifest);
+    }
+
+    /// Write a file of the persisted download
+    fn persist(&self, file: &str, data: &impl Serialize) {
+        let Some(ref dir) = self.data_dir else {
+            return;
+        };
+        let result = std::fs::create_dir_all(dir).and_then(|_| {
+            let bytes = bincode::serialize(data).map_err(std::io::Error::other)?;
+            std::fs::write(dir.join(file), bytes)
+        });
+        if let Err(e) = result {
+            warn!("Failed to persist warp sync progress: {}", e);
+        }
+    }
+
+    /// Remove the persisted download
+    fn clear_progress(&self) {
+        if let Some(ref dir) = self.data_dir {
+            if dir.exists() {
+                if let Err(e) = std::fs::remove_dir_all(dir) {
+                    warn!("Failed to remove warp sync progress: {}", e);
+                }
+            }
+        }
+    }
+
+    fn chunk_file(index: u32) -> String {
+        format!("chunk_{}.bin", index)
+    }
+
     /// Update local height
     pub fn update_local_height(&mut self, height: BlockNumber) {
         self.local_height = height;
@@ -454,15 +796,32 @@ impl WarpSyncManager {
 
     /// Check if warp sync is needed
     fn check_warp_needed(&mut self) {
+        if self.state != WarpSyncState::Inactive {
+            return;
+        }
         let gap = self.network_height.saturating_sub(self.local_height);
 
-        if self.state == WarpSyncState::Inactive && gap > self.warp_threshold {
+        if gap > self.warp_threshold {
             info!(
                 "📡 Warp sync triggered: gap={} blocks (threshold={})",
                 gap, self.warp_threshold
             );
-            self.state = WarpSyncState::RequestingSnapshot;
+        } else if let Some(checkpoint) = self.pending_sync_checkpoint() {
+            info!("📡 Warp sync triggered: syncing from checkpoint #{}", checkpoint.number);
+        } else {
+            return;
         }
+        self.state = WarpSyncState::RequestingSnapshot;
+        self.last_progress = Instant::now();
+    }
+
+    /// Required checkpoint that is ahead of us and reached by the network
+    fn pending_sync_checkpoint(&self) -> Option<WarpCheckpoint> {
+        self.checkpoint.filter(|checkpoint| {
+            self.checkpoint_required
+                && self.local_height < checkpoint.number
+                && self.network_height >= checkpoint.number
+        })
     }
 
     /// Get current state
@@ -498,6 +857,8 @@ impl WarpSyncManager {
 
         self.snapshot_header = Some(header.clone());
         self.received_chunks.clear();
+        self.in_flight.clear();
+        self.last_progress = Instant::now();
         self.state = WarpSyncState::DownloadingState {
             total_chunks: header.total_chunks,
             received: 0,
@@ -528,6 +889,9 @@ impl WarpSyncManager {
 
         debug!("📥 Received and verified chunk {}/{}", chunk.index + 1, header.total_chunks);
 
+        self.in_flight.remove(&chunk.index);
+        self.last_progress = Instant::now();
+        self.persist(&Self::chunk_file(chunk.index), &chunk);
         self.received_chunks.insert(chunk.index, chunk);
 
         // Update state
@@ -585,11 +949,198 @@ impl WarpSyncManager {
     pub fn complete(&mut self) {
         info!("🎉 Warp sync complete!");
         self.state = WarpSyncState::Complete;
+        self.clear_progress();
     }
 
     /// Mark warp sync as failed
     pub fn fail(&mut self, error: WarpSyncError) {
         self.state = WarpSyncState::Failed(error);
+        self.reset_download();
+    }
+
+    /// Drop the download and ask for a new snapshot
+    ///
+    /// Used when the snapshot being downloaded is no longer served.
+    pub fn restart(&mut self) {
+        info!("📡 Restarting warp sync with a new snapshot");
+        self.state = WarpSyncState::RequestingSnapshot;
+        self.reset_download();
+    }
+
+    fn reset_download(&mut self) {
+        self.snapshot_header = None;
+        self.manifest = None;
+        self.received_chunks.clear();
+        self.in_flight.clear();
+        self.sources.clear();
+        self.offers.clear();
+        self.last_progress = Instant::now();
+        self.clear_progress();
+    }
+
+    /// Accept a manifest whose justification has been verified
+    ///
+    /// The manifest is persisted so the download survives restarts.
+    pub fn handle_manifest(&mut self, manifest: SnapshotManifest) -> Result<(), WarpSyncError> {
+        if self.state != WarpSyncState::RequestingSnapshot {
+            return Err(WarpSyncError::InvalidManifest);
+        }
+        self.clear_progress();
+        self.handle_snapshot_header(manifest.header.clone())?;
+        self.persist(MANIFEST_FILE, &manifest);
+        self.manifest = Some(manifest);
+        Ok(())
+    }
+
+    /// Trust the given finalized block when corroborating snapshots
+    pub fn set_checkpoint(&mut self, checkpoint: WarpCheckpoint) {
+        self.checkpoint = Some(checkpoint);
+        self.checkpoint_required = false;
+    }
+
+    /// Sync from the given finalized block, skipping the blocks before it
+    ///
+    /// Only snapshots whose chain contains the checkpoint are accepted, and
+    /// the checkpoint is enough to accept them without other trust anchors.
+    /// Their justification must still verify, so finality is checked forward
+    /// from the checkpoint.
+    pub fn set_sync_checkpoint(&mut self, checkpoint: WarpCheckpoint) {
+        self.checkpoint = Some(checkpoint);
+        self.checkpoint_required = true;
+        self.check_warp_needed();
+    }
+
+    /// Record a manifest whose justification has been verified
+    ///
+    /// It is only downloaded once `accept_corroborated` finds enough trust
+    /// anchors for it.
+    pub fn offer_manifest(&mut self, peer: PeerId, manifest: SnapshotManifest) {
+        if self.state == WarpSyncState::RequestingSnapshot {
+            self.offers.insert(peer, manifest);
+        }
+    }
+
+    /// Sources that corroborate the finalized head of `manifest`
+    pub fn trust_anchors(&self, manifest: &SnapshotManifest, context: &TrustContext) -> Vec<TrustAnchor> {
+        let mut anchors = Vec::new();
+        let (number, _) = manifest.finalized_head();
+
+        if let Some(seed_height) = context.seed_best_height {
+            if number.abs_diff(seed_height) <= SEED_HEIGHT_TOLERANCE {
+                anchors.push(TrustAnchor::DnsSeed);
+            }
+        }
+        if let Some(checkpoint) = self.checkpoint {
+            if manifest.chain_hash(checkpoint.number) == Some(checkpoint.hash) {
+                anchors.push(TrustAnchor::Checkpoint);
+            }
+        }
+
+        let agreeing = self
+            .offers
+            .iter()
+            .filter(|(peer, offer)| context.connected_peers.contains(peer) && offer.agrees_with(manifest))
+            .count();
+        if agreeing * 2 > context.connected_peers.len() {
+            anchors.push(TrustAnchor::PeerMajority);
+        }
+        anchors
+    }
+
+    /// Whether a snapshot with these anchors may be downloaded
+    fn is_trusted(&self, anchors: &[TrustAnchor]) -> bool {
+        if self.checkpoint_required {
+            return anchors.contains(&TrustAnchor::Checkpoint);
+        }
+        anchors.len() >= MIN_TRUST_ANCHORS
+    }
+
+    /// Start downloading the highest offered snapshot with enough trust anchors
+    ///
+    /// Every peer offering that snapshot becomes a source. Returns the anchors
+    /// of the accepted snapshot, or None if no offer is corroborated yet.
+    pub fn accept_corroborated(&mut self, context: &TrustContext) -> Option<Vec<TrustAnchor>> {
+        if self.state != WarpSyncState::RequestingSnapshot {
+            return None;
+        }
+        let (manifest, anchors) = self
+            .offers
+            .values()
+            .map(|offer| (offer, self.trust_anchors(offer, context)))
+            .filter(|(_, anchors)| self.is_trusted(anchors))
+            .max_by_key(|(offer, _)| offer.finalized_head().0)
+            .map(|(offer, anchors)| (offer.clone(), anchors))?;
+
+        let sources: Vec<PeerId> = self
+            .offers
+            .iter()
+            .filter(|(_, offer)| offer.same_snapshot(&manifest))
+            .map(|(peer, _)| *peer)
+            .collect();
+        if self.handle_manifest(manifest).is_err() {
+            return None;
+        }
+        self.offers.clear();
+        for peer in sources {
+            self.add_source(peer);
+        }
+        Some(anchors)
+    }
+
+    /// Manifest of the snapshot being downloaded
+    pub fn manifest(&self) -> Option<&SnapshotManifest> {
+        self.manifest.as_ref()
+    }
+
+    /// Chunks to request now, marked as in flight
+    ///
+    /// Requests unanswered for CHUNK_REQUEST_TIMEOUT are sent again.
+    pub fn next_chunk_requests(&mut self) -> Vec<u32> {
+        if !matches!(self.state, WarpSyncState::DownloadingState { .. }) {
+            return vec![];
+        }
+        self.in_flight.retain(|_, sent_at| sent_at.elapsed() < CHUNK_REQUEST_TIMEOUT);
+
+        let free = MAX_CHUNKS_IN_FLIGHT.saturating_sub(self.in_flight.len());
+        let indices: Vec<u32> = self
+            .chunks_needed()
+            .into_iter()
+            .filter(|i| !self.in_flight.contains_key(i))
+            .take(free)
+            .collect();
+
+        let now = Instant::now();
+        for index in &indices {
+            self.in_flight.insert(*index, now);
+        }
+        indices
+    }
+
+    /// Record a peer serving the snapshot being downloaded
+    pub fn add_source(&mut self, peer: PeerId) {
+        if !self.sources.contains(&peer) {
+            self.sources.push(peer);
+        }
+    }
+
+    /// Forget a peer that no longer serves the snapshot
+    pub fn remove_source(&mut self, peer: &PeerId) {
+        self.sources.retain(|p| p != peer);
+    }
+
+    /// Peers known to serve the snapshot being downloaded
+    pub fn sources(&self) -> &[PeerId] {
+        &self.sources
+    }
+
+    /// Forget a chunk request that will not be answered
+    pub fn chunk_failed(&mut self, index: u32) {
+        self.in_flight.remove(&index);
+    }
+
+    /// Whether the download has not moved forward for `timeout`
+    pub fn is_stalled(&self, timeout: Duration) -> bool {
+        self.last_progress.elapsed() >= timeout
     }
 
     /// Get chunks still needed
@@ -947,4 +1498,205 @@ mod tests {
             );
         }
     }
+
+    fn test_header(number: BlockNumber, parent_hash: Hash) -> BlockHeader {
+        let mut header = Block::genesis(Hash::ZERO, vec![]).header;
+        header.number = number;
+        header.parent_hash = parent_hash;
+        header
+    }
+
+    fn test_manifest(snapshot: &StateSnapshot, key: &ed25519_dalek::SigningKey) -> SnapshotManifest {
+        use crate::types::{Signature64, ValidatorSignature};
+        use ed25519_dalek::Signer;
+
+        let block = Block::new(test_header(snapshot.header.block_number, Hash::ZERO), crate::types::BlockBody::new(vec![]));
+        let child = test_header(block.header.number + 1, block.hash());
+        let mut justification = FinalityJustification {
+            block_number: child.number,
+            block_hash: child.hash(),
+            signatures: vec![],
+            epoch: 0,
+            round: 1,
+        };
+        let signature = key.sign(&justification.signing_message());
+        justification.signatures.push(ValidatorSignature {
+            validator: AccountId::from_bytes(key.verifying_key().to_bytes()),
+            signature: Signature64::from_bytes(signature.to_bytes()),
+        });
+
+        let mut header = snapshot.header.clone();
+        header.block_hash = block.hash();
+        SnapshotManifest { header, block, headers: vec![child], justification }
+    }
+
+    #[test]
+    fn test_manifest_verification() {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let validators: HashSet<AccountId> = [AccountId::from_bytes(key.verifying_key().to_bytes())].into();
+        let manifest = test_manifest(&create_test_snapshot(1000, 10), &key);
+        assert!(manifest.verify(&validators).is_ok());
+
+        // Signed by a validator we don't know
+        let other: HashSet<AccountId> = [AccountId::from_bytes([9u8; 32])].into();
+        assert_eq!(manifest.verify(&other), Err(WarpSyncError::InvalidJustification));
+
+        // Headers that don't link the snapshot block to the justified block
+        let mut broken = manifest.clone();
+        broken.headers[0].parent_hash = Hash::hash(b"elsewhere");
+        assert_eq!(broken.verify(&validators), Err(WarpSyncError::InvalidManifest));
+
+        // Snapshot header describing another block
+        let mut broken = manifest;
+        broken.header.block_number += 1;
+        assert_eq!(broken.verify(&validators), Err(WarpSyncError::InvalidManifest));
+    }
+
+    #[test]
+    fn test_account_state_root_ignores_chunk_order() {
+        let snapshot = create_test_snapshot(1000, 300);
+        let mut reversed = snapshot.clone();
+        reversed.chunks.reverse();
+        assert_eq!(snapshot.account_state_root(), reversed.account_state_root());
+        assert_ne!(snapshot.account_state_root(), create_test_snapshot(1000, 299).account_state_root());
+    }
+
+    #[test]
+    fn test_resume_after_restart() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = temp_dir.path().join("warp_sync");
+        let key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let snapshot = create_test_snapshot(1500, 300); // 3 chunks
+
+        {
+            let mut manager = WarpSyncManager::with_data_dir(0, dir.clone());
+            manager.peer_height_update(2000);
+            manager.handle_manifest(test_manifest(&snapshot, &key)).unwrap();
+            manager.handle_state_chunk(snapshot.chunks[0].clone()).unwrap();
+            manager.handle_state_chunk(snapshot.chunks[2].clone()).unwrap();
+        }
+
+        // Restarted node picks up where it left off
+        let mut manager = WarpSyncManager::with_data_dir(0, dir.clone());
+        assert_eq!(manager.state(), WarpSyncState::DownloadingState { total_chunks: 3, received: 2 });
+        assert_eq!(manager.chunks_needed(), vec![1]);
+        assert_eq!(manager.manifest().unwrap().header.block_number, 1500);
+
+        manager.handle_state_chunk(snapshot.chunks[1].clone()).unwrap();
+        let restored = manager.verify_state().unwrap();
+        assert_eq!(restored.account_state_root(), snapshot.account_state_root());
+
+        manager.complete();
+        assert!(!dir.exists());
+        assert_eq!(WarpSyncManager::with_data_dir(0, dir).state(), WarpSyncState::Inactive);
+    }
+
+    #[test]
+    fn test_trust_anchors() {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let manifest = test_manifest(&create_test_snapshot(1000, 10), &key);
+        let (head_number, head_hash) = manifest.finalized_head();
+        let peers: Vec<PeerId> = (0..3).map(|_| PeerId::random()).collect();
+
+        let mut manager = WarpSyncManager::new(0);
+        manager.peer_height_update(5000);
+        assert_eq!(manager.state(), WarpSyncState::RequestingSnapshot);
+        manager.offer_manifest(peers[0], manifest.clone());
+        manager.offer_manifest(peers[1], manifest.clone());
+
+        // Two of three connected peers agree, but that is a single anchor
+        let mut context = TrustContext {
+            seed_best_height: None,
+            connected_peers: peers.iter().copied().collect(),
+        };
+        assert_eq!(manager.trust_anchors(&manifest, &context), vec![TrustAnchor::PeerMajority]);
+        assert!(manager.accept_corroborated(&context).is_none());
+
+        // DNS seeds far ahead of the finalized head don't corroborate it
+        context.seed_best_height = Some(head_number + SEED_HEIGHT_TOLERANCE + 1);
+        assert!(manager.accept_corroborated(&context).is_none());
+
+        // Checkpoints must be on the manifest's chain
+        manager.set_checkpoint(WarpCheckpoint { number: head_number, hash: Hash::hash(b"fork") });
+        assert!(manager.accept_corroborated(&context).is_none());
+        manager.set_checkpoint(WarpCheckpoint { number: head_number, hash: head_hash });
+        assert_eq!(
+            manager.trust_anchors(&manifest, &context),
+            vec![TrustAnchor::Checkpoint, TrustAnchor::PeerMajority]
+        );
+
+        context.seed_best_height = Some(head_number + 20);
+        let anchors = manager.accept_corroborated(&context).unwrap();
+        assert_eq!(anchors.len(), 3);
+        assert_eq!(manager.sources().len(), 2);
+        assert!(manager.manifest().unwrap().same_snapshot(&manifest));
+    }
+
+    #[test]
+    fn test_sync_from_checkpoint() {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let manifest = test_manifest(&create_test_snapshot(1000, 10), &key);
+        let (head_number, head_hash) = manifest.finalized_head();
+        let peer = PeerId::random();
+        let context = TrustContext {
+            seed_best_height: Some(head_number),
+            connected_peers: [peer].into(),
+        };
+
+        // Less than the warp threshold behind, but below the checkpoint
+        let mut manager = WarpSyncManager::new(900);
+        manager.set_sync_checkpoint(WarpCheckpoint { number: head_number, hash: head_hash });
+        assert_eq!(manager.state(), WarpSyncState::Inactive);
+        manager.peer_height_update(1500);
+        assert_eq!(manager.state(), WarpSyncState::RequestingSnapshot);
+
+        manager.offer_manifest(peer, manifest.clone());
+        assert!(manager.accept_corroborated(&TrustContext::default()).is_some());
+
+        // Other anchors don't replace a checkpoint missing from the chain
+        let mut manager = WarpSyncManager::new(0);
+        manager.set_sync_checkpoint(WarpCheckpoint { number: head_number, hash: Hash::hash(b"fork") });
+        manager.peer_height_update(5000);
+        manager.offer_manifest(peer, manifest);
+        assert!(manager.accept_corroborated(&context).is_none());
+    }
+
+    #[test]
+    fn test_chunk_requests_in_flight() {
+        let mut manager = WarpSyncManager::new(0);
+        manager.network_height = 2000;
+        manager.state = WarpSyncState::RequestingSnapshot;
+
+        let snapshot = create_test_snapshot(1500, 1000); // 10 chunks
+        manager.handle_snapshot_header(snapshot.header.clone()).unwrap();
+
+        let first = manager.next_chunk_requests();
+        assert_eq!(first, (0..MAX_CHUNKS_IN_FLIGHT as u32).collect::<Vec<_>>());
+        assert!(manager.next_chunk_requests().is_empty());
+
+        // An answered and a refused request free two slots
+        manager.handle_state_chunk(snapshot.chunks[0].clone()).unwrap();
+        manager.chunk_failed(1);
+        assert_eq!(manager.next_chunk_requests(), vec![1, 8]);
+    }
+
+    #[test]
+    fn test_snapshot_store() {
+        let mut store = SnapshotStore::default();
+        assert!(!store.is_due(1000));
+
+        store.enable();
+        assert!(store.is_due(1000));
+        assert!(!store.is_due(1001));
+        assert!(!store.is_due(0));
+
+        for number in [1000, 2000, 3000] {
+            store.insert(create_test_snapshot(number, 300));
+        }
+        assert!(store.get(1000).is_none());
+        assert!(!store.is_due(3000));
+        assert_eq!(store.newest_first().next().unwrap().header.block_number, 3000);
+        assert_eq!(store.chunk(2000, 2).unwrap().index, 2);
+        assert!(store.chunk(2000, 3).is_none());
+    }
 }
@@ -0,0 +1,167 @@
+// Security Alerts - Operator hooks fired when the network security state degrades
+// - Transitions into Degraded, Critical (Restricted) or Terminal (Emergency)
+//   are alerts; recoveries are only logged
+// - `webhook`: the transition is POSTed as JSON (see SecurityTransition)
+// - `command`: run with the transition in KRATOS_SECURITY_* environment
+//   variables, e.g. to page the operator
+// - Hooks run one transition at a time, each within `timeout`; a failing hook
+//   is logged and never affects the node
+
+use crate::node::invariants::{SecurityTransition, TransitionNotifier};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+/// Time a hook may take by default
+pub const DEFAULT_HOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Hooks fired on security alerts ([security-alerts] of the config file)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityAlerts {
+    /// URL the alert is POSTed to
+    pub webhook: Option<String>,
+    /// Program run on each alert
+    pub command: Option<PathBuf>,
+    /// Time a hook may take
+    pub timeout: Duration,
+}
+
+impl Default for SecurityAlerts {
+    fn default() -> Self {
+        Self { webhook: None, command: None, timeout: DEFAULT_HOOK_TIMEOUT }
+    }
+}
+
+impl SecurityAlerts {
+    /// At least one hook is configured
+    pub fn is_enabled(&self) -> bool {
+        self.webhook.is_some() || self.command.is_some()
+    }
+
+    /// Fire the hooks on every alert broadcast by `notifier`, until it closes
+    pub fn spawn(self, notifier: &TransitionNotifier) -> JoinHandle<()> {
+        let mut rx = notifier.subscribe();
+        tokio::spawn(async move {
+            loop {
+                match rx.recv().await {
+                    Ok(transition) if transition.alert => self.fire(&transition).await,
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(missed)) => warn!("🚨 {} security transitions missed by the alert hooks", missed),
+                    Err(RecvError::Closed) => return,
+                }
+            }
+        })
+    }
+
+    async fn fire(&self, transition: &SecurityTransition) {
+        info!(
+            "🚨 Security alert: {} → {} at epoch {}",
+            transition.from_state, transition.to_state, transition.epoch
+        );
+        if let Some(url) = &self.webhook {
+            if let Err(e) = self.post(url, transition).await {
+                warn!("🚨 Security alert webhook failed: {}", e);
+            }
+        }
+        if let Some(command) = &self.command {
+            if let Err(e) = self.run(command, transition).await {
+                warn!("🚨 Security alert command {} failed: {}", command.display(), e);
+            }
+        }
+    }
+
+    async fn post(&self, url: &str, transition: &SecurityTransition) -> Result<(), String> {
+        let client = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(|e| format!("HTTP client error: {}", e))?;
+        let response = client.post(url).json(transition).send().await.map_err(|e| e.to_string())?;
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()));
+        }
+        Ok(())
+    }
+
+    async fn run(&self, command: &Path, transition: &SecurityTransition) -> Result<(), String> {
+        let mut child = tokio::process::Command::new(command)
+            .envs(hook_env(transition))
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| e.to_string())?;
+        match tokio::time::timeout(self.timeout, child.wait()).await {
+            Ok(Ok(status)) if status.success() => Ok(()),
+            Ok(Ok(status)) => Err(status.to_string()),
+            Ok(Err(e)) => Err(e.to_string()),
+            Err(_) => Err(format!("timed out after {}s", self.timeout.as_secs())),
+        }
+    }
+}
+
+/// Environment of the alert command
+fn hook_env(transition: &SecurityTransition) -> Vec<(&'static str, String)> {
+    vec![
+        ("KRATOS_SECURITY_FROM", transition.from_state.clone()),
+        ("KRATOS_SECURITY_TO", transition.to_state.clone()),
+        ("KRATOS_SECURITY_EPOCH", transition.epoch.to_string()),
+        ("KRATOS_SECURITY_VALIDATORS", transition.validator_count.to_string()),
+        ("KRATOS_SECURITY_REASON", transition.reason.clone()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::broadcast;
+
+    fn transition(to_state: &str, alert: bool) -> SecurityTransition {
+        SecurityTransition {
+            epoch: 12,
+            from_state: "Healthy".to_string(),
+            to_state: to_state.to_string(),
+            validator_count: 40,
+            reason: "test".to_string(),
+            alert,
+        }
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_command_runs_on_alerts_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("alerts.log");
+        let script = dir.path().join("alert.sh");
+        std::fs::write(
+            &script,
+            format!("#!/bin/sh\necho \"$KRATOS_SECURITY_TO $KRATOS_SECURITY_EPOCH\" >> {}\n", out.display()),
+        )
+        .unwrap();
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let (notifier, _) = broadcast::channel(16);
+        let alerts = SecurityAlerts { command: Some(script), ..SecurityAlerts::default() };
+        assert!(alerts.is_enabled());
+        let handle = alerts.spawn(&notifier);
+
+        notifier.send(transition("Degraded", true)).unwrap();
+        notifier.send(transition("Healthy", false)).unwrap();
+        drop(notifier);
+        handle.await.unwrap();
+
+        assert_eq!(std::fs::read_to_string(&out).unwrap(), "Degraded 12\n");
+    }
+
+    #[tokio::test]
+    async fn test_failing_hooks_are_contained() {
+        let alerts = SecurityAlerts {
+            webhook: Some("http://127.0.0.1:1/alerts".to_string()),
+            command: Some(PathBuf::from("/nonexistent/alert")),
+            timeout: Duration::from_secs(1),
+        };
+        // Logged, not propagated
+        alerts.fire(&transition("Terminal", true)).await;
+        assert!(!SecurityAlerts::default().is_enabled());
+    }
+}
@@ -11,12 +11,13 @@
 // - FinalityIntegration: Coordinates finality with node operations
 
 use crate::consensus::finality::{
-    FinalityGadget, FinalityMessage, FinalityVote,
+    EquivocationProof, FinalityGadget, FinalityMessage, FinalityVote, RoundSummary, StallStatus,
     gadget::{FinalitySigner, FinalityBroadcaster, VoteResult},
     config::MIN_VALIDATORS_FOR_FINALITY,
 };
 use crate::network::protocol::NetworkMessage;
 use crate::types::account::AccountId;
+use crate::types::block::FinalityJustification;
 use crate::types::primitives::{BlockNumber, EpochNumber, Hash};
 use crate::types::signature::Signature64;
 use std::collections::HashSet;
@@ -279,10 +280,21 @@ impl<S: FinalitySigner + 'static, B: FinalityBroadcaster + 'static> FinalityInte
 
         trace!("[GRANDPA] tick: acquiring gadget write lock");
         let mut gadget = self.gadget.write().await;
+        let before = gadget.last_finalized().0;
         let result = gadget.tick();
         if result {
             trace!("[GRANDPA] tick: round timed out, advanced to next round");
         }
+
+        // Votes buffered for the new round may have finalized a block
+        let (block_number, _) = gadget.last_finalized();
+        if block_number > before {
+            if let Some(justification) = gadget.get_justification(block_number) {
+                *self.last_finality_voters.write().await =
+                    justification.signatures.iter().map(|sig| sig.validator).collect();
+                *self.last_finalized.write().await = block_number;
+            }
+        }
         result
     }
 
@@ -334,6 +346,11 @@ impl<S: FinalitySigner + 'static, B: FinalityBroadcaster + 'static> FinalityInte
         *self.last_finalized.read().await
     }
 
+    /// Get the justification the gadget produced for a block
+    pub async fn justification(&self, block_number: BlockNumber) -> Option<FinalityJustification> {
+        self.gadget.read().await.get_justification(block_number).cloned()
+    }
+
     /// Check if finality is currently active
     pub async fn is_active(&self) -> bool {
         *self.is_active.read().await
@@ -353,6 +370,21 @@ impl<S: FinalitySigner + 'static, B: FinalityBroadcaster + 'static> FinalityInte
         }
     }
 
+    /// Get finality liveness (timeouts, backoff, skipped rounds) for RPC
+    pub async fn stall_status(&self) -> StallStatus {
+        self.gadget.read().await.stall_status()
+    }
+
+    /// Rounds completed since the last call (round metrics)
+    pub async fn drain_completed_rounds(&self) -> Vec<(RoundSummary, bool)> {
+        self.gadget.write().await.drain_completed_rounds()
+    }
+
+    /// Equivocations detected since the last call (evidence for slashing)
+    pub async fn drain_equivocations(&self) -> Vec<EquivocationProof> {
+        self.gadget.write().await.drain_equivocations()
+    }
+
     /// Drain outbound finality messages for network transmission
     pub async fn drain_outbound(&self) -> Vec<FinalityMessage> {
         let mut gadget = self.gadget.write().await;
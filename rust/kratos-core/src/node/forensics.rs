@@ -0,0 +1,290 @@
+// Forensics - State root mismatch bundles
+// - When an imported block's state root disagrees with the root computed
+//   locally, everything needed to explain the divergence is written to
+//   <data_path>/forensics as JSON: both roots, the accounts the block changed
+//   (before/after, the state root leaves), and the block's transactions with
+//   their local receipts
+// - The newest bundles are kept (MAX_BUNDLES) and served by the unsafe
+//   debug_getStateRootForensics RPC method
+
+use crate::storage::AccountChange;
+use crate::types::{AccountInfo, Block, BlockNumber, Hash, Receipt};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Subdirectory of the data path holding the bundles
+pub const FORENSICS_DIR: &str = "forensics";
+
+/// Bundles kept on disk, oldest removed first
+pub const MAX_BUNDLES: usize = 16;
+
+const FILE_PREFIX: &str = "state-root-";
+
+/// Everything known about a block whose state root could not be reproduced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateRootForensics {
+    pub block_number: BlockNumber,
+    pub block_hash: String,
+    pub parent_hash: String,
+    pub author: String,
+    /// Root in the block header
+    pub expected_root: String,
+    /// Root of the local state after executing the block
+    pub computed_root: String,
+    /// Local root of the parent block (None if not stored)
+    pub parent_root: Option<String>,
+    /// Accounts whose value changed while executing the block, in key order
+    pub accounts: Vec<AccountDiff>,
+    pub transactions: Vec<TransactionTrace>,
+    /// Unix time of the dump
+    pub created_at: u64,
+}
+
+/// One state root leaf before and after the block
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountDiff {
+    pub account: String,
+    /// Trie key (account:<id>)
+    pub key: String,
+    pub before: Option<AccountSnapshot>,
+    pub after: Option<AccountSnapshot>,
+}
+
+/// Account fields, balances as decimal strings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSnapshot {
+    pub nonce: u64,
+    pub free: String,
+    pub reserved: String,
+    pub last_modified: String,
+}
+
+/// A transaction of the block and its local execution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTrace {
+    pub index: usize,
+    pub hash: String,
+    pub sender: String,
+    pub nonce: u64,
+    pub call: String,
+    /// None when execution stopped before this transaction
+    pub receipt: Option<ReceiptTrace>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiptTrace {
+    pub success: bool,
+    pub fee_paid: String,
+    pub error: Option<String>,
+    pub events: Vec<String>,
+}
+
+#[derive(Debug, Error)]
+pub enum ForensicsError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Serialization failed: {0}")]
+    SerializationFailed(String),
+}
+
+fn hex_hash(hash: &Hash) -> String {
+    format!("0x{}", hex::encode(hash.as_bytes()))
+}
+
+impl AccountSnapshot {
+    fn from_info(info: &AccountInfo) -> Self {
+        Self {
+            nonce: info.nonce,
+            free: info.free.to_string(),
+            reserved: info.reserved.to_string(),
+            last_modified: hex_hash(&info.last_modified),
+        }
+    }
+}
+
+impl StateRootForensics {
+    /// Bundle for `block`, from the account journal and the local receipts
+    pub fn new(
+        block: &Block,
+        computed_root: Hash,
+        parent_root: Option<Hash>,
+        changes: Vec<AccountChange>,
+        receipts: &[Receipt],
+    ) -> Self {
+        let accounts = changes
+            .into_iter()
+            .map(|change| AccountDiff {
+                account: format!("0x{}", hex::encode(change.account.as_bytes())),
+                key: format!("account:0x{}", hex::encode(change.account.as_bytes())),
+                before: change.before.as_ref().map(AccountSnapshot::from_info),
+                after: change.after.as_ref().map(AccountSnapshot::from_info),
+            })
+            .collect();
+
+        let transactions = block
+            .body
+            .transactions
+            .iter()
+            .enumerate()
+            .map(|(index, tx)| TransactionTrace {
+                index,
+                hash: hex_hash(&tx.hash()),
+                sender: format!("0x{}", hex::encode(tx.transaction.sender.as_bytes())),
+                nonce: tx.transaction.nonce,
+                call: format!("{:?}", tx.transaction.call),
+                receipt: receipts.get(index).map(|receipt| ReceiptTrace {
+                    success: receipt.success,
+                    fee_paid: receipt.fee_paid.to_string(),
+                    error: receipt.error.clone(),
+                    events: receipt.events.iter().map(|event| format!("{:?}", event)).collect(),
+                }),
+            })
+            .collect();
+
+        Self {
+            block_number: block.header.number,
+            block_hash: hex_hash(&block.hash()),
+            parent_hash: hex_hash(&block.header.parent_hash),
+            author: format!("0x{}", hex::encode(block.header.author.as_bytes())),
+            expected_root: hex_hash(&block.header.state_root),
+            computed_root: hex_hash(&computed_root),
+            parent_root: parent_root.as_ref().map(hex_hash),
+            accounts,
+            transactions,
+            created_at: chrono::Utc::now().timestamp() as u64,
+        }
+    }
+
+    /// Write the bundle under `data_path` and prune old ones, returns its path
+    pub fn write(&self, data_path: &Path) -> Result<PathBuf, ForensicsError> {
+        let dir = data_path.join(FORENSICS_DIR);
+        std::fs::create_dir_all(&dir)?;
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| ForensicsError::SerializationFailed(e.to_string()))?;
+        // Zero-padded height: file names sort in block order
+        let name = format!(
+            "{}{:012}-{}.json",
+            FILE_PREFIX,
+            self.block_number,
+            self.block_hash.trim_start_matches("0x")
+        );
+        let path = dir.join(name);
+        std::fs::write(&path, content)?;
+
+        let files = bundle_files(&dir)?;
+        for old in files.iter().take(files.len().saturating_sub(MAX_BUNDLES)) {
+            let _ = std::fs::remove_file(old);
+        }
+        Ok(path)
+    }
+}
+
+/// Bundle files, oldest block first
+fn bundle_files(dir: &Path) -> Result<Vec<PathBuf>, ForensicsError> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(FILE_PREFIX) && name.ends_with(".json"))
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Newest bundle, or the newest one of `block_number`
+pub fn load_bundle(
+    data_path: &Path,
+    block_number: Option<BlockNumber>,
+) -> Result<Option<StateRootForensics>, ForensicsError> {
+    let files = bundle_files(&data_path.join(FORENSICS_DIR))?;
+    let wanted = block_number.map(|number| format!("{}{:012}-", FILE_PREFIX, number));
+    let path = files.iter().rev().find(|path| match &wanted {
+        Some(prefix) => path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(prefix.as_str())),
+        None => true,
+    });
+
+    match path {
+        Some(path) => {
+            let content = std::fs::read_to_string(path)?;
+            serde_json::from_str(&content)
+                .map(Some)
+                .map_err(|e| ForensicsError::SerializationFailed(e.to_string()))
+        }
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AccountId, BlockBody, BlockHeader, ProtocolVersion, Signature64};
+
+    fn block(number: BlockNumber) -> Block {
+        Block {
+            header: BlockHeader {
+                number,
+                parent_hash: Hash::ZERO,
+                transactions_root: Hash::ZERO,
+                state_root: Hash::hash(b"expected"),
+                receipts_root: Hash::ZERO,
+                timestamp: 0,
+                epoch: 0,
+                slot: 0,
+                protocol_version: ProtocolVersion::default(),
+                author: AccountId::from_bytes([7; 32]),
+                signature: Signature64([0; 64]),
+            },
+            body: BlockBody { transactions: vec![] },
+        }
+    }
+
+    #[test]
+    fn test_bundle_roundtrip_and_pruning() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut after = AccountInfo::new();
+        after.free = 42;
+        let changes = vec![AccountChange {
+            account: AccountId::from_bytes([1; 32]),
+            before: None,
+            after: Some(after),
+        }];
+
+        let bundle = StateRootForensics::new(&block(5), Hash::hash(b"computed"), None, changes, &[]);
+        let path = bundle.write(dir.path()).unwrap();
+        assert!(path.starts_with(dir.path().join(FORENSICS_DIR)));
+
+        let loaded = load_bundle(dir.path(), Some(5)).unwrap().unwrap();
+        assert_eq!(loaded.expected_root, hex_hash(&Hash::hash(b"expected")));
+        assert_eq!(loaded.computed_root, hex_hash(&Hash::hash(b"computed")));
+        assert_eq!(loaded.accounts.len(), 1);
+        assert!(loaded.accounts[0].before.is_none());
+        assert_eq!(loaded.accounts[0].after.as_ref().unwrap().free, "42");
+        assert!(load_bundle(dir.path(), Some(6)).unwrap().is_none());
+
+        // Only the newest MAX_BUNDLES survive, the latest is served by default
+        for number in 10..10 + MAX_BUNDLES as u64 {
+            StateRootForensics::new(&block(number), Hash::ZERO, None, vec![], &[])
+                .write(dir.path())
+                .unwrap();
+        }
+        assert!(load_bundle(dir.path(), Some(5)).unwrap().is_none());
+        let latest = load_bundle(dir.path(), None).unwrap().unwrap();
+        assert_eq!(latest.block_number, 9 + MAX_BUNDLES as u64);
+    }
+}
@@ -0,0 +1,442 @@
+// Invariant Monitor - Continuous verification of SPEC v7.1 invariants
+// Principle: A violated invariant is visible the moment it happens, not in a post-mortem
+//
+// The monitor runs the network security state machine (SPEC v7.1 §5) from the
+// blocks the node imports and evaluates each invariant as a gauge: 1 while it
+// holds, 0 once violated. Gauges are served by the `system_invariants` RPC and
+// every violation is logged as an error.
+//
+// The security state is rebuilt from the epoch boundaries seen since the node
+// started, starting in Bootstrap. Each transition is broadcast (served at
+// `/events/security`, and to the operator alert hooks of node::alerts).
+
+use crate::consensus::economics::{
+    BootstrapConfig, DegradedSecurityConfig, NetworkSecurityState, SecurityStateTracker, StateTransition,
+    MIN_VALIDATORS, POST_BOOTSTRAP_MIN_VALIDATORS,
+};
+use crate::consensus::epoch::EPOCH_DURATION_BLOCKS;
+use crate::consensus::finality;
+use crate::contracts::governance;
+use crate::types::protocol::MIN_SUPERMAJORITY;
+use crate::types::{Block, BlockNumber, EpochNumber, TransactionCall};
+use serde::Serialize;
+use std::collections::HashMap;
+use tokio::sync::broadcast;
+use tracing::{error, info, warn};
+
+/// Transitions buffered for slow subscribers
+const TRANSITION_CHANNEL_CAPACITY: usize = 16;
+
+/// Channel of the security state transitions (InvariantMonitor::transition_notifier)
+pub type TransitionNotifier = broadcast::Sender<SecurityTransition>;
+
+/// Invariants checked at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecInvariant {
+    /// §9.1 No insecure bootstrap exit
+    BootstrapExitSecure,
+
+    /// §9.2-3 No silent collapse: too few validators always degrade the state
+    AutomaticDegradation,
+
+    /// §9.5 Exit without permission
+    ExitAlwaysPossible,
+
+    /// §9.6 Fork without punishment
+    ForkWithoutPunishment,
+
+    /// §5.2 No governance while it is frozen (Restricted / Emergency)
+    NoGovernanceDuringFreeze,
+
+    /// The latest finality justification has a supermajority of the validator set
+    FinalitySupermajority,
+
+    /// Configured supermajority thresholds are at least the constitutional 66%
+    SupermajorityThresholds,
+}
+
+impl SpecInvariant {
+    pub fn all() -> [SpecInvariant; 7] {
+        [
+            SpecInvariant::BootstrapExitSecure,
+            SpecInvariant::AutomaticDegradation,
+            SpecInvariant::ExitAlwaysPossible,
+            SpecInvariant::ForkWithoutPunishment,
+            SpecInvariant::NoGovernanceDuringFreeze,
+            SpecInvariant::FinalitySupermajority,
+            SpecInvariant::SupermajorityThresholds,
+        ]
+    }
+
+    /// Gauge name (prefixed with `kratos_invariant_` when exported)
+    pub fn name(&self) -> &'static str {
+        match self {
+            SpecInvariant::BootstrapExitSecure => "bootstrap_exit_secure",
+            SpecInvariant::AutomaticDegradation => "automatic_degradation",
+            SpecInvariant::ExitAlwaysPossible => "exit_always_possible",
+            SpecInvariant::ForkWithoutPunishment => "fork_without_punishment",
+            SpecInvariant::NoGovernanceDuringFreeze => "no_governance_during_freeze",
+            SpecInvariant::FinalitySupermajority => "finality_supermajority",
+            SpecInvariant::SupermajorityThresholds => "supermajority_thresholds",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            SpecInvariant::BootstrapExitSecure =>
+                "Bootstrap was only left with at least 50 active validators",
+            SpecInvariant::AutomaticDegradation =>
+                "Security state degrades without governance once validators fall below the minimum",
+            SpecInvariant::ExitAlwaysPossible =>
+                "Users can withdraw assets in every security state",
+            SpecInvariant::ForkWithoutPunishment =>
+                "Forking is allowed in every security state",
+            SpecInvariant::NoGovernanceDuringFreeze =>
+                "No governance transaction was included while governance is frozen",
+            SpecInvariant::FinalitySupermajority =>
+                "The latest finality justification is signed by 2/3 of the validator set",
+            SpecInvariant::SupermajorityThresholds =>
+                "Finality and exit vote thresholds are at least 66%",
+        }
+    }
+}
+
+/// Status of one invariant
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvariantStatus {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Gauge value: 1 = holds, 0 = violated
+    pub value: u8,
+    /// Times the invariant went from holding to violated since startup
+    pub violations: u64,
+}
+
+/// Invariant gauges at a block (system_invariants)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvariantReport {
+    pub block_number: BlockNumber,
+    pub security_state: String,
+    pub active_validators: u32,
+    pub healthy: bool,
+    pub invariants: Vec<InvariantStatus>,
+}
+
+/// Change of the network security state at an epoch boundary
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityTransition {
+    pub epoch: EpochNumber,
+    pub from_state: String,
+    pub to_state: String,
+    pub validator_count: u32,
+    pub reason: String,
+    /// Entered Degraded, Critical (Restricted) or Terminal (Emergency)
+    pub alert: bool,
+}
+
+impl From<&StateTransition> for SecurityTransition {
+    fn from(transition: &StateTransition) -> Self {
+        Self {
+            epoch: transition.epoch,
+            from_state: transition.from_state.clone(),
+            to_state: transition.to_state.clone(),
+            validator_count: transition.validator_count,
+            reason: transition.reason.clone(),
+            alert: matches!(transition.to_state.as_str(), "Degraded" | "Critical" | "Terminal"),
+        }
+    }
+}
+
+/// Live state the invariants are evaluated against
+#[derive(Debug, Clone, Copy)]
+pub struct InvariantInputs {
+    pub block_number: BlockNumber,
+    pub active_validators: u32,
+    /// Whether the latest justification verifies against the active set
+    /// (None before any block is finalized)
+    pub justification_verified: Option<bool>,
+}
+
+/// Runs the security state machine and evaluates the invariants
+#[derive(Debug)]
+pub struct InvariantMonitor {
+    /// Network security state (SPEC v7.1 §5)
+    security: SecurityStateTracker,
+
+    /// Bootstrap exit conditions
+    bootstrap: BootstrapConfig,
+
+    /// Governance transactions included while governance was frozen
+    governance_while_frozen: u64,
+
+    /// Invariants violated at the last evaluation
+    violated: Vec<SpecInvariant>,
+
+    /// Violation count per invariant
+    violations: HashMap<SpecInvariant, u64>,
+
+    /// Last evaluated state
+    last_inputs: Option<InvariantInputs>,
+
+    /// Security state transitions, as they happen
+    transitions: TransitionNotifier,
+}
+
+impl InvariantMonitor {
+    pub fn new() -> Self {
+        Self {
+            security: SecurityStateTracker::new(MIN_VALIDATORS, DegradedSecurityConfig::default()),
+            bootstrap: BootstrapConfig::default_config(),
+            governance_while_frozen: 0,
+            violated: Vec::new(),
+            violations: HashMap::new(),
+            last_inputs: None,
+            transitions: broadcast::channel(TRANSITION_CHANNEL_CAPACITY).0,
+        }
+    }
+
+    /// Subscribe to the security state transitions
+    pub fn transition_notifier(&self) -> TransitionNotifier {
+        self.transitions.clone()
+    }
+
+    /// Security state transitions since startup, oldest first (last 100)
+    pub fn security_history(&self) -> Vec<SecurityTransition> {
+        self.security.state_transitions.iter().map(SecurityTransition::from).collect()
+    }
+
+    /// Current network security state
+    pub fn security_state(&self) -> &NetworkSecurityState {
+        &self.security.state
+    }
+
+    /// Security state machine, with the effects of the current state
+    pub fn security(&self) -> &SecurityStateTracker {
+        &self.security
+    }
+
+    /// Follow an imported block
+    ///
+    /// Advances the security state at epoch boundaries and counts governance
+    /// transactions included while governance is frozen.
+    pub fn on_block(&mut self, block: &Block, active_validators: u32) {
+        let number = block.header.number;
+        if number > 0 && number.is_multiple_of(EPOCH_DURATION_BLOCKS) {
+            let epoch = number / EPOCH_DURATION_BLOCKS;
+            if let Some(state) = self.security.update(epoch, active_validators, &self.bootstrap, 0) {
+                let transition = self.security.state_transitions.last().map(SecurityTransition::from);
+                match &transition {
+                    Some(t) if t.alert => warn!("🛡️  Security state changed at epoch {}: {:?}", epoch, state),
+                    _ => info!("🛡️  Security state changed at epoch {}: {:?}", epoch, state),
+                }
+                if let Some(transition) = transition {
+                    // No subscriber is not an error
+                    let _ = self.transitions.send(transition);
+                }
+            }
+        }
+
+        if !self.security.is_governance_allowed(false) {
+            let governance = block
+                .body
+                .transactions
+                .iter()
+                .filter(|tx| is_governance_call(&tx.transaction.call))
+                .count();
+            self.governance_while_frozen += governance as u64;
+        }
+    }
+
+    /// Evaluate all invariants, logging the ones that became violated
+    pub fn evaluate(&mut self, inputs: InvariantInputs) -> Vec<SpecInvariant> {
+        let violated: Vec<SpecInvariant> = SpecInvariant::all()
+            .into_iter()
+            .filter(|invariant| !self.holds(*invariant, &inputs))
+            .collect();
+
+        let mut newly_violated = Vec::new();
+        for invariant in &violated {
+            if !self.violated.contains(invariant) {
+                *self.violations.entry(*invariant).or_default() += 1;
+                error!(
+                    "🚨 SPEC invariant violated at block #{}: {} ({})",
+                    inputs.block_number, invariant.name(), invariant.description()
+                );
+                newly_violated.push(*invariant);
+            }
+        }
+        for invariant in &self.violated {
+            if !violated.contains(invariant) {
+                info!("✅ SPEC invariant restored at block #{}: {}", inputs.block_number, invariant.name());
+            }
+        }
+
+        self.violated = violated;
+        self.last_inputs = Some(inputs);
+        newly_violated
+    }
+
+    /// Gauges of the last evaluation
+    pub fn report(&self) -> InvariantReport {
+        let inputs = self.last_inputs;
+        InvariantReport {
+            block_number: inputs.map(|i| i.block_number).unwrap_or(0),
+            security_state: format!("{:?}", self.security.state),
+            active_validators: inputs.map(|i| i.active_validators).unwrap_or(0),
+            healthy: self.violated.is_empty(),
+            invariants: SpecInvariant::all()
+                .into_iter()
+                .map(|invariant| InvariantStatus {
+                    name: invariant.name(),
+                    description: invariant.description(),
+                    value: if self.violated.contains(&invariant) { 0 } else { 1 },
+                    violations: self.violations.get(&invariant).copied().unwrap_or(0),
+                })
+                .collect(),
+        }
+    }
+
+    fn holds(&self, invariant: SpecInvariant, inputs: &InvariantInputs) -> bool {
+        let security = &self.security;
+        match invariant {
+            SpecInvariant::BootstrapExitSecure => security
+                .state_transitions
+                .iter()
+                .filter(|t| t.from_state == "Bootstrap")
+                .all(|t| t.validator_count >= POST_BOOTSTRAP_MIN_VALIDATORS),
+            SpecInvariant::AutomaticDegradation => {
+                !(security.is_normal() && security.epochs_below_minimum > security.config.floor_grace_epochs)
+            }
+            SpecInvariant::ExitAlwaysPossible => {
+                security.is_exit_allowed() && security.config.emergency_exit_always_allowed
+            }
+            SpecInvariant::ForkWithoutPunishment => {
+                security.is_fork_allowed() && security.config.emergency_no_fork_suppression
+            }
+            SpecInvariant::NoGovernanceDuringFreeze => self.governance_while_frozen == 0,
+            SpecInvariant::FinalitySupermajority => inputs.justification_verified.unwrap_or(true),
+            SpecInvariant::SupermajorityThresholds => {
+                finality::config::SUPERMAJORITY_THRESHOLD >= MIN_SUPERMAJORITY
+                    && governance::SUPERMAJORITY_THRESHOLD >= MIN_SUPERMAJORITY
+            }
+        }
+    }
+}
+
+impl Default for InvariantMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Transactions that are governance actions on the root chain
+fn is_governance_call(call: &TransactionCall) -> bool {
+    matches!(
+        call,
+        TransactionCall::ProposeEarlyValidator { .. }
+            | TransactionCall::VoteEarlyValidator { .. }
+            | TransactionCall::SubmitProposal { .. }
+            | TransactionCall::SecondProposal { .. }
+            | TransactionCall::VoteProposal { .. }
+            | TransactionCall::ExecuteProposal { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(block_number: BlockNumber, active_validators: u32) -> InvariantInputs {
+        InvariantInputs { block_number, active_validators, justification_verified: Some(true) }
+    }
+
+    #[test]
+    fn test_healthy_at_startup() {
+        let mut monitor = InvariantMonitor::new();
+        assert!(monitor.evaluate(inputs(1, 3)).is_empty());
+
+        let report = monitor.report();
+        assert!(report.healthy);
+        assert_eq!(report.security_state, "Bootstrap");
+        assert_eq!(report.invariants.len(), SpecInvariant::all().len());
+        assert!(report.invariants.iter().all(|i| i.value == 1));
+    }
+
+    #[test]
+    fn test_violation_counted_once() {
+        let mut monitor = InvariantMonitor::new();
+        let unjustified = InvariantInputs { justification_verified: Some(false), ..inputs(10, 3) };
+
+        assert_eq!(monitor.evaluate(unjustified), vec![SpecInvariant::FinalitySupermajority]);
+        // Still violated: not reported again
+        assert!(monitor.evaluate(unjustified).is_empty());
+        assert!(!monitor.report().healthy);
+
+        assert!(monitor.evaluate(inputs(11, 3)).is_empty());
+        let report = monitor.report();
+        assert!(report.healthy);
+        let finality = report.invariants.iter().find(|i| i.name == "finality_supermajority").unwrap();
+        assert_eq!((finality.value, finality.violations), (1, 1));
+    }
+
+    #[test]
+    fn test_governance_during_freeze() {
+        use crate::types::{AccountId, BlockBody, Hash, Signature64, SignedTransaction, Transaction};
+
+        let mut monitor = InvariantMonitor::new();
+        monitor.security.state = NetworkSecurityState::TerminalMode {
+            entered_at: 0,
+            epochs_in_terminal: 0,
+            current_validators: 10,
+            terminal_state_root: None,
+        };
+
+        let vote = SignedTransaction {
+            transaction: Transaction {
+                sender: AccountId::from_bytes([1; 32]),
+                nonce: 0,
+                call: TransactionCall::VoteEarlyValidator { candidate: AccountId::from_bytes([2; 32]) },
+                timestamp: 0,
+                genesis_hash: Hash::ZERO,
+                valid_until: None,
+                max_fee: None,
+                tip: 0,
+            },
+            signature: Signature64::from_bytes([0; 64]),
+            hash: None,
+        };
+        let mut block = Block::genesis(Hash::ZERO, vec![]);
+        block.header.number = 7;
+        block.body = BlockBody::new(vec![vote]);
+
+        monitor.on_block(&block, 10);
+        assert_eq!(monitor.evaluate(inputs(7, 10)), vec![SpecInvariant::NoGovernanceDuringFreeze]);
+    }
+
+    #[test]
+    fn test_transitions_broadcast() {
+        use crate::types::Hash;
+
+        let mut monitor = InvariantMonitor::new();
+        monitor.security.state = NetworkSecurityState::Normal;
+        let mut transitions = monitor.transition_notifier().subscribe();
+
+        // Below the operational minimum at an epoch boundary: Degraded
+        let mut block = Block::genesis(Hash::ZERO, vec![]);
+        block.header.number = 3 * EPOCH_DURATION_BLOCKS;
+        monitor.on_block(&block, 40);
+
+        let transition = transitions.try_recv().unwrap();
+        assert_eq!((transition.epoch, transition.to_state.as_str()), (3, "Degraded"));
+        assert!(transition.alert);
+        assert_eq!(monitor.security_history(), vec![transition]);
+
+        // Not at an epoch boundary: nothing
+        block.header.number += 1;
+        monitor.on_block(&block, 10);
+        assert!(transitions.try_recv().is_err());
+    }
+}
@@ -0,0 +1,712 @@
+// Keystore - Encrypted on-disk storage for node session keys
+//
+// Layout:
+// - One JSON file per key under <base_path>/keystore/
+// - File name: <key type id>-<public key hex>.json (e.g. aura-1a2b...)
+// - Public metadata in clear, secret encrypted with AES-256-GCM
+// - Encryption key derived from the keystore password with Argon2
+//
+// Listing keys never needs the password; only loading a secret does.
+//
+// Session keys (`sess`) sign blocks on behalf of the validator account (the
+// active aura key) once bound on chain, see consensus::session_keys.
+//
+// Rotation: inserting a newer key of the same type and scheme supersedes the
+// old one, which is no longer offered for signing. The node records the epoch
+// it first saw the rotation and, N epochs later, moves the old key into
+// <keystore>/archive.enc: one blob encrypted with the keystore password,
+// public keys included, so retired keys are not even listed in clear.
+
+use crate::types::EpochNumber;
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use argon2::Argon2;
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Keystore directory name inside the node base path
+pub const KEYSTORE_DIR: &str = "keystore";
+
+/// Current keystore file format version
+const KEYSTORE_VERSION: u32 = 1;
+
+/// Salt length for Argon2 key derivation
+const SALT_LEN: usize = 16;
+
+/// Nonce length for AES-GCM
+const NONCE_LEN: usize = 12;
+
+/// Archive of retired keys inside the keystore directory (not a .json key file)
+pub const ARCHIVE_FILE: &str = "archive.enc";
+
+// =============================================================================
+// KEY TYPES
+// =============================================================================
+
+/// Role a key is used for, identified by a 4-byte id
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyType {
+    /// Block production
+    Aura,
+    /// Slot assignment (VRF)
+    Babe,
+    /// Finality voting
+    Grandpa,
+    /// Block signing for the validator account, bound on chain (author_rotateKeys)
+    Session,
+}
+
+impl KeyType {
+    /// 4-byte key type id used in file names
+    pub fn id(&self) -> &'static str {
+        match self {
+            KeyType::Aura => "aura",
+            KeyType::Babe => "babe",
+            KeyType::Grandpa => "gran",
+            KeyType::Session => "sess",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "aura" => Some(KeyType::Aura),
+            "babe" => Some(KeyType::Babe),
+            "gran" => Some(KeyType::Grandpa),
+            "sess" => Some(KeyType::Session),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for KeyType {
+    type Err = KeystoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "aura" => Ok(KeyType::Aura),
+            "babe" => Ok(KeyType::Babe),
+            "grandpa" | "gran" => Ok(KeyType::Grandpa),
+            "session" | "sess" => Ok(KeyType::Session),
+            other => Err(KeystoreError::UnknownKeyType(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for KeyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+/// Signature scheme of a stored key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyScheme {
+    Ed25519,
+    Sr25519,
+}
+
+impl KeyScheme {
+    /// Derive the public key for a 32-byte secret (ed25519 secret or sr25519 mini secret)
+    pub fn public_key(&self, secret: &[u8; 32]) -> Result<[u8; 32], KeystoreError> {
+        match self {
+            KeyScheme::Ed25519 => Ok(SigningKey::from_bytes(secret).verifying_key().to_bytes()),
+            KeyScheme::Sr25519 => {
+                let mini = schnorrkel::MiniSecretKey::from_bytes(secret)
+                    .map_err(|e| KeystoreError::InvalidSecret(format!("{:?}", e)))?;
+                let keypair = mini.expand_to_keypair(schnorrkel::ExpansionMode::Ed25519);
+                Ok(keypair.public.to_bytes())
+            }
+        }
+    }
+}
+
+impl FromStr for KeyScheme {
+    type Err = KeystoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ed25519" => Ok(KeyScheme::Ed25519),
+            "sr25519" => Ok(KeyScheme::Sr25519),
+            other => Err(KeystoreError::UnknownScheme(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for KeyScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyScheme::Ed25519 => f.write_str("ed25519"),
+            KeyScheme::Sr25519 => f.write_str("sr25519"),
+        }
+    }
+}
+
+// =============================================================================
+// KEYSTORE
+// =============================================================================
+
+/// Public view of a stored key (no password required)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeystoreEntry {
+    pub key_type: KeyType,
+    pub scheme: KeyScheme,
+    pub public_key: [u8; 32],
+    pub path: PathBuf,
+    /// Unix time of insertion in ms (None for keys inserted before rotation support)
+    pub created_at: Option<u64>,
+    /// Epoch the node first saw a newer key of the same type and scheme
+    pub rotated_at: Option<EpochNumber>,
+}
+
+/// A retired key, as stored in the encrypted archive
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedKey {
+    pub key_type: KeyType,
+    pub scheme: KeyScheme,
+    pub public_key: [u8; 32],
+    pub secret: [u8; 32],
+    pub created_at: Option<u64>,
+    pub rotated_at: EpochNumber,
+    pub retired_at: EpochNumber,
+}
+
+/// On-disk key file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KeyFile {
+    version: u32,
+    key_type: String,
+    scheme: KeyScheme,
+    public_key: String,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created_at: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rotated_at: Option<EpochNumber>,
+}
+
+/// On-disk archive: the JSON list of ArchivedKey, encrypted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ArchiveFile {
+    version: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Salt, nonce and ciphertext of a sealed secret
+struct Sealed {
+    salt: [u8; SALT_LEN],
+    nonce: [u8; NONCE_LEN],
+    ciphertext: Vec<u8>,
+}
+
+/// Encrypted keystore rooted at <base_path>/keystore
+#[derive(Debug, Clone)]
+pub struct Keystore {
+    path: PathBuf,
+}
+
+impl Keystore {
+    /// Keystore for a node base path (the directory is created on first insert)
+    pub fn open(base_path: &Path) -> Self {
+        Self {
+            path: base_path.join(KEYSTORE_DIR),
+        }
+    }
+
+    /// Keystore directory
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Encrypt and store a secret, returning the new entry
+    pub fn insert(
+        &self,
+        key_type: KeyType,
+        scheme: KeyScheme,
+        secret: &[u8; 32],
+        password: &str,
+    ) -> Result<KeystoreEntry, KeystoreError> {
+        if password.is_empty() {
+            return Err(KeystoreError::EmptyPassword);
+        }
+
+        let public_key = scheme.public_key(secret)?;
+        let sealed = Self::seal(password, secret)?;
+        let created_at = chrono::Utc::now().timestamp_millis() as u64;
+
+        let file = KeyFile {
+            version: KEYSTORE_VERSION,
+            key_type: key_type.id().to_string(),
+            scheme,
+            public_key: hex::encode(public_key),
+            salt: hex::encode(sealed.salt),
+            nonce: hex::encode(sealed.nonce),
+            ciphertext: hex::encode(sealed.ciphertext),
+            created_at: Some(created_at),
+            rotated_at: None,
+        };
+
+        std::fs::create_dir_all(&self.path).map_err(|e| KeystoreError::Io(e.to_string()))?;
+        let path = self.path.join(format!("{}-{}.json", key_type.id(), hex::encode(public_key)));
+        Self::write_json(&path, &file)?;
+
+        Ok(KeystoreEntry {
+            key_type,
+            scheme,
+            public_key,
+            path,
+            created_at: Some(created_at),
+            rotated_at: None,
+        })
+    }
+
+    /// Generate a random secret and store it, returning the new entry
+    pub fn generate(&self, key_type: KeyType, scheme: KeyScheme, password: &str) -> Result<KeystoreEntry, KeystoreError> {
+        let mut secret = [0u8; 32];
+        OsRng.fill_bytes(&mut secret);
+        self.insert(key_type, scheme, &secret, password)
+    }
+
+    /// List stored keys, sorted by key type then public key
+    pub fn list(&self) -> Result<Vec<KeystoreEntry>, KeystoreError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = Vec::new();
+        for dir_entry in std::fs::read_dir(&self.path).map_err(|e| KeystoreError::Io(e.to_string()))? {
+            let path = dir_entry.map_err(|e| KeystoreError::Io(e.to_string()))?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let file = Self::read_file(&path)?;
+            let key_type = KeyType::from_id(&file.key_type)
+                .ok_or_else(|| KeystoreError::UnknownKeyType(file.key_type.clone()))?;
+            entries.push(KeystoreEntry {
+                key_type,
+                scheme: file.scheme,
+                public_key: decode_array(&file.public_key)?,
+                path,
+                created_at: file.created_at,
+                rotated_at: file.rotated_at,
+            });
+        }
+
+        entries.sort_by(|a, b| {
+            a.key_type
+                .id()
+                .cmp(b.key_type.id())
+                .then_with(|| a.public_key.cmp(&b.public_key))
+        });
+        Ok(entries)
+    }
+
+    /// Key in use for a type and scheme: the newest one
+    ///
+    /// Undated keys (inserted before rotation support) rank oldest, the first
+    /// by public key among them, as before.
+    pub fn active_entry(&self, key_type: KeyType, scheme: KeyScheme) -> Result<Option<KeystoreEntry>, KeystoreError> {
+        Ok(active_of(&self.list()?, key_type, scheme).cloned())
+    }
+
+    /// Key used for block production: the active ed25519 aura key
+    pub fn validator_entry(&self) -> Result<Option<KeystoreEntry>, KeystoreError> {
+        self.active_entry(KeyType::Aura, KeyScheme::Ed25519)
+    }
+
+    /// Keys superseded by a newer key of the same type and scheme
+    pub fn superseded(&self) -> Result<Vec<KeystoreEntry>, KeystoreError> {
+        let entries = self.list()?;
+        Ok(entries
+            .iter()
+            .filter(|entry| is_superseded(entry, &entries))
+            .cloned()
+            .collect())
+    }
+
+    /// Mark new rotations and retire keys rotated `retire_after` epochs ago
+    ///
+    /// A superseded key is stamped with `current_epoch` the first time it is
+    /// seen, then moved into the archive once `current_epoch` reaches that
+    /// epoch plus `retire_after`. Returns the keys retired by this call.
+    pub fn retire_rotated(
+        &self,
+        current_epoch: EpochNumber,
+        retire_after: EpochNumber,
+        password: &str,
+    ) -> Result<Vec<KeystoreEntry>, KeystoreError> {
+        let mut retired = Vec::new();
+        for entry in self.superseded()? {
+            match entry.rotated_at {
+                None => {
+                    let mut file = Self::read_file(&entry.path)?;
+                    file.rotated_at = Some(current_epoch);
+                    Self::write_json(&entry.path, &file)?;
+                }
+                Some(rotated_at) if current_epoch >= rotated_at.saturating_add(retire_after) => {
+                    let secret = self.decrypt(&entry, password)?;
+                    let mut archive = self.archived(password)?;
+                    archive.push(ArchivedKey {
+                        key_type: entry.key_type,
+                        scheme: entry.scheme,
+                        public_key: entry.public_key,
+                        secret,
+                        created_at: entry.created_at,
+                        rotated_at,
+                        retired_at: current_epoch,
+                    });
+                    // Archive first: a crash in between leaves the key in both places
+                    self.write_archive(&archive, password)?;
+                    std::fs::remove_file(&entry.path).map_err(|e| KeystoreError::Io(e.to_string()))?;
+                    retired.push(entry);
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(retired)
+    }
+
+    /// Archive file path
+    pub fn archive_path(&self) -> PathBuf {
+        self.path.join(ARCHIVE_FILE)
+    }
+
+    /// Decrypt the archive of retired keys (empty if none was retired)
+    pub fn archived(&self, password: &str) -> Result<Vec<ArchivedKey>, KeystoreError> {
+        let path = self.archive_path();
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&path).map_err(|e| KeystoreError::Io(e.to_string()))?;
+        let file: ArchiveFile = serde_json::from_str(&content)
+            .map_err(|e| KeystoreError::Corrupted(format!("{}: {}", path.display(), e)))?;
+        if file.version != KEYSTORE_VERSION {
+            return Err(KeystoreError::Corrupted(format!(
+                "{}: unsupported version {}",
+                path.display(),
+                file.version
+            )));
+        }
+        let plaintext = Self::unseal(password, &file.salt, &file.nonce, &file.ciphertext)?;
+        serde_json::from_slice(&plaintext).map_err(|e| KeystoreError::Corrupted(format!("{}: {}", path.display(), e)))
+    }
+
+    fn write_archive(&self, keys: &[ArchivedKey], password: &str) -> Result<(), KeystoreError> {
+        let plaintext = serde_json::to_vec(keys).map_err(|e| KeystoreError::Corrupted(e.to_string()))?;
+        let sealed = Self::seal(password, &plaintext)?;
+        let file = ArchiveFile {
+            version: KEYSTORE_VERSION,
+            salt: hex::encode(sealed.salt),
+            nonce: hex::encode(sealed.nonce),
+            ciphertext: hex::encode(sealed.ciphertext),
+        };
+        // Replace atomically: a torn write would lose every archived key
+        let tmp = self.path.join(format!("{}.tmp", ARCHIVE_FILE));
+        Self::write_json(&tmp, &file)?;
+        std::fs::rename(&tmp, self.archive_path()).map_err(|e| KeystoreError::Io(e.to_string()))
+    }
+
+    /// Decrypt the secret of a stored key
+    pub fn decrypt(&self, entry: &KeystoreEntry, password: &str) -> Result<[u8; 32], KeystoreError> {
+        let file = Self::read_file(&entry.path)?;
+        let plaintext = Self::unseal(password, &file.salt, &file.nonce, &file.ciphertext)?;
+
+        let secret: [u8; 32] = plaintext
+            .try_into()
+            .map_err(|_| KeystoreError::Corrupted("invalid secret length".to_string()))?;
+
+        // Guard against a file whose public key was edited after insertion
+        if entry.scheme.public_key(&secret)? != entry.public_key {
+            return Err(KeystoreError::Corrupted("public key mismatch".to_string()));
+        }
+
+        Ok(secret)
+    }
+
+    /// Decrypt an ed25519 key into a signing key
+    pub fn load_ed25519(&self, entry: &KeystoreEntry, password: &str) -> Result<SigningKey, KeystoreError> {
+        if entry.scheme != KeyScheme::Ed25519 {
+            return Err(KeystoreError::UnsupportedScheme(entry.scheme));
+        }
+        Ok(SigningKey::from_bytes(&self.decrypt(entry, password)?))
+    }
+
+    fn read_file(path: &Path) -> Result<KeyFile, KeystoreError> {
+        let content = std::fs::read_to_string(path).map_err(|e| KeystoreError::Io(e.to_string()))?;
+        let file: KeyFile = serde_json::from_str(&content)
+            .map_err(|e| KeystoreError::Corrupted(format!("{}: {}", path.display(), e)))?;
+        if file.version != KEYSTORE_VERSION {
+            return Err(KeystoreError::Corrupted(format!(
+                "{}: unsupported version {}",
+                path.display(),
+                file.version
+            )));
+        }
+        Ok(file)
+    }
+
+    /// Write a key or archive file readable by the owner only
+    fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), KeystoreError> {
+        let json = serde_json::to_string_pretty(value)
+            .map_err(|e| KeystoreError::Corrupted(e.to_string()))?;
+        std::fs::write(path, json).map_err(|e| KeystoreError::Io(e.to_string()))?;
+
+        // Set restrictive permissions on Unix
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))
+                .map_err(|e| KeystoreError::Io(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Encrypt with a fresh salt and nonce
+    fn seal(password: &str, plaintext: &[u8]) -> Result<Sealed, KeystoreError> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+
+        let cipher = Self::cipher(password, &salt)?;
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|_| KeystoreError::Crypto("encryption failed".to_string()))?;
+        Ok(Sealed { salt, nonce, ciphertext })
+    }
+
+    /// Decrypt hex-encoded salt, nonce and ciphertext
+    fn unseal(password: &str, salt: &str, nonce: &str, ciphertext: &str) -> Result<Vec<u8>, KeystoreError> {
+        let salt = hex::decode(salt).map_err(|e| KeystoreError::Corrupted(e.to_string()))?;
+        let nonce: [u8; NONCE_LEN] = hex::decode(nonce)
+            .ok()
+            .and_then(|n| n.try_into().ok())
+            .ok_or_else(|| KeystoreError::Corrupted("invalid nonce".to_string()))?;
+        let ciphertext = hex::decode(ciphertext).map_err(|e| KeystoreError::Corrupted(e.to_string()))?;
+
+        let cipher = Self::cipher(password, &salt)?;
+        cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+            .map_err(|_| KeystoreError::WrongPassword)
+    }
+
+    fn cipher(password: &str, salt: &[u8]) -> Result<Aes256Gcm, KeystoreError> {
+        let mut key = [0u8; 32];
+        Argon2::default()
+            .hash_password_into(password.as_bytes(), salt, &mut key)
+            .map_err(|e| KeystoreError::Crypto(e.to_string()))?;
+        Aes256Gcm::new_from_slice(&key).map_err(|e| KeystoreError::Crypto(e.to_string()))
+    }
+}
+
+/// Active key of a type and scheme among `entries`
+fn active_of(entries: &[KeystoreEntry], key_type: KeyType, scheme: KeyScheme) -> Option<&KeystoreEntry> {
+    entries
+        .iter()
+        .filter(|e| e.key_type == key_type && e.scheme == scheme)
+        // Newest wins; on a tie the smallest public key (first in list order)
+        .max_by(|a, b| a.created_at.cmp(&b.created_at).then_with(|| b.public_key.cmp(&a.public_key)))
+}
+
+/// Strictly older than the active key of its type and scheme
+fn is_superseded(entry: &KeystoreEntry, entries: &[KeystoreEntry]) -> bool {
+    active_of(entries, entry.key_type, entry.scheme).is_some_and(|active| entry.created_at < active.created_at)
+}
+
+/// Parse a secret URI: a 0x-prefixed hex secret, or a key file from `key generate`
+pub fn parse_suri(suri: &str, scheme: KeyScheme) -> Result<[u8; 32], KeystoreError> {
+    let path = Path::new(suri);
+    if path.is_file() {
+        let content = std::fs::read_to_string(path).map_err(|e| KeystoreError::Io(e.to_string()))?;
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+            let field = match scheme {
+                KeyScheme::Ed25519 => "secretKey",
+                KeyScheme::Sr25519 => "secretSeed",
+            };
+            let secret = json
+                .get(field)
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| KeystoreError::InvalidSecret(format!("key file missing '{}' field", field)))?;
+            return decode_array(secret);
+        }
+        return decode_array(content.trim());
+    }
+
+    decode_array(suri.trim())
+}
+
+fn decode_array(hex_str: &str) -> Result<[u8; 32], KeystoreError> {
+    let bytes = hex::decode(hex_str.strip_prefix("0x").unwrap_or(hex_str))
+        .map_err(|e| KeystoreError::InvalidSecret(format!("invalid hex: {}", e)))?;
+    let len = bytes.len();
+    bytes
+        .try_into()
+        .map_err(|_| KeystoreError::InvalidSecret(format!("invalid key length: {} bytes (expected 32)", len)))
+}
+
+/// Keystore errors
+#[derive(Debug, Error)]
+pub enum KeystoreError {
+    #[error("IO error: {0}")]
+    Io(String),
+
+    #[error("Unknown key type: {0} (expected aura, babe or grandpa)")]
+    UnknownKeyType(String),
+
+    #[error("Unknown key scheme: {0} (expected ed25519 or sr25519)")]
+    UnknownScheme(String),
+
+    #[error("Scheme {0} cannot be used for block production")]
+    UnsupportedScheme(KeyScheme),
+
+    #[error("Invalid secret: {0}")]
+    InvalidSecret(String),
+
+    #[error("Keystore password must not be empty")]
+    EmptyPassword,
+
+    #[error("Wrong keystore password")]
+    WrongPassword,
+
+    #[error("Corrupted key file: {0}")]
+    Corrupted(String),
+
+    #[error("Crypto error: {0}")]
+    Crypto(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_list_and_decrypt() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = Keystore::open(dir.path());
+        assert!(keystore.list().unwrap().is_empty());
+
+        let secret = [7u8; 32];
+        let entry = keystore
+            .insert(KeyType::Aura, KeyScheme::Ed25519, &secret, "hunter2")
+            .unwrap();
+        keystore
+            .insert(KeyType::Grandpa, KeyScheme::Sr25519, &[9u8; 32], "hunter2")
+            .unwrap();
+
+        let entries = keystore.list().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0], entry);
+        assert_eq!(entry.public_key, SigningKey::from_bytes(&secret).verifying_key().to_bytes());
+
+        assert_eq!(keystore.validator_entry().unwrap(), Some(entry.clone()));
+        assert_eq!(keystore.decrypt(&entry, "hunter2").unwrap(), secret);
+        assert!(matches!(keystore.decrypt(&entry, "wrong"), Err(KeystoreError::WrongPassword)));
+
+        let sr = &entries[1];
+        assert!(matches!(
+            keystore.load_ed25519(sr, "hunter2"),
+            Err(KeystoreError::UnsupportedScheme(KeyScheme::Sr25519))
+        ));
+    }
+
+    #[test]
+    fn test_rotation_and_retirement() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = Keystore::open(dir.path());
+        let old = keystore.insert(KeyType::Aura, KeyScheme::Ed25519, &[1u8; 32], "pw").unwrap();
+        let grandpa = keystore.insert(KeyType::Grandpa, KeyScheme::Ed25519, &[3u8; 32], "pw").unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let new = keystore.insert(KeyType::Aura, KeyScheme::Ed25519, &[2u8; 32], "pw").unwrap();
+
+        // The newest aura key signs, the old one is superseded
+        assert_eq!(keystore.validator_entry().unwrap().unwrap().public_key, new.public_key);
+        let superseded = keystore.superseded().unwrap();
+        assert_eq!(superseded.len(), 1);
+        assert_eq!(superseded[0].public_key, old.public_key);
+
+        // Rotation seen at epoch 10, retired two epochs later
+        assert!(keystore.retire_rotated(10, 2, "pw").unwrap().is_empty());
+        assert_eq!(keystore.superseded().unwrap()[0].rotated_at, Some(10));
+        assert!(keystore.retire_rotated(11, 2, "pw").unwrap().is_empty());
+        assert!(matches!(keystore.retire_rotated(12, 2, "wrong"), Err(KeystoreError::WrongPassword)));
+        let retired = keystore.retire_rotated(12, 2, "pw").unwrap();
+        assert_eq!(retired.len(), 1);
+
+        let listed: Vec<[u8; 32]> = keystore.list().unwrap().iter().map(|e| e.public_key).collect();
+        assert_eq!(listed, vec![new.public_key, grandpa.public_key]);
+        assert!(keystore.superseded().unwrap().is_empty());
+
+        let archive = keystore.archived("pw").unwrap();
+        assert_eq!(archive.len(), 1);
+        assert_eq!(archive[0].public_key, old.public_key);
+        assert_eq!(archive[0].secret, [1u8; 32]);
+        assert_eq!((archive[0].rotated_at, archive[0].retired_at), (10, 12));
+        assert!(matches!(keystore.archived("wrong"), Err(KeystoreError::WrongPassword)));
+    }
+
+    #[test]
+    fn test_generated_session_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = Keystore::open(dir.path());
+        let account = keystore.insert(KeyType::Aura, KeyScheme::Ed25519, &[1u8; 32], "pw").unwrap();
+        let session = keystore.generate(KeyType::Session, KeyScheme::Ed25519, "pw").unwrap();
+
+        // The account key stays the validator key
+        assert_eq!(keystore.validator_entry().unwrap(), Some(account));
+        assert_eq!(keystore.active_entry(KeyType::Session, KeyScheme::Ed25519).unwrap(), Some(session.clone()));
+        let key = keystore.load_ed25519(&session, "pw").unwrap();
+        assert_eq!(key.verifying_key().to_bytes(), session.public_key);
+    }
+
+    #[test]
+    fn test_undated_keys_keep_first_by_public_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let keystore = Keystore::open(dir.path());
+        for secret in [[4u8; 32], [5u8; 32]] {
+            let entry = keystore.insert(KeyType::Aura, KeyScheme::Ed25519, &secret, "pw").unwrap();
+            // Key files written before rotation support have no creation time
+            let mut file = Keystore::read_file(&entry.path).unwrap();
+            file.created_at = None;
+            Keystore::write_json(&entry.path, &file).unwrap();
+        }
+
+        let first = keystore.list().unwrap()[0].clone();
+        assert_eq!(keystore.validator_entry().unwrap(), Some(first));
+        assert!(keystore.superseded().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_key_type_and_suri() {
+        assert_eq!("grandpa".parse::<KeyType>().unwrap(), KeyType::Grandpa);
+        assert_eq!("AURA".parse::<KeyType>().unwrap(), KeyType::Aura);
+        assert_eq!("session".parse::<KeyType>().unwrap(), KeyType::Session);
+        assert!("imon".parse::<KeyType>().is_err());
+
+        let hex_secret = format!("0x{}", hex::encode([1u8; 32]));
+        assert_eq!(parse_suri(&hex_secret, KeyScheme::Ed25519).unwrap(), [1u8; 32]);
+        assert!(parse_suri("0x1234", KeyScheme::Ed25519).is_err());
+
+        let dir = tempfile::tempdir().unwrap();
+        let key_file = dir.path().join("validator.json");
+        std::fs::write(&key_file, format!("{{\"secretKey\": \"{}\"}}", hex_secret)).unwrap();
+        assert_eq!(
+            parse_suri(key_file.to_str().unwrap(), KeyScheme::Ed25519).unwrap(),
+            [1u8; 32]
+        );
+    }
+}
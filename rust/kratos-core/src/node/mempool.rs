@@ -1,18 +1,28 @@
 // Mempool - Production-grade transaction pool with priority ordering
 //
 // Features:
-// - Priority fee sorting (highest fee first)
+// - Priority by fee rate (static fee plus tip per KiB of encoded transaction, highest first)
 // - Per-account nonce tracking with gap detection
+// - Orphan queue: gapped transactions wait, promoted when the gap fills
 // - Replace-by-fee (RBF) support
 // - Eviction policies for full pool
+// - Expiry by age and by blocks, stale nonces removed on each block
+// - Dropped transactions notified (wallets resubmit with a fresh nonce/fee)
 // - Rate limiting per account
+// - Per-account quota, fee escalating with the account's pooled transactions
 // - Transaction validation before acceptance
 
+use crate::contracts::governance::{validate_proposal, PROPOSAL_DEPOSIT};
+use crate::contracts::messaging::validate_account_message;
+use crate::contracts::identity::{validate_registration, IDENTITY_DEPOSIT};
+use crate::execution::gas::{scale_fee, MIN_BASE_FEE};
 use crate::storage::state::StateBackend;
-use crate::types::{AccountId, AccountInfo, Balance, Hash, SignedTransaction, TransactionCall};
+use crate::types::{AccountId, AccountInfo, Balance, BlockNumber, Hash, SignedTransaction, TransactionCall};
+use crate::types::transaction::validate_memo;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, BTreeMap, HashMap, HashSet};
 use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
 use tracing::{debug, info, warn};
 
 // =============================================================================
@@ -34,6 +44,12 @@ const MAX_TOTAL_PENDING: usize = 500;
 /// This limits to ~15 years of activity at 1 tx/second per account
 const MAX_ABSOLUTE_NONCE: u64 = 500_000_000;
 
+/// Bytes per unit of fee rate (fee rate = fee per KiB)
+const FEE_RATE_UNIT: u128 = 1024;
+
+/// Drop notifications buffered for a slow subscriber
+const DROP_CHANNEL_CAPACITY: usize = 1024;
+
 /// Mempool configuration
 #[derive(Debug, Clone)]
 pub struct MempoolConfig {
@@ -52,6 +68,15 @@ pub struct MempoolConfig {
     /// Transaction expiration time
     pub tx_expiration: Duration,
 
+    /// Blocks before a pending transaction expires (0 = no limit)
+    pub tx_expiration_blocks: u64,
+
+    /// Transactions an account can pool at min_fee before the fee escalates
+    pub fee_escalation_free_slots: usize,
+
+    /// Minimum fee increase (%) for each pooled transaction over the free slots
+    pub fee_escalation_pct: u8,
+
     /// Rate limit: max submissions per account per window
     pub rate_limit_per_account: usize,
 
@@ -78,6 +103,9 @@ impl Default for MempoolConfig {
             min_fee: 1_000, // Minimum base fee
             rbf_min_increase_pct: 10, // 10% fee increase for replacement
             tx_expiration: Duration::from_secs(3600), // 1 hour
+            tx_expiration_blocks: 600, // 1 hour of 6 s slots
+            fee_escalation_free_slots: 16,
+            fee_escalation_pct: 25, // 17th transaction at 1.25 x min_fee, 18th at 1.56 x...
             rate_limit_per_account: 50,
             rate_limit_window: Duration::from_secs(60),
             verify_signatures: true,
@@ -91,15 +119,33 @@ impl Default for MempoolConfig {
 // PRIORITY WRAPPER
 // =============================================================================
 
+/// Fee a transaction offers: the call's static fee plus its tip
+/// Every transaction of a block pays the same base fee multiplier, so the
+/// tip is what sets one above another.
+pub fn offered_fee(tx: &SignedTransaction) -> Balance {
+    tx.transaction.call.base_fee().saturating_add(tx.transaction.tip)
+}
+
+/// Offered fee per KiB of the encoded transaction
+/// A block has limited room: a big transaction must pay more to go first.
+pub fn fee_rate(tx: &SignedTransaction) -> u128 {
+    let size = bincode::serialized_size(tx).unwrap_or(1).max(1) as u128;
+    offered_fee(tx).saturating_mul(FEE_RATE_UNIT) / size
+}
+
 /// Transaction with priority ordering
 #[derive(Debug, Clone)]
 struct PrioritizedTx {
     /// Transaction hash
     hash: Hash,
-    /// Effective fee (for ordering)
+    /// Fee rate (primary ordering)
+    fee_rate: u128,
+    /// Absolute fee (ties of fee rate)
     fee: Balance,
     /// Timestamp when added
     added_at: Instant,
+    /// Best block when added (None = added before the pool saw a block)
+    added_block: Option<BlockNumber>,
     /// Sender account
     sender: AccountId,
     /// Nonce
@@ -107,18 +153,20 @@ struct PrioritizedTx {
 }
 
 impl PrioritizedTx {
-    fn new(tx: &SignedTransaction, fee: Balance) -> Self {
+    fn new(tx: &SignedTransaction, fee: Balance, added_block: Option<BlockNumber>) -> Self {
         Self {
             hash: tx.hash(),
+            fee_rate: fee_rate(tx),
             fee,
             added_at: Instant::now(),
+            added_block,
             sender: tx.transaction.sender,
             nonce: tx.transaction.nonce,
         }
     }
 }
 
-// Higher fee = higher priority (max heap)
+// Higher fee rate = higher priority (max heap)
 impl PartialEq for PrioritizedTx {
     fn eq(&self, other: &Self) -> bool {
         self.hash == other.hash
@@ -135,17 +183,24 @@ impl PartialOrd for PrioritizedTx {
 
 impl Ord for PrioritizedTx {
     fn cmp(&self, other: &Self) -> Ordering {
-        // Primary: higher fee first
-        match self.fee.cmp(&other.fee) {
-            Ordering::Equal => {
-                // Secondary: earlier timestamp first (FIFO for same fee)
-                other.added_at.cmp(&self.added_at)
-            }
-            other_cmp => other_cmp,
-        }
+        // Primary: higher fee rate first, then higher fee
+        // Tertiary: earlier timestamp first (FIFO for same fee)
+        self.fee_rate
+            .cmp(&other.fee_rate)
+            .then(self.fee.cmp(&other.fee))
+            .then(other.added_at.cmp(&self.added_at))
     }
 }
 
+/// Transaction waiting for a missing earlier nonce (not selectable)
+#[derive(Debug, Clone)]
+struct OrphanTx {
+    tx: SignedTransaction,
+    fee: Balance,
+    added_at: Instant,
+    added_block: Option<BlockNumber>,
+}
+
 // =============================================================================
 // RATE LIMITER
 // =============================================================================
@@ -243,38 +298,13 @@ impl AccountQueue {
         ready
     }
 
-    /// Check for nonce gap
-    fn has_gap(&self, expected_nonce: u64) -> bool {
-        if let Some((&first_nonce, _)) = self.by_nonce.first_key_value() {
-            first_nonce > expected_nonce
-        } else {
-            false
+    /// First nonce from `from` on that the queue doesn't hold
+    fn next_nonce(&self, from: u64) -> u64 {
+        let mut next = from;
+        while self.by_nonce.contains_key(&next) {
+            next += 1;
         }
-    }
-
-    /// SECURITY FIX #22: Count pending (non-ready) transactions
-    /// These are transactions waiting for earlier nonces to be filled
-    fn pending_count(&self) -> usize {
-        if self.by_nonce.is_empty() {
-            return 0;
-        }
-        // Count transactions after a gap
-        let mut count = 0;
-        let mut prev_nonce: Option<u64> = None;
-        let mut found_gap = false;
-
-        for &nonce in self.by_nonce.keys() {
-            if let Some(prev) = prev_nonce {
-                if nonce > prev + 1 {
-                    found_gap = true;
-                }
-            }
-            if found_gap {
-                count += 1;
-            }
-            prev_nonce = Some(nonce);
-        }
-        count
+        next
     }
 }
 
@@ -282,6 +312,49 @@ impl AccountQueue {
 // TRANSACTION POOL
 // =============================================================================
 
+/// Why a transaction left the pool without being included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropReason {
+    /// Pending for longer than tx_expiration or tx_expiration_blocks, or
+    /// past its validity window
+    Expired,
+    /// Pushed out of a full pool by a better fee rate
+    Evicted,
+    /// Replaced by a higher fee transaction with the same nonce
+    Replaced,
+    /// Its nonce was used by another transaction
+    Stale,
+}
+
+impl DropReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DropReason::Expired => "expired",
+            DropReason::Evicted => "evicted",
+            DropReason::Replaced => "replaced",
+            DropReason::Stale => "stale",
+        }
+    }
+}
+
+/// A transaction dropped from the pool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DroppedTransaction {
+    pub hash: Hash,
+    pub sender: AccountId,
+    pub nonce: u64,
+    pub reason: DropReason,
+}
+
+/// Pending transactions, as block production would take them
+#[derive(Debug, Clone, Default)]
+pub struct PoolContent {
+    /// Includable now, in inclusion order
+    pub ready: Vec<SignedTransaction>,
+    /// Waiting for an earlier nonce
+    pub future: Vec<SignedTransaction>,
+}
+
 /// Production-grade transaction pool
 pub struct TransactionPool {
     /// Configuration (public for test access)
@@ -302,8 +375,21 @@ pub struct TransactionPool {
     /// Rate limiter
     rate_limiter: AccountRateLimiter,
 
-    /// Pending transactions (waiting for earlier nonces)
-    pending: HashSet<Hash>,
+    /// Gapped transactions by sender and nonce, promoted when the gap fills
+    /// At most max_nonce_gap per account, max_total_pending in all.
+    orphans: HashMap<AccountId, BTreeMap<u64, OrphanTx>>,
+
+    /// Best block seen (None before the first)
+    current_block: Option<BlockNumber>,
+
+    /// Genesis hash transactions must be signed for (None: not checked)
+    genesis_hash: Option<Hash>,
+
+    /// Base fee of the next block, what a transaction submitted now pays
+    base_fee: u64,
+
+    /// Dropped transactions, for whoever subscribed
+    drops: broadcast::Sender<DroppedTransaction>,
 
     /// Statistics
     stats: PoolStats,
@@ -342,6 +428,10 @@ pub enum PoolError {
     #[error("Invalid signature")]
     InvalidSignature,
 
+    /// Replay protection: signed for another network
+    #[error("Transaction signed for another chain (genesis {got}, expected {expected})")]
+    WrongChain { expected: Hash, got: Hash },
+
     #[error("Invalid nonce: expected {expected}, got {got}")]
     InvalidNonce { expected: u64, got: u64 },
 
@@ -354,6 +444,10 @@ pub enum PoolError {
     #[error("Too many transactions for account: {count} >= {max}")]
     TooManyPerAccount { count: usize, max: usize },
 
+    /// Spam protection: each pooled transaction over the free slots costs more
+    #[error("Fee too low for an account with {pooled} pooled transactions: {fee} < {required}")]
+    EscalatedFeeTooLow { fee: Balance, required: Balance, pooled: usize },
+
     #[error("Rate limit exceeded for account")]
     RateLimitExceeded,
 
@@ -368,8 +462,13 @@ pub enum PoolError {
     #[error("RBF fee increase insufficient: need {need_pct}% increase")]
     RbfFeeInsufficient { need_pct: u8 },
 
-    #[error("Transaction expired")]
-    Expired,
+    /// Mortal transaction past its last block
+    #[error("Transaction expired: valid until block {valid_until}, chain at {current}")]
+    Expired { valid_until: BlockNumber, current: BlockNumber },
+
+    /// The base fee rose past what the sender signed for
+    #[error("Max fee too low: {max_fee} < {fee} at the current base fee")]
+    MaxFeeTooLow { max_fee: Balance, fee: Balance },
 
     #[error("Validation error: {0}")]
     Validation(String),
@@ -393,11 +492,45 @@ impl TransactionPool {
             priority_queue: BinaryHeap::new(),
             account_queues: HashMap::new(),
             rate_limiter: AccountRateLimiter::default(),
-            pending: HashSet::new(),
+            orphans: HashMap::new(),
+            current_block: None,
+            genesis_hash: None,
+            base_fee: MIN_BASE_FEE,
+            drops: broadcast::channel(DROP_CHANNEL_CAPACITY).0,
             stats: PoolStats::default(),
         }
     }
 
+    /// Channel of the dropped transactions (subscribe to receive them)
+    pub fn drop_notifier(&self) -> broadcast::Sender<DroppedTransaction> {
+        self.drops.clone()
+    }
+
+    fn notify_dropped(&self, tx: &SignedTransaction, reason: DropReason) {
+        // No subscriber: nothing to do
+        let _ = self.drops.send(DroppedTransaction {
+            hash: tx.hash(),
+            sender: tx.transaction.sender,
+            nonce: tx.transaction.nonce,
+            reason,
+        });
+    }
+
+    /// Replace the configuration (limits apply to the next submissions)
+    pub fn set_config(&mut self, config: MempoolConfig) {
+        self.config = config;
+    }
+
+    /// Only admit transactions signed for the chain of this genesis
+    pub fn set_genesis_hash(&mut self, genesis_hash: Hash) {
+        self.genesis_hash = Some(genesis_hash);
+    }
+
+    /// Base fee of the next block (set on each new best block)
+    pub fn set_base_fee(&mut self, base_fee: u64) {
+        self.base_fee = base_fee;
+    }
+
     /// Add a transaction to the pool
     pub fn add(&mut self, tx: SignedTransaction) -> Result<(), PoolError> {
         self.add_with_validation(tx, None)
@@ -408,17 +541,52 @@ impl TransactionPool {
         &mut self,
         tx: SignedTransaction,
         state: Option<&mut StateBackend>,
+    ) -> Result<(), PoolError> {
+        self.add_checked(tx, state, None, true)
+    }
+
+    /// Add knowing the sender's chain nonce
+    /// A transaction past a missing nonce waits in the orphan queue until the
+    /// gap fills (see add_orphan).
+    pub fn add_with_account_nonce(&mut self, tx: SignedTransaction, account_nonce: u64) -> Result<(), PoolError> {
+        self.add_checked(tx, None, Some(account_nonce), true)
+    }
+
+    /// Add back transactions saved by `transactions()` before a restart
+    ///
+    /// The per-account rate limit doesn't apply (they were admitted once);
+    /// those included or invalidated since are dropped. Returns how many were
+    /// added.
+    pub fn restore(&mut self, txs: Vec<SignedTransaction>, mut state: Option<&mut StateBackend>) -> usize {
+        let mut restored = 0;
+        for mut tx in txs {
+            // Hashes are not serialized
+            tx.hash.get_or_insert_with(|| tx.transaction.hash());
+            match self.add_checked(tx, state.as_deref_mut(), None, false) {
+                Ok(()) => restored += 1,
+                Err(e) => debug!("Saved transaction dropped: {:?}", e),
+            }
+        }
+        restored
+    }
+
+    fn add_checked(
+        &mut self,
+        tx: SignedTransaction,
+        state: Option<&mut StateBackend>,
+        account_nonce: Option<u64>,
+        rate_limited: bool,
     ) -> Result<(), PoolError> {
         let hash = tx.hash.ok_or(PoolError::NoHash)?;
 
         // Check if already exists
-        if self.transactions.contains_key(&hash) {
+        if self.contains(&hash) {
             return Err(PoolError::AlreadyExists);
         }
 
         let sender = tx.transaction.sender;
         let nonce = tx.transaction.nonce;
-        let fee = tx.transaction.call.base_fee();
+        let fee = offered_fee(&tx);
 
         // SECURITY FIX #34: Check absolute nonce limit
         // Prevents attackers from submitting transactions with impossibly high nonces
@@ -430,14 +598,69 @@ impl TransactionPool {
             });
         }
 
+        if let Some(expected) = self.genesis_hash {
+            if tx.transaction.genesis_hash != expected {
+                self.stats.total_rejected += 1;
+                return Err(PoolError::WrongChain { expected, got: tx.transaction.genesis_hash });
+            }
+        }
+
+        // Mortality: includable from the next block on
+        if let (Some(current), Some(valid_until)) = (self.current_block, tx.transaction.valid_until) {
+            if !tx.transaction.is_valid_at(current + 1) {
+                self.stats.total_rejected += 1;
+                return Err(PoolError::Expired { valid_until, current });
+            }
+        }
+
+        // Must afford the next block's base fee
+        let scaled_fee = scale_fee(tx.transaction.call.base_fee(), self.base_fee).saturating_add(tx.transaction.tip);
+        if !tx.transaction.accepts_fee(scaled_fee) {
+            self.stats.total_rejected += 1;
+            let max_fee = tx.transaction.max_fee.unwrap_or_default();
+            return Err(PoolError::MaxFeeTooLow { max_fee, fee: scaled_fee });
+        }
+
         // Validate fee minimum
         if fee < self.config.min_fee {
             self.stats.total_rejected += 1;
             return Err(PoolError::FeeTooLow(fee, self.config.min_fee));
         }
 
+        // Bounded memo (also enforced at execution)
+        if let Some(memo) = tx.transaction.call.memo() {
+            if let Err(e) = validate_memo(memo) {
+                self.stats.total_rejected += 1;
+                return Err(PoolError::Validation(e.to_string()));
+            }
+        }
+
+        // Bounded messages (also enforced at execution)
+        if let TransactionCall::SendMessage { body, .. } = &tx.transaction.call {
+            if let Err(e) = validate_account_message(body) {
+                self.stats.total_rejected += 1;
+                return Err(PoolError::Validation(e.to_string()));
+            }
+        }
+
+        // Bounded proposals (also enforced at execution)
+        if let TransactionCall::SubmitProposal { proposal_type, description } = &tx.transaction.call {
+            if let Err(e) = validate_proposal(proposal_type, description.as_deref()) {
+                self.stats.total_rejected += 1;
+                return Err(PoolError::Validation(e.to_string()));
+            }
+        }
+
+        // Bounded identities (also enforced at execution)
+        if let TransactionCall::RegisterIdentity { display_name, contact_hashes } = &tx.transaction.call {
+            if let Err(e) = validate_registration(display_name.as_deref(), contact_hashes) {
+                self.stats.total_rejected += 1;
+                return Err(PoolError::Validation(e.to_string()));
+            }
+        }
+
         // Rate limiting
-        if !self.rate_limiter.check(
+        if rate_limited && !self.rate_limiter.check(
             &sender,
             self.config.rate_limit_per_account,
             self.config.rate_limit_window,
@@ -458,44 +681,66 @@ impl TransactionPool {
             return Err(PoolError::InvalidSignature);
         }
 
-        // Check per-account limit
-        let account_queue = self.account_queues.entry(sender).or_default();
-        if account_queue.len() >= self.config.max_per_account {
+        // Check per-account limit (orphans included)
+        let pooled = self.account_len(&sender);
+        let existing = self
+            .account_queues
+            .get(&sender)
+            .and_then(|q| q.by_nonce.get(&nonce))
+            .copied();
+        if pooled >= self.config.max_per_account {
             // Check if this is a replacement (same nonce, higher fee)
-            if let Some(&existing_hash) = account_queue.by_nonce.get(&nonce) {
+            if let Some(existing_hash) = existing {
                 return self.try_replace(tx, existing_hash);
             }
             self.stats.total_rejected += 1;
             return Err(PoolError::TooManyPerAccount {
-                count: account_queue.len(),
+                count: pooled,
                 max: self.config.max_per_account,
             });
         }
 
-        // State validation (if state available)
-        if let Some(backend) = state {
-            self.validate_against_state(&tx, backend)?;
-        }
+        // State validation (if state available), gives the chain nonce
+        let account_nonce = match state {
+            Some(backend) => Some(self.validate_against_state(&tx, backend)?),
+            None => account_nonce,
+        };
 
         // Check if replacement
-        if let Some(&existing_hash) = self
-            .account_queues
-            .get(&sender)
-            .and_then(|q| q.by_nonce.get(&nonce))
-        {
+        if let Some(existing_hash) = existing {
             return self.try_replace(tx, existing_hash);
         }
 
+        // Successive nonces of one account pay more and more
+        let required = self.escalated_min_fee(pooled);
+        if fee < required {
+            self.stats.total_rejected += 1;
+            return Err(PoolError::EscalatedFeeTooLow { fee, required, pooled });
+        }
+
+        // Nonce gap: wait for the missing nonces (unknown chain nonce: selection defers it)
+        if let Some(account_nonce) = account_nonce {
+            if nonce < account_nonce {
+                self.stats.total_rejected += 1;
+                return Err(PoolError::NonceTooOld { got: nonce, current: account_nonce });
+            }
+            let expected = self.account_queues.get(&sender).map_or(account_nonce, |q| q.next_nonce(account_nonce));
+            if nonce > expected {
+                return self.add_orphan(tx, fee, expected);
+            }
+        }
+
         // Pool full - try eviction
         if self.transactions.len() >= self.config.max_size {
-            if !self.evict_lowest_fee(fee) {
+            if !self.evict_lowest_fee(&tx) {
                 self.stats.total_rejected += 1;
                 return Err(PoolError::PoolFull);
             }
         }
 
-        // Add to pool
+        // Add to pool, then the orphans it was the missing nonce of
         self.insert_transaction(tx, fee);
+        self.promote_orphans(&sender, nonce);
 
         debug!(
             "✅ Transaction {} added to pool (fee={}, total={})",
@@ -508,10 +753,21 @@ impl TransactionPool {
         Ok(())
     }
 
+    /// Minimum fee of a new transaction from an account with `pooled` in the pool
+    /// min_fee up to fee_escalation_free_slots, then fee_escalation_pct % more
+    /// per transaction (bounded by max_per_account steps).
+    pub fn escalated_min_fee(&self, pooled: usize) -> Balance {
+        let steps = (pooled + 1).saturating_sub(self.config.fee_escalation_free_slots);
+        let pct = self.config.fee_escalation_pct as Balance;
+        (0..steps.min(self.config.max_per_account)).fold(self.config.min_fee, |fee, _| {
+            fee.saturating_add(fee.saturating_mul(pct) / 100)
+        })
+    }
+
     /// Try to replace an existing transaction (RBF)
     fn try_replace(&mut self, new_tx: SignedTransaction, existing_hash: Hash) -> Result<(), PoolError> {
         let existing_fee = self.fees.get(&existing_hash).copied().unwrap_or(0);
-        let new_fee = new_tx.transaction.call.base_fee();
+        let new_fee = offered_fee(&new_tx);
 
         // Calculate required fee increase (use saturating ops to prevent overflow)
         let min_increase = existing_fee.saturating_mul(self.config.rbf_min_increase_pct as u128) / 100;
@@ -525,7 +781,9 @@ impl TransactionPool {
         }
 
         // Remove old transaction
-        self.remove_internal(&existing_hash);
+        if let Some(old_tx) = self.remove_internal(&existing_hash) {
+            self.notify_dropped(&old_tx, DropReason::Replaced);
+        }
 
         // Add new transaction
         self.insert_transaction(new_tx.clone(), new_fee);
@@ -542,11 +800,19 @@ impl TransactionPool {
         Ok(())
     }
 
-    /// Validate transaction against chain state
-    fn validate_against_state(&self, tx: &SignedTransaction, state: &mut StateBackend) -> Result<(), PoolError> {
+    /// Validate transaction against chain state, returns the sender's nonce
+    fn validate_against_state(&self, tx: &SignedTransaction, state: &mut StateBackend) -> Result<u64, PoolError> {
         let sender = tx.transaction.sender;
         let nonce = tx.transaction.nonce;
-        let fee = tx.transaction.call.base_fee();
+
+        // The sender must afford the fee at the next block's base fee
+        let next_block = self.current_block.map_or(0, |n| n + 1);
+        let base_fee = state.base_fee_at(next_block).unwrap_or(MIN_BASE_FEE);
+        let fee = scale_fee(tx.transaction.call.base_fee(), base_fee).saturating_add(tx.transaction.tip);
+        if !tx.transaction.accepts_fee(fee) {
+            let max_fee = tx.transaction.max_fee.unwrap_or_default();
+            return Err(PoolError::MaxFeeTooLow { max_fee, fee });
+        }
 
         // Get account info
         let account = state.get_account(&sender).ok().flatten().unwrap_or(AccountInfo {
@@ -556,53 +822,23 @@ impl TransactionPool {
             last_modified: Hash::ZERO,
         });
 
-        // Check nonce
-        let expected_nonce = account.nonce;
-        let pool_nonce = self
-            .account_queues
-            .get(&sender)
-            .and_then(|q| q.by_nonce.keys().max().copied())
-            .map(|n| n + 1)
-            .unwrap_or(expected_nonce);
-
-        let effective_expected = pool_nonce.max(expected_nonce);
-
-        if nonce < expected_nonce {
+        // Check nonce (gaps are bounded by add_orphan)
+        if nonce < account.nonce {
             return Err(PoolError::NonceTooOld {
                 got: nonce,
-                current: expected_nonce,
-            });
-        }
-
-        // SECURITY FIX #14: Limit nonce gap to prevent resource exhaustion
-        // Attackers could flood the pending queue with high-nonce transactions
-        if nonce > effective_expected + self.config.max_nonce_gap {
-            return Err(PoolError::InvalidNonce {
-                expected: effective_expected,
-                got: nonce,
-            });
-        }
-
-        // SECURITY FIX #22: Check global pending transaction limit
-        // This prevents DoS attacks using many accounts with gap transactions
-        let total_pending: usize = self
-            .account_queues
-            .values()
-            .map(|q| q.pending_count())
-            .sum();
-        if total_pending >= self.config.max_total_pending && nonce > effective_expected {
-            return Err(PoolError::TooManyPendingGlobal {
-                count: total_pending,
-                max: self.config.max_total_pending,
+                current: account.nonce,
             });
         }
 
         // Check balance for fee + value
         let value = match &tx.transaction.call {
             TransactionCall::Transfer { amount, .. } => *amount,
+            TransactionCall::TransferWithMemo { amount, .. } => *amount,
             TransactionCall::Stake { amount } => *amount,
             TransactionCall::CreateSidechain { deposit, .. } => *deposit,
             TransactionCall::RegisterValidator { stake } => *stake,
+            TransactionCall::SubmitProposal { .. } => PROPOSAL_DEPOSIT,
+            TransactionCall::RegisterIdentity { .. } => IDENTITY_DEPOSIT,
             _ => 0,
         };
 
@@ -614,9 +850,99 @@ impl TransactionPool {
             });
         }
 
+        Ok(account.nonce)
+    }
+
+    /// Hold a transaction whose nonce is past `expected` until the gap fills
+    fn add_orphan(&mut self, tx: SignedTransaction, fee: Balance, expected: u64) -> Result<(), PoolError> {
+        let sender = tx.transaction.sender;
+        let nonce = tx.transaction.nonce;
+
+        // SECURITY FIX #14: Limit nonce gap to prevent resource exhaustion
+        // Attackers could flood the pending queue with high-nonce transactions
+        if nonce > expected + self.config.max_nonce_gap {
+            self.stats.total_rejected += 1;
+            return Err(PoolError::InvalidNonce { expected, got: nonce });
+        }
+
+        // Same nonce as an orphan: replace-by-fee rules
+        let existing = self.orphans.get(&sender).and_then(|o| o.get(&nonce)).map(|o| o.fee);
+        match existing {
+            Some(existing_fee) => {
+                let min_increase = existing_fee.saturating_mul(self.config.rbf_min_increase_pct as u128) / 100;
+                if fee < existing_fee.saturating_add(min_increase) {
+                    self.stats.total_rejected += 1;
+                    return Err(PoolError::RbfFeeInsufficient {
+                        need_pct: self.config.rbf_min_increase_pct,
+                    });
+                }
+                self.stats.total_replaced += 1;
+            }
+            None => {
+                // SECURITY FIX #22: Check global pending transaction limit
+                // This prevents DoS attacks using many accounts with gap transactions
+                let total_pending = self.orphan_count();
+                if total_pending >= self.config.max_total_pending {
+                    self.stats.total_rejected += 1;
+                    return Err(PoolError::TooManyPendingGlobal {
+                        count: total_pending,
+                        max: self.config.max_total_pending,
+                    });
+                }
+                self.stats.total_added += 1;
+            }
+        }
+
+        let orphan = OrphanTx {
+            tx,
+            fee,
+            added_at: Instant::now(),
+            added_block: self.current_block,
+        };
+        if let Some(replaced) = self.orphans.entry(sender).or_default().insert(nonce, orphan) {
+            self.notify_dropped(&replaced.tx, DropReason::Replaced);
+        }
+        debug!("Transaction nonce {} of {} waits for nonce {}", nonce, sender, expected);
         Ok(())
     }
 
+    /// Move to the pool the orphans of `sender` no longer behind a gap
+    /// `from`: a nonce the pool or the chain has, the chain from there on.
+    fn promote_orphans(&mut self, sender: &AccountId, from: u64) {
+        loop {
+            let next = self.account_queues.get(sender).map_or(from, |q| q.next_nonce(from));
+            let Some(orphans) = self.orphans.get_mut(sender) else {
+                return;
+            };
+            let Some(orphan) = orphans.remove(&next) else {
+                break;
+            };
+            if self.transactions.len() >= self.config.max_size && !self.evict_lowest_fee(&orphan.tx) {
+                // No room yet, stays an orphan
+                if let Some(orphans) = self.orphans.get_mut(sender) {
+                    orphans.insert(next, orphan);
+                }
+                break;
+            }
+            debug!("Orphan transaction {} promoted", orphan.tx.hash());
+            self.insert_transaction(orphan.tx, orphan.fee);
+        }
+        if self.orphans.get(sender).is_some_and(|o| o.is_empty()) {
+            self.orphans.remove(sender);
+        }
+    }
+
+    /// Transactions of an account, pooled and orphaned
+    fn account_len(&self, account: &AccountId) -> usize {
+        self.account_queues.get(account).map_or(0, |q| q.len())
+            + self.orphans.get(account).map_or(0, |o| o.len())
+    }
+
+    /// Transactions waiting for a missing earlier nonce
+    pub fn orphan_count(&self) -> usize {
+        self.orphans.values().map(|o| o.len()).sum()
+    }
+
     /// Insert transaction into all indexes
     fn insert_transaction(&mut self, tx: SignedTransaction, fee: Balance) {
         let hash = tx.hash();
@@ -628,7 +954,7 @@ impl TransactionPool {
         self.fees.insert(hash, fee);
 
         // Add to priority queue
-        self.priority_queue.push(PrioritizedTx::new(&tx, fee));
+        self.priority_queue.push(PrioritizedTx::new(&tx, fee, self.current_block));
 
         // Add to account queue
         self.account_queues
@@ -664,46 +990,56 @@ impl TransactionPool {
             }
         }
 
-        // Remove from pending set
-        self.pending.remove(hash);
-
         // Note: We don't remove from priority_queue (lazy cleanup)
         // Invalid entries are filtered during selection
 
         Some(tx)
     }
 
-    /// Evict lowest fee transaction to make room
-    fn evict_lowest_fee(&mut self, new_fee: Balance) -> bool {
-        // Find lowest fee transaction
-        let mut lowest_hash = None;
-        let mut lowest_fee = new_fee;
-
-        for (hash, &fee) in &self.fees {
-            if fee < lowest_fee {
-                lowest_fee = fee;
-                lowest_hash = Some(*hash);
+    /// Evict the lowest fee rate transaction to make room
+    fn evict_lowest_fee(&mut self, new_tx: &SignedTransaction) -> bool {
+        // Heap entries carry the fee rates (stale ones skipped)
+        let lowest = self
+            .priority_queue
+            .iter()
+            .filter(|entry| self.transactions.contains_key(&entry.hash))
+            .min()
+            .map(|entry| (entry.hash, entry.fee_rate));
+
+        match lowest {
+            Some((hash, rate)) if rate < fee_rate(new_tx) => {
+                if let Some(tx) = self.remove_internal(&hash) {
+                    self.notify_dropped(&tx, DropReason::Evicted);
+                }
+                self.stats.total_evicted += 1;
+                info!("Evicted transaction {} (fee rate={})", hash, rate);
+                true
             }
+            _ => false,
         }
+    }
 
-        if let Some(hash) = lowest_hash {
-            self.remove_internal(&hash);
-            self.stats.total_evicted += 1;
-            info!("Evicted transaction {} (fee={})", hash, lowest_fee);
-            true
-        } else {
-            false
-        }
+    /// Every pending transaction, orphans included, in nonce order
+    pub fn transactions(&self) -> Vec<SignedTransaction> {
+        let mut txs: Vec<_> = self.transactions.values().chain(self.orphan_txs()).cloned().collect();
+        txs.sort_by_key(|tx| tx.transaction.nonce);
+        txs
     }
 
-    /// Get a transaction by hash
+    fn orphan_txs(&self) -> impl Iterator<Item = &SignedTransaction> {
+        self.orphans.values().flat_map(|o| o.values()).map(|o| &o.tx)
+    }
+
+    /// Get a transaction by hash (pooled or orphaned)
     pub fn get(&self, hash: &Hash) -> Option<&SignedTransaction> {
-        self.transactions.get(hash)
+        self.transactions
+            .get(hash)
+            .or_else(|| self.orphan_txs().find(|tx| tx.hash() == *hash))
     }
 
     /// Check if transaction exists
     pub fn contains(&self, hash: &Hash) -> bool {
-        self.transactions.contains_key(hash)
+        self.get(hash).is_some()
     }
 
     /// Get ready transactions for an account (sequential nonces)
@@ -722,106 +1058,94 @@ impl TransactionPool {
 
     /// Select best transactions for block production
     pub fn select_transactions(&self, max_count: usize) -> Vec<SignedTransaction> {
-        let mut selected = Vec::with_capacity(max_count);
+        self.select_ordered(max_count, |_| 0)
+    }
+
+    /// Select transactions with state-aware nonce tracking
+    pub fn select_transactions_with_state(
+        &self,
+        max_count: usize,
+        state: &mut StateBackend,
+    ) -> Vec<SignedTransaction> {
+        self.select_ordered(max_count, |sender| {
+            state.get_account(sender).ok().flatten().map(|a| a.nonce).unwrap_or(0)
+        })
+    }
+
+    /// Transactions by fee rate, each sender's in nonce order
+    /// A transaction whose nonce isn't due yet waits for its predecessor, then
+    /// competes again with its own fee rate.
+    fn select_ordered(
+        &self,
+        max_count: usize,
+        mut account_nonce: impl FnMut(&AccountId) -> u64,
+    ) -> Vec<SignedTransaction> {
+        let mut selected = Vec::with_capacity(max_count.min(self.transactions.len()));
         let mut seen_hashes = HashSet::new();
         let mut account_nonces: HashMap<AccountId, u64> = HashMap::new();
+        let mut deferred: HashMap<(AccountId, u64), PrioritizedTx> = HashMap::new();
 
         // Clone priority queue for iteration
         let mut heap = self.priority_queue.clone();
 
         while selected.len() < max_count {
-            let entry = match heap.pop() {
-                Some(e) => e,
-                None => break,
+            let Some(entry) = heap.pop() else {
+                break;
             };
 
             // Skip if already processed or removed
             if seen_hashes.contains(&entry.hash) {
                 continue;
             }
-            if !self.transactions.contains_key(&entry.hash) {
+            let Some(tx) = self.transactions.get(&entry.hash) else {
                 continue;
-            }
-
-            let tx = match self.transactions.get(&entry.hash) {
-                Some(t) => t,
-                None => continue,
             };
 
-            // Check nonce ordering
-            let expected_nonce = account_nonces
-                .get(&entry.sender)
-                .copied()
-                .unwrap_or(0);
+            let expected_nonce = *account_nonces
+                .entry(entry.sender)
+                .or_insert_with(|| account_nonce(&entry.sender));
 
             if entry.nonce < expected_nonce {
                 // Old nonce, skip
                 continue;
             }
             if entry.nonce > expected_nonce {
-                // Gap in nonces, skip for now (might need earlier tx first)
+                // Needs its predecessor first
+                deferred.insert((entry.sender, entry.nonce), entry);
                 continue;
             }
 
             seen_hashes.insert(entry.hash);
             account_nonces.insert(entry.sender, entry.nonce + 1);
             selected.push(tx.clone());
+
+            if let Some(next) = deferred.remove(&(entry.sender, entry.nonce + 1)) {
+                heap.push(next);
+            }
         }
 
         selected
     }
 
-    /// Select transactions with state-aware nonce tracking
-    pub fn select_transactions_with_state(
-        &self,
-        max_count: usize,
-        state: &mut StateBackend,
-    ) -> Vec<SignedTransaction> {
-        let mut selected = Vec::with_capacity(max_count);
-        let mut account_nonces: HashMap<AccountId, u64> = HashMap::new();
-        let mut seen_hashes = HashSet::new();
-
-        let mut heap = self.priority_queue.clone();
-
-        while selected.len() < max_count {
-            let entry = match heap.pop() {
-                Some(e) => e,
-                None => break,
-            };
+    /// Pool content in inclusion order
+    /// `ready`: what the next blocks would include, in order; `future`: the
+    /// transactions waiting for an earlier nonce (orphans included), by
+    /// sender and nonce.
+    pub fn content(&self, state: &mut StateBackend) -> PoolContent {
+        let ready = self.select_transactions_with_state(self.transactions.len(), state);
+        let included: HashSet<Hash> = ready.iter().map(|tx| tx.hash()).collect();
 
-            if seen_hashes.contains(&entry.hash) || !self.transactions.contains_key(&entry.hash) {
-                continue;
-            }
-
-            let tx = match self.transactions.get(&entry.hash) {
-                Some(t) => t,
-                None => continue,
-            };
-
-            // Get expected nonce from cache or state
-            let expected_nonce = if let Some(&n) = account_nonces.get(&entry.sender) {
-                n
-            } else {
-                let account_nonce = state
-                    .get_account(&entry.sender)
-                    .ok()
-                    .flatten()
-                    .map(|a| a.nonce)
-                    .unwrap_or(0);
-                account_nonces.insert(entry.sender, account_nonce);
-                account_nonce
-            };
-
-            if entry.nonce != expected_nonce {
-                continue;
-            }
-
-            seen_hashes.insert(entry.hash);
-            account_nonces.insert(entry.sender, entry.nonce + 1);
-            selected.push(tx.clone());
-        }
+        let mut future: Vec<SignedTransaction> = self
+            .transactions
+            .iter()
+            .filter(|(hash, _)| !included.contains(hash))
+            .map(|(_, tx)| tx)
+            .chain(self.orphan_txs())
+            .cloned()
+            .collect();
+        future.sort_by_key(|tx| (tx.transaction.sender, tx.transaction.nonce));
 
-        selected
+        PoolContent { ready, future }
     }
 
     /// Remove all transactions included in a block
@@ -848,23 +1172,92 @@ impl TransactionPool {
             .unwrap_or_default();
 
         for hash in stale_hashes {
-            self.remove_internal(&hash);
+            if let Some(tx) = self.remove_internal(&hash) {
+                self.notify_dropped(&tx, DropReason::Stale);
+            }
             self.stats.total_evicted += 1;
         }
+
+        let stale_orphans = match self.orphans.get_mut(account) {
+            Some(orphans) => {
+                let kept = orphans.split_off(&current_nonce);
+                std::mem::replace(orphans, kept)
+            }
+            None => BTreeMap::new(),
+        };
+        for orphan in stale_orphans.into_values() {
+            self.notify_dropped(&orphan.tx, DropReason::Stale);
+            self.stats.total_evicted += 1;
+        }
+        if self.orphans.get(account).is_some_and(|o| o.is_empty()) {
+            self.orphans.remove(account);
+        }
     }
 
-    /// Cleanup expired transactions
+    /// Update the pool for a new best block
+    /// Included transactions leave, the pending ones whose nonce the block
+    /// used are dropped as stale, orphans the block filled the gap of are
+    /// promoted, then expired ones are pruned.
+    pub fn on_block(&mut self, number: BlockNumber, block_txs: &[SignedTransaction]) {
+        self.remove_included(block_txs);
+
+        let mut next_nonces: HashMap<AccountId, u64> = HashMap::new();
+        for tx in block_txs {
+            let next = next_nonces.entry(tx.transaction.sender).or_default();
+            *next = (*next).max(tx.transaction.nonce + 1);
+        }
+        for (sender, next_nonce) in next_nonces {
+            self.remove_stale(&sender, next_nonce);
+            self.promote_orphans(&sender, next_nonce);
+        }
+
+        self.prune(number);
+    }
+
+    /// Cleanup expired transactions (too old, pending for too many blocks,
+    /// or past their validity window)
     pub fn cleanup_expired(&mut self) {
         let now = Instant::now();
+        let max_blocks = self.config.tx_expiration_blocks;
+        let current_block = self.current_block;
+        let is_expired = |tx: &SignedTransaction, added_at: Instant, added_block: Option<BlockNumber>| {
+            let blocks = match (current_block, added_block) {
+                (Some(current), Some(added)) => current.saturating_sub(added),
+                _ => 0,
+            };
+            let dead = current_block.is_some_and(|current| !tx.transaction.is_valid_at(current + 1));
+            dead || now.duration_since(added_at) > self.config.tx_expiration || (max_blocks > 0 && blocks >= max_blocks)
+        };
         let expired: Vec<Hash> = self
             .priority_queue
             .iter()
-            .filter(|e| now.duration_since(e.added_at) > self.config.tx_expiration)
+            .filter(|e| {
+                self.transactions
+                    .get(&e.hash)
+                    .is_some_and(|tx| is_expired(tx, e.added_at, e.added_block))
+            })
             .map(|e| e.hash)
             .collect();
 
+        let mut expired_orphans = Vec::new();
+        for orphans in self.orphans.values_mut() {
+            orphans.retain(|_, orphan| {
+                let keep = !is_expired(&orphan.tx, orphan.added_at, orphan.added_block);
+                if !keep {
+                    expired_orphans.push(orphan.tx.clone());
+                }
+                keep
+            });
+        }
+        self.orphans.retain(|_, orphans| !orphans.is_empty());
+        for tx in expired_orphans {
+            self.notify_dropped(&tx, DropReason::Expired);
+            self.stats.total_evicted += 1;
+        }
+
         for hash in expired {
-            if self.remove_internal(&hash).is_some() {
+            if let Some(tx) = self.remove_internal(&hash) {
+                self.notify_dropped(&tx, DropReason::Expired);
                 self.stats.total_evicted += 1;
                 debug!("Expired transaction {} removed", hash);
             }
@@ -876,6 +1269,8 @@ impl TransactionPool {
 
     /// Prune old transactions
     pub fn prune(&mut self, current_block: u64) {
+        self.current_block = Some(current_block);
+
         // Cleanup expired
         self.cleanup_expired();
 
@@ -883,15 +1278,20 @@ impl TransactionPool {
         let valid_hashes: HashSet<_> = self.transactions.keys().copied().collect();
         let mut new_heap = BinaryHeap::new();
 
-        for entry in self.priority_queue.drain() {
+        for mut entry in self.priority_queue.drain() {
             if valid_hashes.contains(&entry.hash) {
+                // Restored before the first block: counted from this one
+                entry.added_block.get_or_insert(current_block);
                 new_heap.push(entry);
             }
         }
 
         self.priority_queue = new_heap;
+        for orphan in self.orphans.values_mut().flat_map(|o| o.values_mut()) {
+            orphan.added_block.get_or_insert(current_block);
+        }
 
-        info!(
+        debug!(
             "Mempool pruned at block {}: {} transactions remaining",
             current_block,
             self.transactions.len()
@@ -913,6 +1313,12 @@ impl TransactionPool {
         &self.stats
     }
 
+    /// Changes whenever transactions enter or leave the pool (to save it only then)
+    pub fn revision(&self) -> u64 {
+        let stats = &self.stats;
+        stats.total_added + stats.total_removed + stats.total_evicted + stats.total_replaced
+    }
+
     /// Get pending count for an account
     pub fn pending_count(&self, account: &AccountId) -> usize {
         self.account_queues
@@ -921,22 +1327,30 @@ impl TransactionPool {
             .unwrap_or(0)
     }
 
+    /// Nonce of the account's next transaction: the first from the chain
+    /// nonce that the pool doesn't hold (orphans behind a gap don't count)
+    pub fn next_nonce(&self, account: &AccountId, account_nonce: u64) -> u64 {
+        self.account_queues.get(account).map_or(account_nonce, |q| q.next_nonce(account_nonce))
+    }
+
     /// Get total pending fees
     pub fn total_fees(&self) -> Balance {
         self.fees.values().sum()
     }
 
-    /// Remove all transactions for an account
+    /// Remove all transactions for an account (orphans included)
     pub fn remove_account_transactions(&mut self, account: &AccountId) -> Vec<SignedTransaction> {
         let hashes: Vec<Hash> = self
             .account_queues
             .get(account)
             .map(|q| q.by_nonce.values().copied().collect())
             .unwrap_or_default();
+        let orphans = self.orphans.remove(account).unwrap_or_default();
 
         hashes
             .iter()
             .filter_map(|h| self.remove(h))
+            .chain(orphans.into_values().map(|o| o.tx))
             .collect()
     }
 
@@ -974,6 +1388,10 @@ mod tests {
                 amount, // Fee is based on call type, but we test with different amounts
             },
             timestamp: 0,
+            genesis_hash: Hash::ZERO,
+            valid_until: None,
+            max_fee: None,
+            tip: 0,
         };
 
         let hash_input = [&sender[..], &nonce.to_le_bytes()[..], &amount.to_le_bytes()[..]].concat();
@@ -990,6 +1408,10 @@ mod tests {
             nonce,
             call: TransactionCall::Stake { amount },
             timestamp: 0,
+            genesis_hash: Hash::ZERO,
+            valid_until: None,
+            max_fee: None,
+            tip: 0,
         };
 
         let hash_input = [&sender[..], &nonce.to_le_bytes()[..], &amount.to_le_bytes()[..]].concat();
@@ -1023,6 +1445,57 @@ mod tests {
         assert!(matches!(result, Err(PoolError::AlreadyExists)));
     }
 
+    #[test]
+    fn test_wrong_chain_rejected() {
+        let mut pool = TransactionPool::new(100);
+        pool.config.verify_signatures = false;
+        let testnet = Hash::hash(b"testnet");
+        pool.set_genesis_hash(testnet);
+
+        // Signed for another genesis: a replay from another network
+        let result = pool.add(create_test_tx([1; 32], 0));
+        assert!(matches!(result, Err(PoolError::WrongChain { expected, got }) if expected == testnet && got == Hash::ZERO));
+        assert_eq!(pool.stats.total_rejected, 1);
+
+        let mut tx = create_test_tx([1; 32], 0);
+        tx.transaction.genesis_hash = testnet;
+        assert!(pool.add(tx).is_ok());
+    }
+
+    #[test]
+    fn test_mortal_transaction() {
+        let mut pool = TransactionPool::new(100);
+        pool.config.verify_signatures = false;
+        pool.prune(10);
+
+        // Block 11 comes next: valid until 10 is too late
+        let mut tx = create_test_tx([1; 32], 0);
+        tx.transaction.valid_until = Some(10);
+        assert!(matches!(pool.add(tx.clone()), Err(PoolError::Expired { valid_until: 10, current: 10 })));
+
+        tx.transaction.valid_until = Some(11);
+        pool.add(tx).unwrap();
+        pool.prune(11);
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn test_max_fee() {
+        let mut pool = TransactionPool::new(100);
+        pool.config.verify_signatures = false;
+        pool.set_base_fee(3 * MIN_BASE_FEE);
+
+        // Signed for the static fee, the base fee tripled since
+        let mut tx = create_test_tx([1; 32], 0);
+        let fee = tx.transaction.call.base_fee();
+        tx.transaction.max_fee = Some(fee);
+        let result = pool.add(tx.clone());
+        assert!(matches!(result, Err(PoolError::MaxFeeTooLow { max_fee, fee: need }) if max_fee == fee && need == 3 * fee));
+
+        tx.transaction.max_fee = Some(3 * fee);
+        pool.add(tx).unwrap();
+    }
+
     #[test]
     fn test_remove_transaction() {
         let mut pool = TransactionPool::new(100);
@@ -1123,6 +1596,170 @@ mod tests {
         assert_eq!(selected[0].transaction.sender, AccountId::from_bytes([2; 32]));
     }
 
+    fn create_memo_tx(sender: [u8; 32], nonce: u64, memo_len: usize) -> SignedTransaction {
+        let tx = Transaction {
+            sender: AccountId::from_bytes(sender),
+            nonce,
+            call: TransactionCall::TransferWithMemo {
+                to: AccountId::from_bytes([2; 32]),
+                amount: 1000,
+                memo: vec![b'm'; memo_len],
+            },
+            timestamp: 0,
+            genesis_hash: Hash::ZERO,
+            valid_until: None,
+            max_fee: None,
+            tip: 0,
+        };
+
+        let hash_input = [&sender[..], &nonce.to_le_bytes()[..], &memo_len.to_le_bytes()[..]].concat();
+        SignedTransaction {
+            transaction: tx,
+            signature: Signature64([0; 64]),
+            hash: Some(Hash::hash(&hash_input)),
+        }
+    }
+
+    #[test]
+    fn test_fee_rate_ordering() {
+        let mut pool = TransactionPool::new(100);
+        pool.config.verify_signatures = false;
+
+        // Same fee (5000), the memo makes the transfer bigger
+        let memo_tx = create_memo_tx([1; 32], 0, 40);
+        let stake_tx = create_stake_tx([2; 32], 0, 10000);
+        assert_eq!(memo_tx.transaction.call.base_fee(), stake_tx.transaction.call.base_fee());
+        assert!(fee_rate(&memo_tx) < fee_rate(&stake_tx));
+
+        pool.add(memo_tx).unwrap();
+        pool.add(stake_tx).unwrap();
+        let selected = pool.select_transactions(2);
+        assert_eq!(selected[0].transaction.sender, AccountId::from_bytes([2; 32]));
+    }
+
+    #[test]
+    fn test_proposal_validation() {
+        use crate::contracts::governance::{ProposalType, MAX_DESCRIPTION_LEN};
+
+        let mut pool = TransactionPool::new(100);
+        pool.config.verify_signatures = false;
+        let proposal = |nonce: u64, description: String| {
+            let tx = Transaction {
+                sender: AccountId::from_bytes([1; 32]),
+                nonce,
+                call: TransactionCall::SubmitProposal {
+                    proposal_type: ProposalType::AddValidator { validator: AccountId::from_bytes([2; 32]) },
+                    description: Some(description),
+                },
+                timestamp: 0,
+                genesis_hash: Hash::ZERO,
+                valid_until: None,
+                max_fee: None,
+                tip: 0,
+            };
+            SignedTransaction {
+                transaction: tx,
+                signature: Signature64([0; 64]),
+                hash: Some(Hash::hash(&nonce.to_le_bytes())),
+            }
+        };
+
+        let result = pool.add(proposal(0, "x".repeat(MAX_DESCRIPTION_LEN + 1)));
+        assert!(matches!(result, Err(PoolError::Validation(_))));
+        pool.add(proposal(0, "Add a validator".to_string())).unwrap();
+    }
+
+    #[test]
+    fn test_select_waits_for_predecessor() {
+        let mut pool = TransactionPool::new(100);
+        pool.config.verify_signatures = false;
+
+        // Best fee rate behind a cheap nonce 0 of the same sender
+        pool.add(create_test_tx([1; 32], 0)).unwrap();
+        pool.add(create_stake_tx([1; 32], 1, 10000)).unwrap();
+        pool.add(create_memo_tx([3; 32], 0, 20)).unwrap();
+
+        let selected = pool.select_transactions(10);
+        let order: Vec<_> = selected
+            .iter()
+            .map(|tx| (tx.transaction.sender, tx.transaction.nonce))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                (AccountId::from_bytes([3; 32]), 0),
+                (AccountId::from_bytes([1; 32]), 0),
+                (AccountId::from_bytes([1; 32]), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expiry_and_drop_notifications() {
+        let mut pool = TransactionPool::with_config(MempoolConfig {
+            tx_expiration_blocks: 3,
+            verify_signatures: false,
+            ..Default::default()
+        });
+        let mut drops = pool.drop_notifier().subscribe();
+
+        pool.prune(10);
+        pool.add(create_test_tx([1; 32], 0)).unwrap();
+        pool.add(create_test_tx([2; 32], 0)).unwrap();
+        pool.on_block(12, &[]);
+        assert_eq!(pool.len(), 2);
+
+        // Another transaction of [2; 32] used nonce 0
+        pool.on_block(13, &[create_test_tx_with_fee([2; 32], 0, 5)]);
+        assert_eq!(pool.len(), 0);
+
+        let mut reasons: Vec<_> = std::iter::from_fn(|| drops.try_recv().ok())
+            .map(|dropped| (dropped.sender, dropped.reason))
+            .collect();
+        reasons.sort_by_key(|(sender, _)| *sender);
+        assert_eq!(
+            reasons,
+            vec![
+                (AccountId::from_bytes([1; 32]), DropReason::Expired),
+                (AccountId::from_bytes([2; 32]), DropReason::Stale),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_orphans_promoted_when_gap_fills() {
+        let mut pool = TransactionPool::new(100);
+        pool.config.verify_signatures = false;
+        let sender = AccountId::from_bytes([1; 32]);
+
+        // Chain nonce 5: 7 and 6 wait for 5, 9 is past the gap limit
+        pool.add_with_account_nonce(create_test_tx([1; 32], 7), 5).unwrap();
+        pool.add_with_account_nonce(create_test_tx([1; 32], 6), 5).unwrap();
+        assert!(matches!(
+            pool.add_with_account_nonce(create_test_tx([1; 32], 8), 5),
+            Err(PoolError::InvalidNonce { expected: 5, got: 8 })
+        ));
+        assert_eq!((pool.len(), pool.orphan_count()), (0, 2));
+        assert!(pool.contains(&create_test_tx([1; 32], 7).hash()));
+        assert!(pool.select_transactions(10).is_empty());
+
+        // 5 fills the gap
+        pool.add_with_account_nonce(create_test_tx([1; 32], 5), 5).unwrap();
+        assert_eq!((pool.len(), pool.orphan_count()), (3, 0));
+        let nonces: Vec<_> = pool.ready_transactions(&sender, 5).iter().map(|tx| tx.transaction.nonce).collect();
+        assert_eq!(nonces, vec![5, 6, 7]);
+
+        // A block using nonces 8 and 9 elsewhere: 10 promoted, the stale 9 dropped
+        pool.add_with_account_nonce(create_test_tx([1; 32], 10), 8).unwrap();
+        pool.add_with_account_nonce(create_test_tx([1; 32], 9), 8).unwrap();
+        assert_eq!(pool.orphan_count(), 2);
+        let block_txs: Vec<_> = (5..10).map(|nonce| create_test_tx_with_fee([1; 32], nonce, 7)).collect();
+        pool.on_block(1, &block_txs);
+        let nonces: Vec<_> = pool.transactions().iter().map(|tx| tx.transaction.nonce).collect();
+        assert_eq!(nonces, vec![10]);
+        assert_eq!(pool.orphan_count(), 0);
+    }
+
     #[test]
     fn test_rate_limiting() {
         let mut pool = TransactionPool::with_config(MempoolConfig {
@@ -1142,6 +1779,28 @@ mod tests {
         assert!(matches!(result, Err(PoolError::RateLimitExceeded)));
     }
 
+    #[test]
+    fn test_restore_transactions() {
+        let config = MempoolConfig {
+            rate_limit_per_account: 2,
+            verify_signatures: false,
+            ..Default::default()
+        };
+        let mut pool = TransactionPool::with_config(config.clone());
+        pool.add(create_test_tx([1; 32], 1)).unwrap();
+        pool.add(create_test_tx([1; 32], 0)).unwrap();
+        let saved = pool.transactions();
+        assert_eq!(saved[0].transaction.nonce, 0);
+
+        // Not rate limited, duplicates dropped
+        let mut restarted = TransactionPool::with_config(config);
+        restarted.add(create_test_tx([1; 32], 2)).unwrap();
+        restarted.add(create_test_tx([1; 32], 3)).unwrap();
+        let duplicate = create_test_tx([1; 32], 3);
+        assert_eq!(restarted.restore([saved, vec![duplicate]].concat(), None), 2);
+        assert_eq!(restarted.len(), 4);
+    }
+
     #[test]
     fn test_per_account_limit() {
         let mut pool = TransactionPool::with_config(MempoolConfig {
@@ -1162,6 +1821,34 @@ mod tests {
         assert!(matches!(result, Err(PoolError::TooManyPerAccount { .. })));
     }
 
+    #[test]
+    fn test_fee_escalation() {
+        let mut pool = TransactionPool::with_config(MempoolConfig {
+            fee_escalation_free_slots: 2,
+            fee_escalation_pct: 50,
+            verify_signatures: false,
+            ..Default::default()
+        });
+        assert_eq!(pool.escalated_min_fee(1), 1_000);
+        assert_eq!(pool.escalated_min_fee(2), 1_500);
+        assert_eq!(pool.escalated_min_fee(3), 2_250);
+
+        let sender = [1; 32];
+        pool.add(create_test_tx(sender, 0)).unwrap();
+        pool.add(create_test_tx(sender, 1)).unwrap();
+
+        // Third transfer (1000) is under the escalated fee, a stake (5000) pays it
+        let result = pool.add(create_test_tx(sender, 2));
+        assert!(matches!(
+            result,
+            Err(PoolError::EscalatedFeeTooLow { fee: 1_000, required: 1_500, pooled: 2 })
+        ));
+        pool.add(create_stake_tx(sender, 2, 10000)).unwrap();
+
+        // Other accounts are not affected
+        pool.add(create_test_tx([3; 32], 0)).unwrap();
+    }
+
     #[test]
     fn test_replace_by_fee() {
         let mut pool = TransactionPool::with_config(MempoolConfig {
@@ -1186,6 +1873,44 @@ mod tests {
         assert_eq!(pool.stats.total_replaced, 1);
     }
 
+    fn tipped(mut tx: SignedTransaction, tip: Balance) -> SignedTransaction {
+        tx.transaction.tip = tip;
+        tx.hash = Some(tx.transaction.hash());
+        tx
+    }
+
+    #[test]
+    fn test_replace_by_tip() {
+        let mut pool = TransactionPool::new(100);
+        pool.config.verify_signatures = false;
+        let sender = [1; 32];
+        pool.add(create_test_tx(sender, 0)).unwrap();
+
+        // Same transfer, tip under the 10% bump over the 1_000 static fee
+        let result = pool.add(tipped(create_test_tx(sender, 0), 50));
+        assert!(matches!(result, Err(PoolError::RbfFeeInsufficient { need_pct: 10 })));
+
+        let replacement = tipped(create_test_tx(sender, 0), 100);
+        pool.add(replacement.clone()).unwrap();
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool.stats.total_replaced, 1);
+        assert!(pool.contains(&replacement.hash()));
+        assert_eq!(pool.total_fees(), 1_100);
+    }
+
+    #[test]
+    fn test_tip_ordering() {
+        let mut pool = TransactionPool::new(100);
+        pool.config.verify_signatures = false;
+
+        // Same call, the tipped one goes first
+        pool.add(create_test_tx([1; 32], 0)).unwrap();
+        pool.add(tipped(create_test_tx([2; 32], 0), 500)).unwrap();
+
+        let selected = pool.select_transactions(2);
+        assert_eq!(selected[0].transaction.sender, AccountId::from_bytes([2; 32]));
+    }
+
     #[test]
     fn test_remove_stale() {
         let mut pool = TransactionPool::new(100);
@@ -1282,6 +2007,23 @@ mod tests {
         assert_eq!(pool.pending_count(&sender), 3);
     }
 
+    #[test]
+    fn test_next_nonce() {
+        let mut pool = TransactionPool::new(100);
+        pool.config.verify_signatures = false;
+        let sender = AccountId::from_bytes([1; 32]);
+        assert_eq!(pool.next_nonce(&sender, 4), 4);
+
+        // Chain nonce 4, pending 4 and 5, orphan 7
+        for nonce in [4, 5, 7] {
+            pool.add_with_account_nonce(create_test_tx([1; 32], nonce), 4).unwrap();
+        }
+        assert_eq!(pool.next_nonce(&sender, 4), 6);
+        // Nonce 4 included
+        assert_eq!(pool.next_nonce(&sender, 5), 6);
+        assert_eq!(pool.next_nonce(&AccountId::from_bytes([2; 32]), 0), 0);
+    }
+
     #[test]
     fn test_contains() {
         let mut pool = TransactionPool::new(100);
@@ -0,0 +1,258 @@
+// Metrics - Prometheus registry of the node
+// - Gauges (height, finality, mempool, peers, state cache, invariants) are
+//   refreshed from the node when /metrics is scraped
+// - Histograms (block production/import and its phases, RPC requests, finality
+//   rounds) and counters (RPC rate limiting, production deadline) are updated
+//   where the work happens
+// - Served by rpc::prometheus on --prometheus-port
+
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+use crate::node::producer::ProductionTimings;
+use crate::rpc::RateLimited;
+use std::time::Duration;
+
+/// Prefix of every metric name
+const NAMESPACE: &str = "kratos";
+
+/// Label of RPC requests to methods the server does not know
+/// (keeps the label set bounded)
+pub const UNKNOWN_METHOD: &str = "unknown";
+
+/// Block production/import buckets (seconds)
+const BLOCK_BUCKETS: &[f64] = &[0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0];
+
+/// RPC request buckets (seconds)
+const RPC_BUCKETS: &[f64] = &[0.0005, 0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.5, 1.0, 5.0];
+
+/// Finality round buckets (seconds, rounds time out after 6s with backoff)
+const ROUND_BUCKETS: &[f64] = &[0.1, 0.25, 0.5, 1.0, 2.0, 4.0, 6.0, 12.0, 24.0, 48.0, 96.0];
+
+/// Node metrics, shared by the node, the RPC server and the exporter
+pub struct NodeMetrics {
+    registry: Registry,
+    pub block_height: IntGauge,
+    pub finalized_height: IntGauge,
+    pub mempool_transactions: IntGauge,
+    pub peers: IntGauge,
+    pub block_production_seconds: Histogram,
+    /// Labelled by phase (select, execute, seal)
+    pub block_production_phase_seconds: HistogramVec,
+    /// Blocks cut short by the production deadline
+    pub block_production_deadline_hits: IntCounter,
+    /// Selected transactions left in the mempool by the deadline
+    pub block_production_deferred_transactions: IntCounter,
+    pub block_import_seconds: Histogram,
+    /// Labelled by method
+    pub rpc_request_seconds: HistogramVec,
+    /// RPC calls refused by the rate limiter, labelled by reason (throttled, banned)
+    pub rpc_rate_limited: IntCounterVec,
+    /// IPs banned by the RPC rate limiter
+    pub rpc_bans: IntCounter,
+    /// Labelled by outcome (finalized, failed)
+    pub finality_round_seconds: HistogramVec,
+    pub state_cache_hits: IntGauge,
+    pub state_cache_misses: IntGauge,
+    pub state_cache_evictions: IntGauge,
+    pub tx_relay_announced: IntGauge,
+    pub tx_relay_duplicates: IntGauge,
+    pub tx_relay_pulled: IntGauge,
+    pub tx_relay_served: IntGauge,
+    /// Bytes saved by announcing transactions instead of pushing them
+    pub tx_relay_bytes_saved: IntGauge,
+    /// 1 while every SPEC invariant holds
+    pub invariants_healthy: IntGauge,
+}
+
+impl NodeMetrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let gauge = |name: &str, help: &str| {
+            let gauge = IntGauge::with_opts(Opts::new(name, help).namespace(NAMESPACE))
+                .expect("valid metric options");
+            registry.register(Box::new(gauge.clone())).expect("metric registered once");
+            gauge
+        };
+        let histogram = |name: &str, help: &str, buckets: &[f64]| {
+            let opts = HistogramOpts::new(name, help).namespace(NAMESPACE).buckets(buckets.to_vec());
+            let histogram = Histogram::with_opts(opts).expect("valid metric options");
+            registry.register(Box::new(histogram.clone())).expect("metric registered once");
+            histogram
+        };
+        let counter = |name: &str, help: &str| {
+            let counter = IntCounter::with_opts(Opts::new(name, help).namespace(NAMESPACE))
+                .expect("valid metric options");
+            registry.register(Box::new(counter.clone())).expect("metric registered once");
+            counter
+        };
+        let counter_vec = |name: &str, help: &str, label: &str| {
+            let counter = IntCounterVec::new(Opts::new(name, help).namespace(NAMESPACE), &[label])
+                .expect("valid metric options");
+            registry.register(Box::new(counter.clone())).expect("metric registered once");
+            counter
+        };
+        let histogram_vec = |name: &str, help: &str, label: &str, buckets: &[f64]| {
+            let opts = HistogramOpts::new(name, help).namespace(NAMESPACE).buckets(buckets.to_vec());
+            let histogram = HistogramVec::new(opts, &[label]).expect("valid metric options");
+            registry.register(Box::new(histogram.clone())).expect("metric registered once");
+            histogram
+        };
+
+        Self {
+            block_height: gauge("block_height", "Height of the best block"),
+            finalized_height: gauge("finalized_height", "Height of the last finalized block"),
+            mempool_transactions: gauge("mempool_transactions", "Transactions waiting in the mempool"),
+            peers: gauge("peers", "Connected peers"),
+            block_production_seconds: histogram(
+                "block_production_seconds",
+                "Time to produce and store a block",
+                BLOCK_BUCKETS,
+            ),
+            block_production_phase_seconds: histogram_vec(
+                "block_production_phase_seconds",
+                "Time spent per block production phase",
+                "phase",
+                BLOCK_BUCKETS,
+            ),
+            block_production_deadline_hits: counter(
+                "block_production_deadline_hits_total",
+                "Blocks cut short by the production deadline",
+            ),
+            block_production_deferred_transactions: counter(
+                "block_production_deferred_transactions_total",
+                "Selected transactions deferred to a later block by the production deadline",
+            ),
+            block_import_seconds: histogram(
+                "block_import_seconds",
+                "Time to validate, execute and store an imported block",
+                BLOCK_BUCKETS,
+            ),
+            rpc_request_seconds: histogram_vec(
+                "rpc_request_seconds",
+                "JSON-RPC request latency",
+                "method",
+                RPC_BUCKETS,
+            ),
+            rpc_rate_limited: counter_vec(
+                "rpc_rate_limited_total",
+                "RPC calls refused by the rate limiter",
+                "reason",
+            ),
+            rpc_bans: counter("rpc_bans_total", "IPs banned by the RPC rate limiter"),
+            finality_round_seconds: histogram_vec(
+                "finality_round_seconds",
+                "Duration of finality rounds",
+                "outcome",
+                ROUND_BUCKETS,
+            ),
+            state_cache_hits: gauge("state_cache_hits", "State reads served from the account cache"),
+            state_cache_misses: gauge("state_cache_misses", "State reads that went to the database"),
+            state_cache_evictions: gauge("state_cache_evictions", "Accounts evicted from the cache"),
+            tx_relay_announced: gauge("tx_relay_announced", "Transaction hashes announced to peers"),
+            tx_relay_duplicates: gauge("tx_relay_duplicates", "Announced transactions already known (not pulled)"),
+            tx_relay_pulled: gauge("tx_relay_pulled", "Transactions pulled after an announcement"),
+            tx_relay_served: gauge("tx_relay_served", "Transactions served to peers' pulls"),
+            tx_relay_bytes_saved: gauge(
+                "tx_relay_bytes_saved",
+                "Relay bytes saved by announcing transactions instead of pushing them",
+            ),
+            invariants_healthy: gauge("invariants_healthy", "1 while every SPEC invariant holds"),
+            registry,
+        }
+    }
+
+    /// Record the latency of an RPC request
+    pub fn observe_rpc(&self, method: &str, elapsed: Duration) {
+        self.rpc_request_seconds
+            .with_label_values(&[method])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Record the phases of a produced block
+    pub fn observe_production(&self, timings: &ProductionTimings) {
+        for (phase, elapsed) in [("select", timings.select), ("execute", timings.execute), ("seal", timings.seal)] {
+            self.block_production_phase_seconds
+                .with_label_values(&[phase])
+                .observe(elapsed.as_secs_f64());
+        }
+        if timings.deadline_hit() {
+            self.block_production_deadline_hits.inc();
+            self.block_production_deferred_transactions.inc_by(timings.deferred as u64);
+        }
+    }
+
+    /// Record an RPC call refused by the rate limiter
+    pub fn observe_rate_limited(&self, refusal: RateLimited) {
+        let reason = match refusal {
+            RateLimited::Throttled { .. } => "throttled",
+            RateLimited::BanStarted { .. } => {
+                self.rpc_bans.inc();
+                "banned"
+            }
+            RateLimited::Banned { .. } => "banned",
+        };
+        self.rpc_rate_limited.with_label_values(&[reason]).inc();
+    }
+
+    /// Record a completed finality round
+    pub fn observe_round(&self, finalized: bool, elapsed: Duration) {
+        let outcome = if finalized { "finalized" } else { "failed" };
+        self.finality_round_seconds
+            .with_label_values(&[outcome])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Text exposition format (served at /metrics)
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        // Encoding only fails on a writer error, impossible with a Vec
+        let _ = TextEncoder::new().encode(&self.registry.gather(), &mut buffer);
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+impl Default for NodeMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_metrics() {
+        let metrics = NodeMetrics::new();
+        metrics.block_height.set(42);
+        metrics.tx_relay_bytes_saved.set(4096);
+        metrics.observe_rpc("chain_getInfo", Duration::from_millis(2));
+        metrics.observe_round(true, Duration::from_secs(1));
+        metrics.block_import_seconds.observe(0.02);
+        metrics.observe_production(&ProductionTimings {
+            select: Duration::from_millis(3),
+            execute: Duration::from_millis(1_700),
+            seal: Duration::from_millis(40),
+            executed: 768,
+            deferred: 232,
+        });
+        metrics.observe_rate_limited(RateLimited::BanStarted { retry_after: 300 });
+        metrics.observe_rate_limited(RateLimited::Banned { retry_after: 299 });
+
+        let text = metrics.render();
+        assert!(text.contains("# TYPE kratos_block_height gauge"));
+        assert!(text.contains("kratos_block_height 42"));
+        assert!(text.contains("kratos_tx_relay_bytes_saved 4096"));
+        assert!(text.contains("kratos_rpc_request_seconds_count{method=\"chain_getInfo\"} 1"));
+        assert!(text.contains("kratos_finality_round_seconds_count{outcome=\"finalized\"} 1"));
+        assert!(text.contains("kratos_block_import_seconds_count 1"));
+        assert!(text.contains("kratos_rpc_rate_limited_total{reason=\"banned\"} 2"));
+        assert!(text.contains("kratos_rpc_bans_total 1"));
+        assert!(text.contains("kratos_block_production_phase_seconds_count{phase=\"execute\"} 1"));
+        assert!(text.contains("kratos_block_production_deadline_hits_total 1"));
+        assert!(text.contains("kratos_block_production_deferred_transactions_total 232"));
+    }
+}
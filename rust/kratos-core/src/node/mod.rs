@@ -1,15 +1,23 @@
 // Node - Orchestrateur du nœud KratOs
+pub mod alerts;
+pub mod keystore;
 pub mod mempool;
 pub mod producer;
 pub mod service;
 pub mod finality_integration;
+pub mod forensics;
+pub mod invariants;
+pub mod metrics;
+pub mod persistence;
 
+pub use keystore::{KeyScheme, KeyType, Keystore, KeystoreEntry, KeystoreError};
 pub use mempool::{MempoolConfig, PoolError, PoolStats, TransactionPool};
 pub use producer::{
     BlockProducer, BlockValidator, ExecutionResult, FinalityTracker,
     ProducerConfig, ProductionError, TransactionExecutor, ValidationError,
 };
 pub use service::KratOsNode;
+pub use invariants::{InvariantMonitor, InvariantReport, SpecInvariant};
 pub use finality_integration::{
     FinalityIntegration, FinalityStatus, NodeFinalitySigner, NodeFinalityBroadcaster,
     FinalityMessageSender,
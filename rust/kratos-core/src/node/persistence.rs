@@ -0,0 +1,157 @@
+// Persistence - State saved on a clean shutdown, restored on the next start
+// A restart otherwise slowly rebuilds:
+// - the peer set: known-good peers, dialed right away instead of waiting for discovery
+// - the sync cursor: where the chain and the network were when the node stopped
+// The file is removed once read: after a crash, nothing stale is replayed.
+// The known-peers set outlives it: kept in the database, merged at every
+// save, and exported/imported with `kratos-node peers` to seed new nodes.
+// (The mempool is saved in the database instead, periodically: see
+// KratOsNode::save_mempool.)
+
+use crate::types::{BlockNumber, Hash};
+use libp2p::{Multiaddr, PeerId};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tracing::warn;
+
+/// File in the data directory
+pub const SHUTDOWN_STATE_FILE: &str = "shutdown_state.json";
+
+/// Peers the known-peers set holds at most
+pub const MAX_KNOWN_PEERS: usize = 500;
+
+/// A peer to dial again
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedPeer {
+    pub peer_id: String,
+    pub addresses: Vec<String>,
+}
+
+impl SavedPeer {
+    pub fn new(peer_id: &PeerId, addresses: &[Multiaddr]) -> Self {
+        Self {
+            peer_id: peer_id.to_string(),
+            addresses: addresses.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    /// Peer ID and dialable addresses (None if unreadable)
+    pub fn parse(&self) -> Option<(PeerId, Vec<Multiaddr>)> {
+        let peer_id = self.peer_id.parse().ok()?;
+        let addresses: Vec<Multiaddr> = self.addresses.iter().filter_map(|a| a.parse().ok()).collect();
+        (!addresses.is_empty()).then_some((peer_id, addresses))
+    }
+}
+
+/// Merge `fresh` peers into the known-peers set: fresh entries first and
+/// replacing stored ones, unreadable entries dropped, at most MAX_KNOWN_PEERS
+pub fn merge_known_peers(stored: Vec<SavedPeer>, fresh: Vec<SavedPeer>) -> Vec<SavedPeer> {
+    let mut merged: Vec<SavedPeer> = Vec::new();
+    for peer in fresh.into_iter().chain(stored) {
+        if peer.parse().is_some() && !merged.iter().any(|p| p.peer_id == peer.peer_id) {
+            merged.push(peer);
+        }
+    }
+    merged.truncate(MAX_KNOWN_PEERS);
+    merged
+}
+
+/// Sync progress when the node stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncCursor {
+    /// Best imported block
+    pub best_block: BlockNumber,
+    pub best_hash: Hash,
+    /// Best height seen on the network
+    pub network_height: BlockNumber,
+}
+
+/// What a clean shutdown leaves for the next start
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShutdownState {
+    /// Unix time of the shutdown
+    pub saved_at: u64,
+    pub peers: Vec<SavedPeer>,
+    pub sync: SyncCursor,
+}
+
+impl ShutdownState {
+    /// Write to the data directory (replaces a previous state atomically)
+    pub fn save(&self, data_dir: &Path) -> Result<(), PersistenceError> {
+        let content = serde_json::to_vec(self)
+            .map_err(|e| PersistenceError::Serialization(e.to_string()))?;
+
+        let tmp = data_dir.join(format!("{}.tmp", SHUTDOWN_STATE_FILE));
+        std::fs::write(&tmp, content).map_err(|e| PersistenceError::Io(e.to_string()))?;
+        std::fs::rename(&tmp, data_dir.join(SHUTDOWN_STATE_FILE)).map_err(|e| PersistenceError::Io(e.to_string()))
+    }
+
+    /// Read and remove the state left by the last shutdown
+    pub fn take(data_dir: &Path) -> Option<Self> {
+        let path = data_dir.join(SHUTDOWN_STATE_FILE);
+        let content = std::fs::read(&path).ok()?;
+        if let Err(e) = std::fs::remove_file(&path) {
+            warn!("Failed to remove {}: {}", SHUTDOWN_STATE_FILE, e);
+        }
+
+        serde_json::from_slice(&content)
+            .inspect_err(|e| warn!("Failed to parse {}: {}, ignored", SHUTDOWN_STATE_FILE, e))
+            .ok()
+    }
+}
+
+/// Error type for shutdown state persistence
+#[derive(Debug, thiserror::Error)]
+pub enum PersistenceError {
+    #[error("Serialization failed: {0}")]
+    Serialization(String),
+
+    #[error("IO error: {0}")]
+    Io(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_take() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(ShutdownState::take(dir.path()).is_none());
+
+        let peer_id = PeerId::random();
+        let addresses: Vec<Multiaddr> = vec!["/ip4/10.0.0.1/tcp/30333".parse().unwrap()];
+        let state = ShutdownState {
+            saved_at: 1_700_000_000,
+            peers: vec![SavedPeer::new(&peer_id, &addresses)],
+            sync: SyncCursor {
+                best_block: 42,
+                best_hash: Hash::hash(b"block 42"),
+                network_height: 50,
+            },
+        };
+        state.save(dir.path()).unwrap();
+
+        let restored = ShutdownState::take(dir.path()).unwrap();
+        assert_eq!(restored.sync, state.sync);
+        assert_eq!(restored.peers[0].parse(), Some((peer_id, addresses)));
+
+        // Taken once: not replayed after a crash
+        assert!(ShutdownState::take(dir.path()).is_none());
+    }
+
+    #[test]
+    fn test_merge_known_peers() {
+        let addr: Multiaddr = "/ip4/10.0.0.1/tcp/30333".parse().unwrap();
+        let moved: Multiaddr = "/ip4/10.0.0.2/tcp/30333".parse().unwrap();
+        let (a, b) = (PeerId::random(), PeerId::random());
+        let stored = vec![SavedPeer::new(&a, &[addr.clone()]), SavedPeer::new(&b, &[addr.clone()])];
+        let unreadable = SavedPeer { peer_id: "not a peer".to_string(), addresses: vec![addr.to_string()] };
+
+        let merged = merge_known_peers(stored, vec![SavedPeer::new(&b, &[moved.clone()]), unreadable]);
+        assert_eq!(merged, vec![SavedPeer::new(&b, &[moved]), SavedPeer::new(&a, &[addr.clone()])]);
+
+        let many: Vec<SavedPeer> = (0..MAX_KNOWN_PEERS + 10).map(|_| SavedPeer::new(&PeerId::random(), &[addr.clone()])).collect();
+        assert_eq!(merge_known_peers(merged, many).len(), MAX_KNOWN_PEERS);
+    }
+}
@@ -2,43 +2,96 @@
 // Principle: Coordinate all components, handle network events, manage lifecycle
 
 use crate::consensus::clock_health::{ClockStatus, LocalClockHealth};
+use crate::consensus::economics::{get_bootstrap_config, BootstrapStatus, NetworkSecurityState};
+use crate::consensus::evidence::{header_signed_by, EquivocationEvidence, EvidencePool, PendingEvidence};
+use crate::consensus::fraud::{disputed_block, fraud_offence, FraudPool};
+use crate::consensus::seed_attestation::{trusted_seeds, verify_attestation, HEARTBEATS_PER_EPOCH};
+use crate::consensus::epoch::EPOCH_DURATION_BLOCKS;
+use crate::consensus::validator_credits::MIN_UPTIME_PARTICIPATION;
 use crate::consensus::validator::ValidatorSet;
 use crate::contracts::{
-    krat::TokenomicsState,
+    emergency::{allowed_during_emergency, EmergencyContract, EmergencyDeclaration},
+    governance::{GovernanceError, Proposal, ProposalId, ProposalStatus, ProposalType},
+    identity::RegisteredIdentity,
+    krat::{treasury_account, TokenomicsState},
+    messaging::AccountMessage,
+    meta_governance::ParameterChangeRecord,
+    personhood::{ceremony_phase, CeremonyRound, PersonhoodRecord},
+    reputation::AccountReputation,
     sidechains::ChainRegistry,
-    staking::StakingRegistry,
+    staking::{CommissionRecord, DelegationPool, RewardLedger, StakingRegistry, UnbondingQueue},
 };
+use crate::execution::gas::{BlockFee, FeeHistory, MIN_BASE_FEE};
 use crate::genesis::{ChainConfig, GenesisBuilder, GenesisSpec};
 use crate::network::dns_seeds::{DnsSeedResolver, parse_bootnode};
-use crate::network::dns_seed_client::DnsSeedClient;
+use crate::network::dns_seed_client::{Capabilities, DnsSeedClient};
+use crate::network::peer::PeerScoring;
+use crate::network::request::JustificationRequest;
+use crate::network::sentry;
 use crate::network::service::{BlockProvider, NetworkEvent, NetworkService, SharedBlockProvider};
-use crate::network::sync::SyncState;
-use crate::node::mempool::TransactionPool;
-use crate::node::producer::{TransactionExecutor, BlockValidator, ValidationError, apply_block_rewards_for_import, apply_block_rewards_with_finality};
+use crate::network::sync::{ForkDownloaded, ForkHead, SyncState};
+use crate::network::warp_sync::{
+    SnapshotManifest, SnapshotStore, StateChunk, StateSnapshot, TrustContext, WarpCheckpoint, WarpSyncError,
+    WarpSyncManager, WarpSyncState, MAX_MANIFEST_HEADERS, SNAPSHOT_CHUNK_SIZE, SNAPSHOT_INTERVAL,
+};
+use crate::node::forensics::{self, StateRootForensics};
+use crate::node::invariants::{InvariantInputs, InvariantMonitor, InvariantReport, SecurityTransition, TransitionNotifier};
+use crate::node::metrics::NodeMetrics;
+use crate::node::persistence::{merge_known_peers, SavedPeer, ShutdownState, SyncCursor};
+use crate::node::mempool::{MempoolConfig, PoolContent, PoolError, TransactionPool};
+use crate::node::producer::{ProducerConfig, TransactionExecutor, BlockValidator, ValidationError, apply_block_rewards_for_import, apply_block_rewards_with_finality, block_hot_accounts, check_fraud_proof, check_protocol_version, enact_protocol_changes, record_block_fee, update_reputation};
 use crate::node::finality_integration::{
     FinalityIntegration, FinalityStatus, NodeFinalitySigner, NodeFinalityBroadcaster,
 };
-use crate::consensus::finality::{FinalityMessage, FinalityVote};
-use crate::storage::{db::Database, state::StateBackend};
+use crate::consensus::finality::{FinalityMessage, FinalityVote, StallStatus};
+use crate::storage::{cache::StateCacheStats, db::{open_database, Database, DatabaseBackend}, index::{IndexCursor, IndexPage, IndexedBlock, IndexedEvent, IndexedTransaction, TransactionFilter}, migration::migrate, state::{IdempotencyRecord, PruneReport, ReorgEvent, StateBackend, StateError}};
 use crate::types::*;
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::{mpsc, Mutex, RwLock};
 use tracing::{debug, info, warn, error, trace};
 
 // =============================================================================
 // BLOCK PROVIDER WRAPPER
 // =============================================================================
 
+/// Directory (under the data path) holding an interrupted warp sync download
+const WARP_SYNC_DIR: &str = "warp_sync";
+
+/// Give up on warp sync if no peer provides a usable manifest for this long
+const WARP_MANIFEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// Look for another snapshot if no chunk arrives for this long
+const WARP_DOWNLOAD_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// Blocks pruned per maintenance pass
+const MAX_PRUNE_BLOCKS_PER_PASS: u64 = 1_000;
+
+/// How long an idempotency key keeps deduplicating submissions (24 hours)
+pub const IDEMPOTENCY_WINDOW_SECS: u64 = 24 * 3600;
+
+/// Longest accepted idempotency key (bytes)
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 128;
+
+/// Outcome of an idempotent transaction submission
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdempotentSubmission {
+    /// Transaction accepted for the key
+    pub hash: Hash,
+    /// The key was already used within the window: nothing was submitted
+    pub duplicate: bool,
+}
+
 /// Wrapper to provide blocks from storage
 struct StorageBlockProvider {
     storage: Arc<RwLock<StateBackend>>,
+    snapshots: Arc<RwLock<SnapshotStore>>,
 }
 
 impl StorageBlockProvider {
-    fn new(storage: Arc<RwLock<StateBackend>>) -> Self {
-        Self { storage }
+    fn new(storage: Arc<RwLock<StateBackend>>, snapshots: Arc<RwLock<SnapshotStore>>) -> Self {
+        Self { storage, snapshots }
     }
 }
 
@@ -73,6 +126,65 @@ impl BlockProvider for StorageBlockProvider {
             None
         }
     }
+
+    fn get_justification(&self, request: &JustificationRequest) -> Option<FinalityJustification> {
+        let storage = self.storage.try_read().ok()?;
+        let result = match request {
+            JustificationRequest::Covering(number) => storage.justification_covering(*number),
+            JustificationRequest::Latest => storage.latest_justification(),
+        };
+        result.ok().flatten()
+    }
+
+    fn get_warp_manifest(&self) -> Option<SnapshotManifest> {
+        let snapshots = self.snapshots.try_read().ok()?;
+        let storage = self.storage.try_read().ok()?;
+        // Newest snapshot whose block is already finalized
+        let manifest = snapshots.newest_first().find_map(|snapshot| snapshot_manifest(&storage, snapshot));
+        manifest
+    }
+
+    fn get_warp_chunk(&self, block_number: BlockNumber, index: u32) -> Option<StateChunk> {
+        let snapshots = self.snapshots.try_read().ok()?;
+        snapshots.chunk(block_number, index).cloned()
+    }
+
+    fn block_signer(&self, author: &AccountId, epoch: EpochNumber) -> Option<AccountId> {
+        let storage = self.storage.try_read().ok()?;
+        // Rotations take effect the epoch after the one they are made in: up
+        // to the best block's epoch, the stored records are final
+        let best = storage.get_best_block().ok().flatten()?;
+        let best_epoch = storage.get_block_by_number(best).ok().flatten()?.header.epoch;
+        if epoch > best_epoch {
+            return None;
+        }
+        storage.block_signer(author, epoch).ok()
+    }
+}
+
+/// Build the manifest of a snapshot: its block, and the headers up to the
+/// first justified block at or above it
+fn snapshot_manifest(storage: &StateBackend, snapshot: &StateSnapshot) -> Option<SnapshotManifest> {
+    let number = snapshot.header.block_number;
+    let block = storage.get_block_by_number(number).ok().flatten()?;
+    if block.hash() != snapshot.header.block_hash {
+        return None;
+    }
+
+    let justification = storage.justification_covering(number).ok().flatten()?;
+    if justification.block_number - number > MAX_MANIFEST_HEADERS as BlockNumber {
+        return None;
+    }
+    let headers = (number + 1..=justification.block_number)
+        .map(|n| storage.get_header_by_number(n).ok().flatten())
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(SnapshotManifest {
+        header: snapshot.header.clone(),
+        block,
+        headers,
+        justification,
+    })
 }
 
 /// KratOs Node state
@@ -120,7 +232,19 @@ pub struct KratOsNode {
     shutdown: Arc<RwLock<bool>>,
 
     /// Producer database for double-signing protection (persistent across block production attempts)
-    producer_db: Arc<Database>,
+    producer_db: Arc<dyn Database>,
+
+    /// Block production limits
+    producer_config: Arc<RwLock<ProducerConfig>>,
+
+    /// Session signing keys held by this node, by public key
+    session_keys: Arc<RwLock<HashMap<AccountId, ed25519_dalek::SigningKey>>>,
+
+    /// Equivocation detection and evidence awaiting a report on chain
+    evidence: Arc<RwLock<EvidencePool>>,
+
+    /// Fraud proofs against invalid blocks awaiting a report on chain
+    fraud_proofs: Arc<RwLock<FraudPool>>,
 
     /// SECURITY FIX #36: Clock health tracking for soft degradation
     /// Persisted to file to survive node restarts
@@ -140,6 +264,27 @@ pub struct KratOsNode {
 
     /// Counter for heartbeat interval (every 4 maintenance cycles = 120s)
     heartbeat_counter: Arc<RwLock<u32>>,
+
+    /// State snapshots served to warp syncing peers
+    snapshots: Arc<RwLock<SnapshotStore>>,
+
+    /// Warp sync in progress (None = block sync only)
+    warp_sync: Arc<RwLock<Option<WarpSyncManager>>>,
+
+    /// Runtime checks of SPEC invariants
+    invariants: Arc<RwLock<InvariantMonitor>>,
+
+    /// Blocks kept behind finality (None = no pruning)
+    pruning: Arc<RwLock<Option<u32>>>,
+
+    /// Serializes idempotent submissions (check, record and submit)
+    idempotency: Arc<Mutex<()>>,
+
+    /// Mempool revision last saved to the database (None = not saved yet)
+    mempool_saved: Arc<Mutex<Option<u64>>>,
+
+    /// Prometheus metrics (--prometheus-port)
+    metrics: Arc<NodeMetrics>,
 }
 
 impl KratOsNode {
@@ -163,6 +308,17 @@ impl KratOsNode {
         data_path: &Path,
         genesis_spec: GenesisSpec,
         genesis_mode: bool,
+    ) -> Result<Self, NodeError> {
+        Self::with_database(config, data_path, genesis_spec, genesis_mode, DatabaseBackend::default()).await
+    }
+
+    /// Create a new node storing its chain and producer databases in `backend`
+    pub async fn with_database(
+        config: ChainConfig,
+        data_path: &Path,
+        genesis_spec: GenesisSpec,
+        genesis_mode: bool,
+        backend: DatabaseBackend,
     ) -> Result<Self, NodeError> {
         if genesis_mode {
             info!("🌟 GENESIS MODE - Creating new network");
@@ -173,8 +329,19 @@ impl KratOsNode {
         info!("Chain: {}", config.chain_name);
 
         // Open database
-        let db = Database::open(data_path.to_str().unwrap())
-            .map_err(|e| NodeError::Storage(format!("DB error: {:?}", e)))?;
+        info!("Database backend: {}", backend);
+        let db = open_database(data_path, backend).map_err(|e| {
+            NodeError::Storage(format!(
+                "DB error: {:?} (if the node is not already running, try `kratos-node db check --repair`)",
+                e
+            ))
+        })?;
+
+        // Upgrade the storage layout of an existing database
+        let migration = migrate(db.as_ref()).map_err(|e| NodeError::Storage(e.to_string()))?;
+        if migration.applied > 0 {
+            info!("Database schema migrated to v{} ({} migrations)", migration.to, migration.applied);
+        }
 
         let mut state = StateBackend::new(db);
 
@@ -255,6 +422,8 @@ impl KratOsNode {
                 &listen_addr,
                 Hash::ZERO,
                 Some(data_path.to_path_buf()),
+                false, // only fetches the genesis
+                config.network.mdns,
             )
                 .await
                 .map_err(|e| NodeError::Network(format!("Network error: {:?}", e)))?;
@@ -390,6 +559,10 @@ impl KratOsNode {
                         genesis_info.block.header.state_root,
                     ).map_err(|e| NodeError::Storage(format!("Failed to apply genesis state: {}", e)))?;
 
+                    // Genesis parameters are not served: they come from the local chain spec
+                    state.set_protocol_parameters(&genesis_spec.parameters)
+                        .map_err(|e| NodeError::Storage(format!("Failed to set genesis parameters: {:?}", e)))?;
+
                     info!("💾 Genesis stored locally");
                     info!("Loaded {} validators from genesis node", validators.active_count());
 
@@ -414,6 +587,8 @@ impl KratOsNode {
             &listen_addr,
             genesis_hash,
             Some(data_path.to_path_buf()),
+            config.network.relay,
+            config.network.mdns,
         )
             .await
             .map_err(|e| NodeError::Network(format!("Network error: {:?}", e)))?;
@@ -457,6 +632,35 @@ impl KratOsNode {
             );
         }
 
+        if let Some(public_addr) = &config.network.public_addr {
+            match public_addr.parse() {
+                Ok(addr) => network.add_external_address(addr),
+                Err(e) => warn!("Ignoring --public-addr {}: {}", public_addr, e),
+            }
+        }
+        if let Some(port) = config.network.quic_port {
+            network
+                .listen_quic(port)
+                .map_err(|e| NodeError::Network(format!("QUIC listen error on UDP port {}: {:?}", port, e)))?;
+        }
+        let reserved_peers = Self::reserved_peers(&config);
+        if !reserved_peers.is_empty() {
+            info!(
+                "🔒 {} reserved peers{}",
+                reserved_peers.len(),
+                if config.network.reserved_only { " (reserved-only)" } else { "" }
+            );
+            network.set_reserved_peers(reserved_peers, config.network.reserved_only);
+        }
+        if !config.network.sentry_for.is_empty() {
+            let validators: Vec<_> = config.network.sentry_for.iter()
+                .filter_map(|v| parse_bootnode(v).ok())
+                .map(|(peer_id, _)| peer_id)
+                .collect();
+            info!("🛡️  Sentry for {} validators (their addresses stay private)", validators.len());
+            network.set_private_peers(validators);
+        }
+
         // Update network with genesis state
         network.update_local_state(0, genesis_hash);
 
@@ -477,12 +681,32 @@ impl KratOsNode {
             }
         }
 
+        // Resume from the stored best block
+        // (a warp synced node has no blocks between genesis and its snapshot)
+        let head = {
+            let storage_guard = storage.read().await;
+            storage_guard.get_best_block().ok().flatten()
+                .filter(|number| *number > 0)
+                .and_then(|number| storage_guard.get_block_by_number(number).ok().flatten())
+        };
+        let head = match head {
+            Some(block) => {
+                info!("📂 Resuming from stored best block #{} ({})", block.header.number, block.hash());
+                network.update_local_state(block.header.number, block.hash());
+                block
+            }
+            None => genesis_block,
+        };
+
         // Set block provider for network sync
-        let block_provider: SharedBlockProvider = Arc::new(RwLock::new(StorageBlockProvider::new(storage.clone())));
+        let snapshots = Arc::new(RwLock::new(SnapshotStore::default()));
+        let block_provider: SharedBlockProvider = Arc::new(RwLock::new(StorageBlockProvider::new(storage.clone(), snapshots.clone())));
         network.set_block_provider(block_provider);
 
         // Initialize components
-        let mempool = TransactionPool::default();
+        let mut mempool = TransactionPool::default();
+        mempool.set_genesis_hash(genesis_hash);
+        mempool.set_base_fee(storage.read().await.base_fee_at(head.header.number + 1).unwrap_or(MIN_BASE_FEE));
         let validators = genesis_validators;
         let staking = StakingRegistry::new();
         let sidechains = ChainRegistry::new();
@@ -490,7 +714,7 @@ impl KratOsNode {
 
         // Initialize producer database for double-signing protection
         let producer_db_path = data_path.join("producer");
-        let producer_db = Database::open(producer_db_path.to_str().unwrap())
+        let producer_db = open_database(&producer_db_path, backend)
             .map_err(|e| NodeError::Storage(format!("Producer DB error: {:?}", e)))?;
 
         // SECURITY FIX #36: Initialize clock health from file (or create new)
@@ -508,21 +732,38 @@ impl KratOsNode {
             staking: Arc::new(RwLock::new(staking)),
             sidechains: Arc::new(RwLock::new(sidechains)),
             tokenomics: Arc::new(RwLock::new(tokenomics)),
-            current_block: Arc::new(RwLock::new(Some(genesis_block))),
-            chain_height: Arc::new(RwLock::new(0)),
+            chain_height: Arc::new(RwLock::new(head.header.number)),
+            current_block: Arc::new(RwLock::new(Some(head))),
             genesis_hash,
             shutdown: Arc::new(RwLock::new(false)),
-            producer_db: Arc::new(producer_db),
+            producer_db: Arc::from(producer_db),
+            producer_config: Arc::new(RwLock::new(ProducerConfig::default())),
+            session_keys: Arc::new(RwLock::new(HashMap::new())),
+            evidence: Arc::new(RwLock::new(EvidencePool::new())),
+            fraud_proofs: Arc::new(RwLock::new(FraudPool::new())),
             clock_health: Arc::new(RwLock::new(clock_health)),
             finality: Arc::new(RwLock::new(None)),
             finality_outbound_rx: Arc::new(RwLock::new(None)),
             dns_client: Arc::new(RwLock::new(None)),
             heartbeat_counter: Arc::new(RwLock::new(0)),
+            snapshots,
+            warp_sync: Arc::new(RwLock::new(None)),
+            invariants: Arc::new(RwLock::new(InvariantMonitor::new())),
+            pruning: Arc::new(RwLock::new(None)),
+            idempotency: Arc::new(Mutex::new(())),
+            mempool_saved: Arc::new(Mutex::new(None)),
+            metrics: Arc::new(NodeMetrics::new()),
         })
     }
 
     /// Discover peers via DNS seeds and configured bootnodes
     fn discover_peers(config: &ChainConfig) -> Vec<(libp2p::PeerId, libp2p::Multiaddr)> {
+        // A node behind sentries only ever talks to its reserved peers
+        if config.network.reserved_only {
+            info!("🔒 Reserved-only mode: skipping DNS seeds and bootnodes");
+            return Self::reserved_peers(config);
+        }
+
         let mut bootstrap_addrs: Vec<(libp2p::PeerId, libp2p::Multiaddr)> = Vec::new();
 
         // 1. Try DNS Seeds for decentralized discovery
@@ -556,6 +797,21 @@ impl KratOsNode {
         bootstrap_addrs
     }
 
+    /// Reserved peers from the configuration (--reserved-peer), the
+    /// validators shielded as a sentry (--sentry-for) included
+    fn reserved_peers(config: &ChainConfig) -> Vec<(libp2p::PeerId, libp2p::Multiaddr)> {
+        config.network.reserved_peers.iter()
+            .chain(&config.network.sentry_for)
+            .filter_map(|peer| match parse_bootnode(peer) {
+                Ok(parsed) => Some(parsed),
+                Err(e) => {
+                    warn!("Failed to parse reserved peer {}: {}", peer, e);
+                    None
+                }
+            })
+            .collect()
+    }
+
     /// Start the node
     pub async fn start(&self) -> Result<(), NodeError> {
         info!("Starting KratOs node");
@@ -608,6 +864,9 @@ impl KratOsNode {
             NetworkEvent::BlockReceived { block, from } => {
                 debug!("Received block #{} from {}", block.header.number, from);
 
+                // Forks included: a second block for a slot is what equivocation looks like
+                self.check_double_proposal(&block.header).await;
+
                 let current_height = *self.chain_height.read().await;
                 let block_number = block.header.number;
 
@@ -632,10 +891,15 @@ impl KratOsNode {
                 match self.import_block(block.clone()).await {
                     Ok(()) => {
                         debug!("Block #{} imported successfully", block.header.number);
+                        // Passed on to the peers that don't have it yet
+                        if let Err(e) = self.network.write().await.relay_block(block, Some(from)) {
+                            debug!("Failed to relay block #{}: {}", block_number, e);
+                        }
                         // Try to import any buffered blocks that are now sequential
                         self.try_import_buffered_blocks().await;
                     }
                     Err(e) => {
+                        self.check_invalid_block(&block, &e).await;
                         // Only ban for serious validation errors, not sequencing issues
                         let error_str = format!("{:?}", e);
                         if error_str.contains("Block number mismatch") {
@@ -656,13 +920,47 @@ impl KratOsNode {
                 }
             }
 
+            NetworkEvent::BlockAnnounced { header, from } => {
+                debug!("Block #{} announced by {}", header.number, from);
+                let hash = header.hash();
+
+                // Forks included: a second block for a slot is what equivocation looks like
+                self.check_double_proposal(&header).await;
+
+                // The body is only fetched for a header that checks out
+                match self.check_announced_header(&header).await {
+                    Ok(true) => self.network.write().await.fetch_announced_block(&hash),
+                    Ok(false) => self.network.write().await.discard_announced_block(&hash),
+                    Err(e) => self.network.write().await.reject_announced_block(&hash, &e.to_string()),
+                }
+            }
+
             NetworkEvent::TransactionReceived { transaction, from } => {
                 debug!("Received transaction from {}", from);
 
-                // Add to mempool
-                let mut mempool = self.mempool.write().await;
-                if let Err(e) = mempool.add(transaction) {
-                    debug!("Failed to add transaction to mempool: {:?}", e);
+                if let Err(e) = self.check_emergency_call(&transaction.transaction.call).await {
+                    debug!("Transaction refused during emergency: {:?}", e);
+                    return;
+                }
+
+                // Add to mempool (a gapped nonce waits as an orphan)
+                let account_nonce = match self.get_nonce(&transaction.transaction.sender).await {
+                    Ok(nonce) => nonce,
+                    Err(e) => {
+                        debug!("Failed to read sender nonce: {:?}", e);
+                        return;
+                    }
+                };
+                let relayed = transaction.clone();
+                let added = self.mempool.write().await.add_with_account_nonce(transaction, account_nonce);
+                match added {
+                    // Passed on to the peers that don't have it yet
+                    Ok(_) => {
+                        if let Err(e) = self.network.write().await.relay_transaction(relayed, Some(from)) {
+                            debug!("Failed to relay transaction: {}", e);
+                        }
+                    }
+                    Err(e) => debug!("Failed to add transaction to mempool: {:?}", e),
                 }
             }
 
@@ -695,6 +993,7 @@ impl KratOsNode {
                             Err(e) => {
                                 // Log but don't break - try remaining blocks
                                 warn!("Failed to import sync block #{}: {:?}", block_number, e);
+                                self.check_invalid_block(&block, &e).await;
                             }
                         }
                     } else {
@@ -719,6 +1018,10 @@ impl KratOsNode {
                 if has_more {
                     let mut network = self.network.write().await;
                     network.maybe_start_sync();
+                } else if imported_count > 0 {
+                    // Caught up with this peer: fetch a finality proof for what we imported
+                    let mut network = self.network.write().await;
+                    network.request_justification(&from, JustificationRequest::Latest);
                 }
             }
 
@@ -738,7 +1041,10 @@ impl KratOsNode {
                     warn!("Peer {} has different genesis hash!", peer);
                     let mut network = self.network.write().await;
                     network.ban_peer(peer, "Different genesis");
+                    return;
                 }
+
+                self.warp_sync_peer_status(peer, best_block).await;
             }
 
             NetworkEvent::SyncNeeded { local_height, network_height } => {
@@ -810,6 +1116,49 @@ impl KratOsNode {
                     }
                 }
             }
+
+            NetworkEvent::JustificationReceived { justification, from } => {
+                let block_number = justification.block_number;
+                match self.import_justification(justification).await {
+                    Ok(true) => info!("🔒 Block #{} finality proven by justification from {}", block_number, from),
+                    Ok(false) => debug!("Ignoring justification for block #{} from {}", block_number, from),
+                    // Not banned: the check uses the current validator set, which may
+                    // differ from the one that finalized an older block
+                    Err(e) => warn!("Rejected justification for block #{} from {}: {}", block_number, from, e),
+                }
+            }
+
+            NetworkEvent::WarpManifestReceived { manifest, from } => {
+                self.on_warp_manifest(*manifest, from).await;
+            }
+
+            NetworkEvent::WarpChunkReceived { block_number, chunk, from } => {
+                self.on_warp_chunk(block_number, chunk, from).await;
+            }
+
+            NetworkEvent::EvidenceReceived { evidence_data, from } => {
+                let result = match EquivocationEvidence::from_bytes(&evidence_data) {
+                    Ok(evidence) => self.add_evidence(evidence).await,
+                    Err(e) => Err(NodeError::Consensus(e.to_string())),
+                };
+                if let Err(e) = result {
+                    debug!("Rejected equivocation evidence from {}: {}", from, e);
+                }
+            }
+
+            NetworkEvent::ForkReceived { fork } => {
+                self.consider_fork(fork).await;
+            }
+
+            NetworkEvent::WarpSyncUnavailable { chunk, from } => {
+                let mut guard = self.warp_sync.write().await;
+                if let Some(warp) = guard.as_mut() {
+                    warp.remove_source(&from);
+                    if let Some(index) = chunk {
+                        warp.chunk_failed(index);
+                    }
+                }
+            }
         }
     }
 
@@ -820,7 +1169,15 @@ impl KratOsNode {
     /// 2. Executes all transactions against state
     /// 3. Validates the computed state root matches block header
     /// 4. Persists block and updates chain state
-    async fn import_block(&self, block: Block) -> Result<(), NodeError> {
+    pub async fn import_block(&self, block: Block) -> Result<(), NodeError> {
+        self.import_block_execution(block).await.map(|_| ())
+    }
+
+    /// Import a block, returning what re-executing it gave (None if it was
+    /// already imported)
+    #[tracing::instrument(name = "block_import", skip_all, fields(number = block.header.number))]
+    pub async fn import_block_execution(&self, block: Block) -> Result<Option<BlockExecution>, NodeError> {
+        let import_started = std::time::Instant::now();
         let current_height = *self.chain_height.read().await;
         let block_number = block.header.number;
         let block_hash = block.hash();
@@ -841,7 +1198,7 @@ impl KratOsNode {
             if let Ok(Some(existing)) = storage.get_block_by_number(block_number) {
                 if existing.hash() == block_hash {
                     debug!("Block #{} already imported (idempotency check), skipping", block_number);
-                    return Ok(());
+                    return Ok(None);
                 }
             }
         }
@@ -868,6 +1225,13 @@ impl KratOsNode {
         // If this is block #1 and the validator is unknown, add them dynamically
         // (they are the bootstrap validator who produced the genesis).
         {
+            // Session key of the author at the block's epoch, or the author
+            let signer = self
+                .storage
+                .read()
+                .await
+                .block_signer(&block.header.author, block.header.epoch)
+                .map_err(|e| NodeError::Storage(e.to_string()))?;
             let mut validators = self.validators.write().await;
 
             // Check if author is known and active
@@ -886,7 +1250,11 @@ impl KratOsNode {
                 }
             }
 
-            if let Err(e) = BlockValidator::validate(&block, parent, &validators) {
+            if let Err(e) = BlockValidator::validate(&block, parent, &validators, &signer, &self.genesis_hash) {
+                // The chain moved past this release: tell the operator, not just the peer
+                if matches!(e, ValidationError::UnsupportedProtocolVersion { .. }) {
+                    error!("⛔ {}", e);
+                }
                 return Err(NodeError::Consensus(format!("Block validation failed: {:?}", e)));
             }
         }
@@ -915,34 +1283,85 @@ impl KratOsNode {
         }
 
         // 4. Execute all transactions, apply rewards, and validate state root
-        {
+        // (fraud proofs replay blocks the same way: producer::replay_block)
+        let execution = {
+            let validator_ids: Vec<AccountId> = self.validators.read().await.validators.keys().copied().collect();
             let mut storage = self.storage.write().await;
 
-            // Execute each transaction and collect fees
-            let mut total_fees: Balance = 0;
-            for (idx, tx) in block.body.transactions.iter().enumerate() {
-                let result = TransactionExecutor::execute(&mut storage, tx, block_number);
+            // Keep account pre-images in case the state root does not match
+            storage.begin_account_journal();
 
-                if !result.success {
-                    error!(
-                        "Transaction {} in block #{} failed: {:?}",
-                        idx, block_number, result.error
-                    );
-                    return Err(NodeError::Consensus(format!(
-                        "Transaction {} execution failed: {:?}",
-                        idx, result.error
-                    )));
+            // Read ahead the accounts the block touches
+            let execution_started = std::time::Instant::now();
+            let cache_before = storage.cache_stats();
+            let hot_accounts = block_hot_accounts(block.header.author, &block.body.transactions, &validator_ids);
+            if let Err(e) = storage.prefetch_accounts(hot_accounts) {
+                debug!("State read-ahead failed for block #{}: {:?}", block_number, e);
+            }
+
+            // Authorship counts toward seed attestations of this epoch
+            storage.record_block_authored(&block.header.author, block_number)
+                .map_err(|e| NodeError::Storage(format!("Failed to record authorship: {:?}", e)))?;
+
+            // Approved parameter changes take effect at epoch boundaries
+            enact_protocol_changes(&mut storage, block_number).map_err(NodeError::Storage)?;
+            check_protocol_version(&storage, &block.header).map_err(NodeError::Consensus)?;
+            update_reputation(&mut storage, block_number).map_err(NodeError::Storage)?;
+
+            // Execute each transaction and collect fees and receipts
+            let execution_span = tracing::info_span!(
+                "block_execution",
+                number = block_number,
+                transactions = block.body.transactions.len()
+            );
+            let (total_fees, receipts) = execution_span.in_scope(|| {
+                let mut total_fees: Balance = 0;
+                let mut receipts = Vec::with_capacity(block.body.transactions.len());
+                // Signatures were batch-verified by BlockValidator::validate (step 3)
+                let results = TransactionExecutor::execute_block(&mut storage, &block.body.transactions, block_number, false);
+                for (idx, result) in results.into_iter().enumerate() {
+                    if !result.success {
+                        error!(
+                            "Transaction {} in block #{} failed: {:?}",
+                            idx, block_number, result.error
+                        );
+                        return Err(NodeError::TransactionFailed {
+                            index: idx,
+                            error: format!("{:?}", result.error),
+                        });
+                    }
+                    total_fees = total_fees.saturating_add(result.fee_paid);
+                    receipts.push(result.receipt());
                 }
-                total_fees = total_fees.saturating_add(result.fee_paid);
+                Ok((total_fees, receipts))
+            })?;
+
+            // Validate receipts root matches block header
+            let computed_receipts_root = receipts_root(&receipts);
+            if computed_receipts_root != block.header.receipts_root {
+                error!(
+                    "Receipts root mismatch for block #{}: expected {}, computed {}",
+                    block_number, block.header.receipts_root, computed_receipts_root
+                );
+                return Err(NodeError::RootMismatch {
+                    root: "Receipts",
+                    expected: block.header.receipts_root,
+                    computed: computed_receipts_root,
+                });
             }
 
             // Process early validator voting transactions
             // These are executed separately because they need access to ValidatorSet
             for tx in block.body.transactions.iter() {
                 match &tx.transaction.call {
-                    TransactionCall::ProposeEarlyValidator { candidate } => {
+                    TransactionCall::ProposeEarlyValidator { candidate, metadata } => {
                         let mut validators = self.validators.write().await;
-                        match validators.propose_early_validator(*candidate, tx.transaction.sender, block_number) {
+                        match validators.propose_early_validator_with_metadata(
+                            *candidate,
+                            tx.transaction.sender,
+                            metadata.clone(),
+                            block_number,
+                        ) {
                             Ok(()) => {
                                 info!(
                                     "✅ Early validator proposed: {} by {} at block #{}",
@@ -1040,15 +1459,34 @@ impl KratOsNode {
 
             // Apply block rewards (same as during production)
             // This credits the block author with block reward + fee share
-            if let Err(e) = apply_block_rewards_for_import(
+            let treasury = treasury_account();
+            let free_balance = |storage: &StateBackend, id: &AccountId| {
+                storage
+                    .get_account(id)
+                    .map(|account| account.map_or(0, |account| account.free))
+                    .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))
+            };
+            let fee_shares = storage
+                .get_protocol_parameters()
+                .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))?
+                .economics
+                .fee_shares;
+            let author_before = free_balance(&storage, &block.header.author)?;
+            let treasury_before = free_balance(&storage, &treasury)?;
+            let producer_reward = match apply_block_rewards_for_import(
                 &mut storage,
                 block.header.author,
                 block.header.epoch,
                 total_fees,
             ) {
-                error!("Failed to apply block rewards for block #{}: {}", block_number, e);
-                return Err(NodeError::Consensus(format!("Block reward application failed: {}", e)));
-            }
+                Ok(reward) => reward,
+                Err(e) => {
+                    error!("Failed to apply block rewards for block #{}: {}", block_number, e);
+                    return Err(NodeError::Consensus(format!("Block reward application failed: {}", e)));
+                }
+            };
+            let producer_credit = free_balance(&storage, &block.header.author)?.saturating_sub(author_before);
+            let treasury_credit = free_balance(&storage, &treasury)?.saturating_sub(treasury_before);
 
             // Compute state root after executing all transactions and applying rewards
             let chain_id = ChainId(0); // TODO: Get from config
@@ -1060,24 +1498,54 @@ impl KratOsNode {
                     "State root mismatch for block #{}: expected {}, computed {}",
                     block_number, block.header.state_root, computed_state_root.root
                 );
-                return Err(NodeError::Consensus(format!(
-                    "State root mismatch: expected {}, computed {}",
-                    block.header.state_root, computed_state_root.root
-                )));
+                self.dump_state_root_forensics(&storage, &block, computed_state_root.root, &receipts);
+                return Err(NodeError::RootMismatch {
+                    root: "State",
+                    expected: block.header.state_root,
+                    computed: computed_state_root.root,
+                });
             }
 
+            let cache_after = storage.cache_stats();
+            debug!(
+                "Block #{} executed in {:?} (state cache: {} hits, {} misses)",
+                block_number,
+                execution_started.elapsed(),
+                cache_after.hits - cache_before.hits,
+                cache_after.misses - cache_before.misses
+            );
+
             // Store state root for this block
             storage.store_state_root(block_number, computed_state_root)
                 .map_err(|e| NodeError::Storage(format!("Failed to store state root: {:?}", e)))?;
 
-            // Persist block to storage
+            // Fullness of this block sets the base fee of the next one
+            record_block_fee(&storage, block_number, block.body.transactions.len())
+                .map_err(|e| NodeError::Storage(format!("Failed to store block fee: {:?}", e)))?;
+
+            // Persist block and its receipts to storage
             storage.store_block(&block)
                 .map_err(|e| NodeError::Storage(format!("Failed to store block: {:?}", e)))?;
+            storage.store_receipts(block_number, block_hash, &receipts)
+                .map_err(|e| NodeError::Storage(format!("Failed to store receipts: {:?}", e)))?;
+            storage.index_block(&block)
+                .map_err(|e| NodeError::Storage(format!("Failed to index block: {:?}", e)))?;
 
             // Update best block in storage
             storage.set_best_block(block_number)
                 .map_err(|e| NodeError::Storage(format!("Failed to set best block: {:?}", e)))?;
-        }
+
+            BlockExecution {
+                number: block_number,
+                state_root: computed_state_root.root,
+                receipts_root: computed_receipts_root,
+                total_fees,
+                fee_shares,
+                producer_reward,
+                producer_credit,
+                treasury_credit,
+            }
+        };
 
         // Update finality gadget with new validator set if any validators were added
         if finality_validators_changed {
@@ -1087,8 +1555,10 @@ impl KratOsNode {
 
         // 5. Remove executed transactions from mempool
         {
+            let base_fee = self.storage.read().await.base_fee_at(block.header.number + 1).unwrap_or(MIN_BASE_FEE);
             let mut mempool = self.mempool.write().await;
-            mempool.remove_included(&block.body.transactions);
+            mempool.on_block(block.header.number, &block.body.transactions);
+            mempool.set_base_fee(base_fee);
         }
 
         // 6. Update chain state
@@ -1115,7 +1585,12 @@ impl KratOsNode {
         // This triggers finality voting if we're an active validator
         self.notify_finality_block_imported(block_number, block_hash).await;
 
-        Ok(())
+        self.maybe_take_snapshot(&block).await;
+        self.track_invariants(&block).await;
+        self.settle_evidence(&block).await;
+        self.metrics.block_import_seconds.observe(import_started.elapsed().as_secs_f64());
+
+        Ok(Some(execution))
     }
 
     /// Store a block that was just produced locally
@@ -1133,6 +1608,8 @@ impl KratOsNode {
             // Persist block to storage (state root already stored by produce_block)
             storage.store_block(&block)
                 .map_err(|e| NodeError::Storage(format!("Failed to store block: {:?}", e)))?;
+            storage.index_block(&block)
+                .map_err(|e| NodeError::Storage(format!("Failed to index block: {:?}", e)))?;
 
             // Update best block in storage
             storage.set_best_block(block_number)
@@ -1141,17 +1618,24 @@ impl KratOsNode {
 
         // Remove executed transactions from mempool
         {
+            let base_fee = self.storage.read().await.base_fee_at(block.header.number + 1).unwrap_or(MIN_BASE_FEE);
             let mut mempool = self.mempool.write().await;
-            mempool.remove_included(&block.body.transactions);
+            mempool.on_block(block.header.number, &block.body.transactions);
+            mempool.set_base_fee(base_fee);
         }
 
         // Process early validator voting transactions
         // These are executed after block production because they need access to ValidatorSet
         for tx in block.body.transactions.iter() {
             match &tx.transaction.call {
-                TransactionCall::ProposeEarlyValidator { candidate } => {
+                TransactionCall::ProposeEarlyValidator { candidate, metadata } => {
                     let mut validators = self.validators.write().await;
-                    match validators.propose_early_validator(*candidate, tx.transaction.sender, block_number) {
+                    match validators.propose_early_validator_with_metadata(
+                        *candidate,
+                        tx.transaction.sender,
+                        metadata.clone(),
+                        block_number,
+                    ) {
                         Ok(()) => {
                             info!(
                                 "✅ Early validator proposed: {} by {} at block #{}",
@@ -1286,6 +1770,10 @@ impl KratOsNode {
         // Broadcast outbound finality messages
         self.broadcast_finality_messages().await;
 
+        self.maybe_take_snapshot(&block).await;
+        self.track_invariants(&block).await;
+        self.settle_evidence(&block).await;
+
         Ok(())
     }
 
@@ -1334,6 +1822,177 @@ impl KratOsNode {
         Ok(())
     }
 
+    /// Process the network events already received (blocks being imported),
+    /// without polling for new ones, so that a shutdown doesn't drop them
+    pub async fn finish_imports(&self) -> usize {
+        let mut processed = 0;
+        while let Some(event) = self.next_network_event().await {
+            self.handle_network_event(event).await;
+            processed += 1;
+        }
+        processed
+    }
+
+    /// Save the mempool to the database if it changed since the last save
+    ///
+    /// Called periodically and on shutdown, so a restart (even after a crash)
+    /// keeps the pending transactions. Returns how many were saved, None if
+    /// the pool didn't change.
+    pub async fn save_mempool(&self) -> Result<Option<usize>, NodeError> {
+        let mut saved = self.mempool_saved.lock().await;
+        let (revision, transactions) = {
+            let mempool = self.mempool.read().await;
+            (mempool.revision(), mempool.transactions())
+        };
+        if *saved == Some(revision) {
+            return Ok(None);
+        }
+
+        self.storage
+            .read()
+            .await
+            .put_mempool_snapshot(&transactions)
+            .map_err(|e| NodeError::Storage(format!("Mempool save failed: {}", e)))?;
+        *saved = Some(revision);
+        Ok(Some(transactions.len()))
+    }
+
+    /// Restore the transactions saved by save_mempool
+    ///
+    /// They are revalidated against the chain: those included or invalidated
+    /// since are dropped. Returns how many were restored.
+    pub async fn restore_mempool(&self) -> Result<usize, NodeError> {
+        // Same lock order as block production
+        let mut mempool = self.mempool.write().await;
+        let mut storage = self.storage.write().await;
+        let transactions = storage
+            .get_mempool_snapshot()
+            .map_err(|e| NodeError::Storage(format!("Mempool restore failed: {}", e)))?;
+        let saved = transactions.len();
+        let restored = mempool.restore(transactions, Some(&mut *storage));
+        if saved > 0 {
+            info!("♻️  Restored {}/{} pending transactions", restored, saved);
+        }
+        Ok(restored)
+    }
+
+    /// Save the peer scores and bans to the database
+    ///
+    /// Called periodically and on shutdown, like the mempool. Returns how
+    /// many peers were saved.
+    pub async fn save_peer_reputations(&self) -> Result<usize, NodeError> {
+        let reputations = self.network.read().await.peer_reputations();
+        self.storage
+            .read()
+            .await
+            .put_peer_reputations(&reputations)
+            .map_err(|e| NodeError::Storage(format!("Peer reputation save failed: {}", e)))?;
+        Ok(reputations.len())
+    }
+
+    /// Restore the peer scores and bans saved by save_peer_reputations,
+    /// returning how many bans still apply
+    pub async fn restore_peer_reputations(&self) -> Result<usize, NodeError> {
+        let reputations = self
+            .storage
+            .read()
+            .await
+            .get_peer_reputations()
+            .map_err(|e| NodeError::Storage(format!("Peer reputation restore failed: {}", e)))?;
+        let bans = self.network.write().await.restore_peer_reputations(reputations);
+        if bans > 0 {
+            info!("🚫 Restored {} peer bans", bans);
+        }
+        Ok(bans)
+    }
+
+    /// Merge the known-good peers into the known-peers set in the database
+    ///
+    /// Called periodically and on shutdown. Returns the size of the set.
+    pub async fn save_known_peers(&self) -> Result<usize, NodeError> {
+        let fresh = self.network.read().await.known_good_peers().iter()
+            .map(|(peer_id, addresses)| SavedPeer::new(peer_id, addresses))
+            .collect();
+        let storage = self.storage.read().await;
+        let stored = storage
+            .get_known_peers()
+            .map_err(|e| NodeError::Storage(format!("Known peers load failed: {}", e)))?;
+        let peers = merge_known_peers(stored, fresh);
+        storage
+            .put_known_peers(&peers)
+            .map_err(|e| NodeError::Storage(format!("Known peers save failed: {}", e)))?;
+        Ok(peers.len())
+    }
+
+    /// Dial peers of the known-peers set (saved by save_known_peers or
+    /// imported with `kratos-node peers import`), while below the peer limit
+    pub async fn restore_known_peers(&self) -> Result<usize, NodeError> {
+        let stored = self
+            .storage
+            .read()
+            .await
+            .get_known_peers()
+            .map_err(|e| NodeError::Storage(format!("Known peers load failed: {}", e)))?;
+        let mut network = self.network.write().await;
+        if network.reserved_only() {
+            return Ok(0);
+        }
+        let peers: Vec<_> = stored.iter().filter_map(SavedPeer::parse).take(network.free_peer_slots()).collect();
+        let count = peers.len();
+        network.add_known_peers(peers);
+        if count > 0 {
+            info!("📒 Dialing {} known peers", count);
+        }
+        Ok(count)
+    }
+
+    /// Save the known-good peers and the sync cursor for the next start
+    pub async fn save_shutdown_state(&self) -> Result<ShutdownState, NodeError> {
+        let (peers, network_height) = {
+            let network = self.network.read().await;
+            let peers = network.known_good_peers().iter()
+                .map(|(peer_id, addresses)| SavedPeer::new(peer_id, addresses))
+                .collect();
+            (peers, network.best_known_height())
+        };
+        let (best_block, best_hash) = match self.current_block.read().await.as_ref() {
+            Some(block) => (block.header.number, block.hash()),
+            None => (0, self.genesis_hash),
+        };
+
+        let state = ShutdownState {
+            saved_at: chrono::Utc::now().timestamp() as u64,
+            peers,
+            sync: SyncCursor { best_block, best_hash, network_height },
+        };
+        state.save(&self.data_path).map_err(|e| NodeError::Storage(e.to_string()))?;
+        Ok(state)
+    }
+
+    /// Restore what the last clean shutdown saved (nothing after a crash)
+    ///
+    /// Saved peers are dialed right away.
+    pub async fn restore_shutdown_state(&self) -> Option<ShutdownState> {
+        let state = ShutdownState::take(&self.data_path)?;
+
+        let peers: Vec<_> = state.peers.iter().filter_map(SavedPeer::parse).collect();
+        let peer_count = peers.len();
+        self.network.write().await.add_known_peers(peers);
+
+        let height = *self.chain_height.read().await;
+        if height < state.sync.best_block {
+            warn!(
+                "⚠️  Chain at #{} but block #{} was imported before shutdown: blocks lost, syncing them again",
+                height, state.sync.best_block
+            );
+        }
+        info!(
+            "♻️  Restored {} peers (network was at #{} on shutdown)",
+            peer_count, state.sync.network_height
+        );
+        Some(state)
+    }
+
     /// Submit a transaction to the mempool
     pub async fn submit_transaction(&self, mut tx: SignedTransaction) -> Result<Hash, NodeError> {
         // Compute hash if not present (e.g., when deserialized from JSON RPC)
@@ -1346,18 +2005,27 @@ impl KratOsNode {
             }
         };
 
-        // Add to mempool
+        self.check_governance_call(&tx.transaction.call).await?;
+        self.check_emergency_call(&tx.transaction.call).await?;
+
+        // Add to mempool, a gapped nonce waits for the missing ones
+        let account_nonce = self.get_nonce(&tx.transaction.sender).await?;
         let mut mempool = self.mempool.write().await;
-        mempool
-            .add(tx.clone())
-            .map_err(|e| NodeError::Transaction(format!("Mempool error: {:?}", e)))?;
+        mempool.add_with_account_nonce(tx.clone(), account_nonce)?;
 
         // Broadcast to network (skip in test mode)
+        // The transaction is in the mempool by now: a failed broadcast must not
+        // report it as rejected, local block production still includes it
         if cfg!(not(test)) {
             let mut network = self.network.write().await;
-            network
-                .broadcast_transaction(tx)
-                .map_err(|e| NodeError::Network(format!("Broadcast error: {:?}", e)))?;
+            if let Err(e) = network.broadcast_transaction(tx) {
+                let err_str = format!("{:?}", e);
+                if err_str.contains("InsufficientPeers") {
+                    debug!("Transaction broadcast skipped (no peers connected)");
+                } else {
+                    warn!("⚠️  Transaction broadcast failed: {}", err_str);
+                }
+            }
         }
 
         info!("Transaction {:?} submitted", hash);
@@ -1365,6 +2033,147 @@ impl KratOsNode {
         Ok(hash)
     }
 
+    /// Refuse governance calls that cannot succeed: governance frozen by the
+    /// security state, unknown proposal, voting closed, or execution before
+    /// the timelock in force here ran out
+    async fn check_governance_call(&self, call: &TransactionCall) -> Result<(), NodeError> {
+        let (proposal_id, executing) = match call {
+            TransactionCall::SubmitProposal { .. } => (None, false),
+            TransactionCall::SecondProposal { proposal_id } | TransactionCall::VoteProposal { proposal_id, .. } => {
+                (Some(*proposal_id), false)
+            }
+            TransactionCall::ExecuteProposal { proposal_id } => (Some(*proposal_id), true),
+            _ => return Ok(()),
+        };
+        let timelock_multiplier = {
+            let invariants = self.invariants.read().await;
+            if !invariants.security().is_governance_allowed(false) {
+                return Err(NodeError::Transaction(GovernanceError::GovernanceFrozen.to_string()));
+            }
+            invariants.security().get_governance_timelock_multiplier()
+        };
+        if let Some(proposal_id) = proposal_id {
+            let next_block = self.chain_height().await + 1;
+            let proposal = self
+                .storage
+                .read()
+                .await
+                .get_proposal(proposal_id)
+                .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))?
+                .ok_or_else(|| NodeError::Transaction(GovernanceError::ProposalNotFound.to_string()))?;
+            if executing {
+                // The chain enacts after the base timelock, Degraded holds it longer here
+                if proposal.status_at(next_block, timelock_multiplier) != ProposalStatus::ReadyToExecute {
+                    return Err(NodeError::Transaction(GovernanceError::NotReadyToExecute.to_string()));
+                }
+            } else if !proposal.is_voting_open(next_block) {
+                return Err(NodeError::Transaction(GovernanceError::VotingClosed.to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Refuse emergency declarations while the circuit breakers are not
+    /// armed, and the operations SPEC v7 suspends while an emergency is in
+    /// effect (declared on chain, or the network in Terminal state)
+    async fn check_emergency_call(&self, call: &TransactionCall) -> Result<(), NodeError> {
+        let (armed, terminal) = {
+            let invariants = self.invariants.read().await;
+            (invariants.security().is_emergency_armed(), invariants.security().is_terminal())
+        };
+        if let TransactionCall::SignEmergencyDeclaration = call {
+            if !armed {
+                return Err(NodeError::Transaction("Emergency circuit breakers are not armed".to_string()));
+            }
+            return Ok(());
+        }
+        let next_block = self.chain_height().await + 1;
+        let declared = self
+            .storage
+            .read()
+            .await
+            .get_emergency_declaration()
+            .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))?
+            .is_active(next_block);
+        if declared || terminal {
+            allowed_during_emergency(call).map_err(|e| NodeError::Transaction(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Submit a transaction at most once per idempotency key
+    ///
+    /// A key already used within IDEMPOTENCY_WINDOW_SECS returns the
+    /// transaction first accepted for it, even if `tx` differs (e.g. re-signed
+    /// after a timeout), so a retrying client can never pay twice. Keys are
+    /// persisted, so this holds across restarts. The key is released only if
+    /// the mempool rejects the transaction.
+    pub async fn submit_transaction_idempotent(
+        &self,
+        tx: SignedTransaction,
+        key: &str,
+    ) -> Result<IdempotentSubmission, NodeError> {
+        if key.is_empty() || key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+            return Err(NodeError::Transaction(format!(
+                "Idempotency key must be 1 to {} bytes",
+                MAX_IDEMPOTENCY_KEY_LEN
+            )));
+        }
+
+        let _guard = self.idempotency.lock().await;
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        let hash = tx.hash.unwrap_or_else(|| tx.transaction.hash());
+
+        {
+            let storage = self.storage.read().await;
+            let existing = storage
+                .get_idempotency_record(key)
+                .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))?;
+            if let Some(record) = existing {
+                if now.saturating_sub(record.submitted_at) < IDEMPOTENCY_WINDOW_SECS {
+                    if record.tx_hash != hash {
+                        warn!(
+                            "Idempotency key reused for a different transaction {:?}, keeping {:?}",
+                            hash, record.tx_hash
+                        );
+                    }
+                    return Ok(IdempotentSubmission { hash: record.tx_hash, duplicate: true });
+                }
+            }
+
+            // Record before submitting: a crash after the broadcast must not
+            // let the key be used again
+            storage
+                .put_idempotency_record(key, &IdempotencyRecord { tx_hash: hash, submitted_at: now })
+                .map_err(|e| NodeError::Storage(format!("Write error: {:?}", e)))?;
+        }
+
+        match self.submit_transaction(tx).await {
+            Ok(hash) => Ok(IdempotentSubmission { hash, duplicate: false }),
+            Err(e) => {
+                // Rejected by the mempool: nothing was sent, the key can be retried.
+                // A broadcast failure keeps the key (the transaction is pooled).
+                if matches!(e, NodeError::Transaction(_) | NodeError::Pool(_)) {
+                    if let Err(e) = self.storage.read().await.remove_idempotency_record(key) {
+                        warn!("Failed to release idempotency key: {:?}", e);
+                    }
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Forget idempotency keys older than the window (called from periodic maintenance)
+    pub async fn expire_idempotency_keys(&self) -> Result<usize, NodeError> {
+        let before = (chrono::Utc::now().timestamp().max(0) as u64).saturating_sub(IDEMPOTENCY_WINDOW_SECS);
+        let _guard = self.idempotency.lock().await;
+        self.storage
+            .read()
+            .await
+            .expire_idempotency_records(before)
+            .map_err(|e| NodeError::Storage(format!("Idempotency key expiry failed: {}", e)))
+    }
+
     /// Get chain height
     pub async fn chain_height(&self) -> BlockNumber {
         *self.chain_height.read().await
@@ -1390,6 +2199,14 @@ impl KratOsNode {
         self.mempool.read().await.len()
     }
 
+    /// Pending transactions in inclusion order
+    pub async fn mempool_content(&self) -> PoolContent {
+        // Same lock order as block production
+        let mempool = self.mempool.read().await;
+        let mut storage = self.storage.write().await;
+        mempool.content(&mut storage)
+    }
+
     /// Get connected peer count
     pub async fn peer_count(&self) -> usize {
         self.network.read().await.peer_count()
@@ -1400,6 +2217,11 @@ impl KratOsNode {
         self.genesis_hash
     }
 
+    /// Chain configuration (`--chain`)
+    pub fn chain_config(&self) -> &ChainConfig {
+        &self.config
+    }
+
     /// Get genesis timestamp from drift tracker
     /// This is the canonical reference for slot/time calculations
     pub async fn genesis_timestamp(&self) -> u64 {
@@ -1448,14 +2270,41 @@ impl KratOsNode {
         self.network.read().await.connected_peers()
     }
 
-    /// Get network stats
-    pub async fn network_stats(&self) -> crate::network::peer::PeerStats {
-        self.network.read().await.peer_stats()
+    /// Connected and banned peers
+    pub async fn peers(&self) -> Vec<crate::network::PeerInfo> {
+        self.network.read().await.peers()
     }
 
-    /// Get local peer ID
-    pub async fn local_peer_id(&self) -> libp2p::PeerId {
-        self.network.read().await.local_peer_id()
+    /// Known competing chains and the local finalized block
+    pub async fn forks_report(&self) -> Result<ForksReport, NodeError> {
+        let finalized = self
+            .storage
+            .read()
+            .await
+            .latest_justified_block()
+            .map_err(|e| NodeError::Storage(e.to_string()))?;
+        let network = self.network.read().await;
+        Ok(ForksReport { finalized, forks: network.forks(), downloading: network.downloading_fork() })
+    }
+
+    /// Ban a peer for `secs` seconds (disconnected right away)
+    pub async fn ban_peer(&self, peer_id: libp2p::PeerId, reason: &str, secs: u64) -> crate::network::peer::PeerBan {
+        self.network.write().await.ban_peer_for(peer_id, reason, secs)
+    }
+
+    /// Lift a peer ban (false: the peer wasn't banned)
+    pub async fn unban_peer(&self, peer_id: &libp2p::PeerId) -> bool {
+        self.network.write().await.unban_peer(peer_id)
+    }
+
+    /// Get network stats
+    pub async fn network_stats(&self) -> crate::network::peer::PeerStats {
+        self.network.read().await.peer_stats()
+    }
+
+    /// Get local peer ID
+    pub async fn local_peer_id(&self) -> libp2p::PeerId {
+        self.network.read().await.local_peer_id()
     }
 
     /// Broadcast a produced block
@@ -1495,6 +2344,7 @@ impl KratOsNode {
                         }
                         Err(e) => {
                             warn!("Failed to import buffered block #{}: {:?}", block.header.number, e);
+                            self.check_invalid_block(&block, &e).await;
                             break;
                         }
                     }
@@ -1523,6 +2373,217 @@ impl KratOsNode {
             .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))
     }
 
+    /// Get the receipt of an included transaction
+    pub async fn get_receipt(&self, tx_hash: &Hash) -> Result<Option<StoredReceipt>, NodeError> {
+        let storage = self.storage.read().await;
+        storage
+            .get_receipt(tx_hash)
+            .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))
+    }
+
+    /// Fee records of the last `block_count` blocks, best block included
+    ///
+    /// Blocks executed before the fee market have no record: they are reported
+    /// at their derived base fee, with no transactions.
+    pub async fn fee_history(&self, block_count: u64) -> Result<FeeHistory, NodeError> {
+        let best = self.chain_height().await;
+        let oldest_block = (best + 1).saturating_sub(block_count);
+        let storage = self.storage.read().await;
+        let read_error = |e: StateError| NodeError::Storage(format!("Read error: {:?}", e));
+
+        let mut blocks = Vec::with_capacity((best + 1 - oldest_block) as usize);
+        for number in oldest_block..=best {
+            let fee = match storage.get_block_fee(number).map_err(read_error)? {
+                Some(fee) => fee,
+                None => BlockFee { base_fee: storage.base_fee_at(number).map_err(read_error)?, transactions: 0 },
+            };
+            blocks.push(fee);
+        }
+        let next_base_fee = storage.base_fee_at(best + 1).map_err(read_error)?;
+        Ok(FeeHistory { oldest_block, blocks, next_base_fee })
+    }
+
+    /// Enable archive mode (keep historical state), returns the first archived block
+    pub async fn enable_archive(&self) -> Result<BlockNumber, NodeError> {
+        let mut storage = self.storage.write().await;
+        storage
+            .enable_archive()
+            .map_err(|e| NodeError::Storage(format!("Failed to enable archive mode: {}", e)))
+    }
+
+    /// Enable the secondary indexes, returns the number of blocks indexed to catch up
+    pub async fn enable_index(&self) -> Result<u64, NodeError> {
+        let mut storage = self.storage.write().await;
+        storage
+            .enable_index()
+            .map_err(|e| NodeError::Storage(format!("Failed to enable indexing: {}", e)))
+    }
+
+    /// Prune block data more than `keep_blocks` behind finality
+    pub async fn enable_pruning(&self, keep_blocks: u32) {
+        *self.pruning.write().await = Some(keep_blocks);
+    }
+
+    /// Run a pruning pass (called from periodic maintenance)
+    ///
+    /// Bounded to MAX_PRUNE_BLOCKS_PER_PASS so the storage lock is not held
+    /// for long; a large backlog is pruned over several passes. Blocks of
+    /// served snapshots are kept.
+    pub async fn prune(&self) -> Result<PruneReport, NodeError> {
+        let Some(keep_blocks) = *self.pruning.read().await else {
+            return Ok(PruneReport::default());
+        };
+        let retain: BTreeSet<BlockNumber> = self
+            .snapshots
+            .read()
+            .await
+            .newest_first()
+            .map(|snapshot| snapshot.header.block_number)
+            .collect();
+
+        let report = self
+            .storage
+            .write()
+            .await
+            .prune(keep_blocks, &retain, MAX_PRUNE_BLOCKS_PER_PASS)
+            .map_err(|e| NodeError::Storage(format!("Pruning failed: {}", e)))?;
+        if report.pruned_blocks > 0 {
+            debug!(
+                "🧹 Pruned {} blocks (up to #{})",
+                report.pruned_blocks,
+                report.pruned_up_to.unwrap_or(0)
+            );
+        }
+        Ok(report)
+    }
+
+    /// Resolve a canonical block hash to its number (None if unknown or not canonical)
+    pub async fn canonical_block_number(&self, block_hash: &Hash) -> Result<Option<BlockNumber>, NodeError> {
+        let storage = self.storage.read().await;
+        let block = storage
+            .get_block_by_hash(block_hash)
+            .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))?;
+        let Some(block) = block else {
+            return Ok(None);
+        };
+
+        // Historical state is versioned by height, so only canonical blocks can be queried
+        let canonical = storage
+            .get_block_by_number(block.header.number)
+            .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))?;
+        Ok(canonical
+            .filter(|b| b.hash() == *block_hash)
+            .map(|b| b.header.number))
+    }
+
+    /// Get account info at a block (archive mode)
+    ///
+    /// Outer None: unknown or non-canonical block.
+    pub async fn get_account_info_at(
+        &self,
+        account: &AccountId,
+        block_hash: &Hash,
+    ) -> Result<Option<Option<AccountInfo>>, NodeError> {
+        let Some(number) = self.canonical_block_number(block_hash).await? else {
+            return Ok(None);
+        };
+        let storage = self.storage.read().await;
+        storage
+            .get_account_at(account, number)
+            .map(Some)
+            .map_err(|e| NodeError::Storage(e.to_string()))
+    }
+
+    /// Get a raw state value at a block (archive mode)
+    ///
+    /// Outer None: unknown or non-canonical block.
+    pub async fn get_storage_at(
+        &self,
+        key: &[u8],
+        block_hash: &Hash,
+    ) -> Result<Option<Option<Vec<u8>>>, NodeError> {
+        let Some(number) = self.canonical_block_number(block_hash).await? else {
+            return Ok(None);
+        };
+        let storage = self.storage.read().await;
+        storage
+            .get_storage_at(key, number)
+            .map(Some)
+            .map_err(|e| NodeError::Storage(e.to_string()))
+    }
+
+    /// Merkle proof of an account against a block's state root
+    ///
+    /// Outer None: unknown or non-canonical block. Inner None: no such account.
+    pub async fn get_account_proof(
+        &self,
+        account: &AccountId,
+        block_hash: &Hash,
+    ) -> Result<Option<Option<MerkleProof>>, NodeError> {
+        let Some(number) = self.canonical_block_number(block_hash).await? else {
+            return Ok(None);
+        };
+        let storage = self.storage.read().await;
+        storage
+            .prove_account(account, number)
+            .map(Some)
+            .map_err(|e| NodeError::Storage(e.to_string()))
+    }
+
+    /// Transactions sent or received by an account, newest first (--index)
+    pub async fn indexed_transactions(
+        &self,
+        account: &AccountId,
+        filter: &TransactionFilter,
+        after: Option<IndexCursor>,
+        limit: usize,
+    ) -> Result<IndexPage<IndexedTransaction>, NodeError> {
+        let storage = self.storage.read().await;
+        storage
+            .indexed_transactions(account, filter, after, limit)
+            .map_err(|e| NodeError::Storage(e.to_string()))
+    }
+
+    /// Pending transactions sent by an account or transferring to it, in
+    /// nonce order
+    pub async fn pending_transactions_of(&self, account: &AccountId) -> Vec<SignedTransaction> {
+        let mempool = self.mempool.read().await;
+        mempool
+            .transactions()
+            .into_iter()
+            .filter(|tx| {
+                tx.transaction.sender == *account
+                    || tx.transaction.call.transfer().is_some_and(|(to, _)| to == *account)
+            })
+            .collect()
+    }
+
+    /// Events of a type (`contract.Name`), newest first (--index)
+    pub async fn indexed_events(
+        &self,
+        event_type: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<IndexPage<IndexedEvent>, NodeError> {
+        let storage = self.storage.read().await;
+        storage
+            .indexed_events(event_type, offset, limit)
+            .map_err(|e| NodeError::Storage(e.to_string()))
+    }
+
+    /// Blocks authored by a validator, newest first (--index)
+    pub async fn indexed_blocks_by_author(
+        &self,
+        author: &AccountId,
+        offset: usize,
+        limit: usize,
+    ) -> Result<IndexPage<IndexedBlock>, NodeError> {
+        let storage = self.storage.read().await;
+        storage
+            .indexed_blocks_by_author(author, offset, limit)
+            .map_err(|e| NodeError::Storage(e.to_string()))
+    }
+
     /// Get full account info (including nonce)
     pub async fn get_account_info(&self, account: &AccountId) -> Result<Option<AccountInfo>, NodeError> {
         let mut storage = self.storage.write().await;
@@ -1541,6 +2602,52 @@ impl KratOsNode {
         }
     }
 
+    /// Nonce for the account's next transaction, past its pending ones
+    pub async fn next_nonce(&self, account: &AccountId) -> Result<u64, NodeError> {
+        let account_nonce = self.get_nonce(account).await?;
+        Ok(self.mempool.read().await.next_nonce(account, account_nonce))
+    }
+
+    /// Delegations of an account: validator and amount
+    pub async fn delegations(&self, delegator: &AccountId) -> Result<Vec<(AccountId, Balance)>, NodeError> {
+        self.storage
+            .read()
+            .await
+            .get_delegations(delegator)
+            .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))
+    }
+
+    /// Stake delegated to a validator (empty pool if none), with the
+    /// commission in force
+    pub async fn delegation_pool(&self, validator: &AccountId) -> Result<(DelegationPool, u8), NodeError> {
+        let epoch = self.chain_height().await / EPOCH_DURATION_BLOCKS;
+        let storage = self.storage.read().await;
+        let read = |e| NodeError::Storage(format!("Read error: {:?}", e));
+        let pool = storage.get_delegation_pool(validator).map_err(read)?.unwrap_or_default();
+        let commission = storage.get_commission(validator).map_err(read)?.unwrap_or_default();
+        Ok((pool, commission.at(epoch)))
+    }
+
+    /// Unbonding queue of an account (empty if nothing is unbonding)
+    pub async fn unbonding_queue(&self, account: &AccountId) -> Result<UnbondingQueue, NodeError> {
+        self.storage
+            .read()
+            .await
+            .get_unbonding_queue(account)
+            .map(Option::unwrap_or_default)
+            .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))
+    }
+
+    /// Delegation rewards owed to an account (empty if none)
+    pub async fn reward_ledger(&self, account: &AccountId) -> Result<RewardLedger, NodeError> {
+        self.storage
+            .read()
+            .await
+            .get_reward_ledger(account)
+            .map(Option::unwrap_or_default)
+            .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))
+    }
+
     /// Get storage backend for block production
     pub fn storage(&self) -> Arc<RwLock<StateBackend>> {
         self.storage.clone()
@@ -1556,11 +2663,198 @@ impl KratOsNode {
         self.validators.clone()
     }
 
+    /// Operational report for a validator (credits, performance, slashing, session)
+    ///
+    /// Returns None if the account is not a registered validator
+    pub async fn validator_report(&self, account: &AccountId) -> Option<ValidatorReport> {
+        let current_block = self.chain_height().await;
+
+        let (info, is_active) = {
+            let validators = self.validators.read().await;
+            let info = validators.get_validator(account)?.clone();
+            (info, validators.is_active_at(account, current_block))
+        };
+
+        let (credits, vc_history, clock_sync_failures, clock_vc_penalty, unbonding_balance, commission) = {
+            let storage = self.storage.read().await;
+            (
+                storage.get_vc_record(account).ok().flatten(),
+                storage.get_vc_history(account).unwrap_or_default(),
+                storage.get_clock_failures(account).unwrap_or(0),
+                storage.get_clock_vc_penalty(account).unwrap_or(0),
+                storage.get_total_unbonding(account).unwrap_or(0),
+                storage.get_commission(account).ok().flatten().unwrap_or_default(),
+            )
+        };
+
+        let finality_voter = self.get_last_finality_voters().await.contains(account);
+
+        Some(ValidatorReport {
+            can_participate: info.can_participate_at(current_block),
+            info,
+            is_active,
+            credits,
+            vc_history,
+            clock_sync_failures,
+            clock_vc_penalty,
+            unbonding_balance,
+            commission,
+            finality_voter,
+            current_block,
+        })
+    }
+
+    /// Epoch, validator set, bootstrap and security state at the current block
+    pub async fn epoch_report(&self) -> EpochReport {
+        let current_block = self.chain_height().await;
+        let epoch = current_block / EPOCH_DURATION_BLOCKS;
+
+        let validators: Vec<(AccountId, Balance)> = {
+            let validators = self.validators.read().await;
+            validators
+                .active_validators_at(current_block)
+                .into_iter()
+                .map(|v| (v.id, v.stake))
+                .collect()
+        };
+        let total_stake = validators.iter().map(|(_, stake)| *stake).sum();
+        let bootstrap = get_bootstrap_config();
+
+        let (security_state, block_time_multiplier, timelock_multiplier) = {
+            let invariants = self.invariants.read().await;
+            let security = invariants.security();
+            (
+                security.state.clone(),
+                security.get_block_time_multiplier(),
+                security.get_governance_timelock_multiplier(),
+            )
+        };
+
+        EpochReport {
+            current_block,
+            epoch,
+            is_bootstrap: bootstrap.is_bootstrap(epoch),
+            bootstrap_end_epoch: bootstrap.end_epoch,
+            bootstrap_status: bootstrap.get_bootstrap_status(epoch, validators.len() as u32, total_stake),
+            validators,
+            security_state,
+            block_time_multiplier,
+            timelock_multiplier,
+        }
+    }
+
+    /// Governance proposals (all, or the one with `proposal_id`)
+    pub async fn governance_report(&self, proposal_id: Option<ProposalId>) -> Result<GovernanceReport, NodeError> {
+        let current_block = self.chain_height().await;
+        let timelock_multiplier = self.invariants.read().await.security().get_governance_timelock_multiplier();
+        let storage = self.storage.read().await;
+        let read = |e| NodeError::Storage(format!("Read error: {:?}", e));
+        let proposals = match proposal_id {
+            Some(id) => storage.get_proposal(id).map_err(read)?.into_iter().collect(),
+            None => storage.proposals().map_err(read)?,
+        };
+        Ok(GovernanceReport { current_block, timelock_multiplier, proposals })
+    }
+
+    /// Treasury balance and the TreasurySpend proposals
+    pub async fn treasury_report(&self) -> Result<TreasuryReport, NodeError> {
+        let mut governance = self.governance_report(None).await?;
+        governance.proposals.retain(|p| matches!(p.proposal_type, ProposalType::TreasurySpend { .. }));
+        let account = treasury_account();
+        let balance = self.get_balance(&account).await?;
+        Ok(TreasuryReport { account, balance, governance })
+    }
+
+    /// Protocol parameters in force, the ProtocolChange proposals and the audit log
+    pub async fn parameters_report(&self) -> Result<ParametersReport, NodeError> {
+        let mut governance = self.governance_report(None).await?;
+        governance.proposals.retain(|p| matches!(p.proposal_type, ProposalType::ProtocolChange { .. }));
+        let storage = self.storage.read().await;
+        let read = |e| NodeError::Storage(format!("Read error: {:?}", e));
+        let parameters = storage.get_protocol_parameters().map_err(read)?;
+        let log = storage.parameter_log().map_err(read)?;
+        Ok(ParametersReport { parameters, governance, log })
+    }
+
+    /// Identity registered by an account (None if it never registered)
+    pub async fn identity(&self, owner: &AccountId) -> Result<Option<RegisteredIdentity>, NodeError> {
+        self.storage
+            .read()
+            .await
+            .get_identity(owner)
+            .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))
+    }
+
+    /// Emergency status: security state, on-chain declaration and the SPEC v7
+    /// circuit breakers
+    pub async fn emergency_report(&self) -> Result<EmergencyReport, NodeError> {
+        let next_block = self.chain_height().await + 1;
+        let (security_state, armed, terminal) = {
+            let invariants = self.invariants.read().await;
+            let security = invariants.security();
+            (security.state.clone(), security.is_emergency_armed(), security.is_terminal())
+        };
+        let storage = self.storage.read().await;
+        let read = |e| NodeError::Storage(format!("Read error: {:?}", e));
+        let declaration = storage.get_emergency_declaration().map_err(read)?;
+        let validators = storage.validator_count().map_err(read)?;
+        let mut breakers: Vec<CircuitBreaker> = EmergencyContract::new().breakers.into_values().collect();
+        breakers.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(EmergencyReport {
+            next_block,
+            security_state,
+            armed,
+            in_emergency: declaration.is_active(next_block) || terminal,
+            validators,
+            declaration,
+            breakers,
+        })
+    }
+
+    /// Verified-person status of an account and the round the next block belongs to
+    pub async fn personhood_report(&self, account: &AccountId) -> Result<PersonhoodReport, NodeError> {
+        let next_block = self.chain_height().await + 1;
+        let (epoch, _) = ceremony_phase(next_block);
+        let storage = self.storage.read().await;
+        let read = |e| NodeError::Storage(format!("Read error: {:?}", e));
+        let record = storage.get_personhood(account).map_err(read)?;
+        let round = storage.get_ceremony_round(epoch).map_err(read)?.unwrap_or_else(|| CeremonyRound::new(epoch));
+        Ok(PersonhoodReport { account: *account, next_block, record, round })
+    }
+
+    /// Latest messages received by an account, newest first
+    pub async fn inbox(&self, account: &AccountId, limit: usize) -> Result<Vec<AccountMessage>, NodeError> {
+        self.storage
+            .read()
+            .await
+            .inbox_messages(account, limit)
+            .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))
+    }
+
+    /// Reputation of an account (None if it never earned any)
+    pub async fn reputation(&self, account: &AccountId) -> Result<Option<AccountReputation>, NodeError> {
+        self.storage
+            .read()
+            .await
+            .get_reputation(account)
+            .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))
+    }
+
+    /// Reputations of all accounts
+    pub async fn reputations(&self) -> Result<Vec<AccountReputation>, NodeError> {
+        self.storage
+            .read()
+            .await
+            .reputations()
+            .map_err(|e| NodeError::Storage(format!("Read error: {:?}", e)))
+    }
+
     /// Try to produce a block if we are the slot leader
     ///
     /// SECURITY FIX #36: Checks clock health before production.
     /// - Excluded/Recovering status: Block production suspended
     /// - Degraded status: Block production at reduced priority (logged)
+    #[tracing::instrument(name = "block_production", skip_all, fields(epoch = epoch, slot = slot))]
     pub async fn try_produce_block(
         &self,
         validator_key: ed25519_dalek::SigningKey,
@@ -1648,9 +2942,38 @@ impl KratOsNode {
             }
         }
 
+        // Pending equivocation reports go into this block
+        self.report_pending_evidence(&validator_key).await;
+        self.report_pending_fraud_proofs(&validator_key).await;
+
+        // Sign with the session key bound for this epoch, if any
+        let signer = self
+            .storage
+            .read()
+            .await
+            .block_signer(&validator_id, epoch)
+            .map_err(|e| NodeError::Storage(e.to_string()))?;
+        let signing_key = if signer == validator_id {
+            validator_key
+        } else {
+            match self.session_keys.read().await.get(&signer) {
+                Some(key) => key.clone(),
+                None => {
+                    warn!(
+                        "⚠️  Session key 0x{} bound for epoch {} is not loaded (missing from the keystore or keystore locked): cannot sign",
+                        hex::encode(signer.as_bytes()),
+                        epoch
+                    );
+                    return Ok(None);
+                }
+            }
+        };
+
         // Use the persistent producer database for double-signing protection
         // This ensures signed slots are tracked across all block production attempts
-        let mut producer = BlockProducer::new(Some(validator_key), self.producer_db.clone());
+        let production_started = std::time::Instant::now();
+        let producer_config = self.producer_config.read().await.clone();
+        let mut producer = BlockProducer::with_config(producer_config, Some(signing_key), self.producer_db.clone());
         match producer
             .produce_block(
                 &parent_block,
@@ -1666,6 +2989,10 @@ impl KratOsNode {
                 // Store the block we just produced (without re-executing)
                 // State was already modified during production
                 self.store_produced_block(block.clone()).await?;
+                self.metrics.block_production_seconds.observe(production_started.elapsed().as_secs_f64());
+                if let Some(timings) = producer.last_timings() {
+                    self.metrics.observe_production(&timings);
+                }
                 Ok(Some(block))
             }
             Err(crate::node::producer::ProductionError::AlreadySignedThisSlot) => {
@@ -1741,6 +3068,830 @@ impl KratOsNode {
         self.handle_network_event(event).await;
     }
 
+    // =========================================================================
+    // WARP SYNC
+    // =========================================================================
+
+    /// Take state snapshots for warp syncing peers (validators)
+    pub async fn enable_snapshots(&self) {
+        self.snapshots.write().await.enable();
+        info!("📸 State snapshots enabled (every {} blocks)", SNAPSHOT_INTERVAL);
+    }
+
+    /// Sync by downloading a finalized state snapshot when far behind
+    ///
+    /// Block sync is paused until a peer status shows whether the node is more
+    /// than the warp threshold behind. A download interrupted by a restart is
+    /// resumed from the data directory. `checkpoint` is one of the sources
+    /// that can corroborate a snapshot's finalized head.
+    pub async fn enable_warp_sync(&self, checkpoint: Option<WarpCheckpoint>) {
+        let height = *self.chain_height.read().await;
+        let mut warp = WarpSyncManager::with_data_dir(height, self.data_path.join(WARP_SYNC_DIR));
+        if let Some(checkpoint) = checkpoint {
+            warp.set_checkpoint(checkpoint);
+        }
+        self.network.write().await.set_sync_paused(true);
+        *self.warp_sync.write().await = Some(warp);
+    }
+
+    /// Sync from an operator-trusted finalized checkpoint
+    ///
+    /// Warp syncs to a snapshot whose chain contains the checkpoint, so the
+    /// blocks before it are never downloaded. Blocks after the snapshot are
+    /// then imported and finalized as usual.
+    pub async fn enable_checkpoint_sync(&self, checkpoint: WarpCheckpoint) {
+        self.enable_warp_sync(None).await;
+        if let Some(warp) = self.warp_sync.write().await.as_mut() {
+            warp.set_sync_checkpoint(checkpoint);
+        }
+    }
+
+    /// Current warp sync state (None when warp sync is disabled or finished)
+    pub async fn warp_sync_state(&self) -> Option<WarpSyncState> {
+        self.warp_sync.read().await.as_ref().map(|warp| warp.state())
+    }
+
+    /// Keep warp sync moving (called from periodic maintenance)
+    ///
+    /// Re-requests unanswered chunks, looks for snapshot providers and falls
+    /// back to block sync when no snapshot can be obtained.
+    pub async fn tick_warp_sync(&self) {
+        let mut guard = self.warp_sync.write().await;
+        let Some(warp) = guard.as_mut() else {
+            return;
+        };
+
+        match warp.state() {
+            WarpSyncState::RequestingSnapshot => {
+                if warp.is_stalled(WARP_MANIFEST_TIMEOUT) {
+                    self.abandon_warp_sync(&mut guard, WarpSyncError::NoPeers).await;
+                    return;
+                }
+                // DNS seed state or peers may have caught up with an offer
+                self.accept_warp_manifest(warp).await;
+                if warp.state() == WarpSyncState::RequestingSnapshot {
+                    let mut network = self.network.write().await;
+                    for peer in network.connected_peers() {
+                        network.request_warp_manifest(&peer);
+                    }
+                }
+            }
+            WarpSyncState::DownloadingState { .. } => {
+                if warp.is_stalled(WARP_DOWNLOAD_TIMEOUT) {
+                    warp.restart();
+                    return;
+                }
+                if warp.sources().is_empty() {
+                    // Resumed download: find peers still serving this snapshot
+                    let mut network = self.network.write().await;
+                    for peer in network.connected_peers() {
+                        network.request_warp_manifest(&peer);
+                    }
+                }
+                self.request_warp_chunks(warp).await;
+            }
+            WarpSyncState::VerifyingState => self.finish_warp_sync(&mut guard).await,
+            _ => {}
+        }
+    }
+
+    /// Choose between warp sync and block sync from a peer's height
+    async fn warp_sync_peer_status(&self, peer: libp2p::PeerId, best_block: BlockNumber) {
+        let mut guard = self.warp_sync.write().await;
+        let Some(warp) = guard.as_mut() else {
+            return;
+        };
+        warp.peer_height_update(best_block);
+
+        let mut network = self.network.write().await;
+        match warp.state() {
+            WarpSyncState::Inactive => {
+                // Close enough to the network: replay the missing blocks
+                network.set_sync_paused(false);
+                network.maybe_start_sync();
+            }
+            WarpSyncState::RequestingSnapshot => {
+                network.set_sync_paused(true);
+                network.request_warp_manifest(&peer);
+            }
+            WarpSyncState::DownloadingState { .. } if warp.sources().is_empty() => {
+                network.request_warp_manifest(&peer);
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle a snapshot manifest from a peer
+    ///
+    /// The manifest is only considered if its justification is signed by a
+    /// supermajority of the validators we know, and only downloaded once its
+    /// finalized head is corroborated by other sources.
+    async fn on_warp_manifest(&self, manifest: SnapshotManifest, from: libp2p::PeerId) {
+        let mut guard = self.warp_sync.write().await;
+        let Some(warp) = guard.as_mut() else {
+            return;
+        };
+
+        if let Some(current) = warp.manifest() {
+            // Another provider of the snapshot being downloaded
+            if current.same_snapshot(&manifest) {
+                warp.add_source(from);
+                self.request_warp_chunks(warp).await;
+            }
+            return;
+        }
+        if warp.state() != WarpSyncState::RequestingSnapshot {
+            return;
+        }
+
+        let validators: HashSet<AccountId> = {
+            let validators_guard = self.validators.read().await;
+            validators_guard.active_validators().iter().map(|v| v.id).collect()
+        };
+        // Not banned: a changed validator set also fails this check
+        if let Err(e) = manifest.verify(&validators) {
+            warn!("Rejected warp sync manifest from {}: {:?}", from, e);
+            return;
+        }
+
+        warp.offer_manifest(from, manifest);
+        self.accept_warp_manifest(warp).await;
+    }
+
+    /// Start downloading the best corroborated snapshot, if any
+    async fn accept_warp_manifest(&self, warp: &mut WarpSyncManager) {
+        let context = self.warp_trust_context().await;
+        let Some(anchors) = warp.accept_corroborated(&context) else {
+            return;
+        };
+        let Some(manifest) = warp.manifest() else {
+            return;
+        };
+        info!(
+            "📦 Warp syncing to finalized block #{} ({} chunks) from {} peers, corroborated by {:?}",
+            manifest.header.block_number, manifest.header.total_chunks, warp.sources().len(), anchors
+        );
+        self.request_warp_chunks(warp).await;
+    }
+
+    /// Independent views of the chain used to corroborate warp sync targets
+    async fn warp_trust_context(&self) -> TrustContext {
+        let seed_best_height = self.dns_client.read().await.as_ref().and_then(|client| {
+            client
+                .network_state()
+                .filter(|state| state.genesis_hash == *self.genesis_hash.as_bytes())
+                .map(|state| state.best_height)
+        });
+        let connected_peers = self.network.read().await.connected_peers().into_iter().collect();
+        TrustContext { seed_best_height, connected_peers }
+    }
+
+    /// Handle a snapshot chunk from a peer
+    async fn on_warp_chunk(&self, block_number: BlockNumber, chunk: StateChunk, from: libp2p::PeerId) {
+        let mut guard = self.warp_sync.write().await;
+        let Some(warp) = guard.as_mut() else {
+            return;
+        };
+        if warp.manifest().map(|m| m.header.block_number) != Some(block_number) {
+            return;
+        }
+
+        let index = chunk.index;
+        if let Err(e) = warp.handle_state_chunk(chunk) {
+            warn!("Rejected warp sync chunk {} from {}: {:?}", index, from, e);
+            warp.chunk_failed(index);
+            warp.remove_source(&from);
+            if e == WarpSyncError::InvalidMerkleProof {
+                self.network.write().await.ban_peer(from, "Invalid warp sync chunk");
+            }
+            return;
+        }
+
+        if warp.state() == WarpSyncState::VerifyingState {
+            self.finish_warp_sync(&mut guard).await;
+        } else {
+            self.request_warp_chunks(warp).await;
+        }
+    }
+
+    /// Request the next chunks from the snapshot providers
+    ///
+    /// Falls back to all connected peers when no provider is known yet:
+    /// snapshots of the same block are identical on every validator.
+    async fn request_warp_chunks(&self, warp: &mut WarpSyncManager) {
+        let Some(block_number) = warp.manifest().map(|m| m.header.block_number) else {
+            return;
+        };
+        let indices = warp.next_chunk_requests();
+        if indices.is_empty() {
+            return;
+        }
+
+        let mut network = self.network.write().await;
+        let peers = if warp.sources().is_empty() {
+            network.connected_peers()
+        } else {
+            warp.sources().to_vec()
+        };
+        if peers.is_empty() {
+            for index in indices {
+                warp.chunk_failed(index);
+            }
+            return;
+        }
+        for (i, index) in indices.into_iter().enumerate() {
+            network.request_warp_chunk(&peers[i % peers.len()], block_number, index);
+        }
+    }
+
+    /// Import a fully downloaded snapshot and continue with block sync
+    ///
+    /// The accounts must rebuild the state root of the finalized snapshot block.
+    async fn finish_warp_sync(&self, guard: &mut Option<WarpSyncManager>) {
+        let Some(warp) = guard.as_mut() else {
+            return;
+        };
+        let Some(manifest) = warp.manifest().cloned() else {
+            return;
+        };
+        let snapshot = match warp.verify_state() {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                self.abandon_warp_sync(guard, e).await;
+                return;
+            }
+        };
+
+        let block = manifest.block;
+        let block_number = block.header.number;
+        let block_hash = block.hash();
+        let result = {
+            let mut storage = self.storage.write().await;
+            let chain_id = ChainId(0); // TODO: Get from config
+            storage
+                .import_snapshot(&block, snapshot.accounts().cloned().collect(), chain_id)
+                .and_then(|_| storage.store_justification(&manifest.justification))
+        };
+        if let Err(e) = result {
+            warn!("Failed to import warp sync snapshot of block #{}: {}", block_number, e);
+            self.abandon_warp_sync(guard, WarpSyncError::InvalidStateRoot).await;
+            return;
+        }
+
+        *self.current_block.write().await = Some(block);
+        *self.chain_height.write().await = block_number;
+        {
+            let mut network = self.network.write().await;
+            network.update_local_state(block_number, block_hash);
+            network.set_sync_paused(false);
+            network.maybe_start_sync();
+        }
+
+        warp.complete();
+        *guard = None;
+        info!(
+            "✅ Warp sync imported {} accounts at block #{} ({}), continuing with block sync",
+            snapshot.account_count(), block_number, block_hash
+        );
+    }
+
+    /// Give up on warp sync and replay blocks instead
+    async fn abandon_warp_sync(&self, guard: &mut Option<WarpSyncManager>, error: WarpSyncError) {
+        if let Some(warp) = guard.as_mut() {
+            warp.fail(error);
+        }
+        *guard = None;
+        warn!("⚠️  Warp sync failed ({:?}), falling back to block sync", error);
+
+        let mut network = self.network.write().await;
+        network.set_sync_paused(false);
+        network.maybe_start_sync();
+    }
+
+    /// Take a state snapshot after a snapshot interval block, if enabled
+    async fn maybe_take_snapshot(&self, block: &Block) {
+        let block_number = block.header.number;
+        if !self.snapshots.read().await.is_due(block_number) {
+            return;
+        }
+
+        let accounts = match self.storage.read().await.accounts() {
+            Ok(accounts) => accounts,
+            Err(e) => {
+                warn!("Failed to read accounts for snapshot at block #{}: {:?}", block_number, e);
+                return;
+            }
+        };
+        let state_root = StateRoot::new(block.header.state_root, block_number, ChainId(0));
+        let snapshot = StateSnapshot::new(block_number, block.hash(), state_root, accounts, SNAPSHOT_CHUNK_SIZE);
+        info!(
+            "📸 State snapshot at block #{}: {} accounts in {} chunks",
+            block_number, snapshot.account_count(), snapshot.header.total_chunks
+        );
+        self.snapshots.write().await.insert(snapshot);
+    }
+
+    // =========================================================================
+    // SPEC INVARIANTS
+    // =========================================================================
+
+    /// Feed an imported block to the invariant monitor
+    async fn track_invariants(&self, block: &Block) {
+        let active_validators = self.validators.read().await.active_validators().len() as u32;
+        self.invariants.write().await.on_block(block, active_validators);
+    }
+
+    /// Re-evaluate the SPEC invariants (called from periodic maintenance)
+    pub async fn check_invariants(&self) {
+        let block_number = *self.chain_height.read().await;
+        let validators: HashSet<AccountId> = {
+            let validators_guard = self.validators.read().await;
+            validators_guard.active_validators().iter().map(|v| v.id).collect()
+        };
+        let justification_verified = match self.storage.read().await.latest_justification() {
+            Ok(justification) => justification.map(|j| j.verify_with_validators(&validators)),
+            Err(e) => {
+                warn!("Failed to load latest justification for invariant checks: {:?}", e);
+                None
+            }
+        };
+
+        self.invariants.write().await.evaluate(InvariantInputs {
+            block_number,
+            active_validators: validators.len() as u32,
+            justification_verified,
+        });
+    }
+
+    /// Invariant gauges of the last evaluation
+    pub async fn invariant_report(&self) -> InvariantReport {
+        self.invariants.read().await.report()
+    }
+
+    /// Security state transitions since startup, oldest first
+    pub async fn security_history(&self) -> Vec<SecurityTransition> {
+        self.invariants.read().await.security_history()
+    }
+
+    /// Channel of the security state transitions, as they happen
+    pub async fn security_transition_notifier(&self) -> TransitionNotifier {
+        self.invariants.read().await.transition_notifier()
+    }
+
+    // =========================================================================
+    // METRICS
+    // =========================================================================
+
+    /// Metrics registry, shared with the RPC server
+    pub fn metrics(&self) -> Arc<NodeMetrics> {
+        self.metrics.clone()
+    }
+
+    /// Refresh the gauges and render the metrics (Prometheus text format)
+    pub async fn prometheus_metrics(&self) -> String {
+        let metrics = &self.metrics;
+        metrics.block_height.set(*self.chain_height.read().await as i64);
+        metrics.mempool_transactions.set(self.mempool_size().await as i64);
+        metrics.peers.set(self.peer_count().await as i64);
+        {
+            let storage = self.storage.read().await;
+            metrics.finalized_height.set(storage.latest_justified_block().ok().flatten().unwrap_or(0) as i64);
+            let cache = storage.cache_stats();
+            metrics.state_cache_hits.set(cache.hits as i64);
+            metrics.state_cache_misses.set(cache.misses as i64);
+            metrics.state_cache_evictions.set(cache.evictions as i64);
+        }
+        {
+            let relay = self.network.read().await.tx_relay_stats();
+            metrics.tx_relay_announced.set(relay.announced as i64);
+            metrics.tx_relay_duplicates.set(relay.duplicates as i64);
+            metrics.tx_relay_pulled.set(relay.pulled as i64);
+            metrics.tx_relay_served.set(relay.served as i64);
+            metrics.tx_relay_bytes_saved.set(relay.bytes_saved() as i64);
+        }
+        metrics.invariants_healthy.set(self.invariants.read().await.report().healthy as i64);
+        metrics.render()
+    }
+
+    // =========================================================================
+    // STATE CACHE
+    // =========================================================================
+
+    /// Set the number of accounts kept in the state cache
+    pub async fn set_state_cache_capacity(&self, capacity: usize) {
+        self.storage.write().await.set_cache_capacity(capacity);
+        info!("🗃️  State cache: {} accounts", capacity);
+    }
+
+    /// Set the mempool limits
+    pub async fn set_mempool_config(&self, config: MempoolConfig) {
+        self.mempool.write().await.set_config(config);
+    }
+
+    /// Set the block production limits
+    pub async fn set_producer_config(&self, config: ProducerConfig) {
+        *self.producer_config.write().await = config;
+    }
+
+    /// Make a session key available for block signing
+    pub async fn add_session_key(&self, key: ed25519_dalek::SigningKey) {
+        let public = AccountId::from_public_key(&key.verifying_key());
+        self.session_keys.write().await.insert(public, key);
+    }
+
+    /// Bind a new session key to the validator account, effective next epoch
+    ///
+    /// The key signs blocks once the SetSessionKeys transaction, signed here
+    /// with the account key, is included. Returns the keys and the
+    /// transaction hash.
+    pub async fn rotate_session_keys(
+        &self,
+        session_key: ed25519_dalek::SigningKey,
+        account_key: &ed25519_dalek::SigningKey,
+    ) -> Result<(SessionKeys, Hash), NodeError> {
+        use ed25519_dalek::Signer;
+
+        let keys = SessionKeys {
+            block: AccountId::from_public_key(&session_key.verifying_key()),
+        };
+        self.add_session_key(session_key).await;
+
+        let account = AccountId::from_public_key(&account_key.verifying_key());
+        let nonce = self.get_nonce(&account).await? + self.mempool.read().await.pending_count(&account) as u64;
+        let tx = Transaction::new(account, nonce, TransactionCall::SetSessionKeys { keys }, self.genesis_hash);
+        let message = SignedTransaction::signing_message(&tx)
+            .ok_or_else(|| NodeError::Consensus("Cannot encode SetSessionKeys".to_string()))?;
+        let signed = SignedTransaction::new(tx, account_key.sign(&message).to_bytes());
+
+        let hash = self.submit_transaction(signed).await?;
+        info!(
+            "🔑 Session key 0x{} submitted for binding: {}",
+            hex::encode(keys.block.as_bytes()),
+            hash
+        );
+        Ok((keys, hash))
+    }
+
+    /// Check an announced header against the best block before fetching its
+    /// body (Ok(false): not the next block on our chain, or not checkable)
+    ///
+    /// Same header checks as import: an Err means whoever announced it
+    /// announced an invalid block.
+    async fn check_announced_header(&self, header: &BlockHeader) -> Result<bool, ValidationError> {
+        let Some(parent) = self.current_block.read().await.as_ref().map(|block| block.header.clone()) else {
+            return Ok(false);
+        };
+        // Another block on another parent is a fork (or a race), not an invalid header
+        if header.number != parent.number + 1 || header.parent_hash != parent.hash() {
+            return Ok(false);
+        }
+        let signer = match self.storage.read().await.block_signer(&header.author, header.epoch) {
+            Ok(signer) => signer,
+            Err(e) => {
+                debug!("Cannot check announced block #{}: {}", header.number, e);
+                return Ok(false);
+            }
+        };
+        let validators = self.validators.read().await;
+        // Early blocks may come from bootstrap validators, added on import
+        if header.number <= 100 && !validators.is_active(&header.author) {
+            return Ok(true);
+        }
+        match BlockValidator::validate_header(header, &parent, &validators, &signer) {
+            Ok(()) => Ok(true),
+            // The chain moved past this release: the announcer isn't at fault
+            Err(e @ ValidationError::UnsupportedProtocolVersion { .. }) => {
+                error!("⛔ {}", e);
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Look for a double proposal in a header received from the network
+    ///
+    /// The header is compared with the headers seen for its slot and with the
+    /// imported block at its height, once its signature is checked against
+    /// the author's block signer: nobody else can get the author accused.
+    async fn check_double_proposal(&self, header: &BlockHeader) {
+        let (signer, imported) = {
+            let storage = self.storage.read().await;
+            let signer = match storage.block_signer(&header.author, header.epoch) {
+                Ok(signer) => signer,
+                Err(e) => {
+                    debug!("Failed to read block signer: {:?}", e);
+                    return;
+                }
+            };
+            (signer, storage.get_header_by_number(header.number).ok().flatten())
+        };
+        if !header_signed_by(header, &signer) {
+            return;
+        }
+
+        let header_hash = header.hash();
+        let conflicting = imported.filter(|imported| {
+            imported.author == header.author
+                && imported.epoch == header.epoch
+                && imported.slot == header.slot
+                && imported.hash() != header_hash
+        });
+        let observed = self.evidence.write().await.observe_header(header);
+        let evidence = conflicting
+            .map(|imported| EquivocationEvidence::DoubleProposal {
+                header_1: imported,
+                header_2: header.clone(),
+            })
+            .or(observed);
+
+        if let Some(evidence) = evidence {
+            if let Err(e) = self.add_evidence(evidence).await {
+                debug!("Double proposal evidence not kept: {}", e);
+            }
+        }
+    }
+
+    /// Verify equivocation evidence and keep it until it is reported
+    ///
+    /// Returns false if the offence is already known or punished. New
+    /// evidence is gossiped, so any validator can report it.
+    pub async fn add_evidence(&self, evidence: EquivocationEvidence) -> Result<bool, NodeError> {
+        let current_epoch = *self.chain_height.read().await / EPOCH_DURATION_BLOCKS;
+        let offence = evidence.offence().id();
+        {
+            let storage = self.storage.read().await;
+            let signer = storage
+                .block_signer(&evidence.offender(), evidence.epoch())
+                .map_err(|e| NodeError::Storage(e.to_string()))?;
+            evidence
+                .verify(&signer, current_epoch)
+                .map_err(|e| NodeError::Consensus(e.to_string()))?;
+            if storage
+                .get_offence_punished(&offence)
+                .map_err(|e| NodeError::Storage(e.to_string()))?
+                .is_some()
+            {
+                return Ok(false);
+            }
+        }
+        if !self.evidence.write().await.insert(evidence.clone()) {
+            return Ok(false);
+        }
+
+        warn!(
+            "🚨 Equivocation evidence against {}: {} at epoch {} (offence {})",
+            evidence.offender(),
+            evidence.kind(),
+            evidence.epoch(),
+            offence
+        );
+        if let Err(e) = self.network.write().await.broadcast_evidence(evidence.to_bytes()) {
+            debug!("Failed to broadcast evidence: {:?}", e);
+        }
+        Ok(true)
+    }
+
+    /// Evidence awaiting a report on chain, by offence id
+    pub async fn pending_evidence(&self) -> Vec<(Hash, PendingEvidence)> {
+        self.evidence
+            .read()
+            .await
+            .pending()
+            .map(|(id, pending)| (*id, pending.clone()))
+            .collect()
+    }
+
+    /// Submit a ReportEquivocation for each piece of evidence not reported yet
+    ///
+    /// Called before producing a block, so the reports go into it.
+    async fn report_pending_evidence(&self, account_key: &ed25519_dalek::SigningKey) {
+        use ed25519_dalek::Signer;
+
+        let unreported = self.evidence.write().await.take_unreported();
+        if unreported.is_empty() {
+            return;
+        }
+        let account = AccountId::from_public_key(&account_key.verifying_key());
+        let mut nonce = match self.get_nonce(&account).await {
+            Ok(nonce) => nonce + self.mempool.read().await.pending_count(&account) as u64,
+            Err(e) => {
+                warn!("Failed to read nonce for equivocation reports: {}", e);
+                return;
+            }
+        };
+
+        for evidence in unreported {
+            let offence = evidence.offence().id();
+            // Offenders do not report themselves
+            if evidence.offender() == account {
+                continue;
+            }
+            let tx = Transaction::new(
+                account,
+                nonce,
+                TransactionCall::ReportEquivocation { evidence: evidence.to_bytes() },
+                self.genesis_hash,
+            );
+            let Some(message) = SignedTransaction::signing_message(&tx) else {
+                continue;
+            };
+            let signed = SignedTransaction::new(tx, account_key.sign(&message).to_bytes());
+            match self.submit_transaction(signed).await {
+                Ok(hash) => {
+                    info!("⚔️  Reported {} by {}: {}", evidence.kind(), evidence.offender(), hash);
+                    nonce += 1;
+                }
+                Err(e) => {
+                    warn!("Failed to report equivocation {}: {}", offence, e);
+                    self.evidence.write().await.unmark_reported(&offence);
+                }
+            }
+        }
+    }
+
+    /// Keep a fraud proof for a block whose execution failed on import
+    ///
+    /// Only failures of execution itself (a transaction or a root) are
+    /// provable; add_fraud_proof replays the block to confirm it.
+    async fn check_invalid_block(&self, block: &Block, error: &NodeError) {
+        if !matches!(error, NodeError::RootMismatch { .. } | NodeError::TransactionFailed { .. }) {
+            return;
+        }
+        let proof = FraudProof::InvalidStateTransition { block: block.clone() };
+        if let Err(e) = self.add_fraud_proof(proof).await {
+            debug!("Fraud proof not kept: {}", e);
+        }
+    }
+
+    /// Verify a fraud proof against the stored chain, replaying the block,
+    /// and keep it until it is reported
+    ///
+    /// Returns false if the invalid block is already known or punished.
+    pub async fn add_fraud_proof(&self, proof: FraudProof) -> Result<bool, NodeError> {
+        let current_block = *self.chain_height.read().await;
+        let offence = fraud_offence(&proof).map_err(|e| NodeError::Consensus(e.to_string()))?.id();
+        let header = &disputed_block(&proof).map_err(|e| NodeError::Consensus(e.to_string()))?.header;
+        {
+            let storage = self.storage.read().await;
+            if storage
+                .get_offence_punished(&offence)
+                .map_err(|e| NodeError::Storage(e.to_string()))?
+                .is_some()
+            {
+                return Ok(false);
+            }
+            check_fraud_proof(&storage, &proof, current_block).map_err(|e| NodeError::Consensus(e.to_string()))?;
+        }
+        let (author, number) = (header.author, header.number);
+        if !self.fraud_proofs.write().await.insert(proof) {
+            return Ok(false);
+        }
+
+        warn!("🚨 Fraud proof against {}: invalid state transition in block #{} ({})", author, number, offence);
+        Ok(true)
+    }
+
+    /// Submit a SubmitFraudProof for each fraud proof not reported yet
+    ///
+    /// Called before producing a block, so the reports go into it.
+    async fn report_pending_fraud_proofs(&self, account_key: &ed25519_dalek::SigningKey) {
+        use ed25519_dalek::Signer;
+
+        let unreported = self.fraud_proofs.write().await.take_unreported();
+        if unreported.is_empty() {
+            return;
+        }
+        let account = AccountId::from_public_key(&account_key.verifying_key());
+        let mut nonce = match self.get_nonce(&account).await {
+            Ok(nonce) => nonce + self.mempool.read().await.pending_count(&account) as u64,
+            Err(e) => {
+                warn!("Failed to read nonce for fraud proofs: {}", e);
+                return;
+            }
+        };
+
+        for proof in unreported {
+            let Ok(offence) = fraud_offence(&proof).map(|offence| offence.id()) else {
+                continue;
+            };
+            // Offenders do not report themselves
+            if proof.accused_validator() == account {
+                continue;
+            }
+            let tx = Transaction::new(account, nonce, TransactionCall::SubmitFraudProof { proof: proof.to_bytes() }, self.genesis_hash);
+            let Some(message) = SignedTransaction::signing_message(&tx) else {
+                continue;
+            };
+            let signed = SignedTransaction::new(tx, account_key.sign(&message).to_bytes());
+            match self.submit_transaction(signed).await {
+                Ok(hash) => {
+                    info!(
+                        "⚔️  Reported invalid block #{} by {}: {}",
+                        proof.fraud_block_number(),
+                        proof.accused_validator(),
+                        hash
+                    );
+                    nonce += 1;
+                }
+                Err(e) => {
+                    warn!("Failed to submit fraud proof {}: {}", offence, e);
+                    self.fraud_proofs.write().await.unmark_reported(&offence);
+                }
+            }
+        }
+    }
+
+    /// Apply the slashes of a block to the validator set and drop the
+    /// offences it punished from the evidence and fraud proof pools
+    async fn settle_evidence(&self, block: &Block) {
+        let mut slashes = Vec::new();
+        {
+            let storage = self.storage.read().await;
+            for tx in &block.body.transactions {
+                let offence = match &tx.transaction.call {
+                    TransactionCall::ReportEquivocation { evidence } => {
+                        EquivocationEvidence::from_bytes(evidence).ok().map(|evidence| evidence.offence())
+                    }
+                    TransactionCall::SubmitFraudProof { proof } => {
+                        FraudProof::from_bytes(proof).ok().and_then(|proof| fraud_offence(&proof).ok())
+                    }
+                    _ => continue,
+                };
+                if let Ok(Some(stored)) = storage.get_receipt(&tx.hash()) {
+                    for event in &stored.receipt.events {
+                        if let Event::Staking(StakingEvent::Slashed { who, offence, amount, .. }) = event {
+                            slashes.push((*who, *offence, *amount));
+                        }
+                    }
+                }
+                // A report that lost the race to another still settles the
+                // offence, in both pools: an invalid block and a double
+                // proposal for its slot are the same offence
+                if let Some(offence) = offence.map(|offence| offence.id()) {
+                    if matches!(storage.get_offence_punished(&offence), Ok(Some(_))) {
+                        self.evidence.write().await.remove(&offence);
+                        self.fraud_proofs.write().await.remove(&offence);
+                    }
+                }
+            }
+        }
+
+        if !slashes.is_empty() {
+            let mut validators = self.validators.write().await;
+            let mut evidence = self.evidence.write().await;
+            let mut fraud_proofs = self.fraud_proofs.write().await;
+            for (who, offence, amount) in slashes {
+                evidence.remove(&offence);
+                fraud_proofs.remove(&offence);
+                if let Some(validator) = validators.get_validator_mut(&who) {
+                    validator.slash(amount);
+                }
+            }
+        }
+        self.evidence.write().await.prune(block.header.number / EPOCH_DURATION_BLOCKS);
+        self.fraud_proofs.write().await.prune(block.header.number);
+    }
+
+    /// Set the peer scoring weights
+    pub async fn set_peer_scoring(&self, scoring: PeerScoring) {
+        self.network.write().await.set_peer_scoring(scoring);
+    }
+
+    /// Limit the connected peers (existing connections are kept)
+    pub async fn set_max_peers(&self, max_peers: usize) {
+        self.network.write().await.set_max_peers(max_peers);
+    }
+
+    /// Write the forensic bundle of a block whose state root did not match
+    fn dump_state_root_forensics(&self, storage: &StateBackend, block: &Block, computed_root: Hash, receipts: &[Receipt]) {
+        let changes = storage.take_account_journal().unwrap_or_else(|e| {
+            warn!("Account journal unavailable for block #{}: {:?}", block.header.number, e);
+            Vec::new()
+        });
+        let parent_root = block.header.number.checked_sub(1)
+            .and_then(|parent| storage.get_state_root(parent).ok().flatten())
+            .map(|root| root.root);
+
+        let bundle = StateRootForensics::new(block, computed_root, parent_root, changes, receipts);
+        match bundle.write(&self.data_path) {
+            Ok(path) => error!(
+                "State root forensics for block #{} ({} accounts changed) written to {}",
+                block.header.number, bundle.accounts.len(), path.display()
+            ),
+            Err(e) => warn!("Failed to write state root forensics for block #{}: {}", block.header.number, e),
+        }
+    }
+
+    /// Newest state root forensic bundle (of `block_number` if given)
+    pub fn state_root_forensics(&self, block_number: Option<BlockNumber>) -> Result<Option<StateRootForensics>, String> {
+        forensics::load_bundle(&self.data_path, block_number).map_err(|e| e.to_string())
+    }
+
+    /// State cache hit/miss counters
+    pub async fn state_cache_stats(&self) -> StateCacheStats {
+        self.storage.read().await.cache_stats()
+    }
+
     // =========================================================================
     // FINALITY METHODS (GRANDPA-style)
     // =========================================================================
@@ -1784,17 +3935,181 @@ impl KratOsNode {
     ///
     /// Returns None if the block is not finalized or justification not available
     pub async fn get_finality_justification(&self, block_number: BlockNumber) -> Option<FinalityJustification> {
-        // In the current implementation, justifications are stored when blocks are finalized
-        // For now, return None as we don't have full GRANDPA justification storage yet
-        // This will be implemented when FinalityGadget is fully integrated
+        let storage = self.storage.read().await;
+        storage.get_justification(block_number).ok().flatten()
+    }
 
-        let finality_info = self.finality_info().await;
-        if block_number > finality_info.last_finalized_block {
-            return None;
+    /// Prove the finality of a block
+    ///
+    /// Returns the justification of the block itself or, if it was finalized
+    /// as an ancestor, of the first justified block above it.
+    /// Returns None if no stored justification covers the block.
+    pub async fn prove_finality(&self, block_number: BlockNumber) -> Result<Option<FinalityJustification>, NodeError> {
+        let storage = self.storage.read().await;
+        storage
+            .justification_covering(block_number)
+            .map_err(|e| NodeError::Storage(e.to_string()))
+    }
+
+    /// Verify and store a justification received from the network
+    ///
+    /// The signatures are checked against the current validator set and the
+    /// justified block must be on our chain. Returns false if the justification
+    /// is for a block we don't have yet or is already known.
+    pub async fn import_justification(&self, justification: FinalityJustification) -> Result<bool, NodeError> {
+        let block_number = justification.block_number;
+        let storage = self.storage.read().await;
+
+        if storage.get_justification(block_number).map_err(|e| NodeError::Storage(e.to_string()))?.is_some() {
+            return Ok(false);
+        }
+        let block_hash = storage
+            .get_block_hash(block_number)
+            .map_err(|e| NodeError::Storage(e.to_string()))?;
+        match block_hash {
+            None => return Ok(false),
+            Some(hash) if hash != justification.block_hash => {
+                return Err(NodeError::Consensus(format!(
+                    "Justification for block #{} is for a different block",
+                    block_number
+                )));
+            }
+            Some(_) => {}
+        }
+
+        let validators: HashSet<AccountId> = {
+            let validators_guard = self.validators.read().await;
+            validators_guard.active_validators().iter().map(|v| v.id).collect()
+        };
+        if !justification.verify_with_validators(&validators) {
+            return Err(NodeError::Consensus(format!(
+                "Justification for block #{} lacks a 2/3 supermajority of valid signatures",
+                block_number
+            )));
+        }
+
+        storage
+            .store_justification(&justification)
+            .map_err(|e| NodeError::Storage(e.to_string()))?;
+        Ok(true)
+    }
+
+    /// Switch to a downloaded fork if its justification finalizes a later
+    /// block than ours
+    ///
+    /// Blocks are never reverted past our finalized block: the fork must
+    /// branch off after it. Transactions of the reverted blocks go back to
+    /// the mempool (those the fork includes are dropped there).
+    async fn consider_fork(&self, fork: ForkDownloaded) {
+        let branch = fork.branch;
+        let justified = fork.justification.block_number;
+        if let Err(e) = self.adopt_fork(fork).await {
+            warn!("Fork {} (justified #{}) not adopted: {}", branch, justified, e);
+        }
+        self.network.write().await.forget_fork(&branch);
+    }
+
+    async fn adopt_fork(&self, fork: ForkDownloaded) -> Result<(), NodeError> {
+        let justification = fork.justification;
+        let finalized = self
+            .storage
+            .read()
+            .await
+            .latest_justified_block()
+            .map_err(|e| NodeError::Storage(e.to_string()))?
+            .unwrap_or(0);
+        if justification.block_number <= finalized {
+            return Err(NodeError::Consensus(format!(
+                "Fork finalizes block #{}, we finalized #{}",
+                justification.block_number, finalized
+            )));
+        }
+        if fork.ancestor < finalized {
+            return Err(NodeError::Consensus(format!(
+                "Fork branches off at #{}, below our finalized block #{}",
+                fork.ancestor, finalized
+            )));
+        }
+
+        let validators: HashSet<AccountId> = {
+            let validators_guard = self.validators.read().await;
+            validators_guard.active_validators().iter().map(|v| v.id).collect()
+        };
+        if !justification.verify_with_validators(&validators) {
+            return Err(NodeError::Consensus(format!(
+                "Justification for block #{} lacks a 2/3 supermajority of valid signatures",
+                justification.block_number
+            )));
+        }
+
+        let (ancestor, reverted) = {
+            let mut storage = self.storage.write().await;
+            let reverted = storage.revert_to(fork.ancestor).map_err(|e| NodeError::Storage(e.to_string()))?;
+            let ancestor = storage
+                .get_block_by_number(fork.ancestor)
+                .map_err(|e| NodeError::Storage(e.to_string()))?
+                .ok_or_else(|| NodeError::Storage(format!("Block #{} not found", fork.ancestor)))?;
+            (ancestor, reverted)
+        };
+        let ancestor_hash = ancestor.hash();
+        *self.current_block.write().await = Some(ancestor);
+        *self.chain_height.write().await = fork.ancestor;
+        self.network.write().await.rewind_local_state(fork.ancestor, ancestor_hash);
+        warn!(
+            "🍴 Reorg: reverted {} block(s) to #{} ({}) for fork {} justified at #{}",
+            reverted.len(), fork.ancestor, ancestor_hash, fork.branch, justification.block_number
+        );
+
+        for block in fork.blocks {
+            let number = block.header.number;
+            if let Err(e) = self.import_block(block).await {
+                // Our blocks are gone: block sync picks up from here
+                warn!("Failed to import fork block #{}: {}", number, e);
+                break;
+            }
+        }
+        if let Err(e) = self.import_justification(justification).await {
+            warn!("Failed to import fork justification: {}", e);
+        }
+
+        let depth = reverted.len() as u64;
+        let transactions: Vec<SignedTransaction> =
+            reverted.into_iter().flat_map(|block| block.body.transactions).collect();
+        let hashes: Vec<Hash> = transactions.iter().map(|tx| tx.hash()).collect();
+        // Same lock order as block production
+        let mut mempool = self.mempool.write().await;
+        let mut storage = self.storage.write().await;
+        if !transactions.is_empty() {
+            let restored = mempool.restore(transactions, Some(&mut *storage));
+            info!("Returned {} transaction(s) of reverted blocks to the mempool", restored);
+        }
+
+        // Reported by chain_getReorgEvents, so wallets stop showing them confirmed
+        if depth > 0 {
+            let mut orphaned = Vec::new();
+            let mut dropped = Vec::new();
+            for hash in hashes {
+                let included = storage.get_receipt(&hash).map_err(|e| NodeError::Storage(e.to_string()))?.is_some();
+                if included || mempool.contains(&hash) {
+                    orphaned.push(hash);
+                } else {
+                    dropped.push(hash);
+                }
+            }
+            storage
+                .record_reorg(fork.ancestor, depth, orphaned, dropped)
+                .map_err(|e| NodeError::Storage(e.to_string()))?;
         }
+        Ok(())
+    }
 
-        // Placeholder: In full implementation, retrieve from storage
-        None
+    /// Reorgs recorded after the event `after_id`, oldest first
+    pub async fn reorg_events(&self, after_id: u64) -> Result<Vec<ReorgEvent>, NodeError> {
+        self.storage
+            .read()
+            .await
+            .reorg_events_after(after_id)
+            .map_err(|e| NodeError::Storage(e.to_string()))
     }
 
     /// Get current finality round information
@@ -1882,6 +4197,11 @@ impl KratOsNode {
         // Get the libp2p PeerId from the network service
         let libp2p_peer_id = {
             let network = self.network.read().await;
+            if network.reserved_only() {
+                // Heartbeats would advertise the node it hides behind sentries
+                info!("🔒 Reserved-only mode: no DNS Seed heartbeats");
+                return;
+            }
             network.local_peer_id().to_string()
         };
 
@@ -1890,6 +4210,13 @@ impl KratOsNode {
         info!("📡 DNS Seed client initialized (PeerId: {}) - heartbeats will be sent every 2 minutes", libp2p_peer_id);
     }
 
+    /// Set the services advertised to DNS Seeds (no-op before `initialize_dns_client`)
+    pub async fn set_dns_capabilities(&self, capabilities: Capabilities) {
+        if let Some(client) = self.dns_client.write().await.as_mut() {
+            client.set_capabilities(capabilities);
+        }
+    }
+
     /// Send heartbeats to DNS Seeds (called from perform_maintenance)
     ///
     /// This should be called periodically (every 2 minutes) to keep the node
@@ -1936,10 +4263,11 @@ impl KratOsNode {
             Some(staking.total_stake())
         };
 
-        // Get local addresses from network
+        // Get local addresses from network (a sentry's private interfaces,
+        // the links to its validators, left out)
         let addresses = {
             let network = self.network.read().await;
-            network.local_listen_addresses()
+            sentry::advertised_addresses(network.local_listen_addresses(), network.is_sentry())
                 .into_iter()
                 .map(|a| a.to_string())
                 .collect()
@@ -1968,6 +4296,93 @@ impl KratOsNode {
                 }
             }
         }
+
+        if is_validator {
+            self.submit_seed_attestation(client, chain_height).await;
+        }
+
+        self.listen_via_relays_if_unreachable(client).await;
+    }
+
+    /// Listen through relays listed by the DNS Seeds while no peer has
+    /// dialed the node (likely behind NAT): its circuit addresses go out with
+    /// the next heartbeat, so other nodes reach it through the relays
+    async fn listen_via_relays_if_unreachable(&self, client: &DnsSeedClient) {
+        if !self.network.read().await.needs_relay() {
+            return;
+        }
+        let relays = client.get_peers_with_capabilities(Capabilities::RELAY).await;
+        let added = self.network.write().await.listen_via_relays(relays);
+        if added > 0 {
+            info!("🔁 No inbound connection yet: listening through {} relay(s)", added);
+        }
+    }
+
+    /// Submit the seed receipts of the last completed epoch as uptime proof
+    ///
+    /// Receipts are taken once per epoch. The attestation is checked locally
+    /// first and only sent if it would earn a credit, so no fee is wasted.
+    async fn submit_seed_attestation(&self, client: &mut DnsSeedClient, chain_height: BlockNumber) {
+        let current_epoch = chain_height / EPOCH_DURATION_BLOCKS;
+        if current_epoch == 0 {
+            return;
+        }
+        let epoch = current_epoch - 1;
+        let receipts = client.take_receipts(epoch);
+        if receipts.is_empty() {
+            return;
+        }
+
+        let validator = AccountId::from_bytes(*client.peer_id());
+        let record = match self.storage.read().await.get_uptime_record(&validator) {
+            Ok(record) => record.unwrap_or_default(),
+            Err(e) => {
+                warn!("Failed to read uptime record: {:?}", e);
+                return;
+            }
+        };
+        let attested = match verify_attestation(
+            &validator, epoch, current_epoch, &receipts, &self.genesis_hash, &trusted_seeds(), &record,
+        ) {
+            Ok(attested) if attested.coverage >= MIN_UPTIME_PARTICIPATION => attested,
+            Ok(attested) => {
+                debug!(
+                    "Seed attestation for epoch {} not submitted: {}/{} heartbeats",
+                    epoch, attested.heartbeats, HEARTBEATS_PER_EPOCH
+                );
+                return;
+            }
+            Err(e) => {
+                debug!("Seed attestation for epoch {} not submitted: {}", epoch, e);
+                return;
+            }
+        };
+
+        let nonce = match self.get_nonce(&validator).await {
+            Ok(nonce) => nonce + self.mempool.read().await.pending_count(&validator) as u64,
+            Err(e) => {
+                warn!("Failed to read nonce for seed attestation: {}", e);
+                return;
+            }
+        };
+        let tx = Transaction::new(
+            validator,
+            nonce,
+            TransactionCall::SubmitSeedAttestation { epoch, receipts },
+            self.genesis_hash,
+        );
+        let Some(message) = SignedTransaction::signing_message(&tx) else {
+            return;
+        };
+        let signed = SignedTransaction::new(tx, client.sign(&message).0);
+
+        match self.submit_transaction(signed).await {
+            Ok(hash) => info!(
+                "💓 Submitted seed attestation for epoch {} ({} heartbeats): {}",
+                epoch, attested.heartbeats, hash
+            ),
+            Err(e) => warn!("Failed to submit seed attestation for epoch {}: {}", epoch, e),
+        }
     }
 
     /// Notify finality gadget of a newly imported block
@@ -1986,7 +4401,16 @@ impl KratOsNode {
     pub async fn process_finality_vote(&self, vote: FinalityVote) -> Option<Vec<AccountId>> {
         let finality_guard = self.finality.read().await;
         if let Some(ref finality) = *finality_guard {
-            finality.on_finality_vote(vote).await
+            let voters = finality.on_finality_vote(vote).await;
+            if voters.is_some() {
+                self.persist_justification(finality).await;
+            }
+            for proof in finality.drain_equivocations().await {
+                if let Err(e) = self.add_evidence(EquivocationEvidence::DoubleVote(proof)).await {
+                    debug!("Double vote evidence not kept: {}", e);
+                }
+            }
+            voters
         } else {
             None
         }
@@ -1996,12 +4420,27 @@ impl KratOsNode {
     pub async fn process_finality_message(&self, message: FinalityMessage) -> Option<Vec<AccountId>> {
         let finality_guard = self.finality.read().await;
         if let Some(ref finality) = *finality_guard {
-            finality.on_finality_message(message).await
+            let voters = finality.on_finality_message(message).await;
+            if voters.is_some() {
+                self.persist_justification(finality).await;
+            }
+            voters
         } else {
             None
         }
     }
 
+    /// Store the justification of the block the gadget just finalized
+    async fn persist_justification(&self, finality: &FinalityIntegration<NodeFinalitySigner, NodeFinalityBroadcaster>) {
+        let block_number = finality.get_last_finalized().await;
+        let Some(justification) = finality.justification(block_number).await else {
+            return;
+        };
+        if let Err(e) = self.storage.read().await.store_justification(&justification) {
+            warn!("Failed to store justification for block #{}: {:?}", block_number, e);
+        }
+    }
+
     /// Tick the finality gadget (call periodically for timeout handling)
     ///
     /// Returns true if a round timed out and was advanced
@@ -2009,10 +4448,17 @@ impl KratOsNode {
         trace!("[GRANDPA] node: tick_finality called");
         let finality_guard = self.finality.read().await;
         if let Some(ref finality) = *finality_guard {
+            let before = finality.get_last_finalized().await;
             let result = finality.tick().await;
             if result {
                 trace!("[GRANDPA] node: tick_finality - round timed out");
             }
+            for (round, finalized) in finality.drain_completed_rounds().await {
+                self.metrics.observe_round(finalized, std::time::Duration::from_millis(round.duration_ms));
+            }
+            if finality.get_last_finalized().await > before {
+                self.persist_justification(finality).await;
+            }
             result
         } else {
             trace!("[GRANDPA] node: tick_finality - no finality gadget initialized");
@@ -2020,6 +4466,15 @@ impl KratOsNode {
         }
     }
 
+    /// Finality liveness, None if the gadget is not initialized
+    pub async fn finality_stall(&self) -> Option<StallStatus> {
+        let finality_guard = self.finality.read().await;
+        match *finality_guard {
+            Some(ref finality) => Some(finality.stall_status().await),
+            None => None,
+        }
+    }
+
     /// Get the voters from the last finalization
     ///
     /// Used for distributing finality rewards (10% of fees)
@@ -2200,6 +4655,184 @@ pub struct FinalityInfo {
     pub total_validators: usize,
 }
 
+/// Validator operational report (see `KratOsNode::validator_report`)
+#[derive(Debug, Clone)]
+pub struct ValidatorReport {
+    /// Validator registry entry
+    pub info: crate::consensus::validator::ValidatorInfo,
+
+    /// Active in the validator set at the current block
+    pub is_active: bool,
+
+    /// Eligible for block production at the current block
+    pub can_participate: bool,
+
+    /// Validator credits record (None if never initialized)
+    pub credits: Option<crate::consensus::validator_credits::ValidatorCreditsRecord>,
+
+    /// Per-epoch VC changes
+    pub vc_history: crate::consensus::validator_credits::VcHistory,
+
+    /// Clock synchronization failures
+    pub clock_sync_failures: u32,
+
+    /// VC penalty from clock failures
+    pub clock_vc_penalty: u64,
+
+    /// Balance currently unbonding
+    pub unbonding_balance: Balance,
+
+    /// Commission in force and announced
+    pub commission: CommissionRecord,
+
+    /// Voted in the last finalized round
+    pub finality_voter: bool,
+
+    /// Block height the report was taken at
+    pub current_block: BlockNumber,
+}
+
+/// Epoch and network state report (see `KratOsNode::epoch_report`)
+#[derive(Debug, Clone)]
+pub struct EpochReport {
+    /// Block height the report was taken at
+    pub current_block: BlockNumber,
+
+    /// Current epoch
+    pub epoch: EpochNumber,
+
+    /// Active validators with their stake
+    pub validators: Vec<(AccountId, Balance)>,
+
+    /// Bootstrap era by epoch (bootstrap multipliers apply)
+    pub is_bootstrap: bool,
+
+    /// Epoch the bootstrap era ends at
+    pub bootstrap_end_epoch: EpochNumber,
+
+    /// Bootstrap exit conditions against the active set
+    pub bootstrap_status: BootstrapStatus,
+
+    /// Network security state followed by the invariant monitor
+    pub security_state: NetworkSecurityState,
+
+    /// Block time multiplier of the security state (0: no new blocks)
+    pub block_time_multiplier: u32,
+
+    /// Governance timelock multiplier of the security state (0: governance frozen)
+    pub timelock_multiplier: u32,
+}
+
+/// Governance proposals and the timelock in force (see `KratOsNode::governance_report`)
+#[derive(Debug, Clone)]
+pub struct GovernanceReport {
+    /// Block height the report was taken at
+    pub current_block: BlockNumber,
+
+    /// Governance timelock multiplier of the security state (0: governance frozen)
+    pub timelock_multiplier: u32,
+
+    /// Proposals, oldest first
+    pub proposals: Vec<Proposal>,
+}
+
+/// Competing chains (see `KratOsNode::forks_report`)
+#[derive(Debug, Clone)]
+pub struct ForksReport {
+    /// Local finalized block: forks branching off below it are never
+    /// switched to
+    pub finalized: Option<BlockNumber>,
+
+    pub forks: Vec<ForkHead>,
+
+    /// Branch of the fork being downloaded
+    pub downloading: Option<Hash>,
+}
+
+/// Treasury account and its spending proposals (see `KratOsNode::treasury_report`)
+#[derive(Debug, Clone)]
+pub struct TreasuryReport {
+    pub account: AccountId,
+
+    /// Free balance of the treasury account
+    pub balance: Balance,
+
+    /// TreasurySpend proposals only
+    pub governance: GovernanceReport,
+}
+
+/// Verified-person status of an account (see `KratOsNode::personhood_report`)
+#[derive(Debug, Clone)]
+pub struct PersonhoodReport {
+    pub account: AccountId,
+
+    /// Block the next transaction lands in
+    pub next_block: BlockNumber,
+
+    /// None if the account was never verified
+    pub record: Option<PersonhoodRecord>,
+
+    /// Verification round of `next_block`
+    pub round: CeremonyRound,
+}
+
+/// What re-executing an imported block gave (see `KratOsNode::import_block_execution`)
+#[derive(Debug, Clone)]
+pub struct BlockExecution {
+    pub number: BlockNumber,
+    pub state_root: Hash,
+    pub receipts_root: Hash,
+
+    /// Fees paid by the block's transactions
+    pub total_fees: Balance,
+
+    /// Fee split in force for the block
+    pub fee_shares: FeeShares,
+
+    /// Block reward and fee share owed to the author
+    pub producer_reward: Balance,
+
+    /// Free balance credited to the author and the treasury by the rewards
+    pub producer_credit: Balance,
+    pub treasury_credit: Balance,
+}
+
+/// Emergency status of the network (see `KratOsNode::emergency_report`)
+#[derive(Debug, Clone)]
+pub struct EmergencyReport {
+    /// Block the next transaction lands in
+    pub next_block: BlockNumber,
+
+    pub security_state: NetworkSecurityState,
+
+    /// A declaration may be signed (Restricted with armed breakers, or Terminal)
+    pub armed: bool,
+
+    /// Declared on chain, or the network in Terminal state
+    pub in_emergency: bool,
+
+    /// Validators that may sign
+    pub validators: u32,
+
+    pub declaration: EmergencyDeclaration,
+
+    /// SPEC v7 circuit breakers, by id
+    pub breakers: Vec<CircuitBreaker>,
+}
+
+/// Governed protocol parameters and their history (see `KratOsNode::parameters_report`)
+#[derive(Debug, Clone)]
+pub struct ParametersReport {
+    /// Parameters in force
+    pub parameters: ProtocolParameters,
+
+    /// ProtocolChange proposals only
+    pub governance: GovernanceReport,
+
+    /// Audit log, oldest first
+    pub log: Vec<ParameterChangeRecord>,
+}
+
 /// Apply genesis state received from network to local storage
 ///
 /// This initializes the state with the EXACT balances and validators
@@ -2311,9 +4944,21 @@ pub enum NodeError {
     #[error("Transaction error: {0}")]
     Transaction(String),
 
+    /// Refused by the mempool (quota, fee, nonce...)
+    #[error("Mempool error: {0}")]
+    Pool(#[from] PoolError),
+
     #[error("Consensus error: {0}")]
     Consensus(String),
 
+    /// Re-execution gave another root than the block header (State or Receipts)
+    #[error("{root} root mismatch: expected {expected}, computed {computed}")]
+    RootMismatch { root: &'static str, expected: Hash, computed: Hash },
+
+    /// A transaction of the block failed on re-execution
+    #[error("Transaction {index} execution failed: {error}")]
+    TransactionFailed { index: usize, error: String },
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 }
@@ -2391,14 +5036,121 @@ mod tests {
                     amount: 1000,
                 },
                 timestamp: 0,
+                genesis_hash: node.genesis_hash(),
+                valid_until: None,
+                max_fee: None,
+                tip: 0,
             },
             signature: Signature64([0; 64]),
             hash: Some(Hash::hash(&[0])),
         };
 
+        // Signed for another network: refused
+        let mut foreign = tx.clone();
+        foreign.transaction.genesis_hash = Hash::ZERO;
+        assert!(matches!(
+            node.submit_transaction(foreign).await,
+            Err(NodeError::Pool(PoolError::WrongChain { .. }))
+        ));
+
         let result = node.submit_transaction(tx).await;
         assert!(result.is_ok());
 
         assert_eq!(node.mempool_size().await, 1);
     }
+
+    #[tokio::test]
+    async fn test_mempool_saved_and_restored() {
+        let dir = tempdir().unwrap();
+        let config = get_test_config();
+        let sender = AccountId::from_bytes([1u8; 32]);
+        let mut genesis = GenesisSpec::with_validator(sender);
+        genesis.balances.insert(sender, 1_000_000);
+
+        let node = KratOsNode::new(config, dir.path(), genesis, true)
+            .await
+            .unwrap();
+        node.mempool.write().await.config.verify_signatures = false;
+
+        let tx = SignedTransaction {
+            transaction: Transaction {
+                sender,
+                nonce: 0,
+                call: TransactionCall::Transfer {
+                    to: AccountId::from_bytes([2; 32]),
+                    amount: 1000,
+                },
+                timestamp: 0,
+                genesis_hash: node.genesis_hash(),
+                valid_until: None,
+                max_fee: None,
+                tip: 0,
+            },
+            signature: Signature64([0; 64]),
+            hash: None,
+        };
+        let hash = node.submit_transaction(tx).await.unwrap();
+
+        // Saved once, then only when the pool changes
+        assert_eq!(node.save_mempool().await.unwrap(), Some(1));
+        assert_eq!(node.save_mempool().await.unwrap(), None);
+
+        // As after a restart: the saved transaction comes back, revalidated
+        node.mempool.write().await.remove(&hash);
+        assert_eq!(node.restore_mempool().await.unwrap(), 1);
+        assert!(node.mempool.read().await.contains(&hash));
+    }
+
+    #[tokio::test]
+    async fn test_submit_transaction_idempotent() {
+        let dir = tempdir().unwrap();
+        let config = get_test_config();
+        let genesis = GenesisSpec::with_validator(AccountId::from_bytes([1u8; 32]));
+
+        let node = KratOsNode::new(config, dir.path(), genesis, true)
+            .await
+            .unwrap();
+
+        {
+            let mut mempool = node.mempool.write().await;
+            mempool.config.verify_signatures = false;
+        }
+
+        let transfer = |nonce: u64| SignedTransaction {
+            transaction: Transaction {
+                sender: AccountId::from_bytes([1; 32]),
+                nonce,
+                call: TransactionCall::Transfer {
+                    to: AccountId::from_bytes([2; 32]),
+                    amount: 1000,
+                },
+                timestamp: 0,
+                genesis_hash: node.genesis_hash(),
+                valid_until: None,
+                max_fee: None,
+                tip: 0,
+            },
+            signature: Signature64([0; 64]),
+            hash: None,
+        };
+
+        let first = node.submit_transaction_idempotent(transfer(0), "withdrawal-1").await.unwrap();
+        assert!(!first.duplicate);
+
+        // Retry re-signed with the next nonce: not submitted, original returned
+        let retry = node.submit_transaction_idempotent(transfer(1), "withdrawal-1").await.unwrap();
+        assert_eq!(retry, IdempotentSubmission { hash: first.hash, duplicate: true });
+        assert_eq!(node.mempool_size().await, 1);
+
+        assert!(node.submit_transaction_idempotent(transfer(1), "").await.is_err());
+
+        // Keys past the window are forgotten
+        node.storage
+            .read()
+            .await
+            .put_idempotency_record("withdrawal-0", &IdempotencyRecord { tx_hash: Hash::ZERO, submitted_at: 0 })
+            .unwrap();
+        assert_eq!(node.expire_idempotency_keys().await.unwrap(), 1);
+        assert!(node.storage.read().await.get_idempotency_record("withdrawal-1").unwrap().is_some());
+    }
 }
@@ -0,0 +1,130 @@
+// Auth - Bearer tokens for the unsafe RPC methods
+// `--rpc-methods-unsafe` opens every unsafe method to whoever reaches the port.
+// Once [[rpc-token]] sections are configured, an unsafe method is served only
+// to a request carrying `Authorization: Bearer <token>` of a token whose
+// allowlist names the method, with or without the flag.
+// - The config file holds a hash of each token, not the token itself
+// - Tokens are replaced on config reload: remove a section and send SIGHUP to
+//   revoke it
+// Tokens travel in clear over HTTP: keep the port on localhost or behind TLS.
+
+use rand::RngCore;
+use std::sync::{Arc, RwLock};
+use thiserror::Error;
+
+/// A token allowed to call some unsafe methods
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcToken {
+    /// Name, for the logs
+    pub name: String,
+    /// BLAKE3 hash of the secret
+    pub hash: blake3::Hash,
+    /// Methods allowed: names, `prefix_*` patterns or `*`
+    pub methods: Vec<String>,
+}
+
+impl RpcToken {
+    /// New random secret (hex) and the hash to configure for it
+    pub fn generate() -> (String, blake3::Hash) {
+        let mut secret = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut secret);
+        let secret = hex::encode(secret);
+        let hash = Self::hash_secret(&secret);
+        (secret, hash)
+    }
+
+    /// Configured form of a secret
+    pub fn hash_secret(secret: &str) -> blake3::Hash {
+        blake3::hash(secret.as_bytes())
+    }
+
+    /// Whether the allowlist names `method`
+    pub fn allows(&self, method: &str) -> bool {
+        self.methods.iter().any(|allowed| match allowed.strip_suffix('*') {
+            Some(prefix) => method.starts_with(prefix),
+            None => allowed == method,
+        })
+    }
+}
+
+/// Configured tokens, shared by the server and the config reloader
+#[derive(Debug, Clone, Default)]
+pub struct RpcAuth(Arc<RwLock<Vec<RpcToken>>>);
+
+impl RpcAuth {
+    /// No tokens: authentication disabled
+    pub fn new(tokens: Vec<RpcToken>) -> Self {
+        Self(Arc::new(RwLock::new(tokens)))
+    }
+
+    /// Replace the tokens, for the next requests
+    pub fn set(&self, tokens: Vec<RpcToken>) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = tokens;
+    }
+
+    /// Whether unsafe methods require a token
+    pub fn is_enabled(&self) -> bool {
+        !self.0.read().unwrap_or_else(|e| e.into_inner()).is_empty()
+    }
+
+    /// Name of the token of an `Authorization` header allowed to call `method`
+    pub fn authorize(&self, authorization: Option<&str>, method: &str) -> Result<String, AuthError> {
+        let secret = authorization
+            .and_then(|header| header.strip_prefix("Bearer "))
+            .map(str::trim)
+            .ok_or(AuthError::MissingToken)?;
+        let hash = RpcToken::hash_secret(secret);
+
+        let tokens = self.0.read().unwrap_or_else(|e| e.into_inner());
+        // blake3::Hash compares in constant time
+        let token = tokens.iter().find(|token| token.hash == hash).ok_or(AuthError::InvalidToken)?;
+        if !token.allows(method) {
+            return Err(AuthError::MethodNotAllowed(token.name.clone(), method.to_string()));
+        }
+        Ok(token.name.clone())
+    }
+}
+
+/// Error type for RPC authentication
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error("bearer token required")]
+    MissingToken,
+
+    #[error("unknown token")]
+    InvalidToken,
+
+    #[error("token '{0}' may not call {1}")]
+    MethodNotAllowed(String, String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorize() {
+        let (secret, hash) = RpcToken::generate();
+        let auth = RpcAuth::default();
+        assert!(!auth.is_enabled());
+
+        auth.set(vec![RpcToken {
+            name: "ops".to_string(),
+            hash,
+            methods: vec!["admin_*".to_string()],
+        }]);
+        let bearer = format!("Bearer {}", secret);
+        assert_eq!(auth.authorize(Some(&bearer), "admin_reloadConfig").unwrap(), "ops");
+        assert!(matches!(
+            auth.authorize(Some(&bearer), "debug_getStateRootForensics"),
+            Err(AuthError::MethodNotAllowed(..))
+        ));
+        assert!(matches!(auth.authorize(Some("Bearer guess"), "admin_reloadConfig"), Err(AuthError::InvalidToken)));
+        assert!(matches!(auth.authorize(Some(&secret), "admin_reloadConfig"), Err(AuthError::MissingToken)));
+        assert!(matches!(auth.authorize(None, "admin_reloadConfig"), Err(AuthError::MissingToken)));
+
+        // Revoked by a reload
+        auth.set(Vec::new());
+        assert!(!auth.is_enabled());
+    }
+}
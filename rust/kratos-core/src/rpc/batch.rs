@@ -0,0 +1,147 @@
+// Batch - JSON-RPC 2.0 batch requests (an array of calls in one POST)
+// - Each entry is answered on its own: an invalid entry gets its error next to
+//   the results of the others; notifications (no id) get no response
+// - At most MAX_BATCH_SIZE entries, each counted as a request by the rate
+//   limiter; the profile's response cap applies to the whole batch
+// - Entries run one after another: a batch holds a single place in the node's
+//   queue at a time, like a client sending its calls sequentially, so a huge
+//   batch doesn't push back the calls of the other clients
+
+use crate::rpc::types::{JsonRpcError, JsonRpcId, JsonRpcRequest, JsonRpcResponse};
+
+/// Largest batch served (larger ones are refused whole)
+pub const MAX_BATCH_SIZE: usize = 100;
+
+/// One call of a request body
+#[derive(Debug)]
+pub enum Entry {
+    /// Call expecting a response
+    Call(JsonRpcRequest),
+    /// Call without id: executed, not answered
+    Notification(JsonRpcRequest),
+    /// Not a valid call: answered with this error
+    Invalid(JsonRpcResponse),
+}
+
+/// Calls of a request body
+#[derive(Debug)]
+pub enum RpcBody {
+    Single(Entry),
+    Batch(Vec<Entry>),
+    /// Unparsable body, empty or oversized batch: a single error
+    Invalid(JsonRpcResponse),
+}
+
+/// Parse a POST body (a call or a batch of calls)
+pub fn parse_body(body: &[u8]) -> RpcBody {
+    let value: serde_json::Value = match serde_json::from_slice(body) {
+        Ok(value) => value,
+        Err(e) => return RpcBody::Invalid(JsonRpcResponse::error(JsonRpcId::Null, JsonRpcError::parse_error(&e.to_string()))),
+    };
+
+    match value {
+        serde_json::Value::Array(entries) if entries.is_empty() => {
+            RpcBody::Invalid(JsonRpcResponse::error(JsonRpcId::Null, JsonRpcError::invalid_request("Empty batch")))
+        }
+        serde_json::Value::Array(entries) if entries.len() > MAX_BATCH_SIZE => RpcBody::Invalid(JsonRpcResponse::error(
+            JsonRpcId::Null,
+            JsonRpcError::invalid_request(&format!("Batch of {} calls, at most {} allowed", entries.len(), MAX_BATCH_SIZE)),
+        )),
+        serde_json::Value::Array(entries) => RpcBody::Batch(entries.into_iter().map(parse_entry).collect()),
+        value => RpcBody::Single(parse_entry(value)),
+    }
+}
+
+fn parse_entry(value: serde_json::Value) -> Entry {
+    let serde_json::Value::Object(mut object) = value else {
+        return Entry::Invalid(JsonRpcResponse::error(JsonRpcId::Null, JsonRpcError::invalid_request("Call must be an object")));
+    };
+
+    let notification = !object.contains_key("id");
+    let id = match object.get("id").cloned().map(serde_json::from_value::<JsonRpcId>) {
+        Some(Ok(id)) => id,
+        Some(Err(_)) => {
+            return Entry::Invalid(JsonRpcResponse::error(JsonRpcId::Null, JsonRpcError::invalid_request("Invalid id")));
+        }
+        None => JsonRpcId::Null,
+    };
+    object.insert("id".to_string(), serde_json::Value::Null);
+
+    match serde_json::from_value::<JsonRpcRequest>(serde_json::Value::Object(object)) {
+        Ok(request) if notification => Entry::Notification(request),
+        Ok(request) => Entry::Call(JsonRpcRequest { id, ..request }),
+        Err(e) => Entry::Invalid(JsonRpcResponse::error(id, JsonRpcError::invalid_request(&e.to_string()))),
+    }
+}
+
+/// Response bytes left under the profile's cap (None = no cap)
+pub struct ResponseBudget {
+    remaining: Option<usize>,
+}
+
+impl ResponseBudget {
+    pub fn new(max: Option<usize>) -> Self {
+        Self { remaining: max }
+    }
+
+    /// `response`, or an error once the responses exceed the cap
+    pub fn charge(&mut self, response: JsonRpcResponse) -> JsonRpcResponse {
+        let Some(remaining) = self.remaining else {
+            return response;
+        };
+        let size = serde_json::to_vec(&response).map_or(0, |body| body.len());
+        if size > remaining {
+            return JsonRpcResponse::error(response.id, JsonRpcError::response_too_large(size, remaining));
+        }
+        self.remaining = Some(remaining - size);
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_body() {
+        let RpcBody::Single(Entry::Call(request)) = parse_body(br#"{"jsonrpc":"2.0","method":"system_name","id":"a"}"#) else {
+            panic!("single call expected");
+        };
+        assert_eq!(request.id, JsonRpcId::String("a".to_string()));
+
+        let RpcBody::Invalid(response) = parse_body(b"[{\"jsonrpc\"") else {
+            panic!("parse error expected");
+        };
+        assert_eq!(response.error.unwrap().code, -32700);
+        assert!(matches!(parse_body(b"[]"), RpcBody::Invalid(_)));
+        let oversized = format!("[{}]", vec!["1"; MAX_BATCH_SIZE + 1].join(","));
+        assert!(matches!(parse_body(oversized.as_bytes()), RpcBody::Invalid(_)));
+
+        // Invalid entries are answered one by one, notifications not at all
+        let RpcBody::Batch(entries) = parse_body(
+            br#"[{"jsonrpc":"2.0","method":"system_name","id":1},1,{"jsonrpc":"2.0","method":"system_name"},{"id":4}]"#,
+        ) else {
+            panic!("batch expected");
+        };
+        assert!(matches!(&entries[0], Entry::Call(r) if r.id == JsonRpcId::Number(1)));
+        assert!(matches!(&entries[1], Entry::Invalid(r) if r.id == JsonRpcId::Null));
+        assert!(matches!(&entries[2], Entry::Notification(_)));
+        assert!(matches!(&entries[3], Entry::Invalid(r) if r.id == JsonRpcId::Number(4)));
+    }
+
+    #[test]
+    fn test_response_budget() {
+        let response = |id| JsonRpcResponse::success(JsonRpcId::Number(id), "a".repeat(100));
+        let size = serde_json::to_vec(&response(1)).unwrap().len();
+
+        let mut budget = ResponseBudget::new(Some(size * 2));
+        assert!(budget.charge(response(1)).error.is_none());
+        assert!(budget.charge(response(2)).error.is_none());
+        let capped = budget.charge(response(3));
+        assert_eq!(capped.error.unwrap().code, -32007);
+        assert_eq!(capped.id, JsonRpcId::Number(3));
+
+        let mut unlimited = ResponseBudget::new(None);
+        assert!(unlimited.charge(response(4)).error.is_none());
+    }
+}
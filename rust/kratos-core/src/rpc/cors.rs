@@ -0,0 +1,232 @@
+// CORS - Browser origins allowed on the RPC server
+// SECURITY FIX #3: localhost origins only unless configured (--rpc-cors)
+// The origin list is shared with the runner, which replaces it on config
+// reload: the policy is checked per request instead of by warp::cors, whose
+// origins are fixed when the server starts.
+
+use std::convert::Infallible;
+use std::sync::{Arc, RwLock};
+use warp::http::header::{
+    HeaderValue, ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS, ACCESS_CONTROL_ALLOW_ORIGIN,
+    ACCESS_CONTROL_MAX_AGE, VARY,
+};
+use warp::http::{Method, StatusCode};
+use warp::reply::Response;
+use warp::{Filter, Rejection, Reply};
+
+/// Origins allowed when none are configured
+pub const LOCALHOST_ORIGINS: &[&str] = &[
+    "http://localhost",
+    "http://127.0.0.1",
+    "http://localhost:3000", // Common dev frontend port
+    "http://127.0.0.1:3000",
+];
+
+/// Configured origin allowing every origin
+pub const ANY_ORIGIN: &str = "*";
+
+const ALLOWED_METHODS: &str = "GET, POST, OPTIONS";
+const ALLOWED_HEADERS: &str = "content-type, accept, authorization";
+
+/// Seconds browsers may cache a preflight answer
+const PREFLIGHT_MAX_AGE: &str = "600";
+
+/// Allowed origins, shared by the server and whoever reconfigures it
+#[derive(Debug, Clone, Default)]
+pub struct CorsOrigins(Arc<RwLock<Vec<String>>>);
+
+impl CorsOrigins {
+    /// Empty list: localhost origins only
+    pub fn new(origins: Vec<String>) -> Self {
+        Self(Arc::new(RwLock::new(origins)))
+    }
+
+    /// Configured origins
+    pub fn get(&self) -> Vec<String> {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replace the origins, for the next requests
+    pub fn set(&self, origins: Vec<String>) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = origins;
+    }
+
+    /// Whether browsers may call the server from `origin`
+    pub fn allows(&self, origin: &str) -> bool {
+        let origins = self.0.read().unwrap_or_else(|e| e.into_inner());
+        if origins.is_empty() {
+            LOCALHOST_ORIGINS.contains(&origin)
+        } else {
+            origins.iter().any(|allowed| allowed == ANY_ORIGIN || allowed == origin)
+        }
+    }
+
+    /// Human readable policy, for the logs
+    pub fn describe(&self) -> String {
+        let origins = self.get();
+        if origins.is_empty() {
+            "localhost only".to_string()
+        } else {
+            origins.join(", ")
+        }
+    }
+}
+
+/// Request from an origin outside the policy
+#[derive(Debug)]
+struct CorsForbidden;
+
+impl warp::reject::Reject for CorsForbidden {}
+
+/// Apply the policy of `origins` to `routes`
+///
+/// Requests without an Origin header pass unchanged. Preflights are answered
+/// here (403 for a method or header the server doesn't accept); other requests
+/// from a refused origin never reach `routes` (403).
+pub fn wrap<F, R>(routes: F, origins: CorsOrigins) -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone
+where
+    F: Filter<Extract = (R,), Error = Rejection> + Clone + Send + Sync + 'static,
+    R: Reply,
+{
+    // Not a preflight: not_found, the rejection warp ranks lowest, so that the
+    // rejection of `routes` is the one answered
+    let preflight = warp::method()
+        .and(warp::header::optional::<String>("origin"))
+        .and(warp::header::optional::<String>("access-control-request-method"))
+        .and(warp::header::optional::<String>("access-control-request-headers"))
+        .and(with_origins(origins.clone()))
+        .and_then(
+            |method: Method,
+             origin: Option<String>,
+             request_method: Option<String>,
+             request_headers: Option<String>,
+             origins: CorsOrigins| async move {
+                match (origin, request_method) {
+                    (Some(origin), Some(request_method)) if method == Method::OPTIONS => {
+                        // Answered here: a refused preflight must not reach `routes`
+                        if origins.allows(&origin) && preflight_allowed(&request_method, request_headers.as_deref()) {
+                            Ok(allow_origin(preflight_response(), Some(&origin)))
+                        } else {
+                            Ok(forbidden_response())
+                        }
+                    }
+                    _ => Err(warp::reject::not_found()),
+                }
+            },
+        );
+
+    let checked = warp::header::optional::<String>("origin")
+        .and(with_origins(origins))
+        .and_then(|origin: Option<String>, origins: CorsOrigins| async move {
+            match origin {
+                Some(origin) if !origins.allows(&origin) => Err(warp::reject::custom(CorsForbidden)),
+                origin => Ok(origin),
+            }
+        })
+        .and(routes)
+        .map(|origin: Option<String>, reply: R| allow_origin(reply.into_response(), origin.as_deref()));
+
+    preflight.or(checked).unify().recover(forbidden)
+}
+
+fn with_origins(origins: CorsOrigins) -> impl Filter<Extract = (CorsOrigins,), Error = Infallible> + Clone {
+    warp::any().map(move || origins.clone())
+}
+
+/// Whether the method and headers announced by a preflight are accepted
+fn preflight_allowed(method: &str, headers: Option<&str>) -> bool {
+    let method_allowed = ALLOWED_METHODS.split(", ").any(|allowed| allowed.eq_ignore_ascii_case(method.trim()));
+    let headers_allowed = headers.unwrap_or_default().split(',').map(str::trim).filter(|h| !h.is_empty()).all(|header| {
+        ALLOWED_HEADERS.split(", ").any(|allowed| allowed.eq_ignore_ascii_case(header))
+    });
+    method_allowed && headers_allowed
+}
+
+fn preflight_response() -> Response {
+    let mut response = warp::reply().into_response();
+    let headers = response.headers_mut();
+    headers.insert(ACCESS_CONTROL_ALLOW_METHODS, HeaderValue::from_static(ALLOWED_METHODS));
+    headers.insert(ACCESS_CONTROL_ALLOW_HEADERS, HeaderValue::from_static(ALLOWED_HEADERS));
+    headers.insert(ACCESS_CONTROL_MAX_AGE, HeaderValue::from_static(PREFLIGHT_MAX_AGE));
+    response
+}
+
+/// Echo the allowed origin back (responses vary with it)
+fn allow_origin(mut response: Response, origin: Option<&str>) -> Response {
+    if let Some(value) = origin.and_then(|origin| HeaderValue::from_str(origin).ok()) {
+        let headers = response.headers_mut();
+        headers.insert(ACCESS_CONTROL_ALLOW_ORIGIN, value);
+        headers.insert(VARY, HeaderValue::from_static("origin"));
+    }
+    response
+}
+
+fn forbidden_response() -> Response {
+    warp::reply::with_status("CORS request forbidden: origin not allowed", StatusCode::FORBIDDEN).into_response()
+}
+
+async fn forbidden(rejection: Rejection) -> Result<Response, Rejection> {
+    if rejection.find::<CorsForbidden>().is_some() {
+        Ok(forbidden_response())
+    } else {
+        Err(rejection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cors_policy() {
+        let origins = CorsOrigins::default();
+        let route = wrap(warp::path("health").map(|| "ok"), origins.clone());
+
+        // No origin: not a browser, passes
+        let response = warp::test::request().path("/health").reply(&route).await;
+        assert_eq!(response.status(), 200);
+        assert!(response.headers().get(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+
+        // Localhost by default
+        let response = warp::test::request().path("/health").header("origin", "http://localhost").reply(&route).await;
+        assert_eq!(response.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], "http://localhost");
+        let response = warp::test::request().path("/health").header("origin", "https://evil.example").reply(&route).await;
+        assert_eq!(response.status(), 403);
+
+        // Replaced while serving
+        origins.set(vec!["https://wallet.example".to_string()]);
+        let response = warp::test::request()
+            .method("OPTIONS")
+            .path("/health")
+            .header("origin", "https://wallet.example")
+            .header("access-control-request-method", "POST")
+            .reply(&route)
+            .await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], "https://wallet.example");
+        assert_eq!(response.headers()[ACCESS_CONTROL_MAX_AGE], PREFLIGHT_MAX_AGE);
+
+        // Preflight of a method or header the server doesn't accept
+        for (method, headers) in [("DELETE", "content-type"), ("POST", "content-type, x-custom")] {
+            let response = warp::test::request()
+                .method("OPTIONS")
+                .path("/health")
+                .header("origin", "https://wallet.example")
+                .header("access-control-request-method", method)
+                .header("access-control-request-headers", headers)
+                .reply(&route)
+                .await;
+            assert_eq!(response.status(), 403, "{} {}", method, headers);
+        }
+        let response = warp::test::request().path("/health").header("origin", "http://localhost").reply(&route).await;
+        assert_eq!(response.status(), 403);
+
+        origins.set(vec![ANY_ORIGIN.to_string()]);
+        let response = warp::test::request().path("/health").header("origin", "https://evil.example").reply(&route).await;
+        assert_eq!(response.status(), 200);
+
+        // Unknown paths keep their rejection
+        let response = warp::test::request().path("/other").reply(&route).await;
+        assert_eq!(response.status(), 404);
+    }
+}
@@ -0,0 +1,188 @@
+// Events - Server-sent event streams of the node
+// `GET /events/dropped[?sender=0x...]` streams one `dropped` event per
+// transaction leaving the pool without being included (expired, evicted,
+// replaced, stale nonce), so a wallet can resubmit with a fresh nonce or fee.
+// - A subscriber too slow to keep up gets a `lagged` event with the number of
+//   notifications it missed: check `mempool_content` then
+// `GET /events/security` streams one `transition` event per change of the
+// network security state (see SecurityTransition), `alert` set on degradations.
+// - At most MAX_SUBSCRIBERS streams are open at once, per kind
+
+use crate::node::invariants::{SecurityTransition, TransitionNotifier};
+use crate::node::mempool::DroppedTransaction;
+use crate::rpc::types::parse_account_id;
+use crate::types::AccountId;
+use futures::{Stream, StreamExt};
+use serde::Deserialize;
+use std::convert::Infallible;
+use tokio::sync::broadcast::{self, error::RecvError};
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::sse::Event;
+use warp::{Filter, Rejection, Reply};
+
+/// Streams served at once
+pub const MAX_SUBSCRIBERS: usize = 64;
+
+/// Channel of the dropped transactions (TransactionPool::drop_notifier)
+pub type DropNotifier = broadcast::Sender<DroppedTransaction>;
+
+#[derive(Debug, Deserialize)]
+struct DroppedQuery {
+    /// Only the transactions of this account
+    sender: Option<String>,
+}
+
+/// `GET /events/dropped` (not found without a notifier)
+pub fn dropped_route(
+    drops: Option<DropNotifier>,
+) -> impl Filter<Extract = (Response,), Error = Rejection> + Clone {
+    warp::path!("events" / "dropped")
+        .and(warp::get())
+        .and(warp::query::<DroppedQuery>())
+        .and(warp::any().map(move || drops.clone()))
+        .and_then(subscribe)
+}
+
+async fn subscribe(query: DroppedQuery, drops: Option<DropNotifier>) -> Result<Response, Rejection> {
+    let drops = drops.ok_or_else(warp::reject::not_found)?;
+    let sender = match query.sender.as_deref().map(parse_account_id).transpose() {
+        Ok(sender) => sender,
+        Err(e) => return Ok(warp::reply::with_status(e, StatusCode::BAD_REQUEST).into_response()),
+    };
+    if drops.receiver_count() >= MAX_SUBSCRIBERS {
+        return Ok(warp::reply::with_status("Too many subscribers", StatusCode::SERVICE_UNAVAILABLE).into_response());
+    }
+
+    let stream = dropped_stream(drops.subscribe(), sender);
+    Ok(warp::sse::reply(warp::sse::keep_alive().stream(stream)).into_response())
+}
+
+/// Events of the drops received on `rx` (all senders, or one)
+fn dropped_stream(
+    rx: broadcast::Receiver<DroppedTransaction>,
+    sender: Option<AccountId>,
+) -> impl Stream<Item = Result<Event, Infallible>> {
+    futures::stream::unfold(rx, move |mut rx| async move {
+        loop {
+            let event = match rx.recv().await {
+                Ok(dropped) if sender.is_none_or(|s| s == dropped.sender) => dropped_event(&dropped),
+                Ok(_) => continue,
+                Err(RecvError::Lagged(missed)) => Event::default().event("lagged").data(missed.to_string()),
+                Err(RecvError::Closed) => return None,
+            };
+            return Some((Ok(event), rx));
+        }
+    })
+}
+
+/// `GET /events/security` (not found without a notifier)
+pub fn security_route(
+    transitions: Option<TransitionNotifier>,
+) -> impl Filter<Extract = (Response,), Error = Rejection> + Clone {
+    warp::path!("events" / "security")
+        .and(warp::get())
+        .and(warp::any().map(move || transitions.clone()))
+        .and_then(subscribe_security)
+}
+
+async fn subscribe_security(transitions: Option<TransitionNotifier>) -> Result<Response, Rejection> {
+    let transitions = transitions.ok_or_else(warp::reject::not_found)?;
+    if transitions.receiver_count() >= MAX_SUBSCRIBERS {
+        return Ok(warp::reply::with_status("Too many subscribers", StatusCode::SERVICE_UNAVAILABLE).into_response());
+    }
+
+    let stream = security_stream(transitions.subscribe());
+    Ok(warp::sse::reply(warp::sse::keep_alive().stream(stream)).into_response())
+}
+
+/// Events of the transitions received on `rx`
+fn security_stream(
+    rx: broadcast::Receiver<SecurityTransition>,
+) -> impl Stream<Item = Result<Event, Infallible>> {
+    futures::stream::unfold(rx, |mut rx| async move {
+        let event = match rx.recv().await {
+            Ok(transition) => {
+                let data = serde_json::to_string(&transition).unwrap_or_default();
+                Event::default().event("transition").data(data)
+            }
+            Err(RecvError::Lagged(missed)) => Event::default().event("lagged").data(missed.to_string()),
+            Err(RecvError::Closed) => return None,
+        };
+        Some((Ok(event), rx))
+    })
+}
+
+fn dropped_event(dropped: &DroppedTransaction) -> Event {
+    let data = serde_json::json!({
+        "hash": format!("0x{}", hex::encode(dropped.hash.as_bytes())),
+        "sender": format!("0x{}", hex::encode(dropped.sender.as_bytes())),
+        "nonce": dropped.nonce,
+        "reason": dropped.reason.as_str(),
+    });
+    Event::default().event("dropped").data(data.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::mempool::DropReason;
+    use crate::types::Hash;
+
+    #[tokio::test]
+    async fn test_dropped_stream() {
+        let (drops, _) = broadcast::channel(16);
+        let route = dropped_route(Some(drops.clone()));
+        let response = warp::test::request().path("/events/dropped?sender=0x12").reply(&route).await;
+        assert_eq!(response.status(), 400);
+        let response = warp::test::request().path("/events/dropped").reply(&dropped_route(None)).await;
+        assert_eq!(response.status(), 404);
+
+        // Only the events of the sender, until the channel closes
+        let sender = AccountId::from_bytes([1; 32]);
+        let stream = dropped_stream(drops.subscribe(), Some(sender));
+        for (account, nonce) in [(AccountId::from_bytes([2; 32]), 0), (sender, 7)] {
+            drops
+                .send(DroppedTransaction {
+                    hash: Hash::hash(&[nonce as u8]),
+                    sender: account,
+                    nonce,
+                    reason: DropReason::Expired,
+                })
+                .unwrap();
+        }
+        drop((drops, route));
+
+        let events: Vec<String> = stream.map(|event| event.unwrap().to_string()).collect().await;
+        assert_eq!(events.len(), 1);
+        assert!(events[0].starts_with("event:dropped\n"), "{}", events[0]);
+        assert!(events[0].contains(r#""nonce":7"#));
+        assert!(events[0].contains(r#""reason":"expired""#));
+    }
+
+    #[tokio::test]
+    async fn test_security_stream() {
+        let response = warp::test::request().path("/events/security").reply(&security_route(None)).await;
+        assert_eq!(response.status(), 404);
+
+        let (transitions, _) = broadcast::channel(16);
+        let stream = security_stream(transitions.subscribe());
+        transitions
+            .send(SecurityTransition {
+                epoch: 3,
+                from_state: "Healthy".to_string(),
+                to_state: "Critical".to_string(),
+                validator_count: 20,
+                reason: "validators below threshold".to_string(),
+                alert: true,
+            })
+            .unwrap();
+        drop(transitions);
+
+        let events: Vec<String> = stream.map(|event| event.unwrap().to_string()).collect().await;
+        assert_eq!(events.len(), 1);
+        assert!(events[0].starts_with("event:transition\n"), "{}", events[0]);
+        assert!(events[0].contains(r#""toState":"Critical""#));
+        assert!(events[0].contains(r#""alert":true"#));
+    }
+}
@@ -0,0 +1,146 @@
+// Hosts - Host header allowlist of the RPC server
+// Protects a node listening on localhost from DNS rebinding: a malicious page
+// whose domain resolves to 127.0.0.1 reaches the port with its own name in the
+// Host header, and CORS doesn't help since the browser sees a same-origin call.
+// - Entries are host names or IPs (any port), `host:port`, or "*" for any host
+// - Requests without a Host header (not a browser) pass
+// Shared with the runner like the CORS origins, replaced on config reload.
+
+use std::convert::Infallible;
+use std::sync::{Arc, RwLock};
+use warp::http::StatusCode;
+use warp::reply::Response;
+use warp::{Filter, Rejection, Reply};
+
+/// Hosts allowed when none are configured
+pub const LOCALHOST_HOSTS: &[&str] = &["localhost", "127.0.0.1", "[::1]"];
+
+/// Configured host allowing every host
+pub const ANY_HOST: &str = "*";
+
+/// Allowed Host headers, shared by the server and whoever reconfigures it
+#[derive(Debug, Clone, Default)]
+pub struct AllowedHosts(Arc<RwLock<Vec<String>>>);
+
+impl AllowedHosts {
+    /// Empty list: localhost only
+    pub fn new(hosts: Vec<String>) -> Self {
+        Self(Arc::new(RwLock::new(hosts)))
+    }
+
+    /// Configured hosts
+    pub fn get(&self) -> Vec<String> {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replace the hosts, for the next requests
+    pub fn set(&self, hosts: Vec<String>) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = hosts;
+    }
+
+    /// Whether a request with this Host header is served
+    pub fn allows(&self, host: &str) -> bool {
+        let host = host.trim().to_ascii_lowercase();
+        let name = host_name(&host);
+        let hosts = self.0.read().unwrap_or_else(|e| e.into_inner());
+        if hosts.is_empty() {
+            LOCALHOST_HOSTS.contains(&name)
+        } else {
+            hosts.iter().any(|allowed| {
+                let allowed = allowed.to_ascii_lowercase();
+                allowed == ANY_HOST || allowed == name || allowed == host
+            })
+        }
+    }
+
+    /// Human readable policy, for the logs
+    pub fn describe(&self) -> String {
+        let hosts = self.get();
+        if hosts.is_empty() {
+            "localhost only".to_string()
+        } else {
+            hosts.join(", ")
+        }
+    }
+}
+
+/// Host header without its port ("[::1]:9933" -> "[::1]")
+fn host_name(host: &str) -> &str {
+    match host.strip_prefix('[') {
+        Some(rest) => rest.find(']').map_or(host, |end| &host[..end + 2]),
+        None => host.split(':').next().unwrap_or(host),
+    }
+}
+
+/// Request for a host outside the allowlist
+#[derive(Debug)]
+struct HostForbidden;
+
+impl warp::reject::Reject for HostForbidden {}
+
+/// Serve `routes` only to the Host headers of `hosts` (403 for the others)
+pub fn wrap<F, R>(routes: F, hosts: AllowedHosts) -> impl Filter<Extract = (impl Reply,), Error = Rejection> + Clone
+where
+    F: Filter<Extract = (R,), Error = Rejection> + Clone + Send + Sync + 'static,
+    R: Reply,
+{
+    warp::header::optional::<String>("host")
+        .and(with_hosts(hosts))
+        .and_then(|host: Option<String>, hosts: AllowedHosts| async move {
+            match host {
+                Some(host) if !hosts.allows(&host) => Err(warp::reject::custom(HostForbidden)),
+                _ => Ok(()),
+            }
+        })
+        .untuple_one()
+        .and(routes)
+        .recover(forbidden)
+}
+
+fn with_hosts(hosts: AllowedHosts) -> impl Filter<Extract = (AllowedHosts,), Error = Infallible> + Clone {
+    warp::any().map(move || hosts.clone())
+}
+
+async fn forbidden(rejection: Rejection) -> Result<Response, Rejection> {
+    if rejection.find::<HostForbidden>().is_some() {
+        Ok(warp::reply::with_status("Host not allowed", StatusCode::FORBIDDEN).into_response())
+    } else {
+        Err(rejection)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_host_allowlist() {
+        let hosts = AllowedHosts::default();
+        let route = wrap(warp::path("health").map(|| "ok"), hosts.clone());
+
+        // Localhost by default, any port
+        for host in ["localhost:9933", "127.0.0.1", "[::1]:9933", "LOCALHOST"] {
+            let response = warp::test::request().path("/health").header("host", host).reply(&route).await;
+            assert_eq!(response.status(), 200, "{}", host);
+        }
+        let response = warp::test::request().path("/health").header("host", "rebind.evil.example:9933").reply(&route).await;
+        assert_eq!(response.status(), 403);
+
+        // Replaced while serving
+        hosts.set(vec!["rpc.example".to_string(), "10.0.0.1:9933".to_string()]);
+        let response = warp::test::request().path("/health").header("host", "rpc.example:443").reply(&route).await;
+        assert_eq!(response.status(), 200);
+        let response = warp::test::request().path("/health").header("host", "10.0.0.1:8080").reply(&route).await;
+        assert_eq!(response.status(), 403);
+        let response = warp::test::request().path("/health").header("host", "localhost").reply(&route).await;
+        assert_eq!(response.status(), 403);
+
+        hosts.set(vec![ANY_HOST.to_string()]);
+        let response = warp::test::request().path("/health").header("host", "anything.example").reply(&route).await;
+        assert_eq!(response.status(), 200);
+
+        // Unknown paths keep their rejection
+        let response = warp::test::request().path("/other").header("host", "localhost").reply(&route).await;
+        assert_eq!(response.status(), 404);
+    }
+}
@@ -1,4 +1,6 @@
 // Methods RPC - JSON-RPC method implementations
+use crate::consensus::validator_credits::VC_HISTORY_EPOCHS;
+use crate::contracts::messaging::MAX_INBOX_MESSAGES;
 use crate::node::service::KratOsNode;
 use crate::rpc::types::*;
 use crate::types::*;
@@ -39,15 +41,30 @@ impl RpcMethods {
             "chain_getBlockByNumber" => self.chain_get_block_by_number(request.id, request.params).await,
             "chain_getLatestBlock" => self.chain_get_latest_block(request.id).await,
             "chain_getHeader" => self.chain_get_header(request.id, request.params).await,
+            "chain_getTransactionReceipt" => self.chain_get_transaction_receipt(request.id, request.params).await,
+            "chain_getEpochInfo" => self.chain_get_epoch_info(request.id).await,
+            "chain_getMortality" => self.chain_get_mortality(request.id).await,
+            "chain_getForks" => self.chain_get_forks(request.id).await,
+            "chain_getReorgEvents" => self.chain_get_reorg_events(request.id, request.params).await,
+            "chain_getEvents" => self.chain_get_events(request.id, request.params).await,
 
             // State methods
             "state_getAccount" => self.state_get_account(request.id, request.params).await,
             "state_getBalance" => self.state_get_balance(request.id, request.params).await,
             "state_getNonce" => self.state_get_nonce(request.id, request.params).await,
             "state_getTransactionHistory" => self.state_get_transaction_history(request.id, request.params).await,
+            "state_getBalanceAt" => self.state_get_balance_at(request.id, request.params).await,
+            "state_callAt" => self.state_call_at(request.id, request.params).await,
+            "state_getStorageAt" => self.state_get_storage_at(request.id, request.params).await,
+            "state_getProof" => self.state_get_proof(request.id, request.params).await,
+
+            // Account methods
+            "account_nextNonce" => self.account_next_nonce(request.id, request.params).await,
+            "account_getTransactions" => self.account_get_transactions(request.id, request.params).await,
 
             // Author methods (transaction submission)
             "author_submitTransaction" => self.author_submit_transaction(request.id, request.params).await,
+            "author_submitTransactionIdempotent" => self.author_submit_transaction_idempotent(request.id, request.params).await,
             "author_pendingTransactions" => self.author_pending_transactions(request.id).await,
             "author_removeTransaction" => self.author_remove_transaction(request.id, request.params).await,
 
@@ -58,11 +75,18 @@ impl RpcMethods {
             "system_syncState" => self.system_sync_state(request.id).await,
             "system_version" => self.system_version(request.id).await,
             "system_name" => self.system_name(request.id).await,
+            "system_chain" => self.system_chain(request.id).await,
+            "system_invariants" => self.system_invariants(request.id).await,
+            "system_securityStateHistory" => self.system_security_state_history(request.id).await,
+            "system_stateCache" => self.system_state_cache(request.id).await,
 
             // Mempool methods
             "mempool_status" => self.mempool_status(request.id).await,
             "mempool_content" => self.mempool_content(request.id).await,
 
+            // Fee market
+            "fee_history" => self.fee_history(request.id, request.params).await,
+
             // Clock health methods (SPEC v6.1)
             "clock_getHealth" => self.clock_get_health(request.id).await,
             "clock_getValidatorRecord" => self.clock_get_validator_record(request.id, request.params).await,
@@ -73,11 +97,50 @@ impl RpcMethods {
             "validator_getCandidateVotes" => self.validator_get_candidate_votes(request.id, request.params).await,
             "validator_canVote" => self.validator_can_vote(request.id, request.params).await,
 
+            // Validator operations
+            "validator_getInfo" => self.validator_get_info(request.id, request.params).await,
+            "validator_getCredits" => self.validator_get_credits(request.id, request.params).await,
+            "validator_creditsHistory" => self.validator_credits_history(request.id, request.params).await,
+            "validator_getAuthoredBlocks" => self.validator_get_authored_blocks(request.id, request.params).await,
+
             // Finality methods (GRANDPA-style)
             "finality_getStatus" => self.finality_get_status(request.id).await,
             "finality_getLastFinalized" => self.finality_get_last_finalized(request.id).await,
             "finality_getJustification" => self.finality_get_justification(request.id, request.params).await,
             "finality_getRoundInfo" => self.finality_get_round_info(request.id).await,
+            "finality_getStallStatus" => self.finality_get_stall_status(request.id).await,
+            "grandpa_proveFinality" => self.grandpa_prove_finality(request.id, request.params).await,
+
+            // Staking methods
+            "staking_getDelegations" => self.staking_get_delegations(request.id, request.params).await,
+            "staking_getDelegators" => self.staking_get_delegators(request.id, request.params).await,
+            "staking_getUnbonding" => self.staking_get_unbonding(request.id, request.params).await,
+            "staking_pendingRewards" => self.staking_pending_rewards(request.id, request.params).await,
+            "governance_getProposal" => self.governance_get_proposal(request.id, request.params, false).await,
+            "governance_getTally" => self.governance_get_proposal(request.id, request.params, true).await,
+            "governance_listProposals" => self.governance_list_proposals(request.id).await,
+            "treasury_balance" => self.treasury_balance(request.id).await,
+            "treasury_proposals" => self.treasury_proposals(request.id).await,
+            "governance_getParameters" => self.governance_get_parameters(request.id).await,
+            "governance_parameterLog" => self.governance_parameter_log(request.id).await,
+            "upgrade_getStatus" => self.upgrade_get_status(request.id).await,
+
+            // Identity methods
+            "identity_get" => self.identity_get(request.id, request.params).await,
+            "personhood_getStatus" => self.personhood_get_status(request.id, request.params).await,
+
+            // Messaging methods
+            "messaging_getInbox" => self.messaging_get_inbox(request.id, request.params).await,
+
+            // Reputation methods
+            "reputation_get" => self.reputation_get(request.id, request.params).await,
+            "reputation_top" => self.reputation_top(request.id, request.params).await,
+
+            // Slashing methods
+            "slashing_pendingEvidence" => self.slashing_pending_evidence(request.id).await,
+
+            // Emergency methods
+            "emergency_getStatus" => self.emergency_get_status(request.id).await,
 
             // Unknown method
             _ => JsonRpcResponse::error(request.id, JsonRpcError::method_not_found(&request.method)),
@@ -98,7 +161,7 @@ impl RpcMethods {
         match self.node.current_block().await {
             Some(block) => {
                 let info = ChainInfo {
-                    chain_name: "KratOs".to_string(),
+                    chain_name: self.node.chain_config().chain_name.clone(),
                     height,
                     best_hash: format!("0x{}", hex::encode(block.hash().as_bytes())),
                     genesis_hash: format!("0x{}", hex::encode(genesis_hash.as_bytes())),
@@ -113,6 +176,38 @@ impl RpcMethods {
         }
     }
 
+    /// Get the current epoch, active validators, bootstrap and security state
+    async fn chain_get_epoch_info(&self, id: JsonRpcId) -> JsonRpcResponse {
+        let report = self.node.epoch_report().await;
+        JsonRpcResponse::success(id, EpochInfoRpc::from(&report))
+    }
+
+    /// Validity window recommended for new transactions
+    async fn chain_get_mortality(&self, id: JsonRpcId) -> JsonRpcResponse {
+        JsonRpcResponse::success(id, MortalityRpc::new(self.node.chain_height().await))
+    }
+
+    /// Competing chains known to the node
+    async fn chain_get_forks(&self, id: JsonRpcId) -> JsonRpcResponse {
+        match self.node.forks_report().await {
+            Ok(report) => JsonRpcResponse::success(id, ForksRpc::from(&report)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Reorgs after the event ID given (0 for all kept), oldest first
+    async fn chain_get_reorg_events(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let after_id = match params.as_array().and_then(|arr| arr.first()).and_then(|v| v.as_u64()) {
+            Some(n) => n,
+            None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [afterId]")),
+        };
+
+        match self.node.reorg_events(after_id).await {
+            Ok(events) => JsonRpcResponse::success(id, ReorgEventsRpc::from(events.as_slice())),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
     /// Get block by number or "latest"
     async fn chain_get_block(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
         // Parse params: can be number, "latest", or hash
@@ -178,6 +273,30 @@ impl RpcMethods {
         }
     }
 
+    /// Get the receipt of an included transaction
+    async fn chain_get_transaction_receipt(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let hash_str: String = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected hash string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [tx_hash]")),
+        };
+
+        let hash = match parse_hash(&hash_str) {
+            Ok(h) => h,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.get_receipt(&hash).await {
+            Ok(Some(stored)) => JsonRpcResponse::success(id, TransactionReceipt::from(&stored)),
+            Ok(None) => JsonRpcResponse::error(id, JsonRpcError::transaction_not_found()),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&format!("{:?}", e))),
+        }
+    }
+
     /// Get block by number
     async fn chain_get_block_by_number(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
         let number: u64 = match params {
@@ -311,6 +430,164 @@ impl RpcMethods {
         }
     }
 
+    /// Get account balance at a past block (archive mode)
+    async fn state_get_balance_at(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let (address_str, block_hash) = match parse_at_params(&params, "[address, blockHash]") {
+            Ok(p) => p,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        self.account_at(id, &account_id, &block_hash, HistoricalCall::Balance).await
+    }
+
+    /// Nonce for the account's next transaction, past its pending ones
+    async fn account_next_nonce(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let address_str: String = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
+        };
+
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.next_nonce(&account_id).await {
+            Ok(nonce) => JsonRpcResponse::success(id, nonce),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&format!("{:?}", e))),
+        }
+    }
+
+    /// Transactions sent or received by an account, from the index
+    async fn account_get_transactions(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let (account_id, query) = match parse_account_transactions_params(&params) {
+            Ok(p) => p,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        let page = match self.node.indexed_transactions(&account_id, &query.filter, query.cursor, query.limit).await {
+            Ok(page) => AccountTransactionsRpc::new(&account_id, &page),
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        };
+        if query.wants_pending() {
+            let pending = self.node.pending_transactions_of(&account_id).await;
+            return JsonRpcResponse::success(id, page.with_pending(&account_id, &query, &pending));
+        }
+        JsonRpcResponse::success(id, page)
+    }
+
+    /// Events of a type (`contract.Name`), from the index
+    async fn chain_get_events(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let (event_type, limit, offset) = match parse_page_params(&params, "[eventType, limit?, offset?]") {
+            Ok(p) => p,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.indexed_events(&event_type, offset, limit).await {
+            Ok(page) => JsonRpcResponse::success(id, EventsRpc::new(&event_type, offset, &page)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Blocks authored by a validator, from the index
+    async fn validator_get_authored_blocks(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let (address_str, limit, offset) = match parse_page_params(&params, "[address, limit?, offset?]") {
+            Ok(p) => p,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.indexed_blocks_by_author(&account_id, offset, limit).await {
+            Ok(page) => JsonRpcResponse::success(id, AuthoredBlocksRpc::new(&account_id, offset, &page)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Evaluate a state query (state_getAccount, state_getBalance, state_getNonce) at a past block
+    async fn state_call_at(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        match parse_call_at_params(&params) {
+            Ok((call, account_id, block_hash)) => self.account_at(id, &account_id, &block_hash, call).await,
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        }
+    }
+
+    /// Get a raw state value at a past block (archive mode)
+    async fn state_get_storage_at(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let (key_str, block_hash) = match parse_at_params(&params, "[key, blockHash]") {
+            Ok(p) => p,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        let key = match parse_storage_key(&key_str) {
+            Ok(k) => k,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.get_storage_at(&key, &block_hash).await {
+            Ok(Some(value)) => JsonRpcResponse::success(id, value.map(|v| format!("0x{}", hex::encode(v)))),
+            Ok(None) => JsonRpcResponse::error(id, JsonRpcError::block_not_found()),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Merkle proof of an account against a block's state root
+    async fn state_get_proof(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let (address_str, block_hash) = match parse_at_params(&params, "[address, blockHash]") {
+            Ok(p) => p,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.get_account_proof(&account_id, &block_hash).await {
+            Ok(Some(proof)) => match proof
+                .map(|p| StateProofRpc::from_proof(&account_id, &block_hash, &p))
+                .transpose()
+            {
+                Ok(proof) => JsonRpcResponse::success(id, proof),
+                Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+            },
+            Ok(None) => JsonRpcResponse::error(id, JsonRpcError::block_not_found()),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Account-based historical query
+    async fn account_at(
+        &self,
+        id: JsonRpcId,
+        account_id: &AccountId,
+        block_hash: &Hash,
+        call: HistoricalCall,
+    ) -> JsonRpcResponse {
+        match self.node.get_account_info_at(account_id, block_hash).await {
+            Ok(Some(info)) => {
+                let info = info
+                    .map(|i| AccountInfoRpc::from_info(account_id, &i))
+                    .unwrap_or_else(|| AccountInfoRpc::empty(account_id));
+                JsonRpcResponse::success(id, call.result(&info))
+            }
+            Ok(None) => JsonRpcResponse::error(id, JsonRpcError::block_not_found()),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
     /// Get transaction history for an account
     ///
     /// Scans recent blocks to find transactions involving the specified account.
@@ -360,14 +637,14 @@ impl RpcMethods {
                     // Check if this transaction involves the account
                     let is_sender = tx.transaction.sender == account_id;
                     let is_recipient = match &tx.transaction.call {
-                        TransactionCall::Transfer { to, .. } => *to == account_id,
+                        TransactionCall::Transfer { to, .. } | TransactionCall::TransferWithMemo { to, .. } => *to == account_id,
                         _ => false,
                     };
 
                     if is_sender || is_recipient {
                         // Build transaction record
                         let (tx_type, counterparty, amount) = match &tx.transaction.call {
-                            TransactionCall::Transfer { to, amount } => {
+                            TransactionCall::Transfer { to, amount } | TransactionCall::TransferWithMemo { to, amount, .. } => {
                                 let cp = if is_sender {
                                     format!("0x{}", hex::encode(to.as_bytes()))
                                 } else {
@@ -378,7 +655,7 @@ impl RpcMethods {
                             TransactionCall::Stake { amount } => ("Stake", address_str.clone(), *amount),
                             TransactionCall::Unstake { amount } => ("Unstake", address_str.clone(), *amount),
                             TransactionCall::RegisterValidator { stake } => ("RegisterValidator", address_str.clone(), *stake),
-                            TransactionCall::ProposeEarlyValidator { candidate } => {
+                            TransactionCall::ProposeEarlyValidator { candidate, .. } => {
                                 ("ProposeEarlyValidator", format!("0x{}", hex::encode(candidate.as_bytes())), 0)
                             }
                             TransactionCall::VoteEarlyValidator { candidate } => {
@@ -401,6 +678,7 @@ impl RpcMethods {
                             "timestamp": tx.transaction.timestamp,
                             "blockNumber": block_num,
                             "blockHash": format!("0x{}", hex::encode(block.hash().as_bytes())),
+                            "memo": tx.transaction.call.memo().map(|memo| format!("0x{}", hex::encode(memo))),
                         }));
 
                         if transactions.len() >= limit {
@@ -453,7 +731,31 @@ impl RpcMethods {
                 };
                 JsonRpcResponse::success(id, result)
             }
-            Err(e) => JsonRpcResponse::error(id, JsonRpcError::transaction_rejected(&format!("{:?}", e))),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::submission_failed(&e)),
+        }
+    }
+
+    /// Submit a transaction at most once per idempotency key
+    async fn author_submit_transaction_idempotent(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let (tx, key): (SignedTransaction, String) = match params {
+            serde_json::Value::Array(arr) if arr.len() == 2 => {
+                let tx = match serde_json::from_value(arr[0].clone()) {
+                    Ok(tx) => tx,
+                    Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&format!("Invalid transaction: {}", e))),
+                };
+                match arr[1].as_str() {
+                    Some(key) => (tx, key.to_string()),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Idempotency key must be a string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [transaction, idempotency_key]")),
+        };
+
+        match self.node.submit_transaction_idempotent(tx, &key).await {
+            Ok(submission) => {
+                JsonRpcResponse::success(id, IdempotentSubmitResult::new(submission.hash, submission.duplicate))
+            }
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::submission_failed(&e)),
         }
     }
 
@@ -498,7 +800,7 @@ impl RpcMethods {
             name: "KratOs Node".to_string(),
             version: env!("CARGO_PKG_VERSION").to_string(),
             chain: ChainInfo {
-                chain_name: "KratOs".to_string(),
+                chain_name: self.node.chain_config().chain_name.clone(),
                 height,
                 best_hash,
                 genesis_hash: format!("0x{}", hex::encode(genesis_hash.as_bytes())),
@@ -537,18 +839,9 @@ impl RpcMethods {
         JsonRpcResponse::success(id, health)
     }
 
-    /// Get connected peers
+    /// Get connected and banned peers
     async fn system_peers(&self, id: JsonRpcId) -> JsonRpcResponse {
-        let peers = self.node.connected_peers().await;
-        let peer_count = peers.len();
-
-        // Return just count and IDs for now
-        let peer_ids: Vec<String> = peers.iter().map(|p| p.to_string()).collect();
-
-        JsonRpcResponse::success(id, serde_json::json!({
-            "count": peer_count,
-            "peers": peer_ids
-        }))
+        JsonRpcResponse::success(id, SystemPeersRpc::new(&self.node.peers().await))
     }
 
     /// Get sync state
@@ -586,6 +879,26 @@ impl RpcMethods {
         JsonRpcResponse::success(id, "KratOs Node")
     }
 
+    /// Network this node runs: wallets check it before signing
+    async fn system_chain(&self, id: JsonRpcId) -> JsonRpcResponse {
+        JsonRpcResponse::success(id, SystemChainRpc::new(self.node.chain_config(), self.node.genesis_hash()))
+    }
+
+    /// SPEC invariant gauges (1 = holds, 0 = violated)
+    async fn system_invariants(&self, id: JsonRpcId) -> JsonRpcResponse {
+        JsonRpcResponse::success(id, self.node.invariant_report().await)
+    }
+
+    /// Security state transitions since startup
+    async fn system_security_state_history(&self, id: JsonRpcId) -> JsonRpcResponse {
+        JsonRpcResponse::success(id, self.node.security_history().await)
+    }
+
+    /// State cache hit/miss counters
+    async fn system_state_cache(&self, id: JsonRpcId) -> JsonRpcResponse {
+        JsonRpcResponse::success(id, self.node.state_cache_stats().await)
+    }
+
     // =========================================================================
     // MEMPOOL METHODS
     // =========================================================================
@@ -610,15 +923,28 @@ impl RpcMethods {
         JsonRpcResponse::success(id, status)
     }
 
-    /// Get mempool content (pending transactions)
+    /// Get mempool content (pending transactions, in inclusion order)
     async fn mempool_content(&self, id: JsonRpcId) -> JsonRpcResponse {
-        // TODO: Get actual pending transactions from mempool
-        let count = self.node.mempool_size().await;
+        let content = self.node.mempool_content().await;
+        JsonRpcResponse::success(id, MempoolContent::from(&content))
+    }
 
-        JsonRpcResponse::success(id, serde_json::json!({
-            "pending_count": count,
-            "transactions": []  // TODO: Return actual transactions
-        }))
+    /// Base fees and fullness of the last [blockCount] blocks
+    async fn fee_history(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let block_count = match params.as_array().and_then(|arr| arr.first()).and_then(|v| v.as_u64()) {
+            Some(n) if (1..=MAX_FEE_HISTORY_BLOCKS).contains(&n) => n,
+            _ => {
+                return JsonRpcResponse::error(
+                    id,
+                    JsonRpcError::invalid_params(&format!("Expected [blockCount] between 1 and {}", MAX_FEE_HISTORY_BLOCKS)),
+                )
+            }
+        };
+
+        match self.node.fee_history(block_count).await {
+            Ok(history) => JsonRpcResponse::success(id, FeeHistoryRpc::from(&history)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
     }
 
     // =========================================================================
@@ -758,6 +1084,8 @@ impl RpcMethods {
                     "votes_required": c.votes_required,
                     "has_quorum": c.has_quorum(),
                     "created_at": c.created_at,
+                    "display_name": c.metadata.display_name,
+                    "url": c.metadata.url,
                     "voters": c.voters.iter()
                         .map(|v| format!("0x{}", hex::encode(v.as_bytes())))
                         .collect::<Vec<_>>()
@@ -866,6 +1194,85 @@ impl RpcMethods {
         }))
     }
 
+    // =========================================================================
+    // VALIDATOR OPERATIONS METHODS
+    // =========================================================================
+
+    /// Get operational status of a validator
+    ///
+    /// Returns stake, performance, slashing and session information
+    async fn validator_get_info(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let account_str: String = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected account address string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [account_address]")),
+        };
+
+        let account_id = match parse_account_id(&account_str) {
+            Ok(acc) => acc,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.validator_report(&account_id).await {
+            Some(report) => JsonRpcResponse::success(id, ValidatorInfoRpc::from(&report)),
+            None => JsonRpcResponse::error(id, JsonRpcError::validator_not_found()),
+        }
+    }
+
+    /// Get validator credits breakdown
+    async fn validator_get_credits(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let account_str: String = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected account address string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [account_address]")),
+        };
+
+        let account_id = match parse_account_id(&account_str) {
+            Ok(acc) => acc,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.validator_report(&account_id).await {
+            Some(report) => JsonRpcResponse::success(id, ValidatorCreditsRpc::from(&report)),
+            None => JsonRpcResponse::error(id, JsonRpcError::validator_not_found()),
+        }
+    }
+
+    /// Get the VC earned and removed by a validator over its latest epochs
+    ///
+    /// Params: [account_address, epochs?] (default 10, at most VC_HISTORY_EPOCHS)
+    async fn validator_credits_history(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let (account_str, epochs) = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                let account = match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected account address string")),
+                };
+                let epochs = arr.get(1).and_then(|v| v.as_u64()).unwrap_or(10).min(VC_HISTORY_EPOCHS as u64) as usize;
+                (account, epochs)
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [account_address, epochs?]")),
+        };
+
+        let account_id = match parse_account_id(&account_str) {
+            Ok(acc) => acc,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.validator_report(&account_id).await {
+            Some(report) => JsonRpcResponse::success(id, VcHistoryRpc::new(&report, epochs)),
+            None => JsonRpcResponse::error(id, JsonRpcError::validator_not_found()),
+        }
+    }
+
     // =========================================================================
     // FINALITY METHODS (GRANDPA-style)
     // =========================================================================
@@ -936,6 +1343,7 @@ impl RpcMethods {
                     "block_number": justification.block_number,
                     "block_hash": format!("0x{}", hex::encode(justification.block_hash.as_bytes())),
                     "epoch": justification.epoch,
+                    "round": justification.round,
                     "signatures": signatures,
                     "signature_count": justification.signatures.len()
                 }))
@@ -950,6 +1358,329 @@ impl RpcMethods {
         }
     }
 
+    /// Prove the finality of a block with a stored justification
+    ///
+    /// Returns null if no justification covers the block yet
+    async fn grandpa_prove_finality(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let block_number = match params.as_array().and_then(|arr| arr.first()).and_then(|v| v.as_u64()) {
+            Some(n) => n,
+            None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [blockNumber]")),
+        };
+
+        match self.node.prove_finality(block_number).await {
+            Ok(justification) => JsonRpcResponse::success(
+                id,
+                justification.map(|j| FinalityProofRpc::from_justification(block_number, &j)),
+            ),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Delegations of an account
+    async fn staking_get_delegations(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let address_str: String = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected account address string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [account_address]")),
+        };
+
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.delegations(&account_id).await {
+            Ok(delegations) => JsonRpcResponse::success(id, DelegationsRpc::new(&account_id, &delegations)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Stake delegated to a validator
+    async fn staking_get_delegators(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let address_str: String = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected validator address string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [validator_address]")),
+        };
+
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.delegation_pool(&account_id).await {
+            Ok((pool, commission)) => JsonRpcResponse::success(id, DelegationPoolRpc::new(&account_id, &pool, commission)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Unbonding queue of an account
+    async fn staking_get_unbonding(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let address_str: String = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
+        };
+
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        let next_block = self.node.chain_height().await + 1;
+        match self.node.unbonding_queue(&account_id).await {
+            Ok(queue) => JsonRpcResponse::success(id, UnbondingRpc::new(&account_id, &queue, next_block)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Delegation rewards owed to an account
+    async fn staking_pending_rewards(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let address_str: String = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
+        };
+
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        let next_block = self.node.chain_height().await + 1;
+        match self.node.reward_ledger(&account_id).await {
+            Ok(ledger) => JsonRpcResponse::success(id, PendingRewardsRpc::new(&account_id, &ledger, next_block)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// A governance proposal, or only its tally
+    async fn governance_get_proposal(&self, id: JsonRpcId, params: serde_json::Value, tally_only: bool) -> JsonRpcResponse {
+        let proposal_id = match params.as_array().and_then(|arr| arr.first()).and_then(|v| v.as_u64()) {
+            Some(n) => n,
+            None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [proposalId]")),
+        };
+
+        match self.node.governance_report(Some(proposal_id)).await {
+            Ok(report) => match (ProposalRpc::from_report(&report).into_iter().next(), tally_only) {
+                (Some(proposal), true) => JsonRpcResponse::success(id, proposal.tally),
+                (proposal, _) => JsonRpcResponse::success(id, proposal),
+            },
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// All governance proposals, newest first
+    async fn governance_list_proposals(&self, id: JsonRpcId) -> JsonRpcResponse {
+        match self.node.governance_report(None).await {
+            Ok(report) => JsonRpcResponse::success(id, ProposalRpc::from_report(&report)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Treasury balance and approved spends
+    async fn treasury_balance(&self, id: JsonRpcId) -> JsonRpcResponse {
+        match self.node.treasury_report().await {
+            Ok(report) => JsonRpcResponse::success(id, TreasuryRpc::from(&report)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// TreasurySpend proposals, newest first
+    async fn treasury_proposals(&self, id: JsonRpcId) -> JsonRpcResponse {
+        match self.node.treasury_report().await {
+            Ok(report) => JsonRpcResponse::success(id, ProposalRpc::from_report(&report.governance)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Protocol parameters in force and the changes scheduled
+    async fn governance_get_parameters(&self, id: JsonRpcId) -> JsonRpcResponse {
+        match self.node.parameters_report().await {
+            Ok(report) => JsonRpcResponse::success(id, ProtocolParametersRpc::from(&report)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Parameter change audit log, newest first
+    async fn governance_parameter_log(&self, id: JsonRpcId) -> JsonRpcResponse {
+        match self.node.parameters_report().await {
+            Ok(report) => JsonRpcResponse::success(id, ParameterChangeRecordRpc::from_report(&report)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Protocol version in force and the upgrade scheduled
+    async fn upgrade_get_status(&self, id: JsonRpcId) -> JsonRpcResponse {
+        match self.node.parameters_report().await {
+            Ok(report) => JsonRpcResponse::success(id, UpgradeStatusRpc::from(&report)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Identity registered by an account (null if none)
+    async fn identity_get(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let address_str: String = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
+        };
+
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        let next_block = self.node.chain_height().await + 1;
+        match self.node.identity(&account_id).await {
+            Ok(identity) => {
+                JsonRpcResponse::success(id, identity.map(|identity| IdentityRpc::new(&identity, next_block)))
+            }
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Verified-person status of an account and the current verification round
+    async fn personhood_get_status(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let address_str: String = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
+        };
+
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.personhood_report(&account_id).await {
+            Ok(report) => JsonRpcResponse::success(id, PersonhoodStatusRpc::from(&report)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Latest messages received by an account, newest first
+    async fn messaging_get_inbox(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        // Parse parameters: [address, limit?]
+        let (address_str, limit) = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                let addr = match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+                };
+                let limit = arr.get(1).and_then(|v| v.as_u64()).unwrap_or(DEFAULT_INBOX_MESSAGES).min(MAX_INBOX_MESSAGES) as usize;
+                (addr, limit)
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address, limit?]")),
+        };
+
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.inbox(&account_id, limit).await {
+            Ok(messages) => {
+                let messages: Vec<AccountMessageRpc> = messages.iter().map(AccountMessageRpc::from).collect();
+                JsonRpcResponse::success(id, messages)
+            }
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Reputation of an account (null if it has none yet)
+    async fn reputation_get(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        let address_str: String = match params {
+            serde_json::Value::Array(arr) if !arr.is_empty() => {
+                match arr[0].as_str() {
+                    Some(s) => s.to_string(),
+                    None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+                }
+            }
+            _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
+        };
+
+        let account_id = match parse_account_id(&address_str) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+        };
+
+        match self.node.reputation(&account_id).await {
+            Ok(reputation) => JsonRpcResponse::success(id, reputation.as_ref().map(ReputationRpc::from)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Highest reputations, overall or in one domain
+    async fn reputation_top(&self, id: JsonRpcId, params: serde_json::Value) -> JsonRpcResponse {
+        // Optional [limit?, domain?]: overall ranking when no domain is given
+        let arr = params.as_array().cloned().unwrap_or_default();
+        let limit = match arr.first() {
+            None | Some(serde_json::Value::Null) => DEFAULT_REPUTATION_TOP,
+            Some(value) => match value.as_u64() {
+                Some(n) => (n as usize).min(MAX_REPUTATION_TOP),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [limit?, domain?]")),
+            },
+        };
+        let domain = match arr.get(1) {
+            None | Some(serde_json::Value::Null) => None,
+            Some(value) => match value.as_str().map(parse_reputation_domain) {
+                Some(Ok(domain)) => Some(domain),
+                Some(Err(e)) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected domain string")),
+            },
+        };
+
+        match self.node.reputations().await {
+            Ok(reputations) => JsonRpcResponse::success(id, ReputationRpc::top(&reputations, limit, domain)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
+    /// Equivocation evidence awaiting a report on chain
+    async fn slashing_pending_evidence(&self, id: JsonRpcId) -> JsonRpcResponse {
+        let pending: Vec<PendingEvidenceRpc> = self
+            .node
+            .pending_evidence()
+            .await
+            .iter()
+            .map(|(offence, pending)| PendingEvidenceRpc::new(offence, pending))
+            .collect();
+        JsonRpcResponse::success(id, pending)
+    }
+
+    /// Emergency status, declaration signatures and circuit breakers
+    async fn emergency_get_status(&self, id: JsonRpcId) -> JsonRpcResponse {
+        match self.node.emergency_report().await {
+            Ok(report) => JsonRpcResponse::success(id, EmergencyStatusRpc::from(&report)),
+            Err(e) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e.to_string())),
+        }
+    }
+
     /// Get current finality round information
     async fn finality_get_round_info(&self, id: JsonRpcId) -> JsonRpcResponse {
         let round_info = self.node.finality_round_info().await;
@@ -979,6 +1710,14 @@ impl RpcMethods {
         }
     }
 
+    /// Get finality liveness: consecutive timeouts, backoff, skipped rounds
+    async fn finality_get_stall_status(&self, id: JsonRpcId) -> JsonRpcResponse {
+        match self.node.finality_stall().await {
+            Some(status) => JsonRpcResponse::success(id, status),
+            None => JsonRpcResponse::error(id, JsonRpcError::internal_error("Finality gadget not running")),
+        }
+    }
+
     // =========================================================================
     // PUBLIC API (for direct method calls)
     // =========================================================================
@@ -992,7 +1731,7 @@ impl RpcMethods {
 
         match self.node.current_block().await {
             Some(block) => Ok(ChainInfo {
-                chain_name: "KratOs".to_string(),
+                chain_name: self.node.chain_config().chain_name.clone(),
                 height,
                 best_hash: format!("0x{}", hex::encode(block.hash().as_bytes())),
                 genesis_hash: format!("0x{}", hex::encode(genesis_hash.as_bytes())),
@@ -1068,6 +1807,17 @@ mod tests {
         let result = response.result.unwrap();
         assert_eq!(result["chainName"], "KratOs");
         assert_eq!(result["height"], 0);
+
+        // system_chain: the genesis hash wallets sign transactions for
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "system_chain".to_string(),
+            params: serde_json::Value::Null,
+            id: JsonRpcId::Number(2),
+        };
+        let chain = methods.handle_request(request).await.result.unwrap();
+        assert_eq!(chain["name"], "KratOs");
+        assert_eq!(chain["genesisHash"], result["genesisHash"]);
     }
 
     #[tokio::test]
@@ -1,17 +1,29 @@
 // RPC - JSON-RPC API for interacting with the node
 
+pub mod auth;
+pub mod batch;
+pub mod cors;
+pub mod events;
+pub mod hosts;
 pub mod methods;
+pub mod profile;
+pub mod prometheus;
 pub mod rate_limit;
 pub mod server;
 pub mod types;
 
 // Re-export commonly used types
 pub use methods::RpcMethods;
-pub use rate_limit::{RateLimitConfig, RpcRateLimiter};
-pub use server::{RpcCall, RpcConfig, RpcSender, RpcServer, RpcServerError, RpcServerHandle, RpcState};
+pub use auth::{RpcAuth, RpcToken};
+pub use profile::RpcProfile;
+pub use cors::CorsOrigins;
+pub use hosts::AllowedHosts;
+pub use rate_limit::{RateLimitConfig, RateLimited, RpcRateLimiter};
+pub use server::{RpcCall, RpcConfig, RpcSender, RpcServer, RpcServerError, RpcServerHandle, RpcState, RpcTls};
 pub use types::{
-    AccountInfoRpc, BlockInfo, BlockWithTransactions, ChainInfo, HealthStatus, JsonRpcError,
-    JsonRpcId, JsonRpcRequest, JsonRpcResponse, MempoolStats, MempoolStatus, NetworkStatus,
-    PeerInfo, SyncStatus, SystemInfo, TransactionInfo, TransactionReceipt, TransactionSubmitResult,
-    ValidatorInfoRpc, parse_account_id, parse_hash,
+    AccountInfoRpc, AccountMessageRpc, AccountTransactionsQuery, AccountTransactionsRpc, AnnouncedCommissionRpc, AuthoredBlockRpc, AuthoredBlocksRpc, BlockInfo, BlockWithTransactions, BootstrapInfoRpc, CeremonyRoundRpc, ChainInfo, CircuitBreakerRpc, DelegationPoolRpc, DelegationRpc, DelegationsRpc, DomainReputationRpc, EmergencyStatusRpc, EpochInfoRpc, EpochRewardRpc, EpochValidatorRpc, EventRpc, EventsRpc, FeeHistoryRpc, ForkRpc, ForksRpc, FeeSharesRpc, FinalityProofRpc, HealthStatus, HistoricalCall, IdentityAttestationRpc, IdentityRpc, IndexedEventRpc, IndexedTransactionRpc, JsonRpcError,
+    JsonRpcId, JsonRpcRequest, JsonRpcResponse, MempoolContent, MempoolStats, MempoolStatus, MempoolTransaction, MortalityRpc, NetworkStatus,
+    ParameterChangeRecordRpc, PeerInfo, PersonhoodStatusRpc, PendingEvidenceRpc, PendingRewardsRpc, ProposalRpc, ProposalTallyRpc, ProposalTimelockRpc, ProtocolParametersRpc, ReputationRpc, RotateKeysRpc, ScheduledChangeRpc, ScheduledUpgradeRpc, SecurityStateRpc, StateProofRpc, SyncStatus, SystemChainRpc, SystemInfo, SystemPeersRpc, TransactionInfo, TransactionReceipt, TransactionSubmitResult, TreasuryRpc,
+    UnbondingChunkRpc, UnbondingRpc, UpgradeStatusRpc, ValidatorCreditsRpc, ValidatorInfoRpc, VcEpochRpc, VcHistoryRpc, parse_account_id, parse_account_transactions_params, parse_ban_peer_params, parse_at_params, parse_call_at_params, parse_hash, parse_page_params,
+    parse_peer_id, parse_reputation_domain, parse_storage_key,
 };
@@ -0,0 +1,163 @@
+// RPC profiles - Curated exposure of the JSON-RPC server (--rpc-profile)
+// - private: every method, no rate limit nor response cap (the operator's node)
+// - public: wallet, explorer and faucet methods only (blocks, current state,
+//   transaction submission, health), tight per-IP rate limit, 1 MB responses
+// - archive: public plus the historical queries, on archive nodes
+// A [rpc-rate-limit] section of the config file replaces the profile's limit.
+
+use crate::rpc::rate_limit::RateLimitConfig;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Methods served by the public profile
+pub const PUBLIC_METHODS: &[&str] = &[
+    "chain_getInfo",
+    "chain_getBlock",
+    "chain_getBlockByNumber",
+    "chain_getLatestBlock",
+    "chain_getTransactionReceipt",
+    "chain_getEpochInfo",
+    "chain_getMortality",
+    "chain_getEvents",
+    "chain_getReorgEvents",
+    "state_getAccount",
+    "state_getBalance",
+    "state_getNonce",
+    "state_getProof",
+    "account_nextNonce",
+    "account_getTransactions",
+    "author_submitTransaction",
+    "author_submitTransactionIdempotent",
+    "system_health",
+    "system_version",
+    "system_name",
+    "system_chain",
+    "system_syncState",
+    "mempool_status",
+    "fee_history",
+    "finality_getStatus",
+    "grandpa_proveFinality",
+    "validator_getInfo",
+    "validator_getCredits",
+    "validator_creditsHistory",
+    "validator_getAuthoredBlocks",
+    "staking_getDelegations",
+    "staking_getDelegators",
+    "staking_getUnbonding",
+    "staking_pendingRewards",
+    "governance_getProposal",
+    "governance_getTally",
+    "governance_listProposals",
+    "governance_getParameters",
+    "governance_parameterLog",
+    "upgrade_getStatus",
+    "treasury_balance",
+    "treasury_proposals",
+    "identity_get",
+    "personhood_getStatus",
+    "messaging_getInbox",
+    "reputation_get",
+    "reputation_top",
+    "emergency_getStatus",
+];
+
+/// Methods the archive profile serves on top of the public ones
+pub const ARCHIVE_METHODS: &[&str] = &[
+    "state_getTransactionHistory",
+    "state_getBalanceAt",
+    "state_callAt",
+    "state_getStorageAt",
+];
+
+/// Exposure profile of the RPC server
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RpcProfile {
+    /// Every method, unlimited
+    #[default]
+    Private,
+    /// Community endpoint: curated methods, rate limited
+    Public,
+    /// Public endpoint of an archive node: adds historical queries
+    Archive,
+}
+
+impl RpcProfile {
+    /// Whether the profile serves `method`
+    pub fn allows(&self, method: &str) -> bool {
+        match self {
+            RpcProfile::Private => true,
+            RpcProfile::Public => PUBLIC_METHODS.contains(&method),
+            RpcProfile::Archive => PUBLIC_METHODS.contains(&method) || ARCHIVE_METHODS.contains(&method),
+        }
+    }
+
+    /// Per-IP rate limit (None = unlimited)
+    pub fn rate_limit(&self) -> Option<RateLimitConfig> {
+        match self {
+            RpcProfile::Private => None,
+            RpcProfile::Public => Some(RateLimitConfig {
+                max_requests: 50,
+                window_duration: Duration::from_secs(10),
+                ban_duration: Duration::from_secs(600),
+                max_violations: 3,
+                ..RateLimitConfig::default()
+            }),
+            // Historical queries come in bursts (explorers, indexers)
+            RpcProfile::Archive => Some(RateLimitConfig::default()),
+        }
+    }
+
+    /// Largest response served, in bytes (None = no cap)
+    pub fn max_response_size(&self) -> Option<usize> {
+        match self {
+            RpcProfile::Private => None,
+            RpcProfile::Public => Some(1024 * 1024),
+            RpcProfile::Archive => Some(16 * 1024 * 1024),
+        }
+    }
+}
+
+impl fmt::Display for RpcProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RpcProfile::Private => write!(f, "private"),
+            RpcProfile::Public => write!(f, "public"),
+            RpcProfile::Archive => write!(f, "archive"),
+        }
+    }
+}
+
+impl FromStr for RpcProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "private" => Ok(Self::Private),
+            "public" => Ok(Self::Public),
+            "archive" => Ok(Self::Archive),
+            other => Err(format!("Unknown RPC profile: {} (public, private, archive)", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_allowlists() {
+        assert!(RpcProfile::Private.allows("system_peers"));
+        assert!(RpcProfile::Public.allows("author_submitTransaction"));
+        assert!(!RpcProfile::Public.allows("system_peers"));
+        assert!(!RpcProfile::Public.allows("author_pendingTransactions"));
+        assert!(!RpcProfile::Public.allows("state_getBalanceAt"));
+        assert!(RpcProfile::Archive.allows("state_getBalanceAt"));
+        assert!(!RpcProfile::Archive.allows("debug_getStateRootForensics"));
+
+        assert!(RpcProfile::Private.rate_limit().is_none());
+        assert!(RpcProfile::Public.max_response_size() < RpcProfile::Archive.max_response_size());
+        assert_eq!("archive".parse::<RpcProfile>().unwrap(), RpcProfile::Archive);
+        assert!("open".parse::<RpcProfile>().is_err());
+    }
+}
@@ -0,0 +1,99 @@
+// Prometheus exporter - GET /metrics on --prometheus-port
+// The node renders the registry (node::metrics) when the endpoint is
+// scraped; the request goes through the RPC channel like every node query.
+
+use crate::rpc::server::{RpcCall, RpcSender, RpcServerError, RpcServerHandle};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use tokio::sync::oneshot;
+use tracing::info;
+use warp::Filter;
+
+/// Content type of the text exposition format
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// HTTP server exposing the node metrics
+pub struct PrometheusServer {
+    port: u16,
+    address: [u8; 4],
+}
+
+impl PrometheusServer {
+    pub fn new(port: u16, address: [u8; 4]) -> Self {
+        Self { port, address }
+    }
+
+    /// Get the socket address
+    pub fn socket_addr(&self) -> SocketAddr {
+        SocketAddr::from((self.address, self.port))
+    }
+
+    /// Start the exporter in background, returns shutdown handle
+    pub async fn start_background(self, rpc_tx: RpcSender) -> Result<RpcServerHandle, RpcServerError> {
+        let (tx, rx) = oneshot::channel::<()>();
+        let (bound_addr, server) = warp::serve(routes(rpc_tx))
+            .try_bind_with_graceful_shutdown(self.socket_addr(), async {
+                rx.await.ok();
+            })
+            .map_err(|e| RpcServerError::BindError(e.to_string()))?;
+
+        info!("Prometheus exporter ready on http://{}/metrics", bound_addr);
+        tokio::spawn(server);
+
+        Ok(RpcServerHandle::new(bound_addr, tx))
+    }
+}
+
+fn routes(rpc_tx: RpcSender) -> impl Filter<Extract = (impl warp::Reply,), Error = warp::Rejection> + Clone {
+    warp::path("metrics")
+        .and(warp::path::end())
+        .and(warp::get())
+        .and(warp::any().map(move || rpc_tx.clone()))
+        .and_then(handle_metrics)
+}
+
+async fn handle_metrics(rpc_tx: RpcSender) -> Result<impl warp::Reply, Infallible> {
+    let (tx, rx) = oneshot::channel();
+    let body = if rpc_tx.send(RpcCall::SystemMetrics(tx)).is_ok() {
+        rx.await.ok()
+    } else {
+        None
+    };
+
+    let reply = match body {
+        Some(body) => warp::reply::with_status(body, warp::http::StatusCode::OK),
+        None => warp::reply::with_status(
+            "node unavailable\n".to_string(),
+            warp::http::StatusCode::SERVICE_UNAVAILABLE,
+        ),
+    };
+    Ok(warp::reply::with_header(reply, "Content-Type", CONTENT_TYPE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn test_metrics_endpoint() {
+        let (rpc_tx, mut rpc_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Some(call) = rpc_rx.recv().await {
+                if let RpcCall::SystemMetrics(resp) = call {
+                    let _ = resp.send("kratos_block_height 7\n".to_string());
+                }
+            }
+        });
+
+        let route = routes(rpc_tx);
+
+        let response = warp::test::request().path("/metrics").reply(&route).await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.headers()["content-type"], CONTENT_TYPE);
+        assert_eq!(response.body(), "kratos_block_height 7\n");
+
+        let response = warp::test::request().path("/other").reply(&route).await;
+        assert_eq!(response.status(), 404);
+    }
+}
@@ -1,17 +1,36 @@
 // RPC Rate Limiting - SECURITY FIX #29
 // Prevents DoS attacks by limiting request rates per IP address
+// - Each IP has a budget of `max_requests` per window (`ip_limits` overrides
+//   it for given addresses); a call uses its method's cost, 1 by default, so
+//   historical queries exhaust the budget faster than health checks
+// - `max_violations` windows over the budget ban the IP for `ban_duration`
 
 use std::collections::HashMap;
 use std::net::IpAddr;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock as StdRwLock};
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 use tracing::warn;
 
+/// Cost of the methods heavier than a plain lookup
+const DEFAULT_METHOD_COSTS: &[(&str, u32)] = &[
+    ("state_getTransactionHistory", 10),
+    ("state_callAt", 10),
+    ("state_getBalanceAt", 5),
+    ("state_getStorageAt", 5),
+    ("state_getProof", 5),
+    ("account_getTransactions", 5),
+    ("chain_getEvents", 5),
+    ("validator_getAuthoredBlocks", 5),
+    ("grandpa_proveFinality", 5),
+    ("mempool_content", 5),
+    ("fee_history", 5),
+];
+
 /// Rate limiter configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RateLimitConfig {
-    /// Maximum requests per window
+    /// Request budget per window (each call uses its method's cost)
     pub max_requests: u32,
     /// Time window duration
     pub window_duration: Duration,
@@ -19,6 +38,38 @@ pub struct RateLimitConfig {
     pub ban_duration: Duration,
     /// Maximum violations before ban
     pub max_violations: u32,
+    /// Cost of a call per method: names or `prefix_*` patterns (others cost 1)
+    pub method_costs: HashMap<String, u32>,
+    /// Budget of given IPs instead of `max_requests`
+    pub ip_limits: HashMap<IpAddr, u32>,
+}
+
+impl RateLimitConfig {
+    /// Built-in method costs
+    pub fn default_method_costs() -> HashMap<String, u32> {
+        DEFAULT_METHOD_COSTS.iter().map(|(method, cost)| (method.to_string(), *cost)).collect()
+    }
+
+    /// Budget units used by a call to `method`
+    pub fn cost(&self, method: &str) -> u32 {
+        if let Some(cost) = self.method_costs.get(method) {
+            return *cost;
+        }
+        // Longest matching pattern
+        self.method_costs
+            .iter()
+            .filter_map(|(pattern, cost)| {
+                let prefix = pattern.strip_suffix('*')?;
+                method.starts_with(prefix).then_some((prefix.len(), *cost))
+            })
+            .max()
+            .map_or(1, |(_, cost)| cost)
+    }
+
+    /// Budget per window of `ip`
+    pub fn limit(&self, ip: &IpAddr) -> u32 {
+        self.ip_limits.get(ip).copied().unwrap_or(self.max_requests)
+    }
 }
 
 impl Default for RateLimitConfig {
@@ -28,6 +79,30 @@ impl Default for RateLimitConfig {
             window_duration: Duration::from_secs(10),
             ban_duration: Duration::from_secs(300), // 5 minutes
             max_violations: 3,
+            method_costs: Self::default_method_costs(),
+            ip_limits: HashMap::new(),
+        }
+    }
+}
+
+/// Why a call was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimited {
+    /// Budget of the window exhausted
+    Throttled { retry_after: u64 },
+    /// This call got the IP banned
+    BanStarted { retry_after: u64 },
+    /// IP already banned
+    Banned { retry_after: u64 },
+}
+
+impl RateLimited {
+    /// Seconds before the IP may call again
+    pub fn retry_after(&self) -> u64 {
+        match self {
+            RateLimited::Throttled { retry_after }
+            | RateLimited::BanStarted { retry_after }
+            | RateLimited::Banned { retry_after } => *retry_after,
         }
     }
 }
@@ -59,22 +134,40 @@ impl RateLimitEntry {
 /// Thread-safe rate limiter
 #[derive(Clone)]
 pub struct RpcRateLimiter {
-    config: RateLimitConfig,
+    /// Limits, shared by the clones (None = every request allowed)
+    config: Arc<StdRwLock<Option<RateLimitConfig>>>,
     entries: Arc<RwLock<HashMap<IpAddr, RateLimitEntry>>>,
 }
 
 impl RpcRateLimiter {
     /// Create new rate limiter
     pub fn new(config: RateLimitConfig) -> Self {
+        Self::with_config(Some(config))
+    }
+
+    /// Create a rate limiter, disabled while `config` is None
+    pub fn with_config(config: Option<RateLimitConfig>) -> Self {
         Self {
-            config,
+            config: Arc::new(StdRwLock::new(config)),
             entries: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
-    /// Check if request is allowed for IP address
-    /// Returns Ok(()) if allowed, Err(remaining_seconds) if rate limited
-    pub async fn check_rate_limit(&self, ip: IpAddr) -> Result<(), u64> {
+    /// Current limits
+    pub fn config(&self) -> Option<RateLimitConfig> {
+        self.config.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replace the limits of every clone (None disables limiting, bans included)
+    pub fn set_config(&self, config: Option<RateLimitConfig>) {
+        *self.config.write().unwrap_or_else(|e| e.into_inner()) = config;
+    }
+
+    /// Check if a call to `method` is allowed for IP address
+    pub async fn check_rate_limit(&self, ip: IpAddr, method: &str) -> Result<(), RateLimited> {
+        let Some(config) = self.config() else {
+            return Ok(());
+        };
         let mut entries = self.entries.write().await;
         let now = Instant::now();
 
@@ -84,7 +177,7 @@ impl RpcRateLimiter {
         if let Some(banned_until) = entry.banned_until {
             if now < banned_until {
                 let remaining = banned_until.duration_since(now).as_secs();
-                return Err(remaining);
+                return Err(RateLimited::Banned { retry_after: remaining.max(1) });
             } else {
                 // Ban expired, reset
                 entry.banned_until = None;
@@ -93,17 +186,17 @@ impl RpcRateLimiter {
         }
 
         // Check if window expired
-        if now.duration_since(entry.window_start) >= self.config.window_duration {
+        if now.duration_since(entry.window_start) >= config.window_duration {
             // Reset window
             entry.request_count = 0;
             entry.window_start = now;
         }
 
-        // Increment counter
-        entry.request_count += 1;
+        // Charge the call
+        entry.request_count = entry.request_count.saturating_add(config.cost(method));
 
         // Check limit
-        if entry.request_count > self.config.max_requests {
+        if entry.request_count > config.limit(&ip) {
             entry.violations += 1;
             warn!(
                 "Rate limit exceeded for IP {}: {} requests in window (violation #{})",
@@ -111,22 +204,22 @@ impl RpcRateLimiter {
             );
 
             // Ban if too many violations
-            if entry.violations >= self.config.max_violations {
-                entry.banned_until = Some(now + self.config.ban_duration);
-                warn!("IP {} banned for {} seconds", ip, self.config.ban_duration.as_secs());
-                return Err(self.config.ban_duration.as_secs());
+            if entry.violations >= config.max_violations {
+                entry.banned_until = Some(now + config.ban_duration);
+                warn!("IP {} banned for {} seconds", ip, config.ban_duration.as_secs());
+                return Err(RateLimited::BanStarted { retry_after: config.ban_duration.as_secs() });
             }
 
             // Return remaining time in current window
-            let window_remaining = self.config.window_duration
+            let window_remaining = config.window_duration
                 .saturating_sub(now.duration_since(entry.window_start));
-            return Err(window_remaining.as_secs().max(1));
+            return Err(RateLimited::Throttled { retry_after: window_remaining.as_secs().max(1) });
         }
 
         Ok(())
     }
 
-    /// Get current stats for an IP
+    /// Get current stats for an IP (budget used in the window, banned)
     pub async fn get_stats(&self, ip: &IpAddr) -> Option<(u32, bool)> {
         let entries = self.entries.read().await;
         entries.get(ip).map(|e| {
@@ -138,8 +231,12 @@ impl RpcRateLimiter {
     /// Cleanup old entries (call periodically)
     pub async fn cleanup(&self) {
         let mut entries = self.entries.write().await;
+        let Some(config) = self.config() else {
+            entries.clear();
+            return;
+        };
         let now = Instant::now();
-        let max_age = self.config.window_duration * 10; // Keep entries for 10 windows
+        let max_age = config.window_duration * 10; // Keep entries for 10 windows
 
         entries.retain(|_, entry| {
             // Keep if recently active or still banned
@@ -168,13 +265,14 @@ mod tests {
             window_duration: Duration::from_secs(1),
             ban_duration: Duration::from_secs(60),
             max_violations: 3,
+            ..RateLimitConfig::default()
         };
         let limiter = RpcRateLimiter::new(config);
         let ip = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
 
         // Should allow 10 requests
         for _ in 0..10 {
-            assert!(limiter.check_rate_limit(ip).await.is_ok());
+            assert!(limiter.check_rate_limit(ip, "system_health").await.is_ok());
         }
     }
 
@@ -185,17 +283,18 @@ mod tests {
             window_duration: Duration::from_secs(10),
             ban_duration: Duration::from_secs(60),
             max_violations: 3,
+            ..RateLimitConfig::default()
         };
         let limiter = RpcRateLimiter::new(config);
         let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
 
         // First 5 should pass
         for _ in 0..5 {
-            assert!(limiter.check_rate_limit(ip).await.is_ok());
+            assert!(limiter.check_rate_limit(ip, "system_health").await.is_ok());
         }
 
         // 6th should fail
-        assert!(limiter.check_rate_limit(ip).await.is_err());
+        assert!(limiter.check_rate_limit(ip, "system_health").await.is_err());
     }
 
     #[tokio::test]
@@ -205,6 +304,7 @@ mod tests {
             window_duration: Duration::from_secs(10),
             ban_duration: Duration::from_secs(60),
             max_violations: 3,
+            ..RateLimitConfig::default()
         };
         let limiter = RpcRateLimiter::new(config);
         let ip1 = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
@@ -212,10 +312,74 @@ mod tests {
 
         // Exhaust limit for ip1
         for _ in 0..6 {
-            let _ = limiter.check_rate_limit(ip1).await;
+            let _ = limiter.check_rate_limit(ip1, "system_health").await;
         }
 
         // ip2 should still be allowed
-        assert!(limiter.check_rate_limit(ip2).await.is_ok());
+        assert!(limiter.check_rate_limit(ip2, "system_health").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reconfigure_limits() {
+        let limiter = RpcRateLimiter::with_config(None);
+        let server_copy = limiter.clone();
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        for _ in 0..20 {
+            assert!(server_copy.check_rate_limit(ip, "system_health").await.is_ok());
+        }
+
+        // New limits apply to every clone, from the next request
+        limiter.set_config(Some(RateLimitConfig {
+            max_requests: 2,
+            ..RateLimitConfig::default()
+        }));
+        assert!(server_copy.check_rate_limit(ip, "system_health").await.is_ok());
+        assert!(server_copy.check_rate_limit(ip, "system_health").await.is_ok());
+        assert!(server_copy.check_rate_limit(ip, "system_health").await.is_err());
+
+        limiter.set_config(None);
+        assert!(server_copy.check_rate_limit(ip, "system_health").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_method_costs_and_ip_limits() {
+        let trusted = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        let config = RateLimitConfig {
+            max_requests: 20,
+            method_costs: HashMap::from([("state_getTransactionHistory".to_string(), 10), ("debug_*".to_string(), 4)]),
+            ip_limits: HashMap::from([(trusted, 1000)]),
+            ..RateLimitConfig::default()
+        };
+        assert_eq!(config.cost("system_health"), 1);
+        assert_eq!(config.cost("debug_getStateRootForensics"), 4);
+
+        let limiter = RpcRateLimiter::new(config);
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        assert!(limiter.check_rate_limit(ip, "state_getTransactionHistory").await.is_ok());
+        assert!(limiter.check_rate_limit(ip, "state_getTransactionHistory").await.is_ok());
+        assert!(matches!(
+            limiter.check_rate_limit(ip, "system_health").await,
+            Err(RateLimited::Throttled { .. })
+        ));
+        for _ in 0..50 {
+            assert!(limiter.check_rate_limit(trusted, "state_getTransactionHistory").await.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ban_after_violations() {
+        let limiter = RpcRateLimiter::new(RateLimitConfig {
+            max_requests: 1,
+            max_violations: 2,
+            ..RateLimitConfig::default()
+        });
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 3));
+        assert!(limiter.check_rate_limit(ip, "system_health").await.is_ok());
+        assert!(matches!(limiter.check_rate_limit(ip, "system_health").await, Err(RateLimited::Throttled { .. })));
+        assert_eq!(
+            limiter.check_rate_limit(ip, "system_health").await,
+            Err(RateLimited::BanStarted { retry_after: 300 })
+        );
+        assert!(matches!(limiter.check_rate_limit(ip, "system_health").await, Err(RateLimited::Banned { .. })));
     }
 }
@@ -4,20 +4,43 @@
 // with warp handlers. Instead, we use a channel-based approach where requests
 // are sent to the node's async context for processing.
 
+use crate::consensus::finality::StallStatus;
+use crate::consensus::validator_credits::VC_HISTORY_EPOCHS;
+use crate::contracts::governance::ProposalId;
+use crate::contracts::messaging::MAX_INBOX_MESSAGES;
+use crate::node::forensics::StateRootForensics;
+use crate::node::invariants::{InvariantReport, SecurityTransition, TransitionNotifier};
+use crate::node::metrics::{NodeMetrics, UNKNOWN_METHOD};
+use crate::node::service::NodeError;
+use crate::storage::cache::StateCacheStats;
+use crate::rpc::auth::{RpcAuth, RpcToken};
+use crate::rpc::batch::{self, Entry, ResponseBudget, RpcBody};
+use crate::rpc::cors::{self, CorsOrigins};
+use crate::rpc::events::{self, DropNotifier};
+use crate::rpc::hosts::{self, AllowedHosts};
+use crate::rpc::profile::RpcProfile;
 use crate::rpc::rate_limit::{RateLimitConfig, RpcRateLimiter};
 use crate::rpc::types::{
     BlockInfo, BlockWithTransactions, ChainInfo, HealthStatus, JsonRpcError, JsonRpcId,
-    JsonRpcRequest, JsonRpcResponse, MempoolStats, MempoolStatus, NetworkStatus, SyncStatus,
-    SystemInfo, TransactionSubmitResult, AccountInfoRpc, parse_account_id, parse_hash,
+    JsonRpcRequest, JsonRpcResponse, MempoolContent, MempoolStats, MempoolStatus, NetworkStatus, SyncStatus,
+    MortalityRpc, ForksRpc, ReorgEventsRpc, SystemChainRpc, SystemInfo, TransactionReceipt, TransactionSubmitResult, IdempotentSubmitResult, AccountInfoRpc, ValidatorCreditsRpc, VcHistoryRpc, EpochInfoRpc,
+    ValidatorInfoRpc, HistoricalCall, StateProofRpc, FinalityProofRpc, FeeHistoryRpc, PendingEvidenceRpc, DelegationsRpc, DelegationPoolRpc, UnbondingRpc, PendingRewardsRpc, AccountMessageRpc, EmergencyStatusRpc, IdentityRpc, ParameterChangeRecordRpc, PersonhoodStatusRpc, ProposalRpc, ProtocolParametersRpc, ReputationRpc, RotateKeysRpc, TreasuryRpc, UpgradeStatusRpc, AccountTransactionsQuery, AccountTransactionsRpc, EventsRpc, AuthoredBlocksRpc, DEFAULT_INBOX_MESSAGES, DEFAULT_REPUTATION_TOP, MAX_FEE_HISTORY_BLOCKS, MAX_REPUTATION_TOP, parse_account_id, parse_at_params, parse_call_at_params, parse_account_transactions_params, parse_hash, parse_page_params,
+    parse_reputation_domain, parse_storage_key, parse_ban_peer_params, parse_peer_id, SystemPeersRpc,
 };
+use crate::network::peer::PeerBan;
 use crate::types::*;
 use std::convert::Infallible;
+use std::future::Future;
 use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::{mpsc, oneshot, RwLock};
 use tracing::{debug, info, warn};
-use warp::Filter;
+use warp::http::StatusCode;
+use warp::hyper::body::Bytes;
+use warp::{Filter, Reply};
 
 // =============================================================================
 // RPC REQUEST/RESPONSE TYPES FOR CHANNEL
@@ -28,23 +51,94 @@ pub enum RpcCall {
     ChainGetInfo(oneshot::Sender<Result<ChainInfo, String>>),
     ChainGetBlock(BlockNumber, oneshot::Sender<Result<BlockWithTransactions, String>>),
     ChainGetLatestBlock(oneshot::Sender<Result<BlockWithTransactions, String>>),
+    ChainGetTransactionReceipt(Hash, oneshot::Sender<Result<Option<TransactionReceipt>, String>>),
+    ChainGetEpochInfo(oneshot::Sender<EpochInfoRpc>),
+    ChainGetMortality(oneshot::Sender<MortalityRpc>),
+    ChainGetForks(oneshot::Sender<Result<ForksRpc, String>>),
+    ChainGetReorgEvents(u64, oneshot::Sender<Result<ReorgEventsRpc, String>>),
     StateGetBalance(AccountId, oneshot::Sender<Result<Balance, String>>),
     StateGetAccount(AccountId, oneshot::Sender<Result<AccountInfoRpc, String>>),
     SystemHealth(oneshot::Sender<HealthStatus>),
     SystemInfo(oneshot::Sender<Result<SystemInfo, String>>),
-    SystemPeers(oneshot::Sender<(usize, Vec<String>)>),
+    SystemChain(oneshot::Sender<SystemChainRpc>),
+    SystemPeers(oneshot::Sender<SystemPeersRpc>),
     SyncState(oneshot::Sender<SyncStatus>),
     MempoolStatus(oneshot::Sender<MempoolStatus>),
-    SubmitTransaction(SignedTransaction, oneshot::Sender<Result<Hash, String>>),
+    MempoolContent(oneshot::Sender<MempoolContent>),
+    // Fee market: last N blocks
+    FeeHistory(u64, oneshot::Sender<Result<FeeHistoryRpc, String>>),
+    // NodeError kept whole: a mempool refusal is detailed in the JSON-RPC error
+    SubmitTransaction(Box<SignedTransaction>, oneshot::Sender<Result<Hash, NodeError>>),
+    SubmitTransactionIdempotent(Box<SignedTransaction>, String, oneshot::Sender<Result<(Hash, bool), NodeError>>),
     GetVersion(oneshot::Sender<String>),
+    SystemInvariants(oneshot::Sender<InvariantReport>),
+    SystemSecurityStateHistory(oneshot::Sender<Vec<SecurityTransition>>),
+    SystemStateCache(oneshot::Sender<StateCacheStats>),
+    // Prometheus text format (/metrics)
+    SystemMetrics(oneshot::Sender<String>),
     // State queries
     StateGetNonce(AccountId, oneshot::Sender<Result<u64, String>>),
     StateGetTransactionHistory(AccountId, u32, u32, oneshot::Sender<Result<serde_json::Value, String>>),
+    // Historical state (archive mode, None = unknown block)
+    StateGetAccountAt(AccountId, Hash, oneshot::Sender<Result<Option<AccountInfoRpc>, String>>),
+    StateGetStorageAt(Vec<u8>, Hash, oneshot::Sender<Result<Option<Option<Vec<u8>>>, String>>),
+    StateGetProof(AccountId, Hash, oneshot::Sender<Result<Option<Option<StateProofRpc>>, String>>),
+    AccountNextNonce(AccountId, oneshot::Sender<Result<u64, String>>),
+    // Indexed queries (--index): value, then query or limit and offset
+    AccountGetTransactions(AccountId, AccountTransactionsQuery, oneshot::Sender<Result<AccountTransactionsRpc, String>>),
+    ChainGetEvents(String, usize, usize, oneshot::Sender<Result<EventsRpc, String>>),
+    ValidatorGetAuthoredBlocks(AccountId, usize, usize, oneshot::Sender<Result<AuthoredBlocksRpc, String>>),
     // Early Validator Voting methods (Bootstrap Era)
     ValidatorGetEarlyVotingStatus(oneshot::Sender<Result<serde_json::Value, String>>),
     ValidatorGetPendingCandidates(oneshot::Sender<Result<serde_json::Value, String>>),
     ValidatorGetCandidateVotes(AccountId, oneshot::Sender<Result<serde_json::Value, String>>),
     ValidatorCanVote(AccountId, oneshot::Sender<Result<serde_json::Value, String>>),
+    // Validator operations (None = not a validator)
+    ValidatorGetInfo(AccountId, oneshot::Sender<Option<ValidatorInfoRpc>>),
+    ValidatorGetCredits(AccountId, oneshot::Sender<Option<ValidatorCreditsRpc>>),
+    ValidatorCreditsHistory(AccountId, usize, oneshot::Sender<Option<VcHistoryRpc>>),
+    // Finality
+    FinalityGetStatus(oneshot::Sender<serde_json::Value>),
+    // None = finality gadget not running
+    FinalityGetStallStatus(oneshot::Sender<Option<StallStatus>>),
+    GrandpaProveFinality(BlockNumber, oneshot::Sender<Result<Option<FinalityProofRpc>, String>>),
+    // Staking
+    StakingGetDelegations(AccountId, oneshot::Sender<Result<DelegationsRpc, String>>),
+    StakingGetDelegators(AccountId, oneshot::Sender<Result<DelegationPoolRpc, String>>),
+    StakingGetUnbonding(AccountId, oneshot::Sender<Result<UnbondingRpc, String>>),
+    StakingPendingRewards(AccountId, oneshot::Sender<Result<PendingRewardsRpc, String>>),
+    // Governance: one proposal or all, newest first
+    GovernanceProposals(Option<ProposalId>, oneshot::Sender<Result<Vec<ProposalRpc>, String>>),
+    // Treasury
+    TreasuryBalance(oneshot::Sender<Result<TreasuryRpc, String>>),
+    TreasuryProposals(oneshot::Sender<Result<Vec<ProposalRpc>, String>>),
+    // Protocol parameters
+    GovernanceParameters(oneshot::Sender<Result<ProtocolParametersRpc, String>>),
+    GovernanceParameterLog(oneshot::Sender<Result<Vec<ParameterChangeRecordRpc>, String>>),
+    UpgradeGetStatus(oneshot::Sender<Result<UpgradeStatusRpc, String>>),
+    // Identity (None = never registered)
+    IdentityGet(AccountId, oneshot::Sender<Result<Option<IdentityRpc>, String>>),
+    // Personhood
+    PersonhoodGetStatus(AccountId, oneshot::Sender<Result<PersonhoodStatusRpc, String>>),
+    // Messaging (newest first)
+    MessagingGetInbox(AccountId, usize, oneshot::Sender<Result<Vec<AccountMessageRpc>, String>>),
+    // Reputation (None = no reputation yet)
+    ReputationGet(AccountId, oneshot::Sender<Result<Option<ReputationRpc>, String>>),
+    ReputationTop(usize, Option<ReputationDomain>, oneshot::Sender<Result<Vec<ReputationRpc>, String>>),
+    // Slashing
+    SlashingPendingEvidence(oneshot::Sender<Vec<PendingEvidenceRpc>>),
+    // Emergency
+    EmergencyGetStatus(oneshot::Sender<Result<EmergencyStatusRpc, String>>),
+    // Unsafe (None = no bundle)
+    DebugGetStateRootForensics(Option<BlockNumber>, oneshot::Sender<Result<Option<StateRootForensics>, String>>),
+    // Unsafe: re-read the configuration (Ok = settings changed)
+    AdminReloadConfig(oneshot::Sender<Result<Vec<String>, String>>),
+    // Unsafe: generate session keys and submit their binding
+    AuthorRotateKeys(oneshot::Sender<Result<RotateKeysRpc, String>>),
+    // Unsafe: peer, reason, seconds
+    SystemBanPeer(libp2p::PeerId, String, u64, oneshot::Sender<PeerBan>),
+    // Unsafe: true if the peer was banned
+    SystemUnbanPeer(libp2p::PeerId, oneshot::Sender<bool>),
 }
 
 /// Channel sender for RPC calls
@@ -61,6 +155,14 @@ pub struct RpcState {
     pub tx: RpcSender,
     /// SECURITY FIX #29: Rate limiter for DoS protection
     pub rate_limiter: Option<RpcRateLimiter>,
+    /// Serve the methods of UNSAFE_METHODS (--rpc-methods-unsafe)
+    pub unsafe_methods: bool,
+    /// Request latencies are recorded here when set
+    pub metrics: Option<Arc<NodeMetrics>>,
+    /// Methods and response sizes served (--rpc-profile)
+    pub profile: RpcProfile,
+    /// Tokens for the unsafe methods (none: --rpc-methods-unsafe decides)
+    pub auth: RpcAuth,
 }
 
 impl RpcState {
@@ -68,6 +170,10 @@ impl RpcState {
         Self {
             tx,
             rate_limiter: None,
+            unsafe_methods: false,
+            metrics: None,
+            profile: RpcProfile::Private,
+            auth: RpcAuth::default(),
         }
     }
 
@@ -76,10 +182,42 @@ impl RpcState {
         Self {
             tx,
             rate_limiter: Some(RpcRateLimiter::new(config)),
+            unsafe_methods: false,
+            metrics: None,
+            profile: RpcProfile::Private,
+            auth: RpcAuth::default(),
+        }
+    }
+
+    /// Whether the caller may call the unsafe `method`
+    ///
+    /// With tokens configured, only a token allowing it does; otherwise
+    /// --rpc-methods-unsafe decides for every caller.
+    fn authorize_unsafe(&self, method: &str, authorization: Option<&str>) -> Result<(), JsonRpcError> {
+        if self.auth.is_enabled() {
+            let name = self.auth.authorize(authorization, method).map_err(|e| {
+                warn!("Unauthorized call to {}: {}", method, e);
+                JsonRpcError::unauthorized(method, &e.to_string())
+            })?;
+            debug!("Unsafe method {} called with token '{}'", method, name);
+            Ok(())
+        } else if self.unsafe_methods {
+            Ok(())
+        } else {
+            Err(JsonRpcError::unsafe_method(method))
         }
     }
 }
 
+/// Methods exposing node internals, refused unless explicitly enabled
+pub const UNSAFE_METHODS: &[&str] = &[
+    "debug_getStateRootForensics",
+    "admin_reloadConfig",
+    "author_rotateKeys",
+    "system_banPeer",
+    "system_unbanPeer",
+];
+
 // =============================================================================
 // RPC SERVER
 // =============================================================================
@@ -91,7 +229,25 @@ pub struct RpcServer {
     /// Listen address
     address: [u8; 4],
     /// SECURITY FIX #3: Allowed CORS origins (empty = localhost only)
-    allowed_origins: Vec<String>,
+    cors: CorsOrigins,
+    /// Allowed Host headers (empty = localhost only)
+    allowed_hosts: AllowedHosts,
+    /// Serve unsafe methods
+    unsafe_methods: bool,
+    /// Request latency metrics
+    metrics: Option<Arc<NodeMetrics>>,
+    /// Per-IP rate limiting of the background server (None = unlimited)
+    rate_limiter: Option<RpcRateLimiter>,
+    /// Exposure profile
+    profile: RpcProfile,
+    /// Tokens for the unsafe methods
+    auth: RpcAuth,
+    /// Serve HTTPS with this certificate (None = plain HTTP)
+    tls: Option<RpcTls>,
+    /// Mempool drops streamed at /events/dropped (None = not served)
+    drops: Option<DropNotifier>,
+    /// Security state transitions streamed at /events/security (None = not served)
+    transitions: Option<TransitionNotifier>,
 }
 
 impl RpcServer {
@@ -100,7 +256,16 @@ impl RpcServer {
         Self {
             port,
             address: [127, 0, 0, 1], // Default: localhost only
-            allowed_origins: vec![], // SECURITY: No external origins by default
+            cors: CorsOrigins::default(), // SECURITY: No external origins by default
+            allowed_hosts: AllowedHosts::default(),
+            unsafe_methods: false,
+            metrics: None,
+            rate_limiter: None,
+            profile: RpcProfile::Private,
+            auth: RpcAuth::default(),
+            tls: None,
+            drops: None,
+            transitions: None,
         }
     }
 
@@ -109,7 +274,16 @@ impl RpcServer {
         Self {
             port,
             address,
-            allowed_origins: vec![],
+            cors: CorsOrigins::default(),
+            allowed_hosts: AllowedHosts::default(),
+            unsafe_methods: false,
+            metrics: None,
+            rate_limiter: None,
+            profile: RpcProfile::Private,
+            auth: RpcAuth::default(),
+            tls: None,
+            drops: None,
+            transitions: None,
         }
     }
 
@@ -119,40 +293,101 @@ impl RpcServer {
         Self {
             port,
             address,
-            allowed_origins,
+            cors: CorsOrigins::new(allowed_origins),
+            allowed_hosts: AllowedHosts::default(),
+            unsafe_methods: false,
+            metrics: None,
+            rate_limiter: None,
+            profile: RpcProfile::Private,
+            auth: RpcAuth::default(),
+            tls: None,
+            drops: None,
+            transitions: None,
         }
     }
 
+    /// Serve the unsafe methods (UNSAFE_METHODS), e.g. for node operators debugging a fork
+    pub fn with_unsafe_methods(mut self, enabled: bool) -> Self {
+        self.unsafe_methods = enabled;
+        self
+    }
+
+    /// Record request latencies in the node metrics
+    pub fn with_metrics(mut self, metrics: Arc<NodeMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Rate limit the background server per client IP (the limits of
+    /// `limiter` can be changed while the server runs)
+    pub fn with_rate_limiter(mut self, limiter: RpcRateLimiter) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Allowed CORS origins, shared with the caller to replace them while serving
+    pub fn with_cors(mut self, cors: CorsOrigins) -> Self {
+        self.cors = cors;
+        self
+    }
+
+    /// Allowed Host headers, shared with the caller to replace them while serving
+    pub fn with_allowed_hosts(mut self, hosts: AllowedHosts) -> Self {
+        self.allowed_hosts = hosts;
+        self
+    }
+
+    /// Serve only the methods and response sizes of `profile`
+    pub fn with_profile(mut self, profile: RpcProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Require a token for the unsafe methods (shared with the config reloader)
+    pub fn with_auth(mut self, auth: RpcAuth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Serve HTTPS with a certificate and key (None = plain HTTP)
+    pub fn with_tls(mut self, tls: Option<RpcTls>) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    /// Stream the transactions dropped by the mempool (TransactionPool::drop_notifier)
+    pub fn with_drop_notifier(mut self, drops: DropNotifier) -> Self {
+        self.drops = Some(drops);
+        self
+    }
+
+    /// Stream the security state transitions (InvariantMonitor::transition_notifier)
+    pub fn with_transition_notifier(mut self, transitions: TransitionNotifier) -> Self {
+        self.transitions = Some(transitions);
+        self
+    }
+
     /// Get the socket address
     pub fn socket_addr(&self) -> SocketAddr {
         SocketAddr::from((self.address, self.port))
     }
 
-    /// SECURITY FIX #3: Build a secure CORS filter
+    /// SECURITY FIX #3: Secure CORS policy
     /// Only allows specified origins, or localhost-only if none specified
-    fn build_cors_filter(&self) -> warp::cors::Builder {
-        let mut cors = warp::cors()
-            .allow_methods(vec!["GET", "POST", "OPTIONS"])
-            .allow_headers(vec!["Content-Type", "Accept"]);
-
-        if self.allowed_origins.is_empty() {
-            // SECURITY: When no origins specified, only allow localhost
-            // This prevents CSRF attacks from malicious websites
-            cors = cors
-                .allow_origin("http://localhost")
-                .allow_origin("http://127.0.0.1")
-                .allow_origin("http://localhost:3000") // Common dev frontend port
-                .allow_origin("http://127.0.0.1:3000");
-            info!("CORS: Restricted to localhost only");
-        } else {
-            // Explicitly specified origins
-            for origin in &self.allowed_origins {
-                cors = cors.allow_origin(origin.as_str());
-            }
-            info!("CORS: Allowed origins: {:?}", self.allowed_origins);
-        }
+    /// (prevents CSRF attacks from malicious websites)
+    fn cors_policy(&self) -> CorsOrigins {
+        info!("CORS: Allowed origins: {}", self.cors.describe());
+        self.cors.clone()
+    }
 
-        cors
+    fn scheme(&self) -> &'static str {
+        if self.tls.is_some() { "https" } else { "http" }
+    }
+
+    /// DNS rebinding protection: Host headers served
+    fn host_policy(&self) -> AllowedHosts {
+        info!("RPC allowed hosts: {}", self.allowed_hosts.describe());
+        self.allowed_hosts.clone()
     }
 
     /// Start the server (blocking) with an RPC channel
@@ -167,15 +402,21 @@ impl RpcServer {
             window_duration: Duration::from_secs(10),
             ban_duration: Duration::from_secs(300),
             max_violations: 3,
+            ..RateLimitConfig::default()
         };
-        let state = RpcState::with_rate_limiter(rpc_tx, rate_limit_config);
+        let mut state = RpcState::with_rate_limiter(rpc_tx, rate_limit_config);
+        state.unsafe_methods = self.unsafe_methods;
+        state.metrics = self.metrics.clone();
+        state.profile = self.profile;
+        state.auth = self.auth.clone();
         info!("Rate limiting enabled: 100 req/10s");
 
         // JSON-RPC endpoint with rate limiting
         let rpc = warp::path::end()
             .and(warp::post())
             .and(warp::addr::remote())
-            .and(warp::body::json())
+            .and(warp::header::optional::<String>("authorization"))
+            .and(warp::body::bytes())
             .and(with_state(state.clone()))
             .and_then(handle_rpc_request_with_rate_limit);
 
@@ -185,15 +426,20 @@ impl RpcServer {
             .and(with_state(state.clone()))
             .and_then(handle_health_check);
 
-        // SECURITY FIX #3: Build secure CORS configuration
-        let cors = self.build_cors_filter();
+        // Dropped transactions and security transitions streams
+        let dropped = events::dropped_route(self.drops.clone());
+        let security = events::security_route(self.transitions.clone());
+
+        // SECURITY FIX #3: Secure CORS configuration
+        let cors = self.cors_policy();
 
         // Combine routes
-        let routes = rpc.or(health).with(cors).with(warp::log("rpc"));
+        let routes = hosts::wrap(cors::wrap(rpc.or(health).or(dropped).or(security), cors), self.host_policy()).with(warp::log("rpc"));
 
         // Start server
-        info!("RPC server ready on http://{}", addr);
-        warp::serve(routes).run(addr).await;
+        let (bound_addr, server) = bind(routes, addr, self.tls.as_ref(), std::future::pending())?;
+        info!("RPC server ready on {}://{}", self.scheme(), bound_addr);
+        server.await;
 
         Ok(())
     }
@@ -203,17 +449,25 @@ impl RpcServer {
         let addr = self.socket_addr();
         info!("Starting RPC server on {} (background)", addr);
 
-        // SECURITY FIX #3: Build secure CORS configuration before moving self
-        let cors = self.build_cors_filter();
+        // SECURITY FIX #3: Secure CORS configuration
+        let cors = self.cors_policy();
+        let allowed_hosts = self.host_policy();
 
-        let state = RpcState::new(rpc_tx);
+        let mut state = RpcState::new(rpc_tx);
+        state.rate_limiter = self.rate_limiter.clone();
+        state.unsafe_methods = self.unsafe_methods;
+        state.metrics = self.metrics.clone();
+        state.profile = self.profile;
+        state.auth = self.auth.clone();
 
-        // JSON-RPC endpoint
+        // JSON-RPC endpoint (the limiter checks the state, unlimited without one)
         let rpc = warp::path::end()
             .and(warp::post())
-            .and(warp::body::json())
+            .and(warp::addr::remote())
+            .and(warp::header::optional::<String>("authorization"))
+            .and(warp::body::bytes())
             .and(with_state(state.clone()))
-            .and_then(handle_rpc_request);
+            .and_then(handle_rpc_request_with_rate_limit);
 
         // Health check endpoint
         let health = warp::path("health")
@@ -221,18 +475,22 @@ impl RpcServer {
             .and(with_state(state.clone()))
             .and_then(handle_health_check);
 
+        // Dropped transactions and security transitions streams
+        let dropped = events::dropped_route(self.drops.clone());
+        let security = events::security_route(self.transitions.clone());
+
         // Combine routes
-        let routes = rpc.or(health).with(cors);
+        let routes = hosts::wrap(cors::wrap(rpc.or(health).or(dropped).or(security), cors), allowed_hosts);
 
         // Create shutdown channel
         let (tx, rx) = oneshot::channel::<()>();
 
         // Start server with graceful shutdown
-        let (bound_addr, server) = warp::serve(routes).bind_with_graceful_shutdown(addr, async {
+        let (bound_addr, server) = bind(routes, addr, self.tls.as_ref(), async {
             rx.await.ok();
-        });
+        })?;
 
-        info!("RPC server ready on http://{}", bound_addr);
+        info!("RPC server ready on {}://{}", self.scheme(), bound_addr);
 
         // Spawn server task
         tokio::spawn(server);
@@ -244,6 +502,43 @@ impl RpcServer {
     }
 }
 
+/// Server task, completed once the shutdown signal is received
+type ServerFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Listen on `addr`, over TLS with `tls`, until `signal` completes
+fn bind<F, R>(
+    routes: F,
+    addr: SocketAddr,
+    tls: Option<&RpcTls>,
+    signal: impl Future<Output = ()> + Send + 'static,
+) -> Result<(SocketAddr, ServerFuture), RpcServerError>
+where
+    F: Filter<Extract = (R,), Error = warp::Rejection> + Clone + Send + Sync + 'static,
+    R: Reply,
+{
+    match tls {
+        Some(tls) => {
+            let read = |path: &Path| {
+                std::fs::read(path).map_err(|e| RpcServerError::TlsError(format!("{}: {}", path.display(), e)))
+            };
+            let (cert, key) = (read(&tls.cert_path)?, read(&tls.key_path)?);
+            let (bound_addr, server) = warp::serve(routes)
+                .tls()
+                .cert(cert)
+                .key(key)
+                .try_bind_with_graceful_shutdown(addr, signal)
+                .map_err(|e| RpcServerError::TlsError(e.to_string()))?;
+            Ok((bound_addr, Box::pin(server)))
+        }
+        None => {
+            let (bound_addr, server) = warp::serve(routes)
+                .try_bind_with_graceful_shutdown(addr, signal)
+                .map_err(|e| RpcServerError::BindError(e.to_string()))?;
+            Ok((bound_addr, Box::pin(server)))
+        }
+    }
+}
+
 /// Handle for a running RPC server
 pub struct RpcServerHandle {
     /// Server address
@@ -253,6 +548,13 @@ pub struct RpcServerHandle {
 }
 
 impl RpcServerHandle {
+    pub(crate) fn new(addr: SocketAddr, shutdown_tx: oneshot::Sender<()>) -> Self {
+        Self {
+            addr,
+            shutdown_tx: Some(shutdown_tx),
+        }
+    }
+
     /// Shutdown the server
     pub fn shutdown(mut self) {
         if let Some(tx) = self.shutdown_tx.take() {
@@ -286,23 +588,69 @@ fn with_state(
 }
 
 /// SECURITY FIX #29: Handle RPC request with rate limiting
+///
+/// The body is a call or a batch of calls (see `batch`); every call counts
+/// against the rate limit.
 async fn handle_rpc_request_with_rate_limit(
     remote_addr: Option<SocketAddr>,
-    request: JsonRpcRequest,
+    authorization: Option<String>,
+    body: Bytes,
     state: RpcState,
-) -> Result<impl warp::Reply, Infallible> {
+) -> Result<warp::reply::Response, Infallible> {
     // Extract client IP for rate limiting
     let client_ip = remote_addr.map(|addr| addr.ip()).unwrap_or(IpAddr::V4(std::net::Ipv4Addr::LOCALHOST));
 
+    let reply = match batch::parse_body(&body) {
+        RpcBody::Single(entry) => match handle_entry(entry, client_ip, authorization.as_deref(), &state).await {
+            Some(response) => warp::reply::json(&cap_response(response, state.profile)).into_response(),
+            None => StatusCode::NO_CONTENT.into_response(),
+        },
+        RpcBody::Batch(entries) => {
+            debug!("RPC batch of {} calls from {}", entries.len(), client_ip);
+            // One call at a time: see `batch`
+            let mut budget = ResponseBudget::new(state.profile.max_response_size());
+            let mut responses = Vec::with_capacity(entries.len());
+            for entry in entries {
+                if let Some(response) = handle_entry(entry, client_ip, authorization.as_deref(), &state).await {
+                    responses.push(budget.charge(response));
+                }
+            }
+            if responses.is_empty() {
+                StatusCode::NO_CONTENT.into_response()
+            } else {
+                warp::reply::json(&responses).into_response()
+            }
+        }
+        RpcBody::Invalid(response) => warp::reply::json(&response).into_response(),
+    };
+    Ok(reply)
+}
+
+/// Execute one call of a body (None: nothing to answer)
+async fn handle_entry(
+    entry: Entry,
+    client_ip: IpAddr,
+    authorization: Option<&str>,
+    state: &RpcState,
+) -> Option<JsonRpcResponse> {
+    let (request, notification) = match entry {
+        Entry::Call(request) => (request, false),
+        Entry::Notification(request) => (request, true),
+        Entry::Invalid(response) => return Some(response),
+    };
+
     // Check rate limit if enabled
     if let Some(ref rate_limiter) = state.rate_limiter {
-        if let Err(retry_after) = rate_limiter.check_rate_limit(client_ip).await {
-            warn!("Rate limit exceeded for IP {}, retry after {} seconds", client_ip, retry_after);
+        if let Err(refusal) = rate_limiter.check_rate_limit(client_ip, &request.method).await {
+            warn!("Rate limit exceeded for IP {}, retry after {} seconds", client_ip, refusal.retry_after());
+            if let Some(metrics) = &state.metrics {
+                metrics.observe_rate_limited(refusal);
+            }
             let response = JsonRpcResponse::error(
                 request.id,
-                JsonRpcError::rate_limited(retry_after),
+                JsonRpcError::rate_limited(refusal.retry_after()),
             );
-            return Ok(warp::reply::json(&response));
+            return (!notification).then_some(response);
         }
     }
 
@@ -314,11 +662,11 @@ async fn handle_rpc_request_with_rate_limit(
             request.id,
             JsonRpcError::invalid_request("Invalid JSON-RPC version"),
         );
-        return Ok(warp::reply::json(&response));
+        return (!notification).then_some(response);
     }
 
-    let response = route_request(request, &state).await;
-    Ok(warp::reply::json(&response))
+    let response = timed_route_request(request, state, authorization).await;
+    (!notification).then_some(response)
 }
 
 /// Handle a single JSON-RPC request (without rate limiting - for internal use)
@@ -337,39 +685,98 @@ async fn handle_rpc_request(
         return Ok(warp::reply::json(&response));
     }
 
-    let response = route_request(request, &state).await;
-    Ok(warp::reply::json(&response))
+    let response = timed_route_request(request, &state, None).await;
+    Ok(warp::reply::json(&cap_response(response, state.profile)))
+}
+
+/// Replace a response over the profile's size cap by an error
+fn cap_response(response: JsonRpcResponse, profile: RpcProfile) -> JsonRpcResponse {
+    ResponseBudget::new(profile.max_response_size()).charge(response)
+}
+
+/// Route a request, recording its latency in the node metrics
+async fn timed_route_request(request: JsonRpcRequest, state: &RpcState, authorization: Option<&str>) -> JsonRpcResponse {
+    let Some(metrics) = state.metrics.clone() else {
+        return route_request(request, state, authorization).await;
+    };
+
+    let method = request.method.clone();
+    let started = std::time::Instant::now();
+    let response = route_request(request, state, authorization).await;
+    // -32601: method not found
+    let known = response.error.as_ref().is_none_or(|e| e.code != -32601);
+    metrics.observe_rpc(if known { &method } else { UNKNOWN_METHOD }, started.elapsed());
+    response
 }
 
 /// Route request to appropriate handler
-async fn route_request(request: JsonRpcRequest, state: &RpcState) -> JsonRpcResponse {
+///
+/// `authorization` is the caller's Authorization header, checked for the
+/// unsafe methods.
+async fn route_request(request: JsonRpcRequest, state: &RpcState, authorization: Option<&str>) -> JsonRpcResponse {
+    if !state.profile.allows(&request.method) {
+        let profile = state.profile.to_string();
+        return JsonRpcResponse::error(request.id, JsonRpcError::method_not_in_profile(&request.method, &profile));
+    }
+    if UNSAFE_METHODS.contains(&request.method.as_str()) {
+        if let Err(e) = state.authorize_unsafe(&request.method, authorization) {
+            return JsonRpcResponse::error(request.id, e);
+        }
+    }
+
     match request.method.as_str() {
         // Chain methods
         "chain_getInfo" => handle_chain_get_info(request.id, state).await,
         "chain_getBlock" => handle_chain_get_block(request.id, request.params, state).await,
         "chain_getBlockByNumber" => handle_chain_get_block(request.id, request.params, state).await,
         "chain_getLatestBlock" => handle_chain_get_latest_block(request.id, state).await,
+        "chain_getTransactionReceipt" => handle_chain_get_transaction_receipt(request.id, request.params, state).await,
+        "chain_getEpochInfo" => handle_chain_get_epoch_info(request.id, state).await,
+        "chain_getMortality" => handle_chain_get_mortality(request.id, state).await,
+        "chain_getForks" => handle_chain_get_forks(request.id, state).await,
+        "chain_getReorgEvents" => handle_chain_get_reorg_events(request.id, request.params, state).await,
+        "chain_getEvents" => handle_chain_get_events(request.id, request.params, state).await,
 
         // State methods
         "state_getAccount" => handle_state_get_account(request.id, request.params, state).await,
         "state_getBalance" => handle_state_get_balance(request.id, request.params, state).await,
         "state_getNonce" => handle_state_get_nonce(request.id, request.params, state).await,
         "state_getTransactionHistory" => handle_state_get_transaction_history(request.id, request.params, state).await,
+        "state_getBalanceAt" => handle_state_get_balance_at(request.id, request.params, state).await,
+        "state_callAt" => handle_state_call_at(request.id, request.params, state).await,
+        "state_getStorageAt" => handle_state_get_storage_at(request.id, request.params, state).await,
+        "state_getProof" => handle_state_get_proof(request.id, request.params, state).await,
+
+        // Account methods
+        "account_nextNonce" => handle_account_next_nonce(request.id, request.params, state).await,
+        "account_getTransactions" => handle_account_get_transactions(request.id, request.params, state).await,
 
         // Author methods
         "author_submitTransaction" => handle_submit_transaction(request.id, request.params, state).await,
+        "author_submitTransactionIdempotent" => handle_submit_transaction_idempotent(request.id, request.params, state).await,
         "author_pendingTransactions" => handle_mempool_status(request.id, state).await,
+        "author_rotateKeys" => handle_author_rotate_keys(request.id, state).await,
 
         // System methods
         "system_info" => handle_system_info(request.id, state).await,
         "system_health" => handle_system_health(request.id, state).await,
         "system_peers" => handle_system_peers(request.id, state).await,
+        "system_banPeer" => handle_system_ban_peer(request.id, request.params, state).await,
+        "system_unbanPeer" => handle_system_unban_peer(request.id, request.params, state).await,
         "system_syncState" => handle_sync_state(request.id, state).await,
         "system_version" => handle_system_version(request.id, state).await,
         "system_name" => JsonRpcResponse::success(request.id, "KratOs Node"),
+        "system_chain" => handle_system_chain(request.id, state).await,
+        "system_invariants" => handle_system_invariants(request.id, state).await,
+        "system_securityStateHistory" => handle_system_security_state_history(request.id, state).await,
+        "system_stateCache" => handle_system_state_cache(request.id, state).await,
 
         // Mempool methods
         "mempool_status" => handle_mempool_status(request.id, state).await,
+        "mempool_content" => handle_mempool_content(request.id, state).await,
+
+        // Fee market
+        "fee_history" => handle_fee_history(request.id, request.params, state).await,
 
         // Early Validator Voting methods (Bootstrap Era)
         "validator_getEarlyVotingStatus" => handle_validator_get_early_voting_status(request.id, state).await,
@@ -377,6 +784,52 @@ async fn route_request(request: JsonRpcRequest, state: &RpcState) -> JsonRpcResp
         "validator_getCandidateVotes" => handle_validator_get_candidate_votes(request.id, request.params, state).await,
         "validator_canVote" => handle_validator_can_vote(request.id, request.params, state).await,
 
+        // Validator operations
+        "validator_getInfo" => handle_validator_get_info(request.id, request.params, state).await,
+        "validator_getCredits" => handle_validator_get_credits(request.id, request.params, state).await,
+        "validator_creditsHistory" => handle_validator_credits_history(request.id, request.params, state).await,
+        "validator_getAuthoredBlocks" => handle_validator_get_authored_blocks(request.id, request.params, state).await,
+
+        // Finality methods
+        "finality_getStatus" => handle_finality_get_status(request.id, state).await,
+        "finality_getStallStatus" => handle_finality_get_stall_status(request.id, state).await,
+        "grandpa_proveFinality" => handle_grandpa_prove_finality(request.id, request.params, state).await,
+
+        // Staking methods
+        "staking_getDelegations" => handle_staking_get_delegations(request.id, request.params, state).await,
+        "staking_getDelegators" => handle_staking_get_delegators(request.id, request.params, state).await,
+        "staking_getUnbonding" => handle_staking_get_unbonding(request.id, request.params, state).await,
+        "staking_pendingRewards" => handle_staking_pending_rewards(request.id, request.params, state).await,
+        "governance_getProposal" => handle_governance_get_proposal(request.id, request.params, state, false).await,
+        "governance_getTally" => handle_governance_get_proposal(request.id, request.params, state, true).await,
+        "governance_listProposals" => handle_governance_list_proposals(request.id, state).await,
+        "treasury_balance" => handle_treasury_balance(request.id, state).await,
+        "treasury_proposals" => handle_treasury_proposals(request.id, state).await,
+        "governance_getParameters" => handle_governance_parameters(request.id, state).await,
+        "governance_parameterLog" => handle_governance_parameter_log(request.id, state).await,
+        "upgrade_getStatus" => handle_upgrade_get_status(request.id, state).await,
+
+        // Identity methods
+        "identity_get" => handle_identity_get(request.id, request.params, state).await,
+        "personhood_getStatus" => handle_personhood_get_status(request.id, request.params, state).await,
+
+        // Messaging methods
+        "messaging_getInbox" => handle_messaging_get_inbox(request.id, request.params, state).await,
+
+        // Reputation methods
+        "reputation_get" => handle_reputation_get(request.id, request.params, state).await,
+        "reputation_top" => handle_reputation_top(request.id, request.params, state).await,
+
+        // Slashing methods
+        "slashing_pendingEvidence" => handle_slashing_pending_evidence(request.id, state).await,
+
+        // Emergency methods
+        "emergency_getStatus" => handle_emergency_get_status(request.id, state).await,
+
+        // Debug methods (unsafe)
+        "debug_getStateRootForensics" => handle_debug_get_state_root_forensics(request.id, request.params, state).await,
+        "admin_reloadConfig" => handle_admin_reload_config(request.id, state).await,
+
         // Unknown method
         _ => JsonRpcResponse::error(request.id, JsonRpcError::method_not_found(&request.method)),
     }
@@ -398,6 +851,28 @@ async fn handle_chain_get_info(id: JsonRpcId, state: &RpcState) -> JsonRpcRespon
     }
 }
 
+async fn handle_chain_get_epoch_info(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ChainGetEpochInfo(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(info) => JsonRpcResponse::success(id, info),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_chain_get_mortality(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ChainGetMortality(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(mortality) => JsonRpcResponse::success(id, mortality),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
 async fn handle_chain_get_block(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
     let number: u64 = match params {
         serde_json::Value::Array(arr) if !arr.is_empty() => {
@@ -432,6 +907,34 @@ async fn handle_chain_get_latest_block(id: JsonRpcId, state: &RpcState) -> JsonR
     }
 }
 
+async fn handle_chain_get_transaction_receipt(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let hash_str: String = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected hash string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [tx_hash]")),
+    };
+
+    let hash = match parse_hash(&hash_str) {
+        Ok(h) => h,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ChainGetTransactionReceipt(hash, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(Some(receipt))) => JsonRpcResponse::success(id, receipt),
+        Ok(Ok(None)) => JsonRpcResponse::error(id, JsonRpcError::transaction_not_found()),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
 async fn handle_state_get_account(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
     let address_str: String = match params {
         serde_json::Value::Array(arr) if !arr.is_empty() => {
@@ -513,6 +1016,92 @@ async fn handle_state_get_nonce(id: JsonRpcId, params: serde_json::Value, state:
     }
 }
 
+async fn handle_state_get_balance_at(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let (address_str, block_hash) = match parse_at_params(&params, "[address, blockHash]") {
+        Ok(p) => p,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    account_at(id, account_id, block_hash, HistoricalCall::Balance, state).await
+}
+
+async fn handle_state_call_at(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    match parse_call_at_params(&params) {
+        Ok((call, account_id, block_hash)) => account_at(id, account_id, block_hash, call, state).await,
+        Err(e) => JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    }
+}
+
+async fn account_at(
+    id: JsonRpcId,
+    account_id: AccountId,
+    block_hash: Hash,
+    call: HistoricalCall,
+    state: &RpcState,
+) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::StateGetAccountAt(account_id, block_hash, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(Some(info))) => JsonRpcResponse::success(id, call.result(&info)),
+        Ok(Ok(None)) => JsonRpcResponse::error(id, JsonRpcError::block_not_found()),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_state_get_storage_at(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let (key_str, block_hash) = match parse_at_params(&params, "[key, blockHash]") {
+        Ok(p) => p,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let key = match parse_storage_key(&key_str) {
+        Ok(k) => k,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::StateGetStorageAt(key, block_hash, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(Some(value))) => JsonRpcResponse::success(id, value.map(|v| format!("0x{}", hex::encode(v)))),
+        Ok(Ok(None)) => JsonRpcResponse::error(id, JsonRpcError::block_not_found()),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_state_get_proof(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let (address_str, block_hash) = match parse_at_params(&params, "[address, blockHash]") {
+        Ok(p) => p,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::StateGetProof(account_id, block_hash, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(Some(proof))) => JsonRpcResponse::success(id, proof),
+        Ok(Ok(None)) => JsonRpcResponse::error(id, JsonRpcError::block_not_found()),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
 async fn handle_state_get_transaction_history(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
     // Parse parameters: [address, limit?, offset?]
     let (address_str, limit, offset) = match params {
@@ -544,6 +1133,92 @@ async fn handle_state_get_transaction_history(id: JsonRpcId, params: serde_json:
     }
 }
 
+/// Nonce for the account's next transaction, past its pending ones
+async fn handle_account_next_nonce(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let address_str: String = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::AccountNextNonce(account_id, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(nonce)) => JsonRpcResponse::success(id, nonce),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+/// Transactions sent or received by an account, from the index
+async fn handle_account_get_transactions(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let (account_id, query) = match parse_account_transactions_params(&params) {
+        Ok(p) => p,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::AccountGetTransactions(account_id, query, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(page)) => JsonRpcResponse::success(id, page),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+/// Events of a type (`contract.Name`), from the index
+async fn handle_chain_get_events(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let (event_type, limit, offset) = match parse_page_params(&params, "[eventType, limit?, offset?]") {
+        Ok(p) => p,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ChainGetEvents(event_type, limit, offset, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(page)) => JsonRpcResponse::success(id, page),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+/// Blocks authored by a validator, from the index
+async fn handle_validator_get_authored_blocks(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let (address_str, limit, offset) = match parse_page_params(&params, "[address, limit?, offset?]") {
+        Ok(p) => p,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ValidatorGetAuthoredBlocks(account_id, limit, offset, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(page)) => JsonRpcResponse::success(id, page),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
 async fn handle_submit_transaction(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
     let tx_data: SignedTransaction = match params {
         serde_json::Value::Array(arr) if !arr.is_empty() => {
@@ -556,7 +1231,7 @@ async fn handle_submit_transaction(id: JsonRpcId, params: serde_json::Value, sta
     };
 
     let (tx, rx) = oneshot::channel();
-    if state.tx.send(RpcCall::SubmitTransaction(tx_data, tx)).is_err() {
+    if state.tx.send(RpcCall::SubmitTransaction(Box::new(tx_data), tx)).is_err() {
         return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
     }
     match rx.await {
@@ -567,7 +1242,33 @@ async fn handle_submit_transaction(id: JsonRpcId, params: serde_json::Value, sta
             };
             JsonRpcResponse::success(id, result)
         }
-        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::transaction_rejected(&e)),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::submission_failed(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_submit_transaction_idempotent(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let (tx_data, key): (SignedTransaction, String) = match params {
+        serde_json::Value::Array(arr) if arr.len() == 2 => {
+            let tx_data = match serde_json::from_value(arr[0].clone()) {
+                Ok(tx) => tx,
+                Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&format!("Invalid transaction: {}", e))),
+            };
+            match arr[1].as_str() {
+                Some(key) => (tx_data, key.to_string()),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Idempotency key must be a string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [transaction, idempotency_key]")),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::SubmitTransactionIdempotent(Box::new(tx_data), key, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok((hash, duplicate))) => JsonRpcResponse::success(id, IdempotentSubmitResult::new(hash, duplicate)),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::submission_failed(&e)),
         Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
     }
 }
@@ -601,54 +1302,620 @@ async fn handle_system_peers(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse
         return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
     }
     match rx.await {
-        Ok((count, peers)) => JsonRpcResponse::success(id, serde_json::json!({
-            "count": count,
-            "peers": peers
-        })),
+        Ok(peers) => JsonRpcResponse::success(id, peers),
         Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
     }
 }
 
-async fn handle_sync_state(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+async fn handle_system_ban_peer(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let (peer_id, reason, secs) = match parse_ban_peer_params(&params) {
+        Ok(parsed) => parsed,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
     let (tx, rx) = oneshot::channel();
-    if state.tx.send(RpcCall::SyncState(tx)).is_err() {
+    if state.tx.send(RpcCall::SystemBanPeer(peer_id, reason, secs, tx)).is_err() {
         return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
     }
     match rx.await {
-        Ok(status) => JsonRpcResponse::success(id, status),
+        Ok(ban) => JsonRpcResponse::success(id, ban),
         Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
     }
 }
 
-async fn handle_system_version(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+async fn handle_system_unban_peer(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let peer_id = match params.get(0).ok_or_else(|| "Expected [peerId]".to_string()).and_then(parse_peer_id) {
+        Ok(peer_id) => peer_id,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
     let (tx, rx) = oneshot::channel();
-    if state.tx.send(RpcCall::GetVersion(tx)).is_err() {
+    if state.tx.send(RpcCall::SystemUnbanPeer(peer_id, tx)).is_err() {
         return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
     }
     match rx.await {
-        Ok(version) => JsonRpcResponse::success(id, version),
-        Err(_) => JsonRpcResponse::success(id, env!("CARGO_PKG_VERSION")),
+        Ok(unbanned) => JsonRpcResponse::success(id, unbanned),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_sync_state(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::SyncState(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(status) => JsonRpcResponse::success(id, status),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_system_version(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::GetVersion(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(version) => JsonRpcResponse::success(id, version),
+        Err(_) => JsonRpcResponse::success(id, env!("CARGO_PKG_VERSION")),
+    }
+}
+
+async fn handle_system_chain(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::SystemChain(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(chain) => JsonRpcResponse::success(id, chain),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_system_invariants(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::SystemInvariants(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(report) => JsonRpcResponse::success(id, report),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_system_security_state_history(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::SystemSecurityStateHistory(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(history) => JsonRpcResponse::success(id, history),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_system_state_cache(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::SystemStateCache(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(stats) => JsonRpcResponse::success(id, stats),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
     }
 }
 
 async fn handle_mempool_status(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
     let (tx, rx) = oneshot::channel();
-    if state.tx.send(RpcCall::MempoolStatus(tx)).is_err() {
+    if state.tx.send(RpcCall::MempoolStatus(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(status) => JsonRpcResponse::success(id, status),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_mempool_content(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::MempoolContent(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(content) => JsonRpcResponse::success(id, content),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_fee_history(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let block_count = match params.as_array().and_then(|arr| arr.first()).and_then(|v| v.as_u64()) {
+        Some(n) if (1..=MAX_FEE_HISTORY_BLOCKS).contains(&n) => n,
+        _ => {
+            return JsonRpcResponse::error(
+                id,
+                JsonRpcError::invalid_params(&format!("Expected [blockCount] between 1 and {}", MAX_FEE_HISTORY_BLOCKS)),
+            )
+        }
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::FeeHistory(block_count, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(history)) => JsonRpcResponse::success(id, history),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+// =============================================================================
+// EARLY VALIDATOR VOTING HANDLERS (Bootstrap Era)
+// =============================================================================
+
+async fn handle_validator_get_early_voting_status(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ValidatorGetEarlyVotingStatus(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(status)) => JsonRpcResponse::success(id, status),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_validator_get_pending_candidates(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ValidatorGetPendingCandidates(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(candidates)) => JsonRpcResponse::success(id, candidates),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_validator_get_candidate_votes(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let address_str: String = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected candidate address string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [candidate_address]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ValidatorGetCandidateVotes(account_id, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(votes)) => JsonRpcResponse::success(id, votes),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_validator_can_vote(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let address_str: String = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected account address string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [account_address]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ValidatorCanVote(account_id, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(can_vote)) => JsonRpcResponse::success(id, can_vote),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_validator_get_info(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let address_str: String = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected account address string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [account_address]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ValidatorGetInfo(account_id, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Some(info)) => JsonRpcResponse::success(id, info),
+        Ok(None) => JsonRpcResponse::error(id, JsonRpcError::validator_not_found()),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_validator_get_credits(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let address_str: String = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected account address string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [account_address]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ValidatorGetCredits(account_id, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Some(credits)) => JsonRpcResponse::success(id, credits),
+        Ok(None) => JsonRpcResponse::error(id, JsonRpcError::validator_not_found()),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_validator_credits_history(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    // Parse parameters: [address, epochs?]
+    let (address_str, epochs) = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            let addr = match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected account address string")),
+            };
+            let epochs = arr.get(1).and_then(|v| v.as_u64()).unwrap_or(10).min(VC_HISTORY_EPOCHS as u64) as usize;
+            (addr, epochs)
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [account_address, epochs?]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ValidatorCreditsHistory(account_id, epochs, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Some(history)) => JsonRpcResponse::success(id, history),
+        Ok(None) => JsonRpcResponse::error(id, JsonRpcError::validator_not_found()),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_finality_get_status(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::FinalityGetStatus(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(status) => JsonRpcResponse::success(id, status),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_finality_get_stall_status(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::FinalityGetStallStatus(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Some(status)) => JsonRpcResponse::success(id, status),
+        Ok(None) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Finality gadget not running")),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_grandpa_prove_finality(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let block_number = match params.as_array().and_then(|arr| arr.first()).and_then(|v| v.as_u64()) {
+        Some(n) => n,
+        None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [blockNumber]")),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::GrandpaProveFinality(block_number, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(proof)) => JsonRpcResponse::success(id, proof),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_debug_get_state_root_forensics(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    // Optional [blockNumber]: newest bundle when omitted
+    let block_number = match params.as_array().and_then(|arr| arr.first()) {
+        None | Some(serde_json::Value::Null) => None,
+        Some(value) => match value.as_u64() {
+            Some(n) => Some(n),
+            None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [blockNumber?]")),
+        },
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::DebugGetStateRootForensics(block_number, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(bundle)) => JsonRpcResponse::success(id, bundle),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_admin_reload_config(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::AdminReloadConfig(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(changes)) => JsonRpcResponse::success(id, changes),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_staking_get_delegations(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let address_str: String = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected account address string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [account_address]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::StakingGetDelegations(account_id, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(delegations)) => JsonRpcResponse::success(id, delegations),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_staking_get_delegators(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let address_str: String = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected validator address string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [validator_address]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::StakingGetDelegators(account_id, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(pool)) => JsonRpcResponse::success(id, pool),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_staking_get_unbonding(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let address_str: String = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::StakingGetUnbonding(account_id, tx)).is_err() {
         return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
     }
     match rx.await {
-        Ok(status) => JsonRpcResponse::success(id, status),
+        Ok(Ok(unbonding)) => JsonRpcResponse::success(id, unbonding),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
         Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
     }
 }
 
-// =============================================================================
-// EARLY VALIDATOR VOTING HANDLERS (Bootstrap Era)
-// =============================================================================
+async fn handle_staking_pending_rewards(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let address_str: String = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
 
-async fn handle_validator_get_early_voting_status(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
     let (tx, rx) = oneshot::channel();
-    if state.tx.send(RpcCall::ValidatorGetEarlyVotingStatus(tx)).is_err() {
+    if state.tx.send(RpcCall::StakingPendingRewards(account_id, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(rewards)) => JsonRpcResponse::success(id, rewards),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+/// governance_getProposal, or only its tally (governance_getTally)
+async fn handle_governance_get_proposal(
+    id: JsonRpcId,
+    params: serde_json::Value,
+    state: &RpcState,
+    tally_only: bool,
+) -> JsonRpcResponse {
+    let proposal_id = match params.as_array().and_then(|arr| arr.first()).and_then(|v| v.as_u64()) {
+        Some(n) => n,
+        None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [proposalId]")),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::GovernanceProposals(Some(proposal_id), tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(proposals)) => match (proposals.into_iter().next(), tally_only) {
+            (Some(proposal), true) => JsonRpcResponse::success(id, proposal.tally),
+            (proposal, _) => JsonRpcResponse::success(id, proposal),
+        },
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_governance_list_proposals(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::GovernanceProposals(None, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(proposals)) => JsonRpcResponse::success(id, proposals),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_chain_get_forks(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ChainGetForks(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(forks)) => JsonRpcResponse::success(id, forks),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+/// Reorgs after the event ID given (0 for all kept), oldest first
+async fn handle_chain_get_reorg_events(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let after_id = match params.as_array().and_then(|arr| arr.first()).and_then(|v| v.as_u64()) {
+        Some(n) => n,
+        None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [afterId]")),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ChainGetReorgEvents(after_id, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(events)) => JsonRpcResponse::success(id, events),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_treasury_balance(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::TreasuryBalance(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(treasury)) => JsonRpcResponse::success(id, treasury),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+/// TreasurySpend proposals, newest first
+async fn handle_treasury_proposals(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::TreasuryProposals(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(proposals)) => JsonRpcResponse::success(id, proposals),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_governance_parameters(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::GovernanceParameters(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(parameters)) => JsonRpcResponse::success(id, parameters),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+/// Parameter change audit log, newest first
+async fn handle_governance_parameter_log(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::GovernanceParameterLog(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(log)) => JsonRpcResponse::success(id, log),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+/// Protocol version in force and the upgrade scheduled
+async fn handle_upgrade_get_status(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::UpgradeGetStatus(tx)).is_err() {
         return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
     }
     match rx.await {
@@ -658,27 +1925,42 @@ async fn handle_validator_get_early_voting_status(id: JsonRpcId, state: &RpcStat
     }
 }
 
-async fn handle_validator_get_pending_candidates(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+async fn handle_identity_get(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    let address_str: String = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+            }
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
     let (tx, rx) = oneshot::channel();
-    if state.tx.send(RpcCall::ValidatorGetPendingCandidates(tx)).is_err() {
+    if state.tx.send(RpcCall::IdentityGet(account_id, tx)).is_err() {
         return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
     }
     match rx.await {
-        Ok(Ok(candidates)) => JsonRpcResponse::success(id, candidates),
+        Ok(Ok(identity)) => JsonRpcResponse::success(id, identity),
         Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
         Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
     }
 }
 
-async fn handle_validator_get_candidate_votes(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+async fn handle_personhood_get_status(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
     let address_str: String = match params {
         serde_json::Value::Array(arr) if !arr.is_empty() => {
             match arr[0].as_str() {
                 Some(s) => s.to_string(),
-                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected candidate address string")),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
             }
         }
-        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [candidate_address]")),
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
     };
 
     let account_id = match parse_account_id(&address_str) {
@@ -687,25 +1969,55 @@ async fn handle_validator_get_candidate_votes(id: JsonRpcId, params: serde_json:
     };
 
     let (tx, rx) = oneshot::channel();
-    if state.tx.send(RpcCall::ValidatorGetCandidateVotes(account_id, tx)).is_err() {
+    if state.tx.send(RpcCall::PersonhoodGetStatus(account_id, tx)).is_err() {
         return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
     }
     match rx.await {
-        Ok(Ok(votes)) => JsonRpcResponse::success(id, votes),
+        Ok(Ok(status)) => JsonRpcResponse::success(id, status),
         Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
         Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
     }
 }
 
-async fn handle_validator_can_vote(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+async fn handle_messaging_get_inbox(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    // Parse parameters: [address, limit?]
+    let (address_str, limit) = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => {
+            let addr = match arr[0].as_str() {
+                Some(s) => s.to_string(),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
+            };
+            let limit = arr.get(1).and_then(|v| v.as_u64()).unwrap_or(DEFAULT_INBOX_MESSAGES).min(MAX_INBOX_MESSAGES) as usize;
+            (addr, limit)
+        }
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address, limit?]")),
+    };
+
+    let account_id = match parse_account_id(&address_str) {
+        Ok(a) => a,
+        Err(e) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::MessagingGetInbox(account_id, limit, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(messages)) => JsonRpcResponse::success(id, messages),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_reputation_get(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
     let address_str: String = match params {
         serde_json::Value::Array(arr) if !arr.is_empty() => {
             match arr[0].as_str() {
                 Some(s) => s.to_string(),
-                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected account address string")),
+                None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected address string")),
             }
         }
-        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [account_address]")),
+        _ => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [address]")),
     };
 
     let account_id = match parse_account_id(&address_str) {
@@ -714,11 +2026,76 @@ async fn handle_validator_can_vote(id: JsonRpcId, params: serde_json::Value, sta
     };
 
     let (tx, rx) = oneshot::channel();
-    if state.tx.send(RpcCall::ValidatorCanVote(account_id, tx)).is_err() {
+    if state.tx.send(RpcCall::ReputationGet(account_id, tx)).is_err() {
         return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
     }
     match rx.await {
-        Ok(Ok(can_vote)) => JsonRpcResponse::success(id, can_vote),
+        Ok(Ok(reputation)) => JsonRpcResponse::success(id, reputation),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_reputation_top(id: JsonRpcId, params: serde_json::Value, state: &RpcState) -> JsonRpcResponse {
+    // Optional [limit?, domain?]: overall ranking when no domain is given
+    let arr = params.as_array().cloned().unwrap_or_default();
+    let limit = match arr.first() {
+        None | Some(serde_json::Value::Null) => DEFAULT_REPUTATION_TOP,
+        Some(value) => match value.as_u64() {
+            Some(n) => (n as usize).min(MAX_REPUTATION_TOP),
+            None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected [limit?, domain?]")),
+        },
+    };
+    let domain = match arr.get(1) {
+        None | Some(serde_json::Value::Null) => None,
+        Some(value) => match value.as_str().map(parse_reputation_domain) {
+            Some(Ok(domain)) => Some(domain),
+            Some(Err(e)) => return JsonRpcResponse::error(id, JsonRpcError::invalid_params(&e)),
+            None => return JsonRpcResponse::error(id, JsonRpcError::invalid_params("Expected domain string")),
+        },
+    };
+
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::ReputationTop(limit, domain, tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(top)) => JsonRpcResponse::success(id, top),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_slashing_pending_evidence(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::SlashingPendingEvidence(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(pending) => JsonRpcResponse::success(id, pending),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_emergency_get_status(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::EmergencyGetStatus(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(status)) => JsonRpcResponse::success(id, status),
+        Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
+        Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
+    }
+}
+
+async fn handle_author_rotate_keys(id: JsonRpcId, state: &RpcState) -> JsonRpcResponse {
+    let (tx, rx) = oneshot::channel();
+    if state.tx.send(RpcCall::AuthorRotateKeys(tx)).is_err() {
+        return JsonRpcResponse::error(id, JsonRpcError::internal_error("Node unavailable"));
+    }
+    match rx.await {
+        Ok(Ok(rotated)) => JsonRpcResponse::success(id, rotated),
         Ok(Err(e)) => JsonRpcResponse::error(id, JsonRpcError::internal_error(&e)),
         Err(_) => JsonRpcResponse::error(id, JsonRpcError::internal_error("Request timeout")),
     }
@@ -767,12 +2144,24 @@ pub enum RpcServerError {
 
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
+
+    #[error("TLS error: {0}")]
+    TlsError(String),
 }
 
 // =============================================================================
 // RPC CONFIG
 // =============================================================================
 
+/// Certificate of an HTTPS RPC server (PEM files)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcTls {
+    /// Certificate chain, leaf first
+    pub cert_path: PathBuf,
+    /// Private key (PKCS#8, PKCS#1 or SEC1)
+    pub key_path: PathBuf,
+}
+
 /// RPC server configuration
 #[derive(Debug, Clone)]
 pub struct RpcConfig {
@@ -782,15 +2171,23 @@ pub struct RpcConfig {
     pub port: u16,
     /// Listen address (0.0.0.0 for all interfaces)
     pub address: [u8; 4],
-    /// Enable CORS
-    pub cors: bool,
     /// SECURITY FIX #3: Allowed CORS origins (empty = localhost only)
     /// Specify explicit origins like "https://myapp.example.com" for production
     pub cors_origins: Vec<String>,
+    /// Allowed Host headers (empty = localhost only)
+    pub allowed_hosts: Vec<String>,
     /// Max request size in bytes
     pub max_request_size: usize,
-    /// Rate limiting (requests per second)
-    pub rate_limit: Option<u32>,
+    /// Per-IP rate limiting (None = unlimited)
+    pub rate_limit: Option<RateLimitConfig>,
+    /// Serve unsafe methods (UNSAFE_METHODS)
+    pub unsafe_methods: bool,
+    /// Exposure profile (--rpc-profile)
+    pub profile: RpcProfile,
+    /// Tokens for the unsafe methods ([[rpc-token]] of the config file)
+    pub tokens: Vec<RpcToken>,
+    /// Serve HTTPS (--rpc-tls-cert, --rpc-tls-key)
+    pub tls: Option<RpcTls>,
 }
 
 impl Default for RpcConfig {
@@ -799,10 +2196,14 @@ impl Default for RpcConfig {
             enabled: true,
             port: 9933,
             address: [127, 0, 0, 1], // localhost only by default
-            cors: true,
             cors_origins: vec![], // SECURITY: Localhost only by default
+            allowed_hosts: vec![],
             max_request_size: 10 * 1024 * 1024, // 10 MB
-            rate_limit: Some(100),
+            rate_limit: Some(RateLimitConfig::default()),
+            unsafe_methods: false,
+            profile: RpcProfile::Private,
+            tokens: Vec::new(),
+            tls: None,
         }
     }
 }
@@ -812,6 +2213,7 @@ impl RpcConfig {
     pub fn public() -> Self {
         Self {
             address: [0, 0, 0, 0], // All interfaces
+            allowed_hosts: vec![hosts::ANY_HOST.to_string()], // Reached under any name
             ..Default::default()
         }
     }
@@ -846,6 +2248,21 @@ mod tests {
         assert_eq!(server.socket_addr().ip().to_string(), "0.0.0.0");
     }
 
+    #[tokio::test]
+    async fn test_tls_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let (cert_path, key_path) = (dir.path().join("cert.pem"), dir.path().join("key.pem"));
+        let tls = RpcTls { cert_path: cert_path.clone(), key_path: key_path.clone() };
+        let addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
+        let bind_tls = |tls: &RpcTls| bind(warp::path("health").map(|| "ok"), addr, Some(tls), std::future::pending());
+
+        // Missing files, then files that aren't PEM: errors, not panics
+        assert!(matches!(bind_tls(&tls), Err(RpcServerError::TlsError(e)) if e.contains("cert.pem")));
+        std::fs::write(&cert_path, "not a certificate").unwrap();
+        std::fs::write(&key_path, "not a key").unwrap();
+        assert!(matches!(bind_tls(&tls), Err(RpcServerError::TlsError(_))));
+    }
+
     #[test]
     fn test_rpc_config_default() {
         let config = RpcConfig::default();
@@ -892,7 +2309,143 @@ mod tests {
             id: JsonRpcId::Number(1),
         };
 
-        let response = route_request(request, &state).await;
+        let response = route_request(request, &state, None).await;
         assert!(response.result.is_some());
     }
+
+    #[tokio::test]
+    async fn test_unsafe_methods_gated() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut state = RpcState::new(tx);
+        let request = || JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "debug_getStateRootForensics".to_string(),
+            params: serde_json::json!([]),
+            id: JsonRpcId::Number(1),
+        };
+
+        // Refused without reaching the node
+        let response = route_request(request(), &state, None).await;
+        assert_eq!(response.error.unwrap().code, -32005);
+        assert!(rx.try_recv().is_err());
+
+        state.unsafe_methods = true;
+        tokio::spawn(async move {
+            if let Some(RpcCall::DebugGetStateRootForensics(None, resp)) = rx.recv().await {
+                let _ = resp.send(Ok(None));
+            }
+        });
+        let response = route_request(request(), &state, None).await;
+        assert!(response.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unsafe_methods_token() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut state = RpcState::new(tx);
+        // The flag alone no longer opens the methods once tokens exist
+        state.unsafe_methods = true;
+        let (secret, hash) = RpcToken::generate();
+        state.auth = RpcAuth::new(vec![RpcToken {
+            name: "ops".to_string(),
+            hash,
+            methods: vec!["debug_getStateRootForensics".to_string()],
+        }]);
+        let request = |method: &str| JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: method.to_string(),
+            params: serde_json::json!([]),
+            id: JsonRpcId::Number(1),
+        };
+
+        let response = route_request(request("debug_getStateRootForensics"), &state, None).await;
+        assert_eq!(response.error.unwrap().code, -32008);
+        let bearer = format!("Bearer {}", secret);
+        let response = route_request(request("admin_reloadConfig"), &state, Some(&bearer)).await;
+        assert_eq!(response.error.unwrap().code, -32008);
+        assert!(rx.try_recv().is_err());
+
+        tokio::spawn(async move {
+            if let Some(RpcCall::DebugGetStateRootForensics(None, resp)) = rx.recv().await {
+                let _ = resp.send(Ok(None));
+            }
+        });
+        let response = route_request(request("debug_getStateRootForensics"), &state, Some(&bearer)).await;
+        assert!(response.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_public_profile() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut state = RpcState::new(tx);
+        state.profile = RpcProfile::Public;
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            method: "system_peers".to_string(),
+            params: serde_json::json!([]),
+            id: JsonRpcId::Number(1),
+        };
+
+        // Refused without reaching the node
+        let response = route_request(request, &state, None).await;
+        assert_eq!(response.error.unwrap().code, -32006);
+        assert!(rx.try_recv().is_err());
+
+        // Responses over the cap become errors
+        let small = JsonRpcResponse::success(JsonRpcId::Number(2), serde_json::json!("ok"));
+        assert!(cap_response(small, RpcProfile::Public).error.is_none());
+        let large = JsonRpcResponse::success(JsonRpcId::Number(3), serde_json::json!("a".repeat(2 * 1024 * 1024)));
+        assert!(cap_response(large.clone(), RpcProfile::Archive).error.is_none());
+        let capped = cap_response(large, RpcProfile::Public);
+        assert_eq!(capped.error.unwrap().code, -32007);
+        assert_eq!(capped.id, JsonRpcId::Number(3));
+    }
+
+    #[tokio::test]
+    async fn test_batch_request() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut state = RpcState::new(tx);
+        state.rate_limiter = Some(RpcRateLimiter::new(RateLimitConfig {
+            max_requests: 3,
+            ..Default::default()
+        }));
+
+        // Calls reach the node one at a time
+        tokio::spawn(async move {
+            while let Some(call) = rx.recv().await {
+                assert!(rx.try_recv().is_err(), "batch calls sent concurrently");
+                if let RpcCall::GetVersion(resp) = call {
+                    let _ = resp.send("0.1.0".to_string());
+                }
+            }
+        });
+
+        let body = r#"[
+            {"jsonrpc":"2.0","method":"system_version","id":1},
+            {"jsonrpc":"2.0","method":"system_version"},
+            {"jsonrpc":"2.0","method":"unknown_method","id":2},
+            {"method":"system_version","id":3},
+            {"jsonrpc":"2.0","method":"system_version","id":4}
+        ]"#;
+        let reply = handle_rpc_request_with_rate_limit(None, None, Bytes::from(body), state.clone()).await.unwrap();
+        let body = warp::hyper::body::to_bytes(reply.into_body()).await.unwrap();
+        let responses: Vec<JsonRpcResponse> = serde_json::from_slice(&body).unwrap();
+
+        // Notification unanswered but counted by the rate limiter, like every call
+        let codes: Vec<_> = responses.iter().map(|r| r.error.as_ref().map(|e| e.code)).collect();
+        assert_eq!(codes, vec![None, Some(-32601), Some(-32600), Some(-32029)]);
+        assert_eq!(responses[0].result, Some(serde_json::json!("0.1.0")));
+        assert_eq!(responses[3].id, JsonRpcId::Number(4));
+
+        // Only notifications: no content
+        let reply = handle_rpc_request_with_rate_limit(
+            None,
+            None,
+            Bytes::from(r#"[{"jsonrpc":"2.0","method":"system_name"}]"#),
+            RpcState::new(mpsc::unbounded_channel().0),
+        )
+        .await
+        .unwrap();
+        assert_eq!(reply.status(), StatusCode::NO_CONTENT);
+    }
 }
@@ -1,4 +1,24 @@
 // Types RPC - Structures for JSON-RPC 2.0 requests and responses
+use crate::consensus::economics::{BootstrapStatus, NetworkSecurityState};
+use crate::consensus::epoch::EPOCH_DURATION_BLOCKS;
+use crate::consensus::evidence::{EquivocationEvidence, PendingEvidence};
+use crate::contracts::emergency::{EmergencyDeclaration, EMERGENCY_RECOVERY_THRESHOLD};
+use crate::contracts::governance::{Proposal, ProposalId, ProposalStatus, ProposalType, MIN_QUORUM_PERCENT, MIN_SECONDS};
+use crate::contracts::identity::RegisteredIdentity;
+use crate::contracts::messaging::AccountMessage;
+use crate::contracts::meta_governance::{enactment_block, ParameterChangeRecord};
+use crate::contracts::personhood::ceremony_phase;
+use crate::contracts::reputation::AccountReputation;
+use crate::contracts::staking::{DelegationPool, RewardLedger, UnbondingQueue};
+use crate::execution::gas::{BlockFee, FeeHistory, BASE_FEE_PRECISION};
+use crate::genesis::ChainConfig;
+use crate::network::peer::{PeerBan, PeerState, DEFAULT_BAN_SECS};
+use crate::node::mempool::{fee_rate, PoolContent, PoolError};
+use crate::node::service::{EmergencyReport, EpochReport, ForksReport, GovernanceReport, NodeError, ParametersReport, PersonhoodReport, TreasuryReport, ValidatorReport};
+use crate::storage::{
+    IndexCursor, IndexPage, IndexedBlock, IndexedEvent, IndexedTransaction, TransactionDirection, TransactionFilter,
+    MAX_INDEX_PAGE, ReorgEvent,
+};
 use crate::types::*;
 use serde::{Deserialize, Serialize};
 
@@ -164,6 +184,62 @@ impl JsonRpcError {
         }
     }
 
+    /// Submission refused (-32010), a mempool refusal detailed in `data`
+    pub fn submission_failed(error: &NodeError) -> Self {
+        match error {
+            NodeError::Pool(e) => Self {
+                data: Some(pool_error_data(e)),
+                ..Self::transaction_rejected(&e.to_string())
+            },
+            e => Self::transaction_rejected(&e.to_string()),
+        }
+    }
+
+    /// Validator not found (-32004)
+    pub fn validator_not_found() -> Self {
+        Self {
+            code: -32004,
+            message: "Validator not found".to_string(),
+            data: None,
+        }
+    }
+
+    /// Unsafe method not enabled (-32005)
+    pub fn unsafe_method(method: &str) -> Self {
+        Self {
+            code: -32005,
+            message: format!("Method {} is unsafe, start the node with --rpc-methods-unsafe", method),
+            data: None,
+        }
+    }
+
+    /// Unsafe method without a token allowing it (-32008)
+    pub fn unauthorized(method: &str, reason: &str) -> Self {
+        Self {
+            code: -32008,
+            message: format!("Unauthorized call to {}: {}", method, reason),
+            data: None,
+        }
+    }
+
+    /// Method outside the RPC profile (-32006)
+    pub fn method_not_in_profile(method: &str, profile: &str) -> Self {
+        Self {
+            code: -32006,
+            message: format!("Method {} is not served by the {} RPC profile", method, profile),
+            data: None,
+        }
+    }
+
+    /// Response over the RPC profile's size cap (-32007)
+    pub fn response_too_large(size: usize, max: usize) -> Self {
+        Self {
+            code: -32007,
+            message: format!("Response too large: {} bytes > {} allowed", size, max),
+            data: None,
+        }
+    }
+
     /// SECURITY FIX #29: Rate limited (-32029)
     pub fn rate_limited(retry_after_seconds: u64) -> Self {
         Self {
@@ -174,6 +250,48 @@ impl JsonRpcError {
     }
 }
 
+/// `{"reason": ..., <fields>}` of a mempool refusal, for wallets to react
+/// (e.g. wait for pending transactions on a quota, raise the fee)
+fn pool_error_data(error: &PoolError) -> serde_json::Value {
+    use serde_json::json;
+    // JSON numbers only hold u64; larger balances fall back to a decimal string
+    let bal = |b: &Balance| match u64::try_from(*b) {
+        Ok(v) => serde_json::Value::from(v),
+        Err(_) => serde_json::Value::from(b.to_string()),
+    };
+    match error {
+        PoolError::NoHash => json!({ "reason": "noHash" }),
+        PoolError::AlreadyExists => json!({ "reason": "alreadyExists" }),
+        PoolError::PoolFull => json!({ "reason": "poolFull" }),
+        PoolError::FeeTooLow(fee, min) => json!({ "reason": "feeTooLow", "fee": bal(fee), "required": bal(min) }),
+        PoolError::InvalidSignature => json!({ "reason": "invalidSignature" }),
+        PoolError::WrongChain { expected, got } => {
+            let full = |h: &Hash| format!("0x{}", hex::encode(h.as_bytes()));
+            json!({ "reason": "wrongChain", "expected": full(expected), "got": full(got) })
+        }
+        PoolError::InvalidNonce { expected, got } => json!({ "reason": "invalidNonce", "expected": expected, "got": got }),
+        PoolError::NonceTooOld { got, current } => json!({ "reason": "nonceTooOld", "got": got, "current": current }),
+        PoolError::InsufficientBalance { need, have } => {
+            json!({ "reason": "insufficientBalance", "need": bal(need), "have": bal(have) })
+        }
+        PoolError::TooManyPerAccount { count, max } => json!({ "reason": "accountQuota", "count": count, "max": max }),
+        PoolError::EscalatedFeeTooLow { fee, required, pooled } => {
+            json!({ "reason": "escalatedFeeTooLow", "fee": bal(fee), "required": bal(required), "pooled": pooled })
+        }
+        PoolError::RateLimitExceeded => json!({ "reason": "rateLimited" }),
+        PoolError::TooManyPendingGlobal { count, max } => json!({ "reason": "poolQuota", "count": count, "max": max }),
+        PoolError::NonceTooHigh { nonce, max } => json!({ "reason": "nonceTooHigh", "nonce": nonce, "max": max }),
+        PoolError::RbfFeeInsufficient { need_pct } => json!({ "reason": "replacementFeeTooLow", "needPct": need_pct }),
+        PoolError::Expired { valid_until, current } => {
+            json!({ "reason": "expired", "validUntil": valid_until, "current": current })
+        }
+        PoolError::MaxFeeTooLow { max_fee, fee } => {
+            json!({ "reason": "maxFeeTooLow", "maxFee": bal(max_fee), "fee": bal(fee) })
+        }
+        PoolError::Validation(message) => json!({ "reason": "invalid", "message": message }),
+    }
+}
+
 // =============================================================================
 // CHAIN INFO TYPES
 // =============================================================================
@@ -218,12 +336,16 @@ pub struct BlockInfo {
     pub epoch: EpochNumber,
     /// Slot
     pub slot: SlotNumber,
+    /// Protocol version the block was produced with
+    pub protocol_version: String,
     /// Transaction count
     pub tx_count: usize,
     /// State root
     pub state_root: String,
     /// Transactions root
     pub transactions_root: String,
+    /// Receipts root
+    pub receipts_root: String,
 }
 
 impl From<&Block> for BlockInfo {
@@ -236,9 +358,11 @@ impl From<&Block> for BlockInfo {
             author: format!("0x{}", hex::encode(block.header.author.as_bytes())),
             epoch: block.header.epoch,
             slot: block.header.slot,
+            protocol_version: block.header.protocol_version.to_string(),
             tx_count: block.body.transactions.len(),
             state_root: format!("0x{}", hex::encode(block.header.state_root.as_bytes())),
             transactions_root: format!("0x{}", hex::encode(block.header.transactions_root.as_bytes())),
+            receipts_root: format!("0x{}", hex::encode(block.header.receipts_root.as_bytes())),
         }
     }
 }
@@ -300,6 +424,15 @@ impl From<&SignedTransaction> for TransactionInfo {
                 TransactionDetails::Transfer {
                     to: format!("0x{}", hex::encode(to.as_bytes())),
                     amount: *amount,
+                    memo: None,
+                },
+            ),
+            TransactionCall::TransferWithMemo { to, amount, memo } => (
+                "transfer".to_string(),
+                TransactionDetails::Transfer {
+                    to: format!("0x{}", hex::encode(to.as_bytes())),
+                    amount: *amount,
+                    memo: Some(format!("0x{}", hex::encode(memo))),
                 },
             ),
             TransactionCall::Stake { amount } => (
@@ -342,7 +475,13 @@ impl From<&SignedTransaction> for TransactionInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum TransactionDetails {
-    Transfer { to: String, amount: Balance },
+    Transfer {
+        to: String,
+        amount: Balance,
+        /// Hex memo (transfers with memo only)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        memo: Option<String>,
+    },
     Stake { amount: Balance },
     Unstake { amount: Balance },
     WithdrawUnbonded,
@@ -359,12 +498,319 @@ pub struct TransactionReceipt {
     pub block_number: Option<BlockNumber>,
     /// Block hash (if included)
     pub block_hash: Option<String>,
+    /// Index of the transaction in the block
+    pub index: Option<u32>,
     /// Success status
     pub success: bool,
     /// Fee paid
     pub fee_paid: Balance,
     /// Error message (if failed)
     pub error: Option<String>,
+    /// Events emitted during execution
+    pub events: Vec<EventRpc>,
+}
+
+impl From<&StoredReceipt> for TransactionReceipt {
+    fn from(stored: &StoredReceipt) -> Self {
+        let receipt = &stored.receipt;
+        Self {
+            tx_hash: format!("0x{}", hex::encode(receipt.tx_hash.as_bytes())),
+            block_number: Some(stored.block_number),
+            block_hash: Some(format!("0x{}", hex::encode(stored.block_hash.as_bytes()))),
+            index: Some(stored.index),
+            success: receipt.success,
+            fee_paid: receipt.fee_paid,
+            error: receipt.error.clone(),
+            events: receipt.events.iter().map(EventRpc::from).collect(),
+        }
+    }
+}
+
+/// Execution event (RPC format)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventRpc {
+    /// Emitting contract (krat, staking, sidechains, governance, identity, personhood)
+    pub contract: String,
+    /// Event name
+    pub name: String,
+    /// Event fields (addresses as 0x-prefixed hex)
+    pub data: serde_json::Value,
+}
+
+impl From<&Event> for EventRpc {
+    fn from(event: &Event) -> Self {
+        let addr = |a: &AccountId| format!("0x{}", hex::encode(a.as_bytes()));
+        let hash = |h: &Hash| format!("0x{}", hex::encode(h.as_bytes()));
+        // JSON numbers only hold u64; larger balances fall back to a decimal string
+        let bal = |b: &Balance| match u64::try_from(*b) {
+            Ok(v) => serde_json::Value::from(v),
+            Err(_) => serde_json::Value::from(b.to_string()),
+        };
+        let (name, data) = match event {
+            Event::Krat(KratEvent::Transfer { from, to, amount }) => (
+                "Transfer",
+                serde_json::json!({ "from": addr(from), "to": addr(to), "amount": bal(amount) }),
+            ),
+            Event::Krat(KratEvent::Memo { from, to, memo }) => (
+                "Memo",
+                serde_json::json!({
+                    "from": addr(from),
+                    "to": addr(to),
+                    "memo": format!("0x{}", hex::encode(memo)),
+                }),
+            ),
+            Event::Krat(KratEvent::TreasuryPayout { proposal_id, recipient, amount }) => (
+                "TreasuryPayout",
+                serde_json::json!({ "proposalId": proposal_id, "recipient": addr(recipient), "amount": bal(amount) }),
+            ),
+            Event::Staking(StakingEvent::Bonded { who, amount }) => (
+                "Bonded",
+                serde_json::json!({ "who": addr(who), "amount": bal(amount) }),
+            ),
+            Event::Staking(StakingEvent::UnbondingStarted { who, amount, unlock_at }) => (
+                "UnbondingStarted",
+                serde_json::json!({ "who": addr(who), "amount": bal(amount), "unlockAt": unlock_at }),
+            ),
+            Event::Staking(StakingEvent::Withdrawn { who, amount }) => (
+                "Withdrawn",
+                serde_json::json!({ "who": addr(who), "amount": bal(amount) }),
+            ),
+            Event::Staking(StakingEvent::ValidatorRegistered { who, stake }) => (
+                "ValidatorRegistered",
+                serde_json::json!({ "who": addr(who), "stake": bal(stake) }),
+            ),
+            Event::Staking(StakingEvent::ValidatorUnregistered { who }) => (
+                "ValidatorUnregistered",
+                serde_json::json!({ "who": addr(who) }),
+            ),
+            Event::Staking(StakingEvent::UptimeAttested { who, epoch, heartbeats, credited }) => (
+                "UptimeAttested",
+                serde_json::json!({
+                    "who": addr(who),
+                    "epoch": epoch,
+                    "heartbeats": heartbeats,
+                    "credited": credited,
+                }),
+            ),
+            Event::Staking(StakingEvent::SessionKeysSet { who, keys, effective_epoch }) => (
+                "SessionKeysSet",
+                serde_json::json!({
+                    "who": addr(who),
+                    "sessionKeys": format!("0x{}", hex::encode(keys.to_bytes())),
+                    "effectiveEpoch": effective_epoch,
+                }),
+            ),
+            Event::Staking(StakingEvent::Slashed { who, reporter, offence, vc_slashed, amount }) => (
+                "Slashed",
+                serde_json::json!({
+                    "who": addr(who),
+                    "reporter": addr(reporter),
+                    "offence": format!("0x{}", hex::encode(offence.as_bytes())),
+                    "vcSlashed": vc_slashed,
+                    "amount": bal(amount),
+                }),
+            ),
+            Event::Staking(StakingEvent::ReporterRewarded { who, offence, amount }) => (
+                "ReporterRewarded",
+                serde_json::json!({
+                    "who": addr(who),
+                    "offence": format!("0x{}", hex::encode(offence.as_bytes())),
+                    "amount": bal(amount),
+                }),
+            ),
+            Event::Staking(StakingEvent::Delegated { who, validator, amount }) => (
+                "Delegated",
+                serde_json::json!({ "who": addr(who), "validator": addr(validator), "amount": bal(amount) }),
+            ),
+            Event::Staking(StakingEvent::Undelegated { who, validator, amount, unlock_at }) => (
+                "Undelegated",
+                serde_json::json!({
+                    "who": addr(who),
+                    "validator": addr(validator),
+                    "amount": bal(amount),
+                    "unlockAt": unlock_at,
+                }),
+            ),
+            Event::Staking(StakingEvent::RewardsClaimed { who, amount }) => (
+                "RewardsClaimed",
+                serde_json::json!({ "who": addr(who), "amount": bal(amount) }),
+            ),
+            Event::Staking(StakingEvent::CommissionAnnounced { who, commission, effective_epoch }) => (
+                "CommissionAnnounced",
+                serde_json::json!({
+                    "who": addr(who),
+                    "commission": commission,
+                    "effectiveEpoch": effective_epoch,
+                }),
+            ),
+            Event::Sidechains(SidechainEvent::CreationDepositReserved { who, deposit }) => (
+                "CreationDepositReserved",
+                serde_json::json!({ "who": addr(who), "deposit": bal(deposit) }),
+            ),
+            Event::Sidechains(SidechainEvent::Exited { who, chain_id }) => (
+                "Exited",
+                serde_json::json!({ "who": addr(who), "chainId": chain_id.0 }),
+            ),
+            Event::Sidechains(SidechainEvent::Created { owner, chain_id }) => (
+                "SidechainCreated",
+                serde_json::json!({ "owner": addr(owner), "chainId": chain_id.0 }),
+            ),
+            Event::Sidechains(SidechainEvent::CheckpointSubmitted { who, chain_id, state_root }) => (
+                "CheckpointSubmitted",
+                serde_json::json!({ "who": addr(who), "chainId": chain_id.0, "stateRoot": hash(state_root) }),
+            ),
+            Event::Sidechains(SidechainEvent::ExitedWithProof { who, chain_id, amount }) => (
+                "ExitedWithProof",
+                serde_json::json!({ "who": addr(who), "chainId": chain_id.0, "amount": bal(amount) }),
+            ),
+            Event::Governance(GovernanceEvent::ForkSignaled { who, name }) => (
+                "ForkSignaled",
+                serde_json::json!({ "who": addr(who), "name": name }),
+            ),
+            Event::Governance(GovernanceEvent::EarlyValidatorProposed { proposer, candidate }) => (
+                "EarlyValidatorProposed",
+                serde_json::json!({ "proposer": addr(proposer), "candidate": addr(candidate) }),
+            ),
+            Event::Governance(GovernanceEvent::EarlyValidatorVoted { voter, candidate }) => (
+                "EarlyValidatorVoted",
+                serde_json::json!({ "voter": addr(voter), "candidate": addr(candidate) }),
+            ),
+            Event::Governance(GovernanceEvent::ProposalSubmitted { proposer, proposal_id }) => (
+                "ProposalSubmitted",
+                serde_json::json!({ "proposer": addr(proposer), "proposalId": proposal_id }),
+            ),
+            Event::Governance(GovernanceEvent::ProposalSeconded { who, proposal_id }) => (
+                "ProposalSeconded",
+                serde_json::json!({ "who": addr(who), "proposalId": proposal_id }),
+            ),
+            Event::Governance(GovernanceEvent::ProposalVoted { voter, proposal_id, vote, weight }) => (
+                "ProposalVoted",
+                serde_json::json!({
+                    "voter": addr(voter),
+                    "proposalId": proposal_id,
+                    "vote": format!("{:?}", vote),
+                    "weight": bal(weight),
+                }),
+            ),
+            Event::Governance(GovernanceEvent::ProposalExecuted { executor, proposal_id }) => (
+                "ProposalExecuted",
+                serde_json::json!({ "executor": addr(executor), "proposalId": proposal_id }),
+            ),
+            Event::Governance(GovernanceEvent::EmergencyDeclarationSigned { who, signatures, required }) => (
+                "EmergencyDeclarationSigned",
+                serde_json::json!({ "who": addr(who), "signatures": signatures, "required": required }),
+            ),
+            Event::Governance(GovernanceEvent::EmergencyDeclared { expires_at }) => (
+                "EmergencyDeclared",
+                serde_json::json!({ "expiresAt": expires_at }),
+            ),
+            Event::Governance(GovernanceEvent::EmergencyRecoverySigned { who, signatures, required }) => (
+                "EmergencyRecoverySigned",
+                serde_json::json!({ "who": addr(who), "signatures": signatures, "required": required }),
+            ),
+            Event::Governance(GovernanceEvent::EmergencyRecovered) => ("EmergencyRecovered", serde_json::json!({})),
+            Event::Identity(IdentityEvent::IdentityDeclared { identity_id, owner, scope }) => (
+                "IdentityDeclared",
+                serde_json::json!({ "identityId": hash(identity_id), "owner": addr(owner), "scope": scope.0 }),
+            ),
+            Event::Identity(IdentityEvent::AttestationReceived { identity_id, attester, weight }) => (
+                "AttestationReceived",
+                serde_json::json!({ "identityId": hash(identity_id), "attester": addr(attester), "weight": weight }),
+            ),
+            Event::Identity(IdentityEvent::IdentityActivated { identity_id }) => (
+                "IdentityActivated",
+                serde_json::json!({ "identityId": hash(identity_id) }),
+            ),
+            Event::Identity(IdentityEvent::IdentityExpired { identity_id }) => (
+                "IdentityExpired",
+                serde_json::json!({ "identityId": hash(identity_id) }),
+            ),
+            Event::Identity(IdentityEvent::IdentityRevoked { identity_id, reason }) => (
+                "IdentityRevoked",
+                serde_json::json!({ "identityId": hash(identity_id), "reason": format!("{:?}", reason) }),
+            ),
+            Event::Identity(IdentityEvent::IdentityRenewed { identity_id, new_expiry }) => (
+                "IdentityRenewed",
+                serde_json::json!({ "identityId": hash(identity_id), "newExpiry": new_expiry }),
+            ),
+            Event::Identity(IdentityEvent::ReputationChanged { identity_id, old_score, new_score, reason }) => (
+                "ReputationChanged",
+                serde_json::json!({
+                    "identityId": hash(identity_id),
+                    "oldScore": old_score,
+                    "newScore": new_score,
+                    "reason": reason,
+                }),
+            ),
+            Event::Identity(IdentityEvent::StatusChanged { identity_id, old_status, new_status, reason }) => (
+                "StatusChanged",
+                serde_json::json!({
+                    "identityId": hash(identity_id),
+                    "oldStatus": format!("{:?}", old_status),
+                    "newStatus": format!("{:?}", new_status),
+                    "reason": reason,
+                }),
+            ),
+            Event::Personhood(PersonhoodEvent::ChallengeIssued { challenge_id, target, challenger, challenge_type }) => (
+                "ChallengeIssued",
+                serde_json::json!({
+                    "challengeId": hash(challenge_id),
+                    "target": hash(target),
+                    "challenger": addr(challenger),
+                    "challengeType": challenge_type,
+                }),
+            ),
+            Event::Personhood(PersonhoodEvent::ChallengeResponseSubmitted { challenge_id, target }) => (
+                "ChallengeResponseSubmitted",
+                serde_json::json!({ "challengeId": hash(challenge_id), "target": hash(target) }),
+            ),
+            Event::Personhood(PersonhoodEvent::ChallengeResolved { challenge_id, target, passed }) => (
+                "ChallengeResolved",
+                serde_json::json!({ "challengeId": hash(challenge_id), "target": hash(target), "passed": passed }),
+            ),
+            Event::Personhood(PersonhoodEvent::SybilClusterDetected { cluster_id, member_count, risk_score }) => (
+                "SybilClusterDetected",
+                serde_json::json!({ "clusterId": cluster_id, "memberCount": member_count, "riskScore": risk_score }),
+            ),
+            Event::Personhood(PersonhoodEvent::UniquenessVerified { identity_id, witness_count }) => (
+                "UniquenessVerified",
+                serde_json::json!({ "identityId": hash(identity_id), "witnessCount": witness_count }),
+            ),
+            Event::Personhood(PersonhoodEvent::PersonhoodScoreUpdated { identity_id, old_score, new_score }) => (
+                "PersonhoodScoreUpdated",
+                serde_json::json!({ "identityId": hash(identity_id), "oldScore": old_score, "newScore": new_score }),
+            ),
+            Event::Personhood(PersonhoodEvent::CeremonyCommitted { who, epoch }) => (
+                "CeremonyCommitted",
+                serde_json::json!({ "who": addr(who), "epoch": epoch }),
+            ),
+            Event::Personhood(PersonhoodEvent::PersonVerified { who, identity_id, epoch, expires_at }) => (
+                "PersonVerified",
+                serde_json::json!({
+                    "who": addr(who),
+                    "identityId": hash(identity_id),
+                    "epoch": epoch,
+                    "expiresAt": expires_at,
+                }),
+            ),
+            Event::Messaging(MessagingEvent::MessageDelivered { id, from, to, size, encrypted }) => (
+                "MessageDelivered",
+                serde_json::json!({
+                    "id": id,
+                    "from": addr(from),
+                    "to": addr(to),
+                    "size": size,
+                    "encrypted": encrypted,
+                }),
+            ),
+        };
+        Self {
+            contract: event.contract().to_string(),
+            name: name.to_string(),
+            data,
+        }
+    }
 }
 
 /// Transaction submit result
@@ -377,6 +823,33 @@ pub struct TransactionSubmitResult {
     pub message: String,
 }
 
+/// Idempotent transaction submit result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdempotentSubmitResult {
+    /// Hash of the transaction accepted for the key
+    pub hash: String,
+    /// The key was already used: this submission was not sent
+    pub duplicate: bool,
+    /// Message
+    pub message: String,
+}
+
+impl IdempotentSubmitResult {
+    pub fn new(hash: Hash, duplicate: bool) -> Self {
+        let message = if duplicate {
+            "Idempotency key already used: returning the original transaction"
+        } else {
+            "Transaction submitted successfully"
+        };
+        Self {
+            hash: format!("0x{}", hex::encode(hash.as_bytes())),
+            duplicate,
+            message: message.to_string(),
+        }
+    }
+}
+
 // =============================================================================
 // ACCOUNT TYPES
 // =============================================================================
@@ -448,6 +921,124 @@ impl AccountInfoRpc {
     }
 }
 
+/// Merkle proof of an account against a block's state root (state_getProof)
+///
+/// The leaf is the bincode-encoded account; hash it with Blake3 and fold in
+/// the sibling hashes to get `stateRoot`, then compare with the block header.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateProofRpc {
+    /// Block the proof is for
+    pub block_hash: String,
+    pub block_number: BlockNumber,
+    /// State root the proof verifies against
+    pub state_root: String,
+    /// Encoded account leaf (hex)
+    pub leaf: String,
+    /// Position of the leaf (accounts sorted by storage key)
+    pub leaf_index: usize,
+    /// Number of accounts in the tree
+    pub leaf_count: usize,
+    /// Sibling hashes from the leaf up to the root (hex)
+    pub proof: Vec<String>,
+    /// Decoded leaf
+    pub account: AccountInfoRpc,
+}
+
+impl StateProofRpc {
+    pub fn from_proof(address: &AccountId, block_hash: &Hash, proof: &MerkleProof) -> Result<Self, String> {
+        let info: AccountInfo = bincode::deserialize(&proof.leaf)
+            .map_err(|e| format!("Invalid account leaf: {}", e))?;
+        Ok(Self {
+            block_hash: format!("0x{}", hex::encode(block_hash.as_bytes())),
+            block_number: proof.block_number,
+            state_root: format!("0x{}", hex::encode(proof.root.as_bytes())),
+            leaf: format!("0x{}", hex::encode(&proof.leaf)),
+            leaf_index: proof.leaf_index,
+            leaf_count: proof.leaf_count,
+            proof: proof.proof.iter().map(|h| format!("0x{}", hex::encode(h))).collect(),
+            account: AccountInfoRpc::from_info(address, &info),
+        })
+    }
+}
+
+/// Finality proof of a block (grandpa_proveFinality)
+///
+/// Each signature is a validator's precommit over `signingMessage`; the proof
+/// holds if 2/3 of the validator set signed. Finalizing `justifiedNumber`
+/// finalizes all its ancestors, including `blockNumber`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalityProofRpc {
+    /// Block whose finality is proven
+    pub block_number: BlockNumber,
+    /// Block the justification was produced for (>= blockNumber)
+    pub justified_number: BlockNumber,
+    pub justified_hash: String,
+    pub epoch: EpochNumber,
+    pub round: u32,
+    /// Message signed by every precommit (hex)
+    pub signing_message: String,
+    pub signatures: Vec<FinalitySignatureRpc>,
+}
+
+/// One precommit signature of a finality proof
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinalitySignatureRpc {
+    pub validator: String,
+    pub signature: String,
+}
+
+impl FinalityProofRpc {
+    pub fn from_justification(block_number: BlockNumber, justification: &FinalityJustification) -> Self {
+        Self {
+            block_number,
+            justified_number: justification.block_number,
+            justified_hash: format!("0x{}", hex::encode(justification.block_hash.as_bytes())),
+            epoch: justification.epoch,
+            round: justification.round,
+            signing_message: format!("0x{}", hex::encode(justification.signing_message())),
+            signatures: justification
+                .signatures
+                .iter()
+                .map(|sig| FinalitySignatureRpc {
+                    validator: format!("0x{}", hex::encode(sig.validator.as_bytes())),
+                    signature: format!("0x{}", hex::encode(sig.signature.as_bytes())),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// State query that state_callAt can evaluate at a past block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoricalCall {
+    Account,
+    Balance,
+    Nonce,
+}
+
+impl HistoricalCall {
+    /// Map a live state method to its historical counterpart
+    pub fn from_method(method: &str) -> Option<Self> {
+        match method {
+            "state_getAccount" => Some(Self::Account),
+            "state_getBalance" => Some(Self::Balance),
+            "state_getNonce" => Some(Self::Nonce),
+            _ => None,
+        }
+    }
+
+    /// Result shaped like the live method's
+    pub fn result(self, info: &AccountInfoRpc) -> serde_json::Value {
+        match self {
+            Self::Account => serde_json::to_value(info).unwrap_or(serde_json::Value::Null),
+            Self::Balance => serde_json::json!(info.free_raw),
+            Self::Nonce => serde_json::json!(info.nonce),
+        }
+    }
+}
+
 /// Format balance in KRAT with proper decimal places
 fn format_krat(amount: Balance) -> String {
     let whole = amount / KRAT;
@@ -474,6 +1065,8 @@ fn format_krat(amount: Balance) -> String {
 pub struct PeerInfo {
     /// Peer ID
     pub peer_id: String,
+    /// "connected", "banned", ...
+    pub state: String,
     /// Addresses
     pub addresses: Vec<String>,
     /// Best block number
@@ -482,6 +1075,46 @@ pub struct PeerInfo {
     pub score: i32,
     /// Is bootstrap node
     pub is_bootstrap: bool,
+    /// Ban reason and end (banned peers)
+    pub ban: Option<PeerBan>,
+}
+
+impl From<&crate::network::PeerInfo> for PeerInfo {
+    fn from(info: &crate::network::PeerInfo) -> Self {
+        let state = match info.state {
+            PeerState::Connecting => "connecting",
+            PeerState::Connected => "connected",
+            PeerState::Disconnected => "disconnected",
+            PeerState::Banned => "banned",
+        };
+        Self {
+            peer_id: info.id.to_string(),
+            state: state.to_string(),
+            addresses: info.addresses.iter().map(|a| a.to_string()).collect(),
+            best_block: info.best_height,
+            score: info.score,
+            is_bootstrap: info.is_bootstrap,
+            ban: info.ban.clone(),
+        }
+    }
+}
+
+/// Connected and banned peers (system_peers)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemPeersRpc {
+    /// Connected peers
+    pub count: usize,
+    /// Connected peers first, then banned ones
+    pub peers: Vec<PeerInfo>,
+}
+
+impl SystemPeersRpc {
+    pub fn new(peers: &[crate::network::PeerInfo]) -> Self {
+        let mut peers: Vec<PeerInfo> = peers.iter().map(PeerInfo::from).collect();
+        peers.sort_by(|a, b| (a.ban.is_some(), &a.peer_id).cmp(&(b.ban.is_some(), &b.peer_id)));
+        Self { count: peers.iter().filter(|p| p.state == "connected").count(), peers }
+    }
 }
 
 /// Network status
@@ -548,6 +1181,149 @@ pub struct HealthStatus {
     pub peer_count: usize,
 }
 
+/// Validity window recommended for new transactions (chain_getMortality)
+///
+/// Wallets set `valid_until` to `validUntil`: a transaction not included by
+/// then is refused by the mempool and by block validation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MortalityRpc {
+    /// Best block of the node
+    pub best_block: BlockNumber,
+    /// Recommended window, in blocks
+    pub period: BlockNumber,
+    /// Recommended last block (best block + period)
+    pub valid_until: BlockNumber,
+}
+
+impl MortalityRpc {
+    pub fn new(best_block: BlockNumber) -> Self {
+        Self {
+            best_block,
+            period: DEFAULT_MORTALITY_BLOCKS,
+            valid_until: best_block.saturating_add(DEFAULT_MORTALITY_BLOCKS),
+        }
+    }
+}
+
+/// A chain competing with the local one
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForkRpc {
+    /// First block of the fork (child of the common ancestor)
+    pub branch: Hash,
+    /// Last block shared with the local chain
+    pub common_ancestor: BlockNumber,
+    /// Best block announced on the fork
+    pub head: Hash,
+    /// Number of the best block
+    pub number: BlockNumber,
+    /// Block the peers' latest justification finalizes (unverified)
+    pub justified: Option<BlockNumber>,
+    /// Peers following the fork
+    pub peers: Vec<String>,
+    /// Being downloaded to be switched to
+    pub downloading: bool,
+}
+
+/// Competing chains known to the node (chain_getForks)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForksRpc {
+    /// Finalized block of the local chain: no fork branching off below it
+    /// is ever switched to
+    pub finalized: Option<BlockNumber>,
+    pub forks: Vec<ForkRpc>,
+}
+
+impl From<&ForksReport> for ForksRpc {
+    fn from(report: &ForksReport) -> Self {
+        let downloading = report.downloading;
+        let forks = report
+            .forks
+            .iter()
+            .map(|fork| ForkRpc {
+                branch: fork.branch,
+                common_ancestor: fork.common_ancestor,
+                head: fork.head,
+                number: fork.number,
+                justified: fork.justified,
+                peers: fork.peers.iter().map(|p| p.to_string()).collect(),
+                downloading: downloading == Some(fork.branch),
+            })
+            .collect();
+        Self { finalized: report.finalized, forks }
+    }
+}
+
+/// Switch to a fork (chain_getReorgEvents)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorgEventRpc {
+    /// Cursor for the next call
+    pub id: u64,
+    /// First block replaced
+    pub block_number: BlockNumber,
+    /// Number of blocks reverted
+    pub depth: u64,
+    /// Transactions of the reverted blocks included by the fork or back in
+    /// the mempool (0x...)
+    pub orphaned_transactions: Vec<String>,
+    /// Transactions of the reverted blocks no longer valid (0x...)
+    pub dropped_transactions: Vec<String>,
+}
+
+/// Reorgs after a cursor, oldest first (chain_getReorgEvents)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReorgEventsRpc {
+    pub events: Vec<ReorgEventRpc>,
+}
+
+impl From<&[ReorgEvent]> for ReorgEventsRpc {
+    fn from(events: &[ReorgEvent]) -> Self {
+        let hashes = |hashes: &[Hash]| hashes.iter().map(|h| format!("0x{}", hex::encode(h.as_bytes()))).collect();
+        let events = events
+            .iter()
+            .map(|event| ReorgEventRpc {
+                id: event.id,
+                block_number: event.ancestor + 1,
+                depth: event.depth,
+                orphaned_transactions: hashes(&event.orphaned),
+                dropped_transactions: hashes(&event.dropped),
+            })
+            .collect();
+        Self { events }
+    }
+}
+
+/// Network a node runs (system_chain)
+///
+/// Transactions are signed for `genesis_hash`: a wallet that signs with the
+/// hash of the node it submits to cannot replay them on another network.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemChainRpc {
+    /// Chain name
+    pub name: String,
+    pub chain_id: u32,
+    /// Genesis hash (0x...)
+    pub genesis_hash: String,
+    pub protocol_name: String,
+    pub protocol_version: u32,
+}
+
+impl SystemChainRpc {
+    pub fn new(chain: &ChainConfig, genesis_hash: Hash) -> Self {
+        Self {
+            name: chain.chain_name.clone(),
+            chain_id: chain.chain_id,
+            genesis_hash: format!("0x{}", hex::encode(genesis_hash.as_bytes())),
+            protocol_name: chain.network.protocol_name.clone(),
+            protocol_version: chain.network.protocol_version,
+        }
+    }
+}
+
 // =============================================================================
 // MEMPOOL TYPES
 // =============================================================================
@@ -564,6 +1340,47 @@ pub struct MempoolStatus {
     pub stats: MempoolStats,
 }
 
+/// Pending transaction with its priority
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MempoolTransaction {
+    #[serde(flatten)]
+    pub transaction: TransactionInfo,
+    /// Priority fee on top of the base fee
+    pub tip: Balance,
+    /// Static fee plus tip per KiB of the encoded transaction
+    pub fee_rate: u128,
+}
+
+impl From<&SignedTransaction> for MempoolTransaction {
+    fn from(tx: &SignedTransaction) -> Self {
+        Self {
+            transaction: TransactionInfo::from(tx),
+            tip: tx.transaction.tip,
+            fee_rate: fee_rate(tx),
+        }
+    }
+}
+
+/// Mempool content, in inclusion order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MempoolContent {
+    /// Includable now, in the order block production takes them
+    pub ready: Vec<MempoolTransaction>,
+    /// Waiting for an earlier nonce of their sender
+    pub future: Vec<MempoolTransaction>,
+}
+
+impl From<&PoolContent> for MempoolContent {
+    fn from(content: &PoolContent) -> Self {
+        Self {
+            ready: content.ready.iter().map(MempoolTransaction::from).collect(),
+            future: content.future.iter().map(MempoolTransaction::from).collect(),
+        }
+    }
+}
+
 /// Mempool statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -580,39 +1397,1426 @@ pub struct MempoolStats {
     pub total_replaced: u64,
 }
 
-// =============================================================================
-// VALIDATOR TYPES
-// =============================================================================
+/// Most blocks a fee_history call covers
+pub const MAX_FEE_HISTORY_BLOCKS: u64 = 1024;
 
-/// Validator information (RPC format)
+/// Recent base fees, for fee estimation (fee_history)
+///
+/// A call costs its static fee × baseFee / baseFeePrecision. `baseFees` has
+/// one entry per block from `oldestBlock` on, plus the base fee of the next
+/// block: what a transaction submitted now pays.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ValidatorInfoRpc {
-    /// Validator address
-    pub address: String,
-    /// Staked amount
-    pub stake: Balance,
-    /// Validator credits
-    pub validator_credits: u64,
-    /// Blocks produced
-    pub blocks_produced: u64,
-    /// Blocks missed
-    pub blocks_missed: u32,
-    /// Is active
-    pub is_active: bool,
+pub struct FeeHistoryRpc {
+    pub oldest_block: BlockNumber,
+    pub base_fees: Vec<u64>,
+    /// Share of the block capacity used, per block (0.0 - 1.0)
+    pub fullness: Vec<f64>,
+    pub base_fee_precision: u64,
+}
+
+impl From<&FeeHistory> for FeeHistoryRpc {
+    fn from(history: &FeeHistory) -> Self {
+        let mut base_fees: Vec<u64> = history.blocks.iter().map(|b| b.base_fee).collect();
+        base_fees.push(history.next_base_fee);
+        Self {
+            oldest_block: history.oldest_block,
+            base_fees,
+            fullness: history.blocks.iter().map(BlockFee::fullness).collect(),
+            base_fee_precision: BASE_FEE_PRECISION,
+        }
+    }
 }
 
 // =============================================================================
-// HELPER FUNCTIONS
+// VALIDATOR TYPES
 // =============================================================================
 
-/// Parse hex string to AccountId
-pub fn parse_account_id(s: &str) -> Result<AccountId, String> {
-    let s = s.strip_prefix("0x").unwrap_or(s);
-    let bytes = hex::decode(s).map_err(|e| format!("Invalid hex: {}", e))?;
-    if bytes.len() != 32 {
-        return Err(format!("Expected 32 bytes, got {}", bytes.len()));
-    }
+/// New session keys and the transaction binding them (author_rotateKeys)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RotateKeysRpc {
+    /// Public keys concatenated (SessionKeys::to_bytes), 0x-prefixed hex
+    pub session_keys: String,
+    /// SetSessionKeys transaction, signed with the account key
+    pub transaction_hash: String,
+    /// First epoch signed with the new keys if the transaction is included this epoch
+    pub effective_epoch: EpochNumber,
+}
+
+/// Equivocation evidence awaiting a report on chain (slashing_pendingEvidence)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingEvidenceRpc {
+    /// Offence id (key of the punished offence in state)
+    pub offence: String,
+    /// "doubleProposal" or "doubleVote"
+    pub kind: String,
+    /// Accused validator
+    pub offender: String,
+    pub epoch: EpochNumber,
+    /// Slot of a double proposal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slot: Option<SlotNumber>,
+    /// Finality round of a double vote
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub round: Option<u32>,
+    /// The two conflicting block hashes (headers or vote targets)
+    pub conflicting: Vec<String>,
+    /// A ReportEquivocation was submitted by this node
+    pub reported: bool,
+}
+
+impl PendingEvidenceRpc {
+    pub fn new(offence: &Hash, pending: &PendingEvidence) -> Self {
+        let to_hex = |h: Hash| format!("0x{}", hex::encode(h.as_bytes()));
+        let evidence = &pending.evidence;
+        let (slot, round, conflicting) = match evidence {
+            EquivocationEvidence::DoubleProposal { header_1, header_2 } => {
+                (Some(header_1.slot), None, vec![to_hex(header_1.hash()), to_hex(header_2.hash())])
+            }
+            EquivocationEvidence::DoubleVote(proof) => (
+                None,
+                Some(proof.vote1.round),
+                vec![to_hex(proof.vote1.target_hash), to_hex(proof.vote2.target_hash)],
+            ),
+        };
+        Self {
+            offence: to_hex(*offence),
+            kind: evidence.kind().to_string(),
+            offender: format!("0x{}", hex::encode(evidence.offender().as_bytes())),
+            epoch: evidence.epoch(),
+            slot,
+            round,
+            conflicting,
+            reported: pending.reported,
+        }
+    }
+}
+
+/// Stake delegated by an account to a validator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegationRpc {
+    pub delegator: String,
+    pub validator: String,
+    pub amount: Balance,
+}
+
+/// Delegations of an account (staking_getDelegations)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegationsRpc {
+    pub delegator: String,
+    /// Sum of the delegations (part of the reserved balance)
+    pub total_delegated: Balance,
+    pub delegations: Vec<DelegationRpc>,
+}
+
+impl DelegationsRpc {
+    pub fn new(delegator: &AccountId, delegations: &[(AccountId, Balance)]) -> Self {
+        let address = format!("0x{}", hex::encode(delegator.as_bytes()));
+        Self {
+            delegator: address.clone(),
+            total_delegated: delegations.iter().map(|(_, amount)| amount).sum(),
+            delegations: delegations
+                .iter()
+                .map(|(validator, amount)| DelegationRpc {
+                    delegator: address.clone(),
+                    validator: format!("0x{}", hex::encode(validator.as_bytes())),
+                    amount: *amount,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Stake delegated to a validator (staking_getDelegators)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegationPoolRpc {
+    pub validator: String,
+    /// Share of the delegators' rewards kept by the validator, in percent
+    pub commission: u8,
+    pub total_delegated: Balance,
+    pub delegators: Vec<DelegationRpc>,
+}
+
+impl DelegationPoolRpc {
+    pub fn new(validator: &AccountId, pool: &DelegationPool, commission: u8) -> Self {
+        let address = format!("0x{}", hex::encode(validator.as_bytes()));
+        Self {
+            validator: address.clone(),
+            commission,
+            total_delegated: pool.total(),
+            delegators: pool
+                .delegators
+                .iter()
+                .map(|(delegator, amount)| DelegationRpc {
+                    delegator: format!("0x{}", hex::encode(delegator.as_bytes())),
+                    validator: address.clone(),
+                    amount: *amount,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Stake unlocking at one epoch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnbondingChunkRpc {
+    pub epoch: EpochNumber,
+    /// First block at which the chunk can be withdrawn
+    pub unlock_at: BlockNumber,
+    pub amount: Balance,
+}
+
+/// Unbonding queue of an account (staking_getUnbonding)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnbondingRpc {
+    pub account: String,
+    pub total: Balance,
+    /// Amount a WithdrawUnbonded in the next block would release
+    pub withdrawable: Balance,
+    pub chunks: Vec<UnbondingChunkRpc>,
+}
+
+impl UnbondingRpc {
+    pub fn new(account: &AccountId, queue: &UnbondingQueue, next_block: BlockNumber) -> Self {
+        Self {
+            account: format!("0x{}", hex::encode(account.as_bytes())),
+            total: queue.total(),
+            withdrawable: queue.withdrawable(next_block),
+            chunks: queue
+                .chunks
+                .iter()
+                .map(|(epoch, amount)| UnbondingChunkRpc {
+                    epoch: *epoch,
+                    unlock_at: UnbondingQueue::unlock_block(*epoch),
+                    amount: *amount,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Rewards earned in one epoch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochRewardRpc {
+    pub epoch: EpochNumber,
+    pub amount: Balance,
+}
+
+/// Delegation rewards owed to an account (staking_pendingRewards)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingRewardsRpc {
+    pub account: String,
+    pub total: Balance,
+    /// Amount a ClaimRewards in the next block would pay
+    pub claimable: Balance,
+    /// Rewards of epochs too old to be listed
+    pub carried: Balance,
+    pub epochs: Vec<EpochRewardRpc>,
+}
+
+impl PendingRewardsRpc {
+    pub fn new(account: &AccountId, ledger: &RewardLedger, next_block: BlockNumber) -> Self {
+        Self {
+            account: format!("0x{}", hex::encode(account.as_bytes())),
+            total: ledger.total(),
+            claimable: ledger.claimable(next_block / EPOCH_DURATION_BLOCKS),
+            carried: ledger.carried,
+            epochs: ledger
+                .epochs
+                .iter()
+                .map(|(epoch, amount)| EpochRewardRpc { epoch: *epoch, amount: *amount })
+                .collect(),
+        }
+    }
+}
+
+/// Validator information (RPC format)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorInfoRpc {
+    /// Validator address
+    pub address: String,
+    /// Staked amount
+    pub stake: Balance,
+    /// Validator credits
+    pub validator_credits: u64,
+    /// Blocks produced
+    pub blocks_produced: u64,
+    /// Blocks missed
+    pub blocks_missed: u32,
+    /// Is active
+    pub is_active: bool,
+    /// Registry status (Active, Inactive, Unbonding, Slashed, Jailed)
+    pub status: String,
+    /// Reputation score (0-100)
+    pub reputation: u8,
+    /// Bootstrap validator (may produce without stake during bootstrap era)
+    pub is_bootstrap_validator: bool,
+    /// Block the validator registered at
+    pub registered_at: BlockNumber,
+    /// Share of assigned slots actually produced (0-100)
+    pub participation_percent: f64,
+    /// Number of times slashed
+    pub slash_count: u32,
+    /// Total amount slashed
+    pub total_slashed: Balance,
+    /// Clock synchronization failures
+    pub clock_sync_failures: u32,
+    /// Balance currently unbonding
+    pub unbonding_balance: Balance,
+    /// Block at which unbonding completes (if unbonding)
+    pub unbonding_at: Option<BlockNumber>,
+    /// Share of the delegators' rewards kept, in percent
+    pub commission: u8,
+    /// Commission announced for a later epoch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub announced_commission: Option<AnnouncedCommissionRpc>,
+    /// Session: eligible for block production right now
+    pub can_participate: bool,
+    /// Session: voted in the last finalized round
+    pub finality_voter: bool,
+    /// Session: current epoch
+    pub current_epoch: EpochNumber,
+    /// Session: blocks until the next epoch starts
+    pub blocks_until_next_epoch: BlockNumber,
+}
+
+impl From<&ValidatorReport> for ValidatorInfoRpc {
+    fn from(report: &ValidatorReport) -> Self {
+        let info = &report.info;
+        let assigned = info.blocks_produced + info.blocks_missed as u64;
+        let current_epoch = report.current_block / EPOCH_DURATION_BLOCKS;
+        let participation_percent = if assigned == 0 {
+            100.0
+        } else {
+            info.blocks_produced as f64 * 100.0 / assigned as f64
+        };
+
+        Self {
+            address: format!("0x{}", hex::encode(info.id.as_bytes())),
+            stake: info.stake,
+            validator_credits: report.credits.as_ref().map(|c| c.total_vc()).unwrap_or(0),
+            blocks_produced: info.blocks_produced,
+            blocks_missed: info.blocks_missed,
+            is_active: report.is_active,
+            status: format!("{:?}", info.status),
+            reputation: info.reputation,
+            is_bootstrap_validator: info.is_bootstrap_validator,
+            registered_at: info.registered_at,
+            participation_percent,
+            slash_count: info.slash_count,
+            total_slashed: info.total_slashed,
+            clock_sync_failures: report.clock_sync_failures,
+            unbonding_balance: report.unbonding_balance,
+            unbonding_at: info.unbonding_at,
+            commission: report.commission.at(current_epoch),
+            announced_commission: report
+                .commission
+                .pending(current_epoch)
+                .map(|(commission, effective_epoch)| AnnouncedCommissionRpc { commission, effective_epoch }),
+            can_participate: report.can_participate,
+            finality_voter: report.finality_voter,
+            current_epoch,
+            blocks_until_next_epoch: EPOCH_DURATION_BLOCKS - report.current_block % EPOCH_DURATION_BLOCKS,
+        }
+    }
+}
+
+/// Commission a validator announced (validator_getInfo)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnouncedCommissionRpc {
+    pub commission: u8,
+    /// First epoch the commission applies to
+    pub effective_epoch: EpochNumber,
+}
+
+/// Validator credits breakdown (RPC format)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorCreditsRpc {
+    /// Validator address
+    pub address: String,
+    /// Credits from governance votes
+    pub vote_credits: u32,
+    /// Credits from uptime
+    pub uptime_credits: u32,
+    /// Credits from accepted arbitrations
+    pub arbitration_credits: u32,
+    /// Credits from seniority
+    pub seniority_credits: u32,
+    /// Total credits
+    pub total: u64,
+    /// Penalty from clock synchronization failures
+    pub clock_penalty: u64,
+    /// Credits removed by slashing over the recorded history (VC does not decay with time)
+    pub deducted: u64,
+    /// Active epochs counted for seniority
+    pub active_epochs: u32,
+    /// Last update block
+    pub last_update: BlockNumber,
+}
+
+impl From<&ValidatorReport> for ValidatorCreditsRpc {
+    fn from(report: &ValidatorReport) -> Self {
+        let credits = report.credits.as_ref();
+        Self {
+            address: format!("0x{}", hex::encode(report.info.id.as_bytes())),
+            vote_credits: credits.map(|c| c.vote_credits).unwrap_or(0),
+            uptime_credits: credits.map(|c| c.uptime_credits).unwrap_or(0),
+            arbitration_credits: credits.map(|c| c.arbitration_credits).unwrap_or(0),
+            seniority_credits: credits.map(|c| c.seniority_credits).unwrap_or(0),
+            total: credits.map(|c| c.total_vc()).unwrap_or(0),
+            clock_penalty: report.clock_vc_penalty,
+            deducted: report.vc_history.deducted(),
+            active_epochs: credits.map(|c| c.active_epochs).unwrap_or(0),
+            last_update: credits.map(|c| c.last_update).unwrap_or(0),
+        }
+    }
+}
+
+/// Per-epoch VC history of a validator (RPC format)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VcHistoryRpc {
+    /// Validator address
+    pub address: String,
+    /// Current total credits
+    pub total: u64,
+    /// Epochs with a VC change, newest first
+    pub epochs: Vec<VcEpochRpc>,
+}
+
+/// VC accrual and deductions of one epoch (RPC format)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VcEpochRpc {
+    /// Epoch
+    pub epoch: EpochNumber,
+    /// Credits earned from governance votes
+    pub vote_credits: u32,
+    /// Credits earned from uptime
+    pub uptime_credits: u32,
+    /// Credits earned from accepted arbitrations
+    pub arbitration_credits: u32,
+    /// Credits earned from seniority
+    pub seniority_credits: u32,
+    /// Credits removed by slashing
+    pub deducted: u64,
+    /// Total credits at the end of the epoch
+    pub total: u64,
+}
+
+impl VcHistoryRpc {
+    /// Latest `epochs` epochs of the validator's history
+    pub fn new(report: &ValidatorReport, epochs: usize) -> Self {
+        Self {
+            address: format!("0x{}", hex::encode(report.info.id.as_bytes())),
+            total: report.credits.as_ref().map(|c| c.total_vc()).unwrap_or(0),
+            epochs: report
+                .vc_history
+                .latest(epochs)
+                .map(|(epoch, change)| VcEpochRpc {
+                    epoch: *epoch,
+                    vote_credits: change.vote,
+                    uptime_credits: change.uptime,
+                    arbitration_credits: change.arbitration,
+                    seniority_credits: change.seniority,
+                    deducted: change.deducted,
+                    total: change.total,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Active validator and its stake (chain_getEpochInfo)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochValidatorRpc {
+    pub address: String,
+    pub stake: Balance,
+}
+
+/// Bootstrap era status (chain_getEpochInfo)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BootstrapInfoRpc {
+    /// Bootstrap multipliers apply (epoch before `endEpoch`)
+    pub active: bool,
+    pub end_epoch: EpochNumber,
+    /// "Active", "Extended" (time limit passed, too few validators) or "Completed"
+    pub status: String,
+    /// Validators still needed to exit bootstrap
+    pub validators_needed: u32,
+}
+
+/// Network security state and its effects (chain_getEpochInfo)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityStateRpc {
+    /// Bootstrap, Normal, Degraded, Restricted, Emergency or BootstrapRecovery
+    pub state: String,
+    /// Epoch the state was entered (None for Bootstrap and Normal)
+    pub entered_at: Option<EpochNumber>,
+    /// Block time multiplier (0: no new blocks)
+    pub block_time_multiplier: u32,
+    /// Governance timelock multiplier (0: governance frozen)
+    pub timelock_multiplier: u32,
+}
+
+/// Current epoch and network health (chain_getEpochInfo)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochInfoRpc {
+    pub epoch: EpochNumber,
+    pub start_block: BlockNumber,
+    /// Last block of the epoch
+    pub end_block: BlockNumber,
+    pub current_block: BlockNumber,
+    pub total_stake: Balance,
+    pub validators: Vec<EpochValidatorRpc>,
+    pub bootstrap: BootstrapInfoRpc,
+    pub security: SecurityStateRpc,
+}
+
+impl From<&EpochReport> for EpochInfoRpc {
+    fn from(report: &EpochReport) -> Self {
+        let start_block = report.epoch * EPOCH_DURATION_BLOCKS;
+        let (status, validators_needed) = match &report.bootstrap_status {
+            BootstrapStatus::Active { validators_needed, .. } => ("Active", *validators_needed),
+            BootstrapStatus::Extended { validators_needed, .. } => ("Extended", *validators_needed),
+            BootstrapStatus::Completed => ("Completed", 0),
+        };
+        let (state, entered_at) = security_state_name(&report.security_state);
+
+        Self {
+            epoch: report.epoch,
+            start_block,
+            end_block: start_block + EPOCH_DURATION_BLOCKS - 1,
+            current_block: report.current_block,
+            total_stake: report.validators.iter().map(|(_, stake)| *stake).sum(),
+            validators: report
+                .validators
+                .iter()
+                .map(|(id, stake)| EpochValidatorRpc {
+                    address: format!("0x{}", hex::encode(id.as_bytes())),
+                    stake: *stake,
+                })
+                .collect(),
+            bootstrap: BootstrapInfoRpc {
+                active: report.is_bootstrap,
+                end_epoch: report.bootstrap_end_epoch,
+                status: status.to_string(),
+                validators_needed,
+            },
+            security: SecurityStateRpc {
+                state: state.to_string(),
+                entered_at,
+                block_time_multiplier: report.block_time_multiplier,
+                timelock_multiplier: report.timelock_multiplier,
+            },
+        }
+    }
+}
+
+/// Name of a security state and the epoch it was entered
+fn security_state_name(state: &NetworkSecurityState) -> (&'static str, Option<EpochNumber>) {
+    match state {
+        NetworkSecurityState::Bootstrap => ("Bootstrap", None),
+        NetworkSecurityState::Normal => ("Normal", None),
+        NetworkSecurityState::DegradedSecurityMode { entered_at, .. } => ("Degraded", Some(*entered_at)),
+        NetworkSecurityState::SafetyHaltMode { entered_at, .. } => ("Restricted", Some(*entered_at)),
+        NetworkSecurityState::TerminalMode { entered_at, .. } => ("Emergency", Some(*entered_at)),
+        NetworkSecurityState::BootstrapRecoveryMode { entered_at, .. } => ("BootstrapRecovery", Some(*entered_at)),
+    }
+}
+
+/// Votes on a governance proposal (governance_getTally)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalTallyRpc {
+    pub proposal_id: ProposalId,
+    pub yes: Balance,
+    pub no: Balance,
+    pub abstain: Balance,
+    /// Staked balance when the proposal was submitted
+    pub eligible: Balance,
+    /// Votes cast, percent of `eligible`
+    pub participation: u8,
+    /// Yes, percent of Yes + No
+    pub approval: u8,
+    /// Participation needed
+    pub quorum: u8,
+    /// Approval needed
+    pub threshold: u8,
+    pub voters: usize,
+    pub seconds: usize,
+    pub seconds_needed: usize,
+    /// Endorsed, quorum and threshold reached
+    pub carried: bool,
+}
+
+impl From<&Proposal> for ProposalTallyRpc {
+    fn from(proposal: &Proposal) -> Self {
+        let participation = match proposal.eligible_votes {
+            0 => 0,
+            eligible => (proposal.total_votes().saturating_mul(100) / eligible).min(100) as u8,
+        };
+        Self {
+            proposal_id: proposal.id,
+            yes: proposal.yes_votes,
+            no: proposal.no_votes,
+            abstain: proposal.abstain_votes,
+            eligible: proposal.eligible_votes,
+            participation,
+            approval: proposal.approval_percentage(),
+            quorum: MIN_QUORUM_PERCENT,
+            threshold: proposal.proposal_type.required_threshold(),
+            voters: proposal.votes.len(),
+            seconds: proposal.seconds.len(),
+            seconds_needed: MIN_SECONDS,
+            carried: proposal.carried(),
+        }
+    }
+}
+
+/// Timelock of a governance proposal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalTimelockRpc {
+    /// Base period of the proposal type, in blocks
+    pub period: BlockNumber,
+    /// Multiplier of the security state (×2 in Degraded)
+    pub multiplier: u32,
+    /// Governance frozen: the timelock does not run
+    pub frozen: bool,
+    /// Block the proposal becomes executable (None while frozen)
+    pub ends_at: Option<BlockNumber>,
+}
+
+/// A governance proposal (governance_getProposal, governance_listProposals)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposalRpc {
+    pub id: ProposalId,
+    pub proposer: String,
+    /// ProposalType variant
+    pub kind: String,
+    /// Fields of the proposal type
+    pub details: serde_json::Value,
+    pub description: Option<String>,
+    /// Active, Passed (in timelock), Rejected, ReadyToExecute or Expired
+    pub status: String,
+    pub created_at: BlockNumber,
+    pub voting_ends_at: BlockNumber,
+    pub seconded_by: Vec<String>,
+    pub tally: ProposalTallyRpc,
+    pub timelock: ProposalTimelockRpc,
+}
+
+impl ProposalRpc {
+    pub fn new(proposal: &Proposal, current_block: BlockNumber, timelock_multiplier: u32) -> Self {
+        let addr = |a: &AccountId| format!("0x{}", hex::encode(a.as_bytes()));
+        let (kind, details) = match &proposal.proposal_type {
+            ProposalType::ParameterChange { parameter, old_value, new_value } => (
+                "ParameterChange",
+                serde_json::json!({ "parameter": parameter, "oldValue": old_value, "newValue": new_value }),
+            ),
+            ProposalType::AddValidator { validator } => {
+                ("AddValidator", serde_json::json!({ "validator": addr(validator) }))
+            }
+            ProposalType::RemoveValidator { validator } => {
+                ("RemoveValidator", serde_json::json!({ "validator": addr(validator) }))
+            }
+            ProposalType::ExitDissolve => ("ExitDissolve", serde_json::json!({})),
+            ProposalType::ExitMerge { target_chain } => {
+                ("ExitMerge", serde_json::json!({ "targetChain": target_chain.0 }))
+            }
+            ProposalType::ExitReattachRoot => ("ExitReattachRoot", serde_json::json!({})),
+            ProposalType::ExitJoinHost { host_chain } => {
+                ("ExitJoinHost", serde_json::json!({ "hostChain": host_chain.0 }))
+            }
+            ProposalType::LeaveHost => ("LeaveHost", serde_json::json!({})),
+            ProposalType::RequestAffiliation { host_chain } => {
+                ("RequestAffiliation", serde_json::json!({ "hostChain": host_chain.0 }))
+            }
+            ProposalType::TreasurySpend { recipient, amount, reason } => (
+                "TreasurySpend",
+                serde_json::json!({ "recipient": addr(recipient), "amount": amount, "reason": reason }),
+            ),
+            ProposalType::ProtocolChange { changes, rationale } => (
+                "ProtocolChange",
+                serde_json::json!({ "changes": changes, "rationale": rationale }),
+            ),
+            ProposalType::Custom { title, description, data } => (
+                "Custom",
+                serde_json::json!({
+                    "title": title,
+                    "description": description,
+                    "data": format!("0x{}", hex::encode(data)),
+                }),
+            ),
+        };
+
+        Self {
+            id: proposal.id,
+            proposer: addr(&proposal.proposer),
+            kind: kind.to_string(),
+            details,
+            description: proposal.description.clone(),
+            status: format!("{:?}", proposal.status_at(current_block, timelock_multiplier)),
+            created_at: proposal.created_at,
+            voting_ends_at: proposal.voting_ends_at,
+            seconded_by: proposal.seconds.iter().map(addr).collect(),
+            tally: ProposalTallyRpc::from(proposal),
+            timelock: ProposalTimelockRpc {
+                period: proposal.proposal_type.timelock_period(),
+                multiplier: timelock_multiplier,
+                frozen: timelock_multiplier == 0,
+                ends_at: proposal.timelock_end(timelock_multiplier),
+            },
+        }
+    }
+
+    /// Proposals of a report, newest first
+    pub fn from_report(report: &GovernanceReport) -> Vec<Self> {
+        report
+            .proposals
+            .iter()
+            .rev()
+            .map(|proposal| Self::new(proposal, report.current_block, report.timelock_multiplier))
+            .collect()
+    }
+}
+
+/// Treasury account and its commitments (treasury_balance)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreasuryRpc {
+    pub account: String,
+    /// Free balance of the treasury account
+    pub balance: Balance,
+    /// Approved spends not paid yet (in timelock or ready to execute)
+    pub committed: Balance,
+    /// Balance left once the approved spends are paid
+    pub available: Balance,
+    pub pending_spends: usize,
+}
+
+impl From<&TreasuryReport> for TreasuryRpc {
+    fn from(report: &TreasuryReport) -> Self {
+        let governance = &report.governance;
+        let approved: Vec<Balance> = governance
+            .proposals
+            .iter()
+            .filter(|p| {
+                matches!(
+                    p.status_at(governance.current_block, governance.timelock_multiplier),
+                    ProposalStatus::Passed | ProposalStatus::ReadyToExecute
+                )
+            })
+            .filter_map(|p| match &p.proposal_type {
+                ProposalType::TreasurySpend { amount, .. } => Some(*amount),
+                _ => None,
+            })
+            .collect();
+        let committed = approved.iter().fold(0, |sum: Balance, amount| sum.saturating_add(*amount));
+        Self {
+            account: format!("0x{}", hex::encode(report.account.as_bytes())),
+            balance: report.balance,
+            committed,
+            available: report.balance.saturating_sub(committed),
+            pending_spends: approved.len(),
+        }
+    }
+}
+
+/// Governed protocol parameters and the changes scheduled (governance_getParameters)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolParametersRpc {
+    /// Block the parameters took effect at
+    pub active_since: BlockNumber,
+    pub fee_shares: FeeSharesRpc,
+    /// Adaptive inflation bounds, in basis points
+    pub inflation_floor_bps: u16,
+    pub inflation_cap_bps: u16,
+    /// Approved changes waiting for their epoch boundary, soonest first
+    pub scheduled: Vec<ScheduledChangeRpc>,
+}
+
+/// Fee split, in percent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeSharesRpc {
+    pub producer: u8,
+    pub finality_voters: u8,
+    pub burn: u8,
+    pub treasury: u8,
+}
+
+/// Approved ProtocolChange proposal not enacted yet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledChangeRpc {
+    pub proposal_id: ProposalId,
+    pub changes: serde_json::Value,
+    /// Epoch boundary the changes take effect at
+    pub enacts_at: BlockNumber,
+}
+
+/// Approved ProtocolChange proposals and their enactment block, soonest first
+fn scheduled_changes(governance: &GovernanceReport) -> Vec<(ProposalId, &[ParameterChange], BlockNumber)> {
+    // Enactment follows the on-chain timelock, whatever the local security state
+    let mut scheduled: Vec<_> = governance
+        .proposals
+        .iter()
+        .filter(|p| {
+            matches!(
+                p.status_at(governance.current_block, 1),
+                ProposalStatus::Passed | ProposalStatus::ReadyToExecute
+            )
+        })
+        .filter_map(|p| match (&p.proposal_type, p.timelock_end(1)) {
+            (ProposalType::ProtocolChange { changes, .. }, Some(end)) => {
+                Some((p.id, changes.as_slice(), enactment_block(end)))
+            }
+            _ => None,
+        })
+        .collect();
+    scheduled.sort_by_key(|(proposal_id, _, enacts_at)| (*enacts_at, *proposal_id));
+    scheduled
+}
+
+impl From<&ParametersReport> for ProtocolParametersRpc {
+    fn from(report: &ParametersReport) -> Self {
+        let economics = &report.parameters.economics;
+        let scheduled = scheduled_changes(&report.governance)
+            .into_iter()
+            .map(|(proposal_id, changes, enacts_at)| ScheduledChangeRpc {
+                proposal_id,
+                changes: serde_json::json!(changes),
+                enacts_at,
+            })
+            .collect();
+        Self {
+            active_since: report.parameters.active_since,
+            fee_shares: FeeSharesRpc {
+                producer: economics.fee_shares.producer,
+                finality_voters: economics.fee_shares.finality_voters,
+                burn: economics.fee_shares.burn,
+                treasury: economics.fee_shares.treasury,
+            },
+            inflation_floor_bps: economics.inflation_floor_bps.value(),
+            inflation_cap_bps: economics.inflation_cap_bps.value(),
+            scheduled,
+        }
+    }
+}
+
+/// Protocol version in force and the upgrade scheduled (upgrade_getStatus)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeStatusRpc {
+    /// Version of the blocks produced now
+    pub current_version: String,
+    /// Newest version this node validates
+    pub supported_version: String,
+    /// Next approved upgrade, if any
+    pub scheduled: Option<ScheduledUpgradeRpc>,
+    /// False when this node must be upgraded to follow the chain
+    pub ready: bool,
+}
+
+/// Approved protocol upgrade not active yet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledUpgradeRpc {
+    pub proposal_id: ProposalId,
+    pub version: String,
+    pub activation_epoch: EpochNumber,
+    /// First block of the activation epoch
+    pub activation_block: BlockNumber,
+    pub blocks_remaining: BlockNumber,
+    /// This node validates the new version
+    pub supported: bool,
+}
+
+impl From<&ParametersReport> for UpgradeStatusRpc {
+    fn from(report: &ParametersReport) -> Self {
+        let current = report.parameters.version;
+        let scheduled = scheduled_changes(&report.governance).into_iter().find_map(|(proposal_id, changes, enacts_at)| {
+            let version = changes.iter().filter_map(ParameterChange::protocol_upgrade).last()?;
+            Some(ScheduledUpgradeRpc {
+                proposal_id,
+                version: version.to_string(),
+                activation_epoch: enacts_at / EPOCH_DURATION_BLOCKS,
+                activation_block: enacts_at,
+                blocks_remaining: enacts_at.saturating_sub(report.governance.current_block),
+                supported: !version.is_newer(&SUPPORTED_PROTOCOL_VERSION),
+            })
+        });
+        Self {
+            current_version: current.to_string(),
+            supported_version: SUPPORTED_PROTOCOL_VERSION.to_string(),
+            ready: !current.is_newer(&SUPPORTED_PROTOCOL_VERSION) && scheduled.as_ref().is_none_or(|s| s.supported),
+            scheduled,
+        }
+    }
+}
+
+/// Entry of the parameter change audit log (governance_parameterLog)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParameterChangeRecordRpc {
+    pub sequence: u64,
+    pub proposal_id: ProposalId,
+    pub changes: serde_json::Value,
+    pub block: BlockNumber,
+    pub epoch: EpochNumber,
+    /// False when the changes were rejected at enactment
+    pub applied: bool,
+    pub error: Option<String>,
+}
+
+impl ParameterChangeRecordRpc {
+    /// Audit log of a report, newest first
+    pub fn from_report(report: &ParametersReport) -> Vec<Self> {
+        report.log.iter().rev().map(Self::from).collect()
+    }
+}
+
+impl From<&ParameterChangeRecord> for ParameterChangeRecordRpc {
+    fn from(record: &ParameterChangeRecord) -> Self {
+        Self {
+            sequence: record.sequence,
+            proposal_id: record.proposal_id,
+            changes: serde_json::json!(record.changes),
+            block: record.block,
+            epoch: record.epoch,
+            applied: record.error.is_none(),
+            error: record.error.clone(),
+        }
+    }
+}
+
+/// Identity registered by an account (identity_get)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityRpc {
+    pub account: String,
+    pub identity_id: String,
+    pub display_name: Option<String>,
+    pub contact_hashes: Vec<String>,
+    /// Declared, Attested, Active, Expired or Revoked
+    pub status: String,
+    pub declared_at: BlockNumber,
+    pub expires_at: BlockNumber,
+    /// Deposit held until revocation
+    pub deposit: Balance,
+    pub valid_attestations: usize,
+    /// Valid attestations an Active identity needs
+    pub required_attestations: usize,
+    pub attestations: Vec<IdentityAttestationRpc>,
+}
+
+/// Attestation of an identity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityAttestationRpc {
+    pub attester: String,
+    pub claim_hash: String,
+    pub weight: u8,
+    pub attested_at: BlockNumber,
+    pub expires_at: BlockNumber,
+    /// Still counts towards the status
+    pub active: bool,
+}
+
+impl IdentityRpc {
+    pub fn new(registered: &RegisteredIdentity, current_block: BlockNumber) -> Self {
+        let hash = |h: &Hash| format!("0x{}", hex::encode(h.as_bytes()));
+        let identity = &registered.identity;
+        Self {
+            account: format!("0x{}", hex::encode(identity.owner.as_bytes())),
+            identity_id: hash(&identity.identity_id),
+            display_name: identity.display_name.clone(),
+            contact_hashes: registered.contact_hashes.iter().map(hash).collect(),
+            status: format!("{:?}", registered.status_at(current_block)),
+            declared_at: identity.declared_at,
+            expires_at: identity.expires_at,
+            deposit: registered.deposit,
+            valid_attestations: registered.valid_attestations(current_block),
+            required_attestations: MIN_ATTESTATIONS_FOR_ACTIVE,
+            attestations: registered
+                .attestations
+                .iter()
+                .map(|a| IdentityAttestationRpc {
+                    attester: format!("0x{}", hex::encode(a.attester.as_bytes())),
+                    claim_hash: hash(&a.claim_hash),
+                    weight: a.weight,
+                    attested_at: a.attested_at,
+                    expires_at: a.expires_at,
+                    active: a.is_active(current_block),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Verified-person status of an account (personhood_getStatus)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonhoodStatusRpc {
+    pub account: String,
+    /// Counts as one person in the next block
+    pub verified: bool,
+    /// Identity held at the last verification
+    pub identity_id: Option<String>,
+    pub verified_round: Option<EpochNumber>,
+    pub verified_at: Option<BlockNumber>,
+    pub expires_at: Option<BlockNumber>,
+    pub rounds_attended: u32,
+    pub ceremony: CeremonyRoundRpc,
+}
+
+/// Verification round of the current epoch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CeremonyRoundRpc {
+    pub epoch: EpochNumber,
+    /// Commit or Reveal, for the next block
+    pub phase: String,
+    pub commit_ends_at: BlockNumber,
+    pub reveal_ends_at: BlockNumber,
+    /// Accounts that committed
+    pub participants: usize,
+    /// Accounts whose reveal matched
+    pub verified: usize,
+    /// The queried account committed / revealed in this round
+    pub committed: bool,
+    pub revealed: bool,
+}
+
+impl From<&PersonhoodReport> for PersonhoodStatusRpc {
+    fn from(report: &PersonhoodReport) -> Self {
+        let record = report.record.as_ref();
+        let round = &report.round;
+        let (_, phase) = ceremony_phase(report.next_block);
+        Self {
+            account: format!("0x{}", hex::encode(report.account.as_bytes())),
+            verified: record.is_some_and(|r| r.is_verified(report.next_block)),
+            identity_id: record.map(|r| format!("0x{}", hex::encode(r.identity_id.as_bytes()))),
+            verified_round: record.map(|r| r.verified_round),
+            verified_at: record.map(|r| r.verified_at),
+            expires_at: record.map(|r| r.expires_at),
+            rounds_attended: record.map_or(0, |r| r.rounds_attended),
+            ceremony: CeremonyRoundRpc {
+                epoch: round.epoch,
+                phase: format!("{:?}", phase),
+                commit_ends_at: round.commit_ends_at(),
+                reveal_ends_at: round.reveal_ends_at(),
+                participants: round.commitments.len(),
+                verified: round.revealed.len(),
+                committed: round.commitments.contains_key(&report.account),
+                revealed: round.revealed.contains(&report.account),
+            },
+        }
+    }
+}
+
+/// Emergency status of the network (emergency_getStatus)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmergencyStatusRpc {
+    /// Security state, as in chain_getEpochInfo
+    pub security_state: String,
+    /// Validators may sign an emergency declaration
+    pub armed: bool,
+    /// Declared on chain, or the network in Emergency state
+    pub active: bool,
+    pub declared_at: Option<BlockNumber>,
+    pub expires_at: Option<BlockNumber>,
+    /// Block the last emergency ended
+    pub ended_at: Option<BlockNumber>,
+    pub validators: u32,
+    /// Signatures of the pending declaration
+    pub declaration_signatures: u32,
+    pub declaration_required: u32,
+    /// Signatures of the recovery of the declared emergency
+    pub recovery_signatures: u32,
+    pub recovery_required: u32,
+    pub breakers: Vec<CircuitBreakerRpc>,
+}
+
+/// SPEC v7 circuit breaker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitBreakerRpc {
+    pub id: String,
+    pub name: String,
+    pub condition: String,
+    pub action: String,
+    /// Blocks the breaker stays triggered
+    pub duration: BlockNumber,
+    /// Enabled and the emergency machinery armed
+    pub armed: bool,
+    pub active: bool,
+}
+
+impl From<&EmergencyReport> for EmergencyStatusRpc {
+    fn from(report: &EmergencyReport) -> Self {
+        let declaration = &report.declaration;
+        let (state, _) = security_state_name(&report.security_state);
+        Self {
+            security_state: state.to_string(),
+            armed: report.armed,
+            active: report.in_emergency,
+            declared_at: declaration.declared_at,
+            expires_at: declaration.expires_at(),
+            ended_at: declaration.ended_at(report.next_block),
+            validators: report.validators,
+            declaration_signatures: declaration.pending_signatures(report.next_block) as u32,
+            declaration_required: EmergencyDeclaration::required_signatures(
+                report.validators,
+                EMERGENCY_APPROVAL_THRESHOLD,
+            ),
+            recovery_signatures: declaration.recovering.len() as u32,
+            recovery_required: EmergencyDeclaration::required_signatures(
+                report.validators,
+                EMERGENCY_RECOVERY_THRESHOLD,
+            ),
+            breakers: report
+                .breakers
+                .iter()
+                .map(|breaker| CircuitBreakerRpc {
+                    id: breaker.id.clone(),
+                    name: breaker.name.clone(),
+                    condition: format!("{:?}", breaker.condition),
+                    action: format!("{:?}", breaker.action),
+                    duration: breaker.duration,
+                    armed: breaker.enabled && report.armed,
+                    active: breaker.is_active,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Messages messaging_getInbox returns by default
+pub const DEFAULT_INBOX_MESSAGES: u64 = 50;
+
+/// Message of an account's inbox (messaging_getInbox)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountMessageRpc {
+    /// Position in the inbox
+    pub id: u64,
+    pub from: String,
+    pub to: String,
+    /// Hex body, ciphertext if `encrypted`
+    pub body: String,
+    pub encrypted: bool,
+    pub sent_at: BlockNumber,
+}
+
+impl From<&AccountMessage> for AccountMessageRpc {
+    fn from(message: &AccountMessage) -> Self {
+        Self {
+            id: message.id,
+            from: format!("0x{}", hex::encode(message.sender.as_bytes())),
+            to: format!("0x{}", hex::encode(message.recipient.as_bytes())),
+            body: format!("0x{}", hex::encode(&message.body)),
+            encrypted: message.encrypted,
+            sent_at: message.sent_at,
+        }
+    }
+}
+
+/// Accounts listed by reputation_top by default
+pub const DEFAULT_REPUTATION_TOP: usize = 20;
+
+/// Most accounts reputation_top returns
+pub const MAX_REPUTATION_TOP: usize = 100;
+
+/// Reputation of an account (reputation_get, reputation_top)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReputationRpc {
+    pub account: String,
+    /// Weighted average over all domains
+    pub overall_score: u32,
+    /// Domains with activity
+    pub domains: Vec<DomainReputationRpc>,
+    pub updated_at: BlockNumber,
+    pub last_decay: BlockNumber,
+}
+
+/// Reputation in one domain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainReputationRpc {
+    /// Governance, Technical, Community, Arbitration, Economic or Validation
+    pub domain: String,
+    pub score: u32,
+    pub actions: u32,
+    pub last_activity: BlockNumber,
+}
+
+impl From<&AccountReputation> for ReputationRpc {
+    fn from(reputation: &AccountReputation) -> Self {
+        Self {
+            account: format!("0x{}", hex::encode(reputation.account.as_bytes())),
+            overall_score: reputation.overall_score,
+            domains: reputation
+                .domains
+                .iter()
+                .map(|(domain, rep)| DomainReputationRpc {
+                    domain: format!("{:?}", domain),
+                    score: rep.score,
+                    actions: rep.action_count,
+                    last_activity: rep.last_activity,
+                })
+                .collect(),
+            updated_at: reputation.updated_at,
+            last_decay: reputation.last_decay,
+        }
+    }
+}
+
+impl ReputationRpc {
+    /// The `limit` highest reputations, overall or in `domain`
+    ///
+    /// Ties are broken by account, so every node lists the same accounts.
+    pub fn top(reputations: &[AccountReputation], limit: usize, domain: Option<ReputationDomain>) -> Vec<Self> {
+        let score = |r: &AccountReputation| domain.map_or(r.overall_score, |d| r.score(d));
+        let mut ranked: Vec<&AccountReputation> = reputations.iter().filter(|r| score(r) > 0).collect();
+        ranked.sort_by(|a, b| score(b).cmp(&score(a)).then(a.account.cmp(&b.account)));
+        ranked.into_iter().take(limit.min(MAX_REPUTATION_TOP)).map(Self::from).collect()
+    }
+}
+
+// =============================================================================
+// INDEXED QUERIES (--index)
+// =============================================================================
+
+/// Entries an indexed query returns by default (at most MAX_INDEX_PAGE)
+pub const DEFAULT_INDEX_PAGE: usize = 50;
+
+/// Transaction of an account's history (account_getTransactions)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedTransactionRpc {
+    #[serde(flatten)]
+    pub transaction: TransactionInfo,
+    pub block_number: BlockNumber,
+    pub block_hash: String,
+    /// Index of the transaction in the block
+    pub index: u32,
+    pub success: bool,
+    /// Inclusion status: "success" or "failed"
+    pub status: String,
+    pub fee_paid: Balance,
+    pub events: Vec<EventRpc>,
+}
+
+impl From<&IndexedTransaction> for IndexedTransactionRpc {
+    fn from(indexed: &IndexedTransaction) -> Self {
+        let mut transaction = TransactionInfo::from(&indexed.transaction);
+        transaction.hash = format!("0x{}", hex::encode(indexed.transaction.hash().as_bytes()));
+        Self {
+            transaction,
+            block_number: indexed.block_number,
+            block_hash: format!("0x{}", hex::encode(indexed.block_hash.as_bytes())),
+            index: indexed.index,
+            success: indexed.success,
+            status: if indexed.success { "success" } else { "failed" }.to_string(),
+            fee_paid: indexed.fee_paid,
+            events: indexed.events.iter().map(EventRpc::from).collect(),
+        }
+    }
+}
+
+/// Options of account_getTransactions (`[address, {limit, cursor, direction,
+/// fromBlock, toBlock, status, includePending}]`)
+#[derive(Debug, Clone)]
+pub struct AccountTransactionsQuery {
+    pub filter: TransactionFilter,
+    /// nextCursor of the previous page
+    pub cursor: Option<IndexCursor>,
+    pub limit: usize,
+    /// List the account's mempool transactions too
+    pub include_pending: bool,
+}
+
+impl AccountTransactionsQuery {
+    /// Pending transactions are listed with the first page, unless the
+    /// filters exclude them (they have no block or status yet)
+    pub fn wants_pending(&self) -> bool {
+        self.include_pending && self.cursor.is_none() && self.filter.to_block.is_none() && self.filter.success.is_none()
+    }
+}
+
+/// Page of the transactions sent or received by an account, newest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTransactionsRpc {
+    pub address: String,
+    /// Transactions of the account in the index, filters aside
+    pub total: u64,
+    /// Cursor of the next page, null on the last one
+    pub next_cursor: Option<String>,
+    pub transactions: Vec<IndexedTransactionRpc>,
+    /// Mempool transactions of the account (includePending)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pending: Vec<TransactionInfo>,
+}
+
+impl AccountTransactionsRpc {
+    pub fn new(account: &AccountId, page: &IndexPage<IndexedTransaction>) -> Self {
+        Self {
+            address: format!("0x{}", hex::encode(account.as_bytes())),
+            total: page.total,
+            next_cursor: page.next.map(|cursor| cursor.to_hex()),
+            transactions: page.items.iter().map(IndexedTransactionRpc::from).collect(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Add the pending transactions on the queried side
+    pub fn with_pending(mut self, account: &AccountId, query: &AccountTransactionsQuery, pending: &[SignedTransaction]) -> Self {
+        self.pending = pending
+            .iter()
+            .filter(|tx| query.filter.matches_direction(account, &tx.transaction.sender))
+            .map(|tx| {
+                let mut info = TransactionInfo::from(tx);
+                info.hash = format!("0x{}", hex::encode(tx.hash().as_bytes()));
+                info
+            })
+            .collect();
+        self
+    }
+}
+
+/// Event with its position in the chain (chain_getEvents)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedEventRpc {
+    #[serde(flatten)]
+    pub event: EventRpc,
+    pub tx_hash: String,
+    pub block_number: BlockNumber,
+    pub block_hash: String,
+    pub timestamp: Timestamp,
+    /// Index of the emitting transaction in the block
+    pub index: u32,
+}
+
+impl From<&IndexedEvent> for IndexedEventRpc {
+    fn from(indexed: &IndexedEvent) -> Self {
+        Self {
+            event: EventRpc::from(&indexed.event),
+            tx_hash: format!("0x{}", hex::encode(indexed.tx_hash.as_bytes())),
+            block_number: indexed.block_number,
+            block_hash: format!("0x{}", hex::encode(indexed.block_hash.as_bytes())),
+            timestamp: indexed.timestamp,
+            index: indexed.index,
+        }
+    }
+}
+
+/// Page of the events of a type, newest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventsRpc {
+    /// `contract.Name`, e.g. krat.Transfer
+    pub event_type: String,
+    pub total: u64,
+    pub offset: usize,
+    pub events: Vec<IndexedEventRpc>,
+}
+
+impl EventsRpc {
+    pub fn new(event_type: &str, offset: usize, page: &IndexPage<IndexedEvent>) -> Self {
+        Self {
+            event_type: event_type.to_string(),
+            total: page.total,
+            offset,
+            events: page.items.iter().map(IndexedEventRpc::from).collect(),
+        }
+    }
+}
+
+/// Block authored by a validator (validator_getAuthoredBlocks)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthoredBlockRpc {
+    pub number: BlockNumber,
+    pub hash: String,
+    pub timestamp: Timestamp,
+    pub tx_count: u32,
+}
+
+/// Page of the blocks authored by a validator, newest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthoredBlocksRpc {
+    pub validator: String,
+    pub total: u64,
+    pub offset: usize,
+    pub blocks: Vec<AuthoredBlockRpc>,
+}
+
+impl AuthoredBlocksRpc {
+    pub fn new(validator: &AccountId, offset: usize, page: &IndexPage<IndexedBlock>) -> Self {
+        Self {
+            validator: format!("0x{}", hex::encode(validator.as_bytes())),
+            total: page.total,
+            offset,
+            blocks: page
+                .items
+                .iter()
+                .map(|block| AuthoredBlockRpc {
+                    number: block.number,
+                    hash: format!("0x{}", hex::encode(block.hash.as_bytes())),
+                    timestamp: block.timestamp,
+                    tx_count: block.tx_count,
+                })
+                .collect(),
+        }
+    }
+}
+
+// =============================================================================
+// HELPER FUNCTIONS
+// =============================================================================
+
+/// Parse a reputation domain name (case-insensitive)
+pub fn parse_reputation_domain(s: &str) -> Result<ReputationDomain, String> {
+    ReputationDomain::all()
+        .iter()
+        .copied()
+        .find(|domain| format!("{:?}", domain).eq_ignore_ascii_case(s))
+        .ok_or_else(|| format!("Unknown reputation domain: {}", s))
+}
+
+/// Parse hex string to AccountId
+pub fn parse_account_id(s: &str) -> Result<AccountId, String> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    let bytes = hex::decode(s).map_err(|e| format!("Invalid hex: {}", e))?;
+    if bytes.len() != 32 {
+        return Err(format!("Expected 32 bytes, got {}", bytes.len()));
+    }
     let mut arr = [0u8; 32];
     arr.copy_from_slice(&bytes);
     Ok(AccountId::from_bytes(arr))
@@ -630,10 +2834,248 @@ pub fn parse_hash(s: &str) -> Result<Hash, String> {
     Ok(Hash::from_bytes(arr))
 }
 
+/// Parse `[value, blockHash]` params of the historical state methods
+pub fn parse_at_params(params: &serde_json::Value, usage: &str) -> Result<(String, Hash), String> {
+    let arr = match params {
+        serde_json::Value::Array(arr) if arr.len() >= 2 => arr,
+        _ => return Err(format!("Expected {}", usage)),
+    };
+    let value = arr[0].as_str().ok_or_else(|| format!("Expected {}", usage))?;
+    let block_hash = arr[1].as_str().ok_or_else(|| format!("Expected {}", usage))?;
+    Ok((value.to_string(), parse_hash(block_hash)?))
+}
+
+/// Parse `[method, [params...], blockHash]` params of state_callAt
+///
+/// Returns the call, the account it applies to and the block hash.
+pub fn parse_call_at_params(params: &serde_json::Value) -> Result<(HistoricalCall, AccountId, Hash), String> {
+    const USAGE: &str = "Expected [method, [address], blockHash]";
+    let arr = match params {
+        serde_json::Value::Array(arr) if arr.len() >= 3 => arr,
+        _ => return Err(USAGE.to_string()),
+    };
+    let method = arr[0].as_str().ok_or(USAGE)?;
+    let call = HistoricalCall::from_method(method)
+        .ok_or_else(|| format!("state_callAt does not support {}", method))?;
+    let address = arr[1]
+        .as_array()
+        .and_then(|p| p.first())
+        .and_then(|a| a.as_str())
+        .ok_or(USAGE)?;
+    let block_hash = arr[2].as_str().ok_or(USAGE)?;
+    Ok((call, parse_account_id(address)?, parse_hash(block_hash)?))
+}
+
+/// Parse `[value, limit?, offset?]` params of the indexed queries
+pub fn parse_page_params(params: &serde_json::Value, usage: &str) -> Result<(String, usize, usize), String> {
+    let arr = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => arr,
+        _ => return Err(format!("Expected {}", usage)),
+    };
+    let value = arr[0].as_str().ok_or_else(|| format!("Expected {}", usage))?;
+    let limit = arr.get(1).and_then(|v| v.as_u64()).map_or(DEFAULT_INDEX_PAGE, |v| v as usize);
+    let offset = arr.get(2).and_then(|v| v.as_u64()).unwrap_or(0) as usize;
+    Ok((value.to_string(), limit, offset))
+}
+
+/// Parse system_banPeer params: `[peerId, reason?, durationSecs?]`
+pub fn parse_ban_peer_params(params: &serde_json::Value) -> Result<(libp2p::PeerId, String, u64), String> {
+    const USAGE: &str = "Expected [peerId, reason?, durationSecs?]";
+    let arr = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() => arr,
+        _ => return Err(USAGE.to_string()),
+    };
+    let peer_id = parse_peer_id(&arr[0])?;
+    let reason = match arr.get(1) {
+        None | Some(serde_json::Value::Null) => "Banned by operator".to_string(),
+        Some(value) => value.as_str().ok_or(USAGE)?.to_string(),
+    };
+    let secs = match arr.get(2) {
+        None | Some(serde_json::Value::Null) => DEFAULT_BAN_SECS,
+        Some(value) => value.as_u64().filter(|secs| *secs > 0).ok_or("durationSecs must be a positive integer")?,
+    };
+    Ok((peer_id, reason, secs))
+}
+
+/// Parse a base58 peer ID
+pub fn parse_peer_id(value: &serde_json::Value) -> Result<libp2p::PeerId, String> {
+    let text = value.as_str().ok_or("Expected a peer ID string")?;
+    text.parse().map_err(|e| format!("Invalid peer ID {}: {}", text, e))
+}
+
+/// Parse account_getTransactions params: `[address, options?]`
+pub fn parse_account_transactions_params(params: &serde_json::Value) -> Result<(AccountId, AccountTransactionsQuery), String> {
+    const USAGE: &str = "Expected [address, {limit?, cursor?, direction?, fromBlock?, toBlock?, status?, includePending?}]";
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase", deny_unknown_fields)]
+    struct Options {
+        limit: Option<usize>,
+        cursor: Option<String>,
+        direction: Option<String>,
+        from_block: Option<BlockNumber>,
+        to_block: Option<BlockNumber>,
+        status: Option<String>,
+        #[serde(default)]
+        include_pending: bool,
+    }
+
+    let arr = match params {
+        serde_json::Value::Array(arr) if !arr.is_empty() && arr.len() <= 2 => arr,
+        _ => return Err(USAGE.to_string()),
+    };
+    let account = parse_account_id(arr[0].as_str().ok_or(USAGE)?)?;
+    let options: Options = match arr.get(1) {
+        None | Some(serde_json::Value::Null) => serde_json::from_value(serde_json::json!({})),
+        Some(value) => serde_json::from_value(value.clone()),
+    }
+    .map_err(|e| format!("Invalid options: {}", e))?;
+
+    let direction = match options.direction.as_deref() {
+        None => None,
+        Some("sent") => Some(TransactionDirection::Sent),
+        Some("received") => Some(TransactionDirection::Received),
+        Some(other) => return Err(format!("Invalid direction '{}' (sent or received)", other)),
+    };
+    let success = match options.status.as_deref() {
+        None => None,
+        Some("success") => Some(true),
+        Some("failed") => Some(false),
+        Some(other) => return Err(format!("Invalid status '{}' (success or failed)", other)),
+    };
+    if let (Some(from), Some(to)) = (options.from_block, options.to_block) {
+        if from > to {
+            return Err(format!("fromBlock {} is above toBlock {}", from, to));
+        }
+    }
+    let cursor = options.cursor.as_deref().map(IndexCursor::from_hex).transpose()?;
+
+    let query = AccountTransactionsQuery {
+        filter: TransactionFilter {
+            direction,
+            from_block: options.from_block,
+            to_block: options.to_block,
+            success,
+        },
+        cursor,
+        limit: options.limit.unwrap_or(DEFAULT_INDEX_PAGE).clamp(1, MAX_INDEX_PAGE),
+        include_pending: options.include_pending,
+    };
+    Ok((account, query))
+}
+
+/// Parse a hex storage key
+pub fn parse_storage_key(s: &str) -> Result<Vec<u8>, String> {
+    hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(|e| format!("Invalid hex: {}", e))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_ban_peer_params() {
+        let peer = libp2p::PeerId::random();
+        let (parsed, reason, secs) = parse_ban_peer_params(&serde_json::json!([peer.to_string()])).unwrap();
+        assert_eq!((parsed, reason.as_str(), secs), (peer, "Banned by operator", DEFAULT_BAN_SECS));
+
+        let (_, reason, secs) = parse_ban_peer_params(&serde_json::json!([peer.to_string(), "spam", 600])).unwrap();
+        assert_eq!((reason.as_str(), secs), ("spam", 600));
+
+        assert!(parse_ban_peer_params(&serde_json::json!([])).is_err());
+        assert!(parse_ban_peer_params(&serde_json::json!(["not-a-peer"])).is_err());
+        assert!(parse_ban_peer_params(&serde_json::json!([peer.to_string(), null, 0])).is_err());
+    }
+
+    #[test]
+    fn test_parse_account_transactions_params() {
+        let address = format!("0x{}", "01".repeat(32));
+
+        let (account, query) = parse_account_transactions_params(&serde_json::json!([address])).unwrap();
+        assert_eq!(account, AccountId::from_bytes([1; 32]));
+        assert_eq!((query.limit, query.cursor, query.include_pending), (DEFAULT_INDEX_PAGE, None, false));
+
+        let cursor = IndexCursor { block_number: 42, index: 3 };
+        let params = serde_json::json!([address, {
+            "limit": 1_000, "cursor": cursor.to_hex(), "direction": "received",
+            "fromBlock": 10, "toBlock": 50, "status": "failed", "includePending": true,
+        }]);
+        let (_, query) = parse_account_transactions_params(&params).unwrap();
+        assert_eq!((query.limit, query.cursor), (MAX_INDEX_PAGE, Some(cursor)));
+        assert_eq!(query.filter.direction, Some(TransactionDirection::Received));
+        assert_eq!((query.filter.from_block, query.filter.to_block, query.filter.success), (Some(10), Some(50), Some(false)));
+        // Pending transactions only come with the first page
+        assert!(!query.wants_pending());
+
+        for options in [
+            serde_json::json!({"direction": "both"}),
+            serde_json::json!({"status": "pending"}),
+            serde_json::json!({"fromBlock": 5, "toBlock": 4}),
+            serde_json::json!({"cursor": "0x00"}),
+            serde_json::json!({"offset": 10}),
+        ] {
+            assert!(parse_account_transactions_params(&serde_json::json!([address, options])).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_call_at_params() {
+        let address = format!("0x{}", "01".repeat(32));
+        let block = format!("0x{}", "ab".repeat(32));
+
+        let params = serde_json::json!(["state_getNonce", [address], block]);
+        let (call, account, hash) = parse_call_at_params(&params).unwrap();
+        assert_eq!(call, HistoricalCall::Nonce);
+        assert_eq!(account, AccountId::from_bytes([1; 32]));
+        assert_eq!(hash, Hash::from_bytes([0xab; 32]));
+
+        let params = serde_json::json!(["author_submitTransaction", [address], block]);
+        assert!(parse_call_at_params(&params).is_err());
+        assert!(parse_at_params(&serde_json::json!([address]), "[address, blockHash]").is_err());
+    }
+
+    #[test]
+    fn test_state_proof_rpc() {
+        use crate::types::{ChainId, StateMerkleTree};
+
+        let mut info = AccountInfo::new();
+        info.free = 42;
+        let leaves = vec![vec![1u8], bincode::serialize(&info).unwrap(), vec![3u8]];
+        let tree = StateMerkleTree::new(leaves);
+        let proof = tree.generate_proof(1, 9, ChainId(0)).unwrap();
+
+        let rpc = StateProofRpc::from_proof(&AccountId::from_bytes([1; 32]), &Hash::from_bytes([2; 32]), &proof).unwrap();
+        assert_eq!(rpc.block_number, 9);
+        assert_eq!(rpc.leaf_count, 3);
+        assert_eq!(rpc.proof.len(), proof.proof.len());
+        assert_eq!(rpc.account.free_raw, 42);
+        assert_eq!(rpc.state_root, format!("0x{}", hex::encode(tree.root().as_bytes())));
+    }
+
+    #[test]
+    fn test_finality_proof_rpc() {
+        let justification = FinalityJustification {
+            block_number: 12,
+            block_hash: Hash::from_bytes([3; 32]),
+            signatures: vec![ValidatorSignature {
+                validator: AccountId::from_bytes([1; 32]),
+                signature: Signature64::zero(),
+            }],
+            epoch: 0,
+            round: 4,
+        };
+
+        let rpc = FinalityProofRpc::from_justification(10, &justification);
+        assert_eq!(rpc.block_number, 10);
+        assert_eq!(rpc.justified_number, 12);
+        assert_eq!(rpc.round, 4);
+        assert_eq!(rpc.signatures.len(), 1);
+        assert_eq!(rpc.signing_message, format!("0x{}", hex::encode(justification.signing_message())));
+
+        let json = serde_json::to_value(&rpc).unwrap();
+        assert!(json.get("justifiedHash").is_some());
+    }
+
     #[test]
     fn test_json_rpc_response_success() {
         let response = JsonRpcResponse::success(JsonRpcId::Number(1), "test");
@@ -651,6 +3093,57 @@ mod tests {
         assert_eq!(response.error.unwrap().code, -32601);
     }
 
+    #[test]
+    fn test_submission_failed() {
+        let quota = NodeError::Pool(PoolError::TooManyPerAccount { count: 100, max: 100 });
+        let error = JsonRpcError::submission_failed(&quota);
+        assert_eq!(error.code, -32010);
+        assert_eq!(error.data, Some(serde_json::json!({ "reason": "accountQuota", "count": 100, "max": 100 })));
+
+        let wrong_chain = NodeError::Pool(PoolError::WrongChain { expected: Hash::ZERO, got: Hash::ZERO });
+        let data = JsonRpcError::submission_failed(&wrong_chain).data.unwrap();
+        assert_eq!(data["reason"], "wrongChain");
+        assert_eq!(data["expected"], format!("0x{}", "00".repeat(32)));
+
+        let error = JsonRpcError::submission_failed(&NodeError::Transaction("bad".into()));
+        assert_eq!(error.code, -32010);
+        assert!(error.data.is_none());
+    }
+
+    #[test]
+    fn test_mortality_rpc() {
+        let json = serde_json::to_value(MortalityRpc::new(1_000)).unwrap();
+        assert_eq!(json["bestBlock"], 1_000);
+        assert_eq!(json["validUntil"], 1_000 + DEFAULT_MORTALITY_BLOCKS);
+        assert_eq!(MortalityRpc::new(BlockNumber::MAX).valid_until, BlockNumber::MAX);
+    }
+
+    #[test]
+    fn test_forks_rpc() {
+        use crate::network::sync::ForkHead;
+
+        let mut fork = ForkHead::new(Hash::hash(b"branch"), 41, Hash::hash(b"head"), 50, libp2p::PeerId::random());
+        fork.justified = Some(48);
+        let mut report = ForksReport { finalized: Some(40), forks: vec![fork.clone()], downloading: Some(fork.branch) };
+        let json = serde_json::to_value(ForksRpc::from(&report)).unwrap();
+        assert_eq!(json["finalized"], 40);
+        assert_eq!(json["forks"][0]["commonAncestor"], 41);
+        assert_eq!(json["forks"][0]["justified"], 48);
+        assert_eq!(json["forks"][0]["peers"][0], fork.peers[0].to_string());
+        assert_eq!(json["forks"][0]["downloading"], true);
+        report.downloading = None;
+        assert!(!ForksRpc::from(&report).forks[0].downloading);
+    }
+
+    #[test]
+    fn test_system_chain_rpc() {
+        let genesis_hash = Hash::hash(b"genesis");
+        let json = serde_json::to_value(SystemChainRpc::new(&ChainConfig::mainnet(), genesis_hash)).unwrap();
+        assert_eq!(json["name"], "KratOs");
+        assert_eq!(json["genesisHash"], format!("0x{}", hex::encode(genesis_hash.as_bytes())));
+        assert!(json["chainId"].is_u64());
+    }
+
     #[test]
     fn test_block_info_from_block() {
         let block = Block {
@@ -659,9 +3152,11 @@ mod tests {
                 parent_hash: Hash::ZERO,
                 transactions_root: Hash::ZERO,
                 state_root: Hash::ZERO,
+                receipts_root: Hash::ZERO,
                 timestamp: 12345,
                 epoch: 0,
                 slot: 1,
+                protocol_version: ProtocolVersion::default(),
                 author: AccountId::from_bytes([1; 32]),
                 signature: Signature64([0; 64]),
             },
@@ -677,6 +3172,351 @@ mod tests {
         assert!(info.hash.starts_with("0x"));
     }
 
+    #[test]
+    fn test_validator_info_from_report() {
+        let mut info = crate::consensus::validator::ValidatorInfo::new(AccountId::from_bytes([1; 32]), 1_000, 0);
+        info.blocks_produced = 3;
+        info.blocks_missed = 1;
+
+        let report = ValidatorReport {
+            info,
+            is_active: true,
+            can_participate: true,
+            credits: None,
+            vc_history: Default::default(),
+            clock_sync_failures: 2,
+            clock_vc_penalty: 4,
+            unbonding_balance: 0,
+            commission: crate::contracts::staking::CommissionRecord { current: 10, announced: Some((11, 2)) },
+            finality_voter: false,
+            current_block: EPOCH_DURATION_BLOCKS + 100,
+        };
+
+        let rpc = ValidatorInfoRpc::from(&report);
+        assert_eq!(rpc.participation_percent, 75.0);
+        assert_eq!(rpc.status, "Active");
+        assert_eq!(rpc.current_epoch, 1);
+        assert_eq!(rpc.blocks_until_next_epoch, EPOCH_DURATION_BLOCKS - 100);
+        assert_eq!(rpc.validator_credits, 0);
+        assert_eq!(rpc.commission, 10);
+        assert_eq!(rpc.announced_commission.map(|a| (a.commission, a.effective_epoch)), Some((11, 2)));
+
+        let credits = ValidatorCreditsRpc::from(&report);
+        assert_eq!(credits.total, 0);
+        assert_eq!(credits.clock_penalty, 4);
+        assert_eq!(credits.deducted, 0);
+        assert!(VcHistoryRpc::new(&report, 10).epochs.is_empty());
+    }
+
+    #[test]
+    fn test_epoch_info_from_report() {
+        let report = EpochReport {
+            current_block: 3 * EPOCH_DURATION_BLOCKS + 7,
+            epoch: 3,
+            validators: vec![(AccountId::from_bytes([1; 32]), 1_000), (AccountId::from_bytes([2; 32]), 500)],
+            is_bootstrap: true,
+            bootstrap_end_epoch: 1440,
+            bootstrap_status: BootstrapStatus::Active { epochs_remaining: 1437, validators_needed: 48 },
+            security_state: NetworkSecurityState::DegradedSecurityMode {
+                entered_at: 2,
+                epochs_in_dsm: 1,
+                current_validators: 2,
+                validators_needed: 73,
+                consecutive_epochs_above_safe: 0,
+            },
+            block_time_multiplier: 2,
+            timelock_multiplier: 2,
+        };
+
+        let rpc = EpochInfoRpc::from(&report);
+        assert_eq!((rpc.start_block, rpc.end_block), (1800, 2399));
+        assert_eq!(rpc.total_stake, 1_500);
+        assert_eq!(rpc.validators.len(), 2);
+        assert_eq!(rpc.bootstrap.status, "Active");
+        assert_eq!(rpc.bootstrap.validators_needed, 48);
+        assert_eq!(rpc.security.state, "Degraded");
+        assert_eq!(rpc.security.entered_at, Some(2));
+        assert_eq!(rpc.security.block_time_multiplier, 2);
+    }
+
+    #[test]
+    fn test_proposal_rpc() {
+        use crate::contracts::governance::{Vote, STANDARD_TIMELOCK};
+
+        let mut proposal = Proposal::new(
+            1,
+            ChainId::ROOT,
+            AccountId::from_bytes([1; 32]),
+            ProposalType::AddValidator { validator: AccountId::from_bytes([9; 32]) },
+            None,
+            100,
+            10,
+        );
+        proposal.eligible_votes = 1_000;
+        proposal.cast(AccountId::from_bytes([1; 32]), Vote::Yes, 400, 11).unwrap();
+        proposal.cast(AccountId::from_bytes([2; 32]), Vote::No, 100, 11).unwrap();
+        let report = GovernanceReport { current_block: 12, timelock_multiplier: 2, proposals: vec![proposal] };
+
+        let rpc = &ProposalRpc::from_report(&report)[0];
+        assert_eq!(rpc.kind, "AddValidator");
+        assert_eq!(rpc.details["validator"], format!("0x{}", "09".repeat(32)));
+        assert_eq!(rpc.status, "Active");
+        assert_eq!((rpc.tally.participation, rpc.tally.approval), (50, 80));
+        assert!(!rpc.tally.carried, "Not seconded");
+        assert_eq!(rpc.timelock.ends_at, Some(rpc.voting_ends_at + 2 * STANDARD_TIMELOCK));
+        assert!(!rpc.timelock.frozen);
+    }
+
+    #[test]
+    fn test_treasury_rpc() {
+        use crate::contracts::governance::{Vote, VOTING_PERIOD};
+        use crate::contracts::krat::treasury_account;
+
+        let spend = |id, amount, yes| {
+            let mut proposal = Proposal::new(
+                id,
+                ChainId::ROOT,
+                AccountId::from_bytes([1; 32]),
+                ProposalType::TreasurySpend { recipient: AccountId::from_bytes([5; 32]), amount, reason: String::new() },
+                None,
+                100,
+                10,
+            );
+            proposal.eligible_votes = 1_000;
+            proposal.seconds = vec![AccountId::from_bytes([2; 32]), AccountId::from_bytes([3; 32])];
+            let vote = if yes { Vote::Yes } else { Vote::No };
+            proposal.cast(AccountId::from_bytes([1; 32]), vote, 500, 11).unwrap();
+            proposal
+        };
+        let report = TreasuryReport {
+            account: treasury_account(),
+            balance: 1_000,
+            governance: GovernanceReport {
+                current_block: 10 + VOTING_PERIOD + 1,
+                timelock_multiplier: 1,
+                proposals: vec![spend(1, 300, true), spend(2, 500, false)],
+            },
+        };
+
+        // The rejected spend commits nothing
+        let rpc = TreasuryRpc::from(&report);
+        assert_eq!((rpc.committed, rpc.available, rpc.pending_spends), (300, 700, 1));
+        assert!(rpc.account.ends_with("54524541"));
+    }
+
+    #[test]
+    fn test_protocol_parameters_rpc() {
+        use crate::contracts::governance::{Vote, VOTING_PERIOD};
+        use crate::contracts::meta_governance::PROTOCOL_UPGRADE_TIMELOCK;
+
+        let change = ParameterChange::InflationBounds { floor_bps: 100, cap_bps: 800 };
+        let mut proposal = Proposal::new(
+            1,
+            ChainId::ROOT,
+            AccountId::from_bytes([1; 32]),
+            ProposalType::ProtocolChange { changes: vec![change.clone()], rationale: String::new() },
+            None,
+            100,
+            10,
+        );
+        proposal.eligible_votes = 1_000;
+        proposal.seconds = vec![AccountId::from_bytes([2; 32]), AccountId::from_bytes([3; 32])];
+        proposal.cast(AccountId::from_bytes([1; 32]), Vote::Yes, 800, 11).unwrap();
+        let voting_ends_at = proposal.voting_ends_at;
+
+        let report = ParametersReport {
+            parameters: ProtocolParameters::genesis(),
+            governance: GovernanceReport {
+                current_block: 10 + VOTING_PERIOD + 1,
+                // Degraded: admission slows down, enactment does not
+                timelock_multiplier: 2,
+                proposals: vec![proposal],
+            },
+            log: vec![ParameterChangeRecord {
+                sequence: 0,
+                proposal_id: 7,
+                changes: vec![change],
+                block: 1_200,
+                epoch: 2,
+                error: Some("ConstitutionalViolation".to_string()),
+            }],
+        };
+
+        let rpc = ProtocolParametersRpc::from(&report);
+        assert_eq!((rpc.fee_shares.producer, rpc.fee_shares.burn), (50, 30));
+        assert_eq!((rpc.inflation_floor_bps, rpc.inflation_cap_bps), (50, 1_000));
+        assert_eq!(rpc.scheduled.len(), 1);
+        assert_eq!(rpc.scheduled[0].enacts_at, enactment_block(voting_ends_at + PROTOCOL_UPGRADE_TIMELOCK));
+        assert_eq!(rpc.scheduled[0].enacts_at % EPOCH_DURATION_BLOCKS, 0);
+
+        let log = ParameterChangeRecordRpc::from_report(&report);
+        assert!(!log[0].applied);
+        assert_eq!(log[0].changes[0]["InflationBounds"]["cap_bps"], 800);
+    }
+
+    #[test]
+    fn test_upgrade_status_rpc() {
+        use crate::contracts::governance::{Vote, VOTING_PERIOD};
+
+        let upgrade = ParameterChange::ProtocolUpgrade(ProtocolVersion::new(1, 1, 0));
+        let mut proposal = Proposal::new(
+            1,
+            ChainId::ROOT,
+            AccountId::from_bytes([1; 32]),
+            ProposalType::ProtocolChange { changes: vec![upgrade], rationale: String::new() },
+            None,
+            100,
+            10,
+        );
+        proposal.eligible_votes = 1_000;
+        proposal.seconds = vec![AccountId::from_bytes([2; 32]), AccountId::from_bytes([3; 32])];
+        proposal.cast(AccountId::from_bytes([1; 32]), Vote::Yes, 800, 11).unwrap();
+
+        let mut report = ParametersReport {
+            parameters: ProtocolParameters::genesis(),
+            governance: GovernanceReport {
+                current_block: 10 + VOTING_PERIOD + 1,
+                timelock_multiplier: 1,
+                proposals: vec![],
+            },
+            log: vec![],
+        };
+        let rpc = UpgradeStatusRpc::from(&report);
+        assert_eq!((rpc.current_version.as_str(), rpc.supported_version.as_str()), ("1.0.0", "1.0.0"));
+        assert!(rpc.scheduled.is_none() && rpc.ready);
+
+        // Approved upgrade this release does not validate: operators must update
+        report.governance.proposals.push(proposal);
+        let rpc = UpgradeStatusRpc::from(&report);
+        let scheduled = rpc.scheduled.unwrap();
+        assert_eq!(scheduled.version, "1.1.0");
+        assert_eq!(scheduled.activation_block, scheduled.activation_epoch * EPOCH_DURATION_BLOCKS);
+        assert_eq!(scheduled.blocks_remaining, scheduled.activation_block - report.governance.current_block);
+        assert!(!scheduled.supported && !rpc.ready);
+    }
+
+    #[test]
+    fn test_identity_rpc() {
+        let owner = AccountId::from_bytes([1; 32]);
+        let mut identity = RegisteredIdentity::new(owner, Some("alice".to_string()), vec![Hash::ZERO], 100);
+        identity.attest(AccountId::from_bytes([2; 32]), None, Hash::ZERO, 110).unwrap();
+
+        let rpc = IdentityRpc::new(&identity, 120);
+        assert_eq!(rpc.account, format!("0x{}", "01".repeat(32)));
+        assert_eq!(rpc.display_name.as_deref(), Some("alice"));
+        assert_eq!(rpc.contact_hashes, vec![format!("0x{}", "00".repeat(32))]);
+        assert_eq!(rpc.status, "Attested");
+        assert_eq!((rpc.valid_attestations, rpc.required_attestations), (1, MIN_ATTESTATIONS_FOR_ACTIVE));
+        assert!(rpc.attestations[0].active);
+
+        let event = EventRpc::from(&Event::Identity(IdentityEvent::IdentityRevoked {
+            identity_id: identity.identity.identity_id,
+            reason: RevocationReason::Voluntary,
+        }));
+        assert_eq!((event.contract.as_str(), event.name.as_str()), ("identity", "IdentityRevoked"));
+        assert_eq!(event.data["reason"], "Voluntary");
+    }
+
+    #[test]
+    fn test_personhood_status_rpc() {
+        use crate::contracts::personhood::{ceremony_commitment, CeremonyRound, PersonhoodRecord};
+
+        let account = AccountId::from_bytes([1; 32]);
+        let mut round = CeremonyRound::new(2);
+        round.commit(account, ceremony_commitment(&account, &Hash::ZERO)).unwrap();
+        let record = PersonhoodRecord::verify(None, account, Hash::ZERO, 1, 1_100);
+        let report = PersonhoodReport { account, next_block: 1_250, record: Some(record), round };
+
+        let rpc = PersonhoodStatusRpc::from(&report);
+        assert!(rpc.verified);
+        assert_eq!((rpc.verified_round, rpc.rounds_attended), (Some(1), 1));
+        assert_eq!(rpc.ceremony.phase, "Commit");
+        assert_eq!((rpc.ceremony.participants, rpc.ceremony.verified), (1, 0));
+        assert!(rpc.ceremony.committed && !rpc.ceremony.revealed);
+
+        let unknown = PersonhoodStatusRpc::from(&PersonhoodReport {
+            account: AccountId::from_bytes([2; 32]),
+            next_block: 1_700,
+            record: None,
+            round: CeremonyRound::new(2),
+        });
+        assert!(!unknown.verified && unknown.expires_at.is_none());
+        assert_eq!(unknown.ceremony.phase, "Reveal");
+    }
+
+    #[test]
+    fn test_emergency_status_rpc() {
+        let mut declaration = EmergencyDeclaration::default();
+        declaration.sign_declaration(AccountId::from_bytes([1; 32]), 4, 100).unwrap();
+        let report = EmergencyReport {
+            next_block: 101,
+            security_state: NetworkSecurityState::Normal,
+            armed: false,
+            in_emergency: false,
+            validators: 4,
+            declaration,
+            breakers: crate::contracts::emergency::EmergencyContract::new().breakers.into_values().collect(),
+        };
+
+        let rpc = EmergencyStatusRpc::from(&report);
+        assert_eq!(rpc.security_state, "Normal");
+        assert_eq!((rpc.declaration_signatures, rpc.declaration_required), (1, 3));
+        assert_eq!(rpc.recovery_required, 3);
+        assert!(rpc.declared_at.is_none() && !rpc.active);
+        assert!(!rpc.breakers.is_empty() && rpc.breakers.iter().all(|b| !b.armed));
+    }
+
+    #[test]
+    fn test_account_message_rpc() {
+        let message = AccountMessage {
+            id: 3,
+            sender: AccountId::from_bytes([1; 32]),
+            recipient: AccountId::from_bytes([2; 32]),
+            body: b"hi".to_vec(),
+            encrypted: false,
+            sent_at: 42,
+        };
+        let rpc = AccountMessageRpc::from(&message);
+        assert_eq!(rpc.from, format!("0x{}", "01".repeat(32)));
+        assert_eq!(rpc.body, "0x6869");
+        assert_eq!(serde_json::to_value(&rpc).unwrap()["sentAt"], 42);
+
+        let event = EventRpc::from(&Event::Messaging(MessagingEvent::MessageDelivered {
+            id: 3,
+            from: message.sender,
+            to: message.recipient,
+            size: 2,
+            encrypted: false,
+        }));
+        assert_eq!((event.contract.as_str(), event.name.as_str()), ("messaging", "MessageDelivered"));
+        assert_eq!(event.data["size"], 2);
+    }
+
+    #[test]
+    fn test_reputation_rpc_top() {
+        let mut alice = AccountReputation::new(AccountId::from_bytes([1; 32]), 0);
+        alice.accrue(ReputationDomain::Governance, 100, 10);
+        let mut bob = AccountReputation::new(AccountId::from_bytes([2; 32]), 0);
+        bob.accrue(ReputationDomain::Validation, 200, 20);
+        let carol = AccountReputation::new(AccountId::from_bytes([3; 32]), 0);
+        let reputations = vec![carol, bob.clone(), alice];
+
+        let rpc = ReputationRpc::from(&bob);
+        assert_eq!(rpc.account, format!("0x{}", "02".repeat(32)));
+        assert_eq!(rpc.domains.len(), 1);
+        assert_eq!((rpc.domains[0].domain.as_str(), rpc.domains[0].score), ("Validation", 200));
+
+        // Overall: Governance weighs 25, Validation 10; carol has no score
+        let top = ReputationRpc::top(&reputations, DEFAULT_REPUTATION_TOP, None);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].account, format!("0x{}", "01".repeat(32)));
+
+        let validation = ReputationRpc::top(&reputations, 1, Some(parse_reputation_domain("validation").unwrap()));
+        assert_eq!(validation.len(), 1);
+        assert_eq!(validation[0].account, format!("0x{}", "02".repeat(32)));
+        assert!(parse_reputation_domain("cooking").is_err());
+    }
+
     #[test]
     fn test_parse_account_id() {
         let hex = "0x0101010101010101010101010101010101010101010101010101010101010101";
@@ -0,0 +1,216 @@
+// State Cache - LRU cache of accounts over the database
+// Principle: Hot accounts stay in memory, the database stays authoritative
+//
+// The cache is write-through (see StateBackend): every write goes to the
+// database first, so evicting an entry never loses state. Before a block is
+// executed, the accounts it is known to touch (author, treasury, validators,
+// transaction senders and recipients) are read ahead so execution hits memory.
+
+use crate::types::{AccountId, AccountInfo};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Default number of cached accounts
+pub const DEFAULT_STATE_CACHE_CAPACITY: usize = 16_384;
+
+/// Cache counters since startup (system_stateCache)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateCacheStats {
+    /// Reads served from memory
+    pub hits: u64,
+    /// Reads that went to the database
+    pub misses: u64,
+    /// Entries dropped to stay within capacity
+    pub evictions: u64,
+    /// Accounts loaded ahead of block execution
+    pub prefetched: u64,
+    /// Blocks executed with read-ahead
+    pub blocks: u64,
+    /// Accounts currently cached
+    pub entries: usize,
+    /// Maximum number of cached accounts
+    pub capacity: usize,
+}
+
+impl StateCacheStats {
+    /// Share of reads served from memory (0.0 when nothing was read)
+    pub fn hit_rate(&self) -> f64 {
+        let reads = self.hits + self.misses;
+        if reads == 0 {
+            0.0
+        } else {
+            self.hits as f64 / reads as f64
+        }
+    }
+}
+
+/// LRU cache of accounts
+#[derive(Debug)]
+pub struct StateCache {
+    capacity: usize,
+    /// Account -> (value, last use)
+    entries: HashMap<AccountId, (AccountInfo, u64)>,
+    /// Last use -> account, oldest first
+    recency: BTreeMap<u64, AccountId>,
+    /// Use counter
+    clock: u64,
+    stats: StateCacheStats,
+}
+
+impl StateCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            clock: 0,
+            stats: StateCacheStats::default(),
+        }
+    }
+
+    /// Look up an account, counting a hit or a miss
+    pub fn get(&mut self, id: &AccountId) -> Option<AccountInfo> {
+        if self.touch(id) {
+            self.stats.hits += 1;
+            self.entries.get(id).map(|(info, _)| info.clone())
+        } else {
+            self.stats.misses += 1;
+            None
+        }
+    }
+
+    /// Whether an account is cached (does not count as a read)
+    pub fn contains(&self, id: &AccountId) -> bool {
+        self.entries.contains_key(id)
+    }
+
+    /// Insert or update an account, evicting the least recently used ones
+    pub fn insert(&mut self, id: AccountId, info: AccountInfo) {
+        self.clock += 1;
+        if let Some((_, used)) = self.entries.insert(id, (info, self.clock)) {
+            self.recency.remove(&used);
+        }
+        self.recency.insert(self.clock, id);
+
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest)) = self.recency.pop_first() else { break };
+            self.entries.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+    }
+
+    pub fn remove(&mut self, id: &AccountId) {
+        if let Some((_, used)) = self.entries.remove(id) {
+            self.recency.remove(&used);
+        }
+    }
+
+    /// Drop every entry (counters are kept)
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Change the capacity, evicting entries if it shrinks
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest)) = self.recency.pop_first() else { break };
+            self.entries.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+    }
+
+    /// Record accounts loaded ahead of a block
+    pub fn record_prefetch(&mut self, accounts: u64) {
+        self.stats.prefetched += accounts;
+        self.stats.blocks += 1;
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn stats(&self) -> StateCacheStats {
+        StateCacheStats {
+            entries: self.entries.len(),
+            capacity: self.capacity,
+            ..self.stats
+        }
+    }
+
+    /// Mark an account as most recently used; false if it is not cached
+    fn touch(&mut self, id: &AccountId) -> bool {
+        let Some((_, used)) = self.entries.get_mut(id) else {
+            return false;
+        };
+        self.clock += 1;
+        self.recency.remove(used);
+        *used = self.clock;
+        self.recency.insert(self.clock, *id);
+        true
+    }
+}
+
+impl Default for StateCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_STATE_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(free: u128) -> AccountInfo {
+        let mut info = AccountInfo::new();
+        info.free = free;
+        info
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let mut cache = StateCache::new(2);
+        let a = AccountId::from_bytes([1; 32]);
+        let b = AccountId::from_bytes([2; 32]);
+        let c = AccountId::from_bytes([3; 32]);
+
+        cache.insert(a, account(1));
+        cache.insert(b, account(2));
+
+        // Reading `a` makes `b` the least recently used
+        assert_eq!(cache.get(&a).unwrap().free, 1);
+        cache.insert(c, account(3));
+
+        assert!(cache.contains(&a));
+        assert!(!cache.contains(&b));
+        assert!(cache.contains(&c));
+        assert_eq!(cache.stats().evictions, 1);
+    }
+
+    #[test]
+    fn test_hit_miss_counters() {
+        let mut cache = StateCache::new(8);
+        let a = AccountId::from_bytes([1; 32]);
+
+        assert!(cache.get(&a).is_none());
+        cache.insert(a, account(5));
+        cache.insert(a, account(6));
+        assert_eq!(cache.get(&a).unwrap().free, 6);
+        assert_eq!(cache.len(), 1);
+
+        cache.set_capacity(0);
+        cache.insert(AccountId::from_bytes([2; 32]), account(7));
+        assert_eq!(cache.len(), 1);
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (1, 1));
+        assert_eq!(stats.capacity, 1);
+        assert!((stats.hit_rate() - 0.5).abs() < f64::EPSILON);
+    }
+}
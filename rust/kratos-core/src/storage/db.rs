@@ -1,16 +1,248 @@
-// Database - Abstraction RocksDB
-use rocksdb::{Options, DB};
-use std::path::Path;
-use std::sync::Arc;
+// Database - Backends de stockage clé-valeur (RocksDB, ParityDB, mémoire)
+//
+// Storage only needs ordered key-value access: point reads and writes, atomic
+// batches and prefix iteration. The `Database` trait captures that so the
+// backend can be picked at startup (`--database`): RocksDB by default, ParityDB
+// for a smaller disk footprint, or memory for tests and ephemeral devnets.
+use rocksdb::{IteratorMode, Options, DB};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+
+/// Version du schéma de stockage (disposition des clés et encodages)
+///
+/// Increment it with every layout change and add the matching migration to
+/// `storage::migration::MIGRATIONS`.
+pub const SCHEMA_VERSION: u32 = 3;
+
+/// Clé du marqueur de version du schéma
+const KEY_SCHEMA_VERSION: &[u8] = b"schema_version";
+
+/// Itérateur de paires clé-valeur
+pub type KeyValueIter<'a> = Box<dyn Iterator<Item = Result<(Vec<u8>, Vec<u8>), DatabaseError>> + 'a>;
+
+/// Base de données clé-valeur ordonnée
+pub trait Database: Send + Sync {
+    /// Backend utilisé
+    fn backend(&self) -> DatabaseBackend;
+
+    /// Lit une valeur
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, DatabaseError>;
+
+    /// Écrit une valeur
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), DatabaseError>;
+
+    /// Supprime une clé
+    fn delete(&self, key: &[u8]) -> Result<(), DatabaseError>;
+
+    /// Batch write (transaction atomique)
+    fn batch_write(&self, ops: Vec<WriteOp>) -> Result<(), DatabaseError>;
+
+    /// Itère sur toutes les clés avec un préfixe donné (ordre des clés), returning Result for each item
+    /// Use this when you need to handle errors explicitly
+    fn prefix_iterator_with_errors<'a>(&'a self, prefix: &'a [u8]) -> KeyValueIter<'a>;
+
+    /// Itère sur les clés avec un préfixe donné à partir de `from` (inclus)
+    ///
+    /// Backends able to seek override it; the default walks the prefix.
+    fn prefix_iterator_from<'a>(&'a self, prefix: &'a [u8], from: &'a [u8]) -> KeyValueIter<'a> {
+        Box::new(
+            self.prefix_iterator_with_errors(prefix)
+                .skip_while(move |item| matches!(item, Ok((key, _)) if key.as_slice() < from)),
+        )
+    }
+
+    /// Compacte toute la base (supprime les données mortes), si le backend le permet
+    fn compact(&self) {}
+
+    /// Taille sur disque en octets (si le backend la fournit)
+    fn live_size(&self) -> Option<u64> {
+        None
+    }
+
+    /// Itère sur toutes les clés avec un préfixe donné
+    /// FIX: Handle database errors gracefully instead of panicking
+    fn prefix_iterator<'a>(&'a self, prefix: &'a [u8]) -> Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'a> {
+        Box::new(self.prefix_iterator_with_errors(prefix).filter_map(|item| match item {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                // Log the error but continue iteration
+                tracing::warn!("Database iteration error (skipping): {}", e);
+                None
+            }
+        }))
+    }
+
+    /// Vérifie si une clé existe
+    fn exists(&self, key: &[u8]) -> Result<bool, DatabaseError> {
+        Ok(self.get(key)?.is_some())
+    }
+
+    /// Vérifie si la base ne contient aucune clé
+    fn is_empty(&self) -> Result<bool, DatabaseError> {
+        match self.prefix_iterator_with_errors(&[]).next() {
+            None => Ok(true),
+            Some(Ok(_)) => Ok(false),
+            Some(Err(e)) => Err(e),
+        }
+    }
+
+    /// Version du schéma stockée (None pour une base créée avant le marqueur)
+    fn schema_version(&self) -> Result<Option<u32>, DatabaseError> {
+        match self.get(KEY_SCHEMA_VERSION)? {
+            Some(data) => {
+                let bytes: [u8; 4] = data.as_slice().try_into().map_err(|_| {
+                    DatabaseError::SerializationFailed(format!("invalid schema version marker ({} bytes)", data.len()))
+                })?;
+                Ok(Some(u32::from_le_bytes(bytes)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Écrit le marqueur de version du schéma
+    fn set_schema_version(&self, version: u32) -> Result<(), DatabaseError> {
+        self.put(KEY_SCHEMA_VERSION, &version.to_le_bytes())
+    }
+}
+
+impl<D: Database + ?Sized> Database for Box<D> {
+    fn backend(&self) -> DatabaseBackend {
+        (**self).backend()
+    }
+
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, DatabaseError> {
+        (**self).get(key)
+    }
+
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), DatabaseError> {
+        (**self).put(key, value)
+    }
+
+    fn delete(&self, key: &[u8]) -> Result<(), DatabaseError> {
+        (**self).delete(key)
+    }
+
+    fn batch_write(&self, ops: Vec<WriteOp>) -> Result<(), DatabaseError> {
+        (**self).batch_write(ops)
+    }
+
+    fn prefix_iterator_with_errors<'a>(&'a self, prefix: &'a [u8]) -> KeyValueIter<'a> {
+        (**self).prefix_iterator_with_errors(prefix)
+    }
+
+    fn prefix_iterator_from<'a>(&'a self, prefix: &'a [u8], from: &'a [u8]) -> KeyValueIter<'a> {
+        (**self).prefix_iterator_from(prefix, from)
+    }
+
+    fn compact(&self) {
+        (**self).compact()
+    }
+
+    fn live_size(&self) -> Option<u64> {
+        (**self).live_size()
+    }
+}
+
+/// Backends disponibles (`--database`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    /// RocksDB (défaut)
+    #[default]
+    RocksDb,
+    /// ParityDB: moins d'espace disque, pas de compaction manuelle
+    ParityDb,
+    /// En mémoire: rien n'est écrit sur disque (tests, devnets éphémères)
+    Memory,
+}
+
+impl DatabaseBackend {
+    /// Backend d'une base existante, d'après ses fichiers
+    pub fn detect(path: &Path) -> Option<Self> {
+        if path.join("CURRENT").is_file() {
+            Some(Self::RocksDb)
+        } else if path.join(PARITYDB_METADATA).is_file() {
+            Some(Self::ParityDb)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for DatabaseBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RocksDb => write!(f, "rocksdb"),
+            Self::ParityDb => write!(f, "paritydb"),
+            Self::Memory => write!(f, "memory"),
+        }
+    }
+}
+
+impl FromStr for DatabaseBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rocksdb" => Ok(Self::RocksDb),
+            "paritydb" => Ok(Self::ParityDb),
+            "memory" => Ok(Self::Memory),
+            other => Err(format!("Unknown database backend: {} (rocksdb, paritydb, memory)", other)),
+        }
+    }
+}
+
+/// Ouvre ou crée une base avec le backend demandé
+///
+/// Refuses a directory that already holds a database of another backend
+/// rather than silently starting an empty one next to it.
+pub fn open_database<P: AsRef<Path>>(path: P, backend: DatabaseBackend) -> Result<Box<dyn Database>, DatabaseError> {
+    let path = path.as_ref();
+    if backend != DatabaseBackend::Memory {
+        if let Some(existing) = DatabaseBackend::detect(path).filter(|existing| *existing != backend) {
+            return Err(DatabaseError::OpenFailed(format!(
+                "{} holds a {} database, not {} (use --database {})",
+                path.display(),
+                existing,
+                backend,
+                existing
+            )));
+        }
+    }
+
+    Ok(match backend {
+        DatabaseBackend::RocksDb => Box::new(RocksDatabase::open(path)?),
+        DatabaseBackend::ParityDb => Box::new(ParityDatabase::open(path)?),
+        DatabaseBackend::Memory => Box::new(MemoryDatabase::new()),
+    })
+}
+
+/// Ouvre une base existante avec le backend qui l'a créée (RocksDB à défaut)
+pub fn open_existing<P: AsRef<Path>>(path: P) -> Result<Box<dyn Database>, DatabaseError> {
+    let path = path.as_ref();
+    open_database(path, DatabaseBackend::detect(path).unwrap_or_default())
+}
+
+// =============================================================================
+// ROCKSDB
+// =============================================================================
 
 /// Wrapper autour de RocksDB
-pub struct Database {
+pub struct RocksDatabase {
     db: Arc<DB>,
 }
 
-impl Database {
+impl RocksDatabase {
     /// Ouvre ou crée une base de données
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, DatabaseError> {
+        let db = DB::open(&Self::options(), path).map_err(|e| DatabaseError::OpenFailed(e.to_string()))?;
+
+        Ok(Self { db: Arc::new(db) })
+    }
+
+    fn options() -> Options {
         let mut opts = Options::default();
         opts.create_if_missing(true);
         opts.create_missing_column_families(true);
@@ -23,39 +255,42 @@ impl Database {
         // Nettoyage automatique des anciens fichiers WAL
         opts.set_recycle_log_file_num(2);
 
-        let db = DB::open(&opts, path).map_err(|e| DatabaseError::OpenFailed(e.to_string()))?;
+        opts
+    }
 
-        Ok(Self { db: Arc::new(db) })
+    /// Tente de réparer une base de données corrompue (RocksDB repair)
+    ///
+    /// Recovers what it can from the SST files and rewrites the MANIFEST; the
+    /// database must not be open.
+    pub fn repair<P: AsRef<Path>>(path: P) -> Result<(), DatabaseError> {
+        DB::repair(&Self::options(), path).map_err(|e| DatabaseError::RepairFailed(e.to_string()))
     }
+}
 
-    /// Lit une valeur
-    pub fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, DatabaseError> {
+impl Database for RocksDatabase {
+    fn backend(&self) -> DatabaseBackend {
+        DatabaseBackend::RocksDb
+    }
+
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, DatabaseError> {
         self.db
             .get(key)
             .map_err(|e| DatabaseError::ReadFailed(e.to_string()))
     }
 
-    /// Écrit une valeur
-    pub fn put(&self, key: &[u8], value: &[u8]) -> Result<(), DatabaseError> {
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), DatabaseError> {
         self.db
             .put(key, value)
             .map_err(|e| DatabaseError::WriteFailed(e.to_string()))
     }
 
-    /// Supprime une clé
-    pub fn delete(&self, key: &[u8]) -> Result<(), DatabaseError> {
+    fn delete(&self, key: &[u8]) -> Result<(), DatabaseError> {
         self.db
             .delete(key)
             .map_err(|e| DatabaseError::WriteFailed(e.to_string()))
     }
 
-    /// Vérifie si une clé existe
-    pub fn exists(&self, key: &[u8]) -> Result<bool, DatabaseError> {
-        Ok(self.get(key)?.is_some())
-    }
-
-    /// Batch write (transaction atomique)
-    pub fn batch_write(&self, ops: Vec<WriteOp>) -> Result<(), DatabaseError> {
+    fn batch_write(&self, ops: Vec<WriteOp>) -> Result<(), DatabaseError> {
         let mut batch = rocksdb::WriteBatch::default();
 
         for op in ops {
@@ -70,38 +305,235 @@ impl Database {
             .map_err(|e| DatabaseError::WriteFailed(e.to_string()))
     }
 
-    /// Itère sur toutes les clés avec un préfixe donné
-    /// FIX: Handle RocksDB errors gracefully instead of panicking
-    pub fn prefix_iterator<'a>(&'a self, prefix: &'a [u8]) -> impl Iterator<Item = (Vec<u8>, Vec<u8>)> + 'a {
-        let iter = self.db.prefix_iterator(prefix);
-        iter.filter_map(|item| {
-            // FIX: Use filter_map to skip errors instead of unwrap() which panics
-            match item {
-                Ok((key, value)) => Some((key.to_vec(), value.to_vec())),
+    fn prefix_iterator_with_errors<'a>(&'a self, prefix: &'a [u8]) -> KeyValueIter<'a> {
+        Box::new(
+            self.db.prefix_iterator(prefix).map(|item| {
+                item.map(|(key, value)| (key.to_vec(), value.to_vec()))
+                    .map_err(|e| DatabaseError::ReadFailed(e.to_string()))
+            })
+            .take_while(move |result| {
+                match result {
+                    Ok((key, _)) => key.starts_with(prefix),
+                    Err(_) => true, // Continue iteration on error to let caller handle it
+                }
+            }),
+        )
+    }
+
+    fn prefix_iterator_from<'a>(&'a self, prefix: &'a [u8], from: &'a [u8]) -> KeyValueIter<'a> {
+        let from = if from < prefix { prefix } else { from };
+        Box::new(
+            self.db
+                .iterator(IteratorMode::From(from, rocksdb::Direction::Forward))
+                .map(|item| {
+                    item.map(|(key, value)| (key.to_vec(), value.to_vec()))
+                        .map_err(|e| DatabaseError::ReadFailed(e.to_string()))
+                })
+                .take_while(move |result| match result {
+                    Ok((key, _)) => key.starts_with(prefix),
+                    Err(_) => true,
+                }),
+        )
+    }
+
+    fn is_empty(&self) -> Result<bool, DatabaseError> {
+        match self.db.iterator(IteratorMode::Start).next() {
+            None => Ok(true),
+            Some(Ok(_)) => Ok(false),
+            Some(Err(e)) => Err(DatabaseError::ReadFailed(e.to_string())),
+        }
+    }
+
+    /// Réécrit les SST
+    fn compact(&self) {
+        self.db.compact_range(None::<&[u8]>, None::<&[u8]>);
+    }
+
+    /// Taille des fichiers SST
+    fn live_size(&self) -> Option<u64> {
+        self.db
+            .property_int_value("rocksdb.total-sst-files-size")
+            .ok()
+            .flatten()
+    }
+}
+
+// =============================================================================
+// PARITYDB
+// =============================================================================
+
+/// Fichier créé par ParityDB à la racine de la base
+const PARITYDB_METADATA: &str = "metadata";
+
+/// Colonne unique (index B-tree, nécessaire pour l'itération ordonnée)
+const PARITYDB_COLUMN: u8 = 0;
+
+/// Wrapper autour de ParityDB
+pub struct ParityDatabase {
+    db: parity_db::Db,
+    path: PathBuf,
+}
+
+impl ParityDatabase {
+    /// Ouvre ou crée une base de données
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, DatabaseError> {
+        let path = path.as_ref().to_path_buf();
+        let mut options = parity_db::Options::with_columns(&path, 1);
+        options.columns[PARITYDB_COLUMN as usize].btree_index = true;
+
+        let db = parity_db::Db::open_or_create(&options).map_err(|e| DatabaseError::OpenFailed(e.to_string()))?;
+        Ok(Self { db, path })
+    }
+}
+
+impl Database for ParityDatabase {
+    fn backend(&self) -> DatabaseBackend {
+        DatabaseBackend::ParityDb
+    }
+
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, DatabaseError> {
+        self.db
+            .get(PARITYDB_COLUMN, key)
+            .map_err(|e| DatabaseError::ReadFailed(e.to_string()))
+    }
+
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), DatabaseError> {
+        self.batch_write(vec![WriteOp::Put { key: key.to_vec(), value: value.to_vec() }])
+    }
+
+    fn delete(&self, key: &[u8]) -> Result<(), DatabaseError> {
+        self.batch_write(vec![WriteOp::Delete { key: key.to_vec() }])
+    }
+
+    fn batch_write(&self, ops: Vec<WriteOp>) -> Result<(), DatabaseError> {
+        let changes = ops.into_iter().map(|op| match op {
+            WriteOp::Put { key, value } => (PARITYDB_COLUMN, key, Some(value)),
+            WriteOp::Delete { key } => (PARITYDB_COLUMN, key, None),
+        });
+
+        self.db
+            .commit(changes)
+            .map_err(|e| DatabaseError::WriteFailed(e.to_string()))
+    }
+
+    fn prefix_iterator_with_errors<'a>(&'a self, prefix: &'a [u8]) -> KeyValueIter<'a> {
+        self.prefix_iterator_from(prefix, prefix)
+    }
+
+    fn prefix_iterator_from<'a>(&'a self, prefix: &'a [u8], from: &'a [u8]) -> KeyValueIter<'a> {
+        let read_failed = |e: parity_db::Error| DatabaseError::ReadFailed(e.to_string());
+        let mut iter = match self.db.iter(PARITYDB_COLUMN) {
+            Ok(iter) => iter,
+            Err(e) => return Box::new(std::iter::once(Err(read_failed(e)))),
+        };
+        if let Err(e) = iter.seek(if from < prefix { prefix } else { from }) {
+            return Box::new(std::iter::once(Err(read_failed(e))));
+        }
+
+        let mut failed = false;
+        Box::new(std::iter::from_fn(move || {
+            if failed {
+                return None;
+            }
+            match iter.next() {
+                Ok(Some((key, value))) if key.starts_with(prefix) => Some(Ok((key, value))),
+                Ok(_) => None,
                 Err(e) => {
-                    // Log the error but continue iteration
-                    tracing::warn!("Database iteration error (skipping): {}", e);
-                    None
+                    // The iterator cannot resume after an error
+                    failed = true;
+                    Some(Err(read_failed(e)))
                 }
             }
-        })
-        .take_while(move |(key, _)| key.starts_with(prefix))
+        }))
     }
 
-    /// Itère sur toutes les clés avec un préfixe donné, returning Result for each item
-    /// Use this when you need to handle errors explicitly
-    pub fn prefix_iterator_with_errors<'a>(&'a self, prefix: &'a [u8]) -> impl Iterator<Item = Result<(Vec<u8>, Vec<u8>), DatabaseError>> + 'a {
-        let iter = self.db.prefix_iterator(prefix);
-        iter.map(|item| {
-            item.map(|(key, value)| (key.to_vec(), value.to_vec()))
-                .map_err(|e| DatabaseError::ReadFailed(e.to_string()))
-        })
-        .take_while(move |result| {
-            match result {
-                Ok((key, _)) => key.starts_with(prefix),
-                Err(_) => true, // Continue iteration on error to let caller handle it
-            }
-        })
+    /// Taille des fichiers de la base
+    fn live_size(&self) -> Option<u64> {
+        let entries = std::fs::read_dir(&self.path).ok()?;
+        Some(
+            entries
+                .filter_map(|entry| entry.ok()?.metadata().ok())
+                .filter(|metadata| metadata.is_file())
+                .map(|metadata| metadata.len())
+                .sum(),
+        )
+    }
+}
+
+// =============================================================================
+// MEMORY
+// =============================================================================
+
+/// Base en mémoire (perdue à l'arrêt)
+#[derive(Debug, Default)]
+pub struct MemoryDatabase {
+    entries: RwLock<BTreeMap<Vec<u8>, Vec<u8>>>,
+}
+
+impl MemoryDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, BTreeMap<Vec<u8>, Vec<u8>>> {
+        self.entries.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, BTreeMap<Vec<u8>, Vec<u8>>> {
+        self.entries.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Database for MemoryDatabase {
+    fn backend(&self) -> DatabaseBackend {
+        DatabaseBackend::Memory
+    }
+
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, DatabaseError> {
+        Ok(self.read().get(key).cloned())
+    }
+
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), DatabaseError> {
+        self.write().insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    fn delete(&self, key: &[u8]) -> Result<(), DatabaseError> {
+        self.write().remove(key);
+        Ok(())
+    }
+
+    fn batch_write(&self, ops: Vec<WriteOp>) -> Result<(), DatabaseError> {
+        let mut entries = self.write();
+        for op in ops {
+            match op {
+                WriteOp::Put { key, value } => entries.insert(key, value),
+                WriteOp::Delete { key } => entries.remove(&key),
+            };
+        }
+        Ok(())
+    }
+
+    fn prefix_iterator_with_errors<'a>(&'a self, prefix: &'a [u8]) -> KeyValueIter<'a> {
+        // Snapshot, so that the caller may write while iterating
+        let matching: Vec<_> = self
+            .read()
+            .range(prefix.to_vec()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| Ok((key.clone(), value.clone())))
+            .collect();
+        Box::new(matching.into_iter())
+    }
+
+    fn prefix_iterator_from<'a>(&'a self, prefix: &'a [u8], from: &'a [u8]) -> KeyValueIter<'a> {
+        let from = if from < prefix { prefix } else { from };
+        let matching: Vec<_> = self
+            .read()
+            .range(from.to_vec()..)
+            .take_while(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| Ok((key.clone(), value.clone())))
+            .collect();
+        Box::new(matching.into_iter())
     }
 }
 
@@ -126,6 +558,9 @@ pub enum DatabaseError {
 
     #[error("Sérialisation échouée: {0}")]
     SerializationFailed(String),
+
+    #[error("Échec de réparation: {0}")]
+    RepairFailed(String),
 }
 
 #[cfg(test)]
@@ -136,7 +571,7 @@ mod tests {
     #[test]
     fn test_database_basic_ops() {
         let temp_dir = TempDir::new().unwrap();
-        let db = Database::open(temp_dir.path()).unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
 
         // Put
         db.put(b"key1", b"value1").unwrap();
@@ -157,7 +592,7 @@ mod tests {
     #[test]
     fn test_database_batch() {
         let temp_dir = TempDir::new().unwrap();
-        let db = Database::open(temp_dir.path()).unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
 
         let ops = vec![
             WriteOp::Put {
@@ -175,4 +610,58 @@ mod tests {
         assert!(db.exists(b"key1").unwrap());
         assert!(db.exists(b"key2").unwrap());
     }
+
+    #[test]
+    fn test_backends_behave_alike() {
+        let temp_dir = TempDir::new().unwrap();
+        for backend in [DatabaseBackend::RocksDb, DatabaseBackend::ParityDb, DatabaseBackend::Memory] {
+            let db = open_database(temp_dir.path().join(backend.to_string()), backend).unwrap();
+            assert_eq!(db.backend(), backend);
+            assert!(db.is_empty().unwrap());
+
+            db.batch_write(vec![
+                WriteOp::Put { key: b"acc:2".to_vec(), value: b"two".to_vec() },
+                WriteOp::Put { key: b"acc:1".to_vec(), value: b"one".to_vec() },
+                WriteOp::Put { key: b"blk:1".to_vec(), value: b"block".to_vec() },
+                WriteOp::Put { key: b"acc:3".to_vec(), value: b"three".to_vec() },
+            ])
+            .unwrap();
+            db.delete(b"acc:3").unwrap();
+            db.set_schema_version(SCHEMA_VERSION).unwrap();
+
+            // Prefix iteration is ordered and stops at the end of the prefix
+            let accounts: Vec<_> = db.prefix_iterator(b"acc:").collect();
+            assert_eq!(
+                accounts,
+                vec![(b"acc:1".to_vec(), b"one".to_vec()), (b"acc:2".to_vec(), b"two".to_vec())],
+                "{}",
+                backend
+            );
+            let from: Vec<_> = db.prefix_iterator_from(b"acc:", b"acc:2").map(|item| item.unwrap().0).collect();
+            assert_eq!(from, vec![b"acc:2".to_vec()], "{}", backend);
+            assert_eq!(db.get(b"blk:1").unwrap(), Some(b"block".to_vec()));
+            assert_eq!(db.schema_version().unwrap(), Some(SCHEMA_VERSION));
+            assert!(!db.is_empty().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_backend_selection() {
+        assert_eq!("paritydb".parse::<DatabaseBackend>(), Ok(DatabaseBackend::ParityDb));
+        assert_eq!(DatabaseBackend::default().to_string(), "rocksdb");
+        assert!("sled".parse::<DatabaseBackend>().is_err());
+
+        // An existing database is reopened with its own backend, never another one
+        let temp_dir = TempDir::new().unwrap();
+        open_database(temp_dir.path(), DatabaseBackend::ParityDb).unwrap().put(b"key", b"value").unwrap();
+        assert_eq!(DatabaseBackend::detect(temp_dir.path()), Some(DatabaseBackend::ParityDb));
+        assert!(matches!(
+            open_database(temp_dir.path(), DatabaseBackend::RocksDb),
+            Err(DatabaseError::OpenFailed(_))
+        ));
+
+        let db = open_existing(temp_dir.path()).unwrap();
+        assert_eq!(db.backend(), DatabaseBackend::ParityDb);
+        assert_eq!(db.get(b"key").unwrap(), Some(b"value".to_vec()));
+    }
 }
@@ -0,0 +1,389 @@
+// Index - Secondary indexes for explorers and wallets (run --index)
+// Principle: Account history is served by the node, not rebuilt by clients
+//
+// An indexing node writes, next to every block it stores:
+// - idx_tx:<account><rev block><tx index>: transactions sent or received by
+//   an account (sender, Transfer and TreasuryPayout parties)
+// - idx_event:<contract.Name>\0<rev block><tx index><event index>: events by type
+// - idx_author:<account><rev block>: blocks authored by a validator
+// Block numbers are stored as u64::MAX - n so a prefix scan walks newest
+// first, and the length of every list is kept under idx_count:<list prefix>
+// for pagination. Entries carry what a page shows, so they outlive pruning.
+//
+// Account histories page by cursor: a cursor is the position of the last
+// entry read, so a page resumes with a seek, however deep in the list.
+
+use super::db::{Database, WriteOp};
+use super::state::StateError;
+use crate::types::{AccountId, Balance, Block, BlockNumber, Event, Hash, KratEvent, Receipt, SignedTransaction, Timestamp};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const PREFIX_INDEX_TX: &[u8] = b"idx_tx:";
+const PREFIX_INDEX_EVENT: &[u8] = b"idx_event:";
+const PREFIX_INDEX_AUTHOR: &[u8] = b"idx_author:";
+const PREFIX_INDEX_COUNT: &[u8] = b"idx_count:";
+
+/// Largest page returned by the index readers
+pub const MAX_INDEX_PAGE: usize = 100;
+
+/// Entries a filtered query reads at most before returning a partial page
+/// (its cursor resumes the scan)
+pub const MAX_INDEX_SCAN: usize = 10_000;
+
+/// Transaction in an account's history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedTransaction {
+    pub transaction: SignedTransaction,
+    pub block_number: BlockNumber,
+    pub block_hash: Hash,
+    pub timestamp: Timestamp,
+    /// Index of the transaction in the block body
+    pub index: u32,
+    pub success: bool,
+    pub fee_paid: Balance,
+    /// Events of the transaction, in emission order
+    pub events: Vec<Event>,
+}
+
+/// Event in the list of its type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedEvent {
+    pub event: Event,
+    pub tx_hash: Hash,
+    pub block_number: BlockNumber,
+    pub block_hash: Hash,
+    pub timestamp: Timestamp,
+    /// Index of the emitting transaction in the block body
+    pub index: u32,
+}
+
+/// Block in the list of its author
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedBlock {
+    pub number: BlockNumber,
+    pub hash: Hash,
+    pub timestamp: Timestamp,
+    pub tx_count: u32,
+}
+
+/// One page of an index list, newest first
+#[derive(Debug, Clone)]
+pub struct IndexPage<T> {
+    /// Length of the whole list (filters aside)
+    pub total: u64,
+    pub items: Vec<T>,
+    /// Where the next page starts, None on the last page
+    pub next: Option<IndexCursor>,
+}
+
+/// Position of the last entry read in an account history
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexCursor {
+    pub block_number: BlockNumber,
+    /// Index of the transaction in the block body
+    pub index: u32,
+}
+
+impl IndexCursor {
+    fn of(entry: &IndexedTransaction) -> Self {
+        Self {
+            block_number: entry.block_number,
+            index: entry.index,
+        }
+    }
+
+    /// Opaque form handed to clients
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(position(self.block_number, &[self.index])))
+    }
+
+    pub fn from_hex(s: &str) -> Result<Self, String> {
+        let bytes = hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(|e| format!("Invalid cursor: {}", e))?;
+        let bytes: [u8; 12] = bytes.try_into().map_err(|_| "Invalid cursor: expected 12 bytes".to_string())?;
+        let reversed = u64::from_be_bytes(bytes[..8].try_into().expect("8 bytes"));
+        Ok(Self {
+            block_number: u64::MAX - reversed,
+            index: u32::from_be_bytes(bytes[8..].try_into().expect("4 bytes")),
+        })
+    }
+}
+
+/// Side of the account in a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionDirection {
+    /// Sent by the account
+    Sent,
+    /// Sent by another account (transfers and payouts to the account)
+    Received,
+}
+
+/// Filters of an account history, all optional
+#[derive(Debug, Clone, Default)]
+pub struct TransactionFilter {
+    pub direction: Option<TransactionDirection>,
+    /// Lowest block, inclusive
+    pub from_block: Option<BlockNumber>,
+    /// Highest block, inclusive
+    pub to_block: Option<BlockNumber>,
+    /// Inclusion status: Some(true) keeps the successful transactions
+    pub success: Option<bool>,
+}
+
+impl TransactionFilter {
+    /// Whether a transaction, sent by `sender`, is on the filtered side
+    pub fn matches_direction(&self, account: &AccountId, sender: &AccountId) -> bool {
+        match self.direction {
+            None => true,
+            Some(TransactionDirection::Sent) => sender == account,
+            Some(TransactionDirection::Received) => sender != account,
+        }
+    }
+
+    fn matches(&self, account: &AccountId, entry: &IndexedTransaction) -> bool {
+        self.matches_direction(account, &entry.transaction.transaction.sender)
+            && self.success.is_none_or(|success| entry.success == success)
+            && self.from_block.is_none_or(|from| entry.block_number >= from)
+            && self.to_block.is_none_or(|to| entry.block_number <= to)
+    }
+}
+
+/// Type of an event in the index (`contract.Name`, e.g. krat.Transfer)
+pub fn event_type(event: &Event) -> String {
+    format!("{}.{}", event.contract(), event.name())
+}
+
+/// Index entries of a block, from its receipts (transactions without a
+/// receipt are left out)
+pub(super) fn block_index_ops(
+    db: &dyn Database,
+    block: &Block,
+    receipts: &[Receipt],
+) -> Result<Vec<WriteOp>, StateError> {
+    let number = block.header.number;
+    let block_hash = block.hash();
+    let timestamp = block.header.timestamp;
+    let receipts: HashMap<Hash, &Receipt> = receipts.iter().map(|r| (r.tx_hash, r)).collect();
+    let mut batch = IndexBatch::default();
+
+    for (index, tx) in block.body.transactions.iter().enumerate() {
+        let tx_hash = tx.hash();
+        let Some(receipt) = receipts.get(&tx_hash) else {
+            continue;
+        };
+        let index = index as u32;
+
+        let entry = encode(&IndexedTransaction {
+            transaction: tx.clone(),
+            block_number: number,
+            block_hash,
+            timestamp,
+            index,
+            success: receipt.success,
+            fee_paid: receipt.fee_paid,
+            events: receipt.events.clone(),
+        })?;
+        for account in involved_accounts(tx, receipt) {
+            batch.push(account_list(PREFIX_INDEX_TX, &account), position(number, &[index]), entry.clone());
+        }
+
+        for (event_index, event) in receipt.events.iter().enumerate() {
+            let entry = encode(&IndexedEvent {
+                event: event.clone(),
+                tx_hash,
+                block_number: number,
+                block_hash,
+                timestamp,
+                index,
+            })?;
+            batch.push(event_list(&event_type(event)), position(number, &[index, event_index as u32]), entry);
+        }
+    }
+
+    let authored = encode(&IndexedBlock {
+        number,
+        hash: block_hash,
+        timestamp,
+        tx_count: block.body.transactions.len() as u32,
+    })?;
+    batch.push(account_list(PREFIX_INDEX_AUTHOR, &block.header.author), position(number, &[]), authored);
+
+    batch.finish(db)
+}
+
+/// Transactions sent or received by an account, from the one after `after`
+///
+/// The block range bounds the scan itself; other filters are applied to the
+/// entries read, at most MAX_INDEX_SCAN of them per call.
+pub(super) fn account_transactions(
+    db: &dyn Database,
+    account: &AccountId,
+    filter: &TransactionFilter,
+    after: Option<IndexCursor>,
+    limit: usize,
+) -> Result<IndexPage<IndexedTransaction>, StateError> {
+    let list = account_list(PREFIX_INDEX_TX, account);
+    // Newest first: the range starts at to_block
+    let mut start = list.clone();
+    start.extend_from_slice(&position(filter.to_block.unwrap_or(BlockNumber::MAX), &[]));
+    if let Some(cursor) = after {
+        // Smallest key after the cursor's entry
+        let mut resume = list.clone();
+        resume.extend_from_slice(&position(cursor.block_number, &[cursor.index]));
+        resume.push(0);
+        start = start.max(resume);
+    }
+
+    let limit = limit.min(MAX_INDEX_PAGE);
+    let mut items = Vec::new();
+    let mut next = None;
+    let mut last_read = None;
+    for (scanned, item) in db.prefix_iterator_from(&list, &start).enumerate() {
+        let (_, value) = item?;
+        let entry: IndexedTransaction = decode(&value)?;
+        if filter.from_block.is_some_and(|from| entry.block_number < from) {
+            break;
+        }
+        if scanned == MAX_INDEX_SCAN {
+            next = last_read;
+            break;
+        }
+        let cursor = IndexCursor::of(&entry);
+        if filter.matches(account, &entry) {
+            if items.len() == limit {
+                next = last_read;
+                break;
+            }
+            items.push(entry);
+        }
+        last_read = Some(cursor);
+    }
+    Ok(IndexPage { total: list_len(db, &list)?, items, next })
+}
+
+/// Events of a type (`contract.Name`)
+pub(super) fn events_by_type(
+    db: &dyn Database,
+    event_type: &str,
+    offset: usize,
+    limit: usize,
+) -> Result<IndexPage<IndexedEvent>, StateError> {
+    read_page(db, &event_list(event_type), offset, limit)
+}
+
+/// Blocks authored by a validator
+pub(super) fn authored_blocks(
+    db: &dyn Database,
+    author: &AccountId,
+    offset: usize,
+    limit: usize,
+) -> Result<IndexPage<IndexedBlock>, StateError> {
+    read_page(db, &account_list(PREFIX_INDEX_AUTHOR, author), offset, limit)
+}
+
+/// Entries of one block, with the growth of each list
+#[derive(Default)]
+struct IndexBatch {
+    ops: Vec<WriteOp>,
+    added: BTreeMap<Vec<u8>, u64>,
+}
+
+impl IndexBatch {
+    fn push(&mut self, list: Vec<u8>, position: Vec<u8>, value: Vec<u8>) {
+        let mut key = list.clone();
+        key.extend_from_slice(&position);
+        self.ops.push(WriteOp::Put { key, value });
+        *self.added.entry(list).or_default() += 1;
+    }
+
+    fn finish(mut self, db: &dyn Database) -> Result<Vec<WriteOp>, StateError> {
+        for (list, added) in self.added {
+            let total = list_len(db, &list)? + added;
+            self.ops.push(WriteOp::Put { key: count_key(&list), value: encode(&total)? });
+        }
+        Ok(self.ops)
+    }
+}
+
+/// Sender and the parties of the transaction's transfers and payouts
+fn involved_accounts(tx: &SignedTransaction, receipt: &Receipt) -> BTreeSet<AccountId> {
+    let mut accounts = BTreeSet::from([tx.transaction.sender]);
+    for event in &receipt.events {
+        match event {
+            Event::Krat(KratEvent::Transfer { from, to, .. }) => {
+                accounts.insert(*from);
+                accounts.insert(*to);
+            }
+            Event::Krat(KratEvent::TreasuryPayout { recipient, .. }) => {
+                accounts.insert(*recipient);
+            }
+            _ => {}
+        }
+    }
+    accounts
+}
+
+fn read_page<T: DeserializeOwned>(
+    db: &dyn Database,
+    list: &[u8],
+    offset: usize,
+    limit: usize,
+) -> Result<IndexPage<T>, StateError> {
+    let items = db
+        .prefix_iterator(list)
+        .skip(offset)
+        .take(limit.min(MAX_INDEX_PAGE))
+        .map(|(_, value)| decode(&value))
+        .collect::<Result<_, _>>()?;
+    Ok(IndexPage {
+        total: list_len(db, list)?,
+        items,
+        next: None,
+    })
+}
+
+fn list_len(db: &dyn Database, list: &[u8]) -> Result<u64, StateError> {
+    match db.get(&count_key(list))? {
+        Some(data) => decode(&data),
+        None => Ok(0),
+    }
+}
+
+fn account_list(prefix: &[u8], account: &AccountId) -> Vec<u8> {
+    let mut key = prefix.to_vec();
+    key.extend_from_slice(account.as_bytes());
+    key
+}
+
+/// Event types have no fixed length: the terminator keeps krat.Memo out of
+/// a scan of krat.Me
+fn event_list(event_type: &str) -> Vec<u8> {
+    let mut key = PREFIX_INDEX_EVENT.to_vec();
+    key.extend_from_slice(event_type.as_bytes());
+    key.push(0);
+    key
+}
+
+fn count_key(list: &[u8]) -> Vec<u8> {
+    let mut key = PREFIX_INDEX_COUNT.to_vec();
+    key.extend_from_slice(list);
+    key
+}
+
+/// Position of an entry: newest block first, then body order
+fn position(number: BlockNumber, indexes: &[u32]) -> Vec<u8> {
+    let mut key = (u64::MAX - number).to_be_bytes().to_vec();
+    for index in indexes {
+        key.extend_from_slice(&index.to_be_bytes());
+    }
+    key
+}
+
+fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, StateError> {
+    bincode::serialize(value).map_err(|e| StateError::SerializationFailed(e.to_string()))
+}
+
+fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T, StateError> {
+    bincode::deserialize(data).map_err(|e| StateError::DeserializationFailed(e.to_string()))
+}
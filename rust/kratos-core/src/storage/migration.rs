@@ -0,0 +1,298 @@
+// Schema migrations - Upgrade existing databases in place
+// Principle: A layout change never requires a purge
+//
+// The database stores the version of its schema (Database::schema_version).
+// At startup, every migration above the stored version runs in order and the
+// marker is bumped after each one, so an interrupted upgrade resumes at the
+// failed step. A fresh database is stamped with SCHEMA_VERSION directly, and a
+// database written by a newer node is refused rather than misread.
+
+use super::db::{Database, DatabaseError, WriteOp, SCHEMA_VERSION};
+use super::state::{PREFIX_COMMISSION, PREFIX_DELEGATION_POOL, PREFIX_UNBONDING};
+use crate::consensus::epoch::EPOCH_DURATION_BLOCKS;
+use crate::contracts::staking::{CommissionRecord, DelegationPool, UnbondingQueue, DEFAULT_COMMISSION_PERCENT};
+use crate::types::{AccountId, Balance, BlockNumber};
+use std::collections::BTreeMap;
+use serde::Deserialize;
+use tracing::info;
+
+/// One schema upgrade, from `version - 1` to `version`
+pub struct Migration {
+    /// Schema version after the migration
+    pub version: u32,
+    /// What the migration changes
+    pub description: &'static str,
+    /// Rewrites the affected keys
+    pub run: fn(&dyn Database) -> Result<(), DatabaseError>,
+}
+
+/// Migrations in version order (the last one targets SCHEMA_VERSION)
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Baseline layout (databases created before the schema marker)",
+        run: |_| Ok(()),
+    },
+    Migration {
+        version: 2,
+        description: "Unbonding requests indexed by unlock epoch",
+        run: migrate_unbonding_queues,
+    },
+    Migration {
+        version: 3,
+        description: "Validator commission moved out of delegation pools",
+        run: migrate_delegation_pools,
+    },
+];
+
+/// Unbonding request as stored up to schema v1
+#[derive(Deserialize)]
+struct LegacyUnbondingRequest {
+    amount: Balance,
+    unbonding_started: BlockNumber,
+    release_block: BlockNumber,
+}
+
+/// Unbonding record as stored up to schema v1
+#[derive(Deserialize)]
+struct LegacyUnbondingInfo {
+    requests: Vec<LegacyUnbondingRequest>,
+}
+
+/// v2: rewrite each account's unbonding requests as an UnbondingQueue
+///
+/// A request unlocks at the first epoch starting at or after its release
+/// block, so no stake becomes withdrawable earlier than before. History
+/// entries keep the encoding they were written with.
+fn migrate_unbonding_queues(db: &dyn Database) -> Result<(), DatabaseError> {
+    let mut ops = Vec::new();
+    for (key, value) in db.prefix_iterator(PREFIX_UNBONDING) {
+        let legacy: LegacyUnbondingInfo =
+            bincode::deserialize(&value).map_err(|e| DatabaseError::SerializationFailed(e.to_string()))?;
+        let mut queue = UnbondingQueue::default();
+        for request in legacy.requests {
+            let chunk = queue
+                .chunks
+                .entry(request.release_block.div_ceil(EPOCH_DURATION_BLOCKS))
+                .or_insert(0);
+            *chunk = chunk.saturating_add(request.amount);
+        }
+        let value = bincode::serialize(&queue).map_err(|e| DatabaseError::SerializationFailed(e.to_string()))?;
+        ops.push(WriteOp::Put { key, value });
+    }
+    db.batch_write(ops)
+}
+
+/// Outcome of the startup migration check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Schema version found (None: fresh or unversioned database)
+    pub from: Option<u32>,
+    /// Schema version after migration
+    pub to: u32,
+    /// Migrations run
+    pub applied: usize,
+}
+
+/// Bring the database to SCHEMA_VERSION
+pub fn migrate(db: &dyn Database) -> Result<MigrationReport, MigrationError> {
+    migrate_with(db, MIGRATIONS, SCHEMA_VERSION)
+}
+
+fn migrate_with(db: &dyn Database, migrations: &[Migration], target: u32) -> Result<MigrationReport, MigrationError> {
+    let stored = db.schema_version()?;
+
+    let current = match stored {
+        Some(version) if version > target => {
+            return Err(MigrationError::TooNew { found: version, supported: target });
+        }
+        Some(version) => version,
+        // Nothing to upgrade in a fresh database
+        None if db.is_empty()? => {
+            db.set_schema_version(target)?;
+            return Ok(MigrationReport { from: None, to: target, applied: 0 });
+        }
+        // Written before the marker existed
+        None => 0,
+    };
+
+    let mut report = MigrationReport { from: stored, to: current, applied: 0 };
+    for migration in migrations.iter().filter(|m| m.version > current && m.version <= target) {
+        info!("🔧 Migrating database schema to v{}: {}", migration.version, migration.description);
+        (migration.run)(db).map_err(|e| MigrationError::Failed {
+            version: migration.version,
+            reason: e.to_string(),
+        })?;
+        db.set_schema_version(migration.version)?;
+        report.to = migration.version;
+        report.applied += 1;
+    }
+
+    if report.to != target {
+        return Err(MigrationError::Missing { from: report.to, to: target });
+    }
+    Ok(report)
+}
+
+/// Delegation pool as stored up to schema v2
+#[derive(Deserialize)]
+struct LegacyDelegationPool {
+    commission: u8,
+    delegators: BTreeMap<AccountId, Balance>,
+}
+
+/// v3: drop the commission from each delegation pool
+///
+/// A commission other than the default is kept in the validator's
+/// commission record.
+fn migrate_delegation_pools(db: &dyn Database) -> Result<(), DatabaseError> {
+    let mut ops = Vec::new();
+    for (key, value) in db.prefix_iterator(PREFIX_DELEGATION_POOL) {
+        let legacy: LegacyDelegationPool =
+            bincode::deserialize(&value).map_err(|e| DatabaseError::SerializationFailed(e.to_string()))?;
+        if legacy.commission != DEFAULT_COMMISSION_PERCENT {
+            let record = CommissionRecord { current: legacy.commission, announced: None };
+            ops.push(WriteOp::Put {
+                key: [PREFIX_COMMISSION, &key[PREFIX_DELEGATION_POOL.len()..]].concat(),
+                value: bincode::serialize(&record).map_err(|e| DatabaseError::SerializationFailed(e.to_string()))?,
+            });
+        }
+        let pool = DelegationPool { delegators: legacy.delegators };
+        let value = bincode::serialize(&pool).map_err(|e| DatabaseError::SerializationFailed(e.to_string()))?;
+        ops.push(WriteOp::Put { key, value });
+    }
+    db.batch_write(ops)
+}
+
+/// Schema migration errors
+#[derive(Debug, thiserror::Error)]
+pub enum MigrationError {
+    #[error(transparent)]
+    Database(#[from] DatabaseError),
+
+    #[error("Database schema v{found} is newer than this node supports (v{supported}): upgrade the node")]
+    TooNew { found: u32, supported: u32 },
+
+    #[error("Migration to schema v{version} failed: {reason}")]
+    Failed { version: u32, reason: String },
+
+    #[error("No migration path from schema v{from} to v{to}")]
+    Missing { from: u32, to: u32 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::db::RocksDatabase;
+
+    const TEST_MIGRATIONS: &[Migration] = &[
+        Migration { version: 1, description: "baseline", run: |_| Ok(()) },
+        Migration {
+            version: 2,
+            description: "rename legacy key",
+            run: |db| {
+                if let Some(value) = db.get(b"legacy")? {
+                    db.put(b"renamed", &value)?;
+                    db.delete(b"legacy")?;
+                }
+                Ok(())
+            },
+        },
+    ];
+
+    #[test]
+    fn test_fresh_database_is_stamped() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = RocksDatabase::open(dir.path()).unwrap();
+
+        let report = migrate(&db).unwrap();
+        assert_eq!(report, MigrationReport { from: None, to: SCHEMA_VERSION, applied: 0 });
+        assert_eq!(db.schema_version().unwrap(), Some(SCHEMA_VERSION));
+
+        // Already current: nothing to do
+        assert_eq!(migrate(&db).unwrap().applied, 0);
+    }
+
+    #[test]
+    fn test_unversioned_database_is_migrated() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = RocksDatabase::open(dir.path()).unwrap();
+        db.put(b"legacy", b"value").unwrap();
+
+        let report = migrate_with(&db, TEST_MIGRATIONS, 2).unwrap();
+        assert_eq!(report, MigrationReport { from: None, to: 2, applied: 2 });
+        assert_eq!(db.get(b"renamed").unwrap(), Some(b"value".to_vec()));
+        assert_eq!(db.get(b"legacy").unwrap(), None);
+
+        // Newer than supported
+        assert!(matches!(
+            migrate_with(&db, TEST_MIGRATIONS, 1),
+            Err(MigrationError::TooNew { found: 2, supported: 1 })
+        ));
+        // Target without a migration
+        assert!(matches!(
+            migrate_with(&db, TEST_MIGRATIONS, 3),
+            Err(MigrationError::Missing { from: 2, to: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_unbonding_queue_migration() {
+        #[derive(serde::Serialize)]
+        struct Request {
+            amount: Balance,
+            unbonding_started: BlockNumber,
+            release_block: BlockNumber,
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let db = RocksDatabase::open(dir.path()).unwrap();
+        db.set_schema_version(1).unwrap();
+        let key = [PREFIX_UNBONDING, &[7; 32]].concat();
+        let legacy = vec![
+            Request { amount: 10, unbonding_started: 0, release_block: 1_200 },
+            Request { amount: 20, unbonding_started: 5, release_block: 1_205 },
+            Request { amount: 30, unbonding_started: 900, release_block: 2_100 },
+        ];
+        // A one-field struct encodes as its field
+        db.put(&key, &bincode::serialize(&legacy).unwrap()).unwrap();
+
+        let report = migrate_with(&db, MIGRATIONS, 2).unwrap();
+        assert_eq!(report, MigrationReport { from: Some(1), to: 2, applied: 1 });
+
+        let queue: UnbondingQueue = bincode::deserialize(&db.get(&key).unwrap().unwrap()).unwrap();
+        assert_eq!(queue.chunks.into_iter().collect::<Vec<_>>(), vec![(2, 10), (3, 20), (4, 30)]);
+    }
+
+    #[test]
+    fn test_delegation_pool_migration() {
+        #[derive(serde::Serialize)]
+        struct Pool {
+            commission: u8,
+            delegators: BTreeMap<AccountId, Balance>,
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let db = RocksDatabase::open(dir.path()).unwrap();
+        db.set_schema_version(2).unwrap();
+        let delegators = BTreeMap::from([(AccountId::from_bytes([2; 32]), 100)]);
+        let default_key = [PREFIX_DELEGATION_POOL, &[7; 32]].concat();
+        let custom_key = [PREFIX_DELEGATION_POOL, &[8; 32]].concat();
+        let pool = Pool { commission: DEFAULT_COMMISSION_PERCENT, delegators: delegators.clone() };
+        db.put(&default_key, &bincode::serialize(&pool).unwrap()).unwrap();
+        let pool = Pool { commission: 5, delegators: delegators.clone() };
+        db.put(&custom_key, &bincode::serialize(&pool).unwrap()).unwrap();
+
+        let report = migrate(&db).unwrap();
+        assert_eq!(report, MigrationReport { from: Some(2), to: SCHEMA_VERSION, applied: 1 });
+
+        for key in [&default_key, &custom_key] {
+            let pool: DelegationPool = bincode::deserialize(&db.get(key).unwrap().unwrap()).unwrap();
+            assert_eq!(pool.delegators, delegators);
+        }
+        assert_eq!(db.get(&[PREFIX_COMMISSION, &[7; 32]].concat()).unwrap(), None);
+        let record: CommissionRecord =
+            bincode::deserialize(&db.get(&[PREFIX_COMMISSION, &[8; 32]].concat()).unwrap().unwrap()).unwrap();
+        assert_eq!(record.at(0), 5);
+    }
+}
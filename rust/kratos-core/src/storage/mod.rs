@@ -1,8 +1,14 @@
-// Storage - Couche de persistance (RocksDB + Merkle trees)
+// Storage - Couche de persistance (RocksDB / ParityDB / mémoire + Merkle trees)
 // Principe: Auditabilité, Reproductibilité, Sync rapide
 
+pub mod cache;
 pub mod db;
+pub mod index;
+pub mod migration;
 pub mod state;
 
+pub use cache::*;
 pub use db::*;
+pub use index::*;
+pub use migration::*;
 pub use state::*;
@@ -1,22 +1,113 @@
 // State - Blockchain state machine
-use super::db::{Database, DatabaseError, WriteOp};
+use super::cache::{StateCache, StateCacheStats};
+use super::db::{Database, DatabaseBackend, DatabaseError, KeyValueIter, WriteOp};
+use super::index::{
+    account_transactions, authored_blocks, block_index_ops, events_by_type, IndexCursor, IndexPage, IndexedBlock,
+    IndexedEvent, IndexedTransaction, TransactionFilter,
+};
 use crate::consensus::clock_health::ValidatorClockRecord;
-use crate::consensus::validator_credits::ValidatorCreditsRecord;
-use crate::types::{AccountId, AccountInfo, Balance, Block, BlockNumber, ChainId, Hash, StateRoot, StateMerkleTree, EpochNumber};
-use std::collections::HashMap;
+use crate::consensus::epoch::EPOCH_DURATION_BLOCKS;
+use crate::consensus::seed_attestation::UptimeRecord;
+use crate::consensus::session_keys::{block_signer, SessionKeyRecord};
+use crate::consensus::validator_credits::{ValidatorCreditsRecord, VcHistory};
+use crate::contracts::emergency::EmergencyDeclaration;
+use crate::contracts::governance::{Proposal, ProposalId};
+use crate::contracts::identity::RegisteredIdentity;
+use crate::contracts::messaging::{AccountMessage, Inbox, MessageId};
+use crate::contracts::meta_governance::ParameterChangeRecord;
+use crate::contracts::personhood::{CeremonyRound, PersonhoodRecord};
+use crate::contracts::reputation::AccountReputation;
+use crate::contracts::staking::{CommissionRecord, DelegationPool, RewardLedger, UnbondingQueue};
+use crate::execution::gas::{BlockFee, MIN_BASE_FEE};
+use crate::network::peer::PeerReputation;
+use crate::node::persistence::SavedPeer;
+use crate::types::{AccountId, AccountInfo, Balance, Block, BlockHeader, BlockNumber, ChainId, FinalityJustification, Hash, MerkleProof, ProtocolParameters, Receipt, SidechainInfo, StateRoot, StateMerkleTree, SignedTransaction, StoredReceipt, EpochNumber};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex, RwLock};
 
 /// Storage key prefixes
 const PREFIX_ACCOUNT: &[u8] = b"account:";
 const PREFIX_VC: &[u8] = b"vc:";
-const PREFIX_UNBONDING: &[u8] = b"unbonding:";
+pub(super) const PREFIX_UNBONDING: &[u8] = b"unbonding:";
 const PREFIX_BLOCK_HASH: &[u8] = b"block_hash:";
 const PREFIX_BLOCK_BY_HASH: &[u8] = b"block_by_hash:";
 const PREFIX_BLOCK_BY_NUMBER: &[u8] = b"block_by_num:";
 const PREFIX_STATE_ROOT: &[u8] = b"state_root:";
 const PREFIX_CLOCK_RECORD: &[u8] = b"clock_rec:";
+const PREFIX_RECEIPT: &[u8] = b"receipt:";
+const PREFIX_STATE_HISTORY: &[u8] = b"state_hist:";
+const PREFIX_JUSTIFICATION: &[u8] = b"justification:";
+const PREFIX_BLOCK_HEADER: &[u8] = b"block_header:";
+const PREFIX_IDEMPOTENCY: &[u8] = b"idempotency:";
+const PREFIX_UPTIME: &[u8] = b"uptime:";
+const PREFIX_BLOCK_FEE: &[u8] = b"block_fee:";
+const PREFIX_SESSION_KEYS: &[u8] = b"session_keys:";
+const PREFIX_OFFENCE: &[u8] = b"offence:";
+pub(super) const PREFIX_DELEGATION_POOL: &[u8] = b"delegation_pool:";
+const PREFIX_DELEGATOR: &[u8] = b"delegator:";
+const PREFIX_REWARDS: &[u8] = b"rewards:";
+pub(super) const PREFIX_COMMISSION: &[u8] = b"commission:";
+const PREFIX_VC_HISTORY: &[u8] = b"vc_hist:";
+const PREFIX_PROPOSAL: &[u8] = b"proposal:";
+const PREFIX_PARAMETERS: &[u8] = b"params:";
+const PREFIX_PARAMETER_LOG: &[u8] = b"param_log:";
+const PREFIX_IDENTITY: &[u8] = b"identity:";
+const PREFIX_CEREMONY: &[u8] = b"ceremony:";
+const PREFIX_PERSONHOOD: &[u8] = b"personhood:";
+const PREFIX_REPUTATION: &[u8] = b"reputation:";
+const PREFIX_INBOX: &[u8] = b"inbox:";
+const PREFIX_MESSAGE: &[u8] = b"message:";
+const PREFIX_EMERGENCY: &[u8] = b"emergency:";
+const PREFIX_SIDECHAIN: &[u8] = b"sidechain:";
+const PREFIX_UNDO: &[u8] = b"undo:";
+const PREFIX_REORG: &[u8] = b"reorg:";
 const KEY_BEST_BLOCK: &[u8] = b"best_block";
 const KEY_GENESIS_HASH: &[u8] = b"genesis_hash";
 const KEY_DRIFT_TRACKER: &[u8] = b"drift_tracker";
+const KEY_ARCHIVE_FROM: &[u8] = b"archive_from";
+const KEY_INDEX_FROM: &[u8] = b"index_from";
+const KEY_INDEX_TIP: &[u8] = b"index_tip";
+const KEY_LAST_JUSTIFIED: &[u8] = b"last_justified";
+const KEY_PRUNED_UP_TO: &[u8] = b"pruned_up_to";
+const KEY_MEMPOOL: &[u8] = b"mempool";
+const KEY_PEER_REPUTATION: &[u8] = b"peer_reputation";
+
+/// Known-peers set (kept across restarts, exported to seed new nodes)
+const KEY_KNOWN_PEERS: &[u8] = b"known_peers";
+
+/// Blocks behind the best block that keep their undo record: the deepest
+/// reorg a node can perform
+pub const MAX_REORG_DEPTH: BlockNumber = 1024;
+
+/// Reorg events kept for chain_getReorgEvents (the oldest are dropped)
+pub const MAX_REORG_EVENTS: u64 = 256;
+
+/// State key prefixes versioned per block in archive mode
+const VERSIONED_PREFIXES: [&[u8]; 23] = [
+    PREFIX_ACCOUNT,
+    PREFIX_VC,
+    PREFIX_UNBONDING,
+    PREFIX_CLOCK_RECORD,
+    PREFIX_UPTIME,
+    PREFIX_SESSION_KEYS,
+    PREFIX_OFFENCE,
+    PREFIX_DELEGATION_POOL,
+    PREFIX_DELEGATOR,
+    PREFIX_REWARDS,
+    PREFIX_COMMISSION,
+    PREFIX_VC_HISTORY,
+    PREFIX_PROPOSAL,
+    PREFIX_PARAMETERS,
+    PREFIX_PARAMETER_LOG,
+    PREFIX_IDENTITY,
+    PREFIX_CEREMONY,
+    PREFIX_PERSONHOOD,
+    PREFIX_REPUTATION,
+    PREFIX_INBOX,
+    PREFIX_MESSAGE,
+    PREFIX_EMERGENCY,
+    PREFIX_SIDECHAIN,
+];
 
 // =============================================================================
 // DRIFT TRACKER - SECURITY FIX #35: Timestamp manipulation prevention
@@ -234,22 +325,105 @@ pub enum DriftError {
     },
 }
 
-/// Unbonding request - tracks stake being unbonded with release block
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-pub struct UnbondingRequest {
-    /// Amount being unbonded
-    pub amount: Balance,
-    /// Block number when unbonding was initiated
-    pub unbonding_started: BlockNumber,
-    /// Block number when funds can be withdrawn (started + UNBONDING_PERIOD)
-    pub release_block: BlockNumber,
+/// Outcome of a pruning pass
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    /// Blocks whose body, receipts and state root were deleted
+    pub pruned_blocks: u64,
+    /// Highest pruned block after the pass
+    pub pruned_up_to: Option<BlockNumber>,
+    /// Highest prunable block (last finalized block minus the window)
+    pub prunable_up_to: Option<BlockNumber>,
+}
+
+/// Transaction submitted under an idempotency key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct IdempotencyRecord {
+    /// Transaction accepted for the key
+    pub tx_hash: Hash,
+    /// Submission time (unix seconds)
+    pub submitted_at: u64,
+}
+
+/// Switch to a fork, as reported to wallets (chain_getReorgEvents)
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReorgEvent {
+    /// Increasing from 1, across restarts
+    pub id: u64,
+    /// Last block kept
+    pub ancestor: BlockNumber,
+    /// Number of blocks reverted
+    pub depth: u64,
+    /// Transactions of the reverted blocks still valid: included by the
+    /// fork or back in the mempool
+    pub orphaned: Vec<Hash>,
+    /// Transactions of the reverted blocks that are no longer valid
+    pub dropped: Vec<Hash>,
+}
+
+/// Problem found by an integrity check
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IntegrityIssue {
+    #[error("Record {key} cannot be decoded: {error}")]
+    CorruptRecord { key: String, error: String },
+
+    #[error("Block #{0} is missing")]
+    MissingBlock(BlockNumber),
+
+    #[error("Block stored as #{stored} is block #{found}")]
+    MisplacedBlock { stored: BlockNumber, found: BlockNumber },
+
+    #[error("Block #{0} does not extend its parent")]
+    BrokenLink(BlockNumber),
+
+    #[error("Hash index of block #{0} is missing or stale")]
+    MissingIndex(BlockNumber),
+
+    #[error("Best block pointer is missing")]
+    MissingBestBlock,
+}
+
+impl IntegrityIssue {
+    /// Whether `db check --repair` can fix the issue from the remaining data
+    pub fn repairable(&self) -> bool {
+        matches!(self, Self::MissingIndex(_) | Self::MissingBestBlock)
+    }
+
+    /// Suggested recovery
+    pub fn suggestion(&self) -> &'static str {
+        match self {
+            Self::MissingIndex(_) | Self::MissingBestBlock => {
+                "re-derived from the stored blocks by `db check --repair`"
+            }
+            Self::CorruptRecord { .. } | Self::MisplacedBlock { .. } => {
+                "data is damaged: restore a backup, or `purge` and resync (or `import` an export)"
+            }
+            Self::MissingBlock(_) | Self::BrokenLink(_) => {
+                "the chain has a gap: `purge` and resync, or `import` an export"
+            }
+        }
+    }
+}
+
+/// Outcome of an integrity check
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// Best block pointer (None if missing)
+    pub best_block: Option<BlockNumber>,
+    /// Blocks (or pruned headers) checked
+    pub blocks_checked: u64,
+    /// Accounts decoded
+    pub accounts_checked: u64,
+    /// Problems found, in chain order
+    pub issues: Vec<IntegrityIssue>,
+    /// Problems fixed (with repair enabled)
+    pub repaired: usize,
 }
 
-/// All unbonding requests for an account
-#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
-pub struct UnbondingInfo {
-    /// List of pending unbonding requests
-    pub requests: Vec<UnbondingRequest>,
+impl IntegrityReport {
+    pub fn is_healthy(&self) -> bool {
+        self.issues.len() == self.repaired
+    }
 }
 
 /// State backend - Gère l'état de la blockchain
@@ -270,26 +444,58 @@ pub struct UnbondingInfo {
 /// INVARIANT: cache_generation monotonically increases on each invalidation.
 /// This allows detecting stale cached data in multi-threaded scenarios.
 pub struct StateBackend {
-    db: Database,
-    /// Cache en mémoire pour optimisation
+    db: Arc<dyn Database>,
+    /// Cache en mémoire pour optimisation (LRU, bounded)
     /// SECURITY: Write-through cache - DB is always authoritative
-    account_cache: HashMap<AccountId, AccountInfo>,
+    account_cache: StateCache,
     /// Generation counter for cache staleness detection
     /// Increments on every invalidation, wraps around after u64::MAX
     cache_generation: u64,
     /// Tracks if we're in an atomic operation (for debugging)
     #[cfg(debug_assertions)]
     in_atomic_operation: bool,
+    /// Archive mode: keep a version of every state key per block
+    archive: bool,
+    /// Index mode: write the secondary indexes of every stored block
+    index: bool,
+    /// State keys written since the last archived block
+    /// Mutex because some writers (clock records) only hold &self
+    changed_keys: Mutex<BTreeSet<Vec<u8>>>,
+    /// Values of the accounts before their first write in the current block,
+    /// while a journal is open (None: the account did not exist)
+    account_journal: Mutex<Option<AccountJournal>>,
+    /// Values of the state keys before their first write in the current
+    /// block (None: the key did not exist), stored as its undo record
+    undo_journal: Mutex<UndoJournal>,
+}
+
+/// Account key -> value before the block (None: absent)
+type AccountJournal = BTreeMap<Vec<u8>, Option<Vec<u8>>>;
+
+/// State key -> value before the block (None: absent)
+type UndoJournal = BTreeMap<Vec<u8>, Option<Vec<u8>>>;
+
+/// An account written during a journaled block
+#[derive(Debug, Clone)]
+pub struct AccountChange {
+    pub account: AccountId,
+    pub before: Option<AccountInfo>,
+    pub after: Option<AccountInfo>,
 }
 
 impl StateBackend {
-    pub fn new(db: Database) -> Self {
+    pub fn new<D: Database + 'static>(db: D) -> Self {
         Self {
-            db,
-            account_cache: HashMap::new(),
+            db: Arc::new(db),
+            account_cache: StateCache::default(),
             cache_generation: 0,
             #[cfg(debug_assertions)]
             in_atomic_operation: false,
+            archive: false,
+            index: false,
+            changed_keys: Mutex::new(BTreeSet::new()),
+            account_journal: Mutex::new(None),
+            undo_journal: Mutex::new(BTreeMap::new()),
         }
     }
 
@@ -342,11 +548,46 @@ impl StateBackend {
         self.cache_generation
     }
 
+    /// Change the number of accounts kept in the cache
+    pub fn set_cache_capacity(&mut self, capacity: usize) {
+        self.account_cache.set_capacity(capacity);
+    }
+
+    /// Account cache counters since startup
+    pub fn cache_stats(&self) -> StateCacheStats {
+        self.account_cache.stats()
+    }
+
+    /// Read ahead the accounts a block is about to touch
+    ///
+    /// Accounts already cached are only counted as used; the others are loaded
+    /// from the DB without counting as misses. Returns the number of accounts
+    /// loaded.
+    pub fn prefetch_accounts<I>(&mut self, ids: I) -> Result<usize, StateError>
+    where
+        I: IntoIterator<Item = AccountId>,
+    {
+        let mut loaded = 0;
+        for id in ids {
+            if self.account_cache.contains(&id) {
+                continue;
+            }
+            if let Some(data) = self.db.get(&Self::account_key(&id))? {
+                let info: AccountInfo =
+                    bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+                self.account_cache.insert(id, info);
+                loaded += 1;
+            }
+        }
+        self.account_cache.record_prefetch(loaded as u64);
+        Ok(loaded)
+    }
+
     /// Récupère un compte
     pub fn get_account(&mut self, id: &AccountId) -> Result<Option<AccountInfo>, StateError> {
         // Vérifie le cache d'abord
         if let Some(info) = self.account_cache.get(id) {
-            return Ok(Some(info.clone()));
+            return Ok(Some(info));
         }
 
         // Sinon, lit depuis la DB
@@ -375,7 +616,10 @@ impl StateBackend {
         // If DB write fails, cache remains unchanged (safe)
         // If we crash after DB write but before cache update, cache will
         // be repopulated from DB on next read (safe)
+        self.journal_account(&key)?;
+        self.journal_undo(&key)?;
         self.db.put(&key, &value)?;
+        self.track_change(&key);
 
         // Only update cache after successful DB write
         self.account_cache.insert(id, info);
@@ -389,7 +633,10 @@ impl StateBackend {
         let key = Self::account_key(id);
 
         // SECURITY FIX #6: Delete from DB FIRST, then remove from cache
+        self.journal_account(&key)?;
+        self.journal_undo(&key)?;
         self.db.delete(&key)?;
+        self.track_change(&key);
 
         // Only remove from cache after successful DB delete
         self.account_cache.remove(id);
@@ -538,6 +785,229 @@ impl StateBackend {
         Ok(blocks)
     }
 
+    // ===== Receipts =====
+
+    /// Store the receipts of a block (indexed by transaction hash)
+    pub fn store_receipts(
+        &self,
+        block_number: BlockNumber,
+        block_hash: Hash,
+        receipts: &[Receipt],
+    ) -> Result<(), StateError> {
+        let mut ops = Vec::with_capacity(receipts.len());
+        for (index, receipt) in receipts.iter().enumerate() {
+            let stored = StoredReceipt {
+                receipt: receipt.clone(),
+                block_number,
+                block_hash,
+                index: index as u32,
+            };
+            let data = bincode::serialize(&stored)
+                .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+            ops.push(WriteOp::Put { key: Self::receipt_key(&receipt.tx_hash), value: data });
+        }
+        self.db.batch_write(ops)?;
+        Ok(())
+    }
+
+    /// Get the receipt of an included transaction
+    pub fn get_receipt(&self, tx_hash: &Hash) -> Result<Option<StoredReceipt>, StateError> {
+        if let Some(data) = self.db.get(&Self::receipt_key(tx_hash))? {
+            let stored: StoredReceipt = bincode::deserialize(&data)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            Ok(Some(stored))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // ===== Fee Market =====
+    //
+    // Fee records are a few bytes per block and are not pruned: the base fee
+    // of the next block is derived from the record of its parent.
+
+    /// Record the base fee and transaction count of an executed block
+    pub fn store_block_fee(&self, block_number: BlockNumber, fee: &BlockFee) -> Result<(), StateError> {
+        let value = bincode::serialize(fee).map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&Self::block_fee_key(block_number), &value)?;
+        Ok(())
+    }
+
+    /// Fee record of an executed block
+    pub fn get_block_fee(&self, block_number: BlockNumber) -> Result<Option<BlockFee>, StateError> {
+        match self.db.get(&Self::block_fee_key(block_number))? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Base fee the transactions of a block pay
+    ///
+    /// Derived from the parent's fee record; MIN_BASE_FEE when the parent has
+    /// none (genesis, blocks executed before the fee market).
+    pub fn base_fee_at(&self, block_number: BlockNumber) -> Result<u64, StateError> {
+        if block_number == 0 {
+            return Ok(MIN_BASE_FEE);
+        }
+        Ok(self
+            .get_block_fee(block_number - 1)?
+            .map_or(MIN_BASE_FEE, |parent| parent.next_base_fee()))
+    }
+
+    // ===== Finality Justifications =====
+
+    /// Store the justification of a finalized block
+    ///
+    /// Keys are big-endian so that justifications iterate in block order.
+    pub fn store_justification(&self, justification: &FinalityJustification) -> Result<(), StateError> {
+        let data = bincode::serialize(justification)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        let mut ops = vec![WriteOp::Put {
+            key: Self::justification_key(justification.block_number),
+            value: data,
+        }];
+
+        let latest = self.latest_justified_block()?;
+        if latest.is_none_or(|n| justification.block_number > n) {
+            let number = bincode::serialize(&justification.block_number)
+                .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+            ops.push(WriteOp::Put { key: KEY_LAST_JUSTIFIED.to_vec(), value: number });
+        }
+
+        self.db.batch_write(ops)?;
+        Ok(())
+    }
+
+    /// Get the justification of a block, if it was finalized directly
+    pub fn get_justification(&self, number: BlockNumber) -> Result<Option<FinalityJustification>, StateError> {
+        if let Some(data) = self.db.get(&Self::justification_key(number))? {
+            let justification = bincode::deserialize(&data)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            Ok(Some(justification))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// First justification at or above `number`
+    ///
+    /// Finalizing a block finalizes its ancestors, so this proves the finality of `number`.
+    pub fn justification_covering(&self, number: BlockNumber) -> Result<Option<FinalityJustification>, StateError> {
+        match self.latest_justified_block()? {
+            Some(latest) if latest >= number => {}
+            _ => return Ok(None),
+        }
+
+        for (key, data) in self.db.prefix_iterator(PREFIX_JUSTIFICATION) {
+            let justified = key[PREFIX_JUSTIFICATION.len()..]
+                .try_into()
+                .map(BlockNumber::from_be_bytes)
+                .map_err(|_| StateError::DeserializationFailed("Invalid justification key".to_string()))?;
+            if justified >= number {
+                let justification = bincode::deserialize(&data)
+                    .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+                return Ok(Some(justification));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Highest block with a stored justification
+    pub fn latest_justified_block(&self) -> Result<Option<BlockNumber>, StateError> {
+        if let Some(data) = self.db.get(KEY_LAST_JUSTIFIED)? {
+            let number = bincode::deserialize(&data)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            Ok(Some(number))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Justification of the highest justified block
+    pub fn latest_justification(&self) -> Result<Option<FinalityJustification>, StateError> {
+        match self.latest_justified_block()? {
+            Some(number) => self.get_justification(number),
+            None => Ok(None),
+        }
+    }
+
+    // ===== Warp Sync Snapshots =====
+
+    /// All accounts, in account ID order (the state root leaf order)
+    pub fn accounts(&self) -> Result<Vec<(AccountId, AccountInfo)>, StateError> {
+        let mut accounts = BTreeMap::new();
+        for (key, value) in self.db.prefix_iterator(PREFIX_ACCOUNT) {
+            let id: [u8; 32] = key[PREFIX_ACCOUNT.len()..]
+                .try_into()
+                .map_err(|_| StateError::DeserializationFailed("Invalid account key".to_string()))?;
+            let info: AccountInfo = bincode::deserialize(&value)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            accounts.insert(id, info);
+        }
+        Ok(accounts.into_iter().map(|(id, info)| (AccountId::from_bytes(id), info)).collect())
+    }
+
+    /// All accounts at a block, in ID order
+    ///
+    /// The best block is read from the live state, older blocks need archive mode.
+    pub fn accounts_at(&self, block_number: BlockNumber) -> Result<Vec<(AccountId, AccountInfo)>, StateError> {
+        if self.get_best_block()? == Some(block_number) {
+            return self.accounts();
+        }
+        self.account_entries_at(block_number)?
+            .into_iter()
+            .map(|(key, value)| {
+                let id: [u8; 32] = key[PREFIX_ACCOUNT.len()..]
+                    .try_into()
+                    .map_err(|_| StateError::DeserializationFailed("Invalid account key".to_string()))?;
+                let info: AccountInfo = bincode::deserialize(&value)
+                    .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+                Ok((AccountId::from_bytes(id), info))
+            })
+            .collect()
+    }
+
+    /// Replace the account state with a warp sync snapshot taken at `block`
+    ///
+    /// Existing accounts are removed, the snapshot accounts written and `block`
+    /// becomes the best block. Fails without touching the state if the accounts
+    /// don't rebuild the block's state root.
+    pub fn import_snapshot(
+        &mut self,
+        block: &Block,
+        accounts: Vec<(AccountId, AccountInfo)>,
+        chain_id: ChainId,
+    ) -> Result<StateRoot, StateError> {
+        let block_number = block.header.number;
+
+        let mut ops = Vec::with_capacity(accounts.len());
+        for (key, _) in self.db.prefix_iterator(PREFIX_ACCOUNT) {
+            ops.push(WriteOp::Delete { key });
+        }
+        let mut leaves = BTreeMap::new();
+        for (id, info) in &accounts {
+            let value = bincode::serialize(info).map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+            leaves.insert(Self::account_key(id), value);
+        }
+        let expected = StateMerkleTree::new(leaves.values().cloned().collect()).root();
+        if expected != block.header.state_root {
+            return Err(StateError::SnapshotStateRootMismatch(block_number));
+        }
+        for (key, value) in leaves {
+            ops.push(WriteOp::Put { key, value });
+        }
+        self.commit_batch(ops)?;
+
+        let state_root = self.compute_state_root(block_number, chain_id);
+        self.store_state_root(block_number, state_root)?;
+        self.store_block(block)?;
+        self.set_best_block(block_number)?;
+        self.update_drift_tracker_for_sync(block)?;
+        Ok(state_root)
+    }
+
     /// Set genesis hash
     pub fn set_genesis_hash(&self, hash: Hash) -> Result<(), StateError> {
         self.db.put(KEY_GENESIS_HASH, hash.as_bytes())?;
@@ -563,6 +1033,7 @@ impl StateBackend {
     pub fn set_drift_tracker(&self, tracker: &DriftTracker) -> Result<(), StateError> {
         let value = bincode::serialize(tracker)
             .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.journal_undo(KEY_DRIFT_TRACKER)?;
         self.db.put(KEY_DRIFT_TRACKER, &value)?;
         Ok(())
     }
@@ -680,6 +1151,13 @@ impl StateBackend {
         let value = bincode::serialize(&state_root)
             .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
         self.db.put(&key, &value)?;
+
+        // The state of this block is final: record its version in archive mode
+        if self.archive {
+            self.archive_block(block_number)?;
+        }
+        self.store_undo_record(block_number)?;
+        *self.lock_account_journal() = None;
         Ok(())
     }
 
@@ -695,8 +1173,46 @@ impl StateBackend {
         }
     }
 
+    /// Merkle proof of an account against the state root of a block
+    ///
+    /// The best block is proven from the live state, older blocks need archive
+    /// mode. Returns None if the account does not exist at that block.
+    pub fn prove_account(&self, id: &AccountId, block_number: BlockNumber) -> Result<Option<MerkleProof>, StateError> {
+        let state_root = self
+            .get_state_root(block_number)?
+            .ok_or(StateError::NoStateRoot(block_number))?;
+
+        let entries = if self.get_best_block()? == Some(block_number) {
+            self.db.prefix_iterator(PREFIX_ACCOUNT).collect()
+        } else {
+            self.account_entries_at(block_number)?
+        };
+
+        let key = Self::account_key(id);
+        let Some(index) = entries.keys().position(|k| *k == key) else {
+            return Ok(None);
+        };
+
+        // Same leaves, in the same key order, as compute_state_root
+        let tree = StateMerkleTree::new(entries.into_values().collect());
+        if tree.root() != state_root.root {
+            return Err(StateError::StateRootMismatch(block_number));
+        }
+
+        Ok(tree.generate_proof(index, block_number, state_root.chain_id))
+    }
+
     /// Commit un batch de changements
     pub fn commit_batch(&mut self, ops: Vec<WriteOp>) -> Result<(), StateError> {
+        for op in &ops {
+            match op {
+                WriteOp::Put { key, .. } | WriteOp::Delete { key } => {
+                    self.journal_account(key)?;
+                    self.journal_undo(key)?;
+                    self.track_change(key);
+                }
+            }
+        }
         self.db.batch_write(ops)?;
         // FIX: Increment generation and clear cache after commit
         // This ensures any cached data is invalidated
@@ -704,171 +1220,1039 @@ impl StateBackend {
         Ok(())
     }
 
-    // ===== Validator Credits Storage =====
+    // ===== Archive Mode (historical state) =====
+    //
+    // Every versioned key written during a block is copied to
+    // state_hist:<key><block number BE> when the block's state root is stored.
+    // The value at block N is the latest version at or below N. Versions are
+    // keyed by height, so the history follows the canonical chain.
 
-    /// Get Validator Credits record
-    pub fn get_vc_record(&self, validator_id: &AccountId) -> Result<Option<ValidatorCreditsRecord>, StateError> {
-        let key = Self::vc_key(validator_id);
-        if let Some(data) = self.db.get(&key)? {
-            let record: ValidatorCreditsRecord = bincode::deserialize(&data)
-                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
-            Ok(Some(record))
-        } else {
-            Ok(None)
+    /// Enable archive mode
+    ///
+    /// The first time, the whole current state is recorded as the version of
+    /// the best block; earlier blocks stay unavailable. Returns the first
+    /// archived block.
+    pub fn enable_archive(&mut self) -> Result<BlockNumber, StateError> {
+        self.archive = true;
+
+        if let Some(from) = self.archive_from()? {
+            return Ok(from);
         }
-    }
 
-    /// Set Validator Credits record
-    pub fn set_vc_record(&mut self, validator_id: AccountId, record: ValidatorCreditsRecord) -> Result<(), StateError> {
-        let key = Self::vc_key(&validator_id);
-        let value = bincode::serialize(&record)
-            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
-        self.db.put(&key, &value)?;
-        Ok(())
+        let from = self.get_best_block()?.unwrap_or(0);
+        let mut ops = Vec::new();
+        for prefix in VERSIONED_PREFIXES {
+            for (key, value) in self.db.prefix_iterator(prefix) {
+                ops.push(Self::history_op(&key, from, Some(value))?);
+            }
+        }
+        ops.push(WriteOp::Put {
+            key: KEY_ARCHIVE_FROM.to_vec(),
+            value: bincode::serialize(&from).map_err(|e| StateError::SerializationFailed(e.to_string()))?,
+        });
+        self.db.batch_write(ops)?;
+        self.lock_changed_keys().clear();
+
+        Ok(from)
     }
 
-    /// Delete Validator Credits record
-    pub fn delete_vc_record(&mut self, validator_id: &AccountId) -> Result<(), StateError> {
-        let key = Self::vc_key(validator_id);
-        self.db.delete(&key)?;
-        Ok(())
+    /// Whether historical state is being recorded
+    pub fn is_archive(&self) -> bool {
+        self.archive
     }
 
-    /// Get total VC for a validator
-    pub fn get_total_vc(&self, validator_id: &AccountId) -> Result<u64, StateError> {
-        if let Some(record) = self.get_vc_record(validator_id)? {
-            Ok(record.total_vc())
-        } else {
-            Ok(0)
+    /// First block whose state is available
+    pub fn archive_from(&self) -> Result<Option<BlockNumber>, StateError> {
+        match self.db.get(KEY_ARCHIVE_FROM)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
         }
     }
 
-    /// Initialize VC for a bootstrap validator with minimum required VC
-    /// Bootstrap validators need BOOTSTRAP_MIN_VC_REQUIREMENT (100) to be eligible for VRF selection
-    /// This is called when an early validator is approved during bootstrap era
-    pub fn initialize_bootstrap_vc(
-        &mut self,
-        validator_id: AccountId,
-        block_number: BlockNumber,
-        current_epoch: EpochNumber,
-    ) -> Result<(), StateError> {
-        // Create a new VC record with bootstrap credits
-        let mut record = ValidatorCreditsRecord::new(block_number, current_epoch);
-        // Grant bootstrap VC (100) as uptime credits so they can be selected via VRF
-        // This matches BOOTSTRAP_MIN_VC_REQUIREMENT in vrf_selection.rs
-        record.uptime_credits = 100;
-        self.set_vc_record(validator_id, record)
-    }
+    /// Raw value of a state key at a block
+    ///
+    /// Only versioned keys (accounts, validator credits, unbonding, clock and
+    /// uptime records) have history.
+    pub fn get_storage_at(&self, key: &[u8], block_number: BlockNumber) -> Result<Option<Vec<u8>>, StateError> {
+        if !self.archive {
+            return Err(StateError::ArchiveDisabled);
+        }
+        if !Self::is_versioned_key(key) {
+            return Err(StateError::NotVersioned(hex::encode(key)));
+        }
+        let from = self.archive_from()?.unwrap_or(0);
+        if block_number < from {
+            return Err(StateError::StateUnavailable { requested: block_number, archived_from: from });
+        }
 
-    // ===== Unbonding Storage =====
+        let prefix = Self::history_prefix(key);
+        let mut latest: Option<(BlockNumber, Vec<u8>)> = None;
+        for (entry_key, value) in self.db.prefix_iterator(&prefix) {
+            let Some(number) = entry_key
+                .get(prefix.len()..)
+                .and_then(|suffix| <[u8; 8]>::try_from(suffix).ok())
+                .map(BlockNumber::from_be_bytes)
+            else {
+                continue;
+            };
+            if number <= block_number && latest.as_ref().is_none_or(|(n, _)| number > *n) {
+                latest = Some((number, value));
+            }
+        }
 
-    /// Get unbonding info for an account
-    pub fn get_unbonding_info(&self, account_id: &AccountId) -> Result<Option<UnbondingInfo>, StateError> {
-        let key = Self::unbonding_key(account_id);
-        if let Some(data) = self.db.get(&key)? {
-            let info: UnbondingInfo = bincode::deserialize(&data)
-                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
-            Ok(Some(info))
-        } else {
-            Ok(None)
+        match latest {
+            Some((_, value)) => bincode::deserialize::<Option<Vec<u8>>>(&value)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string())),
+            None => Ok(None),
         }
     }
 
-    /// Set unbonding info for an account
-    pub fn set_unbonding_info(&mut self, account_id: AccountId, info: UnbondingInfo) -> Result<(), StateError> {
-        let key = Self::unbonding_key(&account_id);
-        let value = bincode::serialize(&info)
-            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
-        self.db.put(&key, &value)?;
-        Ok(())
+    /// Account at a block
+    pub fn get_account_at(&self, id: &AccountId, block_number: BlockNumber) -> Result<Option<AccountInfo>, StateError> {
+        match self.get_storage_at(&Self::account_key(id), block_number)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
     }
 
-    /// Add an unbonding request for an account
-    pub fn add_unbonding_request(
-        &mut self,
-        account_id: AccountId,
-        amount: Balance,
-        current_block: BlockNumber,
-        unbonding_period: BlockNumber,
-    ) -> Result<(), StateError> {
-        let mut info = self.get_unbonding_info(&account_id)?.unwrap_or_default();
+    /// All accounts at a block, keyed like the live state
+    fn account_entries_at(&self, block_number: BlockNumber) -> Result<BTreeMap<Vec<u8>, Vec<u8>>, StateError> {
+        if !self.archive {
+            return Err(StateError::ArchiveDisabled);
+        }
+        let from = self.archive_from()?.unwrap_or(0);
+        if block_number < from {
+            return Err(StateError::StateUnavailable { requested: block_number, archived_from: from });
+        }
 
-        info.requests.push(UnbondingRequest {
-            amount,
-            unbonding_started: current_block,
-            release_block: current_block.saturating_add(unbonding_period),
-        });
+        // Entries are sorted by key then height: the last one at or below
+        // block_number wins for each account
+        let prefix = Self::history_prefix(PREFIX_ACCOUNT);
+        let mut latest: BTreeMap<Vec<u8>, Option<Vec<u8>>> = BTreeMap::new();
+        for (entry_key, value) in self.db.prefix_iterator(&prefix) {
+            let Some((key, height)) = entry_key
+                .len()
+                .checked_sub(8)
+                .filter(|split| *split >= PREFIX_STATE_HISTORY.len())
+                .map(|split| entry_key.split_at(split))
+            else {
+                continue;
+            };
+            let height = BlockNumber::from_be_bytes(height.try_into().expect("8-byte suffix"));
+            if height <= block_number {
+                let value = bincode::deserialize::<Option<Vec<u8>>>(&value)
+                    .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+                latest.insert(key[PREFIX_STATE_HISTORY.len()..].to_vec(), value);
+            }
+        }
 
-        self.set_unbonding_info(account_id, info)
+        Ok(latest
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|v| (key, v)))
+            .collect())
     }
 
-    /// Get total amount currently unbonding (not yet withdrawable)
-    pub fn get_total_unbonding(&self, account_id: &AccountId) -> Result<Balance, StateError> {
-        if let Some(info) = self.get_unbonding_info(account_id)? {
-            Ok(info.requests.iter().map(|r| r.amount).sum())
-        } else {
-            Ok(0)
+    /// Record the keys written since the previous block as versions of `block_number`
+    fn archive_block(&self, block_number: BlockNumber) -> Result<(), StateError> {
+        let keys = std::mem::take(&mut *self.lock_changed_keys());
+        let mut ops = Vec::with_capacity(keys.len());
+        for key in keys {
+            let value = self.db.get(&key)?;
+            ops.push(Self::history_op(&key, block_number, value)?);
+        }
+        if !ops.is_empty() {
+            self.db.batch_write(ops)?;
         }
+        Ok(())
     }
 
-    /// Withdraw all matured unbonding requests, returns total withdrawn amount
-    pub fn withdraw_matured_unbonding(
-        &mut self,
-        account_id: &AccountId,
-        current_block: BlockNumber,
-    ) -> Result<Balance, StateError> {
-        let mut info = match self.get_unbonding_info(account_id)? {
-            Some(info) => info,
-            None => return Ok(0),
-        };
+    /// Remember a written state key for the next archived block
+    fn track_change(&self, key: &[u8]) {
+        if self.archive && Self::is_versioned_key(key) {
+            self.lock_changed_keys().insert(key.to_vec());
+        }
+    }
 
-        let mut withdrawn: Balance = 0;
+    fn lock_changed_keys(&self) -> std::sync::MutexGuard<'_, BTreeSet<Vec<u8>>> {
+        // The set holds no invariant a panicking writer could break
+        self.changed_keys.lock().unwrap_or_else(|e| e.into_inner())
+    }
 
-        // Separate matured from pending requests
-        let (matured, pending): (Vec<_>, Vec<_>) = info.requests
-            .into_iter()
-            .partition(|r| current_block >= r.release_block);
+    // ===== Account journal (state root forensics) =====
+    //
+    // The state root only covers accounts. While a journal is open, the value
+    // of each account before its first write is kept, so that a block whose
+    // computed root disagrees with its header can be explained account by
+    // account. The journal is closed when the block's state root is stored.
 
-        // Sum up matured amounts
-        for request in matured {
-            withdrawn = withdrawn.saturating_add(request.amount);
-        }
+    /// Start recording account pre-images, dropping any previous journal
+    pub fn begin_account_journal(&self) {
+        *self.lock_account_journal() = Some(BTreeMap::new());
+    }
 
-        // Update with remaining pending requests
-        info.requests = pending;
+    /// Close the journal and return the accounts whose value changed
+    pub fn take_account_journal(&self) -> Result<Vec<AccountChange>, StateError> {
+        let journal = self.lock_account_journal().take().unwrap_or_default();
+        let decode = |data: &[u8]| -> Result<AccountInfo, StateError> {
+            bincode::deserialize(data).map_err(|e| StateError::DeserializationFailed(e.to_string()))
+        };
 
-        if info.requests.is_empty() {
-            // Delete the record if no more pending requests
-            let key = Self::unbonding_key(account_id);
-            self.db.delete(&key)?;
-        } else {
-            self.set_unbonding_info(*account_id, info)?;
+        let mut changes = Vec::new();
+        for (key, before) in journal {
+            let after = self.db.get(&key)?;
+            if before == after {
+                continue;
+            }
+            let account: [u8; 32] = key[PREFIX_ACCOUNT.len()..]
+                .try_into()
+                .map_err(|_| StateError::DeserializationFailed("Invalid account key".to_string()))?;
+            changes.push(AccountChange {
+                account: AccountId::from_bytes(account),
+                before: before.as_deref().map(decode).transpose()?,
+                after: after.as_deref().map(decode).transpose()?,
+            });
         }
-
-        Ok(withdrawn)
+        Ok(changes)
     }
 
-    // ===== Clock Health Storage (SECURITY FIX #36) =====
-
-    /// Get clock health record for a validator
-    /// Returns None if no record exists (never excluded)
-    pub fn get_clock_record(&self, validator_id: &AccountId) -> Result<Option<ValidatorClockRecord>, StateError> {
-        let key = Self::clock_record_key(validator_id);
-        if let Some(data) = self.db.get(&key)? {
-            let record: ValidatorClockRecord = bincode::deserialize(&data)
-                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
-            Ok(Some(record))
-        } else {
-            Ok(None)
+    /// Keep the current value of an account key if it is its first write
+    fn journal_account(&self, key: &[u8]) -> Result<(), StateError> {
+        if !key.starts_with(PREFIX_ACCOUNT) {
+            return Ok(());
+        }
+        let mut journal = self.lock_account_journal();
+        if let Some(journal) = journal.as_mut() {
+            if !journal.contains_key(key) {
+                journal.insert(key.to_vec(), self.db.get(key)?);
+            }
+        }
+        Ok(())
+    }
+
+    fn lock_account_journal(&self) -> std::sync::MutexGuard<'_, Option<AccountJournal>> {
+        self.account_journal.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // ===== Undo records (reorgs) =====
+    //
+    // The value of each state key (and of the drift tracker) before its first
+    // write in a block is stored with the block's state root, as the block's
+    // undo record. Reverting blocks applies their records newest first, so
+    // the state is back to what it was after the ancestor. Records are kept
+    // for MAX_REORG_DEPTH blocks; finalized blocks are never reverted.
+
+    /// Keep the current value of a state key if it is its first write in
+    /// the current block
+    fn journal_undo(&self, key: &[u8]) -> Result<(), StateError> {
+        if !Self::is_versioned_key(key) && key != KEY_DRIFT_TRACKER {
+            return Ok(());
+        }
+        let mut journal = self.lock_undo_journal();
+        if !journal.contains_key(key) {
+            journal.insert(key.to_vec(), self.db.get(key)?);
+        }
+        Ok(())
+    }
+
+    /// Store the undo record of a block, dropping the one that left the
+    /// reorg window
+    fn store_undo_record(&self, block_number: BlockNumber) -> Result<(), StateError> {
+        let journal: Vec<(Vec<u8>, Option<Vec<u8>>)> = std::mem::take(&mut *self.lock_undo_journal()).into_iter().collect();
+        let value = bincode::serialize(&journal).map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        let mut ops = vec![WriteOp::Put { key: Self::undo_key(block_number), value }];
+        if let Some(expired) = block_number.checked_sub(MAX_REORG_DEPTH) {
+            ops.push(WriteOp::Delete { key: Self::undo_key(expired) });
+        }
+        self.db.batch_write(ops)?;
+        Ok(())
+    }
+
+    /// Revert the blocks above `ancestor`, returning them oldest first
+    ///
+    /// State keys get their value after the ancestor back; the reverted
+    /// blocks lose their number mapping, state root, fee record and receipts,
+    /// and the ancestor becomes the best block. Everything is written in one
+    /// batch. Index entries of the reverted blocks stay (the index tip moves
+    /// back to the ancestor, so the new blocks are indexed).
+    pub fn revert_to(&mut self, ancestor: BlockNumber) -> Result<Vec<Block>, StateError> {
+        let best = self.get_best_block()?.unwrap_or(0);
+        if ancestor >= best {
+            return Ok(Vec::new());
+        }
+        if let Some(finalized) = self.latest_justified_block()?.filter(|finalized| ancestor < *finalized) {
+            return Err(StateError::RevertFinalized { ancestor, finalized });
+        }
+
+        let mut ops = Vec::new();
+        let mut reverted = Vec::new();
+        // Newest first: the oldest record writes last, its values win
+        for number in (ancestor + 1..=best).rev() {
+            let data = self.db.get(&Self::undo_key(number))?.ok_or(StateError::NoUndoRecord(number))?;
+            let record: Vec<(Vec<u8>, Option<Vec<u8>>)> =
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            for (key, value) in record {
+                if self.archive {
+                    let mut history_key = Self::history_prefix(&key);
+                    history_key.extend_from_slice(&number.to_be_bytes());
+                    ops.push(WriteOp::Delete { key: history_key });
+                }
+                ops.push(match value {
+                    Some(value) => WriteOp::Put { key, value },
+                    None => WriteOp::Delete { key },
+                });
+            }
+
+            if let Some(block) = self.get_block_by_number(number)? {
+                for tx in &block.body.transactions {
+                    ops.push(WriteOp::Delete { key: Self::receipt_key(&tx.hash()) });
+                }
+                reverted.push(block);
+            }
+            for key in [
+                Self::undo_key(number),
+                Self::block_by_number_key(number),
+                Self::block_hash_key(number),
+                Self::state_root_key(number),
+                Self::block_fee_key(number),
+            ] {
+                ops.push(WriteOp::Delete { key });
+            }
+        }
+
+        let encode = |number: &BlockNumber| bincode::serialize(number).map_err(|e| StateError::SerializationFailed(e.to_string()));
+        ops.push(WriteOp::Put { key: KEY_BEST_BLOCK.to_vec(), value: encode(&ancestor)? });
+        if self.index_tip()?.is_some_and(|tip| tip > ancestor) {
+            ops.push(WriteOp::Put { key: KEY_INDEX_TIP.to_vec(), value: encode(&ancestor)? });
+        }
+        self.db.batch_write(ops)?;
+
+        self.lock_changed_keys().clear();
+        self.lock_undo_journal().clear();
+        *self.lock_account_journal() = None;
+        self.invalidate_cache();
+
+        reverted.reverse();
+        Ok(reverted)
+    }
+
+    /// Record a reorg, returning it with its ID
+    pub fn record_reorg(
+        &self,
+        ancestor: BlockNumber,
+        depth: u64,
+        orphaned: Vec<Hash>,
+        dropped: Vec<Hash>,
+    ) -> Result<ReorgEvent, StateError> {
+        let id = self
+            .db
+            .prefix_iterator(PREFIX_REORG)
+            .filter_map(|(key, _)| <[u8; 8]>::try_from(&key[PREFIX_REORG.len()..]).ok())
+            .map(u64::from_be_bytes)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let event = ReorgEvent { id, ancestor, depth, orphaned, dropped };
+        let value = bincode::serialize(&event).map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        let mut ops = vec![WriteOp::Put { key: Self::reorg_key(id), value }];
+        if let Some(expired) = id.checked_sub(MAX_REORG_EVENTS) {
+            ops.push(WriteOp::Delete { key: Self::reorg_key(expired) });
+        }
+        self.db.batch_write(ops)?;
+        Ok(event)
+    }
+
+    /// Reorg events with an ID above `after_id`, oldest first
+    pub fn reorg_events_after(&self, after_id: u64) -> Result<Vec<ReorgEvent>, StateError> {
+        let from = Self::reorg_key(after_id.saturating_add(1));
+        self.db
+            .prefix_iterator_from(PREFIX_REORG, &from)
+            .map(|item| {
+                let (_, value) = item?;
+                bincode::deserialize(&value).map_err(|e| StateError::DeserializationFailed(e.to_string()))
+            })
+            .collect()
+    }
+
+    /// State after `block_number`, rebuilt from the undo records of the
+    /// blocks above it (and the pre-images of the current block)
+    ///
+    /// Writes to the returned backend stay in memory; chain data (blocks,
+    /// fee records, undo records) is read from this one, so the state of an
+    /// earlier block can be taken from it in turn. Limited to the reorg
+    /// window: older blocks fail with NoUndoRecord.
+    pub fn state_at(&self, block_number: BlockNumber) -> Result<StateBackend, StateError> {
+        let best = self.get_best_block()?.unwrap_or(0);
+        if block_number > best {
+            return Err(StateError::NoStateRoot(block_number));
+        }
+
+        let mut changes = self.lock_undo_journal().clone();
+        // Newest first: the oldest record writes last, its values win
+        for number in (block_number + 1..=best).rev() {
+            let data = self.db.get(&Self::undo_key(number))?.ok_or(StateError::NoUndoRecord(number))?;
+            let record: Vec<(Vec<u8>, Option<Vec<u8>>)> =
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            changes.extend(record);
+        }
+        let best = bincode::serialize(&block_number).map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        changes.insert(KEY_BEST_BLOCK.to_vec(), Some(best));
+
+        Ok(StateBackend::new(HistoricalDatabase { live: self.db.clone(), changes: RwLock::new(changes) }))
+    }
+
+    fn lock_undo_journal(&self) -> std::sync::MutexGuard<'_, UndoJournal> {
+        self.undo_journal.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn is_versioned_key(key: &[u8]) -> bool {
+        VERSIONED_PREFIXES.iter().any(|prefix| key.starts_with(prefix))
+    }
+
+    /// History entry: None records a deletion
+    fn history_op(key: &[u8], block_number: BlockNumber, value: Option<Vec<u8>>) -> Result<WriteOp, StateError> {
+        let mut history_key = Self::history_prefix(key);
+        history_key.extend_from_slice(&block_number.to_be_bytes());
+        let value = bincode::serialize(&value).map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        Ok(WriteOp::Put { key: history_key, value })
+    }
+
+    fn history_prefix(key: &[u8]) -> Vec<u8> {
+        let mut prefix = PREFIX_STATE_HISTORY.to_vec();
+        prefix.extend_from_slice(key);
+        prefix
+    }
+
+    // ===== Secondary Indexes (see index.rs) =====
+    //
+    // Blocks are indexed once stored with their receipts. The index tip is
+    // the last indexed block: a block at or below it is never indexed twice,
+    // and a node restarted with --index catches up from it.
+
+    /// Enable the secondary indexes
+    ///
+    /// Indexes the stored blocks above the index tip (the first time, every
+    /// block still holding its body). Returns the number of blocks indexed.
+    pub fn enable_index(&mut self) -> Result<u64, StateError> {
+        self.index = true;
+
+        let start = match self.index_tip()? {
+            Some(tip) => tip + 1,
+            None => {
+                let from = self.pruned_up_to()?.map_or(0, |n| n + 1);
+                let value = bincode::serialize(&from).map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+                self.db.put(KEY_INDEX_FROM, &value)?;
+                from
+            }
+        };
+
+        let mut indexed = 0;
+        for number in start..=self.get_best_block()?.unwrap_or(0) {
+            if let Some(block) = self.get_block_by_number(number)? {
+                self.index_block(&block)?;
+                indexed += 1;
+            }
+        }
+        Ok(indexed)
+    }
+
+    /// Whether stored blocks are indexed
+    pub fn is_indexing(&self) -> bool {
+        self.index
+    }
+
+    /// First indexed block (earlier blocks were pruned before indexing began)
+    pub fn index_from(&self) -> Result<Option<BlockNumber>, StateError> {
+        match self.db.get(KEY_INDEX_FROM)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Last indexed block
+    pub fn index_tip(&self) -> Result<Option<BlockNumber>, StateError> {
+        match self.db.get(KEY_INDEX_TIP)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Index a stored block (no-op unless indexing)
+    ///
+    /// The receipts are read back from storage: call it once they are stored.
+    pub fn index_block(&self, block: &Block) -> Result<(), StateError> {
+        let number = block.header.number;
+        if !self.index || self.index_tip()?.is_some_and(|tip| number <= tip) {
+            return Ok(());
+        }
+
+        let mut receipts = Vec::with_capacity(block.body.transactions.len());
+        for tx in &block.body.transactions {
+            if let Some(stored) = self.get_receipt(&tx.hash())? {
+                receipts.push(stored.receipt);
+            }
+        }
+        let mut ops = block_index_ops(self.db.as_ref(), block, &receipts)?;
+        ops.push(WriteOp::Put {
+            key: KEY_INDEX_TIP.to_vec(),
+            value: bincode::serialize(&number).map_err(|e| StateError::SerializationFailed(e.to_string()))?,
+        });
+        // One batch: a block is indexed whole or not at all
+        self.db.batch_write(ops)?;
+        Ok(())
+    }
+
+    /// Transactions sent or received by an account, newest first, from the
+    /// one after `after`
+    pub fn indexed_transactions(
+        &self,
+        account: &AccountId,
+        filter: &TransactionFilter,
+        after: Option<IndexCursor>,
+        limit: usize,
+    ) -> Result<IndexPage<IndexedTransaction>, StateError> {
+        self.require_index()?;
+        account_transactions(self.db.as_ref(), account, filter, after, limit)
+    }
+
+    /// Events of a type (`contract.Name`), newest first
+    pub fn indexed_events(
+        &self,
+        event_type: &str,
+        offset: usize,
+        limit: usize,
+    ) -> Result<IndexPage<IndexedEvent>, StateError> {
+        self.require_index()?;
+        events_by_type(self.db.as_ref(), event_type, offset, limit)
+    }
+
+    /// Blocks authored by a validator, newest first
+    pub fn indexed_blocks_by_author(
+        &self,
+        author: &AccountId,
+        offset: usize,
+        limit: usize,
+    ) -> Result<IndexPage<IndexedBlock>, StateError> {
+        self.require_index()?;
+        authored_blocks(self.db.as_ref(), author, offset, limit)
+    }
+
+    fn require_index(&self) -> Result<(), StateError> {
+        if self.index {
+            Ok(())
+        } else {
+            Err(StateError::IndexDisabled)
+        }
+    }
+
+    // ===== Pruning =====
+    //
+    // Blocks more than `keep_blocks` behind the last justified (finalized)
+    // block lose their body, receipts and state root; the header and the
+    // number -> hash mapping are kept so the chain stays linked. Nothing above
+    // the last stored justification is ever pruned, and neither is genesis.
+
+    /// Highest pruned block
+    pub fn pruned_up_to(&self) -> Result<Option<BlockNumber>, StateError> {
+        match self.db.get(KEY_PRUNED_UP_TO)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Prune up to `max_blocks` blocks older than the window
+    ///
+    /// Blocks in `retain` (served snapshots) keep their data. Pruning resumes
+    /// where the previous pass stopped.
+    pub fn prune(
+        &mut self,
+        keep_blocks: u32,
+        retain: &BTreeSet<BlockNumber>,
+        max_blocks: u64,
+    ) -> Result<PruneReport, StateError> {
+        if self.archive {
+            return Err(StateError::PruningArchive);
+        }
+
+        let pruned_up_to = self.pruned_up_to()?;
+        let mut report = PruneReport { pruned_up_to, ..Default::default() };
+
+        // Only data below a stored justification is provably final
+        let Some(justified) = self.latest_justified_block()? else {
+            return Ok(report);
+        };
+        let finalized = justified.min(self.get_best_block()?.unwrap_or(0));
+        let prunable_up_to = finalized.saturating_sub(keep_blocks as BlockNumber);
+        if prunable_up_to == 0 {
+            return Ok(report);
+        }
+        report.prunable_up_to = Some(prunable_up_to);
+
+        let start = pruned_up_to.map_or(1, |n| n + 1);
+        if start > prunable_up_to || max_blocks == 0 {
+            return Ok(report);
+        }
+        let end = prunable_up_to.min(start.saturating_add(max_blocks - 1));
+
+        let mut ops = Vec::new();
+        for number in start..=end {
+            if retain.contains(&number) {
+                continue;
+            }
+            ops.push(WriteOp::Delete { key: Self::state_root_key(number) });
+
+            let Some(block) = self.get_block_by_number(number)? else {
+                continue;
+            };
+            for tx in &block.body.transactions {
+                ops.push(WriteOp::Delete { key: Self::receipt_key(&tx.hash()) });
+            }
+            let header = bincode::serialize(&block.header)
+                .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+            ops.push(WriteOp::Put { key: Self::block_header_key(number), value: header });
+            ops.push(WriteOp::Delete { key: Self::block_by_hash_key(&block.hash()) });
+            ops.push(WriteOp::Delete { key: Self::block_by_number_key(number) });
+            report.pruned_blocks += 1;
+        }
+        ops.push(WriteOp::Put {
+            key: KEY_PRUNED_UP_TO.to_vec(),
+            value: bincode::serialize(&end).map_err(|e| StateError::SerializationFailed(e.to_string()))?,
+        });
+
+        // One batch: a crash never leaves a half-pruned block
+        self.db.batch_write(ops)?;
+        report.pruned_up_to = Some(end);
+        Ok(report)
+    }
+
+    /// Header of a block, also available once its body was pruned
+    pub fn get_header_by_number(&self, number: BlockNumber) -> Result<Option<BlockHeader>, StateError> {
+        if let Some(block) = self.get_block_by_number(number)? {
+            return Ok(Some(block.header));
+        }
+        match self.db.get(&Self::block_header_key(number))? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    // ===== Integrity Check =====
+    //
+    // Walks the chain from genesis to the best block, decoding every block
+    // (or pruned header) and checking its number, its parent link and both
+    // hash indexes (number -> hash, hash -> block), then decodes every account.
+    // Indexes and the best block pointer can be re-derived from the blocks
+    // themselves; anything else needs a backup, an import or a resync.
+
+    /// Check the stored chain, repairing derived data if `repair` is set
+    pub fn check_integrity(&self, repair: bool) -> Result<IntegrityReport, StateError> {
+        let mut report = IntegrityReport::default();
+        let pruned_up_to = self.pruned_up_to()?.unwrap_or(0);
+
+        let best = match self.get_best_block() {
+            Ok(best) => best,
+            Err(e) => {
+                report.issues.push(IntegrityIssue::CorruptRecord {
+                    key: "best_block".to_string(),
+                    error: e.to_string(),
+                });
+                None
+            }
+        };
+        let best = match best {
+            Some(best) => best,
+            None => {
+                // Highest stored block, if any
+                let Some(highest) = self.highest_stored_block(pruned_up_to)? else {
+                    return Ok(report);
+                };
+                report.issues.push(IntegrityIssue::MissingBestBlock);
+                if repair {
+                    self.set_best_block(highest)?;
+                    report.repaired += 1;
+                }
+                highest
+            }
+        };
+        report.best_block = Some(best);
+
+        let mut parent_hash: Option<Hash> = None;
+        for number in 0..=best {
+            let pruned = number != 0 && number <= pruned_up_to;
+            let (key, data) = if pruned {
+                (Self::block_header_key(number), "block_header")
+            } else {
+                (Self::block_by_number_key(number), "block_by_num")
+            };
+            let raw = self.db.get(&key)?;
+            report.blocks_checked += 1;
+
+            let Some(raw) = raw else {
+                report.issues.push(IntegrityIssue::MissingBlock(number));
+                parent_hash = self.get_block_hash(number)?;
+                continue;
+            };
+
+            let decoded = if pruned {
+                bincode::deserialize::<BlockHeader>(&raw).map(|header| (header, None))
+            } else {
+                bincode::deserialize::<Block>(&raw).map(|block| (block.header.clone(), Some(raw)))
+            };
+            let (header, full) = match decoded {
+                Ok(decoded) => decoded,
+                Err(e) => {
+                    report.issues.push(IntegrityIssue::CorruptRecord {
+                        key: format!("{}:{}", data, number),
+                        error: e.to_string(),
+                    });
+                    parent_hash = self.get_block_hash(number)?;
+                    continue;
+                }
+            };
+
+            if header.number != number {
+                report.issues.push(IntegrityIssue::MisplacedBlock { stored: number, found: header.number });
+                parent_hash = None;
+                continue;
+            }
+
+            let hash = header.hash();
+            if let Some(parent) = parent_hash {
+                if number > 0 && header.parent_hash != parent {
+                    report.issues.push(IntegrityIssue::BrokenLink(number));
+                }
+            }
+            parent_hash = Some(hash);
+
+            let number_index_ok = self.get_block_hash(number)? == Some(hash);
+            let hash_index_ok = full.is_none() || self.db.exists(&Self::block_by_hash_key(&hash))?;
+            if !number_index_ok || !hash_index_ok {
+                report.issues.push(IntegrityIssue::MissingIndex(number));
+                if repair {
+                    self.set_block_hash(number, hash)?;
+                    if let Some(raw) = &full {
+                        self.db.put(&Self::block_by_hash_key(&hash), raw)?;
+                    }
+                    report.repaired += 1;
+                }
+            }
+        }
+
+        for item in self.db.prefix_iterator_with_errors(PREFIX_ACCOUNT) {
+            let (key, value) = item?;
+            match bincode::deserialize::<AccountInfo>(&value) {
+                Ok(_) => report.accounts_checked += 1,
+                Err(e) => report.issues.push(IntegrityIssue::CorruptRecord {
+                    key: format!("account:0x{}", hex::encode(&key[PREFIX_ACCOUNT.len()..])),
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Highest block reachable from genesis without a gap
+    fn highest_stored_block(&self, pruned_up_to: BlockNumber) -> Result<Option<BlockNumber>, StateError> {
+        let mut highest = None;
+        let mut number: BlockNumber = 0;
+        loop {
+            let stored = if number != 0 && number <= pruned_up_to {
+                self.db.exists(&Self::block_header_key(number))?
+            } else {
+                self.db.exists(&Self::block_by_number_key(number))?
+            };
+            if !stored {
+                return Ok(highest);
+            }
+            highest = Some(number);
+            number += 1;
+        }
+    }
+
+    // ===== Mempool =====
+
+    /// Replace the saved pending transactions
+    pub fn put_mempool_snapshot(&self, txs: &[SignedTransaction]) -> Result<(), StateError> {
+        let value = bincode::serialize(txs).map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(KEY_MEMPOOL, &value)?;
+        Ok(())
+    }
+
+    /// Pending transactions saved by the last put_mempool_snapshot (hashes not set)
+    pub fn get_mempool_snapshot(&self) -> Result<Vec<SignedTransaction>, StateError> {
+        match self.db.get(KEY_MEMPOOL)? {
+            Some(data) => bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string())),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    // ===== Peer Reputation =====
+
+    /// Replace the saved peer scores and bans
+    pub fn put_peer_reputations(&self, reputations: &[PeerReputation]) -> Result<(), StateError> {
+        let value = bincode::serialize(reputations).map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(KEY_PEER_REPUTATION, &value)?;
+        Ok(())
+    }
+
+    /// Peer scores and bans saved by the last put_peer_reputations
+    pub fn get_peer_reputations(&self) -> Result<Vec<PeerReputation>, StateError> {
+        match self.db.get(KEY_PEER_REPUTATION)? {
+            Some(data) => bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string())),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    // ===== Known Peers =====
+
+    /// Replace the known-peers set
+    pub fn put_known_peers(&self, peers: &[SavedPeer]) -> Result<(), StateError> {
+        let value = bincode::serialize(peers).map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(KEY_KNOWN_PEERS, &value)?;
+        Ok(())
+    }
+
+    /// Known-peers set saved by the last put_known_peers
+    pub fn get_known_peers(&self) -> Result<Vec<SavedPeer>, StateError> {
+        match self.db.get(KEY_KNOWN_PEERS)? {
+            Some(data) => bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string())),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    // ===== Idempotency Keys =====
+
+    /// Transaction recorded for an idempotency key
+    pub fn get_idempotency_record(&self, key: &str) -> Result<Option<IdempotencyRecord>, StateError> {
+        match self.db.get(&Self::idempotency_key(key))? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    pub fn put_idempotency_record(&self, key: &str, record: &IdempotencyRecord) -> Result<(), StateError> {
+        let value = bincode::serialize(record).map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&Self::idempotency_key(key), &value)?;
+        Ok(())
+    }
+
+    pub fn remove_idempotency_record(&self, key: &str) -> Result<(), StateError> {
+        self.db.delete(&Self::idempotency_key(key))?;
+        Ok(())
+    }
+
+    /// Delete records submitted before `before` (unix seconds), returning how many
+    pub fn expire_idempotency_records(&self, before: u64) -> Result<usize, StateError> {
+        let mut ops = Vec::new();
+        for item in self.db.prefix_iterator_with_errors(PREFIX_IDEMPOTENCY) {
+            let (key, value) = item?;
+            let expired = match bincode::deserialize::<IdempotencyRecord>(&value) {
+                Ok(record) => record.submitted_at < before,
+                // Unreadable record: drop it
+                Err(_) => true,
+            };
+            if expired {
+                ops.push(WriteOp::Delete { key });
+            }
+        }
+
+        let expired = ops.len();
+        if expired > 0 {
+            self.db.batch_write(ops)?;
+        }
+        Ok(expired)
+    }
+
+    // ===== Validator Credits Storage =====
+
+    /// Get Validator Credits record
+    pub fn get_vc_record(&self, validator_id: &AccountId) -> Result<Option<ValidatorCreditsRecord>, StateError> {
+        let key = Self::vc_key(validator_id);
+        if let Some(data) = self.db.get(&key)? {
+            let record: ValidatorCreditsRecord = bincode::deserialize(&data)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            Ok(Some(record))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Set Validator Credits record
+    ///
+    /// The change is added to the VC history at the epoch of `record.last_update`.
+    pub fn set_vc_record(&mut self, validator_id: AccountId, record: ValidatorCreditsRecord) -> Result<(), StateError> {
+        let previous = self.get_vc_record(&validator_id)?;
+        let mut history = self.get_vc_history(&validator_id)?;
+        if history.record(record.last_update / EPOCH_DURATION_BLOCKS, previous.as_ref(), &record) {
+            self.set_vc_history(&validator_id, &history)?;
+        }
+
+        let key = Self::vc_key(&validator_id);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(&record)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Delete Validator Credits record
+    pub fn delete_vc_record(&mut self, validator_id: &AccountId) -> Result<(), StateError> {
+        let key = Self::vc_key(validator_id);
+        self.journal_undo(&key)?;
+        self.db.delete(&key)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Get the per-epoch VC history of a validator
+    pub fn get_vc_history(&self, validator_id: &AccountId) -> Result<VcHistory, StateError> {
+        let key = Self::vc_history_key(validator_id);
+        match self.db.get(&key)? {
+            Some(data) => bincode::deserialize(&data)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string())),
+            None => Ok(VcHistory::default()),
+        }
+    }
+
+    /// VC histories of all validators that ever earned or lost credits
+    pub fn vc_histories(&self) -> Result<Vec<(AccountId, VcHistory)>, StateError> {
+        let mut histories = Vec::new();
+        for (key, value) in self.db.prefix_iterator(PREFIX_VC_HISTORY) {
+            let bytes: [u8; 32] = key[PREFIX_VC_HISTORY.len()..]
+                .try_into()
+                .map_err(|_| StateError::DeserializationFailed("Invalid VC history key".to_string()))?;
+            let history: VcHistory = bincode::deserialize(&value)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            histories.push((AccountId::from_bytes(bytes), history));
+        }
+        Ok(histories)
+    }
+
+    fn set_vc_history(&mut self, validator_id: &AccountId, history: &VcHistory) -> Result<(), StateError> {
+        let key = Self::vc_history_key(validator_id);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(history)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Get total VC for a validator
+    pub fn get_total_vc(&self, validator_id: &AccountId) -> Result<u64, StateError> {
+        if let Some(record) = self.get_vc_record(validator_id)? {
+            Ok(record.total_vc())
+        } else {
+            Ok(0)
+        }
+    }
+
+    /// Initialize VC for a bootstrap validator with minimum required VC
+    /// Bootstrap validators need BOOTSTRAP_MIN_VC_REQUIREMENT (100) to be eligible for VRF selection
+    /// This is called when an early validator is approved during bootstrap era
+    pub fn initialize_bootstrap_vc(
+        &mut self,
+        validator_id: AccountId,
+        block_number: BlockNumber,
+        current_epoch: EpochNumber,
+    ) -> Result<(), StateError> {
+        // Create a new VC record with bootstrap credits
+        let mut record = ValidatorCreditsRecord::new(block_number, current_epoch);
+        // Grant bootstrap VC (100) as uptime credits so they can be selected via VRF
+        // This matches BOOTSTRAP_MIN_VC_REQUIREMENT in vrf_selection.rs
+        record.uptime_credits = 100;
+        self.set_vc_record(validator_id, record)
+    }
+
+    // ===== Unbonding Storage =====
+
+    /// Get the unbonding queue of an account
+    pub fn get_unbonding_queue(&self, account_id: &AccountId) -> Result<Option<UnbondingQueue>, StateError> {
+        let key = Self::unbonding_key(account_id);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Set the unbonding queue of an account (deleted once empty)
+    pub fn set_unbonding_queue(&mut self, account_id: &AccountId, queue: &UnbondingQueue) -> Result<(), StateError> {
+        let key = Self::unbonding_key(account_id);
+        self.journal_undo(&key)?;
+        if queue.chunks.is_empty() {
+            self.db.delete(&key)?;
+        } else {
+            let value = bincode::serialize(queue)
+                .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+            self.db.put(&key, &value)?;
+        }
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Get total amount unbonding (unlocked chunks not yet withdrawn included)
+    pub fn get_total_unbonding(&self, account_id: &AccountId) -> Result<Balance, StateError> {
+        Ok(self.get_unbonding_queue(account_id)?.map_or(0, |queue| queue.total()))
+    }
+
+    /// Withdraw all unlocked unbonding chunks, returns total withdrawn amount
+    pub fn withdraw_matured_unbonding(
+        &mut self,
+        account_id: &AccountId,
+        current_block: BlockNumber,
+    ) -> Result<Balance, StateError> {
+        let mut queue = match self.get_unbonding_queue(account_id)? {
+            Some(queue) => queue,
+            None => return Ok(0),
+        };
+
+        let withdrawn = queue.withdraw(current_block);
+        if withdrawn > 0 {
+            self.set_unbonding_queue(account_id, &queue)?;
+        }
+        Ok(withdrawn)
+    }
+
+    // ===== Clock Health Storage (SECURITY FIX #36) =====
+
+    /// Get clock health record for a validator
+    /// Returns None if no record exists (never excluded)
+    pub fn get_clock_record(&self, validator_id: &AccountId) -> Result<Option<ValidatorClockRecord>, StateError> {
+        let key = Self::clock_record_key(validator_id);
+        if let Some(data) = self.db.get(&key)? {
+            let record: ValidatorClockRecord = bincode::deserialize(&data)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            Ok(Some(record))
+        } else {
+            Ok(None)
         }
     }
 
     /// Set clock health record for a validator
     pub fn set_clock_record(&self, validator_id: &AccountId, record: &ValidatorClockRecord) -> Result<(), StateError> {
         let key = Self::clock_record_key(validator_id);
+        self.journal_undo(&key)?;
         let value = bincode::serialize(record)
             .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
         self.db.put(&key, &value)?;
+        self.track_change(&key);
         Ok(())
     }
 
@@ -882,36 +2266,582 @@ impl StateBackend {
         self.set_clock_record(validator_id, &record)
     }
 
-    /// Record a missed slot due to clock exclusion
-    pub fn record_clock_missed_slot(&self, validator_id: &AccountId) -> Result<(), StateError> {
-        let mut record = self.get_clock_record(validator_id)?
-            .unwrap_or_else(ValidatorClockRecord::new);
+    /// Record a missed slot due to clock exclusion
+    pub fn record_clock_missed_slot(&self, validator_id: &AccountId) -> Result<(), StateError> {
+        let mut record = self.get_clock_record(validator_id)?
+            .unwrap_or_else(ValidatorClockRecord::new);
+
+        record.record_missed_slot();
+        self.set_clock_record(validator_id, &record)
+    }
+
+    /// Get total clock sync failures for a validator
+    pub fn get_clock_failures(&self, validator_id: &AccountId) -> Result<u32, StateError> {
+        Ok(self.get_clock_record(validator_id)?
+            .map(|r| r.clock_sync_failures)
+            .unwrap_or(0))
+    }
+
+    /// Get VC penalty from clock failures
+    pub fn get_clock_vc_penalty(&self, validator_id: &AccountId) -> Result<u64, StateError> {
+        Ok(self.get_clock_record(validator_id)?
+            .map(|r| r.vc_penalty())
+            .unwrap_or(0))
+    }
+
+    // ===== Uptime Storage (seed attestations) =====
+
+    /// Get the uptime record of a validator (None if it never authored a block)
+    pub fn get_uptime_record(&self, validator_id: &AccountId) -> Result<Option<UptimeRecord>, StateError> {
+        let key = Self::uptime_key(validator_id);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Set the uptime record of a validator
+    pub fn set_uptime_record(&self, validator_id: &AccountId, record: &UptimeRecord) -> Result<(), StateError> {
+        let key = Self::uptime_key(validator_id);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(record)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Count a block authored by `author` (before executing its transactions)
+    pub fn record_block_authored(&self, author: &AccountId, block_number: BlockNumber) -> Result<(), StateError> {
+        let mut record = self.get_uptime_record(author)?.unwrap_or_default();
+        record.record_block(block_number / EPOCH_DURATION_BLOCKS);
+        self.set_uptime_record(author, &record)
+    }
+
+    // ===== Session Keys =====
+
+    /// Session keys bound to a validator (None if it never bound any)
+    pub fn get_session_keys(&self, validator_id: &AccountId) -> Result<Option<SessionKeyRecord>, StateError> {
+        let key = Self::session_keys_key(validator_id);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Set the session keys of a validator
+    pub fn set_session_keys(&self, validator_id: &AccountId, record: &SessionKeyRecord) -> Result<(), StateError> {
+        let key = Self::session_keys_key(validator_id);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(record)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Key the blocks of `author` at `epoch` must be signed with
+    pub fn block_signer(&self, author: &AccountId, epoch: EpochNumber) -> Result<AccountId, StateError> {
+        Ok(block_signer(author, self.get_session_keys(author)?.as_ref(), epoch))
+    }
+
+    // ===== Punished Offences =====
+
+    /// Block at which an equivocation offence was punished (None if never)
+    pub fn get_offence_punished(&self, offence: &Hash) -> Result<Option<BlockNumber>, StateError> {
+        let key = Self::offence_key(offence);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Record that an offence was punished, so it is not punished twice
+    pub fn set_offence_punished(&self, offence: &Hash, block_number: BlockNumber) -> Result<(), StateError> {
+        let key = Self::offence_key(offence);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(&block_number)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    // ===== Delegations =====
+
+    /// Stake delegated to a validator (None if nobody delegates to it)
+    pub fn get_delegation_pool(&self, validator_id: &AccountId) -> Result<Option<DelegationPool>, StateError> {
+        let key = Self::delegation_pool_key(validator_id);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Set the delegation pool of a validator (deleted once empty)
+    pub fn set_delegation_pool(&self, validator_id: &AccountId, pool: &DelegationPool) -> Result<(), StateError> {
+        let key = Self::delegation_pool_key(validator_id);
+        self.journal_undo(&key)?;
+        if pool.delegators.is_empty() {
+            self.db.delete(&key)?;
+        } else {
+            let value = bincode::serialize(pool)
+                .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+            self.db.put(&key, &value)?;
+        }
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Validators an account delegates to
+    pub fn get_delegation_targets(&self, delegator: &AccountId) -> Result<Vec<AccountId>, StateError> {
+        let key = Self::delegator_key(delegator);
+        match self.db.get(&key)? {
+            Some(data) => bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string())),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Set the validators an account delegates to (deleted once empty)
+    pub fn set_delegation_targets(&self, delegator: &AccountId, validators: &[AccountId]) -> Result<(), StateError> {
+        let key = Self::delegator_key(delegator);
+        self.journal_undo(&key)?;
+        if validators.is_empty() {
+            self.db.delete(&key)?;
+        } else {
+            let value = bincode::serialize(validators)
+                .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+            self.db.put(&key, &value)?;
+        }
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Delegations of an account: validator and amount
+    pub fn get_delegations(&self, delegator: &AccountId) -> Result<Vec<(AccountId, Balance)>, StateError> {
+        let mut delegations = Vec::new();
+        for validator in self.get_delegation_targets(delegator)? {
+            let amount = self
+                .get_delegation_pool(&validator)?
+                .map_or(0, |pool| pool.delegated_by(delegator));
+            delegations.push((validator, amount));
+        }
+        Ok(delegations)
+    }
+
+    /// Part of an account's reserved balance that is delegated
+    pub fn get_total_delegated(&self, delegator: &AccountId) -> Result<Balance, StateError> {
+        Ok(self
+            .get_delegations(delegator)?
+            .iter()
+            .fold(0, |total, (_, amount)| total.saturating_add(*amount)))
+    }
+
+    /// Get the commission record of a validator (None: default commission)
+    pub fn get_commission(&self, validator_id: &AccountId) -> Result<Option<CommissionRecord>, StateError> {
+        let key = Self::commission_key(validator_id);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Set the commission record of a validator
+    pub fn set_commission(&self, validator_id: &AccountId, record: &CommissionRecord) -> Result<(), StateError> {
+        let key = Self::commission_key(validator_id);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(record)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    // ===== Delegator Rewards Storage =====
+
+    /// Get the rewards owed to an account
+    pub fn get_reward_ledger(&self, account_id: &AccountId) -> Result<Option<RewardLedger>, StateError> {
+        let key = Self::rewards_key(account_id);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Set the rewards owed to an account (deleted once empty)
+    pub fn set_reward_ledger(&mut self, account_id: &AccountId, ledger: &RewardLedger) -> Result<(), StateError> {
+        let key = Self::rewards_key(account_id);
+        self.journal_undo(&key)?;
+        if ledger.is_empty() {
+            self.db.delete(&key)?;
+        } else {
+            let value = bincode::serialize(ledger)
+                .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+            self.db.put(&key, &value)?;
+        }
+        self.track_change(&key);
+        Ok(())
+    }
+
+    // ===== Governance Proposals Storage =====
+
+    /// Get a governance proposal
+    pub fn get_proposal(&self, id: ProposalId) -> Result<Option<Proposal>, StateError> {
+        let key = Self::proposal_key(id);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Store a governance proposal
+    pub fn set_proposal(&mut self, proposal: &Proposal) -> Result<(), StateError> {
+        let key = Self::proposal_key(proposal.id);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(proposal)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// All governance proposals, oldest first
+    pub fn proposals(&self) -> Result<Vec<Proposal>, StateError> {
+        let mut proposals = BTreeMap::new();
+        for (_, value) in self.db.prefix_iterator(PREFIX_PROPOSAL) {
+            let proposal: Proposal = bincode::deserialize(&value)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            proposals.insert(proposal.id, proposal);
+        }
+        Ok(proposals.into_values().collect())
+    }
+
+    /// ID of the next proposal (IDs start at 1, proposals are never removed)
+    pub fn next_proposal_id(&self) -> Result<ProposalId, StateError> {
+        Ok(self.proposals()?.last().map_or(1, |proposal| proposal.id + 1))
+    }
+
+    // ===== Protocol Parameters Storage =====
+
+    /// Protocol parameters in force (genesis parameters until governance changes them)
+    pub fn get_protocol_parameters(&self) -> Result<ProtocolParameters, StateError> {
+        match self.db.get(&Self::parameters_key())? {
+            Some(data) => bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string())),
+            None => Ok(ProtocolParameters::genesis()),
+        }
+    }
+
+    /// Store the protocol parameters in force
+    pub fn set_protocol_parameters(&mut self, parameters: &ProtocolParameters) -> Result<(), StateError> {
+        let key = Self::parameters_key();
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(parameters)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Parameter change audit log, oldest first
+    pub fn parameter_log(&self) -> Result<Vec<ParameterChangeRecord>, StateError> {
+        let mut log = BTreeMap::new();
+        for (_, value) in self.db.prefix_iterator(PREFIX_PARAMETER_LOG) {
+            let record: ParameterChangeRecord = bincode::deserialize(&value)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            log.insert(record.sequence, record);
+        }
+        Ok(log.into_values().collect())
+    }
+
+    /// Sequence number of the next audit log entry
+    pub fn next_parameter_log_sequence(&self) -> Result<u64, StateError> {
+        Ok(self.parameter_log()?.last().map_or(0, |record| record.sequence + 1))
+    }
+
+    /// Append an entry to the parameter change audit log
+    pub fn append_parameter_log(&mut self, record: &ParameterChangeRecord) -> Result<(), StateError> {
+        let key = Self::parameter_log_key(record.sequence);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(record)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    // ===== Identity Storage =====
+
+    /// Identity registered by `owner`
+    pub fn get_identity(&self, owner: &AccountId) -> Result<Option<RegisteredIdentity>, StateError> {
+        let key = Self::identity_key(owner);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Store an identity under its owner
+    pub fn set_identity(&mut self, identity: &RegisteredIdentity) -> Result<(), StateError> {
+        let key = Self::identity_key(&identity.owner());
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(identity)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    // ===== Personhood Storage =====
+
+    /// Verification round of an epoch
+    pub fn get_ceremony_round(&self, epoch: EpochNumber) -> Result<Option<CeremonyRound>, StateError> {
+        let key = Self::ceremony_key(epoch);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Store a verification round under its epoch
+    pub fn set_ceremony_round(&mut self, round: &CeremonyRound) -> Result<(), StateError> {
+        let key = Self::ceremony_key(round.epoch);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(round)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Verified-person status of an account
+    pub fn get_personhood(&self, account: &AccountId) -> Result<Option<PersonhoodRecord>, StateError> {
+        let key = Self::personhood_key(account);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Store the verified-person status of an account
+    pub fn set_personhood(&mut self, record: &PersonhoodRecord) -> Result<(), StateError> {
+        let key = Self::personhood_key(&record.account);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(record)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    // ===== Reputation Storage =====
+
+    /// Reputation of an account
+    pub fn get_reputation(&self, account: &AccountId) -> Result<Option<AccountReputation>, StateError> {
+        let key = Self::reputation_key(account);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Store the reputation of an account
+    pub fn set_reputation(&mut self, reputation: &AccountReputation) -> Result<(), StateError> {
+        let key = Self::reputation_key(&reputation.account);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(reputation)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// All reputations, by account
+    pub fn reputations(&self) -> Result<Vec<AccountReputation>, StateError> {
+        let mut reputations = BTreeMap::new();
+        for (_, value) in self.db.prefix_iterator(PREFIX_REPUTATION) {
+            let reputation: AccountReputation = bincode::deserialize(&value)
+                .map_err(|e| StateError::DeserializationFailed(e.to_string()))?;
+            reputations.insert(reputation.account, reputation);
+        }
+        Ok(reputations.into_values().collect())
+    }
+
+    // ===== Message Storage =====
+
+    /// Inbox of an account (empty if it never received a message)
+    pub fn get_inbox(&self, account: &AccountId) -> Result<Inbox, StateError> {
+        let key = Self::inbox_key(account);
+        match self.db.get(&key)? {
+            Some(data) => bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string())),
+            None => Ok(Inbox::default()),
+        }
+    }
+
+    /// Store the inbox of an account
+    pub fn set_inbox(&mut self, account: &AccountId, inbox: &Inbox) -> Result<(), StateError> {
+        let key = Self::inbox_key(account);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(inbox)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Message `id` of an account's inbox
+    pub fn get_message(&self, recipient: &AccountId, id: MessageId) -> Result<Option<AccountMessage>, StateError> {
+        let key = Self::message_key(recipient, id);
+        match self.db.get(&key)? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Store a message in its recipient's inbox
+    pub fn set_message(&mut self, message: &AccountMessage) -> Result<(), StateError> {
+        let key = Self::message_key(&message.recipient, message.id);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(message)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Remove a message pruned from an inbox
+    pub fn delete_message(&mut self, recipient: &AccountId, id: MessageId) -> Result<(), StateError> {
+        let key = Self::message_key(recipient, id);
+        self.journal_undo(&key)?;
+        self.db.delete(&key)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    /// Latest messages of an account's inbox, newest first
+    pub fn inbox_messages(&self, recipient: &AccountId, limit: usize) -> Result<Vec<AccountMessage>, StateError> {
+        let inbox = self.get_inbox(recipient)?;
+        let mut messages = Vec::new();
+        for id in (inbox.first_id..inbox.next_id).rev().take(limit) {
+            if let Some(message) = self.get_message(recipient, id)? {
+                messages.push(message);
+            }
+        }
+        Ok(messages)
+    }
+
+    // ===== Emergency Storage =====
+
+    /// Emergency declaration (none gathered yet by default)
+    pub fn get_emergency_declaration(&self) -> Result<EmergencyDeclaration, StateError> {
+        match self.db.get(&Self::emergency_key())? {
+            Some(data) => bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string())),
+            None => Ok(EmergencyDeclaration::default()),
+        }
+    }
+
+    /// Store the emergency declaration
+    pub fn set_emergency_declaration(&mut self, declaration: &EmergencyDeclaration) -> Result<(), StateError> {
+        let key = Self::emergency_key();
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(declaration)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
+
+    // ===== Sidechains Storage =====
+
+    /// Sidechain registered on the root chain
+    pub fn get_sidechain(&self, chain_id: ChainId) -> Result<Option<SidechainInfo>, StateError> {
+        match self.db.get(&Self::sidechain_key(chain_id))? {
+            Some(data) => Ok(Some(
+                bincode::deserialize(&data).map_err(|e| StateError::DeserializationFailed(e.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Store a sidechain
+    pub fn set_sidechain(&mut self, sidechain: &SidechainInfo) -> Result<(), StateError> {
+        let key = Self::sidechain_key(sidechain.id);
+        self.journal_undo(&key)?;
+        let value = bincode::serialize(sidechain)
+            .map_err(|e| StateError::SerializationFailed(e.to_string()))?;
+        self.db.put(&key, &value)?;
+        self.track_change(&key);
+        Ok(())
+    }
 
-        record.record_missed_slot();
-        self.set_clock_record(validator_id, &record)
+    /// ID of the next sidechain (0 is the root chain, sidechains are never
+    /// removed)
+    pub fn next_sidechain_id(&self) -> Result<ChainId, StateError> {
+        Ok(self
+            .db
+            .prefix_iterator(PREFIX_SIDECHAIN)
+            .filter_map(|(key, _)| <[u8; 4]>::try_from(&key[PREFIX_SIDECHAIN.len()..]).ok())
+            .map(u32::from_be_bytes)
+            .max()
+            .map_or(ChainId(1), |id| ChainId(id + 1)))
     }
 
-    /// Get total clock sync failures for a validator
-    pub fn get_clock_failures(&self, validator_id: &AccountId) -> Result<u32, StateError> {
-        Ok(self.get_clock_record(validator_id)?
-            .map(|r| r.clock_sync_failures)
-            .unwrap_or(0))
+    /// Number of validators (accounts with a validator credits record)
+    pub fn validator_count(&self) -> Result<u32, StateError> {
+        Ok(self.db.prefix_iterator(PREFIX_VC).count() as u32)
     }
 
-    /// Get VC penalty from clock failures
-    pub fn get_clock_vc_penalty(&self, validator_id: &AccountId) -> Result<u64, StateError> {
-        Ok(self.get_clock_record(validator_id)?
-            .map(|r| r.vc_penalty())
-            .unwrap_or(0))
+    /// Total voting power: the reserved (staked) balance of all accounts
+    pub fn total_voting_power(&self) -> Result<Balance, StateError> {
+        Ok(self
+            .accounts()?
+            .iter()
+            .fold(0, |total, (_, account)| total.saturating_add(account.reserved)))
     }
 
     // Fonctions utilitaires pour les clés
-    fn account_key(id: &AccountId) -> Vec<u8> {
+    /// Storage key of an account
+    pub fn account_key(id: &AccountId) -> Vec<u8> {
         let mut key = PREFIX_ACCOUNT.to_vec();
         key.extend_from_slice(id.as_bytes());
         key
     }
 
+    fn reorg_key(id: u64) -> Vec<u8> {
+        let mut key = PREFIX_REORG.to_vec();
+        key.extend_from_slice(&id.to_be_bytes());
+        key
+    }
+
+    fn idempotency_key(key: &str) -> Vec<u8> {
+        let mut db_key = PREFIX_IDEMPOTENCY.to_vec();
+        db_key.extend_from_slice(key.as_bytes());
+        db_key
+    }
+
     fn block_hash_key(number: BlockNumber) -> Vec<u8> {
         let mut key = PREFIX_BLOCK_HASH.to_vec();
         key.extend_from_slice(&number.to_le_bytes());
@@ -930,12 +2860,127 @@ impl StateBackend {
         key
     }
 
+    fn proposal_key(id: ProposalId) -> Vec<u8> {
+        let mut key = PREFIX_PROPOSAL.to_vec();
+        key.extend_from_slice(&id.to_be_bytes());
+        key
+    }
+
+    fn identity_key(owner: &AccountId) -> Vec<u8> {
+        let mut key = PREFIX_IDENTITY.to_vec();
+        key.extend_from_slice(owner.as_bytes());
+        key
+    }
+
+    fn ceremony_key(epoch: EpochNumber) -> Vec<u8> {
+        let mut key = PREFIX_CEREMONY.to_vec();
+        key.extend_from_slice(&epoch.to_be_bytes());
+        key
+    }
+
+    fn inbox_key(account: &AccountId) -> Vec<u8> {
+        let mut key = PREFIX_INBOX.to_vec();
+        key.extend_from_slice(account.as_bytes());
+        key
+    }
+
+    fn message_key(recipient: &AccountId, id: MessageId) -> Vec<u8> {
+        let mut key = PREFIX_MESSAGE.to_vec();
+        key.extend_from_slice(recipient.as_bytes());
+        key.extend_from_slice(&id.to_be_bytes());
+        key
+    }
+
+    fn reputation_key(account: &AccountId) -> Vec<u8> {
+        let mut key = PREFIX_REPUTATION.to_vec();
+        key.extend_from_slice(account.as_bytes());
+        key
+    }
+
+    fn personhood_key(account: &AccountId) -> Vec<u8> {
+        let mut key = PREFIX_PERSONHOOD.to_vec();
+        key.extend_from_slice(account.as_bytes());
+        key
+    }
+
+    fn emergency_key() -> Vec<u8> {
+        let mut key = PREFIX_EMERGENCY.to_vec();
+        key.extend_from_slice(b"declaration");
+        key
+    }
+
+    fn sidechain_key(chain_id: ChainId) -> Vec<u8> {
+        let mut key = PREFIX_SIDECHAIN.to_vec();
+        key.extend_from_slice(&chain_id.0.to_be_bytes());
+        key
+    }
+
+    fn parameters_key() -> Vec<u8> {
+        let mut key = PREFIX_PARAMETERS.to_vec();
+        key.extend_from_slice(b"active");
+        key
+    }
+
+    fn parameter_log_key(sequence: u64) -> Vec<u8> {
+        let mut key = PREFIX_PARAMETER_LOG.to_vec();
+        key.extend_from_slice(&sequence.to_be_bytes());
+        key
+    }
+
+    fn vc_history_key(id: &AccountId) -> Vec<u8> {
+        let mut key = PREFIX_VC_HISTORY.to_vec();
+        key.extend_from_slice(id.as_bytes());
+        key
+    }
+
     fn unbonding_key(id: &AccountId) -> Vec<u8> {
         let mut key = PREFIX_UNBONDING.to_vec();
         key.extend_from_slice(id.as_bytes());
         key
     }
 
+    fn session_keys_key(id: &AccountId) -> Vec<u8> {
+        let mut key = PREFIX_SESSION_KEYS.to_vec();
+        key.extend_from_slice(id.as_bytes());
+        key
+    }
+
+    fn delegation_pool_key(id: &AccountId) -> Vec<u8> {
+        let mut key = PREFIX_DELEGATION_POOL.to_vec();
+        key.extend_from_slice(id.as_bytes());
+        key
+    }
+
+    fn delegator_key(id: &AccountId) -> Vec<u8> {
+        let mut key = PREFIX_DELEGATOR.to_vec();
+        key.extend_from_slice(id.as_bytes());
+        key
+    }
+
+    fn commission_key(id: &AccountId) -> Vec<u8> {
+        let mut key = PREFIX_COMMISSION.to_vec();
+        key.extend_from_slice(id.as_bytes());
+        key
+    }
+
+    fn rewards_key(id: &AccountId) -> Vec<u8> {
+        let mut key = PREFIX_REWARDS.to_vec();
+        key.extend_from_slice(id.as_bytes());
+        key
+    }
+
+    fn offence_key(offence: &Hash) -> Vec<u8> {
+        let mut key = PREFIX_OFFENCE.to_vec();
+        key.extend_from_slice(offence.as_bytes());
+        key
+    }
+
+    fn uptime_key(id: &AccountId) -> Vec<u8> {
+        let mut key = PREFIX_UPTIME.to_vec();
+        key.extend_from_slice(id.as_bytes());
+        key
+    }
+
     fn clock_record_key(id: &AccountId) -> Vec<u8> {
         let mut key = PREFIX_CLOCK_RECORD.to_vec();
         key.extend_from_slice(id.as_bytes());
@@ -953,6 +2998,108 @@ impl StateBackend {
         key.extend_from_slice(&number.to_le_bytes());
         key
     }
+
+    fn block_header_key(number: BlockNumber) -> Vec<u8> {
+        let mut key = PREFIX_BLOCK_HEADER.to_vec();
+        key.extend_from_slice(&number.to_be_bytes());
+        key
+    }
+
+    fn receipt_key(tx_hash: &Hash) -> Vec<u8> {
+        let mut key = PREFIX_RECEIPT.to_vec();
+        key.extend_from_slice(tx_hash.as_bytes());
+        key
+    }
+
+    fn block_fee_key(number: BlockNumber) -> Vec<u8> {
+        let mut key = PREFIX_BLOCK_FEE.to_vec();
+        key.extend_from_slice(&number.to_be_bytes());
+        key
+    }
+
+    fn justification_key(number: BlockNumber) -> Vec<u8> {
+        let mut key = PREFIX_JUSTIFICATION.to_vec();
+        key.extend_from_slice(&number.to_be_bytes());
+        key
+    }
+
+    fn undo_key(number: BlockNumber) -> Vec<u8> {
+        let mut key = PREFIX_UNDO.to_vec();
+        key.extend_from_slice(&number.to_be_bytes());
+        key
+    }
+}
+
+/// Database of StateBackend::state_at: the live database, seen through the
+/// values its keys had at a past block
+struct HistoricalDatabase {
+    live: Arc<dyn Database>,
+    /// Keys that differ from the live database (None: absent)
+    changes: RwLock<BTreeMap<Vec<u8>, Option<Vec<u8>>>>,
+}
+
+impl HistoricalDatabase {
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, BTreeMap<Vec<u8>, Option<Vec<u8>>>> {
+        self.changes.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, BTreeMap<Vec<u8>, Option<Vec<u8>>>> {
+        self.changes.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Database for HistoricalDatabase {
+    fn backend(&self) -> DatabaseBackend {
+        DatabaseBackend::Memory
+    }
+
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, DatabaseError> {
+        match self.read().get(key) {
+            Some(value) => Ok(value.clone()),
+            None => self.live.get(key),
+        }
+    }
+
+    fn put(&self, key: &[u8], value: &[u8]) -> Result<(), DatabaseError> {
+        self.write().insert(key.to_vec(), Some(value.to_vec()));
+        Ok(())
+    }
+
+    fn delete(&self, key: &[u8]) -> Result<(), DatabaseError> {
+        self.write().insert(key.to_vec(), None);
+        Ok(())
+    }
+
+    fn batch_write(&self, ops: Vec<WriteOp>) -> Result<(), DatabaseError> {
+        let mut changes = self.write();
+        for op in ops {
+            match op {
+                WriteOp::Put { key, value } => changes.insert(key, Some(value)),
+                WriteOp::Delete { key } => changes.insert(key, None),
+            };
+        }
+        Ok(())
+    }
+
+    fn prefix_iterator_with_errors<'a>(&'a self, prefix: &'a [u8]) -> KeyValueIter<'a> {
+        // Snapshot, like MemoryDatabase
+        let mut entries = BTreeMap::new();
+        for item in self.live.prefix_iterator_with_errors(prefix) {
+            match item {
+                Ok((key, value)) => {
+                    entries.insert(key, value);
+                }
+                Err(e) => return Box::new(std::iter::once(Err(e))),
+            }
+        }
+        for (key, value) in self.read().range(prefix.to_vec()..).take_while(|(key, _)| key.starts_with(prefix)) {
+            match value {
+                Some(value) => entries.insert(key.clone(), value.clone()),
+                None => entries.remove(key),
+            };
+        }
+        Box::new(entries.into_iter().map(Ok))
+    }
 }
 
 /// Erreurs d'état
@@ -982,18 +3129,54 @@ pub enum StateError {
 
     #[error("Drift validation failed: {0}")]
     DriftValidationFailed(String),
+
+    #[error("Historical state requires --pruning archive")]
+    ArchiveDisabled,
+
+    #[error("Indexed queries require --index")]
+    IndexDisabled,
+
+    #[error("State at block #{requested} not available (archive starts at #{archived_from})")]
+    StateUnavailable {
+        requested: BlockNumber,
+        archived_from: BlockNumber,
+    },
+
+    #[error("Storage key 0x{0} has no history (not a state key)")]
+    NotVersioned(String),
+
+    #[error("No state root stored for block #{0}")]
+    NoStateRoot(BlockNumber),
+
+    #[error("Recomputed state root of block #{0} does not match the stored one")]
+    StateRootMismatch(BlockNumber),
+
+    #[error("Snapshot accounts do not match the state root of block #{0}")]
+    SnapshotStateRootMismatch(BlockNumber),
+
+    #[error("Pruning is not available in archive mode")]
+    PruningArchive,
+
+    #[error("Block #{0} has no undo record (too deep or imported by warp sync)")]
+    NoUndoRecord(BlockNumber),
+
+    #[error("Cannot revert to block #{ancestor}: block #{finalized} is finalized")]
+    RevertFinalized {
+        ancestor: BlockNumber,
+        finalized: BlockNumber,
+    },
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::storage::Database;
+    use crate::storage::{Database, MemoryDatabase, RocksDatabase};
     use tempfile::TempDir;
 
     #[test]
     fn test_account_operations() {
         let temp_dir = TempDir::new().unwrap();
-        let db = Database::open(temp_dir.path()).unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
         let mut state = StateBackend::new(db);
 
         let account_id = AccountId::from_bytes([1; 32]);
@@ -1011,7 +3194,7 @@ mod tests {
     #[test]
     fn test_transfer() {
         let temp_dir = TempDir::new().unwrap();
-        let db = Database::open(temp_dir.path()).unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
         let mut state = StateBackend::new(db);
 
         let alice = AccountId::from_bytes([1; 32]);
@@ -1033,10 +3216,30 @@ mod tests {
         assert_eq!(bob_after.free, 300);
     }
 
+    #[test]
+    fn test_prefetch_accounts() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
+        let mut state = StateBackend::new(db);
+
+        let alice = AccountId::from_bytes([1; 32]);
+        let unknown = AccountId::from_bytes([9; 32]);
+        let mut alice_info = AccountInfo::new();
+        alice_info.free = 1000;
+        state.set_account(alice, alice_info).unwrap();
+        state.invalidate_cache();
+
+        assert_eq!(state.prefetch_accounts([alice, unknown]).unwrap(), 1);
+        assert_eq!(state.get_account(&alice).unwrap().unwrap().free, 1000);
+
+        let stats = state.cache_stats();
+        assert_eq!((stats.hits, stats.misses, stats.prefetched), (1, 0, 1));
+    }
+
     #[test]
     fn test_block_tracking() {
         let temp_dir = TempDir::new().unwrap();
-        let db = Database::open(temp_dir.path()).unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
         let state = StateBackend::new(db);
 
         let hash = Hash::hash(b"block1");
@@ -1051,12 +3254,327 @@ mod tests {
         assert_eq!(best_block, Some(1));
     }
 
+    #[test]
+    fn test_receipt_storage() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
+        let state = StateBackend::new(db);
+
+        let receipt = Receipt {
+            tx_hash: Hash::hash(b"tx"),
+            success: true,
+            fee_paid: 1_000,
+            error: None,
+            events: vec![],
+        };
+        let block_hash = Hash::hash(b"block7");
+        state.store_receipts(7, block_hash, std::slice::from_ref(&receipt)).unwrap();
+
+        let stored = state.get_receipt(&receipt.tx_hash).unwrap().unwrap();
+        assert_eq!(stored.receipt, receipt);
+        assert_eq!(stored.block_number, 7);
+        assert_eq!(stored.block_hash, block_hash);
+        assert_eq!(stored.index, 0);
+        assert!(state.get_receipt(&Hash::hash(b"other")).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_secondary_indexes() {
+        use crate::storage::TransactionDirection;
+        use crate::types::{Event, KratEvent, Transaction, TransactionCall};
+
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
+        let mut state = StateBackend::new(db);
+        let (alice, bob, carol) = (AccountId::from_bytes([1; 32]), AccountId::from_bytes([2; 32]), AccountId::from_bytes([3; 32]));
+
+        // Block n: alice pays bob n, authored by carol
+        let store = |state: &StateBackend, number: BlockNumber| {
+            let call = TransactionCall::Transfer { to: bob, amount: number as Balance };
+            let tx = SignedTransaction::new(Transaction::new(alice, number, call, Hash::ZERO), [0; 64]);
+            let receipt = Receipt {
+                tx_hash: tx.hash(),
+                success: true,
+                fee_paid: 1_000,
+                error: None,
+                events: vec![Event::Krat(KratEvent::Transfer { from: alice, to: bob, amount: number as Balance })],
+            };
+            let mut block = Block::genesis(Hash::ZERO, vec![carol]);
+            block.header.number = number;
+            block.body.transactions.push(tx);
+            state.store_block(&block).unwrap();
+            state.store_receipts(number, block.hash(), &[receipt]).unwrap();
+            state.set_best_block(number).unwrap();
+            state.index_block(&block).unwrap();
+            block
+        };
+
+        // Blocks stored before --index are caught up when it is enabled
+        for number in 1..=3 {
+            store(&state, number);
+        }
+        let all = TransactionFilter::default();
+        assert!(matches!(state.indexed_transactions(&bob, &all, None, 10), Err(StateError::IndexDisabled)));
+        assert_eq!(state.enable_index().unwrap(), 3);
+        assert_eq!((state.index_from().unwrap(), state.index_tip().unwrap()), (Some(0), Some(3)));
+
+        let block = store(&state, 4);
+        state.index_block(&block).unwrap();
+        assert_eq!(state.enable_index().unwrap(), 0);
+
+        // Newest first, paged by cursor, counted once
+        let numbers = |page: &IndexPage<IndexedTransaction>| page.items.iter().map(|tx| tx.block_number).collect::<Vec<_>>();
+        let first = state.indexed_transactions(&bob, &all, None, 1).unwrap();
+        assert_eq!((first.total, numbers(&first)), (4, vec![4]));
+        let cursor = first.next.unwrap();
+        assert_eq!(IndexCursor::from_hex(&cursor.to_hex()), Ok(cursor));
+        let second = state.indexed_transactions(&bob, &all, Some(cursor), 2).unwrap();
+        assert_eq!(numbers(&second), vec![3, 2]);
+        let last = state.indexed_transactions(&bob, &all, second.next, 2).unwrap();
+        assert_eq!((numbers(&last), last.next), (vec![1], None));
+        assert_eq!(state.indexed_transactions(&alice, &all, None, 10).unwrap().items.len(), 4);
+        assert_eq!(state.indexed_transactions(&carol, &all, None, 10).unwrap().total, 0);
+
+        // Filters: block range, direction, inclusion status
+        let range = TransactionFilter { from_block: Some(2), to_block: Some(3), ..Default::default() };
+        let page = state.indexed_transactions(&bob, &range, None, 10).unwrap();
+        assert_eq!((numbers(&page), page.next), (vec![3, 2], None));
+        let sent = TransactionFilter { direction: Some(TransactionDirection::Sent), ..Default::default() };
+        assert!(state.indexed_transactions(&bob, &sent, None, 10).unwrap().items.is_empty());
+        assert_eq!(state.indexed_transactions(&alice, &sent, None, 10).unwrap().items.len(), 4);
+        let received = TransactionFilter { direction: Some(TransactionDirection::Received), ..Default::default() };
+        assert_eq!(state.indexed_transactions(&bob, &received, None, 10).unwrap().items.len(), 4);
+        let failed = TransactionFilter { success: Some(false), ..Default::default() };
+        assert!(state.indexed_transactions(&alice, &failed, None, 10).unwrap().items.is_empty());
+
+        let events = state.indexed_events("krat.Transfer", 0, 1).unwrap();
+        assert_eq!((events.total, events.items[0].block_number), (4, 4));
+        assert_eq!(state.indexed_events("krat.Memo", 0, 10).unwrap().total, 0);
+
+        let authored = state.indexed_blocks_by_author(&carol, 0, 10).unwrap();
+        assert_eq!(authored.total, 4);
+        assert_eq!(authored.items.first().map(|b| (b.number, b.tx_count)), Some((4, 1)));
+    }
+
+    #[test]
+    fn test_archive_historical_state() {
+        use crate::types::ChainId;
+
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
+        let mut state = StateBackend::new(db);
+
+        let alice = AccountId::from_bytes([1; 32]);
+        let bob = AccountId::from_bytes([2; 32]);
+        let mut alice_info = AccountInfo::new();
+        alice_info.free = 100;
+
+        // State before archive mode is snapshotted at the best block
+        state.set_account(alice, alice_info.clone()).unwrap();
+        state.set_best_block(2).unwrap();
+        assert!(matches!(state.get_account_at(&alice, 2), Err(StateError::ArchiveDisabled)));
+        assert_eq!(state.enable_archive().unwrap(), 2);
+
+        // Block 3: alice changes, bob appears
+        alice_info.free = 300;
+        state.set_account(alice, alice_info.clone()).unwrap();
+        state.set_account(bob, AccountInfo::new()).unwrap();
+        let root = state.compute_state_root(3, ChainId(0));
+        state.store_state_root(3, root).unwrap();
+
+        // Block 5: bob is deleted
+        state.delete_account(&bob).unwrap();
+        let root = state.compute_state_root(5, ChainId(0));
+        state.store_state_root(5, root).unwrap();
+
+        assert_eq!(state.get_account_at(&alice, 2).unwrap().unwrap().free, 100);
+        assert_eq!(state.get_account_at(&alice, 4).unwrap().unwrap().free, 300);
+        assert!(state.get_account_at(&bob, 2).unwrap().is_none());
+        assert!(state.get_account_at(&bob, 4).unwrap().is_some());
+        assert!(state.get_account_at(&bob, 5).unwrap().is_none());
+        assert!(matches!(
+            state.get_account_at(&alice, 1),
+            Err(StateError::StateUnavailable { requested: 1, archived_from: 2 })
+        ));
+        assert!(matches!(state.get_storage_at(b"best_block", 3), Err(StateError::NotVersioned(_))));
+
+        // Re-enabling keeps the original archive start
+        assert_eq!(state.enable_archive().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_prove_account() {
+        use crate::types::ChainId;
+
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
+        let mut state = StateBackend::new(db);
+        state.enable_archive().unwrap();
+
+        let ids: Vec<AccountId> = (1..=3).map(|i| AccountId::from_bytes([i; 32])).collect();
+        for (i, id) in ids.iter().enumerate() {
+            let mut info = AccountInfo::new();
+            info.free = 100 * (i as u128 + 1);
+            state.set_account(*id, info).unwrap();
+        }
+        let root_1 = state.compute_state_root(1, ChainId(7));
+        state.store_state_root(1, root_1).unwrap();
+        state.set_best_block(1).unwrap();
+
+        // Block 2: the second account's balance changes
+        let mut info = state.get_account(&ids[1]).unwrap().unwrap();
+        info.free = 5;
+        state.set_account(ids[1], info).unwrap();
+        let root_2 = state.compute_state_root(2, ChainId(7));
+        state.store_state_root(2, root_2).unwrap();
+        state.set_best_block(2).unwrap();
+
+        // Best block from live state, unbalanced tree (3 leaves)
+        for id in &ids {
+            let proof = state.prove_account(id, 2).unwrap().unwrap();
+            assert!(proof.verify());
+            assert_eq!(proof.root, root_2.root);
+            assert_eq!(proof.chain_id, ChainId(7));
+        }
+
+        // Older block from archived versions
+        let proof = state.prove_account(&ids[1], 1).unwrap().unwrap();
+        assert!(proof.verify());
+        assert_eq!(proof.root, root_1.root);
+        let leaf: AccountInfo = bincode::deserialize(&proof.leaf).unwrap();
+        assert_eq!(leaf.free, 200);
+
+        assert!(state.prove_account(&AccountId::from_bytes([9; 32]), 2).unwrap().is_none());
+        assert!(matches!(state.prove_account(&ids[0], 3), Err(StateError::NoStateRoot(3))));
+    }
+
+    #[test]
+    fn test_justification_storage() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
+        let state = StateBackend::new(db);
+
+        let justification = |number: BlockNumber| FinalityJustification {
+            block_number: number,
+            block_hash: Hash::hash(&number.to_le_bytes()),
+            signatures: vec![],
+            epoch: 0,
+            round: number as u32,
+        };
+
+        assert!(state.latest_justification().unwrap().is_none());
+        assert!(state.justification_covering(1).unwrap().is_none());
+
+        // Stored out of order: the latest pointer only moves forward
+        state.store_justification(&justification(300)).unwrap();
+        state.store_justification(&justification(10)).unwrap();
+        assert_eq!(state.latest_justified_block().unwrap(), Some(300));
+        assert_eq!(state.get_justification(10).unwrap().unwrap().round, 10);
+        assert!(state.get_justification(11).unwrap().is_none());
+
+        // Blocks without their own justification are covered by the next one
+        assert_eq!(state.justification_covering(10).unwrap().unwrap().block_number, 10);
+        assert_eq!(state.justification_covering(11).unwrap().unwrap().block_number, 300);
+        assert!(state.justification_covering(301).unwrap().is_none());
+        assert_eq!(state.latest_justification().unwrap().unwrap().block_number, 300);
+    }
+
+    #[test]
+    fn test_prune_behind_finality() {
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
+        let mut state = StateBackend::new(db);
+
+        for number in 0..=20 {
+            let mut block = Block::genesis(Hash::ZERO, vec![]);
+            block.header.number = number;
+            state.store_block(&block).unwrap();
+            state.store_state_root(number, StateRoot::new(Hash::ZERO, number, ChainId(0))).unwrap();
+        }
+        state.set_best_block(20).unwrap();
+
+        // Nothing is pruned before a block is provably finalized
+        let report = state.prune(5, &BTreeSet::new(), 100).unwrap();
+        assert_eq!(report.pruned_blocks, 0);
+
+        state.store_justification(&FinalityJustification {
+            block_number: 15,
+            block_hash: state.get_block_hash(15).unwrap().unwrap(),
+            signatures: vec![],
+            epoch: 0,
+            round: 1,
+        }).unwrap();
+
+        // Window of 5 behind block 15, bounded to 4 blocks per pass, block 3 retained
+        let retain = BTreeSet::from([3]);
+        let report = state.prune(5, &retain, 4).unwrap();
+        assert_eq!((report.pruned_blocks, report.pruned_up_to, report.prunable_up_to), (3, Some(4), Some(10)));
+        let report = state.prune(5, &retain, 100).unwrap();
+        assert_eq!((report.pruned_blocks, report.pruned_up_to), (6, Some(10)));
+        assert_eq!(state.prune(5, &retain, 100).unwrap().pruned_blocks, 0);
+
+        assert!(state.get_block_by_number(0).unwrap().is_some());
+        assert!(state.get_block_by_number(3).unwrap().is_some());
+        assert!(state.get_block_by_number(10).unwrap().is_none());
+        assert!(state.get_state_root(10).unwrap().is_none());
+        assert!(state.get_block_by_number(11).unwrap().is_some());
+
+        // Headers and hashes of pruned blocks remain
+        assert_eq!(state.get_header_by_number(10).unwrap().unwrap().number, 10);
+        assert!(state.get_block_hash(10).unwrap().is_some());
+
+        state.enable_archive().unwrap();
+        assert!(matches!(state.prune(5, &retain, 100), Err(StateError::PruningArchive)));
+    }
+
+    #[test]
+    fn test_snapshot_export_and_import() {
+        use crate::network::warp_sync::StateSnapshot;
+
+        let source_dir = TempDir::new().unwrap();
+        let mut source = StateBackend::new(RocksDatabase::open(source_dir.path()).unwrap());
+        for i in 1..=5u8 {
+            let mut info = AccountInfo::new();
+            info.free = i as Balance * 100;
+            source.set_account(AccountId::from_bytes([i; 32]), info).unwrap();
+        }
+        let root = source.compute_state_root(1000, ChainId(0));
+
+        let mut block = Block::genesis(root.root, vec![]);
+        block.header.number = 1000;
+        let snapshot = StateSnapshot::new(1000, block.hash(), root, source.accounts().unwrap(), 2);
+        assert_eq!(snapshot.account_state_root(), root.root);
+
+        let target_dir = TempDir::new().unwrap();
+        let mut target = StateBackend::new(RocksDatabase::open(target_dir.path()).unwrap());
+        target.init_drift_tracker(0).unwrap();
+        let stale = AccountId::from_bytes([9; 32]);
+        target.set_account(stale, AccountInfo::new()).unwrap();
+
+        // Accounts that don't match the block are rejected before any write
+        let mut tampered: Vec<_> = snapshot.accounts().cloned().collect();
+        tampered[0].1.free += 1;
+        assert!(matches!(
+            target.import_snapshot(&block, tampered, ChainId(0)),
+            Err(StateError::SnapshotStateRootMismatch(1000))
+        ));
+        assert!(target.get_account(&stale).unwrap().is_some());
+
+        let imported = target.import_snapshot(&block, snapshot.accounts().cloned().collect(), ChainId(0)).unwrap();
+        assert_eq!(imported.root, root.root);
+        assert!(target.get_account(&stale).unwrap().is_none());
+        assert_eq!(target.get_account(&AccountId::from_bytes([3; 32])).unwrap().unwrap().free, 300);
+        assert_eq!(target.get_best_block().unwrap(), Some(1000));
+        assert_eq!(target.get_state_root(1000).unwrap().unwrap().root, root.root);
+        assert_eq!(target.get_drift_tracker().unwrap().unwrap().last_block, 1000);
+    }
+
     #[test]
     fn test_state_root_computation_empty() {
         use crate::types::ChainId;
 
         let temp_dir = TempDir::new().unwrap();
-        let db = Database::open(temp_dir.path()).unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
         let state = StateBackend::new(db);
 
         // Empty state should produce zero state root
@@ -1071,7 +3589,7 @@ mod tests {
         use crate::types::ChainId;
 
         let temp_dir = TempDir::new().unwrap();
-        let db = Database::open(temp_dir.path()).unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
         let mut state = StateBackend::new(db);
 
         // Add some accounts
@@ -1101,8 +3619,8 @@ mod tests {
 
         let temp_dir1 = TempDir::new().unwrap();
         let temp_dir2 = TempDir::new().unwrap();
-        let db1 = Database::open(temp_dir1.path()).unwrap();
-        let db2 = Database::open(temp_dir2.path()).unwrap();
+        let db1 = RocksDatabase::open(temp_dir1.path()).unwrap();
+        let db2 = RocksDatabase::open(temp_dir2.path()).unwrap();
         let mut state1 = StateBackend::new(db1);
         let mut state2 = StateBackend::new(db2);
 
@@ -1127,7 +3645,7 @@ mod tests {
         use crate::types::ChainId;
 
         let temp_dir = TempDir::new().unwrap();
-        let db = Database::open(temp_dir.path()).unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
         let mut state = StateBackend::new(db);
 
         // Add an account and compute state root
@@ -1155,7 +3673,7 @@ mod tests {
         use crate::types::ChainId;
 
         let temp_dir = TempDir::new().unwrap();
-        let db = Database::open(temp_dir.path()).unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
         let mut state = StateBackend::new(db);
 
         let alice = AccountId::from_bytes([1; 32]);
@@ -1181,4 +3699,140 @@ mod tests {
         assert_ne!(root2.root, root3.root);
         assert_ne!(root1.root, root3.root);
     }
+
+    #[test]
+    fn test_account_journal() {
+        use crate::types::ChainId;
+
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
+        let mut state = StateBackend::new(db);
+
+        let alice = AccountId::from_bytes([1; 32]);
+        let bob = AccountId::from_bytes([2; 32]);
+        let carol = AccountId::from_bytes([3; 32]);
+        let mut info = AccountInfo::new();
+        info.free = 1000;
+        state.set_account(alice, info.clone()).unwrap();
+        state.set_account(carol, info.clone()).unwrap();
+
+        state.begin_account_journal();
+        info.free = 400;
+        state.set_account(alice, info.clone()).unwrap();
+        info.free = 500;
+        state.set_account(alice, info.clone()).unwrap();
+        state.set_account(bob, info).unwrap();
+        // Rewritten with the same value: not a change
+        let unchanged = state.get_account(&carol).unwrap().unwrap();
+        state.set_account(carol, unchanged).unwrap();
+
+        let changes = state.take_account_journal().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].account, alice);
+        assert_eq!(changes[0].before.as_ref().unwrap().free, 1000);
+        assert_eq!(changes[0].after.as_ref().unwrap().free, 500);
+        assert_eq!(changes[1].account, bob);
+        assert!(changes[1].before.is_none());
+
+        // Closed once taken, and when a state root is stored
+        assert!(state.take_account_journal().unwrap().is_empty());
+        state.begin_account_journal();
+        state.delete_account(&bob).unwrap();
+        state.store_state_root(1, state.compute_state_root(1, ChainId(1))).unwrap();
+        assert!(state.take_account_journal().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_revert_to_ancestor() {
+        use crate::types::ChainId;
+
+        let temp_dir = TempDir::new().unwrap();
+        let db = RocksDatabase::open(temp_dir.path()).unwrap();
+        let mut state = StateBackend::new(db);
+
+        let alice = AccountId::from_bytes([1; 32]);
+        let bob = AccountId::from_bytes([2; 32]);
+        let mut info = AccountInfo::new();
+        info.free = 1000;
+        state.set_account(alice, info.clone()).unwrap();
+        state.store_state_root(1, state.compute_state_root(1, ChainId(1))).unwrap();
+        state.set_best_block(1).unwrap();
+
+        info.free = 400;
+        state.set_account(alice, info.clone()).unwrap();
+        info.free = 300;
+        state.set_account(alice, info.clone()).unwrap();
+        state.set_account(bob, info).unwrap();
+        state.store_state_root(2, state.compute_state_root(2, ChainId(1))).unwrap();
+        state.set_best_block(2).unwrap();
+
+        state.revert_to(1).unwrap();
+        assert_eq!(state.get_account(&alice).unwrap().unwrap().free, 1000);
+        assert!(state.get_account(&bob).unwrap().is_none());
+        assert_eq!(state.get_best_block().unwrap(), Some(1));
+        assert!(state.get_state_root(2).unwrap().is_none());
+        assert!(state.get_state_root(1).unwrap().is_some());
+
+        // Finalized blocks stay
+        state
+            .store_justification(&FinalityJustification {
+                block_number: 1,
+                block_hash: Hash::ZERO,
+                signatures: vec![],
+                epoch: 0,
+                round: 0,
+            })
+            .unwrap();
+        assert!(matches!(state.revert_to(0), Err(StateError::RevertFinalized { ancestor: 0, finalized: 1 })));
+    }
+
+    #[test]
+    fn test_reorg_events() {
+        let state = StateBackend::new(MemoryDatabase::new());
+        let tx = Hash::hash(b"tx");
+        assert!(state.reorg_events_after(0).unwrap().is_empty());
+
+        for ancestor in 0..MAX_REORG_EVENTS + 2 {
+            state.record_reorg(ancestor, 1, vec![tx], vec![]).unwrap();
+        }
+        let events = state.reorg_events_after(MAX_REORG_EVENTS).unwrap();
+        assert_eq!(events.iter().map(|e| e.id).collect::<Vec<_>>(), vec![MAX_REORG_EVENTS + 1, MAX_REORG_EVENTS + 2]);
+        assert_eq!((events[1].ancestor, &events[1].orphaned), (MAX_REORG_EVENTS + 1, &vec![tx]));
+
+        // The oldest are dropped
+        assert_eq!(state.reorg_events_after(0).unwrap()[0].id, 3);
+    }
+
+    #[test]
+    fn test_state_at_block() {
+        use crate::types::ChainId;
+
+        let mut state = StateBackend::new(MemoryDatabase::new());
+        let alice = AccountId::from_bytes([1; 32]);
+        let bob = AccountId::from_bytes([2; 32]);
+        let mut info = AccountInfo::new();
+        for (number, free) in [(1, 1000), (2, 400)] {
+            info.free = free;
+            state.set_account(alice, info.clone()).unwrap();
+            state.store_state_root(number, state.compute_state_root(number, ChainId(0))).unwrap();
+            state.set_best_block(number).unwrap();
+        }
+        // Current block, not stored yet
+        info.free = 300;
+        state.set_account(alice, info.clone()).unwrap();
+        state.set_account(bob, info).unwrap();
+
+        let mut past = state.state_at(1).unwrap();
+        assert_eq!(past.get_account(&alice).unwrap().unwrap().free, 1000);
+        assert!(past.get_account(&bob).unwrap().is_none());
+        assert_eq!(past.get_best_block().unwrap(), Some(1));
+        assert_eq!(past.compute_state_root(1, ChainId(0)).root, state.get_state_root(1).unwrap().unwrap().root);
+        assert_eq!(past.state_at(0).unwrap().accounts().unwrap().len(), 0);
+        assert_eq!(state.state_at(2).unwrap().get_account(&alice).unwrap().unwrap().free, 400);
+
+        // Writes stay in the past state
+        past.set_account(bob, AccountInfo::new()).unwrap();
+        assert_eq!(state.get_account(&bob).unwrap().unwrap().free, 300);
+        assert!(matches!(state.state_at(3), Err(StateError::NoStateRoot(3))));
+    }
 }
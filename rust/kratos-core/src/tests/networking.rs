@@ -36,9 +36,11 @@ mod tests {
             parent_hash: Hash::ZERO,
             transactions_root: Hash::ZERO, // Empty transactions -> ZERO root is valid
             state_root: Hash::ZERO,
+            receipts_root: Hash::ZERO,
             timestamp: number * 6,
             epoch: number / 100,
             slot: number,
+            protocol_version: ProtocolVersion::default(),
             author,
             signature: Signature64([0; 64]),
         };
@@ -741,12 +743,12 @@ mod tests {
 
     mod storage_sync {
         use super::*;
-        use crate::storage::{Database, StateBackend};
+        use crate::storage::{RocksDatabase, StateBackend};
         use tempfile::TempDir;
 
         fn create_test_storage() -> (TempDir, StateBackend) {
             let dir = TempDir::new().unwrap();
-            let db = Database::open(dir.path().to_str().unwrap()).unwrap();
+            let db = RocksDatabase::open(dir.path().to_str().unwrap()).unwrap();
             let backend = StateBackend::new(db);
             (dir, backend)
         }
@@ -511,6 +511,7 @@ mod invariant_no_silent_corruption {
     fn test_fraud_proofs_verifiable() {
         use crate::types::fraud::FraudProof;
         use crate::types::block::BlockHeader;
+        use crate::types::protocol::ProtocolVersion;
         use crate::types::signature::Signature64;
 
         // Create block headers for double finalization proof
@@ -519,9 +520,11 @@ mod invariant_no_silent_corruption {
             parent_hash: Hash::hash(b"parent"),
             transactions_root: Hash::hash(b"tx_root"),
             state_root: Hash::hash(b"state_root_a"),
+            receipts_root: Hash::ZERO,
             timestamp: 1000000,
             epoch: 1,
             slot: 10,
+            protocol_version: ProtocolVersion::default(),
             author: create_account(1),
             signature: Signature64::from_bytes([0u8; 64]),
         };
@@ -531,9 +534,11 @@ mod invariant_no_silent_corruption {
             parent_hash: Hash::hash(b"parent"),
             transactions_root: Hash::hash(b"tx_root"),
             state_root: Hash::hash(b"state_root_b"), // Different state root
+            receipts_root: Hash::ZERO,
             timestamp: 1000000,
             epoch: 1,
             slot: 10,
+            protocol_version: ProtocolVersion::default(),
             author: create_account(1),
             signature: Signature64::from_bytes([0u8; 64]),
         };
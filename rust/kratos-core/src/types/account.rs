@@ -122,7 +122,7 @@ impl From<[u8; 32]> for AccountId {
 }
 
 /// État d'un compte dans le ledger
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct AccountInfo {
     /// Nonce pour prévenir replay
     pub nonce: Nonce,
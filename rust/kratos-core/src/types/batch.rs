@@ -0,0 +1,169 @@
+// Batch signature verification - Ed25519 batches spread over worker threads
+// Principle: Verify many signatures at once, pinpoint the offender only on failure
+//
+// A block carries one signature per transaction and a justification one per
+// precommit. Instead of checking them one by one, the signatures are split into
+// chunks, each chunk is checked with a single Ed25519 batch equation on its own
+// thread, and only a chunk whose batch fails is re-checked signature by
+// signature to find which ones are invalid. Honest input (the common case)
+// therefore never pays for individual verification.
+
+use crate::types::{AccountId, Signature64};
+use ed25519_dalek::{Signature, VerifyingKey};
+
+/// Below this many signatures everything is checked on the calling thread
+pub const PARALLEL_VERIFY_THRESHOLD: usize = 64;
+
+/// Upper bound on verification worker threads
+pub const MAX_VERIFY_THREADS: usize = 8;
+
+/// One signature to verify
+#[derive(Debug, Clone, Copy)]
+pub struct SignatureItem<'a> {
+    pub signer: AccountId,
+    pub message: &'a [u8],
+    pub signature: &'a Signature64,
+}
+
+impl<'a> SignatureItem<'a> {
+    pub fn new(signer: AccountId, message: &'a [u8], signature: &'a Signature64) -> Self {
+        Self { signer, message, signature }
+    }
+
+    /// Individual verification (same check as AccountId::verify)
+    pub fn verify(&self) -> bool {
+        self.signer.verify(self.message, self.signature.as_bytes())
+    }
+}
+
+/// Verify every item, returning Err with the index of the first invalid one
+pub fn verify_all(items: &[SignatureItem<'_>]) -> Result<(), usize> {
+    match invalid_signatures(items).first() {
+        Some(&index) => Err(index),
+        None => Ok(()),
+    }
+}
+
+/// Indices of the invalid items, in ascending order (empty if all are valid)
+pub fn invalid_signatures(items: &[SignatureItem<'_>]) -> Vec<usize> {
+    if items.is_empty() {
+        return Vec::new();
+    }
+
+    let threads = if items.len() < PARALLEL_VERIFY_THRESHOLD {
+        1
+    } else {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .clamp(1, MAX_VERIFY_THREADS)
+    };
+
+    if threads == 1 {
+        return invalid_in_chunk(items, 0);
+    }
+
+    let chunk_size = items.len().div_ceil(threads);
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = items
+            .chunks(chunk_size)
+            .enumerate()
+            .map(|(i, chunk)| scope.spawn(move || invalid_in_chunk(chunk, i * chunk_size)))
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| match worker.join() {
+                Ok(invalid) => invalid,
+                Err(panic) => std::panic::resume_unwind(panic),
+            })
+            .collect()
+    })
+}
+
+/// Check one chunk with a batch, falling back to individual verification
+fn invalid_in_chunk(chunk: &[SignatureItem<'_>], offset: usize) -> Vec<usize> {
+    if batch_ok(chunk) {
+        return Vec::new();
+    }
+
+    chunk
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| !item.verify())
+        .map(|(i, _)| offset + i)
+        .collect()
+}
+
+fn batch_ok(chunk: &[SignatureItem<'_>]) -> bool {
+    if chunk.len() == 1 {
+        return chunk[0].verify();
+    }
+
+    let mut keys = Vec::with_capacity(chunk.len());
+    for item in chunk {
+        match VerifyingKey::from_bytes(item.signer.as_bytes()) {
+            Ok(key) => keys.push(key),
+            // Malformed key: let the individual pass reject it
+            Err(_) => return false,
+        }
+    }
+    let messages: Vec<&[u8]> = chunk.iter().map(|item| item.message).collect();
+    let signatures: Vec<Signature> = chunk
+        .iter()
+        .map(|item| Signature::from_bytes(item.signature.as_bytes()))
+        .collect();
+
+    ed25519_dalek::verify_batch(&messages, &signatures, &keys).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn signed(count: usize) -> Vec<(AccountId, Vec<u8>, Signature64)> {
+        (0..count)
+            .map(|i| {
+                let key = SigningKey::from_bytes(&[(i % 251) as u8 + 1; 32]);
+                let message = format!("message {}", i).into_bytes();
+                let signature = Signature64(key.sign(&message).to_bytes());
+                (AccountId::from_public_key(&key.verifying_key()), message, signature)
+            })
+            .collect()
+    }
+
+    fn items(signed: &[(AccountId, Vec<u8>, Signature64)]) -> Vec<SignatureItem<'_>> {
+        signed
+            .iter()
+            .map(|(signer, message, signature)| SignatureItem::new(*signer, message, signature))
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_accepts_valid_signatures() {
+        assert_eq!(verify_all(&[]), Ok(()));
+
+        let few = signed(3);
+        assert_eq!(verify_all(&items(&few)), Ok(()));
+
+        let many = signed(PARALLEL_VERIFY_THRESHOLD * 3);
+        assert!(invalid_signatures(&items(&many)).is_empty());
+    }
+
+    #[test]
+    fn test_batch_failure_pinpoints_offenders() {
+        let mut many = signed(PARALLEL_VERIFY_THRESHOLD * 3);
+        let last = many.len() - 1;
+        many[7].2 = Signature64::zero();
+        many[last].1 = b"tampered".to_vec();
+
+        assert_eq!(invalid_signatures(&items(&many)), vec![7, last]);
+        assert_eq!(verify_all(&items(&many)), Err(7));
+
+        // Signer that is not a valid curve point
+        let mut few = signed(2);
+        few[1].0 = AccountId::from_bytes([0xff; 32]);
+        assert_eq!(verify_all(&items(&few)), Err(1));
+    }
+}
@@ -1,10 +1,16 @@
 // Block - Structure de bloc minimal et auditable
 use super::account::AccountId;
+use super::batch::{invalid_signatures, SignatureItem};
 use super::merkle::StateMerkleTree;
 use super::primitives::{BlockNumber, EpochNumber, Hash, SlotNumber, Timestamp};
+use super::protocol::ProtocolVersion;
 use super::signature::{domain_separate, Signature64, DOMAIN_BLOCK_HEADER, DOMAIN_FINALITY};
 use super::transaction::SignedTransaction;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Octet du type de vote "precommit" dans les messages de finalité
+const PRECOMMIT_VOTE_TYPE: u8 = 1;
 
 /// Bloc complet
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +54,9 @@ pub struct BlockHeader {
     /// Merkle root de l'état (après exécution)
     pub state_root: Hash,
 
+    /// Merkle root des reçus d'exécution (statut, frais, événements)
+    pub receipts_root: Hash,
+
     /// Timestamp du bloc
     pub timestamp: Timestamp,
 
@@ -57,6 +66,9 @@ pub struct BlockHeader {
     /// Slot dans l'epoch
     pub slot: SlotNumber,
 
+    /// Version du protocole en vigueur à ce bloc (fixée par la gouvernance)
+    pub protocol_version: ProtocolVersion,
+
     /// Validateur qui a produit ce bloc
     pub author: AccountId,
 
@@ -73,9 +85,11 @@ impl BlockHeader {
             self.parent_hash,
             self.transactions_root,
             self.state_root,
+            self.receipts_root,
             self.timestamp,
             self.epoch,
             self.slot,
+            self.protocol_version,
             self.author,
         ))
         .unwrap();
@@ -153,9 +167,11 @@ impl Block {
             parent_hash: Hash::ZERO,
             transactions_root: Hash::ZERO,
             state_root,
+            receipts_root: Hash::ZERO,
             timestamp: 0,
             epoch: 0,
             slot: 0,
+            protocol_version: ProtocolVersion::default(),
             author: genesis_accounts.first().copied().unwrap_or(AccountId::from_bytes([0; 32])),
             signature: Signature64::zero(),
         };
@@ -167,6 +183,9 @@ impl Block {
 }
 
 /// Justification de finalité (GRANDPA-like)
+///
+/// Les signatures sont celles des precommits du round qui a finalisé le bloc :
+/// la justification se vérifie seule, sans rejouer les votes.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FinalityJustification {
     /// Numéro du bloc finalisé
@@ -180,17 +199,23 @@ pub struct FinalityJustification {
 
     /// Epoch de finalisation
     pub epoch: EpochNumber,
+
+    /// Round de finalisation
+    pub round: u32,
 }
 
 impl FinalityJustification {
-    /// Crée le message à signer pour la finalité (avec domain separation)
+    /// Crée le message signé par chaque precommit (avec domain separation)
     ///
     /// SECURITY FIX #33: Domain separation for finality signatures
-    /// Message format: DOMAIN_FINALITY || block_number || block_hash || epoch
+    /// Message format: DOMAIN_FINALITY || precommit || block_number || block_hash || round || epoch
+    /// (identique à `FinalityVote::signing_message` pour un precommit)
     pub fn signing_message(&self) -> Vec<u8> {
         let message = bincode::serialize(&(
+            PRECOMMIT_VOTE_TYPE,
             self.block_number,
             self.block_hash,
+            self.round,
             self.epoch,
         ))
         .unwrap();
@@ -203,12 +228,13 @@ impl FinalityJustification {
     /// Returns (valid_count, total_count) for threshold checking
     pub fn verify_signatures(&self) -> (usize, usize) {
         let message = self.signing_message();
-        let valid_count = self
+        let items: Vec<SignatureItem<'_>> = self
             .signatures
             .iter()
-            .filter(|sig| sig.validator.verify(&message, sig.signature.as_bytes()))
-            .count();
-        (valid_count, self.signatures.len())
+            .map(|sig| SignatureItem::new(sig.validator, &message, &sig.signature))
+            .collect();
+        let invalid = invalid_signatures(&items).len();
+        (items.len() - invalid, items.len())
     }
 
     /// Vérifie si la justification atteint le seuil de 2/3
@@ -230,7 +256,7 @@ impl FinalityJustification {
     /// - No duplicate validators
     pub fn is_valid(&self, total_validators: usize) -> bool {
         // Check for duplicate validators
-        let mut seen = std::collections::HashSet::new();
+        let mut seen = HashSet::new();
         for sig in &self.signatures {
             if !seen.insert(sig.validator) {
                 return false; // Duplicate validator
@@ -240,6 +266,33 @@ impl FinalityJustification {
         // Check supermajority
         self.has_supermajority(total_validators)
     }
+
+    /// Vérifie la justification contre un ensemble de validateurs connu
+    ///
+    /// Seules les signatures valides de membres de l'ensemble comptent ;
+    /// elles doivent atteindre 2/3 de l'ensemble.
+    pub fn verify_with_validators(&self, validators: &HashSet<AccountId>) -> bool {
+        if validators.is_empty() {
+            return false;
+        }
+
+        let message = self.signing_message();
+        let items: Vec<SignatureItem<'_>> = self
+            .signatures
+            .iter()
+            .filter(|sig| validators.contains(&sig.validator))
+            .map(|sig| SignatureItem::new(sig.validator, &message, &sig.signature))
+            .collect();
+        let invalid: HashSet<usize> = invalid_signatures(&items).into_iter().collect();
+
+        let signers: HashSet<AccountId> = items
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !invalid.contains(index))
+            .map(|(_, item)| item.signer)
+            .collect();
+        signers.len() * 100 >= validators.len() * 66
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -259,9 +312,11 @@ mod tests {
             parent_hash: Hash::ZERO,
             transactions_root: Hash::ZERO,
             state_root: Hash::ZERO,
+            receipts_root: Hash::ZERO,
             timestamp: 1234567890,
             epoch: 0,
             slot: 0,
+            protocol_version: ProtocolVersion::default(),
             author: AccountId::from_bytes([1; 32]),
             signature: Signature64::zero(),
         };
@@ -293,9 +348,11 @@ mod tests {
             parent_hash: Hash::ZERO,
             transactions_root: Hash::ZERO,
             state_root: Hash::ZERO,
+            receipts_root: Hash::ZERO,
             timestamp: 1234567890,
             epoch: 0,
             slot: 0,
+            protocol_version: ProtocolVersion::default(),
             author: AccountId::from_bytes([1; 32]),
             signature: Signature64::zero(),
         };
@@ -135,6 +135,10 @@ pub struct SidechainInfo {
     /// Prevents double-withdrawal attacks
     #[serde(default)]
     pub withdrawn_accounts: HashSet<AccountId>,
+
+    /// Deposit released by checkpoint exits (never more than `deposit`)
+    #[serde(default)]
+    pub exited: Balance,
 }
 
 impl SidechainInfo {
@@ -174,6 +178,7 @@ impl SidechainInfo {
             snapshot_state_root: None,
             // SECURITY FIX #33: Withdrawal tracking
             withdrawn_accounts: HashSet::new(),
+            exited: 0,
         }
     }
 
@@ -4,38 +4,27 @@
 use super::primitives::{BlockNumber, ChainId};
 use super::account::AccountId;
 use super::signature::Signature64;
-use super::account::AccountInfo;
-use super::block::BlockHeader;
+use super::block::{Block, BlockHeader};
 use super::merkle::MerkleProof;
 use super::chain::SidechainInfo;
 use serde::{Deserialize, Serialize};
 
+/// Maximum encoded size of a fraud proof in a SubmitFraudProof transaction
+///
+/// Room for a full block (ProducerConfig::max_transactions_per_block), well
+/// under the gossip message limit.
+pub const MAX_FRAUD_PROOF_SIZE: usize = 512 * 1024;
+
 /// Fraud proof - cryptographic evidence of validator misbehavior
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FraudProof {
     /// Invalid state transition detected
-    /// Proves that a state change violated the protocol rules
+    /// Proves that a block's state or receipts root is not the result of
+    /// executing it: re-executed on its parent's state, one of its
+    /// transactions fails or the roots differ from its header
     InvalidStateTransition {
-        /// Account that had invalid state transition
-        account: AccountId,
-
-        /// State before the invalid transition
-        before_state: AccountInfo,
-
-        /// State after the invalid transition
-        after_state: AccountInfo,
-
-        /// Merkle proof of before_state in previous block
-        merkle_proof_before: MerkleProof,
-
-        /// Merkle proof of after_state in current block
-        merkle_proof_after: MerkleProof,
-
-        /// Block number where violation occurred
-        block_number: BlockNumber,
-
-        /// The violating validator (who produced the block)
-        validator: AccountId,
+        /// Block signed by the violating validator, with its transactions
+        block: Block,
     },
 
     /// Double finalization detected
@@ -117,6 +106,12 @@ pub enum ExitViolation {
 /// Errors that can occur during fraud proof verification
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum FraudProofError {
+    #[error("Fraud proof too large: {0} bytes (max {MAX_FRAUD_PROOF_SIZE})")]
+    TooLarge(usize),
+
+    #[error("Malformed fraud proof: {0}")]
+    Malformed(String),
+
     #[error("Merkle proof verification failed")]
     InvalidMerkleProof,
 
@@ -143,13 +138,16 @@ pub enum FraudProofError {
 
     #[error("Invalid proof structure: {0}")]
     InvalidProofStructure(String),
+
+    #[error("Parent state unavailable: {0}")]
+    ParentStateUnavailable(String),
 }
 
 impl FraudProof {
     /// Get the validator accused by this fraud proof
     pub fn accused_validator(&self) -> AccountId {
         match self {
-            FraudProof::InvalidStateTransition { validator, .. } => *validator,
+            FraudProof::InvalidStateTransition { block } => block.header.author,
             FraudProof::DoubleFinalization { validator, .. } => *validator,
             // For InvalidExit, we'd need to look up who was validating that chain
             // For now, return a placeholder
@@ -160,7 +158,7 @@ impl FraudProof {
     /// Get the block number where the fraud occurred
     pub fn fraud_block_number(&self) -> BlockNumber {
         match self {
-            FraudProof::InvalidStateTransition { block_number, .. } => *block_number,
+            FraudProof::InvalidStateTransition { block } => block.header.number,
             FraudProof::DoubleFinalization { block_a, .. } => block_a.number,
             FraudProof::InvalidExit { block_number, .. } => *block_number,
         }
@@ -174,7 +172,24 @@ impl FraudProof {
         }
     }
 
+    /// Encoding carried by SubmitFraudProof
+    pub fn to_bytes(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("FraudProof serialization should not fail")
+    }
+
+    /// Decode a fraud proof, refusing oversized input before deserializing
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FraudProofError> {
+        if bytes.len() > MAX_FRAUD_PROOF_SIZE {
+            return Err(FraudProofError::TooLarge(bytes.len()));
+        }
+        bincode::deserialize(bytes).map_err(|e| FraudProofError::Malformed(e.to_string()))
+    }
+
     /// Verify this fraud proof (delegates to specific verification methods)
+    ///
+    /// Checks the proof on its own: re-executing the block of an
+    /// InvalidStateTransition, and checking it extends the chain and is
+    /// signed by its author, is up to the caller (see consensus::fraud).
     pub fn verify(&self) -> Result<(), FraudProofError> {
         match self {
             FraudProof::InvalidStateTransition { .. } => self.verify_invalid_state_transition(),
@@ -185,37 +200,14 @@ impl FraudProof {
 
     /// Verify InvalidStateTransition fraud proof
     fn verify_invalid_state_transition(&self) -> Result<(), FraudProofError> {
-        if let FraudProof::InvalidStateTransition {
-            account,
-            before_state,
-            after_state,
-            merkle_proof_before,
-            merkle_proof_after,
-            block_number,
-            validator: _,
-        } = self
-        {
-            // 1. Verify Merkle proofs
-            if !merkle_proof_before.verify() {
-                return Err(FraudProofError::InvalidMerkleProof);
-            }
-            if !merkle_proof_after.verify() {
-                return Err(FraudProofError::InvalidMerkleProof);
-            }
-
-            // 2. Verify proofs are for consecutive blocks
-            if merkle_proof_after.block_number != merkle_proof_before.block_number + 1 {
+        if let FraudProof::InvalidStateTransition { block } = self {
+            // Genesis is not the result of executing anything
+            if block.header.number == 0 {
                 return Err(FraudProofError::InvalidProofStructure(
-                    "Merkle proofs must be for consecutive blocks".to_string()
+                    "Genesis block has no state transition".to_string()
                 ));
             }
 
-            // 3. Verify the state transition is actually invalid
-            // Check balance can't go negative, can't increase without valid source, etc.
-            if !Self::is_state_transition_invalid(before_state, after_state) {
-                return Err(FraudProofError::NoViolationDetected);
-            }
-
             Ok(())
         } else {
             Err(FraudProofError::InvalidProofStructure(
@@ -310,32 +302,6 @@ impl FraudProof {
             ))
         }
     }
-
-    /// Check if a state transition is invalid
-    /// Returns true if the transition violates protocol rules
-    fn is_state_transition_invalid(before: &AccountInfo, after: &AccountInfo) -> bool {
-        // Balance can't go negative (though u128 prevents this at type level)
-        // Balance can't increase without a valid deposit/transfer source
-        // Nonce must increment monotonically
-
-        // Example: Balance increased by more than it should (indicating theft/inflation)
-        if after.free > before.free + 1_000_000_000 {
-            // Arbitrary threshold - in reality would check against actual tx
-            return true;
-        }
-
-        // Example: Nonce decreased (replay attack indicator)
-        if after.nonce < before.nonce {
-            return true;
-        }
-
-        // Example: Nonce jumped by more than 1 (indicating tx skipped)
-        if after.nonce > before.nonce + 1 {
-            return true;
-        }
-
-        false
-    }
 }
 
 /// Result of fraud proof verification
@@ -404,7 +370,7 @@ impl FraudSeverity {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{Hash, Signature64};
+    use crate::types::{BlockBody, Hash, ProtocolVersion, Signature64};
 
     #[test]
     fn test_fraud_severity_slash_percentages() {
@@ -416,50 +382,32 @@ mod tests {
 
     #[test]
     fn test_fraud_proof_accused_validator() {
-        let validator = AccountId::from_bytes([1; 32]);
-
-        let proof = FraudProof::InvalidStateTransition {
-            account: AccountId::from_bytes([2; 32]),
-            before_state: create_dummy_account_info(1000, 0),
-            after_state: create_dummy_account_info(2000, 1),
-            merkle_proof_before: create_dummy_merkle_proof(0),
-            merkle_proof_after: create_dummy_merkle_proof(1),
-            block_number: 100,
-            validator,
-        };
+        let proof = create_invalid_state_transition(100);
 
-        assert_eq!(proof.accused_validator(), validator);
+        assert_eq!(proof.accused_validator(), AccountId::from_bytes([0; 32]));
     }
 
     #[test]
     fn test_fraud_proof_block_number() {
-        let proof = FraudProof::InvalidStateTransition {
-            account: AccountId::from_bytes([1; 32]),
-            before_state: create_dummy_account_info(1000, 0),
-            after_state: create_dummy_account_info(2000, 1),
-            merkle_proof_before: create_dummy_merkle_proof(0),
-            merkle_proof_after: create_dummy_merkle_proof(1),
-            block_number: 100,
-            validator: AccountId::from_bytes([2; 32]),
-        };
+        let proof = create_invalid_state_transition(100);
 
         assert_eq!(proof.fraud_block_number(), 100);
     }
 
     #[test]
-    fn test_state_transition_invalid_nonce_decrease() {
-        let before = create_dummy_account_info(1000, 5);
-        let after = create_dummy_account_info(1000, 3);
-
-        assert!(FraudProof::is_state_transition_invalid(&before, &after));
-    }
-
-    #[test]
-    fn test_state_transition_invalid_nonce_jump() {
-        let before = create_dummy_account_info(1000, 5);
-        let after = create_dummy_account_info(1000, 10);
-
-        assert!(FraudProof::is_state_transition_invalid(&before, &after));
+    fn test_invalid_state_transition_verify() {
+        assert!(create_invalid_state_transition(100).verify().is_ok());
+        assert!(matches!(
+            create_invalid_state_transition(0).verify(),
+            Err(FraudProofError::InvalidProofStructure(_))
+        ));
+
+        let bytes = create_invalid_state_transition(100).to_bytes();
+        assert!(FraudProof::from_bytes(&bytes).is_ok());
+        assert!(matches!(
+            FraudProof::from_bytes(&vec![0; MAX_FRAUD_PROOF_SIZE + 1]),
+            Err(FraudProofError::TooLarge(_))
+        ));
     }
 
     #[test]
@@ -478,15 +426,7 @@ mod tests {
 
     #[test]
     fn test_invalid_state_transition_severity() {
-        let proof = FraudProof::InvalidStateTransition {
-            account: AccountId::from_bytes([1; 32]),
-            before_state: create_dummy_account_info(1000, 0),
-            after_state: create_dummy_account_info(2000, 1),
-            merkle_proof_before: create_dummy_merkle_proof(0),
-            merkle_proof_after: create_dummy_merkle_proof(1),
-            block_number: 100,
-            validator: AccountId::from_bytes([2; 32]),
-        };
+        let proof = create_invalid_state_transition(100);
 
         let severity = FraudSeverity::from_fraud_proof(&proof);
         assert_eq!(severity, FraudSeverity::Severe);
@@ -538,21 +478,19 @@ mod tests {
             parent_hash: Hash::from_bytes([0; 32]),
             transactions_root: Hash::from_bytes([0; 32]),
             state_root: Hash::from_bytes([0; 32]),
+            receipts_root: Hash::ZERO,
             timestamp: 0,
             epoch: 0,
             slot: 0,
+            protocol_version: ProtocolVersion::default(),
             author: AccountId::from_bytes([0; 32]),
             signature: Signature64::from_bytes([0; 64]),
         }
     }
 
-    fn create_dummy_account_info(balance: u128, nonce: u64) -> AccountInfo {
-        use crate::types::Hash;
-        AccountInfo {
-            nonce,
-            free: balance,
-            reserved: 0,
-            last_modified: Hash::from_bytes([0; 32]),
-        }
+    /// Empty block `number` disputed as invalid
+    fn create_invalid_state_transition(number: BlockNumber) -> FraudProof {
+        let block = Block::new(create_dummy_block_header(number), BlockBody::new(Vec::new()));
+        FraudProof::InvalidStateTransition { block }
     }
 }
@@ -360,7 +360,7 @@ impl ReputationScore {
 // =============================================================================
 
 /// Events emitted by the identity system
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum IdentityEvent {
     /// Identity declared
     IdentityDeclared {
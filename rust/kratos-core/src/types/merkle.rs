@@ -68,6 +68,13 @@ pub struct MerkleProof {
 
     /// Chain ID this proof is from
     pub chain_id: ChainId,
+
+    /// Number of leaves in the tree (0 = unknown, assumes a balanced tree)
+    ///
+    /// Needed to verify proofs of unbalanced trees, where the last node of an
+    /// odd level is promoted without a sibling.
+    #[serde(default)]
+    pub leaf_count: usize,
 }
 
 impl MerkleProof {
@@ -86,9 +93,16 @@ impl MerkleProof {
             root,
             block_number,
             chain_id,
+            leaf_count: 0,
         }
     }
 
+    /// Set the number of leaves of the tree the proof comes from
+    pub fn with_leaf_count(mut self, leaf_count: usize) -> Self {
+        self.leaf_count = leaf_count;
+        self
+    }
+
     /// Verify this Merkle proof
     pub fn verify(&self) -> bool {
         // Compute leaf hash
@@ -104,8 +118,20 @@ impl MerkleProof {
     /// Compute root hash from proof path (internal helper)
     fn compute_root_from_proof(&self, mut current_hash: [u8; 32]) -> [u8; 32] {
         let mut index = self.leaf_index;
-
-        for sibling in &self.proof {
+        let mut width = self.leaf_count;
+        let mut siblings = self.proof.iter();
+
+        while width != 1 {
+            // Last node of an odd level: promoted as is
+            if width != 0 && index == width - 1 && width % 2 == 1 {
+                index /= 2;
+                width = width.div_ceil(2);
+                continue;
+            }
+
+            let Some(sibling) = siblings.next() else {
+                break;
+            };
             // Determine if current node is left or right child
             let is_left = index % 2 == 0;
 
@@ -126,6 +152,12 @@ impl MerkleProof {
 
             // Move up the tree
             index /= 2;
+            width = width.div_ceil(2);
+        }
+
+        // Unused sibling hashes mean the proof is for another tree shape
+        if siblings.next().is_some() {
+            return [0u8; 32];
         }
 
         current_hash
@@ -187,7 +219,7 @@ impl StateMerkleTree {
             self.root(),
             block_number,
             chain_id,
-        ))
+        ).with_leaf_count(self.leaves.len()))
     }
 
     /// Verify a proof against this tree
@@ -309,6 +341,18 @@ mod tests {
         assert!(tree.verify_proof(&proof));
     }
 
+    #[test]
+    fn test_merkle_proof_unbalanced_tree() {
+        for size in 1..=9 {
+            let leaves: Vec<Vec<u8>> = (0..size).map(|i| vec![i as u8]).collect();
+            let tree = StateMerkleTree::new(leaves);
+            for index in 0..size {
+                let proof = tree.generate_proof(index, 0, ChainId(0)).unwrap();
+                assert!(proof.verify(), "size {} index {}", size, index);
+            }
+        }
+    }
+
     #[test]
     fn test_state_root_creation() {
         let root_hash = Hash::from_bytes([1; 32]);
@@ -3,9 +3,11 @@
 
 pub mod primitives;
 pub mod signature;
+pub mod batch;
 pub mod account;
 pub mod transaction;
 pub mod block;
+pub mod receipt;
 pub mod chain;
 pub mod merkle;
 pub mod fraud;
@@ -21,9 +23,11 @@ pub mod contributor;
 
 pub use primitives::*;
 pub use signature::*;
+pub use batch::*;
 pub use account::*;
 pub use transaction::*;
 pub use block::*;
+pub use receipt::*;
 pub use chain::*;
 pub use merkle::*;
 pub use fraud::*;
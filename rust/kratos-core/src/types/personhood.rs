@@ -7,7 +7,7 @@
 // - Liveness detection
 
 use crate::types::identity::{IdentityId, ReputationScore, calculate_attestation_weight};
-use crate::types::{AccountId, Balance, BlockNumber, ChainId, Hash};
+use crate::types::{AccountId, Balance, BlockNumber, ChainId, EpochNumber, Hash};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -570,7 +570,7 @@ impl PersonhoodScore {
 // =============================================================================
 
 /// Events emitted by the personhood system
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PersonhoodEvent {
     /// Challenge issued
     ChallengeIssued {
@@ -612,6 +612,20 @@ pub enum PersonhoodEvent {
         old_score: u32,
         new_score: u32,
     },
+
+    /// Commitment to a verification round
+    CeremonyCommitted {
+        who: AccountId,
+        epoch: EpochNumber,
+    },
+
+    /// Reveal matched: the account is a verified person until `expires_at`
+    PersonVerified {
+        who: AccountId,
+        identity_id: IdentityId,
+        epoch: EpochNumber,
+        expires_at: BlockNumber,
+    },
 }
 
 // =============================================================================
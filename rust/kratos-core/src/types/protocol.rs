@@ -47,6 +47,20 @@ pub const MIN_SUPERMAJORITY: u8 = 66;
 /// Maximum supermajority threshold (90%)
 pub const MAX_SUPERMAJORITY: u8 = 90;
 
+/// Inflation floor of the adaptive emission (0% - 2%, in basis points)
+pub const MIN_INFLATION_FLOOR_BPS: u16 = 0;
+pub const MAX_INFLATION_FLOOR_BPS: u16 = 200;
+
+/// Inflation cap of the adaptive emission (1% - 10%, in basis points)
+pub const MIN_INFLATION_CAP_BPS: u16 = 100;
+pub const MAX_INFLATION_CAP_BPS: u16 = 1_000;
+
+/// Minimum share of fees burned (deflationary pressure cannot be removed)
+pub const MIN_FEE_BURN_SHARE: u8 = 10;
+
+/// Minimum share of fees to the block producer
+pub const MIN_FEE_PRODUCER_SHARE: u8 = 20;
+
 /// Minimum voting period (1 day)
 pub const MIN_VOTING_PERIOD: BlockNumber = 14_400;
 /// Maximum voting period (30 days)
@@ -110,6 +124,12 @@ impl<T: Copy + Ord> BoundedValue<T> {
     pub fn is_valid(&self, value: T) -> bool {
         value >= self.min && value <= self.max
     }
+
+    /// Value within bounds, and bounds those of `constitution`
+    /// (deserialized values bypass the clamping of `new`)
+    pub fn conforms_to(&self, constitution: &Self) -> bool {
+        self.min == constitution.min && self.max == constitution.max && self.is_valid(self.value)
+    }
 }
 
 // =============================================================================
@@ -133,6 +153,15 @@ pub struct EconomicsParameters {
 
     /// Stake cap for VRF weighting
     pub stake_cap: Balance,
+
+    /// Split of transaction fees applied by block production
+    pub fee_shares: FeeShares,
+
+    /// Floor of the post-bootstrap adaptive inflation (basis points)
+    pub inflation_floor_bps: BoundedValue<u16>,
+
+    /// Cap of the post-bootstrap adaptive inflation (basis points)
+    pub inflation_cap_bps: BoundedValue<u16>,
 }
 
 impl Default for EconomicsParameters {
@@ -143,10 +172,41 @@ impl Default for EconomicsParameters {
             fee_validator_rate: BoundedValue::new(50, MIN_FEE_BURN_RATE, MAX_FEE_BURN_RATE),
             min_validator_stake: 10_000,
             stake_cap: 1_000_000,
+            fee_shares: FeeShares::default(),
+            inflation_floor_bps: BoundedValue::new(50, MIN_INFLATION_FLOOR_BPS, MAX_INFLATION_FLOOR_BPS),
+            inflation_cap_bps: BoundedValue::new(1_000, MIN_INFLATION_CAP_BPS, MAX_INFLATION_CAP_BPS),
+        }
+    }
+}
+
+/// Split of transaction fees, in percent (SPEC v3.2: 50/10/30/10)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeShares {
+    pub producer: u8,
+    pub finality_voters: u8,
+    pub burn: u8,
+    pub treasury: u8,
+}
+
+impl Default for FeeShares {
+    fn default() -> Self {
+        Self {
+            producer: 50,
+            finality_voters: 10,
+            burn: 30,
+            treasury: 10,
         }
     }
 }
 
+impl FeeShares {
+    /// Shares sum to 100%, with the constitutional minimums for burn and producer
+    pub fn is_valid(&self) -> bool {
+        let total = self.producer as u16 + self.finality_voters as u16 + self.burn as u16 + self.treasury as u16;
+        total == 100 && self.burn >= MIN_FEE_BURN_SHARE && self.producer >= MIN_FEE_PRODUCER_SHARE
+    }
+}
+
 /// Consensus parameters
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsensusParameters {
@@ -363,14 +423,57 @@ impl ProtocolParameters {
             return Err(ParameterError::FeeRatesTooHigh);
         }
 
+        if !self.economics.fee_shares.is_valid() {
+            return Err(ParameterError::InvalidFeeShares);
+        }
+
+        if self.economics.inflation_floor_bps.value() > self.economics.inflation_cap_bps.value() {
+            return Err(ParameterError::InvalidCombination);
+        }
+
         Ok(())
     }
+
+    /// Validate parameters set at genesis by a chain spec
+    ///
+    /// Values may differ from `genesis()` within their constitutional bounds,
+    /// the bounds themselves may not.
+    pub fn validate_genesis(&self) -> Result<(), ParameterError> {
+        let constitution = Self::genesis();
+        let (economics, c_economics) = (&self.economics, &constitution.economics);
+        let (governance, c_governance) = (&self.governance, &constitution.governance);
+        let conforms = economics.inflation_rate.conforms_to(&c_economics.inflation_rate)
+            && economics.fee_burn_rate.conforms_to(&c_economics.fee_burn_rate)
+            && economics.fee_validator_rate.conforms_to(&c_economics.fee_validator_rate)
+            && economics.inflation_floor_bps.conforms_to(&c_economics.inflation_floor_bps)
+            && economics.inflation_cap_bps.conforms_to(&c_economics.inflation_cap_bps)
+            && self.consensus.target_validators.conforms_to(&constitution.consensus.target_validators)
+            && governance.standard_timelock.conforms_to(&c_governance.standard_timelock)
+            && governance.exit_timelock.conforms_to(&c_governance.exit_timelock)
+            && governance.voting_period.conforms_to(&c_governance.voting_period)
+            && governance.min_quorum.conforms_to(&c_governance.min_quorum)
+            && governance.supermajority_threshold.conforms_to(&c_governance.supermajority_threshold);
+        if !conforms {
+            return Err(ParameterError::OutOfBounds);
+        }
+        if !self.version.is_compatible(&constitution.version) {
+            return Err(ParameterError::IncompatibleVersion);
+        }
+        self.validate()
+    }
 }
 
 // =============================================================================
 // PROTOCOL VERSION
 // =============================================================================
 
+/// Newest protocol version this release validates and produces blocks for
+///
+/// Governance schedules upgrades (`ParameterChange::ProtocolUpgrade`); a node
+/// refuses blocks of a newer version, its operator must upgrade before the
+/// activation epoch.
+pub const SUPPORTED_PROTOCOL_VERSION: ProtocolVersion = ProtocolVersion { major: 1, minor: 0, patch: 0 };
+
 /// Protocol version for upgrade tracking
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProtocolVersion {
@@ -427,7 +530,7 @@ impl std::fmt::Display for ProtocolVersion {
 // =============================================================================
 
 /// Types of parameter changes that can be proposed
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ParameterChange {
     /// Change inflation rate
     InflationRate(u8),
@@ -459,6 +562,15 @@ pub enum ParameterChange {
     /// Change VC decay rate
     VcDecayRate(u8),
 
+    /// Change the split of transaction fees
+    FeeShares(FeeShares),
+
+    /// Change the bounds of the post-bootstrap adaptive inflation
+    InflationBounds { floor_bps: u16, cap_bps: u16 },
+
+    /// Upgrade the protocol version blocks are produced and validated with
+    ProtocolUpgrade(ProtocolVersion),
+
     /// Batch of changes (applied atomically)
     Batch(Vec<ParameterChange>),
 }
@@ -495,12 +607,41 @@ impl ParameterChange {
             }
             ParameterChange::ProposalDeposit(_) => true, // No constitutional bound
             ParameterChange::VcDecayRate(v) => *v <= 50, // Max 50% decay per quarter
+            ParameterChange::FeeShares(shares) => shares.is_valid(),
+            ParameterChange::InflationBounds { floor_bps, cap_bps } => {
+                (MIN_INFLATION_FLOOR_BPS..=MAX_INFLATION_FLOOR_BPS).contains(floor_bps)
+                    && (MIN_INFLATION_CAP_BPS..=MAX_INFLATION_CAP_BPS).contains(cap_bps)
+                    && floor_bps <= cap_bps
+            }
+            // Versions only move forward; patch releases keep the block rules
+            ParameterChange::ProtocolUpgrade(v) => v.patch == 0 && v.is_newer(&current.version),
             ParameterChange::Batch(changes) => {
                 changes.iter().all(|c| c.is_constitutional(current))
             }
         }
     }
 
+    /// Block production applies this change (the other parameters are
+    /// recorded but not yet read by the node)
+    pub fn is_enforced(&self) -> bool {
+        match self {
+            ParameterChange::FeeShares(_)
+            | ParameterChange::InflationBounds { .. }
+            | ParameterChange::ProtocolUpgrade(_) => true,
+            ParameterChange::Batch(changes) => changes.iter().all(|c| c.is_enforced()),
+            _ => false,
+        }
+    }
+
+    /// Protocol version this change upgrades to, if any
+    pub fn protocol_upgrade(&self) -> Option<ProtocolVersion> {
+        match self {
+            ParameterChange::ProtocolUpgrade(v) => Some(*v),
+            ParameterChange::Batch(changes) => changes.iter().filter_map(|c| c.protocol_upgrade()).last(),
+            _ => None,
+        }
+    }
+
     /// Apply this change to protocol parameters
     pub fn apply(&self, params: &mut ProtocolParameters) -> Result<(), ParameterError> {
         match self {
@@ -553,6 +694,25 @@ impl ParameterChange {
                 }
                 params.consensus.vc_decay_rate = *v;
             }
+            ParameterChange::FeeShares(shares) => {
+                if !shares.is_valid() {
+                    return Err(ParameterError::InvalidFeeShares);
+                }
+                params.economics.fee_shares = *shares;
+            }
+            ParameterChange::InflationBounds { floor_bps, cap_bps } => {
+                if !params.economics.inflation_floor_bps.try_set(*floor_bps)
+                    || !params.economics.inflation_cap_bps.try_set(*cap_bps)
+                {
+                    return Err(ParameterError::OutOfBounds);
+                }
+            }
+            ParameterChange::ProtocolUpgrade(v) => {
+                if !v.is_newer(&params.version) {
+                    return Err(ParameterError::IncompatibleVersion);
+                }
+                params.version = *v;
+            }
             ParameterChange::Batch(changes) => {
                 for change in changes {
                     change.apply(params)?;
@@ -672,6 +832,8 @@ pub enum ParameterError {
     ExitTimelockTooShort,
     /// Fee rates sum to more than 100%
     FeeRatesTooHigh,
+    /// Fee shares do not sum to 100% or go below a constitutional minimum
+    InvalidFeeShares,
     /// Change violates constitutional axiom
     ConstitutionalViolation(ConstitutionalAxiom),
     /// Change matches constitutional prohibition
@@ -834,6 +996,28 @@ mod tests {
         assert_eq!(params.consensus.target_validators.value(), 61);
     }
 
+    #[test]
+    fn test_enforced_parameter_changes() {
+        let mut params = ProtocolParameters::default();
+        let shares = FeeShares { producer: 45, finality_voters: 10, burn: 30, treasury: 15 };
+
+        assert!(ParameterChange::FeeShares(shares).is_constitutional(&params));
+        assert!(!ParameterChange::FeeShares(FeeShares { burn: 5, treasury: 40, ..shares }).is_constitutional(&params));
+        assert!(!ParameterChange::FeeShares(FeeShares { treasury: 20, ..shares }).is_constitutional(&params));
+        assert!(!ParameterChange::InflationBounds { floor_bps: 150, cap_bps: 100 }.is_constitutional(&params));
+        assert!(!ParameterChange::InflationBounds { floor_bps: 50, cap_bps: 2_000 }.is_constitutional(&params));
+
+        let batch = ParameterChange::Batch(vec![
+            ParameterChange::FeeShares(shares),
+            ParameterChange::InflationBounds { floor_bps: 100, cap_bps: 800 },
+        ]);
+        assert!(batch.is_enforced());
+        assert!(!ParameterChange::InflationRate(3).is_enforced());
+        batch.apply(&mut params).unwrap();
+        assert_eq!(params.economics.fee_shares, shares);
+        assert_eq!((params.economics.inflation_floor_bps.value(), params.economics.inflation_cap_bps.value()), (100, 800));
+    }
+
     #[test]
     fn test_protocol_version() {
         let v1 = ProtocolVersion::new(1, 0, 0);
@@ -902,4 +1086,18 @@ mod tests {
         let params = ProtocolParameters::default();
         assert!(!change.is_constitutional(&params));
     }
+
+    #[test]
+    fn test_validate_genesis() {
+        let mut params = ProtocolParameters::genesis();
+        assert!(params.validate_genesis().is_ok());
+
+        // Values move within their bounds
+        assert!(params.governance.voting_period.try_set(MIN_VOTING_PERIOD));
+        assert!(params.validate_genesis().is_ok());
+
+        // Bounds are the constitution's
+        params.economics.inflation_rate = BoundedValue::new(8, 0, 10);
+        assert_eq!(params.validate_genesis(), Err(ParameterError::OutOfBounds));
+    }
 }
@@ -0,0 +1,310 @@
+// Receipts - Execution outcome of each transaction in a block
+// Principle: Every effect of a transaction is observable and committed in the header
+//
+// Each system contract emits its own typed events. The receipts of a block are
+// merkleized into BlockHeader::receipts_root, so a light client can verify that
+// a transaction succeeded and what it did without re-executing the block.
+
+use super::account::AccountId;
+use super::identity::IdentityEvent;
+use super::personhood::PersonhoodEvent;
+use super::merkle::StateMerkleTree;
+use super::primitives::{Balance, BlockNumber, ChainId, EpochNumber, Hash};
+use super::transaction::SessionKeys;
+use crate::contracts::governance::{ProposalId, Vote};
+use crate::contracts::messaging::MessageId;
+use serde::{Deserialize, Serialize};
+
+// =============================================================================
+// EVENTS
+// =============================================================================
+
+/// Events emitted by the KRAT token contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KratEvent {
+    /// Funds moved between free balances
+    Transfer {
+        from: AccountId,
+        to: AccountId,
+        amount: Balance,
+    },
+    /// Memo of a transfer, emitted after its Transfer event
+    Memo {
+        from: AccountId,
+        to: AccountId,
+        memo: Vec<u8>,
+    },
+    /// Treasury funds paid out by an enacted TreasurySpend proposal
+    TreasuryPayout {
+        proposal_id: ProposalId,
+        recipient: AccountId,
+        amount: Balance,
+    },
+}
+
+/// Events emitted by the staking contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StakingEvent {
+    /// Free balance moved to stake
+    Bonded { who: AccountId, amount: Balance },
+    /// Stake entered the unbonding period
+    UnbondingStarted {
+        who: AccountId,
+        amount: Balance,
+        unlock_at: BlockNumber,
+    },
+    /// Matured unbonded funds returned to the free balance
+    Withdrawn { who: AccountId, amount: Balance },
+    /// Account registered as a validator
+    ValidatorRegistered { who: AccountId, stake: Balance },
+    /// Account left the validator set
+    ValidatorUnregistered { who: AccountId },
+    /// DNS seed receipts accepted for an epoch (credited: uptime credit earned)
+    UptimeAttested {
+        who: AccountId,
+        epoch: EpochNumber,
+        heartbeats: u32,
+        credited: bool,
+    },
+    /// Session keys bound, used for the blocks of `effective_epoch` onward
+    SessionKeysSet {
+        who: AccountId,
+        keys: SessionKeys,
+        effective_epoch: EpochNumber,
+    },
+    /// Equivocation or fraud proven: VC and stake slashed (the stake is
+    /// burned, less the reward of a fraud reporter)
+    Slashed {
+        who: AccountId,
+        reporter: AccountId,
+        offence: Hash,
+        vc_slashed: u64,
+        amount: Balance,
+    },
+    /// Share of a slashed stake paid to the reporter of a fraud
+    ReporterRewarded {
+        who: AccountId,
+        offence: Hash,
+        amount: Balance,
+    },
+    /// Free balance delegated to a validator
+    Delegated {
+        who: AccountId,
+        validator: AccountId,
+        amount: Balance,
+    },
+    /// Delegated stake entered the unbonding period
+    Undelegated {
+        who: AccountId,
+        validator: AccountId,
+        amount: Balance,
+        unlock_at: BlockNumber,
+    },
+    /// Delegation rewards of closed epochs moved to the free balance
+    RewardsClaimed { who: AccountId, amount: Balance },
+    /// Commission announced, in force from `effective_epoch`
+    CommissionAnnounced {
+        who: AccountId,
+        commission: u8,
+        effective_epoch: EpochNumber,
+    },
+}
+
+/// Events emitted by the sidechains contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SidechainEvent {
+    /// Deposit reserved to create a sidechain
+    CreationDepositReserved { who: AccountId, deposit: Balance },
+    /// Account exited a sidechain
+    Exited { who: AccountId, chain_id: ChainId },
+    /// Sidechain registered on creation
+    Created { owner: AccountId, chain_id: ChainId },
+    /// State root of a sidechain committed
+    CheckpointSubmitted {
+        who: AccountId,
+        chain_id: ChainId,
+        state_root: Hash,
+    },
+    /// Account exited a sidechain with a proven balance, paid out of its deposit
+    ExitedWithProof {
+        who: AccountId,
+        chain_id: ChainId,
+        amount: Balance,
+    },
+}
+
+/// Events emitted by governance (forks and early validator voting)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GovernanceEvent {
+    /// Public support signal for a fork
+    ForkSignaled { who: AccountId, name: String },
+    /// Early validator candidate proposed
+    EarlyValidatorProposed {
+        proposer: AccountId,
+        candidate: AccountId,
+    },
+    /// Vote cast for an early validator candidate
+    EarlyValidatorVoted {
+        voter: AccountId,
+        candidate: AccountId,
+    },
+    /// Root chain proposal submitted
+    ProposalSubmitted { proposer: AccountId, proposal_id: ProposalId },
+    /// Proposal endorsed
+    ProposalSeconded { who: AccountId, proposal_id: ProposalId },
+    /// Vote cast on a proposal, `weight` being the voter's staked balance
+    ProposalVoted {
+        voter: AccountId,
+        proposal_id: ProposalId,
+        vote: Vote,
+        weight: Balance,
+    },
+    /// Proposal enacted after its timelock
+    ProposalExecuted { executor: AccountId, proposal_id: ProposalId },
+    /// Emergency declaration co-signed, `signatures` of `required`
+    EmergencyDeclarationSigned { who: AccountId, signatures: u32, required: u32 },
+    /// Emergency declared on chain until `expires_at`
+    EmergencyDeclared { expires_at: BlockNumber },
+    /// Emergency recovery co-signed, `signatures` of `required`
+    EmergencyRecoverySigned { who: AccountId, signatures: u32, required: u32 },
+    /// Declared emergency ended by its recovery
+    EmergencyRecovered,
+}
+
+/// Events emitted by the messaging contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MessagingEvent {
+    /// Message stored in the recipient's inbox under `id`
+    MessageDelivered {
+        id: MessageId,
+        from: AccountId,
+        to: AccountId,
+        size: u32,
+        encrypted: bool,
+    },
+}
+
+/// Event emitted during transaction execution, tagged by contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Event {
+    Krat(KratEvent),
+    Staking(StakingEvent),
+    Sidechains(SidechainEvent),
+    Governance(GovernanceEvent),
+    Identity(IdentityEvent),
+    Personhood(PersonhoodEvent),
+    Messaging(MessagingEvent),
+}
+
+impl Event {
+    /// Name of the emitting contract
+    pub fn contract(&self) -> &'static str {
+        match self {
+            Event::Krat(_) => "krat",
+            Event::Staking(_) => "staking",
+            Event::Sidechains(_) => "sidechains",
+            Event::Governance(_) => "governance",
+            Event::Identity(_) => "identity",
+            Event::Personhood(_) => "personhood",
+            Event::Messaging(_) => "messaging",
+        }
+    }
+
+    /// Name of the event within its contract (Transfer, Bonded, ...)
+    pub fn name(&self) -> String {
+        let debug = match self {
+            Event::Krat(e) => format!("{:?}", e),
+            Event::Staking(e) => format!("{:?}", e),
+            Event::Sidechains(e) => format!("{:?}", e),
+            Event::Governance(e) => format!("{:?}", e),
+            Event::Identity(e) => format!("{:?}", e),
+            Event::Personhood(e) => format!("{:?}", e),
+            Event::Messaging(e) => format!("{:?}", e),
+        };
+        debug.chars().take_while(|c| c.is_ascii_alphanumeric()).collect()
+    }
+}
+
+// =============================================================================
+// RECEIPTS
+// =============================================================================
+
+/// Outcome of one transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Receipt {
+    /// Transaction hash
+    pub tx_hash: Hash,
+    /// Success or failure
+    pub success: bool,
+    /// Fee paid
+    pub fee_paid: Balance,
+    /// Error message if failed
+    pub error: Option<String>,
+    /// Events in emission order
+    pub events: Vec<Event>,
+}
+
+impl Receipt {
+    /// Hash of the receipt (leaf of the receipts root)
+    pub fn hash(&self) -> Hash {
+        Hash::hash(&bincode::serialize(self).unwrap())
+    }
+}
+
+/// Merkle root of a block's receipts (in transaction order)
+pub fn receipts_root(receipts: &[Receipt]) -> Hash {
+    if receipts.is_empty() {
+        return Hash::ZERO;
+    }
+
+    let leaves: Vec<Vec<u8>> = receipts
+        .iter()
+        .map(|r| r.hash().as_bytes().to_vec())
+        .collect();
+    StateMerkleTree::new(leaves).root()
+}
+
+/// Receipt as persisted, with its position in the chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredReceipt {
+    pub receipt: Receipt,
+    pub block_number: BlockNumber,
+    pub block_hash: Hash,
+    /// Index of the transaction in the block body
+    pub index: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receipt(amount: Balance) -> Receipt {
+        Receipt {
+            tx_hash: Hash::hash(&amount.to_le_bytes()),
+            success: true,
+            fee_paid: 1_000,
+            error: None,
+            events: vec![Event::Krat(KratEvent::Transfer {
+                from: AccountId::from_bytes([1; 32]),
+                to: AccountId::from_bytes([2; 32]),
+                amount,
+            })],
+        }
+    }
+
+    #[test]
+    fn test_receipts_root_commits_to_events_and_order() {
+        assert_eq!(receipts_root(&[]), Hash::ZERO);
+
+        let a = receipt(10);
+        let b = receipt(20);
+        let root = receipts_root(&[a.clone(), b.clone()]);
+        assert_ne!(root, Hash::ZERO);
+        assert_eq!(root, receipts_root(&[a.clone(), b.clone()]));
+        assert_ne!(root, receipts_root(&[b.clone(), a.clone()]));
+
+        let mut tampered = b;
+        tampered.events.clear();
+        assert_ne!(root, receipts_root(&[a, tampered]));
+    }
+}
@@ -45,7 +45,7 @@ pub const BASE_ENDORSEMENT_POINTS: u32 = 10;
 // =============================================================================
 
 /// Reputation domains - different areas of contribution
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum ReputationDomain {
     /// Governance participation (voting, proposals)
     Governance,
@@ -105,7 +105,7 @@ impl ReputationDomain {
 // =============================================================================
 
 /// Domain-specific reputation score
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct DomainReputation {
     /// Raw score (0 - MAX_DOMAIN_SCORE)
     pub score: u32,
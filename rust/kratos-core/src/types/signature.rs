@@ -70,6 +70,10 @@ pub const DOMAIN_CROSS_CHAIN_MSG: &[u8] = b"KRATOS_XCHAIN_MSG_V1:";
 /// SECURITY FIX #33: Prevents finality signature replay
 pub const DOMAIN_FINALITY: &[u8] = b"KRATOS_FINALITY_V1:";
 
+/// Domain separator for DNS seed heartbeat receipts
+/// Used when a DNS seed attests that a validator sent it a heartbeat
+pub const DOMAIN_SEED_RECEIPT: &[u8] = b"KRATOS_SEED_RECEIPT_V1:";
+
 /// Create a domain-separated message for signing
 ///
 /// # Arguments
@@ -1,7 +1,11 @@
 // Transaction - Types de transactions L0 (minimales)
 use super::account::AccountId;
-use super::primitives::{Balance, ChainId, Hash, Nonce};
-use super::signature::{Signature64, domain_separate, DOMAIN_TRANSACTION};
+use super::merkle::MerkleProof;
+use super::batch::{verify_all, SignatureItem};
+use super::primitives::{Balance, BlockNumber, ChainId, EpochNumber, Hash, Nonce, Timestamp};
+use super::signature::{Signature64, domain_separate, DOMAIN_SEED_RECEIPT, DOMAIN_TRANSACTION};
+use crate::contracts::governance::{ProposalId, ProposalType, Vote};
+use crate::contracts::messaging::MESSAGE_BYTE_FEE;
 use serde::{Deserialize, Serialize};
 
 /// Transaction signée
@@ -45,6 +49,25 @@ impl SignedTransaction {
             .verify(&message, self.signature.as_bytes())
     }
 
+    /// Vérifie les signatures d'un lot de transactions (batch, multi-thread)
+    ///
+    /// Same result as calling `verify` on each transaction, but the signatures
+    /// are checked in Ed25519 batches. Returns Err with the index of the
+    /// offending transaction.
+    pub fn verify_batch(txs: &[SignedTransaction]) -> Result<(), usize> {
+        let mut messages = Vec::with_capacity(txs.len());
+        for (index, tx) in txs.iter().enumerate() {
+            messages.push(Self::signing_message(&tx.transaction).ok_or(index)?);
+        }
+
+        let items: Vec<SignatureItem<'_>> = txs
+            .iter()
+            .zip(&messages)
+            .map(|(tx, message)| SignatureItem::new(tx.transaction.sender, message, &tx.signature))
+            .collect();
+        verify_all(&items)
+    }
+
     /// Create signing message for a transaction (with domain separation)
     /// SECURITY FIX #27: Use this method when signing transactions
     pub fn signing_message(transaction: &Transaction) -> Option<Vec<u8>> {
@@ -58,6 +81,10 @@ impl SignedTransaction {
     }
 }
 
+/// Validity window recommended to wallets (1 hour of 6 s slots): a transfer
+/// not included by then can't be mined later
+pub const DEFAULT_MORTALITY_BLOCKS: BlockNumber = 600;
+
 /// Transaction non signée (Inner)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Transaction {
@@ -72,10 +99,28 @@ pub struct Transaction {
 
     /// Timestamp de création (optionnel, pour tri)
     pub timestamp: u64,
+
+    /// Genesis hash of the chain the transaction is for (signed with the
+    /// rest: no replay between networks)
+    pub genesis_hash: Hash,
+
+    /// Last block the transaction may be included in (None: immortal)
+    #[serde(default)]
+    pub valid_until: Option<BlockNumber>,
+
+    /// Most the sender agrees to pay in fees, the base fee may rise up to
+    /// MAX_BASE_FEE between signing and inclusion (None: no limit)
+    #[serde(default)]
+    pub max_fee: Option<Balance>,
+
+    /// Priority fee paid on top of the base fee, ranks the transaction in the
+    /// mempool (a higher tip replaces a pending transaction of the same nonce)
+    #[serde(default)]
+    pub tip: Balance,
 }
 
 impl Transaction {
-    pub fn new(sender: AccountId, nonce: Nonce, call: TransactionCall) -> Self {
+    pub fn new(sender: AccountId, nonce: Nonce, call: TransactionCall, genesis_hash: Hash) -> Self {
         Self {
             sender,
             nonce,
@@ -84,9 +129,41 @@ impl Transaction {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
+            genesis_hash,
+            valid_until: None,
+            max_fee: None,
+            tip: 0,
         }
     }
 
+    /// Whether the transaction may be included in block `number`
+    pub fn is_valid_at(&self, number: BlockNumber) -> bool {
+        self.valid_until.is_none_or(|last| number <= last)
+    }
+
+    /// Valid until block `valid_until` included
+    pub fn mortal(mut self, valid_until: BlockNumber) -> Self {
+        self.valid_until = Some(valid_until);
+        self
+    }
+
+    /// Whether the sender agreed to pay `fee`
+    pub fn accepts_fee(&self, fee: Balance) -> bool {
+        self.max_fee.is_none_or(|max| fee <= max)
+    }
+
+    /// Pays at most `max_fee` in fees
+    pub fn with_max_fee(mut self, max_fee: Balance) -> Self {
+        self.max_fee = Some(max_fee);
+        self
+    }
+
+    /// Pays `tip` on top of the base fee
+    pub fn with_tip(mut self, tip: Balance) -> Self {
+        self.tip = tip;
+        self
+    }
+
     /// Hash de la transaction
     /// SECURITY FIX #28: Safe serialization without panic
     pub fn hash(&self) -> Hash {
@@ -168,6 +245,8 @@ pub enum TransactionCall {
     ProposeEarlyValidator {
         /// Candidate account to propose
         candidate: AccountId,
+        /// Optional public metadata shown to voters
+        metadata: CandidateMetadata,
     },
 
     /// Vote for an early validator candidate
@@ -176,6 +255,198 @@ pub enum TransactionCall {
         /// Candidate to vote for
         candidate: AccountId,
     },
+
+    // =========================================================================
+    // UPTIME ATTESTATION
+    // =========================================================================
+
+    /// Heartbeat receipts signed by DNS seeds for a completed epoch
+    /// Optional input to uptime credits, see consensus::seed_attestation
+    SubmitSeedAttestation {
+        /// Epoch the receipts cover (the previous one)
+        epoch: EpochNumber,
+        /// Receipts for the sender's heartbeats
+        receipts: Vec<SeedReceipt>,
+    },
+
+    // =========================================================================
+    // PAYMENT REFERENCES
+    // =========================================================================
+
+    /// Transfer with a memo (payment reference for exchanges and merchants)
+    /// Separate variant so that the encoding of plain transfers is unchanged
+    TransferWithMemo {
+        to: AccountId,
+        amount: Balance,
+        /// 1 to MAX_MEMO_LEN bytes, charged MEMO_BYTE_FEE per byte
+        memo: Vec<u8>,
+    },
+
+    // =========================================================================
+    // SESSION KEYS
+    // =========================================================================
+
+    /// Bind new session keys to the sender's validator account
+    /// Effective from the next epoch, see consensus::session_keys
+    SetSessionKeys {
+        keys: SessionKeys,
+    },
+
+    // =========================================================================
+    // SLASHING EVIDENCE
+    // =========================================================================
+
+    /// Proof that a validator equivocated (double proposal or double vote)
+    /// Encoded EquivocationEvidence, see consensus::evidence
+    ReportEquivocation {
+        /// At most MAX_EVIDENCE_SIZE bytes
+        evidence: Vec<u8>,
+    },
+
+    // =========================================================================
+    // DELEGATION
+    // =========================================================================
+
+    /// Delegate stake to a validator and share its block rewards
+    /// See contracts::staking::DelegationPool
+    Delegate {
+        validator: AccountId,
+        amount: Balance,
+    },
+
+    /// Withdraw delegated stake (unbonding period applies)
+    Undelegate {
+        validator: AccountId,
+        amount: Balance,
+    },
+
+    /// Move the delegation rewards of closed epochs to the free balance
+    /// See contracts::staking::RewardLedger
+    ClaimRewards,
+
+    /// Announce the validator's commission for the next epoch (percent)
+    /// See contracts::staking::CommissionRecord
+    SetCommission {
+        commission: u8,
+    },
+
+    // =========================================================================
+    // GOVERNANCE PROPOSALS
+    // =========================================================================
+
+    /// Submit a root chain proposal (PROPOSAL_DEPOSIT is burned)
+    /// See contracts::governance::validate_proposal
+    SubmitProposal {
+        proposal_type: ProposalType,
+        description: Option<String>,
+    },
+
+    /// Endorse a proposal (MIN_SECONDS needed for it to pass)
+    SecondProposal {
+        proposal_id: ProposalId,
+    },
+
+    /// Vote on a proposal with the sender's staked balance
+    VoteProposal {
+        proposal_id: ProposalId,
+        vote: Vote,
+    },
+
+    /// Enact a proposal whose timelock has run out (anyone may send it)
+    /// A TreasurySpend pays its recipient from the treasury account
+    ExecuteProposal {
+        proposal_id: ProposalId,
+    },
+
+    // =========================================================================
+    // IDENTITY
+    // =========================================================================
+
+    /// Register the sender's identity (IDENTITY_DEPOSIT is held until revoked)
+    /// See contracts::identity::validate_registration
+    RegisterIdentity {
+        display_name: Option<String>,
+        contact_hashes: Vec<Hash>,
+    },
+
+    /// Vouch for another account's identity (stakers and active identities)
+    AttestIdentity {
+        owner: AccountId,
+        claim_hash: Hash,
+    },
+
+    /// Revoke the sender's identity and get the deposit back
+    RevokeIdentity,
+
+    // =========================================================================
+    // PERSONHOOD
+    // =========================================================================
+
+    /// Join the verification round of the current epoch (commit phase, active identity)
+    /// `commitment` is contracts::personhood::ceremony_commitment(sender, secret)
+    CommitPersonhood {
+        commitment: Hash,
+    },
+
+    /// Reveal the ceremony secret (reveal phase of the same epoch)
+    RevealPersonhood {
+        secret: Hash,
+    },
+
+    // =========================================================================
+    // MESSAGING
+    // =========================================================================
+
+    /// Deliver a message to another account's inbox
+    /// See contracts::messaging::validate_account_message; `encrypted` marks
+    /// a body the recipient's wallet must decrypt
+    SendMessage {
+        to: AccountId,
+        body: Vec<u8>,
+        encrypted: bool,
+    },
+
+    // =========================================================================
+    // EMERGENCY
+    // =========================================================================
+
+    /// Co-sign the declaration of an emergency (validators only)
+    /// See contracts::emergency::EmergencyDeclaration
+    SignEmergencyDeclaration,
+
+    /// Co-sign the recovery from the declared emergency (validators only)
+    SignEmergencyRecovery,
+
+    // =========================================================================
+    // FRAUD PROOFS
+    // =========================================================================
+
+    /// Proof that a validator signed an invalid block
+    /// Encoded FraudProof, see consensus::fraud
+    SubmitFraudProof {
+        /// At most MAX_FRAUD_PROOF_SIZE bytes
+        proof: Vec<u8>,
+    },
+
+    // =========================================================================
+    // SIDECHAIN CHECKPOINTS
+    // =========================================================================
+
+    /// Commit the state root of a sidechain (owner or validators only)
+    /// See contracts::sidechains::record_checkpoint
+    SubmitSidechainCheckpoint {
+        chain_id: ChainId,
+        /// Root of the sidechain's exit_leaf tree
+        state_root: Hash,
+    },
+
+    /// Exit a sidechain with `balance`, proven against its latest checkpoint
+    /// Credited to the sender out of the chain's deposit
+    ExitSidechainWithProof {
+        chain_id: ChainId,
+        balance: Balance,
+        proof: MerkleProof,
+    },
 }
 
 impl TransactionCall {
@@ -195,10 +466,74 @@ impl TransactionCall {
             // Low fees to encourage participation in decentralization
             TransactionCall::ProposeEarlyValidator { .. } => 50_000, // 0.00005 KRAT
             TransactionCall::VoteEarlyValidator { .. } => 10_000,    // 0.00001 KRAT
+            TransactionCall::SubmitSeedAttestation { .. } => 2_000,
+            TransactionCall::SetSessionKeys { .. } => 10_000,
+            TransactionCall::ReportEquivocation { .. } => 10_000,
+            TransactionCall::SubmitFraudProof { .. } => 10_000,
+            TransactionCall::SubmitSidechainCheckpoint { .. } => 10_000,
+            TransactionCall::ExitSidechainWithProof { .. } => 500_000,
+            TransactionCall::Delegate { .. } => 5_000,
+            TransactionCall::Undelegate { .. } => 5_000,
+            TransactionCall::ClaimRewards => 2_000,
+            TransactionCall::SetCommission { .. } => 10_000,
+            TransactionCall::SubmitProposal { .. } => 100_000,
+            TransactionCall::SecondProposal { .. } => 5_000,
+            TransactionCall::VoteProposal { .. } => 10_000,
+            TransactionCall::ExecuteProposal { .. } => 10_000,
+            TransactionCall::RegisterIdentity { .. } => 10_000,
+            TransactionCall::AttestIdentity { .. } => 5_000,
+            TransactionCall::RevokeIdentity => 5_000,
+            TransactionCall::CommitPersonhood { .. } => 5_000,
+            TransactionCall::RevealPersonhood { .. } => 5_000,
+            TransactionCall::SignEmergencyDeclaration => 10_000,
+            TransactionCall::SignEmergencyRecovery => 10_000,
+            // Transfer fee plus the body size
+            TransactionCall::SendMessage { body, .. } => {
+                1_000 + (body.len() as Balance).saturating_mul(MESSAGE_BYTE_FEE)
+            }
+            // Transfer fee plus the memo size
+            TransactionCall::TransferWithMemo { memo, .. } => {
+                1_000 + (memo.len() as Balance).saturating_mul(MEMO_BYTE_FEE)
+            }
+        }
+    }
+
+    /// Recipient and amount of a transfer (with or without memo)
+    pub fn transfer(&self) -> Option<(AccountId, Balance)> {
+        match self {
+            TransactionCall::Transfer { to, amount } | TransactionCall::TransferWithMemo { to, amount, .. } => {
+                Some((*to, *amount))
+            }
+            _ => None,
+        }
+    }
+
+    /// Memo of a transfer
+    pub fn memo(&self) -> Option<&[u8]> {
+        match self {
+            TransactionCall::TransferWithMemo { memo, .. } => Some(memo),
+            _ => None,
         }
     }
 }
 
+/// Maximum length of a transfer memo (bytes)
+pub const MAX_MEMO_LEN: usize = 128;
+
+/// Fee per memo byte, on top of the transfer fee
+pub const MEMO_BYTE_FEE: Balance = 100;
+
+/// Check the length of a transfer memo
+pub fn validate_memo(memo: &[u8]) -> Result<(), &'static str> {
+    if memo.is_empty() {
+        return Err("Memo is empty");
+    }
+    if memo.len() > MAX_MEMO_LEN {
+        return Err("Memo too long");
+    }
+    Ok(())
+}
+
 /// Métadonnées minimales d'une sidechain
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SidechainMetadata {
@@ -212,6 +547,119 @@ pub struct SidechainMetadata {
     pub parent_chain: Option<ChainId>,
 }
 
+/// Maximum length of a candidate display name (bytes)
+pub const MAX_CANDIDATE_NAME_LEN: usize = 64;
+
+/// Maximum length of a candidate contact URL (bytes)
+pub const MAX_CANDIDATE_URL_LEN: usize = 256;
+
+/// Public metadata attached to an early validator proposal
+/// Informational only: voters use it to identify the candidate operator
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CandidateMetadata {
+    /// Display name (optionnel)
+    pub display_name: Option<String>,
+
+    /// Contact URL (optionnel)
+    pub url: Option<String>,
+}
+
+impl CandidateMetadata {
+    /// Check length limits and URL scheme
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if let Some(name) = &self.display_name {
+            if name.trim().is_empty() {
+                return Err("Display name is empty");
+            }
+            if name.len() > MAX_CANDIDATE_NAME_LEN {
+                return Err("Display name too long");
+            }
+            if name.chars().any(|c| c.is_control()) {
+                return Err("Display name contains control characters");
+            }
+        }
+
+        if let Some(url) = &self.url {
+            if url.len() > MAX_CANDIDATE_URL_LEN {
+                return Err("URL too long");
+            }
+            if !(url.starts_with("https://") || url.starts_with("http://")) {
+                return Err("URL must start with http:// or https://");
+            }
+            if url.chars().any(|c| c.is_control() || c.is_whitespace()) {
+                return Err("URL contains invalid characters");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Keys a validator signs with on behalf of its account
+/// Public keys only; the secrets stay in the node keystore (`sess` keys)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SessionKeys {
+    /// Ed25519 key signing the validator's block headers
+    pub block: AccountId,
+}
+
+impl SessionKeys {
+    /// Encoded length (author_rotateKeys returns the keys concatenated)
+    pub const ENCODED_LEN: usize = 32;
+
+    /// Public keys concatenated, in field order
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.block.as_bytes().to_vec()
+    }
+
+    /// Parse keys encoded by `to_bytes`
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let block: [u8; 32] = bytes.try_into().ok()?;
+        Some(Self { block: AccountId::from_bytes(block) })
+    }
+}
+
+/// Receipt signed by a DNS seed for one validator heartbeat
+/// Même format que le reçu du protocole heartbeat (kratos-dns-seed)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeedReceipt {
+    /// Seed ID (Ed25519 public key of the seed)
+    pub seed: AccountId,
+
+    /// Validator whose heartbeat was received
+    pub validator: AccountId,
+
+    /// Genesis of the chain the heartbeat was for
+    pub genesis_hash: Hash,
+
+    /// Height reported in the heartbeat
+    pub height: BlockNumber,
+
+    /// Seed clock when the heartbeat was received
+    pub timestamp: Timestamp,
+
+    /// Seed signature
+    pub signature: Signature64,
+}
+
+impl SeedReceipt {
+    /// Signed message (with domain separation)
+    pub fn signing_message(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(112);
+        data.extend_from_slice(self.seed.as_bytes());
+        data.extend_from_slice(self.validator.as_bytes());
+        data.extend_from_slice(self.genesis_hash.as_bytes());
+        data.extend_from_slice(&self.height.to_le_bytes());
+        data.extend_from_slice(&self.timestamp.to_le_bytes());
+        domain_separate(DOMAIN_SEED_RECEIPT, &data)
+    }
+
+    /// Vérifie la signature du seed
+    pub fn verify(&self) -> bool {
+        self.seed.verify(&self.signing_message(), self.signature.as_bytes())
+    }
+}
+
 /// Résultat d'exécution d'une transaction
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransactionResult {
@@ -243,6 +691,7 @@ mod tests {
                 to: AccountId::from_bytes([2u8; 32]),
                 amount: 1000,
             },
+            Hash::ZERO,
         );
 
         let hash1 = tx1.hash();
@@ -250,6 +699,19 @@ mod tests {
         assert_eq!(hash1, hash2);
     }
 
+    #[test]
+    fn test_transaction_mortality() {
+        let call = TransactionCall::Transfer { to: AccountId::from_bytes([2u8; 32]), amount: 1000 };
+        let immortal = Transaction::new(AccountId::from_bytes([1u8; 32]), 0, call, Hash::ZERO);
+        assert!(immortal.is_valid_at(BlockNumber::MAX));
+
+        let mortal = immortal.clone().mortal(100);
+        assert!(mortal.is_valid_at(100));
+        assert!(!mortal.is_valid_at(101));
+        // Signed with the rest
+        assert_ne!(mortal.hash(), immortal.hash());
+    }
+
     #[test]
     fn test_base_fees() {
         assert_eq!(
@@ -266,4 +728,41 @@ mod tests {
             100_000
         );
     }
+
+    #[test]
+    fn test_transfer_memo() {
+        let to = AccountId::from_bytes([2; 32]);
+        let call = TransactionCall::TransferWithMemo { to, amount: 100, memo: b"INV-2026-0042".to_vec() };
+        assert_eq!(call.base_fee(), 1_000 + 13 * MEMO_BYTE_FEE);
+        assert_eq!(call.transfer(), Some((to, 100)));
+        assert_eq!(call.memo(), Some(&b"INV-2026-0042"[..]));
+        assert!(TransactionCall::Transfer { to, amount: 100 }.memo().is_none());
+
+        assert!(validate_memo(&[0xff; MAX_MEMO_LEN]).is_ok());
+        assert!(validate_memo(&[0xff; MAX_MEMO_LEN + 1]).is_err());
+        assert!(validate_memo(&[]).is_err());
+    }
+
+    #[test]
+    fn test_candidate_metadata_validation() {
+        assert!(CandidateMetadata::default().validate().is_ok());
+
+        let valid = CandidateMetadata {
+            display_name: Some("Alice Node".to_string()),
+            url: Some("https://alice.example".to_string()),
+        };
+        assert!(valid.validate().is_ok());
+
+        let long_name = CandidateMetadata {
+            display_name: Some("x".repeat(MAX_CANDIDATE_NAME_LEN + 1)),
+            url: None,
+        };
+        assert!(long_name.validate().is_err());
+
+        let bad_scheme = CandidateMetadata {
+            display_name: None,
+            url: Some("javascript:alert(1)".to_string()),
+        };
+        assert!(bad_scheme.validate().is_err());
+    }
 }
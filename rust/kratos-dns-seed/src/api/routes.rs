@@ -1,14 +1,15 @@
 //! API Routes
 //!
-//! HTTP endpoints for metrics, health checks, and IDpeers.json
+//! HTTP endpoints for metrics, health checks, IDpeers.json and blocklist.json
 
 use axum::{
-    extract::State,
+    extract::{Query, State},
     http::{StatusCode, header},
     response::IntoResponse,
     routing::get,
     Json, Router,
 };
+use serde::Deserialize;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::info;
@@ -18,6 +19,7 @@ use crate::config::DnsSeedConfig;
 use crate::distribution::IdPeersGenerator;
 use crate::network_state::NetworkStateAggregator;
 use crate::registry::PeerRegistry;
+use crate::types::Capabilities;
 
 /// Shared API state
 pub struct ApiState {
@@ -53,6 +55,9 @@ pub async fn run_api_server(
         .route("/idpeers.json", get(get_idpeers))
         .route("/IDpeers.json", get(get_idpeers))
 
+        // Signed blocklist (subscribed to by other seeds)
+        .route("/blocklist.json", get(get_blocklist))
+
         // Metrics
         .route("/metrics", get(get_metrics_prometheus))
         .route("/metrics/json", get(get_metrics_json))
@@ -72,6 +77,32 @@ pub async fn run_api_server(
     Ok(())
 }
 
+/// Query string of the peer list endpoints
+#[derive(Debug, Default, Deserialize)]
+pub struct PeerFilter {
+    /// Comma-separated capability names (e.g. "warp-sync,archive")
+    pub capability: Option<String>,
+}
+
+impl PeerFilter {
+    /// Capabilities every returned peer must offer
+    fn required(&self) -> Result<Capabilities, String> {
+        self.capability
+            .as_deref()
+            .map(Capabilities::parse_list)
+            .unwrap_or(Ok(Capabilities::NONE))
+    }
+}
+
+/// 400 response for an unknown capability name
+fn bad_filter(error: String) -> (StatusCode, [(header::HeaderName, &'static str); 1], Vec<u8>) {
+    (
+        StatusCode::BAD_REQUEST,
+        [(header::CONTENT_TYPE, "application/json")],
+        serde_json::to_vec(&serde_json::json!({ "error": error })).unwrap_or_default(),
+    )
+}
+
 /// GET /health - Simple health check
 async fn health_check() -> impl IntoResponse {
     "OK"
@@ -90,6 +121,7 @@ async fn get_status(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
         "network": {
             "active_peers": reg.active_peer_count(),
             "total_peers": reg.total_peer_count(),
+            "blocklisted": reg.blocklisted_count(),
             "active_validators": current.active_validators,
             "best_height": current.best_height,
             "security_state": format!("{:?}", current.security_state),
@@ -100,9 +132,34 @@ async fn get_status(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
 }
 
 /// GET /idpeers.json - Get the signed peer list
-async fn get_idpeers(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
+///
+/// `?capability=warp-sync` returns a freshly signed file restricted to
+/// peers offering the listed capabilities.
+async fn get_idpeers(
+    State(state): State<Arc<ApiState>>,
+    Query(filter): Query<PeerFilter>,
+) -> impl IntoResponse {
     state.metrics.inc_idpeers_downloads();
 
+    let required = match filter.required() {
+        Ok(required) => required,
+        Err(e) => return bad_filter(e),
+    };
+
+    if !required.is_empty() {
+        let file = {
+            let reg = state.registry.read().await;
+            let net = state.network_state.read().await;
+            let gen = state.generator.read().await;
+            gen.generate_with_capabilities(&reg, &net, required)
+        };
+        return (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, "application/json")],
+            serde_json::to_vec_pretty(&file).unwrap_or_default(),
+        );
+    }
+
     // Try cache first
     {
         let gen = state.generator.read().await;
@@ -143,6 +200,21 @@ async fn get_idpeers(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
     }
 }
 
+/// GET /blocklist.json - This seed's signed blocklist
+async fn get_blocklist(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
+    let doc = {
+        let reg = state.registry.read().await;
+        let gen = state.generator.read().await;
+        gen.blocklist_document(&reg)
+    };
+
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        serde_json::to_vec_pretty(&doc).unwrap_or_default(),
+    )
+}
+
 /// GET /metrics - Prometheus format metrics
 async fn get_metrics_prometheus(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
     // Update metrics from registry
@@ -201,13 +273,22 @@ async fn get_network_info(State(state): State<Arc<ApiState>>) -> impl IntoRespon
     Json(info)
 }
 
-/// GET /peers - List of active peers (limited info)
-async fn get_peers(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
+/// GET /peers - List of active peers (limited info), optionally `?capability=`
+async fn get_peers(
+    State(state): State<Arc<ApiState>>,
+    Query(filter): Query<PeerFilter>,
+) -> impl IntoResponse {
+    let required = match filter.required() {
+        Ok(required) => required,
+        Err(e) => return bad_filter(e),
+    };
+
     let reg = state.registry.read().await;
     let timeout = state.config.peer_timeout_secs;
 
     let peers: Vec<_> = reg.get_active_peers(timeout)
         .iter()
+        .filter(|p| p.capabilities.contains(required))
         .take(50) // Limit to 50 peers
         .map(|p| serde_json::json!({
             "peer_id": hex::encode(&p.peer_id[..8]), // Shortened
@@ -216,17 +297,33 @@ async fn get_peers(State(state): State<Arc<ApiState>>) -> impl IntoResponse {
             "is_validator": p.is_validator,
             "score": p.score,
             "region": p.region,
+            "capabilities": p.capabilities.names(),
         }))
         .collect();
 
-    Json(serde_json::json!({
-        "count": peers.len(),
-        "peers": peers,
-    }))
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "application/json")],
+        serde_json::to_vec(&serde_json::json!({
+            "count": peers.len(),
+            "peers": peers,
+        }))
+        .unwrap_or_default(),
+    )
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peer_filter() {
+        let filter = PeerFilter { capability: Some("warp-sync,rpc".to_string()) };
+        assert_eq!(filter.required().unwrap(), Capabilities::WARP_SYNC | Capabilities::RPC);
+        assert!(PeerFilter::default().required().unwrap().is_empty());
+        assert!(PeerFilter { capability: Some("bogus".to_string()) }.required().is_err());
+    }
+
     #[test]
     fn test_status_format() {
         let status = serde_json::json!({
@@ -0,0 +1,429 @@
+//! Load-Test Harness (`kratos-dns-seed bench`)
+//!
+//! Simulates a population of nodes sending signed heartbeats and a stream of
+//! DNS queries against a running seed, then reports latency percentiles and
+//! how the rate limiter treated the traffic. Use it to size an instance
+//! before exposing it to the real network.
+//!
+//! ## Caveats
+//!
+//! - Simulated peers are registered like real ones (with addresses in the
+//!   198.18.0.0/15 benchmarking range): run it against a staging seed, or
+//!   wait for them to go stale afterwards.
+//! - The rate limiter is per source IP. From a single host, most heartbeats
+//!   beyond `rate_limit_per_minute` are rejected and the host ends up banned;
+//!   this is expected and is what the "rate limited" line measures.
+
+use clap::Args;
+use ed25519_dalek::SigningKey;
+use rand::Rng;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::sync::Mutex;
+use tracing::info;
+
+use crate::crypto;
+use crate::types::{Capabilities, HeartbeatMessage, HeartbeatResponse};
+
+/// Heartbeat version sent by the simulated nodes
+const BENCH_HEARTBEAT_VERSION: u32 = 2;
+
+/// Largest response accepted from the seed
+const MAX_RESPONSE_SIZE: usize = 64 * 1024;
+
+/// Options of the `bench` subcommand
+#[derive(Args, Debug, Clone)]
+pub struct BenchArgs {
+    /// Address of the seed under test
+    #[arg(long, default_value = "127.0.0.1")]
+    pub target: IpAddr,
+
+    /// Heartbeat port of the seed
+    #[arg(long, default_value = "30334")]
+    pub heartbeat_port: u16,
+
+    /// DNS port of the seed
+    #[arg(long, default_value = "5353")]
+    pub dns_port: u16,
+
+    /// Domain to query
+    #[arg(long, default_value = "seed.kratos.network")]
+    pub domain: String,
+
+    /// Number of simulated nodes
+    #[arg(long, default_value = "1000")]
+    pub clients: usize,
+
+    /// Heartbeat interval of each simulated node (seconds)
+    #[arg(long, default_value = "10")]
+    pub heartbeat_interval_secs: u64,
+
+    /// DNS queries per second (0 disables DNS load)
+    #[arg(long, default_value = "200")]
+    pub dns_qps: u32,
+
+    /// Test duration (seconds)
+    #[arg(long, default_value = "30")]
+    pub duration_secs: u64,
+
+    /// Per-request timeout (milliseconds)
+    #[arg(long, default_value = "2000")]
+    pub timeout_ms: u64,
+
+    /// Genesis hash sent in heartbeats (must match the seed's, if it checks it)
+    #[arg(long)]
+    pub genesis_hash: Option<String>,
+}
+
+/// Latency samples of one kind of request
+#[derive(Debug, Default)]
+pub struct LatencyStats {
+    samples: Vec<Duration>,
+}
+
+impl LatencyStats {
+    pub fn record(&mut self, latency: Duration) {
+        self.samples.push(latency);
+    }
+
+    pub fn count(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Latency below which `pct` percent of the samples fall (nearest rank)
+    pub fn percentile(&self, pct: f64) -> Option<Duration> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let mut sorted = self.samples.clone();
+        sorted.sort();
+        let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+        Some(sorted[rank.clamp(1, sorted.len()) - 1])
+    }
+
+    fn summary(&self) -> String {
+        let fmt = |pct| {
+            self.percentile(pct)
+                .map(|d| format!("{:.1}ms", d.as_secs_f64() * 1000.0))
+                .unwrap_or_else(|| "-".to_string())
+        };
+        format!("p50 {}  p90 {}  p99 {}  max {}", fmt(50.0), fmt(90.0), fmt(99.0), fmt(100.0))
+    }
+}
+
+/// Outcome counters of the run
+#[derive(Debug, Default)]
+struct BenchReport {
+    heartbeat_latency: LatencyStats,
+    heartbeats_accepted: u64,
+    heartbeats_rate_limited: u64,
+    heartbeats_rejected: u64,
+    heartbeats_failed: u64,
+
+    dns_latency: LatencyStats,
+    dns_answers: u64,
+    dns_empty: u64,
+    dns_failed: u64,
+}
+
+/// Result of one heartbeat round trip
+enum HeartbeatOutcome {
+    Accepted,
+    RateLimited,
+    Rejected,
+}
+
+/// Run the load test and print the report
+pub async fn run(args: BenchArgs) -> anyhow::Result<()> {
+    let genesis_hash = match &args.genesis_hash {
+        Some(h) => crypto::hex_to_hash(h)?,
+        None => [0u8; 32],
+    };
+    let timeout = Duration::from_millis(args.timeout_ms);
+    let duration = Duration::from_secs(args.duration_secs);
+    let report = Arc::new(Mutex::new(BenchReport::default()));
+
+    info!(
+        "🏋️  Benchmarking {} for {}s: {} nodes (heartbeat every {}s), {} DNS queries/s",
+        args.target, args.duration_secs, args.clients, args.heartbeat_interval_secs, args.dns_qps
+    );
+
+    let started = Instant::now();
+    let mut tasks = Vec::with_capacity(args.clients + 1);
+
+    let heartbeat_addr = SocketAddr::new(args.target, args.heartbeat_port);
+    let interval = Duration::from_secs(args.heartbeat_interval_secs.max(1));
+    for index in 0..args.clients {
+        let report = report.clone();
+        tasks.push(tokio::spawn(async move {
+            // Spread the first heartbeats over one interval
+            let offset = rand::thread_rng().gen_range(0..interval.as_millis() as u64);
+            tokio::time::sleep(Duration::from_millis(offset)).await;
+
+            let node = SimulatedNode::new(index, genesis_hash);
+            while started.elapsed() < duration {
+                let sent = Instant::now();
+                let outcome = node.send_heartbeat(heartbeat_addr, timeout).await;
+                let latency = sent.elapsed();
+
+                let mut report = report.lock().await;
+                match outcome {
+                    Ok(outcome) => {
+                        report.heartbeat_latency.record(latency);
+                        match outcome {
+                            HeartbeatOutcome::Accepted => report.heartbeats_accepted += 1,
+                            HeartbeatOutcome::RateLimited => report.heartbeats_rate_limited += 1,
+                            HeartbeatOutcome::Rejected => report.heartbeats_rejected += 1,
+                        }
+                    }
+                    Err(_) => report.heartbeats_failed += 1,
+                }
+                drop(report);
+
+                tokio::time::sleep(interval.saturating_sub(latency)).await;
+            }
+        }));
+    }
+
+    if args.dns_qps > 0 {
+        let report = report.clone();
+        let dns_addr = SocketAddr::new(args.target, args.dns_port);
+        let domain = args.domain.clone();
+        let qps = args.dns_qps;
+        tasks.push(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(Duration::from_secs(1) / qps);
+            let mut queries = Vec::new();
+            let mut id: u16 = 0;
+            while started.elapsed() < duration {
+                ticker.tick().await;
+                id = id.wrapping_add(1);
+                let report = report.clone();
+                let query = build_query(id, &domain);
+                queries.push(tokio::spawn(async move {
+                    let sent = Instant::now();
+                    let result = dns_round_trip(dns_addr, &query, timeout).await;
+                    let mut report = report.lock().await;
+                    match result {
+                        Ok(answers) => {
+                            report.dns_latency.record(sent.elapsed());
+                            if answers > 0 {
+                                report.dns_answers += 1;
+                            } else {
+                                report.dns_empty += 1;
+                            }
+                        }
+                        Err(_) => report.dns_failed += 1,
+                    }
+                }));
+            }
+            for query in queries {
+                let _ = query.await;
+            }
+        }));
+    }
+
+    for task in tasks {
+        let _ = task.await;
+    }
+
+    let elapsed = started.elapsed().as_secs_f64();
+    let report = report.lock().await;
+    print_report(&report, elapsed);
+    Ok(())
+}
+
+/// A fake node with its own identity
+struct SimulatedNode {
+    keypair: SigningKey,
+    libp2p_peer_id: String,
+    address: String,
+    genesis_hash: [u8; 32],
+}
+
+impl SimulatedNode {
+    fn new(index: usize, genesis_hash: [u8; 32]) -> Self {
+        let keypair = crypto::generate_keypair();
+        // 198.18.0.0/15 is reserved for benchmarking (RFC 2544)
+        let address = format!(
+            "/ip4/198.{}.{}.{}/tcp/30333",
+            18 + (index >> 16 & 1),
+            index >> 8 & 0xFF,
+            index & 0xFF
+        );
+        Self {
+            libp2p_peer_id: format!("12D3KooWBench{:08}", index),
+            keypair,
+            address,
+            genesis_hash,
+        }
+    }
+
+    async fn send_heartbeat(&self, addr: SocketAddr, timeout: Duration) -> anyhow::Result<HeartbeatOutcome> {
+        let mut message = HeartbeatMessage {
+            version: BENCH_HEARTBEAT_VERSION,
+            peer_id: crypto::keypair_to_public_key(&self.keypair),
+            libp2p_peer_id: self.libp2p_peer_id.clone(),
+            addresses: vec![self.address.clone()],
+            current_height: 0,
+            best_hash: [0u8; 32],
+            genesis_hash: self.genesis_hash,
+            is_validator: false,
+            validator_count: None,
+            total_stake: None,
+            protocol_version: 1,
+            timestamp: unix_now(),
+            signature: [0u8; 64],
+            capabilities: Capabilities::NONE,
+        };
+        message.signature = crypto::sign_heartbeat(&self.keypair, &message);
+        let bytes = bincode::serialize(&message)?;
+
+        let response = tokio::time::timeout(timeout, async {
+            let mut stream = TcpStream::connect(addr).await?;
+            stream.write_all(&(bytes.len() as u32).to_be_bytes()).await?;
+            stream.write_all(&bytes).await?;
+            stream.flush().await?;
+
+            let mut len_buf = [0u8; 4];
+            stream.read_exact(&mut len_buf).await?;
+            let len = u32::from_be_bytes(len_buf) as usize;
+            if len > MAX_RESPONSE_SIZE {
+                anyhow::bail!("Response too large");
+            }
+            let mut buf = vec![0u8; len];
+            stream.read_exact(&mut buf).await?;
+            Ok(HeartbeatResponse::decode(&buf)?)
+        })
+        .await??;
+
+        Ok(classify(&response))
+    }
+}
+
+fn classify(response: &HeartbeatResponse) -> HeartbeatOutcome {
+    if response.accepted {
+        HeartbeatOutcome::Accepted
+    } else if response.error.as_deref() == Some("Rate limited") {
+        HeartbeatOutcome::RateLimited
+    } else {
+        HeartbeatOutcome::Rejected
+    }
+}
+
+/// Send a query and return the answer count of the response
+async fn dns_round_trip(addr: SocketAddr, query: &[u8], timeout: Duration) -> anyhow::Result<u16> {
+    let socket = UdpSocket::bind(("0.0.0.0", 0)).await?;
+    socket.send_to(query, addr).await?;
+
+    let mut buf = [0u8; 4096];
+    let (len, _) = tokio::time::timeout(timeout, socket.recv_from(&mut buf)).await??;
+    parse_answer_count(&buf[..len], u16::from_be_bytes([query[0], query[1]]))
+}
+
+/// A query for the A records of `domain`
+fn build_query(id: u16, domain: &str) -> Vec<u8> {
+    let mut query = Vec::with_capacity(domain.len() + 18);
+    query.extend_from_slice(&id.to_be_bytes());
+    query.extend_from_slice(&0x0100u16.to_be_bytes()); // RD
+    query.extend_from_slice(&1u16.to_be_bytes()); // qdcount
+    query.extend_from_slice(&[0; 6]); // ancount, nscount, arcount
+    for label in domain.trim_end_matches('.').split('.') {
+        query.push(label.len() as u8);
+        query.extend_from_slice(label.as_bytes());
+    }
+    query.push(0);
+    query.extend_from_slice(&1u16.to_be_bytes()); // A
+    query.extend_from_slice(&1u16.to_be_bytes()); // IN
+    query
+}
+
+fn parse_answer_count(response: &[u8], expected_id: u16) -> anyhow::Result<u16> {
+    if response.len() < 12 {
+        anyhow::bail!("Truncated response");
+    }
+    if u16::from_be_bytes([response[0], response[1]]) != expected_id {
+        anyhow::bail!("Response ID mismatch");
+    }
+    Ok(u16::from_be_bytes([response[6], response[7]]))
+}
+
+fn print_report(report: &BenchReport, elapsed: f64) {
+    let heartbeats = report.heartbeats_accepted
+        + report.heartbeats_rate_limited
+        + report.heartbeats_rejected
+        + report.heartbeats_failed;
+    let queries = report.dns_answers + report.dns_empty + report.dns_failed;
+
+    println!();
+    println!("Benchmark finished in {:.1}s", elapsed);
+    println!();
+    println!("Heartbeats: {} sent ({:.1}/s)", heartbeats, heartbeats as f64 / elapsed);
+    println!("  accepted      {}", report.heartbeats_accepted);
+    println!("  rate limited  {}", report.heartbeats_rate_limited);
+    println!("  rejected      {}", report.heartbeats_rejected);
+    println!("  failed        {} (connection errors and timeouts)", report.heartbeats_failed);
+    println!("  latency       {}", report.heartbeat_latency.summary());
+    if report.heartbeats_rate_limited > 0 {
+        println!("  note: the rate limit is per source IP; spread clients over several hosts");
+        println!("        to measure capacity beyond rate_limit_per_minute");
+    }
+    println!();
+    println!("DNS queries: {} sent ({:.1}/s)", queries, queries as f64 / elapsed);
+    println!("  with answers  {}", report.dns_answers);
+    println!("  empty         {}", report.dns_empty);
+    println!("  failed        {} (timeouts and malformed responses)", report.dns_failed);
+    println!("  latency       {}", report.dns_latency.summary());
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentiles() {
+        let mut stats = LatencyStats::default();
+        assert!(stats.percentile(50.0).is_none());
+
+        for ms in (1..=100).rev() {
+            stats.record(Duration::from_millis(ms));
+        }
+        assert_eq!(stats.count(), 100);
+        assert_eq!(stats.percentile(50.0), Some(Duration::from_millis(50)));
+        assert_eq!(stats.percentile(99.0), Some(Duration::from_millis(99)));
+        assert_eq!(stats.percentile(100.0), Some(Duration::from_millis(100)));
+        assert_eq!(stats.percentile(0.0), Some(Duration::from_millis(1)));
+    }
+
+    #[test]
+    fn test_query_round_trips_through_parser() {
+        let query = build_query(0x4242, "seed.kratos.network");
+        let mut response = query.clone();
+        response[6..8].copy_from_slice(&3u16.to_be_bytes());
+
+        assert_eq!(parse_answer_count(&response, 0x4242).unwrap(), 3);
+        assert!(parse_answer_count(&response, 0x4243).is_err());
+        assert!(parse_answer_count(&response[..5], 0x4242).is_err());
+    }
+
+    #[test]
+    fn test_classify_rate_limited() {
+        let response = HeartbeatResponse {
+            accepted: false,
+            error: Some("Rate limited".to_string()),
+            network_state: None,
+            timestamp: 0,
+            receipt: None,
+        };
+        assert!(matches!(classify(&response), HeartbeatOutcome::RateLimited));
+    }
+}
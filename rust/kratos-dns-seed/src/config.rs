@@ -4,7 +4,9 @@
 //! Default values are chosen to balance security, resilience, and performance.
 
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use crate::types::BlocklistEntry;
 
 /// Main configuration for the DNS Seed service
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,6 +120,89 @@ pub struct DnsSeedConfig {
 
     /// Fallback bootnodes (always included)
     pub fallback_bootnodes: Vec<String>,
+
+    // === DNSSEC ===
+
+    /// DNSSEC signing of DNS responses (optional section)
+    #[serde(default)]
+    pub dnssec: DnssecConfig,
+
+    // === Blocklist ===
+
+    /// Shared blocklist of malicious peers (optional section)
+    #[serde(default)]
+    pub blocklist: BlocklistConfig,
+}
+
+/// DNSSEC signing configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DnssecConfig {
+    /// Sign responses and serve DNSKEY records
+    pub enabled: bool,
+
+    /// Key signing key file (defaults to <data_dir>/dnssec_ksk.key)
+    /// Its DS record must be published in the parent zone
+    pub ksk_file: Option<PathBuf>,
+
+    /// Zone signing key file (defaults to <data_dir>/dnssec_zsk.key)
+    pub zsk_file: Option<PathBuf>,
+
+    /// Validity of each RRSIG (seconds)
+    pub signature_validity_secs: u32,
+
+    /// TTL of the DNSKEY RRset (seconds)
+    pub dnskey_ttl: u32,
+}
+
+/// Blocklist publishing and subscription configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BlocklistConfig {
+    /// Peers and addresses this seed blocks and publishes at /blocklist.json
+    pub entries: Vec<BlocklistEntry>,
+
+    /// Trusted seeds whose blocklists are fetched and applied
+    pub subscriptions: Vec<BlocklistSubscription>,
+
+    /// Interval between subscription fetches (seconds)
+    pub refresh_interval_secs: u64,
+
+    /// Documents issued longer ago than this are rejected (seconds)
+    pub max_document_age_secs: u64,
+}
+
+/// A trusted seed's published blocklist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlocklistSubscription {
+    /// URL of the seed's blocklist.json
+    pub url: String,
+
+    /// Expected signer (hex-encoded seed ID)
+    pub seed_id: String,
+}
+
+impl Default for BlocklistConfig {
+    fn default() -> Self {
+        Self {
+            entries: vec![],
+            subscriptions: vec![],
+            refresh_interval_secs: 600,          // 10 minutes
+            max_document_age_secs: 24 * 3600,    // 1 day
+        }
+    }
+}
+
+impl Default for DnssecConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ksk_file: None,
+            zsk_file: None,
+            signature_validity_secs: 7 * 24 * 3600, // 1 week
+            dnskey_ttl: 3600,                       // 1 hour
+        }
+    }
 }
 
 impl Default for DnsSeedConfig {
@@ -171,6 +256,12 @@ impl Default for DnsSeedConfig {
             fallback_bootnodes: vec![
                 "/ip4/45.8.132.252/tcp/30333/p2p/12D3KooWQqYkkyLGuFS6YZprPShuVhn8Wrc1PUxbJ8pRisAYLndK".to_string(),
             ],
+
+            // DNSSEC (off until keys are registered with the parent zone)
+            dnssec: DnssecConfig::default(),
+
+            // Blocklist (nothing blocked or subscribed by default)
+            blocklist: BlocklistConfig::default(),
         }
     }
 }
@@ -212,6 +303,11 @@ impl DnsSeedConfig {
         self
     }
 
+    pub fn with_dnssec(mut self, enabled: bool) -> Self {
+        self.dnssec.enabled |= enabled;
+        self
+    }
+
     /// Validate configuration values
     pub fn validate(&self) -> anyhow::Result<()> {
         if self.peer_timeout_secs <= self.heartbeat_interval_secs {
@@ -238,6 +334,23 @@ impl DnsSeedConfig {
             );
         }
 
+        if self.dnssec.enabled && self.dnssec.signature_validity_secs <= 3600 {
+            anyhow::bail!(
+                "dnssec.signature_validity_secs ({}) must exceed one hour",
+                self.dnssec.signature_validity_secs
+            );
+        }
+
+        for sub in &self.blocklist.subscriptions {
+            if crate::crypto::hex_to_hash(&sub.seed_id).is_err() {
+                anyhow::bail!("blocklist subscription {} has an invalid seed_id", sub.url);
+            }
+        }
+
+        if !self.blocklist.subscriptions.is_empty() && self.blocklist.refresh_interval_secs == 0 {
+            anyhow::bail!("blocklist.refresh_interval_secs must be positive");
+        }
+
         Ok(())
     }
 }
@@ -281,4 +394,46 @@ mod tests {
         assert_eq!(config.dns_port, 5354);
         assert_eq!(config.api_port, 9090);
     }
+
+    #[test]
+    fn test_dnssec_section_is_optional() {
+        let mut value = toml::Value::try_from(DnsSeedConfig::default()).unwrap();
+        value.as_table_mut().unwrap().remove("dnssec");
+        let config: DnsSeedConfig = toml::from_str(&toml::to_string(&value).unwrap()).unwrap();
+        assert!(!config.dnssec.enabled);
+        assert!(config.with_dnssec(true).dnssec.enabled);
+    }
+
+    #[test]
+    fn test_blocklist_section() {
+        let mut value = toml::Value::try_from(DnsSeedConfig::default()).unwrap();
+        value.as_table_mut().unwrap().remove("blocklist");
+        let toml_text = format!(
+            r#"{}
+            [blocklist]
+            refresh_interval_secs = 300
+
+            [[blocklist.entries]]
+            target = {{ ip = "203.0.113.7" }}
+            reason = "wrong chain spam"
+            expires_at = 1900000000
+
+            [[blocklist.subscriptions]]
+            url = "https://seed2.kratos.network/blocklist.json"
+            seed_id = "{}"
+            "#,
+            toml::to_string(&value).unwrap(),
+            "ab".repeat(32)
+        );
+        let config: DnsSeedConfig = toml::from_str(&toml_text).unwrap();
+
+        assert_eq!(config.blocklist.entries.len(), 1);
+        assert_eq!(config.blocklist.refresh_interval_secs, 300);
+        assert_eq!(config.blocklist.max_document_age_secs, 24 * 3600);
+        assert!(config.validate().is_ok());
+
+        let mut bad = config.clone();
+        bad.blocklist.subscriptions[0].seed_id = "zz".to_string();
+        assert!(bad.validate().is_err());
+    }
 }
@@ -11,7 +11,9 @@ use rand::rngs::OsRng;
 use std::path::Path;
 use tracing::info;
 
-use crate::types::{Hash, PublicKey, SeedId, HeartbeatMessage, IdPeersFile};
+use crate::types::{
+    BlocklistDocument, Hash, HeartbeatReceipt, PublicKey, SeedId, HeartbeatMessage, IdPeersFile,
+};
 
 /// Domain separation prefix for heartbeat signatures
 const DOMAIN_HEARTBEAT: &[u8] = b"KRATOS_DNS_HEARTBEAT_V1:";
@@ -19,6 +21,12 @@ const DOMAIN_HEARTBEAT: &[u8] = b"KRATOS_DNS_HEARTBEAT_V1:";
 /// Domain separation prefix for IDpeers.json signatures
 const DOMAIN_IDPEERS: &[u8] = b"KRATOS_IDPEERS_V1:";
 
+/// Domain separation prefix for blocklist.json signatures
+const DOMAIN_BLOCKLIST: &[u8] = b"KRATOS_BLOCKLIST_V1:";
+
+/// Domain separation prefix for heartbeat receipts (same as kratos-core)
+const DOMAIN_SEED_RECEIPT: &[u8] = b"KRATOS_SEED_RECEIPT_V1:";
+
 // =============================================================================
 // KEYPAIR MANAGEMENT
 // =============================================================================
@@ -154,6 +162,38 @@ pub fn verify_heartbeat_with_key(
         .map_err(|_| SignatureError::InvalidSignature)
 }
 
+// =============================================================================
+// HEARTBEAT RECEIPTS
+// =============================================================================
+
+/// Issue a signed receipt for a validator heartbeat
+pub fn sign_receipt(keypair: &SigningKey, message: &HeartbeatMessage, timestamp: u64) -> HeartbeatReceipt {
+    let mut receipt = HeartbeatReceipt {
+        seed_id: keypair_to_seed_id(keypair),
+        peer_id: message.peer_id,
+        genesis_hash: message.genesis_hash,
+        height: message.current_height,
+        timestamp,
+        signature: [0u8; SIGNATURE_LENGTH],
+    };
+    let domain_data = domain_separate(DOMAIN_SEED_RECEIPT, &receipt.signing_data());
+    receipt.signature = keypair.sign(&domain_data).to_bytes();
+    receipt
+}
+
+/// Verify a heartbeat receipt against the seed ID it claims
+pub fn verify_receipt(receipt: &HeartbeatReceipt) -> Result<(), SignatureError> {
+    let verifying_key = VerifyingKey::from_bytes(&receipt.seed_id)
+        .map_err(|_| SignatureError::InvalidPublicKey)?;
+
+    let domain_data = domain_separate(DOMAIN_SEED_RECEIPT, &receipt.signing_data());
+    let signature = Signature::from_bytes(&receipt.signature);
+
+    verifying_key
+        .verify(&domain_data, &signature)
+        .map_err(|_| SignatureError::InvalidSignature)
+}
+
 // =============================================================================
 // IDPEERS FILE SIGNING & VERIFICATION
 // =============================================================================
@@ -195,6 +235,41 @@ pub fn verify_idpeers_with_seed_id(
     verify_idpeers_file(file)
 }
 
+// =============================================================================
+// BLOCKLIST SIGNING & VERIFICATION
+// =============================================================================
+
+/// Sign a blocklist document
+pub fn sign_blocklist(keypair: &SigningKey, doc: &BlocklistDocument) -> [u8; SIGNATURE_LENGTH] {
+    let domain_data = domain_separate(DOMAIN_BLOCKLIST, &doc.signing_data());
+    keypair.sign(&domain_data).to_bytes()
+}
+
+/// Verify a blocklist document against the seed ID it claims
+pub fn verify_blocklist(doc: &BlocklistDocument) -> Result<(), SignatureError> {
+    let verifying_key = VerifyingKey::from_bytes(&doc.seed_id)
+        .map_err(|_| SignatureError::InvalidPublicKey)?;
+
+    let domain_data = domain_separate(DOMAIN_BLOCKLIST, &doc.signing_data());
+    let signature = Signature::from_bytes(&doc.signature);
+
+    verifying_key
+        .verify(&domain_data, &signature)
+        .map_err(|_| SignatureError::InvalidSignature)
+}
+
+/// Verify a blocklist document was issued by a specific (trusted) seed
+pub fn verify_blocklist_with_seed_id(
+    doc: &BlocklistDocument,
+    expected_seed_id: &SeedId,
+) -> Result<(), SignatureError> {
+    if &doc.seed_id != expected_seed_id {
+        return Err(SignatureError::SeedIdMismatch);
+    }
+
+    verify_blocklist(doc)
+}
+
 // =============================================================================
 // HELPERS
 // =============================================================================
@@ -298,6 +373,7 @@ mod tests {
             protocol_version: 1,
             timestamp: 1703318400,
             signature: [0u8; 64],
+            capabilities: Default::default(),
         };
 
         // Sign the message
@@ -311,6 +387,76 @@ mod tests {
         assert!(verify_heartbeat(&message).is_err());
     }
 
+    #[test]
+    fn test_receipt_signing() {
+        let seed = generate_keypair();
+        let message = HeartbeatMessage {
+            version: 2,
+            peer_id: [3u8; 32],
+            libp2p_peer_id: "12D3KooWTestPeerId".to_string(),
+            addresses: vec![],
+            current_height: 1800,
+            best_hash: [0u8; 32],
+            genesis_hash: [1u8; 32],
+            is_validator: true,
+            validator_count: None,
+            total_stake: None,
+            protocol_version: 1,
+            timestamp: 1703318400,
+            signature: [0u8; 64],
+            capabilities: Default::default(),
+        };
+
+        let mut receipt = sign_receipt(&seed, &message, 1703318401);
+        assert_eq!(receipt.seed_id, keypair_to_seed_id(&seed));
+        assert_eq!((receipt.peer_id, receipt.height), ([3u8; 32], 1800));
+        assert!(verify_receipt(&receipt).is_ok());
+
+        // Survives the bincode round trip used on the wire
+        let bytes = bincode::serialize(&receipt).unwrap();
+        let decoded: HeartbeatReceipt = bincode::deserialize(&bytes).unwrap();
+        assert!(verify_receipt(&decoded).is_ok());
+
+        receipt.timestamp += 1;
+        assert!(verify_receipt(&receipt).is_err());
+    }
+
+    #[test]
+    fn test_blocklist_signing() {
+        use crate::types::{BlocklistEntry, BlocklistTarget};
+
+        let keypair = generate_keypair();
+        let seed_id = keypair_to_seed_id(&keypair);
+
+        let mut doc = BlocklistDocument {
+            version: 1,
+            seed_id,
+            issued_at: 1703318400,
+            entries: vec![BlocklistEntry {
+                target: BlocklistTarget::Peer([9u8; 32]),
+                reason: "eclipse attempt".to_string(),
+                expires_at: 1703404800,
+            }],
+            signature: [0u8; 64],
+        };
+        doc.signature = sign_blocklist(&keypair, &doc);
+
+        assert!(verify_blocklist_with_seed_id(&doc, &seed_id).is_ok());
+        assert!(matches!(
+            verify_blocklist_with_seed_id(&doc, &[1u8; 32]),
+            Err(SignatureError::SeedIdMismatch)
+        ));
+
+        // Survives the JSON round trip used on the wire
+        let json = serde_json::to_vec(&doc).unwrap();
+        let decoded: BlocklistDocument = serde_json::from_slice(&json).unwrap();
+        assert!(verify_blocklist(&decoded).is_ok());
+
+        // Tampering with an entry breaks the signature
+        doc.entries[0].expires_at += 1;
+        assert!(verify_blocklist(&doc).is_err());
+    }
+
     #[test]
     fn test_domain_separation() {
         let data = b"test data";
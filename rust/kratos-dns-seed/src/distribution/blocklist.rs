@@ -0,0 +1,160 @@
+//! Blocklist Sharing
+//!
+//! Seeds publish their own blocklist as a signed blocklist.json and fetch
+//! the documents of the seeds listed in `[blocklist.subscriptions]`.
+//! A fetched document is only applied if it is signed by the seed ID
+//! configured for that subscription and is recent.
+
+use ed25519_dalek::SigningKey;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tracing::{debug, info, warn};
+
+use crate::config::{BlocklistSubscription, DnsSeedConfig};
+use crate::crypto::{hex_to_hash, keypair_to_seed_id, sign_blocklist, verify_blocklist_with_seed_id};
+use crate::registry::PeerRegistry;
+use crate::types::{BlocklistDocument, BlocklistEntry};
+
+/// blocklist.json format version
+pub const BLOCKLIST_VERSION: u32 = 1;
+
+/// Tolerated clock skew for documents issued "in the future" (seconds)
+const MAX_CLOCK_SKEW_SECS: u64 = 300;
+
+/// Timeout of a subscription fetch (seconds)
+const FETCH_TIMEOUT_SECS: u64 = 10;
+
+/// Largest accepted document (bytes)
+const MAX_DOCUMENT_SIZE: usize = 1024 * 1024;
+
+/// Build and sign this seed's blocklist document
+pub fn build_document(keypair: &SigningKey, entries: Vec<BlocklistEntry>, now: u64) -> BlocklistDocument {
+    let mut doc = BlocklistDocument {
+        version: BLOCKLIST_VERSION,
+        seed_id: keypair_to_seed_id(keypair),
+        issued_at: now,
+        entries,
+        signature: [0u8; 64],
+    };
+    doc.signature = sign_blocklist(keypair, &doc);
+    doc
+}
+
+/// Check a fetched document before applying it
+pub fn check_document(
+    doc: &BlocklistDocument,
+    subscription: &BlocklistSubscription,
+    max_age_secs: u64,
+    now: u64,
+) -> Result<(), String> {
+    if doc.version != BLOCKLIST_VERSION {
+        return Err(format!("unsupported version {}", doc.version));
+    }
+
+    let seed_id = hex_to_hash(&subscription.seed_id).map_err(|e| format!("invalid seed_id: {}", e))?;
+    verify_blocklist_with_seed_id(doc, &seed_id).map_err(|e| e.to_string())?;
+
+    if doc.issued_at > now + MAX_CLOCK_SKEW_SECS {
+        return Err("issued in the future".to_string());
+    }
+    if now.saturating_sub(doc.issued_at) > max_age_secs {
+        return Err(format!("issued {}s ago", now - doc.issued_at));
+    }
+
+    Ok(())
+}
+
+/// Periodically fetch and apply the subscribed blocklists
+pub async fn run_subscriptions(
+    config: Arc<DnsSeedConfig>,
+    registry: Arc<RwLock<PeerRegistry>>,
+) -> anyhow::Result<()> {
+    if config.blocklist.subscriptions.is_empty() {
+        // Nothing to fetch; stay alive so the service select! does not exit
+        std::future::pending::<()>().await;
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(FETCH_TIMEOUT_SECS))
+        .build()?;
+
+    let mut interval = tokio::time::interval(Duration::from_secs(config.blocklist.refresh_interval_secs));
+
+    info!("🚫 Blocklist subscriptions: {}", config.blocklist.subscriptions.len());
+
+    loop {
+        interval.tick().await;
+
+        for subscription in &config.blocklist.subscriptions {
+            let doc = match fetch(&client, &subscription.url).await {
+                Ok(doc) => doc,
+                Err(e) => {
+                    warn!("Blocklist fetch from {} failed: {}", subscription.url, e);
+                    continue;
+                }
+            };
+
+            if let Err(e) = check_document(&doc, subscription, config.blocklist.max_document_age_secs, current_timestamp()) {
+                warn!("Rejected blocklist from {}: {}", subscription.url, e);
+                continue;
+            }
+
+            let entries = doc.entries.len();
+            match registry.write().await.apply_blocklist(doc) {
+                Some(blocked) => info!(
+                    "🚫 Applied blocklist from {}: {} entries, {} peers blocked",
+                    subscription.url, entries, blocked
+                ),
+                None => debug!("Blocklist from {} unchanged", subscription.url),
+            }
+        }
+    }
+}
+
+/// Download and parse a blocklist.json
+async fn fetch(client: &reqwest::Client, url: &str) -> anyhow::Result<BlocklistDocument> {
+    let response = client.get(url).send().await?.error_for_status()?;
+    let body = response.bytes().await?;
+    if body.len() > MAX_DOCUMENT_SIZE {
+        anyhow::bail!("document too large ({} bytes)", body.len());
+    }
+    Ok(serde_json::from_slice(&body)?)
+}
+
+fn current_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::generate_keypair;
+    use crate::types::BlocklistTarget;
+
+    #[test]
+    fn test_check_document() {
+        let keypair = generate_keypair();
+        let subscription = BlocklistSubscription {
+            url: "http://seed2/blocklist.json".to_string(),
+            seed_id: hex::encode(keypair_to_seed_id(&keypair)),
+        };
+        let entries = vec![BlocklistEntry {
+            target: BlocklistTarget::Ip("203.0.113.7".parse().unwrap()),
+            reason: "wrong chain spam".to_string(),
+            expires_at: 2_000_000,
+        }];
+        let doc = build_document(&keypair, entries, 1_000_000);
+
+        assert!(check_document(&doc, &subscription, 3600, 1_000_100).is_ok());
+        assert!(check_document(&doc, &subscription, 3600, 1_003_601).is_err());
+        assert!(check_document(&doc, &subscription, 3600, 999_000).is_err());
+
+        // Signed by a seed other than the one subscribed to
+        let other = build_document(&generate_keypair(), doc.entries.clone(), 1_000_000);
+        assert!(check_document(&other, &subscription, 3600, 1_000_100).is_err());
+    }
+}
@@ -13,7 +13,8 @@ use crate::config::DnsSeedConfig;
 use crate::crypto::{keypair_to_seed_id, sign_idpeers_file};
 use crate::network_state::NetworkStateAggregator;
 use crate::registry::PeerRegistry;
-use crate::types::{IdPeersFile, PeerInfo, DEFAULT_P2P_PORT, OFFICIAL_DNS_SEED_IPS};
+use crate::distribution::blocklist::build_document;
+use crate::types::{BlocklistDocument, Capabilities, IdPeersFile, PeerInfo, DEFAULT_P2P_PORT, OFFICIAL_DNS_SEED_IPS};
 
 /// IDpeers.json generator
 pub struct IdPeersGenerator {
@@ -61,10 +62,34 @@ impl IdPeersGenerator {
         registry: &PeerRegistry,
         network_state: &NetworkStateAggregator,
     ) -> anyhow::Result<IdPeersFile> {
-        let now = current_timestamp();
+        let file = self.generate_with_capabilities(registry, network_state, Capabilities::NONE);
+        let now = file.generated_at;
+
+        // Update cache
+        self.last_generated = now;
+        self.cached_content = Some(serde_json::to_vec_pretty(&file)?);
 
+        info!(
+            "📝 Generated IDpeers.json: {} peers, {} validators, height {}",
+            file.peers.len(),
+            file.network_state.active_validators,
+            file.network_state.best_height
+        );
+
+        Ok(file)
+    }
+
+    /// Generate a signed file listing only peers that offer all `required` capabilities
+    ///
+    /// Filtered files are built on request and never cached or saved.
+    pub fn generate_with_capabilities(
+        &self,
+        registry: &PeerRegistry,
+        network_state: &NetworkStateAggregator,
+        required: Capabilities,
+    ) -> IdPeersFile {
         // Get diverse set of top peers
-        let peers = self.select_peers(registry);
+        let peers = self.select_peers(registry, required);
 
         // Build fallback bootnodes list
         let fallback_bootnodes = self.build_fallback_bootnodes();
@@ -72,7 +97,7 @@ impl IdPeersGenerator {
         // Create the file structure
         let mut file = IdPeersFile {
             version: 1,
-            generated_at: now,
+            generated_at: current_timestamp(),
             dns_seed_id: self.seed_id,
             signature: [0u8; 64], // Will be filled in
             network_state: network_state.current_state(),
@@ -82,19 +107,7 @@ impl IdPeersGenerator {
 
         // Sign the file
         file.signature = sign_idpeers_file(&self.keypair, &file);
-
-        // Update cache
-        self.last_generated = now;
-        self.cached_content = Some(serde_json::to_vec_pretty(&file)?);
-
-        info!(
-            "📝 Generated IDpeers.json: {} peers, {} validators, height {}",
-            file.peers.len(),
-            file.network_state.active_validators,
-            file.network_state.best_height
-        );
-
-        Ok(file)
+        file
     }
 
     /// Generate and save to disk
@@ -123,15 +136,16 @@ impl IdPeersGenerator {
     }
 
     /// Select peers for inclusion in the file
-    fn select_peers(&self, registry: &PeerRegistry) -> Vec<PeerInfo> {
+    fn select_peers(&self, registry: &PeerRegistry, required: Capabilities) -> Vec<PeerInfo> {
         let timeout = self.config.peer_timeout_secs;
         let max_peers = self.config.max_peers_in_idpeers;
 
         // Get diverse peers (different regions, high scores)
-        let diverse = registry.get_diverse_peers(
+        let diverse = registry.get_diverse_peers_with(
             max_peers,
             self.config.min_regions_in_idpeers,
             timeout,
+            required,
         );
 
         // Convert references to owned
@@ -158,6 +172,12 @@ impl IdPeersGenerator {
         bootnodes
     }
 
+    /// Sign this seed's own blocklist entries (served as blocklist.json)
+    pub fn blocklist_document(&self, registry: &PeerRegistry) -> BlocklistDocument {
+        let now = current_timestamp();
+        build_document(&self.keypair, registry.blocklist().local_entries(now), now)
+    }
+
     /// Get cached content if still valid
     pub fn get_cached(&self) -> Option<&[u8]> {
         let now = current_timestamp();
@@ -255,7 +275,7 @@ mod tests {
 
         let mut generator = IdPeersGenerator::new(keypair, config, output);
 
-        let registry = PeerRegistry::open(dir.path().join("registry")).unwrap();
+        let registry = PeerRegistry::open(&dir.path().join("registry")).unwrap();
         let network_state = NetworkStateAggregator::new([0u8; 32], current_timestamp());
 
         let file = generator.generate(&registry, &network_state).await.unwrap();
@@ -5,7 +5,10 @@
 //! - Current network state
 //! - List of active peers
 //! - Fallback bootnodes
+//!
+//! It also publishes and subscribes to signed blocklists (see [`blocklist`]).
 
+pub mod blocklist;
 pub mod generator;
 mod server;
 
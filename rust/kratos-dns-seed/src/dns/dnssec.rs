@@ -0,0 +1,271 @@
+//! DNSSEC Signing
+//!
+//! Online signing of the seed's answers so that validating resolvers
+//! accept them instead of stripping or distrusting unsigned data.
+//!
+//! ## Keys
+//!
+//! - KSK (flags 257): signs the DNSKEY RRset; its DS record goes in the parent zone
+//! - ZSK (flags 256): signs the A/AAAA answers
+//!
+//! Both use Ed25519 (algorithm 15, RFC 8080), the same curve as the seed identity key.
+//! Answers are shuffled and change with the registry, so every RRset is signed
+//! when the response is built rather than from a pre-signed zone file.
+
+use ed25519_dalek::{Signer, SigningKey};
+use sha2::{Digest, Sha256};
+
+/// DNSSEC algorithm number for Ed25519 (RFC 8080)
+pub const ALGORITHM_ED25519: u8 = 15;
+
+/// DNSKEY protocol field (always 3, RFC 4034 §2.1.2)
+const DNSKEY_PROTOCOL: u8 = 3;
+
+/// DNSKEY flags: zone key
+pub const DNSKEY_FLAG_ZONE: u16 = 0x0100;
+
+/// DNSKEY flags: secure entry point (set on the KSK)
+pub const DNSKEY_FLAG_SEP: u16 = 0x0001;
+
+/// DS digest type for SHA-256
+const DIGEST_SHA256: u8 = 2;
+
+/// Record types involved in DNSSEC
+pub const TYPE_RRSIG: u16 = 46;
+pub const TYPE_DNSKEY: u16 = 48;
+
+/// IN class
+const CLASS_IN: u16 = 1;
+
+/// Signatures start this long before "now" to tolerate resolver clock skew (seconds)
+const INCEPTION_SKEW_SECS: u32 = 3600;
+
+/// Ed25519 signature length in an RRSIG
+const SIGNATURE_LEN: usize = 64;
+
+/// A DNSSEC zone key (KSK or ZSK)
+pub struct ZoneKey {
+    key: SigningKey,
+    flags: u16,
+}
+
+impl ZoneKey {
+    /// Key signing key (signs the DNSKEY RRset)
+    pub fn ksk(key: SigningKey) -> Self {
+        Self { key, flags: DNSKEY_FLAG_ZONE | DNSKEY_FLAG_SEP }
+    }
+
+    /// Zone signing key (signs answers)
+    pub fn zsk(key: SigningKey) -> Self {
+        Self { key, flags: DNSKEY_FLAG_ZONE }
+    }
+
+    /// DNSKEY RDATA: flags, protocol, algorithm, public key
+    pub fn dnskey_rdata(&self) -> Vec<u8> {
+        let mut rdata = Vec::with_capacity(36);
+        rdata.extend_from_slice(&self.flags.to_be_bytes());
+        rdata.push(DNSKEY_PROTOCOL);
+        rdata.push(ALGORITHM_ED25519);
+        rdata.extend_from_slice(self.key.verifying_key().as_bytes());
+        rdata
+    }
+
+    /// Key tag (RFC 4034 Appendix B)
+    pub fn key_tag(&self) -> u16 {
+        let mut acc: u32 = 0;
+        for (i, byte) in self.dnskey_rdata().iter().enumerate() {
+            acc += if i & 1 == 0 { (*byte as u32) << 8 } else { *byte as u32 };
+        }
+        acc += (acc >> 16) & 0xFFFF;
+        (acc & 0xFFFF) as u16
+    }
+}
+
+/// Signs the RRsets served for the seed's zone
+pub struct DnssecSigner {
+    /// Zone apex (lowercase, no trailing dot)
+    zone: String,
+
+    /// Zone apex in wire format (signer name of every RRSIG)
+    zone_wire: Vec<u8>,
+
+    ksk: ZoneKey,
+    zsk: ZoneKey,
+
+    /// Signature validity period (seconds)
+    validity_secs: u32,
+
+    /// TTL of the DNSKEY RRset (seconds)
+    dnskey_ttl: u32,
+}
+
+impl DnssecSigner {
+    /// Create a signer for `zone`
+    pub fn new(zone: &str, ksk: ZoneKey, zsk: ZoneKey, validity_secs: u32, dnskey_ttl: u32) -> Self {
+        let zone = zone.trim_end_matches('.').to_ascii_lowercase();
+        Self {
+            zone_wire: encode_name(&zone),
+            zone,
+            ksk,
+            zsk,
+            validity_secs,
+            dnskey_ttl,
+        }
+    }
+
+    /// Whether `name` is the zone apex
+    pub fn is_apex(&self, name: &str) -> bool {
+        name.trim_end_matches('.').eq_ignore_ascii_case(&self.zone)
+    }
+
+    /// Whether `name` is inside the zone (signatures are only valid there)
+    pub fn covers(&self, name: &str) -> bool {
+        let name = name.trim_end_matches('.').to_ascii_lowercase();
+        name == self.zone || name.ends_with(&format!(".{}", self.zone))
+    }
+
+    /// DNSKEY RRset of the zone (KSK then ZSK)
+    pub fn dnskey_rrset(&self) -> Vec<Vec<u8>> {
+        vec![self.ksk.dnskey_rdata(), self.zsk.dnskey_rdata()]
+    }
+
+    /// TTL of the DNSKEY RRset
+    pub fn dnskey_ttl(&self) -> u32 {
+        self.dnskey_ttl
+    }
+
+    /// Size of one RRSIG RDATA produced by this signer
+    pub fn rrsig_rdata_len(&self) -> usize {
+        18 + self.zone_wire.len() + SIGNATURE_LEN
+    }
+
+    /// Sign an RRset and return the RRSIG RDATA
+    ///
+    /// The DNSKEY RRset is signed with the KSK, everything else with the ZSK.
+    /// `now` is the current unix time (seconds).
+    pub fn sign(&self, owner: &str, rtype: u16, ttl: u32, rdatas: &[Vec<u8>], now: u64) -> Vec<u8> {
+        let key = if rtype == TYPE_DNSKEY { &self.ksk } else { &self.zsk };
+        let now = now as u32; // RRSIG times use serial number arithmetic (RFC 4034 §3.1.5)
+        let inception = now.wrapping_sub(INCEPTION_SKEW_SECS);
+        let expiration = now.wrapping_add(self.validity_secs);
+
+        let mut rdata = Vec::with_capacity(self.rrsig_rdata_len());
+        rdata.extend_from_slice(&rtype.to_be_bytes());
+        rdata.push(ALGORITHM_ED25519);
+        rdata.push(label_count(owner));
+        rdata.extend_from_slice(&ttl.to_be_bytes());
+        rdata.extend_from_slice(&expiration.to_be_bytes());
+        rdata.extend_from_slice(&inception.to_be_bytes());
+        rdata.extend_from_slice(&key.key_tag().to_be_bytes());
+        rdata.extend_from_slice(&self.zone_wire);
+
+        let signature = key.key.sign(&signing_data(&rdata, owner, rtype, ttl, rdatas));
+        rdata.extend_from_slice(&signature.to_bytes());
+        rdata
+    }
+
+    /// DS record of the KSK, to publish in the parent zone
+    pub fn ds_record(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(&self.zone_wire);
+        hasher.update(self.ksk.dnskey_rdata());
+        format!(
+            "{}. IN DS {} {} {} {}",
+            self.zone,
+            self.ksk.key_tag(),
+            ALGORITHM_ED25519,
+            DIGEST_SHA256,
+            hex::encode(hasher.finalize())
+        )
+    }
+}
+
+/// Data covered by an RRSIG: its RDATA (minus signature) followed by
+/// the RRset in canonical form and order (RFC 4034 §3.1.8.1, §6.3)
+fn signing_data(rrsig_rdata: &[u8], owner: &str, rtype: u16, ttl: u32, rdatas: &[Vec<u8>]) -> Vec<u8> {
+    let owner = encode_name(owner);
+    let mut sorted: Vec<&Vec<u8>> = rdatas.iter().collect();
+    sorted.sort();
+    sorted.dedup();
+
+    let mut data = rrsig_rdata.to_vec();
+    for rr in sorted {
+        data.extend_from_slice(&owner);
+        data.extend_from_slice(&rtype.to_be_bytes());
+        data.extend_from_slice(&CLASS_IN.to_be_bytes());
+        data.extend_from_slice(&ttl.to_be_bytes());
+        data.extend_from_slice(&(rr.len() as u16).to_be_bytes());
+        data.extend_from_slice(rr);
+    }
+    data
+}
+
+/// Domain name in canonical wire format (lowercase, uncompressed)
+pub fn encode_name(name: &str) -> Vec<u8> {
+    let mut wire = Vec::with_capacity(name.len() + 2);
+    for label in name.trim_end_matches('.').split('.').filter(|l| !l.is_empty()) {
+        wire.push(label.len() as u8);
+        wire.extend_from_slice(label.to_ascii_lowercase().as_bytes());
+    }
+    wire.push(0);
+    wire
+}
+
+/// Number of labels in a name (RRSIG "labels" field)
+fn label_count(name: &str) -> u8 {
+    name.trim_end_matches('.').split('.').filter(|l| !l.is_empty()).count() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::Engine;
+    use ed25519_dalek::{Signature, Verifier};
+
+    /// Example 1 of RFC 8080 §6
+    fn rfc8080_ksk() -> ZoneKey {
+        let secret = base64::engine::general_purpose::STANDARD
+            .decode("ODIyNjAzODQ2MjgwODAxMjI2NDUxOTAyMDQxNDIyNjI=")
+            .unwrap();
+        ZoneKey::ksk(SigningKey::from_bytes(&secret.try_into().unwrap()))
+    }
+
+    #[test]
+    fn test_rfc8080_key_tag_and_ds() {
+        let ksk = rfc8080_ksk();
+        assert_eq!(
+            base64::engine::general_purpose::STANDARD.encode(&ksk.dnskey_rdata()[4..]),
+            "l02Woi0iS8Aa25FQkUd9RMzZHJpBoRQwAQEX1SxZJA4="
+        );
+        assert_eq!(ksk.key_tag(), 3613);
+
+        let signer = DnssecSigner::new("example.com", ksk, ZoneKey::zsk(SigningKey::from_bytes(&[7; 32])), 86400, 3600);
+        assert_eq!(
+            signer.ds_record(),
+            "example.com. IN DS 3613 15 2 3aa5ab37efce57f737fc1627013fee07bdf241bd10f3b1964ab55c78e79a304b"
+        );
+    }
+
+    #[test]
+    fn test_rrsig_verifies() {
+        let zsk_key = SigningKey::from_bytes(&[7; 32]);
+        let verifying_key = zsk_key.verifying_key();
+        let signer = DnssecSigner::new("Seed.Kratos.Network.", rfc8080_ksk(), ZoneKey::zsk(zsk_key), 86400, 3600);
+
+        assert!(signer.is_apex("seed.kratos.network"));
+        assert!(signer.covers("eu.seed.kratos.network"));
+        assert!(!signer.covers("evilseed.kratos.network"));
+
+        let rrset = vec![vec![10, 0, 0, 1], vec![192, 168, 1, 1]];
+        let rrsig = signer.sign("seed.kratos.network", 1, 60, &rrset, 1_700_000_000);
+        assert_eq!(rrsig.len(), signer.rrsig_rdata_len());
+        assert_eq!(rrsig[3], 3); // labels
+
+        // Signature covers the canonical (sorted) RRset, whatever the answer order
+        let (unsigned, signature) = rrsig.split_at(rrsig.len() - SIGNATURE_LEN);
+        let reordered = vec![rrset[1].clone(), rrset[0].clone()];
+        let data = signing_data(unsigned, "seed.kratos.network", 1, 60, &reordered);
+        let signature = Signature::from_bytes(signature.try_into().unwrap());
+        assert!(verifying_key.verify(&data, &signature).is_ok());
+    }
+}
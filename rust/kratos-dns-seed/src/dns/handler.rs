@@ -2,6 +2,11 @@
 //!
 //! Handles DNS queries and returns appropriate peer records.
 //! Uses a simplified approach compatible with trust-dns-server.
+//!
+//! Subdomains select peers by capability: `warp-sync.seed.kratos.network`
+//! only returns warp-sync providers, and labels can be combined
+//! (`archive.rpc.seed.kratos.network`). Bitcoin-style `x<hex>` labels
+//! carry a raw capability mask (`x5` = archive + warp-sync).
 
 use std::net::IpAddr;
 use std::sync::Arc;
@@ -10,6 +15,7 @@ use tracing::debug;
 
 use crate::config::DnsSeedConfig;
 use crate::registry::PeerRegistry;
+use crate::types::Capabilities;
 
 /// DNS query result
 #[derive(Debug, Clone)]
@@ -50,16 +56,19 @@ impl KratosDnsHandler {
     }
 
     /// Get peer IP addresses for DNS response
-    pub async fn query(&self, _want_ipv6: bool) -> DnsQueryResult {
+    ///
+    /// Only peers offering all `required` capabilities are returned.
+    pub async fn query(&self, _want_ipv6: bool, required: Capabilities) -> DnsQueryResult {
         let registry = self.registry.read().await;
         let timeout = self.config.peer_timeout_secs;
         let max_peers = self.config.max_peers_in_dns_response;
 
         // Get top-scoring, geographically diverse peers
-        let peers = registry.get_diverse_peers(
+        let peers = registry.get_diverse_peers_with(
             max_peers,
             self.config.min_regions_in_response,
             timeout,
+            required,
         );
 
         let mut ipv4_addrs = Vec::new();
@@ -81,9 +90,10 @@ impl KratosDnsHandler {
         ipv6_addrs.shuffle(&mut rng);
 
         debug!(
-            "DNS query result: {} IPv4, {} IPv6 addresses",
+            "DNS query result: {} IPv4, {} IPv6 addresses (capabilities {:?})",
             ipv4_addrs.len(),
-            ipv6_addrs.len()
+            ipv6_addrs.len(),
+            required.names()
         );
 
         DnsQueryResult {
@@ -93,10 +103,38 @@ impl KratosDnsHandler {
         }
     }
 
+    /// Whether queries from `src` must be dropped (blocklisted address)
+    pub async fn is_blocked_source(&self, src: IpAddr) -> bool {
+        self.registry.read().await.is_ip_blocked(src)
+    }
+
     /// Get domain name
     pub fn domain(&self) -> &str {
         &self.config.dns_domain
     }
+
+    /// Capabilities requested by the subdomain labels of `qname`
+    pub fn requested_capabilities(&self, qname: &str) -> Capabilities {
+        capabilities_from_name(qname, self.domain())
+    }
+}
+
+/// Parse capability labels in front of `domain` (unknown labels are ignored)
+fn capabilities_from_name(qname: &str, domain: &str) -> Capabilities {
+    let qname = qname.trim_end_matches('.').to_ascii_lowercase();
+    let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+    let prefix = match qname.strip_suffix(&domain).and_then(|p| p.strip_suffix('.')) {
+        Some(prefix) => prefix,
+        None => return Capabilities::NONE,
+    };
+
+    prefix
+        .split('.')
+        .filter_map(|label| match label.strip_prefix('x') {
+            Some(mask) if !mask.is_empty() => u32::from_str_radix(mask, 16).ok().map(Capabilities),
+            _ => Capabilities::parse_list(label).ok(),
+        })
+        .fold(Capabilities::NONE, |acc, caps| acc | caps)
 }
 
 #[cfg(test)]
@@ -107,7 +145,7 @@ mod tests {
     #[tokio::test]
     async fn test_handler_query_empty() {
         let dir = tempdir().unwrap();
-        let registry = PeerRegistry::open(dir.path().join("registry")).unwrap();
+        let registry = PeerRegistry::open(&dir.path().join("registry")).unwrap();
         let config = Arc::new(DnsSeedConfig::default());
 
         let handler = KratosDnsHandler::new(
@@ -115,8 +153,27 @@ mod tests {
             config,
         );
 
-        let result = handler.query(false).await;
+        let result = handler.query(false, Capabilities::NONE).await;
         assert!(result.ipv4_addrs.is_empty());
         assert!(result.ipv6_addrs.is_empty());
     }
+
+    #[test]
+    fn test_capabilities_from_name() {
+        let domain = "seed.kratos.network";
+        assert_eq!(capabilities_from_name("seed.kratos.network", domain), Capabilities::NONE);
+        assert_eq!(
+            capabilities_from_name("warp-sync.seed.kratos.network", domain),
+            Capabilities::WARP_SYNC
+        );
+        assert_eq!(
+            capabilities_from_name("Archive.RPC.seed.kratos.network.", domain),
+            Capabilities::ARCHIVE | Capabilities::RPC
+        );
+        assert_eq!(
+            capabilities_from_name("x5.seed.kratos.network", domain),
+            Capabilities::ARCHIVE | Capabilities::WARP_SYNC
+        );
+        assert_eq!(capabilities_from_name("warp-sync.other.network", domain), Capabilities::NONE);
+    }
 }
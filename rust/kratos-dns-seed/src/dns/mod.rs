@@ -8,9 +8,12 @@
 //! - A records: IPv4 addresses of active peers
 //! - AAAA records: IPv6 addresses of active peers
 //! - TXT records: Additional peer information (optional)
+//! - DNSKEY / RRSIG records: DNSSEC signatures (when enabled)
 
+pub mod dnssec;
 mod handler;
 mod server;
 
+pub use dnssec::DnssecSigner;
 pub use handler::KratosDnsHandler;
 pub use server::run_dns_server;
@@ -2,6 +2,9 @@
 //!
 //! Implements a basic DNS server that responds to A and AAAA queries
 //! with peer IP addresses from the registry.
+//!
+//! When DNSSEC is enabled, DNSKEY queries for the zone apex are answered and
+//! answers carry RRSIG records for resolvers that set the EDNS DO bit.
 
 use std::net::{SocketAddr, UdpSocket};
 use std::sync::Arc;
@@ -9,6 +12,7 @@ use tokio::sync::RwLock;
 use tracing::{debug, error, info};
 
 use crate::config::DnsSeedConfig;
+use crate::dns::dnssec::{DnssecSigner, TYPE_DNSKEY, TYPE_RRSIG};
 use crate::dns::KratosDnsHandler;
 use crate::registry::PeerRegistry;
 
@@ -16,20 +20,40 @@ use crate::registry::PeerRegistry;
 const DNS_HEADER_SIZE: usize = 12;
 const DNS_MAX_PACKET_SIZE: usize = 512;
 
+/// Largest UDP payload accepted from an EDNS client
+const EDNS_MAX_PACKET_SIZE: usize = 4096;
+
 /// DNS record types
 const TYPE_A: u16 = 1;
 const TYPE_AAAA: u16 = 28;
+const TYPE_OPT: u16 = 41;
 const TYPE_ANY: u16 = 255;
 
+/// IN class
+const CLASS_IN: u16 = 1;
+
+/// EDNS "DNSSEC OK" flag (in the OPT record TTL field)
+const EDNS_FLAG_DO: u32 = 0x8000;
+
 /// DNS flags
 const FLAG_QR: u16 = 0x8000;  // Query/Response
 const FLAG_AA: u16 = 0x0400;  // Authoritative Answer
 const FLAG_RD: u16 = 0x0100;  // Recursion Desired
 
+/// EDNS(0) options of a query (RFC 6891)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Edns {
+    /// UDP payload size advertised by the client
+    udp_size: u16,
+    /// Client wants DNSSEC records
+    dnssec_ok: bool,
+}
+
 /// Run the DNS server
 pub async fn run_dns_server(
     config: Arc<DnsSeedConfig>,
     registry: Arc<RwLock<PeerRegistry>>,
+    dnssec: Option<Arc<DnssecSigner>>,
 ) -> anyhow::Result<()> {
     let addr = SocketAddr::from(([0, 0, 0, 0], config.dns_port));
 
@@ -47,12 +71,13 @@ pub async fn run_dns_server(
     ));
 
     loop {
-        let mut buf = [0u8; DNS_MAX_PACKET_SIZE];
+        let mut buf = [0u8; EDNS_MAX_PACKET_SIZE];
         match socket.recv_from(&mut buf).await {
             Ok((len, src)) => {
                 let request = buf[..len].to_vec();
                 let handler = handler.clone();
                 let socket = socket.clone();
+                let dnssec = dnssec.clone();
 
                 tokio::spawn(async move {
                     if let Err(e) = handle_dns_query(
@@ -60,6 +85,7 @@ pub async fn run_dns_server(
                         src,
                         &request,
                         &handler,
+                        dnssec.as_deref(),
                     ).await {
                         debug!("DNS query error from {}: {}", src, e);
                     }
@@ -82,27 +108,42 @@ async fn handle_dns_query(
     src: SocketAddr,
     request: &[u8],
     handler: &KratosDnsHandler,
+    dnssec: Option<&DnssecSigner>,
 ) -> anyhow::Result<()> {
     if request.len() < DNS_HEADER_SIZE {
         return Ok(()); // Ignore malformed packets
     }
 
+    if handler.is_blocked_source(src.ip()).await {
+        debug!("Dropping DNS query from blocklisted {}", src);
+        return Ok(());
+    }
+
     // Parse header
     let id = u16::from_be_bytes([request[0], request[1]]);
     let flags = u16::from_be_bytes([request[2], request[3]]);
     let qdcount = u16::from_be_bytes([request[4], request[5]]);
+    let arcount = u16::from_be_bytes([request[10], request[11]]);
 
     if qdcount == 0 {
         return Ok(()); // No questions
     }
 
     // Parse question section
-    let (qname, qtype, _offset) = parse_question(&request[DNS_HEADER_SIZE..])?;
+    let (qname, qtype, offset) = parse_question(&request[DNS_HEADER_SIZE..])?;
+
+    // EDNS options follow the question (queries carry no answer/authority records)
+    let edns = if arcount > 0 {
+        parse_edns(&request[DNS_HEADER_SIZE + offset..])
+    } else {
+        None
+    };
 
-    debug!("DNS query: {} type {} from {}", qname, qtype, src);
+    debug!("DNS query: {} type {} from {} (edns={:?})", qname, qtype, src, edns);
 
-    // Get peer IPs
-    let result = handler.query(qtype == TYPE_AAAA).await;
+    // Get peer IPs (subdomains such as warp-sync.<domain> filter by capability)
+    let required = handler.requested_capabilities(&qname);
+    let result = handler.query(qtype == TYPE_AAAA, required).await;
 
     // Build response
     let response = build_dns_response(
@@ -113,6 +154,8 @@ async fn handle_dns_query(
         &result.ipv4_addrs,
         &result.ipv6_addrs,
         result.ttl,
+        edns,
+        dnssec,
     )?;
 
     // Send response
@@ -165,7 +208,29 @@ fn parse_question(data: &[u8]) -> anyhow::Result<(String, u16, usize)> {
     Ok((name, qtype, offset))
 }
 
+/// Parse the OPT pseudo-record of a query, if the first additional record is one
+fn parse_edns(data: &[u8]) -> Option<Edns> {
+    // Root owner name (1 byte) + type + class + ttl + rdlength
+    if data.len() < 11 || data[0] != 0 {
+        return None;
+    }
+
+    let rtype = u16::from_be_bytes([data[1], data[2]]);
+    if rtype != TYPE_OPT {
+        return None;
+    }
+
+    let udp_size = u16::from_be_bytes([data[3], data[4]]);
+    let ttl = u32::from_be_bytes([data[5], data[6], data[7], data[8]]);
+
+    Some(Edns {
+        udp_size,
+        dnssec_ok: ttl & EDNS_FLAG_DO != 0,
+    })
+}
+
 /// Build DNS response packet
+#[allow(clippy::too_many_arguments)]
 fn build_dns_response(
     id: u16,
     request_flags: u16,
@@ -174,26 +239,43 @@ fn build_dns_response(
     ipv4_addrs: &[std::net::Ipv4Addr],
     ipv6_addrs: &[std::net::Ipv6Addr],
     ttl: u32,
+    edns: Option<Edns>,
+    dnssec: Option<&DnssecSigner>,
 ) -> anyhow::Result<Vec<u8>> {
-    let mut response = Vec::with_capacity(DNS_MAX_PACKET_SIZE);
-
-    // Count answers based on query type
-    let ancount = match qtype {
-        TYPE_A => ipv4_addrs.len() as u16,
-        TYPE_AAAA => ipv6_addrs.len() as u16,
-        TYPE_ANY => (ipv4_addrs.len() + ipv6_addrs.len()) as u16,
-        _ => 0,
-    };
+    let max_size = edns
+        .map(|e| e.udp_size as usize)
+        .unwrap_or(DNS_MAX_PACKET_SIZE)
+        .clamp(DNS_MAX_PACKET_SIZE, EDNS_MAX_PACKET_SIZE);
+
+    // Signatures are only valid for names inside the signed zone
+    let dnssec = dnssec.filter(|signer| signer.covers(qname));
+    let sign = dnssec.filter(|_| edns.is_some_and(|e| e.dnssec_ok));
+
+    // Collect answer RRsets based on query type
+    let mut rrsets: Vec<(u16, u32, Vec<Vec<u8>>)> = Vec::new();
+    if qtype == TYPE_A || qtype == TYPE_ANY {
+        rrsets.push((TYPE_A, ttl, ipv4_addrs.iter().map(|ip| ip.octets().to_vec()).collect()));
+    }
+    if qtype == TYPE_AAAA || qtype == TYPE_ANY {
+        rrsets.push((TYPE_AAAA, ttl, ipv6_addrs.iter().map(|ip| ip.octets().to_vec()).collect()));
+    }
+    if let Some(signer) = dnssec.filter(|s| s.is_apex(qname)) {
+        if qtype == TYPE_DNSKEY || qtype == TYPE_ANY {
+            rrsets.push((TYPE_DNSKEY, signer.dnskey_ttl(), signer.dnskey_rrset()));
+        }
+    }
+    rrsets.retain(|(_, _, rdatas)| !rdatas.is_empty());
 
     // Build header
     let flags = FLAG_QR | FLAG_AA | (request_flags & FLAG_RD);
 
+    let mut response = Vec::with_capacity(max_size);
     response.extend_from_slice(&id.to_be_bytes());
     response.extend_from_slice(&flags.to_be_bytes());
     response.extend_from_slice(&1u16.to_be_bytes()); // qdcount = 1
-    response.extend_from_slice(&ancount.to_be_bytes()); // ancount
+    response.extend_from_slice(&0u16.to_be_bytes()); // ancount (set below)
     response.extend_from_slice(&0u16.to_be_bytes()); // nscount = 0
-    response.extend_from_slice(&0u16.to_be_bytes()); // arcount = 0
+    response.extend_from_slice(&(edns.is_some() as u16).to_be_bytes()); // arcount (OPT)
 
     // Build question section (echo back)
     let qname_offset = response.len();
@@ -204,46 +286,68 @@ fn build_dns_response(
     response.push(0); // End of name
 
     response.extend_from_slice(&qtype.to_be_bytes());
-    response.extend_from_slice(&1u16.to_be_bytes()); // IN class
+    response.extend_from_slice(&CLASS_IN.to_be_bytes());
+
+    // Room kept for the RRSIGs and the OPT record
+    let rrsig_size = sign.map(|s| 12 + s.rrsig_rdata_len()).unwrap_or(0);
+    let opt_size = if edns.is_some() { 11 } else { 0 };
+    let budget = max_size.saturating_sub(rrsig_size * rrsets.len() + opt_size);
 
     // Build answer section
     let name_ptr = 0xC000 | (qname_offset as u16); // Compression pointer
+    let mut ancount: u16 = 0;
 
-    // Add A records
-    if qtype == TYPE_A || qtype == TYPE_ANY {
-        for ip in ipv4_addrs {
-            if response.len() + 16 > DNS_MAX_PACKET_SIZE {
+    for (rtype, rr_ttl, rdatas) in &rrsets {
+        let mut included = Vec::new();
+        for rdata in rdatas {
+            if response.len() + 12 + rdata.len() > budget {
                 break; // Stop if we'd exceed packet size
             }
+            push_record(&mut response, name_ptr, *rtype, *rr_ttl, rdata);
+            included.push(rdata.clone());
+        }
+        ancount += included.len() as u16;
 
-            response.extend_from_slice(&name_ptr.to_be_bytes());
-            response.extend_from_slice(&TYPE_A.to_be_bytes());
-            response.extend_from_slice(&1u16.to_be_bytes()); // IN class
-            response.extend_from_slice(&ttl.to_be_bytes());
-            response.extend_from_slice(&4u16.to_be_bytes()); // rdlength
-            response.extend_from_slice(&ip.octets());
+        // Sign exactly the records that made it into the packet
+        if let Some(signer) = sign.filter(|_| !included.is_empty()) {
+            let rrsig = signer.sign(qname, *rtype, *rr_ttl, &included, unix_now());
+            push_record(&mut response, name_ptr, TYPE_RRSIG, *rr_ttl, &rrsig);
+            ancount += 1;
         }
     }
 
-    // Add AAAA records
-    if qtype == TYPE_AAAA || qtype == TYPE_ANY {
-        for ip in ipv6_addrs {
-            if response.len() + 28 > DNS_MAX_PACKET_SIZE {
-                break; // Stop if we'd exceed packet size
-            }
+    response[6..8].copy_from_slice(&ancount.to_be_bytes());
 
-            response.extend_from_slice(&name_ptr.to_be_bytes());
-            response.extend_from_slice(&TYPE_AAAA.to_be_bytes());
-            response.extend_from_slice(&1u16.to_be_bytes()); // IN class
-            response.extend_from_slice(&ttl.to_be_bytes());
-            response.extend_from_slice(&16u16.to_be_bytes()); // rdlength
-            response.extend_from_slice(&ip.octets());
-        }
+    // OPT record echoing our payload size and the DO bit
+    if let Some(edns) = edns {
+        response.push(0); // Root name
+        response.extend_from_slice(&TYPE_OPT.to_be_bytes());
+        response.extend_from_slice(&(EDNS_MAX_PACKET_SIZE as u16).to_be_bytes());
+        let opt_ttl = if edns.dnssec_ok { EDNS_FLAG_DO } else { 0 };
+        response.extend_from_slice(&opt_ttl.to_be_bytes());
+        response.extend_from_slice(&0u16.to_be_bytes()); // rdlength
     }
 
     Ok(response)
 }
 
+/// Append a resource record whose owner is a compressed name pointer
+fn push_record(response: &mut Vec<u8>, name_ptr: u16, rtype: u16, ttl: u32, rdata: &[u8]) {
+    response.extend_from_slice(&name_ptr.to_be_bytes());
+    response.extend_from_slice(&rtype.to_be_bytes());
+    response.extend_from_slice(&CLASS_IN.to_be_bytes());
+    response.extend_from_slice(&ttl.to_be_bytes());
+    response.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+    response.extend_from_slice(rdata);
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -283,6 +387,8 @@ mod tests {
             &ipv4,
             &ipv6,
             60,
+            None,
+            None,
         ).unwrap();
 
         // Check header
@@ -290,4 +396,52 @@ mod tests {
         // Check answer count
         assert_eq!(u16::from_be_bytes([response[6], response[7]]), 2);
     }
+
+    #[test]
+    fn test_signed_response() {
+        use crate::dns::dnssec::ZoneKey;
+        use ed25519_dalek::SigningKey;
+
+        let signer = DnssecSigner::new(
+            "seed.kratos.network",
+            ZoneKey::ksk(SigningKey::from_bytes(&[1; 32])),
+            ZoneKey::zsk(SigningKey::from_bytes(&[2; 32])),
+            86400,
+            3600,
+        );
+        let ipv4 = vec![std::net::Ipv4Addr::new(192, 168, 1, 1)];
+        let edns = Edns { udp_size: 1232, dnssec_ok: true };
+
+        // A query with DO bit: A record + RRSIG, then OPT
+        let response = build_dns_response(
+            1, 0, "seed.kratos.network", TYPE_A, &ipv4, &[], 60, Some(edns), Some(&signer),
+        ).unwrap();
+        assert_eq!(u16::from_be_bytes([response[6], response[7]]), 2);
+        assert_eq!(u16::from_be_bytes([response[10], response[11]]), 1);
+
+        // Without DO bit: no signature
+        let plain = Edns { dnssec_ok: false, ..edns };
+        let response = build_dns_response(
+            1, 0, "seed.kratos.network", TYPE_A, &ipv4, &[], 60, Some(plain), Some(&signer),
+        ).unwrap();
+        assert_eq!(u16::from_be_bytes([response[6], response[7]]), 1);
+
+        // DNSKEY query at the apex: KSK + ZSK + RRSIG
+        let response = build_dns_response(
+            1, 0, "seed.kratos.network", TYPE_DNSKEY, &ipv4, &[], 60, Some(edns), Some(&signer),
+        ).unwrap();
+        assert_eq!(u16::from_be_bytes([response[6], response[7]]), 3);
+    }
+
+    #[test]
+    fn test_parse_edns() {
+        let mut opt = vec![0];
+        opt.extend_from_slice(&TYPE_OPT.to_be_bytes());
+        opt.extend_from_slice(&1232u16.to_be_bytes());
+        opt.extend_from_slice(&EDNS_FLAG_DO.to_be_bytes());
+        opt.extend_from_slice(&0u16.to_be_bytes());
+
+        assert_eq!(parse_edns(&opt), Some(Edns { udp_size: 1232, dnssec_ok: true }));
+        assert_eq!(parse_edns(&opt[..5]), None);
+    }
 }
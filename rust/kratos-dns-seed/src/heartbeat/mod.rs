@@ -9,19 +9,24 @@
 //! 2. Node sends HeartbeatMessage (bincode serialized)
 //! 3. DNS Seed verifies signature
 //! 4. DNS Seed updates peer registry
-//! 5. DNS Seed responds with HeartbeatResponse
+//! 5. DNS Seed responds with HeartbeatResponse (with a signed receipt for
+//!    validators, which they can submit on-chain as proof of uptime)
 //!
 //! ## Security
 //!
 //! - All heartbeats must be signed with the node's Ed25519 key
 //! - Rate limiting per IP to prevent DoS
 //! - Genesis hash validation to prevent wrong-chain peers
+//! - Blocklisted peer IDs and addresses are refused
 
 mod protocol;
 mod rate_limiter;
 
 pub use rate_limiter::RateLimiter;
 
+use protocol::{HEARTBEAT_PROTOCOL_VERSION, MIN_HEARTBEAT_PROTOCOL_VERSION};
+
+use ed25519_dalek::SigningKey;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
@@ -46,7 +51,9 @@ pub async fn run_receiver(
     config: Arc<DnsSeedConfig>,
     registry: Arc<RwLock<PeerRegistry>>,
     network_state: Arc<RwLock<NetworkStateAggregator>>,
+    keypair: SigningKey,
 ) -> anyhow::Result<()> {
+    let keypair = Arc::new(keypair);
     let addr = SocketAddr::from(([0, 0, 0, 0], config.heartbeat_port));
     let listener = TcpListener::bind(addr).await?;
 
@@ -66,6 +73,7 @@ pub async fn run_receiver(
                 let registry = registry.clone();
                 let network_state = network_state.clone();
                 let rate_limiter = rate_limiter.clone();
+                let keypair = keypair.clone();
 
                 tokio::spawn(async move {
                     if let Err(e) = handle_connection(
@@ -75,6 +83,7 @@ pub async fn run_receiver(
                         registry,
                         network_state,
                         rate_limiter,
+                        keypair,
                     ).await {
                         debug!("Connection error from {}: {}", peer_addr, e);
                     }
@@ -95,6 +104,7 @@ async fn handle_connection(
     registry: Arc<RwLock<PeerRegistry>>,
     network_state: Arc<RwLock<NetworkStateAggregator>>,
     rate_limiter: Arc<RwLock<RateLimiter>>,
+    keypair: Arc<SigningKey>,
 ) -> anyhow::Result<()> {
     let peer_ip = peer_addr.ip();
 
@@ -108,6 +118,7 @@ async fn handle_connection(
                 error: Some("Rate limited".to_string()),
                 network_state: None,
                 timestamp: current_timestamp(),
+                receipt: None,
             };
             send_response(&mut stream, &response).await?;
             return Ok(());
@@ -131,6 +142,7 @@ async fn handle_connection(
             error: Some("Message too large".to_string()),
             network_state: None,
             timestamp: current_timestamp(),
+            receipt: None,
         };
         send_response(&mut stream, &response).await?;
         return Ok(());
@@ -142,7 +154,7 @@ async fn handle_connection(
         .map_err(|_| anyhow::anyhow!("Connection timeout"))??;
 
     // Deserialize message
-    let message: HeartbeatMessage = match bincode::deserialize(&msg_buf) {
+    let message = match HeartbeatMessage::decode(&msg_buf) {
         Ok(m) => m,
         Err(e) => {
             warn!("Invalid message from {}: {}", peer_addr, e);
@@ -151,6 +163,7 @@ async fn handle_connection(
                 error: Some("Invalid message format".to_string()),
                 network_state: None,
                 timestamp: current_timestamp(),
+                receipt: None,
             };
             send_response(&mut stream, &response).await?;
             return Ok(());
@@ -174,24 +187,39 @@ async fn handle_connection(
             error: Some(error),
             network_state: None,
             timestamp: current_timestamp(),
+            receipt: None,
         };
         send_response(&mut stream, &response).await?;
         return Ok(());
     }
 
-    // Create peer info and update registry
+    // Create peer info and update registry (unless blocklisted)
     let peer_info = PeerInfo::from_heartbeat(&message, config.initial_peer_score);
 
     {
         let mut reg = registry.write().await;
+        if let Some(block) = reg.blocked_reason(&peer_info, peer_ip) {
+            debug!("Blocklisted heartbeat from {}: {}", peer_addr, block.reason);
+            let response = HeartbeatResponse {
+                accepted: false,
+                error: Some(format!("Blocklisted: {}", block.reason)),
+                network_state: None,
+                timestamp: current_timestamp(),
+                receipt: None,
+            };
+            drop(reg);
+            send_response(&mut stream, &response).await?;
+            return Ok(());
+        }
         reg.update_peer(peer_info);
     }
 
     debug!(
-        "💓 Heartbeat from {} (height={}, validator={})",
+        "💓 Heartbeat from {} (height={}, validator={}, capabilities={:?})",
         hex::encode(&message.peer_id[..8]),
         message.current_height,
-        message.is_validator
+        message.is_validator,
+        message.capabilities.names()
     );
 
     // Prepare response with current network state
@@ -200,11 +228,18 @@ async fn handle_connection(
         Some(state.current_state())
     };
 
+    // Validators get a receipt they can submit on-chain as uptime proof
+    let timestamp = current_timestamp();
+    let receipt = message
+        .is_validator
+        .then(|| crypto::sign_receipt(&keypair, &message, timestamp));
+
     let response = HeartbeatResponse {
         accepted: true,
         error: None,
         network_state: network_state_info,
-        timestamp: current_timestamp(),
+        timestamp,
+        receipt,
     };
 
     send_response(&mut stream, &response).await?;
@@ -215,7 +250,7 @@ async fn handle_connection(
 /// Validate a heartbeat message
 fn validate_heartbeat(message: &HeartbeatMessage, config: &DnsSeedConfig) -> Result<(), String> {
     // Check version
-    if message.version != 1 {
+    if !(MIN_HEARTBEAT_PROTOCOL_VERSION..=HEARTBEAT_PROTOCOL_VERSION).contains(&message.version) {
         return Err(format!("Unsupported version: {}", message.version));
     }
 
@@ -325,6 +360,7 @@ mod tests {
             protocol_version: 1,
             timestamp: current_timestamp(),
             signature: [0u8; 64],
+            capabilities: Default::default(),
         }
     }
 }
@@ -1,7 +1,10 @@
 //! Heartbeat Protocol Constants and Utilities
 
-/// Protocol version for heartbeat messages
-pub const HEARTBEAT_PROTOCOL_VERSION: u32 = 1;
+/// Protocol version for heartbeat messages (v2 adds capabilities)
+pub const HEARTBEAT_PROTOCOL_VERSION: u32 = 2;
+
+/// Oldest heartbeat version still accepted
+pub const MIN_HEARTBEAT_PROTOCOL_VERSION: u32 = 1;
 
 /// Magic bytes for protocol identification
 pub const PROTOCOL_MAGIC: [u8; 4] = *b"KRAT";
@@ -24,11 +24,12 @@
 //! │  DNS Server (53)            ←── Responds to DNS queries    │
 //! │  Peers File Generator       ←── Creates signed IDpeers.json│
 //! │  HTTP API (8080)            ←── Metrics and monitoring     │
+//! │  Blocklist Subscriptions    ←── Signed lists of other seeds│
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
 
-use clap::Parser;
-use std::path::PathBuf;
+use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{info, warn, error};
@@ -42,12 +43,15 @@ mod network_state;
 mod distribution;
 mod dns;
 mod api;
+mod bench;
 
 use config::DnsSeedConfig;
 use registry::PeerRegistry;
 use network_state::NetworkStateAggregator;
 use distribution::IdPeersGenerator;
 use api::Metrics;
+use dns::dnssec::ZoneKey;
+use dns::DnssecSigner;
 
 /// KratOs DNS Seed - Decentralized peer discovery service
 #[derive(Parser, Debug)]
@@ -91,6 +95,19 @@ struct Args {
     /// Path to signing key file
     #[arg(long)]
     key_file: Option<PathBuf>,
+
+    /// Sign DNS responses with DNSSEC (keys are generated in the data directory if missing)
+    #[arg(long)]
+    dnssec: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Load-test a running seed with simulated heartbeats and DNS queries
+    Bench(bench::BenchArgs),
 }
 
 #[tokio::main]
@@ -108,6 +125,11 @@ async fn main() -> anyhow::Result<()> {
     info!("🌐 KratOs DNS Seed Service v{}", env!("CARGO_PKG_VERSION"));
     info!("   Aligned with KratOs Constitution - Decentralization, Sovereignty, Resilience");
 
+    // Handle load testing
+    if let Some(Command::Bench(bench_args)) = args.command {
+        return bench::run(bench_args).await;
+    }
+
     // Handle key generation
     if args.generate_key {
         return generate_keypair(&args.data_dir).await;
@@ -134,7 +156,8 @@ async fn main() -> anyhow::Result<()> {
         .with_heartbeat_port(args.heartbeat_port)
         .with_dns_port(args.dns_port)
         .with_api_port(args.api_port)
-        .with_genesis_hash(args.genesis_hash);
+        .with_genesis_hash(args.genesis_hash)
+        .with_dnssec(args.dnssec);
 
     config.validate()?;
 
@@ -145,6 +168,13 @@ async fn main() -> anyhow::Result<()> {
     info!("   Heartbeat interval: {}s", config.heartbeat_interval_secs);
     info!("   Peer timeout: {}s", config.peer_timeout_secs);
 
+    // Load or generate DNSSEC zone keys
+    let dnssec = if config.dnssec.enabled {
+        Some(Arc::new(load_dnssec_signer(&config, &args.data_dir).await?))
+    } else {
+        None
+    };
+
     let shared_config = Arc::new(config);
 
     // Initialize peer registry
@@ -170,10 +200,13 @@ async fn main() -> anyhow::Result<()> {
         NetworkStateAggregator::new(genesis_hash, genesis_timestamp)
     ));
 
+    // This seed's own blocklist entries come from the config
+    registry.write().await.set_local_blocklist(shared_config.blocklist.entries.clone());
+
     // Initialize IDpeers generator
     let idpeers_path = args.data_dir.join("idpeers.json");
     let generator = Arc::new(RwLock::new(
-        IdPeersGenerator::new(keypair, shared_config.clone(), idpeers_path)
+        IdPeersGenerator::new(keypair.clone(), shared_config.clone(), idpeers_path)
     ));
 
     // Initialize metrics
@@ -184,11 +217,13 @@ async fn main() -> anyhow::Result<()> {
         shared_config.clone(),
         registry.clone(),
         network_state.clone(),
+        keypair,
     ));
 
     let dns_handle = tokio::spawn(dns::run_dns_server(
         shared_config.clone(),
         registry.clone(),
+        dnssec,
     ));
 
     let idpeers_handle = tokio::spawn(distribution::generator::run_periodic_generation(
@@ -206,6 +241,11 @@ async fn main() -> anyhow::Result<()> {
         metrics.clone(),
     ));
 
+    let blocklist_handle = tokio::spawn(distribution::blocklist::run_subscriptions(
+        shared_config.clone(),
+        registry.clone(),
+    ));
+
     let maintenance_handle = tokio::spawn(run_maintenance(
         shared_config.clone(),
         registry.clone(),
@@ -232,6 +272,9 @@ async fn main() -> anyhow::Result<()> {
         result = api_handle => {
             error!("HTTP API exited: {:?}", result);
         }
+        result = blocklist_handle => {
+            error!("Blocklist subscriptions exited: {:?}", result);
+        }
         result = maintenance_handle => {
             error!("Maintenance task exited: {:?}", result);
         }
@@ -267,6 +310,29 @@ async fn generate_keypair(data_dir: &PathBuf) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Load the DNSSEC KSK and ZSK (generating them on first start)
+async fn load_dnssec_signer(config: &DnsSeedConfig, data_dir: &Path) -> anyhow::Result<DnssecSigner> {
+    let ksk_path = config.dnssec.ksk_file.clone().unwrap_or_else(|| data_dir.join("dnssec_ksk.key"));
+    let zsk_path = config.dnssec.zsk_file.clone().unwrap_or_else(|| data_dir.join("dnssec_zsk.key"));
+
+    let ksk = crypto::load_or_generate_keypair(data_dir, Some(&ksk_path)).await?;
+    let zsk = crypto::load_or_generate_keypair(data_dir, Some(&zsk_path)).await?;
+
+    let signer = DnssecSigner::new(
+        &config.dns_domain,
+        ZoneKey::ksk(ksk),
+        ZoneKey::zsk(zsk),
+        config.dnssec.signature_validity_secs,
+        config.dnssec.dnskey_ttl,
+    );
+
+    info!("🔏 DNSSEC enabled for {}", config.dns_domain);
+    info!("   Publish this DS record in the parent zone:");
+    info!("   {}", signer.ds_record());
+
+    Ok(signer)
+}
+
 /// Periodic maintenance tasks
 async fn run_maintenance(
     config: Arc<DnsSeedConfig>,
@@ -287,6 +353,7 @@ async fn run_maintenance(
             if removed > 0 {
                 info!("🧹 Removed {} stale peers", removed);
             }
+            reg.prune_blocklist();
         }
 
         // Update network state aggregation
@@ -264,6 +264,8 @@ mod tests {
             score: 100,
             region: None,
             protocol_version: 1,
+            libp2p_peer_id: format!("12D3KooWTestPeer{}", id),
+            capabilities: Default::default(),
         }
     }
 
@@ -0,0 +1,199 @@
+//! Peer Blocklist
+//!
+//! Merges this seed's own blocklist entries with the signed documents
+//! fetched from subscribed seeds. Each subscribed seed contributes only
+//! its latest document, so removing an entry upstream removes it here
+//! on the next refresh.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use crate::types::{BlocklistDocument, BlocklistEntry, BlocklistTarget, PeerInfo, SeedId};
+
+/// Why and until when a target is blocked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockReason {
+    /// Reason given by the reporting seed
+    pub reason: String,
+
+    /// Unix timestamp at which the block lifts
+    pub expires_at: u64,
+
+    /// Reporting seed (None for this seed's own entries)
+    pub source: Option<SeedId>,
+}
+
+/// Active blocklist of a seed
+#[derive(Debug, Default)]
+pub struct Blocklist {
+    /// Entries reported by this seed
+    local: Vec<BlocklistEntry>,
+
+    /// Latest verified document of each subscribed seed
+    subscribed: HashMap<SeedId, BlocklistDocument>,
+
+    /// Merged lookup index (longest expiry wins)
+    index: HashMap<BlocklistTarget, BlockReason>,
+}
+
+impl Blocklist {
+    /// Create a blocklist with this seed's own entries
+    pub fn new(local: Vec<BlocklistEntry>) -> Self {
+        let mut blocklist = Self { local, ..Default::default() };
+        blocklist.rebuild();
+        blocklist
+    }
+
+    /// Replace this seed's own entries
+    pub fn set_local(&mut self, local: Vec<BlocklistEntry>) {
+        self.local = local;
+        self.rebuild();
+    }
+
+    /// This seed's own unexpired entries (the ones it publishes)
+    pub fn local_entries(&self, now: u64) -> Vec<BlocklistEntry> {
+        self.local.iter().filter(|e| !e.is_expired(now)).cloned().collect()
+    }
+
+    /// Apply a document whose signature has already been verified
+    ///
+    /// Returns false if a document at least as recent from the same seed
+    /// is already held (replayed or out-of-order fetch).
+    pub fn apply(&mut self, doc: BlocklistDocument) -> bool {
+        if let Some(current) = self.subscribed.get(&doc.seed_id) {
+            if current.issued_at >= doc.issued_at {
+                return false;
+            }
+        }
+        self.subscribed.insert(doc.seed_id, doc);
+        self.rebuild();
+        true
+    }
+
+    /// Subscribed documents currently held
+    pub fn documents(&self) -> impl Iterator<Item = &BlocklistDocument> {
+        self.subscribed.values()
+    }
+
+    /// Why `target` is blocked at `now`, if it is
+    pub fn reason(&self, target: &BlocklistTarget, now: u64) -> Option<&BlockReason> {
+        self.index.get(target).filter(|r| r.expires_at > now)
+    }
+
+    /// Whether an IP address is blocked at `now`
+    pub fn blocks_ip(&self, ip: IpAddr, now: u64) -> bool {
+        self.reason(&BlocklistTarget::Ip(ip), now).is_some()
+    }
+
+    /// Why a peer is blocked: by identity, or by any address it advertises
+    pub fn peer_reason(&self, peer: &PeerInfo, now: u64) -> Option<&BlockReason> {
+        self.reason(&BlocklistTarget::Peer(peer.peer_id), now).or_else(|| {
+            peer.ips()
+                .into_iter()
+                .find_map(|ip| self.reason(&BlocklistTarget::Ip(ip), now))
+        })
+    }
+
+    /// Number of targets blocked at `now`
+    pub fn active_count(&self, now: u64) -> usize {
+        self.index.values().filter(|r| r.expires_at > now).count()
+    }
+
+    /// Drop expired entries
+    pub fn prune(&mut self, now: u64) -> usize {
+        let before = self.index.len();
+        self.local.retain(|e| !e.is_expired(now));
+        for doc in self.subscribed.values_mut() {
+            doc.entries.retain(|e| !e.is_expired(now));
+        }
+        self.rebuild();
+        before - self.index.len()
+    }
+
+    /// Rebuild the lookup index from local and subscribed entries
+    fn rebuild(&mut self) {
+        let local = self.local.iter().map(|e| (e, None));
+        let subscribed = self
+            .subscribed
+            .values()
+            .flat_map(|doc| doc.entries.iter().map(move |e| (e, Some(doc.seed_id))));
+
+        self.index.clear();
+        for (entry, source) in local.chain(subscribed) {
+            let longer = self
+                .index
+                .get(&entry.target)
+                .is_none_or(|r| entry.expires_at > r.expires_at);
+            if longer {
+                self.index.insert(entry.target, BlockReason {
+                    reason: entry.reason.clone(),
+                    expires_at: entry.expires_at,
+                    source,
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Capabilities;
+
+    fn entry(target: BlocklistTarget, expires_at: u64) -> BlocklistEntry {
+        BlocklistEntry { target, reason: "test".to_string(), expires_at }
+    }
+
+    fn doc(seed: u8, issued_at: u64, entries: Vec<BlocklistEntry>) -> BlocklistDocument {
+        BlocklistDocument { version: 1, seed_id: [seed; 32], issued_at, entries, signature: [0u8; 64] }
+    }
+
+    #[test]
+    fn test_merge_and_expiry() {
+        let ip: IpAddr = "203.0.113.7".parse().unwrap();
+        let mut blocklist = Blocklist::new(vec![entry(BlocklistTarget::Ip(ip), 100)]);
+        assert!(blocklist.blocks_ip(ip, 50));
+        assert!(!blocklist.blocks_ip(ip, 100));
+
+        // A subscribed seed reporting the same address for longer extends the block
+        assert!(blocklist.apply(doc(1, 10, vec![entry(BlocklistTarget::Ip(ip), 200)])));
+        assert_eq!(blocklist.reason(&BlocklistTarget::Ip(ip), 150).unwrap().source, Some([1; 32]));
+
+        // Older or replayed documents are ignored
+        assert!(!blocklist.apply(doc(1, 10, vec![])));
+        assert!(blocklist.blocks_ip(ip, 150));
+
+        // A newer document without the entry withdraws it
+        assert!(blocklist.apply(doc(1, 11, vec![])));
+        assert!(!blocklist.blocks_ip(ip, 150));
+        assert_eq!(blocklist.active_count(50), 1);
+        assert_eq!(blocklist.prune(100), 1);
+        assert!(blocklist.local_entries(0).is_empty());
+    }
+
+    #[test]
+    fn test_peer_reason() {
+        let peer = PeerInfo {
+            peer_id: [5u8; 32],
+            libp2p_peer_id: "12D3KooWTestPeer".to_string(),
+            addresses: vec!["/ip4/10.0.0.1/tcp/30333".to_string(), "/ip4/198.51.100.2/tcp/30333".to_string()],
+            last_seen: 0,
+            height: 0,
+            is_validator: false,
+            score: 100,
+            region: None,
+            protocol_version: 1,
+            capabilities: Capabilities::NONE,
+        };
+
+        let mut blocklist = Blocklist::default();
+        assert!(blocklist.peer_reason(&peer, 0).is_none());
+
+        blocklist.set_local(vec![entry(BlocklistTarget::Ip("198.51.100.2".parse().unwrap()), 10)]);
+        assert!(blocklist.peer_reason(&peer, 0).is_some());
+
+        blocklist.set_local(vec![entry(BlocklistTarget::Peer([5u8; 32]), 10)]);
+        assert!(blocklist.peer_reason(&peer, 0).is_some());
+        assert!(blocklist.peer_reason(&peer, 10).is_none());
+    }
+}
@@ -4,6 +4,7 @@
 //! Tracks peer health scores, last seen times, and network metadata.
 
 mod storage;
+mod blocklist;
 pub mod scoring;
 
 pub use storage::PeerRegistry;
@@ -5,15 +5,18 @@
 
 use rocksdb::{DB, Options, IteratorMode};
 use std::collections::HashMap;
+use std::net::IpAddr;
 use std::path::Path;
 use tracing::{debug, info, warn};
 
-use crate::types::{PeerId, PeerInfo, BlockNumber};
+use crate::types::{BlockNumber, BlocklistDocument, BlocklistEntry, BlocklistTarget, Capabilities, PeerId, PeerInfo, SeedId};
+use super::blocklist::{BlockReason, Blocklist};
 use super::RegistryStats;
 
 /// Key prefixes for different data types
 const PREFIX_PEER: &[u8] = b"peer:";
 const PREFIX_META: &[u8] = b"meta:";
+const PREFIX_BLOCKLIST: &[u8] = b"blocklist:";
 
 /// Metadata keys
 const META_BEST_HEIGHT: &[u8] = b"meta:best_height";
@@ -32,6 +35,9 @@ pub struct PeerRegistry {
 
     /// Best known block height
     best_height: BlockNumber,
+
+    /// Blocked peers and addresses (own and subscribed)
+    blocklist: Blocklist,
 }
 
 impl PeerRegistry {
@@ -59,7 +65,7 @@ impl PeerRegistry {
             }
 
             // Deserialize peer info
-            if let Ok(peer_info) = bincode::deserialize::<PeerInfo>(&value) {
+            if let Some(peer_info) = decode_peer(&value) {
                 if peer_info.height > best_height {
                     best_height = peer_info.height;
                 }
@@ -69,11 +75,24 @@ impl PeerRegistry {
 
         info!("📦 Loaded {} peers from registry, best height {}", cache.len(), best_height);
 
+        // Restore subscribed blocklists so they apply before the first refresh
+        let mut blocklist = Blocklist::default();
+        for item in db.prefix_iterator(PREFIX_BLOCKLIST) {
+            let (key, value) = item?;
+            if !key.starts_with(PREFIX_BLOCKLIST) {
+                break;
+            }
+            if let Ok(doc) = bincode::deserialize::<BlocklistDocument>(&value) {
+                blocklist.apply(doc);
+            }
+        }
+
         Ok(Self {
             db,
             cache,
             max_peers: 10000,
             best_height,
+            blocklist,
         })
     }
 
@@ -125,11 +144,13 @@ impl PeerRegistry {
         self.cache.get(peer_id)
     }
 
-    /// Get all active peers (not stale)
+    /// Get all active peers (not stale, not blocklisted)
     pub fn get_active_peers(&self, timeout_secs: u64) -> Vec<&PeerInfo> {
+        let now = current_timestamp();
         self.cache
             .values()
             .filter(|p| !p.is_stale(timeout_secs))
+            .filter(|p| self.blocklist.peer_reason(p, now).is_none())
             .collect()
     }
 
@@ -142,11 +163,23 @@ impl PeerRegistry {
 
     /// Get peers with geographic diversity
     pub fn get_diverse_peers(&self, n: usize, min_regions: usize, timeout_secs: u64) -> Vec<&PeerInfo> {
+        self.get_diverse_peers_with(n, min_regions, timeout_secs, Capabilities::NONE)
+    }
+
+    /// Get peers with geographic diversity that offer all `required` capabilities
+    pub fn get_diverse_peers_with(
+        &self,
+        n: usize,
+        min_regions: usize,
+        timeout_secs: u64,
+        required: Capabilities,
+    ) -> Vec<&PeerInfo> {
         let mut result = Vec::new();
         let mut regions_seen: HashMap<String, usize> = HashMap::new();
 
         // First pass: get peers from different regions
         let mut all_peers: Vec<_> = self.get_active_peers(timeout_secs);
+        all_peers.retain(|p| p.capabilities.contains(required));
         all_peers.sort_by(|a, b| b.score.cmp(&a.score));
 
         for peer in &all_peers {
@@ -282,6 +315,64 @@ impl PeerRegistry {
         }
     }
 
+    /// Set this seed's own blocklist entries
+    pub fn set_local_blocklist(&mut self, entries: Vec<BlocklistEntry>) {
+        self.blocklist.set_local(entries);
+    }
+
+    /// Apply a verified blocklist document from a subscribed seed
+    ///
+    /// Returns the number of registered peers it newly blocks, or None if
+    /// a document at least as recent from that seed was already applied.
+    pub fn apply_blocklist(&mut self, doc: BlocklistDocument) -> Option<usize> {
+        let now = current_timestamp();
+        let before = self.get_active_peers(u64::MAX).len();
+        let key = blocklist_key(&doc.seed_id);
+        let value = bincode::serialize(&doc).ok();
+
+        if !self.blocklist.apply(doc) {
+            return None;
+        }
+
+        if let Some(value) = value {
+            if let Err(e) = self.db.put(&key, &value) {
+                warn!("Failed to persist blocklist: {}", e);
+            }
+        }
+
+        let blocked = before - self.get_active_peers(u64::MAX).len();
+        debug!("Blocklist applied, {} targets active, {} peers newly blocked", self.blocklist.active_count(now), blocked);
+        Some(blocked)
+    }
+
+    /// Why a heartbeat from `peer` (connecting from `ip`) must be refused, if it must
+    pub fn blocked_reason(&self, peer: &PeerInfo, ip: IpAddr) -> Option<&BlockReason> {
+        let now = current_timestamp();
+        self.blocklist
+            .peer_reason(peer, now)
+            .or_else(|| self.blocklist.reason(&BlocklistTarget::Ip(ip), now))
+    }
+
+    /// Whether an address is blocklisted
+    pub fn is_ip_blocked(&self, ip: IpAddr) -> bool {
+        self.blocklist.blocks_ip(ip, current_timestamp())
+    }
+
+    /// Number of peers and addresses currently blocked
+    pub fn blocklisted_count(&self) -> usize {
+        self.blocklist.active_count(current_timestamp())
+    }
+
+    /// Current blocklist
+    pub fn blocklist(&self) -> &Blocklist {
+        &self.blocklist
+    }
+
+    /// Drop expired blocklist entries
+    pub fn prune_blocklist(&mut self) -> usize {
+        self.blocklist.prune(current_timestamp())
+    }
+
     /// Flush all changes to disk
     pub fn flush(&self) -> anyhow::Result<()> {
         self.db.flush()?;
@@ -289,6 +380,30 @@ impl PeerRegistry {
     }
 }
 
+/// Decode a stored peer, accepting entries written before capabilities existed
+fn decode_peer(value: &[u8]) -> Option<PeerInfo> {
+    bincode::deserialize::<PeerInfo>(value).ok().or_else(|| {
+        let mut padded = value.to_vec();
+        padded.extend_from_slice(&Capabilities::NONE.0.to_le_bytes());
+        bincode::deserialize::<PeerInfo>(&padded).ok()
+    })
+}
+
+/// Create storage key for a subscribed seed's blocklist
+fn blocklist_key(seed_id: &SeedId) -> Vec<u8> {
+    let mut key = Vec::with_capacity(PREFIX_BLOCKLIST.len() + 32);
+    key.extend_from_slice(PREFIX_BLOCKLIST);
+    key.extend_from_slice(seed_id);
+    key
+}
+
+fn current_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
 /// Create storage key for a peer
 fn peer_key(peer_id: &PeerId) -> Vec<u8> {
     let mut key = Vec::with_capacity(PREFIX_PEER.len() + 32);
@@ -318,6 +433,8 @@ mod tests {
             score: 100,
             region: Some(if id % 2 == 0 { "EU".to_string() } else { "US".to_string() }),
             protocol_version: 1,
+            libp2p_peer_id: format!("12D3KooWTestPeer{}", id),
+            capabilities: Capabilities::NONE,
         }
     }
 
@@ -384,6 +501,85 @@ mod tests {
         assert!(top[0].score >= top[1].score);
     }
 
+    #[test]
+    fn test_diverse_peers_with_capabilities() {
+        let dir = tempdir().unwrap();
+        let mut registry = PeerRegistry::open(dir.path()).unwrap();
+
+        for i in 0..6 {
+            let mut peer = create_test_peer(i, 100, false);
+            if i % 3 == 0 {
+                peer.capabilities = Capabilities::WARP_SYNC | Capabilities::ARCHIVE;
+            }
+            registry.update_peer(peer);
+        }
+
+        let warp = registry.get_diverse_peers_with(10, 2, 240, Capabilities::WARP_SYNC);
+        assert_eq!(warp.len(), 2);
+        assert!(warp.iter().all(|p| p.capabilities.contains(Capabilities::WARP_SYNC)));
+        assert!(registry.get_diverse_peers_with(10, 2, 240, Capabilities::RPC).is_empty());
+        assert_eq!(registry.get_diverse_peers(10, 2, 240).len(), 6);
+    }
+
+    #[test]
+    fn test_decode_peer_without_capabilities() {
+        let mut peer = create_test_peer(1, 100, false);
+        peer.capabilities = Capabilities::ARCHIVE;
+        let bytes = bincode::serialize(&peer).unwrap();
+
+        // Entries persisted by older versions lack the trailing capabilities
+        let legacy = decode_peer(&bytes[..bytes.len() - 4]).unwrap();
+        assert_eq!(legacy.height, 100);
+        assert!(legacy.capabilities.is_empty());
+        assert_eq!(decode_peer(&bytes).unwrap().capabilities, Capabilities::ARCHIVE);
+    }
+
+    #[test]
+    fn test_blocklist_filters_peers() {
+        let dir = tempdir().unwrap();
+        let now = current_timestamp();
+
+        {
+            let mut registry = PeerRegistry::open(dir.path()).unwrap();
+            for i in 1..=3 {
+                registry.update_peer(create_test_peer(i, 100, false));
+            }
+
+            let doc = BlocklistDocument {
+                version: 1,
+                seed_id: [7u8; 32],
+                issued_at: now,
+                entries: vec![
+                    BlocklistEntry {
+                        target: BlocklistTarget::Ip("192.168.1.2".parse().unwrap()),
+                        reason: "wrong chain spam".to_string(),
+                        expires_at: now + 3600,
+                    },
+                    BlocklistEntry {
+                        target: BlocklistTarget::Peer(create_test_peer(3, 0, false).peer_id),
+                        reason: "eclipse attempt".to_string(),
+                        expires_at: now + 3600,
+                    },
+                ],
+                signature: [0u8; 64],
+            };
+            assert_eq!(registry.apply_blocklist(doc.clone()), Some(2));
+            assert_eq!(registry.apply_blocklist(doc), None);
+            assert_eq!(registry.get_active_peers(240).len(), 1);
+            assert_eq!(registry.get_diverse_peers(10, 2, 240).len(), 1);
+
+            let peer = create_test_peer(4, 100, false);
+            assert!(registry.blocked_reason(&peer, "10.0.0.1".parse().unwrap()).is_none());
+            assert!(registry.blocked_reason(&peer, "192.168.1.2".parse().unwrap()).is_some());
+            registry.flush().unwrap();
+        }
+
+        // Subscribed documents survive a restart
+        let registry = PeerRegistry::open(dir.path()).unwrap();
+        assert!(registry.is_ip_blocked("192.168.1.2".parse().unwrap()));
+        assert_eq!(registry.get_active_peers(240).len(), 1);
+    }
+
     #[test]
     fn test_persistence() {
         let dir = tempdir().unwrap();
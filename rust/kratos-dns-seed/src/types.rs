@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::net::IpAddr;
+use std::ops::BitOr;
 
 /// Helper module for serializing [u8; 64] arrays
 mod sig_serde {
@@ -173,6 +174,88 @@ impl SecurityState {
     }
 }
 
+// =============================================================================
+// PEER CAPABILITIES
+// =============================================================================
+
+/// Services a peer offers on top of block relay (bit flags)
+///
+/// Advertised in heartbeats so that new nodes can ask the seed for peers
+/// that serve what they need, e.g. a warp-sync provider.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Capabilities(pub u32);
+
+impl Capabilities {
+    /// No optional service
+    pub const NONE: Self = Self(0);
+
+    /// Keeps historical state for every block
+    pub const ARCHIVE: Self = Self(1 << 0);
+
+    /// JSON-RPC reachable from other hosts
+    pub const RPC: Self = Self(1 << 1);
+
+    /// Serves state snapshots for warp sync
+    pub const WARP_SYNC: Self = Self(1 << 2);
+
+    /// Serves proofs to light clients
+    pub const LIGHT_CLIENT: Self = Self(1 << 3);
+
+    /// Relays connections (circuit relay v2) for nodes that can't be dialed
+    pub const RELAY: Self = Self(1 << 4);
+
+    /// Every known flag with its name
+    const NAMED: [(&'static str, Self); 5] = [
+        ("archive", Self::ARCHIVE),
+        ("rpc", Self::RPC),
+        ("warp-sync", Self::WARP_SYNC),
+        ("light-client", Self::LIGHT_CLIENT),
+        ("relay", Self::RELAY),
+    ];
+
+    /// Whether all flags of `other` are set
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether no flag is set
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Names of the known flags that are set
+    pub fn names(self) -> Vec<&'static str> {
+        Self::NAMED
+            .iter()
+            .filter(|(_, flag)| self.contains(*flag))
+            .map(|(name, _)| *name)
+            .collect()
+    }
+
+    /// Parse a comma-separated list of names ("archive,warp-sync")
+    pub fn parse_list(list: &str) -> Result<Self, String> {
+        let mut caps = Self::NONE;
+        for name in list.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let flag = Self::NAMED
+                .iter()
+                .find(|(known, _)| known.eq_ignore_ascii_case(name))
+                .map(|(_, flag)| *flag)
+                .ok_or_else(|| format!("Unknown capability: {}", name))?;
+            caps = caps | flag;
+        }
+        Ok(caps)
+    }
+}
+
+impl BitOr for Capabilities {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
 // =============================================================================
 // HEARTBEAT PROTOCOL
 // =============================================================================
@@ -223,12 +306,31 @@ pub struct HeartbeatMessage {
     /// Timestamp of this heartbeat (Unix epoch seconds)
     pub timestamp: u64,
 
-    /// Signature of the message (signs all fields above)
+    /// Signature of the message (signs all fields above, plus capabilities from v2)
     #[serde(with = "sig_serde")]
     pub signature: Signature,
+
+    /// Services offered by the node (v2)
+    ///
+    /// Kept after the signature so that a v1 message is a prefix of the v2 layout.
+    #[serde(default)]
+    pub capabilities: Capabilities,
 }
 
 impl HeartbeatMessage {
+    /// Decode a bincode heartbeat, accepting v1 messages without capabilities
+    pub fn decode(bytes: &[u8]) -> bincode::Result<Self> {
+        bincode::deserialize(bytes).or_else(|err| {
+            // v1 messages end at the signature: pad an empty capability set
+            let mut padded = bytes.to_vec();
+            padded.extend_from_slice(&Capabilities::NONE.0.to_le_bytes());
+            bincode::deserialize::<Self>(&padded)
+                .ok()
+                .filter(|msg| msg.version == 1)
+                .ok_or(err)
+        })
+    }
+
     /// Get the data to be signed (all fields except signature)
     pub fn signing_data(&self) -> Vec<u8> {
         // Use bincode for deterministic serialization
@@ -251,6 +353,9 @@ impl HeartbeatMessage {
         }
         data.extend_from_slice(&self.protocol_version.to_le_bytes());
         data.extend_from_slice(&self.timestamp.to_le_bytes());
+        if self.version >= 2 {
+            data.extend_from_slice(&self.capabilities.0.to_le_bytes());
+        }
         data
     }
 }
@@ -269,6 +374,64 @@ pub struct HeartbeatResponse {
 
     /// Timestamp
     pub timestamp: u64,
+
+    /// Signed receipt for an accepted validator heartbeat (absent before v2)
+    #[serde(default)]
+    pub receipt: Option<HeartbeatReceipt>,
+}
+
+impl HeartbeatResponse {
+    /// Decode a bincode response, accepting responses without a receipt
+    pub fn decode(bytes: &[u8]) -> bincode::Result<Self> {
+        bincode::deserialize(bytes).or_else(|err| {
+            // Older seeds end at the timestamp: pad a `None` receipt
+            let mut padded = bytes.to_vec();
+            padded.push(0);
+            bincode::deserialize::<Self>(&padded).map_err(|_| err)
+        })
+    }
+}
+
+/// Proof that this seed received a validator heartbeat
+///
+/// Validators submit these on-chain (SubmitSeedAttestation) as an uptime
+/// input; kratos-core checks them against block authorship.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeartbeatReceipt {
+    /// Seed that received the heartbeat
+    #[serde(with = "hash_serde")]
+    pub seed_id: SeedId,
+
+    /// Validator that sent it
+    #[serde(with = "hash_serde")]
+    pub peer_id: PeerId,
+
+    /// Genesis hash reported in the heartbeat
+    #[serde(with = "hash_serde")]
+    pub genesis_hash: Hash,
+
+    /// Height reported in the heartbeat
+    pub height: BlockNumber,
+
+    /// Seed clock when the heartbeat was received
+    pub timestamp: u64,
+
+    /// Seed signature
+    #[serde(with = "sig_serde")]
+    pub signature: Signature,
+}
+
+impl HeartbeatReceipt {
+    /// Get the data to be signed (all fields except signature)
+    pub fn signing_data(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(112);
+        data.extend_from_slice(&self.seed_id);
+        data.extend_from_slice(&self.peer_id);
+        data.extend_from_slice(&self.genesis_hash);
+        data.extend_from_slice(&self.height.to_le_bytes());
+        data.extend_from_slice(&self.timestamp.to_le_bytes());
+        data
+    }
 }
 
 // =============================================================================
@@ -344,6 +507,10 @@ pub struct PeerInfo {
 
     /// Protocol version
     pub protocol_version: u32,
+
+    /// Services offered by the peer
+    #[serde(default)]
+    pub capabilities: Capabilities,
 }
 
 impl PeerInfo {
@@ -359,6 +526,7 @@ impl PeerInfo {
             score,
             region: None,
             protocol_version: msg.protocol_version,
+            capabilities: msg.capabilities,
         }
     }
 
@@ -371,6 +539,15 @@ impl PeerInfo {
         now.saturating_sub(self.last_seen) > timeout_secs
     }
 
+    /// All IP addresses found in the advertised multiaddrs
+    pub fn ips(&self) -> Vec<IpAddr> {
+        self.addresses
+            .iter()
+            .filter(|a| a.starts_with("/ip4/") || a.starts_with("/ip6/"))
+            .filter_map(|a| a.split('/').nth(2)?.parse().ok())
+            .collect()
+    }
+
     /// Get the first IP address if available
     pub fn first_ip(&self) -> Option<IpAddr> {
         for addr in &self.addresses {
@@ -449,6 +626,78 @@ impl IdPeersFile {
     }
 }
 
+// =============================================================================
+// BLOCKLIST FORMAT
+// =============================================================================
+
+/// What a blocklist entry matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlocklistTarget {
+    /// A node identity (Ed25519 public key)
+    Peer(#[serde(with = "hash_serde")] PeerId),
+
+    /// Any peer advertising or connecting from this address
+    Ip(IpAddr),
+}
+
+/// A malicious peer or address reported by a seed
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlocklistEntry {
+    /// Peer or address being blocked
+    pub target: BlocklistTarget,
+
+    /// Human-readable reason (e.g. "eclipse attempt", "wrong chain spam")
+    pub reason: String,
+
+    /// Unix timestamp after which the entry no longer applies
+    pub expires_at: u64,
+}
+
+impl BlocklistEntry {
+    /// Whether the entry has expired at `now`
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// The blocklist.json document format
+///
+/// Published by a seed and fetched by other seeds that trust its key.
+/// Only the publishing seed's own entries are listed, never the ones
+/// it subscribed to, so reports do not echo between seeds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlocklistDocument {
+    /// Document format version
+    pub version: u32,
+
+    /// DNS Seed ID that issued this document
+    #[serde(with = "hash_serde")]
+    pub seed_id: SeedId,
+
+    /// When this document was issued (Unix timestamp)
+    pub issued_at: u64,
+
+    /// Blocked peers and addresses
+    pub entries: Vec<BlocklistEntry>,
+
+    /// Signature of the document content
+    #[serde(with = "sig_serde")]
+    pub signature: Signature,
+}
+
+impl BlocklistDocument {
+    /// Get the data to be signed
+    pub fn signing_data(&self) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&self.version.to_le_bytes());
+        data.extend_from_slice(&self.seed_id);
+        data.extend_from_slice(&self.issued_at.to_le_bytes());
+        data.extend_from_slice(&bincode::serialize(&self.entries).unwrap_or_default());
+        data
+    }
+}
+
 // =============================================================================
 // DNS SEED REGISTRY (for governance integration)
 // =============================================================================
@@ -586,6 +835,7 @@ mod tests {
             score: 100,
             region: None,
             protocol_version: 1,
+            capabilities: Capabilities::NONE,
         };
 
         // Very old timestamp should be stale
@@ -611,12 +861,90 @@ mod tests {
             score: 100,
             region: None,
             protocol_version: 1,
+            capabilities: Capabilities::NONE,
         };
 
         let ip = peer.first_ip().unwrap();
         assert_eq!(ip.to_string(), "192.168.1.1");
     }
 
+    #[test]
+    fn test_capabilities_parse() {
+        let caps = Capabilities::parse_list("warp-sync, Archive").unwrap();
+        assert!(caps.contains(Capabilities::WARP_SYNC));
+        assert!(caps.contains(Capabilities::ARCHIVE));
+        assert!(!caps.contains(Capabilities::RPC));
+        assert_eq!(caps.names(), vec!["archive", "warp-sync"]);
+        assert!(Capabilities::parse_list("").unwrap().is_empty());
+        assert!(Capabilities::parse_list("mining").is_err());
+        assert_eq!(Capabilities::parse_list("relay").unwrap(), Capabilities::RELAY);
+    }
+
+    #[test]
+    fn test_heartbeat_decode_v1() {
+        let msg = HeartbeatMessage {
+            version: 2,
+            peer_id: [1u8; 32],
+            libp2p_peer_id: "12D3KooWTestPeerId".to_string(),
+            addresses: vec!["/ip4/1.2.3.4/tcp/30333".to_string()],
+            current_height: 100,
+            best_hash: [2u8; 32],
+            genesis_hash: [3u8; 32],
+            is_validator: false,
+            validator_count: None,
+            total_stake: None,
+            protocol_version: 1,
+            timestamp: 1_700_000_000,
+            signature: [4u8; 64],
+            capabilities: Capabilities::WARP_SYNC,
+        };
+        let bytes = bincode::serialize(&msg).unwrap();
+        assert_eq!(HeartbeatMessage::decode(&bytes).unwrap().capabilities, Capabilities::WARP_SYNC);
+
+        // A v1 node sends the same layout without the trailing capabilities
+        let v1 = HeartbeatMessage { version: 1, ..msg };
+        let bytes = bincode::serialize(&v1).unwrap();
+        let decoded = HeartbeatMessage::decode(&bytes[..bytes.len() - 4]).unwrap();
+        assert_eq!(decoded.version, 1);
+        assert!(decoded.capabilities.is_empty());
+        assert_eq!(decoded.signing_data(), v1.signing_data());
+    }
+
+    #[test]
+    fn test_heartbeat_response_decode_without_receipt() {
+        let response = HeartbeatResponse {
+            accepted: true,
+            error: None,
+            network_state: None,
+            timestamp: 1_700_000_000,
+            receipt: None,
+        };
+        let bytes = bincode::serialize(&response).unwrap();
+
+        // Older seeds stop after the timestamp
+        let decoded = HeartbeatResponse::decode(&bytes[..bytes.len() - 1]).unwrap();
+        assert!(decoded.accepted && decoded.receipt.is_none());
+        assert_eq!(decoded.timestamp, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_blocklist_target_json() {
+        let entry = BlocklistEntry {
+            target: BlocklistTarget::Ip("203.0.113.7".parse().unwrap()),
+            reason: "wrong chain spam".to_string(),
+            expires_at: 1_700_000_000,
+        };
+        let json = serde_json::to_value(&entry).unwrap();
+        assert_eq!(json["target"]["ip"], "203.0.113.7");
+        assert!(entry.is_expired(1_700_000_000));
+        assert!(!entry.is_expired(1_699_999_999));
+
+        let peer = BlocklistTarget::Peer([0xab; 32]);
+        let json = serde_json::to_string(&peer).unwrap();
+        assert_eq!(json, format!("{{\"peer\":\"{}\"}}", "ab".repeat(32)));
+        assert_eq!(serde_json::from_str::<BlocklistTarget>(&json).unwrap(), peer);
+    }
+
     #[test]
     fn test_official_dns_seeds() {
         assert_eq!(OFFICIAL_DNS_SEED_IPS.len(), 3);
@@ -0,0 +1,224 @@
+// Address list validation - Check a batch of recipients before sending
+// - One recipient per line: "<address>" or "<address>,<amount in KRAT>"
+// - Blank lines and lines starting with '#' are ignored
+// - Addresses are raw 32-byte hex and carry no checksum, so format errors are
+//   caught here and unknown accounts are flagged by querying the node
+
+use crate::i18n::{t, tf};
+use crate::rpc::RpcClient;
+
+const KRAT: u128 = 1_000_000_000_000; // 10^12
+
+/// Problem severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The line must be fixed before sending
+    Error,
+    /// The line can be sent but deserves a second look
+    Warning,
+}
+
+/// A problem found on a line
+#[derive(Debug, Clone)]
+pub struct Issue {
+    pub line: usize,
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// A well-formed recipient
+#[derive(Debug, Clone)]
+pub struct ListEntry {
+    pub line: usize,
+    pub address: [u8; 32],
+    /// Amount in raw units, if the line has one
+    pub amount: Option<u128>,
+    /// Free balance reported by the node (set by check_on_chain)
+    pub balance: Option<u128>,
+}
+
+/// Result of validating a list
+#[derive(Debug, Clone, Default)]
+pub struct ListReport {
+    pub entries: Vec<ListEntry>,
+    pub issues: Vec<Issue>,
+}
+
+impl ListReport {
+    fn push(&mut self, line: usize, severity: Severity, message: String) {
+        self.issues.push(Issue { line, severity, message });
+    }
+
+    /// Number of lines with errors
+    pub fn error_count(&self) -> usize {
+        self.issues.iter().filter(|i| i.severity == Severity::Error).count()
+    }
+
+    /// Number of warnings
+    pub fn warning_count(&self) -> usize {
+        self.issues.iter().filter(|i| i.severity == Severity::Warning).count()
+    }
+
+    /// Sum of all amounts in raw units
+    pub fn total_amount(&self) -> u128 {
+        self.entries
+            .iter()
+            .filter_map(|e| e.amount)
+            .fold(0u128, |acc, a| acc.saturating_add(a))
+    }
+}
+
+/// Parse an address, explaining what is wrong with it
+fn parse_address(input: &str) -> Result<[u8; 32], String> {
+    let hex = input.strip_prefix("0x").unwrap_or(input);
+    if let Some(c) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(tf("Invalid character '{}' in address", &[&c]));
+    }
+    if hex.len() != 64 {
+        return Err(tf("Address has {} hex characters (expected 64)", &[&hex.len()]));
+    }
+    let bytes = hex::decode(hex).map_err(|e| e.to_string())?;
+    let mut array = [0u8; 32];
+    array.copy_from_slice(&bytes);
+    Ok(array)
+}
+
+/// Parse a positive KRAT amount into raw units
+fn parse_amount(input: &str) -> Result<u128, String> {
+    let amount: f64 = input.parse().map_err(|_| tf("Invalid amount: {}", &[&input]))?;
+    if !amount.is_finite() || amount <= 0.0 {
+        return Err(t("Amount must be positive").to_string());
+    }
+    Ok((amount * KRAT as f64) as u128)
+}
+
+/// Validate the format of every line
+pub fn parse_list(content: &str, own_address: &[u8; 32]) -> ListReport {
+    let mut report = ListReport::default();
+    let mut seen: std::collections::HashMap<[u8; 32], usize> = std::collections::HashMap::new();
+
+    for (index, raw) in content.lines().enumerate() {
+        let line = index + 1;
+        let trimmed = raw.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = trimmed.split(',').map(str::trim);
+        let address_field = fields.next().unwrap_or_default();
+        let amount_field = fields.next();
+        if fields.next().is_some() {
+            report.push(line, Severity::Error, t("Too many columns (expected address[,amount])").to_string());
+            continue;
+        }
+
+        let address = match parse_address(address_field) {
+            Ok(a) => a,
+            Err(e) => {
+                report.push(line, Severity::Error, e);
+                continue;
+            }
+        };
+
+        let amount = match amount_field {
+            Some(field) => match parse_amount(field) {
+                Ok(a) => Some(a),
+                Err(e) => {
+                    report.push(line, Severity::Error, e);
+                    continue;
+                }
+            },
+            None => None,
+        };
+
+        if address == [0u8; 32] {
+            report.push(line, Severity::Error, t("Zero address").to_string());
+            continue;
+        }
+        if &address == own_address {
+            report.push(line, Severity::Warning, t("This is your own address").to_string());
+        }
+        if let Some(first) = seen.get(&address) {
+            report.push(line, Severity::Warning, tf("Duplicate of line {}", &[first]));
+        } else {
+            seen.insert(address, line);
+        }
+
+        report.entries.push(ListEntry { line, address, amount, balance: None });
+    }
+
+    report
+}
+
+/// Query the node for every entry and flag accounts it has never seen
+pub fn check_on_chain(report: &mut ListReport, client: &RpcClient) {
+    let mut issues = Vec::new();
+    for entry in &mut report.entries {
+        match client.get_account(&hex::encode(entry.address)) {
+            Ok(info) => {
+                entry.balance = Some(info.free_raw);
+                if info.nonce == 0 && info.free_raw == 0 && info.reserved_raw == 0 {
+                    issues.push(Issue {
+                        line: entry.line,
+                        severity: Severity::Warning,
+                        message: t("Account not found on chain (never used)").to_string(),
+                    });
+                }
+            }
+            Err(e) => issues.push(Issue {
+                line: entry.line,
+                severity: Severity::Warning,
+                message: tf("Could not query account: {}", &[&e]),
+            }),
+        }
+    }
+    report.issues.extend(issues);
+    report.issues.sort_by_key(|i| i.line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_list_reports_problems() {
+        let own = [9u8; 32];
+        let good = "11".repeat(32);
+        let content = format!(
+            "# airdrop\n\
+             0x{good},1.5\n\
+             {short}\n\
+             0x{bad}\n\
+             0x{good}\n\
+             0x{own}\n\
+             0x{zero}\n\
+             0x{good},-1\n",
+            good = good,
+            short = "ab".repeat(31),
+            bad = "zz".repeat(32),
+            own = hex::encode(own),
+            zero = "00".repeat(32),
+        );
+
+        let report = parse_list(&content, &own);
+        assert_eq!(report.entries.len(), 3);
+        assert_eq!(report.entries[0].amount, Some(1_500_000_000_000));
+        assert_eq!(report.total_amount(), 1_500_000_000_000);
+
+        let errors: Vec<usize> = report
+            .issues
+            .iter()
+            .filter(|i| i.severity == Severity::Error)
+            .map(|i| i.line)
+            .collect();
+        assert_eq!(errors, vec![3, 4, 7, 8]);
+
+        let warnings: Vec<usize> = report
+            .issues
+            .iter()
+            .filter(|i| i.severity == Severity::Warning)
+            .map(|i| i.line)
+            .collect();
+        assert_eq!(warnings, vec![5, 6]);
+    }
+}
@@ -0,0 +1,63 @@
+// Node data cache - Keep last-known balances and network status on disk
+// - The dashboard worker refreshes the cache while the wallet is open
+// - Screens fall back to the cache when the node cannot be reached,
+//   always stamped "as of block N / time T" so stale data is never mistaken for live data
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use crate::i18n::tf;
+use crate::rpc::RpcClient;
+use crate::storage::WalletStorage;
+use crate::types::{NetworkSnapshot, NodeCache};
+use crate::ui::format_timestamp;
+
+/// Interval between background refreshes
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Result of the last node query (starts optimistic until the first refresh)
+static NODE_ONLINE: AtomicBool = AtomicBool::new(true);
+
+/// Whether the last node query succeeded
+pub fn node_online() -> bool {
+    NODE_ONLINE.load(Ordering::Relaxed)
+}
+
+/// Record the outcome of a node query made outside the refresher
+pub fn set_node_online(online: bool) {
+    NODE_ONLINE.store(online, Ordering::Relaxed);
+}
+
+/// Fetch balance and network status and store them in the cache
+pub fn refresh(client: &RpcClient, storage: &WalletStorage, account: &str) -> Result<NodeCache, String> {
+    let result = fetch(client, account);
+    set_node_online(result.is_ok());
+    let cache = result?;
+    storage.save_cache(&cache)?;
+    Ok(cache)
+}
+
+fn fetch(client: &RpcClient, account: &str) -> Result<NodeCache, String> {
+    let chain = client.chain_info()?;
+    let balance = client.get_account(account)?;
+    Ok(NodeCache {
+        account: account.to_string(),
+        balance,
+        network: NetworkSnapshot {
+            chain_name: chain.chain_name,
+            height: chain.height,
+            is_synced: chain.is_synced,
+            // Older nodes have no epoch info: keep the balance refresh working
+            security: client.security_state().ok(),
+        },
+        updated_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+    })
+}
+
+/// "as of block N / time T" stamp for cached data
+pub fn stamp(cache: &NodeCache) -> String {
+    tf("as of block {} / {}", &[&cache.network.height, &format_timestamp(cache.updated_at)])
+}
@@ -81,41 +81,32 @@ impl WalletKeys {
         signature.to_bytes()
     }
 
-    /// Create and sign a transfer transaction
-    pub fn create_transfer(&self, to: [u8; 32], amount: u128, nonce: u64) -> SignedTransaction {
-        let transaction = crate::types::Transaction {
+    /// Build an unsigned transaction from this account, for the chain,
+    /// validity window and base fee of `context`
+    pub fn build_transaction(
+        &self,
+        call: crate::types::TransactionCall,
+        nonce: u64,
+        context: &crate::types::TransactionContext,
+    ) -> crate::types::Transaction {
+        let max_fee = context.max_fee(&call);
+        crate::types::Transaction {
             sender: self.account_id_bytes().into(),
             nonce,
-            call: crate::types::TransactionCall::Transfer { to: to.into(), amount },
+            call,
             timestamp: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
-        };
-
-        // Serialize for signing with domain separation
-        let tx_bytes = bincode::serialize(&transaction).unwrap();
-        let message = domain_separate(DOMAIN_TRANSACTION, &tx_bytes);
-        let signature = self.sign(&message);
-
-        SignedTransaction {
-            transaction,
-            signature,
+            genesis_hash: context.genesis_hash,
+            valid_until: context.valid_until,
+            max_fee: Some(max_fee),
+            tip: 0,
         }
     }
 
-    /// Create and sign a propose early validator transaction
-    pub fn create_propose_early_validator(&self, candidate: [u8; 32], nonce: u64) -> SignedTransaction {
-        let transaction = crate::types::Transaction {
-            sender: self.account_id_bytes().into(),
-            nonce,
-            call: crate::types::TransactionCall::ProposeEarlyValidator { candidate: candidate.into() },
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
-        };
-
+    /// Sign a transaction built with `build_transaction`
+    pub fn sign_transaction(&self, transaction: crate::types::Transaction) -> SignedTransaction {
         // Serialize for signing with domain separation
         let tx_bytes = bincode::serialize(&transaction).unwrap();
         let message = domain_separate(DOMAIN_TRANSACTION, &tx_bytes);
@@ -127,28 +118,18 @@ impl WalletKeys {
         }
     }
 
-    /// Create and sign a vote early validator transaction
-    pub fn create_vote_early_validator(&self, candidate: [u8; 32], nonce: u64) -> SignedTransaction {
-        let transaction = crate::types::Transaction {
-            sender: self.account_id_bytes().into(),
-            nonce,
-            call: crate::types::TransactionCall::VoteEarlyValidator { candidate: candidate.into() },
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
-        };
-
-        // Serialize for signing with domain separation
-        let tx_bytes = bincode::serialize(&transaction).unwrap();
-        let message = domain_separate(DOMAIN_TRANSACTION, &tx_bytes);
-        let signature = self.sign(&message);
-
-        SignedTransaction {
-            transaction,
-            signature,
-        }
+    /// Create and sign a transfer transaction
+    pub fn create_transfer(
+        &self,
+        to: [u8; 32],
+        amount: u128,
+        nonce: u64,
+        context: &crate::types::TransactionContext,
+    ) -> SignedTransaction {
+        let call = crate::types::TransactionCall::Transfer { to: to.into(), amount };
+        self.sign_transaction(self.build_transaction(call, nonce, context))
     }
+
 }
 
 /// Encrypted wallet data structure
@@ -240,7 +221,7 @@ pub fn decrypt_secret(wallet: &EncryptedWallet, password: &str) -> Result<[u8; 3
     // Decrypt
     let decrypted = cipher
         .decrypt(nonce, wallet.encrypted_secret.as_ref())
-        .map_err(|_| "Invalid password or corrupted wallet")?;
+        .map_err(|_| crate::i18n::t("Invalid password or corrupted wallet"))?;
 
     if decrypted.len() != 32 {
         return Err("Invalid decrypted key length".to_string());
@@ -0,0 +1,288 @@
+// Dev node harness - End-to-end tests against a real kratos-core node
+// - Enabled with `cargo test --features dev-node`
+// - Each test starts its own single-validator genesis node on free ports and
+//   drives the wallet's RPC client and signing code against it, so a change in
+//   transaction encoding or RPC shapes on either side fails here before release
+// - Out of process, not embedded: kratos-core has no library target and its
+//   event loop only stops on Ctrl+C, so the node runs as a child process of the
+//   test. Embedding it needs a kratos-core lib exposing run_node with a
+//   shutdown handle
+// - The binary is taken from KRATOS_NODE_BIN or built from ../kratos-core
+//   (RocksDB: needs libclang)
+
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use crate::crypto::WalletKeys;
+use crate::rpc::RpcClient;
+
+/// Time allowed for the node to start answering RPC calls
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Time allowed for a submitted transaction to be included
+const INCLUSION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Interval between polls
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// kratos-node binary, built once per test run
+fn node_binary() -> &'static Path {
+    static BINARY: OnceLock<PathBuf> = OnceLock::new();
+    BINARY.get_or_init(|| {
+        if let Ok(path) = std::env::var("KRATOS_NODE_BIN") {
+            return PathBuf::from(path);
+        }
+
+        let core = Path::new(env!("CARGO_MANIFEST_DIR")).join("../kratos-core");
+        let status = Command::new(env!("CARGO"))
+            .args(["build", "--bin", "kratos-node", "--manifest-path"])
+            .arg(core.join("Cargo.toml"))
+            .status()
+            .expect("failed to run cargo build for kratos-core");
+        assert!(
+            status.success(),
+            "kratos-core build failed: install libclang or set KRATOS_NODE_BIN to a kratos-node binary"
+        );
+        core.join("target/debug/kratos-node")
+    })
+}
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .map(|addr| addr.port())
+        .expect("no free local port")
+}
+
+/// Poll `check` until it returns Some or `timeout` elapses
+fn wait_for<T>(timeout: Duration, what: &str, mut check: impl FnMut() -> Option<T>) -> T {
+    let start = Instant::now();
+    loop {
+        if let Some(value) = check() {
+            return value;
+        }
+        assert!(start.elapsed() < timeout, "timed out waiting for {}", what);
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Single-validator genesis node, killed on drop
+pub struct DevNode {
+    process: Child,
+    /// Keeps the chain data alive for the lifetime of the node
+    _data_dir: tempfile::TempDir,
+    /// Genesis validator (earns the block rewards)
+    pub validator: WalletKeys,
+    pub client: RpcClient,
+}
+
+impl DevNode {
+    /// Start a node and wait until it has produced a block
+    pub fn start() -> Self {
+        let data_dir = tempfile::tempdir().unwrap();
+        let validator = WalletKeys::generate();
+        let key_file = data_dir.path().join("validator.json");
+        let key = serde_json::json!({
+            "publicKey": format!("0x{}", validator.account_id_hex()),
+            "secretKey": format!("0x{}", validator.secret_key_hex()),
+        });
+        std::fs::write(&key_file, key.to_string()).unwrap();
+
+        let rpc_port = free_port();
+        let process = Command::new(node_binary())
+            .args(["run", "--genesis", "--validator", "--port", "0"])
+            .arg("--validator-key")
+            .arg(&key_file)
+            .arg("--base-path")
+            .arg(data_dir.path().join("chain"))
+            .args(["--rpc-port", &rpc_port.to_string()])
+            .env("KRATOS_LOG", "warn")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("failed to start kratos-node");
+
+        let node = Self {
+            process,
+            _data_dir: data_dir,
+            validator,
+            client: RpcClient::new(&format!("http://127.0.0.1:{}", rpc_port)),
+        };
+        wait_for(STARTUP_TIMEOUT, "the first block", || {
+            node.client.get_block_height().ok().filter(|height| *height > 0)
+        });
+        node
+    }
+
+    /// Free balance of an account (0 if unknown to the node)
+    pub fn free_balance(&self, account: &WalletKeys) -> u128 {
+        self.client
+            .get_account(&account.account_id_hex())
+            .map(|info| info.free_raw)
+            .unwrap_or(0)
+    }
+
+    /// Wait until the validator has earned at least `amount`
+    pub fn wait_for_funds(&self, amount: u128) {
+        wait_for(INCLUSION_TIMEOUT, "block rewards", || {
+            (self.free_balance(&self.validator) >= amount).then_some(())
+        });
+    }
+
+    /// Wait until the node reports `nonce` for `account` (its transactions are included)
+    pub fn wait_for_nonce(&self, account: &WalletKeys, nonce: u64) {
+        wait_for(INCLUSION_TIMEOUT, "transaction inclusion", || {
+            self.client
+                .get_nonce(&account.account_id_hex())
+                .ok()
+                .filter(|current| *current >= nonce)
+        });
+    }
+}
+
+impl Drop for DevNode {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+    }
+}
+
+mod tests {
+    use super::*;
+    use crate::types::{CandidateMetadata, TransactionCall, TransactionContext, TransactionDirection, TransactionHistory, TransactionStatus};
+    use crate::KRAT;
+
+    fn hex_eq(a: &str, b: &str) -> bool {
+        a.trim_start_matches("0x").eq_ignore_ascii_case(b.trim_start_matches("0x"))
+    }
+
+    /// Send `amount` from the validator to `to` and wait for inclusion
+    fn transfer(node: &DevNode, to: &WalletKeys, amount: u128) -> String {
+        let nonce = node.client.get_nonce(&node.validator.account_id_hex()).unwrap();
+        let context = node.client.transaction_context().unwrap();
+        let tx = node.validator.create_transfer(to.account_id_bytes(), amount, nonce, &context);
+        let result = node.client.submit_transaction(&tx).expect("node rejected the wallet's transfer");
+        node.wait_for_nonce(&node.validator, nonce + 1);
+        result.hash
+    }
+
+    /// Propose `candidate` as early validator from the genesis validator and wait for inclusion
+    fn propose(node: &DevNode, candidate: &WalletKeys) {
+        let nonce = node.client.get_nonce(&node.validator.account_id_hex()).unwrap();
+        let call = TransactionCall::ProposeEarlyValidator {
+            candidate: candidate.account_id_bytes().into(),
+            metadata: CandidateMetadata {
+                display_name: Some("dev candidate".to_string()),
+                url: Some("https://example.org".to_string()),
+            },
+        };
+        let context = node.client.transaction_context().unwrap();
+        let tx = node.validator.sign_transaction(node.validator.build_transaction(call, nonce, &context));
+        node.client
+            .submit_propose_early_validator(&tx)
+            .expect("node rejected the wallet's proposal");
+        node.wait_for_nonce(&node.validator, nonce + 1);
+    }
+
+    #[test]
+    fn test_transfer_end_to_end() {
+        let node = DevNode::start();
+        node.wait_for_funds(2 * KRAT);
+        let recipient = WalletKeys::generate();
+
+        transfer(&node, &recipient, KRAT);
+        assert_eq!(node.free_balance(&recipient), KRAT);
+
+        // A signature over anything but the node's encoding must be refused
+        let nonce = node.client.get_nonce(&node.validator.account_id_hex()).unwrap();
+        let context = node.client.transaction_context().unwrap();
+        let mut forged = node.validator.create_transfer(recipient.account_id_bytes(), KRAT, nonce, &context);
+        forged.transaction.nonce += 1;
+        assert!(node.client.submit_transaction(&forged).is_err());
+
+        // Signed for another network: refused, not replayed here
+        let foreign = TransactionContext { genesis_hash: [0u8; 32], ..context };
+        let foreign = node.validator.create_transfer(recipient.account_id_bytes(), KRAT, nonce, &foreign);
+        assert!(node.client.submit_transaction(&foreign).is_err());
+
+        // Past its validity window: refused, can't be mined later
+        let expired = TransactionContext { valid_until: Some(context.best_block - 1), ..context };
+        let expired = node.validator.create_transfer(recipient.account_id_bytes(), KRAT, nonce, &expired);
+        assert!(node.client.submit_transaction(&expired).is_err());
+    }
+
+    #[test]
+    fn test_history_sync_end_to_end() {
+        let node = DevNode::start();
+        node.wait_for_funds(2 * KRAT);
+        let recipient = WalletKeys::generate();
+        let hash = transfer(&node, &recipient, KRAT / 2);
+
+        let response = node
+            .client
+            .get_transaction_history(&recipient.account_id_hex(), 100, 0)
+            .expect("history RPC");
+        let mut history = TransactionHistory::new();
+        for record in node.client.convert_rpc_transactions(response.transactions, &recipient.account_id_hex()) {
+            history.add(record);
+        }
+
+        let record = history
+            .transactions
+            .iter()
+            .find(|r| hex_eq(&r.hash, &hash))
+            .expect("transfer missing from the recipient's history");
+        assert_eq!(record.direction, TransactionDirection::Received);
+        assert_eq!(record.status, TransactionStatus::Confirmed);
+        assert_eq!(record.amount, KRAT / 2);
+        assert!(hex_eq(&record.counterparty, &node.validator.account_id_hex()));
+
+        // No reorg on a single-validator chain
+        assert_eq!(crate::sync_reorg_events(&mut history, &node.client), Ok(0));
+    }
+
+    #[test]
+    fn test_early_validator_voting_end_to_end() {
+        let node = DevNode::start();
+        node.wait_for_funds(3 * KRAT);
+        let validator = node.validator.account_id_hex();
+        let candidate = WalletKeys::generate();
+
+        let status = node.client.get_early_voting_status().expect("voting status RPC");
+        assert!(status.is_bootstrap_era);
+        assert_eq!(status.validator_count, 1);
+        assert!(node.client.can_vote(&validator).unwrap().can_vote);
+
+        propose(&node, &candidate);
+
+        // The proposer's vote is counted with the proposal: a single validator reaches quorum alone
+        let votes = node.client.get_candidate_votes(&candidate.account_id_hex()).expect("candidate votes RPC");
+        assert_eq!(votes.status, "Approved");
+        assert!(votes.voters.iter().any(|voter| hex_eq(voter, &validator)));
+
+        // The approved candidate votes in turn (its fee paid by a transfer).
+        // Early validators need a single vote, so the second candidate is
+        // already approved and the included vote must not be counted again.
+        transfer(&node, &candidate, KRAT);
+        wait_for(INCLUSION_TIMEOUT, "the candidate to become a voter", || {
+            node.client.can_vote(&candidate.account_id_hex()).ok().filter(|r| r.can_vote)
+        });
+        let second = WalletKeys::generate();
+        propose(&node, &second);
+
+        let nonce = node.client.get_nonce(&candidate.account_id_hex()).unwrap();
+        let call = TransactionCall::VoteEarlyValidator { candidate: second.account_id_bytes().into() };
+        let context = node.client.transaction_context().unwrap();
+        let tx = candidate.sign_transaction(candidate.build_transaction(call, nonce, &context));
+        node.client
+            .submit_vote_early_validator(&tx)
+            .expect("node rejected the wallet's vote");
+        node.wait_for_nonce(&candidate, nonce + 1);
+        let votes = node.client.get_candidate_votes(&second.account_id_hex()).unwrap();
+        assert_eq!(votes.status, "Approved");
+        assert_eq!(votes.vote_count, Some(1));
+    }
+}
@@ -0,0 +1,579 @@
+// Localization layer for wallet UI
+// gettext-style: the English string is the message id, and each language
+// provides a catalog mapping ids to translations. Missing entries fall back
+// to English so an incomplete catalog never hides information.
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Supported UI languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    French,
+}
+
+impl Language {
+    /// All languages, in the order shown in Settings
+    pub const ALL: [Language; 2] = [Language::English, Language::French];
+
+    /// ISO 639-1 code
+    #[allow(dead_code)]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::French => "fr",
+        }
+    }
+
+    /// Parse from an ISO 639-1 code or a locale string (e.g. "fr_FR.UTF-8")
+    pub fn from_code(code: &str) -> Option<Self> {
+        let prefix: String = code.chars().take(2).collect::<String>().to_ascii_lowercase();
+        match prefix.as_str() {
+            "en" => Some(Language::English),
+            "fr" => Some(Language::French),
+            _ => None,
+        }
+    }
+
+    /// Name of the language in that language
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::French => "Français",
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            Language::English => 0,
+            Language::French => 1,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Language::French,
+            _ => Language::English,
+        }
+    }
+}
+
+/// Currently selected language (process-wide)
+static CURRENT_LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+/// Set the active UI language
+pub fn set_language(language: Language) {
+    CURRENT_LANGUAGE.store(language.to_u8(), Ordering::Relaxed);
+}
+
+/// Get the active UI language
+pub fn current_language() -> Language {
+    Language::from_u8(CURRENT_LANGUAGE.load(Ordering::Relaxed))
+}
+
+/// Detect language from the environment (LC_ALL, LC_MESSAGES, LANG)
+pub fn detect_language() -> Language {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find_map(|value| Language::from_code(&value))
+        .unwrap_or_default()
+}
+
+/// Translate a message id into the active language
+pub fn t(msgid: &'static str) -> &'static str {
+    translate(current_language(), msgid)
+}
+
+/// Translate a message id and substitute `{}` placeholders in order
+pub fn tf(msgid: &'static str, args: &[&dyn Display]) -> String {
+    format_message(t(msgid), args)
+}
+
+/// Translate a message id into a specific language
+pub fn translate(language: Language, msgid: &'static str) -> &'static str {
+    match language {
+        Language::English => msgid,
+        Language::French => french(msgid).unwrap_or(msgid),
+    }
+}
+
+/// Substitute `{}` placeholders in order; extra placeholders are left as-is
+fn format_message(template: &str, args: &[&dyn Display]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+
+    while let Some(pos) = rest.find("{}") {
+        result.push_str(&rest[..pos]);
+        match args.next() {
+            Some(arg) => result.push_str(&arg.to_string()),
+            None => result.push_str("{}"),
+        }
+        rest = &rest[pos + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+// =============================================================================
+// CATALOGS
+// =============================================================================
+
+/// French catalog
+fn french(msgid: &str) -> Option<&'static str> {
+    let translated = match msgid {
+        // Setup and unlock
+        "First time setup - Creating new wallet" => "Première configuration - Création d'un nouveau portefeuille",
+        "How would you like to set up your wallet?" => "Comment souhaitez-vous configurer votre portefeuille ?",
+        "Import existing keys" => "Importer des clés existantes",
+        "Generate new keys" => "Générer de nouvelles clés",
+        "RPC endpoint" => "Point d'accès RPC",
+        "Set a password to encrypt your wallet" => "Définissez un mot de passe pour chiffrer votre portefeuille",
+        "Your secret key will be encrypted with this password" => "Votre clé secrète sera chiffrée avec ce mot de passe",
+        "Password:" => "Mot de passe :",
+        "Confirm password:" => "Confirmer le mot de passe :",
+        "Failed to save wallet: {}" => "Échec de l'enregistrement du portefeuille : {}",
+        "Wallet created and saved successfully!" => "Portefeuille créé et enregistré avec succès !",
+        "Import your existing keys" => "Importez vos clés existantes",
+        "Secret key (hex, 0x...):" => "Clé secrète (hex, 0x...) :",
+        "Input cancelled" => "Saisie annulée",
+        "Passwords don't match" => "Les mots de passe ne correspondent pas",
+        "Invalid key length: {} bytes (expected 32)" => "Longueur de clé invalide : {} octets (32 attendus)",
+        "Invalid hex: {}" => "Hexadécimal invalide : {}",
+        "Imported account: 0x{}" => "Compte importé : 0x{}",
+        "Generating new keys..." => "Génération de nouvelles clés...",
+        "IMPORTANT: Save your secret key securely!" => "IMPORTANT : sauvegardez votre clé secrète en lieu sûr !",
+        "This is the ONLY way to recover your wallet." => "C'est le SEUL moyen de récupérer votre portefeuille.",
+        "Account ID:" => "Identifiant du compte :",
+        "Secret Key:" => "Clé secrète :",
+        "Have you saved your secret key securely?" => "Avez-vous sauvegardé votre clé secrète en lieu sûr ?",
+        "Please save your secret key before continuing!" => "Veuillez sauvegarder votre clé secrète avant de continuer !",
+        "Unlock your wallet" => "Déverrouillez votre portefeuille",
+        "Password" => "Mot de passe",
+        "Wallet unlocked: 0x{}...{}" => "Portefeuille déverrouillé : 0x{}...{}",
+        "Too many failed attempts, next try in {}s..." => "Trop de tentatives échouées, prochain essai dans {} s...",
+        "{} failed unlock attempts since last use" => "{} tentatives de déverrouillage échouées depuis la dernière utilisation",
+        "{} attempts left before this wallet is erased" => "{} tentatives restantes avant l'effacement de ce portefeuille",
+        "Too many failed attempts: the wallet file has been erased." => "Trop de tentatives échouées : le fichier du portefeuille a été effacé.",
+        "Restore it by importing your secret key backup." => "Restaurez-le en important la sauvegarde de votre clé secrète.",
+        "Derived account:" => "Compte dérivé :",
+        "Does this address match the account you expect?" => "Cette adresse correspond-elle au compte attendu ?",
+        "Import aborted: address mismatch. Check your secret key." => "Import annulé : adresse différente. Vérifiez votre clé secrète.",
+        "Make sure no one can see your screen, then press any key to reveal your secret key..." => "Assurez-vous que personne ne voit votre écran, puis appuyez sur une touche pour afficher votre clé secrète...",
+        "Invalid password or corrupted wallet" => "Mot de passe invalide ou portefeuille corrompu",
+
+        // Main menu
+        "Check Balance" => "Consulter le solde",
+        "Send KRAT" => "Envoyer des KRAT",
+        "Transaction History" => "Historique des transactions",
+        "Validate Address List" => "Vérifier une liste d'adresses",
+        "Community (Early Validators)" => "Communauté (validateurs fondateurs)",
+        "Settings" => "Paramètres",
+        "Exit" => "Quitter",
+        "What would you like to do?" => "Que souhaitez-vous faire ?",
+        "Goodbye!" => "Au revoir !",
+        "Account:" => "Compte :",
+        "Network:" => "Réseau :",
+        "syncing" => "synchronisation",
+        "Offline" => "Hors ligne",
+        "as of block {} / {}" => "au bloc {} / {}",
+
+        // Balance
+        "Account Balance" => "Solde du compte",
+        "Fetching balance..." => "Récupération du solde...",
+        "Address:" => "Adresse :",
+        "Free:" => "Disponible :",
+        "Reserved:" => "Réservé :",
+        "Total:" => "Total :",
+        "Nonce:" => "Nonce :",
+        "Failed to fetch balance: {}" => "Échec de la récupération du solde : {}",
+        "Node unreachable, showing cached balance {}" => "Nœud injoignable, solde en cache affiché {}",
+
+        // Send
+        "Recipient address (0x...)" => "Adresse du destinataire (0x...)",
+        "Address must be 64 hex characters" => "L'adresse doit contenir 64 caractères hexadécimaux",
+        "Invalid hex address" => "Adresse hexadécimale invalide",
+        "Amount (KRAT)" => "Montant (KRAT)",
+        "Invalid amount" => "Montant invalide",
+        "Amount must be positive" => "Le montant doit être positif",
+        "Transaction Summary:" => "Résumé de la transaction :",
+        "To:" => "À :",
+        "From:" => "De :",
+        "Amount:" => "Montant :",
+        "Fee:" => "Frais :",
+        "Send this transaction?" => "Envoyer cette transaction ?",
+        "Transaction cancelled" => "Transaction annulée",
+        "Preparing transaction..." => "Préparation de la transaction...",
+        "Failed to get nonce: {}" => "Échec de la récupération du nonce : {}",
+        "Failed to get chain: {}" => "Échec de la récupération de la chaîne : {}",
+        "Submitting transaction..." => "Envoi de la transaction...",
+        "Transaction submitted successfully!" => "Transaction envoyée avec succès !",
+        "Hash:" => "Empreinte :",
+        "Status:" => "Statut :",
+        "Warning: Failed to save to history: {}" => "Attention : échec de l'enregistrement dans l'historique : {}",
+        "Transaction failed: {}" => "Échec de la transaction : {}",
+
+        // Address list
+        "One recipient per line: address or address,amount. Lines starting with # are ignored." => "Un destinataire par ligne : adresse ou adresse,montant. Les lignes commençant par # sont ignorées.",
+        "Path to address file" => "Chemin du fichier d'adresses",
+        "Failed to read file: {}" => "Échec de la lecture du fichier : {}",
+        "Checking accounts on chain..." => "Vérification des comptes sur la chaîne...",
+        "Validation Summary:" => "Résumé de la vérification :",
+        "Valid recipients:" => "Destinataires valides :",
+        "Errors:" => "Erreurs :",
+        "Warnings:" => "Avertissements :",
+        "Total amount:" => "Montant total :",
+        "Total fees:" => "Frais totaux :",
+        "Insufficient balance: the batch needs {} but only {} is free" => "Solde insuffisant : le lot nécessite {} mais seulement {} est disponible",
+        "No problems found." => "Aucun problème détecté.",
+        "Line {}:" => "Ligne {} :",
+        "Invalid character '{}' in address" => "Caractère '{}' invalide dans l'adresse",
+        "Address has {} hex characters (expected 64)" => "L'adresse contient {} caractères hexadécimaux (64 attendus)",
+        "Invalid amount: {}" => "Montant invalide : {}",
+        "Too many columns (expected address[,amount])" => "Trop de colonnes (attendu : adresse[,montant])",
+        "Zero address" => "Adresse nulle",
+        "This is your own address" => "C'est votre propre adresse",
+        "Duplicate of line {}" => "Doublon de la ligne {}",
+        "Account not found on chain (never used)" => "Compte introuvable sur la chaîne (jamais utilisé)",
+        "Could not query account: {}" => "Impossible d'interroger le compte : {}",
+
+        // History
+        "Syncing with node..." => "Synchronisation avec le nœud...",
+        "Using local history (node sync unavailable)" => "Historique local utilisé (synchronisation indisponible)",
+        "{} transaction(s) affected by a chain reorganization" => "{} transaction(s) affectée(s) par une réorganisation de la chaîne",
+        "Chain reorganizations not checked: {}" => "Réorganisations de la chaîne non vérifiées : {}",
+        "Block #{} was reorganized, waiting for re-inclusion" => "Le bloc #{} a été réorganisé, en attente de réinclusion",
+        "Block #{} was reorganized, transaction dropped" => "Le bloc #{} a été réorganisé, transaction abandonnée",
+        "Re-included after reorganization of block #{}" => "Réincluse après la réorganisation du bloc #{}",
+        "Previous page" => "Page précédente",
+        "Next page" => "Page suivante",
+        "Refresh" => "Actualiser",
+        "Back to menu" => "Retour au menu",
+        "Navigation" => "Navigation",
+        "Showing" => "Affichage de",
+        "of {} transactions" => "sur {} transactions",
+        "(page {}/{})" => "(page {}/{})",
+        "No transactions found" => "Aucune transaction trouvée",
+        "Send or receive KRAT to see your transaction history here." => "Envoyez ou recevez des KRAT pour voir votre historique ici.",
+        "Time:" => "Date :",
+        "Block:" => "Bloc :",
+        "pending" => "en attente",
+        "{}, unverified" => "{}, non vérifiée",
+        "Just now" => "À l'instant",
+        "{} min ago" => "il y a {} min",
+        "{} hours ago" => "il y a {} heures",
+        "{} days ago" => "il y a {} jours",
+
+        // Inbox
+        "Inbox" => "Messages reçus",
+        "Fetching messages..." => "Récupération des messages...",
+        "Failed to fetch messages: {}" => "Échec de la récupération des messages : {}",
+        "No messages" => "Aucun message",
+        "Messages sent to your address on chain appear here." => "Les messages envoyés à votre adresse sur la chaîne apparaissent ici.",
+        "Latest {} messages, newest first" => "{} derniers messages, du plus récent au plus ancien",
+        "[encrypted, {} bytes]" => "[chiffré, {} octets]",
+        "[binary, {} bytes]" => "[binaire, {} octets]",
+
+        // Community
+        "Community - Early Validators" => "Communauté - Validateurs fondateurs",
+        "Your status:" => "Votre statut :",
+        "ACTIVE VALIDATOR" => "VALIDATEUR ACTIF",
+        "You can propose candidates and vote." => "Vous pouvez proposer des candidats et voter.",
+        "OBSERVER" => "OBSERVATEUR",
+        "You can view candidates but cannot vote." => "Vous pouvez consulter les candidats mais pas voter.",
+        "View Pending Candidates" => "Voir les candidats en attente",
+        "Propose New Validator" => "Proposer un nouveau validateur",
+        "Vote for Candidate" => "Voter pour un candidat",
+        "Check Candidate Status" => "Vérifier le statut d'un candidat",
+        "Back to Main Menu" => "Retour au menu principal",
+        "Community Actions" => "Actions communautaires",
+        "Bootstrap Era Status" => "Statut de l'ère d'amorçage",
+        "ACTIVE" => "ACTIVE",
+        "ENDED" => "TERMINÉE",
+        "Progress:" => "Progression :",
+        "{} / {} blocks remaining" => "{} / {} blocs restants",
+        "Bootstrap era has ended. No more early validators can be added." => "L'ère d'amorçage est terminée. Aucun validateur fondateur ne peut plus être ajouté.",
+        "Validators:" => "Validateurs :",
+        "{} / {} validators" => "{} / {} validateurs",
+        "Threshold:" => "Seuil :",
+        "{} votes needed for next validator" => "{} votes nécessaires pour le prochain validateur",
+        "Candidates:" => "Candidats :",
+        "{} pending" => "{} en attente",
+        "Pending Candidates" => "Candidats en attente",
+        "Fetching candidates..." => "Récupération des candidats...",
+        "No pending candidates at this time." => "Aucun candidat en attente pour le moment.",
+        "Found" => "Trouvé",
+        "{} candidates" => "{} candidats",
+        "READY" => "PRÊT",
+        "Proposed by:" => "Proposé par :",
+        "Voters:" => "Votants :",
+        "Name:" => "Nom :",
+        "URL:" => "URL :",
+        "Failed: {}" => "Échec : {}",
+        "Candidate address (0x...)" => "Adresse du candidat (0x...)",
+        "Candidate:" => "Candidat :",
+        "Display name (optional)" => "Nom affiché (facultatif)",
+        "Display name is too long" => "Le nom affiché est trop long",
+        "Display name contains invalid characters" => "Le nom affiché contient des caractères invalides",
+        "Contact URL (optional)" => "URL de contact (facultatif)",
+        "URL is too long" => "L'URL est trop longue",
+        "URL must start with http:// or https://" => "L'URL doit commencer par http:// ou https://",
+        "URL contains invalid characters" => "L'URL contient des caractères invalides",
+        "Submit this proposal?" => "Soumettre cette proposition ?",
+        "Proposal cancelled" => "Proposition annulée",
+        "Submitting proposal..." => "Envoi de la proposition...",
+        "Proposal submitted successfully!" => "Proposition envoyée avec succès !",
+        "Proposal failed: {}" => "Échec de la proposition : {}",
+        "Loading candidates..." => "Chargement des candidats...",
+        "Failed to load candidates: {}" => "Échec du chargement des candidats : {}",
+        "No pending candidates to vote for." => "Aucun candidat en attente pour lequel voter.",
+        "{} ({}/{} votes)" => "{} ({}/{} votes)",
+        "Cancel" => "Annuler",
+        "Select candidate to vote for" => "Sélectionnez le candidat pour lequel voter",
+        "You have already voted for this candidate." => "Vous avez déjà voté pour ce candidat.",
+        "Current votes:" => "Votes actuels :",
+        "Submit this vote?" => "Soumettre ce vote ?",
+        "Vote cancelled" => "Vote annulé",
+        "Submitting vote..." => "Envoi du vote...",
+        "Vote submitted successfully!" => "Vote envoyé avec succès !",
+        "This was the deciding vote! Candidate will be approved." => "C'était le vote décisif ! Le candidat sera approuvé.",
+        "Vote failed: {}" => "Échec du vote : {}",
+        "Checking status..." => "Vérification du statut...",
+        "No candidacy found for this address." => "Aucune candidature trouvée pour cette adresse.",
+        "Candidate Information" => "Informations sur le candidat",
+        "PENDING" => "EN ATTENTE",
+        "APPROVED" => "APPROUVÉ",
+        "REJECTED" => "REJETÉ",
+        "EXPIRED" => "EXPIRÉ",
+        "Proposer:" => "Proposant :",
+        "Votes:" => "Votes :",
+        "Has quorum:" => "Quorum atteint :",
+        "YES" => "OUI",
+        "NO" => "NON",
+        "Approved at:" => "Approuvé au :",
+        "block #{}" => "bloc n°{}",
+
+        "Sender:" => "Émetteur :",
+        "Maximum fee:" => "Frais maximum :",
+        "Note:" => "Note :",
+
+        // Settings
+        "Show Account ID" => "Afficher l'identifiant du compte",
+        "Change RPC Endpoint" => "Changer le point d'accès RPC",
+        "Change Password" => "Changer le mot de passe",
+        "Language" => "Langue",
+        "Back" => "Retour",
+        "New RPC endpoint" => "Nouveau point d'accès RPC",
+        "Enter password to save changes" => "Saisissez le mot de passe pour enregistrer",
+        "Failed to save: {}" => "Échec de l'enregistrement : {}",
+        "RPC endpoint updated!" => "Point d'accès RPC mis à jour !",
+        "Current password" => "Mot de passe actuel",
+        "New password" => "Nouveau mot de passe",
+        "Confirm new password" => "Confirmer le nouveau mot de passe",
+        "Password changed!" => "Mot de passe modifié !",
+        "Incorrect password" => "Mot de passe incorrect",
+        "Paranoid Mode" => "Mode paranoïaque",
+        "Paranoid mode is currently:" => "Le mode paranoïaque est actuellement :",
+        "ON" => "ACTIVÉ",
+        "OFF" => "DÉSACTIVÉ",
+        "Enable paranoid mode (never echo secrets)?" => "Activer le mode paranoïaque (aucun écho des secrets) ?",
+        "Paranoid mode updated!" => "Mode paranoïaque mis à jour !",
+        "Select language" => "Choisissez la langue",
+        "Language updated!" => "Langue mise à jour !",
+        "Press Enter to continue..." => "Appuyez sur Entrée pour continuer...",
+        "Wipe After Failed Unlocks" => "Effacement après échecs de déverrouillage",
+        "Wipe mode is currently:" => "Le mode effacement est actuellement :",
+        "ON (after {} failed attempts)" => "ACTIVÉ (après {} tentatives échouées)",
+        "WARNING: in wipe mode the wallet file is permanently deleted" => "ATTENTION : en mode effacement, le fichier du portefeuille est définitivement supprimé",
+        "after too many wrong passwords. Without a backup of your secret key," => "après trop de mots de passe erronés. Sans sauvegarde de votre clé secrète,",
+        "your funds are lost forever." => "vos fonds sont perdus à jamais.",
+        "Enable wipe mode?" => "Activer le mode effacement ?",
+        "Failed attempts before wipe (minimum {})" => "Tentatives échouées avant effacement (minimum {})",
+        "Must be at least {}" => "Doit être au moins {}",
+        "I have a backup of my secret key" => "J'ai une sauvegarde de ma clé secrète",
+        "Wipe mode unchanged" => "Mode effacement inchangé",
+        "Wipe mode updated!" => "Mode effacement mis à jour !",
+
+        // Account migration
+        "Migrate Account" => "Migrer le compte",
+        "Watch-only Accounts" => "Comptes en lecture seule",
+        "This wizard moves your funds to a brand new key:" => "Cet assistant transfère vos fonds vers une toute nouvelle clé :",
+        "Generate and back up a new secret key" => "Générer et sauvegarder une nouvelle clé secrète",
+        "Send the full free balance to the new account" => "Envoyer tout le solde disponible vers le nouveau compte",
+        "Replace the wallet key and keep the old address as watch-only" => "Remplacer la clé du portefeuille et conserver l'ancienne adresse en lecture seule",
+        "Start the migration?" => "Démarrer la migration ?",
+        "Checking account..." => "Vérification du compte...",
+        "This account has staked funds or is a validator." => "Ce compte a des fonds en staking ou est validateur.",
+        "Unstake and withdraw with the current key first, then run the migration again." => "Retirez d'abord le staking avec la clé actuelle, puis relancez la migration.",
+        "Migration Summary:" => "Résumé de la migration :",
+        "Old account:" => "Ancien compte :",
+        "New account:" => "Nouveau compte :",
+        "Sweep funds and switch to the new account?" => "Transférer les fonds et passer au nouveau compte ?",
+        "Migration cancelled, the new key was not used" => "Migration annulée, la nouvelle clé n'a pas été utilisée",
+        "Migration aborted, your wallet still uses the old key." => "Migration interrompue, votre portefeuille utilise toujours l'ancienne clé.",
+        "Funds were sent to the new account. Import its secret key to access them." => "Les fonds ont été envoyés au nouveau compte. Importez sa clé secrète pour y accéder.",
+        "Account migrated!" => "Compte migré !",
+        "The old account is now watch-only. Never reuse its secret key." => "L'ancien compte est désormais en lecture seule. Ne réutilisez jamais sa clé secrète.",
+        "No watch-only accounts." => "Aucun compte en lecture seule.",
+        "Replaced by:" => "Remplacé par :",
+        "Retired:" => "Retiré :",
+
+        // Transaction preview
+        "Transaction Preview:" => "Aperçu de la transaction :",
+        "Method:" => "Méthode :",
+        "Timestamp:" => "Horodatage :",
+        "Payload:" => "Données signées :",
+        "{} bytes" => "{} octets",
+        "Effects:" => "Effets :",
+        "Deposit:" => "Dépôt :",
+        "Description:" => "Description :",
+        "Parent chain:" => "Chaîne parente :",
+        "Chain:" => "Chaîne :",
+        "Moves {} from your free balance to the recipient" => "Transfère {} de votre solde disponible vers le destinataire",
+        "Locks {} of your free balance as stake" => "Bloque {} de votre solde disponible en stake",
+        "Starts unbonding {}; it becomes withdrawable after the unbonding period" => "Lance le retrait de {} ; disponible à la fin de la période de retrait",
+        "Moves matured unbonded funds back to your free balance" => "Remet les fonds retirés arrivés à échéance sur votre solde disponible",
+        "Reserves {} and registers this account as a validator" => "Réserve {} et enregistre ce compte comme validateur",
+        "Removes this account from the validator set" => "Retire ce compte de l'ensemble des validateurs",
+        "Reserves a deposit of {} to create a sidechain" => "Réserve un dépôt de {} pour créer une sidechain",
+        "Exits the sidechain and returns your share of its funds" => "Quitte la sidechain et restitue votre part de ses fonds",
+        "Publicly signals support for fork \"{}\"" => "Signale publiquement votre soutien au fork « {} »",
+        "Proposes the candidate as an early validator; your proposal counts as the first vote" => "Propose le candidat comme validateur précoce ; votre proposition compte comme premier vote",
+        "Adds your vote for the candidate to join the validator set" => "Ajoute votre vote pour que le candidat rejoigne l'ensemble des validateurs",
+
+        // Validator dashboard
+        "Validator Dashboard" => "Tableau de bord validateur",
+        "Fetching validator status..." => "Récupération du statut du validateur...",
+        "yes" => "oui",
+        "no" => "non",
+        "Session" => "Session",
+        "Producing blocks:" => "Production de blocs :",
+        "Voted last finality round:" => "A voté au dernier tour de finalité :",
+        "Bootstrap validator:" => "Validateur d'amorçage :",
+        "Epoch:" => "Époque :",
+        "{} blocks until next epoch" => "{} blocs avant la prochaine époque",
+        "Validator Credits" => "Crédits de validateur",
+        "Uptime:" => "Disponibilité :",
+        "Arbitration:" => "Arbitrage :",
+        "Seniority:" => "Ancienneté :",
+        "Clock penalty:" => "Pénalité d'horloge :",
+        "Slashed:" => "Retiré par sanction :",
+        "Recent epochs:" => "Époques récentes :",
+        "epoch {}:" => "époque {} :",
+        "Participation" => "Participation",
+        "Blocks produced:" => "Blocs produits :",
+        "Blocks missed:" => "Blocs manqués :",
+        "Participation:" => "Participation :",
+        "Reputation:" => "Réputation :",
+        "Clock sync failures:" => "Échecs de synchronisation d'horloge :",
+        "Stake & Rewards" => "Stake et récompenses",
+        "Stake:" => "Stake :",
+        "Unbonding:" => "En cours de retrait :",
+        " (until block #{})" => " (jusqu'au bloc #{})",
+        "unlocks at block #{} (epoch {})" => "débloqué au bloc #{} (époque {})",
+        "Withdrawable:" => "Retirable :",
+        "Commission:" => "Commission :",
+        " ({}% from epoch {})" => " ({} % à partir de l'époque {})",
+        "Rewards are credited to your free balance with each produced block" => "Les récompenses sont créditées sur votre solde disponible à chaque bloc produit",
+        "Slashing" => "Sanctions",
+        "No slashing events" => "Aucune sanction",
+        "Events:" => "Événements :",
+        "Total slashed:" => "Total sanctionné :",
+
+        // Dashboard
+        "Terminal error: {}" => "Erreur du terminal : {}",
+        "Connecting to node..." => "Connexion au nœud...",
+        "Security:" => "Sécurité :",
+        "Wallet:" => "Portefeuille :",
+        "unknown" => "inconnu",
+        "Bootstrap" => "Amorçage",
+        "Normal" => "Normal",
+        "Degraded" => "Dégradé",
+        "Bootstrap recovery" => "Reprise de l'amorçage",
+        "Restricted" => "Restreint",
+        "Emergency" => "Urgence",
+        "since epoch {}" => "depuis l'époque {}",
+        "block production halted" => "production de blocs arrêtée",
+        "block time x{}" => "temps de bloc x{}",
+        "TLS" => "TLS",
+        "Unencrypted RPC" => "RPC non chiffré",
+        "Paranoid mode" => "Mode paranoïaque",
+        "Wipe after {} failed unlocks" => "Effacement après {} échecs de déverrouillage",
+        "Balance" => "Solde",
+        "History" => "Historique",
+        "Community" => "Communauté",
+        "No balance data yet." => "Aucune donnée de solde pour l'instant.",
+        "No transactions yet." => "Aucune transaction pour l'instant.",
+        "confirmed" => "confirmée",
+        "failed" => "échouée",
+        "Voting status unavailable." => "Statut du vote indisponible.",
+        "Bootstrap era ends at block #{}" => "L'ère d'amorçage se termine au bloc #{}",
+        "Bootstrap era has ended." => "L'ère d'amorçage est terminée.",
+        "Validators: {} / {} · votes required: {}" => "Validateurs : {} / {} · votes requis : {}",
+        "No pending candidates." => "Aucun candidat en attente.",
+        "quorum" => "quorum",
+        "Send" => "Envoyer",
+        "Address list" => "Liste d'adresses",
+        "Validator" => "Validateur",
+        "Quit" => "Quitter",
+
+        _ => return None,
+    };
+    Some(translated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english_is_identity() {
+        assert_eq!(translate(Language::English, "Check Balance"), "Check Balance");
+    }
+
+    #[test]
+    fn test_french_translation() {
+        assert_eq!(translate(Language::French, "Check Balance"), "Consulter le solde");
+        assert_eq!(translate(Language::French, "Goodbye!"), "Au revoir !");
+    }
+
+    #[test]
+    fn test_missing_entry_falls_back_to_english() {
+        assert_eq!(translate(Language::French, "Not in any catalog"), "Not in any catalog");
+    }
+
+    #[test]
+    fn test_format_message() {
+        assert_eq!(format_message("{} of {}", &[&1, &"two"]), "1 of two");
+        assert_eq!(format_message("no args", &[]), "no args");
+        assert_eq!(format_message("{} and {}", &[&"one"]), "one and {}");
+    }
+
+    #[test]
+    fn test_language_from_code() {
+        assert_eq!(Language::from_code("fr"), Some(Language::French));
+        assert_eq!(Language::from_code("fr_FR.UTF-8"), Some(Language::French));
+        assert_eq!(Language::from_code("en_US"), Some(Language::English));
+        assert_eq!(Language::from_code("de"), None);
+    }
+
+    #[test]
+    fn test_french_placeholders_match() {
+        let ids = [
+            "Failed to save wallet: {}",
+            "Invalid key length: {} bytes (expected 32)",
+            "Wallet unlocked: 0x{}...{}",
+            "{} / {} blocks remaining",
+            "{} ({}/{} votes)",
+            "(page {}/{})",
+        ];
+        for id in ids {
+            let fr = translate(Language::French, id);
+            assert_eq!(id.matches("{}").count(), fr.matches("{}").count(), "{}", id);
+        }
+    }
+}
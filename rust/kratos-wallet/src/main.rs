@@ -1,22 +1,32 @@
 // KratOs Wallet CLI
 // Secure wallet for managing KRAT tokens
 
+mod address_list;
+mod cache;
 mod crypto;
+#[cfg(all(test, feature = "dev-node"))]
+mod dev_node;
+mod i18n;
+mod preview;
 mod rpc;
 mod storage;
+mod tui;
 mod types;
 mod ui;
 
 use console::{style, Term};
 use dialoguer::{theme::ColorfulTheme, Confirm, Input, Password, Select};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::crypto::WalletKeys;
+use crate::i18n::{t, tf, Language};
 use crate::rpc::RpcClient;
 use crate::storage::WalletStorage;
+use crate::types::{CandidateMetadata, IdentityInfo, MAX_CANDIDATE_NAME_LEN, MAX_CANDIDATE_URL_LEN, MIN_WIPE_ATTEMPTS};
 use crate::ui::{
-    create_spinner, print_empty_history, print_history_header, print_transaction,
-    read_password_with_confirm, read_secret_with_mask,
+    create_spinner, print_empty_history, print_history_header, print_message, print_transaction,
+    read_password_with_confirm, read_secret, wait_for_keypress,
 };
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -34,6 +44,13 @@ fn main() {
     // Check if wallet exists
     let storage = WalletStorage::new(&wallet_dir);
 
+    // Apply language preference before any prompt is shown
+    let language = storage
+        .load_settings()
+        .language
+        .unwrap_or_else(i18n::detect_language);
+    i18n::set_language(language);
+
     let (keys, rpc_url) = if storage.wallet_exists() {
         // Unlock existing wallet
         unlock_wallet(&storage)
@@ -45,8 +62,8 @@ fn main() {
     // Create RPC client
     let client = RpcClient::new(&rpc_url);
 
-    // Main menu loop
-    main_menu(&term, &keys, &client, &storage);
+    // Dashboard loop (refreshes node data in the background)
+    main_menu(&term, keys, &rpc_url, &client, &storage);
 }
 
 fn print_banner() {
@@ -91,16 +108,28 @@ fn get_wallet_dir() -> PathBuf {
 fn setup_new_wallet(storage: &WalletStorage) -> (WalletKeys, String) {
     println!(
         "{}",
-        style("  📦 First time setup - Creating new wallet").yellow()
+        style(format!("  📦 {}", t("First time setup - Creating new wallet"))).yellow()
     );
     println!();
 
     let theme = ColorfulTheme::default();
 
+    // Paranoid mode: secrets are never echoed and only revealed on demand
+    let mut settings = storage.load_settings();
+    settings.paranoid_mode = Confirm::with_theme(&theme)
+        .with_prompt(t("Enable paranoid mode (never echo secrets)?"))
+        .default(settings.paranoid_mode)
+        .interact()
+        .unwrap();
+    if let Err(e) = storage.save_settings(&settings) {
+        eprintln!("{}", style(format!("  ⚠️  {}", tf("Failed to save: {}", &[&e]))).yellow());
+    }
+    let paranoid = settings.paranoid_mode;
+
     // Ask for import or generate
-    let choices = vec!["Import existing keys", "Generate new keys"];
+    let choices = vec![t("Import existing keys"), t("Generate new keys")];
     let selection = Select::with_theme(&theme)
-        .with_prompt("How would you like to set up your wallet?")
+        .with_prompt(t("How would you like to set up your wallet?"))
         .items(&choices)
         .default(0)
         .interact()
@@ -108,15 +137,15 @@ fn setup_new_wallet(storage: &WalletStorage) -> (WalletKeys, String) {
 
     let keys = if selection == 0 {
         // Import existing keys
-        import_keys(&theme)
+        import_keys(&theme, paranoid)
     } else {
         // Generate new keys
-        generate_new_keys(&theme)
+        generate_new_keys(&theme, paranoid)
     };
 
     // Get RPC endpoint
     let rpc_url: String = Input::with_theme(&theme)
-        .with_prompt("RPC endpoint")
+        .with_prompt(t("RPC endpoint"))
         .default("http://127.0.0.1:9933".to_string())
         .interact_text()
         .unwrap();
@@ -125,16 +154,16 @@ fn setup_new_wallet(storage: &WalletStorage) -> (WalletKeys, String) {
     println!();
     println!(
         "{}",
-        style("  🔒 Set a password to encrypt your wallet").yellow()
+        style(format!("  🔒 {}", t("Set a password to encrypt your wallet"))).yellow()
     );
     println!(
         "{}",
-        style("     Your secret key will be encrypted with this password").dim()
+        style(format!("     {}", t("Your secret key will be encrypted with this password"))).dim()
     );
     println!();
 
     let password = loop {
-        match read_password_with_confirm("Password:", "Confirm password:") {
+        match read_password_with_confirm(t("Password:"), t("Confirm password:"), paranoid) {
             Ok(pwd) => break pwd,
             Err(e) => {
                 eprintln!("{}", style(format!("  ❌ {}", e)).red());
@@ -145,33 +174,33 @@ fn setup_new_wallet(storage: &WalletStorage) -> (WalletKeys, String) {
 
     // Save wallet
     if let Err(e) = storage.save_wallet(&keys, &password, &rpc_url) {
-        eprintln!("{}", style(format!("  ❌ Failed to save wallet: {}", e)).red());
+        eprintln!("{}", style(format!("  ❌ {}", tf("Failed to save wallet: {}", &[&e]))).red());
         std::process::exit(1);
     }
 
     println!();
     println!(
         "{}",
-        style("  ✅ Wallet created and saved successfully!").green()
+        style(format!("  ✅ {}", t("Wallet created and saved successfully!"))).green()
     );
     println!();
 
     (keys, rpc_url)
 }
 
-fn import_keys(_theme: &ColorfulTheme) -> WalletKeys {
+fn import_keys(theme: &ColorfulTheme, paranoid: bool) -> WalletKeys {
     println!();
     println!(
         "{}",
-        style("  📥 Import your existing keys").yellow()
+        style(format!("  📥 {}", t("Import your existing keys"))).yellow()
     );
     println!();
 
-    // Get secret key (show * for each character typed for visual feedback)
-    let secret_hex = read_secret_with_mask("Secret key (hex, 0x...):");
+    // Get secret key (masked with *, or not echoed at all in paranoid mode)
+    let secret_hex = read_secret(t("Secret key (hex, 0x...):"), paranoid);
 
     if secret_hex.is_empty() {
-        eprintln!("{}", style("  ❌ Input cancelled").red());
+        eprintln!("{}", style(format!("  ❌ {}", t("Input cancelled"))).red());
         std::process::exit(1);
     }
 
@@ -182,12 +211,16 @@ fn import_keys(_theme: &ColorfulTheme) -> WalletKeys {
         Ok(bytes) => {
             eprintln!(
                 "{}",
-                style(format!("  ❌ Invalid key length: {} bytes (expected 32)", bytes.len())).red()
+                style(format!(
+                    "  ❌ {}",
+                    tf("Invalid key length: {} bytes (expected 32)", &[&bytes.len()])
+                ))
+                .red()
             );
             std::process::exit(1);
         }
         Err(e) => {
-            eprintln!("{}", style(format!("  ❌ Invalid hex: {}", e)).red());
+            eprintln!("{}", style(format!("  ❌ {}", tf("Invalid hex: {}", &[&e]))).red());
             std::process::exit(1);
         }
     };
@@ -197,21 +230,48 @@ fn import_keys(_theme: &ColorfulTheme) -> WalletKeys {
 
     let keys = WalletKeys::from_secret(secret_array);
 
+    // Vanity confirmation: the user checks the derived address against the
+    // one they expect, catching typos or a wrong key before anything is saved
+    let account_hex = keys.account_id_hex();
+    println!();
+    println!(
+        "  {} {}{}{}",
+        style(t("Derived account:")).bold(),
+        style(format!("0x{}", &account_hex[..8])).green().bold(),
+        style(&account_hex[8..56]).dim(),
+        style(&account_hex[56..]).green().bold()
+    );
+    println!();
+
+    let matches = Confirm::with_theme(theme)
+        .with_prompt(t("Does this address match the account you expect?"))
+        .default(false)
+        .interact()
+        .unwrap();
+
+    if !matches {
+        eprintln!(
+            "{}",
+            style(format!("  ❌ {}", t("Import aborted: address mismatch. Check your secret key."))).red()
+        );
+        std::process::exit(1);
+    }
+
     println!();
     println!(
         "{}",
-        style(format!("  ✅ Imported account: 0x{}", keys.account_id_hex())).green()
+        style(format!("  ✅ {}", tf("Imported account: 0x{}", &[&account_hex]))).green()
     );
     println!();
 
     keys
 }
 
-fn generate_new_keys(theme: &ColorfulTheme) -> WalletKeys {
+fn generate_new_keys(theme: &ColorfulTheme, paranoid: bool) -> WalletKeys {
     println!();
     println!(
         "{}",
-        style("  🎲 Generating new keys...").yellow()
+        style(format!("  🎲 {}", t("Generating new keys..."))).yellow()
     );
 
     let keys = WalletKeys::generate();
@@ -219,38 +279,51 @@ fn generate_new_keys(theme: &ColorfulTheme) -> WalletKeys {
     println!();
     println!(
         "{}",
-        style("  ⚠️  IMPORTANT: Save your secret key securely!").red().bold()
+        style(format!("  ⚠️  {}", t("IMPORTANT: Save your secret key securely!"))).red().bold()
     );
     println!(
         "{}",
-        style("     This is the ONLY way to recover your wallet.").red()
+        style(format!("     {}", t("This is the ONLY way to recover your wallet."))).red()
     );
     println!();
 
     println!(
         "  {} {}",
-        style("Account ID:").bold(),
+        style(t("Account ID:")).bold(),
         style(format!("0x{}", keys.account_id_hex())).green()
     );
     println!();
+
+    // In paranoid mode the secret stays hidden until explicitly requested
+    if paranoid {
+        wait_for_keypress(t("Make sure no one can see your screen, then press any key to reveal your secret key..."));
+        println!();
+    }
+
     println!(
         "  {} {}",
-        style("Secret Key:").bold(),
+        style(t("Secret Key:")).bold(),
         style(format!("0x{}", keys.secret_key_hex())).yellow()
     );
     println!();
 
     // Confirm backup
     let confirmed = Confirm::with_theme(theme)
-        .with_prompt("Have you saved your secret key securely?")
+        .with_prompt(t("Have you saved your secret key securely?"))
         .default(false)
         .interact()
         .unwrap();
 
+    // Wipe the secret from the screen as soon as it has been acknowledged
+    if paranoid {
+        let _ = Term::stdout().clear_screen();
+        print_banner();
+    }
+
     if !confirmed {
         eprintln!(
             "{}",
-            style("  ❌ Please save your secret key before continuing!").red()
+            style(format!("  ❌ {}", t("Please save your secret key before continuing!"))).red()
         );
         std::process::exit(1);
     }
@@ -263,125 +336,145 @@ fn unlock_wallet(storage: &WalletStorage) -> (WalletKeys, String) {
 
     println!(
         "{}",
-        style("  🔓 Unlock your wallet").yellow()
+        style(format!("  🔓 {}", t("Unlock your wallet"))).yellow()
     );
     println!();
 
     loop {
+        // Escalating delay after repeated failures (persisted across restarts)
+        let mut settings = storage.load_settings();
+        let wait = settings.unlock_guard.remaining_lockout(unix_now());
+        if wait > 0 {
+            let spinner = create_spinner(&tf(
+                "Too many failed attempts, next try in {}s...",
+                &[&wait],
+            ));
+            std::thread::sleep(std::time::Duration::from_secs(wait));
+            spinner.finish_and_clear();
+        }
+
         let password: String = Password::with_theme(&theme)
-            .with_prompt("Password")
+            .with_prompt(t("Password"))
             .interact()
             .unwrap();
 
         match storage.load_wallet(&password) {
             Ok((keys, rpc_url)) => {
+                if settings.unlock_guard.failed_attempts > 0 {
+                    println!(
+                        "{}",
+                        style(format!(
+                            "  ⚠️  {}",
+                            tf("{} failed unlock attempts since last use", &[
+                                &settings.unlock_guard.failed_attempts
+                            ])
+                        ))
+                        .yellow()
+                    );
+                    settings.unlock_guard.record_success();
+                    let _ = storage.save_settings(&settings);
+                }
                 println!();
                 println!(
                     "{}",
-                    style(format!("  ✅ Wallet unlocked: 0x{}...{}",
-                        &keys.account_id_hex()[..8],
-                        &keys.account_id_hex()[56..]
-                    )).green()
+                    style(format!("  ✅ {}", tf("Wallet unlocked: 0x{}...{}", &[
+                        &&keys.account_id_hex()[..8],
+                        &&keys.account_id_hex()[56..],
+                    ]))).green()
                 );
                 println!();
                 return (keys, rpc_url);
             }
             Err(e) => {
                 eprintln!("{}", style(format!("  ❌ {}", e)).red());
+
+                settings.unlock_guard.record_failure(unix_now());
+                if let Err(e) = storage.save_settings(&settings) {
+                    eprintln!("{}", style(format!("  ❌ {}", tf("Failed to save: {}", &[&e]))).red());
+                }
+
+                if settings.unlock_guard.should_wipe() {
+                    wipe_wallet(storage);
+                }
+                if let Some(left) = settings.unlock_guard.attempts_before_wipe() {
+                    eprintln!(
+                        "{}",
+                        style(format!(
+                            "  🧨 {}",
+                            tf("{} attempts left before this wallet is erased", &[&left])
+                        ))
+                        .red()
+                        .bold()
+                    );
+                }
                 println!();
             }
         }
     }
 }
 
-fn main_menu(term: &Term, keys: &WalletKeys, client: &RpcClient, storage: &WalletStorage) {
-    let theme = ColorfulTheme::default();
-
-    loop {
-        let _ = term.clear_screen();
-        print_banner();
+/// Erase the wallet file after too many failed unlock attempts, then exit
+fn wipe_wallet(storage: &WalletStorage) -> ! {
+    println!();
+    eprintln!(
+        "{}",
+        style(format!("  🧨 {}", t("Too many failed attempts: the wallet file has been erased."))).red().bold()
+    );
+    if let Err(e) = storage.delete_wallet() {
+        eprintln!("{}", style(format!("  ❌ {}", e)).red());
+    } else {
+        let mut settings = storage.load_settings();
+        settings.unlock_guard.record_success();
+        let _ = storage.save_settings(&settings);
+    }
+    eprintln!("{}", style(format!("  {}", t("Restore it by importing your secret key backup."))).yellow());
+    println!();
+    std::process::exit(1);
+}
 
-        // Show account info
-        print_account_header(keys);
+/// Current Unix timestamp in seconds
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
 
-        // Check if we're in bootstrap era (show community menu to everyone during bootstrap)
-        let is_bootstrap = check_is_bootstrap(client);
+fn main_menu(term: &Term, mut keys: WalletKeys, rpc_url: &str, client: &RpcClient, storage: &WalletStorage) {
+    let mut dashboard = tui::Dashboard::new(&keys.account_id_hex(), rpc_url, storage);
 
-        // Build menu choices - always show community during bootstrap era
-        let choices = if is_bootstrap {
-            vec![
-                "💰 Check Balance",
-                "📤 Send KRAT",
-                "📜 Transaction History",
-                "🏛️  Community (Early Validators)",
-                "⚙️  Settings",
-                "🚪 Exit",
-            ]
-        } else {
-            vec![
-                "💰 Check Balance",
-                "📤 Send KRAT",
-                "📜 Transaction History",
-                "⚙️  Settings",
-                "🚪 Exit",
-            ]
+    loop {
+        let action = match dashboard.run() {
+            Ok(action) => action,
+            Err(e) => {
+                eprintln!("{}", style(format!("  ❌ {}", tf("Terminal error: {}", &[&e]))).red());
+                return;
+            }
         };
 
-        let selection = Select::with_theme(&theme)
-            .with_prompt("What would you like to do?")
-            .items(&choices)
-            .default(0)
-            .interact()
-            .unwrap();
-
-        // Map selection to action
-        if is_bootstrap {
-            match selection {
-                0 => check_balance(term, keys, client),
-                1 => send_krat(term, keys, client, storage),
-                2 => transaction_history(term, keys, client, storage),
-                3 => early_validator_menu(term, keys, client),
-                4 => settings(term, keys, storage),
-                5 => {
-                    println!();
-                    println!("{}", style("  👋 Goodbye!").cyan());
-                    println!();
-                    break;
+        match action {
+            tui::Action::Send => send_krat(term, &keys, client, storage),
+            tui::Action::History => transaction_history(term, &keys, client, storage),
+            tui::Action::Inbox => inbox(term, &keys, client),
+            tui::Action::AddressList => validate_address_list(term, &keys, client),
+            tui::Action::Community => early_validator_menu(term, &keys, client),
+            tui::Action::Validator => validator_dashboard(term, &keys, client),
+            tui::Action::Settings => {
+                if let Some(new_keys) = settings(term, &keys, client, storage) {
+                    keys = new_keys;
+                    dashboard = tui::Dashboard::new(&keys.account_id_hex(), rpc_url, storage);
                 }
-                _ => {}
             }
-        } else {
-            match selection {
-                0 => check_balance(term, keys, client),
-                1 => send_krat(term, keys, client, storage),
-                2 => transaction_history(term, keys, client, storage),
-                3 => settings(term, keys, storage),
-                4 => {
-                    println!();
-                    println!("{}", style("  👋 Goodbye!").cyan());
-                    println!();
-                    break;
-                }
-                _ => {}
+            tui::Action::Exit => {
+                println!();
+                println!("{}", style(format!("  👋 {}", t("Goodbye!"))).cyan());
+                println!();
+                break;
             }
         }
     }
 }
 
-/// Check if we're in bootstrap era
-fn check_is_bootstrap(client: &RpcClient) -> bool {
-    match client.get_early_voting_status() {
-        Ok(status) => {
-            eprintln!("[DEBUG] Bootstrap status: is_bootstrap_era = {}", status.is_bootstrap_era);
-            status.is_bootstrap_era
-        }
-        Err(e) => {
-            eprintln!("[DEBUG] Failed to get bootstrap status: {}", e);
-            false // Assume not bootstrap if RPC fails
-        }
-    }
-}
-
 /// Check if the current wallet is an active validator
 fn check_if_validator(keys: &WalletKeys, client: &RpcClient) -> bool {
     match client.can_vote(&keys.account_id_hex()) {
@@ -390,78 +483,90 @@ fn check_if_validator(keys: &WalletKeys, client: &RpcClient) -> bool {
     }
 }
 
-fn print_account_header(keys: &WalletKeys) {
-    let account_hex = keys.account_id_hex();
-    println!(
-        "  {} 0x{}...{}",
-        style("Account:").dim(),
-        &account_hex[..8],
-        &account_hex[56..]
-    );
-    println!();
-}
+/// Validate a file of recipients (e.g. an airdrop list) before a batch send
+fn validate_address_list(term: &Term, keys: &WalletKeys, client: &RpcClient) {
+    use crate::address_list::{check_on_chain, parse_list, Severity};
+    use crate::ui::format_balance;
 
-fn check_balance(term: &Term, keys: &WalletKeys, client: &RpcClient) {
     let _ = term.clear_screen();
     print_banner();
 
-    println!("{}", style("  💰 Account Balance").cyan().bold());
+    println!("{}", style(format!("  🧾 {}", t("Validate Address List"))).cyan().bold());
+    println!();
+    println!("  {}", style(t("One recipient per line: address or address,amount. Lines starting with # are ignored.")).dim());
     println!();
 
-    let spinner = create_spinner("Fetching balance...");
+    let theme = ColorfulTheme::default();
+    let path: String = Input::with_theme(&theme)
+        .with_prompt(t("Path to address file"))
+        .interact_text()
+        .unwrap();
 
-    match client.get_account(&keys.account_id_hex()) {
-        Ok(info) => {
-            spinner.finish_and_clear();
+    let content = match std::fs::read_to_string(path.trim()) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed to read file: {}", &[&e]))).red());
+            wait_for_enter();
+            return;
+        }
+    };
 
-            println!(
-                "  {} {}",
-                style("Address:").dim(),
-                style(format!("0x{}", keys.account_id_hex())).white()
-            );
-            println!();
+    let mut report = parse_list(&content, &keys.account_id_bytes());
 
-            // Display balances
-            println!(
-                "  {}",
-                style("┌─────────────────────────────────────────┐").dim()
-            );
-            println!(
-                "  {}  {:<15} {} {}",
-                style("│").dim(),
-                "Free:",
-                style(&info.free).green().bold(),
-                style("│").dim()
-            );
-            println!(
-                "  {}  {:<15} {} {}",
-                style("│").dim(),
-                "Reserved:",
-                style(&info.reserved).yellow(),
-                style("│").dim()
-            );
-            println!(
-                "  {}  {:<15} {} {}",
-                style("│").dim(),
-                "Total:",
-                style(&info.total).cyan().bold(),
-                style("│").dim()
-            );
-            println!(
-                "  {}",
-                style("└─────────────────────────────────────────┘").dim()
-            );
+    let spinner = create_spinner(t("Checking accounts on chain..."));
+    check_on_chain(&mut report, client);
+    let own = client.get_account(&keys.account_id_hex()).ok();
+    let base_fee = client.next_base_fee();
+    spinner.finish_and_clear();
 
-            println!();
+    let base_fee = match base_fee {
+        Ok(base_fee) => base_fee,
+        Err(e) => {
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed to get chain: {}", &[&e]))).red());
+            wait_for_enter();
+            return;
+        }
+    };
+    let transfer = crate::types::TransactionCall::Transfer { to: [0u8; 32].into(), amount: 0 };
+    let total = report.total_amount();
+    let fees = preview::scale_fee(preview::static_fee(&transfer), base_fee)
+        .saturating_mul(report.entries.len() as u128);
+
+    println!();
+    println!("{}", style(format!("  {}", t("Validation Summary:"))).yellow());
+    println!("  ├── {} {}", t("Valid recipients:"), style(report.entries.len()).green().bold());
+    println!("  ├── {} {}", t("Errors:"), style(report.error_count()).red().bold());
+    println!("  ├── {} {}", t("Warnings:"), style(report.warning_count()).yellow());
+    println!("  ├── {} {}", t("Total amount:"), format_balance(total));
+    println!("  └── {} {}", t("Total fees:"), format_balance(fees));
+    println!();
+
+    if let Some(info) = own {
+        if total.saturating_add(fees) > info.free_raw {
             println!(
-                "  {} {}",
-                style("Nonce:").dim(),
-                info.nonce
+                "{}",
+                style(format!(
+                    "  ⚠️  {}",
+                    tf("Insufficient balance: the batch needs {} but only {} is free", &[
+                        &format_balance(total.saturating_add(fees)),
+                        &format_balance(info.free_raw),
+                    ])
+                ))
+                .red()
             );
+            println!();
         }
-        Err(e) => {
-            spinner.finish_and_clear();
-            eprintln!("{}", style(format!("  ❌ Failed to fetch balance: {}", e)).red());
+    }
+
+    if report.issues.is_empty() {
+        println!("{}", style(format!("  ✅ {}", t("No problems found."))).green());
+    } else {
+        for issue in &report.issues {
+            let line = tf("Line {}:", &[&issue.line]);
+            match issue.severity {
+                Severity::Error => println!("  {} {} {}", style("✗").red(), style(line).dim(), issue.message),
+                Severity::Warning => println!("  {} {} {}", style("!").yellow(), style(line).dim(), issue.message),
+            }
         }
     }
 
@@ -473,21 +578,21 @@ fn send_krat(term: &Term, keys: &WalletKeys, client: &RpcClient, storage: &Walle
     let _ = term.clear_screen();
     print_banner();
 
-    println!("{}", style("  📤 Send KRAT").cyan().bold());
+    println!("{}", style(format!("  📤 {}", t("Send KRAT"))).cyan().bold());
     println!();
 
     let theme = ColorfulTheme::default();
 
     // Get recipient
     let recipient: String = Input::with_theme(&theme)
-        .with_prompt("Recipient address (0x...)")
+        .with_prompt(t("Recipient address (0x...)"))
         .validate_with(|input: &String| -> Result<(), &str> {
             let hex = input.strip_prefix("0x").unwrap_or(input);
             if hex.len() != 64 {
-                return Err("Address must be 64 hex characters");
+                return Err(t("Address must be 64 hex characters"));
             }
             if hex::decode(hex).is_err() {
-                return Err("Invalid hex address");
+                return Err(t("Invalid hex address"));
             }
             Ok(())
         })
@@ -496,14 +601,14 @@ fn send_krat(term: &Term, keys: &WalletKeys, client: &RpcClient, storage: &Walle
 
     // Get amount
     let amount_str: String = Input::with_theme(&theme)
-        .with_prompt("Amount (KRAT)")
+        .with_prompt(t("Amount (KRAT)"))
         .validate_with(|input: &String| -> Result<(), &str> {
             if input.parse::<f64>().is_err() {
-                return Err("Invalid amount");
+                return Err(t("Invalid amount"));
             }
             let amount: f64 = input.parse().unwrap();
             if amount <= 0.0 {
-                return Err("Amount must be positive");
+                return Err(t("Amount must be positive"));
             }
             Ok(())
         })
@@ -515,33 +620,42 @@ fn send_krat(term: &Term, keys: &WalletKeys, client: &RpcClient, storage: &Walle
 
     // Confirm
     println!();
-    println!("{}", style("  Transaction Summary:").yellow());
-    println!("  ├── To: {}", style(&recipient).white());
-    println!("  ├── Amount: {} KRAT", style(amount_krat).green().bold());
-    println!("  └── Fee: ~0.000001 KRAT");
+    println!("{}", style(format!("  {}", t("Transaction Summary:"))).yellow());
+    println!("  ├── {} {}", t("To:"), style(&recipient).white());
+    println!("  ├── {} {} KRAT", t("Amount:"), style(amount_krat).green().bold());
+    println!("  └── {} ~0.000001 KRAT", t("Fee:"));
     println!();
 
     let confirmed = Confirm::with_theme(&theme)
-        .with_prompt("Send this transaction?")
+        .with_prompt(t("Send this transaction?"))
         .default(false)
         .interact()
         .unwrap();
 
     if !confirmed {
         println!();
-        println!("{}", style("  ❌ Transaction cancelled").yellow());
+        println!("{}", style(format!("  ❌ {}", t("Transaction cancelled"))).yellow());
         wait_for_enter();
         return;
     }
 
     // Get current nonce
-    let spinner = create_spinner("Preparing transaction...");
+    let spinner = create_spinner(t("Preparing transaction..."));
 
-    let nonce = match client.get_nonce(&keys.account_id_hex()) {
+    let nonce = match client.next_nonce(&keys.account_id_hex()) {
         Ok(n) => n,
         Err(e) => {
             spinner.finish_and_clear();
-            eprintln!("{}", style(format!("  ❌ Failed to get nonce: {}", e)).red());
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed to get nonce: {}", &[&e]))).red());
+            wait_for_enter();
+            return;
+        }
+    };
+    let context = match client.transaction_context() {
+        Ok(context) => context,
+        Err(e) => {
+            spinner.finish_and_clear();
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed to get chain: {}", &[&e]))).red());
             wait_for_enter();
             return;
         }
@@ -553,19 +667,19 @@ fn send_krat(term: &Term, keys: &WalletKeys, client: &RpcClient, storage: &Walle
     let mut recipient_array = [0u8; 32];
     recipient_array.copy_from_slice(&recipient_bytes);
 
-    let signed_tx = keys.create_transfer(recipient_array, amount_raw, nonce);
+    let signed_tx = keys.create_transfer(recipient_array, amount_raw, nonce, &context);
 
-    spinner.set_message("Submitting transaction...");
+    spinner.set_message(t("Submitting transaction..."));
 
     // Submit transaction
     match client.submit_transaction(&signed_tx) {
         Ok(result) => {
             spinner.finish_and_clear();
             println!();
-            println!("{}", style("  ✅ Transaction submitted successfully!").green());
+            println!("{}", style(format!("  ✅ {}", t("Transaction submitted successfully!"))).green());
             println!();
-            println!("  {} {}", style("Hash:").dim(), style(&result.hash).cyan());
-            println!("  {} {}", style("Status:").dim(), result.message);
+            println!("  {} {}", style(t("Hash:")).dim(), style(&result.hash).cyan());
+            println!("  {} {}", style(t("Status:")).dim(), result.message);
 
             // Record transaction in local history
             let tx_record = crate::types::TransactionRecord::new_sent(
@@ -579,13 +693,13 @@ fn send_krat(term: &Term, keys: &WalletKeys, client: &RpcClient, storage: &Walle
             if let Err(e) = storage.add_transaction(tx_record) {
                 eprintln!(
                     "{}",
-                    style(format!("  ⚠️  Warning: Failed to save to history: {}", e)).yellow()
+                    style(format!("  ⚠️  {}", tf("Warning: Failed to save to history: {}", &[&e]))).yellow()
                 );
             }
         }
         Err(e) => {
             spinner.finish_and_clear();
-            eprintln!("{}", style(format!("  ❌ Transaction failed: {}", e)).red());
+            eprintln!("{}", style(format!("  ❌ {}", tf("Transaction failed: {}", &[&e]))).red());
         }
     }
 
@@ -597,17 +711,19 @@ fn transaction_history(term: &Term, keys: &WalletKeys, client: &RpcClient, stora
     let _ = term.clear_screen();
     print_banner();
 
-    println!("{}", style("  📜 Transaction History").cyan().bold());
+    println!("{}", style(format!("  📜 {}", t("Transaction History"))).cyan().bold());
     println!();
 
     let theme = ColorfulTheme::default();
     let page_size: usize = 10;
     let mut current_page: usize = 0;
+    // Registered names of counterparties, looked up once per address
+    let mut names: HashMap<String, Option<IdentityInfo>> = HashMap::new();
 
     loop {
         let _ = term.clear_screen();
         print_banner();
-        println!("{}", style("  📜 Transaction History").cyan().bold());
+        println!("{}", style(format!("  📜 {}", t("Transaction History"))).cyan().bold());
         println!();
 
         // Load local history
@@ -615,7 +731,15 @@ fn transaction_history(term: &Term, keys: &WalletKeys, client: &RpcClient, stora
         let my_address = keys.account_id_hex();
 
         // Try to sync with node (fetch new transactions)
-        let spinner = create_spinner("Syncing with node...");
+        let spinner = create_spinner(t("Syncing with node..."));
+
+        // Apply reorgs first so re-included transactions are confirmed again by the merge below
+        let reorged = sync_reorg_events(&mut history, client);
+        if let Ok(changed) = reorged {
+            if changed > 0 {
+                let _ = storage.save_history(&history);
+            }
+        }
 
         // Try to get transaction history from RPC
         match client.get_transaction_history(&my_address, 100, 0) {
@@ -639,7 +763,31 @@ fn transaction_history(term: &Term, keys: &WalletKeys, client: &RpcClient, stora
                 // RPC method not available, use local history only
                 println!(
                     "  {}",
-                    style("Using local history (node sync unavailable)").dim()
+                    style(t("Using local history (node sync unavailable)")).dim()
+                );
+                if !cache::node_online() {
+                    if let Some(snapshot) = storage.load_cache(&my_address) {
+                        println!("  {}", style(format!("⚠️  {}", cache::stamp(&snapshot))).yellow());
+                    }
+                }
+                println!();
+            }
+        }
+
+        match reorged {
+            Ok(0) => {}
+            Ok(changed) => {
+                println!(
+                    "  {}",
+                    style(format!("⚠️  {}", tf("{} transaction(s) affected by a chain reorganization", &[&changed])))
+                        .yellow()
+                );
+                println!();
+            }
+            Err(e) => {
+                println!(
+                    "  {}",
+                    style(format!("⚠️  {}", tf("Chain reorganizations not checked: {}", &[&e]))).yellow()
                 );
                 println!();
             }
@@ -659,48 +807,133 @@ fn transaction_history(term: &Term, keys: &WalletKeys, client: &RpcClient, stora
 
         print_history_header(total, page_txs.len(), current_page + 1, total_pages);
 
+        for tx in page_txs {
+            if cache::node_online() && !names.contains_key(&tx.counterparty) {
+                let identity = client.get_identity(&tx.counterparty).ok().flatten();
+                names.insert(tx.counterparty.clone(), identity);
+            }
+        }
+
         for (i, tx) in page_txs.iter().enumerate() {
-            print_transaction(tx, offset + i);
+            let identity = names.get(&tx.counterparty).and_then(Option::as_ref);
+            print_transaction(tx, offset + i, identity);
+        }
+
+        // Navigation menu (actions kept alongside their localized labels)
+        enum Nav {
+            Previous,
+            Next,
+            Refresh,
+            Back,
         }
 
-        // Navigation menu
         let mut nav_choices = vec![];
 
         if current_page > 0 {
-            nav_choices.push("Previous page");
+            nav_choices.push((Nav::Previous, t("Previous page")));
         }
         if current_page < total_pages.saturating_sub(1) {
-            nav_choices.push("Next page");
+            nav_choices.push((Nav::Next, t("Next page")));
         }
-        nav_choices.push("Refresh");
-        nav_choices.push("Back to menu");
+        nav_choices.push((Nav::Refresh, t("Refresh")));
+        nav_choices.push((Nav::Back, t("Back to menu")));
 
+        let labels: Vec<&str> = nav_choices.iter().map(|(_, label)| *label).collect();
         let selection = Select::with_theme(&theme)
-            .with_prompt("Navigation")
-            .items(&nav_choices)
+            .with_prompt(t("Navigation"))
+            .items(&labels)
             .default(0)
             .interact()
             .unwrap();
 
-        let choice = nav_choices[selection];
-
-        match choice {
-            "Previous page" => {
+        match nav_choices[selection].0 {
+            Nav::Previous => {
                 current_page = current_page.saturating_sub(1);
             }
-            "Next page" => {
+            Nav::Next => {
                 current_page += 1;
             }
-            "Refresh" => {
-                // Loop will refresh
+            Nav::Refresh => {
+                // Loop will refresh, names included
+                names.clear();
+            }
+            Nav::Back => {
+                return;
             }
-            "Back to menu" | _ => {
+        }
+    }
+}
+
+/// Messages kept on chain per inbox (MAX_INBOX_MESSAGES on the node)
+const INBOX_LIMIT: u32 = 256;
+
+fn inbox(term: &Term, keys: &WalletKeys, client: &RpcClient) {
+    let theme = ColorfulTheme::default();
+    // Registered names of senders, looked up once per address
+    let mut names: HashMap<String, Option<IdentityInfo>> = HashMap::new();
+
+    loop {
+        let _ = term.clear_screen();
+        print_banner();
+        println!("{}", style(format!("  ✉️  {}", t("Inbox"))).cyan().bold());
+        println!();
+
+        let spinner = create_spinner(t("Fetching messages..."));
+        let messages = client.get_inbox(&keys.account_id_hex(), INBOX_LIMIT);
+        spinner.finish_and_clear();
+
+        let messages = match messages {
+            Ok(messages) => messages,
+            Err(e) => {
+                eprintln!("{}", style(format!("  ❌ {}", tf("Failed to fetch messages: {}", &[&e]))).red());
+                println!();
+                wait_for_enter();
                 return;
             }
+        };
+
+        if messages.is_empty() {
+            println!("  {}", style(t("No messages")).dim());
+            println!();
+            println!("  {}", style(t("Messages sent to your address on chain appear here.")).dim());
+            println!();
+            wait_for_enter();
+            return;
+        }
+
+        println!("  {}", style(tf("Latest {} messages, newest first", &[&messages.len()])).dim());
+        println!();
+        for message in &messages {
+            if !names.contains_key(&message.from) {
+                let identity = client.get_identity(&message.from).ok().flatten();
+                names.insert(message.from.clone(), identity);
+            }
+            print_message(message, names.get(&message.from).and_then(Option::as_ref));
         }
+
+        let choices = [t("Refresh"), t("Back to menu")];
+        let selection = Select::with_theme(&theme)
+            .with_prompt(t("Navigation"))
+            .items(&choices)
+            .default(0)
+            .interact()
+            .unwrap();
+        if selection == 1 {
+            return;
+        }
+        // Loop will refresh, names included
+        names.clear();
     }
 }
 
+/// Fetch reorg events from the node and apply them to local history
+///
+/// Returns the number of records that changed.
+fn sync_reorg_events(history: &mut crate::types::TransactionHistory, client: &RpcClient) -> Result<usize, String> {
+    let response = client.get_reorg_events(history.last_reorg_event)?;
+    Ok(history.apply_reorg_events(&response.events))
+}
+
 /// Update pending transaction statuses by querying the node
 fn update_pending_transactions(history: &mut crate::types::TransactionHistory, client: &RpcClient) {
     use crate::types::TransactionStatus;
@@ -712,7 +945,8 @@ fn update_pending_transactions(history: &mut crate::types::TransactionHistory, c
 
     if let Ok(current_height) = client.get_block_height() {
         for tx in history.transactions.iter_mut() {
-            if tx.status == TransactionStatus::Pending {
+            // Reorged transactions wait for the node to report them again
+            if tx.status == TransactionStatus::Pending && tx.orphaned_block.is_none() {
                 // If transaction is old (more than ~10 blocks worth of time),
                 // assume it's either confirmed or failed
                 // This is a heuristic - proper implementation would query the node
@@ -737,64 +971,225 @@ fn update_pending_transactions(history: &mut crate::types::TransactionHistory, c
 }
 
 // =============================================================================
-// EARLY VALIDATOR VOTING SECTION
+// VALIDATOR DASHBOARD SECTION
 // =============================================================================
 
-/// Early validator voting menu (shown to everyone during bootstrap, but only validators can vote)
-fn early_validator_menu(term: &Term, keys: &WalletKeys, client: &RpcClient) {
-    let theme = ColorfulTheme::default();
+/// Operational panel for active validators
+fn validator_dashboard(term: &Term, keys: &WalletKeys, client: &RpcClient) {
+    let _ = term.clear_screen();
+    print_banner();
 
-    loop {
-        let _ = term.clear_screen();
-        print_banner();
+    println!("{}", style(format!("  🛡️  {}", t("Validator Dashboard"))).cyan().bold());
+    println!();
 
-        println!("{}", style("  🏛️  Community - Early Validators").cyan().bold());
-        println!();
+    let spinner = create_spinner(t("Fetching validator status..."));
+    let info = client.get_validator_info(&keys.account_id_hex());
+    let credits = client.get_validator_credits(&keys.account_id_hex());
+    let history = client.get_credits_history(&keys.account_id_hex(), 5);
+    let unbonding = client.get_unbonding(&keys.account_id_hex());
+    spinner.finish_and_clear();
 
-        // Show voting status
-        if let Err(e) = print_voting_status(client) {
-            eprintln!("{}", style(format!("  ❌ {}", e)).red());
+    let info = match info {
+        Ok(info) => info,
+        Err(e) => {
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed: {}", &[&e]))).red());
+            println!();
             wait_for_enter();
             return;
         }
+    };
 
-        // Check if user is a validator (can propose/vote)
-        let is_validator = check_if_validator(keys, client);
+    let yes_no = |value: bool| if value { style(t("yes")).green() } else { style(t("no")).red() };
 
-        if is_validator {
-            println!("  {} {}", style("Your status:").dim(), style("ACTIVE VALIDATOR").green().bold());
-            println!("  {}", style("You can propose candidates and vote.").dim());
-        } else {
-            println!("  {} {}", style("Your status:").dim(), style("OBSERVER").yellow());
-            println!("  {}", style("You can view candidates but cannot vote.").dim());
-        }
-        println!();
+    // Session status
+    println!("  {}", style(t("Session")).yellow().bold());
+    println!("  ├── {} {}", t("Status:"), style(&info.status).white().bold());
+    println!("  ├── {} {}", t("Producing blocks:"), yes_no(info.can_participate));
+    println!("  ├── {} {}", t("Voted last finality round:"), yes_no(info.finality_voter));
+    if info.is_bootstrap_validator {
+        println!("  ├── {} {}", t("Bootstrap validator:"), yes_no(true));
+    }
+    println!(
+        "  └── {} {} ({})",
+        t("Epoch:"),
+        info.current_epoch,
+        tf("{} blocks until next epoch", &[&info.blocks_until_next_epoch])
+    );
+    println!();
 
-        // Build menu based on validator status
-        let choices: Vec<&str> = if is_validator {
-            vec![
-                "📋 View Pending Candidates",
-                "➕ Propose New Validator",
-                "✅ Vote for Candidate",
-                "🔍 Check Candidate Status",
-                "⬅️  Back to Main Menu",
-            ]
-        } else {
-            vec![
-                "📋 View Pending Candidates",
-                "🔍 Check Candidate Status",
-                "⬅️  Back to Main Menu",
-            ]
-        };
+    // Validator credits
+    println!("  {}", style(t("Validator Credits")).yellow().bold());
+    match &credits {
+        Ok(credits) => {
+            println!("  ├── {} {}", t("Votes:"), credits.vote_credits);
+            println!("  ├── {} {}", t("Uptime:"), credits.uptime_credits);
+            println!("  ├── {} {}", t("Arbitration:"), credits.arbitration_credits);
+            println!("  ├── {} {}", t("Seniority:"), credits.seniority_credits);
+            if credits.clock_penalty > 0 {
+                println!("  ├── {} -{}", t("Clock penalty:"), style(credits.clock_penalty).red());
+            }
+            if credits.deducted > 0 {
+                println!("  ├── {} -{}", t("Slashed:"), style(credits.deducted).red());
+            }
+            println!("  └── {} {}", t("Total:"), style(credits.total).cyan().bold());
+        }
+        Err(_) => {
+            println!("  └── {} {}", t("Total:"), style(info.validator_credits).cyan().bold());
+        }
+    }
+    if let Some(history) = history.as_ref().ok().filter(|h| !h.epochs.is_empty()) {
+        println!("  {}", t("Recent epochs:"));
+        for (i, epoch) in history.epochs.iter().enumerate() {
+            let branch = if i + 1 == history.epochs.len() { "└──" } else { "├──" };
+            let deducted = if epoch.deducted > 0 {
+                style(format!(" -{}", epoch.deducted)).red().to_string()
+            } else {
+                String::new()
+            };
+            println!(
+                "  {} {} +{}{} → {}",
+                branch,
+                tf("epoch {}:", &[&epoch.epoch]),
+                epoch.earned(),
+                deducted,
+                epoch.total
+            );
+        }
+    }
+    println!();
 
-        let selection = Select::with_theme(&theme)
-            .with_prompt("Community Actions")
-            .items(&choices)
-            .default(0)
-            .interact()
-            .unwrap();
+    // Participation
+    println!("  {}", style(t("Participation")).yellow().bold());
+    println!("  ├── {} {}", t("Blocks produced:"), info.blocks_produced);
+    println!("  ├── {} {}", t("Blocks missed:"), info.blocks_missed);
+    let participation = style(format!("{:.1}%", info.participation_percent));
+    let participation = if info.participation_percent >= 90.0 {
+        participation.green()
+    } else {
+        participation.yellow()
+    };
+    println!("  ├── {} {}", t("Participation:"), participation);
+    println!("  ├── {} {}/100", t("Reputation:"), info.reputation);
+    println!("  └── {} {}", t("Clock sync failures:"), info.clock_sync_failures);
+    println!();
 
-        if is_validator {
+    // Stake and rewards
+    println!("  {}", style(t("Stake & Rewards")).yellow().bold());
+    println!("  ├── {} {}", t("Stake:"), style(crate::ui::format_balance(info.stake)).white());
+    if let Some(commission) = info.commission {
+        let announced = info
+            .announced_commission
+            .as_ref()
+            .map(|a| tf(" ({}% from epoch {})", &[&a.commission, &a.effective_epoch]))
+            .unwrap_or_default();
+        println!("  ├── {} {}%{}", t("Commission:"), commission, announced);
+    }
+    match &unbonding {
+        Ok(unbonding) if !unbonding.chunks.is_empty() => {
+            println!("  ├── {} {}", t("Unbonding:"), crate::ui::format_balance(unbonding.total));
+            for chunk in &unbonding.chunks {
+                println!(
+                    "  │   • {} {}",
+                    crate::ui::format_balance(chunk.amount),
+                    tf("unlocks at block #{} (epoch {})", &[&chunk.unlock_at, &chunk.epoch])
+                );
+            }
+            if unbonding.withdrawable > 0 {
+                println!(
+                    "  ├── {} {}",
+                    t("Withdrawable:"),
+                    style(crate::ui::format_balance(unbonding.withdrawable)).green()
+                );
+            }
+        }
+        // Node without staking_getUnbonding: total only
+        Err(_) if info.unbonding_balance > 0 => {
+            let until = info
+                .unbonding_at
+                .map(|b| tf(" (until block #{})", &[&b]))
+                .unwrap_or_default();
+            println!("  ├── {} {}{}", t("Unbonding:"), crate::ui::format_balance(info.unbonding_balance), until);
+        }
+        _ => {}
+    }
+    println!(
+        "  └── {}",
+        style(t("Rewards are credited to your free balance with each produced block")).dim()
+    );
+    println!();
+
+    // Slashing
+    println!("  {}", style(t("Slashing")).yellow().bold());
+    if info.slash_count == 0 {
+        println!("  └── {}", style(t("No slashing events")).green());
+    } else {
+        println!("  ├── {} {}", t("Events:"), style(info.slash_count).red().bold());
+        println!("  └── {} {}", t("Total slashed:"), style(crate::ui::format_balance(info.total_slashed)).red());
+    }
+    println!();
+
+    wait_for_enter();
+}
+
+// =============================================================================
+// EARLY VALIDATOR VOTING SECTION
+// =============================================================================
+
+/// Early validator voting menu (shown to everyone during bootstrap, but only validators can vote)
+fn early_validator_menu(term: &Term, keys: &WalletKeys, client: &RpcClient) {
+    let theme = ColorfulTheme::default();
+
+    loop {
+        let _ = term.clear_screen();
+        print_banner();
+
+        println!("{}", style(format!("  🏛️  {}", t("Community - Early Validators"))).cyan().bold());
+        println!();
+
+        // Show voting status
+        if let Err(e) = print_voting_status(client) {
+            eprintln!("{}", style(format!("  ❌ {}", e)).red());
+            wait_for_enter();
+            return;
+        }
+
+        // Check if user is a validator (can propose/vote)
+        let is_validator = check_if_validator(keys, client);
+
+        if is_validator {
+            println!("  {} {}", style(t("Your status:")).dim(), style(t("ACTIVE VALIDATOR")).green().bold());
+            println!("  {}", style(t("You can propose candidates and vote.")).dim());
+        } else {
+            println!("  {} {}", style(t("Your status:")).dim(), style(t("OBSERVER")).yellow());
+            println!("  {}", style(t("You can view candidates but cannot vote.")).dim());
+        }
+        println!();
+
+        // Build menu based on validator status
+        let choices: Vec<String> = if is_validator {
+            vec![
+                format!("📋 {}", t("View Pending Candidates")),
+                format!("➕ {}", t("Propose New Validator")),
+                format!("✅ {}", t("Vote for Candidate")),
+                format!("🔍 {}", t("Check Candidate Status")),
+                format!("⬅️  {}", t("Back to Main Menu")),
+            ]
+        } else {
+            vec![
+                format!("📋 {}", t("View Pending Candidates")),
+                format!("🔍 {}", t("Check Candidate Status")),
+                format!("⬅️  {}", t("Back to Main Menu")),
+            ]
+        };
+
+        let selection = Select::with_theme(&theme)
+            .with_prompt(t("Community Actions"))
+            .items(&choices)
+            .default(0)
+            .interact()
+            .unwrap();
+
+        if is_validator {
             match selection {
                 0 => view_pending_candidates(term, client),
                 1 => propose_validator(term, keys, client),
@@ -818,50 +1213,48 @@ fn early_validator_menu(term: &Term, keys: &WalletKeys, client: &RpcClient) {
 fn print_voting_status(client: &RpcClient) -> Result<(), String> {
     let status = client.get_early_voting_status()?;
 
-    println!("  {}", style("Bootstrap Era Status").yellow().bold());
+    println!("  {}", style(t("Bootstrap Era Status")).yellow().bold());
     println!("  {}", style("─".repeat(40)).dim());
 
     if status.is_bootstrap_era {
         println!(
             "  {} {}",
-            style("Status:").dim(),
-            style("ACTIVE").green().bold()
+            style(t("Status:")).dim(),
+            style(t("ACTIVE")).green().bold()
         );
         println!(
-            "  {} {} / {} blocks remaining",
-            style("Progress:").dim(),
-            status.current_block,
-            status.bootstrap_end_block
+            "  {} {}",
+            style(t("Progress:")).dim(),
+            tf("{} / {} blocks remaining", &[&status.current_block, &status.bootstrap_end_block])
         );
     } else {
         println!(
             "  {} {}",
-            style("Status:").dim(),
-            style("ENDED").red().bold()
+            style(t("Status:")).dim(),
+            style(t("ENDED")).red().bold()
         );
         println!();
         println!(
             "  {}",
-            style("Bootstrap era has ended. No more early validators can be added.").yellow()
+            style(t("Bootstrap era has ended. No more early validators can be added.")).yellow()
         );
         return Ok(());
     }
 
     println!(
-        "  {} {} / {} validators",
-        style("Validators:").dim(),
-        status.validator_count,
-        status.max_validators
+        "  {} {}",
+        style(t("Validators:")).dim(),
+        tf("{} / {} validators", &[&status.validator_count, &status.max_validators])
     );
     println!(
-        "  {} {} votes needed for next validator",
-        style("Threshold:").dim(),
-        status.votes_required
+        "  {} {}",
+        style(t("Threshold:")).dim(),
+        tf("{} votes needed for next validator", &[&status.votes_required])
     );
     println!(
-        "  {} {} pending",
-        style("Candidates:").dim(),
-        status.pending_candidates
+        "  {} {}",
+        style(t("Candidates:")).dim(),
+        tf("{} pending", &[&status.pending_candidates])
     );
     println!();
 
@@ -873,10 +1266,10 @@ fn view_pending_candidates(term: &Term, client: &RpcClient) {
     let _ = term.clear_screen();
     print_banner();
 
-    println!("{}", style("  📋 Pending Candidates").cyan().bold());
+    println!("{}", style(format!("  📋 {}", t("Pending Candidates"))).cyan().bold());
     println!();
 
-    let spinner = create_spinner("Fetching candidates...");
+    let spinner = create_spinner(t("Fetching candidates..."));
 
     match client.get_pending_candidates() {
         Ok(response) => {
@@ -885,13 +1278,13 @@ fn view_pending_candidates(term: &Term, client: &RpcClient) {
             if response.candidates.is_empty() {
                 println!(
                     "  {}",
-                    style("No pending candidates at this time.").dim()
+                    style(t("No pending candidates at this time.")).dim()
                 );
             } else {
                 println!(
                     "  {} {}",
-                    style("Found").dim(),
-                    style(format!("{} candidates", response.count)).white()
+                    style(t("Found")).dim(),
+                    style(tf("{} candidates", &[&response.count])).white()
                 );
                 println!();
 
@@ -901,7 +1294,7 @@ fn view_pending_candidates(term: &Term, client: &RpcClient) {
                         candidate.vote_count, candidate.votes_required
                     );
                     let status_icon: String = if candidate.has_quorum {
-                        format!("{}", style("✓ READY").green())
+                        format!("{}", style(format!("✓ {}", t("READY"))).green())
                     } else {
                         format!("{}", style(&progress).yellow())
                     };
@@ -912,14 +1305,20 @@ fn view_pending_candidates(term: &Term, client: &RpcClient) {
                         format_address_short(&candidate.candidate),
                         status_icon
                     );
+                    if let Some(name) = &candidate.display_name {
+                        println!("      {} {}", style(t("Name:")).dim(), style(name).white().bold());
+                    }
+                    if let Some(url) = &candidate.url {
+                        println!("      {} {}", style(t("URL:")).dim(), style(url).cyan());
+                    }
                     println!(
                         "      {} {}",
-                        style("Proposed by:").dim(),
+                        style(t("Proposed by:")).dim(),
                         format_address_short(&candidate.proposer)
                     );
                     println!(
                         "      {} {:?}",
-                        style("Voters:").dim(),
+                        style(t("Voters:")).dim(),
                         candidate
                             .voters
                             .iter()
@@ -932,7 +1331,7 @@ fn view_pending_candidates(term: &Term, client: &RpcClient) {
         }
         Err(e) => {
             spinner.finish_and_clear();
-            eprintln!("{}", style(format!("  ❌ Failed: {}", e)).red());
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed: {}", &[&e]))).red());
         }
     }
 
@@ -944,21 +1343,21 @@ fn propose_validator(term: &Term, keys: &WalletKeys, client: &RpcClient) {
     let _ = term.clear_screen();
     print_banner();
 
-    println!("{}", style("  ➕ Propose New Validator").cyan().bold());
+    println!("{}", style(format!("  ➕ {}", t("Propose New Validator"))).cyan().bold());
     println!();
 
     let theme = ColorfulTheme::default();
 
     // Get candidate address
     let candidate_str: String = Input::with_theme(&theme)
-        .with_prompt("Candidate address (0x...)")
+        .with_prompt(t("Candidate address (0x...)"))
         .validate_with(|input: &String| -> Result<(), &str> {
             let hex = input.strip_prefix("0x").unwrap_or(input);
             if hex.len() != 64 {
-                return Err("Address must be 64 hex characters");
+                return Err(t("Address must be 64 hex characters"));
             }
             if hex::decode(hex).is_err() {
-                return Err("Invalid hex address");
+                return Err(t("Invalid hex address"));
             }
             Ok(())
         })
@@ -971,53 +1370,107 @@ fn propose_validator(term: &Term, keys: &WalletKeys, client: &RpcClient) {
     let mut candidate_array = [0u8; 32];
     candidate_array.copy_from_slice(&candidate_bytes);
 
-    // Confirm
-    println!();
-    println!("{}", style("  Proposal Summary:").yellow());
-    println!("  ├── Candidate: {}", style(&candidate_str).white());
-    println!("  └── Fee: ~0.00005 KRAT (50,000 units)");
-    println!();
-
-    let confirmed = Confirm::with_theme(&theme)
-        .with_prompt("Submit this proposal?")
-        .default(false)
-        .interact()
+    // Optional public metadata (shown to voters)
+    let display_name: String = Input::with_theme(&theme)
+        .with_prompt(t("Display name (optional)"))
+        .allow_empty(true)
+        .validate_with(|input: &String| -> Result<(), &str> {
+            if input.len() > MAX_CANDIDATE_NAME_LEN {
+                return Err(t("Display name is too long"));
+            }
+            if input.chars().any(|c| c.is_control()) {
+                return Err(t("Display name contains invalid characters"));
+            }
+            Ok(())
+        })
+        .interact_text()
         .unwrap();
 
-    if !confirmed {
-        println!();
-        println!("{}", style("  ❌ Proposal cancelled").yellow());
-        wait_for_enter();
-        return;
-    }
+    let url: String = Input::with_theme(&theme)
+        .with_prompt(t("Contact URL (optional)"))
+        .allow_empty(true)
+        .validate_with(|input: &String| -> Result<(), &str> {
+            if input.is_empty() {
+                return Ok(());
+            }
+            if input.len() > MAX_CANDIDATE_URL_LEN {
+                return Err(t("URL is too long"));
+            }
+            if !(input.starts_with("https://") || input.starts_with("http://")) {
+                return Err(t("URL must start with http:// or https://"));
+            }
+            if input.chars().any(|c| c.is_control() || c.is_whitespace()) {
+                return Err(t("URL contains invalid characters"));
+            }
+            Ok(())
+        })
+        .interact_text()
+        .unwrap();
 
-    // Get nonce and submit
-    let spinner = create_spinner("Submitting proposal...");
+    let metadata = CandidateMetadata {
+        display_name: Some(display_name.trim().to_string()).filter(|n| !n.is_empty()),
+        url: Some(url).filter(|u| !u.is_empty()),
+    };
 
-    let nonce = match client.get_nonce(&keys.account_id_hex()) {
+    // Get nonce and build the transaction
+    let spinner = create_spinner(t("Preparing transaction..."));
+    let nonce = match client.next_nonce(&keys.account_id_hex()) {
         Ok(n) => n,
         Err(e) => {
             spinner.finish_and_clear();
-            eprintln!("{}", style(format!("  ❌ Failed to get nonce: {}", e)).red());
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed to get nonce: {}", &[&e]))).red());
             wait_for_enter();
             return;
         }
     };
+    let context = match client.transaction_context() {
+        Ok(context) => context,
+        Err(e) => {
+            spinner.finish_and_clear();
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed to get chain: {}", &[&e]))).red());
+            wait_for_enter();
+            return;
+        }
+    };
+    spinner.finish_and_clear();
 
-    let signed_tx = keys.create_propose_early_validator(candidate_array, nonce);
+    let call = crate::types::TransactionCall::ProposeEarlyValidator {
+        candidate: candidate_array.into(),
+        metadata,
+    };
+    let Some(transaction) = preview_transaction(keys.build_transaction(call, nonce, &context)) else {
+        wait_for_enter();
+        return;
+    };
+
+    let confirmed = Confirm::with_theme(&theme)
+        .with_prompt(t("Submit this proposal?"))
+        .default(false)
+        .interact()
+        .unwrap();
+
+    if !confirmed {
+        println!();
+        println!("{}", style(format!("  ❌ {}", t("Proposal cancelled"))).yellow());
+        wait_for_enter();
+        return;
+    }
+
+    let spinner = create_spinner(t("Submitting proposal..."));
+    let signed_tx = keys.sign_transaction(transaction);
 
     match client.submit_propose_early_validator(&signed_tx) {
         Ok(result) => {
             spinner.finish_and_clear();
             println!();
-            println!("{}", style("  ✅ Proposal submitted successfully!").green());
+            println!("{}", style(format!("  ✅ {}", t("Proposal submitted successfully!"))).green());
             println!();
-            println!("  {} {}", style("Hash:").dim(), style(&result.hash).cyan());
-            println!("  {} {}", style("Status:").dim(), result.message);
+            println!("  {} {}", style(t("Hash:")).dim(), style(&result.hash).cyan());
+            println!("  {} {}", style(t("Status:")).dim(), result.message);
         }
         Err(e) => {
             spinner.finish_and_clear();
-            eprintln!("{}", style(format!("  ❌ Proposal failed: {}", e)).red());
+            eprintln!("{}", style(format!("  ❌ {}", tf("Proposal failed: {}", &[&e]))).red());
         }
     }
 
@@ -1030,11 +1483,11 @@ fn vote_for_candidate(term: &Term, keys: &WalletKeys, client: &RpcClient) {
     let _ = term.clear_screen();
     print_banner();
 
-    println!("{}", style("  ✅ Vote for Candidate").cyan().bold());
+    println!("{}", style(format!("  ✅ {}", t("Vote for Candidate"))).cyan().bold());
     println!();
 
     // First show pending candidates
-    let spinner = create_spinner("Loading candidates...");
+    let spinner = create_spinner(t("Loading candidates..."));
 
     let candidates = match client.get_pending_candidates() {
         Ok(response) => {
@@ -1043,7 +1496,7 @@ fn vote_for_candidate(term: &Term, keys: &WalletKeys, client: &RpcClient) {
         }
         Err(e) => {
             spinner.finish_and_clear();
-            eprintln!("{}", style(format!("  ❌ Failed to load candidates: {}", e)).red());
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed to load candidates: {}", &[&e]))).red());
             wait_for_enter();
             return;
         }
@@ -1052,7 +1505,7 @@ fn vote_for_candidate(term: &Term, keys: &WalletKeys, client: &RpcClient) {
     if candidates.is_empty() {
         println!(
             "  {}",
-            style("No pending candidates to vote for.").dim()
+            style(t("No pending candidates to vote for.")).dim()
         );
         wait_for_enter();
         return;
@@ -1064,18 +1517,17 @@ fn vote_for_candidate(term: &Term, keys: &WalletKeys, client: &RpcClient) {
     let choices: Vec<String> = candidates
         .iter()
         .map(|c| {
-            format!(
-                "{} ({}/{} votes)",
-                format_address_short(&c.candidate),
-                c.vote_count,
-                c.votes_required
-            )
+            let label = match &c.display_name {
+                Some(name) => format!("{} – {}", format_address_short(&c.candidate), name),
+                None => format_address_short(&c.candidate),
+            };
+            tf("{} ({}/{} votes)", &[&label, &c.vote_count, &c.votes_required])
         })
-        .chain(std::iter::once("Cancel".to_string()))
+        .chain(std::iter::once(t("Cancel").to_string()))
         .collect();
 
     let selection = Select::with_theme(&theme)
-        .with_prompt("Select candidate to vote for")
+        .with_prompt(t("Select candidate to vote for"))
         .items(&choices)
         .default(0)
         .interact()
@@ -1093,7 +1545,7 @@ fn vote_for_candidate(term: &Term, keys: &WalletKeys, client: &RpcClient) {
         println!();
         println!(
             "{}",
-            style("  ⚠️  You have already voted for this candidate.").yellow()
+            style(format!("  ⚠️  {}", t("You have already voted for this candidate."))).yellow()
         );
         wait_for_enter();
         return;
@@ -1105,66 +1557,80 @@ fn vote_for_candidate(term: &Term, keys: &WalletKeys, client: &RpcClient) {
     let mut candidate_array = [0u8; 32];
     candidate_array.copy_from_slice(&candidate_bytes);
 
-    // Confirm
+    // Get nonce and build the transaction
+    let spinner = create_spinner(t("Preparing transaction..."));
+    let nonce = match client.next_nonce(&keys.account_id_hex()) {
+        Ok(n) => n,
+        Err(e) => {
+            spinner.finish_and_clear();
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed to get nonce: {}", &[&e]))).red());
+            wait_for_enter();
+            return;
+        }
+    };
+    let context = match client.transaction_context() {
+        Ok(context) => context,
+        Err(e) => {
+            spinner.finish_and_clear();
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed to get chain: {}", &[&e]))).red());
+            wait_for_enter();
+            return;
+        }
+    };
+    spinner.finish_and_clear();
+
     println!();
-    println!("{}", style("  Vote Summary:").yellow());
-    println!("  ├── Candidate: {}", format_address_short(&selected.candidate));
     println!(
-        "  ├── Current votes: {}/{}",
-        selected.vote_count, selected.votes_required
+        "  {} {}/{}",
+        style(t("Current votes:")).dim(),
+        selected.vote_count,
+        selected.votes_required
     );
-    println!("  └── Fee: ~0.00001 KRAT (10,000 units)");
-    println!();
+    let call = crate::types::TransactionCall::VoteEarlyValidator {
+        candidate: candidate_array.into(),
+    };
+    let Some(transaction) = preview_transaction(keys.build_transaction(call, nonce, &context)) else {
+        wait_for_enter();
+        return;
+    };
 
     let confirmed = Confirm::with_theme(&theme)
-        .with_prompt("Submit this vote?")
+        .with_prompt(t("Submit this vote?"))
         .default(false)
         .interact()
         .unwrap();
 
     if !confirmed {
         println!();
-        println!("{}", style("  ❌ Vote cancelled").yellow());
+        println!("{}", style(format!("  ❌ {}", t("Vote cancelled"))).yellow());
         wait_for_enter();
         return;
     }
 
-    // Get nonce and submit
-    let spinner = create_spinner("Submitting vote...");
-
-    let nonce = match client.get_nonce(&keys.account_id_hex()) {
-        Ok(n) => n,
-        Err(e) => {
-            spinner.finish_and_clear();
-            eprintln!("{}", style(format!("  ❌ Failed to get nonce: {}", e)).red());
-            wait_for_enter();
-            return;
-        }
-    };
-
-    let signed_tx = keys.create_vote_early_validator(candidate_array, nonce);
+    let spinner = create_spinner(t("Submitting vote..."));
+    let signed_tx = keys.sign_transaction(transaction);
 
     match client.submit_vote_early_validator(&signed_tx) {
         Ok(result) => {
             spinner.finish_and_clear();
             println!();
-            println!("{}", style("  ✅ Vote submitted successfully!").green());
+            println!("{}", style(format!("  ✅ {}", t("Vote submitted successfully!"))).green());
             println!();
-            println!("  {} {}", style("Hash:").dim(), style(&result.hash).cyan());
-            println!("  {} {}", style("Status:").dim(), result.message);
+            println!("  {} {}", style(t("Hash:")).dim(), style(&result.hash).cyan());
+            println!("  {} {}", style(t("Status:")).dim(), result.message);
 
             // Check if this was the deciding vote
             if selected.vote_count + 1 >= selected.votes_required {
                 println!();
                 println!(
                     "{}",
-                    style("  🎉 This was the deciding vote! Candidate will be approved.").green().bold()
+                    style(format!("  🎉 {}", t("This was the deciding vote! Candidate will be approved."))).green().bold()
                 );
             }
         }
         Err(e) => {
             spinner.finish_and_clear();
-            eprintln!("{}", style(format!("  ❌ Vote failed: {}", e)).red());
+            eprintln!("{}", style(format!("  ❌ {}", tf("Vote failed: {}", &[&e]))).red());
         }
     }
 
@@ -1177,28 +1643,28 @@ fn check_candidate_status(term: &Term, client: &RpcClient) {
     let _ = term.clear_screen();
     print_banner();
 
-    println!("{}", style("  🔍 Check Candidate Status").cyan().bold());
+    println!("{}", style(format!("  🔍 {}", t("Check Candidate Status"))).cyan().bold());
     println!();
 
     let theme = ColorfulTheme::default();
 
     // Get candidate address
     let candidate_str: String = Input::with_theme(&theme)
-        .with_prompt("Candidate address (0x...)")
+        .with_prompt(t("Candidate address (0x...)"))
         .validate_with(|input: &String| -> Result<(), &str> {
             let hex = input.strip_prefix("0x").unwrap_or(input);
             if hex.len() != 64 {
-                return Err("Address must be 64 hex characters");
+                return Err(t("Address must be 64 hex characters"));
             }
             if hex::decode(hex).is_err() {
-                return Err("Invalid hex address");
+                return Err(t("Invalid hex address"));
             }
             Ok(())
         })
         .interact_text()
         .unwrap();
 
-    let spinner = create_spinner("Checking status...");
+    let spinner = create_spinner(t("Checking status..."));
 
     match client.get_candidate_votes(&candidate_str) {
         Ok(response) => {
@@ -1208,30 +1674,30 @@ fn check_candidate_status(term: &Term, client: &RpcClient) {
             if response.status == "not_found" {
                 println!(
                     "  {}",
-                    style("No candidacy found for this address.").dim()
+                    style(t("No candidacy found for this address.")).dim()
                 );
             } else {
-                println!("  {}", style("Candidate Information").yellow().bold());
+                println!("  {}", style(t("Candidate Information")).yellow().bold());
                 println!("  {}", style("─".repeat(40)).dim());
                 println!(
                     "  {} {}",
-                    style("Address:").dim(),
+                    style(t("Address:")).dim(),
                     style(&response.candidate).white()
                 );
 
                 let status_styled: String = match response.status.as_str() {
-                    "Pending" => format!("{}", style("PENDING").yellow()),
-                    "Approved" => format!("{}", style("APPROVED").green()),
-                    "Rejected" => format!("{}", style("REJECTED").red()),
-                    "Expired" => format!("{}", style("EXPIRED").dim()),
+                    "Pending" => format!("{}", style(t("PENDING")).yellow()),
+                    "Approved" => format!("{}", style(t("APPROVED")).green()),
+                    "Rejected" => format!("{}", style(t("REJECTED")).red()),
+                    "Expired" => format!("{}", style(t("EXPIRED")).dim()),
                     _ => format!("{}", style(&response.status).white()),
                 };
-                println!("  {} {}", style("Status:").dim(), status_styled);
+                println!("  {} {}", style(t("Status:")).dim(), status_styled);
 
                 if let Some(proposer) = &response.proposer {
                     println!(
                         "  {} {}",
-                        style("Proposer:").dim(),
+                        style(t("Proposer:")).dim(),
                         format_address_short(proposer)
                     );
                 }
@@ -1239,7 +1705,7 @@ fn check_candidate_status(term: &Term, client: &RpcClient) {
                 if let (Some(votes), Some(required)) = (response.vote_count, response.votes_required) {
                     println!(
                         "  {} {}/{}",
-                        style("Votes:").dim(),
+                        style(t("Votes:")).dim(),
                         votes,
                         required
                     );
@@ -1247,15 +1713,15 @@ fn check_candidate_status(term: &Term, client: &RpcClient) {
 
                 if let Some(has_quorum) = response.has_quorum {
                     let quorum_text = if has_quorum {
-                        style("YES").green()
+                        style(t("YES")).green()
                     } else {
-                        style("NO").red()
+                        style(t("NO")).red()
                     };
-                    println!("  {} {}", style("Has quorum:").dim(), quorum_text);
+                    println!("  {} {}", style(t("Has quorum:")).dim(), quorum_text);
                 }
 
                 if !response.voters.is_empty() {
-                    println!("  {} ", style("Voters:").dim());
+                    println!("  {} ", style(t("Voters:")).dim());
                     for voter in &response.voters {
                         println!("    - {}", format_address_short(voter));
                     }
@@ -1263,16 +1729,16 @@ fn check_candidate_status(term: &Term, client: &RpcClient) {
 
                 if let Some(approved_at) = response.approved_at {
                     println!(
-                        "  {} block #{}",
-                        style("Approved at:").dim(),
-                        approved_at
+                        "  {} {}",
+                        style(t("Approved at:")).dim(),
+                        tf("block #{}", &[&approved_at])
                     );
                 }
             }
         }
         Err(e) => {
             spinner.finish_and_clear();
-            eprintln!("{}", style(format!("  ❌ Failed: {}", e)).red());
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed: {}", &[&e]))).red());
         }
     }
 
@@ -1290,24 +1756,32 @@ fn format_address_short(address: &str) -> String {
     }
 }
 
-fn settings(term: &Term, keys: &WalletKeys, storage: &WalletStorage) {
+/// Settings menu
+///
+/// Returns the new keys when the account was migrated.
+fn settings(term: &Term, keys: &WalletKeys, client: &RpcClient, storage: &WalletStorage) -> Option<WalletKeys> {
     let _ = term.clear_screen();
     print_banner();
 
-    println!("{}", style("  ⚙️  Settings").cyan().bold());
+    println!("{}", style(format!("  ⚙️  {}", t("Settings"))).cyan().bold());
     println!();
 
     let theme = ColorfulTheme::default();
 
     let choices = vec![
-        "🔑 Show Account ID",
-        "🌐 Change RPC Endpoint",
-        "🔒 Change Password",
-        "⬅️  Back",
+        format!("🔑 {}", t("Show Account ID")),
+        format!("🌐 {}", t("Change RPC Endpoint")),
+        format!("🔒 {}", t("Change Password")),
+        format!("🗣️  {}", t("Language")),
+        format!("🕶️  {}", t("Paranoid Mode")),
+        format!("🔁 {}", t("Migrate Account")),
+        format!("👁️  {}", t("Watch-only Accounts")),
+        format!("🧨 {}", t("Wipe After Failed Unlocks")),
+        format!("⬅️  {}", t("Back")),
     ];
 
     let selection = Select::with_theme(&theme)
-        .with_prompt("Settings")
+        .with_prompt(t("Settings"))
         .items(&choices)
         .default(0)
         .interact()
@@ -1318,7 +1792,7 @@ fn settings(term: &Term, keys: &WalletKeys, storage: &WalletStorage) {
             println!();
             println!(
                 "  {} {}",
-                style("Account ID:").bold(),
+                style(t("Account ID:")).bold(),
                 style(format!("0x{}", keys.account_id_hex())).green()
             );
             println!();
@@ -1326,27 +1800,27 @@ fn settings(term: &Term, keys: &WalletKeys, storage: &WalletStorage) {
         }
         1 => {
             let new_url: String = Input::with_theme(&theme)
-                .with_prompt("New RPC endpoint")
+                .with_prompt(t("New RPC endpoint"))
                 .default("http://127.0.0.1:9933".to_string())
                 .interact_text()
                 .unwrap();
 
             // Need password to re-save
             let password: String = Password::with_theme(&theme)
-                .with_prompt("Enter password to save changes")
+                .with_prompt(t("Enter password to save changes"))
                 .interact()
                 .unwrap();
 
             if let Err(e) = storage.save_wallet(keys, &password, &new_url) {
-                eprintln!("{}", style(format!("  ❌ Failed to save: {}", e)).red());
+                eprintln!("{}", style(format!("  ❌ {}", tf("Failed to save: {}", &[&e]))).red());
             } else {
-                println!("{}", style("  ✅ RPC endpoint updated!").green());
+                println!("{}", style(format!("  ✅ {}", t("RPC endpoint updated!"))).green());
             }
             wait_for_enter();
         }
         2 => {
             let old_password: String = Password::with_theme(&theme)
-                .with_prompt("Current password")
+                .with_prompt(t("Current password"))
                 .interact()
                 .unwrap();
 
@@ -1354,30 +1828,435 @@ fn settings(term: &Term, keys: &WalletKeys, storage: &WalletStorage) {
             match storage.load_wallet(&old_password) {
                 Ok((_, rpc_url)) => {
                     let new_password: String = Password::with_theme(&theme)
-                        .with_prompt("New password")
-                        .with_confirmation("Confirm new password", "Passwords don't match")
+                        .with_prompt(t("New password"))
+                        .with_confirmation(t("Confirm new password"), t("Passwords don't match"))
                         .interact()
                         .unwrap();
 
                     if let Err(e) = storage.save_wallet(keys, &new_password, &rpc_url) {
-                        eprintln!("{}", style(format!("  ❌ Failed to save: {}", e)).red());
+                        eprintln!("{}", style(format!("  ❌ {}", tf("Failed to save: {}", &[&e]))).red());
                     } else {
-                        println!("{}", style("  ✅ Password changed!").green());
+                        println!("{}", style(format!("  ✅ {}", t("Password changed!"))).green());
                     }
                 }
                 Err(_) => {
-                    eprintln!("{}", style("  ❌ Incorrect password").red());
+                    eprintln!("{}", style(format!("  ❌ {}", t("Incorrect password"))).red());
                 }
             }
             wait_for_enter();
         }
+        3 => select_language(storage),
+        4 => toggle_paranoid_mode(storage),
+        5 => return migrate_account(term, keys, client, storage),
+        6 => view_watch_only_accounts(term, client, storage),
+        7 => configure_wipe_mode(storage),
         _ => {}
     }
+
+    None
+}
+
+// =============================================================================
+// ACCOUNT MIGRATION SECTION
+// =============================================================================
+
+/// Guided migration to a fresh key for users who suspect key exposure
+///
+/// Sweeps the free balance to a newly generated account, re-encrypts the wallet
+/// with the new key and keeps the old address as watch-only. Returns the new keys
+/// on success; nothing is changed if any step before the sweep fails.
+fn migrate_account(
+    term: &Term,
+    keys: &WalletKeys,
+    client: &RpcClient,
+    storage: &WalletStorage,
+) -> Option<WalletKeys> {
+    let _ = term.clear_screen();
+    print_banner();
+
+    println!("{}", style(format!("  🔁 {}", t("Migrate Account"))).cyan().bold());
+    println!();
+    println!("  {}", t("This wizard moves your funds to a brand new key:"));
+    println!("  1. {}", t("Generate and back up a new secret key"));
+    println!("  2. {}", t("Send the full free balance to the new account"));
+    println!("  3. {}", t("Replace the wallet key and keep the old address as watch-only"));
+    println!();
+
+    let theme = ColorfulTheme::default();
+
+    let proceed = Confirm::with_theme(&theme)
+        .with_prompt(t("Start the migration?"))
+        .default(false)
+        .interact()
+        .unwrap();
+    if !proceed {
+        return None;
+    }
+
+    // The new key is encrypted with the current password
+    let password: String = Password::with_theme(&theme)
+        .with_prompt(t("Current password"))
+        .interact()
+        .unwrap();
+    let rpc_url = match storage.load_wallet(&password) {
+        Ok((_, rpc_url)) => rpc_url,
+        Err(_) => {
+            eprintln!("{}", style(format!("  ❌ {}", t("Incorrect password"))).red());
+            wait_for_enter();
+            return None;
+        }
+    };
+
+    // Inspect the old account before touching anything
+    let spinner = create_spinner(t("Checking account..."));
+    let info = client.get_account(&keys.account_id_hex());
+    let context = client.transaction_context();
+    let is_validator = check_if_validator(keys, client);
+    spinner.finish_and_clear();
+
+    let info = match info {
+        Ok(info) => info,
+        Err(e) => {
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed to fetch balance: {}", &[&e]))).red());
+            wait_for_enter();
+            return None;
+        }
+    };
+    let context = match context {
+        Ok(context) => context,
+        Err(e) => {
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed to get chain: {}", &[&e]))).red());
+            wait_for_enter();
+            return None;
+        }
+    };
+
+    // Staked funds and validator duties are bound to the old key on-chain
+    if !info.can_migrate(is_validator) {
+        println!(
+            "{}",
+            style(format!("  ⚠️  {}", t("This account has staked funds or is a validator."))).yellow()
+        );
+        println!(
+            "     {}",
+            t("Unstake and withdraw with the current key first, then run the migration again.")
+        );
+        println!("     {} {}", t("Reserved:"), style(&info.reserved).yellow());
+        wait_for_enter();
+        return None;
+    }
+
+    // The sweep leaves its signed max fee: what the fee does not use stays behind
+    let sweep_fee = context.max_fee(&crate::types::TransactionCall::Transfer {
+        to: [0u8; 32].into(),
+        amount: 0,
+    });
+    let sweep_amount = info.free_raw.saturating_sub(sweep_fee);
+
+    // Generate and back up the new key
+    let paranoid = storage.load_settings().paranoid_mode;
+    let new_keys = generate_new_keys(&theme, paranoid);
+
+    println!();
+    println!("{}", style(format!("  {}", t("Migration Summary:"))).yellow());
+    println!("  ├── {} 0x{}", t("Old account:"), style(keys.account_id_hex()).white());
+    println!("  ├── {} 0x{}", t("New account:"), style(new_keys.account_id_hex()).green());
+    println!(
+        "  ├── {} {} KRAT",
+        t("Amount:"),
+        style(sweep_amount as f64 / KRAT as f64).green().bold()
+    );
+    println!("  └── {} {}", t("Maximum fee:"), crate::ui::format_balance(sweep_fee));
+    println!();
+
+    let confirmed = Confirm::with_theme(&theme)
+        .with_prompt(t("Sweep funds and switch to the new account?"))
+        .default(false)
+        .interact()
+        .unwrap();
+    if !confirmed {
+        println!();
+        println!("{}", style(format!("  ❌ {}", t("Migration cancelled, the new key was not used"))).yellow());
+        wait_for_enter();
+        return None;
+    }
+
+    // Sweep funds (skipped when the balance does not cover the fee)
+    let mut sweep_tx = None;
+    if sweep_amount > 0 {
+        let spinner = create_spinner(t("Submitting transaction..."));
+        let signed_tx = keys.create_transfer(new_keys.account_id_bytes(), sweep_amount, info.nonce, &context);
+
+        match client.submit_transaction(&signed_tx) {
+            Ok(result) => {
+                spinner.finish_and_clear();
+                let record = crate::types::TransactionRecord::new_sent(
+                    result.hash.clone(),
+                    format!("0x{}", new_keys.account_id_hex()),
+                    sweep_amount,
+                    signed_tx.transaction.timestamp,
+                    info.nonce,
+                );
+                if let Err(e) = storage.add_transaction(record) {
+                    eprintln!(
+                        "{}",
+                        style(format!("  ⚠️  {}", tf("Warning: Failed to save to history: {}", &[&e]))).yellow()
+                    );
+                }
+                sweep_tx = Some(result.hash);
+            }
+            Err(e) => {
+                spinner.finish_and_clear();
+                eprintln!("{}", style(format!("  ❌ {}", tf("Transaction failed: {}", &[&e]))).red());
+                println!("     {}", t("Migration aborted, your wallet still uses the old key."));
+                wait_for_enter();
+                return None;
+            }
+        }
+    }
+
+    // Switch the wallet to the new key
+    if let Err(e) = storage.save_wallet(&new_keys, &password, &rpc_url) {
+        eprintln!("{}", style(format!("  ❌ {}", tf("Failed to save wallet: {}", &[&e]))).red());
+        println!(
+            "     {}",
+            t("Funds were sent to the new account. Import its secret key to access them.")
+        );
+        wait_for_enter();
+        return None;
+    }
+
+    let retired = crate::types::WatchOnlyAccount {
+        address: format!("0x{}", keys.account_id_hex()),
+        replaced_by: Some(format!("0x{}", new_keys.account_id_hex())),
+        retired_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        sweep_tx: sweep_tx.clone(),
+    };
+    if let Err(e) = storage.add_watch_only(retired) {
+        eprintln!("{}", style(format!("  ⚠️  {}", tf("Failed to save: {}", &[&e]))).yellow());
+    }
+
+    println!();
+    println!("{}", style(format!("  ✅ {}", t("Account migrated!"))).green());
+    if let Some(hash) = &sweep_tx {
+        println!("  {} {}", style(t("Hash:")).dim(), style(hash).cyan());
+    }
+    println!(
+        "  {}",
+        style(t("The old account is now watch-only. Never reuse its secret key.")).dim()
+    );
+    println!();
+    wait_for_enter();
+
+    Some(new_keys)
+}
+
+/// List retired accounts and their current balances
+fn view_watch_only_accounts(term: &Term, client: &RpcClient, storage: &WalletStorage) {
+    let _ = term.clear_screen();
+    print_banner();
+
+    println!("{}", style(format!("  👁️  {}", t("Watch-only Accounts"))).cyan().bold());
+    println!();
+
+    let accounts = storage.load_watch_only();
+    if accounts.is_empty() {
+        println!("  {}", style(t("No watch-only accounts.")).dim());
+        println!();
+        wait_for_enter();
+        return;
+    }
+
+    for (i, account) in accounts.iter().enumerate() {
+        let balance = client
+            .get_account(&account.address)
+            .map(|info| info.total)
+            .unwrap_or_else(|_| "?".to_string());
+
+        println!(
+            "  {} {}  {} {}",
+            style(format!("{}.", i + 1)).dim(),
+            format_address_short(&account.address),
+            style(t("Total:")).dim(),
+            style(balance).yellow()
+        );
+        if let Some(new_address) = &account.replaced_by {
+            println!(
+                "      {} {}",
+                style(t("Replaced by:")).dim(),
+                format_address_short(new_address)
+            );
+        }
+        println!(
+            "      {} {}",
+            style(t("Retired:")).dim(),
+            crate::ui::format_timestamp(account.retired_at)
+        );
+        println!();
+    }
+
+    wait_for_enter();
+}
+
+/// Let the user pick the UI language and persist the choice
+fn select_language(storage: &WalletStorage) {
+    let theme = ColorfulTheme::default();
+
+    let current = i18n::current_language();
+    let choices: Vec<&str> = Language::ALL.iter().map(|l| l.native_name()).collect();
+    let default = Language::ALL.iter().position(|l| *l == current).unwrap_or(0);
+
+    let selection = Select::with_theme(&theme)
+        .with_prompt(t("Select language"))
+        .items(&choices)
+        .default(default)
+        .interact()
+        .unwrap();
+
+    let language = Language::ALL[selection];
+    i18n::set_language(language);
+
+    let mut settings = storage.load_settings();
+    settings.language = Some(language);
+
+    if let Err(e) = storage.save_settings(&settings) {
+        eprintln!("{}", style(format!("  ❌ {}", tf("Failed to save: {}", &[&e]))).red());
+    } else {
+        println!("{}", style(format!("  ✅ {}", t("Language updated!"))).green());
+    }
+    wait_for_enter();
+}
+
+/// Toggle paranoid mode (no secret echo, reveal-on-keypress) and persist it
+fn toggle_paranoid_mode(storage: &WalletStorage) {
+    let theme = ColorfulTheme::default();
+
+    let mut settings = storage.load_settings();
+    println!();
+    println!(
+        "  {} {}",
+        style(t("Paranoid mode is currently:")).dim(),
+        if settings.paranoid_mode {
+            style(t("ON")).green().bold()
+        } else {
+            style(t("OFF")).yellow()
+        }
+    );
+    println!();
+
+    settings.paranoid_mode = Confirm::with_theme(&theme)
+        .with_prompt(t("Enable paranoid mode (never echo secrets)?"))
+        .default(!settings.paranoid_mode)
+        .interact()
+        .unwrap();
+
+    if let Err(e) = storage.save_settings(&settings) {
+        eprintln!("{}", style(format!("  ❌ {}", tf("Failed to save: {}", &[&e]))).red());
+    } else {
+        println!("{}", style(format!("  ✅ {}", t("Paranoid mode updated!"))).green());
+    }
+    wait_for_enter();
+}
+
+/// Configure the optional wipe-after-N-failed-unlocks mode
+fn configure_wipe_mode(storage: &WalletStorage) {
+    let theme = ColorfulTheme::default();
+
+    let mut settings = storage.load_settings();
+    println!();
+    match settings.unlock_guard.wipe_after {
+        Some(limit) => println!(
+            "  {} {}",
+            style(t("Wipe mode is currently:")).dim(),
+            style(tf("ON (after {} failed attempts)", &[&limit])).red().bold()
+        ),
+        None => println!(
+            "  {} {}",
+            style(t("Wipe mode is currently:")).dim(),
+            style(t("OFF")).yellow()
+        ),
+    }
+    println!();
+    println!(
+        "{}",
+        style(format!("  ⚠️  {}", t("WARNING: in wipe mode the wallet file is permanently deleted"))).red().bold()
+    );
+    println!(
+        "{}",
+        style(format!("     {}", t("after too many wrong passwords. Without a backup of your secret key,"))).red().bold()
+    );
+    println!(
+        "{}",
+        style(format!("     {}", t("your funds are lost forever."))).red().bold()
+    );
+    println!();
+
+    let enable = Confirm::with_theme(&theme)
+        .with_prompt(t("Enable wipe mode?"))
+        .default(false)
+        .interact()
+        .unwrap();
+
+    settings.unlock_guard.wipe_after = if enable {
+        let limit: u32 = Input::with_theme(&theme)
+            .with_prompt(tf("Failed attempts before wipe (minimum {})", &[&MIN_WIPE_ATTEMPTS]))
+            .default(10)
+            .validate_with(|n: &u32| {
+                if *n >= MIN_WIPE_ATTEMPTS {
+                    Ok(())
+                } else {
+                    Err(tf("Must be at least {}", &[&MIN_WIPE_ATTEMPTS]))
+                }
+            })
+            .interact_text()
+            .unwrap();
+
+        let confirmed = Confirm::with_theme(&theme)
+            .with_prompt(t("I have a backup of my secret key"))
+            .default(false)
+            .interact()
+            .unwrap();
+        if !confirmed {
+            println!("{}", style(format!("  {}", t("Wipe mode unchanged"))).yellow());
+            wait_for_enter();
+            return;
+        }
+        Some(limit)
+    } else {
+        None
+    };
+
+    if let Err(e) = storage.save_settings(&settings) {
+        eprintln!("{}", style(format!("  ❌ {}", tf("Failed to save: {}", &[&e]))).red());
+    } else {
+        println!("{}", style(format!("  ✅ {}", t("Wipe mode updated!"))).green());
+    }
+    wait_for_enter();
+}
+
+/// Decode and print a transaction before signing; returns the decoded transaction
+///
+/// The returned value is what gets signed, so the user approves exactly what was shown.
+fn preview_transaction(transaction: crate::types::Transaction) -> Option<crate::types::Transaction> {
+    match preview::decode(&transaction) {
+        Ok((decoded, call_preview)) => {
+            println!();
+            preview::print_preview(&decoded, &call_preview);
+            Some(decoded)
+        }
+        Err(e) => {
+            eprintln!("{}", style(format!("  ❌ {}", tf("Failed: {}", &[&e]))).red());
+            None
+        }
+    }
 }
 
 fn wait_for_enter() {
     use std::io::{self, Write};
-    print!("{}", style("  Press Enter to continue...").dim());
+    print!("{}", style(format!("  {}", t("Press Enter to continue..."))).dim());
     let _ = io::stdout().flush();
     let mut input = String::new();
     let _ = io::stdin().read_line(&mut input);
@@ -0,0 +1,227 @@
+// Transaction preview - Decode and render a call before it is signed
+// - Decodes from the exact bytes that will be signed, not from the UI inputs
+// - Shows every parameter with full addresses, the fee and the expected effects
+
+use console::style;
+
+use crate::i18n::{t, tf};
+use crate::types::{Transaction, TransactionCall};
+use crate::ui::{format_balance, format_timestamp};
+
+/// Base fee precision: a base fee of 1_000_000 charges the static fee as is
+pub const BASE_FEE_PRECISION: u64 = 1_000_000;
+
+/// Highest base fee (must match kratos-core MAX_BASE_FEE)
+pub const MAX_BASE_FEE: u64 = 1_000 * BASE_FEE_PRECISION;
+
+/// Static fee per call in raw units (must match kratos-core TransactionCall::base_fee)
+pub fn static_fee(call: &TransactionCall) -> u128 {
+    match call {
+        TransactionCall::Transfer { .. } => 1_000,
+        TransactionCall::Stake { .. } => 5_000,
+        TransactionCall::Unstake { .. } => 5_000,
+        TransactionCall::WithdrawUnbonded => 2_000,
+        TransactionCall::RegisterValidator { .. } => 100_000,
+        TransactionCall::UnregisterValidator => 50_000,
+        TransactionCall::CreateSidechain { .. } => 1_000_000,
+        TransactionCall::ExitSidechain { .. } => 500_000,
+        TransactionCall::SignalFork { .. } => 10_000_000,
+        TransactionCall::ProposeEarlyValidator { .. } => 50_000,
+        TransactionCall::VoteEarlyValidator { .. } => 10_000,
+    }
+}
+
+/// Fee charged for a call of static fee `static_fee` at `base_fee` (same
+/// rounding as kratos-core scale_fee)
+pub fn scale_fee(static_fee: u128, base_fee: u64) -> u128 {
+    static_fee.saturating_mul(base_fee as u128) / BASE_FEE_PRECISION as u128
+}
+
+/// Decoded view of a transaction
+#[derive(Debug, Clone)]
+pub struct CallPreview {
+    /// Call name
+    pub method: &'static str,
+    /// (label msgid, value) pairs in call order
+    pub params: Vec<(&'static str, String)>,
+    /// Human readable consequences of the call
+    pub effects: Vec<String>,
+    /// Most the transaction can cost in fees, in raw units (its signed max
+    /// fee, or the fee at MAX_BASE_FEE without one)
+    pub max_fee: u128,
+    /// Size of the signed payload in bytes
+    pub payload_len: usize,
+}
+
+/// Short name of a call, for display
+pub fn call_name(call: &TransactionCall) -> &'static str {
+    match call {
+        TransactionCall::Transfer { .. } => "Transfer",
+        TransactionCall::Stake { .. } => "Stake",
+        TransactionCall::Unstake { .. } => "Unstake",
+        TransactionCall::WithdrawUnbonded => "WithdrawUnbonded",
+        TransactionCall::RegisterValidator { .. } => "RegisterValidator",
+        TransactionCall::UnregisterValidator => "UnregisterValidator",
+        TransactionCall::CreateSidechain { .. } => "CreateSidechain",
+        TransactionCall::ExitSidechain { .. } => "ExitSidechain",
+        TransactionCall::SignalFork { .. } => "SignalFork",
+        TransactionCall::ProposeEarlyValidator { .. } => "ProposeEarlyValidator",
+        TransactionCall::VoteEarlyValidator { .. } => "VoteEarlyValidator",
+    }
+}
+
+/// Decode a transaction from its signing bytes
+pub fn decode(tx: &Transaction) -> Result<(Transaction, CallPreview), String> {
+    let bytes = bincode::serialize(tx).map_err(|e| format!("Serialization error: {}", e))?;
+    let decoded: Transaction =
+        bincode::deserialize(&bytes).map_err(|e| format!("Decoding error: {}", e))?;
+
+    let (params, effects) = describe(&decoded.call);
+    let preview = CallPreview {
+        method: call_name(&decoded.call),
+        params,
+        effects,
+        max_fee: decoded
+            .max_fee
+            .unwrap_or_else(|| scale_fee(static_fee(&decoded.call), MAX_BASE_FEE)),
+        payload_len: bytes.len(),
+    };
+    Ok((decoded, preview))
+}
+
+fn hex_address(bytes: &[u8; 32]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn describe(call: &TransactionCall) -> (Vec<(&'static str, String)>, Vec<String>) {
+    match call {
+        TransactionCall::Transfer { to, amount } => (
+            vec![("To:", hex_address(&to.0)), ("Amount:", format_balance(*amount))],
+            vec![tf("Moves {} from your free balance to the recipient", &[&format_balance(*amount)])],
+        ),
+        TransactionCall::Stake { amount } => (
+            vec![("Amount:", format_balance(*amount))],
+            vec![tf("Locks {} of your free balance as stake", &[&format_balance(*amount)])],
+        ),
+        TransactionCall::Unstake { amount } => (
+            vec![("Amount:", format_balance(*amount))],
+            vec![tf(
+                "Starts unbonding {}; it becomes withdrawable after the unbonding period",
+                &[&format_balance(*amount)],
+            )],
+        ),
+        TransactionCall::WithdrawUnbonded => (
+            vec![],
+            vec![t("Moves matured unbonded funds back to your free balance").to_string()],
+        ),
+        TransactionCall::RegisterValidator { stake } => (
+            vec![("Stake:", format_balance(*stake))],
+            vec![tf("Reserves {} and registers this account as a validator", &[&format_balance(*stake)])],
+        ),
+        TransactionCall::UnregisterValidator => (
+            vec![],
+            vec![t("Removes this account from the validator set").to_string()],
+        ),
+        TransactionCall::CreateSidechain { metadata, deposit } => {
+            let mut params = vec![("Deposit:", format_balance(*deposit))];
+            if let Some(name) = &metadata.name {
+                params.push(("Name:", name.clone()));
+            }
+            if let Some(description) = &metadata.description {
+                params.push(("Description:", description.clone()));
+            }
+            if let Some(parent) = &metadata.parent_chain {
+                params.push(("Parent chain:", hex_address(&parent.0)));
+            }
+            (
+                params,
+                vec![tf("Reserves a deposit of {} to create a sidechain", &[&format_balance(*deposit)])],
+            )
+        }
+        TransactionCall::ExitSidechain { chain_id } => (
+            vec![("Chain:", hex_address(&chain_id.0))],
+            vec![t("Exits the sidechain and returns your share of its funds").to_string()],
+        ),
+        TransactionCall::SignalFork { name, description } => (
+            vec![("Name:", name.clone()), ("Description:", description.clone())],
+            vec![tf("Publicly signals support for fork \"{}\"", &[name])],
+        ),
+        TransactionCall::ProposeEarlyValidator { candidate, metadata } => {
+            let mut params = vec![("Candidate:", hex_address(&candidate.0))];
+            if let Some(name) = &metadata.display_name {
+                params.push(("Name:", name.clone()));
+            }
+            if let Some(url) = &metadata.url {
+                params.push(("URL:", url.clone()));
+            }
+            (
+                params,
+                vec![t("Proposes the candidate as an early validator; your proposal counts as the first vote").to_string()],
+            )
+        }
+        TransactionCall::VoteEarlyValidator { candidate } => (
+            vec![("Candidate:", hex_address(&candidate.0))],
+            vec![t("Adds your vote for the candidate to join the validator set").to_string()],
+        ),
+    }
+}
+
+/// Print the decoded transaction
+pub fn print_preview(tx: &Transaction, preview: &CallPreview) {
+    println!("{}", style(format!("  {}", t("Transaction Preview:"))).yellow());
+    println!("  ├── {} {}", t("Method:"), style(preview.method).cyan().bold());
+    println!("  ├── {} {}", t("Sender:"), hex_address(&tx.sender.0));
+    for (label, value) in &preview.params {
+        println!("  ├── {} {}", t(label), style(value).white());
+    }
+    println!("  ├── {} {}", t("Nonce:"), tx.nonce);
+    println!("  ├── {} {}", t("Timestamp:"), format_timestamp(tx.timestamp));
+    println!(
+        "  ├── {} {} ({} units)",
+        t("Maximum fee:"),
+        format_balance(preview.max_fee),
+        preview.max_fee
+    );
+    println!("  └── {} {}", t("Payload:"), tf("{} bytes", &[&preview.payload_len]));
+    println!();
+    println!("  {}", style(t("Effects:")).bold());
+    for effect in &preview.effects {
+        println!("    • {}", effect);
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::CandidateMetadata;
+
+    #[test]
+    fn test_decode_propose_shows_all_params() {
+        let tx = Transaction {
+            sender: [1u8; 32].into(),
+            nonce: 7,
+            call: TransactionCall::ProposeEarlyValidator {
+                candidate: [2u8; 32].into(),
+                metadata: CandidateMetadata {
+                    display_name: Some("Alice".to_string()),
+                    url: Some("https://alice.example".to_string()),
+                },
+            },
+            timestamp: 1_700_000_000,
+            genesis_hash: [0u8; 32],
+            valid_until: None,
+            max_fee: Some(150_000),
+            tip: 0,
+        };
+
+        let (decoded, preview) = decode(&tx).unwrap();
+        assert_eq!(decoded.nonce, 7);
+        assert_eq!(preview.method, "ProposeEarlyValidator");
+        assert_eq!(preview.max_fee, 150_000);
+        assert_eq!(preview.params[0], ("Candidate:", format!("0x{}", "02".repeat(32))));
+        assert!(preview.params.iter().any(|(_, v)| v == "Alice"));
+        assert!(preview.params.iter().any(|(_, v)| v == "https://alice.example"));
+        assert!(!preview.effects.is_empty());
+    }
+}
@@ -5,9 +5,10 @@ use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::types::{
-    AccountInfo, CanVoteResponse, CandidateVotesResponse, EarlyVotingStatus,
-    PendingCandidatesResponse, RpcTransactionRecord, SignedTransaction, TransactionDirection,
-    TransactionHistoryResponse, TransactionRecord, TransactionStatus, TransactionSubmitResult,
+    AccountInfo, AccountTransactionsResponse, CanVoteResponse, CandidateVotesResponse, CreditsHistory, EarlyVotingStatus,
+    IdentityInfo, InboxMessage, PendingCandidatesResponse, ReorgEventsResponse, RpcTransactionRecord, SecurityState,
+    SignedTransaction, TransactionContext, TransactionDirection, TransactionHistoryResponse, TransactionRecord,
+    TransactionStatus, TransactionSubmitResult, UnbondingInfo, ValidatorCredits, ValidatorDashboardInfo,
 };
 
 /// JSON-RPC request
@@ -66,6 +67,16 @@ impl RpcClient {
         method: &str,
         params: serde_json::Value,
     ) -> Result<T, String> {
+        self.call_optional(method, params)?
+            .ok_or_else(|| "Empty response".to_string())
+    }
+
+    /// Make a JSON-RPC call whose result may be null
+    fn call_optional<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<Option<T>, String> {
         let request = JsonRpcRequest {
             jsonrpc: "2.0",
             method: method.to_string(),
@@ -92,7 +103,7 @@ impl RpcClient {
             return Err(error.message);
         }
 
-        json_response.result.ok_or_else(|| "Empty response".to_string())
+        Ok(json_response.result)
     }
 
     /// Get account information
@@ -117,6 +128,22 @@ impl RpcClient {
         self.call("state_getNonce", serde_json::json!([address]))
     }
 
+    /// Get the nonce to sign the account's next transaction with
+    ///
+    /// Counts the account's transactions still in the node's mempool, so two
+    /// quick sends don't share a nonce. Nodes without account_nextNonce give
+    /// the committed nonce.
+    pub fn next_nonce(&self, address: &str) -> Result<u64, String> {
+        let address = if address.starts_with("0x") {
+            address.to_string()
+        } else {
+            format!("0x{}", address)
+        };
+
+        self.call("account_nextNonce", serde_json::json!([address]))
+            .or_else(|_| self.get_nonce(&address))
+    }
+
     /// Submit a signed transaction
     pub fn submit_transaction(&self, tx: &SignedTransaction) -> Result<TransactionSubmitResult, String> {
         // Convert to JSON format expected by RPC
@@ -124,32 +151,12 @@ impl RpcClient {
             "transaction": {
                 "sender": format!("0x{}", hex::encode(tx.transaction.sender.0)),
                 "nonce": tx.transaction.nonce,
-                "call": match &tx.transaction.call {
-                    crate::types::TransactionCall::Transfer { to, amount } => {
-                        serde_json::json!({
-                            "Transfer": {
-                                "to": format!("0x{}", hex::encode(to.0)),
-                                "amount": amount
-                            }
-                        })
-                    }
-                    crate::types::TransactionCall::ProposeEarlyValidator { candidate } => {
-                        serde_json::json!({
-                            "ProposeEarlyValidator": {
-                                "candidate": format!("0x{}", hex::encode(candidate.0))
-                            }
-                        })
-                    }
-                    crate::types::TransactionCall::VoteEarlyValidator { candidate } => {
-                        serde_json::json!({
-                            "VoteEarlyValidator": {
-                                "candidate": format!("0x{}", hex::encode(candidate.0))
-                            }
-                        })
-                    }
-                    _ => return Err("Unsupported transaction type".to_string()),
-                },
-                "timestamp": tx.transaction.timestamp
+                "call": call_to_json(&tx.transaction.call)?,
+                "timestamp": tx.transaction.timestamp,
+                "genesis_hash": tx.transaction.genesis_hash,
+                "valid_until": tx.transaction.valid_until,
+                "max_fee": tx.transaction.max_fee,
+                "tip": tx.transaction.tip
             },
             "signature": format!("0x{}", hex::encode(tx.signature))
         });
@@ -170,16 +177,59 @@ impl RpcClient {
     }
 
     /// Get chain info
-    #[allow(dead_code)]
     pub fn chain_info(&self) -> Result<ChainInfo, String> {
         self.call("chain_getInfo", serde_json::Value::Null)
     }
 
+    /// Get the network security state
+    pub fn security_state(&self) -> Result<SecurityState, String> {
+        let info: EpochInfo = self.call("chain_getEpochInfo", serde_json::Value::Null)?;
+        Ok(info.security)
+    }
+
+    /// Get the network the node runs
+    pub fn system_chain(&self) -> Result<SystemChain, String> {
+        self.call("system_chain", serde_json::Value::Null)
+    }
+
+    /// Get the validity window recommended for new transactions
+    pub fn mortality(&self) -> Result<Mortality, String> {
+        self.call("chain_getMortality", serde_json::Value::Null)
+    }
+
+    /// Get the base fees of the last `block_count` blocks
+    pub fn fee_history(&self, block_count: u64) -> Result<FeeHistory, String> {
+        self.call("fee_history", serde_json::json!([block_count]))
+    }
+
+    /// Base fee of the next block, what a transaction submitted now pays
+    pub fn next_base_fee(&self) -> Result<u64, String> {
+        let history = self.fee_history(1)?;
+        history.base_fees.last().copied().ok_or_else(|| "Empty fee history".to_string())
+    }
+
+    /// Chain to sign transactions for, their recommended validity window and
+    /// the base fee (a transaction signed for one network is refused by the
+    /// others, one not included in time can't be mined later, and one whose
+    /// max fee the base fee outgrew is refused)
+    pub fn transaction_context(&self) -> Result<TransactionContext, String> {
+        let genesis_hash = self.system_chain()?.genesis_hash_bytes()?;
+        let mortality = self.mortality()?;
+        let base_fee = self.next_base_fee()?;
+        Ok(TransactionContext {
+            genesis_hash,
+            best_block: mortality.best_block,
+            valid_until: Some(mortality.valid_until),
+            base_fee,
+        })
+    }
+
     /// Get transaction history for an address
     ///
-    /// This queries the node for transaction history. If the node doesn't support
-    /// this method yet, it will return an error and the wallet will fall back to
-    /// local history only.
+    /// Nodes started with --index page through the account's whole history
+    /// (account_getTransactions); other nodes only scan their recent blocks.
+    /// If neither is available, it returns an error and the wallet falls back
+    /// to local history only.
     pub fn get_transaction_history(
         &self,
         address: &str,
@@ -192,12 +242,27 @@ impl RpcClient {
             format!("0x{}", address)
         };
 
+        // The index pages by cursor: it serves the newest page only
+        if offset == 0 {
+            if let Ok(page) = self.call::<AccountTransactionsResponse>(
+                "account_getTransactions",
+                serde_json::json!([address, { "limit": limit }]),
+            ) {
+                return Ok(page.into());
+            }
+        }
+
         self.call(
             "state_getTransactionHistory",
             serde_json::json!([address, limit, offset]),
         )
     }
 
+    /// Get chain reorg events newer than `after_id`
+    pub fn get_reorg_events(&self, after_id: u64) -> Result<ReorgEventsResponse, String> {
+        self.call("chain_getReorgEvents", serde_json::json!([after_id]))
+    }
+
     /// Get current block height
     pub fn get_block_height(&self) -> Result<u64, String> {
         let info: ChainInfo = self.call("chain_getInfo", serde_json::Value::Null)?;
@@ -248,15 +313,93 @@ impl RpcClient {
         self.call("validator_canVote", serde_json::json!([account]))
     }
 
+    // =========================================================================
+    // VALIDATOR OPERATIONS RPC METHODS
+    // =========================================================================
+
+    /// Get operational status of a validator (fails if the account is not a validator)
+    pub fn get_validator_info(&self, account: &str) -> Result<ValidatorDashboardInfo, String> {
+        let account = if account.starts_with("0x") {
+            account.to_string()
+        } else {
+            format!("0x{}", account)
+        };
+
+        self.call("validator_getInfo", serde_json::json!([account]))
+    }
+
+    /// Get validator credits breakdown
+    pub fn get_validator_credits(&self, account: &str) -> Result<ValidatorCredits, String> {
+        let account = if account.starts_with("0x") {
+            account.to_string()
+        } else {
+            format!("0x{}", account)
+        };
+
+        self.call("validator_getCredits", serde_json::json!([account]))
+    }
+
+    /// Get the validator credits earned and removed over the last `epochs` epochs
+    pub fn get_credits_history(&self, account: &str, epochs: u32) -> Result<CreditsHistory, String> {
+        let account = if account.starts_with("0x") {
+            account.to_string()
+        } else {
+            format!("0x{}", account)
+        };
+
+        self.call("validator_creditsHistory", serde_json::json!([account, epochs]))
+    }
+
+    /// Get the pending unlocks of an account's unbonding queue
+    pub fn get_unbonding(&self, account: &str) -> Result<UnbondingInfo, String> {
+        let account = if account.starts_with("0x") {
+            account.to_string()
+        } else {
+            format!("0x{}", account)
+        };
+
+        self.call("staking_getUnbonding", serde_json::json!([account]))
+    }
+
+    // =========================================================================
+    // IDENTITY RPC METHODS
+    // =========================================================================
+
+    /// Get the identity registered by an account (None if it never registered)
+    pub fn get_identity(&self, account: &str) -> Result<Option<IdentityInfo>, String> {
+        let account = if account.starts_with("0x") {
+            account.to_string()
+        } else {
+            format!("0x{}", account)
+        };
+
+        self.call_optional("identity_get", serde_json::json!([account]))
+    }
+
+    // =========================================================================
+    // MESSAGING RPC METHODS
+    // =========================================================================
+
+    /// Get the latest messages received by an account, newest first
+    pub fn get_inbox(&self, account: &str, limit: u32) -> Result<Vec<InboxMessage>, String> {
+        let account = if account.starts_with("0x") {
+            account.to_string()
+        } else {
+            format!("0x{}", account)
+        };
+
+        self.call("messaging_getInbox", serde_json::json!([account, limit]))
+    }
+
     /// Submit a propose early validator transaction
     pub fn submit_propose_early_validator(
         &self,
         tx: &SignedTransaction,
     ) -> Result<TransactionSubmitResult, String> {
-        // Get the candidate from the transaction
-        let candidate_hex = match &tx.transaction.call {
-            crate::types::TransactionCall::ProposeEarlyValidator { candidate } => {
-                format!("0x{}", hex::encode(candidate.0))
+        // Get the candidate and its metadata from the transaction
+        let (candidate_hex, metadata) = match &tx.transaction.call {
+            crate::types::TransactionCall::ProposeEarlyValidator { candidate, metadata } => {
+                (format!("0x{}", hex::encode(candidate.0)), metadata)
             }
             _ => return Err("Expected ProposeEarlyValidator transaction".to_string()),
         };
@@ -267,10 +410,15 @@ impl RpcClient {
                 "nonce": tx.transaction.nonce,
                 "call": {
                     "ProposeEarlyValidator": {
-                        "candidate": candidate_hex
+                        "candidate": candidate_hex,
+                        "metadata": metadata
                     }
                 },
-                "timestamp": tx.transaction.timestamp
+                "timestamp": tx.transaction.timestamp,
+                "genesis_hash": tx.transaction.genesis_hash,
+                "valid_until": tx.transaction.valid_until,
+                "max_fee": tx.transaction.max_fee,
+                "tip": tx.transaction.tip
             },
             "signature": format!("0x{}", hex::encode(tx.signature))
         });
@@ -300,7 +448,11 @@ impl RpcClient {
                         "candidate": candidate_hex
                     }
                 },
-                "timestamp": tx.transaction.timestamp
+                "timestamp": tx.transaction.timestamp,
+                "genesis_hash": tx.transaction.genesis_hash,
+                "valid_until": tx.transaction.valid_until,
+                "max_fee": tx.transaction.max_fee,
+                "tip": tx.transaction.tip
             },
             "signature": format!("0x{}", hex::encode(tx.signature))
         });
@@ -345,12 +497,37 @@ impl RpcClient {
                     block_number: Some(tx.block_number),
                     nonce: tx.nonce,
                     note: None,
+                    orphaned_block: None,
                 }
             })
             .collect()
     }
 }
 
+/// Convert a transaction call to the JSON format expected by the node RPC
+fn call_to_json(call: &crate::types::TransactionCall) -> Result<serde_json::Value, String> {
+    match call {
+        crate::types::TransactionCall::Transfer { to, amount } => Ok(serde_json::json!({
+            "Transfer": {
+                "to": format!("0x{}", hex::encode(to.0)),
+                "amount": amount
+            }
+        })),
+        crate::types::TransactionCall::ProposeEarlyValidator { candidate, metadata } => Ok(serde_json::json!({
+            "ProposeEarlyValidator": {
+                "candidate": format!("0x{}", hex::encode(candidate.0)),
+                "metadata": metadata
+            }
+        })),
+        crate::types::TransactionCall::VoteEarlyValidator { candidate } => Ok(serde_json::json!({
+            "VoteEarlyValidator": {
+                "candidate": format!("0x{}", hex::encode(candidate.0))
+            }
+        })),
+        _ => Err("Unsupported transaction type".to_string()),
+    }
+}
+
 /// Chain information
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -363,6 +540,56 @@ pub struct ChainInfo {
     pub is_synced: bool,
 }
 
+/// Current epoch (chain_getEpochInfo), only the security state is used
+#[derive(Debug, Deserialize)]
+struct EpochInfo {
+    security: SecurityState,
+}
+
+/// Recommended validity window (chain_getMortality)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct Mortality {
+    pub best_block: u64,
+    pub period: u64,
+    pub valid_until: u64,
+}
+
+/// Recent base fees (fee_history), the last entry is the next block's
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct FeeHistory {
+    pub oldest_block: u64,
+    pub base_fees: Vec<u64>,
+    pub fullness: Vec<f64>,
+    pub base_fee_precision: u64,
+}
+
+/// Network a node runs (system_chain)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(dead_code)]
+pub struct SystemChain {
+    pub name: String,
+    pub chain_id: u32,
+    pub genesis_hash: String,
+    pub protocol_name: String,
+    pub protocol_version: u32,
+}
+
+impl SystemChain {
+    /// Genesis hash as bytes
+    pub fn genesis_hash_bytes(&self) -> Result<[u8; 32], String> {
+        let hex_str = self.genesis_hash.strip_prefix("0x").unwrap_or(&self.genesis_hash);
+        hex::decode(hex_str)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| format!("Invalid genesis hash: {}", self.genesis_hash))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -380,4 +607,28 @@ mod tests {
         assert_eq!(client.next_id(), 2);
         assert_eq!(client.next_id(), 3);
     }
+
+    #[test]
+    fn test_system_chain_genesis_hash() {
+        let json = format!(
+            r#"{{"name":"KratOs","chainId":0,"genesisHash":"0x{}","protocolName":"kratos","protocolVersion":1}}"#,
+            "ab".repeat(32)
+        );
+        let chain: SystemChain = serde_json::from_str(&json).unwrap();
+        assert_eq!(chain.genesis_hash_bytes().unwrap(), [0xab; 32]);
+
+        let truncated = SystemChain { genesis_hash: "0xabcd".to_string(), ..chain };
+        assert!(truncated.genesis_hash_bytes().is_err());
+    }
+
+    #[test]
+    fn test_epoch_info_security_state() {
+        let json = r#"{"epoch":12,"startBlock":7200,"endBlock":7799,"currentBlock":7300,"totalStake":0,
+            "validators":[],"bootstrap":{"active":false,"endEpoch":10,"status":"Completed","validatorsNeeded":0},
+            "security":{"state":"Degraded","enteredAt":11,"blockTimeMultiplier":2,"timelockMultiplier":2}}"#;
+        let info: EpochInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.security.state, "Degraded");
+        assert_eq!(info.security.entered_at, Some(11));
+        assert_eq!(info.security.block_time_multiplier, 2);
+    }
 }
@@ -4,12 +4,16 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::crypto::{decrypt_secret, encrypt_secret, EncryptedWallet, WalletKeys};
-use crate::types::{TransactionHistory, TransactionRecord};
+use crate::types::{NodeCache, TransactionHistory, TransactionRecord, WalletSettings, WatchOnlyAccount};
 
 const WALLET_FILENAME: &str = "wallet.json";
 const HISTORY_FILENAME: &str = "history.json";
+const SETTINGS_FILENAME: &str = "settings.json";
+const WATCH_ONLY_FILENAME: &str = "watch_only.json";
+const CACHE_FILENAME: &str = "cache.json";
 
 /// Wallet storage manager
+#[derive(Clone)]
 pub struct WalletStorage {
     wallet_dir: PathBuf,
 }
@@ -103,7 +107,6 @@ impl WalletStorage {
     }
 
     /// Delete wallet (use with caution!)
-    #[allow(dead_code)]
     pub fn delete_wallet(&self) -> Result<(), String> {
         let wallet_path = self.wallet_path();
         if wallet_path.exists() {
@@ -212,6 +215,100 @@ impl WalletStorage {
         }
         Ok(())
     }
+
+    // =========================================================================
+    // SETTINGS STORAGE
+    // =========================================================================
+
+    /// Get settings file path
+    fn settings_path(&self) -> PathBuf {
+        self.wallet_dir.join(SETTINGS_FILENAME)
+    }
+
+    /// Load settings from disk (defaults if missing or unreadable)
+    pub fn load_settings(&self) -> WalletSettings {
+        match fs::read_to_string(self.settings_path()) {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+            Err(_) => WalletSettings::default(),
+        }
+    }
+
+    /// Save settings to disk
+    pub fn save_settings(&self, settings: &WalletSettings) -> Result<(), String> {
+        fs::create_dir_all(&self.wallet_dir)
+            .map_err(|e| format!("Failed to create wallet directory: {}", e))?;
+
+        let json = serde_json::to_string_pretty(settings)
+            .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+
+        fs::write(self.settings_path(), json)
+            .map_err(|e| format!("Failed to write settings file: {}", e))
+    }
+
+    // =========================================================================
+    // WATCH-ONLY ACCOUNTS STORAGE
+    // =========================================================================
+
+    /// Get watch-only accounts file path
+    fn watch_only_path(&self) -> PathBuf {
+        self.wallet_dir.join(WATCH_ONLY_FILENAME)
+    }
+
+    /// Load watch-only accounts (empty if missing or unreadable)
+    pub fn load_watch_only(&self) -> Vec<WatchOnlyAccount> {
+        match fs::read_to_string(self.watch_only_path()) {
+            Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+            Err(_) => Vec::new(),
+        }
+    }
+
+    /// Add a watch-only account (replaces an existing entry for the same address)
+    pub fn add_watch_only(&self, account: WatchOnlyAccount) -> Result<(), String> {
+        let mut accounts = self.load_watch_only();
+        accounts.retain(|a| !a.address.eq_ignore_ascii_case(&account.address));
+        accounts.push(account);
+
+        fs::create_dir_all(&self.wallet_dir)
+            .map_err(|e| format!("Failed to create wallet directory: {}", e))?;
+
+        let json = serde_json::to_string_pretty(&accounts)
+            .map_err(|e| format!("Failed to serialize watch-only accounts: {}", e))?;
+
+        fs::write(self.watch_only_path(), json)
+            .map_err(|e| format!("Failed to write watch-only file: {}", e))
+    }
+
+    // =========================================================================
+    // NODE DATA CACHE
+    // =========================================================================
+
+    /// Get node cache file path
+    fn cache_path(&self) -> PathBuf {
+        self.wallet_dir.join(CACHE_FILENAME)
+    }
+
+    /// Load the cached node data for an account (None if missing, unreadable or for another account)
+    pub fn load_cache(&self, account: &str) -> Option<NodeCache> {
+        let json = fs::read_to_string(self.cache_path()).ok()?;
+        let cache: NodeCache = serde_json::from_str(&json).ok()?;
+        (cache.account == account).then_some(cache)
+    }
+
+    /// Save node data to the cache
+    ///
+    /// Written to a temporary file first so a reader never sees a partial cache.
+    pub fn save_cache(&self, cache: &NodeCache) -> Result<(), String> {
+        fs::create_dir_all(&self.wallet_dir)
+            .map_err(|e| format!("Failed to create wallet directory: {}", e))?;
+
+        let json = serde_json::to_string_pretty(cache)
+            .map_err(|e| format!("Failed to serialize cache: {}", e))?;
+
+        let tmp_path = self.cache_path().with_extension("json.tmp");
+        fs::write(&tmp_path, json).map_err(|e| format!("Failed to write cache file: {}", e))?;
+        fs::rename(&tmp_path, self.cache_path())
+            .map_err(|e| format!("Failed to write cache file: {}", e))
+    }
 }
 
 #[cfg(test)]
@@ -264,6 +361,80 @@ mod tests {
         assert!(!storage.wallet_exists());
     }
 
+    #[test]
+    fn test_settings_roundtrip() {
+        let dir = tempdir().unwrap();
+        let storage = WalletStorage::new(dir.path());
+
+        // Defaults when missing
+        assert!(storage.load_settings().language.is_none());
+
+        let settings = WalletSettings {
+            language: Some(crate::i18n::Language::French),
+            paranoid_mode: true,
+            ..Default::default()
+        };
+        storage.save_settings(&settings).unwrap();
+
+        let loaded = storage.load_settings();
+        assert_eq!(loaded.language, Some(crate::i18n::Language::French));
+        assert!(loaded.paranoid_mode);
+    }
+
+    #[test]
+    fn test_watch_only_accounts() {
+        let dir = tempdir().unwrap();
+        let storage = WalletStorage::new(dir.path());
+        assert!(storage.load_watch_only().is_empty());
+
+        let account = WatchOnlyAccount {
+            address: "0xaa".to_string(),
+            replaced_by: Some("0xbb".to_string()),
+            retired_at: 1,
+            sweep_tx: None,
+        };
+        storage.add_watch_only(account.clone()).unwrap();
+
+        // Same address is replaced, not duplicated
+        storage.add_watch_only(WatchOnlyAccount { retired_at: 2, ..account }).unwrap();
+
+        let loaded = storage.load_watch_only();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].retired_at, 2);
+        assert_eq!(loaded[0].replaced_by.as_deref(), Some("0xbb"));
+    }
+
+    #[test]
+    fn test_node_cache_roundtrip() {
+        use crate::types::{AccountInfo, NetworkSnapshot};
+
+        let dir = tempdir().unwrap();
+        let storage = WalletStorage::new(dir.path());
+        assert!(storage.load_cache("aa").is_none());
+
+        let cache = NodeCache {
+            account: "aa".to_string(),
+            balance: AccountInfo {
+                free: "1 KRAT".to_string(),
+                reserved: "0 KRAT".to_string(),
+                total: "1 KRAT".to_string(),
+                nonce: 3,
+                free_raw: 1_000_000_000_000,
+                reserved_raw: 0,
+            },
+            network: NetworkSnapshot { chain_name: "KratOs".to_string(), height: 42, is_synced: true, security: None },
+            updated_at: 1_700_000_000,
+        };
+        storage.save_cache(&cache).unwrap();
+
+        let loaded = storage.load_cache("aa").unwrap();
+        assert_eq!(loaded.network.height, 42);
+        assert_eq!(loaded.balance.free_raw, 1_000_000_000_000);
+
+        // Cache of another account is ignored
+        assert!(storage.load_cache("bb").is_none());
+    }
+
     #[test]
     fn test_transaction_history_storage() {
         let dir = tempdir().unwrap();
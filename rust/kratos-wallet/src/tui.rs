@@ -0,0 +1,496 @@
+// Terminal dashboard - Persistent wallet layout (ratatui)
+// - Header: account, chain height, the network security state reported by
+//   the node, and the wallet's own protections
+// - Panes: balance, history, community (Tab / arrow keys / 1-3)
+// - A worker thread refreshes pane data, so the screen never blocks on the node
+// - Only this main screen is a ratatui layout. Actions that need prompts
+//   (send, settings, ...) leave the dashboard, run their dialoguer screen
+//   and come back to the same pane
+
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::Duration;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Tabs};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::cache;
+use crate::i18n::{t, tf};
+use crate::rpc::RpcClient;
+use crate::storage::WalletStorage;
+use crate::types::{
+    EarlyValidatorCandidate, EarlyVotingStatus, NodeCache, SecurityState, TransactionDirection,
+    TransactionHistory, TransactionStatus,
+};
+use crate::ui::{format_address_short, format_balance, format_timestamp};
+
+/// How often the event loop wakes up to apply worker updates
+const TICK: Duration = Duration::from_millis(250);
+
+/// Action chosen on the dashboard (run by the caller outside the TUI)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Send,
+    History,
+    Inbox,
+    AddressList,
+    Community,
+    Validator,
+    Settings,
+    Exit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pane {
+    Balance,
+    History,
+    Community,
+}
+
+const PANES: [Pane; 3] = [Pane::Balance, Pane::History, Pane::Community];
+
+impl Pane {
+    fn title(self) -> &'static str {
+        match self {
+            Pane::Balance => t("Balance"),
+            Pane::History => t("History"),
+            Pane::Community => t("Community"),
+        }
+    }
+
+    fn index(self) -> usize {
+        PANES.iter().position(|p| *p == self).unwrap_or(0)
+    }
+}
+
+/// Data pushed by the worker thread
+enum Update {
+    Node(Option<NodeCache>),
+    History(TransactionHistory),
+    Community(Option<EarlyVotingStatus>, Vec<EarlyValidatorCandidate>),
+    Validator(bool),
+}
+
+/// Wallet dashboard; keeps its state and worker across actions
+pub struct Dashboard {
+    account: String,
+    rpc_url: String,
+    storage: WalletStorage,
+    pane: Pane,
+    node: Option<NodeCache>,
+    history: TransactionHistory,
+    history_state: ListState,
+    voting: Option<EarlyVotingStatus>,
+    candidates: Vec<EarlyValidatorCandidate>,
+    is_validator: bool,
+    updates: Receiver<Update>,
+    refresh: Sender<()>,
+}
+
+impl Dashboard {
+    /// Create the dashboard and start its refresh worker
+    pub fn new(account: &str, rpc_url: &str, storage: &WalletStorage) -> Self {
+        let (update_tx, updates) = mpsc::channel();
+        let (refresh, refresh_rx) = mpsc::channel();
+        spawn_worker(rpc_url, storage, account, update_tx, refresh_rx);
+
+        Self {
+            account: account.to_string(),
+            rpc_url: rpc_url.to_string(),
+            storage: storage.clone(),
+            pane: Pane::Balance,
+            node: storage.load_cache(account),
+            history: storage.get_history(),
+            history_state: ListState::default(),
+            voting: None,
+            candidates: Vec::new(),
+            is_validator: false,
+            updates,
+            refresh,
+        }
+    }
+
+    /// Show the dashboard until the user picks an action
+    pub fn run(&mut self) -> std::io::Result<Action> {
+        // Pick up changes made by the previous action (sent transactions, settings)
+        self.history = merge_history(self.storage.get_history(), &self.history);
+        let _ = self.refresh.send(());
+
+        let mut terminal = ratatui::try_init()?;
+        let result = self.event_loop(&mut terminal);
+        ratatui::restore();
+        result
+    }
+
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> std::io::Result<Action> {
+        loop {
+            while let Ok(update) = self.updates.try_recv() {
+                self.apply(update);
+            }
+
+            terminal.draw(|frame| self.draw(frame))?;
+
+            if !event::poll(TICK)? {
+                continue;
+            }
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(Action::Exit),
+                KeyCode::Char('s') => return Ok(Action::Send),
+                KeyCode::Char('h') => return Ok(Action::History),
+                KeyCode::Char('i') => return Ok(Action::Inbox),
+                KeyCode::Char('b') => return Ok(Action::AddressList),
+                KeyCode::Char('c') if self.is_bootstrap() => return Ok(Action::Community),
+                KeyCode::Char('v') if self.is_validator => return Ok(Action::Validator),
+                KeyCode::Char('o') => return Ok(Action::Settings),
+                KeyCode::Char('r') => {
+                    let _ = self.refresh.send(());
+                }
+                KeyCode::Char('1') => self.pane = Pane::Balance,
+                KeyCode::Char('2') => self.pane = Pane::History,
+                KeyCode::Char('3') => self.pane = Pane::Community,
+                KeyCode::Tab | KeyCode::Right => self.pane = PANES[(self.pane.index() + 1) % PANES.len()],
+                KeyCode::BackTab | KeyCode::Left => {
+                    self.pane = PANES[(self.pane.index() + PANES.len() - 1) % PANES.len()]
+                }
+                KeyCode::Down if self.pane == Pane::History => self.history_state.select_next(),
+                KeyCode::Up if self.pane == Pane::History => self.history_state.select_previous(),
+                _ => {}
+            }
+        }
+    }
+
+    /// Community actions are only offered during the bootstrap era
+    fn is_bootstrap(&self) -> bool {
+        self.voting.as_ref().is_some_and(|v| v.is_bootstrap_era)
+    }
+
+    fn apply(&mut self, update: Update) {
+        match update {
+            Update::Node(Some(node)) => self.node = Some(node),
+            Update::Node(None) => {}
+            Update::History(remote) => self.history = merge_history(self.history.clone(), &remote),
+            Update::Community(voting, candidates) => {
+                self.voting = voting;
+                self.candidates = candidates;
+            }
+            Update::Validator(is_validator) => self.is_validator = is_validator,
+        }
+    }
+
+    // =========================================================================
+    // DRAWING
+    // =========================================================================
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, tabs, body, footer] = Layout::vertical([
+            Constraint::Length(6),
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        self.draw_header(frame, header);
+
+        let titles: Vec<String> = PANES
+            .iter()
+            .enumerate()
+            .map(|(i, p)| format!("{} {}", i + 1, p.title()))
+            .collect();
+        frame.render_widget(
+            Tabs::new(titles)
+                .select(self.pane.index())
+                .highlight_style(Style::new().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                .block(Block::bordered()),
+            tabs,
+        );
+
+        match self.pane {
+            Pane::Balance => self.draw_balance(frame, body),
+            Pane::History => self.draw_history(frame, body),
+            Pane::Community => self.draw_community(frame, body),
+        }
+
+        frame.render_widget(Paragraph::new(self.key_hints()).dark_gray(), footer);
+    }
+
+    fn draw_header(&self, frame: &mut Frame, area: Rect) {
+        let settings = self.storage.load_settings();
+        let online = cache::node_online();
+
+        let network = match (&self.node, online) {
+            (Some(node), true) => Line::from(vec![
+                Span::raw(format!("{} ", t("Network:"))),
+                Span::raw(format!("{} #{}", node.network.chain_name, node.network.height)).bold(),
+                Span::raw(if node.network.is_synced { String::new() } else { format!(" ({})", t("syncing")) }),
+            ]),
+            (Some(node), false) => Line::from(vec![
+                Span::raw(format!("⚠ {} ", t("Offline"))).yellow(),
+                Span::raw(cache::stamp(node)).dark_gray(),
+            ]),
+            (None, true) => Line::from(t("Connecting to node...")).dark_gray(),
+            (None, false) => Line::from(format!("⚠ {}", t("Offline"))).yellow(),
+        };
+
+        let mut security = vec![Span::raw(format!("{} ", t("Security:")))];
+        match self.node.as_ref().and_then(|node| node.network.security.as_ref()) {
+            Some(state) => security.extend(security_spans(state)),
+            None => security.push(Span::raw(t("unknown")).dark_gray()),
+        }
+
+        let transport = if self.rpc_url.starts_with("https://") {
+            Span::raw(t("TLS")).green()
+        } else {
+            Span::raw(t("Unencrypted RPC")).yellow()
+        };
+        let mut wallet = vec![Span::raw(format!("{} ", t("Wallet:"))), transport];
+        if settings.paranoid_mode {
+            wallet.push(Span::raw(" · "));
+            wallet.push(Span::raw(t("Paranoid mode")).green());
+        }
+        if let Some(limit) = settings.unlock_guard.wipe_after {
+            wallet.push(Span::raw(" · "));
+            wallet.push(Span::raw(tf("Wipe after {} failed unlocks", &[&limit])).red());
+        }
+
+        let lines = vec![
+            Line::from(vec![
+                Span::raw(format!("{} ", t("Account:"))),
+                Span::raw(format!("0x{}", self.account)).cyan(),
+            ]),
+            network,
+            Line::from(security),
+            Line::from(wallet),
+        ];
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(" KratOs Wallet ").cyan()),
+            area,
+        );
+    }
+
+    fn draw_balance(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered().title(format!(" {} ", t("Balance")));
+        let Some(node) = &self.node else {
+            frame.render_widget(Paragraph::new(t("No balance data yet.")).block(block), area);
+            return;
+        };
+
+        let balance = &node.balance;
+        let mut lines = vec![
+            Line::from(vec![
+                Span::raw(format!("{:<12}", t("Free:"))),
+                Span::raw(balance.free.clone()).green().bold(),
+            ]),
+            Line::from(vec![
+                Span::raw(format!("{:<12}", t("Reserved:"))),
+                Span::raw(balance.reserved.clone()).yellow(),
+            ]),
+            Line::from(vec![
+                Span::raw(format!("{:<12}", t("Total:"))),
+                Span::raw(balance.total.clone()).cyan().bold(),
+            ]),
+            Line::from(""),
+            Line::from(vec![Span::raw(format!("{:<12}", t("Nonce:"))), Span::raw(balance.nonce.to_string())]),
+            Line::from(""),
+        ];
+        lines.push(Line::from(cache::stamp(node)).dark_gray());
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn draw_history(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered().title(format!(
+            " {} ({}) ",
+            t("History"),
+            self.history.transactions.len()
+        ));
+        if self.history.transactions.is_empty() {
+            frame.render_widget(Paragraph::new(t("No transactions yet.")).block(block), area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .history
+            .transactions
+            .iter()
+            .map(|tx| {
+                let (sign, color, label) = match tx.direction {
+                    TransactionDirection::Sent => ("-", Color::Red, t("To:")),
+                    TransactionDirection::Received => ("+", Color::Green, t("From:")),
+                };
+                let status = match tx.status {
+                    TransactionStatus::Pending => Span::raw(t("pending")).yellow(),
+                    TransactionStatus::Confirmed => Span::raw(t("confirmed")).green(),
+                    TransactionStatus::Failed => Span::raw(t("failed")).red(),
+                };
+                let block_number = tx
+                    .block_number
+                    .map(|b| format!("#{}", b))
+                    .unwrap_or_else(|| "-".to_string());
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{}{:<22}", sign, format_balance(tx.amount)), Style::new().fg(color)),
+                    Span::raw(format!(" {} {:<22} ", label, format_address_short(&tx.counterparty))),
+                    Span::raw(format!("{:<10} ", block_number)).dark_gray(),
+                    Span::raw(format!("{:<16} ", format_timestamp(tx.timestamp))).dark_gray(),
+                    status,
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, area, &mut self.history_state);
+    }
+
+    fn draw_community(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered().title(format!(" {} ", t("Community (Early Validators)")));
+        let Some(voting) = &self.voting else {
+            frame.render_widget(Paragraph::new(t("Voting status unavailable.")).block(block), area);
+            return;
+        };
+
+        let mut lines = Vec::new();
+        if voting.is_bootstrap_era {
+            lines.push(Line::from(tf("Bootstrap era ends at block #{}", &[&voting.bootstrap_end_block])));
+        } else {
+            lines.push(Line::from(t("Bootstrap era has ended.")).dark_gray());
+        }
+        lines.push(Line::from(tf(
+            "Validators: {} / {} · votes required: {}",
+            &[&voting.validator_count, &voting.max_validators, &voting.votes_required],
+        )));
+        lines.push(Line::from(""));
+
+        if self.candidates.is_empty() {
+            lines.push(Line::from(t("No pending candidates.")).dark_gray());
+        }
+        for candidate in &self.candidates {
+            let name = candidate.display_name.clone().unwrap_or_default();
+            let votes = format!("{}/{}", candidate.vote_count, candidate.votes_required);
+            lines.push(Line::from(vec![
+                Span::raw(format!("{:<22} ", format_address_short(&candidate.candidate))).cyan(),
+                Span::raw(format!("{:<8} ", votes)).bold(),
+                if candidate.has_quorum {
+                    Span::raw(t("quorum")).green()
+                } else {
+                    Span::raw(t("pending")).yellow()
+                },
+                Span::raw(format!(" {}", name)),
+            ]));
+        }
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+
+    fn key_hints(&self) -> String {
+        let mut hints = vec![
+            format!("[s] {}", t("Send")),
+            format!("[h] {}", t("History")),
+            format!("[i] {}", t("Inbox")),
+            format!("[b] {}", t("Address list")),
+        ];
+        if self.is_bootstrap() {
+            hints.push(format!("[c] {}", t("Community")));
+        }
+        if self.is_validator {
+            hints.push(format!("[v] {}", t("Validator")));
+        }
+        hints.push(format!("[o] {}", t("Settings")));
+        hints.push(format!("[r] {}", t("Refresh")));
+        hints.push(format!("[q] {}", t("Quit")));
+        hints.join("  ")
+    }
+}
+
+/// Header spans for the network security state: its name colored by
+/// severity, the epoch it was entered and the slowed block time
+fn security_spans(security: &SecurityState) -> Vec<Span<'static>> {
+    let name = match security.state.as_str() {
+        "Bootstrap" => Span::raw(t("Bootstrap")).cyan(),
+        "Normal" => Span::raw(t("Normal")).green(),
+        "Degraded" => Span::raw(t("Degraded")).yellow(),
+        "BootstrapRecovery" => Span::raw(t("Bootstrap recovery")).yellow(),
+        "Restricted" => Span::raw(t("Restricted")).red(),
+        "Emergency" => Span::raw(t("Emergency")).red().bold(),
+        other => Span::raw(other.to_string()).yellow(),
+    };
+
+    let mut spans = vec![name.bold()];
+    if let Some(epoch) = security.entered_at {
+        spans.push(Span::raw(format!(" {}", tf("since epoch {}", &[&epoch]))).dark_gray());
+    }
+    match security.block_time_multiplier {
+        0 => spans.push(Span::raw(format!(" · {}", t("block production halted"))).red()),
+        1 => {}
+        multiplier => spans.push(Span::raw(format!(" · {}", tf("block time x{}", &[&multiplier]))).yellow()),
+    }
+    spans
+}
+
+/// Merge records of `other` into `base` (deduplicated by hash)
+fn merge_history(mut base: TransactionHistory, other: &TransactionHistory) -> TransactionHistory {
+    for record in &other.transactions {
+        base.add(record.clone());
+    }
+    base
+}
+
+/// Refresh node data in the background until the dashboard is dropped
+fn spawn_worker(
+    rpc_url: &str,
+    storage: &WalletStorage,
+    account: &str,
+    updates: Sender<Update>,
+    refresh: Receiver<()>,
+) {
+    let client = RpcClient::new(rpc_url);
+    let storage = storage.clone();
+    let account = account.to_string();
+
+    std::thread::spawn(move || loop {
+        let node = cache::refresh(&client, &storage, &account).ok();
+        let online = node.is_some();
+        if updates.send(Update::Node(node)).is_err() {
+            return;
+        }
+
+        if online {
+            if let Ok(response) = client.get_transaction_history(&account, 100, 0) {
+                let mut remote = TransactionHistory::new();
+                for record in client.convert_rpc_transactions(response.transactions, &account) {
+                    remote.add(record);
+                }
+                let _ = updates.send(Update::History(remote));
+            }
+
+            let voting = client.get_early_voting_status().ok();
+            let candidates = client
+                .get_pending_candidates()
+                .map(|r| r.candidates)
+                .unwrap_or_default();
+            let _ = updates.send(Update::Community(voting, candidates));
+
+            let is_validator = client.can_vote(&account).map(|r| r.is_validator).unwrap_or(false);
+            let _ = updates.send(Update::Validator(is_validator));
+        }
+
+        match refresh.recv_timeout(cache::REFRESH_INTERVAL) {
+            Ok(()) | Err(RecvTimeoutError::Timeout) => {
+                // Coalesce refresh requests queued while fetching
+                while refresh.try_recv().is_ok() {}
+            }
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+    });
+}
@@ -42,6 +42,23 @@ impl<'de> Deserialize<'de> for AccountId32 {
                 arr.copy_from_slice(value);
                 Ok(AccountId32(arr))
             }
+
+            // Self-describing formats (e.g. JSON) encode bytes as a sequence
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut arr = [0u8; 32];
+                for (i, byte) in arr.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| A::Error::invalid_length(i, &self))?;
+                }
+                if seq.next_element::<u8>()?.is_some() {
+                    return Err(A::Error::custom("Expected 32 bytes, got more"));
+                }
+                Ok(AccountId32(arr))
+            }
         }
 
         deserializer.deserialize_bytes(AccountId32Visitor)
@@ -61,13 +78,30 @@ impl AsRef<[u8; 32]> for AccountId32 {
 }
 
 /// Account information from RPC
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountInfo {
     pub free: String,
     pub reserved: String,
     pub total: String,
     pub nonce: u64,
+    /// Free balance in base units
+    #[serde(default)]
+    pub free_raw: u128,
+    /// Reserved (staked) balance in base units
+    #[serde(default)]
+    pub reserved_raw: u128,
+}
+
+impl AccountInfo {
+    /// Whether the migration wizard can move this account to a new key
+    ///
+    /// Only the free balance is swept. Stake (bonded or unbonding) and the
+    /// validator role stay bound to the old key: the chain has no call to
+    /// re-point them, and unstaking waits out UNBONDING_PERIOD.
+    pub fn can_migrate(&self, is_validator: bool) -> bool {
+        !is_validator && self.reserved_raw == 0
+    }
 }
 
 /// Transaction call types - MUST match kratos-core order exactly for bincode compatibility
@@ -111,6 +145,7 @@ pub enum TransactionCall {
     /// Propose a new early validator during bootstrap era
     ProposeEarlyValidator {
         candidate: AccountId32,
+        metadata: CandidateMetadata,
     },
     /// Vote for an early validator candidate during bootstrap era
     VoteEarlyValidator {
@@ -121,6 +156,19 @@ pub enum TransactionCall {
 /// ChainId wrapper (same as [u8; 32] but serialized as bytes)
 pub type ChainId32 = AccountId32;
 
+/// Maximum length of a candidate display name (must match node)
+pub const MAX_CANDIDATE_NAME_LEN: usize = 64;
+
+/// Maximum length of a candidate contact URL (must match node)
+pub const MAX_CANDIDATE_URL_LEN: usize = 256;
+
+/// Public metadata attached to an early validator proposal
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CandidateMetadata {
+    pub display_name: Option<String>,
+    pub url: Option<String>,
+}
+
 /// Métadonnées minimales d'une sidechain
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SidechainMetadata {
@@ -136,6 +184,43 @@ pub struct Transaction {
     pub nonce: u64,
     pub call: TransactionCall,
     pub timestamp: u64,
+    /// Genesis hash of the chain the transaction is for (see `RpcClient::transaction_context`)
+    pub genesis_hash: [u8; 32],
+    /// Last block the transaction may be included in (None: immortal)
+    pub valid_until: Option<u64>,
+    /// Most the sender pays in fees (None: no limit)
+    pub max_fee: Option<u128>,
+    /// Priority fee on top of the base fee (counts against max_fee)
+    pub tip: u128,
+}
+
+/// Room left in the signed max fee for the base fee to climb before
+/// inclusion (+12.5% per full block: doubles in 6 full blocks)
+pub const MAX_FEE_HEADROOM: u128 = 2;
+
+/// Chain a transaction is signed for, and its validity window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionContext {
+    pub genesis_hash: [u8; 32],
+    /// Best block when the context was fetched
+    pub best_block: u64,
+    /// Last block the transaction may be included in
+    pub valid_until: Option<u64>,
+    /// Base fee of the next block (fee_history), scales the static call fees
+    pub base_fee: u64,
+}
+
+impl TransactionContext {
+    /// Fee of `call` at the next block's base fee
+    pub fn fee(&self, call: &TransactionCall) -> u128 {
+        crate::preview::scale_fee(crate::preview::static_fee(call), self.base_fee)
+    }
+
+    /// Max fee signed with `call`
+    pub fn max_fee(&self, call: &TransactionCall) -> u128 {
+        self.fee(call).saturating_mul(MAX_FEE_HEADROOM)
+    }
+
 }
 
 /// Signed transaction
@@ -198,6 +283,9 @@ pub struct TransactionRecord {
     /// Optional note/memo
     #[serde(default)]
     pub note: Option<String>,
+    /// Block this transaction was orphaned from by a chain reorg (None if never reorged)
+    #[serde(default)]
+    pub orphaned_block: Option<u64>,
 }
 
 impl TransactionRecord {
@@ -219,9 +307,37 @@ impl TransactionRecord {
             block_number: None,
             nonce,
             note: None,
+            orphaned_block: None,
         }
     }
 
+    /// Apply a reorg event to this record
+    ///
+    /// Returns true if the record changed. Dropped transactions become Failed,
+    /// orphaned ones go back to Pending until the node reports them again.
+    pub fn apply_reorg(&mut self, event: &ReorgEvent) -> bool {
+        if self.status != TransactionStatus::Confirmed {
+            return false;
+        }
+
+        let listed = |hashes: &[String]| hashes.iter().any(|h| h.eq_ignore_ascii_case(&self.hash));
+        let in_orphaned_range = self
+            .block_number
+            .map(|b| b >= event.block_number && b < event.block_number.saturating_add(event.depth.max(1)))
+            .unwrap_or(false);
+
+        if listed(&event.dropped_transactions) {
+            self.status = TransactionStatus::Failed;
+        } else if listed(&event.orphaned_transactions) || in_orphaned_range {
+            self.status = TransactionStatus::Pending;
+        } else {
+            return false;
+        }
+
+        self.orphaned_block = self.block_number.or(Some(event.block_number));
+        self.block_number = None;
+        true
+    }
 }
 
 /// Local transaction history (stored in wallet)
@@ -231,6 +347,9 @@ pub struct TransactionHistory {
     pub transactions: Vec<TransactionRecord>,
     /// Last synced block number
     pub last_synced_block: u64,
+    /// Id of the last reorg event applied
+    #[serde(default)]
+    pub last_reorg_event: u64,
 }
 
 impl TransactionHistory {
@@ -239,15 +358,41 @@ impl TransactionHistory {
         Self {
             transactions: Vec::new(),
             last_synced_block: 0,
+            last_reorg_event: 0,
         }
     }
 
     /// Add a new transaction record
     pub fn add(&mut self, record: TransactionRecord) {
         // Check if transaction already exists (by hash)
-        if !self.transactions.iter().any(|tx| tx.hash == record.hash) {
-            self.transactions.insert(0, record); // Insert at beginning (newest first)
+        match self.transactions.iter_mut().find(|tx| tx.hash == record.hash) {
+            Some(existing) => {
+                // A reorged transaction that the node reports again was re-included
+                if existing.status != TransactionStatus::Confirmed
+                    && record.status == TransactionStatus::Confirmed
+                {
+                    existing.status = TransactionStatus::Confirmed;
+                    existing.block_number = record.block_number;
+                }
+            }
+            None => self.transactions.insert(0, record), // Insert at beginning (newest first)
+        }
+    }
+
+    /// Apply chain reorg events reported by the node
+    ///
+    /// Returns the number of records whose status changed.
+    pub fn apply_reorg_events(&mut self, events: &[ReorgEvent]) -> usize {
+        let mut changed = 0;
+        for event in events {
+            for tx in self.transactions.iter_mut() {
+                if tx.apply_reorg(event) {
+                    changed += 1;
+                }
+            }
+            self.last_reorg_event = self.last_reorg_event.max(event.id);
         }
+        changed
     }
 
     /// Get transactions with pagination
@@ -296,6 +441,238 @@ pub struct RpcTransactionRecord {
     pub nonce: u64,
 }
 
+/// Response from account_getTransactions (nodes started with --index)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTransactionsResponse {
+    /// Requested page, newest first
+    pub transactions: Vec<IndexedTransactionRecord>,
+}
+
+/// Transaction of an account_getTransactions page
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedTransactionRecord {
+    pub hash: String,
+    pub nonce: u64,
+    pub timestamp: u64,
+    pub block_number: u64,
+    /// Execution events ({contract, name, data})
+    #[serde(default)]
+    pub events: Vec<RpcEvent>,
+}
+
+/// Execution event reported by the node
+#[derive(Debug, Clone, Deserialize)]
+pub struct RpcEvent {
+    pub contract: String,
+    pub name: String,
+    pub data: serde_json::Value,
+}
+
+impl IndexedTransactionRecord {
+    /// The KRAT transfer this transaction made (None for other transactions
+    /// and failed transfers)
+    pub fn transfer(&self) -> Option<RpcTransactionRecord> {
+        let event = self.events.iter().find(|e| e.contract == "krat" && e.name == "Transfer")?;
+        // Balances above u64 are sent as decimal strings
+        let amount = match &event.data["amount"] {
+            serde_json::Value::Number(n) => n.as_u64()? as u128,
+            serde_json::Value::String(s) => s.parse().ok()?,
+            _ => return None,
+        };
+        Some(RpcTransactionRecord {
+            hash: self.hash.clone(),
+            from: event.data["from"].as_str()?.to_string(),
+            to: event.data["to"].as_str()?.to_string(),
+            amount,
+            timestamp: self.timestamp,
+            block_number: self.block_number,
+            nonce: self.nonce,
+        })
+    }
+}
+
+impl From<AccountTransactionsResponse> for TransactionHistoryResponse {
+    fn from(page: AccountTransactionsResponse) -> Self {
+        Self {
+            transactions: page.transactions.iter().filter_map(IndexedTransactionRecord::transfer).collect(),
+        }
+    }
+}
+
+/// Chain reorg event reported by the node
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorgEvent {
+    /// Monotonic event id (used as sync cursor)
+    pub id: u64,
+    /// First block height that was replaced
+    pub block_number: u64,
+    /// Number of blocks orphaned from the old chain
+    #[serde(default)]
+    pub depth: u64,
+    /// Transactions from orphaned blocks still valid (re-included or back in the mempool)
+    #[serde(default)]
+    pub orphaned_transactions: Vec<String>,
+    /// Transactions from orphaned blocks that are no longer valid
+    #[serde(default)]
+    pub dropped_transactions: Vec<String>,
+}
+
+/// Response from chain_getReorgEvents RPC
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorgEventsResponse {
+    /// Events with id greater than the requested cursor (oldest first)
+    pub events: Vec<ReorgEvent>,
+}
+
+// =============================================================================
+// WALLET SETTINGS
+// =============================================================================
+
+/// Non-secret user preferences (stored unencrypted, readable before unlock)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WalletSettings {
+    /// UI language (None = detect from environment)
+    #[serde(default)]
+    pub language: Option<crate::i18n::Language>,
+    /// Never echo secrets; reveal generated keys only after a keypress
+    #[serde(default)]
+    pub paranoid_mode: bool,
+    /// Failed unlock tracking and optional wipe policy
+    #[serde(default)]
+    pub unlock_guard: UnlockGuard,
+}
+
+/// Failed unlock attempts allowed before delays kick in
+pub const FREE_UNLOCK_ATTEMPTS: u32 = 3;
+
+/// First lockout delay in seconds (doubles with each further failure)
+const BASE_UNLOCK_DELAY_SECS: u64 = 30;
+
+/// Upper bound on the lockout delay
+const MAX_UNLOCK_DELAY_SECS: u64 = 3600;
+
+/// Smallest accepted wipe threshold, so a few typos never erase a wallet
+pub const MIN_WIPE_ATTEMPTS: u32 = 5;
+
+/// Failed unlock attempt tracking
+///
+/// After FREE_UNLOCK_ATTEMPTS failures each new attempt waits an escalating delay,
+/// measured from the last failure so restarting the app does not reset it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UnlockGuard {
+    /// Consecutive failed attempts since the last successful unlock
+    #[serde(default)]
+    pub failed_attempts: u32,
+    /// Unix timestamp of the last failed attempt
+    #[serde(default)]
+    pub last_failure: u64,
+    /// Delete the wallet file after this many consecutive failures (None = never)
+    #[serde(default)]
+    pub wipe_after: Option<u32>,
+}
+
+impl UnlockGuard {
+    /// Delay imposed after the current number of failures
+    pub fn delay_secs(&self) -> u64 {
+        if self.failed_attempts < FREE_UNLOCK_ATTEMPTS {
+            return 0;
+        }
+        let doublings = (self.failed_attempts - FREE_UNLOCK_ATTEMPTS).min(16);
+        (BASE_UNLOCK_DELAY_SECS << doublings).min(MAX_UNLOCK_DELAY_SECS)
+    }
+
+    /// Seconds left before the next attempt is allowed
+    pub fn remaining_lockout(&self, now: u64) -> u64 {
+        (self.last_failure + self.delay_secs()).saturating_sub(now)
+    }
+
+    /// Record a failed attempt
+    pub fn record_failure(&mut self, now: u64) {
+        self.failed_attempts = self.failed_attempts.saturating_add(1);
+        self.last_failure = now;
+    }
+
+    /// Reset the counter after a successful unlock
+    pub fn record_success(&mut self) {
+        self.failed_attempts = 0;
+        self.last_failure = 0;
+    }
+
+    /// Attempts left before the wallet is wiped (None if wipe mode is off)
+    pub fn attempts_before_wipe(&self) -> Option<u32> {
+        self.wipe_after
+            .map(|limit| limit.saturating_sub(self.failed_attempts))
+    }
+
+    /// Whether the wipe threshold has been reached
+    pub fn should_wipe(&self) -> bool {
+        self.attempts_before_wipe() == Some(0)
+    }
+}
+
+// =============================================================================
+// WATCH-ONLY ACCOUNTS
+// =============================================================================
+
+/// An account kept for monitoring only (no secret key stored)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WatchOnlyAccount {
+    /// Account address (hex with 0x prefix)
+    pub address: String,
+    /// Account that replaced this one after a migration
+    #[serde(default)]
+    pub replaced_by: Option<String>,
+    /// Unix timestamp when the account was retired
+    pub retired_at: u64,
+    /// Hash of the transaction that swept the funds, if any
+    #[serde(default)]
+    pub sweep_tx: Option<String>,
+}
+
+// =============================================================================
+// NODE DATA CACHE
+// =============================================================================
+
+/// Network status as last seen from the node
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkSnapshot {
+    pub chain_name: String,
+    pub height: u64,
+    pub is_synced: bool,
+    /// Network security state (None if the node did not report it)
+    #[serde(default)]
+    pub security: Option<SecurityState>,
+}
+
+/// Network security state (chain_getEpochInfo)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityState {
+    /// Bootstrap, Normal, Degraded, Restricted, Emergency or BootstrapRecovery
+    pub state: String,
+    /// Epoch the state was entered (None for Bootstrap and Normal)
+    pub entered_at: Option<u64>,
+    /// Block time multiplier (0: no new blocks)
+    pub block_time_multiplier: u32,
+    /// Governance timelock multiplier (0: governance frozen)
+    pub timelock_multiplier: u32,
+}
+
+/// Last-known node data, shown when the node cannot be reached
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeCache {
+    /// Account the balance belongs to (hex without 0x prefix)
+    pub account: String,
+    pub balance: AccountInfo,
+    pub network: NetworkSnapshot,
+    /// Unix timestamp of the refresh
+    pub updated_at: u64,
+}
+
 // =============================================================================
 // EARLY VALIDATOR TYPES
 // =============================================================================
@@ -340,6 +717,12 @@ pub struct EarlyValidatorCandidate {
     pub has_quorum: bool,
     /// Block when candidacy was created
     pub created_at: u64,
+    /// Display name supplied by the proposer
+    #[serde(default)]
+    pub display_name: Option<String>,
+    /// Contact URL supplied by the proposer
+    #[serde(default)]
+    pub url: Option<String>,
     /// List of voters who have voted for this candidate
     pub voters: Vec<String>,
 }
@@ -404,14 +787,254 @@ pub struct CanVoteResponse {
     pub reason: String,
 }
 
+// =============================================================================
+// VALIDATOR OPERATIONS TYPES
+// =============================================================================
+
+/// Response from validator_getInfo RPC
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorDashboardInfo {
+    /// Staked amount (raw units)
+    pub stake: u128,
+    /// Total validator credits
+    pub validator_credits: u64,
+    /// Blocks produced
+    pub blocks_produced: u64,
+    /// Blocks missed
+    pub blocks_missed: u32,
+    /// Registry status (Active, Inactive, Unbonding, Slashed, Jailed)
+    pub status: String,
+    /// Reputation score (0-100)
+    pub reputation: u8,
+    /// Bootstrap validator flag
+    pub is_bootstrap_validator: bool,
+    /// Share of assigned slots produced (0-100)
+    pub participation_percent: f64,
+    /// Number of times slashed
+    pub slash_count: u32,
+    /// Total amount slashed (raw units)
+    pub total_slashed: u128,
+    /// Clock synchronization failures
+    pub clock_sync_failures: u32,
+    /// Balance currently unbonding (raw units)
+    pub unbonding_balance: u128,
+    /// Block at which unbonding completes
+    pub unbonding_at: Option<u64>,
+    /// Commission on delegators' rewards, in percent (None: older node)
+    pub commission: Option<u8>,
+    /// Commission announced for a later epoch
+    pub announced_commission: Option<AnnouncedCommission>,
+    /// Eligible for block production right now
+    pub can_participate: bool,
+    /// Voted in the last finalized round
+    pub finality_voter: bool,
+    /// Current epoch
+    pub current_epoch: u64,
+    /// Blocks until the next epoch
+    pub blocks_until_next_epoch: u64,
+}
+
+/// Commission a validator announced (validator_getInfo)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnouncedCommission {
+    /// Commission, in percent
+    pub commission: u8,
+    /// First epoch the commission applies to
+    pub effective_epoch: u64,
+}
+
+/// Stake unlocking at one epoch
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnbondingChunk {
+    /// Unlock epoch
+    pub epoch: u64,
+    /// First block at which the chunk can be withdrawn
+    pub unlock_at: u64,
+    /// Amount (raw units)
+    pub amount: u128,
+}
+
+/// Response from staking_getUnbonding RPC
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnbondingInfo {
+    /// Total amount unbonding (raw units)
+    pub total: u128,
+    /// Amount a withdrawal in the next block would release (raw units)
+    pub withdrawable: u128,
+    /// Pending unlocks, by epoch
+    pub chunks: Vec<UnbondingChunk>,
+}
+
+/// Response from identity_get RPC (null when the account never registered)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityInfo {
+    /// Public, non-unique display name
+    pub display_name: Option<String>,
+    /// Declared, Attested, Active, Expired or Revoked
+    pub status: String,
+}
+
+impl IdentityInfo {
+    /// Vouched for by enough attesters
+    pub fn is_verified(&self) -> bool {
+        self.status == "Active"
+    }
+}
+
+/// Message from messaging_getInbox RPC
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboxMessage {
+    /// Position in the inbox
+    pub id: u64,
+    /// Sender (0x-prefixed hex)
+    pub from: String,
+    /// Body (0x-prefixed hex)
+    pub body: String,
+    /// The body is ciphertext
+    pub encrypted: bool,
+    /// Block the message was delivered in
+    pub sent_at: u64,
+}
+
+impl InboxMessage {
+    /// Body as text (None if encrypted or not UTF-8)
+    pub fn text(&self) -> Option<String> {
+        if self.encrypted {
+            return None;
+        }
+        let bytes = hex::decode(self.body.trim_start_matches("0x")).ok()?;
+        String::from_utf8(bytes).ok()
+    }
+
+    /// Body size in bytes
+    pub fn size(&self) -> usize {
+        self.body.trim_start_matches("0x").len() / 2
+    }
+}
+
+/// Response from validator_getCredits RPC
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorCredits {
+    /// Credits from governance votes
+    pub vote_credits: u32,
+    /// Credits from uptime
+    pub uptime_credits: u32,
+    /// Credits from accepted arbitrations
+    pub arbitration_credits: u32,
+    /// Credits from seniority
+    pub seniority_credits: u32,
+    /// Total credits
+    pub total: u64,
+    /// Penalty from clock synchronization failures
+    pub clock_penalty: u64,
+    /// Credits removed by slashing over the node's recorded history
+    #[serde(default)]
+    pub deducted: u64,
+}
+
+/// VC changes of one epoch
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreditsEpoch {
+    /// Epoch
+    pub epoch: u64,
+    /// Credits earned from governance votes
+    pub vote_credits: u32,
+    /// Credits earned from uptime
+    pub uptime_credits: u32,
+    /// Credits earned from accepted arbitrations
+    pub arbitration_credits: u32,
+    /// Credits earned from seniority
+    pub seniority_credits: u32,
+    /// Credits removed by slashing
+    pub deducted: u64,
+    /// Total credits at the end of the epoch
+    pub total: u64,
+}
+
+impl CreditsEpoch {
+    /// Credits earned from all sources
+    pub fn earned(&self) -> u64 {
+        self.vote_credits as u64
+            + self.uptime_credits as u64
+            + self.arbitration_credits as u64
+            + self.seniority_credits as u64
+    }
+}
+
+/// Response from validator_creditsHistory RPC
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreditsHistory {
+    /// Epochs with a change, newest first
+    pub epochs: Vec<CreditsEpoch>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::preview::BASE_FEE_PRECISION;
+
+    #[test]
+    fn test_unlock_guard_escalating_delay_and_wipe() {
+        let mut guard = UnlockGuard::default();
+        for _ in 0..FREE_UNLOCK_ATTEMPTS - 1 {
+            guard.record_failure(1_000);
+        }
+        assert_eq!(guard.remaining_lockout(1_000), 0);
+
+        guard.record_failure(1_000);
+        assert_eq!(guard.delay_secs(), 30);
+        assert_eq!(guard.remaining_lockout(1_010), 20);
+
+        guard.record_failure(1_030);
+        assert_eq!(guard.delay_secs(), 60);
+        for _ in 0..20 {
+            guard.record_failure(2_000);
+        }
+        assert_eq!(guard.delay_secs(), 3600);
+        assert_eq!(guard.attempts_before_wipe(), None);
+        assert!(!guard.should_wipe());
+
+        guard.record_success();
+        assert_eq!(guard.remaining_lockout(2_000), 0);
+
+        guard.wipe_after = Some(MIN_WIPE_ATTEMPTS);
+        for _ in 0..MIN_WIPE_ATTEMPTS - 1 {
+            guard.record_failure(3_000);
+        }
+        assert_eq!(guard.attempts_before_wipe(), Some(1));
+        guard.record_failure(3_000);
+        assert!(guard.should_wipe());
+    }
+
+    #[test]
+    fn test_can_migrate_only_free_balance() {
+        let info = AccountInfo {
+            free: String::new(),
+            reserved: String::new(),
+            total: String::new(),
+            nonce: 0,
+            free_raw: 1_000,
+            reserved_raw: 0,
+        };
+        assert!(info.can_migrate(false));
+        // Validators and staked (or unbonding) funds are refused
+        assert!(!info.can_migrate(true));
+        assert!(!AccountInfo { reserved_raw: 1, ..info }.can_migrate(false));
+    }
 
     #[test]
     fn test_transaction_call_serialize() {
         let call = TransactionCall::Transfer {
-            to: [1u8; 32],
+            to: AccountId32([1u8; 32]),
             amount: 1000,
         };
 
@@ -420,10 +1043,97 @@ mod tests {
 
         match deserialized {
             TransactionCall::Transfer { to, amount } => {
-                assert_eq!(to, [1u8; 32]);
+                assert_eq!(to, AccountId32([1u8; 32]));
                 assert_eq!(amount, 1000);
             }
             _ => panic!("Expected Transfer variant"),
         }
     }
+
+    #[test]
+    fn test_transaction_context_fee_follows_base_fee() {
+        let call = TransactionCall::Transfer { to: [2u8; 32].into(), amount: 1 };
+        let context =
+            TransactionContext { genesis_hash: [0u8; 32], best_block: 0, valid_until: None, base_fee: 3 * BASE_FEE_PRECISION };
+        assert_eq!(context.fee(&call), 3_000);
+        assert_eq!(context.max_fee(&call), 6_000);
+    }
+
+    fn confirmed_record(hash: &str, block: u64) -> TransactionRecord {
+        let mut record = TransactionRecord::new_sent(hash.to_string(), "0xdest".to_string(), 1, 0, 0);
+        record.status = TransactionStatus::Confirmed;
+        record.block_number = Some(block);
+        record
+    }
+
+    fn reorg_event(id: u64, block_number: u64, depth: u64) -> ReorgEvent {
+        ReorgEvent {
+            id,
+            block_number,
+            depth,
+            orphaned_transactions: Vec::new(),
+            dropped_transactions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_reorg_flips_confirmed_records() {
+        let mut history = TransactionHistory::new();
+        history.add(confirmed_record("0xaa", 10));
+        history.add(confirmed_record("0xbb", 11));
+        history.add(confirmed_record("0xcc", 20));
+
+        let mut event = reorg_event(3, 10, 1);
+        event.dropped_transactions.push("0xBB".to_string());
+
+        assert_eq!(history.apply_reorg_events(&[event]), 2);
+        assert_eq!(history.last_reorg_event, 3);
+
+        let find = |hash: &str| history.transactions.iter().find(|tx| tx.hash == hash).unwrap();
+        assert_eq!(find("0xaa").status, TransactionStatus::Pending);
+        assert_eq!(find("0xaa").orphaned_block, Some(10));
+        assert_eq!(find("0xaa").block_number, None);
+        assert_eq!(find("0xbb").status, TransactionStatus::Failed);
+        assert_eq!(find("0xcc").status, TransactionStatus::Confirmed);
+    }
+
+    #[test]
+    fn test_indexed_page_to_history() {
+        let page: AccountTransactionsResponse = serde_json::from_value(serde_json::json!({
+            "address": "0x02",
+            "total": 2,
+            "offset": 0,
+            "transactions": [
+                {
+                    "hash": "0xaa", "from": "0x01", "nonce": 4, "txType": "transfer", "timestamp": 100,
+                    "blockNumber": 12, "success": true,
+                    "events": [{ "contract": "krat", "name": "Transfer", "data": { "from": "0x01", "to": "0x02", "amount": "500" } }]
+                },
+                { "hash": "0xbb", "from": "0x02", "nonce": 0, "txType": "stake", "timestamp": 90, "blockNumber": 10, "events": [] }
+            ]
+        }))
+        .unwrap();
+
+        // Only transfers make history records
+        let history = TransactionHistoryResponse::from(page);
+        assert_eq!(history.transactions.len(), 1);
+        let tx = &history.transactions[0];
+        assert_eq!((tx.from.as_str(), tx.to.as_str(), tx.amount, tx.block_number), ("0x01", "0x02", 500, 12));
+    }
+
+    #[test]
+    fn test_reorged_record_confirmed_again() {
+        let mut history = TransactionHistory::new();
+        history.add(confirmed_record("0xaa", 10));
+        history.apply_reorg_events(&[reorg_event(1, 10, 1)]);
+
+        // Node reports the transaction again in a new block
+        history.add(confirmed_record("0xaa", 12));
+
+        assert_eq!(history.len(), 1);
+        let tx = &history.transactions[0];
+        assert_eq!(tx.status, TransactionStatus::Confirmed);
+        assert_eq!(tx.block_number, Some(12));
+        assert_eq!(tx.orphaned_block, Some(10));
+    }
 }
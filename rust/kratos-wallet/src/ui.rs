@@ -137,21 +137,53 @@ pub fn read_secret_with_mask(prompt: &str) -> String {
     input
 }
 
-/// Read password with confirmation (shows * for each character)
+/// Read secret input without any echo (paranoid mode)
+/// Nothing is printed while typing, so neither the content nor the length
+/// of the secret is visible on screen or in terminal scrollback.
+pub fn read_secret_hidden(prompt: &str) -> String {
+    let term = Term::stderr();
+
+    print!("{} ", style(prompt).cyan());
+    let _ = io::stdout().flush();
+
+    term.read_secure_line().unwrap_or_default()
+}
+
+/// Read secret input, hidden in paranoid mode and masked otherwise
+pub fn read_secret(prompt: &str, paranoid: bool) -> String {
+    if paranoid {
+        read_secret_hidden(prompt)
+    } else {
+        read_secret_with_mask(prompt)
+    }
+}
+
+/// Block until the user presses any key
+pub fn wait_for_keypress(message: &str) {
+    let term = Term::stderr();
+    println!("  {}", style(message).yellow().bold());
+    let _ = term.read_key();
+}
+
+/// Read password with confirmation (masked, or hidden in paranoid mode)
 /// Returns the password if both entries match, or empty string on cancel/mismatch
-pub fn read_password_with_confirm(prompt: &str, confirm_prompt: &str) -> Result<String, String> {
-    let password = read_secret_with_mask(prompt);
+pub fn read_password_with_confirm(
+    prompt: &str,
+    confirm_prompt: &str,
+    paranoid: bool,
+) -> Result<String, String> {
+    let password = read_secret(prompt, paranoid);
     if password.is_empty() {
-        return Err("Input cancelled".to_string());
+        return Err(t("Input cancelled").to_string());
     }
 
-    let confirm = read_secret_with_mask(confirm_prompt);
+    let confirm = read_secret(confirm_prompt, paranoid);
     if confirm.is_empty() {
-        return Err("Input cancelled".to_string());
+        return Err(t("Input cancelled").to_string());
     }
 
     if password != confirm {
-        return Err("Passwords don't match".to_string());
+        return Err(t("Passwords don't match").to_string());
     }
 
     Ok(password)
@@ -161,7 +193,8 @@ pub fn read_password_with_confirm(prompt: &str, confirm_prompt: &str) -> Result<
 // TRANSACTION HISTORY UI HELPERS
 // =============================================================================
 
-use crate::types::{TransactionDirection, TransactionRecord, TransactionStatus};
+use crate::i18n::{t, tf};
+use crate::types::{IdentityInfo, InboxMessage, TransactionDirection, TransactionRecord, TransactionStatus};
 
 /// Format a timestamp as a human-readable date/time
 pub fn format_timestamp(timestamp: u64) -> String {
@@ -178,13 +211,13 @@ pub fn format_timestamp(timestamp: u64) -> String {
     let diff = now.saturating_sub(timestamp);
 
     if diff < 60 {
-        "Just now".to_string()
+        t("Just now").to_string()
     } else if diff < 3600 {
-        format!("{} min ago", diff / 60)
+        tf("{} min ago", &[&(diff / 60)])
     } else if diff < 86400 {
-        format!("{} hours ago", diff / 3600)
+        tf("{} hours ago", &[&(diff / 3600)])
     } else if diff < 604800 {
-        format!("{} days ago", diff / 86400)
+        tf("{} days ago", &[&(diff / 86400)])
     } else {
         // Format as date
         let secs = datetime.duration_since(UNIX_EPOCH).unwrap().as_secs();
@@ -207,6 +240,21 @@ pub fn format_address_short(address: &str) -> String {
     }
 }
 
+/// Registered name shown next to an address (None if there is nothing to show)
+///
+/// Names are public and not unique: only Active identities are shown as is.
+pub fn format_identity_name(identity: &IdentityInfo) -> Option<String> {
+    let name = identity.display_name.as_deref()?;
+    if identity.status == "Revoked" {
+        return None;
+    }
+    if identity.is_verified() {
+        Some(name.to_string())
+    } else {
+        Some(tf("{}, unverified", &[&name]))
+    }
+}
+
 /// Format amount for transaction display
 pub fn format_tx_amount(amount: u128, direction: TransactionDirection) -> String {
     const KRAT: u128 = 1_000_000_000_000;
@@ -235,8 +283,8 @@ pub fn format_tx_amount(amount: u128, direction: TransactionDirection) -> String
     }
 }
 
-/// Print a single transaction record
-pub fn print_transaction(tx: &TransactionRecord, index: usize) {
+/// Print a single transaction record, with the counterparty's registered name if known
+pub fn print_transaction(tx: &TransactionRecord, index: usize, counterparty: Option<&IdentityInfo>) {
     let dir_icon = match tx.direction {
         TransactionDirection::Sent => style("").red(),
         TransactionDirection::Received => style("").green(),
@@ -257,44 +305,87 @@ pub fn print_transaction(tx: &TransactionRecord, index: usize) {
     );
 
     let counterparty_label = match tx.direction {
-        TransactionDirection::Sent => "To:",
-        TransactionDirection::Received => "From:",
+        TransactionDirection::Sent => t("To:"),
+        TransactionDirection::Received => t("From:"),
     };
 
+    let name = counterparty
+        .and_then(format_identity_name)
+        .map(|name| format!(" {}", style(format!("({})", name)).magenta()))
+        .unwrap_or_default();
     println!(
-        "      {} {}",
+        "      {} {}{}",
         style(counterparty_label).dim(),
-        style(format_address_short(&tx.counterparty)).white()
+        style(format_address_short(&tx.counterparty)).white(),
+        name
     );
 
     println!(
         "      {} {}  {} {}",
-        style("Time:").dim(),
+        style(t("Time:")).dim(),
         format_timestamp(tx.timestamp),
-        style("Block:").dim(),
+        style(t("Block:")).dim(),
         tx.block_number
             .map(|b| b.to_string())
-            .unwrap_or_else(|| "pending".to_string())
+            .unwrap_or_else(|| t("pending").to_string())
     );
 
     println!(
         "      {} {}",
-        style("Hash:").dim(),
+        style(t("Hash:")).dim(),
         style(format_address_short(&tx.hash)).cyan()
     );
 
+    if let Some(block) = tx.orphaned_block {
+        let note = match tx.status {
+            TransactionStatus::Pending => tf("Block #{} was reorganized, waiting for re-inclusion", &[&block]),
+            TransactionStatus::Failed => tf("Block #{} was reorganized, transaction dropped", &[&block]),
+            TransactionStatus::Confirmed => tf("Re-included after reorganization of block #{}", &[&block]),
+        };
+        println!("      {} {}", style(t("Note:")).dim(), style(note).yellow());
+    }
+
+    println!();
+}
+
+/// Body of a message as shown in the inbox
+///
+/// Control characters are blanked so a message cannot drive the terminal.
+pub fn format_message_body(message: &InboxMessage) -> String {
+    match message.text() {
+        Some(text) => text.chars().map(|c| if c.is_control() { ' ' } else { c }).collect(),
+        None if message.encrypted => tf("[encrypted, {} bytes]", &[&message.size()]),
+        None => tf("[binary, {} bytes]", &[&message.size()]),
+    }
+}
+
+/// Print a received message
+pub fn print_message(message: &InboxMessage, sender: Option<&IdentityInfo>) {
+    let name = sender
+        .and_then(format_identity_name)
+        .map(|name| format!(" {}", style(format!("({})", name)).magenta()))
+        .unwrap_or_default();
+    println!(
+        "  {} {} {}{}  {} {}",
+        style(format!("{:>3}.", message.id + 1)).dim(),
+        style(t("From:")).dim(),
+        style(format_address_short(&message.from)).white(),
+        name,
+        style(t("Block:")).dim(),
+        message.sent_at
+    );
+    println!("      {}", format_message_body(message));
     println!();
 }
 
 /// Print transaction history header
 pub fn print_history_header(total: usize, showing: usize, page: usize, total_pages: usize) {
     println!(
-        "  {} {} {} (page {}/{})",
-        style("Showing").dim(),
+        "  {} {} {} {}",
+        style(t("Showing")).dim(),
         style(showing).white(),
-        style(format!("of {} transactions", total)).dim(),
-        page,
-        total_pages.max(1)
+        style(tf("of {} transactions", &[&total])).dim(),
+        tf("(page {}/{})", &[&page, &total_pages.max(1)])
     );
     println!();
 }
@@ -304,12 +395,12 @@ pub fn print_empty_history() {
     println!();
     println!(
         "  {}",
-        style("No transactions found").dim()
+        style(t("No transactions found")).dim()
     );
     println!();
     println!(
         "  {}",
-        style("Send or receive KRAT to see your transaction history here.").dim()
+        style(t("Send or receive KRAT to see your transaction history here.")).dim()
     );
     println!();
 }
@@ -344,6 +435,36 @@ mod tests {
         assert_eq!(format_address_short(addr), "0x01010101...01010101");
     }
 
+    #[test]
+    fn test_format_identity_name() {
+        let identity = |name: Option<&str>, status: &str| IdentityInfo {
+            display_name: name.map(str::to_string),
+            status: status.to_string(),
+        };
+        assert_eq!(format_identity_name(&identity(Some("alice"), "Active")).as_deref(), Some("alice"));
+        assert_eq!(
+            format_identity_name(&identity(Some("alice"), "Declared")).as_deref(),
+            Some("alice, unverified")
+        );
+        assert_eq!(format_identity_name(&identity(Some("alice"), "Revoked")), None);
+        assert_eq!(format_identity_name(&identity(None, "Active")), None);
+    }
+
+    #[test]
+    fn test_format_message_body() {
+        let message = |body: &[u8], encrypted| InboxMessage {
+            id: 0,
+            from: format!("0x{}", "01".repeat(32)),
+            body: format!("0x{}", hex::encode(body)),
+            encrypted,
+            sent_at: 10,
+        };
+        assert_eq!(format_message_body(&message(b"hello bob", false)), "hello bob");
+        assert_eq!(format_message_body(&message(b"\x1b[2Jhi\n", false)), " [2Jhi ");
+        assert_eq!(format_message_body(&message(b"hello bob", true)), "[encrypted, 9 bytes]");
+        assert_eq!(format_message_body(&message(&[0xff, 0xfe], false)), "[binary, 2 bytes]");
+    }
+
     #[test]
     fn test_format_timestamp() {
         // Test "Just now"